@@ -0,0 +1,281 @@
+//! Opt-in gameplay analytics
+//!
+//! Games report coarse events with `analytics_event(name, value)`, e.g.
+//! `"difficulty"` = 2 or `"car"` = 5. The host only keeps per-(name, value)
+//! counts: no timestamps, player names or session identifiers. When the game
+//! exits, the counts are written to an outbox that the library uploads, and
+//! only if the user has turned on analytics sharing in the privacy settings.
+//! Without consent nothing is written to disk.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+/// Maximum length of an event name in bytes
+pub const MAX_EVENT_NAME_LEN: usize = 32;
+
+/// Maximum distinct (name, value) pairs per session
+pub const MAX_EVENT_KEYS: usize = 256;
+
+/// Ticks after which an event can no longer be rolled back
+const ROLLBACK_WINDOW: u64 = 16;
+
+/// Maximum events from ticks still inside the rollback window
+const MAX_PENDING_EVENTS: usize = 1024;
+
+/// Directory (under the data dir) holding batches waiting to be uploaded
+pub fn outbox_dir(data_dir: &Path) -> PathBuf {
+    data_dir.join("analytics").join("outbox")
+}
+
+/// Whether `name` is a valid event name (1-32 chars of `a-z`, `0-9`, `_`, `.`, `-`)
+pub fn is_valid_event_name(name: &str) -> bool {
+    !name.is_empty()
+        && name.len() <= MAX_EVENT_NAME_LEN
+        && name
+            .bytes()
+            .all(|b| matches!(b, b'a'..=b'z' | b'0'..=b'9' | b'_' | b'.' | b'-'))
+}
+
+/// One aggregated event in an uploaded batch
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EventCount {
+    pub name: String,
+    pub value: i32,
+    pub count: u32,
+}
+
+/// Aggregated events from one play session
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AnalyticsBatch {
+    pub game_id: String,
+    pub events: Vec<EventCount>,
+}
+
+/// Host-side analytics buffer for a running game (never rolled back)
+#[derive(Default)]
+pub struct Analytics {
+    /// Where to write the batch on exit (`None` without user consent)
+    outbox: Option<(PathBuf, String)>,
+    /// Events from recent ticks that a rollback may still replace
+    pending: Vec<(u64, String, i32)>,
+    /// Events from ticks no rollback can reach
+    counts: BTreeMap<(String, i32), u32>,
+    /// Latest tick that reported events
+    last_tick: u64,
+}
+
+impl Analytics {
+    /// Write the session's batch into `outbox_dir` on exit
+    pub fn enable_outbox(&mut self, outbox_dir: PathBuf, game_id: String) {
+        self.outbox = Some((outbox_dir, game_id));
+    }
+
+    /// Called before each `update()`. Events recorded at or after `tick`
+    /// came from a timeline that was rolled back and are discarded.
+    pub fn begin_tick(&mut self, tick: u64) {
+        if tick <= self.last_tick {
+            self.pending.retain(|(t, _, _)| *t < tick);
+        }
+        self.last_tick = tick;
+
+        let confirmed = tick.saturating_sub(ROLLBACK_WINDOW);
+        if self.pending.first().is_some_and(|(t, _, _)| *t < confirmed) {
+            let (old, recent) = std::mem::take(&mut self.pending)
+                .into_iter()
+                .partition(|(t, _, _)| *t < confirmed);
+            self.pending = recent;
+            self.add_counts(old);
+        }
+    }
+
+    /// Record an event for `tick`
+    ///
+    /// Returns false if the name is invalid or the buffer is full.
+    pub fn record(&mut self, tick: u64, name: &str, value: i32) -> bool {
+        if !is_valid_event_name(name) {
+            return false;
+        }
+        let key = (name.to_string(), value);
+        let known = self.counts.contains_key(&key)
+            || self
+                .pending
+                .iter()
+                .any(|(_, n, v)| *n == key.0 && *v == key.1);
+        if !known && self.distinct_keys() >= MAX_EVENT_KEYS {
+            return false;
+        }
+        if self.pending.len() >= MAX_PENDING_EVENTS {
+            return false;
+        }
+        self.pending.push((tick, key.0, value));
+        self.last_tick = self.last_tick.max(tick);
+        true
+    }
+
+    /// Number of distinct (name, value) pairs recorded so far
+    fn distinct_keys(&self) -> usize {
+        let mut keys: Vec<_> = self.counts.keys().map(|(n, v)| (n.as_str(), *v)).collect();
+        keys.extend(self.pending.iter().map(|(_, n, v)| (n.as_str(), *v)));
+        keys.sort_unstable();
+        keys.dedup();
+        keys.len()
+    }
+
+    fn add_counts(&mut self, events: Vec<(u64, String, i32)>) {
+        for (_, name, value) in events {
+            let count = self.counts.entry((name, value)).or_insert(0);
+            *count = count.saturating_add(1);
+        }
+    }
+
+    /// Fold pending events into the counts
+    fn confirm_pending(&mut self) {
+        let pending = std::mem::take(&mut self.pending);
+        self.add_counts(pending);
+    }
+
+    /// Aggregate everything recorded so far
+    pub fn batch(&mut self, game_id: &str) -> AnalyticsBatch {
+        self.confirm_pending();
+        AnalyticsBatch {
+            game_id: game_id.to_string(),
+            events: self
+                .counts
+                .iter()
+                .map(|((name, value), &count)| EventCount {
+                    name: name.clone(),
+                    value: *value,
+                    count,
+                })
+                .collect(),
+        }
+    }
+
+    /// Write the batch to the outbox (no-op without consent or events)
+    pub fn flush(&mut self) -> io::Result<()> {
+        let Some((dir, game_id)) = self.outbox.clone() else {
+            return Ok(());
+        };
+        let batch = self.batch(&game_id);
+        if batch.events.is_empty() {
+            return Ok(());
+        }
+
+        fs::create_dir_all(&dir)?;
+        let stamp = chrono::Local::now().format("%Y%m%d-%H%M%S%3f");
+        let path = dir.join(format!("{}-{}.json", game_id, stamp));
+        let json = serde_json::to_vec(&batch).map_err(io::Error::other)?;
+        fs::write(path, json)?;
+
+        self.counts.clear();
+        Ok(())
+    }
+}
+
+impl Drop for Analytics {
+    fn drop(&mut self) {
+        if let Err(e) = self.flush() {
+            tracing::warn!(error = %e, "Failed to write analytics batch");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_event_names() {
+        assert!(is_valid_event_name("difficulty"));
+        assert!(is_valid_event_name("stage.3-boss_rush"));
+        assert!(!is_valid_event_name(""));
+        assert!(!is_valid_event_name("Player Name"));
+        assert!(!is_valid_event_name(&"a".repeat(MAX_EVENT_NAME_LEN + 1)));
+    }
+
+    #[test]
+    fn test_events_are_aggregated() {
+        let mut analytics = Analytics::default();
+        analytics.begin_tick(1);
+        assert!(analytics.record(1, "car", 5));
+        assert!(analytics.record(1, "car", 5));
+        analytics.begin_tick(2);
+        assert!(analytics.record(2, "car", 2));
+        assert!(!analytics.record(2, "Bad Name", 0));
+
+        let batch = analytics.batch("neon-drift");
+        assert_eq!(batch.game_id, "neon-drift");
+        assert_eq!(
+            batch.events,
+            vec![
+                EventCount {
+                    name: "car".into(),
+                    value: 2,
+                    count: 1
+                },
+                EventCount {
+                    name: "car".into(),
+                    value: 5,
+                    count: 2
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_rollback_discards_resimulated_events() {
+        let mut analytics = Analytics::default();
+        for tick in 1..=3 {
+            analytics.begin_tick(tick);
+            analytics.record(tick, "stage", tick as i32);
+        }
+
+        // Roll back to tick 2 and re-simulate with a different outcome
+        analytics.begin_tick(2);
+        analytics.record(2, "stage", 7);
+        analytics.begin_tick(3);
+
+        let values: Vec<i32> = analytics
+            .batch("game")
+            .events
+            .iter()
+            .map(|e| e.value)
+            .collect();
+        assert_eq!(values, vec![1, 7]);
+    }
+
+    #[test]
+    fn test_distinct_key_limit() {
+        let mut analytics = Analytics::default();
+        for value in 0..MAX_EVENT_KEYS as i32 {
+            assert!(analytics.record(0, "lap", value));
+        }
+        assert!(!analytics.record(0, "lap", -1));
+        // Existing keys still count
+        assert!(analytics.record(0, "lap", 0));
+    }
+
+    #[test]
+    fn test_flush_requires_outbox() {
+        let dir = tempfile::tempdir().unwrap();
+        let outbox = outbox_dir(dir.path());
+
+        let mut analytics = Analytics::default();
+        analytics.record(0, "difficulty", 1);
+        analytics.flush().unwrap();
+        assert!(!outbox.exists());
+
+        analytics.enable_outbox(outbox.clone(), "game".into());
+        analytics.flush().unwrap();
+        let files: Vec<_> = fs::read_dir(&outbox).unwrap().collect();
+        assert_eq!(files.len(), 1);
+
+        let path = files[0].as_ref().unwrap().path();
+        let batch: AnalyticsBatch = serde_json::from_slice(&fs::read(path).unwrap()).unwrap();
+        assert_eq!(batch.events.len(), 1);
+    }
+}
@@ -7,6 +7,8 @@ use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 
 use crate::app::input::InputConfig;
+use crate::console::{Audio, MixerBus};
+use crate::net::nat::{DEFAULT_STUN_SERVERS, NatConfig};
 
 /// Application configuration.
 ///
@@ -29,6 +31,12 @@ pub struct Config {
     /// Capture (screenshot/GIF) settings
     #[serde(default)]
     pub capture: CaptureConfig,
+    /// Online play (NAT traversal) settings
+    #[serde(default)]
+    pub netplay: NetplayConfig,
+    /// Privacy settings
+    #[serde(default)]
+    pub privacy: PrivacyConfig,
 }
 
 /// Scaling mode for render target to window
@@ -58,6 +66,9 @@ pub struct VideoConfig {
     /// Scaling mode for game framebuffer (default: Stretch)
     #[serde(default)]
     pub scale_mode: ScaleMode,
+    /// Minutes of library inactivity before the attract screen starts (default: 0 = off)
+    #[serde(default)]
+    pub screensaver_minutes: u32,
 }
 
 /// Audio configuration.
@@ -66,6 +77,18 @@ pub struct AudioConfig {
     /// Master volume level (default: 0.8, range: 0.0-1.0)
     #[serde(default = "default_volume")]
     pub master_volume: f32,
+    /// Music bus volume (default: 1.0, range: 0.0-1.0)
+    #[serde(default = "default_bus_volume")]
+    pub music_volume: f32,
+    /// Sound effect bus volume (default: 1.0, range: 0.0-1.0)
+    #[serde(default = "default_bus_volume")]
+    pub sfx_volume: f32,
+    /// Interface bus volume (default: 1.0, range: 0.0-1.0)
+    #[serde(default = "default_bus_volume")]
+    pub ui_volume: f32,
+    /// Voice bus volume (default: 1.0, range: 0.0-1.0)
+    #[serde(default = "default_bus_volume")]
+    pub voice_volume: f32,
 }
 
 /// Debug inspection configuration.
@@ -109,6 +132,44 @@ pub struct CaptureConfig {
     pub gif_max_seconds: u32,
 }
 
+/// Online play configuration.
+///
+/// Controls NAT traversal and in-game lobbies for internet play.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NetplayConfig {
+    /// STUN servers used to discover the public address (default: built-in list)
+    #[serde(default = "default_stun_servers")]
+    pub stun_servers: Vec<String>,
+    /// Relay server used when hole punching fails (default: none)
+    #[serde(default)]
+    pub relay_server: Option<String>,
+    /// Rendezvous server used to advertise and browse in-game lobbies
+    /// (default: none, which disables in-game lobbies)
+    #[serde(default)]
+    pub rendezvous_server: Option<String>,
+}
+
+/// Privacy configuration.
+///
+/// Everything here is opt-in and defaults to off.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct PrivacyConfig {
+    /// Share aggregated gameplay events with game developers (default: false)
+    #[serde(default)]
+    pub analytics: bool,
+}
+
+impl NetplayConfig {
+    /// Build the NAT traversal configuration for an NCHS session
+    pub fn nat_config(&self) -> NatConfig {
+        NatConfig {
+            stun_servers: self.stun_servers.clone(),
+            relay_server: self.relay_server.clone(),
+            ..NatConfig::default()
+        }
+    }
+}
+
 // =============================================================================
 // Default keybindings
 // =============================================================================
@@ -160,6 +221,10 @@ fn default_gif_max_seconds() -> u32 {
     60
 }
 
+fn default_stun_servers() -> Vec<String> {
+    DEFAULT_STUN_SERVERS.iter().map(|s| s.to_string()).collect()
+}
+
 fn default_true() -> bool {
     true
 }
@@ -169,6 +234,9 @@ fn default_scale() -> u32 {
 fn default_volume() -> f32 {
     0.8
 }
+fn default_bus_volume() -> f32 {
+    1.0
+}
 
 impl Default for VideoConfig {
     fn default() -> Self {
@@ -177,6 +245,7 @@ impl Default for VideoConfig {
             vsync: default_true(),
             resolution_scale: default_scale(),
             scale_mode: ScaleMode::default(),
+            screensaver_minutes: 0,
         }
     }
 }
@@ -185,6 +254,40 @@ impl Default for AudioConfig {
     fn default() -> Self {
         Self {
             master_volume: default_volume(),
+            music_volume: default_bus_volume(),
+            sfx_volume: default_bus_volume(),
+            ui_volume: default_bus_volume(),
+            voice_volume: default_bus_volume(),
+        }
+    }
+}
+
+impl AudioConfig {
+    /// Player volume for a mixer bus
+    pub fn bus_volume(&self, bus: MixerBus) -> f32 {
+        match bus {
+            MixerBus::Music => self.music_volume,
+            MixerBus::Sfx => self.sfx_volume,
+            MixerBus::Ui => self.ui_volume,
+            MixerBus::Voice => self.voice_volume,
+        }
+    }
+
+    /// Mutable player volume for a mixer bus (for settings sliders)
+    pub fn bus_volume_mut(&mut self, bus: MixerBus) -> &mut f32 {
+        match bus {
+            MixerBus::Music => &mut self.music_volume,
+            MixerBus::Sfx => &mut self.sfx_volume,
+            MixerBus::Ui => &mut self.ui_volume,
+            MixerBus::Voice => &mut self.voice_volume,
+        }
+    }
+
+    /// Apply the master and bus volumes to an audio backend
+    pub fn apply<A: Audio + ?Sized>(&self, audio: &mut A) {
+        audio.set_master_volume(self.master_volume);
+        for bus in MixerBus::ALL {
+            audio.set_bus_volume(bus, self.bus_volume(bus));
         }
     }
 }
@@ -212,6 +315,16 @@ impl Default for CaptureConfig {
     }
 }
 
+impl Default for NetplayConfig {
+    fn default() -> Self {
+        Self {
+            stun_servers: default_stun_servers(),
+            relay_server: None,
+            rendezvous_server: None,
+        }
+    }
+}
+
 /// Returns the platform-specific configuration directory used by Nethercore.
 ///
 /// Uses `directories::ProjectDirs::from("io.nethercore", "", "Nethercore")`.
@@ -347,7 +460,9 @@ mod tests {
         assert!(!config.video.fullscreen);
         assert!(config.video.vsync);
         assert_eq!(config.video.resolution_scale, 2);
+        assert_eq!(config.video.screensaver_minutes, 0);
         assert!((config.audio.master_volume - 0.8).abs() < f32::EPSILON);
+        assert!(!config.privacy.analytics);
     }
 
     // =============================================================
@@ -362,11 +477,22 @@ mod tests {
                 vsync: false,
                 resolution_scale: 3,
                 scale_mode: ScaleMode::PixelPerfect,
+                screensaver_minutes: 10,
+            },
+            audio: AudioConfig {
+                master_volume: 0.5,
+                sfx_volume: 0.25,
+                ..Default::default()
             },
-            audio: AudioConfig { master_volume: 0.5 },
             input: InputConfig::default(),
             debug: DebugConfig::default(),
             capture: CaptureConfig::default(),
+            netplay: NetplayConfig {
+                stun_servers: vec![],
+                relay_server: Some("relay.example.com:3479".to_string()),
+                rendezvous_server: Some("lobby.example.com:7780".to_string()),
+            },
+            privacy: PrivacyConfig { analytics: true },
         };
 
         let toml_str = toml::to_string(&config).unwrap();
@@ -376,7 +502,20 @@ mod tests {
         assert!(!parsed.video.vsync);
         assert_eq!(parsed.video.resolution_scale, 3);
         assert_eq!(parsed.video.scale_mode, ScaleMode::PixelPerfect);
+        assert_eq!(parsed.video.screensaver_minutes, 10);
         assert!((parsed.audio.master_volume - 0.5).abs() < f32::EPSILON);
+        assert!((parsed.audio.bus_volume(MixerBus::Sfx) - 0.25).abs() < f32::EPSILON);
+        assert!((parsed.audio.bus_volume(MixerBus::Music) - 1.0).abs() < f32::EPSILON);
+        assert!(parsed.netplay.stun_servers.is_empty());
+        assert_eq!(
+            parsed.netplay.relay_server.as_deref(),
+            Some("relay.example.com:3479")
+        );
+        assert_eq!(
+            parsed.netplay.rendezvous_server.as_deref(),
+            Some("lobby.example.com:7780")
+        );
+        assert!(parsed.privacy.analytics);
     }
 
     #[test]
@@ -415,6 +554,8 @@ master_volume = 0.3
 "#;
         let config: Config = toml::from_str(toml_str).unwrap();
         assert!((config.audio.master_volume - 0.3).abs() < f32::EPSILON);
+        // bus volumes added after the config was written default to full
+        assert!((config.audio.voice_volume - 1.0).abs() < f32::EPSILON);
         // video should be default
         assert!(!config.video.fullscreen);
     }
@@ -426,6 +567,7 @@ master_volume = 0.3
             vsync: true,
             resolution_scale: 4,
             scale_mode: ScaleMode::Stretch,
+            screensaver_minutes: 0,
         };
         let toml_str = toml::to_string(&video).unwrap();
         assert!(toml_str.contains("fullscreen = true"));
@@ -435,7 +577,10 @@ master_volume = 0.3
 
     #[test]
     fn test_audio_config_serialize() {
-        let audio = AudioConfig { master_volume: 1.0 };
+        let audio = AudioConfig {
+            master_volume: 1.0,
+            ..Default::default()
+        };
         let toml_str = toml::to_string(&audio).unwrap();
         assert!(toml_str.contains("master_volume = 1.0"));
     }
@@ -446,7 +591,10 @@ master_volume = 0.3
 
     #[test]
     fn test_audio_volume_zero() {
-        let audio = AudioConfig { master_volume: 0.0 };
+        let audio = AudioConfig {
+            master_volume: 0.0,
+            ..Default::default()
+        };
         let toml_str = toml::to_string(&audio).unwrap();
         let parsed: AudioConfig = toml::from_str(&toml_str).unwrap();
         assert!((parsed.master_volume - 0.0).abs() < f32::EPSILON);
@@ -454,7 +602,10 @@ master_volume = 0.3
 
     #[test]
     fn test_audio_volume_max() {
-        let audio = AudioConfig { master_volume: 1.0 };
+        let audio = AudioConfig {
+            master_volume: 1.0,
+            ..Default::default()
+        };
         let toml_str = toml::to_string(&audio).unwrap();
         let parsed: AudioConfig = toml::from_str(&toml_str).unwrap();
         assert!((parsed.master_volume - 1.0).abs() < f32::EPSILON);
@@ -468,6 +619,7 @@ master_volume = 0.3
                 vsync: true,
                 resolution_scale: scale,
                 scale_mode: ScaleMode::default(),
+                screensaver_minutes: 0,
             };
             let toml_str = toml::to_string(&video).unwrap();
             let parsed: VideoConfig = toml::from_str(&toml_str).unwrap();
@@ -69,6 +69,15 @@ fn classify_trap(error: &str) -> (String, Vec<String>) {
                 "Verify value ranges before operations".to_string(),
             ],
         )
+    } else if error_lower.contains("game not responding") || error_lower.contains("interrupt") {
+        (
+            "Game Not Responding".to_string(),
+            vec![
+                "Check for infinite loops in init(), update() or render()".to_string(),
+                "Spread heavy work (generation, loading) over several ticks".to_string(),
+                "Verify loop exit conditions don't depend on state that never changes".to_string(),
+            ],
+        )
     } else if error_lower.contains("integer divide by zero")
         || error_lower.contains("division by zero")
     {
@@ -215,6 +224,12 @@ mod tests {
         assert_eq!(summary, "Stack Overflow");
     }
 
+    #[test]
+    fn test_classify_not_responding() {
+        let (summary, _) = classify_trap("game not responding: init() did not return within 10s");
+        assert_eq!(summary, "Game Not Responding");
+    }
+
     #[test]
     fn test_classify_unknown() {
         let (summary, _) = classify_trap("some unknown error");
@@ -59,6 +59,11 @@ impl InputManager {
             // Analog triggers
             left_trigger: trigger(Axis::LeftZ),
             right_trigger: trigger(Axis::RightZ),
+
+            connected: true,
+
+            // The input manager adds the mouse to player 0
+            pointer: None,
         }
     }
 }
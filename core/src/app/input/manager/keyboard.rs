@@ -92,6 +92,11 @@ impl InputManager {
             right_stick_y,
             left_trigger,
             right_trigger,
+
+            connected: true,
+
+            // The input manager adds the mouse to player 0
+            pointer: None,
         }
     }
 }
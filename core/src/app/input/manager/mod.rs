@@ -1,4 +1,4 @@
-//! Input manager handling keyboard and gamepad
+//! Input manager handling keyboard, gamepad and mouse
 
 mod deadzone;
 #[cfg(feature = "gamepad")]
@@ -8,9 +8,11 @@ mod keyboard;
 mod tests;
 
 use crate::console::RawInput;
+use crate::wasm::MAX_PLAYERS;
 #[cfg(feature = "gamepad")]
 use gilrs::Gilrs;
 use hashbrown::HashMap;
+use winit::event::MouseButton;
 use winit::keyboard::KeyCode;
 
 use super::InputConfig;
@@ -26,15 +28,25 @@ pub struct InputManager {
     /// Input configuration
     config: InputConfig,
 
-    /// Input state for up to 4 players.
+    /// Input state for up to MAX_PLAYERS (8) local players.
     /// Each player can have keyboard enabled (via config.keyboards[i]).
     /// Gamepads are dynamically assigned to the first available slot.
     /// If both keyboard and gamepad are present for a slot, inputs are merged.
-    player_inputs: [RawInput; 4],
+    player_inputs: [RawInput; MAX_PLAYERS],
 
     /// Gamepad ID to player slot mapping
     #[cfg(feature = "gamepad")]
     gamepad_to_player: HashMap<gilrs::GamepadId, usize>,
+
+    /// Name of the gamepad assigned to each player slot (empty if none)
+    #[cfg(feature = "gamepad")]
+    gamepad_names: [String; MAX_PLAYERS],
+
+    /// Mouse position in game screen pixels (None while outside the game)
+    pointer: Option<[f32; 2]>,
+
+    /// Left and right mouse buttons, pressed as A and B for player 0
+    mouse_buttons: [bool; 2],
 }
 
 impl InputManager {
@@ -57,9 +69,13 @@ impl InputManager {
             gilrs,
             keyboard_state: HashMap::new(),
             config,
-            player_inputs: [RawInput::default(); 4],
+            player_inputs: [RawInput::default(); MAX_PLAYERS],
             #[cfg(feature = "gamepad")]
             gamepad_to_player: HashMap::new(),
+            #[cfg(feature = "gamepad")]
+            gamepad_names: Default::default(),
+            pointer: None,
+            mouse_buttons: [false; 2],
         }
     }
 
@@ -68,6 +84,20 @@ impl InputManager {
         self.keyboard_state.insert(key, pressed);
     }
 
+    /// Update the mouse position (game screen pixels, None outside the game)
+    pub fn update_mouse_position(&mut self, position: Option<[f32; 2]>) {
+        self.pointer = position;
+    }
+
+    /// Update mouse button state (left and right only)
+    pub fn update_mouse_button(&mut self, button: MouseButton, pressed: bool) {
+        match button {
+            MouseButton::Left => self.mouse_buttons[0] = pressed,
+            MouseButton::Right => self.mouse_buttons[1] = pressed,
+            _ => {}
+        }
+    }
+
     /// Poll gamepad events and update input state
     #[cfg(feature = "gamepad")]
     pub fn update(&mut self) {
@@ -78,10 +108,11 @@ impl InputManager {
                 match event.event {
                     gilrs::EventType::Connected => {
                         // Find next free player slot (inlined to avoid borrow conflict)
-                        let free_slot = (0..4)
+                        let free_slot = (0..MAX_PLAYERS)
                             .find(|&slot| !self.gamepad_to_player.values().any(|&s| s == slot));
                         if let Some(slot) = free_slot {
                             self.gamepad_to_player.insert(event.id, slot);
+                            self.gamepad_names[slot] = gilrs.gamepad(event.id).name().to_string();
                             tracing::info!("Gamepad {} connected as player {}", event.id, slot);
                         } else {
                             tracing::warn!(
@@ -94,6 +125,7 @@ impl InputManager {
                         if let Some(slot) = self.gamepad_to_player.remove(&event.id) {
                             tracing::info!("Gamepad {} (player {}) disconnected", event.id, slot);
                             self.player_inputs[slot] = RawInput::default();
+                            self.gamepad_names[slot].clear();
                         }
                     }
                     _ => {}
@@ -111,7 +143,7 @@ impl InputManager {
 
         // Then, process keyboard input for all players with keyboard enabled
         // Keyboard merges with gamepad if both are present for the same slot
-        for player in 0..4 {
+        for player in 0..MAX_PLAYERS {
             if let Some(keyboard_input) = self.read_keyboard_input_for_player(player) {
                 let has_gamepad = self.gamepad_to_player.values().any(|&slot| slot == player);
                 if has_gamepad {
@@ -125,23 +157,42 @@ impl InputManager {
             }
             // If no keyboard mapping for this player and no gamepad, leave as default
         }
+
+        self.apply_mouse();
     }
 
     /// Poll events and update input state (keyboard only when gamepad feature is disabled)
     #[cfg(not(feature = "gamepad"))]
     pub fn update(&mut self) {
         // Process keyboard input for all players with keyboard enabled
-        for player in 0..4 {
+        for player in 0..MAX_PLAYERS {
             if let Some(keyboard_input) = self.read_keyboard_input_for_player(player) {
                 self.player_inputs[player] = keyboard_input;
             }
             // If no keyboard mapping for this player, leave as default
         }
+
+        self.apply_mouse();
+    }
+
+    /// Add the mouse to player 0's input
+    ///
+    /// The mouse rides along with whatever drives the first slot, so it does
+    /// nothing until a keyboard mapping or gamepad connects player 0.
+    fn apply_mouse(&mut self) {
+        let input = &mut self.player_inputs[0];
+        if !input.connected {
+            input.pointer = None;
+            return;
+        }
+        input.pointer = self.pointer;
+        input.button_a |= self.mouse_buttons[0];
+        input.button_b |= self.mouse_buttons[1];
     }
 
     /// Get input state for a specific player
     pub fn get_player_input(&self, player: usize) -> RawInput {
-        if player < 4 {
+        if player < MAX_PLAYERS {
             self.player_inputs[player]
         } else {
             RawInput::default()
@@ -149,10 +200,28 @@ impl InputManager {
     }
 
     /// Get all player inputs
-    pub fn get_all_inputs(&self) -> [RawInput; 4] {
+    pub fn get_all_inputs(&self) -> [RawInput; MAX_PLAYERS] {
         self.player_inputs
     }
 
+    /// Name of the controller driving a player slot
+    ///
+    /// Returns the gamepad's name, "Keyboard" for a slot driven only by a
+    /// keyboard mapping, or an empty string if nothing drives it.
+    pub fn controller_name(&self, player: usize) -> &str {
+        #[cfg(feature = "gamepad")]
+        if let Some(name) = self.gamepad_names.get(player)
+            && !name.is_empty()
+        {
+            return name;
+        }
+        if self.config.keyboards.get(player).is_some() {
+            "Keyboard"
+        } else {
+            ""
+        }
+    }
+
     /// Update the input configuration (keyboard mappings, deadzones, etc.)
     pub fn update_config(&mut self, config: InputConfig) {
         self.config = config;
@@ -205,5 +274,9 @@ fn merge_inputs(a: RawInput, b: RawInput) -> RawInput {
         // Triggers: use the larger value
         left_trigger: a.left_trigger.max(b.left_trigger),
         right_trigger: a.right_trigger.max(b.right_trigger),
+
+        connected: a.connected || b.connected,
+
+        pointer: a.pointer.or(b.pointer),
     }
 }
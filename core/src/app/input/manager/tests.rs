@@ -3,6 +3,7 @@
 use super::super::{InputConfig, KeyboardMapping, KeyboardsConfig};
 use super::InputManager;
 use crate::app::input::keycode_serde::{keycode_to_string, string_to_keycode};
+use winit::event::MouseButton;
 use winit::keyboard::KeyCode;
 
 #[test]
@@ -299,8 +300,8 @@ fn test_trigger_deadzone_at_boundary() {
 fn test_get_player_input_valid_range() {
     let manager = InputManager::new(InputConfig::default());
 
-    // Players 0-3 should return valid (default) inputs
-    for i in 0..4 {
+    // Players 0-7 should return valid (default) inputs
+    for i in 0..8 {
         let input = manager.get_player_input(i);
         // All buttons should be false by default
         assert!(!input.button_a);
@@ -312,8 +313,8 @@ fn test_get_player_input_valid_range() {
 fn test_get_player_input_out_of_range() {
     let manager = InputManager::new(InputConfig::default());
 
-    // Players >= 4 should return default input
-    let input = manager.get_player_input(4);
+    // Players >= 8 should return default input
+    let input = manager.get_player_input(8);
     assert!(!input.button_a);
     assert!(!input.dpad_up);
 
@@ -323,10 +324,10 @@ fn test_get_player_input_out_of_range() {
 }
 
 #[test]
-fn test_get_all_inputs_returns_four_players() {
+fn test_get_all_inputs_returns_eight_players() {
     let manager = InputManager::new(InputConfig::default());
     let inputs = manager.get_all_inputs();
-    assert_eq!(inputs.len(), 4);
+    assert_eq!(inputs.len(), 8);
 }
 
 // === Keyboard Input Tests ===
@@ -842,3 +843,28 @@ fn test_all_keys_returns_all_bindings() {
     assert!(keys.contains(&KeyCode::KeyW)); // left_stick_up
     assert!(keys.contains(&KeyCode::Enter)); // start
 }
+
+// === Mouse Input Tests ===
+
+#[test]
+fn test_mouse_drives_player_one() {
+    let mut manager = InputManager::new(InputConfig::default());
+
+    manager.update_mouse_position(Some([100.0, 50.0]));
+    manager.update_mouse_button(MouseButton::Left, true);
+    manager.update();
+
+    let input = manager.get_player_input(0);
+    assert_eq!(input.pointer, Some([100.0, 50.0]));
+    assert!(input.button_a);
+    assert!(!input.button_b);
+    assert_eq!(manager.get_player_input(1).pointer, None);
+
+    manager.update_mouse_position(None);
+    manager.update_mouse_button(MouseButton::Left, false);
+    manager.update();
+
+    let input = manager.get_player_input(0);
+    assert_eq!(input.pointer, None);
+    assert!(!input.button_a);
+}
@@ -28,8 +28,8 @@ pub use preview::{
 };
 pub use session::GameSession;
 pub use standalone::{
-    LoadedRom, RomLoader, StandaloneApp, StandaloneConfig, StandaloneGraphicsSupport,
-    run_standalone,
+    HeadlessPlayConfig, LoadedRom, RomLoader, StandaloneApp, StandaloneConfig,
+    StandaloneGraphicsSupport, run_headless, run_standalone,
 };
 pub use types::{
     AppMode, DebugStats, FRAME_TIME_HISTORY_SIZE, GRAPH_MAX_FRAME_TIME_MS, GameError,
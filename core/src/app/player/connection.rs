@@ -6,14 +6,21 @@ use std::time::{Duration, Instant};
 
 use anyhow::{Context, Result};
 use ggrs::PlayerType;
+use nethercore_shared::netplay_rom_hash;
 
-use crate::console::{Audio, Console};
+use crate::console::Console;
+use crate::daily::DailyChallenge;
+use crate::net::chat::ChatChannel;
+use crate::net::nat::ConnectionPath;
+use crate::net::nat::config::resolve_addr;
 use crate::net::nchs::SaveConfig;
 use crate::net::nchs::SessionStart;
+use crate::net::voice::VoiceChannel;
 use crate::rollback::{LocalSocket, RollbackSession, SessionConfig};
 
 use super::super::GameErrorPhase;
 use super::StandaloneApp;
+use super::error_ui::JoiningPeer;
 use super::types::{RomLoader, StandaloneConfig};
 
 /// Formats a GGRS address from an address string and port
@@ -49,6 +56,8 @@ pub(super) fn format_ggrs_addr(addr: &str, port: u16) -> String {
 pub struct SessionFileResult<C: Console> {
     pub session: RollbackSession<C::Input, C::State, C::RollbackState>,
     pub save_config: Option<SaveConfig>,
+    /// Daily challenge picked by the host
+    pub daily: DailyChallenge,
 }
 
 pub(super) fn decode_session_file(session_file: &std::path::Path) -> Result<SessionStart> {
@@ -78,7 +87,18 @@ pub(super) fn create_session_from_file<C>(
 where
     C: Console + Clone,
 {
-    let mut session_start = decode_session_file(session_file)?;
+    let session_start = decode_session_file(session_file)?;
+    create_session_from_start::<C>(session_start, specs)
+}
+
+/// Performs the GGRS handshake and creates a P2P session from a negotiated NCHS session
+pub(super) fn create_session_from_start<C>(
+    mut session_start: SessionStart,
+    specs: &crate::console::ConsoleSpecs,
+) -> Result<SessionFileResult<C>>
+where
+    C: Console + Clone,
+{
     let save_config = session_start.save_config.take();
 
     tracing::info!(
@@ -107,13 +127,47 @@ where
     );
 
     // Bind to our GGRS port
-    let socket = LocalSocket::bind(&format!("0.0.0.0:{}", own_ggrs_port))
+    let mut socket = LocalSocket::bind(&format!("0.0.0.0:{}", own_ggrs_port))
         .context("Failed to bind GGRS socket")?;
 
+    // Register with the host's relay (if any) so peers that failed to hole punch
+    // during NCHS can still reach us
+    let relay_enabled = match session_start.network_config.relay_server.as_deref() {
+        Some(relay) => match resolve_addr(relay) {
+            Some(relay_addr) => {
+                socket.enable_relay(relay_addr);
+                true
+            }
+            None => {
+                tracing::warn!("Session mode: relay server '{}' did not resolve", relay);
+                false
+            }
+        },
+        None => false,
+    };
+
+    if relay_enabled {
+        for p in session_start
+            .players
+            .iter()
+            .filter(|p| session_start.relayed_peers.contains(&p.handle))
+        {
+            if let Ok(addr) = format_ggrs_addr(&p.addr, p.ggrs_port).parse::<SocketAddr>() {
+                tracing::info!("Session mode: routing player {} via relay", p.handle);
+                socket.route_via_relay(addr);
+            }
+        }
+    }
+
     // Handshake magic bytes to identify our packets
     const HANDSHAKE_HELLO: &[u8] = b"NCHS_HELLO";
     const HANDSHAKE_READY: &[u8] = b"NCHS_READY";
     const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+    // How long a guest tries the host directly before falling back to the relay
+    const DIRECT_HANDSHAKE_WINDOW: Duration = Duration::from_secs(3);
+
+    // Connection path to each remote peer, reported through the net stats
+    let mut peer_paths: HashMap<u8, ConnectionPath> = HashMap::new();
 
     // Perform handshake to ensure all peers are ready before creating GGRS session
     // This prevents the race condition where one side starts sending GGRS packets
@@ -140,10 +194,10 @@ where
                 anyhow::bail!("Timeout waiting for guests to connect");
             }
 
-            // Try to receive HELLO from guests
+            // Try to receive HELLO from guests (directly or via the relay)
             let mut buf = [0u8; 64];
-            match socket.socket().recv_from(&mut buf) {
-                Ok((len, from)) => {
+            match socket.recv_raw(&mut buf) {
+                Ok((len, from, path)) => {
                     if len >= HANDSHAKE_HELLO.len()
                         && &buf[..HANDSHAKE_HELLO.len()] == HANDSHAKE_HELLO
                     {
@@ -154,21 +208,27 @@ where
                                 && !received_from.contains_key(&handle)
                             {
                                 tracing::info!(
-                                    "Session mode: received HELLO from guest {} at {}",
+                                    "Session mode: received HELLO from guest {} at {} ({})",
                                     handle,
-                                    from
+                                    from,
+                                    path
                                 );
                                 received_from.insert(handle, from);
+                                peer_paths.insert(handle, path);
+                                if path.is_relayed() {
+                                    socket.route_via_relay(from);
+                                }
 
                                 // Send READY back immediately
                                 let mut ready_msg = HANDSHAKE_READY.to_vec();
                                 ready_msg.push(session_start.local_player_handle);
-                                let _ = socket.socket().send_to(&ready_msg, from);
+                                let _ = socket.send_raw(&ready_msg, from);
                             }
                         }
                     }
                 }
                 Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => {
+                    socket.keep_relay_alive();
                     std::thread::sleep(Duration::from_millis(10));
                 }
                 Err(_) => {
@@ -208,23 +268,34 @@ where
                 anyhow::bail!("Timeout waiting for host READY");
             }
 
+            // Host isn't answering directly; try through the relay
+            if relay_enabled
+                && start.elapsed() > DIRECT_HANDSHAKE_WINDOW
+                && !socket.connection_path(&host_addr).is_relayed()
+            {
+                tracing::warn!("Session mode: no direct answer from host, trying relay");
+                socket.route_via_relay(host_addr);
+            }
+
             // Send HELLO
             let mut hello_msg = HANDSHAKE_HELLO.to_vec();
             hello_msg.push(session_start.local_player_handle);
-            let _ = socket.socket().send_to(&hello_msg, host_addr);
+            let _ = socket.send_raw(&hello_msg, host_addr);
 
             // Wait a bit for READY
             std::thread::sleep(Duration::from_millis(50));
+            socket.keep_relay_alive();
 
             // Check for READY
             let mut buf = [0u8; 64];
-            match socket.socket().recv_from(&mut buf) {
-                Ok((len, _from)) => {
+            match socket.recv_raw(&mut buf) {
+                Ok((len, _from, path)) => {
                     if len >= HANDSHAKE_READY.len()
                         && &buf[..HANDSHAKE_READY.len()] == HANDSHAKE_READY
                     {
-                        tracing::info!("Session mode: received READY from host");
+                        tracing::info!("Session mode: received READY from host ({})", path);
                         received_ready = true;
+                        peer_paths.insert(0, path);
                     }
                 }
                 Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => {}
@@ -265,9 +336,33 @@ where
         .with_input_delay(session_start.network_config.input_delay as usize);
     session_config.fps = session_start.tick_rate.as_hz() as usize;
 
-    let session = RollbackSession::new_p2p(session_config, socket, players, specs.ram_limit)
+    // Guests were hole punched during NCHS unless they had to fall back to the relay
+    for p in session_start.players.iter().filter(|p| p.active) {
+        if p.handle as usize != local_handle && !peer_paths.contains_key(&p.handle) {
+            let path = if session_start.relayed_peers.contains(&p.handle) && relay_enabled {
+                ConnectionPath::Relayed
+            } else {
+                ConnectionPath::HolePunched
+            };
+            peer_paths.insert(p.handle, path);
+        }
+    }
+
+    // Voice and chat share the GGRS socket; voice stays silent until the game enables it
+    let voice = VoiceChannel::new(session_start.local_player_handle);
+    let chat = ChatChannel::new(session_start.local_player_handle);
+    socket.attach_voice(voice.clone());
+    socket.attach_chat(chat.clone());
+
+    let mut session = RollbackSession::new_p2p(session_config, socket, players, specs.ram_limit)
         .context("Failed to create session from NCHS config")?;
 
+    for (handle, path) in peer_paths {
+        session.set_connection_path(handle as usize, path);
+    }
+    session.set_voice_channel(voice);
+    session.set_chat_channel(chat);
+
     tracing::info!(
         "Session mode: session created, local_players = {:?}",
         session.local_players()
@@ -276,6 +371,7 @@ where
     Ok(SessionFileResult {
         session,
         save_config,
+        daily: session_start.daily,
     })
 }
 
@@ -300,6 +396,10 @@ where
     pub(super) fn poll_for_join_connection(&mut self) -> bool {
         use super::error_ui::JoinConnectionState;
 
+        let rom_hash = self
+            .loaded_rom
+            .as_ref()
+            .map_or(0, |rom| netplay_rom_hash(&rom.rom_hash));
         let joining = match &mut self.joining_peer {
             Some(j) => j,
             None => return false,
@@ -321,10 +421,10 @@ where
             if elapsed_ms / probe_interval_ms > joining.attempt_count as u64 {
                 joining.attempt_count += 1;
 
-                // Send probe packet to host
+                // Send probe packet (with our ROM hash) to host
                 if let Some(peer_addr) = joining.socket.peer_addr() {
-                    let probe_data = super::error_ui::JoiningPeer::PROBE_MAGIC;
-                    if let Err(e) = joining.socket.socket().send_to(probe_data, peer_addr) {
+                    let probe_data = JoiningPeer::probe_packet(rom_hash);
+                    if let Err(e) = joining.socket.socket().send_to(&probe_data, peer_addr) {
                         tracing::debug!("Failed to send probe packet: {}", e);
                     } else {
                         tracing::debug!(
@@ -341,8 +441,17 @@ where
         if joining.state == JoinConnectionState::Connecting {
             let mut buf = [0u8; 128];
             match joining.socket.socket().recv_from(&mut buf) {
+                Ok((len, _)) if &buf[..len] == JoiningPeer::ROM_MISMATCH_MAGIC => {
+                    tracing::warn!("Host rejected connection: ROM hash mismatch");
+                    joining.fail(
+                        "The host is running a different build of this game. \
+                         Both players need the same ROM version.",
+                    );
+                    self.needs_redraw = true;
+                    return false;
+                }
                 Ok((len, from)) => {
-                    // Any response from the host means they're listening
+                    // Any other response from the host means they're listening
                     tracing::info!(
                         "Received response from host {} ({} bytes), connection established",
                         from,
@@ -380,13 +489,16 @@ where
                 ];
                 tracing::info!("Join mode: creating P2P session (host=remote p0, local=p1)");
 
-                match RollbackSession::new_p2p(
-                    session_config,
-                    joining.socket,
-                    players,
-                    specs.ram_limit,
-                ) {
-                    Ok(session) => {
+                let voice = VoiceChannel::new(1);
+                let chat = ChatChannel::new(1);
+                let mut socket = joining.socket;
+                socket.attach_voice(voice.clone());
+                socket.attach_chat(chat.clone());
+
+                match RollbackSession::new_p2p(session_config, socket, players, specs.ram_limit) {
+                    Ok(mut session) => {
+                        session.set_voice_channel(voice);
+                        session.set_chat_channel(chat);
                         tracing::info!(
                             "Join mode: session created, local_players = {:?}",
                             session.local_players()
@@ -414,7 +526,7 @@ where
                                 && let Some(audio) = session.runtime.audio_mut()
                             {
                                 let config = super::super::config::load();
-                                audio.set_master_volume(config.audio.master_volume);
+                                config.audio.apply(audio);
                             }
                         }
                     }
@@ -451,13 +563,58 @@ where
     /// Polls for peer connection in Host mode and creates session when connected
     pub(super) fn poll_for_peer_connection(&mut self) -> bool {
         if let Some(ref mut waiting) = self.waiting_for_peer
-            && let Some(peer_addr) = waiting.socket.poll_for_peer()
+            && let Some((peer_addr, probe)) = waiting.socket.poll_for_peer()
         {
             tracing::info!("Peer connected from {}", peer_addr);
 
             // Take the waiting state to get ownership of the socket
             let waiting = self.waiting_for_peer.take().unwrap();
 
+            // Refuse peers running a different build of the game
+            let local_hash = self
+                .loaded_rom
+                .as_ref()
+                .map(|rom| netplay_rom_hash(&rom.rom_hash));
+            match (local_hash, JoiningPeer::probe_rom_hash(&probe)) {
+                (Some(local), Some(peer)) if local != peer => {
+                    tracing::error!(
+                        "Peer {} has a different ROM (local {:016x}, peer {:016x})",
+                        peer_addr,
+                        local,
+                        peer
+                    );
+                    if let Some(addr) = waiting.socket.peer_addr() {
+                        let _ = waiting
+                            .socket
+                            .socket()
+                            .send_to(JoiningPeer::ROM_MISMATCH_MAGIC, addr);
+                    }
+                    self.error_state = Some(super::super::GameError {
+                        summary: "ROM Mismatch".to_string(),
+                        details: format!(
+                            "{} is running a different build of this game \
+                             (local {:016x}, peer {:016x}).",
+                            peer_addr, local, peer
+                        ),
+                        stack_trace: None,
+                        tick: None,
+                        phase: GameErrorPhase::Update,
+                        suggestions: vec![
+                            "Make sure both players have the same ROM version".to_string(),
+                        ],
+                    });
+                    self.needs_redraw = true;
+                    return true;
+                }
+                (_, None) => {
+                    tracing::warn!(
+                        "Peer {} did not send a ROM hash; builds cannot be verified",
+                        peer_addr
+                    );
+                }
+                _ => {}
+            }
+
             // Create the P2P session now that we have a peer
             if let (Some(rom), Some(runner)) = (&self.loaded_rom, &mut self.runner) {
                 let specs = C::specs();
@@ -471,13 +628,16 @@ where
                 ];
                 tracing::info!("Host mode: creating P2P session (host=local p0, peer=remote p1)");
 
-                match RollbackSession::new_p2p(
-                    session_config,
-                    waiting.socket,
-                    players,
-                    specs.ram_limit,
-                ) {
-                    Ok(session) => {
+                let voice = VoiceChannel::new(0);
+                let chat = ChatChannel::new(0);
+                let mut socket = waiting.socket;
+                socket.attach_voice(voice.clone());
+                socket.attach_chat(chat.clone());
+
+                match RollbackSession::new_p2p(session_config, socket, players, specs.ram_limit) {
+                    Ok(mut session) => {
+                        session.set_voice_channel(voice);
+                        session.set_chat_channel(chat);
                         tracing::info!(
                             "Host mode: session created, local_players = {:?}",
                             session.local_players()
@@ -505,7 +665,7 @@ where
                                 && let Some(audio) = session.runtime.audio_mut()
                             {
                                 let config = super::super::config::load();
-                                audio.set_master_volume(config.audio.master_volume);
+                                config.audio.apply(audio);
                             }
                         }
                     }
@@ -547,9 +707,11 @@ mod tests {
 
         let session_start = SessionStart {
             local_player_handle: 0,
+            relayed_peers: vec![],
             random_seed: 123,
             start_frame: 0,
             tick_rate: TickRate::Fixed60,
+            daily: crate::daily::DailyChallenge::for_day(20_742),
             players: vec![PlayerConnectionInfo {
                 handle: 0,
                 active: true,
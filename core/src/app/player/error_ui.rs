@@ -4,11 +4,14 @@ use std::time::{Duration, Instant};
 
 use super::super::GameError;
 use crate::rollback::LocalSocket;
+use crate::wasm::WatchdogTimeout;
 
 /// Action from error screen UI.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ErrorAction {
     None,
+    /// Resume the game (not-responding screen only)
+    Wait,
     Restart,
     Quit,
 }
@@ -88,6 +91,22 @@ impl JoiningPeer {
     /// Magic bytes for connection acknowledgment
     pub const ACK_MAGIC: &'static [u8] = b"NC_ACK";
 
+    /// Magic bytes the host sends when the joiner's ROM hash differs
+    pub const ROM_MISMATCH_MAGIC: &'static [u8] = b"NC_ROM_MISMATCH";
+
+    /// Probe packet: [`Self::PROBE_MAGIC`] followed by the joiner's netplay ROM hash
+    pub fn probe_packet(rom_hash: u64) -> Vec<u8> {
+        let mut packet = Self::PROBE_MAGIC.to_vec();
+        packet.extend_from_slice(&rom_hash.to_le_bytes());
+        packet
+    }
+
+    /// ROM hash carried by a probe packet (`None` for probes without one)
+    pub fn probe_rom_hash(packet: &[u8]) -> Option<u64> {
+        let hash = packet.strip_prefix(Self::PROBE_MAGIC)?;
+        Some(u64::from_le_bytes(hash.try_into().ok()?))
+    }
+
     pub fn new(socket: LocalSocket, address: String) -> Self {
         Self {
             socket,
@@ -257,6 +276,58 @@ pub fn render_error_screen(ctx: &egui::Context, error: &GameError) -> ErrorActio
     action
 }
 
+/// Render the "game not responding" overlay after a watchdog timeout.
+pub fn render_not_responding_screen(ctx: &egui::Context, timeout: &WatchdogTimeout) -> ErrorAction {
+    let mut action = ErrorAction::None;
+
+    egui::Window::new("Game Not Responding")
+        .collapsible(false)
+        .resizable(false)
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .default_width(400.0)
+        .show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                ui.label(
+                    egui::RichText::new("⏳")
+                        .size(24.0)
+                        .color(egui::Color32::YELLOW),
+                );
+                ui.heading("The game is not responding");
+            });
+
+            ui.add_space(10.0);
+            ui.label(format!(
+                "{}() did not return within {:.1} seconds. The frame was skipped \
+                 and the game is paused.",
+                timeout.export,
+                timeout.timeout.as_secs_f32()
+            ));
+
+            ui.add_space(15.0);
+            ui.separator();
+            ui.add_space(10.0);
+
+            ui.horizontal(|ui| {
+                if ui.button("Keep Waiting").clicked() {
+                    action = ErrorAction::Wait;
+                }
+                ui.add_space(20.0);
+                if ui.button("Restart Game").clicked() {
+                    action = ErrorAction::Restart;
+                }
+                ui.add_space(20.0);
+                if ui.button("Force Quit").clicked() {
+                    action = ErrorAction::Quit;
+                }
+            });
+
+            ui.add_space(5.0);
+            ui.label(egui::RichText::new("Press Escape to quit").weak().small());
+        });
+
+    action
+}
+
 /// Convert a game name to a URL-safe game ID.
 ///
 /// - Lowercases the string
@@ -374,6 +445,18 @@ mod tests {
         assert_eq!(joining.error, Some("Connection refused".to_string()));
     }
 
+    #[test]
+    fn probe_packet_carries_rom_hash() {
+        let packet = JoiningPeer::probe_packet(0x0123_4567_89AB_CDEF);
+        assert!(packet.starts_with(JoiningPeer::PROBE_MAGIC));
+        assert_eq!(
+            JoiningPeer::probe_rom_hash(&packet),
+            Some(0x0123_4567_89AB_CDEF)
+        );
+        assert_eq!(JoiningPeer::probe_rom_hash(JoiningPeer::PROBE_MAGIC), None);
+        assert_eq!(JoiningPeer::probe_rom_hash(b"hello"), None);
+    }
+
     #[test]
     fn joining_peer_mark_connected_clears_error() {
         let socket = LocalSocket::bind("0.0.0.0:0").expect("bind LocalSocket");
@@ -0,0 +1,212 @@
+//! Headless input playback
+//!
+//! Runs a cart from recorded inputs without a window, graphics or audio
+//! device and reports a checksum of the final rollback state. Two runs of
+//! the same build with the same inputs must produce the same checksum, so
+//! CI can smoke-test example games for determinism regressions.
+//!
+//! Each tick runs `update()` and `render()` like the windowed player; draw
+//! commands are discarded and audio is mixed into a scratch buffer so the
+//! rolled-back audio state advances as it would on screen.
+
+use std::path::PathBuf;
+
+use anyhow::{Context, Result, bail};
+use wasmtime::Linker;
+
+use crate::console::{AudioGenerator, Console};
+use crate::ffi::register_common_ffi;
+use crate::replay::InputSequence;
+use crate::replay::slots;
+use crate::rollback::{RollbackSession, RollbackStateManager};
+use crate::runtime::Runtime;
+use crate::wasm::{GameInstance, WasmEngine, WasmGameContext};
+
+use super::types::RomLoader;
+
+/// Configuration for `--play-inputs ... --headless`
+#[derive(Debug, Clone)]
+pub struct HeadlessPlayConfig {
+    /// ROM file path
+    pub rom_path: PathBuf,
+    /// Delta patches (.ncpatch) applied to the ROM in order before loading
+    pub rom_patches: Vec<PathBuf>,
+    /// Recorded inputs (.ncrp file)
+    pub inputs: PathBuf,
+    /// Ticks to simulate (defaults to the length of the recording)
+    pub ticks: Option<u64>,
+}
+
+/// Loads the ROM and recorded inputs, simulates them headlessly and returns
+/// the final state checksum
+pub fn run_headless<L: RomLoader>(config: &HeadlessPlayConfig) -> Result<u64> {
+    let rom = L::load_rom_patched(&config.rom_path, &config.rom_patches, &[])?;
+    let replay = slots::load_file(&config.inputs)
+        .with_context(|| format!("Failed to load inputs {}", config.inputs.display()))?;
+
+    let layout = rom
+        .console
+        .replay_input_layout()
+        .context("Console does not support replays")?;
+    if replay.header.console_id != layout.console_id() {
+        bail!(
+            "Inputs were recorded on console {} (expected {})",
+            replay.header.console_id,
+            layout.console_id()
+        );
+    }
+
+    let ticks = config.ticks.unwrap_or(replay.inputs.frame_count());
+    tracing::info!(
+        "Playing {} ticks of {} ({} recorded, {} player(s))",
+        ticks,
+        config.inputs.display(),
+        replay.inputs.frame_count(),
+        replay.header.player_count
+    );
+
+    let mut runtime = load_headless(rom.console, &rom.code, replay.header.player_count as usize)?;
+    if let Some(game) = runtime.game_mut() {
+        game.store_mut().data_mut().capabilities = rom.capabilities;
+        game.store_mut().data_mut().rom_hash = rom.rom_hash;
+    }
+    simulate(&mut runtime, &replay.inputs, ticks)
+}
+
+/// Creates a runtime for `wasm_bytes` with a local session and no graphics
+/// or audio device, then runs `init()` and any split initialization
+fn load_headless<C: Console>(
+    console: C,
+    wasm_bytes: &[u8],
+    num_players: usize,
+) -> Result<Runtime<C>> {
+    let specs = C::specs();
+    let engine = WasmEngine::new()?;
+    let module = engine.load_module(wasm_bytes)?;
+    WasmEngine::validate_module_memory(&module, specs.ram_limit)?;
+
+    let mut linker: Linker<WasmGameContext<C::Input, C::State, C::RollbackState>> =
+        Linker::new(engine.engine());
+    register_common_ffi(&mut linker)?;
+    console.register_ffi(&mut linker)?;
+    let game = GameInstance::with_ram_limit(&engine, &module, &linker, specs.ram_limit)?;
+
+    let mut runtime = Runtime::new(console);
+    runtime.load_game(game);
+    runtime.set_tick_rate(specs.tick_rates[specs.default_tick_rate]);
+
+    let session = RollbackSession::new_local(num_players, specs.ram_limit);
+    let player_config = session.player_config();
+    let (player_count, local_mask) = (
+        player_config.num_players(),
+        player_config.local_player_mask(),
+    );
+    runtime.set_session(session);
+    if let Some(game) = runtime.game_mut() {
+        game.configure_session(player_count, local_mask);
+    }
+
+    // Saves stay in memory so results don't depend on the machine's save files
+    runtime.initialize_console_state();
+    runtime.init_game()?;
+    while !runtime.init_step()? {}
+    Ok(runtime)
+}
+
+/// Runs `ticks` ticks fed from `inputs` and returns the final state checksum
+///
+/// Ticks past the end of the recording get no new input.
+fn simulate<C: Console + Clone>(
+    runtime: &mut Runtime<C>,
+    inputs: &InputSequence,
+    ticks: u64,
+) -> Result<u64> {
+    let tick_rate = runtime.tick_rate();
+    let sample_rate = C::AudioGenerator::default_sample_rate();
+    let mut audio_scratch = Vec::new();
+
+    for tick in 0..ticks {
+        if let Some(frame_inputs) = inputs.get_frame(tick) {
+            let console = runtime.console().clone();
+            for (player_idx, bytes) in frame_inputs.iter().enumerate() {
+                let input = console.decode_replay_bytes(bytes);
+                if let Some(game) = runtime.game_mut() {
+                    game.set_input(player_idx, input);
+                }
+                runtime
+                    .add_local_input(player_idx, input)
+                    .map_err(|e| anyhow::anyhow!("Failed to add input: {:?}", e))?;
+            }
+        }
+
+        runtime
+            .step()
+            .with_context(|| format!("Game frame error at tick {}", tick))?;
+
+        if let Some(game) = runtime.game_mut() {
+            C::clear_frame_state(game.console_state_mut());
+        }
+        runtime
+            .render()
+            .with_context(|| format!("Render error at tick {}", tick))?;
+
+        if let Some(game) = runtime.game_mut() {
+            let (ffi_state, rollback_state) = game.ffi_and_rollback_mut();
+            C::AudioGenerator::generate_frame(
+                rollback_state,
+                ffi_state,
+                tick_rate,
+                sample_rate,
+                &mut audio_scratch,
+            );
+        }
+    }
+
+    let game = runtime.game_mut().context("No game loaded")?;
+    let mut state_manager = RollbackStateManager::new(C::specs().ram_limit);
+    let snapshot = state_manager
+        .save_state(game, ticks as i32)
+        .map_err(|e| anyhow::anyhow!("Failed to snapshot final state: {:?}", e))?;
+    Ok(snapshot.checksum)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::TestConsole;
+
+    /// Counts ticks into memory so the checksum tracks how far it ran
+    const COUNTER_WAT: &str = r#"
+        (module
+            (memory (export "memory") 1)
+            (func (export "init"))
+            (func (export "update")
+                (i32.store (i32.const 0) (i32.add (i32.load (i32.const 0)) (i32.const 1))))
+            (func (export "render"))
+        )
+    "#;
+
+    fn checksum(buttons: &[u8], ticks: u64) -> u64 {
+        let wasm = wat::parse_str(COUNTER_WAT).unwrap();
+        let mut runtime = load_headless(TestConsole, &wasm, 1).unwrap();
+        let mut inputs = InputSequence::new();
+        for &b in buttons {
+            inputs.push_frame(vec![vec![b]]);
+        }
+        simulate(&mut runtime, &inputs, ticks).unwrap()
+    }
+
+    #[test]
+    fn test_headless_playback_is_deterministic() {
+        let buttons = [0, 1, 1, 3, 0, 2];
+        assert_eq!(checksum(&buttons, 6), checksum(&buttons, 6));
+    }
+
+    #[test]
+    fn test_headless_checksum_tracks_inputs_and_ticks() {
+        let buttons = [0, 1, 1, 3, 0, 2];
+        assert_ne!(checksum(&buttons, 6), checksum(&[0, 1, 1, 3, 0, 1], 6));
+        // Running past the recording keeps simulating with no new input
+        assert_ne!(checksum(&buttons, 6), checksum(&buttons, 8));
+    }
+}
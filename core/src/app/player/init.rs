@@ -1,5 +1,6 @@
 //! Window and game initialization logic
 
+use std::path::PathBuf;
 use std::sync::Arc;
 use std::time::Instant;
 
@@ -10,12 +11,14 @@ use winit::window::{Fullscreen, Window};
 
 use crate::capture::CaptureSupport;
 use crate::console::{Audio, Console};
+use crate::daily::DailyChallenge;
 use crate::rollback::{ConnectionMode, LocalSocket, RollbackSession, SessionConfig};
 use crate::runner::ConsoleRunner;
+use crate::series::SeriesState;
 
 use super::StandaloneApp;
 use super::error_ui::{WaitingForPeer, sanitize_game_id};
-use super::types::{RomLoader, StandaloneGraphicsSupport};
+use super::types::{LoadedRom, RomLoader, StandaloneGraphicsSupport};
 
 impl<C, L> StandaloneApp<C, L>
 where
@@ -23,6 +26,22 @@ where
     C::Graphics: StandaloneGraphicsSupport,
     L: RomLoader<Console = C>,
 {
+    /// Loads the configured ROM with its patches, applying override packs for
+    /// local sessions
+    pub(super) fn load_rom(&self) -> Result<LoadedRom<C>> {
+        let overrides: &[PathBuf] = match self.config.connection_mode {
+            ConnectionMode::Local => &self.config.override_packs,
+            _ if !self.config.override_packs.is_empty() => {
+                tracing::warn!(
+                    "Override packs are only supported for local sessions; ignoring --override-pack"
+                );
+                &[]
+            }
+            _ => &[],
+        };
+        L::load_rom_patched(&self.config.rom_path, &self.config.rom_patches, overrides)
+    }
+
     /// Called when the window is created, initializes graphics and loads the game
     pub(super) fn on_window_created_impl(
         &mut self,
@@ -36,7 +55,7 @@ where
         }
 
         let started = Instant::now();
-        let rom = L::load_rom(&self.config.rom_path)?;
+        let rom = self.load_rom()?;
         tracing::info!("ROM load took {:?}", started.elapsed());
         self.loaded_rom = Some(rom.clone());
 
@@ -56,19 +75,61 @@ where
         let mut runner = ConsoleRunner::new(console.clone(), window.clone())?;
         tracing::info!("Graphics init took {:?}", started.elapsed());
         runner.graphics_mut().set_scale_mode(self.scale_mode);
+        runner.set_publisher(rom.publisher.clone());
+        runner.set_capabilities(rom.capabilities);
+        runner.set_rom_hash(rom.rom_hash);
+        runner.set_analytics_consent(super::super::config::load().privacy.analytics);
+
+        if self.config.connection_mode.is_networked() && !rom.capabilities.network {
+            anyhow::bail!(
+                "{} does not declare the `network` capability and cannot join online sessions",
+                rom.game_name
+            );
+        }
+
+        if self.config.best_of.is_some()
+            && !matches!(self.config.connection_mode, ConnectionMode::Local)
+        {
+            tracing::warn!("Series mode is only supported for local sessions; ignoring --best-of");
+        }
+
+        if let Some(seed) = self.config.daily_seed {
+            runner.set_daily(Some(DailyChallenge::today_with_seed(seed)));
+        }
+
+        if let Some(render) = &self.config.replay_render {
+            self.replay_render = Some(self.load_replay_render(render, &console, &rom.game_id)?);
+        }
 
         // Create session based on connection mode
         match &self.config.connection_mode {
             ConnectionMode::Local => {
                 // Standard local session (no rollback)
+                if let Some(best_of) = self.config.best_of {
+                    let series = SeriesState::new(best_of);
+                    tracing::info!(
+                        "Starting best-of-{} series ({} wins needed)",
+                        series.best_of,
+                        series.wins_needed()
+                    );
+                    runner.set_series(Some(series));
+                }
+
+                // A rendered replay brings its own player count
+                let num_players = self
+                    .replay_render
+                    .as_ref()
+                    .map_or(self.config.num_players, |job| {
+                        job.replay.header.player_count as usize
+                    });
+
+                // Games can host or join lobbies and restart as a netplay session
+                self.lobby = self.create_lobby_driver(&rom);
+                runner.set_lobby(self.lobby.as_ref().map(|driver| driver.channel()));
+
                 let started = Instant::now();
                 runner
-                    .load_game(
-                        rom.console,
-                        &rom.code,
-                        self.config.num_players,
-                        &rom.game_id,
-                    )
+                    .load_game(rom.console, &rom.code, num_players, &rom.game_id)
                     .context("Failed to load game")?;
                 tracing::info!("Game load (WASM compile/init) took {:?}", started.elapsed());
             }
@@ -178,6 +239,7 @@ where
 
                 let session = session_file_result.session;
                 let save_config = session_file_result.save_config;
+                runner.set_daily(Some(session_file_result.daily));
 
                 let started = Instant::now();
                 runner
@@ -200,10 +262,17 @@ where
             && let Some(audio) = session.runtime.audio_mut()
         {
             let config = super::super::config::load();
-            audio.set_master_volume(config.audio.master_volume);
+            config.audio.apply(audio);
+            // Offline rendering runs faster or slower than realtime; keep it silent
+            if self.replay_render.is_some() {
+                audio.set_master_volume(0.0);
+            }
         }
         if let Some(session) = runner.session() {
             self.capture.set_source_fps(session.runtime.tick_rate());
+            if let Some(job) = self.replay_render.as_mut() {
+                job.fps = session.runtime.tick_rate();
+            }
         }
 
         // Load replay script if specified
@@ -256,6 +325,7 @@ where
         self.window = Some(window);
         self.runner = Some(runner);
         self.next_tick = Instant::now();
+        self.start_session_recording();
 
         tracing::info!("Game loaded: {}", self.config.rom_path.display());
         tracing::info!("Player startup total {:?}", startup_started.elapsed());
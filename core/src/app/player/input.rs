@@ -1,4 +1,4 @@
-//! Input handling for keyboard and gamepad events
+//! Input handling for keyboard, gamepad and mouse events
 
 use winit::event::{ElementState, KeyEvent};
 use winit::keyboard::{KeyCode, PhysicalKey};
@@ -97,4 +97,15 @@ where
             self.input_manager.update_keyboard(key_code, pressed);
         }
     }
+
+    /// Handles mouse movement, given the position in window pixels (None
+    /// when the mouse leaves the window)
+    pub(super) fn handle_cursor_moved(&mut self, position: Option<(f32, f32)>) {
+        let pointer = position.and_then(|(x, y)| {
+            self.runner
+                .as_ref()
+                .and_then(|runner| runner.graphics().window_to_canvas(x, y))
+        });
+        self.input_manager.update_mouse_position(pointer);
+    }
 }
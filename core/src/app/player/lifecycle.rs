@@ -1,10 +1,13 @@
-//! Game lifecycle methods: restart, run_game_frame, execute_draw_commands
+//! Game lifecycle methods: restart, series rounds, loading frames, run_game_frame,
+//! execute_draw_commands
 
 use std::time::Instant;
 
 use smallvec::SmallVec;
 
 use crate::console::{Audio, AudioGenerator, Console, ConsoleResourceManager};
+use crate::series::SERIES_NO_WINNER;
+use crate::wasm::WatchdogTimeout;
 
 use super::super::{FRAME_TIME_HISTORY_SIZE, GameError, GameErrorPhase, RuntimeError};
 use super::StandaloneApp;
@@ -19,10 +22,13 @@ where
     /// Restarts the game, reloading the ROM and resetting state
     pub(super) fn restart_game(&mut self) {
         self.error_state = None;
+        self.not_responding = None;
+        self.tick_history.clear();
+        self.save_session_replay();
 
         // Try to reload ROM if not already loaded
         if self.loaded_rom.is_none() {
-            match L::load_rom(&self.config.rom_path) {
+            match self.load_rom() {
                 Ok(rom) => {
                     self.loaded_rom = Some(rom);
                 }
@@ -50,7 +56,9 @@ where
         let console = rom.console.clone();
 
         if let Some(runner) = &mut self.runner {
-            if let Err(e) = runner.load_game(console, &rom.code, 1, &rom.game_id) {
+            if let Err(e) =
+                runner.load_game(console, &rom.code, self.config.num_players, &rom.game_id)
+            {
                 tracing::error!("Failed to restart game: {}", e);
                 self.error_state = Some(GameError {
                     summary: "Restart Failed".to_string(),
@@ -70,7 +78,7 @@ where
                 && let Some(audio) = session.runtime.audio_mut()
             {
                 let config = super::super::config::load();
-                audio.set_master_volume(config.audio.master_volume);
+                config.audio.apply(audio);
             }
             if let Some(session) = runner.session() {
                 self.capture.set_source_fps(session.runtime.tick_rate());
@@ -79,9 +87,75 @@ where
 
         self.next_tick = Instant::now();
         self.needs_redraw = true;
+        self.start_session_recording();
         tracing::info!("Game restarted successfully");
     }
 
+    /// Records a round result reported via `series_round_end()`
+    ///
+    /// Loads the next round unless the series has been decided. Once decided,
+    /// the running game keeps going with the final standings so it can show
+    /// the champion.
+    pub(super) fn advance_series(&mut self) {
+        let Some(runner) = &mut self.runner else {
+            return;
+        };
+        let Some(game) = runner.session_mut().and_then(|s| s.runtime.game_mut()) else {
+            return;
+        };
+        let Some(winner) = game.state_mut().series_round_result.take() else {
+            return;
+        };
+        let Some(mut series) = runner.series().cloned() else {
+            return;
+        };
+
+        let winner = (winner != SERIES_NO_WINNER).then_some(winner as usize);
+        let next_round = series.record_round(winner);
+        match series.champion() {
+            Some(champion) => {
+                tracing::info!("Series won by player {} ({:?})", champion + 1, series.wins)
+            }
+            None => tracing::info!("Series round {} starting ({:?})", series.round, series.wins),
+        }
+
+        runner.set_series(Some(series.clone()));
+        if next_round {
+            self.restart_game();
+        } else if let Some(game) = runner.session_mut().and_then(|s| s.runtime.game_mut()) {
+            game.state_mut().series = Some(series);
+        }
+    }
+
+    /// Runs one `init_step()` for a game that splits its initialization across
+    /// frames, uploads what it loaded and records the console's loading screen
+    pub(super) fn run_loading_frame(&mut self) -> Result<(), RuntimeError> {
+        let runner = self
+            .runner
+            .as_mut()
+            .ok_or_else(|| RuntimeError("No runner".to_string()))?;
+        let (graphics, session) = runner.graphics_and_session_mut();
+        let session = session.ok_or_else(|| RuntimeError("No session".to_string()))?;
+
+        // Keep netplay peers from timing out while this side loads
+        session.runtime.poll_remote_clients();
+
+        session
+            .runtime
+            .init_step()
+            .map_err(|e| RuntimeError(format!("Init error: {}", e)))?;
+        session
+            .process_pending_resources(graphics)
+            .map_err(|e| RuntimeError(format!("Init error: {}", e)))?;
+
+        if let Some(game) = session.runtime.game_mut() {
+            let state = game.console_state_mut();
+            C::clear_frame_state(state);
+            C::draw_loading_screen(state);
+        }
+        Ok(())
+    }
+
     /// Runs a single game frame: processes input, advances simulation, renders
     ///
     /// Returns (game_running, did_render) or RuntimeError
@@ -96,8 +170,8 @@ where
             .ok_or_else(|| RuntimeError("No session".to_string()))?;
 
         // Get local player handles from session (e.g., [0] for host, [1] for joiner)
-        // Use SmallVec to avoid heap allocation (max 4 local players)
-        let local_players: SmallVec<[usize; 4]> = session
+        // Use SmallVec to avoid heap allocation (max 8 local players)
+        let local_players: SmallVec<[usize; 8]> = session
             .runtime
             .session()
             .map(|s| s.local_players().iter().copied().collect())
@@ -109,10 +183,15 @@ where
             tracing::info!("run_game_frame: local_players = {:?}", local_players);
         }
 
-        // Get inputs: from replay script or from input manager
-        if let Some(ref executor) = self.replay_executor {
-            // Replay mode: use script inputs
-            if let Some(frame_inputs) = executor.current_inputs() {
+        // Get inputs: from a rendered replay, a replay script or the input manager
+        let replay_inputs = match (&self.replay_render, &self.replay_executor) {
+            (Some(job), _) => Some(job.current_inputs()),
+            (None, Some(executor)) => Some(executor.current_inputs()),
+            (None, None) => None,
+        };
+        if let Some(replay_inputs) = replay_inputs {
+            // Replay mode: use recorded/script inputs
+            if let Some(frame_inputs) = replay_inputs {
                 let console = session.runtime.console().clone();
                 for (player_idx, bytes) in frame_inputs.iter().enumerate() {
                     let console_input = console.decode_replay_bytes(bytes);
@@ -153,6 +232,7 @@ where
             }
         }
 
+        let paused = self.frame_controller.is_paused();
         let should_run = self.frame_controller.should_run_tick();
         let time_scale = self.frame_controller.time_scale();
 
@@ -162,24 +242,96 @@ where
             let state = game.state_mut();
             state.debug_paused = self.frame_controller.is_paused();
             state.debug_time_scale = self.frame_controller.time_scale();
+            state.debug_inspector_open = self.debug_panel.visible;
+            for &player in local_players.iter() {
+                let name = self.input_manager.controller_name(player);
+                if state.controller_names[player] != name {
+                    state.controller_names[player] = name.to_string();
+                }
+            }
+        }
+
+        // A hung call in a local game skips the frame and shows the
+        // "not responding" overlay; in netplay it ends the session
+        let is_local = session.runtime.session().is_none();
+
+        // Rewind to a tick picked in the inspector's history scrubber
+        let mut scrubbed = false;
+        if let Some(index) = self.frame_controller.take_scrub()
+            && is_local
+            && let Some(game) = session.runtime.game_mut()
+        {
+            match self.tick_history.restore(game, index) {
+                Ok(()) => scrubbed = true,
+                Err(e) => tracing::warn!("Failed to restore tick history entry: {}", e),
+            }
         }
 
         let tick_start = Instant::now();
-        let (ticks, _alpha) = if should_run {
+        let (ticks, _alpha) = if self.replay_render.is_some() || (paused && should_run) {
+            // Offline rendering and paused stepping: exactly one tick per frame
             session
                 .runtime
-                .frame_with_time_scale(time_scale)
+                .step()
                 .map_err(|e| RuntimeError(format!("Game frame error: {}", e)))?
+        } else if should_run {
+            match session.runtime.frame_with_time_scale(time_scale) {
+                Ok(result) => result,
+                Err(e) => {
+                    if is_local && let Some(timeout) = e.downcast_ref::<WatchdogTimeout>() {
+                        tracing::warn!("{}", timeout);
+                        self.not_responding = Some(*timeout);
+                        return Ok((true, false));
+                    }
+                    return Err(RuntimeError(format!("Game frame error: {}", e)));
+                }
+            }
         } else {
             (0, 0.0)
         };
         let tick_elapsed = tick_start.elapsed();
 
-        let did_render = if ticks > 0 {
-            let tick_time_ms = tick_elapsed.as_secs_f32() * 1000.0 / ticks as f32;
-            self.debug_stats.game_tick_times.push_back(tick_time_ms);
-            while self.debug_stats.game_tick_times.len() > FRAME_TIME_HISTORY_SIZE {
-                self.debug_stats.game_tick_times.pop_front();
+        // Keep recent ticks for scrubbing while the inspector is open
+        if is_local && self.debug_panel.visible {
+            if ticks > 0
+                && let Some(game) = session.runtime.game_mut()
+                && let Err(e) = self.tick_history.record(game)
+            {
+                tracing::warn!("Failed to record tick history: {}", e);
+                self.tick_history.clear();
+            }
+        } else if !self.tick_history.is_empty() {
+            self.tick_history.clear();
+        }
+        self.frame_controller
+            .set_history(self.tick_history.position(), self.tick_history.len());
+
+        // Record every tick's inputs for the session's replay slot
+        if ticks > 0
+            && let Some(recorder) = self.replay_recorder.as_mut()
+            && let Some(game) = session.runtime.game()
+        {
+            let console = session.runtime.console();
+            let players = recorder.config().player_count as usize;
+            let inputs: Vec<Vec<u8>> = game
+                .state()
+                .input_curr
+                .iter()
+                .take(players)
+                .map(|input| console.encode_replay_bytes(input))
+                .collect();
+            for _ in 0..ticks {
+                recorder.record_frame(inputs.clone());
+            }
+        }
+
+        let did_render = if ticks > 0 || scrubbed {
+            if ticks > 0 {
+                let tick_time_ms = tick_elapsed.as_secs_f32() * 1000.0 / ticks as f32;
+                self.debug_stats.game_tick_times.push_back(tick_time_ms);
+                while self.debug_stats.game_tick_times.len() > FRAME_TIME_HISTORY_SIZE {
+                    self.debug_stats.game_tick_times.pop_front();
+                }
             }
 
             if let Some(game) = session.runtime.game_mut() {
@@ -187,10 +339,18 @@ where
             }
 
             let render_start = Instant::now();
-            session
-                .runtime
-                .render()
-                .map_err(|e| RuntimeError(format!("Render error: {}", e)))?;
+            if let Err(e) = session.runtime.render() {
+                if is_local && let Some(timeout) = e.downcast_ref::<WatchdogTimeout>() {
+                    tracing::warn!("{}", timeout);
+                    self.not_responding = Some(*timeout);
+                    return Ok((true, false));
+                }
+                return Err(RuntimeError(format!("Render error: {}", e)));
+            }
+            if let Some(game) = session.runtime.game_mut() {
+                let (state, rollback) = game.ffi_and_rollback_mut();
+                C::draw_overlays(state, rollback);
+            }
             let render_time_ms = render_start.elapsed().as_secs_f32() * 1000.0;
 
             self.debug_stats.game_render_times.push_back(render_time_ms);
@@ -213,8 +373,12 @@ where
         };
 
         // Process audio using the console's AudioGenerator
-        // This handles both synchronous and threaded audio modes automatically
-        if did_render {
+        // This handles both synchronous and threaded audio modes automatically.
+        // While paused, stepped ticks stay silent and the console fades out.
+        if let Some(audio) = session.runtime.audio_mut() {
+            audio.set_paused(paused);
+        }
+        if did_render && !paused {
             let tick_rate = session.runtime.tick_rate();
             let sample_rate = session
                 .runtime
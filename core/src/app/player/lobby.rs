@@ -0,0 +1,126 @@
+//! In-game lobbies: hosting and joining from inside a local session
+//!
+//! A local session gets a [`LobbyDriver`] when the ROM supports netplay and a
+//! rendezvous server is configured. Once the lobby is ready, the game is
+//! restarted as a netplay session with the negotiated players.
+
+use std::time::Instant;
+
+use crate::console::Console;
+use crate::daily::DailyChallenge;
+use crate::net::lobby::LobbyDriver;
+use crate::net::nchs::{NchsConfig, NetworkConfig, PlayerInfo};
+use crate::rollback::ConnectionMode;
+
+use super::super::{GameError, GameErrorPhase};
+use super::StandaloneApp;
+use super::types::{LoadedRom, RomLoader, StandaloneGraphicsSupport};
+
+impl<C, L> StandaloneApp<C, L>
+where
+    C: Console + Clone,
+    C::Graphics: StandaloneGraphicsSupport,
+    L: RomLoader<Console = C>,
+{
+    /// Creates the lobby driver for a local session, if lobbies are available
+    pub(super) fn create_lobby_driver(&self, rom: &LoadedRom<C>) -> Option<LobbyDriver> {
+        if !matches!(self.config.connection_mode, ConnectionMode::Local)
+            || self.replay_render.is_some()
+            || self.config.replay_script.is_some()
+            || !rom.capabilities.network
+        {
+            return None;
+        }
+        let netplay = rom.netplay.filter(|netplay| netplay.supports_netplay())?;
+        let app_config = super::super::config::load();
+        let rendezvous_server = app_config.netplay.rendezvous_server.as_deref()?;
+
+        let config = NchsConfig {
+            netplay,
+            player_info: PlayerInfo {
+                name: "Player".to_string(),
+                color: [100, 149, 237],
+                avatar_id: 0,
+            },
+            network_config: NetworkConfig::default(),
+            save_config: None,
+            daily: self.config.daily_seed.map(DailyChallenge::today_with_seed),
+            nat: app_config.netplay.nat_config(),
+        };
+
+        match LobbyDriver::new(config, rendezvous_server) {
+            Ok(driver) => {
+                tracing::info!(
+                    "In-game lobbies enabled (rendezvous: {})",
+                    rendezvous_server
+                );
+                Some(driver)
+            }
+            Err(e) => {
+                tracing::warn!("In-game lobbies disabled: {}", e);
+                None
+            }
+        }
+    }
+
+    /// Polls the lobby driver and restarts the game as a netplay session once
+    /// the lobby is ready
+    ///
+    /// Returns true if the game was restarted (or failed to restart).
+    pub(super) fn poll_lobby(&mut self) -> bool {
+        let Some(session_start) = self
+            .lobby
+            .as_mut()
+            .and_then(|driver| driver.poll(Instant::now()))
+        else {
+            return false;
+        };
+        self.lobby = None;
+        self.save_session_replay();
+
+        let (Some(rom), Some(runner)) = (&self.loaded_rom, &mut self.runner) else {
+            return false;
+        };
+        runner.set_lobby(None);
+        runner.set_daily(Some(session_start.daily));
+
+        let result = super::connection::create_session_from_start::<C>(session_start, C::specs())
+            .and_then(|result| {
+                runner.load_game_with_session(
+                    rom.console.clone(),
+                    &rom.code,
+                    result.session,
+                    result.save_config,
+                    &rom.game_id,
+                )
+            });
+
+        match result {
+            Ok(()) => {
+                tracing::info!("Lobby: game restarted as a netplay session");
+                if let Some(session) = runner.session_mut()
+                    && let Some(audio) = session.runtime.audio_mut()
+                {
+                    let config = super::super::config::load();
+                    config.audio.apply(audio);
+                }
+            }
+            Err(e) => {
+                tracing::error!("Failed to start lobby session: {:#}", e);
+                self.error_state = Some(GameError {
+                    summary: "Connection Error".to_string(),
+                    details: format!("Failed to start the lobby's game: {:#}", e),
+                    stack_trace: None,
+                    tick: None,
+                    phase: GameErrorPhase::Init,
+                    suggestions: vec![
+                        "Check that UDP traffic is allowed through your firewall".to_string(),
+                    ],
+                });
+            }
+        }
+
+        self.needs_redraw = true;
+        true
+    }
+}
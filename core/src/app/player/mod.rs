@@ -5,10 +5,13 @@
 
 mod connection;
 mod error_ui;
+mod headless;
 mod init;
 mod input;
 mod lifecycle;
+mod lobby;
 mod rendering;
+mod replays;
 #[cfg(test)]
 mod tests;
 mod trait_impl;
@@ -24,8 +27,8 @@ use winit::window::Window;
 
 use crate::capture::ScreenCapture;
 use crate::console::Console;
-use crate::debug::FrameController;
-use crate::replay::ScriptExecutor;
+use crate::debug::{FrameController, TickHistory};
+use crate::replay::{Recorder, ScriptExecutor};
 use crate::runner::ConsoleRunner;
 
 use super::ui::SharedSettingsUi;
@@ -34,8 +37,9 @@ use super::{DebugStats, FRAME_TIME_HISTORY_SIZE, GameError};
 // Re-export types from submodules
 pub use error_ui::{
     ErrorAction, JoinConnectionState, JoiningPeer, WaitingForPeer, parse_key_code,
-    render_error_screen, sanitize_game_id,
+    render_error_screen, render_not_responding_screen, sanitize_game_id,
 };
+pub use headless::{HeadlessPlayConfig, run_headless};
 pub use types::{LoadedRom, RomLoader, StandaloneConfig, StandaloneGraphicsSupport};
 
 /// Generic standalone player application.
@@ -59,6 +63,8 @@ where
     debug_overlay: bool,
     debug_panel: crate::debug::DebugPanel,
     frame_controller: FrameController,
+    /// Recent ticks for the inspector's history scrubber (local games only)
+    tick_history: TickHistory,
     next_tick: Instant,
     last_sim_rendered: bool,
     needs_redraw: bool,
@@ -71,6 +77,8 @@ where
     egui_renderer: Option<egui_wgpu::Renderer>,
     loaded_rom: Option<LoadedRom<C>>,
     error_state: Option<GameError>,
+    /// Set when a game call hit its watchdog deadline (paused until dismissed)
+    not_responding: Option<crate::wasm::WatchdogTimeout>,
     capture: ScreenCapture,
     screenshot_key: KeyCode,
     gif_toggle_key: KeyCode,
@@ -80,10 +88,16 @@ where
     waiting_for_peer: Option<WaitingForPeer>,
     /// State for connecting to a host (Join mode)
     joining_peer: Option<JoiningPeer>,
+    /// In-game lobby driver (Local mode, when a rendezvous server is configured)
+    lobby: Option<crate::net::lobby::LobbyDriver>,
     _vram_limit: usize,
     _loader_marker: std::marker::PhantomData<L>,
     /// Active replay script executor (when --replay is used)
     replay_executor: Option<ScriptExecutor>,
+    /// Recording of the current local session (saved to a replay slot on exit)
+    replay_recorder: Option<Recorder>,
+    /// Offline replay render in progress (when --render-replay is used)
+    replay_render: Option<replays::ReplayRenderJob>,
     /// Console-specific debug panel visibility (F7)
     ///
     /// Consoles can use this flag to show their own debug panels (e.g., EPU debug panel for ZX).
@@ -147,6 +161,7 @@ where
             scale_mode,
             settings_ui,
             frame_controller: FrameController::new(),
+            tick_history: TickHistory::new(C::specs().ram_limit),
             next_tick: now,
             last_sim_rendered: false,
             needs_redraw: true,
@@ -163,20 +178,35 @@ where
             egui_renderer: None,
             loaded_rom: None,
             error_state: None,
+            not_responding: None,
             capture,
             screenshot_key,
             gif_toggle_key,
             network_overlay_visible: false,
             waiting_for_peer: None,
             joining_peer: None,
+            lobby: None,
             _vram_limit: vram_limit,
             _loader_marker: std::marker::PhantomData,
             replay_executor: None,
+            replay_recorder: None,
+            replay_render: None,
             console_debug_panel_visible: false,
         }
     }
 }
 
+impl<C, L> Drop for StandaloneApp<C, L>
+where
+    C: Console + Clone,
+    C::Graphics: StandaloneGraphicsSupport,
+    L: RomLoader<Console = C>,
+{
+    fn drop(&mut self) {
+        self.save_session_replay();
+    }
+}
+
 /// Run a standalone player for the given console.
 pub fn run_standalone<C, L>(config: types::StandaloneConfig) -> Result<()>
 where
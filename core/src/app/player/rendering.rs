@@ -10,14 +10,14 @@ use crate::console::{Audio, Console, ConsoleResourceManager};
 use crate::debug::ActionRequest;
 use crate::debug::registry::RegisteredValue;
 use crate::debug::types::DebugValue;
-use crate::rollback::{ConnectionQuality, SessionType};
+use crate::rollback::{ConnectionQuality, SessionType, SnapshotStats};
 
 use super::connection::JoinConnectionAction;
 use super::error_ui::JoinConnectionState;
 
 use super::super::ui::SettingsAction;
 use super::StandaloneApp;
-use super::error_ui::{ErrorAction, render_error_screen};
+use super::error_ui::{ErrorAction, render_error_screen, render_not_responding_screen};
 use super::types::{RomLoader, StandaloneGraphicsSupport};
 
 impl<C, L> StandaloneApp<C, L>
@@ -34,6 +34,10 @@ where
         // If a screenshot/GIF frame is pending, ensure the render target is freshly rendered
         // on this redraw, even if the sim loop didn't request a new render.
         let needs_capture = self.capture.needs_capture();
+        let render_frame_pending = self
+            .replay_render
+            .as_ref()
+            .is_some_and(|job| job.frame_pending);
 
         // Get clear color before borrowing runner mutably
         let clear_color = self.get_clear_color();
@@ -74,7 +78,8 @@ where
             }
 
             // Render game if we have new content, or if we need a fresh frame for capture.
-            if self.last_sim_rendered || needs_capture {
+            let game_rendered = self.last_sim_rendered || needs_capture || render_frame_pending;
+            if game_rendered {
                 let (graphics, session_opt) = runner.graphics_and_session_mut();
 
                 if let Some(session) = session_opt
@@ -97,6 +102,7 @@ where
                 || self.debug_panel.visible
                 || self.settings_ui.visible
                 || self.error_state.is_some()
+                || self.not_responding.is_some()
                 || self.network_overlay_visible
                 || self.waiting_for_peer.is_some()
                 || self.joining_peer.is_some()
@@ -122,6 +128,7 @@ where
                     let frame_controller = &mut self.frame_controller;
                     let settings_ui = &mut self.settings_ui;
                     let error_state_ref = &self.error_state;
+                    let not_responding_ref = &self.not_responding;
                     let waiting_for_peer_ref = &self.waiting_for_peer;
                     let joining_peer_ref = &self.joining_peer;
                     let network_overlay_visible = self.network_overlay_visible;
@@ -134,7 +141,8 @@ where
                         local_players,
                         total_rollbacks,
                         current_frame,
-                    ): (_, _, SmallVec<[usize; 4]>, _, _) = {
+                        snapshot_stats,
+                    ): (_, _, SmallVec<[usize; 8]>, _, _, _) = {
                         if let Some(game_session) = runner.session() {
                             if let Some(rollback) = game_session.runtime.session() {
                                 (
@@ -143,12 +151,27 @@ where
                                     rollback.local_players().iter().copied().collect(),
                                     rollback.total_rollback_frames(),
                                     rollback.current_frame(),
+                                    rollback.snapshot_stats(),
                                 )
                             } else {
-                                (SessionType::Local, Vec::new(), SmallVec::new(), 0, 0)
+                                (
+                                    SessionType::Local,
+                                    Vec::new(),
+                                    SmallVec::new(),
+                                    0,
+                                    0,
+                                    SnapshotStats::default(),
+                                )
                             }
                         } else {
-                            (SessionType::Local, Vec::new(), SmallVec::new(), 0, 0)
+                            (
+                                SessionType::Local,
+                                Vec::new(),
+                                SmallVec::new(),
+                                0,
+                                0,
+                                SnapshotStats::default(),
+                            )
                         }
                     };
 
@@ -213,7 +236,7 @@ where
                                                 ui.label(format!("P{}: Local", i + 1));
                                             });
                                         } else if stats.connected {
-                                            // Show: P2: 45ms ████████ Good
+                                            // Show: P2: 45ms ████████ Good P2P
                                             ui.horizontal(|ui| {
                                                 ui.label(format!("P{}: {}ms ", i + 1, stats.ping_ms));
 
@@ -232,6 +255,7 @@ where
                                                     .collect();
                                                 ui.colored_label(color, bar);
                                                 ui.label(quality_label);
+                                                ui.small(stats.path.label());
                                             });
                                         } else {
                                             ui.horizontal(|ui| {
@@ -246,6 +270,12 @@ where
                                     ui.separator();
                                     ui.label(format!("Rollbacks: {} frames", total_rollbacks));
                                     ui.label(format!("Frame: {}", current_frame));
+                                    ui.label(format!(
+                                        "Snapshots: {:.0}% smaller, {:.0}/{:.0}\u{b5}s save/load",
+                                        snapshot_stats.savings() * 100.0,
+                                        snapshot_stats.avg_save_micros(),
+                                        snapshot_stats.avg_load_micros()
+                                    ));
                                 });
                         }
 
@@ -254,6 +284,8 @@ where
                                 && let Some(game) = session.runtime.game()
                             {
                                 let registry = game.store().data().debug_registry.clone();
+                                let console_stats =
+                                    session.runtime.console().debug_stats(game.console_state());
 
                                 let read_value = |reg_val: &RegisteredValue| -> Option<DebugValue> {
                                     let mem = game.store().data().game.memory?;
@@ -283,6 +315,7 @@ where
                                 let (_changed, action) = debug_panel.render(
                                     ctx,
                                     &registry,
+                                    &console_stats,
                                     frame_controller,
                                     read_value,
                                     write_value,
@@ -534,6 +567,11 @@ where
                             if action != ErrorAction::None {
                                 *error_action.borrow_mut() = action;
                             }
+                        } else if let Some(timeout) = not_responding_ref {
+                            let action = render_not_responding_screen(ctx, timeout);
+                            if action != ErrorAction::None {
+                                *error_action.borrow_mut() = action;
+                            }
                         }
 
                         // Console-specific debug panel (e.g., EPU panel for ZX)
@@ -658,6 +696,13 @@ where
                             audio.set_master_volume(volume);
                         }
                     }
+                    SettingsAction::SetBusVolume(bus, volume) => {
+                        if let Some(session) = runner.session_mut()
+                            && let Some(audio) = session.runtime.audio_mut()
+                        {
+                            audio.set_bus_volume(bus, volume);
+                        }
+                    }
                     SettingsAction::ResetDefaults => {
                         // Defaults were applied to temp config in UI, nothing else needed
                     }
@@ -677,7 +722,7 @@ where
                         if let Some(session) = runner.session_mut()
                             && let Some(audio) = session.runtime.audio_mut()
                         {
-                            audio.set_master_volume(config.audio.master_volume);
+                            config.audio.apply(audio);
                         }
                         // Update input manager with new keyboard mappings
                         self.input_manager.update_config(config.input.clone());
@@ -693,6 +738,10 @@ where
                 // Apply error actions
                 match error_action.into_inner() {
                     ErrorAction::None => {}
+                    ErrorAction::Wait => {
+                        self.not_responding = None;
+                        self.next_tick = std::time::Instant::now();
+                    }
                     ErrorAction::Restart => {
                         restart_requested = true;
                     }
@@ -721,8 +770,32 @@ where
                 .submit(std::iter::once(encoder.finish()));
             surface_texture.present();
 
+            // Fill the save slot thumbnails the game asked for while drawing this frame
+            let thumbnail_requests = match runner.session_mut() {
+                Some(session) if game_rendered => session.runtime.game_mut().map_or(0, |game| {
+                    game.store_mut().data_mut().thumbnails.take_requests()
+                }),
+                _ => 0,
+            };
+            if thumbnail_requests != 0 {
+                let (width, height) = runner.graphics().render_target_dimensions();
+                let pixels = read_render_target_pixels(
+                    runner.graphics().device(),
+                    runner.graphics().queue(),
+                    runner.graphics().render_target_texture(),
+                    width,
+                    height,
+                );
+                if let Some(session) = runner.session_mut()
+                    && let Some(game) = session.runtime.game_mut()
+                {
+                    let thumbnails = &mut game.store_mut().data_mut().thumbnails;
+                    thumbnails.capture(thumbnail_requests, &pixels, width, height);
+                }
+            }
+
             // Process screen capture
-            if needs_capture {
+            if needs_capture || render_frame_pending {
                 let (width, height) = runner.graphics().render_target_dimensions();
                 let pixels = read_render_target_pixels(
                     runner.graphics().device(),
@@ -731,7 +804,12 @@ where
                     width,
                     height,
                 );
-                self.capture.process_frame(pixels, width, height);
+                if render_frame_pending {
+                    self.encode_render_frame(&pixels, width, height);
+                }
+                if needs_capture {
+                    self.capture.process_frame(pixels, width, height);
+                }
             }
 
             // Check for capture results
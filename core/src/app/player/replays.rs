@@ -0,0 +1,217 @@
+//! Session recording into replay slots and offline replay rendering
+
+use anyhow::{Context, Result, bail};
+
+use crate::console::Console;
+use crate::replay::slots::{self, replays_dir};
+use crate::replay::{
+    Recorder, RecorderConfig, Replay, ReplayRenderConfig, ReplaySource, VideoEncoder,
+};
+use crate::rollback::ConnectionMode;
+
+use super::StandaloneApp;
+use super::types::{RomLoader, StandaloneGraphicsSupport};
+
+/// Progress of a `--render-replay` run
+pub(super) struct ReplayRenderJob {
+    pub(super) config: ReplayRenderConfig,
+    pub(super) replay: Replay,
+    /// Next replay frame to simulate
+    pub(super) frame: u64,
+    /// A simulated frame is waiting to be captured and encoded
+    pub(super) frame_pending: bool,
+    /// Created on the first captured frame, once the render size is known
+    pub(super) encoder: Option<VideoEncoder>,
+    pub(super) fps: u32,
+}
+
+impl ReplayRenderJob {
+    /// Inputs for the next frame to simulate
+    pub(super) fn current_inputs(&self) -> Option<&Vec<Vec<u8>>> {
+        self.replay.inputs.get_frame(self.frame)
+    }
+
+    pub(super) fn is_complete(&self) -> bool {
+        self.frame >= self.replay.inputs.frame_count()
+    }
+}
+
+impl<C, L> StandaloneApp<C, L>
+where
+    C: Console + Clone,
+    C::Graphics: StandaloneGraphicsSupport,
+    L: RomLoader<Console = C>,
+{
+    /// Loads the replay for `--render-replay` and checks it matches this console
+    pub(super) fn load_replay_render(
+        &self,
+        config: &ReplayRenderConfig,
+        console: &C,
+        game_id: &str,
+    ) -> Result<ReplayRenderJob> {
+        let replay = match &config.source {
+            ReplaySource::Slot(slot) => {
+                let data_dir = super::super::config::data_dir()
+                    .context("Failed to determine data directory")?;
+                slots::load_slot(&replays_dir(&data_dir, game_id), *slot)
+                    .with_context(|| format!("Failed to load replay slot {}", slot))?
+            }
+            ReplaySource::File(path) => slots::load_file(path)
+                .with_context(|| format!("Failed to load replay {}", path.display()))?,
+        };
+
+        let layout = console
+            .replay_input_layout()
+            .context("Console does not support replays")?;
+        if replay.header.console_id != layout.console_id() {
+            bail!(
+                "Replay was recorded on console {} (expected {})",
+                replay.header.console_id,
+                layout.console_id()
+            );
+        }
+        if replay.inputs.is_empty() {
+            bail!("Replay contains no frames");
+        }
+
+        tracing::info!(
+            "Rendering replay: {} frames, {} player(s) -> {}",
+            replay.inputs.frame_count(),
+            replay.header.player_count,
+            config.output.display()
+        );
+
+        Ok(ReplayRenderJob {
+            config: config.clone(),
+            replay,
+            frame: 0,
+            frame_pending: false,
+            encoder: None,
+            fps: 60,
+        })
+    }
+
+    /// Starts recording a local session so it can be saved to a replay slot
+    pub(super) fn start_session_recording(&mut self) {
+        if !matches!(self.config.connection_mode, ConnectionMode::Local)
+            || self.replay_executor.is_some()
+            || self.replay_render.is_some()
+        {
+            if self.config.record_inputs.is_some() {
+                tracing::warn!("Only local sessions can be recorded; ignoring --record-inputs");
+            }
+            return;
+        }
+        let Some(layout) = self
+            .runner
+            .as_ref()
+            .and_then(|r| r.session())
+            .and_then(|s| s.runtime.console().replay_input_layout())
+        else {
+            return;
+        };
+
+        let mut recorder = Recorder::new(RecorderConfig {
+            console_id: layout.console_id(),
+            player_count: self.config.num_players as u8,
+            input_size: layout.input_size() as u8,
+            seed: 0,
+            checkpoint_interval: 0,
+            compress: true,
+        });
+        recorder.start();
+        self.replay_recorder = Some(recorder);
+    }
+
+    /// Saves the recorded session as the newest replay slot
+    pub(super) fn save_session_replay(&mut self) {
+        let Some(mut recorder) = self.replay_recorder.take() else {
+            return;
+        };
+        let replay = recorder.stop();
+        if replay.inputs.is_empty() {
+            return;
+        }
+        if let Some(path) = &self.config.record_inputs {
+            match slots::save_file(path, &replay) {
+                Ok(()) => tracing::info!(
+                    "Inputs recorded: {} ({} frames)",
+                    path.display(),
+                    replay.header.frame_count
+                ),
+                Err(e) => tracing::error!("Failed to write {}: {}", path.display(), e),
+            }
+        }
+        let (Some(data_dir), Some(rom)) = (super::super::config::data_dir(), &self.loaded_rom)
+        else {
+            return;
+        };
+        if !nethercore_shared::is_safe_game_id(&rom.game_id) {
+            return;
+        }
+
+        match slots::push_replay(&replays_dir(&data_dir, &rom.game_id), &replay) {
+            Ok(path) => tracing::info!(
+                "Replay saved: {} ({} frames)",
+                path.display(),
+                replay.header.frame_count
+            ),
+            Err(e) => tracing::error!("Failed to save replay: {}", e),
+        }
+    }
+
+    /// Encodes a captured frame of the replay being rendered
+    pub(super) fn encode_render_frame(&mut self, pixels: &[u8], width: u32, height: u32) {
+        let Some(job) = self.replay_render.as_mut() else {
+            return;
+        };
+        job.frame_pending = false;
+
+        let result = (|| -> Result<()> {
+            if job.encoder.is_none() {
+                let size = job.config.resolution.unwrap_or((width, height));
+                job.encoder = Some(VideoEncoder::create(
+                    &job.config.output,
+                    (width, height),
+                    size,
+                    job.fps,
+                )?);
+            }
+            if let Some(encoder) = job.encoder.as_mut() {
+                encoder.write_frame(pixels)?;
+            }
+            Ok(())
+        })();
+        if let Err(e) = result {
+            tracing::error!("Replay render failed: {:#}", e);
+            self.replay_render = None;
+            self.should_exit = true;
+            return;
+        }
+
+        if job.frame % 600 == 0 {
+            tracing::info!(
+                "Rendered {}/{} frames",
+                job.frame,
+                job.replay.inputs.frame_count()
+            );
+        }
+    }
+
+    /// Closes the video of a finished replay render and requests exit
+    pub(super) fn finish_replay_render(&mut self) {
+        let Some(job) = self.replay_render.take() else {
+            return;
+        };
+        if let Some(encoder) = job.encoder {
+            let frames = encoder.frames();
+            match encoder.finish() {
+                Ok(path) => {
+                    tracing::info!("Replay rendered: {} ({} frames)", path.display(), frames)
+                }
+                Err(e) => tracing::error!("Failed to finish replay video: {:#}", e),
+            }
+        }
+        self.should_exit = true;
+    }
+}
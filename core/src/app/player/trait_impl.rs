@@ -55,6 +55,19 @@ where
                 self.handle_key_input(key_event.clone());
                 false
             }
+            WindowEvent::CursorMoved { position, .. } => {
+                self.handle_cursor_moved(Some((position.x as f32, position.y as f32)));
+                false
+            }
+            WindowEvent::CursorLeft { .. } => {
+                self.handle_cursor_moved(None);
+                false
+            }
+            WindowEvent::MouseInput { state, button, .. } => {
+                self.input_manager
+                    .update_mouse_button(*button, state.is_pressed());
+                false
+            }
             _ => false,
         }
     }
@@ -66,7 +79,7 @@ where
     fn advance_simulation(&mut self) {
         self.last_sim_rendered = false;
 
-        if self.error_state.is_some() {
+        if self.error_state.is_some() || self.not_responding.is_some() {
             return;
         }
 
@@ -80,6 +93,11 @@ where
             return;
         }
 
+        // Restart as a netplay session once an in-game lobby is ready
+        if self.poll_lobby() {
+            return;
+        }
+
         // Still waiting for peer - don't run game simulation
         if self.waiting_for_peer.is_some() {
             return;
@@ -91,6 +109,40 @@ where
             return;
         }
 
+        // Rendering a replay: wait until the last frame has been encoded
+        if let Some(job) = &self.replay_render {
+            if job.frame_pending {
+                return;
+            }
+            if job.is_complete() {
+                self.finish_replay_render();
+                return;
+            }
+        }
+
+        // Split initialization: one init_step() per frame behind the loading screen
+        let loading = self
+            .runner
+            .as_ref()
+            .and_then(|r| r.session())
+            .is_some_and(|s| s.runtime.is_loading());
+        if loading {
+            match self.run_loading_frame() {
+                Ok(()) => {
+                    self.last_sim_rendered = true;
+                    self.execute_draw_commands();
+                }
+                Err(e) => {
+                    let game_error =
+                        parse_wasm_error(&anyhow::anyhow!("{}", e.0), None, GameErrorPhase::Init);
+                    tracing::error!("Game error: {}", game_error);
+                    self.error_state = Some(game_error);
+                    self.needs_redraw = true;
+                }
+            }
+            return;
+        }
+
         self.input_manager.update();
 
         let tick_before = self
@@ -108,6 +160,11 @@ where
                     self.execute_draw_commands();
                 }
 
+                if let Some(job) = self.replay_render.as_mut() {
+                    job.frame += 1;
+                    job.frame_pending = did_render;
+                }
+
                 // Advance replay executor and request screenshots
                 if did_render {
                     if let Some(ref mut executor) = self.replay_executor {
@@ -128,6 +185,8 @@ where
                     tracing::info!("Game requested quit");
                     self.should_exit = true;
                 }
+
+                self.advance_series();
             }
             Err(e) => {
                 let error_msg = e.0.clone();
@@ -147,6 +206,11 @@ where
     }
 
     fn update_next_tick(&mut self) {
+        if self.replay_render.is_some() {
+            // Offline rendering is paced by encoding, not the wall clock
+            self.next_tick = Instant::now();
+            return;
+        }
         self.next_tick += self.tick_duration();
     }
 
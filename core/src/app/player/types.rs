@@ -3,6 +3,8 @@
 use std::path::{Path, PathBuf};
 
 use anyhow::Result;
+use nethercore_shared::capabilities::Capabilities;
+use nethercore_shared::netplay::NetplayMetadata;
 use wgpu;
 
 use crate::capture::CaptureSupport;
@@ -33,6 +35,11 @@ pub trait StandaloneGraphicsSupport: CaptureSupport {
 
     /// Set the scale mode for render target to window.
     fn set_scale_mode(&mut self, mode: ScaleMode);
+
+    /// Map a window position (physical pixels) to render target pixels.
+    ///
+    /// Returns None if the position is outside the game image.
+    fn window_to_canvas(&self, x: f32, y: f32) -> Option<[f32; 2]>;
 }
 
 /// Trait for loading ROM files for a specific console.
@@ -44,6 +51,26 @@ pub trait RomLoader: Sized {
 
     /// Load a ROM file from the given path.
     fn load_rom(path: &Path) -> Result<LoadedRom<Self::Console>>;
+
+    /// Load a ROM file, apply delta patches (`.ncpatch`) in order, then apply
+    /// sideloaded override packs to its assets.
+    ///
+    /// Consoles without override pack support ignore `override_packs`. Patches
+    /// can't be ignored (the game would silently run an old version), so
+    /// consoles without patch support fail instead.
+    fn load_rom_patched(
+        path: &Path,
+        rom_patches: &[PathBuf],
+        override_packs: &[PathBuf],
+    ) -> Result<LoadedRom<Self::Console>> {
+        if !rom_patches.is_empty() {
+            anyhow::bail!("ROM patches are not supported by this console");
+        }
+        if !override_packs.is_empty() {
+            tracing::warn!("Override packs are not supported by this console; ignoring");
+        }
+        Self::load_rom(path)
+    }
 }
 
 /// Loaded ROM data ready for execution.
@@ -57,6 +84,15 @@ pub struct LoadedRom<C: Console + Clone> {
     pub game_name: String,
     /// Stable save identity (filesystem-safe, deterministic)
     pub game_id: String,
+    /// Publisher namespace for unlock tokens (filesystem-safe), if known
+    pub publisher: Option<String>,
+    /// Host capabilities the ROM declared (everything for raw WASM)
+    pub capabilities: Capabilities,
+    /// Content hash of the ROM file as loaded (after patches)
+    pub rom_hash: [u8; 32],
+    /// Netplay metadata with the NCHS ROM hash, if the ROM declares it
+    /// (`None` for raw WASM, which can't host in-game lobbies)
+    pub netplay: Option<NetplayMetadata>,
 }
 
 /// Configuration for standalone player.
@@ -69,7 +105,7 @@ pub struct StandaloneConfig {
     pub scale: u32,
     /// Enable debug overlay
     pub debug: bool,
-    /// Number of players (1-4)
+    /// Number of players (1-8)
     pub num_players: usize,
     /// Input delay in frames (0-10)
     pub input_delay: usize,
@@ -77,4 +113,16 @@ pub struct StandaloneConfig {
     pub connection_mode: ConnectionMode,
     /// Replay script path (.ncrs file) for automated playback
     pub replay_script: Option<PathBuf>,
+    /// Run a best-of-N tournament series (local sessions only)
+    pub best_of: Option<u32>,
+    /// Seed for today's daily challenge (None derives it from the date)
+    pub daily_seed: Option<u64>,
+    /// Override packs replacing ROM assets by ID (local sessions only)
+    pub override_packs: Vec<PathBuf>,
+    /// Delta patches (.ncpatch) applied to the ROM in order before loading
+    pub rom_patches: Vec<PathBuf>,
+    /// Re-simulate a recorded replay offline and encode it to a video
+    pub replay_render: Option<crate::replay::ReplayRenderConfig>,
+    /// Also write the recorded local session's inputs to this `.ncrp` file
+    pub record_inputs: Option<PathBuf>,
 }
@@ -32,7 +32,8 @@ impl<C: Console> GameSession<C> {
 
     /// Process pending resources from game state
     ///
-    /// This should be called after game.init() and after each game.render()
+    /// This should be called after game.init(), after each init_step() and
+    /// after each game.render()
     /// to upload resources (textures, meshes, audio) that were requested
     /// during those phases.
     ///
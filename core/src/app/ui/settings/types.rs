@@ -1,6 +1,7 @@
 //! Public types for settings UI actions and tab selection
 
 use crate::app::config::{Config, ScaleMode};
+use crate::console::MixerBus;
 
 /// Actions returned from the settings UI
 #[derive(Debug, Clone)]
@@ -19,6 +20,8 @@ pub enum SettingsAction {
     ToggleFullscreen(bool),
     /// Set volume (apply immediately for preview)
     SetVolume(f32),
+    /// Set one mixer bus volume (apply immediately for preview)
+    SetBusVolume(MixerBus, f32),
 }
 
 /// Settings tab selection
@@ -28,4 +31,5 @@ pub enum SettingsTab {
     Audio,
     Controls,
     Hotkeys,
+    Privacy,
 }
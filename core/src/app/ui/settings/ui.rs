@@ -5,6 +5,7 @@ use winit::keyboard::KeyCode;
 
 use crate::app::config::{Config, ScaleMode};
 use crate::app::input::KeyboardMapping;
+use crate::console::MixerBus;
 
 use super::input_mapping::{InputAxis, InputButton, WaitingFor};
 use super::types::{SettingsAction, SettingsTab};
@@ -129,6 +130,7 @@ impl SharedSettingsUi {
             ui.selectable_value(&mut self.selected_tab, SettingsTab::Audio, "Audio");
             ui.selectable_value(&mut self.selected_tab, SettingsTab::Controls, "Controls");
             ui.selectable_value(&mut self.selected_tab, SettingsTab::Hotkeys, "Hotkeys");
+            ui.selectable_value(&mut self.selected_tab, SettingsTab::Privacy, "Privacy");
         });
 
         ui.separator();
@@ -150,6 +152,9 @@ impl SharedSettingsUi {
                 SettingsTab::Hotkeys => {
                     self.render_hotkeys_tab(ui);
                 }
+                SettingsTab::Privacy => {
+                    self.render_privacy_tab(ui);
+                }
             });
 
         ui.add_space(20.0);
@@ -196,6 +201,21 @@ impl SharedSettingsUi {
         // VSync
         ui.checkbox(&mut video.vsync, "V-Sync");
         ui.label("   Synchronize framerate with display refresh rate");
+        ui.add_space(5.0);
+
+        // Screensaver
+        ui.add(
+            Slider::new(&mut video.screensaver_minutes, 0..=60)
+                .text("Screensaver")
+                .custom_formatter(|n, _| {
+                    if n == 0.0 {
+                        "Off".to_string()
+                    } else {
+                        format!("{:.0} min", n)
+                    }
+                }),
+        );
+        ui.label("   Start the attract screen after the library sits idle");
         ui.add_space(15.0);
 
         // Scale Mode
@@ -273,9 +293,42 @@ impl SharedSettingsUi {
             action = SettingsAction::SetVolume(audio.master_volume);
         }
 
+        ui.add_space(15.0);
+        ui.heading("Mixer");
+        ui.add_space(10.0);
+
+        for bus in MixerBus::ALL {
+            let volume = audio.bus_volume_mut(bus);
+            let old_volume = *volume;
+            ui.add(
+                Slider::new(volume, 0.0..=1.0)
+                    .text(bus.label())
+                    .suffix("%")
+                    .custom_formatter(|n, _| format!("{:.0}", n * 100.0)),
+            );
+            if (*volume - old_volume).abs() > f32::EPSILON {
+                action = SettingsAction::SetBusVolume(bus, *volume);
+            }
+        }
+
+        ui.add_space(5.0);
+        ui.label("   Applied to every game on top of its own mix");
+
         action
     }
 
+    fn render_privacy_tab(&mut self, ui: &mut Ui) {
+        let privacy = &mut self.temp_config.privacy;
+
+        ui.heading("Analytics");
+        ui.add_space(10.0);
+
+        ui.checkbox(&mut privacy.analytics, "Share gameplay statistics");
+        ui.label("   Games can count choices such as difficulty, vehicle or stage.");
+        ui.label("   Only totals are sent to the game's developer: no names,");
+        ui.label("   timestamps or recordings. Off by default.");
+    }
+
     fn render_controls_tab(&mut self, ui: &mut Ui) {
         ui.heading("Keyboard Controls");
         ui.add_space(5.0);
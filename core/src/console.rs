@@ -12,24 +12,61 @@ use wasmtime::Linker;
 use winit::window::Window;
 
 use crate::debug::DebugStat;
+use crate::event::EventQueue;
+use crate::rollback::ConsoleDataVec;
 use crate::wasm::WasmGameContext;
 
 // Re-export ConsoleSpecs from shared crate for convenience
 pub use nethercore_shared::ConsoleSpecs;
 
-/// Console-specific rollback state (host-side, POD for zero-copy serialization)
+/// Console-specific rollback state (host-side)
 ///
 /// This trait represents state that lives on the host side (not in WASM memory)
 /// but still needs to be rolled back during netcode rollback. Examples include:
 /// - Audio playhead positions
 /// - Channel volumes and pan values
 ///
-/// The state must be POD (Plain Old Data) so it can be serialized/deserialized
-/// with zero-copy using bytemuck.
-pub trait ConsoleRollbackState: Pod + Zeroable + Default + Send + 'static {}
+/// The state serializes itself into every snapshot, so large pools that only
+/// some games use can be allocated on first use and left out of snapshots
+/// until then. Fixed-size parts should be POD and copied with bytemuck.
+pub trait ConsoleRollbackState: Clone + Default + Send + 'static {
+    /// Append the state to a snapshot
+    ///
+    /// The bytes are checksummed for desync detection, so they must depend
+    /// only on the state.
+    fn save(&self, out: &mut ConsoleDataVec);
+
+    /// Restore the state from bytes written by [`save`]
+    ///
+    /// Returns `false` if the bytes don't match this console's layout.
+    ///
+    /// [`save`]: ConsoleRollbackState::save
+    fn load(&mut self, data: &[u8]) -> bool;
+
+    /// Queue events derived from this state for `event_poll()`
+    ///
+    /// Called at the start of every tick, before `update()`, so consoles can
+    /// report changes that happen outside FFI calls (such as music beats).
+    fn queue_events(&mut self, _events: &mut EventQueue) {}
+
+    /// Advance state driven directly by input
+    ///
+    /// Called at the start of every tick, before [`queue_events`], with the
+    /// tick's inputs (the console's input type for every player slot, as
+    /// bytes) and the tick length in seconds.
+    ///
+    /// [`queue_events`]: ConsoleRollbackState::queue_events
+    fn begin_tick(&mut self, _inputs: &[u8], _delta_time: f32) {}
+}
 
 // Unit type implementation for consoles with no rollback state
-impl ConsoleRollbackState for () {}
+impl ConsoleRollbackState for () {
+    fn save(&self, _out: &mut ConsoleDataVec) {}
+
+    fn load(&mut self, data: &[u8]) -> bool {
+        data.is_empty()
+    }
+}
 
 /// Trait for console-specific audio generation
 ///
@@ -182,6 +219,11 @@ pub trait Console: Send + 'static {
     /// input bytes into the console's native input type.
     fn decode_replay_bytes(&self, bytes: &[u8]) -> Self::Input;
 
+    /// Encode console input into replay bytes (inverse of `decode_replay_bytes`).
+    ///
+    /// Used by the player to record local sessions into replay slots.
+    fn encode_replay_bytes(&self, input: &Self::Input) -> Vec<u8>;
+
     /// Get the input layout for replay script compilation.
     /// Returns None if the console doesn't support replay scripts.
     fn replay_input_layout(&self) -> Option<Box<dyn crate::replay::script::InputLayout>> {
@@ -232,12 +274,33 @@ pub trait Console: Send + 'static {
         [0.1, 0.1, 0.1, 1.0]
     }
 
+    /// Get the tick rate (Hz) the game chose during init.
+    ///
+    /// Returns None if the game kept the console's default tick rate.
+    /// Default implementation returns None.
+    fn tick_rate_from_state(_state: &Self::State) -> Option<u32> {
+        None
+    }
+
     /// Clear per-frame state before rendering.
     ///
     /// Called at the start of each rendered frame to reset per-frame state
     /// like draw commands. Default implementation does nothing.
     fn clear_frame_state(_state: &mut Self::State) {}
 
+    /// Record the loading screen shown while the game runs `init_step()`.
+    ///
+    /// Called after [`Console::clear_frame_state`] on each loading frame, in
+    /// place of the game's render(). Default implementation draws nothing.
+    fn draw_loading_screen(_state: &mut Self::State) {}
+
+    /// Record host-drawn overlays that follow rollback state (such as shared
+    /// cursors) over the game's frame.
+    ///
+    /// Called after the game's render() on each rendered frame. Default
+    /// implementation draws nothing.
+    fn draw_overlays(_state: &mut Self::State, _rollback: &Self::RollbackState) {}
+
     /// Render console-specific debug UI.
     ///
     /// Called during egui rendering when the console debug panel is visible.
@@ -279,9 +342,9 @@ pub trait ConsoleResourceManager: Send + 'static {
 
     /// Process pending texture/mesh/audio resources from game state
     ///
-    /// Called once after game.init() to upload all resources requested
-    /// during the initialization phase. Should not be called during the
-    /// game loop (resources are init-only).
+    /// Called after game.init() and after each init_step() to upload the
+    /// resources requested during the initialization phase. Should not be
+    /// called during the game loop (resources are init-only).
     fn process_pending_resources(
         &mut self,
         graphics: &mut Self::Graphics,
@@ -326,6 +389,22 @@ pub trait ConsoleInput:
     + Sync
     + 'static
 {
+    /// Whether a controller drives this input
+    ///
+    /// A change between ticks calls the game's `on_player_join()` or
+    /// `on_player_leave()`. Inputs without a connection flag are always
+    /// connected, so those callbacks never fire.
+    fn connected(&self) -> bool {
+        true
+    }
+
+    /// This input with every button released and the analog controls centered
+    ///
+    /// Used to predict remote players under `PredictPolicy::Neutral`. Keep
+    /// the connection state so a prediction never looks like a disconnect.
+    fn neutral(&self) -> Self {
+        Self::default()
+    }
 }
 
 /// Raw input from physical devices
@@ -368,6 +447,12 @@ pub struct RawInput {
     /// Analog triggers (0.0 to 1.0)
     pub left_trigger: f32,
     pub right_trigger: f32,
+
+    /// A keyboard mapping or gamepad drives this player slot
+    pub connected: bool,
+
+    /// Mouse position in game screen pixels, while the mouse is over the game
+    pub pointer: Option<[f32; 2]>,
 }
 
 /// Trait for graphics backends
@@ -390,6 +475,34 @@ pub trait Graphics: Send {
     }
 }
 
+/// Standard mixer buses with a player volume in the host settings
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MixerBus {
+    /// Music, tracker modules and streams
+    Music,
+    /// Sound effects
+    Sfx,
+    /// Menu and interface feedback
+    Ui,
+    /// Dialogue and announcer lines
+    Voice,
+}
+
+impl MixerBus {
+    /// Every bus, in settings menu order
+    pub const ALL: [MixerBus; 4] = [Self::Music, Self::Sfx, Self::Ui, Self::Voice];
+
+    /// Display name for settings menus
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Music => "Music",
+            Self::Sfx => "Sound Effects",
+            Self::Ui => "Interface",
+            Self::Voice => "Voice",
+        }
+    }
+}
+
 /// Trait for audio backends
 pub trait Audio: Send {
     /// Play a sound
@@ -401,6 +514,12 @@ pub trait Audio: Send {
     /// Set the master volume (0.0 - 1.0)
     fn set_master_volume(&mut self, _volume: f32) {}
 
+    /// Set the player's volume for one mixer bus (0.0 - 1.0)
+    ///
+    /// Applied on top of whatever volume the game gives the bus, so the
+    /// same settings slider works for every game.
+    fn set_bus_volume(&mut self, _bus: MixerBus, _volume: f32) {}
+
     /// Get the output sample rate
     fn sample_rate(&self) -> u32 {
         44_100 // Default to CD quality
@@ -410,6 +529,19 @@ pub trait Audio: Send {
     ///
     /// Samples should be interleaved stereo (left, right, left, right, ...).
     fn push_samples(&mut self, _samples: &[f32]) {}
+
+    /// Pause or resume output for the frame debugger.
+    ///
+    /// Called every frame with the debugger's pause state. While paused the
+    /// player stops feeding audio, so backends should fade out rather than
+    /// keep playing or cut off mid-sample.
+    fn set_paused(&mut self, _paused: bool) {}
+
+    /// Start voice chat capture and playback for a netplay session.
+    ///
+    /// Called once when a P2P session carries a voice channel. Backends
+    /// without voice support ignore it.
+    fn attach_voice(&mut self, _voice: crate::net::voice::VoiceChannel) {}
 }
 
 /// Handle to a loaded sound
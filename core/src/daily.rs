@@ -0,0 +1,103 @@
+//! Daily challenges
+//!
+//! Every player gets the same seed on the same UTC day, so a roguelite run or
+//! time trial generated from `daily_seed()` is identical for everybody. Seeds
+//! are derived from the date, so daily challenges work offline; the launcher
+//! may hand the player a seed fetched from the platform instead. Scores from
+//! the same day share the leaderboard category returned by `daily_category()`.
+//!
+//! The challenge is fixed when the game is loaded, so a session that runs past
+//! midnight keeps the seed it started with. In netplay the host picks it and
+//! sends it in `SessionStart`, so peers agree whatever their clocks say.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::rng::splitmix64;
+
+/// Mixed into the day number so daily seeds don't collide with small seeds
+/// games pick themselves
+const DAILY_SEED_SALT: u64 = 0x6E63_6461_696C_7921;
+
+/// Seconds in a UTC day
+const SECONDS_PER_DAY: u64 = 86_400;
+
+/// The daily challenge for one UTC day
+#[derive(Clone, Copy, Debug, PartialEq, Eq, bitcode::Encode, bitcode::Decode)]
+pub struct DailyChallenge {
+    /// Days since the Unix epoch (UTC)
+    pub day: u32,
+    /// Seed shared by every player on that day
+    pub seed: u64,
+}
+
+impl DailyChallenge {
+    /// The challenge for `day`, with the seed derived offline from the date
+    pub fn for_day(day: u32) -> Self {
+        Self {
+            day,
+            seed: splitmix64(day as u64 ^ DAILY_SEED_SALT),
+        }
+    }
+
+    /// Today's challenge, by the system clock
+    pub fn today() -> Self {
+        Self::for_day(current_day())
+    }
+
+    /// Today's challenge with a seed supplied by the platform
+    pub fn today_with_seed(seed: u64) -> Self {
+        Self {
+            day: current_day(),
+            seed,
+        }
+    }
+
+    /// Leaderboard category shared by every run of this challenge
+    /// (`daily-YYYY-MM-DD`)
+    pub fn category(&self) -> String {
+        let date = chrono::DateTime::from_timestamp(self.day as i64 * SECONDS_PER_DAY as i64, 0)
+            .unwrap_or_default()
+            .date_naive();
+        format!("daily-{}", date.format("%Y-%m-%d"))
+    }
+}
+
+/// Days since the Unix epoch (UTC)
+fn current_day() -> u32 {
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    (secs / SECONDS_PER_DAY) as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_seed_is_stable_per_day() {
+        assert_eq!(
+            DailyChallenge::for_day(20_000),
+            DailyChallenge::for_day(20_000)
+        );
+        assert_ne!(
+            DailyChallenge::for_day(20_000).seed,
+            DailyChallenge::for_day(20_001).seed
+        );
+    }
+
+    #[test]
+    fn test_category_is_the_utc_date() {
+        assert_eq!(DailyChallenge::for_day(0).category(), "daily-1970-01-01");
+        assert_eq!(
+            DailyChallenge::for_day(20_742).category(),
+            "daily-2026-10-16"
+        );
+    }
+
+    #[test]
+    fn test_platform_seed_overrides_derived_seed() {
+        assert_eq!(DailyChallenge::today_with_seed(42).seed, 42);
+    }
+}
@@ -23,6 +23,11 @@ pub fn export_as_rust_flat(
         if let Some(current) = read_value(value) {
             let line = format_rust_const(&value.name, &value.value_type, &current);
             output.push_str(&line);
+            if let DebugValue::I32(v) = current
+                && let Some(label) = value.enum_label(v)
+            {
+                output.push_str(&format!(" // {}", label));
+            }
             output.push('\n');
         }
     }
@@ -214,6 +219,15 @@ mod tests {
         assert!(output.contains("const HEALTH: i32 = 100;"));
     }
 
+    #[test]
+    fn test_export_enum_label() {
+        let mut registry = DebugRegistry::new();
+        registry.register_enum("quality", 0x100, vec!["low".into(), "high".into()]);
+
+        let output = export_as_rust_flat(&registry, |_| Some(DebugValue::I32(1)));
+        assert!(output.contains("const QUALITY: i32 = 1; // high"));
+    }
+
     #[test]
     fn test_name_sanitization() {
         // Names with spaces and dashes should be sanitized
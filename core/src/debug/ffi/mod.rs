@@ -9,10 +9,11 @@
 //! - HasDebugRegistry trait
 //!
 //! ❁EDO NOT add FFI function implementations here
-//! ✁EDO add them to domain-specific submodules (register.rs, watch.rs, control.rs)
+//! ✁EDO add them to domain-specific submodules (register.rs, watch.rs, control.rs, plot.rs)
 
 mod actions;
 mod control;
+mod plot;
 mod register;
 mod watch;
 
@@ -40,6 +41,7 @@ where
     watch::register(linker)?;
     control::register(linker)?;
     actions::register(linker)?;
+    plot::register(linker)?;
     Ok(())
 }
 
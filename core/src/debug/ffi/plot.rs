@@ -0,0 +1,50 @@
+//! Debug stat plot FFI functions
+//!
+//! Functions for recording values that the debug inspector graphs over time.
+
+use anyhow::Result;
+use wasmtime::{Caller, Linker};
+
+use crate::console::{ConsoleInput, ConsoleRollbackState};
+use crate::wasm::WasmGameContext;
+
+use super::read_string;
+use super::register::HasDebugRegistry;
+
+/// Register debug plot FFI functions
+pub(super) fn register<I, S, R>(linker: &mut Linker<WasmGameContext<I, S, R>>) -> Result<()>
+where
+    I: ConsoleInput,
+    S: Send + Default + 'static,
+    R: ConsoleRollbackState,
+    WasmGameContext<I, S, R>: HasDebugRegistry,
+{
+    linker.func_wrap("env", "stat_plot", stat_plot::<I, S, R>)?;
+    Ok(())
+}
+
+/// Record a value for the current tick in the named graph
+///
+/// Call from `update()` or `render()`. Values are kept for the last few
+/// seconds; a name containing `/` is drawn as one line of the graph named
+/// by its prefix (e.g. `"boost/p1"` and `"boost/p2"`).
+fn stat_plot<I, S, R>(
+    mut caller: Caller<'_, WasmGameContext<I, S, R>>,
+    name_ptr: u32,
+    name_len: u32,
+    value: f32,
+) where
+    I: ConsoleInput,
+    S: Send + Default + 'static,
+    R: ConsoleRollbackState,
+    WasmGameContext<I, S, R>: HasDebugRegistry,
+{
+    if let Some(name) = read_string(&caller, name_ptr, name_len) {
+        let tick = caller.data().game.tick_count;
+        caller
+            .data_mut()
+            .debug_registry_mut()
+            .plots
+            .record(&name, tick, value);
+    }
+}
@@ -12,6 +12,11 @@ use crate::wasm::WasmGameContext;
 
 use super::read_string;
 
+/// Maximum number of labels accepted by `debug_register_enum`
+const MAX_ENUM_LABELS: u32 = 64;
+/// Maximum byte length of a single enum label
+const MAX_ENUM_LABEL_LEN: usize = 64;
+
 /// Trait to allow generic access to the debug registry
 ///
 /// This trait is implemented for any type that has a `debug_registry` field.
@@ -37,6 +42,7 @@ where
     linker.func_wrap("env", "debug_register_u32", debug_register_u32::<I, S, R>)?;
     linker.func_wrap("env", "debug_register_f32", debug_register_f32::<I, S, R>)?;
     linker.func_wrap("env", "debug_register_bool", debug_register_bool::<I, S, R>)?;
+    linker.func_wrap("env", "debug_register_enum", debug_register_enum::<I, S, R>)?;
 
     // Value registration functions with range constraints
     linker.func_wrap(
@@ -256,6 +262,74 @@ fn debug_register_bool<I, S, R>(
     }
 }
 
+/// Register an i32 shown as a dropdown of named values
+///
+/// `labels_ptr` points to `count` NUL-terminated labels stored back to back
+/// (e.g. `"low\0medium\0high\0"`); value `n` is shown as the n-th label.
+fn debug_register_enum<I, S, R>(
+    mut caller: Caller<'_, WasmGameContext<I, S, R>>,
+    name_ptr: u32,
+    name_len: u32,
+    ptr: u32,
+    labels_ptr: u32,
+    count: u32,
+) where
+    I: ConsoleInput,
+    S: Send + Default + 'static,
+    R: ConsoleRollbackState,
+    WasmGameContext<I, S, R>: HasDebugRegistry,
+{
+    let Some(name) = read_string(&caller, name_ptr, name_len) else {
+        return;
+    };
+    if count == 0 || count > MAX_ENUM_LABELS {
+        tracing::warn!(
+            "debug_register_enum '{}': label count {} out of range (1-{})",
+            name,
+            count,
+            MAX_ENUM_LABELS
+        );
+        return;
+    }
+    let Some(labels) = read_labels(&caller, labels_ptr, count) else {
+        tracing::warn!("debug_register_enum '{}': invalid labels", name);
+        return;
+    };
+    caller
+        .data_mut()
+        .debug_registry_mut()
+        .register_enum(&name, ptr, labels);
+}
+
+/// Read `count` NUL-terminated UTF-8 labels stored back to back
+fn read_labels<I, S, R>(
+    caller: &Caller<'_, WasmGameContext<I, S, R>>,
+    ptr: u32,
+    count: u32,
+) -> Option<Vec<String>>
+where
+    I: ConsoleInput,
+    R: ConsoleRollbackState,
+{
+    let memory = caller.data().game.memory?;
+    let data = memory.data(caller);
+    let start = ptr as usize;
+    let max_len = count as usize * (MAX_ENUM_LABEL_LEN + 1);
+    let end = start.checked_add(max_len)?.min(data.len());
+    let mut rest = data.get(start..end)?;
+
+    let mut labels = Vec::with_capacity(count as usize);
+    for _ in 0..count {
+        let nul = rest.iter().position(|&b| b == 0)?;
+        if nul > MAX_ENUM_LABEL_LEN {
+            return None;
+        }
+        labels.push(std::str::from_utf8(&rest[..nul]).ok()?.to_string());
+        rest = &rest[nul + 1..];
+    }
+    Some(labels)
+}
+
 // ============================================================================
 // Range-constrained registration
 // ============================================================================
@@ -1,10 +1,16 @@
 //! Frame control for debug inspection
 //!
-//! Provides pause, step, and time scale controls for debugging games.
+//! Provides pause, step, and time scale controls for debugging games, plus
+//! requests to rewind to one of the recent ticks kept by [`TickHistory`].
+//!
+//! [`TickHistory`]: super::TickHistory
 
 /// Preset time scale options for the UI
 pub const TIME_SCALE_OPTIONS: [f32; 6] = [0.1, 0.25, 0.5, 1.0, 2.0, 4.0];
 
+/// Initial tick count for the "Run N" control
+pub const DEFAULT_RUN_COUNT: u32 = 10;
+
 /// Frame controller for debug mode
 ///
 /// Controls game execution timing: pause, step, and time scale.
@@ -13,8 +19,14 @@ pub const TIME_SCALE_OPTIONS: [f32; 6] = [0.1, 0.25, 0.5, 1.0, 2.0, 4.0];
 pub struct FrameController {
     /// Whether the game is paused
     paused: bool,
-    /// Whether a single frame step was requested (consumed after one frame)
-    step_requested: bool,
+    /// Ticks still to run while paused (one consumed per frame)
+    pending_steps: u32,
+    /// Tick count used by the "Run N" control
+    run_count: u32,
+    /// Tick history entry the game should rewind to (consumed by the player)
+    scrub_request: Option<usize>,
+    /// Current tick history entry and length (for UI)
+    history: (usize, usize),
     /// Time scale multiplier (1.0 = normal, 0.5 = half speed, etc.)
     time_scale: f32,
     /// Index into TIME_SCALE_OPTIONS for the current time scale
@@ -34,7 +46,10 @@ impl FrameController {
     pub fn new() -> Self {
         Self {
             paused: false,
-            step_requested: false,
+            pending_steps: 0,
+            run_count: DEFAULT_RUN_COUNT,
+            scrub_request: None,
+            history: (0, 0),
             time_scale: 1.0,
             time_scale_index: 3, // Index of 1.0 in TIME_SCALE_OPTIONS
             disabled: false,
@@ -47,7 +62,8 @@ impl FrameController {
     pub fn disable(&mut self) {
         self.disabled = true;
         self.paused = false;
-        self.step_requested = false;
+        self.pending_steps = 0;
+        self.scrub_request = None;
         self.time_scale = 1.0;
         self.time_scale_index = 3;
     }
@@ -79,7 +95,8 @@ impl FrameController {
         }
         self.paused = !self.paused;
         if !self.paused {
-            self.step_requested = false;
+            self.pending_steps = 0;
+            self.scrub_request = None;
         }
     }
 
@@ -90,20 +107,68 @@ impl FrameController {
         }
         self.paused = paused;
         if !paused {
-            self.step_requested = false;
+            self.pending_steps = 0;
+            self.scrub_request = None;
         }
     }
 
     /// Request a single frame step (only works when paused)
     pub fn request_step(&mut self) {
+        self.request_steps(1);
+    }
+
+    /// Request `count` ticks, one per frame (only works when paused)
+    pub fn request_steps(&mut self, count: u32) {
         if self.disabled {
             return;
         }
         if self.paused {
-            self.step_requested = true;
+            self.pending_steps = self.pending_steps.saturating_add(count);
         }
     }
 
+    /// Ticks still queued by step requests
+    pub fn pending_steps(&self) -> u32 {
+        self.pending_steps
+    }
+
+    /// Get the tick count used by the "Run N" control
+    pub fn run_count(&self) -> u32 {
+        self.run_count
+    }
+
+    /// Set the tick count used by the "Run N" control (at least 1)
+    pub fn set_run_count(&mut self, count: u32) {
+        self.run_count = count.max(1);
+    }
+
+    /// Request a rewind to a tick history entry (only works when paused)
+    ///
+    /// Cancels any queued steps, since they were meant to run from the
+    /// current tick.
+    pub fn request_scrub(&mut self, index: usize) {
+        if self.disabled || !self.paused {
+            return;
+        }
+        self.pending_steps = 0;
+        self.scrub_request = Some(index);
+    }
+
+    /// Take the pending rewind request, if any
+    pub fn take_scrub(&mut self) -> Option<usize> {
+        self.scrub_request.take()
+    }
+
+    /// Update the tick history position shown in the UI
+    pub fn set_history(&mut self, position: usize, len: usize) {
+        self.history = (position, len);
+    }
+
+    /// Current tick history entry and number of entries
+    pub fn history(&self) -> (usize, usize) {
+        self.history
+    }
+
     /// Decrease time scale to the previous preset
     pub fn decrease_time_scale(&mut self) {
         if self.disabled {
@@ -152,15 +217,15 @@ impl FrameController {
 
     /// Check if a game tick should run this frame
     ///
-    /// Returns true if the game should update. Consumes the step request if one was pending.
+    /// Returns true if the game should update. Consumes one queued step if any are pending.
     pub fn should_run_tick(&mut self) -> bool {
         if self.disabled {
             return true;
         }
 
         if self.paused {
-            if self.step_requested {
-                self.step_requested = false;
+            if self.pending_steps > 0 {
+                self.pending_steps -= 1;
                 return true;
             }
             return false;
@@ -181,7 +246,9 @@ impl FrameController {
     /// Reset controller to default state
     pub fn reset(&mut self) {
         self.paused = false;
-        self.step_requested = false;
+        self.pending_steps = 0;
+        self.scrub_request = None;
+        self.history = (0, 0);
         self.time_scale = 1.0;
         self.time_scale_index = 3;
         // Note: `disabled` is not reset - that's controlled externally
@@ -235,6 +302,44 @@ mod tests {
         assert!(fc.should_run_tick()); // Always runs when not paused
     }
 
+    #[test]
+    fn test_run_n_ticks() {
+        let mut fc = FrameController::new();
+        fc.set_paused(true);
+
+        fc.request_steps(3);
+        assert_eq!(fc.pending_steps(), 3);
+        assert!(fc.should_run_tick());
+        assert!(fc.should_run_tick());
+        assert!(fc.should_run_tick());
+        assert!(!fc.should_run_tick());
+
+        // Unpausing drops queued steps
+        fc.request_steps(5);
+        fc.set_paused(false);
+        fc.set_paused(true);
+        assert_eq!(fc.pending_steps(), 0);
+
+        fc.set_run_count(0);
+        assert_eq!(fc.run_count(), 1);
+    }
+
+    #[test]
+    fn test_scrub_request() {
+        let mut fc = FrameController::new();
+
+        // Ignored while running
+        fc.request_scrub(2);
+        assert_eq!(fc.take_scrub(), None);
+
+        fc.set_paused(true);
+        fc.request_steps(4);
+        fc.request_scrub(2);
+        assert_eq!(fc.pending_steps(), 0);
+        assert_eq!(fc.take_scrub(), Some(2));
+        assert_eq!(fc.take_scrub(), None);
+    }
+
     #[test]
     fn test_time_scale() {
         let mut fc = FrameController::new();
@@ -0,0 +1,109 @@
+//! Recent tick history for the frame debugger
+//!
+//! Local sessions don't save rollback snapshots, so while the inspector is
+//! open the player keeps its own ring buffer of recent ticks. Scrubbing back
+//! loads one of them; stepping or resuming from there discards the entries
+//! after it, since the game is about to simulate those ticks again.
+
+use std::collections::VecDeque;
+
+use crate::console::{ConsoleInput, ConsoleRollbackState};
+use crate::rollback::{GameStateSnapshot, LoadStateError, RollbackStateManager, SaveStateError};
+use crate::wasm::GameInstance;
+
+/// Number of ticks kept for scrubbing (2 seconds at 60 fps)
+pub const TICK_HISTORY_LEN: usize = 120;
+
+/// Ring buffer of recent game state snapshots
+pub struct TickHistory {
+    /// Snapshot encoder (entries are deltas against its keyframes)
+    states: RollbackStateManager,
+    /// Snapshots in tick order, oldest first
+    entries: VecDeque<GameStateSnapshot>,
+    /// Maximum number of entries
+    capacity: usize,
+    /// Entry the game was rewound to, if it hasn't moved on since
+    cursor: Option<usize>,
+    /// Frame number given to the next snapshot
+    next_frame: i32,
+}
+
+impl TickHistory {
+    /// Create an empty history of [`TICK_HISTORY_LEN`] ticks
+    ///
+    /// `max_state_size` should match the console's RAM limit.
+    pub fn new(max_state_size: usize) -> Self {
+        Self::with_capacity(max_state_size, TICK_HISTORY_LEN)
+    }
+
+    /// Create an empty history keeping at most `capacity` ticks
+    pub fn with_capacity(max_state_size: usize, capacity: usize) -> Self {
+        Self {
+            states: RollbackStateManager::new(max_state_size),
+            entries: VecDeque::with_capacity(capacity),
+            capacity: capacity.max(1),
+            cursor: None,
+            next_frame: 0,
+        }
+    }
+
+    /// Snapshot the game after a tick
+    ///
+    /// If the game was rewound, the entries after the restored one are
+    /// dropped first. The oldest entry is dropped once the history is full.
+    pub fn record<I: ConsoleInput, S: Send + Default + 'static, R: ConsoleRollbackState>(
+        &mut self,
+        game: &mut GameInstance<I, S, R>,
+    ) -> Result<(), SaveStateError> {
+        if let Some(cursor) = self.cursor.take() {
+            self.entries.truncate(cursor + 1);
+        }
+
+        let snapshot = self.states.save_state(game, self.next_frame)?;
+        self.next_frame = self.next_frame.wrapping_add(1);
+        if self.entries.len() == self.capacity {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(snapshot);
+        Ok(())
+    }
+
+    /// Rewind the game to entry `index` (0 = oldest)
+    ///
+    /// Later entries are kept until the game ticks again, so it can be
+    /// scrubbed forward as well as back.
+    pub fn restore<I: ConsoleInput, S: Send + Default + 'static, R: ConsoleRollbackState>(
+        &mut self,
+        game: &mut GameInstance<I, S, R>,
+        index: usize,
+    ) -> Result<(), LoadStateError> {
+        let Some(snapshot) = self.entries.get(index) else {
+            return Ok(());
+        };
+        self.states.load_state(game, snapshot)?;
+        self.cursor = Some(index);
+        Ok(())
+    }
+
+    /// Entry the game currently matches (the newest unless rewound)
+    pub fn position(&self) -> usize {
+        self.cursor
+            .unwrap_or_else(|| self.entries.len().saturating_sub(1))
+    }
+
+    /// Number of recorded ticks
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Check if no ticks are recorded
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Drop all recorded ticks
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.cursor = None;
+    }
+}
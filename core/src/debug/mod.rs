@@ -10,6 +10,8 @@
 //!
 //! - Debug panel UI for viewing/editing registered values
 //! - Frame control (pause, step, slow-mo) for precise observation
+//! - Tick history for scrubbing back through the last couple of seconds
+//! - Line graphs of values the game plots each tick
 //! - Export to copy tuned values back to source code
 //!
 //! # Usage
@@ -51,7 +53,9 @@
 pub mod export;
 pub mod ffi;
 pub mod frame_control;
+pub mod history;
 pub mod panel;
+pub mod plots;
 pub mod registry;
 pub mod stats;
 pub mod types;
@@ -59,7 +63,9 @@ pub mod types;
 // Re-export commonly used types
 pub use ffi::{HasDebugRegistry, register_debug_ffi};
 pub use frame_control::{FrameController, TIME_SCALE_OPTIONS};
+pub use history::{TICK_HISTORY_LEN, TickHistory};
 pub use panel::{ActionRequest, DebugPanel};
+pub use plots::{MAX_PLOT_SERIES, PLOT_HISTORY_LEN, PlotSeries, StatPlots};
 pub use registry::{DebugRegistry, RegisteredAction, RegisteredValue, TreeNode};
 pub use stats::DebugStat;
 pub use types::{ActionParamValue, Constraints, DebugValue, ValueType};
@@ -7,9 +7,11 @@ use hashbrown::HashSet;
 use super::export::export_as_rust_flat;
 use super::frame_control::{FrameController, TIME_SCALE_OPTIONS};
 use super::registry::{DebugRegistry, RegisteredValue, TreeNode};
+use super::stats::DebugStat;
 use super::types::{ActionParamValue, DebugValue};
 
 mod actions;
+mod plots;
 mod widgets;
 
 use actions::ActionRenderer;
@@ -76,6 +78,10 @@ impl DebugPanel {
 
     /// Render the debug panel
     ///
+    /// `console_stats` are shown in a collapsed "Console Stats" section and
+    /// `stat_plot()` values as graphs, so the panel also opens for games that
+    /// register no values.
+    ///
     /// Returns (value_changed, action_request):
     /// - value_changed: true if any value was changed (caller should invoke on_debug_change)
     /// - action_request: Some if an action button was clicked (caller should invoke the action)
@@ -83,11 +89,14 @@ impl DebugPanel {
         &mut self,
         ctx: &egui::Context,
         registry: &DebugRegistry,
+        console_stats: &[DebugStat],
         frame_controller: &mut FrameController,
         read_value: impl Fn(&RegisteredValue) -> Option<DebugValue>,
         write_value: impl Fn(&RegisteredValue, &DebugValue) -> bool,
     ) -> (bool, Option<ActionRequest>) {
-        if !self.visible || registry.is_empty() {
+        if !self.visible
+            || (registry.is_empty() && registry.plots.is_empty() && console_stats.is_empty())
+        {
             return (false, None);
         }
 
@@ -113,6 +122,32 @@ impl DebugPanel {
                     ui.separator();
                 }
 
+                // Console stats (draw calls, triangles…)
+                if !console_stats.is_empty() {
+                    egui::CollapsingHeader::new("Console Stats")
+                        .default_open(false)
+                        .show(ui, |ui| {
+                            egui::Grid::new("console_stats")
+                                .num_columns(2)
+                                .show(ui, |ui| {
+                                    for stat in console_stats {
+                                        ui.label(&stat.name);
+                                        ui.label(&stat.value);
+                                        ui.end_row();
+                                    }
+                                });
+                        });
+                    ui.separator();
+                }
+
+                // Stat plots (stat_plot values over the last few seconds)
+                if !registry.plots.is_empty() {
+                    egui::CollapsingHeader::new("Plots")
+                        .default_open(true)
+                        .show(ui, |ui| plots::render_plots(ui, &registry.plots));
+                    ui.separator();
+                }
+
                 // Scrollable area for values and actions
                 egui::ScrollArea::vertical()
                     .auto_shrink([false, false])
@@ -134,10 +169,11 @@ impl DebugPanel {
                         }
                     });
 
-                ui.separator();
-
                 // Export buttons
-                self.render_export_buttons(ui, registry, &read_value);
+                if !registry.is_empty() {
+                    ui.separator();
+                    self.render_export_buttons(ui, registry, &read_value);
+                }
             });
 
         (any_changed, action_request)
@@ -163,15 +199,46 @@ impl DebugPanel {
                 changed = true;
             }
 
-            // Step frame (only when paused)
+            // Step frame / run N ticks (only when paused)
             ui.add_enabled_ui(frame_controller.is_paused(), |ui| {
                 if ui.button("⏭ Step").clicked() {
                     frame_controller.request_step();
                     changed = true;
                 }
+                if ui.button("⏩ Run").clicked() {
+                    frame_controller.request_steps(frame_controller.run_count());
+                    changed = true;
+                }
+                let mut run_count = frame_controller.run_count();
+                if ui
+                    .add(
+                        egui::DragValue::new(&mut run_count)
+                            .range(1..=600)
+                            .suffix(" ticks"),
+                    )
+                    .changed()
+                {
+                    frame_controller.set_run_count(run_count);
+                }
             });
         });
 
+        // Tick history scrubber (only when paused)
+        let (position, len) = frame_controller.history();
+        if frame_controller.is_paused() && len > 1 {
+            ui.horizontal(|ui| {
+                ui.label("History:");
+                let mut index = position;
+                let slider = egui::Slider::new(&mut index, 0..=len - 1)
+                    .custom_formatter(|n, _| format!("{}", n as i64 - (len as i64 - 1)))
+                    .suffix(" ticks");
+                if ui.add(slider).changed() && index != position {
+                    frame_controller.request_scrub(index);
+                    changed = true;
+                }
+            });
+        }
+
         ui.horizontal(|ui| {
             ui.label("Speed:");
 
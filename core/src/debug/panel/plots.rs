@@ -0,0 +1,107 @@
+//! Line graphs of values recorded with `stat_plot()`
+
+use super::super::plots::{PLOT_HISTORY_LEN, PlotSeries, StatPlots};
+
+/// Height of each graph in points
+const GRAPH_HEIGHT: f32 = 60.0;
+
+/// Line colors, cycled per graph
+const LINE_COLORS: [egui::Color32; 4] = [
+    egui::Color32::from_rgb(100, 200, 255),
+    egui::Color32::from_rgb(255, 170, 60),
+    egui::Color32::from_rgb(120, 220, 120),
+    egui::Color32::from_rgb(230, 110, 200),
+];
+
+/// Render one graph per plot group, newest samples on the right
+pub(super) fn render_plots(ui: &mut egui::Ui, plots: &StatPlots) {
+    for (graph, lines) in plots.graphs() {
+        render_graph(ui, graph, &lines);
+        ui.add_space(4.0);
+    }
+}
+
+fn render_graph(ui: &mut egui::Ui, name: &str, lines: &[&PlotSeries]) {
+    let (mut lo, mut hi) = lines
+        .iter()
+        .filter_map(|s| s.range())
+        .fold((f32::MAX, f32::MIN), |(lo, hi), (a, b)| {
+            (lo.min(a), hi.max(b))
+        });
+    if lo > hi {
+        return;
+    }
+    if hi <= lo {
+        lo -= 0.5;
+        hi += 0.5;
+    }
+
+    // Legend: name, then each line's latest value in its color
+    ui.horizontal_wrapped(|ui| {
+        ui.strong(name);
+        for (i, series) in lines.iter().enumerate() {
+            let text = match (lines.len(), series.latest()) {
+                (1, Some(v)) => format!("{:.3}", v),
+                (_, Some(v)) => format!("{}: {:.3}", series.label(), v),
+                _ => continue,
+            };
+            ui.colored_label(LINE_COLORS[i % LINE_COLORS.len()], text);
+        }
+    });
+
+    let (rect, _) = ui.allocate_exact_size(
+        egui::vec2(ui.available_width(), GRAPH_HEIGHT),
+        egui::Sense::hover(),
+    );
+    if !ui.is_rect_visible(rect) {
+        return;
+    }
+
+    let painter = ui.painter_at(rect);
+    painter.rect_filled(rect, 2.0, egui::Color32::from_gray(30));
+
+    // Align lines on tick so series plotted at different times line up
+    let newest = lines
+        .iter()
+        .filter_map(|s| s.latest_tick())
+        .max()
+        .unwrap_or(0);
+    let dx = rect.width() / (PLOT_HISTORY_LEN - 1) as f32;
+    let to_pos = |tick: u64, value: f32| {
+        let x = rect.right() - newest.saturating_sub(tick) as f32 * dx;
+        let y = rect.bottom() - (value - lo) / (hi - lo) * rect.height();
+        egui::pos2(x, y)
+    };
+
+    for (i, series) in lines.iter().enumerate() {
+        let points: Vec<egui::Pos2> = series
+            .samples()
+            .map(|(tick, value)| to_pos(tick, value))
+            .filter(|p| p.x >= rect.left())
+            .collect();
+        let stroke = egui::Stroke::new(1.5, LINE_COLORS[i % LINE_COLORS.len()]);
+        if points.len() == 1 {
+            painter.circle_filled(points[0], 1.5, stroke.color);
+        } else {
+            painter.line(points, stroke);
+        }
+    }
+
+    // Range labels
+    let font = egui::FontId::monospace(10.0);
+    let gray = egui::Color32::from_gray(140);
+    painter.text(
+        rect.left_top() + egui::vec2(2.0, 1.0),
+        egui::Align2::LEFT_TOP,
+        format!("{:.3}", hi),
+        font.clone(),
+        gray,
+    );
+    painter.text(
+        rect.left_bottom() + egui::vec2(2.0, -1.0),
+        egui::Align2::LEFT_BOTTOM,
+        format!("{:.3}", lo),
+        font,
+        gray,
+    );
+}
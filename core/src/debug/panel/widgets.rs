@@ -26,6 +26,11 @@ impl ValueWidgetRenderer {
             return None;
         }
 
+        // Enums -> dropdown of labels
+        if !reg_value.labels.is_empty() {
+            return self.render_enum_widget(ui, reg_value, &current);
+        }
+
         match (&reg_value.value_type, &reg_value.constraints) {
             // Float with range -> slider
             (super::super::types::ValueType::F32, Some(c)) => {
@@ -395,6 +400,32 @@ impl ValueWidgetRenderer {
     }
 
     /// Render a read-only watch value as a label
+    /// Render an enum value as a dropdown of its labels
+    fn render_enum_widget(
+        &self,
+        ui: &mut egui::Ui,
+        reg_value: &RegisteredValue,
+        current: &DebugValue,
+    ) -> Option<DebugValue> {
+        let old = current.as_f32() as i32;
+        let mut v = old;
+        ui.horizontal(|ui| {
+            ui.label(&reg_value.name);
+            egui::ComboBox::from_id_salt(&reg_value.full_path)
+                .selected_text(reg_value.display_enum(old))
+                .show_ui(ui, |ui| {
+                    for (i, label) in reg_value.labels.iter().enumerate() {
+                        ui.selectable_value(&mut v, i as i32, label);
+                    }
+                });
+        });
+        if v != old {
+            Some(DebugValue::I32(v))
+        } else {
+            None
+        }
+    }
+
     fn render_watch_value(
         &self,
         ui: &mut egui::Ui,
@@ -406,6 +437,7 @@ impl ValueWidgetRenderer {
             DebugValue::U8(v) => format!("{}", v),
             DebugValue::I16(v) => format!("{}", v),
             DebugValue::U16(v) => format!("{}", v),
+            DebugValue::I32(v) if !reg_value.labels.is_empty() => reg_value.display_enum(*v),
             DebugValue::I32(v) => format!("{}", v),
             DebugValue::U32(v) => format!("{}", v),
             DebugValue::F32(v) => format!("{:.3}", v),
@@ -0,0 +1,217 @@
+//! Stat plots for the debug inspector
+//!
+//! Games feed values with `stat_plot(name, value)` from `update()` or
+//! `render()`, and the inspector draws each name as a line graph of its
+//! recent history. Names with a `/` share a graph per prefix, so
+//! `"boost/p1"` and `"boost/p2"` are drawn as two lines on one `"boost"`
+//! graph for comparing players.
+//!
+//! Samples are keyed by tick. Plotting again during a tick replaces that
+//! tick's sample, and a sample from an earlier tick (after a rollback or a
+//! history scrub) drops the samples after it, so the graph always shows the
+//! timeline the game is on.
+
+use std::collections::VecDeque;
+
+/// Number of samples kept per series (5 seconds at 60 fps)
+pub const PLOT_HISTORY_LEN: usize = 300;
+
+/// Maximum number of series
+pub const MAX_PLOT_SERIES: usize = 64;
+
+/// Recent values of one plotted name
+#[derive(Debug, Clone)]
+pub struct PlotSeries {
+    /// Name passed to `stat_plot()`
+    pub name: String,
+    /// (tick, value) samples, oldest first
+    samples: VecDeque<(u64, f32)>,
+}
+
+impl PlotSeries {
+    /// Graph this series is drawn on (the name up to its last `/`)
+    pub fn graph(&self) -> &str {
+        self.name.rsplit_once('/').map_or(&self.name, |(g, _)| g)
+    }
+
+    /// Line label within its graph (the name after its last `/`)
+    pub fn label(&self) -> &str {
+        self.name.rsplit_once('/').map_or(&self.name, |(_, l)| l)
+    }
+
+    /// (tick, value) samples, oldest first
+    pub fn samples(&self) -> impl Iterator<Item = (u64, f32)> + '_ {
+        self.samples.iter().copied()
+    }
+
+    /// Values, oldest first
+    pub fn values(&self) -> impl Iterator<Item = f32> + '_ {
+        self.samples.iter().map(|&(_, v)| v)
+    }
+
+    /// Most recent value
+    pub fn latest(&self) -> Option<f32> {
+        self.samples.back().map(|&(_, v)| v)
+    }
+
+    /// Tick of the most recent value
+    pub fn latest_tick(&self) -> Option<u64> {
+        self.samples.back().map(|&(t, _)| t)
+    }
+
+    /// (min, max) over the kept samples
+    pub fn range(&self) -> Option<(f32, f32)> {
+        self.values().fold(None, |acc, v| match acc {
+            None => Some((v, v)),
+            Some((lo, hi)) => Some((lo.min(v), hi.max(v))),
+        })
+    }
+
+    /// Number of kept samples
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    /// Check if the series has no samples
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+}
+
+/// Ring buffers of plotted values, one per name
+#[derive(Debug, Clone, Default)]
+pub struct StatPlots {
+    /// Series in the order they were first plotted
+    pub series: Vec<PlotSeries>,
+}
+
+impl StatPlots {
+    /// Create an empty set of plots
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record `value` for `name` at `tick`
+    ///
+    /// Non-finite values and names beyond [`MAX_PLOT_SERIES`] are ignored.
+    pub fn record(&mut self, name: &str, tick: u64, value: f32) {
+        if !value.is_finite() {
+            tracing::warn!("stat_plot '{}': value must be finite - ignored", name);
+            return;
+        }
+
+        let series = match self.series.iter().position(|s| s.name == name) {
+            Some(i) => &mut self.series[i],
+            None => {
+                if self.series.len() >= MAX_PLOT_SERIES {
+                    tracing::warn!(
+                        "stat_plot '{}': at most {} series - ignored",
+                        name,
+                        MAX_PLOT_SERIES
+                    );
+                    return;
+                }
+                self.series.push(PlotSeries {
+                    name: name.to_string(),
+                    samples: VecDeque::with_capacity(PLOT_HISTORY_LEN),
+                });
+                self.series.last_mut().unwrap()
+            }
+        };
+
+        while series.samples.back().is_some_and(|&(t, _)| t >= tick) {
+            series.samples.pop_back();
+        }
+        if series.samples.len() == PLOT_HISTORY_LEN {
+            series.samples.pop_front();
+        }
+        series.samples.push_back((tick, value));
+    }
+
+    /// Series grouped by graph, in the order each graph was first plotted
+    pub fn graphs(&self) -> Vec<(&str, Vec<&PlotSeries>)> {
+        let mut graphs: Vec<(&str, Vec<&PlotSeries>)> = Vec::new();
+        for series in &self.series {
+            match graphs.iter_mut().find(|(g, _)| *g == series.graph()) {
+                Some((_, lines)) => lines.push(series),
+                None => graphs.push((series.graph(), vec![series])),
+            }
+        }
+        graphs
+    }
+
+    /// Drop all series (for game reload)
+    pub fn clear(&mut self) {
+        self.series.clear();
+    }
+
+    /// Check if nothing has been plotted
+    pub fn is_empty(&self) -> bool {
+        self.series.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_keeps_recent_history() {
+        let mut plots = StatPlots::new();
+        for tick in 0..(PLOT_HISTORY_LEN as u64 + 10) {
+            plots.record("enemies", tick, tick as f32);
+        }
+
+        let series = &plots.series[0];
+        assert_eq!(series.len(), PLOT_HISTORY_LEN);
+        assert_eq!(series.values().next(), Some(10.0));
+        assert_eq!(series.latest(), Some(PLOT_HISTORY_LEN as f32 + 9.0));
+        assert_eq!(series.range(), Some((10.0, PLOT_HISTORY_LEN as f32 + 9.0)));
+    }
+
+    #[test]
+    fn test_record_replaces_resimulated_ticks() {
+        let mut plots = StatPlots::new();
+        for tick in 0..10 {
+            plots.record("boost", tick, 1.0);
+        }
+
+        // Same tick again: last value wins
+        plots.record("boost", 9, 2.0);
+        assert_eq!(plots.series[0].len(), 10);
+        assert_eq!(plots.series[0].latest(), Some(2.0));
+
+        // Rolled back to tick 6: ticks 6..9 are replaced
+        plots.record("boost", 6, 3.0);
+        assert_eq!(plots.series[0].len(), 7);
+        assert_eq!(plots.series[0].latest_tick(), Some(6));
+
+        plots.record("boost", 7, f32::NAN);
+        assert_eq!(plots.series[0].len(), 7);
+    }
+
+    #[test]
+    fn test_graphs_group_by_prefix() {
+        let mut plots = StatPlots::new();
+        plots.record("boost/p1", 0, 0.5);
+        plots.record("enemies", 0, 3.0);
+        plots.record("boost/p2", 0, 0.8);
+
+        let graphs = plots.graphs();
+        assert_eq!(graphs.len(), 2);
+        assert_eq!(graphs[0].0, "boost");
+        let labels: Vec<_> = graphs[0].1.iter().map(|s| s.label()).collect();
+        assert_eq!(labels, ["p1", "p2"]);
+        assert_eq!(graphs[1].0, "enemies");
+        assert_eq!(graphs[1].1[0].label(), "enemies");
+    }
+
+    #[test]
+    fn test_series_limit() {
+        let mut plots = StatPlots::new();
+        for i in 0..MAX_PLOT_SERIES + 1 {
+            plots.record(&format!("s{}", i), 0, 1.0);
+        }
+        assert_eq!(plots.series.len(), MAX_PLOT_SERIES);
+    }
+}
@@ -8,6 +8,7 @@ mod tree;
 
 pub use tree::*;
 
+use super::plots::StatPlots;
 use super::types::{ActionParam, ActionParamValue, Constraints, ValueType};
 
 /// A registered debug value
@@ -25,6 +26,24 @@ pub struct RegisteredValue {
     pub constraints: Option<Constraints>,
     /// Whether this value is read-only (watch mode)
     pub read_only: bool,
+    /// Display labels for enum values (index = value), empty for non-enums
+    pub labels: Vec<String>,
+}
+
+impl RegisteredValue {
+    /// Label for an enum value, if it has one
+    pub fn enum_label(&self, value: i32) -> Option<&str> {
+        usize::try_from(value)
+            .ok()
+            .and_then(|i| self.labels.get(i))
+            .map(String::as_str)
+    }
+
+    /// Label for an enum value, falling back to the raw number
+    pub fn display_enum(&self, value: i32) -> String {
+        self.enum_label(value)
+            .map_or_else(|| value.to_string(), str::to_string)
+    }
 }
 
 /// A registered debug action (button that calls a WASM function)
@@ -61,6 +80,8 @@ pub struct DebugRegistry {
     pub finalized: bool,
     /// Pending action being built (between action_begin and action_end)
     pending_action: Option<PendingAction>,
+    /// Values recorded with `stat_plot()` (any time, not just during init)
+    pub plots: StatPlots,
 }
 
 impl Clone for DebugRegistry {
@@ -71,6 +92,7 @@ impl Clone for DebugRegistry {
             group_stack: self.group_stack.clone(),
             finalized: self.finalized,
             pending_action: None, // Don't clone pending state
+            plots: self.plots.clone(),
         }
     }
 }
@@ -109,12 +131,24 @@ impl DebugRegistry {
         value_type: ValueType,
         constraints: Option<Constraints>,
     ) {
-        self.register_internal(name, wasm_ptr, value_type, constraints, false);
+        self.register_internal(name, wasm_ptr, value_type, constraints, false, Vec::new());
+    }
+
+    /// Register an i32 enum shown as a dropdown of `labels`
+    ///
+    /// Value `n` is displayed as `labels[n]`. Ignored if `labels` is empty.
+    pub fn register_enum(&mut self, name: &str, wasm_ptr: u32, labels: Vec<String>) {
+        if labels.is_empty() {
+            tracing::warn!("debug_register_enum '{}' has no labels - ignored", name);
+            return;
+        }
+        let constraints = Some(Constraints::new(0.0, (labels.len() - 1) as f64));
+        self.register_internal(name, wasm_ptr, ValueType::I32, constraints, false, labels);
     }
 
     /// Register a read-only watch value for debug inspection
     pub fn watch(&mut self, name: &str, wasm_ptr: u32, value_type: ValueType) {
-        self.register_internal(name, wasm_ptr, value_type, None, true);
+        self.register_internal(name, wasm_ptr, value_type, None, true, Vec::new());
     }
 
     // =========================================================================
@@ -229,7 +263,7 @@ impl DebugRegistry {
         });
     }
 
-    /// Internal registration with read_only flag
+    /// Internal registration with read_only flag and enum labels
     fn register_internal(
         &mut self,
         name: &str,
@@ -237,6 +271,7 @@ impl DebugRegistry {
         value_type: ValueType,
         constraints: Option<Constraints>,
         read_only: bool,
+        labels: Vec<String>,
     ) {
         if self.finalized {
             tracing::warn!("debug registration called after init - ignored");
@@ -257,6 +292,7 @@ impl DebugRegistry {
             value_type,
             constraints,
             read_only,
+            labels,
         });
     }
 
@@ -295,6 +331,7 @@ impl DebugRegistry {
         self.actions.clear();
         self.group_stack.clear();
         self.pending_action = None;
+        self.plots.clear();
         self.finalized = false;
     }
 
@@ -96,6 +96,20 @@ fn test_constraints() {
     assert_eq!(c.max, 100.0);
 }
 
+#[test]
+fn test_register_enum() {
+    let mut registry = DebugRegistry::new();
+    let labels = vec!["low".to_string(), "medium".to_string(), "high".to_string()];
+    registry.register_enum("quality", 0x100, labels);
+    registry.register_enum("empty", 0x104, Vec::new());
+
+    assert_eq!(registry.len(), 1);
+    let value = &registry.values[0];
+    assert_eq!(value.value_type, ValueType::I32);
+    assert_eq!(value.labels[2], "high");
+    assert_eq!(value.constraints, Some(Constraints::new(0.0, 2.0)));
+}
+
 #[test]
 fn test_read_write_value_slice() {
     let registry = DebugRegistry::new();
@@ -0,0 +1,179 @@
+//! Host event bus (`event_poll`)
+//!
+//! Host subsystems (timers, console collision and gameplay systems) push
+//! fixed-size event records into a single ring buffer that the game drains with
+//! `event_poll()`. The queue is a POD array stored with the host-side rollback
+//! state, so a rollback restores unread events and re-simulated ticks queue
+//! exactly the same ones.
+
+/// Maximum number of unread events (older events are overwritten when full)
+pub const MAX_EVENTS: usize = 64;
+
+/// Event kinds
+///
+/// Kinds below `CONSOLE_BASE` are shared by every console. Consoles define
+/// their own kinds starting at `CONSOLE_BASE`.
+pub mod event_kind {
+    /// A timer expired: `data[0]` = callback id
+    pub const TIMER: u32 = 1;
+    /// A controller connected: `data[0]` = player index
+    pub const PLAYER_JOIN: u32 = 2;
+    /// A controller disconnected: `data[0]` = player index
+    pub const PLAYER_LEAVE: u32 = 3;
+    /// First console-specific event kind
+    pub const CONSOLE_BASE: u32 = 0x100;
+}
+
+/// A single event record (36 bytes)
+///
+/// The meaning of `data` and `position` depends on `kind`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[repr(C)]
+pub struct Event {
+    /// Event kind (see [`event_kind`])
+    pub kind: u32,
+    /// Kind-specific values (handles, indices, user data)
+    pub data: [u32; 5],
+    /// Kind-specific world position (zero if unused)
+    pub position: [f32; 3],
+}
+
+/// Fixed-size ring buffer of unread events
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(C)]
+pub struct EventQueue {
+    events: [Event; MAX_EVENTS],
+    /// Index of the oldest unread event
+    head: u32,
+    /// Number of unread events
+    len: u32,
+}
+
+impl Default for EventQueue {
+    fn default() -> Self {
+        Self {
+            events: [Event::default(); MAX_EVENTS],
+            head: 0,
+            len: 0,
+        }
+    }
+}
+
+// SAFETY: Event and EventQueue are #[repr(C)] with only u32/f32 fields (no padding)
+unsafe impl bytemuck::Zeroable for Event {}
+unsafe impl bytemuck::Pod for Event {}
+unsafe impl bytemuck::Zeroable for EventQueue {}
+unsafe impl bytemuck::Pod for EventQueue {}
+
+impl Event {
+    /// Create an event with no position
+    pub fn new(kind: u32, data: [u32; 5]) -> Self {
+        Self {
+            kind,
+            data,
+            position: [0.0; 3],
+        }
+    }
+
+    /// Create an event at a world position
+    pub fn at(kind: u32, data: [u32; 5], position: [f32; 3]) -> Self {
+        Self {
+            kind,
+            data,
+            position,
+        }
+    }
+}
+
+impl EventQueue {
+    /// Queue an event, overwriting the oldest unread one if the queue is full
+    ///
+    /// Returns `false` if an unread event was overwritten.
+    pub fn push(&mut self, event: Event) -> bool {
+        let tail = (self.head as usize + self.len as usize) % MAX_EVENTS;
+        self.events[tail] = event;
+        if (self.len as usize) < MAX_EVENTS {
+            self.len += 1;
+            true
+        } else {
+            self.head = (self.head + 1) % MAX_EVENTS as u32;
+            false
+        }
+    }
+
+    /// Remove the oldest unread event
+    pub fn pop(&mut self) -> Option<Event> {
+        if self.len == 0 {
+            return None;
+        }
+        let event = self.events[self.head as usize];
+        self.head = (self.head + 1) % MAX_EVENTS as u32;
+        self.len -= 1;
+        Some(event)
+    }
+
+    /// Number of unread events
+    pub fn len(&self) -> usize {
+        self.len as usize
+    }
+
+    /// Whether there are no unread events
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timer(id: u32) -> Event {
+        Event::new(event_kind::TIMER, [id, 0, 0, 0, 0])
+    }
+
+    #[test]
+    fn test_event_size() {
+        assert_eq!(std::mem::size_of::<Event>(), 36);
+        assert_eq!(std::mem::size_of::<EventQueue>() % 8, 0);
+    }
+
+    #[test]
+    fn test_push_pop_in_order() {
+        let mut queue = EventQueue::default();
+        assert!(queue.is_empty());
+        assert!(queue.push(timer(1)));
+        assert!(queue.push(timer(2)));
+        assert_eq!(queue.len(), 2);
+        assert_eq!(queue.pop(), Some(timer(1)));
+        assert_eq!(queue.pop(), Some(timer(2)));
+        assert_eq!(queue.pop(), None);
+    }
+
+    #[test]
+    fn test_full_queue_overwrites_oldest() {
+        let mut queue = EventQueue::default();
+        for i in 0..MAX_EVENTS as u32 {
+            assert!(queue.push(timer(i)));
+        }
+        assert!(!queue.push(timer(100)));
+        assert!(!queue.push(timer(101)));
+        assert_eq!(queue.len(), MAX_EVENTS);
+
+        assert_eq!(queue.pop(), Some(timer(2)));
+        let rest: Vec<u32> = std::iter::from_fn(|| queue.pop())
+            .map(|e| e.data[0])
+            .collect();
+        assert_eq!(rest.len(), MAX_EVENTS - 1);
+        assert_eq!(&rest[rest.len() - 2..], &[100, 101]);
+    }
+
+    #[test]
+    fn test_wraps_around() {
+        let mut queue = EventQueue::default();
+        for i in 0..(MAX_EVENTS as u32 * 3) {
+            queue.push(timer(i));
+            assert_eq!(queue.pop(), Some(timer(i)));
+        }
+        assert!(queue.is_empty());
+    }
+}
@@ -0,0 +1,47 @@
+//! Analytics FFI functions
+//!
+//! Events are aggregated by the host and only leave the machine if the user
+//! has opted in to analytics sharing.
+
+use wasmtime::Caller;
+
+use crate::analytics::MAX_EVENT_NAME_LEN;
+use crate::console::{ConsoleInput, ConsoleRollbackState};
+use crate::wasm::{WasmGameContext, read_bytes_from_memory};
+
+/// Record an analytics event
+///
+/// `name` must be 1-32 characters of `a-z`, `0-9`, `_`, `.` or `-`. The host
+/// only keeps a count per (name, value) pair. Invalid or excess events are
+/// dropped silently so games never need to check whether analytics is on.
+pub(super) fn analytics_event<I: ConsoleInput, S, R: ConsoleRollbackState>(
+    mut caller: Caller<'_, WasmGameContext<I, S, R>>,
+    name_ptr: u32,
+    name_len: u32,
+    value: i32,
+) {
+    if name_len as usize > MAX_EVENT_NAME_LEN {
+        tracing::debug!(
+            "analytics_event: name longer than {} bytes",
+            MAX_EVENT_NAME_LEN
+        );
+        return;
+    }
+    let Some(memory) = caller.data().game.memory else {
+        return;
+    };
+    let Ok(bytes) = read_bytes_from_memory(memory, &caller, name_ptr, name_len) else {
+        tracing::warn!("analytics_event: name out of bounds");
+        return;
+    };
+    let Ok(name) = std::str::from_utf8(&bytes) else {
+        tracing::warn!("analytics_event: name is not valid UTF-8");
+        return;
+    };
+
+    let ctx = caller.data_mut();
+    let tick = ctx.game.tick_count;
+    if !ctx.analytics.record(tick, name, value) {
+        tracing::debug!("analytics_event: dropped '{}' = {}", name, value);
+    }
+}
@@ -0,0 +1,129 @@
+//! Text chat and ping FFI functions
+//!
+//! Chat is host-side and presentation-only, so these are safe to call from
+//! `update()`: nothing here affects the simulation or rollback state.
+
+use wasmtime::Caller;
+
+use crate::console::{ConsoleInput, ConsoleRollbackState};
+use crate::net::chat::{CHAT_MAX_LEN, QUICK_CHAT_MESSAGES};
+use crate::wasm::{WasmGameContext, read_bytes_from_memory, write_bytes_to_memory};
+
+/// Size of the message record written by `chat_poll`
+///
+/// Layout: sender (u32) | len (u32) | text ([u8; CHAT_MAX_LEN])
+const CHAT_MESSAGE_SIZE: usize = 8 + CHAT_MAX_LEN;
+
+/// Size of the ping record written by `ping_poll`
+///
+/// Layout: sender (u32) | kind (u32) | x, y, z (f32)
+const PING_SIZE: usize = 20;
+
+/// Send a UTF-8 text message to all players
+///
+/// Messages longer than 128 bytes are truncated. Invalid UTF-8 is ignored.
+pub(super) fn chat_send<I: ConsoleInput, S, R: ConsoleRollbackState>(
+    caller: Caller<'_, WasmGameContext<I, S, R>>,
+    ptr: u32,
+    len: u32,
+) {
+    let Some(memory) = caller.data().game.memory else {
+        return;
+    };
+
+    match read_bytes_from_memory(memory, &caller, ptr, len) {
+        Ok(bytes) => match std::str::from_utf8(&bytes) {
+            Ok(text) => caller.data().chat.send_text(text),
+            Err(_) => tracing::warn!("chat_send: message is not valid UTF-8"),
+        },
+        Err(_) => tracing::warn!("chat_send: message out of bounds"),
+    }
+}
+
+/// Send a canned quick-chat message by index
+///
+/// Out-of-range indices are ignored.
+pub(super) fn quick_chat<I: ConsoleInput, S, R: ConsoleRollbackState>(
+    caller: Caller<'_, WasmGameContext<I, S, R>>,
+    id: u32,
+) {
+    if let Some(text) = QUICK_CHAT_MESSAGES.get(id as usize) {
+        caller.data().chat.send_text(text);
+    }
+}
+
+/// Pop the oldest unread message into a 136-byte record at `out_ptr`
+///
+/// Returns 1 if a message was written, 0 if there are none (or `out_ptr`
+/// is out of bounds, in which case the message is kept).
+pub(super) fn chat_poll<I: ConsoleInput, S, R: ConsoleRollbackState>(
+    mut caller: Caller<'_, WasmGameContext<I, S, R>>,
+    out_ptr: u32,
+) -> u32 {
+    let Some(memory) = caller.data().game.memory else {
+        return 0;
+    };
+
+    // Check the destination before consuming the message
+    if write_bytes_to_memory(memory, &mut caller, out_ptr, &[0; CHAT_MESSAGE_SIZE]).is_err() {
+        tracing::warn!("chat_poll: output buffer out of bounds");
+        return 0;
+    }
+    let Some(message) = caller.data().chat.poll_message() else {
+        return 0;
+    };
+
+    let mut record = [0u8; CHAT_MESSAGE_SIZE];
+    record[0..4].copy_from_slice(&(message.sender as u32).to_le_bytes());
+    record[4..8].copy_from_slice(&(message.text.len() as u32).to_le_bytes());
+    record[8..8 + message.text.len()].copy_from_slice(message.text.as_bytes());
+
+    match write_bytes_to_memory(memory, &mut caller, out_ptr, &record) {
+        Ok(()) => 1,
+        Err(_) => 0,
+    }
+}
+
+/// Place a world-space ping visible to all players
+pub(super) fn ping<I: ConsoleInput, S, R: ConsoleRollbackState>(
+    caller: Caller<'_, WasmGameContext<I, S, R>>,
+    x: f32,
+    y: f32,
+    z: f32,
+    kind: u32,
+) {
+    caller.data().chat.send_ping(kind, [x, y, z]);
+}
+
+/// Pop the oldest unread ping into a 20-byte record at `out_ptr`
+///
+/// Returns 1 if a ping was written, 0 if there are none (or `out_ptr` is
+/// out of bounds, in which case the ping is kept).
+pub(super) fn ping_poll<I: ConsoleInput, S, R: ConsoleRollbackState>(
+    mut caller: Caller<'_, WasmGameContext<I, S, R>>,
+    out_ptr: u32,
+) -> u32 {
+    let Some(memory) = caller.data().game.memory else {
+        return 0;
+    };
+
+    if write_bytes_to_memory(memory, &mut caller, out_ptr, &[0; PING_SIZE]).is_err() {
+        tracing::warn!("ping_poll: output buffer out of bounds");
+        return 0;
+    }
+    let Some(ping) = caller.data().chat.poll_ping() else {
+        return 0;
+    };
+
+    let mut record = [0u8; PING_SIZE];
+    record[0..4].copy_from_slice(&(ping.sender as u32).to_le_bytes());
+    record[4..8].copy_from_slice(&(ping.kind as u32).to_le_bytes());
+    for (i, v) in ping.position.iter().enumerate() {
+        record[8 + i * 4..12 + i * 4].copy_from_slice(&v.to_le_bytes());
+    }
+
+    match write_bytes_to_memory(memory, &mut caller, out_ptr, &record) {
+        Ok(()) => 1,
+        Err(_) => 0,
+    }
+}
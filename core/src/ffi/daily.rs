@@ -0,0 +1,44 @@
+//! Daily challenge FFI functions
+//!
+//! The daily challenge is host-managed and fixed when the game is loaded, so
+//! both functions return the same values for the whole session.
+
+use wasmtime::Caller;
+
+use crate::console::{ConsoleInput, ConsoleRollbackState};
+use crate::wasm::{WasmGameContext, write_bytes_to_memory};
+
+/// Seed shared by every player for today's daily challenge (UTC)
+pub(super) fn daily_seed<I: ConsoleInput, S, R: ConsoleRollbackState>(
+    caller: Caller<'_, WasmGameContext<I, S, R>>,
+) -> u64 {
+    caller.data().game.daily.seed
+}
+
+/// Write today's leaderboard category to `out_ptr` (UTF-8, up to `max` bytes)
+///
+/// The category is `daily-YYYY-MM-DD` (16 bytes), so scores from the same
+/// daily challenge end up on the same board.
+///
+/// Returns bytes written.
+pub(super) fn daily_category<I: ConsoleInput, S, R: ConsoleRollbackState>(
+    mut caller: Caller<'_, WasmGameContext<I, S, R>>,
+    out_ptr: u32,
+    max: u32,
+) -> u32 {
+    let Some(memory) = caller.data().game.memory else {
+        return 0;
+    };
+
+    // The category is ASCII, so any cut is a character boundary
+    let category = caller.data().game.daily.category();
+    let len = category.len().min(max as usize);
+
+    match write_bytes_to_memory(memory, &mut caller, out_ptr, &category.as_bytes()[..len]) {
+        Ok(()) => len as u32,
+        Err(_) => {
+            tracing::warn!("daily_category: output buffer out of bounds");
+            0
+        }
+    }
+}
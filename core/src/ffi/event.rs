@@ -0,0 +1,52 @@
+//! Event bus FFI functions
+//!
+//! Host subsystems queue typed events into a single ring buffer that the game
+//! drains with `event_poll()`, instead of exporting one callback per feature.
+//! The queue is rolled back with the RNG, so poll it from `update()`.
+
+use wasmtime::Caller;
+
+use crate::console::{ConsoleInput, ConsoleRollbackState};
+use crate::event::Event;
+use crate::wasm::{WasmGameContext, write_bytes_to_memory};
+
+/// Size of one event record in bytes
+const EVENT_SIZE: usize = std::mem::size_of::<Event>();
+
+/// Pop up to `max` of the oldest unread events into 36-byte records at
+/// `out_ptr` (kind, data[5], x, y, z)
+///
+/// Returns the number of events written. Unwritten events stay queued for the
+/// next call, and nothing is consumed if the buffer is out of bounds.
+pub(super) fn event_poll<I: ConsoleInput, S, R: ConsoleRollbackState>(
+    mut caller: Caller<'_, WasmGameContext<I, S, R>>,
+    out_ptr: u32,
+    max: u32,
+) -> u32 {
+    let Some(memory) = caller.data().game.memory else {
+        return 0;
+    };
+    let count = (max as usize).min(caller.data().game.events.len());
+    if count == 0 {
+        return 0;
+    }
+
+    // Check the destination before consuming any events
+    let mut records = vec![0u8; count * EVENT_SIZE];
+    if write_bytes_to_memory(memory, &mut caller, out_ptr, &records).is_err() {
+        tracing::warn!("event_poll: output buffer out of bounds");
+        return 0;
+    }
+
+    let events = &mut caller.data_mut().game.events;
+    for record in records.chunks_exact_mut(EVENT_SIZE) {
+        if let Some(event) = events.pop() {
+            record.copy_from_slice(bytemuck::bytes_of(&event));
+        }
+    }
+
+    match write_bytes_to_memory(memory, &mut caller, out_ptr, &records) {
+        Ok(()) => count as u32,
+        Err(_) => 0,
+    }
+}
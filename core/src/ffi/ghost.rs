@@ -0,0 +1,118 @@
+//! Ghost recording FFI functions
+//!
+//! The host samples the registered transform after every `update()` while a
+//! recording is active. Saved ghosts are written next to the game's save data.
+
+use wasmtime::Caller;
+
+use crate::console::{ConsoleInput, ConsoleRollbackState};
+use crate::ghost::{GHOST_FRAME_SIZE, GhostRecording, MAX_GHOST_SLOTS};
+use crate::wasm::{WasmGameContext, write_bytes_to_memory};
+
+/// Register the transform the host records each tick
+///
+/// `transform_ptr` points to 7 f32 values in game memory: position (x, y, z)
+/// followed by a rotation quaternion (x, y, z, w). Pass 0 to unregister.
+pub(super) fn ghost_register<I: ConsoleInput, S, R: ConsoleRollbackState>(
+    mut caller: Caller<'_, WasmGameContext<I, S, R>>,
+    transform_ptr: u32,
+) {
+    caller.data_mut().ghosts.source_ptr = (transform_ptr != 0).then_some(transform_ptr);
+}
+
+/// Start recording into a ghost slot (0-3)
+///
+/// Recording starts with the transform at the end of the current tick and
+/// replaces any recording already in progress.
+///
+/// Returns: 0 = success, 1 = invalid slot, 2 = no transform registered
+pub(super) fn ghost_record_begin<I: ConsoleInput, S, R: ConsoleRollbackState>(
+    mut caller: Caller<'_, WasmGameContext<I, S, R>>,
+    slot: u32,
+) -> u32 {
+    let slot = slot as usize;
+    if slot >= MAX_GHOST_SLOTS {
+        return 1;
+    }
+
+    let ghosts = &mut caller.data_mut().ghosts;
+    if ghosts.source_ptr.is_none() {
+        tracing::warn!("ghost_record_begin: call ghost_register() first");
+        return 2;
+    }
+    ghosts.recording = Some(GhostRecording::new(slot));
+    0
+}
+
+/// Stop recording
+///
+/// If `save` is non-zero the recording replaces the ghost in its slot and is
+/// written to disk; otherwise it is discarded (e.g. the lap was not a best).
+///
+/// Returns the number of recorded frames (0 if nothing was recording).
+pub(super) fn ghost_record_end<I: ConsoleInput, S, R: ConsoleRollbackState>(
+    mut caller: Caller<'_, WasmGameContext<I, S, R>>,
+    save: u32,
+) -> u32 {
+    let ghosts = &mut caller.data_mut().ghosts;
+    let Some(recording) = ghosts.recording.take() else {
+        return 0;
+    };
+    let frames = recording.frames.len() as u32;
+
+    if save != 0 {
+        ghosts.store.set_slot(recording.slot, recording.frames);
+        if let Err(e) = ghosts.store.flush() {
+            tracing::warn!(error = %e, slot = recording.slot, "Failed to flush GhostStore");
+        }
+    }
+    frames
+}
+
+/// Number of frames in a ghost slot (0 if empty or invalid)
+pub(super) fn ghost_frames<I: ConsoleInput, S, R: ConsoleRollbackState>(
+    caller: Caller<'_, WasmGameContext<I, S, R>>,
+    slot: u32,
+) -> u32 {
+    caller.data().ghosts.store.slot(slot as usize).len() as u32
+}
+
+/// Write the transform recorded `tick` ticks into a ghost to `out_ptr`
+///
+/// Writes 7 f32 values in the same layout as `ghost_register()`.
+///
+/// Returns 1 on success, 0 if the slot is empty or `tick` is past the end
+/// (in which case nothing is written).
+pub(super) fn ghost_playback<I: ConsoleInput, S, R: ConsoleRollbackState>(
+    mut caller: Caller<'_, WasmGameContext<I, S, R>>,
+    slot: u32,
+    tick: u32,
+    out_ptr: u32,
+) -> u32 {
+    let Some(memory) = caller.data().game.memory else {
+        return 0;
+    };
+    let Some(frame) = caller
+        .data()
+        .ghosts
+        .store
+        .slot(slot as usize)
+        .get(tick as usize)
+        .copied()
+    else {
+        return 0;
+    };
+
+    let mut bytes = [0u8; GHOST_FRAME_SIZE];
+    for (chunk, value) in bytes.chunks_exact_mut(4).zip(frame) {
+        chunk.copy_from_slice(&value.to_le_bytes());
+    }
+
+    match write_bytes_to_memory(memory, &mut caller, out_ptr, &bytes) {
+        Ok(()) => 1,
+        Err(_) => {
+            tracing::warn!("ghost_playback: output buffer out of bounds");
+            0
+        }
+    }
+}
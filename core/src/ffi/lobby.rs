@@ -0,0 +1,155 @@
+//! In-game lobby FFI functions
+//!
+//! Lobbies are local to each player and never part of the simulation: these
+//! only queue requests for the runtime's lobby driver and read back its state.
+//! Once the host starts the game, the runtime restarts it as a netplay session.
+
+use wasmtime::Caller;
+
+use crate::console::{ConsoleInput, ConsoleRollbackState};
+use crate::net::lobby::LobbyRequest;
+use crate::net::rendezvous::MAX_LOBBY_NAME;
+use crate::wasm::{WasmGameContext, read_bytes_from_memory, write_bytes_to_memory};
+
+/// Size of the lobby record written by `lobby_list`
+///
+/// Layout: id (u32) | players (u8) | max_players (u8) | name_len (u8) | pad (u8) | name ([u8; 32])
+const LOBBY_RECORD_SIZE: usize = 8 + MAX_LOBBY_NAME;
+
+/// Host a lobby and advertise it under `name`
+///
+/// `max_players` is clamped to 2..=the ROM's maximum. Returns 1 if the request
+/// was queued, 0 if lobbies are unavailable or the name is invalid.
+pub(super) fn lobby_create<I: ConsoleInput, S, R: ConsoleRollbackState>(
+    caller: Caller<'_, WasmGameContext<I, S, R>>,
+    name_ptr: u32,
+    name_len: u32,
+    max_players: u32,
+) -> u32 {
+    if !caller.data().lobby.is_enabled() {
+        tracing::warn!("lobby_create: lobbies are not available");
+        return 0;
+    }
+    let Some(memory) = caller.data().game.memory else {
+        return 0;
+    };
+
+    let name = match read_bytes_from_memory(memory, &caller, name_ptr, name_len) {
+        Ok(bytes) => match String::from_utf8(bytes) {
+            Ok(name) => name,
+            Err(_) => {
+                tracing::warn!("lobby_create: name is not valid UTF-8");
+                return 0;
+            }
+        },
+        Err(_) => {
+            tracing::warn!("lobby_create: name out of bounds");
+            return 0;
+        }
+    };
+
+    caller.data().lobby.request(LobbyRequest::Create {
+        name,
+        max_players: max_players.min(u8::MAX as u32) as u8,
+    });
+    1
+}
+
+/// Write up to `max` 40-byte lobby records to `out_ptr`
+///
+/// Returns the number written from the most recent listing and requests a
+/// fresh one, so calling this every few frames keeps the list current.
+pub(super) fn lobby_list<I: ConsoleInput, S, R: ConsoleRollbackState>(
+    mut caller: Caller<'_, WasmGameContext<I, S, R>>,
+    out_ptr: u32,
+    max: u32,
+) -> u32 {
+    let lobby = caller.data().lobby.clone();
+    if !lobby.is_enabled() {
+        return 0;
+    }
+    lobby.request(LobbyRequest::Refresh);
+
+    let Some(memory) = caller.data().game.memory else {
+        return 0;
+    };
+
+    let listings = lobby.listings();
+    let count = listings.len().min(max as usize);
+    let mut records = vec![0u8; count * LOBBY_RECORD_SIZE];
+    for (listing, record) in listings
+        .iter()
+        .zip(records.chunks_exact_mut(LOBBY_RECORD_SIZE))
+    {
+        let name = listing.name.as_bytes();
+        let name_len = name.len().min(MAX_LOBBY_NAME);
+        record[0..4].copy_from_slice(&listing.id.to_le_bytes());
+        record[4] = listing.players;
+        record[5] = listing.max_players;
+        record[6] = name_len as u8;
+        record[8..8 + name_len].copy_from_slice(&name[..name_len]);
+    }
+
+    match write_bytes_to_memory(memory, &mut caller, out_ptr, &records) {
+        Ok(()) => count as u32,
+        Err(_) => {
+            tracing::warn!("lobby_list: output buffer out of bounds");
+            0
+        }
+    }
+}
+
+/// Join a lobby by the id from `lobby_list`
+///
+/// Returns 1 if the request was queued, 0 if lobbies are unavailable.
+pub(super) fn lobby_join<I: ConsoleInput, S, R: ConsoleRollbackState>(
+    caller: Caller<'_, WasmGameContext<I, S, R>>,
+    id: u32,
+) -> u32 {
+    request(&caller, LobbyRequest::Join(id))
+}
+
+/// Start the game (host only, once another player has joined)
+///
+/// Returns 1 if the request was queued, 0 if the local player isn't hosting.
+pub(super) fn lobby_start<I: ConsoleInput, S, R: ConsoleRollbackState>(
+    caller: Caller<'_, WasmGameContext<I, S, R>>,
+) -> u32 {
+    if !caller.data().lobby.is_host() {
+        return 0;
+    }
+    request(&caller, LobbyRequest::Start)
+}
+
+/// Leave (or close, when hosting) the current lobby
+pub(super) fn lobby_leave<I: ConsoleInput, S, R: ConsoleRollbackState>(
+    caller: Caller<'_, WasmGameContext<I, S, R>>,
+) {
+    request(&caller, LobbyRequest::Leave);
+}
+
+/// Current lobby state (see `lobby_state` constants)
+pub(super) fn lobby_state<I: ConsoleInput, S, R: ConsoleRollbackState>(
+    caller: Caller<'_, WasmGameContext<I, S, R>>,
+) -> u32 {
+    caller.data().lobby.state()
+}
+
+/// Players in the current lobby, including the local player
+pub(super) fn lobby_player_count<I: ConsoleInput, S, R: ConsoleRollbackState>(
+    caller: Caller<'_, WasmGameContext<I, S, R>>,
+) -> u32 {
+    caller.data().lobby.player_count() as u32
+}
+
+fn request<I: ConsoleInput, S, R: ConsoleRollbackState>(
+    caller: &Caller<'_, WasmGameContext<I, S, R>>,
+    request: LobbyRequest,
+) -> u32 {
+    let lobby = &caller.data().lobby;
+    if !lobby.is_enabled() {
+        return 0;
+    }
+    lobby.request(request);
+    1
+}
@@ -3,10 +3,23 @@
 //! These functions are available to all fantasy consoles.
 //! Console-specific FFI functions are registered via the Console trait.
 
+mod analytics;
+mod chat;
+mod daily;
+mod event;
+mod ghost;
+mod lobby;
 mod random;
+mod rng;
 mod save;
+mod series;
 mod session;
+mod state;
 mod system;
+mod thumbnail;
+mod timer;
+mod token;
+mod voice;
 
 #[cfg(test)]
 mod tests;
@@ -32,12 +45,22 @@ pub fn register_common_ffi<
     linker.func_wrap("env", "tick_count", system::tick_count)?;
     linker.func_wrap("env", "log", system::log_message)?;
     linker.func_wrap("env", "quit", system::quit)?;
+    linker.func_wrap("env", "rom_hash", system::rom_hash)?;
 
     // Rollback functions
     linker.func_wrap("env", "random", random::random)?;
     linker.func_wrap("env", "random_range", random::random_range)?;
     linker.func_wrap("env", "random_f32", random::random_f32)?;
     linker.func_wrap("env", "random_f32_range", random::random_f32_range)?;
+    linker.func_wrap("env", "rng_create", rng::rng_create)?;
+    linker.func_wrap("env", "rng_next", rng::rng_next)?;
+    linker.func_wrap("env", "rng_range", rng::rng_range)?;
+    linker.func_wrap("env", "rng_free", rng::rng_free)?;
+    linker.func_wrap("env", "timer_after", timer::timer_after)?;
+    linker.func_wrap("env", "timer_every", timer::timer_every)?;
+    linker.func_wrap("env", "timer_cancel", timer::timer_cancel)?;
+    linker.func_wrap("env", "event_poll", event::event_poll)?;
+    linker.func_wrap("env", "state_register", state::state_register)?;
 
     // Save data functions
     linker.func_wrap("env", "save", save::save)?;
@@ -49,6 +72,66 @@ pub fn register_common_ffi<
     linker.func_wrap("env", "local_player_mask", session::local_player_mask)?;
     linker.func_wrap("env", "player_handle", session::player_handle)?;
     linker.func_wrap("env", "is_connected", session::is_connected)?;
+    linker.func_wrap("env", "controller_name", session::controller_name)?;
+    linker.func_wrap("env", "predict_policy", session::predict_policy)?;
+
+    // Tournament series functions
+    linker.func_wrap("env", "series_state", series::series_state)?;
+    linker.func_wrap("env", "series_round_end", series::series_round_end)?;
+
+    // Daily challenge functions
+    linker.func_wrap("env", "daily_seed", daily::daily_seed)?;
+    linker.func_wrap("env", "daily_category", daily::daily_category)?;
+
+    // Cross-ROM unlock token functions
+    linker.func_wrap("env", "token_grant", token::token_grant)?;
+    linker.func_wrap("env", "token_has", token::token_has)?;
+
+    // Analytics functions
+    linker.func_wrap("env", "analytics_event", analytics::analytics_event)?;
+
+    // Ghost recording functions
+    linker.func_wrap("env", "ghost_register", ghost::ghost_register)?;
+    linker.func_wrap("env", "ghost_record_begin", ghost::ghost_record_begin)?;
+    linker.func_wrap("env", "ghost_record_end", ghost::ghost_record_end)?;
+    linker.func_wrap("env", "ghost_frames", ghost::ghost_frames)?;
+    linker.func_wrap("env", "ghost_playback", ghost::ghost_playback)?;
+
+    // Save slot thumbnail functions
+    linker.func_wrap("env", "thumbnail_capture", thumbnail::thumbnail_capture)?;
+    linker.func_wrap("env", "thumbnail_exists", thumbnail::thumbnail_exists)?;
+    linker.func_wrap("env", "thumbnail_clear", thumbnail::thumbnail_clear)?;
+
+    // Voice chat functions
+    linker.func_wrap("env", "voice_enable", voice::voice_enable)?;
+    linker.func_wrap("env", "voice_disable", voice::voice_disable)?;
+    linker.func_wrap("env", "voice_is_enabled", voice::voice_is_enabled)?;
+    linker.func_wrap("env", "voice_set_muted", voice::voice_set_muted)?;
+    linker.func_wrap("env", "voice_is_muted", voice::voice_is_muted)?;
+    linker.func_wrap("env", "voice_is_speaking", voice::voice_is_speaking)?;
+    linker.func_wrap("env", "voice_set_positional", voice::voice_set_positional)?;
+    linker.func_wrap("env", "voice_set_listener", voice::voice_set_listener)?;
+    linker.func_wrap(
+        "env",
+        "voice_set_player_position",
+        voice::voice_set_player_position,
+    )?;
+
+    // Text chat and ping functions
+    linker.func_wrap("env", "chat_send", chat::chat_send)?;
+    linker.func_wrap("env", "chat_poll", chat::chat_poll)?;
+    linker.func_wrap("env", "quick_chat", chat::quick_chat)?;
+    linker.func_wrap("env", "ping", chat::ping)?;
+    linker.func_wrap("env", "ping_poll", chat::ping_poll)?;
+
+    // In-game lobby functions
+    linker.func_wrap("env", "lobby_create", lobby::lobby_create)?;
+    linker.func_wrap("env", "lobby_list", lobby::lobby_list)?;
+    linker.func_wrap("env", "lobby_join", lobby::lobby_join)?;
+    linker.func_wrap("env", "lobby_start", lobby::lobby_start)?;
+    linker.func_wrap("env", "lobby_leave", lobby::lobby_leave)?;
+    linker.func_wrap("env", "lobby_state", lobby::lobby_state)?;
+    linker.func_wrap("env", "lobby_player_count", lobby::lobby_player_count)?;
 
     // Debug inspection functions
     // These are always registered; release builds won't import them
@@ -0,0 +1,76 @@
+//! RNG stream FFI functions
+//!
+//! Streams are independent seeded generators, so cosmetic rolls can't shift
+//! simulation rolls. They are rolled back with the global RNG.
+
+use wasmtime::Caller;
+
+use crate::console::{ConsoleInput, ConsoleRollbackState};
+use crate::rng::MAX_RNG_STREAMS;
+use crate::wasm::WasmGameContext;
+
+/// Create an RNG stream seeded with `seed`
+///
+/// Returns a stream handle, or 0 if all streams are in use.
+pub(super) fn rng_create<I: ConsoleInput, S, R: ConsoleRollbackState>(
+    mut caller: Caller<'_, WasmGameContext<I, S, R>>,
+    seed: u64,
+) -> u32 {
+    match caller.data_mut().game.rng_streams.create(seed) {
+        Some(handle) => handle,
+        None => {
+            tracing::warn!("rng_create: all {} streams are in use", MAX_RNG_STREAMS);
+            0
+        }
+    }
+}
+
+/// Generate the next u32 from a stream
+///
+/// Returns 0 for an invalid handle.
+pub(super) fn rng_next<I: ConsoleInput, S, R: ConsoleRollbackState>(
+    mut caller: Caller<'_, WasmGameContext<I, S, R>>,
+    handle: u32,
+) -> u32 {
+    next(&mut caller, "rng_next", handle)
+}
+
+/// Generate an i32 in range [min, max) from a stream
+///
+/// Returns `min` for an invalid handle or an empty range.
+pub(super) fn rng_range<I: ConsoleInput, S, R: ConsoleRollbackState>(
+    mut caller: Caller<'_, WasmGameContext<I, S, R>>,
+    handle: u32,
+    min: i32,
+    max: i32,
+) -> i32 {
+    if min >= max {
+        return min;
+    }
+    let range = max.wrapping_sub(min) as u32;
+    min.wrapping_add((next(&mut caller, "rng_range", handle) % range) as i32)
+}
+
+/// Free a stream so its slot can be reused
+///
+/// Returns 1 if the stream existed, 0 otherwise.
+pub(super) fn rng_free<I: ConsoleInput, S, R: ConsoleRollbackState>(
+    mut caller: Caller<'_, WasmGameContext<I, S, R>>,
+    handle: u32,
+) -> u32 {
+    caller.data_mut().game.rng_streams.free(handle) as u32
+}
+
+fn next<I: ConsoleInput, S, R: ConsoleRollbackState>(
+    caller: &mut Caller<'_, WasmGameContext<I, S, R>>,
+    fn_name: &str,
+    handle: u32,
+) -> u32 {
+    match caller.data_mut().game.rng_streams.next(handle) {
+        Some(value) => value,
+        None => {
+            tracing::warn!("{}: invalid stream handle {}", fn_name, handle);
+            0
+        }
+    }
+}
@@ -0,0 +1,61 @@
+//! Tournament series FFI functions
+//!
+//! Series standings are host-managed and only change between sessions, so
+//! `series_state()` returns the same record for the whole round.
+
+use wasmtime::Caller;
+
+use crate::console::{ConsoleInput, ConsoleRollbackState};
+use crate::series::SERIES_NO_WINNER;
+use crate::wasm::{MAX_PLAYERS, WasmGameContext, write_bytes_to_memory};
+
+/// Write the series standings into a 44-byte record at `out_ptr`
+///
+/// Returns 1 if the game is running as part of a series, 0 otherwise (in
+/// which case nothing is written).
+pub(super) fn series_state<I: ConsoleInput, S, R: ConsoleRollbackState>(
+    mut caller: Caller<'_, WasmGameContext<I, S, R>>,
+    out_ptr: u32,
+) -> u32 {
+    let Some(memory) = caller.data().game.memory else {
+        return 0;
+    };
+    let Some(record) = caller.data().game.series.as_ref().map(|s| s.to_bytes()) else {
+        return 0;
+    };
+
+    match write_bytes_to_memory(memory, &mut caller, out_ptr, &record) {
+        Ok(()) => 1,
+        Err(_) => {
+            tracing::warn!("series_state: output buffer out of bounds");
+            0
+        }
+    }
+}
+
+/// Report the winner of the current round
+///
+/// `winner` is a player index, or 0xFF for a draw. The host records the
+/// result after the current frame and loads the next round unless the
+/// series has been decided. Only the first report per round counts.
+pub(super) fn series_round_end<I: ConsoleInput, S, R: ConsoleRollbackState>(
+    mut caller: Caller<'_, WasmGameContext<I, S, R>>,
+    winner: u32,
+) {
+    let game = &mut caller.data_mut().game;
+    if game.series.is_none() {
+        tracing::warn!("series_round_end: not running a series");
+        return;
+    }
+    if winner != SERIES_NO_WINNER && winner as usize >= MAX_PLAYERS {
+        tracing::warn!(
+            "series_round_end: invalid winner {} (must be 0-{} or 0xFF)",
+            winner,
+            MAX_PLAYERS - 1
+        );
+        return;
+    }
+    if game.series_round_result.is_none() {
+        game.series_round_result = Some(winner);
+    }
+}
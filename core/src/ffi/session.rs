@@ -3,7 +3,8 @@
 use wasmtime::Caller;
 
 use crate::console::{ConsoleInput, ConsoleRollbackState};
-use crate::wasm::WasmGameContext;
+use crate::rollback::PredictPolicy;
+use crate::wasm::{MAX_PLAYERS, WasmGameContext, write_bytes_to_memory};
 
 /// Get number of players in session
 pub(super) fn player_count<I: ConsoleInput, S, R: ConsoleRollbackState>(
@@ -19,7 +20,7 @@ pub(super) fn local_player_mask<I: ConsoleInput, S, R: ConsoleRollbackState>(
     caller.data().game.local_player_mask
 }
 
-/// Get local player handle for netplay (0-3)
+/// Get local player handle for netplay (0-7)
 ///
 /// Returns 0xFF if not connected (single player or pre-handshake).
 /// This is only valid after NCHS handshake completes and before post_connect() is called.
@@ -46,3 +47,77 @@ pub(super) fn is_connected<I: ConsoleInput, S, R: ConsoleRollbackState>(
         0
     }
 }
+
+/// Write the name of a local player's controller to `out_ptr` (UTF-8, up to `max` bytes)
+///
+/// Names are "Keyboard" or whatever the gamepad reports, and are only known
+/// for local players. They differ between machines, so use them for display
+/// only, never for game logic.
+///
+/// Returns bytes written, or 0 for remote players and empty slots.
+pub(super) fn controller_name<I: ConsoleInput, S, R: ConsoleRollbackState>(
+    mut caller: Caller<'_, WasmGameContext<I, S, R>>,
+    player: u32,
+    out_ptr: u32,
+    max: u32,
+) -> u32 {
+    let player = player as usize;
+    if player >= MAX_PLAYERS {
+        tracing::warn!(
+            "controller_name: invalid player {} (max {})",
+            player,
+            MAX_PLAYERS - 1
+        );
+        return 0;
+    }
+    let Some(memory) = caller.data().game.memory else {
+        return 0;
+    };
+
+    let name = &caller.data().game.controller_names[player];
+    // Cut at a character boundary so the game never sees partial UTF-8
+    let mut len = name.len().min(max as usize);
+    while !name.is_char_boundary(len) {
+        len -= 1;
+    }
+    let bytes = name.as_bytes()[..len].to_vec();
+
+    match write_bytes_to_memory(memory, &mut caller, out_ptr, &bytes) {
+        Ok(()) => len as u32,
+        Err(_) => {
+            tracing::warn!("controller_name: output buffer out of bounds");
+            0
+        }
+    }
+}
+
+/// Choose how a remote player's input is predicted while it is in flight
+///
+/// `policy` is 0 (hold: repeat the last received input, the default),
+/// 1 (neutral: buttons released, analog centered) or 2 (custom: ask the
+/// game's `predict_input(player, ticks)` export). Mispredictions are rolled
+/// back whatever the policy, so this only changes what players see before
+/// the real input arrives.
+pub(super) fn predict_policy<I: ConsoleInput, S, R: ConsoleRollbackState>(
+    mut caller: Caller<'_, WasmGameContext<I, S, R>>,
+    player: u32,
+    policy: u32,
+) {
+    let player = player as usize;
+    if player >= MAX_PLAYERS {
+        tracing::warn!(
+            "predict_policy: invalid player {} (max {})",
+            player,
+            MAX_PLAYERS - 1
+        );
+        return;
+    }
+    let Some(policy) = PredictPolicy::from_u32(policy) else {
+        tracing::warn!(
+            "predict_policy: invalid policy {} (must be 0-2), ignored",
+            policy
+        );
+        return;
+    };
+    caller.data_mut().game.predict_policies[player] = policy;
+}
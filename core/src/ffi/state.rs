@@ -0,0 +1,65 @@
+//! Rollback state registration FFI functions
+//!
+//! By default rollback snapshots the game's entire linear memory. Games that
+//! register their state regions during `init()` are snapshotted region by
+//! region instead, so allocator bookkeeping, scratch buffers and the stack
+//! stay out of every snapshot.
+
+use wasmtime::Caller;
+
+use crate::console::{ConsoleInput, ConsoleRollbackState};
+use crate::wasm::{MAX_STATE_REGIONS, WasmGameContext};
+
+/// Register a memory region to be saved and restored on rollback
+///
+/// Only callable during `init()`, so the snapshot layout stays fixed for the
+/// whole session. Once any region is registered, memory outside registered
+/// regions is no longer rolled back.
+///
+/// Returns 1 on success, 0 if the region is empty, out of bounds, overlaps a
+/// registered region, the region limit is reached, or init() has finished.
+pub(super) fn state_register<I: ConsoleInput, S, R: ConsoleRollbackState>(
+    mut caller: Caller<'_, WasmGameContext<I, S, R>>,
+    ptr: u32,
+    len: u32,
+) -> u32 {
+    if !caller.data().game.in_init {
+        tracing::warn!("state_register: can only be called during init()");
+        return 0;
+    }
+    let Some(memory) = caller.data().game.memory else {
+        return 0;
+    };
+    let memory_len = memory.data_size(&caller) as u64;
+    let end = ptr as u64 + len as u64;
+    if len == 0 || end > memory_len {
+        tracing::warn!(
+            "state_register: region {:#x}+{} is outside game memory",
+            ptr,
+            len
+        );
+        return 0;
+    }
+
+    let regions = &mut caller.data_mut().game.state_regions;
+    if regions.len() >= MAX_STATE_REGIONS {
+        tracing::warn!(
+            "state_register: all {} state regions are in use",
+            MAX_STATE_REGIONS
+        );
+        return 0;
+    }
+    let overlaps = regions
+        .iter()
+        .any(|&(start, size)| (ptr as u64) < start as u64 + size as u64 && end > start as u64);
+    if overlaps {
+        tracing::warn!(
+            "state_register: region {:#x}+{} overlaps a registered region",
+            ptr,
+            len
+        );
+        return 0;
+    }
+    regions.push((ptr, len));
+    1
+}
@@ -3,7 +3,7 @@
 use wasmtime::Caller;
 
 use crate::console::{ConsoleInput, ConsoleRollbackState};
-use crate::wasm::{WasmGameContext, read_bytes_from_memory};
+use crate::wasm::{WasmGameContext, read_bytes_from_memory, write_bytes_to_memory};
 
 /// Get delta time since last tick (seconds)
 pub(super) fn delta_time<I: ConsoleInput, S, R: ConsoleRollbackState>(
@@ -46,3 +46,30 @@ pub(super) fn quit<I: ConsoleInput, S, R: ConsoleRollbackState>(
 ) {
     caller.data_mut().game.quit_requested = true;
 }
+
+/// Write the 32-byte content hash of the running ROM to `out_ptr`
+///
+/// The hash is SHA-256 of the ROM file, so it identifies an exact build.
+///
+/// Returns 1 on success, 0 if the hash is unknown or the buffer is out of
+/// bounds (in which case nothing is written).
+pub(super) fn rom_hash<I: ConsoleInput, S, R: ConsoleRollbackState>(
+    mut caller: Caller<'_, WasmGameContext<I, S, R>>,
+    out_ptr: u32,
+) -> u32 {
+    let Some(memory) = caller.data().game.memory else {
+        return 0;
+    };
+    let hash = caller.data().rom_hash;
+    if hash == [0; 32] {
+        return 0;
+    }
+
+    match write_bytes_to_memory(memory, &mut caller, out_ptr, &hash) {
+        Ok(()) => 1,
+        Err(_) => {
+            tracing::warn!("rom_hash: output buffer out of bounds");
+            0
+        }
+    }
+}
@@ -123,6 +123,549 @@ fn test_ffi_quit_from_wasm() {
     assert!(store.data().game.quit_requested);
 }
 
+#[test]
+fn test_ffi_rom_hash_from_wasm() {
+    let engine = Engine::default();
+    let mut linker: Linker<WasmGameContext<TestInput, ()>> = Linker::new(&engine);
+    register_common_ffi(&mut linker).unwrap();
+
+    let wat = r#"
+        (module
+            (import "env" "rom_hash" (func $rom_hash (param i32) (result i32)))
+            (memory (export "memory") 1)
+            (func (export "get") (param i32) (result i32)
+                local.get 0
+                call $rom_hash
+            )
+        )
+    "#;
+    let wasm = wat::parse_str(wat).unwrap();
+    let module = wasmtime::Module::new(&engine, wasm).unwrap();
+
+    let mut store = Store::new(&engine, WasmGameContext::<TestInput, ()>::new());
+    let instance = linker.instantiate(&mut store, &module).unwrap();
+    let memory = instance.get_memory(&mut store, "memory").unwrap();
+    store.data_mut().game.memory = Some(memory);
+    let get = instance
+        .get_typed_func::<i32, i32>(&mut store, "get")
+        .unwrap();
+
+    // Unknown hash (raw instance without a loaded ROM)
+    assert_eq!(get.call(&mut store, 64).unwrap(), 0);
+
+    let hash = nethercore_shared::rom_content_hash(b"test rom");
+    store.data_mut().rom_hash = hash;
+    assert_eq!(get.call(&mut store, 64).unwrap(), 1);
+    let mut out = [0u8; 32];
+    memory.read(&store, 64, &mut out).unwrap();
+    assert_eq!(out, hash);
+
+    // Out of bounds
+    assert_eq!(get.call(&mut store, 65536 - 16).unwrap(), 0);
+}
+
+#[test]
+fn test_ffi_controller_name_from_wasm() {
+    let engine = Engine::default();
+    let mut linker: Linker<WasmGameContext<TestInput, ()>> = Linker::new(&engine);
+    register_common_ffi(&mut linker).unwrap();
+
+    let wat = r#"
+        (module
+            (import "env" "controller_name" (func $controller_name (param i32 i32 i32) (result i32)))
+            (memory (export "memory") 1)
+            (func (export "get") (param i32 i32) (result i32)
+                local.get 0
+                i32.const 64
+                local.get 1
+                call $controller_name
+            )
+        )
+    "#;
+    let wasm = wat::parse_str(wat).unwrap();
+    let module = wasmtime::Module::new(&engine, wasm).unwrap();
+
+    let mut store = Store::new(&engine, WasmGameContext::<TestInput, ()>::new());
+    let instance = linker.instantiate(&mut store, &module).unwrap();
+    let memory = instance.get_memory(&mut store, "memory").unwrap();
+    store.data_mut().game.memory = Some(memory);
+    let get = instance
+        .get_typed_func::<(i32, i32), i32>(&mut store, "get")
+        .unwrap();
+
+    store.data_mut().game.controller_names[1] = "Pad – Pro".to_string();
+    assert_eq!(get.call(&mut store, (1, 64)).unwrap(), 11);
+    let mut out = [0u8; 11];
+    memory.read(&store, 64, &mut out).unwrap();
+    assert_eq!(&out, "Pad – Pro".as_bytes());
+
+    // Truncated at a character boundary, not inside the dash
+    assert_eq!(get.call(&mut store, (1, 5)).unwrap(), 4);
+
+    // Empty slot and invalid player
+    assert_eq!(get.call(&mut store, (0, 64)).unwrap(), 0);
+    assert_eq!(get.call(&mut store, (8, 64)).unwrap(), 0);
+}
+
+#[test]
+fn test_ffi_predict_policy_from_wasm() {
+    use crate::rollback::PredictPolicy;
+
+    let engine = Engine::default();
+    let mut linker: Linker<WasmGameContext<TestInput, ()>> = Linker::new(&engine);
+    register_common_ffi(&mut linker).unwrap();
+
+    let wat = r#"
+        (module
+            (import "env" "predict_policy" (func $predict_policy (param i32 i32)))
+            (func (export "set") (param i32 i32)
+                local.get 0
+                local.get 1
+                call $predict_policy
+            )
+        )
+    "#;
+    let wasm = wat::parse_str(wat).unwrap();
+    let module = wasmtime::Module::new(&engine, wasm).unwrap();
+
+    let mut store = Store::new(&engine, WasmGameContext::<TestInput, ()>::new());
+    let instance = linker.instantiate(&mut store, &module).unwrap();
+    let set = instance
+        .get_typed_func::<(i32, i32), ()>(&mut store, "set")
+        .unwrap();
+
+    set.call(&mut store, (1, 2)).unwrap();
+    set.call(&mut store, (3, 1)).unwrap();
+    assert_eq!(store.data().game.predict_policies[1], PredictPolicy::Custom);
+    assert_eq!(
+        store.data().game.predict_policies[3],
+        PredictPolicy::Neutral
+    );
+
+    // Invalid player or policy is ignored
+    set.call(&mut store, (1, 3)).unwrap();
+    set.call(&mut store, (8, 1)).unwrap();
+    assert_eq!(store.data().game.predict_policies[1], PredictPolicy::Custom);
+    assert_eq!(store.data().game.predict_policies[0], PredictPolicy::Hold);
+}
+
+#[test]
+fn test_ffi_daily_from_wasm() {
+    let engine = Engine::default();
+    let mut linker: Linker<WasmGameContext<TestInput, ()>> = Linker::new(&engine);
+    register_common_ffi(&mut linker).unwrap();
+
+    let wat = r#"
+        (module
+            (import "env" "daily_seed" (func $daily_seed (result i64)))
+            (import "env" "daily_category" (func $daily_category (param i32 i32) (result i32)))
+            (memory (export "memory") 1)
+            (func (export "seed") (result i64)
+                call $daily_seed
+            )
+            (func (export "category") (param i32 i32) (result i32)
+                local.get 0
+                local.get 1
+                call $daily_category
+            )
+        )
+    "#;
+    let wasm = wat::parse_str(wat).unwrap();
+    let module = wasmtime::Module::new(&engine, wasm).unwrap();
+
+    let mut store = Store::new(&engine, WasmGameContext::<TestInput, ()>::new());
+    let instance = linker.instantiate(&mut store, &module).unwrap();
+    let memory = instance.get_memory(&mut store, "memory").unwrap();
+    store.data_mut().game.memory = Some(memory);
+    let seed = instance
+        .get_typed_func::<(), i64>(&mut store, "seed")
+        .unwrap();
+    let category = instance
+        .get_typed_func::<(i32, i32), i32>(&mut store, "category")
+        .unwrap();
+
+    let daily = crate::daily::DailyChallenge::for_day(20_742);
+    store.data_mut().game.daily = daily;
+    assert_eq!(seed.call(&mut store, ()).unwrap() as u64, daily.seed);
+
+    assert_eq!(category.call(&mut store, (64, 32)).unwrap(), 16);
+    let mut out = [0u8; 16];
+    memory.read(&store, 64, &mut out).unwrap();
+    assert_eq!(&out, b"daily-2026-10-16");
+
+    // Truncated, and out of bounds
+    assert_eq!(category.call(&mut store, (64, 5)).unwrap(), 5);
+    assert_eq!(category.call(&mut store, (65536 - 8, 32)).unwrap(), 0);
+}
+
+#[test]
+fn test_ffi_analytics_from_wasm() {
+    let engine = Engine::default();
+    let mut linker: Linker<WasmGameContext<TestInput, ()>> = Linker::new(&engine);
+    register_common_ffi(&mut linker).unwrap();
+
+    let wat = r#"
+        (module
+            (import "env" "analytics_event" (func $analytics_event (param i32 i32 i32)))
+            (memory (export "memory") 1)
+            (data (i32.const 0) "carBad!")
+            (func (export "event") (param i32 i32 i32)
+                local.get 0
+                local.get 1
+                local.get 2
+                call $analytics_event
+            )
+        )
+    "#;
+    let wasm = wat::parse_str(wat).unwrap();
+    let module = wasmtime::Module::new(&engine, wasm).unwrap();
+
+    let mut store = Store::new(&engine, WasmGameContext::<TestInput, ()>::new());
+    let instance = linker.instantiate(&mut store, &module).unwrap();
+    let memory = instance.get_memory(&mut store, "memory").unwrap();
+    store.data_mut().game.memory = Some(memory);
+    let event = instance
+        .get_typed_func::<(i32, i32, i32), ()>(&mut store, "event")
+        .unwrap();
+
+    event.call(&mut store, (0, 3, 5)).unwrap();
+    event.call(&mut store, (0, 3, 5)).unwrap();
+    // Invalid name, oversized name and out-of-bounds name are dropped
+    event.call(&mut store, (3, 4, 1)).unwrap();
+    event.call(&mut store, (0, 4096, 1)).unwrap();
+    event.call(&mut store, (65530, 16, 1)).unwrap();
+
+    let batch = store.data_mut().analytics.batch("game");
+    assert_eq!(batch.events.len(), 1);
+    assert_eq!(batch.events[0].name, "car");
+    assert_eq!(batch.events[0].value, 5);
+    assert_eq!(batch.events[0].count, 2);
+}
+
+#[test]
+fn test_ffi_voice_from_wasm() {
+    let engine = Engine::default();
+    let mut linker: Linker<WasmGameContext<TestInput, ()>> = Linker::new(&engine);
+    register_common_ffi(&mut linker).unwrap();
+
+    let wat = r#"
+        (module
+            (import "env" "voice_enable" (func $voice_enable (param i32 i32)))
+            (import "env" "voice_set_muted" (func $voice_set_muted (param i32 i32)))
+            (import "env" "voice_set_positional" (func $voice_set_positional (param f32)))
+            (memory (export "memory") 1)
+            (func (export "setup")
+                i32.const 0
+                i32.const 1
+                call $voice_enable
+                i32.const 3
+                i32.const 0
+                call $voice_enable
+                i32.const 2
+                i32.const 1
+                call $voice_set_muted
+                f32.const 25.0
+                call $voice_set_positional
+            )
+        )
+    "#;
+    let wasm = wat::parse_str(wat).unwrap();
+    let module = wasmtime::Module::new(&engine, wasm).unwrap();
+
+    let mut store = Store::new(&engine, WasmGameContext::<TestInput, ()>::new());
+    assert!(!store.data().voice.is_enabled());
+
+    let instance = linker.instantiate(&mut store, &module).unwrap();
+    let setup = instance
+        .get_typed_func::<(), ()>(&mut store, "setup")
+        .unwrap();
+    setup.call(&mut store, ()).unwrap();
+
+    let voice = &store.data().voice;
+    assert!(voice.is_enabled());
+    assert!(!voice.is_player_enabled(3));
+    assert!(voice.is_player_enabled(2));
+    assert!(voice.is_muted(2));
+    assert!(!voice.is_muted(1));
+    assert_eq!(voice.positional().map(|p| p.max_distance), Some(25.0));
+}
+
+#[test]
+fn test_ffi_voice_requires_capture_capability() {
+    let engine = Engine::default();
+    let mut linker: Linker<WasmGameContext<TestInput, ()>> = Linker::new(&engine);
+    register_common_ffi(&mut linker).unwrap();
+
+    let wat = r#"
+        (module
+            (import "env" "voice_enable" (func $voice_enable (param i32 i32)))
+            (memory (export "memory") 1)
+            (func (export "setup")
+                i32.const 0
+                i32.const 1
+                call $voice_enable
+            )
+        )
+    "#;
+    let wasm = wat::parse_str(wat).unwrap();
+    let module = wasmtime::Module::new(&engine, wasm).unwrap();
+
+    let mut ctx = WasmGameContext::<TestInput, ()>::new();
+    ctx.capabilities = nethercore_shared::Capabilities::NONE;
+    let mut store = Store::new(&engine, ctx);
+
+    let instance = linker.instantiate(&mut store, &module).unwrap();
+    let setup = instance
+        .get_typed_func::<(), ()>(&mut store, "setup")
+        .unwrap();
+    setup.call(&mut store, ()).unwrap();
+
+    assert!(!store.data().voice.is_enabled());
+}
+
+#[test]
+fn test_ffi_chat_from_wasm() {
+    let engine = Engine::default();
+    let mut linker: Linker<WasmGameContext<TestInput, ()>> = Linker::new(&engine);
+    register_common_ffi(&mut linker).unwrap();
+
+    // Send "hi" and a quick-chat, then poll both back into memory at 64 / 256
+    let wat = r#"
+        (module
+            (import "env" "chat_send" (func $chat_send (param i32 i32)))
+            (import "env" "chat_poll" (func $chat_poll (param i32) (result i32)))
+            (import "env" "quick_chat" (func $quick_chat (param i32)))
+            (import "env" "ping" (func $ping (param f32 f32 f32 i32)))
+            (import "env" "ping_poll" (func $ping_poll (param i32) (result i32)))
+            (memory (export "memory") 1)
+            (data (i32.const 0) "hi")
+            (func (export "send")
+                i32.const 0
+                i32.const 2
+                call $chat_send
+                i32.const 0
+                call $quick_chat
+                f32.const 1.0
+                f32.const 2.0
+                f32.const 3.0
+                i32.const 2
+                call $ping
+            )
+            (func (export "poll_chat") (param i32) (result i32)
+                local.get 0
+                call $chat_poll
+            )
+            (func (export "poll_ping") (param i32) (result i32)
+                local.get 0
+                call $ping_poll
+            )
+        )
+    "#;
+    let wasm = wat::parse_str(wat).unwrap();
+    let module = wasmtime::Module::new(&engine, wasm).unwrap();
+
+    let mut store = Store::new(&engine, WasmGameContext::<TestInput, ()>::new());
+    let instance = linker.instantiate(&mut store, &module).unwrap();
+    let memory = instance.get_memory(&mut store, "memory").unwrap();
+    store.data_mut().game.memory = Some(memory);
+
+    let send = instance
+        .get_typed_func::<(), ()>(&mut store, "send")
+        .unwrap();
+    let poll_chat = instance
+        .get_typed_func::<i32, i32>(&mut store, "poll_chat")
+        .unwrap();
+    let poll_ping = instance
+        .get_typed_func::<i32, i32>(&mut store, "poll_ping")
+        .unwrap();
+
+    send.call(&mut store, ()).unwrap();
+
+    assert_eq!(poll_chat.call(&mut store, 64).unwrap(), 1);
+    let data = memory.data(&store);
+    assert_eq!(&data[64..68], &0u32.to_le_bytes());
+    assert_eq!(&data[68..72], &2u32.to_le_bytes());
+    assert_eq!(&data[72..74], b"hi");
+
+    assert_eq!(poll_chat.call(&mut store, 64).unwrap(), 1);
+    let data = memory.data(&store);
+    let len = u32::from_le_bytes(data[68..72].try_into().unwrap()) as usize;
+    assert_eq!(&data[72..72 + len], b"Help!");
+
+    assert_eq!(poll_chat.call(&mut store, 64).unwrap(), 0);
+
+    assert_eq!(poll_ping.call(&mut store, 256).unwrap(), 1);
+    let data = memory.data(&store);
+    assert_eq!(&data[260..264], &2u32.to_le_bytes());
+    assert_eq!(&data[264..268], &1.0f32.to_le_bytes());
+    assert_eq!(poll_ping.call(&mut store, 256).unwrap(), 0);
+
+    // Out-of-bounds output keeps the message queued
+    send.call(&mut store, ()).unwrap();
+    assert_eq!(poll_chat.call(&mut store, 65536).unwrap(), 0);
+    assert_eq!(poll_chat.call(&mut store, 64).unwrap(), 1);
+}
+
+#[test]
+fn test_ffi_tokens_from_wasm() {
+    let engine = Engine::default();
+    let mut linker: Linker<WasmGameContext<TestInput, ()>> = Linker::new(&engine);
+    register_common_ffi(&mut linker).unwrap();
+
+    let wat = r#"
+        (module
+            (import "env" "token_grant" (func $token_grant (param i32) (result i32)))
+            (import "env" "token_has" (func $token_has (param i32) (result i32)))
+            (memory (export "memory") 1)
+            (func (export "grant") (param i32) (result i32)
+                local.get 0
+                call $token_grant
+            )
+            (func (export "has") (param i32) (result i32)
+                local.get 0
+                call $token_has
+            )
+        )
+    "#;
+    let wasm = wat::parse_str(wat).unwrap();
+    let module = wasmtime::Module::new(&engine, wasm).unwrap();
+
+    let tmp = tempfile::TempDir::new().unwrap();
+    let token_path = tmp.path().join("publisher.nctok");
+
+    let mut store = Store::new(&engine, WasmGameContext::<TestInput, ()>::new());
+    let instance = linker.instantiate(&mut store, &module).unwrap();
+    let grant = instance
+        .get_typed_func::<i32, i32>(&mut store, "grant")
+        .unwrap();
+    let has = instance
+        .get_typed_func::<i32, i32>(&mut store, "has")
+        .unwrap();
+
+    // No publisher: tokens are unavailable
+    assert_eq!(grant.call(&mut store, 7).unwrap(), 0);
+    assert_eq!(has.call(&mut store, 7).unwrap(), 0);
+
+    store.data_mut().token_store = Some(crate::token_store::TokenStore::new(token_path.clone()));
+    assert_eq!(has.call(&mut store, 7).unwrap(), 0);
+    assert_eq!(grant.call(&mut store, 7).unwrap(), 1);
+    assert_eq!(grant.call(&mut store, 7).unwrap(), 1);
+    assert_eq!(has.call(&mut store, 7).unwrap(), 1);
+    assert_eq!(has.call(&mut store, 8).unwrap(), 0);
+
+    // Grants are visible to the next game from the same publisher
+    let reloaded = crate::token_store::TokenStore::load_or_new(token_path.clone()).unwrap();
+    assert!(reloaded.has(7));
+    assert!(!reloaded.has(8));
+
+    // With a remote player, tokens are hidden and grants always report success
+    store.data_mut().game.player_count = 2;
+    store.data_mut().game.local_player_mask = 0b01;
+    assert_eq!(has.call(&mut store, 7).unwrap(), 0);
+    assert_eq!(grant.call(&mut store, 9).unwrap(), 1);
+    store.data_mut().token_store = None;
+    assert_eq!(grant.call(&mut store, 10).unwrap(), 1);
+
+    // ...but the grant made during netplay is still kept locally
+    let reloaded = crate::token_store::TokenStore::load_or_new(token_path).unwrap();
+    assert!(reloaded.has(9));
+}
+
+#[test]
+fn test_ffi_ghosts_from_wasm() {
+    let engine = Engine::default();
+    let mut linker: Linker<WasmGameContext<TestInput, ()>> = Linker::new(&engine);
+    register_common_ffi(&mut linker).unwrap();
+
+    let wat = r#"
+        (module
+            (import "env" "ghost_register" (func $ghost_register (param i32)))
+            (import "env" "ghost_record_begin" (func $ghost_record_begin (param i32) (result i32)))
+            (import "env" "ghost_record_end" (func $ghost_record_end (param i32) (result i32)))
+            (import "env" "ghost_frames" (func $ghost_frames (param i32) (result i32)))
+            (import "env" "ghost_playback" (func $ghost_playback (param i32 i32 i32) (result i32)))
+            (memory (export "memory") 1)
+            (func (export "register") (param i32)
+                local.get 0
+                call $ghost_register
+            )
+            (func (export "begin") (param i32) (result i32)
+                local.get 0
+                call $ghost_record_begin
+            )
+            (func (export "end") (param i32) (result i32)
+                local.get 0
+                call $ghost_record_end
+            )
+            (func (export "frames") (param i32) (result i32)
+                local.get 0
+                call $ghost_frames
+            )
+            (func (export "playback") (param i32 i32) (result i32)
+                local.get 0
+                local.get 1
+                i32.const 256
+                call $ghost_playback
+            )
+        )
+    "#;
+    let wasm = wat::parse_str(wat).unwrap();
+    let module = wasmtime::Module::new(&engine, wasm).unwrap();
+
+    let tmp = tempfile::TempDir::new().unwrap();
+    let ghost_path = tmp.path().join("game.ncghost");
+
+    let mut store = Store::new(&engine, WasmGameContext::<TestInput, ()>::new());
+    store.data_mut().ghosts.store = crate::ghost::GhostStore::new(Some(ghost_path.clone()));
+    let instance = linker.instantiate(&mut store, &module).unwrap();
+    let memory = instance.get_memory(&mut store, "memory").unwrap();
+    store.data_mut().game.memory = Some(memory);
+
+    let register = instance
+        .get_typed_func::<i32, ()>(&mut store, "register")
+        .unwrap();
+    let begin = instance
+        .get_typed_func::<i32, i32>(&mut store, "begin")
+        .unwrap();
+    let end = instance
+        .get_typed_func::<i32, i32>(&mut store, "end")
+        .unwrap();
+    let frames = instance
+        .get_typed_func::<i32, i32>(&mut store, "frames")
+        .unwrap();
+    let playback = instance
+        .get_typed_func::<(i32, i32), i32>(&mut store, "playback")
+        .unwrap();
+
+    // Recording needs a registered transform and a valid slot
+    assert_eq!(begin.call(&mut store, 0).unwrap(), 2);
+    register.call(&mut store, 128).unwrap();
+    assert_eq!(begin.call(&mut store, 4).unwrap(), 1);
+    assert_eq!(begin.call(&mut store, 1).unwrap(), 0);
+
+    // The host captures one frame per tick (normally after update())
+    for tick in 0..3u64 {
+        let frame = [tick as f32, 0.0, 0.0, 0.0, 0.0, 0.0, 1.0];
+        let recording = store.data_mut().ghosts.recording.as_mut().unwrap();
+        recording.capture(tick, frame);
+    }
+    assert_eq!(end.call(&mut store, 1).unwrap(), 3);
+    assert_eq!(end.call(&mut store, 1).unwrap(), 0);
+    assert_eq!(frames.call(&mut store, 1).unwrap(), 3);
+    assert_eq!(frames.call(&mut store, 0).unwrap(), 0);
+
+    assert_eq!(playback.call(&mut store, (1, 2)).unwrap(), 1);
+    let mut out = [0u8; 4];
+    memory.read(&store, 256, &mut out).unwrap();
+    assert_eq!(f32::from_le_bytes(out), 2.0);
+    assert_eq!(playback.call(&mut store, (1, 3)).unwrap(), 0);
+    assert_eq!(playback.call(&mut store, (0, 0)).unwrap(), 0);
+
+    // Saved ghosts survive a restart
+    let reloaded = crate::ghost::GhostStore::load_or_new(ghost_path).unwrap();
+    assert_eq!(reloaded.slot(1).len(), 3);
+}
+
 // ============================================================================
 // RNG Tests
 // ============================================================================
@@ -336,6 +879,22 @@ fn test_player_info_multiplayer() {
     assert!(state.local_player_mask & (1 << 1) == 0); // Player 1 remote
     assert!(state.local_player_mask & (1 << 2) != 0); // Player 2 local
     assert!(state.local_player_mask & (1 << 3) == 0); // Player 3 remote
+    assert!(state.has_remote_players());
+}
+
+#[test]
+fn test_has_remote_players() {
+    let mut state = GameState::<TestInput>::new();
+    assert!(!state.has_remote_players());
+
+    // Local multiplayer: every player is on this machine
+    state.player_count = 8;
+    state.local_player_mask = 0xFF;
+    assert!(!state.has_remote_players());
+
+    // Spectators have no local players at all
+    state.local_player_mask = 0;
+    assert!(state.has_remote_players());
 }
 
 // ============================================================================
@@ -0,0 +1,58 @@
+//! Save slot thumbnail FFI functions
+//!
+//! Captures are taken from the next frame the host renders, after the game's
+//! `render()` has drawn it. Stored thumbnails are written next to the game's
+//! save data; drawing them is up to each console.
+
+use wasmtime::Caller;
+
+use crate::console::{ConsoleInput, ConsoleRollbackState};
+use crate::thumbnail::MAX_THUMBNAIL_SLOTS;
+use crate::wasm::WasmGameContext;
+
+/// Capture the frame being drawn into a thumbnail slot (0-7)
+///
+/// The thumbnail replaces whatever the slot held once the current frame has
+/// been rendered, so call this from `render()` on the frame you want kept
+/// (e.g. right after saving).
+///
+/// Returns: 0 = success, 1 = invalid slot
+pub(super) fn thumbnail_capture<I: ConsoleInput, S, R: ConsoleRollbackState>(
+    mut caller: Caller<'_, WasmGameContext<I, S, R>>,
+    slot: u32,
+) -> u32 {
+    if slot as usize >= MAX_THUMBNAIL_SLOTS {
+        return 1;
+    }
+    caller.data_mut().thumbnails.requested |= 1 << slot;
+    0
+}
+
+/// Whether a thumbnail slot holds a capture (1) or not (0)
+pub(super) fn thumbnail_exists<I: ConsoleInput, S, R: ConsoleRollbackState>(
+    caller: Caller<'_, WasmGameContext<I, S, R>>,
+    slot: u32,
+) -> u32 {
+    caller.data().thumbnails.store.slot(slot as usize).is_some() as u32
+}
+
+/// Empty a thumbnail slot (e.g. when its save is deleted)
+///
+/// Returns: 0 = success, 1 = invalid slot
+pub(super) fn thumbnail_clear<I: ConsoleInput, S, R: ConsoleRollbackState>(
+    mut caller: Caller<'_, WasmGameContext<I, S, R>>,
+    slot: u32,
+) -> u32 {
+    let slot = slot as usize;
+    if slot >= MAX_THUMBNAIL_SLOTS {
+        return 1;
+    }
+
+    let thumbnails = &mut caller.data_mut().thumbnails;
+    thumbnails.requested &= !(1 << slot);
+    thumbnails.store.clear_slot(slot);
+    if let Err(e) = thumbnails.store.flush() {
+        tracing::warn!(error = %e, slot, "Failed to flush ThumbnailStore");
+    }
+    0
+}
@@ -0,0 +1,64 @@
+//! Timer FFI functions
+//!
+//! Timers count down on the update tick and call the game's `on_timer()`
+//! export, so they replay identically during rollback.
+
+use wasmtime::Caller;
+
+use crate::console::{ConsoleInput, ConsoleRollbackState};
+use crate::timer::MAX_TIMERS;
+use crate::wasm::WasmGameContext;
+
+/// Call `on_timer(callback_id)` once, `ticks` ticks from now
+///
+/// Returns a timer handle for `timer_cancel()`, or 0 if all timers are in use.
+pub(super) fn timer_after<I: ConsoleInput, S, R: ConsoleRollbackState>(
+    mut caller: Caller<'_, WasmGameContext<I, S, R>>,
+    ticks: u32,
+    callback_id: u32,
+) -> u32 {
+    schedule(&mut caller, "timer_after", ticks, callback_id, 0)
+}
+
+/// Call `on_timer(callback_id)` every `ticks` ticks until cancelled
+///
+/// Returns a timer handle for `timer_cancel()`, or 0 if all timers are in use.
+pub(super) fn timer_every<I: ConsoleInput, S, R: ConsoleRollbackState>(
+    mut caller: Caller<'_, WasmGameContext<I, S, R>>,
+    ticks: u32,
+    callback_id: u32,
+) -> u32 {
+    let interval = ticks.max(1);
+    schedule(&mut caller, "timer_every", interval, callback_id, interval)
+}
+
+/// Cancel a pending timer
+///
+/// Returns 1 if the timer was pending, 0 otherwise.
+pub(super) fn timer_cancel<I: ConsoleInput, S, R: ConsoleRollbackState>(
+    mut caller: Caller<'_, WasmGameContext<I, S, R>>,
+    handle: u32,
+) -> u32 {
+    caller.data_mut().game.timers.cancel(handle) as u32
+}
+
+fn schedule<I: ConsoleInput, S, R: ConsoleRollbackState>(
+    caller: &mut Caller<'_, WasmGameContext<I, S, R>>,
+    fn_name: &str,
+    ticks: u32,
+    callback_id: u32,
+    interval: u32,
+) -> u32 {
+    match caller
+        .data_mut()
+        .game
+        .timers
+        .schedule(ticks, callback_id, interval)
+    {
+        Some(handle) => handle,
+        None => {
+            tracing::warn!("{}: all {} timers are in use", fn_name, MAX_TIMERS);
+            0
+        }
+    }
+}
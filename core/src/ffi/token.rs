@@ -0,0 +1,73 @@
+//! Cross-ROM unlock token FFI functions
+//!
+//! Tokens live in the host's per-publisher token store. The publisher is the
+//! registry author the library recorded when it downloaded the ROM (never the
+//! ROM's own `author` text), so a game can only see tokens granted by games
+//! from the same verified publisher.
+//!
+//! Tokens are per-machine state. While a session has remote players they are
+//! hidden from the game so every peer simulates the same thing.
+
+use wasmtime::Caller;
+
+use crate::console::{ConsoleInput, ConsoleRollbackState};
+use crate::wasm::WasmGameContext;
+
+/// Grant an unlock token to every game from this publisher
+///
+/// Grants are permanent and written to disk immediately; granting a token
+/// that is already held is a no-op.
+///
+/// Returns 1 if the token is now held, 0 if tokens are unavailable (the ROM
+/// has no verified publisher) or the publisher's token store is full. With
+/// remote players in the session the grant is still recorded locally, but
+/// the result is always 1 so it can't differ between peers.
+pub(super) fn token_grant<I: ConsoleInput, S, R: ConsoleRollbackState>(
+    mut caller: Caller<'_, WasmGameContext<I, S, R>>,
+    id: u32,
+) -> u32 {
+    let ctx = caller.data_mut();
+    let granted = grant(ctx.token_store.as_mut(), id);
+    if ctx.game.has_remote_players() {
+        1
+    } else {
+        granted as u32
+    }
+}
+
+/// Record a grant in the token store, returning whether the token is held
+fn grant(store: Option<&mut crate::token_store::TokenStore>, id: u32) -> bool {
+    let Some(store) = store else {
+        tracing::warn!(
+            "token_grant: unlock tokens unavailable (ROM has no verified publisher or lacks the `saves` capability)"
+        );
+        return false;
+    };
+    if store.has(id) {
+        return true;
+    }
+    if !store.grant(id) {
+        tracing::warn!("token_grant: token store is full");
+        return false;
+    }
+    if let Err(e) = store.flush() {
+        tracing::warn!(error = %e, id, "Failed to flush TokenStore");
+    }
+    true
+}
+
+/// Check whether an unlock token has been granted by any game from this
+/// publisher
+///
+/// Returns 1 if held, 0 otherwise. Always 0 while the session has remote
+/// players, since each peer has its own token store.
+pub(super) fn token_has<I: ConsoleInput, S, R: ConsoleRollbackState>(
+    caller: Caller<'_, WasmGameContext<I, S, R>>,
+    id: u32,
+) -> u32 {
+    let ctx = caller.data();
+    if ctx.game.has_remote_players() {
+        return 0;
+    }
+    ctx.token_store.as_ref().is_some_and(|store| store.has(id)) as u32
+}
@@ -0,0 +1,114 @@
+//! Voice chat FFI functions
+//!
+//! Voice is presentation-only and host-side, so these are safe to call from
+//! `update()`: nothing here affects the simulation or rollback state.
+
+use glam::Vec3;
+use wasmtime::Caller;
+
+use crate::console::{ConsoleInput, ConsoleRollbackState};
+use crate::net::voice::PositionalVoice;
+use crate::wasm::WasmGameContext;
+
+/// Enable (1) or disable (0) voice chat for a player
+///
+/// For the local player this turns microphone capture and playback on or
+/// off. For a remote player it controls whether their voice is played
+/// (enabled by default), e.g. to keep voice within a team. Has no audible
+/// effect outside netplay sessions. Enabling is ignored unless the ROM
+/// declares the `capture` capability.
+pub(super) fn voice_enable<I: ConsoleInput, S, R: ConsoleRollbackState>(
+    caller: Caller<'_, WasmGameContext<I, S, R>>,
+    player: u32,
+    on: u32,
+) {
+    if on != 0 && !caller.data().capabilities.capture {
+        tracing::warn!("voice_enable: ROM does not declare the `capture` capability");
+        return;
+    }
+    caller
+        .data()
+        .voice
+        .set_player_enabled(player as usize, on != 0);
+}
+
+/// Disable voice chat for the local player
+pub(super) fn voice_disable<I: ConsoleInput, S, R: ConsoleRollbackState>(
+    caller: Caller<'_, WasmGameContext<I, S, R>>,
+) {
+    caller.data().voice.set_enabled(false);
+}
+
+/// Check if voice chat is enabled for the local player
+///
+/// Returns 1 if enabled, 0 otherwise.
+pub(super) fn voice_is_enabled<I: ConsoleInput, S, R: ConsoleRollbackState>(
+    caller: Caller<'_, WasmGameContext<I, S, R>>,
+) -> u32 {
+    caller.data().voice.is_enabled() as u32
+}
+
+/// Mute (1) or unmute (0) a player's incoming voice
+pub(super) fn voice_set_muted<I: ConsoleInput, S, R: ConsoleRollbackState>(
+    caller: Caller<'_, WasmGameContext<I, S, R>>,
+    player: u32,
+    muted: u32,
+) {
+    caller.data().voice.set_muted(player as usize, muted != 0);
+}
+
+/// Check if a player is muted
+///
+/// Returns 1 if muted, 0 otherwise.
+pub(super) fn voice_is_muted<I: ConsoleInput, S, R: ConsoleRollbackState>(
+    caller: Caller<'_, WasmGameContext<I, S, R>>,
+    player: u32,
+) -> u32 {
+    caller.data().voice.is_muted(player as usize) as u32
+}
+
+/// Check if a remote player is currently speaking
+///
+/// Returns 1 if voice from the player is being played back, 0 otherwise.
+pub(super) fn voice_is_speaking<I: ConsoleInput, S, R: ConsoleRollbackState>(
+    caller: Caller<'_, WasmGameContext<I, S, R>>,
+    player: u32,
+) -> u32 {
+    caller.data().voice.is_speaking(player as usize) as u32
+}
+
+/// Enable positional attenuation
+///
+/// Speakers fade out linearly and are silent beyond `max_distance`.
+/// Pass 0 (or any non-positive value) to disable attenuation.
+pub(super) fn voice_set_positional<I: ConsoleInput, S, R: ConsoleRollbackState>(
+    caller: Caller<'_, WasmGameContext<I, S, R>>,
+    max_distance: f32,
+) {
+    let positional = (max_distance > 0.0).then_some(PositionalVoice { max_distance });
+    caller.data().voice.set_positional(positional);
+}
+
+/// Set the listener position for positional attenuation
+pub(super) fn voice_set_listener<I: ConsoleInput, S, R: ConsoleRollbackState>(
+    caller: Caller<'_, WasmGameContext<I, S, R>>,
+    x: f32,
+    y: f32,
+    z: f32,
+) {
+    caller.data().voice.set_listener(Vec3::new(x, y, z));
+}
+
+/// Set a player's position for positional attenuation
+pub(super) fn voice_set_player_position<I: ConsoleInput, S, R: ConsoleRollbackState>(
+    caller: Caller<'_, WasmGameContext<I, S, R>>,
+    player: u32,
+    x: f32,
+    y: f32,
+    z: f32,
+) {
+    caller
+        .data()
+        .voice
+        .set_player_position(player as usize, Vec3::new(x, y, z));
+}
@@ -0,0 +1,269 @@
+//! Ghost recording for time-trial modes
+//!
+//! The game registers a transform in its own memory with `ghost_register()`.
+//! While a recording is active the host copies that transform after every
+//! `update()`, so the game only marks the start and end of a run. Finished
+//! runs can be kept in a ghost slot, read back tick by tick with
+//! `ghost_playback()`, and are persisted next to the save data when the ROM
+//! declares the `saves` capability.
+//!
+//! Frames are indexed by tick, so re-simulated ticks after a rollback simply
+//! overwrite what was captured before.
+
+use std::fs;
+use std::io::{self, Read, Write};
+use std::path::PathBuf;
+
+pub const GHOST_MAGIC: [u8; 4] = *b"NCGH";
+pub const GHOST_VERSION: u32 = 1;
+
+/// Number of ghost slots per game
+pub const MAX_GHOST_SLOTS: usize = 4;
+
+/// Longest recording (10 minutes at 60 ticks per second)
+pub const MAX_GHOST_FRAMES: usize = 60 * 60 * 10;
+
+/// Floats per recorded transform: position (x, y, z) and rotation quaternion (x, y, z, w)
+pub const GHOST_FRAME_FLOATS: usize = 7;
+
+/// Size of a transform in WASM memory
+pub const GHOST_FRAME_SIZE: usize = GHOST_FRAME_FLOATS * 4;
+
+/// One recorded transform
+pub type GhostFrame = [f32; GHOST_FRAME_FLOATS];
+
+/// Ghost recording state for a running game (host-side, never rolled back)
+#[derive(Default)]
+pub struct Ghosts {
+    /// Address of the transform registered with `ghost_register()`
+    pub source_ptr: Option<u32>,
+    /// Recording in progress, if any
+    pub recording: Option<GhostRecording>,
+    /// Finished ghosts
+    pub store: GhostStore,
+}
+
+/// A recording in progress
+pub struct GhostRecording {
+    /// Slot the finished ghost goes to
+    pub slot: usize,
+    /// Tick of the first captured frame (set on first capture)
+    pub start_tick: Option<u64>,
+    /// Captured frames, one per tick
+    pub frames: Vec<GhostFrame>,
+}
+
+impl GhostRecording {
+    pub fn new(slot: usize) -> Self {
+        Self {
+            slot,
+            start_tick: None,
+            frames: Vec::new(),
+        }
+    }
+
+    /// Store the transform for `tick`
+    ///
+    /// Frames after `tick` (from ticks that were rolled back) are discarded.
+    /// Ticks before the start and frames past [`MAX_GHOST_FRAMES`] are ignored.
+    pub fn capture(&mut self, tick: u64, frame: GhostFrame) {
+        let start = *self.start_tick.get_or_insert(tick);
+        let Some(index) = tick.checked_sub(start) else {
+            return;
+        };
+        let index = index as usize;
+        if index >= MAX_GHOST_FRAMES {
+            return;
+        }
+
+        self.frames.truncate(index);
+        // Fill skipped ticks by holding the last transform
+        while self.frames.len() < index {
+            let last = self.frames.last().copied().unwrap_or(frame);
+            self.frames.push(last);
+        }
+        self.frames.push(frame);
+    }
+}
+
+/// Finished ghosts, optionally backed by a file
+#[derive(Default)]
+pub struct GhostStore {
+    path: Option<PathBuf>,
+    slots: [Vec<GhostFrame>; MAX_GHOST_SLOTS],
+}
+
+impl GhostStore {
+    /// Create an empty store. Without a path, ghosts only live in memory.
+    pub fn new(path: Option<PathBuf>) -> Self {
+        Self {
+            path,
+            slots: Default::default(),
+        }
+    }
+
+    /// Loads the store from disk. Missing or unreadable files yield an empty
+    /// store; oversized ghosts are reported as corruption.
+    pub fn load_or_new(path: PathBuf) -> io::Result<Self> {
+        let mut store = Self::new(Some(path.clone()));
+
+        let mut file = match fs::File::open(&path) {
+            Ok(f) => f,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(store),
+            Err(e) => return Err(e),
+        };
+
+        let mut bytes = Vec::new();
+        file.read_to_end(&mut bytes)?;
+
+        if bytes.len() < 8 || bytes[0..4] != GHOST_MAGIC {
+            return Ok(store);
+        }
+        let word = |i: usize| {
+            bytes
+                .get(i..i + 4)
+                .map(|b| u32::from_le_bytes(b.try_into().unwrap()))
+        };
+        if word(4) != Some(GHOST_VERSION) {
+            return Ok(store);
+        }
+
+        let mut offset = 8;
+        let mut slots: [Vec<GhostFrame>; MAX_GHOST_SLOTS] = Default::default();
+        for slot in &mut slots {
+            let Some(count) = word(offset) else {
+                return Ok(store);
+            };
+            let count = count as usize;
+            if count > MAX_GHOST_FRAMES {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "ghost file exceeds MAX_GHOST_FRAMES",
+                ));
+            }
+            offset += 4;
+
+            let Some(data) = bytes.get(offset..offset + count * GHOST_FRAME_SIZE) else {
+                return Ok(store);
+            };
+            *slot = data
+                .chunks_exact(GHOST_FRAME_SIZE)
+                .map(|frame| {
+                    std::array::from_fn(|i| {
+                        f32::from_le_bytes(frame[i * 4..i * 4 + 4].try_into().unwrap())
+                    })
+                })
+                .collect();
+            offset += count * GHOST_FRAME_SIZE;
+        }
+
+        store.slots = slots;
+        Ok(store)
+    }
+
+    /// Frames stored in `slot` (empty if unused or out of range)
+    pub fn slot(&self, slot: usize) -> &[GhostFrame] {
+        self.slots.get(slot).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    /// Replace the ghost in `slot`
+    pub fn set_slot(&mut self, slot: usize, frames: Vec<GhostFrame>) {
+        assert!(slot < MAX_GHOST_SLOTS);
+        self.slots[slot] = frames;
+    }
+
+    /// Whether ghosts are written to disk
+    pub fn is_persistent(&self) -> bool {
+        self.path.is_some()
+    }
+
+    /// Write all slots to disk (no-op for in-memory stores)
+    pub fn flush(&self) -> io::Result<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let frame_count: usize = self.slots.iter().map(Vec::len).sum();
+        let mut out = Vec::with_capacity(8 + MAX_GHOST_SLOTS * 4 + frame_count * GHOST_FRAME_SIZE);
+        out.extend_from_slice(&GHOST_MAGIC);
+        out.extend_from_slice(&GHOST_VERSION.to_le_bytes());
+        for slot in &self.slots {
+            out.extend_from_slice(&(slot.len() as u32).to_le_bytes());
+            for value in slot.iter().flatten() {
+                out.extend_from_slice(&value.to_le_bytes());
+            }
+        }
+
+        let tmp_path = path.with_extension("ncghost.tmp");
+        {
+            let mut f = fs::File::create(&tmp_path)?;
+            f.write_all(&out)?;
+            f.sync_all()?;
+        }
+
+        #[cfg(windows)]
+        {
+            if path.exists() {
+                // Windows rename fails if destination exists.
+                fs::remove_file(path)?;
+            }
+        }
+
+        fs::rename(&tmp_path, path)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frame(x: f32) -> GhostFrame {
+        [x, 0.0, 0.0, 0.0, 0.0, 0.0, 1.0]
+    }
+
+    #[test]
+    fn test_capture_is_indexed_by_tick() {
+        let mut recording = GhostRecording::new(0);
+        recording.capture(10, frame(1.0));
+        recording.capture(11, frame(2.0));
+        recording.capture(13, frame(4.0));
+        assert_eq!(recording.frames.len(), 4);
+        assert_eq!(recording.frames[2], frame(2.0));
+
+        // Rollback re-simulates from tick 11
+        recording.capture(11, frame(5.0));
+        assert_eq!(recording.frames, vec![frame(1.0), frame(5.0)]);
+
+        // Ticks before the start are ignored
+        recording.capture(9, frame(9.0));
+        assert_eq!(recording.frames.len(), 2);
+    }
+
+    #[test]
+    fn test_store_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("game.ncghost");
+
+        let mut store = GhostStore::new(Some(path.clone()));
+        store.set_slot(2, vec![frame(1.0), frame(2.0)]);
+        store.flush().unwrap();
+
+        let loaded = GhostStore::load_or_new(path).unwrap();
+        assert!(loaded.is_persistent());
+        assert!(loaded.slot(0).is_empty());
+        assert_eq!(loaded.slot(2), &[frame(1.0), frame(2.0)]);
+        assert!(loaded.slot(MAX_GHOST_SLOTS).is_empty());
+    }
+
+    #[test]
+    fn test_in_memory_store_does_not_flush() {
+        let mut store = GhostStore::default();
+        store.set_slot(0, vec![frame(1.0)]);
+        assert!(!store.is_persistent());
+        store.flush().unwrap();
+    }
+}
@@ -235,15 +235,15 @@ fn test_save_data_slot_limits() {
 /// Test player count limits
 #[test]
 fn test_player_count_limits() {
-    assert_eq!(MAX_PLAYERS, 4);
+    assert_eq!(MAX_PLAYERS, 8);
 
     let mut state = GameState::<TestInput>::new();
 
-    // Can set up to 4 players
+    // Can set up to 8 players
     state.player_count = MAX_PLAYERS as u32;
-    state.local_player_mask = 0b1111; // All local
+    state.local_player_mask = 0b1111_1111; // All local
 
-    assert_eq!(state.player_count, 4);
+    assert_eq!(state.player_count, 8);
 
     // Verify all player input slots exist
     assert_eq!(state.input_curr.len(), MAX_PLAYERS);
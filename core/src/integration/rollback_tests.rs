@@ -180,6 +180,66 @@ fn test_rollback_checksum_detection() {
     assert_ne!(snapshot1.data, snapshot2.data);
 }
 
+/// Test that snapshots store only changed pages and still restore exactly
+#[test]
+fn test_rollback_delta_snapshots() {
+    let (engine, linker) = create_test_engine();
+
+    // Counter at address 0; on tick 5 most of memory is overwritten
+    let wat = r#"
+        (module
+            (memory (export "memory") 1)
+
+            (func (export "init"))
+            (func (export "update")
+                (i32.store (i32.const 0)
+                    (i32.add (i32.load (i32.const 0)) (i32.const 1))
+                )
+                (if (i32.eq (i32.load (i32.const 0)) (i32.const 5))
+                    (then (memory.fill (i32.const 1024) (i32.const 7) (i32.const 64512)))
+                )
+            )
+            (func (export "render"))
+        )
+    "#;
+
+    let wasm = wat::parse_str(wat).unwrap();
+    let module = engine.load_module(&wasm).unwrap();
+    let mut game = new_test_game_instance(&engine, &module, &linker);
+    let mut state_manager = RollbackStateManager::with_defaults();
+
+    game.init().unwrap();
+
+    let mut snapshots = Vec::new();
+    for frame in 0..8 {
+        snapshots.push(state_manager.save_state(&mut game, frame).unwrap());
+        game.update(1.0 / 60.0).unwrap();
+    }
+
+    // Frame 0 is a keyframe; frames 1-4 only store the counter's page
+    assert_eq!(snapshots[0].len(), 65536);
+    assert!(snapshots[0].delta.as_ref().unwrap().pages.is_empty());
+    for snapshot in &snapshots[1..5] {
+        assert_eq!(snapshot.len(), 65536);
+        assert_eq!(snapshot.delta.as_ref().unwrap().pages, [0]);
+        assert_eq!(snapshot.data.len(), crate::rollback::DELTA_PAGE_SIZE);
+    }
+
+    // The overwrite on tick 5 makes frame 5 a new keyframe
+    let stats = state_manager.stats();
+    assert_eq!(stats.saves, 8);
+    assert_eq!(stats.keyframes, 2);
+    assert!(stats.savings() > 0.5);
+
+    // Every snapshot restores to the state it was taken from
+    for (frame, snapshot) in snapshots.iter().enumerate().rev() {
+        state_manager.load_state(&mut game, snapshot).unwrap();
+        let restored = state_manager.save_state(&mut game, frame as i32).unwrap();
+        assert_eq!(restored.checksum, snapshot.checksum, "frame {}", frame);
+    }
+    assert_eq!(state_manager.stats().loads, 8);
+}
+
 /// Test rollback simulation with multiple save points
 ///
 /// State must be stored in memory (not globals) for rollback to work.
@@ -273,3 +333,530 @@ fn test_rollback_session_local() {
     // Frame should have advanced
     assert_eq!(session.current_frame(), 1);
 }
+
+/// Test that timers fire on_timer() on the tick and are restored by rollback
+#[test]
+fn test_timers_fire_and_roll_back() {
+    let (engine, linker) = create_test_engine();
+
+    // init: timer_after(3, 1) and timer_every(2, 2)
+    // on_timer(id): memory[id * 4] += 1
+    let wat = r#"
+        (module
+            (import "env" "timer_after" (func $timer_after (param i32 i32) (result i32)))
+            (import "env" "timer_every" (func $timer_every (param i32 i32) (result i32)))
+            (memory (export "memory") 1)
+
+            (func (export "init")
+                (drop (call $timer_after (i32.const 3) (i32.const 1)))
+                (drop (call $timer_every (i32.const 2) (i32.const 2)))
+            )
+            (func (export "update"))
+            (func (export "render"))
+
+            (func (export "on_timer") (param $id i32)
+                (local $addr i32)
+                (local.set $addr (i32.mul (local.get $id) (i32.const 4)))
+                (i32.store (local.get $addr)
+                    (i32.add (i32.load (local.get $addr)) (i32.const 1))
+                )
+            )
+        )
+    "#;
+
+    let wasm = wat::parse_str(wat).unwrap();
+    let module = engine.load_module(&wasm).unwrap();
+    let mut game = new_test_game_instance(&engine, &module, &linker);
+    let mut state_manager = RollbackStateManager::with_defaults();
+
+    let counts = |game: &mut crate::wasm::GameInstance<TestInput, ()>| {
+        let memory = game.save_state().unwrap();
+        let read = |addr: usize| u32::from_le_bytes(memory[addr..addr + 4].try_into().unwrap());
+        (read(4), read(8))
+    };
+
+    game.init().unwrap();
+    assert_eq!(game.state().timers.active_count(), 2);
+
+    // Tick 2: repeating timer fires
+    game.update(1.0 / 60.0).unwrap();
+    game.update(1.0 / 60.0).unwrap();
+    assert_eq!(counts(&mut game), (0, 1));
+    let snapshot = state_manager.save_state(&mut game, 2).unwrap();
+
+    // Ticks 3-4: one-shot fires and is freed, repeating fires again
+    game.update(1.0 / 60.0).unwrap();
+    game.update(1.0 / 60.0).unwrap();
+    assert_eq!(counts(&mut game), (1, 2));
+    assert_eq!(game.state().timers.active_count(), 1);
+
+    // Roll back to tick 2 and re-simulate: same callbacks fire again
+    state_manager.load_state(&mut game, &snapshot).unwrap();
+    assert_eq!(game.state().timers.active_count(), 2);
+    assert_eq!(counts(&mut game), (0, 1));
+    game.update(1.0 / 60.0).unwrap();
+    game.update(1.0 / 60.0).unwrap();
+    assert_eq!(counts(&mut game), (1, 2));
+}
+
+/// Test that RNG streams leave the global RNG alone and are restored by rollback
+#[test]
+fn test_rng_streams_roll_back() {
+    let (engine, linker) = create_test_engine();
+
+    // init: memory[0] = rng_create(7)
+    // update: memory[4] = rng_next(memory[0])
+    let wat = r#"
+        (module
+            (import "env" "rng_create" (func $rng_create (param i64) (result i32)))
+            (import "env" "rng_next" (func $rng_next (param i32) (result i32)))
+            (memory (export "memory") 1)
+
+            (func (export "init")
+                (i32.store (i32.const 0) (call $rng_create (i64.const 7)))
+            )
+            (func (export "update")
+                (i32.store (i32.const 4) (call $rng_next (i32.load (i32.const 0))))
+            )
+            (func (export "render"))
+        )
+    "#;
+
+    let wasm = wat::parse_str(wat).unwrap();
+    let module = engine.load_module(&wasm).unwrap();
+    let mut game = new_test_game_instance(&engine, &module, &linker);
+    let mut state_manager = RollbackStateManager::with_defaults();
+
+    let last_draw = |game: &mut crate::wasm::GameInstance<TestInput, ()>| {
+        let memory = game.save_state().unwrap();
+        u32::from_le_bytes(memory[4..8].try_into().unwrap())
+    };
+
+    game.init().unwrap();
+    let rng_state = game.state().rng_state;
+    game.update(1.0 / 60.0).unwrap();
+    assert_eq!(game.state().rng_streams.active_count(), 1);
+    assert_eq!(game.state().rng_state, rng_state);
+    let snapshot = state_manager.save_state(&mut game, 1).unwrap();
+
+    game.update(1.0 / 60.0).unwrap();
+    game.update(1.0 / 60.0).unwrap();
+    let expected = last_draw(&mut game);
+
+    // Roll back and re-simulate: the stream replays the same values
+    state_manager.load_state(&mut game, &snapshot).unwrap();
+    game.update(1.0 / 60.0).unwrap();
+    game.update(1.0 / 60.0).unwrap();
+    assert_eq!(last_draw(&mut game), expected);
+}
+
+/// Test that expired timers queue events for event_poll() and that unread
+/// events are restored by rollback
+#[test]
+fn test_event_poll_timer_events_roll_back() {
+    let (engine, linker) = create_test_engine();
+
+    // init: two timers firing every tick (ids 7 and 8)
+    // update: event_poll(16, 1), store the count at address 0
+    let wat = r#"
+        (module
+            (import "env" "timer_every" (func $timer_every (param i32 i32) (result i32)))
+            (import "env" "event_poll" (func $event_poll (param i32 i32) (result i32)))
+            (memory (export "memory") 1)
+
+            (func (export "init")
+                (drop (call $timer_every (i32.const 1) (i32.const 7)))
+                (drop (call $timer_every (i32.const 1) (i32.const 8)))
+            )
+            (func (export "update")
+                (i32.store (i32.const 0) (call $event_poll (i32.const 16) (i32.const 1)))
+            )
+            (func (export "render"))
+        )
+    "#;
+
+    let wasm = wat::parse_str(wat).unwrap();
+    let module = engine.load_module(&wasm).unwrap();
+    let mut game = new_test_game_instance(&engine, &module, &linker);
+    let mut state_manager = RollbackStateManager::with_defaults();
+
+    let polled = |game: &mut crate::wasm::GameInstance<TestInput, ()>| {
+        let memory = game.save_state().unwrap();
+        let read = |addr: usize| u32::from_le_bytes(memory[addr..addr + 4].try_into().unwrap());
+        (read(0), read(16), read(20))
+    };
+
+    game.init().unwrap();
+
+    // Two events per tick, one polled per tick
+    game.update(1.0 / 60.0).unwrap();
+    assert_eq!(polled(&mut game), (1, crate::event::event_kind::TIMER, 7));
+    game.update(1.0 / 60.0).unwrap();
+    assert_eq!(game.state().events.len(), 2);
+    let snapshot = state_manager.save_state(&mut game, 2).unwrap();
+
+    game.update(1.0 / 60.0).unwrap();
+    assert_eq!(game.state().events.len(), 3);
+
+    // Roll back to tick 2: the unread events come back
+    state_manager.load_state(&mut game, &snapshot).unwrap();
+    assert_eq!(game.state().events.len(), 2);
+    game.update(1.0 / 60.0).unwrap();
+    assert_eq!(game.state().events.len(), 3);
+}
+
+/// Test that controller connects and disconnects call on_player_join() /
+/// on_player_leave(), queue events and re-simulate on rollback
+#[test]
+fn test_player_join_leave_callbacks_roll_back() {
+    let (engine, linker) = create_test_engine();
+
+    // on_player_join(i): memory[i * 4] += 1
+    // on_player_leave(i): memory[32 + i * 4] += 1
+    let wat = r#"
+        (module
+            (memory (export "memory") 1)
+
+            (func (export "init"))
+            (func (export "update"))
+            (func (export "render"))
+
+            (func (export "on_player_join") (param $i i32)
+                (local $addr i32)
+                (local.set $addr (i32.mul (local.get $i) (i32.const 4)))
+                (i32.store (local.get $addr)
+                    (i32.add (i32.load (local.get $addr)) (i32.const 1))
+                )
+            )
+            (func (export "on_player_leave") (param $i i32)
+                (local $addr i32)
+                (local.set $addr (i32.add (i32.const 32) (i32.mul (local.get $i) (i32.const 4))))
+                (i32.store (local.get $addr)
+                    (i32.add (i32.load (local.get $addr)) (i32.const 1))
+                )
+            )
+        )
+    "#;
+
+    let wasm = wat::parse_str(wat).unwrap();
+    let module = engine.load_module(&wasm).unwrap();
+    let mut game = new_test_game_instance(&engine, &module, &linker);
+    let mut state_manager = RollbackStateManager::with_defaults();
+    game.configure_session(3, 0b111);
+
+    let counts = |game: &mut crate::wasm::GameInstance<TestInput, ()>| {
+        let memory = game.save_state().unwrap();
+        let read = |addr: usize| u32::from_le_bytes(memory[addr..addr + 4].try_into().unwrap());
+        ([read(0), read(4), read(8)], [read(32), read(36), read(40)])
+    };
+    let connected = TestInput {
+        buttons: TestInput::CONNECTED,
+        ..Default::default()
+    };
+
+    game.init().unwrap();
+
+    // Tick 1: player 0 was connected from the start
+    game.set_input(0, connected);
+    game.update(1.0 / 60.0).unwrap();
+    assert_eq!(counts(&mut game), ([1, 0, 0], [0, 0, 0]));
+    let snapshot = state_manager.save_state(&mut game, 1).unwrap();
+
+    // Tick 2: player 2 plugs in; tick 3: player 0 unplugs
+    game.set_input(2, connected);
+    game.update(1.0 / 60.0).unwrap();
+    game.set_input(0, TestInput::default());
+    game.update(1.0 / 60.0).unwrap();
+    assert_eq!(counts(&mut game), ([1, 0, 1], [1, 0, 0]));
+
+    let kinds: Vec<_> = (0..3)
+        .filter_map(|_| game.state_mut().events.pop())
+        .map(|event| (event.kind, event.data[0]))
+        .collect();
+    assert_eq!(
+        kinds,
+        [
+            (crate::event::event_kind::PLAYER_JOIN, 0),
+            (crate::event::event_kind::PLAYER_JOIN, 2),
+            (crate::event::event_kind::PLAYER_LEAVE, 0),
+        ]
+    );
+
+    // Roll back to tick 1 and re-simulate: the same callbacks fire again
+    state_manager.load_state(&mut game, &snapshot).unwrap();
+    assert_eq!(counts(&mut game), ([1, 0, 0], [0, 0, 0]));
+    game.set_input(2, connected);
+    game.update(1.0 / 60.0).unwrap();
+    game.set_input(0, TestInput::default());
+    game.update(1.0 / 60.0).unwrap();
+    assert_eq!(counts(&mut game), ([1, 0, 1], [1, 0, 0]));
+}
+
+/// Test per-player input prediction policies
+///
+/// Player 1 uses the game's `predict_input()` export, player 2 predicts a
+/// neutral input, and player 0 keeps the default of holding the last input.
+#[test]
+fn test_predict_input_policies() {
+    let (engine, linker) = create_test_engine();
+
+    // predict_input(player, ticks) writes {buttons: ticks, x: player, y: 0}
+    // at address 256, or returns 0 for player 3 to keep the held input
+    let wat = r#"
+        (module
+            (import "env" "predict_policy" (func $predict_policy (param i32 i32)))
+            (memory (export "memory") 1)
+
+            (func (export "init")
+                (call $predict_policy (i32.const 1) (i32.const 2))
+                (call $predict_policy (i32.const 2) (i32.const 1))
+                (call $predict_policy (i32.const 3) (i32.const 2))
+            )
+            (func (export "update"))
+            (func (export "render"))
+
+            (func (export "predict_input") (param $player i32) (param $ticks i32) (result i32)
+                (if (i32.eq (local.get $player) (i32.const 3))
+                    (then (return (i32.const 0)))
+                )
+                (i32.store16 (i32.const 256) (local.get $ticks))
+                (i32.store8 (i32.const 258) (local.get $player))
+                (i32.store8 (i32.const 259) (i32.const 0))
+                (i32.const 256)
+            )
+        )
+    "#;
+
+    let wasm = wat::parse_str(wat).unwrap();
+    let module = engine.load_module(&wasm).unwrap();
+    let mut game = new_test_game_instance(&engine, &module, &linker);
+    game.init().unwrap();
+
+    let held = TestInput {
+        buttons: TestInput::CONNECTED | 0x0003,
+        x: 100,
+        y: -50,
+    };
+    assert_eq!(game.predict_input(0, held, 1).unwrap(), held);
+    assert_eq!(
+        game.predict_input(1, held, 4).unwrap(),
+        TestInput {
+            buttons: 4,
+            x: 1,
+            y: 0,
+        }
+    );
+    assert_eq!(
+        game.predict_input(2, held, 1).unwrap(),
+        TestInput {
+            buttons: TestInput::CONNECTED,
+            ..Default::default()
+        }
+    );
+    assert_eq!(game.predict_input(3, held, 1).unwrap(), held);
+}
+
+/// Test the frame debugger's tick history: capacity, rewinding and
+/// discarding the abandoned future once the game ticks again
+#[test]
+fn test_tick_history_scrub() {
+    use crate::debug::TickHistory;
+    use crate::rollback::MAX_STATE_SIZE;
+
+    let (engine, linker) = create_test_engine();
+
+    let wat = r#"
+        (module
+            (memory (export "memory") 1)
+
+            (func (export "init"))
+            (func (export "update")
+                (i32.store (i32.const 0)
+                    (i32.add (i32.load (i32.const 0)) (i32.const 1))
+                )
+            )
+            (func (export "render"))
+        )
+    "#;
+
+    let wasm = wat::parse_str(wat).unwrap();
+    let module = engine.load_module(&wasm).unwrap();
+    let mut game = new_test_game_instance(&engine, &module, &linker);
+    let mut history = TickHistory::with_capacity(MAX_STATE_SIZE, 4);
+
+    game.init().unwrap();
+    for _ in 0..6 {
+        game.update(1.0 / 60.0).unwrap();
+        history.record(&mut game).unwrap();
+    }
+
+    // Only the last 4 ticks are kept
+    assert_eq!(history.len(), 4);
+    assert_eq!(history.position(), 3);
+
+    // Rewind to the oldest entry (tick 3), then scrub forward again
+    history.restore(&mut game, 0).unwrap();
+    assert_eq!(game.state().tick_count, 3);
+    assert_eq!(history.position(), 0);
+    history.restore(&mut game, 2).unwrap();
+    assert_eq!(game.state().tick_count, 5);
+
+    // Ticking from a rewound entry replaces the entries after it
+    game.update(1.0 / 60.0).unwrap();
+    history.record(&mut game).unwrap();
+    assert_eq!(history.len(), 4);
+    assert_eq!(history.position(), 3);
+    history.restore(&mut game, 3).unwrap();
+    assert_eq!(game.state().tick_count, 6);
+
+    history.clear();
+    assert!(history.is_empty());
+}
+
+/// Test that `predict_input()` leaves no trace in the peer that calls it
+///
+/// Two peers run the same inputs. Each one predicts the other's player with a
+/// `predict_input()` that gets the input right but scribbles on memory and
+/// draws a random number, as a real cart's scratch buffer and shadow stack
+/// would. Since the predictions match, nothing is re-simulated, so the
+/// snapshots both peers save must stay identical.
+#[test]
+fn test_predict_input_keeps_peer_checksums_equal() {
+    let (engine, linker) = create_test_engine();
+
+    // Real inputs live at 128 + 4 * player; predict_input copies one into a
+    // scratch buffer at 256, bumps a call counter at 512 and draws a random
+    // number. update() stores a random number at 0.
+    let wat = r#"
+        (module
+            (import "env" "predict_policy" (func $predict_policy (param i32 i32)))
+            (import "env" "random" (func $random (result i32)))
+            (memory (export "memory") 1)
+
+            (func (export "init")
+                (call $predict_policy (i32.const 0) (i32.const 2))
+                (call $predict_policy (i32.const 1) (i32.const 2))
+                (i32.store (i32.const 128) (i32.const 0x000a8001))
+                (i32.store (i32.const 132) (i32.const 0x03fb8002))
+            )
+            (func (export "update")
+                (i32.store (i32.const 0) (call $random))
+            )
+            (func (export "render"))
+
+            (func (export "predict_input") (param $player i32) (param $ticks i32) (result i32)
+                (i32.store (i32.const 256)
+                    (i32.load (i32.add (i32.const 128) (i32.shl (local.get $player) (i32.const 2))))
+                )
+                (i32.store (i32.const 512)
+                    (i32.add (i32.load (i32.const 512)) (i32.const 1))
+                )
+                (drop (call $random))
+                (i32.const 256)
+            )
+        )
+    "#;
+
+    let real = [
+        TestInput {
+            buttons: TestInput::CONNECTED | 1,
+            x: 10,
+            y: 0,
+        },
+        TestInput {
+            buttons: TestInput::CONNECTED | 2,
+            x: -5,
+            y: 3,
+        },
+    ];
+
+    let wasm = wat::parse_str(wat).unwrap();
+    let module = engine.load_module(&wasm).unwrap();
+    let mut checksums = Vec::new();
+    for remote in [1, 0] {
+        let mut game = new_test_game_instance(&engine, &module, &linker);
+        let mut state_manager = RollbackStateManager::with_defaults();
+        game.init().unwrap();
+
+        let mut peer = Vec::new();
+        for frame in 0..8 {
+            let snapshot = state_manager.save_state(&mut game, frame).unwrap();
+            peer.push(snapshot.checksum);
+
+            // The remote player's last input is stale by a tick
+            let held = if frame == 0 {
+                TestInput::default()
+            } else {
+                real[remote]
+            };
+            assert_eq!(game.predict_input(remote, held, 1).unwrap(), real[remote]);
+
+            for (player, input) in real.iter().enumerate() {
+                game.set_input(player, *input);
+            }
+            game.update(1.0 / 60.0).unwrap();
+        }
+        peer.push(state_manager.save_state(&mut game, 8).unwrap().checksum);
+        checksums.push(peer);
+    }
+
+    assert_eq!(checksums[0], checksums[1]);
+}
+
+/// Test that `predict_input()` can't grow memory and that every call in a
+/// tick sees the same state
+///
+/// A grown memory couldn't be put back, leaving the peer out of step. The
+/// call counter at 512 is restored between the two custom predictions, so
+/// both players read it as 0.
+#[test]
+fn test_predict_input_cannot_grow_memory() {
+    let (engine, linker) = create_test_engine();
+
+    // predict_input tries to grow memory, then reports the result of
+    // memory.grow in x and the call counter at 512 in y
+    let wat = r#"
+        (module
+            (import "env" "predict_policy" (func $predict_policy (param i32 i32)))
+            (memory (export "memory") 1)
+
+            (func (export "init")
+                (call $predict_policy (i32.const 0) (i32.const 2))
+                (call $predict_policy (i32.const 1) (i32.const 2))
+            )
+            (func (export "update"))
+            (func (export "render"))
+
+            (func (export "predict_input") (param $player i32) (param $ticks i32) (result i32)
+                (i32.store16 (i32.const 256) (i32.const 0x8000))
+                (i32.store8 (i32.const 258) (memory.grow (i32.const 1)))
+                (i32.store8 (i32.const 259) (i32.load (i32.const 512)))
+                (i32.store (i32.const 512)
+                    (i32.add (i32.load (i32.const 512)) (i32.const 1))
+                )
+                (i32.const 256)
+            )
+        )
+    "#;
+
+    let wasm = wat::parse_str(wat).unwrap();
+    let module = engine.load_module(&wasm).unwrap();
+    let mut game = new_test_game_instance(&engine, &module, &linker);
+    game.init().unwrap();
+    let before = game.save_state().unwrap();
+
+    let held = TestInput::default();
+    let fed = game
+        .predict_inputs(&[(held, true), (held, true)], 1)
+        .unwrap();
+    let expected = TestInput {
+        buttons: TestInput::CONNECTED,
+        x: -1,
+        y: 0,
+    };
+    assert_eq!(fed, vec![expected, expected]);
+    assert_eq!(game.save_state().unwrap(), before);
+
+    // Growth is only refused while predicting
+    assert!(!game.state().predicting);
+}
@@ -10,30 +10,42 @@
 //! - [`GameInstance`] - WASM game loaded and instantiated
 //! - [`RollbackSession`] - GGRS integration for rollback netcode
 
+pub mod analytics;
 pub mod app;
 pub mod capture;
 pub mod console;
+pub mod daily;
 pub mod debug;
+pub mod event;
 pub mod ffi;
+pub mod ghost;
 #[cfg(test)]
 mod integration;
 pub mod library;
 pub mod net;
 pub mod replay;
+pub mod rng;
 pub mod rollback;
 pub mod runner;
 pub mod runtime;
 pub mod save_store;
+pub mod series;
 #[cfg(test)]
 pub mod test_utils;
+pub mod thumbnail;
+pub mod timer;
+pub mod token_store;
 pub mod wasm;
 
 // Re-export core traits and types
 pub use console::{
     Audio, AudioGenerator, Console, ConsoleInput, ConsoleRollbackState, ConsoleSpecs, Graphics,
+    MixerBus,
 };
+pub use daily::DailyChallenge;
 pub use runner::ConsoleRunner;
 pub use runtime::{Runtime, RuntimeConfig};
+pub use series::SeriesState;
 #[allow(deprecated)]
 pub use wasm::{
     GameInstance, GameState, GameStateWithConsole, MAX_PLAYERS, MAX_SAVE_SIZE, MAX_SAVE_SLOTS,
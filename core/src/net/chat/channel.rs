@@ -0,0 +1,377 @@
+//! Shared chat channel state
+//!
+//! [`ChatChannel`] is a cloneable handle shared by the game (FFI) and the
+//! GGRS socket. Chat is best-effort over UDP, so each outgoing packet is
+//! sent a few times and receivers drop the duplicates by sequence number.
+//! Packets may also arrive out of order, so each sender's recently seen
+//! sequence numbers are tracked in a sliding window rather than just the last.
+
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use super::packet::{ChatBody, ChatPacket};
+use super::{CHAT_MAX_LEN, ping_kind};
+use crate::wasm::MAX_PLAYERS;
+
+/// How many times each outgoing packet is sent
+const SEND_REPEATS: u8 = 3;
+
+/// Maximum unread messages (and pings) kept; the oldest are dropped first
+const MAX_INBOX: usize = 32;
+
+/// How many sequence numbers back a [`SeqWindow`] remembers
+const SEQ_WINDOW: u16 = 64;
+
+/// A received text message
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChatMessage {
+    /// Player handle of the sender
+    pub sender: u8,
+    /// Message text (at most [`CHAT_MAX_LEN`] bytes)
+    pub text: String,
+}
+
+/// A received world-space ping
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ping {
+    /// Player handle of the sender
+    pub sender: u8,
+    /// Ping kind (see [`super::ping_kind`])
+    pub kind: u8,
+    /// World position
+    pub position: [f32; 3],
+}
+
+/// Sequence numbers recently received from one sender
+#[derive(Debug, Clone, Copy)]
+struct SeqWindow {
+    /// Newest sequence number received
+    newest: u16,
+    /// Bit `n` is set if `newest - n` was received
+    seen: u64,
+}
+
+impl SeqWindow {
+    fn new(seq: u16) -> Self {
+        Self {
+            newest: seq,
+            seen: 1,
+        }
+    }
+
+    /// Record `seq`; returns `false` if it was already received or is too
+    /// old to tell
+    fn accept(&mut self, seq: u16) -> bool {
+        let ahead = seq.wrapping_sub(self.newest) as i16;
+        if ahead > 0 {
+            let ahead = ahead as u16;
+            self.seen = if ahead < SEQ_WINDOW {
+                self.seen << ahead
+            } else {
+                0
+            };
+            self.seen |= 1;
+            self.newest = seq;
+            return true;
+        }
+
+        let behind = ahead.unsigned_abs();
+        if behind >= SEQ_WINDOW || self.seen & (1 << behind) != 0 {
+            return false;
+        }
+        self.seen |= 1 << behind;
+        true
+    }
+}
+
+#[derive(Debug, Default)]
+struct ChatState {
+    local_handle: u8,
+    next_seq: u16,
+    /// Encoded packets and the number of sends remaining
+    outgoing: VecDeque<(Vec<u8>, u8)>,
+    messages: VecDeque<ChatMessage>,
+    pings: VecDeque<Ping>,
+    received: [Option<SeqWindow>; MAX_PLAYERS],
+}
+
+impl ChatState {
+    fn queue(&mut self, body: ChatBody<'_>) {
+        let packet = ChatPacket {
+            sender: self.local_handle,
+            seq: self.next_seq,
+            body,
+        };
+        self.next_seq = self.next_seq.wrapping_add(1);
+        self.outgoing.push_back((packet.encode(), SEND_REPEATS));
+        self.deliver(packet);
+    }
+
+    fn deliver(&mut self, packet: ChatPacket<'_>) {
+        match packet.body {
+            ChatBody::Text(text) => {
+                push_bounded(
+                    &mut self.messages,
+                    ChatMessage {
+                        sender: packet.sender,
+                        text: text.to_string(),
+                    },
+                );
+            }
+            ChatBody::Ping { kind, position } => {
+                push_bounded(
+                    &mut self.pings,
+                    Ping {
+                        sender: packet.sender,
+                        kind,
+                        position,
+                    },
+                );
+            }
+        }
+    }
+}
+
+fn push_bounded<T>(queue: &mut VecDeque<T>, item: T) {
+    if queue.len() >= MAX_INBOX {
+        queue.pop_front();
+    }
+    queue.push_back(item);
+}
+
+/// Truncate `text` to at most [`CHAT_MAX_LEN`] bytes on a char boundary
+fn truncate(text: &str) -> &str {
+    if text.len() <= CHAT_MAX_LEN {
+        return text;
+    }
+    let mut end = CHAT_MAX_LEN;
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    &text[..end]
+}
+
+/// Shared text chat and ping state for one session
+#[derive(Debug, Clone, Default)]
+pub struct ChatChannel {
+    inner: Arc<Mutex<ChatState>>,
+}
+
+impl ChatChannel {
+    /// Create a channel for the given local player handle
+    pub fn new(local_handle: u8) -> Self {
+        let channel = Self::default();
+        channel.lock().local_handle = local_handle;
+        channel
+    }
+
+    fn lock(&self) -> MutexGuard<'_, ChatState> {
+        self.inner.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Get the local player handle used as the packet sender
+    pub fn local_handle(&self) -> u8 {
+        self.lock().local_handle
+    }
+
+    /// Send a text message (truncated to [`CHAT_MAX_LEN`] bytes)
+    ///
+    /// Empty messages are ignored.
+    pub fn send_text(&self, text: &str) {
+        let text = truncate(text.trim());
+        if !text.is_empty() {
+            self.lock().queue(ChatBody::Text(text));
+        }
+    }
+
+    /// Send a world-space ping (unknown kinds become [`ping_kind::LOOK`])
+    pub fn send_ping(&self, kind: u32, position: [f32; 3]) {
+        let kind = if kind < ping_kind::COUNT {
+            kind as u8
+        } else {
+            ping_kind::LOOK as u8
+        };
+        self.lock().queue(ChatBody::Ping { kind, position });
+    }
+
+    /// Take the packets to send this poll
+    ///
+    /// Each packet is returned [`SEND_REPEATS`] times across successive calls.
+    pub fn take_outgoing(&self) -> Vec<Vec<u8>> {
+        let mut state = self.lock();
+        let packets = state
+            .outgoing
+            .iter_mut()
+            .map(|(packet, remaining)| {
+                *remaining -= 1;
+                packet.clone()
+            })
+            .collect();
+        state.outgoing.retain(|(_, remaining)| *remaining > 0);
+        packets
+    }
+
+    /// Handle an incoming chat datagram
+    ///
+    /// Returns `false` if the datagram was not a valid chat packet. Resent
+    /// duplicates and our own packets are dropped.
+    pub fn receive_packet(&self, data: &[u8]) -> bool {
+        let Some(packet) = ChatPacket::decode(data) else {
+            return false;
+        };
+
+        let mut state = self.lock();
+        let sender = packet.sender as usize;
+        if sender >= MAX_PLAYERS || packet.sender == state.local_handle {
+            return true;
+        }
+
+        let fresh = match &mut state.received[sender] {
+            Some(window) => window.accept(packet.seq),
+            slot @ None => {
+                *slot = Some(SeqWindow::new(packet.seq));
+                true
+            }
+        };
+        if fresh {
+            state.deliver(packet);
+        }
+        true
+    }
+
+    /// Pop the oldest unread message
+    pub fn poll_message(&self) -> Option<ChatMessage> {
+        self.lock().messages.pop_front()
+    }
+
+    /// Pop the oldest unread ping
+    pub fn poll_ping(&self) -> Option<Ping> {
+        self.lock().pings.pop_front()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn deliver_all(from: &ChatChannel, to: &ChatChannel) {
+        for packet in from.take_outgoing() {
+            assert!(to.receive_packet(&packet));
+        }
+    }
+
+    #[test]
+    fn test_local_echo() {
+        let chat = ChatChannel::new(0);
+        chat.send_text("hello");
+        assert_eq!(
+            chat.poll_message(),
+            Some(ChatMessage {
+                sender: 0,
+                text: "hello".to_string()
+            })
+        );
+        assert_eq!(chat.poll_message(), None);
+    }
+
+    #[test]
+    fn test_remote_delivery_dedupes_resends() {
+        let alice = ChatChannel::new(0);
+        let bob = ChatChannel::new(1);
+        alice.send_text("on my way");
+
+        for _ in 0..SEND_REPEATS {
+            deliver_all(&alice, &bob);
+        }
+        assert!(alice.take_outgoing().is_empty());
+
+        assert_eq!(bob.poll_message().unwrap().text, "on my way");
+        assert_eq!(bob.poll_message(), None);
+    }
+
+    #[test]
+    fn test_out_of_order_delivery() {
+        let alice = ChatChannel::new(0);
+        let bob = ChatChannel::new(1);
+        for text in ["one", "two", "three"] {
+            alice.send_text(text);
+        }
+
+        // The first send of each packet arrives in reverse, then the resends
+        let mut first = alice.take_outgoing();
+        first.reverse();
+        for packet in first.iter().chain(&alice.take_outgoing()) {
+            assert!(bob.receive_packet(packet));
+        }
+
+        let texts: Vec<_> = std::iter::from_fn(|| bob.poll_message())
+            .map(|message| message.text)
+            .collect();
+        assert_eq!(texts, ["three", "two", "one"]);
+    }
+
+    #[test]
+    fn test_seq_window() {
+        let mut window = SeqWindow::new(u16::MAX - 1);
+        assert!(window.accept(1));
+        assert!(window.accept(u16::MAX));
+        assert!(window.accept(0));
+        assert!(!window.accept(u16::MAX));
+        assert!(!window.accept(1));
+
+        // A big jump forgets everything older
+        assert!(window.accept(1 + SEQ_WINDOW));
+        assert!(window.accept(2));
+        assert!(!window.accept(1));
+    }
+
+    #[test]
+    fn test_ping_delivery() {
+        let alice = ChatChannel::new(3);
+        let bob = ChatChannel::new(1);
+        alice.send_ping(ping_kind::DANGER, [1.0, 2.0, 3.0]);
+        alice.send_ping(99, [0.0; 3]);
+        deliver_all(&alice, &bob);
+
+        let ping = bob.poll_ping().unwrap();
+        assert_eq!(ping.sender, 3);
+        assert_eq!(ping.kind, ping_kind::DANGER as u8);
+        assert_eq!(ping.position, [1.0, 2.0, 3.0]);
+        assert_eq!(bob.poll_ping().unwrap().kind, ping_kind::LOOK as u8);
+    }
+
+    #[test]
+    fn test_own_packets_are_ignored() {
+        let alice = ChatChannel::new(0);
+        let echo = ChatChannel::new(0);
+        alice.send_text("hi");
+        deliver_all(&alice, &echo);
+        assert_eq!(echo.poll_message(), None);
+    }
+
+    #[test]
+    fn test_long_messages_truncate_on_char_boundary() {
+        let chat = ChatChannel::new(0);
+        chat.send_text(&"é".repeat(CHAT_MAX_LEN));
+        let text = chat.poll_message().unwrap().text;
+        assert!(text.len() <= CHAT_MAX_LEN);
+        assert!(text.chars().all(|c| c == 'é'));
+    }
+
+    #[test]
+    fn test_empty_messages_are_ignored() {
+        let chat = ChatChannel::new(0);
+        chat.send_text("   ");
+        assert_eq!(chat.poll_message(), None);
+        assert!(chat.take_outgoing().is_empty());
+    }
+
+    #[test]
+    fn test_inbox_is_bounded() {
+        let chat = ChatChannel::new(0);
+        for i in 0..MAX_INBOX + 5 {
+            chat.send_text(&i.to_string());
+        }
+        assert_eq!(chat.poll_message().unwrap().text, "5");
+    }
+}
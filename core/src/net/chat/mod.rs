@@ -0,0 +1,46 @@
+//! Text chat and quick-chat pings
+//!
+//! A communication channel for sessions where voice isn't available (or
+//! isn't wanted). Like voice, chat shares the GGRS socket and is
+//! presentation-only: messages never feed back into the simulation.
+//!
+//! - [`packet`] - Wire framing for text messages and world pings
+//! - [`channel`] - Shared inbox/outbox state used by the FFI and the socket
+//!
+//! Local messages are echoed into the local inbox so a game renders its own
+//! chat log the same way in local and online sessions.
+
+pub mod channel;
+pub mod packet;
+
+pub use channel::{ChatChannel, ChatMessage, Ping};
+pub use packet::{CHAT_MAGIC, ChatPacket};
+
+/// Maximum text message length in bytes (UTF-8)
+pub const CHAT_MAX_LEN: usize = 128;
+
+/// Canned quick-chat messages, indexed by `quick_chat(id)`
+pub const QUICK_CHAT_MESSAGES: &[&str] = &[
+    "Help!",
+    "On my way!",
+    "Wait for me!",
+    "Go go go!",
+    "Nice!",
+    "Thanks!",
+    "Sorry!",
+    "Good game!",
+];
+
+/// Ping kinds (`ping(x, y, z, kind)`)
+pub mod ping_kind {
+    /// Generic "look here" marker
+    pub const LOOK: u32 = 0;
+    /// Move to this location
+    pub const GO: u32 = 1;
+    /// Enemy / danger at this location
+    pub const DANGER: u32 = 2;
+    /// Item or resource at this location
+    pub const ITEM: u32 = 3;
+    /// Number of ping kinds (larger values are clamped to LOOK)
+    pub const COUNT: u32 = 4;
+}
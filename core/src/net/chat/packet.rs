@@ -0,0 +1,164 @@
+//! Chat packet framing
+//!
+//! Chat packets share the GGRS socket, so they start with a magic that
+//! can't be mistaken for a bincode-encoded GGRS message:
+//!
+//! ```text
+//! magic "NCCH" (4) | kind (1) | sender (1) | sequence (2, BE) | body
+//!
+//! text: UTF-8 bytes (<= CHAT_MAX_LEN)
+//! ping: ping kind (1) | x, y, z (f32 BE, 12)
+//! ```
+
+use super::CHAT_MAX_LEN;
+
+/// Chat packet magic bytes
+pub const CHAT_MAGIC: [u8; 4] = *b"NCCH";
+
+const KIND_TEXT: u8 = 0;
+const KIND_PING: u8 = 1;
+
+/// Header size (magic + kind + sender + sequence)
+const HEADER_SIZE: usize = 8;
+
+/// Ping body size (kind + position)
+const PING_BODY_SIZE: usize = 13;
+
+/// Chat packet body
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ChatBody<'a> {
+    /// Text message
+    Text(&'a str),
+    /// World-space ping
+    Ping {
+        /// Ping kind (see [`super::ping_kind`])
+        kind: u8,
+        /// World position
+        position: [f32; 3],
+    },
+}
+
+/// A chat message or ping from one player
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ChatPacket<'a> {
+    /// Player handle of the sender
+    pub sender: u8,
+    /// Per-sender sequence number (wraps), used to drop duplicate resends
+    pub seq: u16,
+    /// Message body
+    pub body: ChatBody<'a>,
+}
+
+impl<'a> ChatPacket<'a> {
+    /// Encode into a datagram
+    pub fn encode(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(HEADER_SIZE + CHAT_MAX_LEN);
+        buf.extend_from_slice(&CHAT_MAGIC);
+        buf.push(match self.body {
+            ChatBody::Text(_) => KIND_TEXT,
+            ChatBody::Ping { .. } => KIND_PING,
+        });
+        buf.push(self.sender);
+        buf.extend_from_slice(&self.seq.to_be_bytes());
+
+        match self.body {
+            ChatBody::Text(text) => buf.extend_from_slice(text.as_bytes()),
+            ChatBody::Ping { kind, position } => {
+                buf.push(kind);
+                for v in position {
+                    buf.extend_from_slice(&v.to_be_bytes());
+                }
+            }
+        }
+        buf
+    }
+
+    /// Decode a datagram, returning `None` if it isn't a valid chat packet
+    pub fn decode(data: &'a [u8]) -> Option<Self> {
+        let rest = data.strip_prefix(&CHAT_MAGIC)?;
+        let (&kind, rest) = rest.split_first()?;
+        let (&sender, rest) = rest.split_first()?;
+        let (seq, body) = rest.split_first_chunk::<2>()?;
+
+        let body = match kind {
+            KIND_TEXT => {
+                if body.len() > CHAT_MAX_LEN {
+                    return None;
+                }
+                ChatBody::Text(std::str::from_utf8(body).ok()?)
+            }
+            KIND_PING => {
+                if body.len() != PING_BODY_SIZE {
+                    return None;
+                }
+                let mut coords = body[1..]
+                    .chunks_exact(4)
+                    .map(|b| f32::from_be_bytes([b[0], b[1], b[2], b[3]]));
+                ChatBody::Ping {
+                    kind: body[0],
+                    position: std::array::from_fn(|_| coords.next().unwrap_or(0.0)),
+                }
+            }
+            _ => return None,
+        };
+
+        Some(Self {
+            sender,
+            seq: u16::from_be_bytes(*seq),
+            body,
+        })
+    }
+
+    /// Check whether a datagram starts with the chat magic
+    pub fn is_chat_packet(data: &[u8]) -> bool {
+        data.starts_with(&CHAT_MAGIC)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_text_roundtrip() {
+        let packet = ChatPacket {
+            sender: 2,
+            seq: 7,
+            body: ChatBody::Text("gg ✨"),
+        };
+        let bytes = packet.encode();
+        assert!(ChatPacket::is_chat_packet(&bytes));
+        assert_eq!(ChatPacket::decode(&bytes), Some(packet));
+    }
+
+    #[test]
+    fn test_ping_roundtrip() {
+        let packet = ChatPacket {
+            sender: 5,
+            seq: 0xFFFF,
+            body: ChatBody::Ping {
+                kind: 2,
+                position: [1.5, -2.0, 300.25],
+            },
+        };
+        let bytes = packet.encode();
+        assert_eq!(bytes.len(), HEADER_SIZE + PING_BODY_SIZE);
+        assert_eq!(ChatPacket::decode(&bytes), Some(packet));
+    }
+
+    #[test]
+    fn test_decode_rejects_garbage() {
+        assert_eq!(ChatPacket::decode(b""), None);
+        assert_eq!(ChatPacket::decode(b"NCVC\x00\x00\x00\x00"), None);
+        // Unknown kind
+        assert_eq!(ChatPacket::decode(b"NCCH\x09\x00\x00\x00"), None);
+        // Invalid UTF-8
+        assert_eq!(ChatPacket::decode(b"NCCH\x00\x00\x00\x00\xFF"), None);
+        // Truncated ping
+        assert_eq!(ChatPacket::decode(b"NCCH\x01\x00\x00\x00\x01"), None);
+        // Oversized text
+        let mut bytes = b"NCCH\x00\x00\x00\x00".to_vec();
+        bytes.extend_from_slice(&[b'a'; CHAT_MAX_LEN + 1]);
+        assert_eq!(ChatPacket::decode(&bytes), None);
+    }
+}
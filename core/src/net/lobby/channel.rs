@@ -0,0 +1,185 @@
+//! Shared lobby state
+//!
+//! [`LobbyChannel`] is a cloneable handle shared by the game (FFI) and the
+//! runtime's [`super::LobbyDriver`]. The game queues [`LobbyRequest`]s; the
+//! driver carries them out and publishes the resulting state.
+
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use super::lobby_state;
+use crate::net::rendezvous::LobbyListing;
+
+/// Maximum queued state changes for `on_lobby_state()`; the oldest are dropped first
+const MAX_STATE_CHANGES: usize = 16;
+
+/// A request from the game to the lobby driver
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LobbyRequest {
+    /// Host a new lobby and advertise it
+    Create { name: String, max_players: u8 },
+    /// Refresh the lobby listing
+    Refresh,
+    /// Join a listed lobby by id
+    Join(u32),
+    /// Start the game (host only)
+    Start,
+    /// Leave or close the current lobby
+    Leave,
+}
+
+#[derive(Debug, Default)]
+struct LobbyShared {
+    /// Whether a driver is attached (lobbies are unavailable otherwise)
+    enabled: bool,
+    state: u32,
+    is_host: bool,
+    players: u8,
+    listings: Vec<LobbyListing>,
+    requests: VecDeque<LobbyRequest>,
+    changes: VecDeque<u32>,
+}
+
+/// Shared lobby state for one game
+#[derive(Debug, Clone, Default)]
+pub struct LobbyChannel {
+    inner: Arc<Mutex<LobbyShared>>,
+}
+
+impl LobbyChannel {
+    /// Create a channel with lobbies enabled
+    ///
+    /// The default channel is disabled, so games running without a lobby
+    /// driver (netplay sessions, replays, tools) can't create lobbies.
+    pub fn new() -> Self {
+        let channel = Self::default();
+        channel.lock().enabled = true;
+        channel
+    }
+
+    fn lock(&self) -> MutexGuard<'_, LobbyShared> {
+        self.inner.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Whether a lobby driver is attached
+    pub fn is_enabled(&self) -> bool {
+        self.lock().enabled
+    }
+
+    /// Current `lobby_state` value
+    pub fn state(&self) -> u32 {
+        self.lock().state
+    }
+
+    /// Whether the local player is hosting the current lobby
+    pub fn is_host(&self) -> bool {
+        self.lock().is_host
+    }
+
+    /// Players in the current lobby (including the local player)
+    pub fn player_count(&self) -> u8 {
+        self.lock().players
+    }
+
+    /// Lobbies from the most recent listing
+    pub fn listings(&self) -> Vec<LobbyListing> {
+        self.lock().listings.clone()
+    }
+
+    /// Queue a request for the driver
+    pub fn request(&self, request: LobbyRequest) {
+        self.lock().requests.push_back(request);
+    }
+
+    /// Drain state changes not yet reported to the game, oldest first
+    pub fn take_state_changes(&self) -> Vec<u32> {
+        self.lock().changes.drain(..).collect()
+    }
+
+    /// Drain queued requests (driver side)
+    pub fn take_requests(&self) -> Vec<LobbyRequest> {
+        self.lock().requests.drain(..).collect()
+    }
+
+    /// Publish a new state (driver side), queueing a change if it differs
+    pub fn set_state(&self, state: u32) {
+        let mut shared = self.lock();
+        if shared.state == state {
+            return;
+        }
+        shared.state = state;
+        if shared.changes.len() >= MAX_STATE_CHANGES {
+            shared.changes.pop_front();
+        }
+        shared.changes.push_back(state);
+        if state == lobby_state::IDLE {
+            shared.is_host = false;
+            shared.players = 0;
+        }
+    }
+
+    /// Publish the local player's role and the lobby's player count (driver side)
+    pub fn set_members(&self, is_host: bool, players: u8) {
+        let mut shared = self.lock();
+        shared.is_host = is_host;
+        shared.players = players;
+    }
+
+    /// Publish a new lobby listing (driver side)
+    pub fn set_listings(&self, listings: Vec<LobbyListing>) {
+        self.lock().listings = listings;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_is_disabled() {
+        assert!(!LobbyChannel::default().is_enabled());
+        assert!(LobbyChannel::new().is_enabled());
+    }
+
+    #[test]
+    fn test_requests_are_shared() {
+        let game = LobbyChannel::new();
+        let driver = game.clone();
+        game.request(LobbyRequest::Refresh);
+        game.request(LobbyRequest::Join(3));
+        assert_eq!(
+            driver.take_requests(),
+            vec![LobbyRequest::Refresh, LobbyRequest::Join(3)]
+        );
+        assert!(driver.take_requests().is_empty());
+    }
+
+    #[test]
+    fn test_state_changes_are_queued_once() {
+        let channel = LobbyChannel::new();
+        channel.set_state(lobby_state::HOSTING);
+        channel.set_state(lobby_state::HOSTING);
+        channel.set_members(true, 2);
+        channel.set_state(lobby_state::IN_LOBBY);
+        assert_eq!(
+            channel.take_state_changes(),
+            vec![lobby_state::HOSTING, lobby_state::IN_LOBBY]
+        );
+        assert_eq!(channel.player_count(), 2);
+
+        // Returning to idle clears the membership
+        channel.set_state(lobby_state::IDLE);
+        assert!(!channel.is_host());
+        assert_eq!(channel.player_count(), 0);
+        assert_eq!(channel.take_state_changes(), vec![lobby_state::IDLE]);
+    }
+
+    #[test]
+    fn test_state_change_queue_is_bounded() {
+        let channel = LobbyChannel::new();
+        for i in 0..(MAX_STATE_CHANGES as u32 * 2) {
+            channel.set_state(1 + i % 2);
+        }
+        assert_eq!(channel.take_state_changes().len(), MAX_STATE_CHANGES);
+    }
+}
@@ -0,0 +1,319 @@
+//! Lobby driver
+//!
+//! [`LobbyDriver`] carries out the game's [`LobbyRequest`]s: it hosts or joins
+//! lobbies over NCHS, advertises hosted lobbies on the rendezvous server and
+//! fetches listings from it. The runtime calls [`LobbyDriver::poll`] once per
+//! frame; it never blocks.
+
+use std::io;
+use std::net::{SocketAddr, UdpSocket};
+use std::time::{Duration, Instant};
+
+use super::channel::{LobbyChannel, LobbyRequest};
+use super::lobby_state;
+use crate::net::nat::config::resolve_addr;
+use crate::net::nchs::{
+    DEFAULT_NCHS_PORT, NchsConfig, NchsEvent, NchsRole, NchsSession, NchsState, SessionStart,
+};
+use crate::net::rendezvous::{REGISTER_INTERVAL, RendezvousMessage};
+
+/// Minimum time between listing requests sent to the rendezvous server
+const REFRESH_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Maximum NCHS events handled per poll
+const MAX_EVENTS_PER_POLL: usize = 32;
+
+/// Runs lobbies for one game on behalf of the runtime
+pub struct LobbyDriver {
+    /// NCHS configuration for hosting and joining (ROM netplay metadata, NAT)
+    config: NchsConfig,
+    channel: LobbyChannel,
+    /// Socket used to talk to the rendezvous server
+    socket: UdpSocket,
+    server: SocketAddr,
+    /// Current NCHS session (hosting or joined)
+    session: Option<NchsSession>,
+    /// Whether the guest has marked itself ready
+    ready_sent: bool,
+    /// Hosted lobby name and its rendezvous id (0 until registered)
+    lobby_name: String,
+    lobby_id: u32,
+    last_register: Option<Instant>,
+    last_refresh: Option<Instant>,
+}
+
+impl LobbyDriver {
+    /// Create a driver that advertises lobbies on `rendezvous_server` ("host:port")
+    pub fn new(config: NchsConfig, rendezvous_server: &str) -> io::Result<Self> {
+        let server = resolve_addr(rendezvous_server).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("rendezvous server '{}' did not resolve", rendezvous_server),
+            )
+        })?;
+        let bind_addr = if server.is_ipv4() {
+            "0.0.0.0:0"
+        } else {
+            "[::]:0"
+        };
+        let socket = UdpSocket::bind(bind_addr)?;
+        socket.set_nonblocking(true)?;
+
+        Ok(Self {
+            config,
+            channel: LobbyChannel::new(),
+            socket,
+            server,
+            session: None,
+            ready_sent: false,
+            lobby_name: String::new(),
+            lobby_id: 0,
+            last_register: None,
+            last_refresh: None,
+        })
+    }
+
+    /// The channel to hand to the game
+    pub fn channel(&self) -> LobbyChannel {
+        self.channel.clone()
+    }
+
+    /// Process requests and network traffic
+    ///
+    /// Returns the negotiated session once the host has started the game and
+    /// every peer is connected. The driver is back to idle afterwards.
+    pub fn poll(&mut self, now: Instant) -> Option<SessionStart> {
+        for request in self.channel.take_requests() {
+            self.handle_request(request, now);
+        }
+        self.receive();
+
+        let session_start = self.poll_session();
+        if session_start.is_none() {
+            self.refresh_registration(now);
+        }
+        session_start
+    }
+
+    fn handle_request(&mut self, request: LobbyRequest, now: Instant) {
+        match request {
+            LobbyRequest::Create { name, max_players } => {
+                if self.session.is_some() {
+                    tracing::warn!("lobby_create: already in a lobby");
+                    return;
+                }
+                let mut config = self.config.clone();
+                config.netplay.max_players = max_players.clamp(2, config.netplay.max_players);
+                match NchsSession::host(DEFAULT_NCHS_PORT, config) {
+                    Ok(session) => {
+                        tracing::info!("Hosting lobby '{}'", name);
+                        self.session = Some(session);
+                        self.lobby_name = name;
+                        self.lobby_id = 0;
+                        self.last_register = None;
+                        self.channel.set_members(true, 1);
+                        self.channel.set_state(lobby_state::HOSTING);
+                    }
+                    Err(e) => {
+                        tracing::warn!("lobby_create: failed to host: {}", e);
+                        self.channel.set_state(lobby_state::FAILED);
+                    }
+                }
+            }
+            LobbyRequest::Refresh => {
+                if self
+                    .last_refresh
+                    .is_some_and(|last| now.saturating_duration_since(last) < REFRESH_INTERVAL)
+                {
+                    return;
+                }
+                self.last_refresh = Some(now);
+                self.send(RendezvousMessage::List {
+                    rom_hash: self.config.netplay.rom_hash,
+                });
+            }
+            LobbyRequest::Join(id) => {
+                if self.session.is_some() {
+                    tracing::warn!("lobby_join: already in a lobby");
+                    return;
+                }
+                let Some(listing) = self
+                    .channel
+                    .listings()
+                    .into_iter()
+                    .find(|listing| listing.id == id)
+                else {
+                    tracing::warn!("lobby_join: unknown lobby {}", id);
+                    self.channel.set_state(lobby_state::FAILED);
+                    return;
+                };
+                match NchsSession::join(&listing.addr.to_string(), self.config.clone()) {
+                    Ok(session) => {
+                        tracing::info!("Joining lobby '{}' at {}", listing.name, listing.addr);
+                        self.session = Some(session);
+                        self.ready_sent = false;
+                        self.channel.set_members(false, 0);
+                        self.channel.set_state(lobby_state::JOINING);
+                    }
+                    Err(e) => {
+                        tracing::warn!("lobby_join: failed to connect: {}", e);
+                        self.channel.set_state(lobby_state::FAILED);
+                    }
+                }
+            }
+            LobbyRequest::Start => {
+                let Some(session) = self.session.as_mut() else {
+                    return;
+                };
+                if session.role() != NchsRole::Host
+                    || session.player_count() < 2
+                    || !session.all_ready()
+                {
+                    tracing::warn!("lobby_start: lobby is not ready to start");
+                    return;
+                }
+                if let Err(e) = session.start() {
+                    tracing::warn!("lobby_start: {}", e);
+                }
+            }
+            LobbyRequest::Leave => {
+                self.close();
+                self.channel.set_state(lobby_state::IDLE);
+            }
+        }
+    }
+
+    /// Handle replies from the rendezvous server
+    fn receive(&mut self) {
+        let mut buf = [0u8; 2048];
+        loop {
+            let (len, from) = match self.socket.recv_from(&mut buf) {
+                Ok(received) => received,
+                Err(e) if e.kind() == io::ErrorKind::WouldBlock => break,
+                Err(e) => {
+                    tracing::debug!("Rendezvous receive failed: {}", e);
+                    break;
+                }
+            };
+            if from != self.server {
+                continue;
+            }
+            match RendezvousMessage::decode(&buf[..len]) {
+                Some(RendezvousMessage::Registered { lobby_id }) if self.is_hosting() => {
+                    self.lobby_id = lobby_id;
+                }
+                Some(RendezvousMessage::Listing(listings)) => {
+                    self.channel.set_listings(listings);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Poll the NCHS session and publish its state
+    fn poll_session(&mut self) -> Option<SessionStart> {
+        let session = self.session.as_mut()?;
+
+        for _ in 0..MAX_EVENTS_PER_POLL {
+            match session.poll() {
+                NchsEvent::Pending => break,
+                NchsEvent::Ready(mut session_start) => {
+                    session_start.local_player_handle = session.local_handle().unwrap_or(0);
+                    session_start.relayed_peers = session.relayed_peers();
+                    tracing::info!("Lobby ready, starting netplay session");
+                    self.close();
+                    self.channel.set_state(lobby_state::IDLE);
+                    return Some(session_start);
+                }
+                NchsEvent::Error(e) => {
+                    tracing::warn!("Lobby connection failed: {}", e);
+                    self.close();
+                    self.channel.set_state(lobby_state::FAILED);
+                    return None;
+                }
+                _ => {}
+            }
+        }
+
+        // Guests are ready as soon as they're in; the host decides when to start
+        let is_host = session.role() == NchsRole::Host;
+        if !is_host && !self.ready_sent && session.state() == NchsState::Lobby {
+            match session.set_ready(true) {
+                Ok(()) => self.ready_sent = true,
+                Err(e) => tracing::warn!("Lobby: failed to mark ready: {}", e),
+            }
+        }
+
+        let players = session.player_count();
+        let state = match session.state() {
+            NchsState::Lobby if players >= 2 => lobby_state::IN_LOBBY,
+            NchsState::Idle | NchsState::Connecting | NchsState::Lobby if is_host => {
+                lobby_state::HOSTING
+            }
+            NchsState::Idle | NchsState::Connecting | NchsState::Lobby => lobby_state::JOINING,
+            NchsState::Punching | NchsState::Ready => lobby_state::STARTING,
+            NchsState::Failed => lobby_state::FAILED,
+        };
+        self.channel.set_members(is_host, players);
+        self.channel.set_state(state);
+        None
+    }
+
+    /// Re-advertise the hosted lobby before it expires
+    fn refresh_registration(&mut self, now: Instant) {
+        let Some(session) = self.session.as_ref() else {
+            return;
+        };
+        if session.role() != NchsRole::Host
+            || session.state() != NchsState::Lobby
+            || self
+                .last_register
+                .is_some_and(|last| now.saturating_duration_since(last) < REGISTER_INTERVAL)
+        {
+            return;
+        }
+        self.last_register = Some(now);
+        let message = RendezvousMessage::Register {
+            lobby_id: self.lobby_id,
+            rom_hash: self.config.netplay.rom_hash,
+            port: session.port(),
+            players: session.player_count(),
+            max_players: session
+                .lobby()
+                .map_or(self.config.netplay.max_players, |lobby| lobby.max_players),
+            name: self.lobby_name.clone(),
+        };
+        self.send(message);
+    }
+
+    fn is_hosting(&self) -> bool {
+        self.session
+            .as_ref()
+            .is_some_and(|session| session.role() == NchsRole::Host)
+    }
+
+    /// Drop the current session and withdraw its advertisement
+    fn close(&mut self) {
+        if self.lobby_id != 0 {
+            self.send(RendezvousMessage::Unregister {
+                lobby_id: self.lobby_id,
+            });
+        }
+        self.session = None;
+        self.ready_sent = false;
+        self.lobby_id = 0;
+        self.last_register = None;
+    }
+
+    fn send(&self, message: RendezvousMessage) {
+        if let Err(e) = self.socket.send_to(&message.encode(), self.server) {
+            tracing::debug!("Rendezvous send to {} failed: {}", self.server, e);
+        }
+    }
+}
+
+impl Drop for LobbyDriver {
+    fn drop(&mut self) {
+        self.close();
+    }
+}
@@ -0,0 +1,31 @@
+//! In-game lobbies (`lobby_create` / `lobby_list` / `lobby_join`)
+//!
+//! - [`channel`] - [`LobbyChannel`], the state shared by the lobby FFI and the runtime
+//! - [`driver`] - [`LobbyDriver`], which runs NCHS and talks to the rendezvous server
+//!
+//! The game only queues requests and reads back state; the runtime polls the
+//! driver each frame. Once NCHS reports the session ready, the runtime restarts
+//! the game as a netplay session. Lobby state is local to each player and never
+//! rolled back.
+
+pub mod channel;
+pub mod driver;
+
+pub use channel::{LobbyChannel, LobbyRequest};
+pub use driver::LobbyDriver;
+
+/// Lobby connection states reported by `lobby_state()` and `on_lobby_state()`
+pub mod lobby_state {
+    /// Not hosting or in a lobby
+    pub const IDLE: u32 = 0;
+    /// Hosting a lobby, waiting for other players
+    pub const HOSTING: u32 = 1;
+    /// Connecting to a host
+    pub const JOINING: u32 = 2;
+    /// In a lobby with at least one other player
+    pub const IN_LOBBY: u32 = 3;
+    /// The host started the game; connecting peers
+    pub const STARTING: u32 = 4;
+    /// Creating or joining the lobby failed, or the connection was lost
+    pub const FAILED: u32 = 5;
+}
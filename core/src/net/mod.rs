@@ -3,6 +3,11 @@
 //! This module contains networking protocols used by Nethercore consoles:
 //!
 //! - [`nchs`] - Nethercore Handshake Protocol (pre-GGRS session setup)
+//! - [`nat`] - NAT traversal (STUN candidate gathering, relay fallback)
+//! - [`voice`] - Opt-in voice chat carried alongside GGRS input packets
+//! - [`chat`] - Text chat and quick-chat pings carried alongside GGRS input packets
+//! - [`rendezvous`] - Lobby registry server and its wire format
+//! - [`lobby`] - In-game lobbies (hosting, browsing and joining before netplay)
 //!
 //! # Architecture
 //!
@@ -22,7 +27,24 @@
 //!                    └─────────────────────━E
 //! ```
 
+pub mod chat;
+pub mod lobby;
+pub mod nat;
 pub mod nchs;
+pub mod rendezvous;
+pub mod voice;
+
+// Re-export commonly used NAT traversal types
+pub use nat::{Candidate, CandidateKind, ConnectionPath, NatConfig};
+
+// Re-export commonly used chat types
+pub use chat::{ChatChannel, ChatMessage, Ping};
+
+// Re-export commonly used lobby types
+pub use lobby::{LobbyChannel, LobbyDriver, LobbyRequest};
+
+// Re-export commonly used voice types
+pub use voice::{PositionalVoice, VoiceChannel};
 
 // Re-export commonly used NCHS types
 pub use nchs::{
@@ -0,0 +1,157 @@
+//! ICE-style candidate gathering
+//!
+//! A candidate is an address at which a peer may be reachable. Candidates
+//! are gathered in priority order: local interface addresses first, then the
+//! public address discovered via STUN, then the relay.
+
+use std::net::{IpAddr, SocketAddr, UdpSocket};
+
+use super::config::NatConfig;
+use super::stun::query_binding;
+use crate::rollback::LocalSocket;
+
+/// Candidate type (mirrors ICE candidate types)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CandidateKind {
+    /// Address of a local network interface
+    Host,
+    /// Public address as seen by a STUN server
+    ServerReflexive,
+    /// Address of the relay server
+    Relayed,
+}
+
+impl CandidateKind {
+    /// Type preference (RFC 8445 section 5.1.2.2 recommended values)
+    pub fn type_preference(self) -> u32 {
+        match self {
+            Self::Host => 126,
+            Self::ServerReflexive => 100,
+            Self::Relayed => 0,
+        }
+    }
+}
+
+/// A transport address a peer may be reachable at
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Candidate {
+    /// How this candidate was obtained
+    pub kind: CandidateKind,
+    /// Transport address
+    pub addr: SocketAddr,
+}
+
+impl Candidate {
+    /// Create a new candidate
+    pub fn new(kind: CandidateKind, addr: SocketAddr) -> Self {
+        Self { kind, addr }
+    }
+
+    /// Candidate priority (higher is preferred)
+    pub fn priority(&self) -> u32 {
+        self.kind.type_preference() << 24
+    }
+}
+
+/// Gather candidates for `socket`
+///
+/// Blocks for up to `config.stun_timeout` per configured STUN server. The
+/// returned list is sorted by priority and contains no duplicate addresses.
+pub fn gather_candidates(socket: &UdpSocket, config: &NatConfig) -> Vec<Candidate> {
+    let mut candidates = Vec::new();
+
+    let local = match socket.local_addr() {
+        Ok(addr) => addr,
+        Err(e) => {
+            tracing::warn!(error = %e, "Failed to get socket address for candidates");
+            return candidates;
+        }
+    };
+
+    // Host candidates: a wildcard bind is reachable on every interface
+    if local.ip().is_unspecified() {
+        for ip in LocalSocket::get_local_ips() {
+            if let Ok(ip) = ip.parse::<IpAddr>() {
+                candidates.push(Candidate::new(
+                    CandidateKind::Host,
+                    SocketAddr::new(ip, local.port()),
+                ));
+            }
+        }
+    } else {
+        candidates.push(Candidate::new(CandidateKind::Host, local));
+    }
+
+    // Server-reflexive candidates: first STUN server that answers wins
+    for server in config.resolve_stun_servers() {
+        match query_binding(socket, server, config.stun_timeout) {
+            Ok(addr) => {
+                tracing::debug!(port = addr.port(), "Gathered server-reflexive candidate");
+                candidates.push(Candidate::new(CandidateKind::ServerReflexive, addr));
+                break;
+            }
+            Err(e) => {
+                tracing::debug!(error = %e, "STUN query failed");
+            }
+        }
+    }
+
+    // Relayed candidate
+    if let Some(relay) = config.resolve_relay() {
+        candidates.push(Candidate::new(CandidateKind::Relayed, relay));
+    }
+
+    sort_and_dedup(&mut candidates);
+    candidates
+}
+
+/// Sort candidates by priority and drop duplicate addresses
+///
+/// When the STUN address equals a host address (no NAT), the host candidate wins.
+fn sort_and_dedup(candidates: &mut Vec<Candidate>) {
+    candidates.sort_by_key(|c| std::cmp::Reverse(c.priority()));
+    let mut seen = std::collections::HashSet::new();
+    candidates.retain(|c| seen.insert(c.addr));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_priority_order() {
+        let host = Candidate::new(CandidateKind::Host, "10.0.0.2:7770".parse().unwrap());
+        let srflx = Candidate::new(
+            CandidateKind::ServerReflexive,
+            "203.0.113.5:40000".parse().unwrap(),
+        );
+        let relay = Candidate::new(CandidateKind::Relayed, "198.51.100.1:3479".parse().unwrap());
+
+        assert!(host.priority() > srflx.priority());
+        assert!(srflx.priority() > relay.priority());
+    }
+
+    #[test]
+    fn test_sort_and_dedup_prefers_host() {
+        let addr: SocketAddr = "203.0.113.5:7770".parse().unwrap();
+        let mut candidates = vec![
+            Candidate::new(CandidateKind::ServerReflexive, addr),
+            Candidate::new(CandidateKind::Host, addr),
+        ];
+        sort_and_dedup(&mut candidates);
+        assert_eq!(candidates, vec![Candidate::new(CandidateKind::Host, addr)]);
+    }
+
+    #[test]
+    fn test_gather_without_stun_returns_host_and_relay() {
+        let socket = UdpSocket::bind("127.0.0.1:0").unwrap();
+        socket.set_nonblocking(true).unwrap();
+        let config = NatConfig::disabled().with_relay("127.0.0.1:3479");
+
+        let candidates = gather_candidates(&socket, &config);
+        assert_eq!(candidates.len(), 2);
+        assert_eq!(candidates[0].kind, CandidateKind::Host);
+        assert_eq!(candidates[0].addr, socket.local_addr().unwrap());
+        assert_eq!(candidates[1].kind, CandidateKind::Relayed);
+    }
+}
@@ -0,0 +1,107 @@
+//! NAT traversal configuration
+
+use std::net::{SocketAddr, ToSocketAddrs};
+use std::time::Duration;
+
+/// Public STUN servers used when no servers are configured explicitly
+pub const DEFAULT_STUN_SERVERS: &[&str] = &["stun.l.google.com:19302", "stun.cloudflare.com:3478"];
+
+/// NAT traversal configuration
+///
+/// This is local configuration only; it is never sent over the wire. The
+/// host's `relay_server` is distributed to guests through
+/// [`crate::net::nchs::NetworkConfig::relay_server`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct NatConfig {
+    /// STUN servers ("host:port") queried for server-reflexive candidates
+    pub stun_servers: Vec<String>,
+    /// Optional relay server ("host:port") used when hole punching fails
+    pub relay_server: Option<String>,
+    /// How long to wait for each STUN server before giving up
+    pub stun_timeout: Duration,
+}
+
+impl Default for NatConfig {
+    fn default() -> Self {
+        Self {
+            stun_servers: DEFAULT_STUN_SERVERS.iter().map(|s| s.to_string()).collect(),
+            relay_server: None,
+            stun_timeout: Duration::from_millis(500),
+        }
+    }
+}
+
+impl NatConfig {
+    /// Configuration with STUN and relay disabled (LAN / localhost play)
+    pub fn disabled() -> Self {
+        Self {
+            stun_servers: Vec::new(),
+            relay_server: None,
+            stun_timeout: Duration::ZERO,
+        }
+    }
+
+    /// Set the relay server used as a fallback
+    pub fn with_relay(mut self, relay_server: impl Into<String>) -> Self {
+        self.relay_server = Some(relay_server.into());
+        self
+    }
+
+    /// Resolve the configured STUN servers, skipping entries that fail to resolve
+    pub fn resolve_stun_servers(&self) -> Vec<SocketAddr> {
+        self.stun_servers
+            .iter()
+            .filter_map(|server| resolve_addr(server))
+            .collect()
+    }
+
+    /// Resolve the relay server address, if one is configured
+    pub fn resolve_relay(&self) -> Option<SocketAddr> {
+        self.relay_server.as_deref().and_then(resolve_addr)
+    }
+}
+
+/// Resolve a "host:port" string to the first IPv4 address (falling back to any address)
+pub(crate) fn resolve_addr(addr: &str) -> Option<SocketAddr> {
+    let addrs: Vec<SocketAddr> = match addr.to_socket_addrs() {
+        Ok(addrs) => addrs.collect(),
+        Err(e) => {
+            tracing::warn!(address = %addr, error = %e, "Failed to resolve address");
+            return None;
+        }
+    };
+
+    addrs
+        .iter()
+        .find(|a| a.is_ipv4())
+        .or_else(|| addrs.first())
+        .copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_has_builtin_stun_servers() {
+        let config = NatConfig::default();
+        assert_eq!(config.stun_servers.len(), DEFAULT_STUN_SERVERS.len());
+        assert!(config.relay_server.is_none());
+    }
+
+    #[test]
+    fn test_disabled_resolves_nothing() {
+        let config = NatConfig::disabled();
+        assert!(config.resolve_stun_servers().is_empty());
+        assert!(config.resolve_relay().is_none());
+    }
+
+    #[test]
+    fn test_resolve_literal_relay() {
+        let config = NatConfig::disabled().with_relay("127.0.0.1:3479");
+        assert_eq!(
+            config.resolve_relay(),
+            Some("127.0.0.1:3479".parse().unwrap())
+        );
+    }
+}
@@ -0,0 +1,33 @@
+//! NAT traversal helpers
+//!
+//! ICE-style connectivity support for peers that cannot reach each other
+//! directly:
+//!
+//! - [`stun`] - Minimal STUN (RFC 5389) binding client for discovering the
+//!   public (server-reflexive) address of a UDP socket
+//! - [`candidate`] - Candidate gathering (host, server-reflexive, relayed)
+//! - [`relay`] - Framing for the optional packet relay used when hole punching fails
+//!
+//! # Connection Flow
+//!
+//! ```text
+//! 1. Gather candidates        host addrs + STUN srflx addr (+ relay)
+//! 2. NCHS hole punch          PunchHello/PunchAck between guests
+//! 3. Punch timeout?           fall back to relay if one is configured
+//! 4. GGRS session             LocalSocket routes relayed peers via the relay
+//! ```
+//!
+//! The path each peer ended up on is reported as a [`ConnectionPath`] in
+//! [`crate::rollback::PlayerNetworkStats`].
+
+pub mod candidate;
+pub mod config;
+pub mod path;
+pub mod relay;
+pub mod stun;
+
+pub use candidate::{Candidate, CandidateKind, gather_candidates};
+pub use config::{DEFAULT_STUN_SERVERS, NatConfig};
+pub use path::ConnectionPath;
+pub use relay::{RELAY_MAGIC, RelayPacket};
+pub use stun::{StunError, query_binding};
@@ -0,0 +1,47 @@
+//! Connection path reporting
+
+use super::candidate::CandidateKind;
+
+/// How traffic reaches a remote peer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ConnectionPath {
+    /// Direct connection (LAN, public address, or forwarded port)
+    #[default]
+    Direct,
+    /// Direct connection established through UDP hole punching
+    HolePunched,
+    /// Traffic is forwarded through the relay server
+    Relayed,
+}
+
+impl ConnectionPath {
+    /// Short label for UI display
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Direct => "Direct",
+            Self::HolePunched => "P2P",
+            Self::Relayed => "Relay",
+        }
+    }
+
+    /// Whether packets take an extra hop through the relay
+    pub fn is_relayed(self) -> bool {
+        self == Self::Relayed
+    }
+}
+
+impl From<CandidateKind> for ConnectionPath {
+    fn from(kind: CandidateKind) -> Self {
+        match kind {
+            CandidateKind::Host => Self::Direct,
+            CandidateKind::ServerReflexive => Self::HolePunched,
+            CandidateKind::Relayed => Self::Relayed,
+        }
+    }
+}
+
+impl std::fmt::Display for ConnectionPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.label())
+    }
+}
@@ -0,0 +1,159 @@
+//! Relay packet framing
+//!
+//! The relay is a TURN-like UDP forwarder used when peers can't hole punch.
+//! Clients register with an `Allocate` packet (resent periodically as a NAT
+//! keepalive), then wrap each datagram in a `Data` packet:
+//!
+//! - client -> relay: `peer` is the destination address
+//! - relay -> client: `peer` is the original sender address
+//!
+//! Wire format:
+//!
+//! ```text
+//! magic "NCRY" (4) | kind (1) | [family (1) | ip (4/16) | port (2) | payload...]
+//! ```
+
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+
+/// Relay packet magic bytes
+pub const RELAY_MAGIC: [u8; 4] = *b"NCRY";
+
+const KIND_ALLOCATE: u8 = 0;
+const KIND_DATA: u8 = 1;
+
+const FAMILY_IPV4: u8 = 4;
+const FAMILY_IPV6: u8 = 6;
+
+/// A packet exchanged with the relay server
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RelayPacket<'a> {
+    /// Register with the relay / keep the NAT binding alive
+    Allocate,
+    /// Forwarded datagram
+    Data {
+        /// Destination (outgoing) or source (incoming) peer address
+        peer: SocketAddr,
+        /// Original datagram
+        payload: &'a [u8],
+    },
+}
+
+impl<'a> RelayPacket<'a> {
+    /// Encode into a datagram
+    pub fn encode(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(32);
+        buf.extend_from_slice(&RELAY_MAGIC);
+
+        match self {
+            Self::Allocate => buf.push(KIND_ALLOCATE),
+            Self::Data { peer, payload } => {
+                buf.push(KIND_DATA);
+                match peer.ip() {
+                    IpAddr::V4(ip) => {
+                        buf.push(FAMILY_IPV4);
+                        buf.extend_from_slice(&ip.octets());
+                    }
+                    IpAddr::V6(ip) => {
+                        buf.push(FAMILY_IPV6);
+                        buf.extend_from_slice(&ip.octets());
+                    }
+                }
+                buf.extend_from_slice(&peer.port().to_be_bytes());
+                buf.extend_from_slice(payload);
+            }
+        }
+
+        buf
+    }
+
+    /// Decode a datagram, returning `None` if it isn't a valid relay packet
+    pub fn decode(data: &'a [u8]) -> Option<Self> {
+        let rest = data.strip_prefix(&RELAY_MAGIC)?;
+        let (&kind, rest) = rest.split_first()?;
+
+        match kind {
+            KIND_ALLOCATE => Some(Self::Allocate),
+            KIND_DATA => {
+                let (&family, rest) = rest.split_first()?;
+                let (ip, rest) = match family {
+                    FAMILY_IPV4 => {
+                        let (octets, rest) = rest.split_first_chunk::<4>()?;
+                        (IpAddr::V4(Ipv4Addr::from(*octets)), rest)
+                    }
+                    FAMILY_IPV6 => {
+                        let (octets, rest) = rest.split_first_chunk::<16>()?;
+                        (IpAddr::V6(Ipv6Addr::from(*octets)), rest)
+                    }
+                    _ => return None,
+                };
+                let (port, payload) = rest.split_first_chunk::<2>()?;
+
+                Some(Self::Data {
+                    peer: SocketAddr::new(ip, u16::from_be_bytes(*port)),
+                    payload,
+                })
+            }
+            _ => None,
+        }
+    }
+
+    /// Check whether a datagram starts with the relay magic
+    pub fn is_relay_packet(data: &[u8]) -> bool {
+        data.starts_with(&RELAY_MAGIC)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_allocate_roundtrip() {
+        let bytes = RelayPacket::Allocate.encode();
+        assert_eq!(bytes, b"NCRY\0");
+        assert_eq!(RelayPacket::decode(&bytes), Some(RelayPacket::Allocate));
+    }
+
+    #[test]
+    fn test_data_roundtrip_ipv4() {
+        let peer: SocketAddr = "203.0.113.7:7771".parse().unwrap();
+        let packet = RelayPacket::Data {
+            peer,
+            payload: b"ggrs",
+        };
+        let bytes = packet.encode();
+        assert!(RelayPacket::is_relay_packet(&bytes));
+        assert_eq!(RelayPacket::decode(&bytes), Some(packet));
+    }
+
+    #[test]
+    fn test_data_roundtrip_ipv6() {
+        let peer: SocketAddr = "[2001:db8::7]:7771".parse().unwrap();
+        let packet = RelayPacket::Data {
+            peer,
+            payload: &[1, 2, 3],
+        };
+        assert_eq!(RelayPacket::decode(&packet.encode()), Some(packet));
+    }
+
+    #[test]
+    fn test_empty_payload() {
+        let peer: SocketAddr = "10.0.0.1:1".parse().unwrap();
+        let packet = RelayPacket::Data { peer, payload: &[] };
+        assert_eq!(RelayPacket::decode(&packet.encode()), Some(packet));
+    }
+
+    #[test]
+    fn test_decode_rejects_garbage() {
+        assert_eq!(RelayPacket::decode(b""), None);
+        assert_eq!(RelayPacket::decode(b"NCHS\x01"), None);
+        assert_eq!(RelayPacket::decode(b"NCRY\x09"), None);
+        // Truncated address
+        assert_eq!(RelayPacket::decode(b"NCRY\x01\x04\x0a\x00"), None);
+        // Unknown family
+        assert_eq!(
+            RelayPacket::decode(b"NCRY\x01\x05\x00\x00\x00\x00\x00\x00"),
+            None
+        );
+    }
+}
@@ -0,0 +1,376 @@
+//! Minimal STUN binding client (RFC 5389)
+//!
+//! Only the Binding request/response is implemented, which is all that's
+//! needed to learn the server-reflexive address of a UDP socket.
+
+use std::io;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, UdpSocket};
+use std::time::{Duration, Instant};
+
+/// STUN magic cookie (RFC 5389 section 6)
+pub const STUN_MAGIC_COOKIE: u32 = 0x2112_A442;
+
+/// STUN message header size
+pub const STUN_HEADER_SIZE: usize = 20;
+
+const BINDING_REQUEST: u16 = 0x0001;
+const BINDING_SUCCESS: u16 = 0x0101;
+
+const ATTR_MAPPED_ADDRESS: u16 = 0x0001;
+const ATTR_XOR_MAPPED_ADDRESS: u16 = 0x0020;
+
+const FAMILY_IPV4: u8 = 0x01;
+const FAMILY_IPV6: u8 = 0x02;
+
+/// Interval between binding request retransmissions
+const RETRANSMIT_INTERVAL: Duration = Duration::from_millis(100);
+
+/// STUN errors
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StunError {
+    /// Packet is not a STUN message
+    NotStun,
+    /// Transaction ID doesn't match our request
+    TransactionMismatch,
+    /// Response is not a Binding success response
+    UnexpectedType(u16),
+    /// Response is truncated or malformed
+    Malformed,
+    /// Response contained no mapped address
+    NoMappedAddress,
+    /// Server didn't answer in time
+    Timeout,
+    /// Socket error
+    Io(String),
+}
+
+impl std::fmt::Display for StunError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotStun => write!(f, "Not a STUN message"),
+            Self::TransactionMismatch => write!(f, "STUN transaction ID mismatch"),
+            Self::UnexpectedType(t) => write!(f, "Unexpected STUN message type 0x{:04x}", t),
+            Self::Malformed => write!(f, "Malformed STUN message"),
+            Self::NoMappedAddress => write!(f, "STUN response has no mapped address"),
+            Self::Timeout => write!(f, "STUN request timed out"),
+            Self::Io(e) => write!(f, "STUN socket error: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for StunError {}
+
+/// Encode a Binding request with the given transaction ID
+pub fn encode_binding_request(transaction_id: [u8; 12]) -> [u8; STUN_HEADER_SIZE] {
+    let mut buf = [0u8; STUN_HEADER_SIZE];
+    buf[0..2].copy_from_slice(&BINDING_REQUEST.to_be_bytes());
+    // Message length (no attributes) stays 0
+    buf[4..8].copy_from_slice(&STUN_MAGIC_COOKIE.to_be_bytes());
+    buf[8..20].copy_from_slice(&transaction_id);
+    buf
+}
+
+/// Check whether a datagram looks like a STUN message
+///
+/// Used to separate STUN responses from game traffic on a shared socket.
+pub fn is_stun_message(data: &[u8]) -> bool {
+    data.len() >= STUN_HEADER_SIZE
+        && data[0] & 0xC0 == 0
+        && data[4..8] == STUN_MAGIC_COOKIE.to_be_bytes()
+}
+
+/// Decode a Binding success response and return the mapped address
+///
+/// Prefers XOR-MAPPED-ADDRESS, falling back to the legacy MAPPED-ADDRESS.
+pub fn decode_binding_response(
+    data: &[u8],
+    transaction_id: &[u8; 12],
+) -> Result<SocketAddr, StunError> {
+    if !is_stun_message(data) {
+        return Err(StunError::NotStun);
+    }
+
+    let msg_type = u16::from_be_bytes([data[0], data[1]]);
+    let msg_len = u16::from_be_bytes([data[2], data[3]]) as usize;
+
+    if &data[8..20] != transaction_id {
+        return Err(StunError::TransactionMismatch);
+    }
+    if msg_type != BINDING_SUCCESS {
+        return Err(StunError::UnexpectedType(msg_type));
+    }
+
+    let body = data
+        .get(STUN_HEADER_SIZE..STUN_HEADER_SIZE + msg_len)
+        .ok_or(StunError::Malformed)?;
+
+    let mut mapped = None;
+    let mut offset = 0;
+    while offset + 4 <= body.len() {
+        let attr_type = u16::from_be_bytes([body[offset], body[offset + 1]]);
+        let attr_len = u16::from_be_bytes([body[offset + 2], body[offset + 3]]) as usize;
+        let value = body
+            .get(offset + 4..offset + 4 + attr_len)
+            .ok_or(StunError::Malformed)?;
+
+        match attr_type {
+            ATTR_XOR_MAPPED_ADDRESS => {
+                return decode_address(value, Some(transaction_id));
+            }
+            ATTR_MAPPED_ADDRESS => {
+                mapped = Some(decode_address(value, None)?);
+            }
+            _ => {}
+        }
+
+        // Attributes are padded to 4-byte boundaries
+        offset += 4 + attr_len.next_multiple_of(4);
+    }
+
+    mapped.ok_or(StunError::NoMappedAddress)
+}
+
+/// Decode a (XOR-)MAPPED-ADDRESS attribute value
+fn decode_address(value: &[u8], xor_txid: Option<&[u8; 12]>) -> Result<SocketAddr, StunError> {
+    if value.len() < 4 {
+        return Err(StunError::Malformed);
+    }
+
+    let family = value[1];
+    let mut port = u16::from_be_bytes([value[2], value[3]]);
+    let cookie = STUN_MAGIC_COOKIE.to_be_bytes();
+
+    if xor_txid.is_some() {
+        port ^= (STUN_MAGIC_COOKIE >> 16) as u16;
+    }
+
+    let ip = match family {
+        FAMILY_IPV4 => {
+            let mut octets: [u8; 4] = value
+                .get(4..8)
+                .and_then(|b| b.try_into().ok())
+                .ok_or(StunError::Malformed)?;
+            if xor_txid.is_some() {
+                for (octet, key) in octets.iter_mut().zip(cookie) {
+                    *octet ^= key;
+                }
+            }
+            IpAddr::V4(Ipv4Addr::from(octets))
+        }
+        FAMILY_IPV6 => {
+            let mut octets: [u8; 16] = value
+                .get(4..20)
+                .and_then(|b| b.try_into().ok())
+                .ok_or(StunError::Malformed)?;
+            if let Some(txid) = xor_txid {
+                let key = cookie.iter().chain(txid.iter());
+                for (octet, key) in octets.iter_mut().zip(key) {
+                    *octet ^= key;
+                }
+            }
+            IpAddr::V6(Ipv6Addr::from(octets))
+        }
+        _ => return Err(StunError::Malformed),
+    };
+
+    Ok(SocketAddr::new(ip, port))
+}
+
+/// Query a STUN server for the server-reflexive address of `socket`
+///
+/// The socket must be non-blocking or have a read timeout set. Datagrams from
+/// other sources received while waiting are discarded, so call this before
+/// game or handshake traffic starts flowing on the socket.
+pub fn query_binding(
+    socket: &UdpSocket,
+    server: SocketAddr,
+    timeout: Duration,
+) -> Result<SocketAddr, StunError> {
+    let transaction_id: [u8; 12] = rand::random();
+    let request = encode_binding_request(transaction_id);
+
+    let start = Instant::now();
+    let mut last_send: Option<Instant> = None;
+    let mut buf = [0u8; 512];
+
+    while start.elapsed() < timeout {
+        if last_send.is_none_or(|t| t.elapsed() >= RETRANSMIT_INTERVAL) {
+            socket
+                .send_to(&request, server)
+                .map_err(|e| StunError::Io(e.to_string()))?;
+            last_send = Some(Instant::now());
+        }
+
+        match socket.recv_from(&mut buf) {
+            Ok((len, from)) if from == server => {
+                match decode_binding_response(&buf[..len], &transaction_id) {
+                    Ok(addr) => return Ok(addr),
+                    Err(StunError::TransactionMismatch) => continue,
+                    Err(e) => return Err(e),
+                }
+            }
+            Ok(_) => {
+                // Not from the STUN server; drop it
+            }
+            Err(e)
+                if e.kind() == io::ErrorKind::WouldBlock || e.kind() == io::ErrorKind::TimedOut =>
+            {
+                std::thread::sleep(Duration::from_millis(5));
+            }
+            Err(e) => return Err(StunError::Io(e.to_string())),
+        }
+    }
+
+    Err(StunError::Timeout)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TXID: [u8; 12] = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
+
+    fn response(attrs: &[(u16, Vec<u8>)]) -> Vec<u8> {
+        let mut body = Vec::new();
+        for (attr_type, value) in attrs {
+            body.extend_from_slice(&attr_type.to_be_bytes());
+            body.extend_from_slice(&(value.len() as u16).to_be_bytes());
+            body.extend_from_slice(value);
+            body.resize(body.len().next_multiple_of(4), 0);
+        }
+
+        let mut msg = Vec::new();
+        msg.extend_from_slice(&BINDING_SUCCESS.to_be_bytes());
+        msg.extend_from_slice(&(body.len() as u16).to_be_bytes());
+        msg.extend_from_slice(&STUN_MAGIC_COOKIE.to_be_bytes());
+        msg.extend_from_slice(&TXID);
+        msg.extend_from_slice(&body);
+        msg
+    }
+
+    fn xor_v4(ip: [u8; 4], port: u16) -> Vec<u8> {
+        let cookie = STUN_MAGIC_COOKIE.to_be_bytes();
+        let port = port ^ (STUN_MAGIC_COOKIE >> 16) as u16;
+        let mut value = vec![0, FAMILY_IPV4];
+        value.extend_from_slice(&port.to_be_bytes());
+        for (octet, key) in ip.iter().zip(cookie) {
+            value.push(octet ^ key);
+        }
+        value
+    }
+
+    #[test]
+    fn test_encode_binding_request() {
+        let req = encode_binding_request(TXID);
+        assert_eq!(&req[0..2], &[0x00, 0x01]);
+        assert_eq!(&req[2..4], &[0x00, 0x00]);
+        assert_eq!(&req[4..8], &STUN_MAGIC_COOKIE.to_be_bytes());
+        assert_eq!(&req[8..20], &TXID);
+        assert!(is_stun_message(&req));
+    }
+
+    #[test]
+    fn test_decode_xor_mapped_ipv4() {
+        let msg = response(&[(ATTR_XOR_MAPPED_ADDRESS, xor_v4([203, 0, 113, 7], 54321))]);
+        let addr = decode_binding_response(&msg, &TXID).unwrap();
+        assert_eq!(addr, "203.0.113.7:54321".parse().unwrap());
+    }
+
+    #[test]
+    fn test_decode_xor_mapped_ipv6() {
+        let ip: Ipv6Addr = "2001:db8::1".parse().unwrap();
+        let port: u16 = 40000;
+        let key: Vec<u8> = STUN_MAGIC_COOKIE
+            .to_be_bytes()
+            .into_iter()
+            .chain(TXID)
+            .collect();
+
+        let mut value = vec![0, FAMILY_IPV6];
+        value.extend_from_slice(&(port ^ (STUN_MAGIC_COOKIE >> 16) as u16).to_be_bytes());
+        for (octet, k) in ip.octets().iter().zip(key) {
+            value.push(octet ^ k);
+        }
+
+        let msg = response(&[(ATTR_XOR_MAPPED_ADDRESS, value)]);
+        let addr = decode_binding_response(&msg, &TXID).unwrap();
+        assert_eq!(addr, SocketAddr::new(IpAddr::V6(ip), port));
+    }
+
+    #[test]
+    fn test_decode_legacy_mapped_address() {
+        let mut value = vec![0, FAMILY_IPV4];
+        value.extend_from_slice(&7777u16.to_be_bytes());
+        value.extend_from_slice(&[198, 51, 100, 2]);
+
+        let msg = response(&[(ATTR_MAPPED_ADDRESS, value)]);
+        let addr = decode_binding_response(&msg, &TXID).unwrap();
+        assert_eq!(addr, "198.51.100.2:7777".parse().unwrap());
+    }
+
+    #[test]
+    fn test_decode_prefers_xor_mapped() {
+        let mut legacy = vec![0, FAMILY_IPV4];
+        legacy.extend_from_slice(&1u16.to_be_bytes());
+        legacy.extend_from_slice(&[10, 0, 0, 1]);
+
+        let msg = response(&[
+            (ATTR_MAPPED_ADDRESS, legacy),
+            (ATTR_XOR_MAPPED_ADDRESS, xor_v4([203, 0, 113, 9], 9000)),
+        ]);
+        let addr = decode_binding_response(&msg, &TXID).unwrap();
+        assert_eq!(addr, "203.0.113.9:9000".parse().unwrap());
+    }
+
+    #[test]
+    fn test_decode_rejects_wrong_transaction() {
+        let msg = response(&[(ATTR_XOR_MAPPED_ADDRESS, xor_v4([1, 2, 3, 4], 1))]);
+        let other = [0u8; 12];
+        assert_eq!(
+            decode_binding_response(&msg, &other),
+            Err(StunError::TransactionMismatch)
+        );
+    }
+
+    #[test]
+    fn test_decode_rejects_non_stun() {
+        assert_eq!(
+            decode_binding_response(b"NCHS_HELLO", &TXID),
+            Err(StunError::NotStun)
+        );
+    }
+
+    #[test]
+    fn test_decode_missing_address() {
+        let msg = response(&[]);
+        assert_eq!(
+            decode_binding_response(&msg, &TXID),
+            Err(StunError::NoMappedAddress)
+        );
+    }
+
+    #[test]
+    fn test_decode_truncated_attribute() {
+        let mut msg = response(&[(ATTR_XOR_MAPPED_ADDRESS, xor_v4([1, 2, 3, 4], 1))]);
+        // Claim a longer attribute than is present
+        msg[STUN_HEADER_SIZE + 3] = 40;
+        assert_eq!(
+            decode_binding_response(&msg, &TXID),
+            Err(StunError::Malformed)
+        );
+    }
+
+    #[test]
+    fn test_query_binding_timeout() {
+        let socket = UdpSocket::bind("127.0.0.1:0").unwrap();
+        socket.set_nonblocking(true).unwrap();
+        // Nothing listens on this socket's peer, so the query must time out
+        let silent = UdpSocket::bind("127.0.0.1:0").unwrap();
+        let result = query_binding(
+            &socket,
+            silent.local_addr().unwrap(),
+            Duration::from_millis(50),
+        );
+        assert_eq!(result, Err(StunError::Timeout));
+    }
+}
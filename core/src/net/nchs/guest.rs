@@ -4,9 +4,9 @@
 //! - Connecting to host
 //! - Sending join requests
 //! - Waiting for session start
-//! - UDP hole punching with other guests
+//! - UDP hole punching with other guests (relay fallback if punching fails)
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::net::SocketAddr;
 use std::time::{Duration, Instant};
 
@@ -18,6 +18,7 @@ use super::messages::{
     SessionStart,
 };
 use super::socket::NchsSocket;
+use crate::net::nat::ConnectionPath;
 
 /// Guest state machine states
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -66,6 +67,8 @@ pub struct GuestStateMachine {
     punch_started_at: Option<Instant>,
     /// Nonce for punch messages
     punch_nonce: u64,
+    /// Connection path chosen for each peer (handle -> path)
+    peer_paths: HashMap<u8, ConnectionPath>,
 }
 
 /// Events emitted by the guest state machine
@@ -124,6 +127,7 @@ impl GuestStateMachine {
             join_sent_at: None,
             punch_started_at: None,
             punch_nonce: rand::random(),
+            peer_paths: HashMap::new(),
         };
 
         // Send initial join request
@@ -152,6 +156,11 @@ impl GuestStateMachine {
         self.session_start.as_ref()
     }
 
+    /// Get the connection path chosen for each peer (valid once Ready)
+    pub fn peer_paths(&self) -> &HashMap<u8, ConnectionPath> {
+        &self.peer_paths
+    }
+
     /// Check if we're ready
     pub fn is_ready(&self) -> bool {
         self.ready
@@ -216,6 +225,9 @@ impl GuestStateMachine {
         // If punching, check completion
         if self.state == GuestState::Punching {
             if self.is_punch_complete() {
+                for (handle, _) in &self.peers_to_punch {
+                    self.peer_paths.insert(*handle, ConnectionPath::HolePunched);
+                }
                 self.state = GuestState::Ready;
                 return GuestEvent::Ready;
             }
@@ -246,6 +258,11 @@ impl GuestStateMachine {
             && let Some(started_at) = self.punch_started_at
             && started_at.elapsed() > PUNCH_TIMEOUT
         {
+            if self.has_relay() {
+                self.fall_back_to_relay();
+                self.state = GuestState::Ready;
+                return Some(GuestEvent::Ready);
+            }
             self.state = GuestState::Failed;
             return Some(GuestEvent::Error(NchsError::PunchFailed));
         }
@@ -332,6 +349,10 @@ impl GuestStateMachine {
         // Determine which peers we need to punch (other guests, not host)
         self.peers_to_punch.clear();
         self.punched_peers.clear();
+        self.peer_paths.clear();
+
+        // We reached the host directly to join the lobby
+        self.peer_paths.insert(0, ConnectionPath::Direct);
 
         if let Some(our_handle) = self.player_handle {
             for player in &start.players {
@@ -468,6 +489,31 @@ impl GuestStateMachine {
         true
     }
 
+    /// Check if the host configured a relay for this session
+    fn has_relay(&self) -> bool {
+        self.session_start
+            .as_ref()
+            .is_some_and(|s| s.network_config.relay_server.is_some())
+    }
+
+    /// Route peers that never answered the punch through the relay
+    fn fall_back_to_relay(&mut self) {
+        for (handle, _) in &self.peers_to_punch {
+            let path = if self.punched_peers.contains(handle) {
+                ConnectionPath::HolePunched
+            } else {
+                tracing::warn!(player = *handle, "Hole punch failed; falling back to relay");
+                ConnectionPath::Relayed
+            };
+            self.peer_paths.insert(*handle, path);
+        }
+    }
+
+    /// Get the NCHS socket
+    pub(crate) fn socket(&self) -> &NchsSocket {
+        &self.socket
+    }
+
     /// Get the socket for GGRS transition
     pub fn take_socket(self) -> NchsSocket {
         self.socket
@@ -2,6 +2,7 @@
 
 use std::time::Instant;
 
+use crate::daily::DailyChallenge;
 use crate::net::nchs::NchsError;
 use crate::net::nchs::messages::{NchsMessage, PlayerConnectionInfo, PlayerInfo, SessionStart};
 
@@ -25,9 +26,10 @@ impl HostStateMachine {
             ));
         }
 
-        // Generate random seed
+        // Generate random seed and fix the daily challenge by the host's clock
         let random_seed = rand::random::<u64>();
         self.random_seed = Some(random_seed);
+        let daily = *self.daily.get_or_insert_with(DailyChallenge::today);
 
         // Build player connection info
         let mut players = Vec::with_capacity(self.netplay.max_players as usize);
@@ -73,9 +75,11 @@ impl HostStateMachine {
 
         let session_start = SessionStart {
             local_player_handle: 0, // Will be set per-process by library when serializing
+            relayed_peers: Vec::new(),
             random_seed,
             start_frame: 0,
             tick_rate: self.netplay.tick_rate,
+            daily,
             players,
             player_count: self.player_count(),
             network_config: self.network_config.clone(),
@@ -109,11 +113,14 @@ impl HostStateMachine {
 
     /// Get session start info (only valid after start())
     pub fn session_start(&self) -> Option<SessionStart> {
-        self.random_seed.map(|seed| SessionStart {
+        let (seed, daily) = self.random_seed.zip(self.daily)?;
+        Some(SessionStart {
             local_player_handle: 0, // Will be set per-process by library when serializing
+            relayed_peers: Vec::new(),
             random_seed: seed,
             start_frame: 0,
             tick_rate: self.netplay.tick_rate,
+            daily,
             players: self.build_player_connection_info(),
             player_count: self.player_count(),
             network_config: self.network_config.clone(),
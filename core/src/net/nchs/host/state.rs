@@ -6,6 +6,7 @@ use std::time::{Duration, Instant};
 
 use nethercore_shared::netplay::NetplayMetadata;
 
+use crate::daily::DailyChallenge;
 use crate::net::nchs::NchsError;
 use crate::net::nchs::messages::{
     LobbyState, NetworkConfig, PlayerInfo, PlayerSlot, SaveConfig, SessionStart,
@@ -31,7 +32,7 @@ pub enum HostState {
 #[derive(Debug, Clone)]
 #[allow(dead_code)] // handle field used for debugging
 pub struct ConnectedPlayer {
-    /// Player handle (0-3)
+    /// Player handle (0-7)
     pub(super) handle: u8,
     /// Player info from JoinRequest
     pub(super) info: PlayerInfo,
@@ -86,6 +87,8 @@ pub struct HostStateMachine {
     pub(super) save_config: Option<SaveConfig>,
     /// Random seed for session (generated on start)
     pub(super) random_seed: Option<u64>,
+    /// Daily challenge for the session (today's on start unless set)
+    pub(super) daily: Option<DailyChallenge>,
     /// Session start sent time
     pub(super) start_time: Option<Instant>,
     /// Public address for sharing with peers (real IP, not 0.0.0.0)
@@ -138,11 +141,17 @@ impl HostStateMachine {
             network_config,
             save_config,
             random_seed: None,
+            daily: None,
             start_time: None,
             public_addr,
         })
     }
 
+    /// Set the daily challenge sent to guests (None picks today's on start)
+    pub fn set_daily(&mut self, daily: Option<DailyChallenge>) {
+        self.daily = daily;
+    }
+
     /// Get current state
     pub fn state(&self) -> HostState {
         self.state
@@ -153,6 +162,11 @@ impl HostStateMachine {
         self.socket.port()
     }
 
+    /// Get the NCHS socket
+    pub(crate) fn socket(&self) -> &NchsSocket {
+        &self.socket
+    }
+
     /// Get current lobby state
     pub fn lobby_state(&self) -> LobbyState {
         let mut slots = Vec::with_capacity(self.netplay.max_players as usize);
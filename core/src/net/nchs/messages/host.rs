@@ -3,6 +3,8 @@
 use bitcode::{Decode, Encode};
 use nethercore_shared::console::TickRate;
 
+use crate::daily::DailyChallenge;
+
 use super::shared::{NetworkConfig, PlayerInfo, SaveConfig};
 
 /// Accept a join request
@@ -10,7 +12,7 @@ use super::shared::{NetworkConfig, PlayerInfo, SaveConfig};
 /// Sent by host when a guest's JoinRequest passes validation.
 #[derive(Debug, Clone, PartialEq, Encode, Decode)]
 pub struct JoinAccept {
-    /// Assigned player handle (0-3)
+    /// Assigned player handle (0-7)
     pub player_handle: u8,
     /// Current lobby state
     pub lobby: LobbyState,
@@ -73,7 +75,7 @@ pub struct LobbyState {
 /// A player slot in the lobby
 #[derive(Debug, Clone, PartialEq, Encode, Decode)]
 pub struct PlayerSlot {
-    /// Player handle (0-3)
+    /// Player handle (0-7)
     pub handle: u8,
     /// Whether slot is occupied
     pub active: bool,
@@ -92,9 +94,12 @@ pub struct PlayerSlot {
 #[derive(Debug, Clone, PartialEq, Encode, Decode)]
 pub struct SessionStart {
     // === Local player info (set by library when serializing for player process) ===
-    /// Which player handle this process controls (0-3)
+    /// Which player handle this process controls (0-7)
     /// Set by library when creating session file, not sent over network.
     pub local_player_handle: u8,
+    /// Peer handles this process must reach through the relay (hole punch failed)
+    /// Set by library when creating session file, not sent over network.
+    pub relayed_peers: Vec<u8>,
 
     // === Determinism-critical fields ===
     /// Random seed for deterministic RNG (all players use same seed)
@@ -103,6 +108,8 @@ pub struct SessionStart {
     pub start_frame: u32,
     /// Tick rate for the session (must match host)
     pub tick_rate: TickRate,
+    /// Daily challenge picked by the host (`daily_seed()` on every peer)
+    pub daily: DailyChallenge,
 
     // === Network topology ===
     /// All player connection info (for peer mesh)
@@ -126,7 +133,7 @@ pub struct SessionStart {
 /// Player connection info for peer mesh setup
 #[derive(Debug, Clone, PartialEq, Encode, Decode)]
 pub struct PlayerConnectionInfo {
-    /// Player handle (0-3)
+    /// Player handle (0-7)
     pub handle: u8,
     /// Whether this player is active
     pub active: bool,
@@ -34,7 +34,7 @@ pub use shared::{MAX_PLAYER_NAME_LEN, NetworkConfig, PlayerInfo, SaveConfig, Sav
 pub const NCHS_MAGIC: [u8; 4] = *b"NCHS";
 
 /// Current NCHS protocol version
-pub const NCHS_VERSION: u16 = 1;
+pub const NCHS_VERSION: u16 = 2;
 
 /// Header size: magic (4) + version (2) + length (4)
 pub const NCHS_HEADER_SIZE: usize = 10;
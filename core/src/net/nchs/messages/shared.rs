@@ -37,6 +37,8 @@ pub struct NetworkConfig {
     pub disconnect_timeout_ms: u32,
     /// Whether to enable desync detection
     pub desync_detection: bool,
+    /// Relay server ("host:port") for peers that fail to hole punch
+    pub relay_server: Option<String>,
 }
 
 impl Default for NetworkConfig {
@@ -46,6 +48,7 @@ impl Default for NetworkConfig {
             max_rollback: 8,
             disconnect_timeout_ms: 5000,
             desync_detection: true,
+            relay_server: None,
         }
     }
 }
@@ -33,9 +33,11 @@ fn test_join_request_roundtrip() {
 fn test_session_start_roundtrip() {
     let msg = NchsMessage::SessionStart(SessionStart {
         local_player_handle: 0,
+        relayed_peers: vec![],
         random_seed: 0x123456789ABCDEF0,
         start_frame: 0,
         tick_rate: TickRate::Fixed60,
+        daily: crate::daily::DailyChallenge::for_day(20_742),
         players: vec![
             PlayerConnectionInfo {
                 handle: 0,
@@ -99,7 +101,7 @@ fn test_version_mismatch() {
     assert!(matches!(
         result,
         Err(NchsDecodeError::VersionMismatch {
-            expected: 1,
+            expected: 2,
             got: 99
         })
     ));
@@ -1,5 +1,8 @@
 //! NCHS session implementation
 
+use std::collections::HashMap;
+
+use crate::net::nat::{Candidate, ConnectionPath, gather_candidates};
 use crate::net::nchs::{
     LobbyState, NchsSocket, SessionStart,
     guest::{GuestEvent, GuestState, GuestStateMachine},
@@ -21,6 +24,8 @@ pub struct NchsSession {
     config: NchsConfig,
     /// Cached session start info (when ready)
     session_start: Option<SessionStart>,
+    /// Gathered NAT traversal candidates for the NCHS socket
+    candidates: Vec<Candidate>,
 }
 
 impl NchsSession {
@@ -28,18 +33,26 @@ impl NchsSession {
     ///
     /// The host listens for incoming connections and manages the lobby.
     pub fn host(port: u16, config: NchsConfig) -> Result<Self, NchsError> {
-        let host_machine = HostStateMachine::new(
+        // Distribute our relay to guests so they can fall back to it
+        let mut network_config = config.network_config.clone();
+        if network_config.relay_server.is_none() {
+            network_config.relay_server = config.nat.relay_server.clone();
+        }
+
+        let mut host_machine = HostStateMachine::new(
             port,
             config.netplay,
             config.player_info.clone(),
-            config.network_config.clone(),
+            network_config,
             config.save_config.clone(),
         )?;
+        host_machine.set_daily(config.daily);
 
         Ok(Self {
             inner: SessionInner::Host(host_machine),
             config,
             session_start: None,
+            candidates: Vec::new(),
         })
     }
 
@@ -54,6 +67,7 @@ impl NchsSession {
             inner: SessionInner::Guest(guest_machine),
             config,
             session_start: None,
+            candidates: Vec::new(),
         })
     }
 
@@ -234,6 +248,56 @@ impl NchsSession {
         }
     }
 
+    /// Gather NAT traversal candidates for this session's socket
+    ///
+    /// Queries the configured STUN servers, so this blocks for up to
+    /// `stun_timeout` per server. Call it right after creating the session,
+    /// before any peers connect. Results are cached.
+    pub fn gather_candidates(&mut self) -> &[Candidate] {
+        if self.candidates.is_empty() {
+            let socket = match &self.inner {
+                SessionInner::Host(host) => host.socket(),
+                SessionInner::Guest(guest) => guest.socket(),
+            };
+            self.candidates = gather_candidates(socket.socket(), &self.config.nat);
+        }
+        &self.candidates
+    }
+
+    /// Get previously gathered candidates
+    pub fn candidates(&self) -> &[Candidate] {
+        &self.candidates
+    }
+
+    /// Get the connection path to each remote peer (handle -> path)
+    ///
+    /// Guests know the outcome of hole punching once Ready. The host is
+    /// reached directly by every guest.
+    pub fn connection_paths(&self) -> HashMap<u8, ConnectionPath> {
+        match &self.inner {
+            SessionInner::Host(host) => host
+                .lobby_state()
+                .players
+                .iter()
+                .filter(|p| p.active && p.handle != 0)
+                .map(|p| (p.handle, ConnectionPath::Direct))
+                .collect(),
+            SessionInner::Guest(guest) => guest.peer_paths().clone(),
+        }
+    }
+
+    /// Get handles of peers that must be reached through the relay
+    pub fn relayed_peers(&self) -> Vec<u8> {
+        let mut relayed: Vec<u8> = self
+            .connection_paths()
+            .into_iter()
+            .filter(|(_, path)| path.is_relayed())
+            .map(|(handle, _)| handle)
+            .collect();
+        relayed.sort_unstable();
+        relayed
+    }
+
     /// Take the socket for GGRS transition (consumes self)
     pub fn take_socket(self) -> NchsSocket {
         match self.inner {
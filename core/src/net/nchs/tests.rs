@@ -3,6 +3,8 @@
 #![cfg(test)]
 
 use super::*;
+use crate::daily::DailyChallenge;
+use crate::net::nat::NatConfig;
 use crate::net::nchs::{JoinRejectReason, NetworkConfig, PlayerInfo};
 use nethercore_shared::console::{ConsoleType, TickRate};
 use nethercore_shared::netplay::NetplayMetadata;
@@ -27,6 +29,8 @@ fn test_config(name: &str) -> NchsConfig {
         player_info: test_player_info(name),
         network_config: NetworkConfig::default(),
         save_config: None,
+        daily: None,
+        nat: NatConfig::disabled(),
     }
 }
 
@@ -105,13 +109,19 @@ fn test_host_guest_handshake() {
 
 #[test]
 fn test_host_guest_ready_and_start() {
-    // Create host
-    let host_config = test_config("Host");
+    // Create host, hosting a platform-supplied daily challenge
+    let host_daily = DailyChallenge {
+        day: 20_742,
+        seed: 7,
+    };
+    let mut host_config = test_config("Host");
+    host_config.daily = Some(host_daily);
     let mut host = NchsSession::host(0, host_config).unwrap();
     let port = host.port();
 
-    // Create guest
-    let guest_config = test_config("Guest");
+    // Create guest, whose clock is already past midnight
+    let mut guest_config = test_config("Guest");
+    guest_config.daily = Some(DailyChallenge::for_day(20_743));
     let mut guest = NchsSession::join(&format!("127.0.0.1:{}", port), guest_config).unwrap();
 
     // Wait for guest to join
@@ -158,6 +168,7 @@ fn test_host_guest_ready_and_start() {
     let session_start = host.start().expect("Host should be able to start");
     assert!(session_start.random_seed != 0, "Should have random seed");
     assert_eq!(session_start.player_count, 2, "Should have 2 players");
+    assert_eq!(session_start.daily, host_daily, "Host picks the daily");
 
     // Guest should receive session start
     let mut guest_ready = false;
@@ -168,6 +179,7 @@ fn test_host_guest_ready_and_start() {
                     ss.random_seed, session_start.random_seed,
                     "Seeds should match"
                 );
+                assert_eq!(ss.daily, host_daily, "Daily challenges should match");
                 guest_ready = true;
                 break;
             }
@@ -211,6 +223,8 @@ fn test_rom_hash_mismatch_rejected() {
         player_info: test_player_info("Host"),
         network_config: NetworkConfig::default(),
         save_config: None,
+        daily: None,
+        nat: NatConfig::disabled(),
     };
     let mut host = NchsSession::host(0, host_config).unwrap();
     let port = host.port();
@@ -221,6 +235,8 @@ fn test_rom_hash_mismatch_rejected() {
         player_info: test_player_info("Guest"),
         network_config: NetworkConfig::default(),
         save_config: None,
+        daily: None,
+        nat: NatConfig::disabled(),
     };
     let mut guest = NchsSession::join(&format!("127.0.0.1:{}", port), guest_config).unwrap();
 
@@ -252,6 +268,8 @@ fn test_console_type_mismatch_rejected() {
         player_info: test_player_info("Host"),
         network_config: NetworkConfig::default(),
         save_config: None,
+        daily: None,
+        nat: NatConfig::disabled(),
     };
     let mut host = NchsSession::host(0, host_config).unwrap();
     let port = host.port();
@@ -262,6 +280,8 @@ fn test_console_type_mismatch_rejected() {
         player_info: test_player_info("Guest"),
         network_config: NetworkConfig::default(),
         save_config: None,
+        daily: None,
+        nat: NatConfig::disabled(),
     };
     let mut guest = NchsSession::join(&format!("127.0.0.1:{}", port), guest_config).unwrap();
 
@@ -296,6 +316,8 @@ fn test_tick_rate_mismatch_rejected() {
         player_info: test_player_info("Host"),
         network_config: NetworkConfig::default(),
         save_config: None,
+        daily: None,
+        nat: NatConfig::disabled(),
     };
     let mut host = NchsSession::host(0, host_config).unwrap();
     let port = host.port();
@@ -306,6 +328,8 @@ fn test_tick_rate_mismatch_rejected() {
         player_info: test_player_info("Guest"),
         network_config: NetworkConfig::default(),
         save_config: None,
+        daily: None,
+        nat: NatConfig::disabled(),
     };
     let mut guest = NchsSession::join(&format!("127.0.0.1:{}", port), guest_config).unwrap();
 
@@ -337,6 +361,8 @@ fn test_lobby_full_rejected() {
         player_info: test_player_info("Host"),
         network_config: NetworkConfig::default(),
         save_config: None,
+        daily: None,
+        nat: NatConfig::disabled(),
     };
     let mut host = NchsSession::host(0, host_config).unwrap();
     let port = host.port();
@@ -347,6 +373,8 @@ fn test_lobby_full_rejected() {
         player_info: test_player_info("Guest1"),
         network_config: NetworkConfig::default(),
         save_config: None,
+        daily: None,
+        nat: NatConfig::disabled(),
     };
     let mut guest1 = NchsSession::join(&format!("127.0.0.1:{}", port), guest1_config).unwrap();
 
@@ -371,6 +399,8 @@ fn test_lobby_full_rejected() {
         player_info: test_player_info("Guest2"),
         network_config: NetworkConfig::default(),
         save_config: None,
+        daily: None,
+        nat: NatConfig::disabled(),
     };
     let mut guest2 = NchsSession::join(&format!("127.0.0.1:{}", port), guest2_config).unwrap();
 
@@ -2,6 +2,8 @@
 
 use nethercore_shared::netplay::NetplayMetadata;
 
+use crate::daily::DailyChallenge;
+use crate::net::nat::NatConfig;
 use crate::net::nchs::{JoinReject, NetworkConfig, PlayerInfo, SaveConfig, SessionStart};
 
 /// NCHS session configuration
@@ -15,6 +17,11 @@ pub struct NchsConfig {
     pub network_config: NetworkConfig,
     /// Save slot configuration (optional)
     pub save_config: Option<SaveConfig>,
+    /// Daily challenge to host (None picks today's by the host's clock).
+    /// Guests play the host's challenge.
+    pub daily: Option<DailyChallenge>,
+    /// NAT traversal settings (STUN servers, relay fallback)
+    pub nat: NatConfig,
 }
 
 /// Session role
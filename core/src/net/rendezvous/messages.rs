@@ -0,0 +1,337 @@
+//! Rendezvous packet framing
+//!
+//! Wire format (integers big-endian, names UTF-8 up to [`MAX_LOBBY_NAME`] bytes):
+//!
+//! ```text
+//! magic "NCRZ" (4) | kind (1) | body...
+//!
+//! Register    lobby_id (4) | rom_hash (8) | port (2) | players (1) | max_players (1) | name
+//! Registered  lobby_id (4)
+//! Unregister  lobby_id (4)
+//! List        rom_hash (8)
+//! Listing     count (1) | count × [lobby_id (4) | players (1) | max_players (1) | addr | name]
+//!
+//! name = len (1) | bytes
+//! addr = family (1) | ip (4/16) | port (2)
+//! ```
+
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+
+use super::{MAX_LISTINGS, MAX_LOBBY_NAME};
+
+/// Rendezvous packet magic bytes
+pub const RENDEZVOUS_MAGIC: [u8; 4] = *b"NCRZ";
+
+const KIND_REGISTER: u8 = 0;
+const KIND_REGISTERED: u8 = 1;
+const KIND_UNREGISTER: u8 = 2;
+const KIND_LIST: u8 = 3;
+const KIND_LISTING: u8 = 4;
+
+const FAMILY_IPV4: u8 = 4;
+const FAMILY_IPV6: u8 = 6;
+
+/// A lobby advertised by the rendezvous server
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LobbyListing {
+    /// Server-assigned lobby id (never 0)
+    pub id: u32,
+    /// Lobby name chosen by the host
+    pub name: String,
+    /// Players currently in the lobby (including the host)
+    pub players: u8,
+    /// Maximum players
+    pub max_players: u8,
+    /// Host NCHS address guests connect to
+    pub addr: SocketAddr,
+}
+
+/// A packet exchanged with the rendezvous server
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RendezvousMessage {
+    /// Host -> server: advertise (or refresh) a lobby
+    ///
+    /// `lobby_id` is 0 for a new lobby. The advertised address is the
+    /// packet's source IP with the host's NCHS `port`.
+    Register {
+        lobby_id: u32,
+        rom_hash: u64,
+        port: u16,
+        players: u8,
+        max_players: u8,
+        name: String,
+    },
+    /// Server -> host: the lobby's id
+    Registered { lobby_id: u32 },
+    /// Host -> server: stop advertising a lobby
+    Unregister { lobby_id: u32 },
+    /// Client -> server: request open lobbies for a ROM
+    List { rom_hash: u64 },
+    /// Server -> client: open lobbies (at most [`MAX_LISTINGS`])
+    Listing(Vec<LobbyListing>),
+}
+
+impl RendezvousMessage {
+    /// Encode into a datagram
+    ///
+    /// Names longer than [`MAX_LOBBY_NAME`] bytes are truncated and listings
+    /// beyond [`MAX_LISTINGS`] are dropped.
+    pub fn encode(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(64);
+        buf.extend_from_slice(&RENDEZVOUS_MAGIC);
+
+        match self {
+            Self::Register {
+                lobby_id,
+                rom_hash,
+                port,
+                players,
+                max_players,
+                name,
+            } => {
+                buf.push(KIND_REGISTER);
+                buf.extend_from_slice(&lobby_id.to_be_bytes());
+                buf.extend_from_slice(&rom_hash.to_be_bytes());
+                buf.extend_from_slice(&port.to_be_bytes());
+                buf.push(*players);
+                buf.push(*max_players);
+                put_name(&mut buf, name);
+            }
+            Self::Registered { lobby_id } => {
+                buf.push(KIND_REGISTERED);
+                buf.extend_from_slice(&lobby_id.to_be_bytes());
+            }
+            Self::Unregister { lobby_id } => {
+                buf.push(KIND_UNREGISTER);
+                buf.extend_from_slice(&lobby_id.to_be_bytes());
+            }
+            Self::List { rom_hash } => {
+                buf.push(KIND_LIST);
+                buf.extend_from_slice(&rom_hash.to_be_bytes());
+            }
+            Self::Listing(lobbies) => {
+                let lobbies = &lobbies[..lobbies.len().min(MAX_LISTINGS)];
+                buf.push(KIND_LISTING);
+                buf.push(lobbies.len() as u8);
+                for lobby in lobbies {
+                    buf.extend_from_slice(&lobby.id.to_be_bytes());
+                    buf.push(lobby.players);
+                    buf.push(lobby.max_players);
+                    put_addr(&mut buf, lobby.addr);
+                    put_name(&mut buf, &lobby.name);
+                }
+            }
+        }
+
+        buf
+    }
+
+    /// Decode a datagram, returning `None` if it isn't a valid rendezvous packet
+    pub fn decode(data: &[u8]) -> Option<Self> {
+        let rest = data.strip_prefix(&RENDEZVOUS_MAGIC)?;
+        let (&kind, rest) = rest.split_first()?;
+        let mut reader = Reader(rest);
+
+        let message = match kind {
+            KIND_REGISTER => Self::Register {
+                lobby_id: reader.u32()?,
+                rom_hash: reader.u64()?,
+                port: reader.u16()?,
+                players: reader.u8()?,
+                max_players: reader.u8()?,
+                name: reader.name()?,
+            },
+            KIND_REGISTERED => Self::Registered {
+                lobby_id: reader.u32()?,
+            },
+            KIND_UNREGISTER => Self::Unregister {
+                lobby_id: reader.u32()?,
+            },
+            KIND_LIST => Self::List {
+                rom_hash: reader.u64()?,
+            },
+            KIND_LISTING => {
+                let count = reader.u8()? as usize;
+                if count > MAX_LISTINGS {
+                    return None;
+                }
+                let mut lobbies = Vec::with_capacity(count);
+                for _ in 0..count {
+                    lobbies.push(LobbyListing {
+                        id: reader.u32()?,
+                        players: reader.u8()?,
+                        max_players: reader.u8()?,
+                        addr: reader.addr()?,
+                        name: reader.name()?,
+                    });
+                }
+                Self::Listing(lobbies)
+            }
+            _ => return None,
+        };
+
+        reader.0.is_empty().then_some(message)
+    }
+}
+
+/// Truncate `name` to at most [`MAX_LOBBY_NAME`] bytes on a char boundary
+pub(crate) fn truncate_name(name: &str) -> &str {
+    if name.len() <= MAX_LOBBY_NAME {
+        return name;
+    }
+    let mut end = MAX_LOBBY_NAME;
+    while !name.is_char_boundary(end) {
+        end -= 1;
+    }
+    &name[..end]
+}
+
+fn put_name(buf: &mut Vec<u8>, name: &str) {
+    let name = truncate_name(name);
+    buf.push(name.len() as u8);
+    buf.extend_from_slice(name.as_bytes());
+}
+
+fn put_addr(buf: &mut Vec<u8>, addr: SocketAddr) {
+    match addr.ip() {
+        IpAddr::V4(ip) => {
+            buf.push(FAMILY_IPV4);
+            buf.extend_from_slice(&ip.octets());
+        }
+        IpAddr::V6(ip) => {
+            buf.push(FAMILY_IPV6);
+            buf.extend_from_slice(&ip.octets());
+        }
+    }
+    buf.extend_from_slice(&addr.port().to_be_bytes());
+}
+
+/// Cursor over the body of a packet
+struct Reader<'a>(&'a [u8]);
+
+impl Reader<'_> {
+    fn take<const N: usize>(&mut self) -> Option<[u8; N]> {
+        let (bytes, rest) = self.0.split_first_chunk::<N>()?;
+        self.0 = rest;
+        Some(*bytes)
+    }
+
+    fn u8(&mut self) -> Option<u8> {
+        self.take::<1>().map(|[b]| b)
+    }
+
+    fn u16(&mut self) -> Option<u16> {
+        self.take().map(u16::from_be_bytes)
+    }
+
+    fn u32(&mut self) -> Option<u32> {
+        self.take().map(u32::from_be_bytes)
+    }
+
+    fn u64(&mut self) -> Option<u64> {
+        self.take().map(u64::from_be_bytes)
+    }
+
+    fn name(&mut self) -> Option<String> {
+        let len = self.u8()? as usize;
+        if len > MAX_LOBBY_NAME || len > self.0.len() {
+            return None;
+        }
+        let (bytes, rest) = self.0.split_at(len);
+        self.0 = rest;
+        String::from_utf8(bytes.to_vec()).ok()
+    }
+
+    fn addr(&mut self) -> Option<SocketAddr> {
+        let ip = match self.u8()? {
+            FAMILY_IPV4 => IpAddr::V4(Ipv4Addr::from(self.take::<4>()?)),
+            FAMILY_IPV6 => IpAddr::V6(Ipv6Addr::from(self.take::<16>()?)),
+            _ => return None,
+        };
+        Some(SocketAddr::new(ip, self.u16()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn roundtrip(message: RendezvousMessage) {
+        let bytes = message.encode();
+        assert_eq!(RendezvousMessage::decode(&bytes), Some(message));
+    }
+
+    #[test]
+    fn test_roundtrip_all_kinds() {
+        roundtrip(RendezvousMessage::Register {
+            lobby_id: 0,
+            rom_hash: 0x0123_4567_89ab_cdef,
+            port: 7770,
+            players: 1,
+            max_players: 4,
+            name: "Friday Night Fights".to_string(),
+        });
+        roundtrip(RendezvousMessage::Registered { lobby_id: 42 });
+        roundtrip(RendezvousMessage::Unregister { lobby_id: 42 });
+        roundtrip(RendezvousMessage::List { rom_hash: 7 });
+        roundtrip(RendezvousMessage::Listing(vec![
+            LobbyListing {
+                id: 1,
+                name: "Lobby".to_string(),
+                players: 2,
+                max_players: 4,
+                addr: "203.0.113.7:7770".parse().unwrap(),
+            },
+            LobbyListing {
+                id: 2,
+                name: String::new(),
+                players: 1,
+                max_players: 2,
+                addr: "[2001:db8::7]:7770".parse().unwrap(),
+            },
+        ]));
+        roundtrip(RendezvousMessage::Listing(Vec::new()));
+    }
+
+    #[test]
+    fn test_long_names_are_truncated() {
+        let bytes = RendezvousMessage::Register {
+            lobby_id: 0,
+            rom_hash: 0,
+            port: 1,
+            players: 1,
+            max_players: 2,
+            name: "é".repeat(MAX_LOBBY_NAME),
+        }
+        .encode();
+        let Some(RendezvousMessage::Register { name, .. }) = RendezvousMessage::decode(&bytes)
+        else {
+            panic!("register did not decode");
+        };
+        assert_eq!(name, "é".repeat(MAX_LOBBY_NAME / 2));
+    }
+
+    #[test]
+    fn test_decode_rejects_garbage() {
+        assert_eq!(RendezvousMessage::decode(b""), None);
+        assert_eq!(RendezvousMessage::decode(b"NCRY\x01\0\0\0\x01"), None);
+        assert_eq!(RendezvousMessage::decode(b"NCRZ\x09"), None);
+        // Truncated body
+        assert_eq!(RendezvousMessage::decode(b"NCRZ\x01\0\0"), None);
+        // Trailing bytes
+        assert_eq!(RendezvousMessage::decode(b"NCRZ\x01\0\0\0\x01\0"), None);
+        // Invalid UTF-8 name
+        let mut bytes = RendezvousMessage::Register {
+            lobby_id: 0,
+            rom_hash: 0,
+            port: 1,
+            players: 1,
+            max_players: 2,
+            name: "ab".to_string(),
+        }
+        .encode();
+        let len = bytes.len();
+        bytes[len - 1] = 0xFF;
+        assert_eq!(RendezvousMessage::decode(&bytes), None);
+    }
+}
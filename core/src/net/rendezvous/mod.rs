@@ -0,0 +1,43 @@
+//! Rendezvous service for in-game lobby browsing
+//!
+//! A lightweight UDP lobby registry. Hosts advertise their NCHS lobby and
+//! refresh it every [`REGISTER_INTERVAL`]; other players list the open lobbies
+//! for the same ROM and join one over NCHS directly. The server never relays
+//! game traffic.
+//!
+//! - [`messages`] - Wire format shared by clients and the server
+//! - [`server`] - In-memory registry and the blocking [`serve`] loop
+//!
+//! # Flow
+//!
+//! ```text
+//! Host                     Rendezvous                  Guest
+//!   |--- Register ------------->|                         |
+//!   |<-- Registered(id) --------|                         |
+//!   |                           |<-------- List ----------|
+//!   |                           |--- Listing ------------>|
+//!   |<=========== NCHS JoinRequest (host addr) ===========|
+//! ```
+
+pub mod messages;
+pub mod server;
+
+use std::time::Duration;
+
+pub use messages::{LobbyListing, RENDEZVOUS_MAGIC, RendezvousMessage};
+pub use server::{RendezvousServer, serve};
+
+/// Default rendezvous server UDP port
+pub const DEFAULT_RENDEZVOUS_PORT: u16 = 7780;
+
+/// Maximum lobby name length in bytes
+pub const MAX_LOBBY_NAME: usize = 32;
+
+/// Maximum lobbies returned by one listing
+pub const MAX_LISTINGS: usize = 16;
+
+/// How often hosts refresh their registration
+pub const REGISTER_INTERVAL: Duration = Duration::from_secs(5);
+
+/// How long a lobby stays listed without a refresh
+pub const LOBBY_TTL: Duration = Duration::from_secs(20);
@@ -0,0 +1,291 @@
+//! Rendezvous server
+//!
+//! [`RendezvousServer`] is the packet-in, packet-out lobby registry; [`serve`]
+//! runs it on a UDP socket. Lobbies are kept in memory only and expire when
+//! their host stops refreshing them.
+
+use std::collections::BTreeMap;
+use std::io;
+use std::net::{SocketAddr, UdpSocket};
+use std::time::{Duration, Instant};
+
+use super::messages::{LobbyListing, RendezvousMessage};
+use super::{LOBBY_TTL, MAX_LISTINGS};
+
+/// A lobby registered by a host
+#[derive(Debug, Clone)]
+struct HostedLobby {
+    /// Address the host registers from (only it may refresh or remove the lobby)
+    owner: SocketAddr,
+    rom_hash: u64,
+    listing: LobbyListing,
+    last_seen: Instant,
+}
+
+/// In-memory lobby registry
+#[derive(Debug)]
+pub struct RendezvousServer {
+    lobbies: BTreeMap<u32, HostedLobby>,
+    next_id: u32,
+}
+
+impl Default for RendezvousServer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RendezvousServer {
+    /// Create an empty registry
+    pub fn new() -> Self {
+        Self {
+            lobbies: BTreeMap::new(),
+            next_id: 1,
+        }
+    }
+
+    /// Number of registered lobbies
+    pub fn lobby_count(&self) -> usize {
+        self.lobbies.len()
+    }
+
+    /// Handle one datagram from `from`, returning the reply to send back (if any)
+    pub fn handle(&mut self, from: SocketAddr, data: &[u8], now: Instant) -> Option<Vec<u8>> {
+        let reply = match RendezvousMessage::decode(data)? {
+            RendezvousMessage::Register {
+                lobby_id,
+                rom_hash,
+                port,
+                players,
+                max_players,
+                name,
+            } => {
+                if max_players < 2 || port == 0 {
+                    return None;
+                }
+                let listing = LobbyListing {
+                    id: 0,
+                    name,
+                    players: players.min(max_players),
+                    max_players,
+                    addr: SocketAddr::new(from.ip(), port),
+                };
+                let lobby_id = self.register(lobby_id, from, rom_hash, listing, now);
+                RendezvousMessage::Registered { lobby_id }
+            }
+            RendezvousMessage::Unregister { lobby_id } => {
+                if self
+                    .lobbies
+                    .get(&lobby_id)
+                    .is_some_and(|lobby| lobby.owner == from)
+                {
+                    self.lobbies.remove(&lobby_id);
+                }
+                return None;
+            }
+            RendezvousMessage::List { rom_hash } => {
+                self.expire(now);
+                let open = self
+                    .lobbies
+                    .values()
+                    .filter(|lobby| lobby.rom_hash == rom_hash)
+                    .filter(|lobby| lobby.listing.players < lobby.listing.max_players)
+                    .take(MAX_LISTINGS)
+                    .map(|lobby| lobby.listing.clone())
+                    .collect();
+                RendezvousMessage::Listing(open)
+            }
+            // Server-to-client messages are ignored
+            RendezvousMessage::Registered { .. } | RendezvousMessage::Listing(_) => return None,
+        };
+        Some(reply.encode())
+    }
+
+    /// Drop lobbies whose host hasn't refreshed them within [`LOBBY_TTL`]
+    pub fn expire(&mut self, now: Instant) {
+        self.lobbies
+            .retain(|_, lobby| now.saturating_duration_since(lobby.last_seen) < LOBBY_TTL);
+    }
+
+    /// Refresh the owner's existing lobby, or create a new one
+    fn register(
+        &mut self,
+        lobby_id: u32,
+        owner: SocketAddr,
+        rom_hash: u64,
+        mut listing: LobbyListing,
+        now: Instant,
+    ) -> u32 {
+        let id = match self.lobbies.get(&lobby_id) {
+            Some(lobby) if lobby.owner == owner && lobby.rom_hash == rom_hash => lobby_id,
+            _ => self.allocate_id(),
+        };
+        listing.id = id;
+        self.lobbies.insert(
+            id,
+            HostedLobby {
+                owner,
+                rom_hash,
+                listing,
+                last_seen: now,
+            },
+        );
+        id
+    }
+
+    fn allocate_id(&mut self) -> u32 {
+        loop {
+            let id = self.next_id;
+            self.next_id = self.next_id.wrapping_add(1).max(1);
+            if !self.lobbies.contains_key(&id) {
+                return id;
+            }
+        }
+    }
+}
+
+/// Run a rendezvous server on `bind_addr` until an I/O error occurs
+pub fn serve(bind_addr: &str) -> io::Result<()> {
+    let socket = UdpSocket::bind(bind_addr)?;
+    socket.set_read_timeout(Some(Duration::from_secs(1)))?;
+    tracing::info!("Rendezvous server listening on {}", socket.local_addr()?);
+
+    let mut server = RendezvousServer::new();
+    let mut buf = [0u8; 2048];
+    loop {
+        match socket.recv_from(&mut buf) {
+            Ok((len, from)) => {
+                if let Some(reply) = server.handle(from, &buf[..len], Instant::now())
+                    && let Err(e) = socket.send_to(&reply, from)
+                {
+                    tracing::debug!("Rendezvous reply to {} failed: {}", from, e);
+                }
+            }
+            Err(e)
+                if matches!(
+                    e.kind(),
+                    io::ErrorKind::WouldBlock
+                        | io::ErrorKind::TimedOut
+                        | io::ErrorKind::ConnectionReset
+                ) => {}
+            Err(e) => return Err(e),
+        }
+        server.expire(Instant::now());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ROM: u64 = 0xC0FFEE;
+
+    fn host() -> SocketAddr {
+        "203.0.113.7:50000".parse().unwrap()
+    }
+
+    fn register(lobby_id: u32, players: u8) -> Vec<u8> {
+        RendezvousMessage::Register {
+            lobby_id,
+            rom_hash: ROM,
+            port: 7770,
+            players,
+            max_players: 4,
+            name: "Lobby".to_string(),
+        }
+        .encode()
+    }
+
+    fn registered_id(reply: Option<Vec<u8>>) -> u32 {
+        match RendezvousMessage::decode(&reply.unwrap()) {
+            Some(RendezvousMessage::Registered { lobby_id }) => lobby_id,
+            other => panic!("unexpected reply {:?}", other),
+        }
+    }
+
+    fn list(server: &mut RendezvousServer, rom_hash: u64, now: Instant) -> Vec<LobbyListing> {
+        let client: SocketAddr = "198.51.100.1:40000".parse().unwrap();
+        let reply = server.handle(client, &RendezvousMessage::List { rom_hash }.encode(), now);
+        match RendezvousMessage::decode(&reply.unwrap()) {
+            Some(RendezvousMessage::Listing(lobbies)) => lobbies,
+            other => panic!("unexpected reply {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_register_and_list() {
+        let mut server = RendezvousServer::new();
+        let now = Instant::now();
+        let id = registered_id(server.handle(host(), &register(0, 1), now));
+        assert_ne!(id, 0);
+
+        let lobbies = list(&mut server, ROM, now);
+        assert_eq!(lobbies.len(), 1);
+        assert_eq!(lobbies[0].id, id);
+        assert_eq!(lobbies[0].addr, "203.0.113.7:7770".parse().unwrap());
+        assert!(list(&mut server, ROM + 1, now).is_empty());
+    }
+
+    #[test]
+    fn test_refresh_keeps_id_and_updates_players() {
+        let mut server = RendezvousServer::new();
+        let now = Instant::now();
+        let id = registered_id(server.handle(host(), &register(0, 1), now));
+        assert_eq!(
+            registered_id(server.handle(host(), &register(id, 3), now)),
+            id
+        );
+        assert_eq!(server.lobby_count(), 1);
+        assert_eq!(list(&mut server, ROM, now)[0].players, 3);
+
+        // Full lobbies aren't listed
+        server.handle(host(), &register(id, 4), now);
+        assert!(list(&mut server, ROM, now).is_empty());
+    }
+
+    #[test]
+    fn test_only_owner_can_refresh_or_unregister() {
+        let mut server = RendezvousServer::new();
+        let now = Instant::now();
+        let other: SocketAddr = "198.51.100.9:1234".parse().unwrap();
+        let id = registered_id(server.handle(host(), &register(0, 1), now));
+
+        // Someone else reusing the id gets a lobby of their own
+        assert_ne!(
+            registered_id(server.handle(other, &register(id, 1), now)),
+            id
+        );
+        let unregister = RendezvousMessage::Unregister { lobby_id: id }.encode();
+        assert_eq!(server.handle(other, &unregister, now), None);
+        assert_eq!(server.lobby_count(), 2);
+
+        server.handle(host(), &unregister, now);
+        assert_eq!(server.lobby_count(), 1);
+    }
+
+    #[test]
+    fn test_lobbies_expire() {
+        let mut server = RendezvousServer::new();
+        let now = Instant::now();
+        server.handle(host(), &register(0, 1), now);
+        assert_eq!(list(&mut server, ROM, now + LOBBY_TTL / 2).len(), 1);
+        assert!(list(&mut server, ROM, now + LOBBY_TTL).is_empty());
+    }
+
+    #[test]
+    fn test_ignores_invalid_registrations() {
+        let mut server = RendezvousServer::new();
+        let now = Instant::now();
+        let solo = RendezvousMessage::Register {
+            lobby_id: 0,
+            rom_hash: ROM,
+            port: 7770,
+            players: 1,
+            max_players: 1,
+            name: String::new(),
+        };
+        assert_eq!(server.handle(host(), &solo.encode(), now), None);
+        assert_eq!(server.handle(host(), b"garbage", now), None);
+        assert_eq!(server.lobby_count(), 0);
+    }
+}
@@ -0,0 +1,506 @@
+//! Shared voice channel state
+//!
+//! [`VoiceChannel`] is a cheap, cloneable handle shared by the game (FFI),
+//! the GGRS socket and the audio device callbacks. All state lives behind a
+//! single mutex; every operation is short and allocation-light so the audio
+//! callbacks never block for long.
+
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use glam::Vec3;
+
+use super::codec::{VoiceEncoder, decode_frame};
+use super::packet::VoicePacket;
+use super::{VOICE_FRAME_SAMPLES, VOICE_SAMPLE_RATE};
+use crate::wasm::MAX_PLAYERS;
+
+/// Maximum buffered playback per speaker (200 ms); older audio is dropped
+const MAX_PLAYBACK_SAMPLES: usize = VOICE_FRAME_SAMPLES * 10;
+
+/// Maximum encoded frames waiting to be sent (200 ms)
+const MAX_OUTGOING_FRAMES: usize = 10;
+
+/// Capture level below which a frame is treated as silence and not sent
+const SILENCE_THRESHOLD: i32 = 300;
+
+/// Positional attenuation settings
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PositionalVoice {
+    /// Distance at which a speaker becomes inaudible (world units)
+    pub max_distance: f32,
+}
+
+impl PositionalVoice {
+    /// Gain for a speaker at `distance` from the listener (linear falloff)
+    pub fn gain(&self, distance: f32) -> f32 {
+        if self.max_distance <= 0.0 {
+            return 1.0;
+        }
+        (1.0 - distance / self.max_distance).clamp(0.0, 1.0)
+    }
+}
+
+#[derive(Debug)]
+struct VoiceState {
+    /// Voice enabled by the game (capture and playback)
+    enabled: bool,
+    /// Local player handle (used as the sender in outgoing packets)
+    local_handle: u8,
+    /// Per-player mute (incoming voice from muted players is discarded)
+    muted: [bool; MAX_PLAYERS],
+    /// Remote players the game has enabled voice for (all by default)
+    players: [bool; MAX_PLAYERS],
+    /// Positional attenuation (None = everyone at full volume)
+    positional: Option<PositionalVoice>,
+    listener: Vec3,
+    positions: [Option<Vec3>; MAX_PLAYERS],
+
+    // Capture
+    encoder: VoiceEncoder,
+    capture: Vec<i16>,
+    capture_phase: f32,
+    next_seq: u16,
+    outgoing: VecDeque<Vec<u8>>,
+
+    // Playback
+    playback: [VecDeque<i16>; MAX_PLAYERS],
+    last_seq: [Option<u16>; MAX_PLAYERS],
+    playback_phase: f32,
+    current: [i16; MAX_PLAYERS],
+}
+
+impl Default for VoiceState {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            local_handle: 0,
+            muted: [false; MAX_PLAYERS],
+            players: [true; MAX_PLAYERS],
+            positional: None,
+            listener: Vec3::ZERO,
+            positions: [None; MAX_PLAYERS],
+            encoder: VoiceEncoder::new(),
+            capture: Vec::with_capacity(VOICE_FRAME_SAMPLES),
+            capture_phase: 0.0,
+            next_seq: 0,
+            outgoing: VecDeque::new(),
+            playback: Default::default(),
+            last_seq: [None; MAX_PLAYERS],
+            playback_phase: 0.0,
+            current: [0; MAX_PLAYERS],
+        }
+    }
+}
+
+impl VoiceState {
+    fn clear_buffers(&mut self) {
+        self.capture.clear();
+        self.outgoing.clear();
+        for queue in &mut self.playback {
+            queue.clear();
+        }
+        self.current = [0; MAX_PLAYERS];
+    }
+
+    fn gain_for(&self, player: usize) -> f32 {
+        match (self.positional, self.positions[player]) {
+            (Some(positional), Some(pos)) => positional.gain(pos.distance(self.listener)),
+            _ => 1.0,
+        }
+    }
+
+    fn finish_capture_frame(&mut self) {
+        let pcm: [i16; VOICE_FRAME_SAMPLES] = match self.capture[..].try_into() {
+            Ok(pcm) => pcm,
+            Err(_) => return,
+        };
+        self.capture.clear();
+
+        // Don't spend bandwidth on silence
+        if pcm.iter().all(|s| (*s as i32).abs() < SILENCE_THRESHOLD) {
+            return;
+        }
+
+        let frame = self.encoder.encode(&pcm);
+        let packet = VoicePacket {
+            sender: self.local_handle,
+            seq: self.next_seq,
+            frame: &frame,
+        };
+        self.next_seq = self.next_seq.wrapping_add(1);
+
+        if self.outgoing.len() >= MAX_OUTGOING_FRAMES {
+            self.outgoing.pop_front();
+        }
+        self.outgoing.push_back(packet.encode());
+    }
+}
+
+/// Shared voice chat state for one session
+///
+/// Disabled by default; games opt in with the `voice_enable(player, on)` FFI.
+#[derive(Debug, Clone, Default)]
+pub struct VoiceChannel {
+    inner: Arc<Mutex<VoiceState>>,
+}
+
+impl VoiceChannel {
+    /// Create a channel for the given local player handle
+    pub fn new(local_handle: u8) -> Self {
+        let channel = Self::default();
+        channel.lock().local_handle = local_handle;
+        channel
+    }
+
+    fn lock(&self) -> MutexGuard<'_, VoiceState> {
+        // Voice is best-effort; a poisoned lock just means a callback panicked
+        self.inner.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Enable or disable voice (disabling drops all buffered audio)
+    pub fn set_enabled(&self, enabled: bool) {
+        let mut state = self.lock();
+        if state.enabled != enabled {
+            tracing::info!(enabled, "Voice chat toggled");
+            state.enabled = enabled;
+            state.clear_buffers();
+        }
+    }
+
+    /// Check whether voice is enabled
+    pub fn is_enabled(&self) -> bool {
+        self.lock().enabled
+    }
+
+    /// Enable or disable voice for one player (out-of-range handles are ignored)
+    ///
+    /// For the local player this is [`VoiceChannel::set_enabled`]. For a
+    /// remote player it controls whether their voice is played back.
+    pub fn set_player_enabled(&self, player: usize, enabled: bool) {
+        if player == self.local_handle() as usize {
+            self.set_enabled(enabled);
+            return;
+        }
+
+        let mut state = self.lock();
+        if player < MAX_PLAYERS {
+            state.players[player] = enabled;
+            if !enabled {
+                state.playback[player].clear();
+                state.current[player] = 0;
+            }
+        }
+    }
+
+    /// Check whether voice is enabled for a player
+    pub fn is_player_enabled(&self, player: usize) -> bool {
+        let state = self.lock();
+        if player == state.local_handle as usize {
+            state.enabled
+        } else {
+            player < MAX_PLAYERS && state.players[player]
+        }
+    }
+
+    /// Get the local player handle used as the packet sender
+    pub fn local_handle(&self) -> u8 {
+        self.lock().local_handle
+    }
+
+    /// Mute or unmute a player (out-of-range handles are ignored)
+    pub fn set_muted(&self, player: usize, muted: bool) {
+        let mut state = self.lock();
+        if player < MAX_PLAYERS {
+            state.muted[player] = muted;
+            if muted {
+                state.playback[player].clear();
+                state.current[player] = 0;
+            }
+        }
+    }
+
+    /// Check whether a player is muted
+    pub fn is_muted(&self, player: usize) -> bool {
+        player < MAX_PLAYERS && self.lock().muted[player]
+    }
+
+    /// Check whether a remote player currently has audio queued for playback
+    pub fn is_speaking(&self, player: usize) -> bool {
+        player < MAX_PLAYERS && !self.lock().playback[player].is_empty()
+    }
+
+    /// Set positional attenuation (None restores full volume for everyone)
+    pub fn set_positional(&self, positional: Option<PositionalVoice>) {
+        self.lock().positional = positional;
+    }
+
+    /// Get the positional attenuation settings
+    pub fn positional(&self) -> Option<PositionalVoice> {
+        self.lock().positional
+    }
+
+    /// Set the listener position used for positional attenuation
+    pub fn set_listener(&self, position: Vec3) {
+        self.lock().listener = position;
+    }
+
+    /// Get the listener position used for positional attenuation
+    pub fn listener(&self) -> Vec3 {
+        self.lock().listener
+    }
+
+    /// Set a speaker's position used for positional attenuation
+    pub fn set_player_position(&self, player: usize, position: Vec3) {
+        if player < MAX_PLAYERS {
+            self.lock().positions[player] = Some(position);
+        }
+    }
+
+    /// Get a speaker's position, if one has been set
+    pub fn player_position(&self, player: usize) -> Option<Vec3> {
+        self.lock().positions.get(player).copied().flatten()
+    }
+
+    /// Feed microphone samples (interleaved f32 at the device rate)
+    ///
+    /// Called from the capture device callback. Downmixes to mono and
+    /// resamples to [`VOICE_SAMPLE_RATE`]; complete frames are encoded and
+    /// queued for [`VoiceChannel::take_outgoing`].
+    pub fn push_capture(&self, samples: &[f32], channels: usize, sample_rate: u32) {
+        let mut guard = self.lock();
+        let state = &mut *guard;
+        if !state.enabled || channels == 0 || sample_rate == 0 {
+            return;
+        }
+
+        let step = VOICE_SAMPLE_RATE as f32 / sample_rate as f32;
+        for frame in samples.chunks_exact(channels) {
+            state.capture_phase += step;
+            if state.capture_phase < 1.0 {
+                continue;
+            }
+            state.capture_phase -= 1.0;
+
+            let mono = frame.iter().sum::<f32>() / channels as f32;
+            state
+                .capture
+                .push((mono * i16::MAX as f32).clamp(i16::MIN as f32, i16::MAX as f32) as i16);
+
+            if state.capture.len() == VOICE_FRAME_SAMPLES {
+                state.finish_capture_frame();
+            }
+        }
+    }
+
+    /// Take all encoded voice packets waiting to be sent
+    pub fn take_outgoing(&self) -> Vec<Vec<u8>> {
+        self.lock().outgoing.drain(..).collect()
+    }
+
+    /// Handle an incoming voice datagram
+    ///
+    /// Returns `false` if the datagram was not a valid voice packet. Valid
+    /// packets are silently dropped while voice is disabled, from muted or
+    /// disabled players, from ourselves, or when they arrive out of order.
+    pub fn receive_packet(&self, data: &[u8]) -> bool {
+        let Some(packet) = VoicePacket::decode(data) else {
+            return false;
+        };
+
+        let mut state = self.lock();
+        let sender = packet.sender as usize;
+        if !state.enabled
+            || sender >= MAX_PLAYERS
+            || packet.sender == state.local_handle
+            || state.muted[sender]
+            || !state.players[sender]
+        {
+            return true;
+        }
+
+        // Late packets are useless for realtime audio
+        if let Some(last) = state.last_seq[sender]
+            && (packet.seq.wrapping_sub(last) as i16) <= 0
+        {
+            return true;
+        }
+        state.last_seq[sender] = Some(packet.seq);
+
+        if let Some(pcm) = decode_frame(packet.frame) {
+            let queue = &mut state.playback[sender];
+            queue.extend(pcm);
+            let excess = queue.len().saturating_sub(MAX_PLAYBACK_SAMPLES);
+            queue.drain(..excess);
+        }
+        true
+    }
+
+    /// Mix queued remote voice into an interleaved f32 output buffer
+    ///
+    /// Called from the playback device callback. Voice is added on top of
+    /// whatever is already in `out`, upsampled with sample-and-hold.
+    pub fn mix_into(&self, out: &mut [f32], channels: usize, sample_rate: u32) {
+        let mut guard = self.lock();
+        let state = &mut *guard;
+        if !state.enabled || channels == 0 || sample_rate == 0 {
+            return;
+        }
+
+        let gains: [f32; MAX_PLAYERS] = std::array::from_fn(|p| state.gain_for(p));
+        let step = VOICE_SAMPLE_RATE as f32 / sample_rate as f32;
+
+        for frame in out.chunks_exact_mut(channels) {
+            state.playback_phase += step;
+            if state.playback_phase >= 1.0 {
+                state.playback_phase -= 1.0;
+                for p in 0..MAX_PLAYERS {
+                    state.current[p] = state.playback[p].pop_front().unwrap_or(0);
+                }
+            }
+
+            let mixed: f32 = (0..MAX_PLAYERS)
+                .map(|p| state.current[p] as f32 / i16::MAX as f32 * gains[p])
+                .sum();
+            for sample in frame {
+                *sample = (*sample + mixed).clamp(-1.0, 1.0);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DEVICE_RATE: u32 = 8_000;
+
+    fn tone(len: usize) -> Vec<f32> {
+        (0..len).map(|i| (i as f32 * 0.3).sin() * 0.5).collect()
+    }
+
+    /// Capture one frame on `from` and deliver it to `to`
+    fn transmit(from: &VoiceChannel, to: &VoiceChannel) -> usize {
+        from.push_capture(&tone(VOICE_FRAME_SAMPLES), 1, DEVICE_RATE);
+        let packets = from.take_outgoing();
+        for packet in &packets {
+            assert!(to.receive_packet(packet));
+        }
+        packets.len()
+    }
+
+    fn enabled(handle: u8) -> VoiceChannel {
+        let channel = VoiceChannel::new(handle);
+        channel.set_enabled(true);
+        channel
+    }
+
+    #[test]
+    fn test_disabled_by_default() {
+        let channel = VoiceChannel::new(0);
+        assert!(!channel.is_enabled());
+        channel.push_capture(&tone(VOICE_FRAME_SAMPLES), 1, DEVICE_RATE);
+        assert!(channel.take_outgoing().is_empty());
+    }
+
+    #[test]
+    fn test_capture_roundtrip() {
+        let alice = enabled(0);
+        let bob = enabled(1);
+        assert_eq!(transmit(&alice, &bob), 1);
+        assert!(bob.is_speaking(0));
+
+        let mut out = vec![0.0f32; VOICE_FRAME_SAMPLES];
+        bob.mix_into(&mut out, 1, DEVICE_RATE);
+        assert!(out.iter().any(|s| s.abs() > 0.01));
+        assert!(!bob.is_speaking(0));
+    }
+
+    #[test]
+    fn test_silence_is_not_sent() {
+        let channel = enabled(0);
+        channel.push_capture(&[0.0; VOICE_FRAME_SAMPLES], 1, DEVICE_RATE);
+        assert!(channel.take_outgoing().is_empty());
+    }
+
+    #[test]
+    fn test_muted_player_is_dropped() {
+        let alice = enabled(0);
+        let bob = enabled(1);
+        bob.set_muted(0, true);
+        assert_eq!(transmit(&alice, &bob), 1);
+        assert!(!bob.is_speaking(0));
+    }
+
+    #[test]
+    fn test_player_enable() {
+        let alice = enabled(0);
+        let bob = VoiceChannel::new(1);
+
+        // The local player's flag turns voice on and off
+        bob.set_player_enabled(1, true);
+        assert!(bob.is_enabled());
+        assert!(bob.is_player_enabled(0));
+
+        // A disabled remote player is not played back
+        bob.set_player_enabled(0, false);
+        assert!(!bob.is_player_enabled(0));
+        assert_eq!(transmit(&alice, &bob), 1);
+        assert!(!bob.is_speaking(0));
+
+        bob.set_player_enabled(0, true);
+        assert_eq!(transmit(&alice, &bob), 1);
+        assert!(bob.is_speaking(0));
+
+        bob.set_player_enabled(1, false);
+        assert!(!bob.is_enabled());
+    }
+
+    #[test]
+    fn test_own_packets_are_ignored() {
+        let alice = enabled(0);
+        let echo = enabled(0);
+        transmit(&alice, &echo);
+        assert!(!echo.is_speaking(0));
+    }
+
+    #[test]
+    fn test_out_of_order_packets_are_dropped() {
+        let alice = enabled(0);
+        let bob = enabled(1);
+        alice.push_capture(&tone(VOICE_FRAME_SAMPLES * 2), 1, DEVICE_RATE);
+        let packets = alice.take_outgoing();
+        assert_eq!(packets.len(), 2);
+
+        bob.receive_packet(&packets[1]);
+        bob.receive_packet(&packets[0]);
+        assert_eq!(bob.lock().playback[0].len(), VOICE_FRAME_SAMPLES);
+    }
+
+    #[test]
+    fn test_non_voice_packet_rejected() {
+        assert!(!enabled(0).receive_packet(b"not voice"));
+    }
+
+    #[test]
+    fn test_positional_attenuation() {
+        let alice = enabled(0);
+        let bob = enabled(1);
+        bob.set_positional(Some(PositionalVoice { max_distance: 10.0 }));
+        bob.set_listener(Vec3::ZERO);
+        bob.set_player_position(0, Vec3::new(20.0, 0.0, 0.0));
+        transmit(&alice, &bob);
+
+        let mut out = vec![0.0f32; VOICE_FRAME_SAMPLES];
+        bob.mix_into(&mut out, 1, DEVICE_RATE);
+        assert!(out.iter().all(|s| *s == 0.0));
+    }
+
+    #[test]
+    fn test_positional_gain() {
+        let positional = PositionalVoice { max_distance: 10.0 };
+        assert_eq!(positional.gain(0.0), 1.0);
+        assert_eq!(positional.gain(5.0), 0.5);
+        assert_eq!(positional.gain(15.0), 0.0);
+    }
+}
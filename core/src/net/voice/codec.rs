@@ -0,0 +1,161 @@
+//! Voice frame codec
+//!
+//! Each frame carries its own LMS predictor state followed by the QOA
+//! slices, so frames decode independently and a lost packet never corrupts
+//! the frames after it:
+//!
+//! ```text
+//! LMS state (16) | 8 slices x 8 bytes (64)  = 80 bytes per 20 ms (32 kbps)
+//! ```
+
+use nether_qoa::{
+    QOA_LMS_LEN, QOA_LMS_STATE_SIZE, QOA_SLICE_LEN, QoaLms, decode_slice, encode_slice,
+};
+
+use super::VOICE_FRAME_SAMPLES;
+
+/// QOA slices per voice frame
+const SLICES_PER_FRAME: usize = VOICE_FRAME_SAMPLES / QOA_SLICE_LEN;
+
+/// Encoded size of one voice frame in bytes
+pub const VOICE_FRAME_BYTES: usize = QOA_LMS_STATE_SIZE + SLICES_PER_FRAME * 8;
+
+/// Streaming voice encoder
+///
+/// The predictor carries over between frames for quality; its state is
+/// written into every frame so the decoder can stay stateless.
+#[derive(Debug, Clone, Default)]
+pub struct VoiceEncoder {
+    lms: QoaLms,
+}
+
+impl VoiceEncoder {
+    /// Create a new encoder
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Encode one frame of mono 8 kHz PCM
+    pub fn encode(&mut self, pcm: &[i16; VOICE_FRAME_SAMPLES]) -> [u8; VOICE_FRAME_BYTES] {
+        let mut out = [0u8; VOICE_FRAME_BYTES];
+
+        // The header stores the state as i16, so clamp it first to keep the
+        // encoder and decoder predictors identical
+        for i in 0..QOA_LMS_LEN {
+            self.lms.history[i] = clamp_i16(self.lms.history[i]);
+            self.lms.weights[i] = clamp_i16(self.lms.weights[i]);
+        }
+        write_lms(&self.lms, &mut out[..QOA_LMS_STATE_SIZE]);
+
+        let slices = out[QOA_LMS_STATE_SIZE..].chunks_exact_mut(8);
+        for (samples, slot) in pcm.chunks_exact(QOA_SLICE_LEN).zip(slices) {
+            let slice = encode_slice(samples, &mut self.lms);
+            slot.copy_from_slice(&slice.to_be_bytes());
+        }
+
+        out
+    }
+}
+
+/// Decode one voice frame
+///
+/// Returns `None` if `data` is not exactly [`VOICE_FRAME_BYTES`] long.
+pub fn decode_frame(data: &[u8]) -> Option<[i16; VOICE_FRAME_SAMPLES]> {
+    if data.len() != VOICE_FRAME_BYTES {
+        return None;
+    }
+
+    let mut lms = read_lms(&data[..QOA_LMS_STATE_SIZE]);
+    let mut pcm = [0i16; VOICE_FRAME_SAMPLES];
+
+    let slices = data[QOA_LMS_STATE_SIZE..].chunks_exact(8);
+    for (bytes, samples) in slices.zip(pcm.chunks_exact_mut(QOA_SLICE_LEN)) {
+        let slice = u64::from_be_bytes(bytes.try_into().ok()?);
+        decode_slice(slice, &mut lms, samples);
+    }
+
+    Some(pcm)
+}
+
+fn clamp_i16(v: i32) -> i32 {
+    v.clamp(i16::MIN as i32, i16::MAX as i32)
+}
+
+/// Write history then weights as i16 big-endian (same layout as QOA frames)
+fn write_lms(lms: &QoaLms, out: &mut [u8]) {
+    let values = lms.history.iter().chain(lms.weights.iter());
+    for (v, slot) in values.zip(out.chunks_exact_mut(2)) {
+        slot.copy_from_slice(&(*v as i16).to_be_bytes());
+    }
+}
+
+fn read_lms(data: &[u8]) -> QoaLms {
+    let mut lms = QoaLms::new();
+    let mut values = data
+        .chunks_exact(2)
+        .map(|b| i16::from_be_bytes([b[0], b[1]]) as i32);
+    for v in lms.history.iter_mut().chain(lms.weights.iter_mut()) {
+        *v = values.next().unwrap_or(0);
+    }
+    lms
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sine_frame(offset: usize) -> [i16; VOICE_FRAME_SAMPLES] {
+        std::array::from_fn(|i| {
+            let t = (offset + i) as f32 / 8000.0;
+            ((t * 440.0 * std::f32::consts::TAU).sin() * 8000.0) as i16
+        })
+    }
+
+    #[test]
+    fn test_frame_size() {
+        assert_eq!(VOICE_FRAME_BYTES, 80);
+        let frame = VoiceEncoder::new().encode(&[0; VOICE_FRAME_SAMPLES]);
+        assert_eq!(frame.len(), VOICE_FRAME_BYTES);
+    }
+
+    #[test]
+    fn test_roundtrip_is_close() {
+        let mut encoder = VoiceEncoder::new();
+        for n in 0..4 {
+            let pcm = sine_frame(n * VOICE_FRAME_SAMPLES);
+            let decoded = decode_frame(&encoder.encode(&pcm)).unwrap();
+            let max_err = pcm
+                .iter()
+                .zip(decoded.iter())
+                .map(|(a, b)| (*a as i32 - *b as i32).abs())
+                .max()
+                .unwrap();
+            assert!(max_err < 2000, "frame {n}: max error {max_err}");
+        }
+    }
+
+    #[test]
+    fn test_frames_decode_independently() {
+        let mut encoder = VoiceEncoder::new();
+        let _lost = encoder.encode(&sine_frame(0));
+        let pcm = sine_frame(VOICE_FRAME_SAMPLES);
+        let frame = encoder.encode(&pcm);
+
+        // Decoding the second frame alone matches decoding it in sequence
+        let decoded = decode_frame(&frame).unwrap();
+        let max_err = pcm
+            .iter()
+            .zip(decoded.iter())
+            .map(|(a, b)| (*a as i32 - *b as i32).abs())
+            .max()
+            .unwrap();
+        assert!(max_err < 2000);
+    }
+
+    #[test]
+    fn test_decode_rejects_wrong_length() {
+        assert!(decode_frame(&[]).is_none());
+        assert!(decode_frame(&[0; VOICE_FRAME_BYTES - 1]).is_none());
+        assert!(decode_frame(&[0; VOICE_FRAME_BYTES + 1]).is_none());
+    }
+}
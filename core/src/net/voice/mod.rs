@@ -0,0 +1,32 @@
+//! Voice chat
+//!
+//! Opt-in, low-bitrate voice carried over the same UDP socket as GGRS
+//! input packets:
+//!
+//! - [`codec`] - Self-contained 20 ms frames compressed with nether-qoa
+//! - [`packet`] - Wire framing that lets voice share the GGRS socket
+//! - [`channel`] - Shared capture/playback state (mute, positional attenuation)
+//!
+//! # Data Flow
+//!
+//! ```text
+//! mic (device thread) ──push_capture──▶ VoiceChannel ──take_outgoing──▶ LocalSocket ──▶ peers
+//! speaker (device thread) ◀──mix_into── VoiceChannel ◀──receive_packet── LocalSocket ◀── peers
+//! ```
+//!
+//! Voice is presentation-only: nothing here feeds back into the simulation,
+//! so games may call the voice FFI freely from `update()`.
+
+pub mod channel;
+pub mod codec;
+pub mod packet;
+
+pub use channel::{PositionalVoice, VoiceChannel};
+pub use codec::{VOICE_FRAME_BYTES, VoiceEncoder, decode_frame};
+pub use packet::{VOICE_MAGIC, VoicePacket};
+
+/// Voice sample rate (narrowband telephony quality)
+pub const VOICE_SAMPLE_RATE: u32 = 8_000;
+
+/// Samples per voice frame (20 ms at 8 kHz)
+pub const VOICE_FRAME_SAMPLES: usize = 160;
@@ -0,0 +1,92 @@
+//! Voice packet framing
+//!
+//! Voice packets share the GGRS socket, so they start with a magic that
+//! can't be mistaken for a bincode-encoded GGRS message:
+//!
+//! ```text
+//! magic "NCVC" (4) | sender handle (1) | sequence (2, BE) | frame (80)
+//! ```
+
+use super::codec::VOICE_FRAME_BYTES;
+
+/// Voice packet magic bytes
+pub const VOICE_MAGIC: [u8; 4] = *b"NCVC";
+
+/// Header size (magic + sender + sequence)
+const HEADER_SIZE: usize = 7;
+
+/// A single encoded voice frame from one player
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VoicePacket<'a> {
+    /// Player handle of the speaker
+    pub sender: u8,
+    /// Frame sequence number (wraps)
+    pub seq: u16,
+    /// Encoded frame (see [`super::codec`])
+    pub frame: &'a [u8],
+}
+
+impl<'a> VoicePacket<'a> {
+    /// Encode into a datagram
+    pub fn encode(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(HEADER_SIZE + self.frame.len());
+        buf.extend_from_slice(&VOICE_MAGIC);
+        buf.push(self.sender);
+        buf.extend_from_slice(&self.seq.to_be_bytes());
+        buf.extend_from_slice(self.frame);
+        buf
+    }
+
+    /// Decode a datagram, returning `None` if it isn't a valid voice packet
+    pub fn decode(data: &'a [u8]) -> Option<Self> {
+        let rest = data.strip_prefix(&VOICE_MAGIC)?;
+        let (&sender, rest) = rest.split_first()?;
+        let (seq, frame) = rest.split_first_chunk::<2>()?;
+
+        if frame.len() != VOICE_FRAME_BYTES {
+            return None;
+        }
+
+        Some(Self {
+            sender,
+            seq: u16::from_be_bytes(*seq),
+            frame,
+        })
+    }
+
+    /// Check whether a datagram starts with the voice magic
+    pub fn is_voice_packet(data: &[u8]) -> bool {
+        data.starts_with(&VOICE_MAGIC)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roundtrip() {
+        let frame = [7u8; VOICE_FRAME_BYTES];
+        let packet = VoicePacket {
+            sender: 3,
+            seq: 0xBEEF,
+            frame: &frame,
+        };
+        let bytes = packet.encode();
+        assert_eq!(bytes.len(), HEADER_SIZE + VOICE_FRAME_BYTES);
+        assert!(VoicePacket::is_voice_packet(&bytes));
+        assert_eq!(VoicePacket::decode(&bytes), Some(packet));
+    }
+
+    #[test]
+    fn test_decode_rejects_garbage() {
+        assert_eq!(VoicePacket::decode(b""), None);
+        assert_eq!(VoicePacket::decode(b"NCRY\x00"), None);
+        // Header only
+        assert_eq!(VoicePacket::decode(b"NCVC\x01\x00\x01"), None);
+        // Truncated frame
+        let mut bytes = b"NCVC\x01\x00\x01".to_vec();
+        bytes.extend_from_slice(&[0; VOICE_FRAME_BYTES - 1]);
+        assert_eq!(VoicePacket::decode(&bytes), None);
+    }
+}
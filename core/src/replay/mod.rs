@@ -37,7 +37,7 @@
 //! let config = RecorderConfig {
 //!     console_id: 1,
 //!     player_count: 1,
-//!     input_size: 8,
+//!     input_size: 12,
 //!     seed: 12345,
 //!     checkpoint_interval: 300,
 //!     compress: true,
@@ -105,8 +105,10 @@
 //! ```
 
 pub mod binary;
+pub mod render;
 pub mod runtime;
 pub mod script;
+pub mod slots;
 pub mod types;
 
 // Re-export core types
@@ -126,6 +128,9 @@ pub use script::{
     InputValue, ParseError, ReplayScript, StructuredInput, decompile,
 };
 
+// Re-export offline rendering
+pub use render::{ReplayRenderConfig, ReplaySource, VideoEncoder, parse_resolution};
+
 // Re-export runtime
 pub use runtime::{
     DebugVariableInfo, ExecutionReport, HeadlessBackend, HeadlessConfig, HeadlessRunner, Player,
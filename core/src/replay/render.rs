@@ -0,0 +1,394 @@
+//! Offline replay rendering
+//!
+//! Re-simulates a recorded replay one tick per rendered frame, without
+//! waiting on the wall clock, and encodes every frame to a video. Slow
+//! machines take longer to render but never drop or duplicate frames.
+//!
+//! The output format follows the file extension:
+//! - `.gif` is encoded in-process
+//! - A directory (existing, or a path without an extension) receives a
+//!   numbered PNG sequence
+//! - Anything else (`.mp4`, `.webm`, `.mkv`, ...) is piped to `ffmpeg`,
+//!   which must be on `PATH`
+//!
+//! Frames are upscaled with nearest-neighbour filtering and letterboxed
+//! to the requested resolution. Audio is not included.
+
+use std::fs::{self, File};
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Child, ChildStdin, Command, Stdio};
+use std::str::FromStr;
+
+use anyhow::{Context, Result, bail};
+
+use super::slots::MAX_REPLAY_SLOTS;
+
+/// Where the replay to render comes from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReplaySource {
+    /// One of the game's recent replay slots (0 = newest)
+    Slot(usize),
+    /// An `.ncrp` file
+    File(PathBuf),
+}
+
+impl FromStr for ReplaySource {
+    type Err = anyhow::Error;
+
+    /// Parses a slot number (`0`-`7`) or a path to an `.ncrp` file
+    fn from_str(s: &str) -> Result<Self> {
+        match s.parse::<usize>() {
+            Ok(slot) if slot < MAX_REPLAY_SLOTS => Ok(Self::Slot(slot)),
+            Ok(slot) => bail!(
+                "Replay slot {} out of range (0-{})",
+                slot,
+                MAX_REPLAY_SLOTS - 1
+            ),
+            Err(_) => Ok(Self::File(PathBuf::from(s))),
+        }
+    }
+}
+
+/// Configuration for `--render-replay`
+#[derive(Debug, Clone)]
+pub struct ReplayRenderConfig {
+    /// Replay to re-simulate
+    pub source: ReplaySource,
+    /// Output video file or PNG directory
+    pub output: PathBuf,
+    /// Output resolution (defaults to the console's native resolution)
+    pub resolution: Option<(u32, u32)>,
+}
+
+/// Parses a `WIDTHxHEIGHT` resolution such as `1920x1080`
+pub fn parse_resolution(s: &str) -> Result<(u32, u32)> {
+    let (w, h) = s
+        .split_once(['x', 'X'])
+        .with_context(|| format!("Invalid resolution '{}' (expected WIDTHxHEIGHT)", s))?;
+    let width: u32 = w
+        .trim()
+        .parse()
+        .with_context(|| format!("Invalid width in '{}'", s))?;
+    let height: u32 = h
+        .trim()
+        .parse()
+        .with_context(|| format!("Invalid height in '{}'", s))?;
+    if width == 0 || height == 0 || width > 7680 || height > 4320 {
+        bail!(
+            "Resolution {}x{} out of range (max 7680x4320)",
+            width,
+            height
+        );
+    }
+    Ok((width, height))
+}
+
+/// Output container chosen from the output path
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VideoFormat {
+    Gif,
+    PngSequence,
+    Ffmpeg,
+}
+
+impl VideoFormat {
+    pub fn from_path(path: &Path) -> Self {
+        if path.is_dir() {
+            return Self::PngSequence;
+        }
+        match path.extension().and_then(|e| e.to_str()) {
+            None => Self::PngSequence,
+            Some(ext) if ext.eq_ignore_ascii_case("gif") => Self::Gif,
+            Some(_) => Self::Ffmpeg,
+        }
+    }
+}
+
+/// Nearest-neighbour upscale of an RGBA frame, letterboxed into `dst_w`x`dst_h`
+///
+/// Uses the largest integer scale that fits (or a fractional one when the
+/// target is smaller than the source) so pixels stay square.
+pub fn scale_to_fit(src: &[u8], src_w: u32, src_h: u32, dst_w: u32, dst_h: u32) -> Vec<u8> {
+    if (src_w, src_h) == (dst_w, dst_h) {
+        return src.to_vec();
+    }
+
+    let int_scale = (dst_w / src_w).min(dst_h / src_h);
+    let (out_w, out_h) = if int_scale >= 1 {
+        (src_w * int_scale, src_h * int_scale)
+    } else {
+        let scale = (dst_w as f64 / src_w as f64).min(dst_h as f64 / src_h as f64);
+        (
+            ((src_w as f64 * scale) as u32).max(1),
+            ((src_h as f64 * scale) as u32).max(1),
+        )
+    };
+    let off_x = (dst_w - out_w) / 2;
+    let off_y = (dst_h - out_h) / 2;
+
+    let mut dst = vec![0u8; (dst_w * dst_h * 4) as usize];
+    for chunk in dst.chunks_exact_mut(4) {
+        chunk[3] = 255;
+    }
+    for y in 0..out_h {
+        let sy = (y as u64 * src_h as u64 / out_h as u64) as u32;
+        let src_row = (sy * src_w * 4) as usize;
+        let dst_row = (((y + off_y) * dst_w + off_x) * 4) as usize;
+        for x in 0..out_w {
+            let sx = (x as u64 * src_w as u64 / out_w as u64) as u32;
+            let s = src_row + (sx * 4) as usize;
+            let d = dst_row + (x * 4) as usize;
+            dst[d..d + 4].copy_from_slice(&src[s..s + 4]);
+        }
+    }
+    dst
+}
+
+enum Sink {
+    Gif(gif::Encoder<BufWriter<File>>),
+    Png(PathBuf),
+    Ffmpeg { child: Child, stdin: ChildStdin },
+}
+
+/// Frame-by-frame video writer for replay rendering
+pub struct VideoEncoder {
+    sink: Sink,
+    output: PathBuf,
+    src: (u32, u32),
+    dst: (u32, u32),
+    fps: u32,
+    frames: u64,
+}
+
+impl VideoEncoder {
+    /// Create an encoder for frames of `src` size, written at `dst` size
+    pub fn create(output: &Path, src: (u32, u32), dst: (u32, u32), fps: u32) -> Result<Self> {
+        let fps = fps.max(1);
+        let sink = match VideoFormat::from_path(output) {
+            VideoFormat::Gif => {
+                if dst.0 > u16::MAX as u32 || dst.1 > u16::MAX as u32 {
+                    bail!("GIF output is limited to 65535x65535");
+                }
+                let file = File::create(output)
+                    .with_context(|| format!("Failed to create {}", output.display()))?;
+                let mut encoder =
+                    gif::Encoder::new(BufWriter::new(file), dst.0 as u16, dst.1 as u16, &[])
+                        .context("Failed to create GIF encoder")?;
+                encoder
+                    .set_repeat(gif::Repeat::Infinite)
+                    .context("Failed to set GIF repeat")?;
+                Sink::Gif(encoder)
+            }
+            VideoFormat::PngSequence => {
+                fs::create_dir_all(output)
+                    .with_context(|| format!("Failed to create {}", output.display()))?;
+                Sink::Png(output.to_path_buf())
+            }
+            VideoFormat::Ffmpeg => {
+                if !dst.0.is_multiple_of(2) || !dst.1.is_multiple_of(2) {
+                    bail!(
+                        "Video resolution must be even for {} output",
+                        output.display()
+                    );
+                }
+                let (child, stdin) = spawn_ffmpeg(output, dst, fps)?;
+                Sink::Ffmpeg { child, stdin }
+            }
+        };
+
+        Ok(Self {
+            sink,
+            output: output.to_path_buf(),
+            src,
+            dst,
+            fps,
+            frames: 0,
+        })
+    }
+
+    /// Number of frames written so far
+    pub fn frames(&self) -> u64 {
+        self.frames
+    }
+
+    /// Append one RGBA frame at the source resolution
+    pub fn write_frame(&mut self, rgba: &[u8]) -> Result<()> {
+        let (src_w, src_h) = self.src;
+        let (dst_w, dst_h) = self.dst;
+        let pixels = scale_to_fit(rgba, src_w, src_h, dst_w, dst_h);
+
+        match &mut self.sink {
+            Sink::Gif(encoder) => {
+                // GIF delays are in centiseconds; spread the rounding so the
+                // average frame rate matches exactly
+                let delay = |n: u64| n * 100 / self.fps as u64;
+                let mut rgb: Vec<u8> = Vec::with_capacity(pixels.len() * 3 / 4);
+                for chunk in pixels.chunks_exact(4) {
+                    rgb.extend_from_slice(&chunk[..3]);
+                }
+                let mut frame = gif::Frame::from_rgb(dst_w as u16, dst_h as u16, &rgb);
+                frame.delay = (delay(self.frames + 1) - delay(self.frames)) as u16;
+                encoder
+                    .write_frame(&frame)
+                    .context("Failed to write GIF frame")?;
+            }
+            Sink::Png(dir) => {
+                let path = dir.join(format!("frame_{:06}.png", self.frames));
+                image::save_buffer(&path, &pixels, dst_w, dst_h, image::ColorType::Rgba8)
+                    .with_context(|| format!("Failed to write {}", path.display()))?;
+            }
+            Sink::Ffmpeg { stdin, .. } => {
+                stdin
+                    .write_all(&pixels)
+                    .context("Failed to send frame to ffmpeg")?;
+            }
+        }
+
+        self.frames += 1;
+        Ok(())
+    }
+
+    /// Flush and close the output, returning its path
+    pub fn finish(self) -> Result<PathBuf> {
+        match self.sink {
+            Sink::Gif(encoder) => {
+                encoder
+                    .into_inner()
+                    .context("Failed to finish GIF")?
+                    .flush()
+                    .context("Failed to flush GIF")?;
+            }
+            Sink::Png(_) => {}
+            Sink::Ffmpeg { mut child, stdin } => {
+                drop(stdin);
+                let status = child.wait().context("Failed to wait for ffmpeg")?;
+                if !status.success() {
+                    bail!("ffmpeg exited with {}", status);
+                }
+            }
+        }
+        Ok(self.output)
+    }
+}
+
+/// Start ffmpeg reading raw RGBA frames from stdin
+fn spawn_ffmpeg(
+    output: &Path,
+    (width, height): (u32, u32),
+    fps: u32,
+) -> Result<(Child, ChildStdin)> {
+    let ext = output
+        .extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+    let codec_args: &[&str] = match ext.as_str() {
+        "mp4" | "mkv" | "mov" => &["-c:v", "libx264", "-preset", "slow", "-crf", "12"],
+        "webm" => &["-c:v", "libvpx-vp9", "-crf", "15", "-b:v", "0"],
+        _ => &[],
+    };
+
+    let mut child = Command::new("ffmpeg")
+        .args([
+            "-y",
+            "-loglevel",
+            "error",
+            "-f",
+            "rawvideo",
+            "-pix_fmt",
+            "rgba",
+        ])
+        .args(["-s", &format!("{}x{}", width, height)])
+        .args(["-r", &fps.to_string()])
+        .args(["-i", "-"])
+        .args(codec_args)
+        .args(["-pix_fmt", "yuv420p"])
+        .arg(output)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .spawn()
+        .context("Failed to start ffmpeg (install it, or render to .gif or a PNG directory)")?;
+    let stdin = child.stdin.take().context("Failed to open ffmpeg stdin")?;
+    Ok((child, stdin))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_source() {
+        assert_eq!("0".parse::<ReplaySource>().unwrap(), ReplaySource::Slot(0));
+        assert_eq!(
+            "runs/best.ncrp".parse::<ReplaySource>().unwrap(),
+            ReplaySource::File(PathBuf::from("runs/best.ncrp"))
+        );
+        assert!(
+            MAX_REPLAY_SLOTS
+                .to_string()
+                .parse::<ReplaySource>()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_parse_resolution() {
+        assert_eq!(parse_resolution("1920x1080").unwrap(), (1920, 1080));
+        assert_eq!(parse_resolution("960X540").unwrap(), (960, 540));
+        assert!(parse_resolution("1920").is_err());
+        assert!(parse_resolution("0x100").is_err());
+        assert!(parse_resolution("99999x100").is_err());
+    }
+
+    #[test]
+    fn test_format_from_path() {
+        assert_eq!(
+            VideoFormat::from_path(Path::new("out.gif")),
+            VideoFormat::Gif
+        );
+        assert_eq!(
+            VideoFormat::from_path(Path::new("out.mp4")),
+            VideoFormat::Ffmpeg
+        );
+        assert_eq!(
+            VideoFormat::from_path(Path::new("frames")),
+            VideoFormat::PngSequence
+        );
+    }
+
+    #[test]
+    fn test_scale_to_fit_letterboxes() {
+        // 2x1 source: red, green
+        let src = [255, 0, 0, 255, 0, 255, 0, 255];
+        let dst = scale_to_fit(&src, 2, 1, 4, 4);
+        let px = |x: u32, y: u32| &dst[((y * 4 + x) * 4) as usize..((y * 4 + x) * 4 + 4) as usize];
+
+        // Scaled 2x into rows 1-2, black bars above and below
+        assert_eq!(px(0, 0), [0, 0, 0, 255]);
+        assert_eq!(px(1, 1), [255, 0, 0, 255]);
+        assert_eq!(px(2, 2), [0, 255, 0, 255]);
+        assert_eq!(px(3, 3), [0, 0, 0, 255]);
+    }
+
+    #[test]
+    fn test_gif_and_png_output() {
+        let dir = tempfile::tempdir().unwrap();
+        let frame = vec![128u8; 2 * 2 * 4];
+
+        let gif_path = dir.path().join("out.gif");
+        let mut encoder = VideoEncoder::create(&gif_path, (2, 2), (4, 4), 60).unwrap();
+        for _ in 0..3 {
+            encoder.write_frame(&frame).unwrap();
+        }
+        assert_eq!(encoder.frames(), 3);
+        assert_eq!(encoder.finish().unwrap(), gif_path);
+        assert!(fs::metadata(&gif_path).unwrap().len() > 0);
+
+        let png_dir = dir.path().join("frames");
+        let mut encoder = VideoEncoder::create(&png_dir, (2, 2), (2, 2), 60).unwrap();
+        encoder.write_frame(&frame).unwrap();
+        encoder.finish().unwrap();
+        assert!(png_dir.join("frame_000000.png").exists());
+    }
+}
@@ -26,7 +26,7 @@ impl Default for RecorderConfig {
         Self {
             console_id: 1,
             player_count: 1,
-            input_size: 8,
+            input_size: 12,
             seed: 0,
             checkpoint_interval: 300, // Every 5 seconds at 60fps
             compress: true,
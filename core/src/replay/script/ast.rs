@@ -5,6 +5,8 @@ use std::borrow::Cow;
 use hashbrown::HashMap;
 use serde::{Deserialize, Serialize};
 
+use crate::wasm::MAX_PLAYERS;
+
 /// Complete replay script file (TOML structure)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ReplayScript {
@@ -49,6 +51,22 @@ pub struct FrameEntry {
     #[serde(default)]
     pub p4: Option<InputValue>,
 
+    /// Player 5 input
+    #[serde(default)]
+    pub p5: Option<InputValue>,
+
+    /// Player 6 input
+    #[serde(default)]
+    pub p6: Option<InputValue>,
+
+    /// Player 7 input
+    #[serde(default)]
+    pub p7: Option<InputValue>,
+
+    /// Player 8 input
+    #[serde(default)]
+    pub p8: Option<InputValue>,
+
     /// Capture debug variables before and after update()
     #[serde(default)]
     pub snap: bool,
@@ -177,6 +195,15 @@ pub enum AssertValue {
     PrevValue(String),
 }
 
+impl FrameEntry {
+    /// Per-player inputs in player order (p1 first)
+    pub fn player_inputs(&self) -> [&Option<InputValue>; MAX_PLAYERS] {
+        [
+            &self.p1, &self.p2, &self.p3, &self.p4, &self.p5, &self.p6, &self.p7, &self.p8,
+        ]
+    }
+}
+
 impl ReplayScript {
     /// Get the maximum frame number in the script
     pub fn max_frame(&self) -> u64 {
@@ -134,12 +134,8 @@ impl<'a> Compiler<'a> {
         // Process each frame entry
         for entry in &script.frames {
             // Collect player inputs
-            let player_inputs = vec![
-                entry.p1.clone(),
-                entry.p2.clone(),
-                entry.p3.clone(),
-                entry.p4.clone(),
-            ];
+            let player_inputs: Vec<Option<InputValue>> =
+                entry.player_inputs().into_iter().cloned().collect();
             frame_inputs.insert(entry.f, player_inputs);
 
             // Track snap frames
@@ -332,6 +328,10 @@ mod tests {
                     p2: None,
                     p3: None,
                     p4: None,
+                    p5: None,
+                    p6: None,
+                    p7: None,
+                    p8: None,
                     snap: true,
                     screenshot: false,
                     assert: None,
@@ -344,6 +344,10 @@ mod tests {
                     p2: None,
                     p3: None,
                     p4: None,
+                    p5: None,
+                    p6: None,
+                    p7: None,
+                    p8: None,
                     snap: false,
                     screenshot: false,
                     assert: Some("$player_x > 0".to_string()),
@@ -384,6 +388,10 @@ mod tests {
                     p2: None,
                     p3: None,
                     p4: None,
+                    p5: None,
+                    p6: None,
+                    p7: None,
+                    p8: None,
                     snap: false,
                     screenshot: false,
                     assert: None,
@@ -396,6 +404,10 @@ mod tests {
                     p2: None,
                     p3: None,
                     p4: None,
+                    p5: None,
+                    p6: None,
+                    p7: None,
+                    p8: None,
                     snap: false,
                     screenshot: false,
                     assert: None,
@@ -442,6 +454,10 @@ mod tests {
                     p2: None,
                     p3: None,
                     p4: None,
+                    p5: None,
+                    p6: None,
+                    p7: None,
+                    p8: None,
                     snap: false,
                     screenshot: false,
                     assert: None,
@@ -454,6 +470,10 @@ mod tests {
                     p2: None,
                     p3: None,
                     p4: None,
+                    p5: None,
+                    p6: None,
+                    p7: None,
+                    p8: None,
                     snap: true,
                     screenshot: false,
                     assert: None,
@@ -487,6 +507,10 @@ mod tests {
                     p2: None,
                     p3: None,
                     p4: None,
+                    p5: None,
+                    p6: None,
+                    p7: None,
+                    p8: None,
                     snap: false,
                     screenshot: true,
                     assert: None,
@@ -499,6 +523,10 @@ mod tests {
                     p2: None,
                     p3: None,
                     p4: None,
+                    p5: None,
+                    p6: None,
+                    p7: None,
+                    p8: None,
                     snap: false,
                     screenshot: true,
                     assert: None,
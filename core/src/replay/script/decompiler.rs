@@ -5,6 +5,7 @@
 use super::ast::{FrameEntry, InputValue, ReplayScript};
 use super::compiler::InputLayout;
 use crate::replay::types::Replay;
+use crate::wasm::MAX_PLAYERS;
 
 /// Decompile a binary replay to a script
 pub fn decompile(replay: &Replay, layout: &dyn InputLayout) -> ReplayScript {
@@ -12,19 +13,12 @@ pub fn decompile(replay: &Replay, layout: &dyn InputLayout) -> ReplayScript {
 
     // Convert each frame to a FrameEntry
     for (frame_idx, frame_inputs) in replay.inputs.iter().enumerate() {
-        // Get player 1-4 inputs
-        let p1 = frame_inputs
-            .first()
-            .map(|bytes| bytes_to_input(bytes, layout));
-        let p2 = frame_inputs
-            .get(1)
-            .map(|bytes| bytes_to_input(bytes, layout));
-        let p3 = frame_inputs
-            .get(2)
-            .map(|bytes| bytes_to_input(bytes, layout));
-        let p4 = frame_inputs
-            .get(3)
-            .map(|bytes| bytes_to_input(bytes, layout));
+        // Get player 1-8 inputs
+        let mut players: [Option<InputValue>; MAX_PLAYERS] = Default::default();
+        for (slot, bytes) in players.iter_mut().zip(frame_inputs.iter()) {
+            *slot = Some(bytes_to_input(bytes, layout));
+        }
+        let [p1, p2, p3, p4, p5, p6, p7, p8] = players;
 
         // Check if this frame has an assertion
         let assert = replay
@@ -39,6 +33,10 @@ pub fn decompile(replay: &Replay, layout: &dyn InputLayout) -> ReplayScript {
             p2,
             p3,
             p4,
+            p5,
+            p6,
+            p7,
+            p8,
             snap: false,       // Binary format doesn't preserve snap flags
             screenshot: false, // Binary format doesn't preserve screenshot flags
             assert,
@@ -50,7 +48,7 @@ pub fn decompile(replay: &Replay, layout: &dyn InputLayout) -> ReplayScript {
     // Optimize: remove trailing idle frames with no assertions
     while frames.len() > 1 {
         let last = frames.last().unwrap();
-        if last.assert.is_none() && is_all_idle(&[&last.p1, &last.p2, &last.p3, &last.p4]) {
+        if last.assert.is_none() && is_all_idle(&last.player_inputs()) {
             frames.pop();
         } else {
             break;
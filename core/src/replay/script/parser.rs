@@ -317,6 +317,10 @@ rt = 0.8
                     p2: Some(InputValue::Symbolic("idle".to_string())),
                     p3: None,
                     p4: None,
+                    p5: None,
+                    p6: None,
+                    p7: None,
+                    p8: None,
                     snap: true,
                     screenshot: false,
                     assert: None,
@@ -329,6 +333,10 @@ rt = 0.8
                     p2: Some(InputValue::Symbolic("b".to_string())),
                     p3: None,
                     p4: None,
+                    p5: None,
+                    p6: None,
+                    p7: None,
+                    p8: None,
                     snap: false,
                     screenshot: false,
                     assert: Some("$velocity_y < 0".to_string()),
@@ -491,6 +499,10 @@ action = "Reset State"
                 p2: None,
                 p3: None,
                 p4: None,
+                p5: None,
+                p6: None,
+                p7: None,
+                p8: None,
                 snap: false,
                 screenshot: false,
                 assert: None,
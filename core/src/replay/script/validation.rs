@@ -3,6 +3,7 @@
 use hashbrown::HashSet;
 
 use super::ast::ReplayScript;
+use crate::wasm::MAX_PLAYERS;
 
 /// Validation errors for replay scripts.
 #[derive(Debug, Clone)]
@@ -53,7 +54,7 @@ pub fn validate_script(script: &ReplayScript) -> Result<(), ValidationError> {
         return Err(ValidationError::EmptyConsole);
     }
 
-    if !(1..=4).contains(&script.players) {
+    if !(1..=MAX_PLAYERS as u8).contains(&script.players) {
         return Err(ValidationError::InvalidPlayerCount(script.players));
     }
 
@@ -67,25 +68,87 @@ pub fn validate_script(script: &ReplayScript) -> Result<(), ValidationError> {
             return Err(ValidationError::OrphanedActionParams(entry.f));
         }
 
-        if script.players < 4 && entry.p4.is_some() {
-            return Err(ValidationError::UnexpectedPlayerInput {
-                frame: entry.f,
-                player: 4,
-            });
+        let inputs = entry.player_inputs();
+        for idx in (script.players as usize..MAX_PLAYERS).rev() {
+            if inputs[idx].is_some() {
+                return Err(ValidationError::UnexpectedPlayerInput {
+                    frame: entry.f,
+                    player: idx as u8 + 1,
+                });
+            }
         }
-        if script.players < 3 && entry.p3.is_some() {
-            return Err(ValidationError::UnexpectedPlayerInput {
-                frame: entry.f,
-                player: 3,
-            });
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::replay::script::{FrameEntry, InputValue};
+
+    fn script_with(players: u8, entry: FrameEntry) -> ReplayScript {
+        ReplayScript {
+            console: "zx".to_string(),
+            seed: 0,
+            players,
+            frames: vec![entry],
         }
-        if script.players < 2 && entry.p2.is_some() {
-            return Err(ValidationError::UnexpectedPlayerInput {
-                frame: entry.f,
-                player: 2,
-            });
+    }
+
+    fn frame(f: u64) -> FrameEntry {
+        FrameEntry {
+            f,
+            p1: None,
+            p2: None,
+            p3: None,
+            p4: None,
+            p5: None,
+            p6: None,
+            p7: None,
+            p8: None,
+            snap: false,
+            screenshot: false,
+            assert: None,
+            action: None,
+            action_params: None,
         }
     }
 
-    Ok(())
+    #[test]
+    fn test_accepts_five_to_eight_players() {
+        for players in 5..=MAX_PLAYERS as u8 {
+            let mut entry = frame(0);
+            entry.p5 = Some(InputValue::Symbolic("a".to_string()));
+            if players == 8 {
+                entry.p8 = Some(InputValue::Symbolic("right".to_string()));
+            }
+            assert!(validate_script(&script_with(players, entry)).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_rejects_player_count_out_of_range() {
+        assert!(matches!(
+            validate_script(&script_with(0, frame(0))),
+            Err(ValidationError::InvalidPlayerCount(0))
+        ));
+        assert!(matches!(
+            validate_script(&script_with(9, frame(0))),
+            Err(ValidationError::InvalidPlayerCount(9))
+        ));
+    }
+
+    #[test]
+    fn test_rejects_input_beyond_player_count() {
+        let mut entry = frame(3);
+        entry.p7 = Some(InputValue::Symbolic("a".to_string()));
+        assert!(matches!(
+            validate_script(&script_with(6, entry)),
+            Err(ValidationError::UnexpectedPlayerInput {
+                frame: 3,
+                player: 7
+            })
+        ));
+    }
 }
@@ -0,0 +1,138 @@
+//! Replay slots
+//!
+//! The standalone player records every local session and keeps the most
+//! recent ones as `.ncrp` files under `<data_dir>/replays/<game_id>/`.
+//! Slot 0 is always the newest recording; saving a new one shifts the
+//! others down and drops the oldest.
+
+use std::fs;
+use std::io::{self, BufReader, BufWriter};
+use std::path::{Path, PathBuf};
+
+use crate::replay::binary::{BinaryReader, BinaryWriter};
+use crate::replay::types::Replay;
+
+/// Number of recent replays kept per game
+pub const MAX_REPLAY_SLOTS: usize = 8;
+
+/// Directory (under the data dir) holding a game's replay slots
+pub fn replays_dir(data_dir: &Path, game_id: &str) -> PathBuf {
+    data_dir.join("replays").join(game_id)
+}
+
+/// Path of a slot file inside a game's replay directory
+pub fn slot_path(dir: &Path, slot: usize) -> PathBuf {
+    dir.join(format!("slot{}.ncrp", slot))
+}
+
+/// Saves `replay` as the newest recording (slot 0), shifting older slots down
+pub fn push_replay(dir: &Path, replay: &Replay) -> io::Result<PathBuf> {
+    fs::create_dir_all(dir)?;
+
+    for slot in (0..MAX_REPLAY_SLOTS - 1).rev() {
+        let from = slot_path(dir, slot);
+        if from.exists() {
+            fs::rename(&from, slot_path(dir, slot + 1))?;
+        }
+    }
+
+    let path = slot_path(dir, 0);
+    save_file(&path, replay)?;
+    Ok(path)
+}
+
+/// Loads the replay stored in `slot`
+pub fn load_slot(dir: &Path, slot: usize) -> io::Result<Replay> {
+    if slot >= MAX_REPLAY_SLOTS {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "replay slot {} out of range (0-{})",
+                slot,
+                MAX_REPLAY_SLOTS - 1
+            ),
+        ));
+    }
+    load_file(&slot_path(dir, slot))
+}
+
+/// Writes `replay` to an `.ncrp` file, replacing it only once fully written
+pub fn save_file(path: &Path, replay: &Replay) -> io::Result<()> {
+    let mut tmp = path.as_os_str().to_owned();
+    tmp.push(".tmp");
+    let tmp = PathBuf::from(tmp);
+    {
+        let file = fs::File::create(&tmp)?;
+        BinaryWriter::new(BufWriter::new(file)).write_replay(replay)?;
+    }
+    fs::rename(&tmp, path)
+}
+
+/// Loads a replay from an `.ncrp` file
+pub fn load_file(path: &Path) -> io::Result<Replay> {
+    let file = fs::File::open(path)?;
+    BinaryReader::new(BufReader::new(file)).read_replay()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::replay::runtime::{Recorder, RecorderConfig};
+
+    fn replay(frames: u8) -> Replay {
+        let mut recorder = Recorder::new(RecorderConfig {
+            checkpoint_interval: 0,
+            ..Default::default()
+        });
+        recorder.start();
+        for i in 0..frames {
+            recorder.record_frame(vec![vec![i; 12]]);
+        }
+        recorder.stop()
+    }
+
+    #[test]
+    fn test_push_shifts_slots() {
+        let dir = tempfile::tempdir().unwrap();
+
+        push_replay(dir.path(), &replay(3)).unwrap();
+        push_replay(dir.path(), &replay(5)).unwrap();
+
+        assert_eq!(load_slot(dir.path(), 0).unwrap().header.frame_count, 5);
+        assert_eq!(load_slot(dir.path(), 1).unwrap().header.frame_count, 3);
+        assert!(load_slot(dir.path(), 2).is_err());
+        assert!(load_slot(dir.path(), MAX_REPLAY_SLOTS).is_err());
+    }
+
+    #[test]
+    fn test_save_file_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("run.ncrp");
+
+        save_file(&path, &replay(4)).unwrap();
+        save_file(&path, &replay(6)).unwrap();
+
+        assert_eq!(load_file(&path).unwrap().header.frame_count, 6);
+        assert!(!dir.path().join("run.ncrp.tmp").exists());
+    }
+
+    #[test]
+    fn test_oldest_slot_is_dropped() {
+        let dir = tempfile::tempdir().unwrap();
+
+        for frames in 1..=MAX_REPLAY_SLOTS as u8 + 1 {
+            push_replay(dir.path(), &replay(frames)).unwrap();
+        }
+
+        let oldest = load_slot(dir.path(), MAX_REPLAY_SLOTS - 1).unwrap();
+        assert_eq!(oldest.header.frame_count, 2);
+        assert!(!slot_path(dir.path(), MAX_REPLAY_SLOTS).exists());
+
+        let newest = load_slot(dir.path(), 0).unwrap();
+        assert_eq!(
+            newest.inputs.get_frame(0),
+            Some(&vec![vec![0u8; 12]]),
+            "inputs survive the round trip"
+        );
+    }
+}
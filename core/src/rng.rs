@@ -0,0 +1,156 @@
+//! Seeded RNG streams (`rng_create` / `rng_next` / `rng_range`)
+//!
+//! `random()` draws from one global stream, so an extra cosmetic roll shifts
+//! every gameplay roll after it. Streams give a game independent generators:
+//! each has its own PCG state, seeded by the game, and draws from one never
+//! affect another or the global RNG. The table is a fixed-size POD array
+//! stored with the host-side rollback state, so streams are restored by
+//! rollback like the global RNG.
+
+/// Maximum number of RNG streams
+pub const MAX_RNG_STREAMS: usize = 16;
+
+/// Fixed-size table of RNG streams
+///
+/// Handles returned to the game are slot index + 1, so 0 is never a valid
+/// handle.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[repr(C)]
+pub struct RngStreams {
+    /// PCG state per slot
+    states: [u64; MAX_RNG_STREAMS],
+    /// Bit per slot in use
+    active: u32,
+    /// Padding for alignment
+    _padding: u32,
+}
+
+// SAFETY: RngStreams is #[repr(C)] with only u64/u32 fields (no padding)
+unsafe impl bytemuck::Zeroable for RngStreams {}
+unsafe impl bytemuck::Pod for RngStreams {}
+
+impl RngStreams {
+    /// Create a stream seeded with `seed`
+    ///
+    /// Equal seeds produce equal sequences. Returns the stream handle, or
+    /// `None` if every slot is in use.
+    pub fn create(&mut self, seed: u64) -> Option<u32> {
+        let index = (0..MAX_RNG_STREAMS).find(|&i| self.active & (1 << i) == 0)?;
+        self.active |= 1 << index;
+        self.states[index] = splitmix64(seed);
+        Some(index as u32 + 1)
+    }
+
+    /// Free a stream. Returns `false` if the handle isn't active.
+    pub fn free(&mut self, handle: u32) -> bool {
+        let Some(index) = self.index(handle) else {
+            return false;
+        };
+        self.active &= !(1 << index);
+        self.states[index] = 0;
+        true
+    }
+
+    /// Next u32 from a stream, or `None` if the handle isn't active
+    pub fn next(&mut self, handle: u32) -> Option<u32> {
+        let index = self.index(handle)?;
+        Some(pcg32(&mut self.states[index]))
+    }
+
+    /// Number of streams in use
+    pub fn active_count(&self) -> usize {
+        self.active.count_ones() as usize
+    }
+
+    fn index(&self, handle: u32) -> Option<usize> {
+        let index = handle.checked_sub(1)? as usize;
+        (index < MAX_RNG_STREAMS && self.active & (1 << index) != 0).then_some(index)
+    }
+}
+
+/// Advance a PCG-XSH-RR state and return the next u32
+pub fn pcg32(state: &mut u64) -> u32 {
+    let old_state = *state;
+    *state = old_state
+        .wrapping_mul(6364136223846793005)
+        .wrapping_add(1442695040888963407);
+    let xor_shifted = (((old_state >> 18) ^ old_state) >> 27) as u32;
+    let rot = (old_state >> 59) as u32;
+    xor_shifted.rotate_right(rot)
+}
+
+/// SplitMix64 finalizer: scrambles a seed so nearby seeds (0, 1, 2...) start
+/// far apart
+pub(crate) fn splitmix64(seed: u64) -> u64 {
+    let mut z = seed.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn draw(streams: &mut RngStreams, handle: u32, n: usize) -> Vec<u32> {
+        (0..n).map(|_| streams.next(handle).unwrap()).collect()
+    }
+
+    #[test]
+    fn test_same_seed_same_sequence() {
+        let mut streams = RngStreams::default();
+        let a = streams.create(42).unwrap();
+        let b = streams.create(42).unwrap();
+        let c = streams.create(43).unwrap();
+        let seq_a = draw(&mut streams, a, 8);
+        assert_eq!(seq_a, draw(&mut streams, b, 8));
+        assert_ne!(seq_a, draw(&mut streams, c, 8));
+    }
+
+    #[test]
+    fn test_streams_are_independent() {
+        let mut streams = RngStreams::default();
+        let gameplay = streams.create(7).unwrap();
+        let cosmetic = streams.create(8).unwrap();
+        let expected = {
+            let mut alone = RngStreams::default();
+            let h = alone.create(7).unwrap();
+            draw(&mut alone, h, 4)
+        };
+
+        // Extra cosmetic draws between gameplay draws don't shift them
+        let mut seq = Vec::new();
+        for _ in 0..4 {
+            seq.push(streams.next(gameplay).unwrap());
+            draw(&mut streams, cosmetic, 3);
+        }
+        assert_eq!(seq, expected);
+    }
+
+    #[test]
+    fn test_invalid_handles() {
+        let mut streams = RngStreams::default();
+        assert_eq!(streams.next(0), None);
+        assert_eq!(streams.next(1), None);
+        assert_eq!(streams.next(MAX_RNG_STREAMS as u32 + 1), None);
+
+        let a = streams.create(1).unwrap();
+        assert!(streams.free(a));
+        assert!(!streams.free(a));
+        assert_eq!(streams.next(a), None);
+    }
+
+    #[test]
+    fn test_table_full_and_reuse() {
+        let mut streams = RngStreams::default();
+        for i in 0..MAX_RNG_STREAMS as u32 {
+            assert_eq!(streams.create(i as u64), Some(i + 1));
+        }
+        assert_eq!(streams.create(99), None);
+        assert_eq!(streams.active_count(), MAX_RNG_STREAMS);
+
+        // Freed slot is reused first
+        assert!(streams.free(3));
+        assert_eq!(streams.create(5), Some(3));
+    }
+}
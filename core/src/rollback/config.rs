@@ -21,6 +21,34 @@ pub const DEFAULT_INPUT_DELAY: usize = 0;
 /// Default input delay for online play (balance between responsiveness and rollbacks)
 pub const DEFAULT_ONLINE_INPUT_DELAY: usize = 2;
 
+/// Player count above which sessions use the large-session tuning
+///
+/// Every rollback frame re-simulates all players, and with more peers a
+/// misprediction is more likely each frame. Sessions larger than this trade one
+/// extra frame of input delay for a shorter prediction window.
+pub const LARGE_SESSION_PLAYERS: usize = 4;
+
+/// Maximum prediction frames for sessions larger than [`LARGE_SESSION_PLAYERS`]
+pub const LARGE_SESSION_ROLLBACK_FRAMES: usize = 6;
+
+/// Recommended prediction window for a session with `num_players` players
+pub const fn prediction_window_for(num_players: usize) -> usize {
+    if num_players > LARGE_SESSION_PLAYERS {
+        LARGE_SESSION_ROLLBACK_FRAMES
+    } else {
+        MAX_ROLLBACK_FRAMES
+    }
+}
+
+/// Recommended online input delay for a session with `num_players` players
+pub const fn online_input_delay_for(num_players: usize) -> usize {
+    if num_players > LARGE_SESSION_PLAYERS {
+        DEFAULT_ONLINE_INPUT_DELAY + 1
+    } else {
+        DEFAULT_ONLINE_INPUT_DELAY
+    }
+}
+
 /// Default maximum state buffer size (4MB fallback).
 ///
 /// Consoles should use their specific RAM limit from `ConsoleSpecs::ram_limit`
@@ -96,10 +124,14 @@ impl SessionConfig {
     }
 
     /// Create config for online play
+    ///
+    /// Prediction window and input delay are tuned for the player count
+    /// (see [`prediction_window_for`] and [`online_input_delay_for`]).
     pub fn online(num_players: usize) -> Self {
         Self {
             num_players,
-            input_delay: DEFAULT_ONLINE_INPUT_DELAY,
+            input_delay: online_input_delay_for(num_players),
+            max_prediction_frames: prediction_window_for(num_players),
             ..Default::default()
         }
     }
@@ -163,6 +195,17 @@ mod tests {
         assert_eq!(config.input_delay, DEFAULT_ONLINE_INPUT_DELAY);
     }
 
+    #[test]
+    fn test_session_config_online_large_session() {
+        let config = SessionConfig::online(8);
+        assert_eq!(config.num_players, 8);
+        assert_eq!(config.input_delay, DEFAULT_ONLINE_INPUT_DELAY + 1);
+        assert_eq!(config.max_prediction_frames, LARGE_SESSION_ROLLBACK_FRAMES);
+
+        let config = SessionConfig::online(4);
+        assert_eq!(config.max_prediction_frames, MAX_ROLLBACK_FRAMES);
+    }
+
     #[test]
     fn test_session_config_sync_test() {
         let config = SessionConfig::sync_test();
@@ -3,8 +3,12 @@
 //! Contains high-level session events, connection quality assessment,
 //! network statistics, and session error types.
 
+use std::time::Duration;
+
 use ggrs::GgrsError;
 
+use crate::net::nat::ConnectionPath;
+
 /// High-level session events for the application layer
 ///
 /// These are translated from raw GGRS events into actionable events
@@ -95,6 +99,8 @@ pub struct PlayerNetworkStats {
     pub quality: ConnectionQuality,
     /// Whether this player is currently connected
     pub connected: bool,
+    /// How traffic reaches this player (direct, hole punched, or relayed)
+    pub path: ConnectionPath,
 }
 
 impl PlayerNetworkStats {
@@ -114,6 +120,56 @@ impl PlayerNetworkStats {
     }
 }
 
+/// Rollback snapshot statistics
+///
+/// Measures what delta snapshots save over storing the full state every
+/// tick. Every snapshot GGRS restores is cloned first, so the stored bytes
+/// are also the bytes copied per rollback.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SnapshotStats {
+    /// Snapshots saved this session
+    pub saves: u64,
+    /// Saved snapshots that became a new keyframe
+    pub keyframes: u64,
+    /// Snapshots restored this session
+    pub loads: u64,
+    /// Bytes the saved snapshots would have taken in full
+    pub full_bytes: u64,
+    /// Bytes actually stored (changed pages plus each keyframe once)
+    pub stored_bytes: u64,
+    /// Time spent saving snapshots, including delta encoding
+    pub save_time: Duration,
+    /// Time spent restoring snapshots, including rebuilding from deltas
+    pub load_time: Duration,
+}
+
+impl SnapshotStats {
+    /// Fraction of snapshot memory saved by deltas (0.0-1.0)
+    pub fn savings(&self) -> f32 {
+        if self.full_bytes == 0 {
+            return 0.0;
+        }
+        1.0 - self.stored_bytes as f32 / self.full_bytes as f32
+    }
+
+    /// Average time per save in microseconds
+    pub fn avg_save_micros(&self) -> f32 {
+        average_micros(self.save_time, self.saves)
+    }
+
+    /// Average time per restore in microseconds
+    pub fn avg_load_micros(&self) -> f32 {
+        average_micros(self.load_time, self.loads)
+    }
+}
+
+fn average_micros(total: Duration, count: u64) -> f32 {
+    if count == 0 {
+        return 0.0;
+    }
+    total.as_secs_f32() * 1_000_000.0 / count as f32
+}
+
 /// Session errors
 #[derive(Debug, Clone)]
 pub enum SessionError {
@@ -123,6 +179,10 @@ pub enum SessionError {
     LoadState(String),
     /// GGRS error
     Ggrs(String),
+    /// Error during a re-simulated update
+    Update(String),
+    /// Game state written by `predict_input()` couldn't be restored
+    Predict(String),
     /// Desync detected
     Desync {
         frame: i32,
@@ -137,6 +197,8 @@ impl std::fmt::Display for SessionError {
             Self::SaveState(e) => write!(f, "Failed to save state: {}", e),
             Self::LoadState(e) => write!(f, "Failed to load state: {}", e),
             Self::Ggrs(e) => write!(f, "GGRS error: {}", e),
+            Self::Update(e) => write!(f, "Re-simulated update failed: {}", e),
+            Self::Predict(e) => write!(f, "Input prediction failed: {}", e),
             Self::Desync {
                 frame,
                 local_checksum,
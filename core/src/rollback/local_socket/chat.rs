@@ -0,0 +1,57 @@
+//! Text chat transport
+//!
+//! Chat packets ride the same socket as GGRS messages, exactly like voice
+//! (see `voice.rs`): outgoing packets are flushed when GGRS polls the socket
+//! and incoming chat datagrams are handed to the [`ChatChannel`] first.
+
+use std::io;
+
+use super::socket::LocalSocket;
+use crate::net::chat::{ChatChannel, ChatPacket};
+
+impl LocalSocket {
+    /// Attach a chat channel to this socket
+    ///
+    /// Must be called before the socket is handed to GGRS.
+    pub fn attach_chat(&mut self, chat: ChatChannel) {
+        self.chat = Some(chat);
+    }
+
+    /// Get the attached chat channel, if any
+    pub fn chat(&self) -> Option<&ChatChannel> {
+        self.chat.as_ref()
+    }
+
+    /// Send queued chat packets to all known peers
+    pub(super) fn flush_chat(&mut self) {
+        let Some(chat) = &self.chat else {
+            return;
+        };
+
+        for packet in chat.take_outgoing() {
+            for peer in &self.side_peers {
+                if let Err(e) = self.send_raw(&packet, *peer)
+                    && e.kind() != io::ErrorKind::WouldBlock
+                {
+                    tracing::warn!(error = %e, "Failed to send chat packet");
+                }
+            }
+        }
+    }
+
+    /// Route an incoming datagram to the chat channel if it is chat
+    ///
+    /// Returns `true` if the datagram was consumed.
+    pub(super) fn handle_chat(&self, data: &[u8]) -> bool {
+        if !ChatPacket::is_chat_packet(data) {
+            return false;
+        }
+
+        if let Some(chat) = &self.chat
+            && !chat.receive_packet(data)
+        {
+            tracing::warn!("Malformed chat packet");
+        }
+        true
+    }
+}
@@ -5,6 +5,7 @@ use std::net::SocketAddr;
 
 use ggrs::NonBlockingSocket;
 
+use super::relay::recv_unwrapped;
 use super::socket::LocalSocket;
 
 impl NonBlockingSocket<String> for LocalSocket {
@@ -18,6 +19,8 @@ impl NonBlockingSocket<String> for LocalSocket {
             }
         };
 
+        self.track_peer(target);
+
         // Serialize the GGRS message
         let data = match bincode::serialize(msg) {
             Ok(d) => d,
@@ -27,8 +30,8 @@ impl NonBlockingSocket<String> for LocalSocket {
             }
         };
 
-        // Send immediately
-        if let Err(e) = self.socket.send_to(&data, target) {
+        // Send immediately (wrapped for the relay if this peer is relayed)
+        if let Err(e) = self.send_raw(&data, target) {
             // WouldBlock is expected for non-blocking sockets when buffer is full
             if e.kind() != io::ErrorKind::WouldBlock {
                 tracing::warn!(error = %e, "Failed to send message");
@@ -39,10 +42,19 @@ impl NonBlockingSocket<String> for LocalSocket {
     fn receive_all_messages(&mut self) -> Vec<(String, ggrs::Message)> {
         let mut messages = Vec::new();
 
+        self.keep_relay_alive();
+        self.flush_voice();
+        self.flush_chat();
+
         // Read all available messages
         loop {
-            match self.socket.recv_from(&mut self.recv_buf) {
-                Ok((len, from)) => {
+            match recv_unwrapped(&self.socket, self.relay_addr, &mut self.recv_buf) {
+                Ok((len, from, _path)) => {
+                    let data = &self.recv_buf[..len];
+                    if self.handle_voice(data) || self.handle_chat(data) {
+                        continue;
+                    }
+
                     // Deserialize the GGRS message
                     match bincode::deserialize::<ggrs::Message>(&self.recv_buf[..len]) {
                         Ok(msg) => {
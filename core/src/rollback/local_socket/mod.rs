@@ -25,15 +25,38 @@
 //! let session = RollbackSession::<ZInput>::new_p2p(config, socket, players)?;
 //! ```
 //!
+//! # Relay Fallback
+//!
+//! When hole punching fails, peers can be routed through a relay server
+//! (see [`crate::net::nat`]):
+//!
+//! ```ignore
+//! socket.enable_relay(relay_addr);
+//! socket.route_via_relay(peer_addr);
+//! ```
+//!
+//! # Voice and Text Chat
+//!
+//! An attached [`crate::net::voice::VoiceChannel`] or
+//! [`crate::net::chat::ChatChannel`] shares the socket with GGRS; their
+//! datagrams are filtered out before GGRS deserializes anything:
+//!
+//! ```ignore
+//! socket.attach_voice(voice.clone());
+//! socket.attach_chat(chat.clone());
+//! ```
+//!
 //! # Limitations
 //!
-//! - No NAT traversal (localhost only)
-//! - No ICE/STUN/TURN
+//! - No STUN/candidate exchange here; NCHS gathers candidates during the handshake
 //! - Simple point-to-point (no mesh networking for >2 players without manual port assignment)
 
+mod chat;
 mod error;
 mod ggrs_impl;
+mod relay;
 mod socket;
+mod voice;
 
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,127 @@
+//! Relay fallback routing
+//!
+//! Peers that couldn't be reached by hole punching are routed through a
+//! relay server. Outgoing datagrams to those peers are wrapped in a
+//! [`RelayPacket::Data`] envelope and sent to the relay; incoming envelopes
+//! from the relay are unwrapped so callers (and GGRS) see the original
+//! sender address.
+
+use std::io;
+use std::net::SocketAddr;
+use std::time::{Duration, Instant};
+
+use super::socket::LocalSocket;
+use crate::net::nat::{ConnectionPath, RelayPacket};
+
+/// How often to refresh the relay allocation (keeps the NAT binding open)
+const RELAY_KEEPALIVE_INTERVAL: Duration = Duration::from_secs(5);
+
+impl LocalSocket {
+    /// Enable relay fallback through the given relay server
+    ///
+    /// Registers with the relay immediately. Peers still need to be routed
+    /// explicitly with [`LocalSocket::route_via_relay`].
+    pub fn enable_relay(&mut self, relay: SocketAddr) {
+        tracing::info!(port = relay.port(), "LocalSocket relay enabled");
+        self.relay_addr = Some(relay);
+        self.last_relay_keepalive = None;
+        self.keep_relay_alive();
+    }
+
+    /// Get the relay server address, if relay fallback is enabled
+    pub fn relay_addr(&self) -> Option<SocketAddr> {
+        self.relay_addr
+    }
+
+    /// Route all traffic for `peer` through the relay
+    ///
+    /// Has no effect until [`LocalSocket::enable_relay`] has been called.
+    pub fn route_via_relay(&mut self, peer: SocketAddr) {
+        if self.relay_addr.is_none() {
+            tracing::warn!("route_via_relay called without a relay; ignoring");
+            return;
+        }
+        self.relayed_peers.insert(peer);
+    }
+
+    /// Get the connection path currently used for `peer`
+    pub fn connection_path(&self, peer: &SocketAddr) -> ConnectionPath {
+        if self.relayed_peers.contains(peer) {
+            ConnectionPath::Relayed
+        } else {
+            ConnectionPath::Direct
+        }
+    }
+
+    /// Send a datagram to `peer`, wrapping it for the relay if needed
+    pub fn send_raw(&self, data: &[u8], peer: SocketAddr) -> io::Result<usize> {
+        match self.relay_addr {
+            Some(relay) if self.relayed_peers.contains(&peer) => {
+                let packet = RelayPacket::Data {
+                    peer,
+                    payload: data,
+                }
+                .encode();
+                self.socket.send_to(&packet, relay)
+            }
+            _ => self.socket.send_to(data, peer),
+        }
+    }
+
+    /// Receive a datagram, unwrapping relay envelopes
+    ///
+    /// Returns the payload length, the original sender, and the path it
+    /// arrived on. Relay keepalive echoes are skipped.
+    pub fn recv_raw(&self, buf: &mut [u8]) -> io::Result<(usize, SocketAddr, ConnectionPath)> {
+        recv_unwrapped(&self.socket, self.relay_addr, buf)
+    }
+
+    /// Resend the relay allocation if the keepalive interval has elapsed
+    pub fn keep_relay_alive(&mut self) {
+        let Some(relay) = self.relay_addr else {
+            return;
+        };
+
+        if self
+            .last_relay_keepalive
+            .is_none_or(|t| t.elapsed() >= RELAY_KEEPALIVE_INTERVAL)
+        {
+            if let Err(e) = self.socket.send_to(&RelayPacket::Allocate.encode(), relay)
+                && e.kind() != io::ErrorKind::WouldBlock
+            {
+                tracing::warn!(error = %e, "Failed to send relay keepalive");
+            }
+            self.last_relay_keepalive = Some(Instant::now());
+        }
+    }
+}
+
+/// Receive from `socket`, unwrapping relay envelopes in place
+pub(super) fn recv_unwrapped(
+    socket: &std::net::UdpSocket,
+    relay: Option<SocketAddr>,
+    buf: &mut [u8],
+) -> io::Result<(usize, SocketAddr, ConnectionPath)> {
+    loop {
+        let (len, from) = socket.recv_from(buf)?;
+
+        if Some(from) != relay {
+            return Ok((len, from, ConnectionPath::Direct));
+        }
+
+        match RelayPacket::decode(&buf[..len]) {
+            Some(RelayPacket::Data { peer, payload }) => {
+                let offset = len - payload.len();
+                let payload_len = payload.len();
+                buf.copy_within(offset..len, 0);
+                return Ok((payload_len, peer, ConnectionPath::Relayed));
+            }
+            Some(RelayPacket::Allocate) => {
+                // Keepalive echo from the relay
+            }
+            None => {
+                tracing::warn!("Malformed packet from relay");
+            }
+        }
+    }
+}
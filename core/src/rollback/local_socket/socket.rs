@@ -1,9 +1,12 @@
 //! Core UDP socket implementation for local P2P testing
 
+use std::collections::HashSet;
 use std::net::{IpAddr, Ipv4Addr, SocketAddr, UdpSocket};
 use std::time::{Duration, Instant};
 
 use super::error::LocalSocketError;
+use crate::net::chat::ChatChannel;
+use crate::net::voice::VoiceChannel;
 
 /// Default port for local testing
 pub const DEFAULT_LOCAL_PORT: u16 = 7777;
@@ -25,6 +28,18 @@ pub struct LocalSocket {
     pub(super) peer_addr: Option<SocketAddr>,
     /// Receive buffer
     pub(super) recv_buf: Vec<u8>,
+    /// Relay server address (if relay fallback is enabled)
+    pub(super) relay_addr: Option<SocketAddr>,
+    /// Peers whose traffic is routed through the relay
+    pub(super) relayed_peers: HashSet<SocketAddr>,
+    /// Last time we sent a relay keepalive
+    pub(super) last_relay_keepalive: Option<Instant>,
+    /// Voice channel sharing this socket (if voice chat is attached)
+    pub(super) voice: Option<VoiceChannel>,
+    /// Text chat channel sharing this socket (if chat is attached)
+    pub(super) chat: Option<ChatChannel>,
+    /// Peers GGRS has sent to; they also receive voice and chat traffic
+    pub(super) side_peers: HashSet<SocketAddr>,
 }
 
 impl LocalSocket {
@@ -65,6 +80,12 @@ impl LocalSocket {
             local_addr,
             peer_addr: None,
             recv_buf: vec![0u8; RECV_BUFFER_SIZE],
+            relay_addr: None,
+            relayed_peers: HashSet::new(),
+            last_relay_keepalive: None,
+            voice: None,
+            chat: None,
+            side_peers: HashSet::new(),
         })
     }
 
@@ -139,6 +160,13 @@ impl LocalSocket {
         &self.socket
     }
 
+    /// Remember a peer so voice and chat traffic is sent to it too
+    pub(super) fn track_peer(&mut self, peer: SocketAddr) {
+        if self.voice.is_some() || self.chat.is_some() {
+            self.side_peers.insert(peer);
+        }
+    }
+
     /// Wait for a peer to connect (blocking with timeout)
     ///
     /// Blocks until a packet is received from any peer, then sets that
@@ -205,14 +233,15 @@ impl LocalSocket {
     ///
     /// # Returns
     ///
-    /// The peer's address if a connection was detected, or `None`.
-    pub fn poll_for_peer(&mut self) -> Option<String> {
+    /// The peer's address and the packet it sent if a connection was
+    /// detected, or `None`.
+    pub fn poll_for_peer(&mut self) -> Option<(String, Vec<u8>)> {
         let mut buf = [0u8; 128];
         match self.socket.recv_from(&mut buf) {
-            Ok((_len, from)) => {
+            Ok((len, from)) => {
                 tracing::info!("Peer connected");
                 self.peer_addr = Some(from);
-                Some(from.to_string())
+                Some((from.to_string(), buf[..len].to_vec()))
             }
             Err(_) => None,
         }
@@ -257,6 +286,9 @@ impl std::fmt::Debug for LocalSocket {
         f.debug_struct("LocalSocket")
             .field("local_addr", &self.local_addr)
             .field("peer_addr", &self.peer_addr)
+            .field("relay_addr", &self.relay_addr)
+            .field("voice", &self.voice.is_some())
+            .field("chat", &self.chat.is_some())
             .finish()
     }
 }
@@ -1,11 +1,16 @@
 //! Tests for local socket implementation
 
-use std::time::Duration;
+use std::io;
+use std::net::SocketAddr;
+use std::time::{Duration, Instant};
 
 use ggrs::NonBlockingSocket;
 
 use super::error::LocalSocketError;
 use super::socket::LocalSocket;
+use crate::net::chat::ChatChannel;
+use crate::net::nat::{ConnectionPath, RelayPacket};
+use crate::net::voice::{VOICE_FRAME_SAMPLES, VOICE_SAMPLE_RATE, VoiceChannel};
 
 #[test]
 fn test_local_socket_bind() {
@@ -277,3 +282,137 @@ fn test_timeout_error_display() {
         display
     );
 }
+
+// =============================================================================
+// Relay routing
+// =============================================================================
+
+#[test]
+fn test_relayed_send_and_receive() {
+    // A fake relay that just records what it receives
+    let relay = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+    relay
+        .set_read_timeout(Some(Duration::from_millis(500)))
+        .unwrap();
+    let relay_addr = relay.local_addr().unwrap();
+
+    let mut socket = LocalSocket::bind("127.0.0.1:0").unwrap();
+    let peer: SocketAddr = "203.0.113.7:7771".parse().unwrap();
+    socket.enable_relay(relay_addr);
+    socket.route_via_relay(peer);
+    assert_eq!(socket.connection_path(&peer), ConnectionPath::Relayed);
+
+    let mut buf = [0u8; 256];
+
+    // First packet is the allocation
+    let (len, _) = relay.recv_from(&mut buf).unwrap();
+    assert_eq!(
+        RelayPacket::decode(&buf[..len]),
+        Some(RelayPacket::Allocate)
+    );
+
+    // Outgoing data is wrapped with the destination peer
+    socket.send_raw(b"hello", peer).unwrap();
+    let (len, from) = relay.recv_from(&mut buf).unwrap();
+    assert_eq!(from, socket.local_addr());
+    assert_eq!(
+        RelayPacket::decode(&buf[..len]),
+        Some(RelayPacket::Data {
+            peer,
+            payload: b"hello"
+        })
+    );
+
+    // Incoming data from the relay is unwrapped to the original sender
+    let reply = RelayPacket::Data {
+        peer,
+        payload: b"world",
+    }
+    .encode();
+    relay.send_to(&reply, socket.local_addr()).unwrap();
+
+    let start = Instant::now();
+    loop {
+        match socket.recv_raw(&mut buf) {
+            Ok((len, from, path)) => {
+                assert_eq!(&buf[..len], b"world");
+                assert_eq!(from, peer);
+                assert_eq!(path, ConnectionPath::Relayed);
+                break;
+            }
+            Err(e) if e.kind() == io::ErrorKind::WouldBlock => {
+                assert!(start.elapsed() < Duration::from_secs(1), "no relay reply");
+                std::thread::sleep(Duration::from_millis(5));
+            }
+            Err(e) => panic!("recv failed: {}", e),
+        }
+    }
+}
+
+#[test]
+fn test_route_without_relay_is_ignored() {
+    let mut socket = LocalSocket::bind("127.0.0.1:0").unwrap();
+    let peer: SocketAddr = "127.0.0.1:9".parse().unwrap();
+    socket.route_via_relay(peer);
+    assert_eq!(socket.connection_path(&peer), ConnectionPath::Direct);
+}
+
+#[test]
+fn test_voice_packets_bypass_ggrs() {
+    let mut sender = LocalSocket::bind("127.0.0.1:0").unwrap();
+    let mut receiver = LocalSocket::bind("127.0.0.1:0").unwrap();
+
+    let voice_tx = VoiceChannel::new(0);
+    let voice_rx = VoiceChannel::new(1);
+    voice_tx.set_enabled(true);
+    voice_rx.set_enabled(true);
+    sender.attach_voice(voice_tx.clone());
+    receiver.attach_voice(voice_rx.clone());
+    sender.track_peer(receiver.local_addr());
+
+    let tone: Vec<f32> = (0..VOICE_FRAME_SAMPLES)
+        .map(|i| (i as f32 * 0.3).sin() * 0.5)
+        .collect();
+    voice_tx.push_capture(&tone, 1, VOICE_SAMPLE_RATE);
+
+    // Voice is flushed when GGRS polls the socket
+    assert!(sender.receive_all_messages().is_empty());
+
+    let start = Instant::now();
+    while !voice_rx.is_speaking(0) {
+        assert!(
+            start.elapsed() < Duration::from_secs(1),
+            "no voice received"
+        );
+        // Voice datagrams never reach GGRS
+        assert!(receiver.receive_all_messages().is_empty());
+        std::thread::sleep(Duration::from_millis(5));
+    }
+}
+
+#[test]
+fn test_chat_packets_bypass_ggrs() {
+    let mut sender = LocalSocket::bind("127.0.0.1:0").unwrap();
+    let mut receiver = LocalSocket::bind("127.0.0.1:0").unwrap();
+
+    let chat_tx = ChatChannel::new(0);
+    let chat_rx = ChatChannel::new(1);
+    sender.attach_chat(chat_tx.clone());
+    receiver.attach_chat(chat_rx.clone());
+    sender.track_peer(receiver.local_addr());
+
+    chat_tx.send_text("gg");
+    assert!(sender.receive_all_messages().is_empty());
+
+    let start = Instant::now();
+    let message = loop {
+        assert!(receiver.receive_all_messages().is_empty());
+        if let Some(message) = chat_rx.poll_message() {
+            break message;
+        }
+        assert!(start.elapsed() < Duration::from_secs(1), "no chat received");
+        std::thread::sleep(Duration::from_millis(5));
+    };
+    assert_eq!(message.sender, 0);
+    assert_eq!(message.text, "gg");
+}
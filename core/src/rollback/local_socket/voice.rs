@@ -0,0 +1,59 @@
+//! Voice chat transport
+//!
+//! Voice packets ride the same socket as GGRS messages. Outgoing frames are
+//! flushed to every peer GGRS has talked to whenever GGRS polls the socket,
+//! and incoming voice datagrams are handed to the [`VoiceChannel`] before
+//! GGRS sees them.
+
+use std::io;
+
+use super::socket::LocalSocket;
+use crate::net::voice::{VoiceChannel, VoicePacket};
+
+impl LocalSocket {
+    /// Attach a voice channel to this socket
+    ///
+    /// Must be called before the socket is handed to GGRS. The channel stays
+    /// silent until the game enables voice.
+    pub fn attach_voice(&mut self, voice: VoiceChannel) {
+        self.voice = Some(voice);
+    }
+
+    /// Get the attached voice channel, if any
+    pub fn voice(&self) -> Option<&VoiceChannel> {
+        self.voice.as_ref()
+    }
+
+    /// Send queued voice frames to all known peers
+    pub(super) fn flush_voice(&mut self) {
+        let Some(voice) = &self.voice else {
+            return;
+        };
+
+        for packet in voice.take_outgoing() {
+            for peer in &self.side_peers {
+                if let Err(e) = self.send_raw(&packet, *peer)
+                    && e.kind() != io::ErrorKind::WouldBlock
+                {
+                    tracing::warn!(error = %e, "Failed to send voice packet");
+                }
+            }
+        }
+    }
+
+    /// Route an incoming datagram to the voice channel if it is voice
+    ///
+    /// Returns `true` if the datagram was consumed.
+    pub(super) fn handle_voice(&self, data: &[u8]) -> bool {
+        if !VoicePacket::is_voice_packet(data) {
+            return false;
+        }
+
+        if let Some(voice) = &self.voice
+            && !voice.receive_packet(data)
+        {
+            tracing::warn!("Malformed voice packet");
+        }
+        true
+    }
+}
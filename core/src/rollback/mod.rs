@@ -8,6 +8,7 @@
 //!
 //! - `NethercoreConfig<I>`: GGRS configuration parameterized by console input type
 //! - `GameStateSnapshot`: Serialized game state with checksum for desync detection
+//! - `StateDelta`: Snapshot pages that differ from a shared keyframe
 //! - `RollbackSession<I>`: Session manager for local, sync-test, and P2P modes
 //! - `StatePool`: Pre-allocated buffer pool to avoid allocations during rollback
 //! - `LocalSocket`: UDP socket for local P2P testing without signaling server
@@ -40,6 +41,7 @@
 //! - `config`: GGRS configuration types and constants
 //! - `player`: Player session configuration (local vs remote)
 //! - `state`: State snapshot and buffer pool management
+//! - `prediction`: Input prediction policies and their corrections
 //! - `session`: GGRS session wrapper and event handling
 //! - `local_socket`: UDP socket for local network testing
 
@@ -48,29 +50,36 @@ mod connection;
 mod events;
 pub mod local_socket;
 mod player;
+mod prediction;
 mod session;
 mod state;
 
 // Re-export public types from config
 pub use config::{
-    DEFAULT_INPUT_DELAY, DEFAULT_ONLINE_INPUT_DELAY, MAX_INPUT_DELAY, MAX_ROLLBACK_FRAMES,
-    MAX_STATE_SIZE, NethercoreConfig, SessionConfig,
+    DEFAULT_INPUT_DELAY, DEFAULT_ONLINE_INPUT_DELAY, LARGE_SESSION_PLAYERS,
+    LARGE_SESSION_ROLLBACK_FRAMES, MAX_INPUT_DELAY, MAX_ROLLBACK_FRAMES, MAX_STATE_SIZE,
+    NethercoreConfig, SessionConfig, online_input_delay_for, prediction_window_for,
 };
 
+// Re-export public types from prediction
+pub use prediction::PredictPolicy;
+
 // Re-export public types from player
 pub use player::{MAX_PLAYERS, PlayerSessionConfig};
 
 // Re-export public types from state
 pub use state::{
-    GameStateSnapshot, HOST_STATE_SIZE, HostRollbackState, LoadStateError, RollbackStateManager,
-    STATE_POOL_SIZE, SaveStateError, StatePool,
+    ConsoleDataVec, DELTA_PAGE_SIZE, GameStateSnapshot, HOST_STATE_SIZE, HostRollbackState,
+    LoadStateError, RollbackStateManager, STATE_POOL_SIZE, SaveStateError, StateDelta, StatePool,
 };
 
 // Re-export public types from session
 pub use session::{NetworkInput, RollbackSession, SessionType};
 
 // Re-export public types from events
-pub use events::{ConnectionQuality, PlayerNetworkStats, SessionError, SessionEvent};
+pub use events::{
+    ConnectionQuality, PlayerNetworkStats, SessionError, SessionEvent, SnapshotStats,
+};
 
 // Re-export public types from local_socket
 pub use local_socket::{DEFAULT_LOCAL_PORT, LocalSocket, LocalSocketError};
@@ -3,7 +3,7 @@
 //! Manages local vs remote player assignments for multiplayer sessions.
 
 /// Maximum number of players in a session
-pub const MAX_PLAYERS: usize = 4;
+pub const MAX_PLAYERS: usize = crate::wasm::MAX_PLAYERS;
 
 /// Configuration for player assignments in a multiplayer session
 ///
@@ -32,7 +32,7 @@ pub const MAX_PLAYERS: usize = 4;
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct PlayerSessionConfig {
-    /// Total number of players (1-4)
+    /// Total number of players (1-8)
     num_players: u32,
     /// Bitmask indicating which players are local (bit N = player N is local)
     local_player_mask: u32,
@@ -42,15 +42,15 @@ impl PlayerSessionConfig {
     /// Create a new player session configuration
     ///
     /// # Arguments
-    /// * `num_players` - Total players in session (1-4)
+    /// * `num_players` - Total players in session (1-8)
     /// * `local_player_mask` - Bitmask of local players (e.g., 0b0011 = players 0 and 1)
     ///
     /// # Panics
-    /// Panics if `num_players` is 0 or greater than 4.
+    /// Panics if `num_players` is 0 or greater than 8.
     pub fn new(num_players: u32, local_player_mask: u32) -> Self {
         assert!(
             num_players >= 1 && num_players <= MAX_PLAYERS as u32,
-            "num_players must be 1-4, got {}",
+            "num_players must be 1-8, got {}",
             num_players
         );
 
@@ -71,7 +71,7 @@ impl PlayerSessionConfig {
     pub fn all_local(num_players: u32) -> Self {
         assert!(
             num_players >= 1 && num_players <= MAX_PLAYERS as u32,
-            "num_players must be 1-4, got {}",
+            "num_players must be 1-8, got {}",
             num_players
         );
         Self {
@@ -87,7 +87,7 @@ impl PlayerSessionConfig {
     pub fn one_local(num_players: u32) -> Self {
         assert!(
             num_players >= 1 && num_players <= MAX_PLAYERS as u32,
-            "num_players must be 1-4, got {}",
+            "num_players must be 1-8, got {}",
             num_players
         );
         Self {
@@ -116,7 +116,7 @@ impl PlayerSessionConfig {
     pub fn with_local_players(num_players: u32, local_players: &[usize]) -> Self {
         assert!(
             num_players >= 1 && num_players <= MAX_PLAYERS as u32,
-            "num_players must be 1-4, got {}",
+            "num_players must be 1-8, got {}",
             num_players
         );
 
@@ -319,14 +319,23 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(expected = "num_players must be 1-4")]
+    fn test_player_session_config_one_local_8p() {
+        let config = PlayerSessionConfig::one_local(8);
+        assert_eq!(config.num_players(), 8);
+        assert_eq!(config.local_player_count(), 1);
+        assert_eq!(config.remote_player_count(), 7);
+        assert_eq!(config.remote_player_indices(), vec![1, 2, 3, 4, 5, 6, 7]);
+    }
+
+    #[test]
+    #[should_panic(expected = "num_players must be 1-8")]
     fn test_player_session_config_zero_players_panics() {
         PlayerSessionConfig::all_local(0);
     }
 
     #[test]
-    #[should_panic(expected = "num_players must be 1-4")]
-    fn test_player_session_config_five_players_panics() {
-        PlayerSessionConfig::all_local(5);
+    #[should_panic(expected = "num_players must be 1-8")]
+    fn test_player_session_config_nine_players_panics() {
+        PlayerSessionConfig::all_local(9);
     }
 }
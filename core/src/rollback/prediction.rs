@@ -0,0 +1,243 @@
+//! Input prediction policies
+//!
+//! GGRS predicts that a remote player keeps doing whatever they did on the
+//! last tick it received. That suits movement, but a digital dodge or dash
+//! predicted that way repeats until the real input arrives and the rollback
+//! snaps the player back. Games can pick a different policy per player, or
+//! supply predictions themselves through a `predict_input()` export.
+//!
+//! GGRS only rolls back when the real input differs from its own prediction.
+//! When the game was fed a different prediction, the session re-simulates
+//! from that tick itself once the tick is confirmed.
+
+use std::collections::VecDeque;
+
+use ggrs::{GameStateCell, InputStatus};
+
+use crate::console::ConsoleInput;
+
+use super::state::GameStateSnapshot;
+
+/// How a remote player's input is predicted while it is in flight
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[repr(u8)]
+pub enum PredictPolicy {
+    /// Repeat the last received input (GGRS default)
+    #[default]
+    Hold = 0,
+    /// Release every button and center the analog controls
+    Neutral = 1,
+    /// Ask the game's `predict_input()` export
+    Custom = 2,
+}
+
+impl PredictPolicy {
+    pub fn from_u32(value: u32) -> Option<Self> {
+        match value {
+            0 => Some(Self::Hold),
+            1 => Some(Self::Neutral),
+            2 => Some(Self::Custom),
+            _ => None,
+        }
+    }
+}
+
+/// One simulated tick that may still be corrected
+pub(crate) struct LoggedTick<I: ConsoleInput> {
+    /// GGRS frame number
+    pub frame: i32,
+    /// State saved at the start of the tick
+    pub cell: Option<GameStateCell<GameStateSnapshot>>,
+    /// Inputs from GGRS (its own predictions for players still in flight)
+    pub given: Vec<(I, InputStatus)>,
+    /// Inputs the game was actually fed
+    pub fed: Vec<I>,
+}
+
+impl<I: ConsoleInput> LoggedTick<I> {
+    /// Whether the game saw a prediction other than the one GGRS checks against
+    fn diverged(&self) -> bool {
+        self.given
+            .iter()
+            .zip(&self.fed)
+            .any(|((given, _), fed)| given != fed)
+    }
+}
+
+/// Ticks simulated since the last confirmed frame, in frame order
+pub(crate) struct PredictionLog<I: ConsoleInput> {
+    ticks: VecDeque<LoggedTick<I>>,
+    /// Frame of the next `AdvanceFrame` request
+    next_frame: i32,
+}
+
+impl<I: ConsoleInput> PredictionLog<I> {
+    pub fn new() -> Self {
+        Self {
+            ticks: VecDeque::new(),
+            next_frame: 0,
+        }
+    }
+
+    /// Entry for `frame`, replacing everything from `frame` on
+    ///
+    /// Ticks are re-simulated in order after a rollback, so later entries
+    /// are stale once an earlier frame is simulated again.
+    fn entry(&mut self, frame: i32) -> &mut LoggedTick<I> {
+        let cell = self
+            .ticks
+            .iter_mut()
+            .find(|t| t.frame == frame)
+            .and_then(|t| t.cell.take());
+        self.ticks.retain(|t| t.frame < frame);
+        self.ticks.push_back(LoggedTick {
+            frame,
+            cell,
+            given: Vec::new(),
+            fed: Vec::new(),
+        });
+        self.ticks.back_mut().expect("entry was just pushed")
+    }
+
+    /// Frame of the next `AdvanceFrame` request
+    pub fn next_frame(&self) -> i32 {
+        self.next_frame
+    }
+
+    /// Record the state GGRS saved at the start of `frame`
+    pub fn saved(&mut self, frame: i32, cell: GameStateCell<GameStateSnapshot>) {
+        self.entry(frame).cell = Some(cell);
+        self.next_frame = frame;
+    }
+
+    /// Record that GGRS rolled back to the start of `frame`
+    pub fn loaded(&mut self, frame: i32) {
+        self.next_frame = frame;
+    }
+
+    /// Record the inputs of the next advanced tick, returning its frame
+    pub fn advanced(&mut self, given: &[(I, InputStatus)], fed: Vec<I>) -> i32 {
+        let frame = self.next_frame;
+        let saved = self
+            .ticks
+            .back()
+            .is_some_and(|t| t.frame == frame && t.fed.is_empty());
+        if !saved {
+            self.entry(frame);
+        }
+        let tick = self.ticks.back_mut().expect("tick was just logged");
+        tick.given = given.to_vec();
+        tick.fed = fed;
+        self.next_frame += 1;
+        frame
+    }
+
+    /// Index of the first confirmed tick the game simulated with a
+    /// prediction GGRS won't roll back
+    ///
+    /// A confirmed tick that GGRS hasn't rolled back got exactly the inputs
+    /// GGRS predicted, so the game's own prediction was wrong wherever it
+    /// differs. Ticks without a saved state can't be corrected.
+    pub fn first_mispredicted(&self, confirmed: i32) -> Option<usize> {
+        self.ticks
+            .iter()
+            .position(|t| t.frame <= confirmed && t.diverged())
+            .filter(|&index| self.ticks[index].cell.is_some())
+    }
+
+    /// Drop ticks up to and including the confirmed frame
+    pub fn prune(&mut self, confirmed: i32) {
+        while self.ticks.front().is_some_and(|t| t.frame <= confirmed) {
+            self.ticks.pop_front();
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.ticks.len()
+    }
+
+    pub fn tick(&self, index: usize) -> &LoggedTick<I> {
+        &self.ticks[index]
+    }
+
+    pub fn tick_mut(&mut self, index: usize) -> &mut LoggedTick<I> {
+        &mut self.ticks[index]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::TestInput;
+
+    fn input(buttons: u16) -> TestInput {
+        TestInput {
+            buttons,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_predict_policy_from_u32() {
+        assert_eq!(PredictPolicy::from_u32(0), Some(PredictPolicy::Hold));
+        assert_eq!(PredictPolicy::from_u32(1), Some(PredictPolicy::Neutral));
+        assert_eq!(PredictPolicy::from_u32(2), Some(PredictPolicy::Custom));
+        assert_eq!(PredictPolicy::from_u32(3), None);
+    }
+
+    #[test]
+    fn test_prediction_log_finds_diverged_ticks() {
+        let mut log = PredictionLog::<TestInput>::new();
+        let given = [
+            (input(1), InputStatus::Confirmed),
+            (input(2), InputStatus::Predicted),
+        ];
+
+        // Frame 0 fed a custom prediction, frame 1 fed what GGRS predicted
+        log.saved(0, GameStateCell::default());
+        assert_eq!(log.advanced(&given, vec![input(1), input(0)]), 0);
+        log.saved(1, GameStateCell::default());
+        assert_eq!(log.advanced(&given, vec![input(1), input(2)]), 1);
+        assert_eq!(log.len(), 2);
+        assert_eq!(log.next_frame(), 2);
+
+        // Nothing to correct until the diverged tick is confirmed
+        assert_eq!(log.first_mispredicted(-1), None);
+        assert_eq!(log.first_mispredicted(0), Some(0));
+
+        log.prune(0);
+        assert_eq!(log.len(), 1);
+        assert_eq!(log.tick(0).frame, 1);
+        assert_eq!(log.first_mispredicted(1), None);
+    }
+
+    #[test]
+    fn test_prediction_log_rollback_replaces_later_ticks() {
+        let mut log = PredictionLog::<TestInput>::new();
+        let given = [(input(0), InputStatus::Predicted)];
+
+        for frame in 0..3 {
+            log.saved(frame, GameStateCell::default());
+            log.advanced(&given, vec![input(4)]);
+        }
+        assert_eq!(log.len(), 3);
+
+        // GGRS rolls back to frame 1 and re-simulates it
+        log.loaded(1);
+        log.saved(1, GameStateCell::default());
+        assert_eq!(log.len(), 2);
+        assert_eq!(log.advanced(&given, vec![input(0)]), 1);
+        assert_eq!(log.len(), 2);
+        assert!(!log.tick(1).diverged());
+        assert!(log.tick(0).diverged());
+    }
+
+    #[test]
+    fn test_prediction_log_skips_ticks_without_state() {
+        let mut log = PredictionLog::<TestInput>::new();
+        let given = [(input(1), InputStatus::Predicted)];
+
+        log.advanced(&given, vec![input(0)]);
+        assert_eq!(log.first_mispredicted(0), None);
+    }
+}
@@ -6,9 +6,10 @@ use ggrs::{GgrsError, NonBlockingSocket, PlayerType, SessionBuilder};
 
 use crate::console::{ConsoleInput, ConsoleRollbackState};
 
-use super::super::config::{NethercoreConfig, SessionConfig};
+use super::super::config::{NethercoreConfig, SessionConfig, prediction_window_for};
 use super::super::events::PlayerNetworkStats;
 use super::super::player::{MAX_PLAYERS, PlayerSessionConfig};
+use super::super::prediction::PredictionLog;
 use super::super::state::RollbackStateManager;
 use super::rollback_session::RollbackSession;
 use super::types::{SessionInner, SessionType};
@@ -59,6 +60,9 @@ impl<I: ConsoleInput, S: Send + Default + 'static, R: ConsoleRollbackState>
             total_rollback_frames: 0,
             last_frame_advantage: 0,
             desync_detected: false,
+            voice: None,
+            chat: None,
+            predictions: PredictionLog::new(),
             _phantom: std::marker::PhantomData,
         }
     }
@@ -107,6 +111,9 @@ impl<I: ConsoleInput, S: Send + Default + 'static, R: ConsoleRollbackState>
             total_rollback_frames: 0,
             last_frame_advantage: 0,
             desync_detected: false,
+            voice: None,
+            chat: None,
+            predictions: PredictionLog::new(),
             _phantom: std::marker::PhantomData,
         })
     }
@@ -197,6 +204,9 @@ impl<I: ConsoleInput, S: Send + Default + 'static, R: ConsoleRollbackState>
             total_rollback_frames: 0,
             last_frame_advantage: 0,
             desync_detected: false,
+            voice: None,
+            chat: None,
+            predictions: PredictionLog::new(),
             _phantom: std::marker::PhantomData,
         })
     }
@@ -209,7 +219,7 @@ impl<I: ConsoleInput, S: Send + Default + 'static, R: ConsoleRollbackState>
     /// # Arguments
     ///
     /// * `session_start` - SessionStart received from NCHS handshake
-    /// * `local_handle` - Our local player handle (0-3)
+    /// * `local_handle` - Our local player handle (0-7)
     /// * `socket` - Network socket for GGRS communication
     /// * `max_state_size` - Maximum state size for rollback (usually console's RAM limit)
     ///
@@ -236,7 +246,8 @@ impl<I: ConsoleInput, S: Send + Default + 'static, R: ConsoleRollbackState>
         let nchs_config = &session_start.network_config;
         let config = SessionConfig {
             num_players: session_start.player_count as usize,
-            max_prediction_frames: nchs_config.max_rollback as usize,
+            max_prediction_frames: (nchs_config.max_rollback as usize)
+                .min(prediction_window_for(session_start.player_count as usize)),
             input_delay: nchs_config.input_delay as usize,
             fps: session_start.tick_rate.as_hz() as usize,
             disconnect_timeout: nchs_config.disconnect_timeout_ms as u64,
@@ -3,11 +3,15 @@
 use ggrs::{GgrsError, GgrsEvent, GgrsRequest, InputStatus, SessionState};
 
 use crate::console::{ConsoleInput, ConsoleRollbackState};
+use crate::net::chat::ChatChannel;
+use crate::net::nat::ConnectionPath;
+use crate::net::voice::VoiceChannel;
 use crate::wasm::GameInstance;
 
 use super::super::config::{NethercoreConfig, SessionConfig};
-use super::super::events::{PlayerNetworkStats, SessionError, SessionEvent};
+use super::super::events::{PlayerNetworkStats, SessionError, SessionEvent, SnapshotStats};
 use super::super::player::PlayerSessionConfig;
+use super::super::prediction::PredictionLog;
 use super::super::state::{
     GameStateSnapshot, LoadStateError, RollbackStateManager, SaveStateError,
 };
@@ -44,6 +48,12 @@ pub struct RollbackSession<
     pub(super) last_frame_advantage: i32,
     /// Whether a desync has been detected
     pub(super) desync_detected: bool,
+    /// Voice channel attached to the session socket (P2P only)
+    pub(super) voice: Option<VoiceChannel>,
+    /// Chat channel attached to the session socket (P2P only)
+    pub(super) chat: Option<ChatChannel>,
+    /// Ticks simulated with game-side predictions, until confirmed (P2P only)
+    pub(super) predictions: PredictionLog<I>,
     pub(super) _phantom: std::marker::PhantomData<(S, R)>,
 }
 
@@ -201,6 +211,46 @@ impl<I: ConsoleInput, S: Send + Default + 'static, R: ConsoleRollbackState>
         &self.network_stats
     }
 
+    /// Record how traffic reaches a remote player
+    ///
+    /// The socket is owned by GGRS once the session starts, so callers that
+    /// set up relay routing report the resulting path here.
+    pub fn set_connection_path(&mut self, player_handle: usize, path: ConnectionPath) {
+        if let Some(stats) = self.network_stats.get_mut(player_handle) {
+            stats.path = path;
+        }
+    }
+
+    /// Record the voice channel attached to the session socket
+    ///
+    /// Like [`Self::set_connection_path`], this exists because GGRS owns the
+    /// socket; the runner hands the channel to the game for the voice FFI.
+    pub fn set_voice_channel(&mut self, voice: VoiceChannel) {
+        self.voice = Some(voice);
+    }
+
+    /// Get the voice channel, if the session socket carries voice
+    pub fn voice_channel(&self) -> Option<&VoiceChannel> {
+        self.voice.as_ref()
+    }
+
+    /// Record the chat channel attached to the session socket
+    pub fn set_chat_channel(&mut self, chat: ChatChannel) {
+        self.chat = Some(chat);
+    }
+
+    /// Get the chat channel, if the session socket carries chat
+    pub fn chat_channel(&self) -> Option<&ChatChannel> {
+        self.chat.as_ref()
+    }
+
+    /// Get measured rollback snapshot sizes and timings
+    ///
+    /// Shows how much memory and copying delta snapshots save this session.
+    pub fn snapshot_stats(&self) -> SnapshotStats {
+        self.state_manager.stats()
+    }
+
     /// Get total rollback frames this session
     pub fn total_rollback_frames(&self) -> u64 {
         self.total_rollback_frames
@@ -368,10 +418,14 @@ impl<I: ConsoleInput, S: Send + Default + 'static, R: ConsoleRollbackState>
                         .save_state(game, frame)
                         .map_err(|e| SessionError::SaveState(e.to_string()))?;
                     let checksum = snapshot.checksum as u128;
+                    if self.is_p2p() {
+                        self.predictions.saved(frame, cell.clone());
+                    }
                     cell.save(frame, Some(snapshot), Some(checksum));
                 }
-                GgrsRequest::LoadGameState { cell, frame: _ } => {
+                GgrsRequest::LoadGameState { cell, frame } => {
                     self.rolling_back = true;
+                    self.predictions.loaded(frame);
                     if let Some(snapshot) = cell.load() {
                         self.state_manager
                             .load_state(game, &snapshot)
@@ -404,6 +458,110 @@ impl<I: ConsoleInput, S: Send + Default + 'static, R: ConsoleRollbackState>
         Ok(advance_inputs)
     }
 
+    /// Inputs to feed the game for the next advanced tick
+    ///
+    /// Replaces GGRS's predictions with each player's `predict_policy()`.
+    /// Call once per `AdvanceFrame`, in order, after `handle_requests()` has
+    /// handled the requests before it. Confirmed inputs pass through
+    /// unchanged.
+    ///
+    /// Fails if the game state can't be restored after `predict_input()`;
+    /// the session can't continue in step with its peers after that.
+    pub fn predict_inputs(
+        &mut self,
+        game: &mut GameInstance<I, S, R>,
+        inputs: &[(I, InputStatus)],
+    ) -> Result<Vec<I>, SessionError> {
+        let SessionInner::P2P(session) = &self.inner else {
+            return Ok(inputs.iter().map(|(input, _)| *input).collect());
+        };
+        let confirmed = session.confirmed_frame();
+        let frame = self.predictions.next_frame();
+        let fed = predict(game, inputs, (frame - confirmed).max(1) as u32)?;
+        self.predictions.advanced(inputs, fed.clone());
+        Ok(fed)
+    }
+
+    /// Re-simulate from the first confirmed tick that was predicted wrongly
+    ///
+    /// GGRS rolls back only when its own prediction was wrong, so a tick the
+    /// game was fed a different prediction for is corrected here once it is
+    /// confirmed. The states saved for later ticks are replaced too, so later
+    /// rollbacks start from corrected states. Call after each batch of
+    /// requests has been handled and advanced.
+    ///
+    /// Returns the number of re-simulated ticks.
+    pub fn correct_predictions(
+        &mut self,
+        game: &mut GameInstance<I, S, R>,
+        delta_time: f32,
+    ) -> Result<u32, SessionError> {
+        let SessionInner::P2P(session) = &self.inner else {
+            return Ok(0);
+        };
+        let confirmed = session.confirmed_frame();
+
+        let mut resimulated = 0u32;
+        if let Some(start) = self.predictions.first_mispredicted(confirmed) {
+            let snapshot = self
+                .predictions
+                .tick(start)
+                .cell
+                .as_ref()
+                .and_then(|cell| cell.load());
+            if let Some(snapshot) = snapshot {
+                self.state_manager
+                    .load_state(game, &snapshot)
+                    .map_err(|e| SessionError::LoadState(e.to_string()))?;
+                self.rolling_back = true;
+
+                for index in start..self.predictions.len() {
+                    let tick = self.predictions.tick(index);
+                    let frame = tick.frame;
+                    if index > start
+                        && let Some(cell) = tick.cell.clone()
+                    {
+                        let snapshot = self
+                            .state_manager
+                            .save_state(game, frame)
+                            .map_err(|e| SessionError::SaveState(e.to_string()))?;
+                        let checksum = snapshot.checksum as u128;
+                        cell.save(frame, Some(snapshot), Some(checksum));
+                    }
+
+                    // Confirmed ticks got GGRS's inputs, later ones are predicted again
+                    let given = tick.given.clone();
+                    let fed = if frame <= confirmed {
+                        given.iter().map(|(input, _)| *input).collect()
+                    } else {
+                        predict(game, &given, (frame - confirmed) as u32)?
+                    };
+                    for (player, input) in fed.iter().enumerate() {
+                        game.set_input(player, *input);
+                    }
+                    game.update(delta_time)
+                        .map_err(|e| SessionError::Update(e.to_string()))?;
+                    self.predictions.tick_mut(index).fed = fed;
+                    resimulated += 1;
+                }
+
+                self.rolling_back = false;
+                self.total_rollback_frames += resimulated as u64;
+                for stats in &mut self.network_stats {
+                    stats.rollback_frames = stats.rollback_frames.saturating_add(resimulated);
+                }
+            }
+        }
+
+        self.predictions.prune(confirmed);
+        Ok(resimulated)
+    }
+
+    /// Whether this is a P2P session
+    fn is_p2p(&self) -> bool {
+        matches!(self.inner, SessionInner::P2P(_))
+    }
+
     /// Save game state (convenience wrapper)
     pub fn save_game_state(
         &mut self,
@@ -422,3 +580,17 @@ impl<I: ConsoleInput, S: Send + Default + 'static, R: ConsoleRollbackState>
         self.state_manager.load_state(game, snapshot)
     }
 }
+
+/// Apply each player's prediction policy to GGRS's predicted inputs
+fn predict<I: ConsoleInput, S: Send + Default + 'static, R: ConsoleRollbackState>(
+    game: &mut GameInstance<I, S, R>,
+    inputs: &[(I, InputStatus)],
+    ticks: u32,
+) -> Result<Vec<I>, SessionError> {
+    let inputs: Vec<(I, bool)> = inputs
+        .iter()
+        .map(|&(input, status)| (input, status == InputStatus::Predicted))
+        .collect();
+    game.predict_inputs(&inputs, ticks)
+        .map_err(|e| SessionError::Predict(e.to_string()))
+}
@@ -4,6 +4,7 @@ use bytemuck::{Pod, Zeroable};
 use ggrs::{GgrsRequest, InputStatus};
 
 use crate::console::{Console, ConsoleInput};
+use crate::net::nat::ConnectionPath;
 use crate::rollback::ConnectionQuality;
 use crate::test_utils::TestConsole;
 
@@ -142,6 +143,7 @@ fn test_player_network_stats_default() {
     assert_eq!(stats.remote_frames_ahead, 0);
     assert_eq!(stats.rollback_frames, 0);
     assert!(!stats.connected);
+    assert_eq!(stats.path, ConnectionPath::Direct);
 }
 
 #[test]
@@ -182,6 +184,15 @@ fn test_local_session_total_rollback_frames() {
     assert_eq!(session.total_rollback_frames(), 0);
 }
 
+#[test]
+fn test_local_session_snapshot_stats() {
+    let session = RollbackSession::<TestInput, ()>::new_local(2, test_ram_limit());
+    let stats = session.snapshot_stats();
+    assert_eq!(stats.saves, 0);
+    assert_eq!(stats.savings(), 0.0);
+    assert_eq!(stats.avg_save_micros(), 0.0);
+}
+
 #[test]
 fn test_local_session_handle_events_empty() {
     let mut session = RollbackSession::<TestInput, ()>::new_local(2, test_ram_limit());
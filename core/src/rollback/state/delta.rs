@@ -0,0 +1,92 @@
+//! Page deltas against a shared keyframe
+//!
+//! Most of a game's memory (asset tables, large static arrays, free heap) is
+//! identical from one tick to the next, so copying all of it into every
+//! snapshot wastes memory and makes GGRS's snapshot clones expensive. The
+//! state manager instead keeps the last full state as a keyframe and stores
+//! each snapshot as the pages that differ from it. Once too much of the state
+//! has drifted, the next snapshot becomes the new keyframe.
+
+use std::sync::Arc;
+
+/// Granularity of delta snapshots in bytes
+pub const DELTA_PAGE_SIZE: usize = 256;
+
+/// A snapshot becomes a new keyframe once more than 1/N of its pages differ
+const REKEY_DIVISOR: usize = 4;
+
+/// WASM state stored as the pages that differ from a keyframe
+///
+/// The page contents live in the snapshot's `data`, in the order of `pages`.
+#[derive(Clone, Debug)]
+pub struct StateDelta {
+    /// Full state the pages apply to (shared until the next keyframe)
+    pub keyframe: Arc<Vec<u8>>,
+    /// Indices of the pages that differ from the keyframe, ascending
+    pub pages: Vec<u32>,
+}
+
+impl StateDelta {
+    /// Delta with no changed pages (the snapshot is the keyframe itself)
+    pub fn keyframe(keyframe: Arc<Vec<u8>>) -> Self {
+        Self {
+            keyframe,
+            pages: Vec::new(),
+        }
+    }
+
+    /// Encode `state` against `keyframe`, returning the delta and page data
+    ///
+    /// Returns `None` if the sizes differ or too much of the state changed,
+    /// in which case `state` should become the next keyframe.
+    pub fn encode(keyframe: &Arc<Vec<u8>>, state: &[u8]) -> Option<(Self, Vec<u8>)> {
+        if keyframe.len() != state.len() {
+            return None;
+        }
+
+        let page_count = state.len().div_ceil(DELTA_PAGE_SIZE);
+        let max_dirty = page_count / REKEY_DIVISOR;
+        let mut pages = Vec::new();
+        let mut data = Vec::new();
+        for (i, (old, new)) in keyframe
+            .chunks(DELTA_PAGE_SIZE)
+            .zip(state.chunks(DELTA_PAGE_SIZE))
+            .enumerate()
+        {
+            if old != new {
+                if pages.len() >= max_dirty {
+                    return None;
+                }
+                pages.push(i as u32);
+                data.extend_from_slice(new);
+            }
+        }
+
+        Some((
+            Self {
+                keyframe: Arc::clone(keyframe),
+                pages,
+            },
+            data,
+        ))
+    }
+
+    /// Rebuild the full state into `out` from the keyframe and page data
+    pub fn apply(&self, data: &[u8], out: &mut Vec<u8>) {
+        out.clear();
+        out.extend_from_slice(&self.keyframe);
+        let mut offset = 0;
+        for &page in &self.pages {
+            let start = page as usize * DELTA_PAGE_SIZE;
+            let end = (start + DELTA_PAGE_SIZE).min(out.len());
+            let len = end - start;
+            out[start..end].copy_from_slice(&data[offset..offset + len]);
+            offset += len;
+        }
+    }
+
+    /// Size of the full state this delta rebuilds
+    pub fn state_len(&self) -> usize {
+        self.keyframe.len()
+    }
+}
@@ -1,5 +1,9 @@
 //! Host-side rollback state that lives outside WASM memory
 
+use crate::event::EventQueue;
+use crate::rng::RngStreams;
+use crate::timer::TimerTable;
+
 /// Size of HostRollbackState in bytes (for inline storage)
 pub const HOST_STATE_SIZE: usize = std::mem::size_of::<HostRollbackState>();
 
@@ -18,6 +22,12 @@ pub struct HostRollbackState {
     pub elapsed_time_bits: u32,
     /// Padding for alignment
     _padding: u32,
+    /// Game-created RNG streams
+    pub rng_streams: RngStreams,
+    /// Pending game timers
+    pub timers: TimerTable,
+    /// Unread game events
+    pub events: EventQueue,
 }
 
 // SAFETY: HostRollbackState is #[repr(C)] with only primitive types
@@ -32,6 +42,9 @@ impl HostRollbackState {
             tick_count,
             elapsed_time_bits: elapsed_time.to_bits(),
             _padding: 0,
+            rng_streams: RngStreams::default(),
+            timers: TimerTable::default(),
+            events: EventQueue::default(),
         }
     }
 
@@ -1,26 +1,40 @@
 //! Rollback state manager integrating GGRS with game state saves/loads
 
+use std::sync::Arc;
+use std::time::Instant;
+
 use smallvec::SmallVec;
 
 use crate::console::{ConsoleInput, ConsoleRollbackState};
 use crate::rollback::config::MAX_STATE_SIZE;
+use crate::rollback::events::SnapshotStats;
 use crate::wasm::GameInstance;
 
+use super::delta::StateDelta;
 use super::host_state::{HOST_STATE_SIZE, HostRollbackState};
 use super::pool::StatePool;
 use super::snapshot::GameStateSnapshot;
-use super::{InputDataVec, STATE_POOL_SIZE};
+use super::{ConsoleDataVec, InputDataVec, STATE_POOL_SIZE};
 
 /// Manages game state saves and loads for GGRS rollback
 ///
 /// This struct handles the integration between GGRS requests and the
 /// `GameInstance` save/load functionality. It uses a `StatePool` to
 /// avoid allocations during the rollback hot path.
+///
+/// WASM state is stored as a [`StateDelta`] against the most recent
+/// keyframe, so each snapshot only holds the pages that changed.
 pub struct RollbackStateManager {
     /// Pre-allocated buffer pool
     pool: StatePool,
     /// Maximum state size in bytes (should match console's RAM limit)
     max_state_size: usize,
+    /// Full state new snapshots are encoded against
+    keyframe: Option<Arc<Vec<u8>>>,
+    /// Reused buffer for rebuilding full state from a delta
+    scratch: Vec<u8>,
+    /// Measured snapshot sizes and timings
+    stats: SnapshotStats,
 }
 
 impl RollbackStateManager {
@@ -34,6 +48,9 @@ impl RollbackStateManager {
         Self {
             pool: StatePool::new(max_state_size, STATE_POOL_SIZE),
             max_state_size,
+            keyframe: None,
+            scratch: Vec::new(),
+            stats: SnapshotStats::default(),
         }
     }
 
@@ -47,38 +64,50 @@ impl RollbackStateManager {
 
     /// Save the current game state
     ///
-    /// Calls `game.save_state()` to snapshot the entire WASM linear memory,
-    /// serializes the console rollback state via bytemuck, captures input state
-    /// (for button_pressed to work correctly), and host-side state (RNG, tick
-    /// count, elapsed time) for determinism.
-    /// Returns a `GameStateSnapshot` with checksum.
+    /// Calls `game.save_state()` to snapshot the WASM linear memory (or the
+    /// regions registered with `state_register()`), serializes the console
+    /// rollback state via bytemuck, captures input state (for button_pressed
+    /// to work correctly), and host-side state (RNG, tick count, elapsed time)
+    /// for determinism.
+    /// Returns a `GameStateSnapshot` with checksum. The checksum covers the
+    /// full state, so it doesn't depend on which keyframe the delta uses.
     pub fn save_state<I: ConsoleInput, S: Send + Default + 'static, R: ConsoleRollbackState>(
         &mut self,
         game: &mut GameInstance<I, S, R>,
         frame: i32,
     ) -> Result<GameStateSnapshot, SaveStateError> {
+        let started = Instant::now();
+
         // Snapshot entire WASM linear memory
         let snapshot_data = game
             .save_state()
             .map_err(|e| SaveStateError::WasmError(e.to_string()))?;
 
-        // Serialize console rollback state via bytemuck (zero-copy for POD types)
-        // SmallVec stores inline (no heap allocation) for typical console states (<512 bytes)
-        let console_data = SmallVec::from_slice(bytemuck::bytes_of(game.rollback_state()));
+        // Serialize console rollback state
+        // SmallVec stores inline (no heap allocation) unless the game uses a
+        // lazily allocated pool
+        let mut console_data = ConsoleDataVec::new();
+        game.rollback_state().save(&mut console_data);
 
         // Serialize input state (input_prev and input_curr)
-        // Required for button_pressed() to work correctly after rollback
+        // Required for button_pressed() to work correctly after rollback.
+        // Only active players are packed so 8-player headroom doesn't bloat
+        // every snapshot of a 2-player session.
         let game_state = game.state();
+        let active = active_input_slots(game_state.player_count);
         let mut input_data: InputDataVec = SmallVec::new();
-        input_data.extend_from_slice(bytemuck::cast_slice(&game_state.input_prev));
-        input_data.extend_from_slice(bytemuck::cast_slice(&game_state.input_curr));
+        input_data.extend_from_slice(bytemuck::cast_slice(&game_state.input_prev[..active]));
+        input_data.extend_from_slice(bytemuck::cast_slice(&game_state.input_curr[..active]));
 
         // Capture host-side state that affects game simulation
-        let host_state = HostRollbackState::new(
+        let mut host_state = HostRollbackState::new(
             game_state.rng_state,
             game_state.tick_count,
             game_state.elapsed_time,
         );
+        host_state.rng_streams = game_state.rng_streams;
+        host_state.timers = game_state.timers;
+        host_state.events = game_state.events;
 
         let total_size =
             snapshot_data.len() + console_data.len() + input_data.len() + HOST_STATE_SIZE;
@@ -90,13 +119,40 @@ impl RollbackStateManager {
         }
 
         // Create snapshot with checksum covering all state
-        Ok(GameStateSnapshot::from_full_state(
+        let mut snapshot = GameStateSnapshot::from_full_state(
             snapshot_data,
             console_data,
             input_data,
             host_state,
             frame,
-        ))
+        );
+        self.compress(&mut snapshot);
+
+        self.stats.saves += 1;
+        self.stats.full_bytes += snapshot.total_len() as u64;
+        self.stats.stored_bytes += snapshot.stored_len() as u64;
+        self.stats.save_time += started.elapsed();
+        Ok(snapshot)
+    }
+
+    /// Replace a snapshot's WASM state with a delta against the keyframe
+    ///
+    /// If there is no keyframe yet, the size changed (memory grew), or too
+    /// much of the state differs, the snapshot becomes the new keyframe.
+    fn compress(&mut self, snapshot: &mut GameStateSnapshot) {
+        if let Some(keyframe) = &self.keyframe
+            && let Some((delta, pages)) = StateDelta::encode(keyframe, &snapshot.data)
+        {
+            snapshot.data = pages;
+            snapshot.delta = Some(delta);
+            return;
+        }
+
+        let keyframe = Arc::new(std::mem::take(&mut snapshot.data));
+        self.stats.keyframes += 1;
+        self.stats.stored_bytes += keyframe.len() as u64;
+        snapshot.delta = Some(StateDelta::keyframe(Arc::clone(&keyframe)));
+        self.keyframe = Some(keyframe);
     }
 
     /// Load a game state from a snapshot
@@ -116,40 +172,51 @@ impl RollbackStateManager {
             // Nothing to load
             return Ok(());
         }
+        let started = Instant::now();
 
-        // Restore WASM linear memory
-        game.load_state(&snapshot.data)
-            .map_err(|e| LoadStateError::WasmError(e.to_string()))?;
+        // Restore WASM linear memory, rebuilding it from the keyframe if needed
+        let restored = match &snapshot.delta {
+            Some(delta) if delta.pages.is_empty() => game.load_state(&delta.keyframe),
+            Some(delta) => {
+                delta.apply(&snapshot.data, &mut self.scratch);
+                game.load_state(&self.scratch)
+            }
+            None => game.load_state(&snapshot.data),
+        };
+        restored.map_err(|e| LoadStateError::WasmError(e.to_string()))?;
 
         // Restore console rollback state if present
-        if !snapshot.console_data.is_empty() {
-            if let Ok(console_state) = bytemuck::try_from_bytes::<R>(&snapshot.console_data) {
-                *game.rollback_state_mut() = *console_state;
-            } else {
-                return Err(LoadStateError::WasmError(
-                    "Console rollback state size mismatch".to_string(),
-                ));
-            }
+        if !snapshot.console_data.is_empty()
+            && !game.rollback_state_mut().load(&snapshot.console_data)
+        {
+            return Err(LoadStateError::WasmError(
+                "Console rollback state size mismatch".to_string(),
+            ));
         }
 
         // Restore input state if present
-        // Input data layout: [input_prev ×MAX_PLAYERS][input_curr ×MAX_PLAYERS]
+        // Input data layout: [input_prev ×N][input_curr ×N], N = active players
         let input_size = std::mem::size_of::<I>();
-        let expected_input_len = input_size * MAX_PLAYERS * 2;
-        if snapshot.input_data.len() == expected_input_len {
+        let pair_size = input_size * 2;
+        let packed = snapshot
+            .input_data
+            .len()
+            .checked_div(pair_size)
+            .unwrap_or(0);
+        if (1..=MAX_PLAYERS).contains(&packed) && snapshot.input_data.len() == packed * pair_size {
             let game_state = game.state_mut();
             let input_bytes = &snapshot.input_data[..];
 
             // Restore input_prev (first half)
-            let prev_bytes = &input_bytes[..input_size * MAX_PLAYERS];
+            let prev_bytes = &input_bytes[..input_size * packed];
             if let Ok(prev_inputs) = bytemuck::try_cast_slice::<u8, I>(prev_bytes) {
-                game_state.input_prev.copy_from_slice(prev_inputs);
+                game_state.input_prev[..packed].copy_from_slice(prev_inputs);
             }
 
             // Restore input_curr (second half)
-            let curr_bytes = &input_bytes[input_size * MAX_PLAYERS..];
+            let curr_bytes = &input_bytes[input_size * packed..];
             if let Ok(curr_inputs) = bytemuck::try_cast_slice::<u8, I>(curr_bytes) {
-                game_state.input_curr.copy_from_slice(curr_inputs);
+                game_state.input_curr[..packed].copy_from_slice(curr_inputs);
             }
         }
 
@@ -158,15 +225,26 @@ impl RollbackStateManager {
         game_state.rng_state = snapshot.host_state.rng_state;
         game_state.tick_count = snapshot.host_state.tick_count;
         game_state.elapsed_time = snapshot.host_state.elapsed_time();
+        game_state.rng_streams = snapshot.host_state.rng_streams;
+        game_state.timers = snapshot.host_state.timers;
+        game_state.events = snapshot.host_state.events;
 
+        self.stats.loads += 1;
+        self.stats.load_time += started.elapsed();
         Ok(())
     }
 
+    /// Measured snapshot sizes and save/restore timings for this session
+    pub fn stats(&self) -> SnapshotStats {
+        self.stats
+    }
+
     /// Return a snapshot's buffer to the pool
     ///
     /// Call this when GGRS is done with a snapshot (e.g., after confirming a frame).
     pub fn recycle_snapshot(&mut self, snapshot: GameStateSnapshot) {
-        if !snapshot.data.is_empty() {
+        // Delta pages are small; only full-size buffers are worth pooling
+        if snapshot.delta.is_none() && !snapshot.data.is_empty() {
             self.pool.release(snapshot.data);
         }
     }
@@ -216,3 +294,10 @@ impl std::fmt::Display for LoadStateError {
 }
 
 impl std::error::Error for LoadStateError {}
+
+/// Number of per-player input slots captured in a snapshot.
+///
+/// Always at least one so single-player snapshots still round-trip input.
+fn active_input_slots(player_count: u32) -> usize {
+    (player_count as usize).clamp(1, crate::wasm::state::MAX_PLAYERS)
+}
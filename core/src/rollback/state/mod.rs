@@ -1,15 +1,18 @@
 //! Rollback state management
 //!
-//! Provides state snapshot and buffer pool functionality for GGRS rollback.
+//! Provides state snapshot, delta compression and buffer pool functionality
+//! for GGRS rollback.
 
 use smallvec::SmallVec;
 
+mod delta;
 mod host_state;
 mod manager;
 mod pool;
 mod snapshot;
 
 // Re-export public types
+pub use delta::{DELTA_PAGE_SIZE, StateDelta};
 pub use host_state::{HOST_STATE_SIZE, HostRollbackState};
 pub use manager::{LoadStateError, RollbackStateManager, SaveStateError};
 pub use pool::StatePool;
@@ -18,12 +21,13 @@ pub use snapshot::GameStateSnapshot;
 // Type aliases and constants
 
 /// Inline storage size for console rollback state (avoids heap allocation)
-/// 512 bytes covers Nethercore ZX's 340-byte AudioPlaybackState with room to spare
-pub type ConsoleDataVec = SmallVec<[u8; 512]>;
+/// 4.5KB covers Nethercore ZX's 4231-byte snapshot; games that use the
+/// projectile, physics or pathfinding pools spill to the heap
+pub type ConsoleDataVec = SmallVec<[u8; 4608]>;
 
 /// Inline storage size for input state (avoids heap allocation)
-/// 128 bytes covers ZInput (8 bytes) ×4 players ×2 (prev+curr) = 64 bytes with room to spare
-pub type InputDataVec = SmallVec<[u8; 128]>;
+/// 192 bytes covers ZInput (12 bytes) ×8 players ×2 (prev+curr); only active players are packed
+pub type InputDataVec = SmallVec<[u8; 192]>;
 
 /// Number of pre-allocated state buffers in the pool
 pub const STATE_POOL_SIZE: usize = super::config::MAX_ROLLBACK_FRAMES + 2;
@@ -67,6 +71,51 @@ mod tests {
         assert_ne!(snapshot1.checksum, snapshot2.checksum);
     }
 
+    #[test]
+    fn test_delta_roundtrip() {
+        let keyframe = std::sync::Arc::new(vec![0u8; DELTA_PAGE_SIZE * 8 + 100]);
+        let mut state = keyframe.to_vec();
+        state[3] = 1;
+        state[DELTA_PAGE_SIZE * 8 + 50] = 2;
+
+        let (delta, data) = StateDelta::encode(&keyframe, &state).unwrap();
+        assert_eq!(delta.pages, [0, 8]);
+        // The last page is partial
+        assert_eq!(data.len(), DELTA_PAGE_SIZE + 100);
+
+        let mut rebuilt = Vec::new();
+        delta.apply(&data, &mut rebuilt);
+        assert_eq!(rebuilt, state);
+    }
+
+    #[test]
+    fn test_delta_rekeys_on_large_changes() {
+        let keyframe = std::sync::Arc::new(vec![0u8; DELTA_PAGE_SIZE * 8]);
+        let mut state = keyframe.to_vec();
+
+        // Two of eight pages changed is within budget, three is not
+        state[0] = 1;
+        state[DELTA_PAGE_SIZE] = 1;
+        assert!(StateDelta::encode(&keyframe, &state).is_some());
+        state[DELTA_PAGE_SIZE * 2] = 1;
+        assert!(StateDelta::encode(&keyframe, &state).is_none());
+
+        // A size change (memory growth) always needs a new keyframe
+        assert!(StateDelta::encode(&keyframe, &[0u8; 16]).is_none());
+    }
+
+    #[test]
+    fn test_delta_snapshot_lengths() {
+        let keyframe = std::sync::Arc::new(vec![0u8; 4096]);
+        let mut snapshot = GameStateSnapshot::from_data(vec![0u8; 4096], 0);
+        snapshot.data = Vec::new();
+        snapshot.delta = Some(StateDelta::keyframe(keyframe));
+
+        assert!(!snapshot.is_empty());
+        assert_eq!(snapshot.len(), 4096);
+        assert_eq!(snapshot.stored_len(), HOST_STATE_SIZE);
+    }
+
     #[test]
     fn test_state_pool_acquire_release() {
         let mut pool = StatePool::new(1024, 3);
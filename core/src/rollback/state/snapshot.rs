@@ -2,6 +2,7 @@
 
 use smallvec::SmallVec;
 
+use super::delta::StateDelta;
 use super::host_state::{HOST_STATE_SIZE, HostRollbackState};
 use super::{ConsoleDataVec, InputDataVec};
 
@@ -11,10 +12,17 @@ use super::{ConsoleDataVec, InputDataVec};
 /// host-side rollback state, input state, and a checksum for desync detection.
 /// The data comes from calling `GameInstance::save_state()` which snapshots
 /// the entire WASM linear memory.
+///
+/// Snapshots taken by [`RollbackStateManager`](super::RollbackStateManager)
+/// store the WASM state as a [`StateDelta`] against a shared keyframe, so
+/// cloning one only copies the pages that changed.
 #[derive(Clone)]
 pub struct GameStateSnapshot {
-    /// Serialized WASM game state (entire linear memory)
+    /// Serialized WASM game state (entire linear memory), or the changed
+    /// pages when `delta` is set
     pub data: Vec<u8>,
+    /// Keyframe and page list `data` applies to (None = `data` is the full state)
+    pub delta: Option<StateDelta>,
     /// Console-specific rollback state (POD, serialized via bytemuck)
     /// Uses SmallVec to store inline (no heap allocation for typical console states)
     pub console_data: ConsoleDataVec,
@@ -34,6 +42,7 @@ impl GameStateSnapshot {
     pub fn new() -> Self {
         Self {
             data: Vec::new(),
+            delta: None,
             console_data: SmallVec::new(),
             input_data: SmallVec::new(),
             host_state: HostRollbackState::default(),
@@ -48,6 +57,7 @@ impl GameStateSnapshot {
         let checksum = Self::compute_checksum(&data, &[], &[], &host_state);
         Self {
             data,
+            delta: None,
             console_data: SmallVec::new(),
             input_data: SmallVec::new(),
             host_state,
@@ -67,6 +77,7 @@ impl GameStateSnapshot {
         let checksum = Self::compute_checksum(&data, &console_data, &input_data, &host_state);
         Self {
             data,
+            delta: None,
             console_data,
             input_data,
             host_state,
@@ -82,6 +93,7 @@ impl GameStateSnapshot {
         let checksum = Self::compute_checksum(buffer, &[], &[], &host_state);
         Self {
             data: std::mem::take(buffer),
+            delta: None,
             console_data: SmallVec::new(),
             input_data: SmallVec::new(),
             host_state,
@@ -92,17 +104,26 @@ impl GameStateSnapshot {
 
     /// Check if this snapshot is empty
     pub fn is_empty(&self) -> bool {
-        self.data.is_empty()
+        self.len() == 0
     }
 
     /// Get the size of the serialized WASM state in bytes
     pub fn len(&self) -> usize {
-        self.data.len()
+        match &self.delta {
+            Some(delta) => delta.state_len(),
+            None => self.data.len(),
+        }
     }
 
     /// Get total snapshot size including all state
     pub fn total_len(&self) -> usize {
-        self.data.len() + self.console_data.len() + self.input_data.len() + HOST_STATE_SIZE
+        self.len() + self.console_data.len() + self.input_data.len() + HOST_STATE_SIZE
+    }
+
+    /// Get the bytes this snapshot holds itself, excluding a shared keyframe
+    pub fn stored_len(&self) -> usize {
+        let pages = self.delta.as_ref().map_or(0, |d| d.pages.len() * 4);
+        self.data.len() + pages + self.console_data.len() + self.input_data.len() + HOST_STATE_SIZE
     }
 
     /// Compute xxHash3 checksum for desync detection
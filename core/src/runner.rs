@@ -7,16 +7,24 @@
 use std::sync::Arc;
 
 use anyhow::Result;
+use nethercore_shared::capabilities::Capabilities;
 use wasmtime::Linker;
 use winit::window::Window;
 
 use crate::{
+    analytics::outbox_dir,
     app::session::GameSession,
-    console::{Console, Graphics, RawInput},
+    console::{Audio, Console, Graphics, RawInput},
+    daily::DailyChallenge,
     ffi::register_common_ffi,
+    ghost::GhostStore,
+    net::lobby::LobbyChannel,
     rollback::{RollbackSession, SessionEvent},
     runtime::Runtime,
     save_store::SaveStore,
+    series::SeriesState,
+    thumbnail::ThumbnailStore,
+    token_store::TokenStore,
     wasm::{GameInstance, WasmEngine, WasmGameContext},
 };
 
@@ -43,6 +51,20 @@ pub struct ConsoleRunner<C: Console> {
     session: Option<GameSession<C>>,
     /// Cached console specs
     specs: &'static crate::console::ConsoleSpecs,
+    /// Tournament series standings handed to each loaded game
+    series: Option<SeriesState>,
+    /// Daily challenge handed to each loaded game (None derives today's)
+    daily: Option<DailyChallenge>,
+    /// Publisher namespace for unlock tokens (from ROM metadata)
+    publisher: Option<String>,
+    /// Host capabilities granted to loaded games (from ROM metadata)
+    capabilities: Capabilities,
+    /// Content hash of the loaded ROM (exposed via `rom_hash()`)
+    rom_hash: [u8; 32],
+    /// Whether the user opted in to sharing analytics events
+    analytics_consent: bool,
+    /// Lobby channel handed to locally loaded games (None disables lobbies)
+    lobby: Option<LobbyChannel>,
 }
 
 impl<C: Console> ConsoleRunner<C> {
@@ -68,6 +90,13 @@ impl<C: Console> ConsoleRunner<C> {
             wasm_engine,
             session: None,
             specs,
+            series: None,
+            daily: None,
+            publisher: None,
+            capabilities: Capabilities::ALL,
+            rom_hash: [0; 32],
+            analytics_consent: false,
+            lobby: None,
         })
     }
 
@@ -114,6 +143,178 @@ impl<C: Console> ConsoleRunner<C> {
         self.session.as_ref()
     }
 
+    /// Set the series standings passed to subsequently loaded games.
+    ///
+    /// Games read them via `series_state()`. Does not affect a game that is
+    /// already running.
+    pub fn set_series(&mut self, series: Option<SeriesState>) {
+        self.series = series;
+    }
+
+    /// Get the series standings passed to loaded games.
+    pub fn series(&self) -> Option<&SeriesState> {
+        self.series.as_ref()
+    }
+
+    /// Set the daily challenge passed to subsequently loaded games.
+    ///
+    /// Games read it via `daily_seed()`. `None` derives the challenge from
+    /// the date each time a game is loaded.
+    pub fn set_daily(&mut self, daily: Option<DailyChallenge>) {
+        self.daily = daily;
+    }
+
+    /// Set the publisher whose unlock tokens subsequently loaded games can use.
+    ///
+    /// Taken from ROM metadata, never from the game itself. `None` disables
+    /// `token_grant()` / `token_has()`.
+    pub fn set_publisher(&mut self, publisher: Option<String>) {
+        self.publisher = publisher.filter(|p| nethercore_shared::is_safe_game_id(p));
+    }
+
+    /// Set the capabilities granted to subsequently loaded games.
+    ///
+    /// Taken from ROM metadata. Without `saves`, save slots and unlock tokens
+    /// are never read from or written to disk.
+    pub fn set_capabilities(&mut self, capabilities: Capabilities) {
+        self.capabilities = capabilities;
+    }
+
+    /// Set the ROM content hash passed to subsequently loaded games.
+    pub fn set_rom_hash(&mut self, rom_hash: [u8; 32]) {
+        self.rom_hash = rom_hash;
+    }
+
+    /// Set whether subsequently loaded games may queue analytics for upload.
+    ///
+    /// Taken from the user's privacy settings. Without consent, events are
+    /// counted in memory and discarded when the game exits.
+    pub fn set_analytics_consent(&mut self, consent: bool) {
+        self.analytics_consent = consent;
+    }
+
+    /// Set the lobby channel handed to subsequently loaded local games.
+    ///
+    /// Games loaded with a rollback session never get one, so a game that
+    /// has already moved into netplay can't open another lobby.
+    pub fn set_lobby(&mut self, lobby: Option<LobbyChannel>) {
+        self.lobby = lobby;
+    }
+
+    /// Load the publisher's token store into a game before init()
+    fn attach_token_store(&self, runtime: &mut Runtime<C>) {
+        if !self.capabilities.saves {
+            return;
+        }
+        let Some(publisher) = &self.publisher else {
+            return;
+        };
+        let Some(data_dir) = crate::app::config::data_dir() else {
+            return;
+        };
+        let token_path = data_dir
+            .join("tokens")
+            .join(self.specs.console_type)
+            .join(format!("{}.nctok", publisher));
+
+        let store = match TokenStore::load_or_new(token_path.clone()) {
+            Ok(store) => store,
+            Err(e) => {
+                tracing::warn!(
+                    "Failed to load token store ({}): {}",
+                    token_path.display(),
+                    e
+                );
+                TokenStore::new(token_path)
+            }
+        };
+
+        if let Some(game) = runtime.game_mut() {
+            game.store_mut().data_mut().token_store = Some(store);
+        }
+    }
+
+    /// Load the game's saved ghosts before init()
+    ///
+    /// Without the `saves` capability ghosts stay in memory for the session.
+    fn attach_ghost_store(&self, runtime: &mut Runtime<C>, game_id: &str) {
+        if !self.capabilities.saves || !nethercore_shared::is_safe_game_id(game_id) {
+            return;
+        }
+        let Some(data_dir) = crate::app::config::data_dir() else {
+            return;
+        };
+        let ghost_path = data_dir
+            .join("saves")
+            .join(self.specs.console_type)
+            .join(format!("{}.ncghost", game_id));
+
+        let store = match GhostStore::load_or_new(ghost_path.clone()) {
+            Ok(store) => store,
+            Err(e) => {
+                tracing::warn!(
+                    "Failed to load ghost store ({}): {}",
+                    ghost_path.display(),
+                    e
+                );
+                GhostStore::new(Some(ghost_path))
+            }
+        };
+
+        if let Some(game) = runtime.game_mut() {
+            game.store_mut().data_mut().ghosts.store = store;
+        }
+    }
+
+    /// Load the game's save slot thumbnails before init()
+    ///
+    /// Without the `saves` capability thumbnails stay in memory for the session.
+    fn attach_thumbnail_store(&self, runtime: &mut Runtime<C>, game_id: &str) {
+        if !self.capabilities.saves || !nethercore_shared::is_safe_game_id(game_id) {
+            return;
+        }
+        let Some(data_dir) = crate::app::config::data_dir() else {
+            return;
+        };
+        let thumbnail_path = data_dir
+            .join("saves")
+            .join(self.specs.console_type)
+            .join(format!("{}.ncthumb", game_id));
+
+        let store = match ThumbnailStore::load_or_new(thumbnail_path.clone()) {
+            Ok(store) => store,
+            Err(e) => {
+                tracing::warn!(
+                    "Failed to load thumbnail store ({}): {}",
+                    thumbnail_path.display(),
+                    e
+                );
+                ThumbnailStore::new(Some(thumbnail_path))
+            }
+        };
+
+        if let Some(game) = runtime.game_mut() {
+            game.store_mut().data_mut().thumbnails.store = store;
+        }
+    }
+
+    /// Queue the game's analytics for upload on exit (only with user consent)
+    fn attach_analytics(&self, runtime: &mut Runtime<C>, game_id: &str) {
+        if !self.analytics_consent || !nethercore_shared::is_safe_game_id(game_id) {
+            return;
+        }
+        let Some(data_dir) = crate::app::config::data_dir() else {
+            return;
+        };
+
+        if let Some(game) = runtime.game_mut() {
+            game.store_mut()
+                .data_mut()
+                .analytics
+                .enable_outbox(outbox_dir(&data_dir), game_id.to_string());
+        }
+    }
+
     /// Get a mutable reference to the console (if a game is loaded).
     pub fn console_mut(&mut self) -> Option<&mut C> {
         self.session.as_mut().map(|s| s.runtime.console_mut())
@@ -132,7 +333,7 @@ impl<C: Console> ConsoleRunner<C> {
     /// # Arguments
     /// * `console` - Fresh console instance for the game
     /// * `wasm_bytes` - The compiled WASM code
-    /// * `num_players` - Number of local players (1-4)
+    /// * `num_players` - Number of local players (1-8)
     ///
     /// # Errors
     /// Returns an error if the game fails to load or initialize.
@@ -194,7 +395,9 @@ impl<C: Console> ConsoleRunner<C> {
         }
 
         // Load persistent saves and prefill per-session save slots before init().
-        if !nethercore_shared::is_safe_game_id(game_id) {
+        if !self.capabilities.saves {
+            tracing::info!("ROM does not declare the `saves` capability; saves stay in memory");
+        } else if !nethercore_shared::is_safe_game_id(game_id) {
             tracing::warn!("Invalid game_id for save path: '{}'", game_id);
         } else if let Some(data_dir) = crate::app::config::data_dir() {
             let save_path = data_dir
@@ -216,6 +419,24 @@ impl<C: Console> ConsoleRunner<C> {
             }
         }
 
+        self.attach_token_store(&mut runtime);
+        self.attach_ghost_store(&mut runtime, game_id);
+        self.attach_thumbnail_store(&mut runtime, game_id);
+        self.attach_analytics(&mut runtime, game_id);
+        if let Some(game) = runtime.game_mut() {
+            game.store_mut().data_mut().capabilities = self.capabilities;
+            game.store_mut().data_mut().rom_hash = self.rom_hash;
+            if let Some(lobby) = &self.lobby {
+                game.store_mut().data_mut().lobby = lobby.clone();
+            }
+        }
+
+        // Hand the series standings and daily challenge to the game before init()
+        if let Some(game) = runtime.game_mut() {
+            game.state_mut().series = self.series.clone();
+            game.state_mut().daily = self.daily.unwrap_or_else(DailyChallenge::today);
+        }
+
         // Initialize console-specific FFI state before calling game init()
         // (e.g., set datapack for rom_* functions)
         runtime.initialize_console_state();
@@ -304,8 +525,29 @@ impl<C: Console> ConsoleRunner<C> {
             game.configure_session(num_players, local_mask);
         }
 
+        // Share the session socket's voice channel with the voice FFI
+        let voice = runtime.session().and_then(|s| s.voice_channel().cloned());
+        if let Some(voice) = voice {
+            if let Some(audio) = runtime.audio_mut() {
+                audio.attach_voice(voice.clone());
+            }
+            if let Some(game) = runtime.game_mut() {
+                game.store_mut().data_mut().voice = voice;
+            }
+        }
+
+        // Share the session socket's chat channel with the chat FFI
+        let chat = runtime.session().and_then(|s| s.chat_channel().cloned());
+        if let Some(chat) = chat
+            && let Some(game) = runtime.game_mut()
+        {
+            game.store_mut().data_mut().chat = chat;
+        }
+
         // Load persistent saves and prefill per-session save slots before init().
-        if !nethercore_shared::is_safe_game_id(game_id) {
+        if !self.capabilities.saves {
+            tracing::info!("ROM does not declare the `saves` capability; saves stay in memory");
+        } else if !nethercore_shared::is_safe_game_id(game_id) {
             tracing::warn!("Invalid game_id for save path: '{}'", game_id);
         } else if let Some(data_dir) = crate::app::config::data_dir() {
             let save_path = data_dir
@@ -334,6 +576,21 @@ impl<C: Console> ConsoleRunner<C> {
             apply_save_config_override(game.state_mut(), save_config);
         }
 
+        self.attach_token_store(&mut runtime);
+        self.attach_ghost_store(&mut runtime, game_id);
+        self.attach_thumbnail_store(&mut runtime, game_id);
+        self.attach_analytics(&mut runtime, game_id);
+        if let Some(game) = runtime.game_mut() {
+            game.store_mut().data_mut().capabilities = self.capabilities;
+            game.store_mut().data_mut().rom_hash = self.rom_hash;
+        }
+
+        // Hand the series standings and daily challenge to the game before init()
+        if let Some(game) = runtime.game_mut() {
+            game.state_mut().series = self.series.clone();
+            game.state_mut().daily = self.daily.unwrap_or_else(DailyChallenge::today);
+        }
+
         // Initialize console-specific FFI state before calling game init()
         runtime.initialize_console_state();
 
@@ -361,7 +618,7 @@ impl<C: Console> ConsoleRunner<C> {
     /// Process input and add it to the game.
     ///
     /// # Arguments
-    /// * `player` - Player index (0-3)
+    /// * `player` - Player index (0-7)
     /// * `raw_input` - Raw input from input manager
     pub fn add_input(&mut self, player: usize, raw_input: &RawInput) {
         if let Some(session) = &mut self.session {
@@ -72,25 +72,36 @@ pub fn execute_frame<C: Console>(
                 .advance_frame()
                 .map_err(|e| anyhow::anyhow!("GGRS advance_frame failed: {}", e))?;
 
-            // Handle all requests (SaveGameState, LoadGameState, AdvanceFrame)
+            // Handle requests (SaveGameState, LoadGameState, AdvanceFrame) in
+            // order, so states saved during a rollback follow the re-simulated ticks
             if let Some(game) = game {
-                let advance_inputs = session
-                    .handle_requests(game, requests)
-                    .map_err(|e| anyhow::anyhow!("GGRS handle_requests failed: {}", e))?;
-
-                // Note: Audio rollback is automatic via ConsoleRollbackState
-                // Audio state is part of snapshot, no explicit mode tracking needed
-
-                // Execute each AdvanceFrame with its inputs
-                for inputs in advance_inputs {
-                    // Set inputs in GameState for FFI access
-                    // Each entry is (input, status) for one player
-                    for (player_idx, (input, _status)) in inputs.iter().enumerate() {
-                        game.set_input(player_idx, *input);
+                let delta_time = tick_duration.as_secs_f32();
+                for request in requests {
+                    let advance_inputs = session
+                        .handle_requests(game, vec![request])
+                        .map_err(|e| anyhow::anyhow!("GGRS handle_requests failed: {}", e))?;
+
+                    // Note: Audio rollback is automatic via ConsoleRollbackState
+                    // Audio state is part of snapshot, no explicit mode tracking needed
+
+                    // Execute each AdvanceFrame with its inputs (one per player,
+                    // with predictions replaced by the game's predict_policy())
+                    for inputs in advance_inputs {
+                        let inputs = session
+                            .predict_inputs(game, &inputs)
+                            .map_err(|e| anyhow::anyhow!("GGRS predict_inputs failed: {}", e))?;
+                        for (player_idx, input) in inputs.iter().enumerate() {
+                            game.set_input(player_idx, *input);
+                        }
+                        game.update(delta_time)?;
+                        ticks += 1;
                     }
-                    game.update(tick_duration.as_secs_f32())?;
-                    ticks += 1;
                 }
+
+                // Re-simulate confirmed ticks whose custom prediction was wrong
+                session
+                    .correct_predictions(game, delta_time)
+                    .map_err(|e| anyhow::anyhow!("Prediction correction failed: {}", e))?;
             }
 
             *accumulator -= tick_duration;
@@ -9,7 +9,7 @@ use anyhow::Result;
 use ggrs::GgrsError;
 
 use crate::console::Console;
-use crate::rollback::{RollbackSession, SessionEvent};
+use crate::rollback::{RollbackSession, SessionEvent, SessionType};
 use crate::wasm::GameInstance;
 
 mod config;
@@ -107,13 +107,49 @@ impl<C: Console> Runtime<C> {
     }
 
     /// Initialize the loaded game
+    ///
+    /// Applies the tick rate the game chose during init to local sessions.
+    /// Online sessions keep the tick rate all peers agreed on.
     pub fn init_game(&mut self) -> Result<()> {
-        if let Some(game) = &mut self.game {
-            game.init()?;
+        let Some(game) = &mut self.game else {
+            return Ok(());
+        };
+        game.init()?;
+
+        if let Some(tick_rate) = C::tick_rate_from_state(game.console_state()) {
+            let online = self
+                .session
+                .as_ref()
+                .is_some_and(|s| s.session_type() == SessionType::P2P);
+            if !online {
+                self.set_tick_rate(tick_rate);
+            } else if tick_rate != self.config.tick_rate {
+                tracing::warn!(
+                    "set_tick_rate({} Hz) ignored: online session runs at {} Hz",
+                    tick_rate,
+                    self.config.tick_rate
+                );
+            }
         }
         Ok(())
     }
 
+    /// Run one step of the game's split initialization
+    ///
+    /// Returns true once loading has finished (immediately for games without
+    /// an `init_step()` export).
+    pub fn init_step(&mut self) -> Result<bool> {
+        match &mut self.game {
+            Some(game) => game.init_step(),
+            None => Ok(true),
+        }
+    }
+
+    /// Check if the loaded game is still running `init_step()`
+    pub fn is_loading(&self) -> bool {
+        self.game.as_ref().is_some_and(|g| g.is_loading())
+    }
+
     /// Add local input for a player
     ///
     /// Input should be added before calling `frame()` each render loop.
@@ -177,6 +213,16 @@ impl<C: Console> Runtime<C> {
         )
     }
 
+    /// Run exactly one tick, ignoring wall-clock time
+    ///
+    /// Used for offline replay rendering, where every tick is rendered no
+    /// matter how long it takes.
+    pub fn step(&mut self) -> Result<(u32, f32)> {
+        self.accumulator = self.tick_duration;
+        self.last_update = Some(Instant::now());
+        self.frame_with_time_scale(0.0)
+    }
+
     /// Render the current frame
     pub fn render(&mut self) -> Result<()> {
         if let Some(game) = &mut self.game {
@@ -195,6 +195,20 @@ fn test_runtime_render_no_game() {
     assert!(result.is_ok());
 }
 
+#[test]
+fn test_runtime_step_runs_one_tick() {
+    let console = TestConsole;
+    let mut runtime = Runtime::new(console);
+
+    // Regardless of elapsed time, each step is exactly one tick
+    for _ in 0..3 {
+        let (ticks, _alpha) = runtime.step().unwrap();
+        assert_eq!(ticks, 1);
+    }
+    std::thread::sleep(std::time::Duration::from_millis(40));
+    assert_eq!(runtime.step().unwrap().0, 1);
+}
+
 #[test]
 fn test_runtime_render_with_game() {
     let console = TestConsole;
@@ -151,12 +151,14 @@ impl SaveStore {
     /// controller slots.
     pub fn prefill_game_save_data<I: ConsoleInput>(&self, game: &mut crate::wasm::GameState<I>) {
         // Clear session slots first so we don't keep stale data across reloads.
-        for slot in 0..crate::wasm::MAX_PLAYERS {
+        for slot in 0..crate::wasm::MAX_SAVE_SLOTS {
             game.save_data[slot] = None;
         }
 
+        // Players beyond MAX_SAVE_SLOTS (e.g. players 5-8) have no session slot.
         let player_count = game.player_count.min(crate::wasm::MAX_PLAYERS as u32);
-        for session_slot in 0..player_count {
+        let slot_count = player_count.min(crate::wasm::MAX_SAVE_SLOTS as u32);
+        for session_slot in 0..slot_count {
             let Some(controller_slot) = map_session_slot_to_controller_slot(
                 game.local_player_mask,
                 player_count,
@@ -0,0 +1,160 @@
+//! Tournament series (best-of-N) standings
+//!
+//! A series chains several local sessions of the same ROM. The host keeps the
+//! standings between rounds and hands them to each new session before `init()`,
+//! where the game can read them with `series_state()`. The game reports the
+//! winner of each round with `series_round_end()`, and the host then loads the
+//! next round until somebody has won a majority of the rounds.
+
+use crate::wasm::MAX_PLAYERS;
+
+/// Largest supported series length
+pub const MAX_BEST_OF: u32 = 99;
+
+/// Winner value meaning "no winner" (a drawn round, or an undecided series)
+pub const SERIES_NO_WINNER: u32 = 0xFF;
+
+/// Size of the record written by `series_state()`
+///
+/// Layout (all little-endian u32):
+/// - `0`: best_of
+/// - `4`: current round (1-based)
+/// - `8`: champion player index, or [`SERIES_NO_WINNER`]
+/// - `12..44`: round wins per player
+pub const SERIES_STATE_SIZE: usize = 12 + MAX_PLAYERS * 4;
+
+/// Standings of a best-of-N series
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SeriesState {
+    /// Series length (a player needs `best_of / 2 + 1` round wins)
+    pub best_of: u32,
+    /// Current round (1-based). Drawn rounds still advance this.
+    pub round: u32,
+    /// Round wins per player
+    pub wins: [u32; MAX_PLAYERS],
+}
+
+impl SeriesState {
+    /// Start a new series, clamping `best_of` to 1..=[`MAX_BEST_OF`]
+    pub fn new(best_of: u32) -> Self {
+        Self {
+            best_of: best_of.clamp(1, MAX_BEST_OF),
+            round: 1,
+            wins: [0; MAX_PLAYERS],
+        }
+    }
+
+    /// Round wins needed to take the series
+    pub fn wins_needed(&self) -> u32 {
+        self.best_of / 2 + 1
+    }
+
+    /// Player who has won the series, if decided
+    pub fn champion(&self) -> Option<usize> {
+        self.wins.iter().position(|&w| w >= self.wins_needed())
+    }
+
+    /// Whether the series has been decided
+    pub fn is_finished(&self) -> bool {
+        self.champion().is_some()
+    }
+
+    /// Record the result of the current round
+    ///
+    /// `winner` is a player index, or `None` for a draw. Returns `true` if
+    /// another round should be played. Results reported after the series is
+    /// decided are ignored.
+    pub fn record_round(&mut self, winner: Option<usize>) -> bool {
+        if self.is_finished() {
+            return false;
+        }
+        if let Some(wins) = winner.and_then(|p| self.wins.get_mut(p)) {
+            *wins += 1;
+        }
+        if self.is_finished() {
+            return false;
+        }
+        self.round += 1;
+        true
+    }
+
+    /// Serialize for `series_state()` (see [`SERIES_STATE_SIZE`])
+    pub fn to_bytes(&self) -> [u8; SERIES_STATE_SIZE] {
+        let champion = self
+            .champion()
+            .map(|p| p as u32)
+            .unwrap_or(SERIES_NO_WINNER);
+
+        let mut bytes = [0u8; SERIES_STATE_SIZE];
+        bytes[0..4].copy_from_slice(&self.best_of.to_le_bytes());
+        bytes[4..8].copy_from_slice(&self.round.to_le_bytes());
+        bytes[8..12].copy_from_slice(&champion.to_le_bytes());
+        for (i, wins) in self.wins.iter().enumerate() {
+            let offset = 12 + i * 4;
+            bytes[offset..offset + 4].copy_from_slice(&wins.to_le_bytes());
+        }
+        bytes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_best_of_three() {
+        let mut series = SeriesState::new(3);
+        assert_eq!(series.wins_needed(), 2);
+
+        assert!(series.record_round(Some(0)));
+        assert_eq!(series.round, 2);
+        assert!(series.record_round(Some(1)));
+        assert_eq!(series.round, 3);
+        assert!(!series.record_round(Some(1)));
+
+        assert_eq!(series.champion(), Some(1));
+        assert_eq!(series.round, 3);
+        assert_eq!(series.wins[..2], [1, 2]);
+    }
+
+    #[test]
+    fn test_draws_extend_the_series() {
+        let mut series = SeriesState::new(1);
+        assert!(series.record_round(None));
+        assert!(series.record_round(Some(9)));
+        assert!(!series.is_finished());
+        assert_eq!(series.round, 3);
+
+        assert!(!series.record_round(Some(2)));
+        assert_eq!(series.champion(), Some(2));
+    }
+
+    #[test]
+    fn test_results_after_finish_are_ignored() {
+        let mut series = SeriesState::new(1);
+        assert!(!series.record_round(Some(0)));
+        assert!(!series.record_round(Some(1)));
+        assert_eq!(series.wins[1], 0);
+        assert_eq!(series.champion(), Some(0));
+    }
+
+    #[test]
+    fn test_best_of_is_clamped() {
+        assert_eq!(SeriesState::new(0).best_of, 1);
+        assert_eq!(SeriesState::new(1000).best_of, MAX_BEST_OF);
+    }
+
+    #[test]
+    fn test_to_bytes_layout() {
+        let mut series = SeriesState::new(5);
+        series.record_round(Some(3));
+
+        let bytes = series.to_bytes();
+        let word = |i: usize| u32::from_le_bytes(bytes[i * 4..i * 4 + 4].try_into().unwrap());
+        assert_eq!(word(0), 5);
+        assert_eq!(word(1), 2);
+        assert_eq!(word(2), SERIES_NO_WINNER);
+        assert_eq!(word(3 + 3), 1);
+        assert_eq!(bytes.len(), 44);
+    }
+}
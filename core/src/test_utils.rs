@@ -82,7 +82,22 @@ pub struct TestInput {
     pub x: i8,
     pub y: i8,
 }
-impl ConsoleInput for TestInput {}
+impl TestInput {
+    /// `buttons` bit set while a controller drives this player
+    pub const CONNECTED: u16 = 1 << 15;
+}
+impl ConsoleInput for TestInput {
+    fn connected(&self) -> bool {
+        self.buttons & Self::CONNECTED != 0
+    }
+
+    fn neutral(&self) -> Self {
+        Self {
+            buttons: self.buttons & Self::CONNECTED,
+            ..Default::default()
+        }
+    }
+}
 
 /// Test resource manager (no-op)
 pub struct TestResourceManager;
@@ -183,6 +198,10 @@ impl Console for TestConsole {
         }
     }
 
+    fn encode_replay_bytes(&self, input: &Self::Input) -> Vec<u8> {
+        vec![input.buttons as u8]
+    }
+
     fn create_resource_manager(&self) -> Self::ResourceManager {
         TestResourceManager
     }
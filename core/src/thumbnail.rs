@@ -0,0 +1,325 @@
+//! Save slot thumbnails
+//!
+//! `thumbnail_capture()` asks the host to grab the frame the game is drawing.
+//! Once that frame has been rendered, the host reads it back, shrinks it to
+//! [`THUMBNAIL_WIDTH`] × [`THUMBNAIL_HEIGHT`] and stores it in a thumbnail
+//! slot, so save and level select screens can show real previews. Slots are
+//! persisted next to the save data when the ROM declares the `saves`
+//! capability.
+//!
+//! Thumbnails are host-side and never rolled back: they only feed what the
+//! game draws, never its simulation.
+
+use std::fs;
+use std::io::{self, Read, Write};
+use std::path::PathBuf;
+
+pub const THUMBNAIL_MAGIC: [u8; 4] = *b"NCTB";
+pub const THUMBNAIL_VERSION: u32 = 1;
+
+/// Number of thumbnail slots per game
+pub const MAX_THUMBNAIL_SLOTS: usize = 8;
+
+/// Thumbnail width in pixels
+pub const THUMBNAIL_WIDTH: u32 = 160;
+
+/// Thumbnail height in pixels
+pub const THUMBNAIL_HEIGHT: u32 = 90;
+
+/// Size of one RGBA8 thumbnail in bytes
+pub const THUMBNAIL_SIZE: usize = (THUMBNAIL_WIDTH * THUMBNAIL_HEIGHT * 4) as usize;
+
+/// Thumbnail state for a running game (host-side, never rolled back)
+#[derive(Default)]
+pub struct Thumbnails {
+    /// Slots to fill from the next rendered frame (bit N = slot N)
+    pub requested: u32,
+    /// Captured thumbnails
+    pub store: ThumbnailStore,
+}
+
+impl Thumbnails {
+    /// Take the pending capture requests
+    pub fn take_requests(&mut self) -> u32 {
+        std::mem::take(&mut self.requested)
+    }
+
+    /// Shrink a rendered RGBA8 frame into every requested slot and write the
+    /// store to disk
+    pub fn capture(&mut self, requests: u32, pixels: &[u8], width: u32, height: u32) {
+        let thumbnail = downscale(pixels, width, height);
+        for slot in (0..MAX_THUMBNAIL_SLOTS).filter(|slot| requests & (1 << slot) != 0) {
+            self.store.set_slot(slot, thumbnail.clone());
+        }
+        if let Err(e) = self.store.flush() {
+            tracing::warn!(error = %e, "Failed to flush ThumbnailStore");
+        }
+    }
+}
+
+/// Captured thumbnails, optionally backed by a file
+#[derive(Default)]
+pub struct ThumbnailStore {
+    path: Option<PathBuf>,
+    slots: [Option<Vec<u8>>; MAX_THUMBNAIL_SLOTS],
+    /// Bumped on every change so consoles know when to re-upload a slot
+    revisions: [u32; MAX_THUMBNAIL_SLOTS],
+}
+
+impl ThumbnailStore {
+    /// Create an empty store. Without a path, thumbnails only live in memory.
+    pub fn new(path: Option<PathBuf>) -> Self {
+        Self {
+            path,
+            ..Default::default()
+        }
+    }
+
+    /// Loads the store from disk. Missing or unreadable files yield an empty
+    /// store; a truncated slot is reported as corruption.
+    pub fn load_or_new(path: PathBuf) -> io::Result<Self> {
+        let mut store = Self::new(Some(path.clone()));
+
+        let mut file = match fs::File::open(&path) {
+            Ok(f) => f,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(store),
+            Err(e) => return Err(e),
+        };
+
+        let mut bytes = Vec::new();
+        file.read_to_end(&mut bytes)?;
+
+        if bytes.len() < 12 || bytes[0..4] != THUMBNAIL_MAGIC {
+            return Ok(store);
+        }
+        let word = |i: usize| u32::from_le_bytes(bytes[i..i + 4].try_into().unwrap());
+        if word(4) != THUMBNAIL_VERSION {
+            return Ok(store);
+        }
+
+        // Bitmask of stored slots, followed by their pixels in slot order
+        let present = word(8);
+        let mut offset = 12;
+        for slot in (0..MAX_THUMBNAIL_SLOTS).filter(|slot| present & (1 << slot) != 0) {
+            let Some(pixels) = bytes.get(offset..offset + THUMBNAIL_SIZE) else {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "thumbnail file is truncated",
+                ));
+            };
+            store.set_slot(slot, pixels.to_vec());
+            offset += THUMBNAIL_SIZE;
+        }
+        Ok(store)
+    }
+
+    /// RGBA8 pixels stored in `slot` (None if unused or out of range)
+    pub fn slot(&self, slot: usize) -> Option<&[u8]> {
+        self.slots.get(slot)?.as_deref()
+    }
+
+    /// Change counter for `slot` (0 until something is stored)
+    pub fn revision(&self, slot: usize) -> u32 {
+        self.revisions.get(slot).copied().unwrap_or(0)
+    }
+
+    /// Replace the thumbnail in `slot`
+    pub fn set_slot(&mut self, slot: usize, pixels: Vec<u8>) {
+        assert!(slot < MAX_THUMBNAIL_SLOTS);
+        assert_eq!(pixels.len(), THUMBNAIL_SIZE);
+        self.slots[slot] = Some(pixels);
+        self.revisions[slot] = self.revisions[slot].wrapping_add(1);
+    }
+
+    /// Empty `slot`
+    pub fn clear_slot(&mut self, slot: usize) {
+        assert!(slot < MAX_THUMBNAIL_SLOTS);
+        if self.slots[slot].take().is_some() {
+            self.revisions[slot] = self.revisions[slot].wrapping_add(1);
+        }
+    }
+
+    /// Whether thumbnails are written to disk
+    pub fn is_persistent(&self) -> bool {
+        self.path.is_some()
+    }
+
+    /// Write all slots to disk (no-op for in-memory stores)
+    pub fn flush(&self) -> io::Result<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let mut present = 0u32;
+        for (slot, pixels) in self.slots.iter().enumerate() {
+            if pixels.is_some() {
+                present |= 1 << slot;
+            }
+        }
+
+        let count = present.count_ones() as usize;
+        let mut out = Vec::with_capacity(12 + count * THUMBNAIL_SIZE);
+        out.extend_from_slice(&THUMBNAIL_MAGIC);
+        out.extend_from_slice(&THUMBNAIL_VERSION.to_le_bytes());
+        out.extend_from_slice(&present.to_le_bytes());
+        for pixels in self.slots.iter().flatten() {
+            out.extend_from_slice(pixels);
+        }
+
+        let tmp_path = path.with_extension("ncthumb.tmp");
+        {
+            let mut f = fs::File::create(&tmp_path)?;
+            f.write_all(&out)?;
+            f.sync_all()?;
+        }
+
+        #[cfg(windows)]
+        {
+            if path.exists() {
+                // Windows rename fails if destination exists.
+                fs::remove_file(path)?;
+            }
+        }
+
+        fs::rename(&tmp_path, path)?;
+        Ok(())
+    }
+}
+
+/// Shrink an RGBA8 frame to thumbnail size
+///
+/// Each thumbnail pixel averages the block of source pixels it covers, so
+/// thin lines and dithering blend instead of flickering in and out. Alpha is
+/// forced opaque, since the frame is always drawn over the clear color.
+pub fn downscale(pixels: &[u8], width: u32, height: u32) -> Vec<u8> {
+    let mut out = vec![0u8; THUMBNAIL_SIZE];
+    if width == 0 || height == 0 || pixels.len() < (width * height * 4) as usize {
+        return out;
+    }
+
+    for ty in 0..THUMBNAIL_HEIGHT {
+        let y0 = ty * height / THUMBNAIL_HEIGHT;
+        let y1 = ((ty + 1) * height / THUMBNAIL_HEIGHT).max(y0 + 1);
+        for tx in 0..THUMBNAIL_WIDTH {
+            let x0 = tx * width / THUMBNAIL_WIDTH;
+            let x1 = ((tx + 1) * width / THUMBNAIL_WIDTH).max(x0 + 1);
+
+            let mut sum = [0u32; 3];
+            for y in y0..y1 {
+                let row = (y * width) as usize * 4;
+                for x in x0..x1 {
+                    let i = row + x as usize * 4;
+                    sum[0] += pixels[i] as u32;
+                    sum[1] += pixels[i + 1] as u32;
+                    sum[2] += pixels[i + 2] as u32;
+                }
+            }
+
+            let count = (y1 - y0) * (x1 - x0);
+            let o = ((ty * THUMBNAIL_WIDTH + tx) * 4) as usize;
+            for c in 0..3 {
+                out[o + c] = ((sum[c] + count / 2) / count) as u8;
+            }
+            out[o + 3] = 255;
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn solid(value: u8) -> Vec<u8> {
+        vec![value; THUMBNAIL_SIZE]
+    }
+
+    #[test]
+    fn test_downscale_averages_blocks() {
+        // 320x180 frame: left half black, right half white, with a 1px red
+        // line every other row on the left
+        let (width, height) = (320, 180);
+        let mut frame = vec![0u8; (width * height * 4) as usize];
+        for y in 0..height {
+            for x in 0..width {
+                let i = ((y * width + x) * 4) as usize;
+                let value = if x >= width / 2 { 255 } else { 0 };
+                frame[i..i + 4].copy_from_slice(&[value, value, value, 0]);
+                if x < width / 2 && y % 2 == 0 {
+                    frame[i] = 255;
+                }
+            }
+        }
+
+        let thumb = downscale(&frame, width, height);
+        assert_eq!(thumb.len(), THUMBNAIL_SIZE);
+        // Left: red lines blend to half red, alpha forced opaque
+        assert_eq!(&thumb[0..4], &[128, 0, 0, 255]);
+        // Right edge is white
+        let last = THUMBNAIL_SIZE - 4;
+        assert_eq!(&thumb[last..], &[255, 255, 255, 255]);
+    }
+
+    #[test]
+    fn test_downscale_upscales_small_frames() {
+        let thumb = downscale(&[10, 20, 30, 40], 1, 1);
+        assert!(thumb.chunks_exact(4).all(|p| p == [10, 20, 30, 255]));
+        assert!(downscale(&[], 0, 0).iter().all(|&b| b == 0));
+    }
+
+    #[test]
+    fn test_capture_fills_requested_slots() {
+        let mut thumbnails = Thumbnails {
+            requested: 0b101,
+            ..Default::default()
+        };
+        let requests = thumbnails.take_requests();
+        assert_eq!(thumbnails.requested, 0);
+
+        thumbnails.capture(requests, &[200; 16], 2, 2);
+        assert!(thumbnails.store.slot(0).is_some());
+        assert!(thumbnails.store.slot(1).is_none());
+        assert_eq!(thumbnails.store.slot(2), thumbnails.store.slot(0));
+        assert_eq!(thumbnails.store.revision(2), 1);
+        assert_eq!(thumbnails.store.revision(1), 0);
+    }
+
+    #[test]
+    fn test_store_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("game.ncthumb");
+
+        let mut store = ThumbnailStore::new(Some(path.clone()));
+        store.set_slot(1, solid(7));
+        store.set_slot(5, solid(9));
+        store.flush().unwrap();
+
+        let mut loaded = ThumbnailStore::load_or_new(path).unwrap();
+        assert!(loaded.is_persistent());
+        assert!(loaded.slot(0).is_none());
+        assert_eq!(loaded.slot(1), Some(solid(7).as_slice()));
+        assert_eq!(loaded.slot(5), Some(solid(9).as_slice()));
+        assert!(loaded.slot(MAX_THUMBNAIL_SLOTS).is_none());
+
+        loaded.clear_slot(1);
+        assert!(loaded.slot(1).is_none());
+        assert_eq!(loaded.revision(1), 2);
+    }
+
+    #[test]
+    fn test_truncated_file_is_corrupt() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("game.ncthumb");
+
+        let mut bytes = THUMBNAIL_MAGIC.to_vec();
+        bytes.extend_from_slice(&THUMBNAIL_VERSION.to_le_bytes());
+        bytes.extend_from_slice(&1u32.to_le_bytes());
+        bytes.extend_from_slice(&[0; 64]);
+        fs::write(&path, bytes).unwrap();
+
+        assert!(ThumbnailStore::load_or_new(path).is_err());
+    }
+}
@@ -0,0 +1,193 @@
+//! Tick-based timers (`timer_after` / `timer_every`)
+//!
+//! Timers count down on the deterministic update tick and call the game's
+//! exported `on_timer(callback_id)` when they expire. The table is a fixed-size
+//! POD array stored with the host-side rollback state, so a rollback restores
+//! pending timers along with the RNG and tick count, and re-simulated ticks
+//! fire exactly the same callbacks.
+
+/// Maximum number of pending timers
+pub const MAX_TIMERS: usize = 32;
+
+/// Low handle bits holding slot index + 1; the rest hold the slot generation
+const SLOT_BITS: u32 = 8;
+const SLOT_MASK: u32 = (1 << SLOT_BITS) - 1;
+const GENERATION_MASK: u32 = u32::MAX >> SLOT_BITS;
+
+/// A single timer slot (inactive when `remaining` is 0)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[repr(C)]
+pub struct Timer {
+    /// Game-defined id passed to `on_timer()`
+    pub callback_id: u32,
+    /// Ticks left until the timer fires
+    pub remaining: u32,
+    /// Repeat interval in ticks (0 = one-shot)
+    pub interval: u32,
+    /// Bumped every time the slot is reused, so stale handles don't match
+    pub generation: u32,
+}
+
+/// Fixed-size table of pending timers
+///
+/// Handles returned to the game are `(generation << 8) | (slot index + 1)`,
+/// so 0 is never a valid handle, and a handle kept after its timer finished
+/// can't cancel a newer timer that reused the slot.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[repr(C)]
+pub struct TimerTable {
+    slots: [Timer; MAX_TIMERS],
+}
+
+// SAFETY: Timer and TimerTable are #[repr(C)] with only u32 fields (no padding)
+unsafe impl bytemuck::Zeroable for Timer {}
+unsafe impl bytemuck::Pod for Timer {}
+unsafe impl bytemuck::Zeroable for TimerTable {}
+unsafe impl bytemuck::Pod for TimerTable {}
+
+impl TimerTable {
+    /// Schedule a timer that fires after `ticks` ticks, then every `interval`
+    /// ticks (0 = once)
+    ///
+    /// `ticks` is clamped to at least 1, so a timer created during tick N
+    /// fires at the start of tick N + `ticks` at the earliest. Returns the
+    /// timer handle, or `None` if every slot is in use.
+    pub fn schedule(&mut self, ticks: u32, callback_id: u32, interval: u32) -> Option<u32> {
+        let index = self.slots.iter().position(|t| t.remaining == 0)?;
+        let timer = &mut self.slots[index];
+        *timer = Timer {
+            callback_id,
+            remaining: ticks.max(1),
+            interval,
+            generation: timer.generation.wrapping_add(1) & GENERATION_MASK,
+        };
+        Some((timer.generation << SLOT_BITS) | (index as u32 + 1))
+    }
+
+    /// Cancel a pending timer. Returns `false` if the handle isn't active.
+    pub fn cancel(&mut self, handle: u32) -> bool {
+        let Some(timer) = (handle & SLOT_MASK)
+            .checked_sub(1)
+            .and_then(|i| self.slots.get_mut(i as usize))
+        else {
+            return false;
+        };
+        if timer.remaining == 0 || timer.generation != handle >> SLOT_BITS {
+            return false;
+        }
+        timer.remaining = 0;
+        timer.interval = 0;
+        true
+    }
+
+    /// Number of pending timers
+    pub fn active_count(&self) -> usize {
+        self.slots.iter().filter(|t| t.remaining != 0).count()
+    }
+
+    /// Count every timer down by one tick, appending the callback ids of
+    /// expired timers to `fired` in slot order
+    ///
+    /// One-shot timers are freed and repeating timers re-armed before the
+    /// callbacks run, so `on_timer()` may schedule new timers (even in the
+    /// slot that just fired) without them being counted down this tick.
+    pub fn tick(&mut self, fired: &mut Vec<u32>) {
+        for timer in self.slots.iter_mut().filter(|t| t.remaining != 0) {
+            timer.remaining -= 1;
+            if timer.remaining == 0 {
+                fired.push(timer.callback_id);
+                timer.remaining = timer.interval;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(table: &mut TimerTable, ticks: u32) -> Vec<(u32, u32)> {
+        let mut log = Vec::new();
+        for tick in 1..=ticks {
+            let mut fired = Vec::new();
+            table.tick(&mut fired);
+            log.extend(fired.into_iter().map(|id| (tick, id)));
+        }
+        log
+    }
+
+    #[test]
+    fn test_one_shot_fires_once() {
+        let mut table = TimerTable::default();
+        assert!(table.schedule(3, 7, 0).is_some());
+        assert_eq!(run(&mut table, 10), vec![(3, 7)]);
+        assert_eq!(table.active_count(), 0);
+    }
+
+    #[test]
+    fn test_repeating_timer() {
+        let mut table = TimerTable::default();
+        table.schedule(2, 1, 2);
+        table.schedule(3, 2, 0);
+        assert_eq!(run(&mut table, 6), vec![(2, 1), (3, 2), (4, 1), (6, 1)]);
+        assert_eq!(table.active_count(), 1);
+    }
+
+    #[test]
+    fn test_zero_ticks_fires_next_tick() {
+        let mut table = TimerTable::default();
+        table.schedule(0, 9, 0);
+        assert_eq!(run(&mut table, 2), vec![(1, 9)]);
+    }
+
+    #[test]
+    fn test_cancel_and_reuse_slot() {
+        let mut table = TimerTable::default();
+        let a = table.schedule(5, 1, 0).unwrap();
+        let b = table.schedule(5, 2, 0).unwrap();
+        assert!(table.cancel(a));
+        assert!(!table.cancel(a));
+        assert!(!table.cancel(0));
+        assert!(!table.cancel(MAX_TIMERS as u32 + 1));
+
+        // Freed slot is reused first, under a new handle
+        let c = table.schedule(1, 3, 0).unwrap();
+        assert_eq!(c & SLOT_MASK, a & SLOT_MASK);
+        assert_ne!(c, a);
+        assert_eq!(run(&mut table, 5), vec![(1, 3), (5, 2)]);
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn test_stale_handle_does_not_cancel_new_timer() {
+        let mut table = TimerTable::default();
+        let old = table.schedule(1, 1, 0).unwrap();
+        assert_eq!(run(&mut table, 1), vec![(1, 1)]);
+
+        // The one-shot fired, so its slot goes to the next timer
+        let new = table.schedule(5, 2, 0).unwrap();
+        assert!(!table.cancel(old));
+        assert_eq!(table.active_count(), 1);
+        assert!(table.cancel(new));
+        assert_eq!(table.active_count(), 0);
+    }
+
+    #[test]
+    fn test_generation_wraps_without_zero_handles() {
+        let mut table = TimerTable::default();
+        table.slots[0].generation = GENERATION_MASK;
+        let handle = table.schedule(1, 1, 0).unwrap();
+        assert_eq!(handle, 1);
+        assert!(table.cancel(handle));
+    }
+
+    #[test]
+    fn test_table_full() {
+        let mut table = TimerTable::default();
+        for i in 0..MAX_TIMERS as u32 {
+            let handle = table.schedule(10, i, 0).unwrap();
+            assert_eq!(handle & SLOT_MASK, i + 1);
+        }
+        assert_eq!(table.schedule(10, 99, 0), None);
+    }
+}
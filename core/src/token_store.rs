@@ -0,0 +1,116 @@
+//! Cross-ROM unlock tokens
+//!
+//! Tokens are small numeric flags shared by every game from the same
+//! publisher. The namespace comes from the ROM metadata rather than the game,
+//! so a ROM can only grant or read tokens belonging to its own publisher.
+//! Tokens are never revoked.
+
+use std::collections::BTreeSet;
+use std::fs;
+use std::io::{self, Read, Write};
+use std::path::PathBuf;
+
+pub const TOKEN_MAGIC: [u8; 4] = *b"NCTK";
+pub const TOKEN_VERSION: u32 = 1;
+/// Maximum number of distinct tokens per publisher
+pub const MAX_TOKENS: usize = 1024;
+
+pub struct TokenStore {
+    path: PathBuf,
+    tokens: BTreeSet<u32>,
+}
+
+impl TokenStore {
+    pub fn new(path: PathBuf) -> Self {
+        Self {
+            path,
+            tokens: BTreeSet::new(),
+        }
+    }
+
+    /// Loads the store from disk. Missing or unreadable files yield an empty
+    /// store; oversized files are reported as corruption.
+    pub fn load_or_new(path: PathBuf) -> io::Result<Self> {
+        let mut store = Self::new(path);
+
+        let mut file = match fs::File::open(&store.path) {
+            Ok(f) => f,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(store),
+            Err(e) => return Err(e),
+        };
+
+        let mut bytes = Vec::new();
+        file.read_to_end(&mut bytes)?;
+
+        if bytes.len() < 12 || bytes[0..4] != TOKEN_MAGIC {
+            return Ok(store);
+        }
+        let word = |i: usize| u32::from_le_bytes(bytes[i..i + 4].try_into().unwrap());
+        if word(4) != TOKEN_VERSION {
+            return Ok(store);
+        }
+
+        let count = word(8) as usize;
+        if count > MAX_TOKENS {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "token file exceeds MAX_TOKENS",
+            ));
+        }
+        if bytes.len() < 12 + count * 4 {
+            return Ok(store);
+        }
+
+        store.tokens = (0..count).map(|i| word(12 + i * 4)).collect();
+        Ok(store)
+    }
+
+    /// Whether the token has been granted
+    pub fn has(&self, id: u32) -> bool {
+        self.tokens.contains(&id)
+    }
+
+    /// Grant a token. Returns `false` if the store is full.
+    pub fn grant(&mut self, id: u32) -> bool {
+        if self.tokens.contains(&id) {
+            return true;
+        }
+        if self.tokens.len() >= MAX_TOKENS {
+            return false;
+        }
+        self.tokens.insert(id);
+        true
+    }
+
+    pub fn flush(&self) -> io::Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let mut out = Vec::with_capacity(12 + self.tokens.len() * 4);
+        out.extend_from_slice(&TOKEN_MAGIC);
+        out.extend_from_slice(&TOKEN_VERSION.to_le_bytes());
+        out.extend_from_slice(&(self.tokens.len() as u32).to_le_bytes());
+        for id in &self.tokens {
+            out.extend_from_slice(&id.to_le_bytes());
+        }
+
+        let tmp_path = self.path.with_extension("nctok.tmp");
+        {
+            let mut f = fs::File::create(&tmp_path)?;
+            f.write_all(&out)?;
+            f.sync_all()?;
+        }
+
+        #[cfg(windows)]
+        {
+            if self.path.exists() {
+                // Windows rename fails if destination exists.
+                fs::remove_file(&self.path)?;
+            }
+        }
+
+        fs::rename(&tmp_path, &self.path)?;
+        Ok(())
+    }
+}
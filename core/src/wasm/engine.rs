@@ -3,6 +3,8 @@
 use anyhow::{Context, Result};
 use wasmtime::{Cache, Config, Engine, ExternType, Module, OptLevel};
 
+use super::watchdog;
+
 /// Shared WASM engine (one per application)
 pub struct WasmEngine {
     engine: Engine,
@@ -34,7 +36,11 @@ impl WasmEngine {
             }
         }
 
+        // Let the watchdog interrupt games stuck in a call (see `watchdog`)
+        config.epoch_interruption(true);
+
         let engine = Engine::new(&config).context("Failed to create WASM engine")?;
+        watchdog::start_epoch_ticker(&engine);
         Ok(Self { engine })
     }
 
@@ -1,12 +1,17 @@
 //! Game instance implementation for loaded WASM modules
 
+use std::time::Duration;
+
 use anyhow::{Context, Result};
 use wasmtime::{Instance, Linker, Module, Store, TypedFunc, Val};
 
 use super::engine::WasmEngine;
 use super::state::{DEFAULT_RAM_LIMIT, GameState, MAX_PLAYERS, WasmGameContext};
+use super::watchdog::{self, CALL_TIMEOUT, INIT_TIMEOUT, WatchdogTimeout};
 use crate::console::{ConsoleInput, ConsoleRollbackState};
 use crate::debug::types::ActionParamValue;
+use crate::event::{Event, event_kind};
+use crate::rollback::PredictPolicy;
 
 /// A loaded and instantiated game
 pub struct GameInstance<I: ConsoleInput, S: Send + Default + 'static, R: ConsoleRollbackState = ()>
@@ -18,12 +23,33 @@ pub struct GameInstance<I: ConsoleInput, S: Send + Default + 'static, R: Console
     #[allow(dead_code)]
     instance: Instance,
     init_fn: Option<TypedFunc<(), ()>>,
+    /// Optional init_step() function for initialization split across frames.
+    /// Called once per frame after init() until it returns non-zero.
+    init_step_fn: Option<TypedFunc<(), u32>>,
+    /// True from init() until init_step() reports that loading is done
+    loading: bool,
     update_fn: Option<TypedFunc<(), ()>>,
     render_fn: Option<TypedFunc<(), ()>>,
     on_debug_change_fn: Option<TypedFunc<(), ()>>,
     /// Optional post_connect function for two-phase initialization.
     /// Called after NCHS handshake completes, before game loop starts.
     post_connect_fn: Option<TypedFunc<(), ()>>,
+    /// Optional on_timer(callback_id) function, called when a timer expires
+    on_timer_fn: Option<TypedFunc<u32, ()>>,
+    /// Optional on_lobby_state(state) function, called when the lobby state changes
+    on_lobby_state_fn: Option<TypedFunc<u32, ()>>,
+    /// Optional on_player_join(index) function, called when a controller connects
+    on_player_join_fn: Option<TypedFunc<u32, ()>>,
+    /// Optional on_player_leave(index) function, called when a controller disconnects
+    on_player_leave_fn: Option<TypedFunc<u32, ()>>,
+    /// Optional predict_input(player, ticks) function for `PredictPolicy::Custom`
+    predict_input_fn: Option<TypedFunc<(u32, u32), u32>>,
+    /// Rollback state `predict_input()` runs against, reused between ticks
+    predict_memory: Vec<u8>,
+    /// Scratch list of timer callbacks due this tick
+    fired_timers: Vec<u32>,
+    /// Watchdog deadline for calls after `init()`
+    call_timeout: Duration,
 }
 
 impl<I: ConsoleInput, S: Send + Default + 'static, R: ConsoleRollbackState> GameInstance<I, S, R> {
@@ -58,6 +84,9 @@ impl<I: ConsoleInput, S: Send + Default + 'static, R: ConsoleRollbackState> Game
         // Enable resource limiter to enforce memory constraints
         store.limiter(|state| state);
 
+        // Covers the module's start function
+        store.set_epoch_deadline(watchdog::deadline_ticks(INIT_TIMEOUT));
+
         let instance = linker
             .instantiate(&mut store, module)
             .map_err(|e| {
@@ -73,6 +102,9 @@ impl<I: ConsoleInput, S: Send + Default + 'static, R: ConsoleRollbackState> Game
 
         // Look up exported functions
         let init_fn = instance.get_typed_func::<(), ()>(&mut store, "init").ok();
+        let init_step_fn = instance
+            .get_typed_func::<(), u32>(&mut store, "init_step")
+            .ok();
         let update_fn = instance.get_typed_func::<(), ()>(&mut store, "update").ok();
         let render_fn = instance.get_typed_func::<(), ()>(&mut store, "render").ok();
         let on_debug_change_fn = instance
@@ -81,23 +113,69 @@ impl<I: ConsoleInput, S: Send + Default + 'static, R: ConsoleRollbackState> Game
         let post_connect_fn = instance
             .get_typed_func::<(), ()>(&mut store, "post_connect")
             .ok();
+        let on_timer_fn = instance
+            .get_typed_func::<u32, ()>(&mut store, "on_timer")
+            .ok();
+        let on_lobby_state_fn = instance
+            .get_typed_func::<u32, ()>(&mut store, "on_lobby_state")
+            .ok();
+        let on_player_join_fn = instance
+            .get_typed_func::<u32, ()>(&mut store, "on_player_join")
+            .ok();
+        let on_player_leave_fn = instance
+            .get_typed_func::<u32, ()>(&mut store, "on_player_leave")
+            .ok();
+        let predict_input_fn = instance
+            .get_typed_func::<(u32, u32), u32>(&mut store, "predict_input")
+            .ok();
 
         Ok(Self {
             store,
             instance,
             init_fn,
+            init_step_fn,
+            loading: false,
             update_fn,
             render_fn,
             on_debug_change_fn,
             post_connect_fn,
+            on_timer_fn,
+            on_lobby_state_fn,
+            on_player_join_fn,
+            on_player_leave_fn,
+            predict_input_fn,
+            predict_memory: Vec::new(),
+            fired_timers: Vec::new(),
+            call_timeout: CALL_TIMEOUT,
         })
     }
 
+    /// Set the watchdog deadline for `update()`, `render()` and other calls
+    ///
+    /// `init()` always gets [`INIT_TIMEOUT`].
+    pub fn set_call_timeout(&mut self, timeout: Duration) {
+        self.call_timeout = timeout;
+    }
+
+    /// Arm the watchdog for the next call into the game
+    fn arm_watchdog(&mut self, timeout: Duration) {
+        self.store
+            .set_epoch_deadline(watchdog::deadline_ticks(timeout));
+    }
+
     /// Call the game's init function
     pub fn init(&mut self) -> Result<()> {
         self.store.data_mut().game.in_init = true;
+        self.arm_watchdog(INIT_TIMEOUT);
         if let Some(init) = &self.init_fn {
             init.call(&mut self.store, ()).map_err(|e| {
+                if watchdog::is_interrupt(&e) {
+                    return WatchdogTimeout {
+                        export: "init",
+                        timeout: INIT_TIMEOUT,
+                    }
+                    .into();
+                }
                 // Extract more detailed error information from wasmtime
                 let error_msg = format!("WASM init() failed: {:#}", e);
                 eprintln!("{}", error_msg);
@@ -105,9 +183,48 @@ impl<I: ConsoleInput, S: Send + Default + 'static, R: ConsoleRollbackState> Game
             })?;
         }
         self.store.data_mut().game.in_init = false;
+        self.loading = self.init_step_fn.is_some();
         Ok(())
     }
 
+    /// Call the game's init_step function (initialization split across frames)
+    ///
+    /// Games that export `init_step() -> u32` get one call per frame after
+    /// `init()` until it returns non-zero, so the host can show a loading
+    /// screen instead of freezing. Init-only functions stay available during
+    /// each call, and each call gets its own [`INIT_TIMEOUT`].
+    ///
+    /// Returns true once loading has finished.
+    pub fn init_step(&mut self) -> Result<bool> {
+        if !self.loading {
+            return Ok(true);
+        }
+        self.store.data_mut().game.in_init = true;
+        self.arm_watchdog(INIT_TIMEOUT);
+        if let Some(init_step) = &self.init_step_fn {
+            let done = init_step.call(&mut self.store, ()).map_err(|e| {
+                if watchdog::is_interrupt(&e) {
+                    return WatchdogTimeout {
+                        export: "init_step",
+                        timeout: INIT_TIMEOUT,
+                    }
+                    .into();
+                }
+                let error_msg = format!("WASM init_step() failed: {:#}", e);
+                eprintln!("{}", error_msg);
+                anyhow::anyhow!(error_msg)
+            })?;
+            self.loading = done == 0;
+        }
+        self.store.data_mut().game.in_init = false;
+        Ok(!self.loading)
+    }
+
+    /// Check if the game is still loading (`init_step()` hasn't finished)
+    pub fn is_loading(&self) -> bool {
+        self.loading
+    }
+
     /// Call the game's post_connect function (two-phase initialization)
     ///
     /// This is called after NCHS handshake completes, when the game knows its
@@ -121,8 +238,17 @@ impl<I: ConsoleInput, S: Send + Default + 'static, R: ConsoleRollbackState> Game
     /// 4. `post_connect()` - Player-aware setup (CAN access player_handle)
     /// 5. Game loop begins
     pub fn post_connect(&mut self) -> Result<()> {
+        let timeout = self.call_timeout;
+        self.arm_watchdog(timeout);
         if let Some(post_connect) = &self.post_connect_fn {
             post_connect.call(&mut self.store, ()).map_err(|e| {
+                if watchdog::is_interrupt(&e) {
+                    return WatchdogTimeout {
+                        export: "post_connect",
+                        timeout,
+                    }
+                    .into();
+                }
                 let error_msg = format!("WASM post_connect() failed: {:#}", e);
                 eprintln!("{}", error_msg);
                 anyhow::anyhow!(error_msg)
@@ -144,8 +270,28 @@ impl<I: ConsoleInput, S: Send + Default + 'static, R: ConsoleRollbackState> Game
             state.elapsed_time += delta_time;
             state.tick_count += 1;
         }
+        let tick = self.store.data().game.tick_count;
+        self.store.data_mut().analytics.begin_tick(tick);
+        {
+            let ctx = self.store.data_mut();
+            let inputs = bytemuck::cast_slice(&ctx.game.input_curr);
+            ctx.rollback.begin_tick(inputs, delta_time);
+            ctx.rollback.queue_events(&mut ctx.game.events);
+        }
+        self.fire_timers()?;
+        self.notify_players()?;
+        self.notify_lobby_state();
+        let timeout = self.call_timeout;
+        self.arm_watchdog(timeout);
         if let Some(update) = &self.update_fn {
             update.call(&mut self.store, ()).map_err(|e| {
+                if watchdog::is_interrupt(&e) {
+                    return WatchdogTimeout {
+                        export: "update",
+                        timeout,
+                    }
+                    .into();
+                }
                 let error_msg = format!(
                     "WASM update() failed at tick {}: {:#}",
                     self.store.data().game.tick_count,
@@ -155,16 +301,171 @@ impl<I: ConsoleInput, S: Send + Default + 'static, R: ConsoleRollbackState> Game
                 anyhow::anyhow!(error_msg)
             })?;
         }
+        self.capture_ghost_frame();
         // Rotate input state
         let state = &mut self.store.data_mut().game;
         state.input_prev = state.input_curr;
         Ok(())
     }
 
+    /// Count down timers and call `on_timer()` for each one that expired
+    ///
+    /// Runs at the start of every tick, before `update()`. Timers still count
+    /// down if the game doesn't export `on_timer()`, they just do nothing.
+    /// Each expired timer also queues an `event_kind::TIMER` event.
+    fn fire_timers(&mut self) -> Result<()> {
+        let mut fired = std::mem::take(&mut self.fired_timers);
+        fired.clear();
+        let game = &mut self.store.data_mut().game;
+        game.timers.tick(&mut fired);
+        for &callback_id in &fired {
+            game.events
+                .push(Event::new(event_kind::TIMER, [callback_id, 0, 0, 0, 0]));
+        }
+
+        let timeout = self.call_timeout;
+        self.arm_watchdog(timeout);
+        let result = match &self.on_timer_fn {
+            Some(on_timer) => fired
+                .iter()
+                .try_for_each(|&callback_id| on_timer.call(&mut self.store, callback_id))
+                .map_err(|e| {
+                    if watchdog::is_interrupt(&e) {
+                        return WatchdogTimeout {
+                            export: "on_timer",
+                            timeout,
+                        }
+                        .into();
+                    }
+                    let error_msg = format!(
+                        "WASM on_timer() failed at tick {}: {:#}",
+                        self.store.data().game.tick_count,
+                        e
+                    );
+                    eprintln!("{}", error_msg);
+                    anyhow::anyhow!(error_msg)
+                }),
+            None => Ok(()),
+        };
+
+        self.fired_timers = fired;
+        result
+    }
+
+    /// Call `on_player_join()` / `on_player_leave()` for each player whose
+    /// controller connected or disconnected since the last tick
+    ///
+    /// Connection is part of each player's input, so joins and leaves happen on
+    /// the same tick for every peer and re-simulate exactly on rollback.
+    /// Controllers already connected when the game starts join on the first
+    /// tick. Each change also queues an `event_kind::PLAYER_JOIN` or
+    /// `event_kind::PLAYER_LEAVE` event.
+    fn notify_players(&mut self) -> Result<()> {
+        let game = &mut self.store.data_mut().game;
+        let mut changes = [(0u32, false); MAX_PLAYERS];
+        let mut count = 0;
+        for player in 0..(game.player_count as usize).min(MAX_PLAYERS) {
+            let connected = game.input_curr[player].connected();
+            if connected == game.input_prev[player].connected() {
+                continue;
+            }
+            let kind = if connected {
+                event_kind::PLAYER_JOIN
+            } else {
+                event_kind::PLAYER_LEAVE
+            };
+            game.events
+                .push(Event::new(kind, [player as u32, 0, 0, 0, 0]));
+            changes[count] = (player as u32, connected);
+            count += 1;
+        }
+
+        let timeout = self.call_timeout;
+        self.arm_watchdog(timeout);
+        for &(player, connected) in &changes[..count] {
+            let (callback, export) = if connected {
+                (&self.on_player_join_fn, "on_player_join")
+            } else {
+                (&self.on_player_leave_fn, "on_player_leave")
+            };
+            let Some(callback) = callback else {
+                continue;
+            };
+            callback.call(&mut self.store, player).map_err(|e| {
+                if watchdog::is_interrupt(&e) {
+                    return WatchdogTimeout { export, timeout }.into();
+                }
+                let error_msg = format!(
+                    "WASM {}() failed at tick {}: {:#}",
+                    export,
+                    self.store.data().game.tick_count,
+                    e
+                );
+                eprintln!("{}", error_msg);
+                anyhow::anyhow!(error_msg)
+            })?;
+        }
+        Ok(())
+    }
+
+    /// Call `on_lobby_state()` for each lobby state change since the last tick
+    ///
+    /// Lobby state is local to this player and outside the simulation, so a
+    /// failing callback is logged rather than treated as a game error.
+    fn notify_lobby_state(&mut self) {
+        let changes = self.store.data().lobby.take_state_changes();
+        let Some(on_lobby_state) = self.on_lobby_state_fn.clone() else {
+            return;
+        };
+        self.arm_watchdog(self.call_timeout);
+        for state in changes {
+            if let Err(e) = on_lobby_state.call(&mut self.store, state) {
+                tracing::warn!("on_lobby_state() failed: {}", e);
+                break;
+            }
+        }
+    }
+
+    /// Copy the registered ghost transform into the active recording
+    fn capture_ghost_frame(&mut self) {
+        let ctx = self.store.data();
+        let (Some(ptr), Some(memory)) = (ctx.ghosts.source_ptr, ctx.game.memory) else {
+            return;
+        };
+        if ctx.ghosts.recording.is_none() {
+            return;
+        }
+        let tick = ctx.game.tick_count;
+
+        let start = ptr as usize;
+        let Some(bytes) = memory
+            .data(&self.store)
+            .get(start..start + crate::ghost::GHOST_FRAME_SIZE)
+        else {
+            return;
+        };
+        let frame: crate::ghost::GhostFrame = std::array::from_fn(|i| {
+            f32::from_le_bytes(bytes[i * 4..i * 4 + 4].try_into().unwrap())
+        });
+
+        if let Some(recording) = self.store.data_mut().ghosts.recording.as_mut() {
+            recording.capture(tick, frame);
+        }
+    }
+
     /// Call the game's render function
     pub fn render(&mut self) -> Result<()> {
+        let timeout = self.call_timeout;
+        self.arm_watchdog(timeout);
         if let Some(render) = &self.render_fn {
             render.call(&mut self.store, ()).map_err(|e| {
+                if watchdog::is_interrupt(&e) {
+                    return WatchdogTimeout {
+                        export: "render",
+                        timeout,
+                    }
+                    .into();
+                }
                 let error_msg = format!("WASM render() failed: {:#}", e);
                 eprintln!("{}", error_msg);
                 anyhow::anyhow!(error_msg)
@@ -173,29 +474,42 @@ impl<I: ConsoleInput, S: Send + Default + 'static, R: ConsoleRollbackState> Game
         Ok(())
     }
 
-    /// Save entire WASM linear memory to a vector (automatic snapshotting)
-    ///
-    /// This snapshots the entire WASM linear memory transparently. Games do not need
-    /// to implement manual serialization - the entire memory is saved for rollback.
-    /// Save entire WASM linear memory to a vector (automatic snapshotting)
+    /// Save WASM linear memory to a vector (automatic snapshotting)
     ///
     /// This snapshots the entire WASM linear memory transparently. Games do not need
     /// to implement manual serialization - the entire memory is saved for rollback.
+    /// If the game registered regions with `state_register()`, only those regions
+    /// are saved, concatenated in registration order.
     pub fn save_state(&mut self) -> Result<Vec<u8>> {
-        let memory = self
-            .store
-            .data()
-            .game
-            .memory
-            .context("No memory export found")?;
+        let mut snapshot = Vec::new();
+        self.save_state_into(&mut snapshot)?;
+        Ok(snapshot)
+    }
+
+    /// Save WASM linear memory (or the registered regions) into `snapshot`
+    ///
+    /// Like `save_state()`, but reuses the buffer's allocation.
+    pub fn save_state_into(&self, snapshot: &mut Vec<u8>) -> Result<()> {
+        let game = &self.store.data().game;
+        let memory = game.memory.context("No memory export found")?;
         let mem_data = memory.data(&self.store);
-        Ok(mem_data.to_vec())
+        snapshot.clear();
+        if game.state_regions.is_empty() {
+            snapshot.extend_from_slice(mem_data);
+            return Ok(());
+        }
+
+        for &(ptr, len) in &game.state_regions {
+            // Memory only grows, so regions validated at registration stay in bounds
+            snapshot.extend_from_slice(&mem_data[ptr as usize..ptr as usize + len as usize]);
+        }
+        Ok(())
     }
 
-    /// Load entire WASM linear memory from a snapshot (automatic snapshotting)
+    /// Load WASM linear memory from a snapshot (automatic snapshotting)
     ///
-    /// Restores the entire WASM linear memory from a previous snapshot.
-    /// This is the inverse of `save_state()`.
+    /// Restores the entire WASM linear memory (or the registered state regions)
+    /// from a previous snapshot. This is the inverse of `save_state()`.
     pub fn load_state(&mut self, snapshot: &[u8]) -> Result<()> {
         let memory = self
             .store
@@ -203,14 +517,32 @@ impl<I: ConsoleInput, S: Send + Default + 'static, R: ConsoleRollbackState> Game
             .game
             .memory
             .context("No memory export found")?;
-        let mem_data = memory.data_mut(&mut self.store);
+        let (mem_data, context) = memory.data_and_store_mut(&mut self.store);
+        let regions = &context.game.state_regions;
+        if regions.is_empty() {
+            anyhow::ensure!(
+                snapshot.len() == mem_data.len(),
+                "Snapshot size mismatch: {} vs {}",
+                snapshot.len(),
+                mem_data.len()
+            );
+            mem_data.copy_from_slice(snapshot);
+            return Ok(());
+        }
+
+        let total: usize = regions.iter().map(|&(_, len)| len as usize).sum();
         anyhow::ensure!(
-            snapshot.len() == mem_data.len(),
-            "Snapshot size mismatch: {} vs {}",
+            snapshot.len() == total,
+            "Snapshot size mismatch: {} vs {} in registered regions",
             snapshot.len(),
-            mem_data.len()
+            total
         );
-        mem_data.copy_from_slice(snapshot);
+        let mut offset = 0;
+        for &(ptr, len) in regions {
+            let (ptr, len) = (ptr as usize, len as usize);
+            mem_data[ptr..ptr + len].copy_from_slice(&snapshot[offset..offset + len]);
+            offset += len;
+        }
         Ok(())
     }
 
@@ -270,6 +602,105 @@ impl<I: ConsoleInput, S: Send + Default + 'static, R: ConsoleRollbackState> Game
         }
     }
 
+    /// Predict a remote player's input from the one GGRS would repeat
+    ///
+    /// Convenience wrapper around [`predict_inputs`](Self::predict_inputs)
+    /// for a single predicted player.
+    pub fn predict_input(&mut self, player: usize, held: I, ticks: u32) -> Result<I> {
+        let mut inputs = vec![(I::default(), false); player + 1];
+        inputs[player] = (held, true);
+        Ok(self.predict_inputs(&inputs, ticks)?[player])
+    }
+
+    /// Predict the in-flight inputs of one tick
+    ///
+    /// `inputs` holds each player's input and whether it is GGRS's prediction
+    /// (the held input) rather than a confirmed one. Each predicted input
+    /// goes through the player's `predict_policy()`. Under
+    /// `PredictPolicy::Custom` the game's `predict_input(player, ticks)`
+    /// export is called with the held input as the player's current input,
+    /// and returns a pointer to the predicted input in the console's input
+    /// layout, or 0 to keep the held one. `ticks` counts how far the tick is
+    /// past the last confirmed one (1 = the first predicted tick).
+    ///
+    /// # Errors
+    /// Returns an error if the game state `predict_input()` ran against
+    /// can't be put back. The state would no longer match the peers', so the
+    /// session has to end.
+    pub fn predict_inputs(&mut self, inputs: &[(I, bool)], ticks: u32) -> Result<Vec<I>> {
+        let policies = self.store.data().game.predict_policies;
+        let policy = |player: usize| policies.get(player).copied().unwrap_or_default();
+        let custom = self.predict_input_fn.is_some()
+            && inputs.iter().enumerate().any(|(player, &(_, predicted))| {
+                predicted && policy(player) == PredictPolicy::Custom
+            });
+
+        // One snapshot serves every call this tick
+        let saved = if custom {
+            let mut memory = std::mem::take(&mut self.predict_memory);
+            self.save_state_into(&mut memory)?;
+            Some(PredictScope::capture(self, memory))
+        } else {
+            None
+        };
+
+        let mut fed = Vec::with_capacity(inputs.len());
+        let mut result = Ok(());
+        for (player, &(held, predicted)) in inputs.iter().enumerate() {
+            let input = match (predicted, policy(player), &saved) {
+                (false, _, _) | (true, PredictPolicy::Hold, _) => held,
+                (true, PredictPolicy::Neutral, _) => held.neutral(),
+                (true, PredictPolicy::Custom, Some(saved)) if result.is_ok() => {
+                    let input = self.call_predict_input(player, held, ticks);
+                    result = saved.restore(self);
+                    input
+                }
+                (true, PredictPolicy::Custom, _) => held,
+            };
+            fed.push(input);
+        }
+
+        if let Some(saved) = saved {
+            self.predict_memory = saved.memory;
+        }
+        result.map(|()| fed)
+    }
+
+    /// Call `predict_input()`, falling back to `held` if it fails
+    ///
+    /// Memory can't grow during the call, so the state snapshotted before it
+    /// can always be restored.
+    fn call_predict_input(&mut self, player: usize, held: I, ticks: u32) -> I {
+        let Some(predict_input) = self.predict_input_fn.clone() else {
+            return held;
+        };
+        self.state_mut().input_curr[player] = held;
+        self.state_mut().predicting = true;
+        self.arm_watchdog(self.call_timeout);
+        let predicted = match predict_input.call(&mut self.store, (player as u32, ticks)) {
+            Ok(0) => held,
+            Ok(ptr) => self.read_input(ptr as usize).unwrap_or_else(|| {
+                tracing::warn!("predict_input() returned out-of-bounds pointer {}", ptr);
+                held
+            }),
+            Err(e) => {
+                tracing::warn!("predict_input() failed: {}", e);
+                held
+            }
+        };
+        self.state_mut().predicting = false;
+        predicted
+    }
+
+    /// Read an input in the console's layout from WASM memory
+    fn read_input(&self, ptr: usize) -> Option<I> {
+        let memory = self.store.data().game.memory?;
+        let bytes = memory
+            .data(&self.store)
+            .get(ptr..ptr + std::mem::size_of::<I>())?;
+        Some(bytemuck::pod_read_unaligned(bytes))
+    }
+
     /// Configure the session's player count and local player mask
     ///
     /// This should be called before `init()` to set up multiplayer state.
@@ -277,7 +708,7 @@ impl<I: ConsoleInput, S: Send + Default + 'static, R: ConsoleRollbackState> Game
     /// `local_player_mask()` FFI functions.
     ///
     /// # Arguments
-    /// * `player_count` - Number of players in session (1-4)
+    /// * `player_count` - Number of players in session (1-8)
     /// * `local_player_mask` - Bitmask of local players (bit N = player N is local)
     ///
     /// # Example
@@ -299,6 +730,7 @@ impl<I: ConsoleInput, S: Send + Default + 'static, R: ConsoleRollbackState> Game
     /// This is called when debug values are modified through the debug panel.
     /// Games can optionally export this function to react to debug value changes.
     pub fn call_on_debug_change(&mut self) {
+        self.arm_watchdog(self.call_timeout);
         if let Some(func) = &self.on_debug_change_fn
             && let Err(e) = func.call(&mut self.store, ())
         {
@@ -331,9 +763,56 @@ impl<I: ConsoleInput, S: Send + Default + 'static, R: ConsoleRollbackState> Game
             .collect();
 
         // Call with no expected results (fire and forget)
+        self.arm_watchdog(self.call_timeout);
         func.call(&mut self.store, &vals, &mut [])
             .with_context(|| format!("Failed to call action '{}'", func_name))?;
 
         Ok(())
     }
 }
+
+/// Game state `predict_input()` may write to, restored after each call
+///
+/// The call runs between a tick's saved state and its `update()`, and peers
+/// predict different players at different times. Anything it writes
+/// (scratch buffers, the shadow stack, RNG draws) would land in the next
+/// snapshot and desync the checksums. Only what that snapshot covers is
+/// captured: the registered state regions if the game has any, otherwise the
+/// whole memory.
+struct PredictScope<I: ConsoleInput, R> {
+    memory: Vec<u8>,
+    rollback: R,
+    inputs: [I; MAX_PLAYERS],
+    rng_state: u64,
+    rng_streams: crate::rng::RngStreams,
+    timers: crate::timer::TimerTable,
+    events: crate::event::EventQueue,
+}
+
+impl<I: ConsoleInput, R: ConsoleRollbackState> PredictScope<I, R> {
+    fn capture<S: Send + Default + 'static>(game: &GameInstance<I, S, R>, memory: Vec<u8>) -> Self {
+        let state = game.state();
+        Self {
+            memory,
+            rollback: game.rollback_state().clone(),
+            inputs: state.input_curr,
+            rng_state: state.rng_state,
+            rng_streams: state.rng_streams,
+            timers: state.timers,
+            events: state.events,
+        }
+    }
+
+    fn restore<S: Send + Default + 'static>(&self, game: &mut GameInstance<I, S, R>) -> Result<()> {
+        game.load_state(&self.memory)
+            .context("predict_input() changed game state that can't be restored")?;
+        *game.rollback_state_mut() = self.rollback.clone();
+        let state = game.state_mut();
+        state.input_curr = self.inputs;
+        state.rng_state = self.rng_state;
+        state.rng_streams = self.rng_streams;
+        state.timers = self.timers;
+        state.events = self.events;
+        Ok(())
+    }
+}
@@ -7,6 +7,7 @@
 //! - [`state`] - Core game state structure (console-agnostic)
 //! - [`engine`] - WASM engine for loading and compiling modules
 //! - [`instance`] - Game instance for executing WASM games
+//! - [`watchdog`] - Deadlines for runaway game calls
 //!
 //! # Key Types
 //!
@@ -18,6 +19,7 @@
 mod engine;
 mod instance;
 pub mod state;
+pub mod watchdog;
 
 #[cfg(test)]
 mod tests;
@@ -25,10 +27,12 @@ mod tests;
 // Re-export main types
 pub use engine::WasmEngine;
 pub use instance::GameInstance;
+pub use watchdog::WatchdogTimeout;
 
 // Re-export public types from state module
 #[allow(deprecated)]
 pub use state::{
-    GameState, GameStateWithConsole, MAX_PLAYERS, MAX_SAVE_SIZE, MAX_SAVE_SLOTS, MemoryAccessError,
-    WasmGameContext, read_bytes_from_memory, read_string_from_memory, write_bytes_to_memory,
+    GameState, GameStateWithConsole, MAX_PLAYERS, MAX_SAVE_SIZE, MAX_SAVE_SLOTS, MAX_STATE_REGIONS,
+    MemoryAccessError, WasmGameContext, read_bytes_from_memory, read_string_from_memory,
+    write_bytes_to_memory,
 };
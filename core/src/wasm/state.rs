@@ -66,9 +66,12 @@ fn checked_range(ptr: u32, len: usize, data_len: usize) -> Option<std::ops::Rang
 
 use crate::debug::ffi::HasDebugRegistry;
 use crate::debug::registry::DebugRegistry;
+use crate::net::chat::ChatChannel;
+use crate::net::lobby::LobbyChannel;
+use crate::net::voice::VoiceChannel;
 
 /// Maximum number of players
-pub const MAX_PLAYERS: usize = 4;
+pub const MAX_PLAYERS: usize = 8;
 
 /// Maximum number of save slots
 pub const MAX_SAVE_SLOTS: usize = 4;
@@ -76,6 +79,9 @@ pub const MAX_SAVE_SLOTS: usize = 4;
 /// Maximum save data size per slot (64KB)
 pub const MAX_SAVE_SIZE: usize = 64 * 1024;
 
+/// Maximum number of memory regions registered with `state_register()`
+pub const MAX_STATE_REGIONS: usize = 64;
+
 /// Default RAM limit used as a fallback for tests and tooling.
 pub const DEFAULT_RAM_LIMIT: usize = 4 * 1024 * 1024;
 
@@ -109,7 +115,7 @@ pub struct GameState<I: ConsoleInput> {
     /// Bitmask of local players (bit N = player N is local)
     pub local_player_mask: u32,
 
-    /// Local player handle for netplay (0-3), or None if not connected
+    /// Local player handle for netplay (0-7), or None if not connected
     ///
     /// Set after NCHS handshake completes, before post_connect() is called.
     /// Games can query this via player_handle() FFI.
@@ -118,23 +124,66 @@ pub struct GameState<I: ConsoleInput> {
     /// Whether we're currently in init phase
     pub in_init: bool,
 
+    /// Memory regions (ptr, len) registered via `state_register()`
+    ///
+    /// When empty, rollback snapshots the entire linear memory. Otherwise only
+    /// these regions are saved and restored, in registration order.
+    pub state_regions: Vec<(u32, u32)>,
+
     /// RNG state for deterministic random
     pub rng_state: u64,
 
+    /// Pending `timer_after()` / `timer_every()` timers (rolled back with the RNG)
+    pub timers: crate::timer::TimerTable,
+
+    /// `rng_create()` streams (rolled back with the RNG)
+    pub rng_streams: crate::rng::RngStreams,
+
+    /// Unread `event_poll()` events (rolled back with the RNG)
+    pub events: crate::event::EventQueue,
+
     /// Input state for all players (previous and current frame)
     pub input_prev: [I; MAX_PLAYERS],
     pub input_curr: [I; MAX_PLAYERS],
 
+    /// How each player's input is predicted while in flight (`predict_policy()`)
+    ///
+    /// A hint for the host, never rolled back: predictions are corrected
+    /// whatever the policy.
+    pub predict_policies: [crate::rollback::PredictPolicy; MAX_PLAYERS],
+
+    /// Whether `predict_input()` is running (memory can't grow meanwhile)
+    pub predicting: bool,
+
     /// Save data slots (8 slots ÁE64KB max each)
     pub save_data: [Option<Vec<u8>>; MAX_SAVE_SLOTS],
 
     /// Quit requested by game
     pub quit_requested: bool,
 
+    /// Tournament series standings (host-managed, set before init)
+    ///
+    /// None unless the player was started with a best-of-N series.
+    pub series: Option<crate::series::SeriesState>,
+
+    /// Round winner reported via `series_round_end()`, consumed by the host
+    pub series_round_result: Option<u32>,
+
+    /// Daily challenge read via `daily_seed()` (host-managed, set before init)
+    pub daily: crate::daily::DailyChallenge,
+
     /// Debug frame control state (synced from host before each frame)
     /// Only active in local/offline mode; disabled during netplay.
     pub debug_paused: bool,
     pub debug_time_scale: f32,
+
+    /// Whether the F4 debug inspector is open (synced from host before each frame)
+    /// Gates the `debug_*` gizmo drawing functions.
+    pub debug_inspector_open: bool,
+
+    /// Names of the local players' controllers (synced from host before each
+    /// frame, empty for remote players). Display only, never rolled back.
+    pub controller_names: [String; MAX_PLAYERS],
 }
 
 /// Context for WASM game execution
@@ -155,8 +204,27 @@ pub struct WasmGameContext<I: ConsoleInput, S, R: ConsoleRollbackState = ()> {
     pub ram_limit: usize,
     /// Active save store for this game (host-managed persistent storage)
     pub save_store: Option<crate::save_store::SaveStore>,
+    /// Publisher-wide unlock tokens (host-managed, never rolled back)
+    pub token_store: Option<crate::token_store::TokenStore>,
+    /// Host capabilities declared by the ROM
+    pub capabilities: nethercore_shared::Capabilities,
+    /// Content hash of the running ROM (zero if unknown)
+    pub rom_hash: [u8; 32],
     /// Debug inspection registry (for runtime value inspection)
     pub debug_registry: DebugRegistry,
+    /// Voice chat channel (host-side, never rolled back)
+    pub voice: VoiceChannel,
+    /// Text chat channel (host-side, never rolled back)
+    pub chat: ChatChannel,
+    /// In-game lobby channel (host-side, never rolled back; disabled unless
+    /// the runtime attaches a lobby driver)
+    pub lobby: LobbyChannel,
+    /// Ghost recording and playback (host-side, never rolled back)
+    pub ghosts: crate::ghost::Ghosts,
+    /// Save slot thumbnails (host-side, never rolled back)
+    pub thumbnails: crate::thumbnail::Thumbnails,
+    /// Opt-in analytics events (host-side, never rolled back)
+    pub analytics: crate::analytics::Analytics,
 }
 
 /// Type alias for backward compatibility
@@ -171,7 +239,16 @@ impl<I: ConsoleInput, S: Default, R: ConsoleRollbackState> Default for WasmGameC
             rollback: R::default(),
             ram_limit: DEFAULT_RAM_LIMIT, // Fallback default (use ConsoleSpecs in production)
             save_store: None,
+            token_store: None,
+            capabilities: nethercore_shared::Capabilities::ALL,
+            rom_hash: [0; 32],
             debug_registry: DebugRegistry::new(),
+            voice: VoiceChannel::default(),
+            chat: ChatChannel::default(),
+            lobby: LobbyChannel::default(),
+            ghosts: crate::ghost::Ghosts::default(),
+            thumbnails: crate::thumbnail::Thumbnails::default(),
+            analytics: crate::analytics::Analytics::default(),
         }
     }
 }
@@ -190,7 +267,16 @@ impl<I: ConsoleInput, S: Default, R: ConsoleRollbackState> WasmGameContext<I, S,
             rollback: R::default(),
             ram_limit,
             save_store: None,
+            token_store: None,
+            capabilities: nethercore_shared::Capabilities::ALL,
+            rom_hash: [0; 32],
             debug_registry: DebugRegistry::new(),
+            voice: VoiceChannel::default(),
+            chat: ChatChannel::default(),
+            lobby: LobbyChannel::default(),
+            ghosts: crate::ghost::Ghosts::default(),
+            thumbnails: crate::thumbnail::Thumbnails::default(),
+            analytics: crate::analytics::Analytics::default(),
         }
     }
 }
@@ -220,8 +306,9 @@ impl<I: ConsoleInput, S: Send, R: ConsoleRollbackState> ResourceLimiter
         desired: usize,
         _maximum: Option<usize>,
     ) -> Result<bool, anyhow::Error> {
-        // Allow growth only if it stays within the RAM limit
-        Ok(desired <= self.ram_limit)
+        // Allow growth only if it stays within the RAM limit. predict_input()
+        // can't grow memory: the host has to put it back exactly as it was.
+        Ok(!self.game.predicting && desired <= self.ram_limit)
     }
 
     fn table_growing(
@@ -247,16 +334,37 @@ impl<I: ConsoleInput> GameState<I> {
             local_player_mask: 1,
             local_player_handle: None,
             in_init: true,
+            state_regions: Vec::new(),
             rng_state: 0,
+            timers: Default::default(),
+            rng_streams: Default::default(),
+            events: Default::default(),
             input_prev: [I::default(); MAX_PLAYERS],
             input_curr: [I::default(); MAX_PLAYERS],
+            predict_policies: Default::default(),
+            predicting: false,
             save_data: Default::default(),
             quit_requested: false,
+            series: None,
+            series_round_result: None,
+            daily: crate::daily::DailyChallenge::today(),
             debug_paused: false,
             debug_time_scale: 1.0,
+            debug_inspector_open: false,
+            controller_names: Default::default(),
         }
     }
 
+    /// Whether any player in the session is on another machine
+    ///
+    /// Per-machine host state (like unlock tokens) must not reach game logic
+    /// while this is true, or peers would simulate different games.
+    pub fn has_remote_players(&self) -> bool {
+        let players = self.player_count.min(MAX_PLAYERS as u32);
+        let all_players = (1u32 << players) - 1;
+        self.local_player_mask & all_players != all_players
+    }
+
     /// Seed the RNG with a deterministic value
     pub fn seed_rng(&mut self, seed: u64) {
         self.rng_state = seed;
@@ -264,14 +372,7 @@ impl<I: ConsoleInput> GameState<I> {
 
     /// Generate a deterministic random u32 using PCG algorithm
     pub fn random(&mut self) -> u32 {
-        // PCG-XSH-RR algorithm
-        let old_state = self.rng_state;
-        self.rng_state = old_state
-            .wrapping_mul(6364136223846793005)
-            .wrapping_add(1442695040888963407);
-        let xor_shifted = (((old_state >> 18) ^ old_state) >> 27) as u32;
-        let rot = (old_state >> 59) as u32;
-        xor_shifted.rotate_right(rot)
+        crate::rng::pcg32(&mut self.rng_state)
     }
 }
 
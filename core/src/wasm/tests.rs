@@ -88,6 +88,41 @@ fn test_game_instance_with_init_function() {
     assert!(!game.state().in_init);
 }
 
+#[test]
+fn test_game_instance_init_step_runs_until_done() {
+    let engine = WasmEngine::new().unwrap();
+    let wasm = wat::parse_str(
+        r#"
+        (module
+            (memory (export "memory") 1)
+            (global $steps (mut i32) (i32.const 0))
+            (func (export "init"))
+            (func (export "init_step") (result i32)
+                (global.set $steps (i32.add (global.get $steps) (i32.const 1)))
+                (i32.ge_u (global.get $steps) (i32.const 3))
+            )
+        )
+    "#,
+    )
+    .unwrap();
+    let module = engine.load_module(&wasm).unwrap();
+    let linker = wasmtime::Linker::new(engine.engine());
+
+    let mut game = GameInstance::<TestInput, ()>::new(&engine, &module, &linker).unwrap();
+    assert!(!game.is_loading());
+    game.init().unwrap();
+    assert!(game.is_loading());
+
+    assert!(!game.init_step().unwrap());
+    assert!(!game.init_step().unwrap());
+    assert!(game.init_step().unwrap());
+    assert!(!game.is_loading());
+    assert!(!game.state().in_init);
+
+    // Further steps are no-ops once loading has finished
+    assert!(game.init_step().unwrap());
+}
+
 #[test]
 fn test_game_instance_with_update_function() {
     let engine = WasmEngine::new().unwrap();
@@ -134,6 +169,29 @@ fn test_game_instance_update_increments_tick() {
     }
 }
 
+#[test]
+fn test_game_instance_update_watchdog_timeout() {
+    let engine = WasmEngine::new().unwrap();
+    let wasm = wat::parse_str(
+        r#"
+        (module
+            (memory (export "memory") 1)
+            (func (export "update") (loop (br 0)))
+        )
+    "#,
+    )
+    .unwrap();
+    let module = engine.load_module(&wasm).unwrap();
+    let linker = wasmtime::Linker::new(engine.engine());
+
+    let mut game = GameInstance::<TestInput, ()>::new(&engine, &module, &linker).unwrap();
+    game.set_call_timeout(std::time::Duration::from_millis(50));
+
+    let err = game.update(1.0 / 60.0).unwrap_err();
+    let timeout = err.downcast_ref::<WatchdogTimeout>().unwrap();
+    assert_eq!(timeout.export, "update");
+}
+
 #[test]
 fn test_game_instance_update_accumulates_elapsed_time() {
     let engine = WasmEngine::new().unwrap();
@@ -375,7 +433,7 @@ fn test_game_instance_configure_session_clamps_players() {
 
     // Try to set more than MAX_PLAYERS
     game.configure_session(100, 0xFFFF);
-    assert_eq!(game.state().player_count, 4); // Clamped to MAX_PLAYERS
+    assert_eq!(game.state().player_count, 8); // Clamped to MAX_PLAYERS
 }
 
 // ============================================================================
@@ -505,6 +563,57 @@ fn test_game_instance_load_state_restores_data() {
     assert_eq!(&restored[..4], &[0xDE, 0xAD, 0xBE, 0xEF]);
 }
 
+#[test]
+fn test_game_instance_state_regions_snapshot() {
+    let engine = WasmEngine::new().unwrap();
+    let wasm = wat::parse_str(
+        r#"
+        (module
+            (import "env" "state_register" (func $state_register (param i32 i32) (result i32)))
+            (memory (export "memory") 1)
+            (func (export "init")
+                ;; Two regions; the overlapping and out-of-bounds ones are rejected
+                (drop (call $state_register (i32.const 16) (i32.const 4)))
+                (drop (call $state_register (i32.const 64) (i32.const 8)))
+                (i32.store (i32.const 100) (call $state_register (i32.const 18) (i32.const 4)))
+                (i32.store (i32.const 104) (call $state_register (i32.const 65535) (i32.const 2)))
+            )
+            (func (export "update")
+                (i32.store (i32.const 108) (call $state_register (i32.const 200) (i32.const 4)))
+            )
+        )
+    "#,
+    )
+    .unwrap();
+    let module = engine.load_module(&wasm).unwrap();
+    let mut linker = wasmtime::Linker::new(engine.engine());
+    crate::ffi::register_common_ffi(&mut linker).unwrap();
+
+    let mut game = GameInstance::<TestInput, ()>::new(&engine, &module, &linker).unwrap();
+    game.init().unwrap();
+    game.update(1.0 / 60.0).unwrap();
+    assert_eq!(game.state().state_regions, [(16, 4), (64, 8)]);
+
+    // Rejected registrations returned 0, including the one after init
+    let memory = game.state().memory.unwrap();
+    assert_eq!(&memory.data(game.store())[100..112], &[0; 12]);
+
+    // Only the registered regions are snapshotted
+    memory.data_mut(game.store_mut())[16..20].copy_from_slice(&[1, 2, 3, 4]);
+    memory.data_mut(game.store_mut())[64] = 9;
+    let snapshot = game.save_state().unwrap();
+    assert_eq!(snapshot, [1, 2, 3, 4, 9, 0, 0, 0, 0, 0, 0, 0]);
+
+    // Loading restores the regions and leaves the rest of memory alone
+    memory.data_mut(game.store_mut())[16..20].fill(0xFF);
+    memory.data_mut(game.store_mut())[300] = 7;
+    game.load_state(&snapshot).unwrap();
+    assert_eq!(&memory.data(game.store())[16..20], &[1, 2, 3, 4]);
+    assert_eq!(memory.data(game.store())[300], 7);
+
+    assert!(game.load_state(&[0; 65536]).is_err());
+}
+
 #[test]
 fn test_game_instance_init_trap_propagates() {
     let engine = WasmEngine::new().unwrap();
@@ -0,0 +1,61 @@
+//! Watchdog for runaway game code
+//!
+//! Uses wasmtime epoch interruption: a background thread bumps the engine
+//! epoch every [`EPOCH_INTERVAL`], and the host sets a deadline before each
+//! call into the game. A call that overruns its deadline traps with
+//! [`wasmtime::Trap::Interrupt`] and is reported as a [`WatchdogTimeout`]
+//! instead of freezing the host.
+
+use std::time::Duration;
+
+use wasmtime::{Engine, Trap};
+
+/// How often the engine epoch advances (watchdog resolution)
+pub const EPOCH_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Time allowed for `init()` (may decode assets or build large tables)
+pub const INIT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Default time allowed for a single `update()`, `render()` or other call
+pub const CALL_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// A game call that didn't return before its watchdog deadline
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+#[error("game not responding: {export}() did not return within {timeout:?}")]
+pub struct WatchdogTimeout {
+    /// Export that was running (`init`, `update`, `render`, ...)
+    pub export: &'static str,
+    /// Deadline that was exceeded
+    pub timeout: Duration,
+}
+
+/// Number of epoch ticks covering `timeout` (at least one)
+pub(super) fn deadline_ticks(timeout: Duration) -> u64 {
+    (timeout.as_millis() / EPOCH_INTERVAL.as_millis()).max(1) as u64
+}
+
+/// Whether an error from a WASM call is a watchdog interrupt
+pub(super) fn is_interrupt(error: &anyhow::Error) -> bool {
+    matches!(error.downcast_ref::<Trap>(), Some(Trap::Interrupt))
+}
+
+/// Spawn the thread that advances `engine`'s epoch
+///
+/// The thread exits once the engine has been dropped.
+pub(super) fn start_epoch_ticker(engine: &Engine) {
+    let weak = engine.weak();
+    let spawned = std::thread::Builder::new()
+        .name("wasm-watchdog".to_string())
+        .spawn(move || {
+            loop {
+                std::thread::sleep(EPOCH_INTERVAL);
+                match weak.upgrade() {
+                    Some(engine) => engine.increment_epoch(),
+                    None => break,
+                }
+            }
+        });
+    if let Err(e) = spawned {
+        tracing::error!("Failed to start WASM watchdog thread: {}", e);
+    }
+}
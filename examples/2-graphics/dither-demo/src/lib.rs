@@ -96,7 +96,7 @@ pub extern "C" fn init() {
 unsafe fn register_debug_values() {
     // Display mode
     debug_group_begin(b"display".as_ptr(), 7);
-    debug_register_i32(b"mode (0=compare, 1=single)".as_ptr(), 25, &DISPLAY_MODE as *const i32 as *const u8);
+    debug_register_enum(b"mode".as_ptr(), 4, &DISPLAY_MODE as *const i32 as *const u8, b"Compare\0Single\0".as_ptr(), 2);
     debug_group_end();
 
     // Alpha levels group
@@ -0,0 +1,20 @@
+//! Value stepping for the on-screen editor
+//!
+//! Free of FFI calls, so the host tests can include it directly.
+
+/// Step a field value, wrapping within 0..=max
+pub fn wrap_step(value: u8, delta: i32, max: u8) -> u8 {
+    (value as i32 + delta).rem_euclid(max as i32 + 1) as u8
+}
+
+/// Step a field value, clamping to 0..=max
+pub fn clamp_step(value: u8, delta: i32, max: u8) -> u8 {
+    (value as i32 + delta).clamp(0, max as i32) as u8
+}
+
+/// Step one 8-bit channel of an RGBA color (channel 0 = red)
+pub fn step_channel(color: u32, channel: u32, delta: i32) -> u32 {
+    let shift = 24 - channel * 8;
+    let value = clamp_step((color >> shift) as u8, delta, 255);
+    (color & !(0xFF << shift)) | ((value as u32) << shift)
+}
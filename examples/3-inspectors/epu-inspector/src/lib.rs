@@ -11,14 +11,22 @@
 //! 5. Toggle "isolate" to view only the selected layer
 //! 6. Click "export hex" to print all layers to console
 //!
+//! Without the debug panel, press Start to open the on-screen editor: L1/R1
+//! select the layer, Up/Down the field and Left/Right change it (hold A for
+//! coarse steps). Select prints the layers as a Rust array.
+//!
 //! ## Features
 //!
 //! - **Layer editing**: All 8 EPU layers accessible via layer selector
 //! - **Live preview**: Changes reflect immediately in the viewport
+//! - **On-screen editor**: Gamepad-driven field editing with the packed
+//!   `[hi, lo]` words of every layer shown live
 //! - **Isolation mode**: View single layer contribution
 //! - **Direction helpers**: Azimuth/elevation instead of raw octahedral encoding
 //! - **Param hints**: Dynamic hints showing what each param does per opcode
 //! - **Export**: Copy hex values for use in preset files
+//! - **Presets**: Load example configurations (crescent moon, solar eclipse,
+//!   retro synthwave)
 
 #![no_std]
 #![no_main]
@@ -34,6 +42,9 @@ fn panic(_info: &PanicInfo) -> ! {
 mod ffi;
 use ffi::*;
 
+mod field_step;
+use field_step::{clamp_step, step_channel, wrap_step};
+
 // Generated EPU metadata from WGSL files (build.rs)
 #[allow(dead_code)]
 mod epu_meta {
@@ -109,6 +120,10 @@ static mut EDITOR: EditorState = EditorState::default();
 static mut LAYER_INDEX: u8 = 1; // 1-8 (user-facing)
 static mut ISOLATE_LAYER: u8 = 0; // bool
 static mut SHOW_HINTS: u8 = 1; // bool
+static mut ONSCREEN_EDIT: u8 = 0; // bool
+
+/// Field under the on-screen editor cursor (index into FIELD_NAMES)
+static mut FIELD_CURSOR: usize = 0;
 
 /// Track previous layer index for change detection
 static mut PREV_LAYER_INDEX: u8 = 1;
@@ -131,6 +146,61 @@ static mut BROWSE_COPY_LAYER: u8 = 0;
 static mut BROWSE_PASTE_LAYER: u8 = 0;
 static mut EXPORT_RUST: u8 = 0;
 
+/// Preset load triggers (set by button press handlers)
+static mut LOAD_PRESET_CRESCENT: u8 = 0;
+static mut LOAD_PRESET_ECLIPSE: u8 = 0;
+static mut LOAD_PRESET_SYNTHWAVE: u8 = 0;
+
+// ============================================================================
+// Presets
+// ============================================================================
+
+/// Night sky with a crescent moon and a small ringed planet
+///
+/// - L5: CELESTIAL/MOON, phase ~140 degrees (crisp terminator + earthshine)
+/// - L6: CELESTIAL/PLANET with the RINGS flag (domain_id = 1)
+const PRESET_CRESCENT: [[u64; 2]; 8] = [
+    [0x0F00_060A_1C0A_0A10, 0x401A_2038_A5FF_80FF], // RAMP night sky
+    [0, 0],
+    [0, 0],
+    [0, 0],
+    [0x5400_DDE4_FFFF_E8C0, 0xC8A0_1440_0700_00F0], // SCATTER stars
+    [0x8400_E8EC_F460_70A0, 0x962B_2864_00ED_DBF4], // CELESTIAL crescent moon
+    [0x840A_D8B0_80C0_A070, 0x8C14_3C28_50D7_14FC], // CELESTIAL ringed planet
+    [0, 0],
+];
+
+/// Dusk sky with a partial solar eclipse
+///
+/// - L5: CELESTIAL/SUN with the OCCLUDED flag (domain_id = 2);
+///   param_c slides the occluder across the disk
+const PRESET_ECLIPSE: [[u64; 2]; 8] = [
+    [0x0F00_2030_6020_1810, 0x60D0_7040_A6FF_80FF], // RAMP dusk
+    [0, 0],
+    [0, 0],
+    [0, 0],
+    [0x8411_FFF0_D0FF_A040, 0xE61E_3C96_80F6_E0FA], // CELESTIAL eclipsed sun
+    [0, 0],
+    [0, 0],
+    [0, 0],
+];
+
+/// Retro synthwave: neon floor grid running toward a big sun on the horizon
+///
+/// - L1: GRID/FLOOR, spacing 0.5, accent every 4th line, slight planet
+///   curvature (param_c = 0x46); the up axis makes +X forward, so raising
+///   param_d scrolls the lines toward a viewer facing the sun
+const PRESET_SYNTHWAVE: [[u64; 2]; 8] = [
+    [0x0F00_1A05_3008_0010, 0x4030_0840_A5FF_80FF], // RAMP purple dusk
+    [0x4900_FF20_C0FF_90FF, 0xFF1D_1546_00FF_80FF], // GRID neon floor
+    [0, 0],
+    [0, 0],
+    [0x5400_DDE4_FFFF_E8C0, 0xC8A0_1440_0700_00F0], // SCATTER stars
+    [0x8401_FFC0_40FF_2080, 0xE630_2000_6090_EEFC], // CELESTIAL horizon sun
+    [0, 0],
+    [0, 0],
+];
+
 /// Mesh handles for reference objects
 static mut SPHERE_MESH: u32 = 0;
 static mut CUBE_MESH: u32 = 0;
@@ -332,6 +402,7 @@ unsafe fn register_debug_panel() {
     debug_register_u8_range(b"layer (1-8)".as_ptr(), 11, &LAYER_INDEX, 1, 8);
     debug_register_bool(b"isolate".as_ptr(), 7, &ISOLATE_LAYER);
     debug_register_bool(b"hints".as_ptr(), 5, &SHOW_HINTS);
+    debug_register_bool(b"on-screen edit".as_ptr(), 14, &raw const ONSCREEN_EDIT);
     debug_group_end();
 
     // =========================================================================
@@ -374,6 +445,30 @@ unsafe fn register_debug_panel() {
     );
     debug_group_end();
 
+    // =========================================================================
+    // presets/ group - Load complete example configurations
+    // =========================================================================
+    debug_group_begin(b"presets".as_ptr(), 7);
+    debug_register_action(
+        b"crescent moon".as_ptr(),
+        13,
+        b"load_preset_crescent".as_ptr(),
+        20,
+    );
+    debug_register_action(
+        b"solar eclipse".as_ptr(),
+        13,
+        b"load_preset_eclipse".as_ptr(),
+        19,
+    );
+    debug_register_action(
+        b"retro synthwave".as_ptr(),
+        15,
+        b"load_preset_synthwave".as_ptr(),
+        21,
+    );
+    debug_group_end();
+
     // =========================================================================
     // raw/hi group - Hi word fields (opcode, region, blend, colors)
     // =========================================================================
@@ -591,6 +686,27 @@ pub extern "C" fn do_export_rust_action() {
     }
 }
 
+#[no_mangle]
+pub extern "C" fn load_preset_crescent() {
+    unsafe {
+        LOAD_PRESET_CRESCENT = 1;
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn load_preset_eclipse() {
+    unsafe {
+        LOAD_PRESET_ECLIPSE = 1;
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn load_preset_synthwave() {
+    unsafe {
+        LOAD_PRESET_SYNTHWAVE = 1;
+    }
+}
+
 // ============================================================================
 // Export Rust Action
 // ============================================================================
@@ -679,11 +795,18 @@ pub extern "C" fn init() {
 #[no_mangle]
 pub extern "C" fn update() {
     unsafe {
-        let layer_idx = (LAYER_INDEX - 1) as usize;
-
         // Handle browse action buttons
         handle_browse_actions();
 
+        // On-screen editor input (may change the selected layer)
+        if button_pressed(0, button::START) != 0 {
+            ONSCREEN_EDIT ^= 1;
+        }
+        if ONSCREEN_EDIT != 0 {
+            handle_onscreen_edit();
+        }
+        let layer_idx = (LAYER_INDEX - 1) as usize;
+
         // Check if layer index changed
         if LAYER_INDEX != PREV_LAYER_INDEX {
             // Save current editor state to previous layer
@@ -726,10 +849,10 @@ pub extern "C" fn update() {
 // Browse Action Handlers
 // ============================================================================
 
-/// Find the next valid opcode (wrapping from 0x13 to 0x01)
+/// Find the next valid opcode (wrapping from 0x14 to 0x01)
 fn next_valid_opcode(current: u8) -> u8 {
     let mut next = current + 1;
-    if next > 0x13 {
+    if next > 0x14 {
         next = 0x01;
     }
     // Skip opcode 0x00 (NOP) - not useful for editing
@@ -739,10 +862,10 @@ fn next_valid_opcode(current: u8) -> u8 {
     next
 }
 
-/// Find the previous valid opcode (wrapping from 0x01 to 0x13)
+/// Find the previous valid opcode (wrapping from 0x01 to 0x14)
 fn prev_valid_opcode(current: u8) -> u8 {
     if current <= 0x01 {
-        return 0x13;
+        return 0x14;
     }
     current - 1
 }
@@ -828,6 +951,31 @@ unsafe fn handle_browse_actions() {
         EXPORT_RUST = 0;
         do_export_rust();
     }
+
+    // Load presets
+    if LOAD_PRESET_CRESCENT != 0 {
+        LOAD_PRESET_CRESCENT = 0;
+        load_preset(&PRESET_CRESCENT);
+        log_str("Loaded preset: crescent moon");
+    }
+    if LOAD_PRESET_ECLIPSE != 0 {
+        LOAD_PRESET_ECLIPSE = 0;
+        load_preset(&PRESET_ECLIPSE);
+        log_str("Loaded preset: solar eclipse");
+    }
+    if LOAD_PRESET_SYNTHWAVE != 0 {
+        LOAD_PRESET_SYNTHWAVE = 0;
+        load_preset(&PRESET_SYNTHWAVE);
+        log_str("Loaded preset: retro synthwave");
+    }
+}
+
+/// Replace all layers and reload the selected layer into the editor
+unsafe fn load_preset(preset: &[[u64; 2]; 8]) {
+    LAYERS = *preset;
+    let idx = (LAYER_INDEX - 1) as usize;
+    unpack_layer(LAYERS[idx][0], LAYERS[idx][1]);
+    PREV_OPCODE = EDITOR.opcode;
 }
 
 #[no_mangle]
@@ -914,14 +1062,293 @@ unsafe fn draw_ui() {
     // Parameter hints
     draw_hints();
 
+    if ONSCREEN_EDIT != 0 {
+        draw_onscreen_editor();
+    }
+
     // Control hints at bottom
     set_color(0x666666FF);
     let hint1 = b"F4: Debug Panel | X: Cycle shapes | Left stick: Orbit";
     draw_text(hint1.as_ptr(), hint1.len() as u32, 10.0, 200.0, 12.0);
-    let hint2 = b"Edit layer values to see live changes";
+    let hint2: &[u8] = if ONSCREEN_EDIT != 0 {
+        b"L1/R1: Layer | Up/Down: Field | Left/Right: Change (hold A: x16) | Select: Export"
+    } else {
+        b"Start: On-screen editor | Edit layer values to see live changes"
+    };
     draw_text(hint2.as_ptr(), hint2.len() as u32, 10.0, 214.0, 12.0);
 }
 
+// ============================================================================
+// On-Screen Editor
+// ============================================================================
+
+/// Fields the on-screen editor can step through, in display order
+const FIELD_NAMES: [&[u8]; 20] = [
+    b"opcode",
+    b"region",
+    b"blend",
+    b"domain_id",
+    b"variant_id",
+    b"color_a.r",
+    b"color_a.g",
+    b"color_a.b",
+    b"color_b.r",
+    b"color_b.g",
+    b"color_b.b",
+    b"intensity",
+    b"param_a",
+    b"param_b",
+    b"param_c",
+    b"param_d",
+    b"azimuth",
+    b"elevation",
+    b"alpha_a",
+    b"alpha_b",
+];
+
+/// Change the field under the cursor by `steps` (negative = down)
+unsafe fn step_field(field: usize, steps: i32, coarse: bool) {
+    let byte_delta = if coarse { steps * 16 } else { steps };
+    let angle_delta = steps as f32 * if coarse { 45.0 } else { 5.0 };
+    match field {
+        0 => {
+            for _ in 0..steps.unsigned_abs() {
+                EDITOR.opcode = if steps > 0 {
+                    next_valid_opcode(EDITOR.opcode)
+                } else {
+                    prev_valid_opcode(EDITOR.opcode)
+                };
+            }
+        }
+        1 => {
+            let region =
+                (EDITOR.region_sky << 2) | (EDITOR.region_walls << 1) | EDITOR.region_floor;
+            let region = wrap_step(region, steps, 7);
+            EDITOR.region_sky = (region >> 2) & 1;
+            EDITOR.region_walls = (region >> 1) & 1;
+            EDITOR.region_floor = region & 1;
+        }
+        2 => EDITOR.blend = wrap_step(EDITOR.blend, steps, 7),
+        3 => EDITOR.domain_id = wrap_step(EDITOR.domain_id, steps, 3),
+        4 => EDITOR.variant_id = wrap_step(EDITOR.variant_id, steps, 7),
+        5..=7 => EDITOR.color_a = step_channel(EDITOR.color_a, field as u32 - 5, byte_delta),
+        8..=10 => EDITOR.color_b = step_channel(EDITOR.color_b, field as u32 - 8, byte_delta),
+        11 => EDITOR.intensity = clamp_step(EDITOR.intensity, byte_delta, 255),
+        12 => EDITOR.param_a = clamp_step(EDITOR.param_a, byte_delta, 255),
+        13 => EDITOR.param_b = clamp_step(EDITOR.param_b, byte_delta, 255),
+        14 => EDITOR.param_c = clamp_step(EDITOR.param_c, byte_delta, 255),
+        15 => EDITOR.param_d = clamp_step(EDITOR.param_d, byte_delta, 255),
+        16 => {
+            let azimuth = EDITOR.azimuth + angle_delta;
+            EDITOR.azimuth = azimuth - 360.0 * libm::floorf(azimuth / 360.0);
+        }
+        17 => EDITOR.elevation = (EDITOR.elevation + angle_delta).clamp(-90.0, 90.0),
+        18 => EDITOR.alpha_a = clamp_step(EDITOR.alpha_a, steps, 15),
+        _ => EDITOR.alpha_b = clamp_step(EDITOR.alpha_b, steps, 15),
+    }
+}
+
+/// Gamepad controls for the on-screen editor
+unsafe fn handle_onscreen_edit() {
+    // Layer selection; update() saves and reloads the editor on change
+    if button_pressed(0, button::L1) != 0 {
+        LAYER_INDEX = if LAYER_INDEX <= 1 { 8 } else { LAYER_INDEX - 1 };
+    }
+    if button_pressed(0, button::R1) != 0 {
+        LAYER_INDEX = if LAYER_INDEX >= 8 { 1 } else { LAYER_INDEX + 1 };
+    }
+
+    let field_count = FIELD_NAMES.len();
+    if button_pressed(0, button::UP) != 0 {
+        FIELD_CURSOR = (FIELD_CURSOR + field_count - 1) % field_count;
+    }
+    if button_pressed(0, button::DOWN) != 0 {
+        FIELD_CURSOR = (FIELD_CURSOR + 1) % field_count;
+    }
+
+    let coarse = button_held(0, button::A) != 0;
+    if button_pressed(0, button::LEFT) != 0 {
+        step_field(FIELD_CURSOR, -1, coarse);
+    }
+    if button_pressed(0, button::RIGHT) != 0 {
+        step_field(FIELD_CURSOR, 1, coarse);
+    }
+
+    if button_pressed(0, button::SELECT) != 0 {
+        do_export_rust();
+    }
+}
+
+/// Small fixed-size text buffer for building UI lines without `alloc`
+struct Line {
+    buf: [u8; 64],
+    len: usize,
+}
+
+impl Line {
+    fn new() -> Self {
+        Self {
+            buf: [0; 64],
+            len: 0,
+        }
+    }
+
+    fn push(&mut self, bytes: &[u8]) -> &mut Self {
+        self.len += copy_slice(&mut self.buf[self.len..], bytes);
+        self
+    }
+
+    /// Pad with spaces up to `width` characters (for column alignment)
+    fn pad_to(&mut self, width: usize) -> &mut Self {
+        while self.len < width.min(self.buf.len()) {
+            self.push(b" ");
+        }
+        self
+    }
+
+    fn push_dec(&mut self, value: i32) -> &mut Self {
+        if value < 0 {
+            self.push(b"-");
+        }
+        let mut digits = [0u8; 10];
+        let mut n = value.unsigned_abs();
+        let mut count = 0;
+        loop {
+            digits[digits.len() - 1 - count] = b'0' + (n % 10) as u8;
+            count += 1;
+            n /= 10;
+            if n == 0 {
+                break;
+            }
+        }
+        self.push(&digits[digits.len() - count..])
+    }
+
+    fn push_hex_u64(&mut self, value: u64) -> &mut Self {
+        let mut hex = [0u8; 16];
+        write_hex_u64(&mut hex, value);
+        self.push(&hex)
+    }
+
+    fn draw(&self, x: f32, y: f32, size: f32) {
+        unsafe {
+            draw_text(self.buf.as_ptr(), self.len as u32, x, y, size);
+        }
+    }
+}
+
+/// Append the display value of an editor field
+unsafe fn push_field_value(line: &mut Line, field: usize) {
+    let channel = |color: u32, channel: u32| ((color >> (24 - channel * 8)) & 0xFF) as i32;
+    match field {
+        0 => {
+            line.push_dec(EDITOR.opcode as i32).push(b" ");
+            line.push(get_opcode_hints(EDITOR.opcode).0);
+        }
+        1 => {
+            line.push(if EDITOR.region_sky != 0 { b"S" } else { b"-" });
+            line.push(if EDITOR.region_walls != 0 { b"W" } else { b"-" });
+            line.push(if EDITOR.region_floor != 0 { b"F" } else { b"-" });
+        }
+        2 => {
+            line.push_dec(EDITOR.blend as i32).push(b" ");
+            line.push(get_blend_name(EDITOR.blend));
+        }
+        3 => {
+            line.push_dec(EDITOR.domain_id as i32);
+        }
+        4 => {
+            line.push_dec(EDITOR.variant_id as i32).push(b" ");
+            line.push(get_variant_hint(EDITOR.opcode, EDITOR.variant_id));
+        }
+        5..=7 => {
+            line.push_dec(channel(EDITOR.color_a, field as u32 - 5));
+        }
+        8..=10 => {
+            line.push_dec(channel(EDITOR.color_b, field as u32 - 8));
+        }
+        11 => {
+            line.push_dec(EDITOR.intensity as i32);
+        }
+        12 => {
+            line.push_dec(EDITOR.param_a as i32);
+        }
+        13 => {
+            line.push_dec(EDITOR.param_b as i32);
+        }
+        14 => {
+            line.push_dec(EDITOR.param_c as i32);
+        }
+        15 => {
+            line.push_dec(EDITOR.param_d as i32);
+        }
+        16 => {
+            line.push_dec(libm::roundf(EDITOR.azimuth) as i32);
+        }
+        17 => {
+            line.push_dec(libm::roundf(EDITOR.elevation) as i32);
+        }
+        18 => {
+            line.push_dec(EDITOR.alpha_a as i32);
+        }
+        _ => {
+            line.push_dec(EDITOR.alpha_b as i32);
+        }
+    }
+}
+
+/// Field list for the selected layer plus the packed words of all layers
+unsafe fn draw_onscreen_editor() {
+    let x = 560.0;
+    let line_height = 14.0;
+    let mut y = 10.0;
+
+    let (hi, lo) = pack_layer();
+    let mut header = Line::new();
+    header.push(b"Layer ").push_dec(LAYER_INDEX as i32);
+    header.push(b"  [0x").push_hex_u64(hi);
+    header.push(b", 0x").push_hex_u64(lo).push(b"]");
+    set_color(0xFFFFFFFF);
+    header.draw(x, y, 12.0);
+    y += line_height + 4.0;
+
+    for (field, name) in FIELD_NAMES.iter().enumerate() {
+        let selected = field == FIELD_CURSOR;
+        let mut line = Line::new();
+        line.push(if selected { b"> " } else { b"  " });
+        line.push(name);
+        line.pad_to(14);
+        push_field_value(&mut line, field);
+        set_color(if selected { 0xFFFF00FF } else { 0xBBBBBBFF });
+        line.draw(x, y, 12.0);
+        y += line_height;
+    }
+    y += 6.0;
+
+    // Packed words of every layer, as they'd be exported
+    // (the selected layer uses the editor's words, which update() stores
+    // before the next frame)
+    let layers = LAYERS;
+    for (i, layer) in layers.iter().enumerate() {
+        let (hi, lo) = if i + 1 == LAYER_INDEX as usize {
+            (hi, lo)
+        } else {
+            (layer[0], layer[1])
+        };
+        let mut line = Line::new();
+        line.push_dec(i as i32 + 1).push(b" ");
+        line.push_hex_u64(hi).push(b" ").push_hex_u64(lo).push(b" ");
+        line.push(get_opcode_hints(((hi >> 59) & 0x1F) as u8).0);
+        set_color(if i + 1 == LAYER_INDEX as usize {
+            0x88FF88FF
+        } else {
+            0x888888FF
+        });
+        line.draw(x, y, 10.0);
+        y += 12.0;
+    }
+}
+
 // ============================================================================
 // Opcode Hint Data
 // ============================================================================
@@ -1000,10 +1427,10 @@ fn get_opcode_hints(
         0x09 => (
             b"GRID",
             b"brightness",
-            b"scale",
-            b"thickness",
-            b"pat+scroll",
-            b"phase",
+            b"spacing",
+            b"width",
+            b"accent+curve",
+            b"scroll",
         ),
         0x0A => (
             b"SCATTER",
@@ -1085,6 +1512,14 @@ fn get_opcode_hints(
             b"softness",
             b"phase",
         ),
+        0x14 => (
+            b"FLASH",
+            b"brightness",
+            b"period",
+            b"seed",
+            b"branch+width",
+            b"phase",
+        ),
         _ => (b"UNKNOWN", b"-", b"-", b"-", b"-", b"-"),
     }
 }
@@ -1101,6 +1536,14 @@ fn get_variant_hint(opcode: u8, variant_id: u8) -> &'static [u8] {
             4 => b"RAGGED",
             _ => b"",
         },
+        0x09 => match variant_id {
+            // GRID
+            0 => b"FLOOR",
+            1 => b"TUBE",
+            2 => b"WALLS",
+            3 => b"SCANLINES",
+            _ => b"",
+        },
         0x0E => match variant_id {
             // ATMOSPHERE
             0 => b"ABSORPTION",
@@ -1142,6 +1585,12 @@ fn get_variant_hint(opcode: u8, variant_id: u8) -> &'static [u8] {
             5 => b"RIFT",
             _ => b"",
         },
+        0x14 => match variant_id {
+            // FLASH
+            0 => b"FORK",
+            1 => b"SHEET",
+            _ => b"",
+        },
         _ => b"", // No variants for this opcode
     }
 }
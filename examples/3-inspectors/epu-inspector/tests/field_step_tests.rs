@@ -0,0 +1,46 @@
+//! Tests for the on-screen editor's value stepping.
+
+#[path = "../src/field_step.rs"]
+mod field_step;
+
+use field_step::*;
+
+#[test]
+fn test_wrap_step_wraps_both_ways() {
+    assert_eq!(wrap_step(3, 1, 7), 4);
+    assert_eq!(wrap_step(7, 1, 7), 0);
+    assert_eq!(wrap_step(0, -1, 7), 7);
+    // Coarse steps wrap past the range more than once
+    assert_eq!(wrap_step(2, 16, 7), 2);
+    assert_eq!(wrap_step(2, -19, 7), 7);
+}
+
+#[test]
+fn test_wrap_step_full_byte() {
+    assert_eq!(wrap_step(255, 1, 255), 0);
+    assert_eq!(wrap_step(0, -16, 255), 240);
+}
+
+#[test]
+fn test_clamp_step_stops_at_bounds() {
+    assert_eq!(clamp_step(10, 16, 15), 15);
+    assert_eq!(clamp_step(3, -16, 15), 0);
+    assert_eq!(clamp_step(250, 16, 255), 255);
+    assert_eq!(clamp_step(8, -1, 15), 7);
+}
+
+#[test]
+fn test_step_channel_changes_one_channel() {
+    let color = 0x10203040;
+    assert_eq!(step_channel(color, 0, 1), 0x11203040);
+    assert_eq!(step_channel(color, 1, -16), 0x10103040);
+    assert_eq!(step_channel(color, 2, 16), 0x10204040);
+    assert_eq!(step_channel(color, 3, -1), 0x1020303F);
+}
+
+#[test]
+fn test_step_channel_clamps() {
+    assert_eq!(step_channel(0xFF000000, 0, 16), 0xFF000000);
+    assert_eq!(step_channel(0x00FFFFFF, 0, -16), 0x00FFFFFF);
+    assert_eq!(step_channel(0x000000F8, 3, 16), 0x000000FF);
+}
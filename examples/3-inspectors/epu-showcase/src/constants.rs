@@ -122,6 +122,8 @@ pub const OP_RAMP: u64 = 0x01;
 // 0x06: PATCHES (bounds)
 // 0x07: APERTURE (bounds)
 pub const OP_DECAL: u64 = 0x08;
+/// GRID - Perspective line grids with accents and curvature
+/// Variants: 0=FLOOR, 1=TUBE, 2=WALLS, 3=SCANLINES
 pub const OP_GRID: u64 = 0x09;
 pub const OP_SCATTER: u64 = 0x0A;
 pub const OP_FLOW: u64 = 0x0B;
@@ -156,7 +158,7 @@ pub const OP_PATCHES: u64 = 0x06;
 pub const OP_APERTURE: u64 = 0x07;
 
 // =============================================================================
-// Radiance Opcodes (0x0C-0x13)
+// Radiance Opcodes (0x0C-0x14)
 // =============================================================================
 
 /// TRACE - Line/crack patterns (lightning, cracks, lead lines, filaments)
@@ -192,6 +194,10 @@ pub const OP_LOBE: u64 = 0x12;
 /// BAND - Horizon band
 pub const OP_BAND: u64 = 0x13;
 
+/// FLASH - Scheduled lightning strikes
+/// Variants: 0=FORK, 1=SHEET
+pub const OP_FLASH: u64 = 0x14;
+
 // =============================================================================
 // Domain ID Constants (for meta5 encoding)
 // =============================================================================
@@ -252,6 +258,12 @@ pub const VEIL_LASER_BARS: u64 = 2;
 pub const VEIL_RAIN_WALL: u64 = 3;
 pub const VEIL_SHARDS: u64 = 4;
 
+// GRID variants
+pub const GRID_FLOOR: u64 = 0;
+pub const GRID_TUBE: u64 = 1;
+pub const GRID_WALLS: u64 = 2;
+pub const GRID_SCANLINES: u64 = 3;
+
 // SCATTER variants
 pub const SCATTER_STARS: u64 = 0;
 pub const SCATTER_DUST: u64 = 1;
@@ -287,6 +299,10 @@ pub const CELESTIAL_RINGED: u64 = 4;
 pub const CELESTIAL_BINARY: u64 = 5;
 pub const CELESTIAL_ECLIPSE: u64 = 6;
 
+// CELESTIAL flags (passed in the domain slot of hi_meta)
+pub const CELESTIAL_FLAG_RINGS: u64 = 1;
+pub const CELESTIAL_FLAG_OCCLUDED: u64 = 2;
+
 // PORTAL variants
 pub const PORTAL_CIRCLE: u64 = 0;
 pub const PORTAL_RECT: u64 = 1;
@@ -295,6 +311,10 @@ pub const PORTAL_VORTEX: u64 = 3;
 pub const PORTAL_CRACK: u64 = 4;
 pub const PORTAL_RIFT: u64 = 5;
 
+// FLASH variants
+pub const FLASH_FORK: u64 = 0;
+pub const FLASH_SHEET: u64 = 1;
+
 // SPLIT variants
 pub const SPLIT_HALF: u64 = 0;
 pub const SPLIT_WEDGE: u64 = 1;
@@ -259,6 +259,7 @@ fn opcode_name(opcode: u8) -> &'static [u8] {
         0x11 => b"PORTAL",
         0x12 => b"LOBE",
         0x13 => b"BAND",
+        0x14 => b"FLASH",
         _ => b"???",
     }
 }
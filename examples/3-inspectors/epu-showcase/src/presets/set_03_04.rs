@@ -115,7 +115,7 @@ pub(super) const PRESET_OCEAN_DEPTHS: [[u64; 2]; 8] = [
 // light spill washes the floor and panels, keeping the sphere reflection readable.
 //
 // L0: SECTOR/BOX           ALL        LERP  hard room bounds
-// L1: GRID                 WALLS|FLOOR ADD  subtle panel lines (animated)
+// L1: GRID/WALLS           WALLS|FLOOR ADD  subtle panel lines (animated)
 // L2: PLANE/GRATING        FLOOR      LERP  deck grating
 // L3: APERTURE/RND_RECT    ALL        LERP  viewport frame + region tag
 // L4: SCATTER/STARS        SKY        ADD   stars only in the viewport
@@ -136,18 +136,19 @@ pub(super) const PRESET_VOID_STATION: [[u64; 2]; 8] = [
         ),
         lo(230, 145, 0, 0, 0, DIR_UP, 15, 15),
     ],
-    // L1: GRID - panel lines (slightly more visible)
+    // L1: GRID/WALLS - panel lines (slightly more visible)
     [
-        hi(
+        hi_meta(
             OP_GRID,
             REGION_WALLS | REGION_FLOOR,
             BLEND_ADD,
-            0,
+            DOMAIN_DIRECT3D,
+            GRID_WALLS,
             0x3a4858, // brighter panel lines
-            0x000000,
+            0x506478, // panel seams every 4th line
         ),
-        // scale, thickness, pattern=GRID, slow scroll; phase animates via ANIM_SPEEDS
-        lo(22, 110, 16, 0x14, 0, 0, 10, 0),
+        // spacing=0.75, accent every 4th line; phase scrolls via ANIM_SPEEDS
+        lo(22, 45, 16, 0x40, 0, DIR_UP, 10, 8),
     ],
     // L2: PLANE/GRATING - deck plating
     [
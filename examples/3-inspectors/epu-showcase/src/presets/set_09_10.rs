@@ -16,7 +16,7 @@ use crate::constants::*;
 // L0: RAMP                  ALL        LERP   blazing sunset gradient (orange to violet)
 // L1: SILHOUETTE/RUINS      SKY        LERP   broken colonnades against blazing sky
 // L2: PLANE/STONE           FLOOR      LERP   warm cream marble platforms
-// L3: GRID                  FLOOR      ADD    subtle marble tile lines
+// L3: GRID/FLOOR            FLOOR      ADD    subtle marble tile lines
 // L4: VEIL/CURTAINS         SKY        SCREEN billowing golden clouds
 // L5: FLOW (noise)          SKY        SCREEN warm cloud drift (animated)
 // L6: BAND                  SKY        ADD    intense sun break band (animated)
@@ -54,10 +54,19 @@ pub(super) const PRESET_SKY_RUINS: [[u64; 2]; 8] = [
         ),
         lo(255, 60, 25, 140, 0, DIR_UP, 15, 12),
     ],
-    // L3: GRID - marble tile lines (subtle warm)
+    // L3: GRID/FLOOR - marble tile lines (subtle warm)
     [
-        hi(OP_GRID, REGION_FLOOR, BLEND_ADD, 0, 0xffe8d0, 0x000000),
-        lo(40, 90, 3, 0x10, 0, 0, 8, 0),
+        hi_meta(
+            OP_GRID,
+            REGION_FLOOR,
+            BLEND_ADD,
+            DOMAIN_DIRECT3D,
+            GRID_FLOOR,
+            0xffe8d0,
+            0x000000,
+        ),
+        // spacing=1.0, thin lines, no accents
+        lo(40, 61, 10, 0x00, 0, DIR_UP, 8, 0),
     ],
     // L4: VEIL/CURTAINS - billowing golden clouds
     [
@@ -110,8 +119,8 @@ pub(super) const PRESET_SKY_RUINS: [[u64; 2]; 8] = [
 //
 // L0: SECTOR/BOX           ALL         LERP   bright clinical white bounds
 // L1: PLANE/TILES          FLOOR       LERP   clean white tile floor
-// L2: GRID                 FLOOR       ADD    vivid cyan scanning grid (animated)
-// L3: GRID                 WALLS       ADD    cyan wall scan lines (animated)
+// L2: GRID/FLOOR           FLOOR       ADD    vivid cyan scanning grid (animated)
+// L3: GRID/SCANLINES       WALLS       ADD    cyan wall scan lines (animated)
 // L4: DECAL/RECT           WALLS       ADD    glowing HUD panels (animated)
 // L5: PORTAL/RECT          WALLS       ADD    holographic display volume (animated)
 // L6: LOBE                 ALL         ADD    harsh fluorescent overhead key
@@ -143,29 +152,32 @@ pub(super) const PRESET_COMBAT_LAB: [[u64; 2]; 8] = [
         ),
         lo(255, 80, 10, 160, 0, DIR_UP, 15, 10),
     ],
-    // L2: GRID - vivid cyan scanning grid on floor (animated)
+    // L2: GRID/FLOOR - vivid cyan scanning grid on floor (animated)
     [
-        hi(
+        hi_meta(
             OP_GRID,
             REGION_FLOOR,
             BLEND_ADD,
-            0,
+            DOMAIN_DIRECT3D,
+            GRID_FLOOR,
             0x00ffff, // vivid cyan
-            0x000000,
+            0xa0ffff, // pale cyan accents
         ),
-        lo(180, 120, 6, 0x18, 0, 0, 15, 0),
+        // spacing=0.5, accent every 4th line
+        lo(180, 29, 26, 0x40, 0, DIR_UP, 15, 15),
     ],
-    // L3: GRID - cyan wall scan lines (animated)
+    // L3: GRID/SCANLINES - cyan wall scan lines (animated)
     [
-        hi(
+        hi_meta(
             OP_GRID,
             REGION_WALLS,
             BLEND_ADD,
-            0,
+            DOMAIN_DIRECT3D,
+            GRID_SCANLINES,
             0x00e0ff, // bright cyan
             0x000000,
         ),
-        lo(120, 200, 4, 0x20, 0, 0, 14, 0),
+        lo(120, 6, 47, 0x00, 0, DIR_UP, 14, 0),
     ],
     // L4: DECAL/RECT - glowing HUD panels on walls (bright cyan/green)
     [
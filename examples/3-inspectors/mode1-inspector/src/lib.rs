@@ -395,14 +395,14 @@ fn hsv_to_rgb(h: f32, s: f32, v: f32) -> (u8, u8, u8) {
 unsafe fn register_debug_values() {
     // Shape group
     debug_group_begin(b"shape".as_ptr(), 5);
-    debug_register_i32(b"shape".as_ptr(), 5, &SHAPE_INDEX as *const i32 as *const u8);
+    debug_register_enum(b"shape".as_ptr(), 5, &SHAPE_INDEX as *const i32 as *const u8, b"Sphere\0Cube\0Torus\0".as_ptr(), 3);
     debug_register_f32(b"rotation".as_ptr(), 8, &ROTATION_SPEED as *const f32 as *const u8);
     debug_register_color(b"color".as_ptr(), 5, &OBJECT_COLOR as *const u32 as *const u8);
     debug_group_end();
 
     // Rendering group
     debug_group_begin(b"rendering".as_ptr(), 9);
-    debug_register_i32(b"filter".as_ptr(), 6, &FILTER_MODE as *const i32 as *const u8);
+    debug_register_enum(b"filter".as_ptr(), 6, &FILTER_MODE as *const i32 as *const u8, b"Nearest\0Linear\0".as_ptr(), 2);
     debug_group_end();
 
     // Matcap slot 1
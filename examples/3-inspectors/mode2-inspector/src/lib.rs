@@ -159,7 +159,7 @@ pub extern "C" fn init() {
 unsafe fn register_debug_values() {
     // Shape group
     debug_group_begin(b"shape".as_ptr(), 5);
-    debug_register_i32(b"index (0-2)".as_ptr(), 11, &SHAPE_INDEX as *const i32 as *const u8);
+    debug_register_enum(b"index".as_ptr(), 5, &SHAPE_INDEX as *const i32 as *const u8, b"Sphere\0Cube\0Torus\0".as_ptr(), 3);
     debug_register_f32(b"rotation_speed".as_ptr(), 14, &ROTATION_SPEED as *const f32 as *const u8);
     debug_register_color(b"color".as_ptr(), 5, &OBJECT_COLOR as *const u32 as *const u8);
     debug_group_end();
@@ -4,14 +4,17 @@
 //! - 2D gameplay using 3D renderer (side-scrolling view)
 //! - Textured sprites for player/enemies
 //! - Billboarded sprites in 3D space
-//! - Simple physics (gravity, friction)
+//! - Simple physics (gravity, friction) in deterministic fixed point (`nethercore-fixed`)
 //! - AABB collision detection (platforms, collectibles)
 //! - Multiple players with analog stick input
-//! - 2D UI overlay with `draw_text()`, `draw_rect()`
+//! - 2D UI overlay with `draw_text()` and `draw_nine_slice()` panels
 //! - Sky background with `set_sky()`
 //! - Rollback-safe game state (all state in statics)
 //!
 //! Note: Rollback state is automatic (entire WASM memory is snapshotted). No save_state/load_state needed.
+//!
+//! Gameplay state uses `Fx32` (Q16.16) instead of `f32`, so every peer
+//! re-simulates rollback frames bit-for-bit. Floats are only used for rendering.
 
 #![no_std]
 #![no_main]
@@ -29,6 +32,7 @@ fn panic(_info: &PanicInfo) -> ! {
 #[path = "../../../../include/zx/mod.rs"]
 mod ffi;
 use ffi::*;
+use nethercore_fixed::Fx32;
 
 
 // === Constants ===
@@ -42,17 +46,25 @@ const MODE_CYLINDRICAL_Y: u32 = 2;
 
 // Game constants
 const MAX_PLAYERS: usize = 4;
-const GRAVITY: f32 = 0.5;
-const JUMP_FORCE: f32 = 12.0;
-const MOVE_SPEED: f32 = 5.0;
-const FRICTION: f32 = 0.85;
-const PLAYER_WIDTH: f32 = 0.8;
-const PLAYER_HEIGHT: f32 = 1.2;
+const GRAVITY: Fx32 = Fx32::from_f32(0.5);
+const JUMP_FORCE: Fx32 = Fx32::from_int(12);
+const MOVE_SPEED: Fx32 = Fx32::from_int(5);
+const FRICTION: Fx32 = Fx32::from_f32(0.85);
+const PLAYER_WIDTH: Fx32 = Fx32::from_f32(0.8);
+const PLAYER_HEIGHT: Fx32 = Fx32::from_f32(1.2);
+
+// Velocity is in units per 10 ticks
+const VELOCITY_SCALE: Fx32 = Fx32::from_f32(0.1);
+// Stick deflection needed to turn around
+const FACING_DEADZONE: Fx32 = Fx32::from_f32(0.3);
+// How far into a platform still counts as landing on / bumping into it
+const LANDING_TOLERANCE: Fx32 = Fx32::from_f32(0.1);
+const HEAD_TOLERANCE: Fx32 = Fx32::from_f32(0.2);
 
 // Level bounds
-const LEVEL_LEFT: f32 = -12.0;
-const LEVEL_RIGHT: f32 = 12.0;
-const LEVEL_BOTTOM: f32 = -2.0;
+const LEVEL_LEFT: Fx32 = Fx32::from_int(-12);
+const LEVEL_RIGHT: Fx32 = Fx32::from_int(12);
+const LEVEL_BOTTOM: Fx32 = Fx32::from_int(-2);
 
 // Platforms
 const MAX_PLATFORMS: usize = 12;
@@ -70,10 +82,10 @@ const PLAYER_COLORS: [u32; 4] = [
 
 #[derive(Clone, Copy)]
 struct Player {
-    x: f32,
-    y: f32,
-    vx: f32,
-    vy: f32,
+    x: Fx32,
+    y: Fx32,
+    vx: Fx32,
+    vy: Fx32,
     on_ground: bool,
     facing_right: bool,
     score: u32,
@@ -83,10 +95,10 @@ struct Player {
 impl Player {
     const fn new() -> Self {
         Self {
-            x: 0.0,
-            y: 0.0,
-            vx: 0.0,
-            vy: 0.0,
+            x: Fx32::ZERO,
+            y: Fx32::ZERO,
+            vx: Fx32::ZERO,
+            vy: Fx32::ZERO,
             on_ground: false,
             facing_right: true,
             score: 0,
@@ -97,42 +109,61 @@ impl Player {
 
 #[derive(Clone, Copy)]
 struct Platform {
-    x: f32,
-    y: f32,
-    width: f32,
-    height: f32,
+    x: Fx32,
+    y: Fx32,
+    width: Fx32,
+    height: Fx32,
     active: bool,
 }
 
 impl Platform {
     const fn new() -> Self {
         Self {
-            x: 0.0,
-            y: 0.0,
-            width: 0.0,
-            height: 0.0,
+            x: Fx32::ZERO,
+            y: Fx32::ZERO,
+            width: Fx32::ZERO,
+            height: Fx32::ZERO,
             active: false,
         }
     }
+
+    const fn at(x: f32, y: f32, width: f32, height: f32) -> Self {
+        Self {
+            x: Fx32::from_f32(x),
+            y: Fx32::from_f32(y),
+            width: Fx32::from_f32(width),
+            height: Fx32::from_f32(height),
+            active: true,
+        }
+    }
 }
 
 #[derive(Clone, Copy)]
 struct Collectible {
-    x: f32,
-    y: f32,
+    x: Fx32,
+    y: Fx32,
     collected: bool,
-    bob_offset: f32,
+    bob_offset: f32, // Render-only
 }
 
 impl Collectible {
     const fn new() -> Self {
         Self {
-            x: 0.0,
-            y: 0.0,
+            x: Fx32::ZERO,
+            y: Fx32::ZERO,
             collected: false,
             bob_offset: 0.0,
         }
     }
+
+    const fn at(x: f32, y: f32, bob_offset: f32) -> Self {
+        Self {
+            x: Fx32::from_f32(x),
+            y: Fx32::from_f32(y),
+            collected: false,
+            bob_offset,
+        }
+    }
 }
 
 // Game state (static for rollback safety)
@@ -146,6 +177,7 @@ static mut GAME_OVER: bool = false;
 static mut PLAYER_TEXTURE: u32 = 0;
 static mut PLATFORM_TEXTURE: u32 = 0;
 static mut COIN_TEXTURE: u32 = 0;
+static mut PANEL_TEXTURE: u32 = 0;
 
 // === Textures (8x8 pixel art) ===
 
@@ -264,6 +296,46 @@ const COIN_PIXELS: [u8; 8 * 8 * 4] = {
     pixels
 };
 
+// UI panel frame (rounded outline around a translucent fill, drawn as a nine-slice)
+const PANEL_BORDER: f32 = 3.0;
+const PANEL_PIXELS: [u8; 8 * 8 * 4] = {
+    let mut pixels = [0u8; 256];
+    let outline = [0xFF, 0xFF, 0xFF, 0xFF];
+    let fill = [0x00, 0x00, 0x00, 0xAA];
+    let trans = [0x00, 0x00, 0x00, 0x00];
+
+    let pattern: [[u8; 8]; 8] = [
+        [0, 0, 1, 1, 1, 1, 0, 0],
+        [0, 1, 2, 2, 2, 2, 1, 0],
+        [1, 2, 2, 2, 2, 2, 2, 1],
+        [1, 2, 2, 2, 2, 2, 2, 1],
+        [1, 2, 2, 2, 2, 2, 2, 1],
+        [1, 2, 2, 2, 2, 2, 2, 1],
+        [0, 1, 2, 2, 2, 2, 1, 0],
+        [0, 0, 1, 1, 1, 1, 0, 0],
+    ];
+
+    let mut y = 0;
+    while y < 8 {
+        let mut x = 0;
+        while x < 8 {
+            let idx = (y * 8 + x) * 4;
+            let color = match pattern[y][x] {
+                0 => trans,
+                1 => outline,
+                _ => fill,
+            };
+            pixels[idx] = color[0];
+            pixels[idx + 1] = color[1];
+            pixels[idx + 2] = color[2];
+            pixels[idx + 3] = color[3];
+            x += 1;
+        }
+        y += 1;
+    }
+    pixels
+};
+
 // === Helper Functions ===
 
 fn draw_text_str(s: &str, x: f32, y: f32, size: f32) {
@@ -272,24 +344,34 @@ fn draw_text_str(s: &str, x: f32, y: f32, size: f32) {
     }
 }
 
+/// Draw a framed UI panel; the nine-slice keeps its corners crisp at any size
+fn draw_panel(x: f32, y: f32, w: f32, h: f32, outline_color: u32) {
+    unsafe {
+        draw_nine_slice(
+            PANEL_TEXTURE,
+            x,
+            y,
+            w,
+            h,
+            PANEL_BORDER,
+            PANEL_BORDER,
+            PANEL_BORDER,
+            PANEL_BORDER,
+            outline_color,
+        );
+    }
+}
+
 // Using libm for accurate no_std math
 #[inline]
 fn sin_approx(x: f32) -> f32 {
     libm::sinf(x)
 }
 
-fn clamp(v: f32, min: f32, max: f32) -> f32 {
-    if v < min { min } else if v > max { max } else { v }
-}
-
-fn abs(v: f32) -> f32 {
-    if v < 0.0 { -v } else { v }
-}
-
 // AABB collision check
 fn aabb_overlap(
-    x1: f32, y1: f32, w1: f32, h1: f32,
-    x2: f32, y2: f32, w2: f32, h2: f32,
+    x1: Fx32, y1: Fx32, w1: Fx32, h1: Fx32,
+    x2: Fx32, y2: Fx32, w2: Fx32, h2: Fx32,
 ) -> bool {
     x1 < x2 + w2 && x1 + w1 > x2 && y1 < y2 + h2 && y1 + h1 > y2
 }
@@ -301,41 +383,38 @@ fn init_level() {
         // Initialize platforms
         // Ground platform (wide)
         PLATFORMS[0] = Platform {
-            x: -10.0,
             y: LEVEL_BOTTOM,
-            width: 20.0,
-            height: 0.5,
-            active: true,
+            ..Platform::at(-10.0, 0.0, 20.0, 0.5)
         };
 
         // Floating platforms
-        PLATFORMS[1] = Platform { x: -8.0, y: 0.0, width: 3.0, height: 0.4, active: true };
-        PLATFORMS[2] = Platform { x: -3.0, y: 1.5, width: 2.5, height: 0.4, active: true };
-        PLATFORMS[3] = Platform { x: 2.0, y: 0.5, width: 3.0, height: 0.4, active: true };
-        PLATFORMS[4] = Platform { x: 6.0, y: 2.0, width: 2.5, height: 0.4, active: true };
-        PLATFORMS[5] = Platform { x: -5.0, y: 3.5, width: 2.0, height: 0.4, active: true };
-        PLATFORMS[6] = Platform { x: 0.0, y: 4.0, width: 3.0, height: 0.4, active: true };
-        PLATFORMS[7] = Platform { x: 5.0, y: 4.5, width: 2.5, height: 0.4, active: true };
+        PLATFORMS[1] = Platform::at(-8.0, 0.0, 3.0, 0.4);
+        PLATFORMS[2] = Platform::at(-3.0, 1.5, 2.5, 0.4);
+        PLATFORMS[3] = Platform::at(2.0, 0.5, 3.0, 0.4);
+        PLATFORMS[4] = Platform::at(6.0, 2.0, 2.5, 0.4);
+        PLATFORMS[5] = Platform::at(-5.0, 3.5, 2.0, 0.4);
+        PLATFORMS[6] = Platform::at(0.0, 4.0, 3.0, 0.4);
+        PLATFORMS[7] = Platform::at(5.0, 4.5, 2.5, 0.4);
 
         // Initialize collectibles (coins)
-        COLLECTIBLES[0] = Collectible { x: -7.0, y: 1.0, collected: false, bob_offset: 0.0 };
-        COLLECTIBLES[1] = Collectible { x: -2.0, y: 2.5, collected: false, bob_offset: 0.5 };
-        COLLECTIBLES[2] = Collectible { x: 3.0, y: 1.5, collected: false, bob_offset: 1.0 };
-        COLLECTIBLES[3] = Collectible { x: 7.0, y: 3.0, collected: false, bob_offset: 1.5 };
-        COLLECTIBLES[4] = Collectible { x: -4.0, y: 4.5, collected: false, bob_offset: 2.0 };
-        COLLECTIBLES[5] = Collectible { x: 1.0, y: 5.0, collected: false, bob_offset: 2.5 };
-        COLLECTIBLES[6] = Collectible { x: 6.0, y: 5.5, collected: false, bob_offset: 3.0 };
-        COLLECTIBLES[7] = Collectible { x: 0.0, y: 0.0, collected: false, bob_offset: 3.5 };
+        COLLECTIBLES[0] = Collectible::at(-7.0, 1.0, 0.0);
+        COLLECTIBLES[1] = Collectible::at(-2.0, 2.5, 0.5);
+        COLLECTIBLES[2] = Collectible::at(3.0, 1.5, 1.0);
+        COLLECTIBLES[3] = Collectible::at(7.0, 3.0, 1.5);
+        COLLECTIBLES[4] = Collectible::at(-4.0, 4.5, 2.0);
+        COLLECTIBLES[5] = Collectible::at(1.0, 5.0, 2.5);
+        COLLECTIBLES[6] = Collectible::at(6.0, 5.5, 3.0);
+        COLLECTIBLES[7] = Collectible::at(0.0, 0.0, 3.5);
 
         // Initialize players
         let count = player_count().min(MAX_PLAYERS as u32) as usize;
         for i in 0..MAX_PLAYERS {
             if i < count {
                 PLAYERS[i] = Player {
-                    x: -8.0 + (i as f32 * 2.0),
-                    y: 0.0,
-                    vx: 0.0,
-                    vy: 0.0,
+                    x: Fx32::from_int(-8 + i as i32 * 2),
+                    y: Fx32::ZERO,
+                    vx: Fx32::ZERO,
+                    vy: Fx32::ZERO,
                     on_ground: false,
                     facing_right: true,
                     score: 0,
@@ -364,6 +443,7 @@ pub extern "C" fn init() {
         PLAYER_TEXTURE = load_texture(8, 8, PLAYER_PIXELS.as_ptr());
         PLATFORM_TEXTURE = load_texture(8, 8, PLATFORM_PIXELS.as_ptr());
         COIN_TEXTURE = load_texture(8, 8, COIN_PIXELS.as_ptr());
+        PANEL_TEXTURE = load_texture(8, 8, PANEL_PIXELS.as_ptr());
 
         // Nearest-neighbor for crisp pixels
         texture_filter(0);
@@ -382,21 +462,21 @@ fn update_player(player_idx: usize) {
             return;
         }
 
-        // Read input
-        let stick_x = left_stick_x(player_idx as u32);
+        // Read input (the stick value is identical on every peer, so converting it is safe)
+        let stick_x = Fx32::from_f32(left_stick_x(player_idx as u32));
         let jump_pressed = button_pressed(player_idx as u32, BUTTON_A) != 0;
         let jump_held = button_held(player_idx as u32, BUTTON_A) != 0;
 
         // Horizontal movement
-        p.vx += stick_x * MOVE_SPEED * 0.1;
+        p.vx += stick_x * MOVE_SPEED * VELOCITY_SCALE;
         p.vx *= FRICTION;
 
         // Clamp horizontal velocity
-        p.vx = clamp(p.vx, -MOVE_SPEED, MOVE_SPEED);
+        p.vx = p.vx.clamp(-MOVE_SPEED, MOVE_SPEED);
 
         // Update facing direction
-        if abs(stick_x) > 0.3 {
-            p.facing_right = stick_x > 0.0;
+        if stick_x.abs() > FACING_DEADZONE {
+            p.facing_right = stick_x > Fx32::ZERO;
         }
 
         // Jump (only when on ground)
@@ -406,16 +486,16 @@ fn update_player(player_idx: usize) {
         }
 
         // Variable jump height (release early = lower jump)
-        if !jump_held && p.vy > 0.0 {
-            p.vy *= 0.5;
+        if !jump_held && p.vy > Fx32::ZERO {
+            p.vy *= Fx32::HALF;
         }
 
         // Apply gravity
         p.vy -= GRAVITY;
 
         // Apply velocity
-        let new_x = p.x + p.vx * 0.1;
-        let new_y = p.y + p.vy * 0.1;
+        let new_x = p.x + p.vx * VELOCITY_SCALE;
+        let new_y = p.y + p.vy * VELOCITY_SCALE;
 
         // Collision detection with platforms
         p.on_ground = false;
@@ -427,7 +507,7 @@ fn update_player(player_idx: usize) {
             }
 
             // Player AABB (centered at x, y is bottom)
-            let px = new_x - PLAYER_WIDTH / 2.0;
+            let px = new_x - PLAYER_WIDTH * Fx32::HALF;
             let py = new_y;
             let pw = PLAYER_WIDTH;
             let ph = PLAYER_HEIGHT;
@@ -442,34 +522,34 @@ fn update_player(player_idx: usize) {
             if aabb_overlap(px, py, pw, ph, plx, ply, plw, plh) {
                 // Determine collision side
                 // Coming from above (landing)
-                if p.vy <= 0.0 && p.y >= platform.y + platform.height - 0.1 {
+                if p.vy <= Fx32::ZERO && p.y >= platform.y + platform.height - LANDING_TOLERANCE {
                     p.y = platform.y + platform.height;
-                    p.vy = 0.0;
+                    p.vy = Fx32::ZERO;
                     p.on_ground = true;
                 }
                 // Coming from below (hitting head)
-                else if p.vy > 0.0 && p.y + PLAYER_HEIGHT <= platform.y + 0.2 {
+                else if p.vy > Fx32::ZERO && p.y + PLAYER_HEIGHT <= platform.y + HEAD_TOLERANCE {
                     p.y = platform.y - PLAYER_HEIGHT;
-                    p.vy = 0.0;
+                    p.vy = Fx32::ZERO;
                 }
             }
         }
 
         // Update position if no collision stopped it
-        if !p.on_ground || p.vy > 0.0 {
+        if !p.on_ground || p.vy > Fx32::ZERO {
             p.y = new_y;
         }
         p.x = new_x;
 
         // Clamp to level bounds
-        p.x = clamp(p.x, LEVEL_LEFT, LEVEL_RIGHT);
+        p.x = p.x.clamp(LEVEL_LEFT, LEVEL_RIGHT);
 
         // Fall off bottom - respawn
-        if p.y < LEVEL_BOTTOM - 5.0 {
-            p.x = 0.0;
-            p.y = 5.0;
-            p.vx = 0.0;
-            p.vy = 0.0;
+        if p.y < LEVEL_BOTTOM - Fx32::from_int(5) {
+            p.x = Fx32::ZERO;
+            p.y = Fx32::from_int(5);
+            p.vx = Fx32::ZERO;
+            p.vy = Fx32::ZERO;
         }
 
         // Check collectible collection
@@ -480,10 +560,10 @@ fn update_player(player_idx: usize) {
 
             // Simple distance check
             let dx = p.x - collectible.x;
-            let dy = (p.y + PLAYER_HEIGHT / 2.0) - collectible.y;
+            let dy = (p.y + PLAYER_HEIGHT * Fx32::HALF) - collectible.y;
             let dist_sq = dx * dx + dy * dy;
 
-            if dist_sq < 1.0 {
+            if dist_sq < Fx32::ONE {
                 collectible.collected = true;
                 p.score += 100;
             }
@@ -540,14 +620,15 @@ fn render_platforms() {
 
             // Draw platform as multiple billboards (tiled)
             let tile_size = 1.0;
-            let tiles_x = libm::ceilf(platform.width / tile_size) as i32;
-            let tiles_y = libm::ceilf(platform.height / tile_size) as i32;
+            let (x, y) = (platform.x.to_f32(), platform.y.to_f32());
+            let tiles_x = libm::ceilf(platform.width.to_f32() / tile_size) as i32;
+            let tiles_y = libm::ceilf(platform.height.to_f32() / tile_size) as i32;
 
             set_color(0xFFFFFFFF);
             for ty in 0..tiles_y {
                 for tx in 0..tiles_x {
-                    let tile_x = platform.x + (tx as f32 + 0.5) * tile_size;
-                    let tile_y = platform.y + (ty as f32 + 0.5) * tile_size;
+                    let tile_x = x + (tx as f32 + 0.5) * tile_size;
+                    let tile_y = y + (ty as f32 + 0.5) * tile_size;
 
                     push_identity();
                     push_translate(tile_x, tile_y, 0.0);
@@ -574,7 +655,7 @@ fn render_collectibles() {
             let bob = sin_approx(time * 3.0 + collectible.bob_offset) * 0.15;
 
             push_identity();
-            push_translate(collectible.x, collectible.y + bob, 0.1);
+            push_translate(collectible.x.to_f32(), collectible.y.to_f32() + bob, 0.1);
             draw_billboard(0.6, 0.6, MODE_CYLINDRICAL_Y);
         }
     }
@@ -590,14 +671,16 @@ fn render_players() {
             }
 
             // Flip sprite based on facing direction
-            let scale_x = if player.facing_right { PLAYER_WIDTH } else { -PLAYER_WIDTH };
+            let width = PLAYER_WIDTH.to_f32();
+            let height = PLAYER_HEIGHT.to_f32();
+            let scale_x = if player.facing_right { width } else { -width };
 
             push_identity();
-            push_translate(player.x, player.y + PLAYER_HEIGHT / 2.0, 0.2);
+            push_translate(player.x.to_f32(), player.y.to_f32() + height / 2.0, 0.2);
 
             // Use player color as tint
             set_color(PLAYER_COLORS[i]);
-            draw_billboard(scale_x, PLAYER_HEIGHT, MODE_CYLINDRICAL_Y);
+            draw_billboard(scale_x, height, MODE_CYLINDRICAL_Y);
         }
     }
 }
@@ -605,8 +688,7 @@ fn render_players() {
 fn render_ui() {
     unsafe {
         // Background panel for scores
-        set_color(0x000000AA);
-        draw_rect(10.0, 10.0, 300.0, 80.0 + (player_count() as f32 * 70.0));
+        draw_panel(10.0, 10.0, 300.0, 80.0 + (player_count() as f32 * 70.0), 0xFFFFFFFF);
 
         set_color(0xFFFFFFFF);
         draw_text_str("PLATFORMER", 20.0, 30.0, 24.0);
@@ -658,8 +740,7 @@ fn render_ui() {
         draw_text(coins_text.as_ptr(), 8, 20.0, y_offset + 20.0, 18.0);
 
         // Controls hint
-        set_color(0x000000AA);
-        draw_rect(10.0, 480.0, 480.0, 90.0);
+        draw_panel(10.0, 480.0, 480.0, 90.0, 0xCCCCCCFF);
         set_color(0xCCCCCCFF);
         draw_text_str("L-Stick: Move  A: Jump", 20.0, 500.0, 16.0);
         set_color(0xFFD700FF);
@@ -667,8 +748,7 @@ fn render_ui() {
 
         // Game over overlay
         if GAME_OVER {
-            set_color(0x000000DD);
-            draw_rect(150.0, 200.0, 660.0, 140.0);
+            draw_panel(150.0, 200.0, 660.0, 140.0, 0xFFD700FF);
             set_color(0xFFD700FF);
             draw_text_str("ALL COINS COLLECTED!", 200.0, 240.0, 28.0);
             set_color(0xCCCCCCFF);
@@ -3,14 +3,19 @@
 //! Demonstrates a driving/racing scenario with a rear-view mirror
 //! using viewport for the mirror view.
 //!
+//! The player's car uses `examples_common::vehicle` for its handling.
+//!
 //! Controls:
 //! - Left stick: Steer and accelerate
+//! - B button (hold): Drift
+//! - X button: Boost
 //! - A button: Toggle mirror visibility
 
 #![no_std]
 #![no_main]
 
 use core::panic::PanicInfo;
+use core::ptr::addr_of_mut;
 
 #[panic_handler]
 fn panic(_info: &PanicInfo) -> ! {
@@ -22,9 +27,12 @@ fn panic(_info: &PanicInfo) -> ! {
 mod ffi;
 use ffi::*;
 
+use examples_common::vehicle::{Fx32, Surface, Vec2, Vehicle, VehicleInput, VehicleParams};
 
 // Input (button indices from zx.rs)
 const BUTTON_A: u32 = 4;
+const BUTTON_B: u32 = 5;
+const BUTTON_X: u32 = 6;
 
 // Screen dimensions
 const SCREEN_WIDTH: u32 = 960;
@@ -43,13 +51,19 @@ static mut ROAD: u32 = 0;
 static mut TREE: u32 = 0;
 static mut SPHERE_MESH: u32 = 0;
 
+// Car handling
+const CAR_PARAMS: VehicleParams = VehicleParams::DEFAULT;
+const ROAD_EDGE: Fx32 = Fx32::from_int(8);
+const BOOST_TICKS: u32 = 90;
+
 // State
-static mut PLAYER_X: f32 = 0.0;
+static mut CAR: Vehicle = Vehicle::new(Vec2::ZERO, Fx32::ZERO);
+static mut PLAYER_X: f32 = 0.0; // Copied from CAR for rendering
 static mut PLAYER_Z: f32 = 0.0;
 static mut PLAYER_ANGLE: f32 = 0.0;
-static mut SPEED: f32 = 0.0;
 static mut SHOW_MIRROR: bool = true;
 static mut PREV_A_BUTTON: u32 = 0;
+static mut PREV_X_BUTTON: u32 = 0;
 static mut TIME: f32 = 0.0;
 
 // Chasing "enemy" car
@@ -82,29 +96,33 @@ pub extern "C" fn update() {
         }
         PREV_A_BUTTON = a_button;
 
-        // Controls
-        let stick_x = left_stick_x(0);
-        let stick_y = left_stick_y(0);
-
-        // Accelerate/brake with stick Y
-        SPEED += stick_y * 0.2;
-        SPEED *= 0.98; // Friction
-        if SPEED > 10.0 { SPEED = 10.0; }
-        if SPEED < -5.0 { SPEED = -5.0; }
+        let car = &mut *addr_of_mut!(CAR);
 
-        // Steering with stick X (only when moving)
-        if SPEED.abs() > 0.5 {
-            PLAYER_ANGLE -= stick_x * 2.0 * (SPEED / 10.0);
+        // Boost with X
+        let x_button = button_held(0, BUTTON_X);
+        if x_button != 0 && PREV_X_BUTTON == 0 {
+            car.boost(BOOST_TICKS);
+        }
+        PREV_X_BUTTON = x_button;
+
+        // Drive: stick Y is throttle/brake, stick X steers, B drifts
+        let input = VehicleInput {
+            throttle: Fx32::from_f32(left_stick_y(0)),
+            steer: Fx32::from_f32(-left_stick_x(0)),
+            drift: button_held(0, BUTTON_B) != 0,
+        };
+        car.step(&CAR_PARAMS, &input, &Surface::ROAD);
+
+        // Keep player on road (push back off the edges)
+        let x = car.position.x;
+        if x > ROAD_EDGE {
+            car.collide(&CAR_PARAMS, Vec2::new(-Fx32::ONE, Fx32::ZERO), x - ROAD_EDGE);
+        } else if x < -ROAD_EDGE {
+            car.collide(&CAR_PARAMS, Vec2::new(Fx32::ONE, Fx32::ZERO), -ROAD_EDGE - x);
         }
 
-        // Move forward
-        let angle_rad = PLAYER_ANGLE.to_radians();
-        PLAYER_X += libm::sinf(angle_rad) * SPEED * 0.1;
-        PLAYER_Z += libm::cosf(angle_rad) * SPEED * 0.1;
-
-        // Keep player on road
-        if PLAYER_X > 8.0 { PLAYER_X = 8.0; }
-        if PLAYER_X < -8.0 { PLAYER_X = -8.0; }
+        [PLAYER_X, PLAYER_Z] = car.position.to_f32();
+        PLAYER_ANGLE = car.heading_degrees();
 
         // Enemy car follows behind
         let target_z = PLAYER_Z - 15.0;
@@ -290,7 +308,7 @@ pub extern "C" fn render() {
             speed_label.as_ptr(), speed_label.len() as u32, SCREEN_WIDTH as f32 - 150.0, SCREEN_HEIGHT as f32 - 60.0, 16.0);
 
         // Controls at bottom
-        let controls = "Controls: Left Stick = Steer/Accelerate | A = Toggle Mirror";
+        let controls = "Controls: Left Stick = Steer/Accelerate | B = Drift | X = Boost | A = Toggle Mirror";
         set_color(0xAAAAAAFF);
         draw_text(
             controls.as_ptr(), controls.len() as u32, 10.0, SCREEN_HEIGHT as f32 - 30.0, 14.0);
@@ -199,12 +199,15 @@ pub extern "C" fn render() {
         draw_rect(half_w as f32 - 1.0, 0.0, 2.0, SCREEN_HEIGHT as f32);
         }
 
-        // HUD overlay on fullscreen
+        // HUD overlay on fullscreen, anchored to the visible part of the canvas
         viewport_clear();
+        let mut area = [0.0f32; 4];
+        safe_area(area.as_mut_ptr());
+        let [area_x, area_y, area_w, area_h] = area;
         let mode_text = if IS_4_PLAYER { "4-Player Mode (Press A to switch)" } else { "2-Player Mode (Press A to switch)" };
         set_color(0xCCCCCCFF,
         );
         draw_text(
-            mode_text.as_ptr(), mode_text.len() as u32, SCREEN_WIDTH as f32 / 2.0 - 180.0, SCREEN_HEIGHT as f32 - 30.0, 16.0);
+            mode_text.as_ptr(), mode_text.len() as u32, area_x + area_w / 2.0 - 180.0, area_y + area_h - 30.0, 16.0);
     }
 }
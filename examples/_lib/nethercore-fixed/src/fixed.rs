@@ -0,0 +1,329 @@
+//! Q16.16 and Q32.32 scalar types
+
+use core::cmp::Ordering;
+use core::fmt;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+macro_rules! fixed_type {
+    (
+        $(#[$meta:meta])*
+        $name:ident, raw = $raw:ty, wide = $wide:ty, uwide = $uwide:ty, frac = $frac:expr,
+        pi = $pi:expr, tau = $tau:expr, half_pi = $half_pi:expr
+    ) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        #[repr(transparent)]
+        pub struct $name($raw);
+
+        impl $name {
+            /// Number of fractional bits
+            pub const FRAC_BITS: u32 = $frac;
+
+            pub const ZERO: Self = Self(0);
+            pub const ONE: Self = Self(1 << $frac);
+            pub const HALF: Self = Self(1 << ($frac - 1));
+            pub const MIN: Self = Self(<$raw>::MIN);
+            pub const MAX: Self = Self(<$raw>::MAX);
+            /// Smallest positive value
+            pub const EPSILON: Self = Self(1);
+
+            pub const PI: Self = Self($pi);
+            pub const TAU: Self = Self($tau);
+            pub const HALF_PI: Self = Self($half_pi);
+
+            /// Create from the raw fixed-point representation
+            #[inline]
+            pub const fn from_raw(raw: $raw) -> Self {
+                Self(raw)
+            }
+
+            /// Raw fixed-point representation
+            #[inline]
+            pub const fn raw(self) -> $raw {
+                self.0
+            }
+
+            /// Create from an integer (wraps if out of range)
+            #[inline]
+            pub const fn from_int(value: $raw) -> Self {
+                Self(value.wrapping_shl($frac))
+            }
+
+            /// Create from `num / den` without going through floats
+            ///
+            /// Saturates if `den` is zero.
+            #[inline]
+            pub const fn from_ratio(num: $raw, den: $raw) -> Self {
+                Self::from_int(num).div_fixed(Self::from_int(den))
+            }
+
+            /// Convert from `f32`, truncating toward zero (saturates if out of range)
+            ///
+            /// Intended for constants and for values that are already
+            /// identical on every peer, such as analog stick input.
+            #[inline]
+            pub const fn from_f32(value: f32) -> Self {
+                Self::from_f64(value as f64)
+            }
+
+            /// Convert from `f64`, truncating toward zero (saturates if out of range)
+            #[inline]
+            pub const fn from_f64(value: f64) -> Self {
+                Self((value * (1u64 << $frac) as f64) as $raw)
+            }
+
+            /// Convert to `f32` (for rendering; keep game state in fixed point)
+            #[inline]
+            pub fn to_f32(self) -> f32 {
+                self.to_f64() as f32
+            }
+
+            /// Convert to `f64`
+            #[inline]
+            pub fn to_f64(self) -> f64 {
+                self.0 as f64 / (1u64 << $frac) as f64
+            }
+
+            /// Integer part, rounded toward negative infinity
+            #[inline]
+            pub const fn to_int(self) -> $raw {
+                self.0 >> $frac
+            }
+
+            /// Largest integer less than or equal to `self`
+            #[inline]
+            pub const fn floor(self) -> Self {
+                Self(self.0 & !((1 << $frac) - 1))
+            }
+
+            /// Smallest integer greater than or equal to `self`
+            #[inline]
+            pub const fn ceil(self) -> Self {
+                Self(self.0.wrapping_add((1 << $frac) - 1)).floor()
+            }
+
+            /// Nearest integer (halves round up)
+            #[inline]
+            pub const fn round(self) -> Self {
+                Self(self.0.wrapping_add(1 << ($frac - 1))).floor()
+            }
+
+            /// Fractional part (always in `[0, 1)`)
+            #[inline]
+            pub const fn fract(self) -> Self {
+                Self(self.0 & ((1 << $frac) - 1))
+            }
+
+            #[inline]
+            pub const fn abs(self) -> Self {
+                Self(self.0.wrapping_abs())
+            }
+
+            /// -1, 0 or 1
+            #[inline]
+            pub const fn signum(self) -> Self {
+                Self::from_int(self.0.signum())
+            }
+
+            #[inline]
+            pub const fn is_negative(self) -> bool {
+                self.0 < 0
+            }
+
+            #[inline]
+            pub fn min(self, other: Self) -> Self {
+                Ord::min(self, other)
+            }
+
+            #[inline]
+            pub fn max(self, other: Self) -> Self {
+                Ord::max(self, other)
+            }
+
+            #[inline]
+            pub fn clamp(self, min: Self, max: Self) -> Self {
+                self.max(min).min(max)
+            }
+
+            /// Fixed-point multiply (wraps on overflow)
+            #[inline]
+            pub const fn mul_fixed(self, rhs: Self) -> Self {
+                Self(((self.0 as $wide * rhs.0 as $wide) >> $frac) as $raw)
+            }
+
+            /// Fixed-point divide (wraps on overflow, saturates on division by zero)
+            #[inline]
+            pub const fn div_fixed(self, rhs: Self) -> Self {
+                if rhs.0 == 0 {
+                    return match self.0 {
+                        0 => Self::ZERO,
+                        v if v < 0 => Self::MIN,
+                        _ => Self::MAX,
+                    };
+                }
+                Self((((self.0 as $wide) << $frac) / rhs.0 as $wide) as $raw)
+            }
+
+            /// Multiply by an integer (wraps on overflow)
+            #[inline]
+            pub const fn mul_int(self, rhs: $raw) -> Self {
+                Self(self.0.wrapping_mul(rhs))
+            }
+
+            /// Square root, rounded down (0 for negative values)
+            #[inline]
+            pub const fn sqrt(self) -> Self {
+                if self.0 <= 0 {
+                    return Self::ZERO;
+                }
+                Self((((self.0 as $uwide) << $frac).isqrt()) as $raw)
+            }
+
+            /// Linear interpolation from `self` to `other` by `t` (0 = self, 1 = other)
+            #[inline]
+            pub const fn lerp(self, other: Self, t: Self) -> Self {
+                Self(self.0.wrapping_add(Self(other.0.wrapping_sub(self.0)).mul_fixed(t).0))
+            }
+        }
+
+        impl Add for $name {
+            type Output = Self;
+            #[inline]
+            fn add(self, rhs: Self) -> Self {
+                Self(self.0.wrapping_add(rhs.0))
+            }
+        }
+
+        impl Sub for $name {
+            type Output = Self;
+            #[inline]
+            fn sub(self, rhs: Self) -> Self {
+                Self(self.0.wrapping_sub(rhs.0))
+            }
+        }
+
+        impl Mul for $name {
+            type Output = Self;
+            #[inline]
+            fn mul(self, rhs: Self) -> Self {
+                self.mul_fixed(rhs)
+            }
+        }
+
+        impl Div for $name {
+            type Output = Self;
+            #[inline]
+            fn div(self, rhs: Self) -> Self {
+                self.div_fixed(rhs)
+            }
+        }
+
+        impl Neg for $name {
+            type Output = Self;
+            #[inline]
+            fn neg(self) -> Self {
+                Self(self.0.wrapping_neg())
+            }
+        }
+
+        impl AddAssign for $name {
+            #[inline]
+            fn add_assign(&mut self, rhs: Self) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl SubAssign for $name {
+            #[inline]
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = *self - rhs;
+            }
+        }
+
+        impl MulAssign for $name {
+            #[inline]
+            fn mul_assign(&mut self, rhs: Self) {
+                *self = *self * rhs;
+            }
+        }
+
+        impl DivAssign for $name {
+            #[inline]
+            fn div_assign(&mut self, rhs: Self) {
+                *self = *self / rhs;
+            }
+        }
+
+        impl fmt::Debug for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{}({})", stringify!($name), self.to_f64())
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(&self.to_f64(), f)
+            }
+        }
+    };
+}
+
+fixed_type!(
+    /// Q16.16 fixed-point number (16 integer bits, 16 fractional bits)
+    ///
+    /// The default choice for positions, velocities and angles.
+    Fx32, raw = i32, wide = i64, uwide = u64, frac = 16,
+    pi = 205_887, tau = 411_775, half_pi = 102_944
+);
+
+fixed_type!(
+    /// Q32.32 fixed-point number (32 integer bits, 32 fractional bits)
+    ///
+    /// For values that outgrow [`Fx32`]: tick timers, accumulated distances,
+    /// large world coordinates, or intermediate products.
+    Fx64, raw = i64, wide = i128, uwide = u128, frac = 32,
+    pi = 13_493_037_705, tau = 26_986_075_409, half_pi = 6_746_518_852
+);
+
+impl Fx64 {
+    /// Widen a Q16.16 value (lossless)
+    #[inline]
+    pub const fn from_fx32(value: Fx32) -> Self {
+        Self((value.raw() as i64) << 16)
+    }
+
+    /// Narrow to Q16.16 (drops the lowest 16 fractional bits, wraps if out of range)
+    #[inline]
+    pub const fn to_fx32(self) -> Fx32 {
+        Fx32::from_raw((self.0 >> 16) as i32)
+    }
+}
+
+impl From<Fx32> for Fx64 {
+    #[inline]
+    fn from(value: Fx32) -> Self {
+        Self::from_fx32(value)
+    }
+}
+
+impl Mul<Fx32> for Fx64 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Fx32) -> Self {
+        self * Fx64::from_fx32(rhs)
+    }
+}
+
+impl PartialEq<Fx32> for Fx64 {
+    #[inline]
+    fn eq(&self, other: &Fx32) -> bool {
+        *self == Fx64::from_fx32(*other)
+    }
+}
+
+impl PartialOrd<Fx32> for Fx64 {
+    #[inline]
+    fn partial_cmp(&self, other: &Fx32) -> Option<Ordering> {
+        Some(self.cmp(&Fx64::from_fx32(*other)))
+    }
+}
@@ -0,0 +1,41 @@
+//! Nethercore Fixed - Deterministic fixed-point math for rollback-safe gameplay
+//!
+//! Rollback netcode re-simulates the same inputs on every peer and expects
+//! bit-identical results. `f32` math usually delivers that inside WASM, but
+//! library functions (`libm::sinf`, `sqrtf` fallbacks, fused operations in
+//! native test builds) are easy places for peers to drift apart. Everything
+//! here is plain integer arithmetic, so it gives the same answer everywhere:
+//!
+//! - [`Fx32`] — Q16.16 in an `i32` (range ±32768, step ~0.000015)
+//! - [`Fx64`] — Q32.32 in an `i64` (range ±2^31, for timers and large worlds)
+//! - [`Fx32::sin`] / [`Fx32::cos`] — quarter-wave lookup table with linear interpolation
+//! - [`Fx64::sin`] / [`Fx64::cos`] — the same table after reducing the angle to one turn
+//! - [`Fx32::sqrt`] / [`Fx64::sqrt`] — exact integer square roots
+//! - [`Vec2`] / [`Vec3`] — vectors of [`Fx32`]
+//!
+//! Keep game state in fixed point and convert with `to_f32()` only when
+//! handing values to rendering FFI calls.
+//!
+//! ```ignore
+//! use nethercore_fixed::{Fx32, Vec2};
+//!
+//! const GRAVITY: Fx32 = Fx32::from_f32(0.5);
+//!
+//! let mut velocity = Vec2::new(Fx32::ONE, Fx32::ZERO);
+//! velocity.y -= GRAVITY;
+//! ```
+//!
+//! Overflow wraps (like release-mode integers) instead of panicking, and
+//! division by zero saturates, so a bad frame can never trap one peer only.
+
+#![no_std]
+
+mod fixed;
+mod trig;
+mod vec;
+
+pub use fixed::{Fx32, Fx64};
+pub use vec::{Vec2, Vec3};
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,193 @@
+//! Tests for nethercore-fixed
+
+use crate::{Fx32, Fx64, Vec2, Vec3};
+
+fn close(a: Fx32, b: f64, tolerance: f64) -> bool {
+    (a.to_f64() - b).abs() <= tolerance
+}
+
+#[test]
+fn test_arithmetic() {
+    let a = Fx32::from_f32(1.5);
+    let b = Fx32::from_int(2);
+
+    assert_eq!(a + b, Fx32::from_f32(3.5));
+    assert_eq!(a - b, Fx32::from_f32(-0.5));
+    assert_eq!(a * b, Fx32::from_int(3));
+    assert_eq!(b / a, Fx32::from_ratio(4, 3));
+    assert_eq!(-a, Fx32::from_f32(-1.5));
+    assert_eq!(a.mul_int(4), Fx32::from_int(6));
+}
+
+#[test]
+fn test_division_by_zero_saturates() {
+    assert_eq!(Fx32::ONE / Fx32::ZERO, Fx32::MAX);
+    assert_eq!(-Fx32::ONE / Fx32::ZERO, Fx32::MIN);
+    assert_eq!(Fx32::ZERO / Fx32::ZERO, Fx32::ZERO);
+    assert_eq!(Fx32::EPSILON / Fx32::ZERO, Fx32::MAX);
+    assert_eq!(Fx32::MIN / Fx32::ZERO, Fx32::MIN);
+
+    assert_eq!(Fx64::ONE / Fx64::ZERO, Fx64::MAX);
+    assert_eq!(-Fx64::ONE / Fx64::ZERO, Fx64::MIN);
+    assert_eq!(Fx64::ZERO / Fx64::ZERO, Fx64::ZERO);
+
+    let mut v = Fx32::from_int(-3);
+    v /= Fx32::ZERO;
+    assert_eq!(v, Fx32::MIN);
+}
+
+#[test]
+fn test_overflow_wraps() {
+    assert_eq!(Fx32::MAX + Fx32::EPSILON, Fx32::MIN);
+    assert_eq!(Fx32::MIN - Fx32::EPSILON, Fx32::MAX);
+    assert_eq!(-Fx32::MIN, Fx32::MIN);
+    assert_eq!(Fx32::MAX.mul_int(2), Fx32::from_raw(-2));
+    // 32767 * 2 = 65534 doesn't fit in 16 integer bits and wraps to -2
+    assert_eq!(
+        Fx32::from_int(32767) * Fx32::from_int(2),
+        Fx32::from_int(-2)
+    );
+    // Quotients that don't fit wrap too instead of trapping
+    assert_eq!(Fx32::MIN / -Fx32::EPSILON, Fx32::ZERO);
+
+    assert_eq!(Fx64::MAX + Fx64::EPSILON, Fx64::MIN);
+    assert_eq!(Fx64::MIN - Fx64::EPSILON, Fx64::MAX);
+    assert_eq!(-Fx64::MIN, Fx64::MIN);
+    assert_eq!(Fx64::MAX.mul_int(2), Fx64::from_raw(-2));
+
+    let mut v = Fx32::MAX;
+    v += Fx32::ONE;
+    assert_eq!(v, Fx32::from_raw(i32::MIN + 0xFFFF));
+}
+
+#[test]
+fn test_overflow_wraps_in_vectors() {
+    let v = Vec2::new(Fx32::MAX, Fx32::MIN);
+    assert_eq!(
+        v + Vec2::new(Fx32::EPSILON, Fx32::ZERO),
+        Vec2::new(Fx32::MIN, Fx32::MIN)
+    );
+    assert_eq!(-v, Vec2::new(-Fx32::MAX, Fx32::MIN));
+}
+
+#[test]
+fn test_rounding() {
+    let v = Fx32::from_f32(-1.25);
+    assert_eq!(v.floor(), Fx32::from_int(-2));
+    assert_eq!(v.ceil(), Fx32::from_int(-1));
+    assert_eq!(v.round(), Fx32::from_int(-1));
+    assert_eq!(v.fract(), Fx32::from_f32(0.75));
+    assert_eq!(v.to_int(), -2);
+    assert_eq!(v.abs(), Fx32::from_f32(1.25));
+}
+
+#[test]
+fn test_sqrt() {
+    assert_eq!(Fx32::from_int(9).sqrt(), Fx32::from_int(3));
+    assert_eq!(Fx32::from_f32(0.25).sqrt(), Fx32::HALF);
+    assert_eq!(Fx32::from_int(-4).sqrt(), Fx32::ZERO);
+    assert!(close(
+        Fx32::from_int(2).sqrt(),
+        core::f64::consts::SQRT_2,
+        1e-4
+    ));
+    assert_eq!(Fx64::from_int(1 << 30).sqrt(), Fx64::from_int(1 << 15));
+}
+
+#[test]
+fn test_trig() {
+    for i in -64..=64 {
+        let angle = Fx32::from_ratio(i, 8);
+        let radians = angle.to_f64();
+        assert!(
+            close(angle.sin(), libm_free_sin(radians), 1e-4),
+            "sin({})",
+            radians
+        );
+        assert!(
+            close(
+                angle.cos(),
+                libm_free_sin(radians + core::f64::consts::FRAC_PI_2),
+                1e-4
+            ),
+            "cos({})",
+            radians
+        );
+    }
+    assert_eq!(Fx32::ZERO.sin(), Fx32::ZERO);
+    assert!(close(Fx32::HALF_PI.sin(), 1.0, 1e-4));
+}
+
+#[test]
+fn test_fx64_trig() {
+    for i in -64..=64 {
+        let angle = Fx64::from_ratio(i, 8);
+        let radians = angle.to_f64();
+        let (sin, cos) = angle.sin_cos();
+        assert!(
+            close(sin.to_fx32(), libm_free_sin(radians), 1e-4),
+            "sin({})",
+            radians
+        );
+        assert!(
+            close(
+                cos.to_fx32(),
+                libm_free_sin(radians + core::f64::consts::FRAC_PI_2),
+                1e-4
+            ),
+            "cos({})",
+            radians
+        );
+    }
+
+    // Agrees with Fx32 wherever both can hold the angle
+    let angle = Fx32::from_f32(2.5);
+    assert_eq!(Fx64::from_fx32(angle).sin(), Fx64::from_fx32(angle.sin()));
+    assert_eq!(
+        Fx64::from_fx32(-angle).cos(),
+        Fx64::from_fx32((-angle).cos())
+    );
+}
+
+#[test]
+fn test_fx64_conversions() {
+    let a = Fx32::from_f32(-3.75);
+    assert_eq!(Fx64::from_fx32(a).to_fx32(), a);
+    assert_eq!(Fx64::from_fx32(a), a);
+
+    // Large angles are wrapped before the table lookup
+    let angle = Fx64::from_int(1_000_000) * Fx64::TAU + Fx64::from_fx32(Fx32::HALF_PI);
+    assert!(close(angle.sin().to_fx32(), 1.0, 1e-3));
+}
+
+#[test]
+fn test_vectors() {
+    let v = Vec2::new(Fx32::from_int(3), Fx32::from_int(4));
+    assert_eq!(v.length(), Fx32::from_int(5));
+    assert!(close(v.normalize_or_zero().length(), 1.0, 1e-4));
+    assert_eq!(Vec2::ZERO.normalize_or_zero(), Vec2::ZERO);
+    assert_eq!(
+        v * Fx32::HALF,
+        Vec2::new(Fx32::from_f32(1.5), Fx32::from_int(2))
+    );
+
+    assert_eq!(Vec3::X.cross(Vec3::Y), Vec3::Z);
+    assert_eq!(Vec3::X.dot(Vec3::Y), Fx32::ZERO);
+}
+
+/// Reference sine from a Taylor series (keeps the crate free of float libm)
+fn libm_free_sin(x: f64) -> f64 {
+    let tau = core::f64::consts::TAU;
+    let mut x = x % tau;
+    if x > core::f64::consts::PI {
+        x -= tau;
+    } else if x < -core::f64::consts::PI {
+        x += tau;
+    }
+    let (mut term, mut sum) = (x, x);
+    for n in 1..12 {
+        term *= -x * x / ((2 * n) as f64 * (2 * n + 1) as f64);
+        sum += term;
+    }
+    sum
+}
@@ -0,0 +1,199 @@
+//! 2D and 3D vectors of [`Fx32`]
+
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::Fx32;
+
+/// 2D vector of Q16.16 values
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Vec2 {
+    pub x: Fx32,
+    pub y: Fx32,
+}
+
+impl Vec2 {
+    pub const ZERO: Self = Self::new(Fx32::ZERO, Fx32::ZERO);
+    pub const X: Self = Self::new(Fx32::ONE, Fx32::ZERO);
+    pub const Y: Self = Self::new(Fx32::ZERO, Fx32::ONE);
+
+    #[inline]
+    pub const fn new(x: Fx32, y: Fx32) -> Self {
+        Self { x, y }
+    }
+
+    /// Build from an angle in radians (unit length)
+    #[inline]
+    pub fn from_angle(angle: Fx32) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Self::new(cos, sin)
+    }
+
+    #[inline]
+    pub fn dot(self, rhs: Self) -> Fx32 {
+        self.x * rhs.x + self.y * rhs.y
+    }
+
+    /// Z component of the 3D cross product (positive if `rhs` is counter-clockwise)
+    #[inline]
+    pub fn perp_dot(self, rhs: Self) -> Fx32 {
+        self.x * rhs.y - self.y * rhs.x
+    }
+
+    #[inline]
+    pub fn length_squared(self) -> Fx32 {
+        self.dot(self)
+    }
+
+    #[inline]
+    pub fn length(self) -> Fx32 {
+        self.length_squared().sqrt()
+    }
+
+    /// Unit vector in the same direction (zero stays zero)
+    pub fn normalize_or_zero(self) -> Self {
+        let len = self.length();
+        if len == Fx32::ZERO {
+            return Self::ZERO;
+        }
+        Self::new(self.x / len, self.y / len)
+    }
+
+    #[inline]
+    pub fn lerp(self, rhs: Self, t: Fx32) -> Self {
+        Self::new(self.x.lerp(rhs.x, t), self.y.lerp(rhs.y, t))
+    }
+
+    /// Convert to `f32` components for rendering
+    #[inline]
+    pub fn to_f32(self) -> [f32; 2] {
+        [self.x.to_f32(), self.y.to_f32()]
+    }
+}
+
+/// 3D vector of Q16.16 values
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Vec3 {
+    pub x: Fx32,
+    pub y: Fx32,
+    pub z: Fx32,
+}
+
+impl Vec3 {
+    pub const ZERO: Self = Self::new(Fx32::ZERO, Fx32::ZERO, Fx32::ZERO);
+    pub const X: Self = Self::new(Fx32::ONE, Fx32::ZERO, Fx32::ZERO);
+    pub const Y: Self = Self::new(Fx32::ZERO, Fx32::ONE, Fx32::ZERO);
+    pub const Z: Self = Self::new(Fx32::ZERO, Fx32::ZERO, Fx32::ONE);
+
+    #[inline]
+    pub const fn new(x: Fx32, y: Fx32, z: Fx32) -> Self {
+        Self { x, y, z }
+    }
+
+    #[inline]
+    pub fn dot(self, rhs: Self) -> Fx32 {
+        self.x * rhs.x + self.y * rhs.y + self.z * rhs.z
+    }
+
+    #[inline]
+    pub fn cross(self, rhs: Self) -> Self {
+        Self::new(
+            self.y * rhs.z - self.z * rhs.y,
+            self.z * rhs.x - self.x * rhs.z,
+            self.x * rhs.y - self.y * rhs.x,
+        )
+    }
+
+    #[inline]
+    pub fn length_squared(self) -> Fx32 {
+        self.dot(self)
+    }
+
+    #[inline]
+    pub fn length(self) -> Fx32 {
+        self.length_squared().sqrt()
+    }
+
+    /// Unit vector in the same direction (zero stays zero)
+    pub fn normalize_or_zero(self) -> Self {
+        let len = self.length();
+        if len == Fx32::ZERO {
+            return Self::ZERO;
+        }
+        Self::new(self.x / len, self.y / len, self.z / len)
+    }
+
+    #[inline]
+    pub fn lerp(self, rhs: Self, t: Fx32) -> Self {
+        Self::new(
+            self.x.lerp(rhs.x, t),
+            self.y.lerp(rhs.y, t),
+            self.z.lerp(rhs.z, t),
+        )
+    }
+
+    /// Convert to `f32` components for rendering
+    #[inline]
+    pub fn to_f32(self) -> [f32; 3] {
+        [self.x.to_f32(), self.y.to_f32(), self.z.to_f32()]
+    }
+}
+
+macro_rules! vec_ops {
+    ($name:ident { $($field:ident),+ }) => {
+        impl Add for $name {
+            type Output = Self;
+            #[inline]
+            fn add(self, rhs: Self) -> Self {
+                Self { $($field: self.$field + rhs.$field),+ }
+            }
+        }
+
+        impl Sub for $name {
+            type Output = Self;
+            #[inline]
+            fn sub(self, rhs: Self) -> Self {
+                Self { $($field: self.$field - rhs.$field),+ }
+            }
+        }
+
+        impl Neg for $name {
+            type Output = Self;
+            #[inline]
+            fn neg(self) -> Self {
+                Self { $($field: -self.$field),+ }
+            }
+        }
+
+        impl Mul<Fx32> for $name {
+            type Output = Self;
+            #[inline]
+            fn mul(self, rhs: Fx32) -> Self {
+                Self { $($field: self.$field * rhs),+ }
+            }
+        }
+
+        impl AddAssign for $name {
+            #[inline]
+            fn add_assign(&mut self, rhs: Self) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl SubAssign for $name {
+            #[inline]
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = *self - rhs;
+            }
+        }
+
+        impl MulAssign<Fx32> for $name {
+            #[inline]
+            fn mul_assign(&mut self, rhs: Fx32) {
+                *self = *self * rhs;
+            }
+        }
+    };
+}
+
+vec_ops!(Vec2 { x, y });
+vec_ops!(Vec3 { x, y, z });
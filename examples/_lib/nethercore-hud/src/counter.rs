@@ -0,0 +1,110 @@
+//! Odometer-style rolling counter
+
+/// Easing curves for `ease()`, with the same values as the ZX `easing::*` constants
+pub mod easing {
+    pub const LINEAR: u32 = 0;
+    pub const EASE_IN: u32 = 1;
+    pub const EASE_OUT: u32 = 2;
+    pub const EASE_IN_OUT: u32 = 3;
+}
+
+/// Map linear progress `t` (0-1) through one of the `easing::*` curves
+///
+/// Matches the curves used by `epu_transition()`. Unknown values fall back to linear.
+pub fn ease(easing_curve: u32, t: f32) -> f32 {
+    let t = t.clamp(0.0, 1.0);
+    match easing_curve {
+        easing::EASE_IN => t * t,
+        easing::EASE_OUT => t * (2.0 - t),
+        easing::EASE_IN_OUT => t * t * (3.0 - 2.0 * t),
+        _ => t,
+    }
+}
+
+/// Odometer-style counter that rolls its digits toward a target value
+///
+/// Call `set()` when the value changes and `tick()` once per `update()`, then
+/// draw `displayed()` from `render()`. All state advances in ticks, so the
+/// counter is rollback-safe when stored in game state.
+#[derive(Copy, Clone, Debug)]
+pub struct RollingCounter {
+    /// Value the counter is rolling toward
+    pub target: u32,
+
+    /// Ticks a roll takes from start to finish (0 snaps instantly)
+    pub duration_ticks: u32,
+
+    /// Easing curve for the roll (see `easing::*`)
+    pub easing: u32,
+
+    /// Minimum number of digits drawn (zero-padded)
+    pub min_digits: u32,
+
+    /// Displayed value when the current roll started
+    from: f32,
+    /// Ticks elapsed in the current roll
+    elapsed: u32,
+}
+
+impl Default for RollingCounter {
+    fn default() -> Self {
+        Self::new(20, easing::EASE_OUT)
+    }
+}
+
+impl RollingCounter {
+    /// Create a counter at zero with the given roll duration and easing curve
+    pub fn new(duration_ticks: u32, easing: u32) -> Self {
+        Self {
+            target: 0,
+            duration_ticks,
+            easing,
+            min_digits: 1,
+            from: 0.0,
+            elapsed: 0,
+        }
+    }
+
+    /// Start rolling toward `value` from whatever is currently displayed
+    pub fn set(&mut self, value: u32) {
+        if value == self.target {
+            return;
+        }
+        self.from = self.displayed();
+        self.target = value;
+        self.elapsed = 0;
+    }
+
+    /// Jump straight to `value` without rolling
+    pub fn snap(&mut self, value: u32) {
+        self.target = value;
+        self.from = value as f32;
+        self.elapsed = self.duration_ticks;
+    }
+
+    /// Advance the roll by one tick
+    pub fn tick(&mut self) {
+        if self.elapsed < self.duration_ticks {
+            self.elapsed += 1;
+        }
+    }
+
+    /// Whether the counter is still rolling
+    pub fn is_rolling(&self) -> bool {
+        self.elapsed < self.duration_ticks && self.from != self.target as f32
+    }
+
+    /// Current displayed value, including the fractional roll of the ones digit
+    ///
+    /// Lands exactly on `target` once the roll finishes.
+    pub fn displayed(&self) -> f32 {
+        if self.elapsed >= self.duration_ticks {
+            return self.target as f32;
+        }
+        let t = ease(
+            self.easing,
+            self.elapsed as f32 / self.duration_ticks as f32,
+        );
+        self.from + (self.target as f32 - self.from) * t
+    }
+}
@@ -0,0 +1,282 @@
+//! Controller-navigable selection grid
+
+/// Most players a `SelectGrid` tracks
+pub const MAX_GRID_PLAYERS: usize = 8;
+
+/// What a player did to a `SelectGrid` this tick
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum GridEvent {
+    /// Nothing happened
+    None,
+    /// The player's cursor moved to a new item
+    Moved,
+    /// The player pressed A and locked in their item
+    Confirmed,
+    /// The player pressed A on an item another player already locked in
+    /// (only when `exclusive` is set)
+    Taken,
+    /// The player pressed B and unlocked their item
+    Unconfirmed,
+    /// The player pressed B without a locked-in item (leave the menu)
+    Back,
+}
+
+/// One player's controller input to a `SelectGrid` for a tick
+#[derive(Copy, Clone, Default, PartialEq, Eq, Debug)]
+pub struct GridInput {
+    /// A was pressed this tick
+    pub confirm: bool,
+    /// B was pressed this tick
+    pub back: bool,
+    /// Direction held this tick, one step per axis (+X right, +Y down)
+    pub direction: (i32, i32),
+}
+
+/// Where a `SelectGrid` is drawn on screen
+#[derive(Copy, Clone)]
+pub struct GridLayout {
+    /// Left edge of the first column
+    pub x: f32,
+    /// Top edge of the first row
+    pub y: f32,
+    /// Width of one cell
+    pub cell_width: f32,
+    /// Height of one cell
+    pub cell_height: f32,
+    /// Space between neighboring cells
+    pub gap: f32,
+}
+
+/// Grid or list of choices navigated by controller, with a cursor per player
+///
+/// Items are numbered row by row, `columns` to a row; the last row may be
+/// short. Set `columns` to 1 for a vertical list. Every player moves their own
+/// cursor with a direction, locks in an item with A and backs out with B, so
+/// one grid serves a level-up choice, a character select or a four-player car
+/// select alike.
+///
+/// Call `handle()` for every player once per `update()`. All state advances
+/// in ticks, so the grid is rollback-safe when stored in game state.
+#[derive(Copy, Clone)]
+pub struct SelectGrid {
+    /// Items per row (1 = vertical list)
+    pub columns: u32,
+
+    /// Number of items
+    pub count: u32,
+
+    /// Moving past an edge wraps to the other side of the row or column
+    pub wrap: bool,
+
+    /// Players can't lock in an item another player already locked in
+    pub exclusive: bool,
+
+    /// Ticks a direction is held before it starts repeating (0 = no repeat)
+    pub repeat_delay: u32,
+
+    /// Ticks between repeats while a direction stays held
+    pub repeat_rate: u32,
+
+    /// Selected item per player
+    selected: [u32; MAX_GRID_PLAYERS],
+    /// Bit per player who has locked in their item
+    confirmed: u32,
+    /// Direction each player held last tick
+    held: [(i32, i32); MAX_GRID_PLAYERS],
+    /// Ticks each player has held that direction
+    held_ticks: [u32; MAX_GRID_PLAYERS],
+}
+
+impl SelectGrid {
+    /// Create a grid of `count` items, `columns` to a row, with every cursor
+    /// on the first item
+    pub fn new(columns: u32, count: u32) -> Self {
+        Self {
+            columns: columns.max(1),
+            count,
+            wrap: true,
+            exclusive: false,
+            repeat_delay: 20,
+            repeat_rate: 6,
+            selected: [0; MAX_GRID_PLAYERS],
+            confirmed: 0,
+            held: [(0, 0); MAX_GRID_PLAYERS],
+            held_ticks: [0; MAX_GRID_PLAYERS],
+        }
+    }
+
+    /// Apply a player's input for this tick: move, confirm or unconfirm
+    /// their cursor
+    pub fn handle(&mut self, player: u32, input: GridInput) -> GridEvent {
+        let p = player as usize;
+        if p >= MAX_GRID_PLAYERS {
+            return GridEvent::None;
+        }
+
+        if input.back {
+            return if self.unconfirm(player) {
+                GridEvent::Unconfirmed
+            } else {
+                GridEvent::Back
+            };
+        }
+        if input.confirm && !self.is_confirmed(player) {
+            return if self.confirm(player) {
+                GridEvent::Confirmed
+            } else {
+                GridEvent::Taken
+            };
+        }
+
+        // Move on the first tick of a direction, then repeat while held
+        let direction = input.direction;
+        let step = if direction == (0, 0) || direction != self.held[p] {
+            self.held_ticks[p] = 0;
+            direction != (0, 0)
+        } else {
+            self.held_ticks[p] += 1;
+            let ticks = self.held_ticks[p];
+            self.repeat_delay > 0
+                && ticks >= self.repeat_delay
+                && (ticks - self.repeat_delay).is_multiple_of(self.repeat_rate.max(1))
+        };
+        self.held[p] = direction;
+
+        if step && self.navigate(player, direction.0, direction.1) {
+            GridEvent::Moved
+        } else {
+            GridEvent::None
+        }
+    }
+
+    /// Move a player's cursor by whole cells; returns whether it moved
+    ///
+    /// Locked-in players don't move. Without `wrap` the cursor stops at the
+    /// edges. Moving vertically into a short last row lands on its last item.
+    pub fn navigate(&mut self, player: u32, dx: i32, dy: i32) -> bool {
+        let p = player as usize;
+        if p >= MAX_GRID_PLAYERS || self.count == 0 || self.is_confirmed(player) {
+            return false;
+        }
+
+        let columns = self.columns.max(1) as i32;
+        let rows = self.count.div_ceil(columns as u32) as i32;
+        let index = self.selected[p].min(self.count - 1) as i32;
+        let (mut col, mut row) = (index % columns, index / columns);
+
+        if dx != 0 {
+            let len = self.row_len(row as u32) as i32;
+            col = self.step(col, dx, len);
+        }
+        if dy != 0 {
+            row = self.step(row, dy, rows);
+            col = col.min(self.row_len(row as u32) as i32 - 1);
+        }
+
+        let new_index = (row * columns + col) as u32;
+        let moved = new_index != self.selected[p];
+        self.selected[p] = new_index;
+        moved
+    }
+
+    /// Lock in a player's current item; returns false if `exclusive` is set
+    /// and another player already locked it in
+    pub fn confirm(&mut self, player: u32) -> bool {
+        let p = player as usize;
+        if p >= MAX_GRID_PLAYERS || self.count == 0 {
+            return false;
+        }
+        let others = self.confirmed_by(self.selected[p]) & !(1 << p);
+        if self.exclusive && others != 0 {
+            return false;
+        }
+        self.confirmed |= 1 << p;
+        true
+    }
+
+    /// Unlock a player's item; returns false if it wasn't locked in
+    pub fn unconfirm(&mut self, player: u32) -> bool {
+        if !self.is_confirmed(player) {
+            return false;
+        }
+        self.confirmed &= !(1 << player);
+        true
+    }
+
+    /// Unlock every player's item, keeping cursors where they are
+    pub fn reset(&mut self) {
+        self.confirmed = 0;
+    }
+
+    /// Item a player's cursor is on
+    pub fn selected(&self, player: u32) -> u32 {
+        self.selected.get(player as usize).copied().unwrap_or(0)
+    }
+
+    /// Move a player's cursor straight to an item (clamped to the grid)
+    pub fn select(&mut self, player: u32, index: u32) {
+        if let Some(selected) = self.selected.get_mut(player as usize) {
+            *selected = index.min(self.count.saturating_sub(1));
+        }
+    }
+
+    /// Change the number of items, pulling cursors back onto the grid
+    pub fn set_count(&mut self, count: u32) {
+        self.count = count;
+        for selected in &mut self.selected {
+            *selected = (*selected).min(count.saturating_sub(1));
+        }
+    }
+
+    /// Whether a player has locked in their item
+    pub fn is_confirmed(&self, player: u32) -> bool {
+        player < MAX_GRID_PLAYERS as u32 && self.confirmed & (1 << player) != 0
+    }
+
+    /// Whether the first `players` players have all locked in
+    pub fn all_confirmed(&self, players: u32) -> bool {
+        let players = players.min(MAX_GRID_PLAYERS as u32);
+        let mask = (1u32 << players) - 1;
+        players > 0 && self.confirmed & mask == mask
+    }
+
+    /// Bit per player whose cursor is on an item
+    pub fn hovered_by(&self, index: u32) -> u32 {
+        self.selected
+            .iter()
+            .enumerate()
+            .filter(|(_, &selected)| selected == index)
+            .fold(0, |mask, (p, _)| mask | (1 << p))
+    }
+
+    /// Bit per player who locked in an item
+    pub fn confirmed_by(&self, index: u32) -> u32 {
+        self.hovered_by(index) & self.confirmed
+    }
+
+    /// Top-left corner of an item's cell
+    pub fn cell_position(&self, index: u32, layout: &GridLayout) -> (f32, f32) {
+        let columns = self.columns.max(1);
+        let (col, row) = (index % columns, index / columns);
+        (
+            layout.x + col as f32 * (layout.cell_width + layout.gap),
+            layout.y + row as f32 * (layout.cell_height + layout.gap),
+        )
+    }
+
+    /// Number of items in a row
+    fn row_len(&self, row: u32) -> u32 {
+        let columns = self.columns.max(1);
+        self.count.saturating_sub(row * columns).min(columns)
+    }
+
+    /// Step `pos` by `delta` within `0..len`, wrapping or stopping at the edges
+    fn step(&self, pos: i32, delta: i32, len: i32) -> i32 {
+        let next = pos + delta;
+        if self.wrap {
+            next.rem_euclid(len)
+        } else {
+            next.clamp(0, len - 1)
+        }
+    }
+}
@@ -0,0 +1,34 @@
+//! Nethercore HUD - Rollback-safe state for heads-up display widgets
+//!
+//! The widgets here only hold state and advance it by ticks; reading
+//! controllers and drawing are left to the game (examples-common wires both
+//! up to the ZX FFI). That keeps them deterministic when stored in game state,
+//! and testable on the host:
+//!
+//! - [`RollingCounter`] — odometer-style number that rolls toward its target
+//! - [`SelectGrid`] — controller-navigable grid or list with a cursor per player
+//!
+//! ```ignore
+//! use nethercore_hud::{GridEvent, GridInput, SelectGrid};
+//!
+//! let mut grid = SelectGrid::new(4, 10);
+//! let input = GridInput {
+//!     confirm: button_pressed(0, button::A) != 0,
+//!     back: button_pressed(0, button::B) != 0,
+//!     direction: dpad_direction(0),
+//! };
+//! if grid.handle(0, input) == GridEvent::Confirmed {
+//!     start_level(grid.selected(0));
+//! }
+//! ```
+
+#![no_std]
+
+mod counter;
+mod grid;
+
+pub use counter::{ease, easing, RollingCounter};
+pub use grid::{GridEvent, GridInput, GridLayout, SelectGrid, MAX_GRID_PLAYERS};
+
+#[cfg(test)]
+mod tests;
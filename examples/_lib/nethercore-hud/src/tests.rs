@@ -0,0 +1,302 @@
+use super::*;
+
+fn press(direction: (i32, i32)) -> GridInput {
+    GridInput {
+        direction,
+        ..GridInput::default()
+    }
+}
+
+const RIGHT: (i32, i32) = (1, 0);
+const LEFT: (i32, i32) = (-1, 0);
+const DOWN: (i32, i32) = (0, 1);
+const UP: (i32, i32) = (0, -1);
+
+/// 4 columns, 10 items: rows of 4, 4 and a short last row of 2
+fn ragged_grid() -> SelectGrid {
+    SelectGrid::new(4, 10)
+}
+
+#[test]
+fn test_horizontal_wrap_within_row() {
+    let mut grid = ragged_grid();
+    assert!(grid.navigate(0, -1, 0));
+    assert_eq!(grid.selected(0), 3);
+    assert!(grid.navigate(0, 1, 0));
+    assert_eq!(grid.selected(0), 0);
+}
+
+#[test]
+fn test_vertical_wrap_between_first_and_last_row() {
+    let mut grid = ragged_grid();
+    grid.select(0, 1);
+    assert!(grid.navigate(0, 0, -1));
+    assert_eq!(grid.selected(0), 9);
+    assert!(grid.navigate(0, 0, 1));
+    assert_eq!(grid.selected(0), 1);
+}
+
+#[test]
+fn test_partial_last_row() {
+    let mut grid = ragged_grid();
+
+    // Moving down into the short row lands on its last item
+    grid.select(0, 7);
+    assert!(grid.navigate(0, 0, 1));
+    assert_eq!(grid.selected(0), 9);
+
+    // Horizontal wrap only spans the items the row has
+    assert!(grid.navigate(0, 1, 0));
+    assert_eq!(grid.selected(0), 8);
+    assert!(grid.navigate(0, -1, 0));
+    assert_eq!(grid.selected(0), 9);
+
+    // Wrapping up from the first row's last column clamps too
+    grid.select(0, 3);
+    assert!(grid.navigate(0, 0, -1));
+    assert_eq!(grid.selected(0), 9);
+}
+
+#[test]
+fn test_no_wrap_stops_at_edges() {
+    let mut grid = ragged_grid();
+    grid.wrap = false;
+    assert!(!grid.navigate(0, -1, 0));
+    assert!(!grid.navigate(0, 0, -1));
+    assert_eq!(grid.selected(0), 0);
+
+    grid.select(0, 9);
+    assert!(!grid.navigate(0, 1, 0));
+    assert!(!grid.navigate(0, 0, 1));
+    assert_eq!(grid.selected(0), 9);
+
+    // Multi-cell steps clamp instead of overshooting
+    grid.select(0, 0);
+    assert!(grid.navigate(0, 5, 0));
+    assert_eq!(grid.selected(0), 3);
+}
+
+#[test]
+fn test_vertical_list_and_single_item() {
+    let mut list = SelectGrid::new(1, 3);
+    assert!(list.navigate(0, 0, 1));
+    assert_eq!(list.selected(0), 1);
+    assert!(!list.navigate(0, 1, 0));
+    assert!(list.navigate(0, 0, 2));
+    assert_eq!(list.selected(0), 0);
+
+    let mut single = SelectGrid::new(4, 1);
+    assert!(!single.navigate(0, 1, 0));
+    assert!(!single.navigate(0, 0, 1));
+    assert_eq!(single.selected(0), 0);
+
+    let mut empty = SelectGrid::new(4, 0);
+    assert!(!empty.navigate(0, 1, 0));
+    assert!(!empty.confirm(0));
+}
+
+#[test]
+fn test_held_direction_repeats_after_delay() {
+    let mut grid = SelectGrid::new(1, 100);
+    grid.repeat_delay = 3;
+    grid.repeat_rate = 2;
+
+    let moves: [bool; 8] =
+        core::array::from_fn(|_| grid.handle(0, press(DOWN)) == GridEvent::Moved);
+    // First tick moves, then nothing until the delay, then every 2 ticks
+    assert_eq!(moves, [true, false, false, true, false, true, false, true]);
+
+    // Releasing and pressing again moves immediately
+    assert_eq!(grid.handle(0, GridInput::default()), GridEvent::None);
+    assert_eq!(grid.handle(0, press(DOWN)), GridEvent::Moved);
+    // So does changing direction
+    assert_eq!(grid.handle(0, press(UP)), GridEvent::Moved);
+
+    grid.repeat_delay = 0;
+    assert_eq!(grid.handle(0, press(UP)), GridEvent::None);
+    assert_eq!(grid.handle(0, press(UP)), GridEvent::None);
+}
+
+#[test]
+fn test_confirm_and_back() {
+    let mut grid = ragged_grid();
+    let a = GridInput {
+        confirm: true,
+        ..GridInput::default()
+    };
+    let b = GridInput {
+        back: true,
+        ..GridInput::default()
+    };
+
+    assert_eq!(grid.handle(0, b), GridEvent::Back);
+    assert_eq!(grid.handle(0, a), GridEvent::Confirmed);
+    assert!(grid.is_confirmed(0));
+    // Locked-in cursors don't move, and A again does nothing
+    assert_eq!(grid.handle(0, press(RIGHT)), GridEvent::None);
+    assert_eq!(grid.handle(0, a), GridEvent::None);
+    assert_eq!(grid.handle(0, b), GridEvent::Unconfirmed);
+    assert_eq!(grid.handle(0, press(LEFT)), GridEvent::Moved);
+}
+
+#[test]
+fn test_exclusive_items_and_all_confirmed() {
+    let mut grid = ragged_grid();
+    grid.exclusive = true;
+
+    assert!(grid.confirm(0));
+    assert!(!grid.confirm(1));
+    assert!(!grid.all_confirmed(2));
+
+    grid.navigate(1, 1, 0);
+    assert!(grid.confirm(1));
+    assert!(grid.all_confirmed(2));
+    assert!(!grid.all_confirmed(3));
+    assert!(!grid.all_confirmed(0));
+
+    assert_eq!(grid.hovered_by(0), 0b1111_1101);
+    assert_eq!(grid.confirmed_by(0), 0b01);
+    assert_eq!(grid.confirmed_by(1), 0b10);
+
+    grid.reset();
+    assert!(!grid.is_confirmed(0) && !grid.is_confirmed(1));
+}
+
+#[test]
+fn test_out_of_range_players_are_ignored() {
+    let mut grid = ragged_grid();
+    let player = MAX_GRID_PLAYERS as u32;
+    assert_eq!(grid.handle(player, press(RIGHT)), GridEvent::None);
+    assert!(!grid.navigate(player, 1, 0));
+    assert!(!grid.confirm(player));
+    assert!(!grid.is_confirmed(player));
+    assert_eq!(grid.selected(player), 0);
+}
+
+#[test]
+fn test_set_count_pulls_cursors_back() {
+    let mut grid = ragged_grid();
+    grid.select(0, 9);
+    grid.select(1, 20);
+    assert_eq!(grid.selected(1), 9);
+
+    grid.set_count(6);
+    assert_eq!(grid.selected(0), 5);
+    assert!(grid.navigate(0, 0, 1));
+    assert_eq!(grid.selected(0), 1);
+}
+
+#[test]
+fn test_cell_position() {
+    let grid = ragged_grid();
+    let layout = GridLayout {
+        x: 10.0,
+        y: 20.0,
+        cell_width: 30.0,
+        cell_height: 40.0,
+        gap: 5.0,
+    };
+    assert_eq!(grid.cell_position(0, &layout), (10.0, 20.0));
+    assert_eq!(grid.cell_position(9, &layout), (45.0, 110.0));
+}
+
+fn run(counter: &mut RollingCounter, ticks: u32) {
+    for _ in 0..ticks {
+        counter.tick();
+    }
+}
+
+#[test]
+fn test_ease_endpoints_and_clamping() {
+    for curve in [
+        easing::LINEAR,
+        easing::EASE_IN,
+        easing::EASE_OUT,
+        easing::EASE_IN_OUT,
+        99,
+    ] {
+        assert_eq!(ease(curve, 0.0), 0.0);
+        assert_eq!(ease(curve, 1.0), 1.0);
+        assert_eq!(ease(curve, -1.0), 0.0);
+        assert_eq!(ease(curve, 2.0), 1.0);
+    }
+    assert_eq!(ease(easing::EASE_IN, 0.5), 0.25);
+    assert_eq!(ease(easing::EASE_OUT, 0.5), 0.75);
+    assert_eq!(ease(easing::EASE_IN_OUT, 0.5), 0.5);
+    assert_eq!(ease(99, 0.3), 0.3);
+}
+
+#[test]
+fn test_counter_reaches_target_exactly() {
+    let mut counter = RollingCounter::new(7, easing::EASE_OUT);
+    counter.set(123_457);
+    assert!(counter.is_rolling());
+
+    let mut last = counter.displayed();
+    for _ in 0..6 {
+        counter.tick();
+        assert!(counter.displayed() > last);
+        last = counter.displayed();
+        assert!(last < 123_457.0);
+    }
+
+    counter.tick();
+    assert!(!counter.is_rolling());
+    assert_eq!(counter.displayed(), 123_457.0);
+
+    // Extra ticks stay put
+    run(&mut counter, 10);
+    assert_eq!(counter.displayed(), 123_457.0);
+}
+
+#[test]
+fn test_counter_target_change_mid_roll() {
+    let mut counter = RollingCounter::new(10, easing::LINEAR);
+    counter.set(100);
+    run(&mut counter, 5);
+    assert_eq!(counter.displayed(), 50.0);
+
+    // A new target restarts the roll from what's on screen, with no jump
+    counter.set(20);
+    assert_eq!(counter.target, 20);
+    assert_eq!(counter.displayed(), 50.0);
+    assert!(counter.is_rolling());
+
+    run(&mut counter, 5);
+    assert_eq!(counter.displayed(), 35.0);
+    run(&mut counter, 5);
+    assert_eq!(counter.displayed(), 20.0);
+    assert!(!counter.is_rolling());
+}
+
+#[test]
+fn test_counter_same_target_keeps_rolling() {
+    let mut counter = RollingCounter::new(10, easing::LINEAR);
+    counter.set(100);
+    run(&mut counter, 5);
+
+    // Setting the value it's already heading to doesn't restart the roll
+    counter.set(100);
+    assert_eq!(counter.displayed(), 50.0);
+    run(&mut counter, 5);
+    assert_eq!(counter.displayed(), 100.0);
+}
+
+#[test]
+fn test_counter_snap_and_zero_duration() {
+    let mut counter = RollingCounter::default();
+    counter.set(40);
+    counter.tick();
+    counter.snap(75);
+    assert!(!counter.is_rolling());
+    assert_eq!(counter.displayed(), 75.0);
+
+    // Rolling on from a snap starts at the snapped value
+    counter.set(85);
+    assert_eq!(counter.displayed(), 75.0);
+
+    let mut instant = RollingCounter::new(0, easing::EASE_OUT);
+    instant.set(9);
+    assert!(!instant.is_rolling());
+    assert_eq!(instant.displayed(), 9.0);
+}
@@ -0,0 +1,57 @@
+//! `#[derive(Snapshot)]` for nethercore-state
+//!
+//! Use it through `nethercore_state::Snapshot`; this crate only exists because
+//! derive macros must live in a proc-macro crate.
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, parse_quote, Data, DeriveInput, Fields, GenericParam};
+
+/// Implement `nethercore_state::Snapshot` for a struct or enum
+///
+/// Every field type must implement `Snapshot` itself, which rules out
+/// references, raw pointers and heap types such as `Vec` or `Box`. Type
+/// parameters get a `Snapshot` bound.
+#[proc_macro_derive(Snapshot)]
+pub fn derive_snapshot(input: TokenStream) -> TokenStream {
+    let mut input = parse_macro_input!(input as DeriveInput);
+
+    let fields: Vec<&Fields> = match &input.data {
+        Data::Struct(data) => vec![&data.fields],
+        Data::Enum(data) => data.variants.iter().map(|v| &v.fields).collect(),
+        Data::Union(data) => {
+            return syn::Error::new_spanned(
+                data.union_token,
+                "Snapshot cannot be derived for unions",
+            )
+            .to_compile_error()
+            .into();
+        }
+    };
+    let field_types: Vec<_> = fields
+        .into_iter()
+        .flat_map(|fields| fields.iter().map(|field| &field.ty))
+        .collect();
+
+    for param in &mut input.generics.params {
+        if let GenericParam::Type(param) = param {
+            param
+                .bounds
+                .push(parse_quote!(::nethercore_state::Snapshot));
+        }
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    quote! {
+        unsafe impl #impl_generics ::nethercore_state::Snapshot for #name #ty_generics #where_clause {}
+
+        const _: () = {
+            fn assert_fields_are_snapshot #impl_generics () #where_clause {
+                fn assert_snapshot<T: ::nethercore_state::Snapshot + ?Sized>() {}
+                #(assert_snapshot::<#field_types>();)*
+            }
+        };
+    }
+    .into()
+}
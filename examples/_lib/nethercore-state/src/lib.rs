@@ -0,0 +1,68 @@
+//! Nethercore State - Register cart state for rollback snapshots
+//!
+//! By default the runtime snapshots a game's entire linear memory every tick
+//! it might roll back to. That is correct for any layout, but it also copies
+//! the allocator, scratch buffers and asset data that never need restoring.
+//! Games that register their state during `init()` are snapshotted by region
+//! instead: only registered values are saved and restored.
+//!
+//! - [`Snapshot`] — marker for types that are safe to restore byte-for-byte,
+//!   with a derive that checks every field
+//! - [`register`] — register a value with the host (`state_register()`)
+//!
+//! ```ignore
+//! use core::ptr::addr_of;
+//! use nethercore_state::Snapshot;
+//!
+//! #[derive(Snapshot)]
+//! struct World {
+//!     players: [Player; 4],
+//!     enemies: [Enemy; 64],
+//!     wave: u32,
+//! }
+//!
+//! static mut WORLD: World = World::new();
+//!
+//! #[no_mangle]
+//! pub extern "C" fn init() {
+//!     nethercore_state::register(addr_of!(WORLD));
+//! }
+//! ```
+//!
+//! Once anything is registered, memory outside registered regions is not
+//! rolled back — keep every value `update()` changes in a registered static.
+
+#![no_std]
+
+// Lets the derive's `::nethercore_state` paths resolve inside this crate
+extern crate self as nethercore_state;
+
+mod snapshot;
+
+pub use nethercore_state_derive::Snapshot;
+pub use snapshot::Snapshot;
+
+#[cfg(target_arch = "wasm32")]
+#[link(wasm_import_module = "env")]
+extern "C" {
+    fn state_register(ptr: *const u8, len: u32) -> u32;
+}
+
+/// Host stand-in for native test builds (nothing to register with)
+#[cfg(not(target_arch = "wasm32"))]
+unsafe fn state_register(_ptr: *const u8, _len: u32) -> u32 {
+    0
+}
+
+/// Register a value to be saved and restored on rollback
+///
+/// Call during `init()` with the address of a static. Returns false if the
+/// host rejected the region (after init, zero-sized, overlapping an already
+/// registered value, or too many regions).
+pub fn register<T: Snapshot>(value: *const T) -> bool {
+    let len = core::mem::size_of::<T>() as u32;
+    unsafe { state_register(value.cast(), len) != 0 }
+}
+
+#[cfg(test)]
+mod tests;
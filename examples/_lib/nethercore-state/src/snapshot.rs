@@ -0,0 +1,66 @@
+//! The [`Snapshot`] marker trait and its impls for core types
+
+/// Types whose bytes can be saved and later restored as-is
+///
+/// Rollback copies registered values byte-for-byte and never restores the
+/// rest of memory, so a snapshot type must hold all of its state inline: no
+/// references, raw pointers or heap handles (`Vec`, `Box`, `String`).
+///
+/// Prefer `#[derive(Snapshot)]`, which checks every field.
+///
+/// # Safety
+///
+/// Implementors must not contain pointers or anything that refers to memory
+/// outside the value itself.
+pub unsafe trait Snapshot {}
+
+macro_rules! impl_snapshot {
+    ($($ty:ty),* $(,)?) => {
+        $(unsafe impl Snapshot for $ty {})*
+    };
+}
+
+impl_snapshot!(
+    (),
+    bool,
+    char,
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize,
+    f32,
+    f64,
+);
+
+unsafe impl<T: Snapshot, const N: usize> Snapshot for [T; N] {}
+unsafe impl<T: Snapshot> Snapshot for Option<T> {}
+unsafe impl<T: ?Sized> Snapshot for core::marker::PhantomData<T> {}
+
+macro_rules! impl_snapshot_tuple {
+    ($($name:ident)+) => {
+        unsafe impl<$($name: Snapshot),+> Snapshot for ($($name,)+) {}
+    };
+}
+
+impl_snapshot_tuple!(A);
+impl_snapshot_tuple!(A B);
+impl_snapshot_tuple!(A B C);
+impl_snapshot_tuple!(A B C D);
+impl_snapshot_tuple!(A B C D E);
+impl_snapshot_tuple!(A B C D E F);
+
+#[cfg(feature = "fixed")]
+impl_snapshot!(
+    nethercore_fixed::Fx32,
+    nethercore_fixed::Fx64,
+    nethercore_fixed::Vec2,
+    nethercore_fixed::Vec3,
+);
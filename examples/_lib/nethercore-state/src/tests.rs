@@ -0,0 +1,72 @@
+//! Tests for nethercore-state
+
+// The types below only exist to exercise the derive
+#![allow(dead_code)]
+
+use crate::{register, Snapshot};
+
+fn assert_snapshot<T: Snapshot>() {}
+
+#[derive(Snapshot)]
+struct Player {
+    position: [f32; 3],
+    health: u16,
+    alive: bool,
+}
+
+#[derive(Snapshot)]
+struct Pair(u8, Option<i32>);
+
+#[derive(Snapshot)]
+struct Marker;
+
+#[derive(Snapshot)]
+enum Phase {
+    Menu,
+    Playing { round: u8, timer: f32 },
+    Over(u32),
+}
+
+#[derive(Snapshot)]
+struct Pool<T, const N: usize> {
+    items: [T; N],
+    count: usize,
+}
+
+#[test]
+fn test_derive_structs_and_enums() {
+    assert_snapshot::<Player>();
+    assert_snapshot::<Pair>();
+    assert_snapshot::<Marker>();
+    assert_snapshot::<Phase>();
+    assert_snapshot::<[Player; 4]>();
+    assert_snapshot::<(Phase, Option<Pair>)>();
+}
+
+#[test]
+fn test_derive_generics_bound_parameters() {
+    assert_snapshot::<Pool<Player, 8>>();
+    assert_snapshot::<Pool<(u8, u8), 0>>();
+}
+
+#[cfg(feature = "fixed")]
+#[test]
+fn test_fixed_types() {
+    #[derive(Snapshot)]
+    struct Body {
+        position: nethercore_fixed::Vec2,
+        timer: nethercore_fixed::Fx64,
+    }
+    assert_snapshot::<Body>();
+}
+
+#[test]
+fn test_register_without_host() {
+    // Native builds have no runtime to register with
+    static STATE: Player = Player {
+        position: [0.0; 3],
+        health: 100,
+        alive: true,
+    };
+    assert!(!register(&STATE as *const Player));
+}
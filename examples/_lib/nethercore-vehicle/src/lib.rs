@@ -0,0 +1,379 @@
+//! Nethercore Vehicle - Arcade car handling for racing games
+//!
+//! A top-down car model on the XZ plane: throttle and brake act along the
+//! car's heading, steering turns the heading, and tire grip pulls the
+//! velocity back in line with it. Easing off the grip (a drift, or a slippery
+//! surface) lets the car slide sideways while it turns.
+//!
+//! Handling is tuned with [`VehicleParams`], including [`Curve`]s for grip
+//! against slip, steering against speed, and boost strength over time, and
+//! per-[`Surface`] grip and drag. State is [`Fx32`] fixed point and advances
+//! by a fixed tick, so the same inputs give bit-identical cars on every peer
+//! and the car is rollback-safe when stored in game state.
+//!
+//! ```ignore
+//! const PARAMS: VehicleParams = VehicleParams::DEFAULT;
+//!
+//! let input = VehicleInput {
+//!     throttle: Fx32::from_f32(left_stick_y(0)),
+//!     steer: Fx32::from_f32(-left_stick_x(0)),
+//!     drift: button_held(0, button::B) != 0,
+//! };
+//! car.step(&PARAMS, &input, &Surface::ROAD);
+//! if car.position.x > ROAD_EDGE {
+//!     car.collide(&PARAMS, Vec2::new(-Fx32::ONE, Fx32::ZERO), car.position.x - ROAD_EDGE);
+//! }
+//! ```
+
+#![no_std]
+
+pub use nethercore_fixed::{Fx32, Vec2};
+
+#[cfg(test)]
+mod tests;
+
+/// Length of one [`Vehicle::step`] (the default 60 Hz update rate)
+pub const VEHICLE_DT: Fx32 = Fx32::from_ratio(1, 60);
+
+/// Piecewise-linear curve through `(x, y)` points sorted by `x`
+///
+/// Samples before the first point or after the last hold that point's `y`.
+#[derive(Clone, Copy, Debug)]
+pub struct Curve(pub &'static [(Fx32, Fx32)]);
+
+impl Curve {
+    /// Value of the curve at `x` (zero for an empty curve)
+    pub fn sample(&self, x: Fx32) -> Fx32 {
+        let points = self.0;
+        let Some(&(first_x, first_y)) = points.first() else {
+            return Fx32::ZERO;
+        };
+        if x <= first_x {
+            return first_y;
+        }
+        for pair in points.windows(2) {
+            let ((x0, y0), (x1, y1)) = (pair[0], pair[1]);
+            if x <= x1 {
+                let span = x1 - x0;
+                if span <= Fx32::ZERO {
+                    return y1;
+                }
+                return y0.lerp(y1, (x - x0) / span);
+            }
+        }
+        points[points.len() - 1].1
+    }
+}
+
+/// Grip and rolling resistance of the ground under the car
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Surface {
+    /// Multiplier on tire grip (1 = tarmac)
+    pub grip: Fx32,
+    /// Extra speed lost per second, as a fraction of speed
+    pub drag: Fx32,
+}
+
+impl Surface {
+    /// Tarmac: full grip, no extra drag
+    pub const ROAD: Self = Self {
+        grip: Fx32::ONE,
+        drag: Fx32::ZERO,
+    };
+    /// Off-road: a little looser and much slower
+    pub const GRASS: Self = Self {
+        grip: Fx32::from_f32(0.7),
+        drag: Fx32::from_f32(1.5),
+    };
+    /// Ice: almost no grip, almost no drag
+    pub const ICE: Self = Self {
+        grip: Fx32::from_f32(0.15),
+        drag: Fx32::from_f32(0.05),
+    };
+}
+
+/// Handling tuning for a [`Vehicle`]
+///
+/// Speeds are in units per second, accelerations in units per second
+/// squared, and angles in radians.
+#[derive(Clone, Copy, Debug)]
+pub struct VehicleParams {
+    /// Acceleration at full throttle
+    pub accel: Fx32,
+    /// Deceleration at full brake (throttle below zero while rolling forward)
+    pub brake: Fx32,
+    /// Top speed under throttle
+    pub max_speed: Fx32,
+    /// Top speed in reverse
+    pub reverse_speed: Fx32,
+    /// Speed lost per second when coasting, as a fraction of speed
+    pub drag: Fx32,
+
+    /// Turn rate at full lock
+    pub steer_rate: Fx32,
+    /// Steering authority (0-1) against speed as a fraction of `max_speed`
+    pub steer_curve: Curve,
+
+    /// Sideways speed removed per second, as a fraction of sideways speed,
+    /// against slip (the sine of the angle between heading and velocity)
+    pub grip_curve: Curve,
+    /// Multiplier on grip while drifting
+    pub drift_grip: Fx32,
+    /// Multiplier on steering while drifting
+    pub drift_steer: Fx32,
+    /// Speed below which the drift input is ignored
+    pub drift_min_speed: Fx32,
+
+    /// Extra acceleration while boosting
+    pub boost_accel: Fx32,
+    /// Extra top speed while boosting
+    pub boost_speed: Fx32,
+    /// Boost strength (0-1) against boost progress (0 = start, 1 = end)
+    pub boost_curve: Curve,
+
+    /// Fraction of the speed into a wall that bounces back out (0-1)
+    pub wall_bounce: Fx32,
+    /// Fraction of the speed along a wall lost on contact (0-1)
+    pub wall_scrub: Fx32,
+}
+
+impl VehicleParams {
+    /// A nimble kart: grippy until pushed past ~20 degrees of slip
+    pub const DEFAULT: Self = Self {
+        accel: Fx32::from_int(24),
+        brake: Fx32::from_int(48),
+        max_speed: Fx32::from_int(40),
+        reverse_speed: Fx32::from_int(10),
+        drag: Fx32::from_f32(0.3),
+
+        steer_rate: Fx32::from_f32(2.6),
+        steer_curve: Curve(&[
+            (Fx32::ZERO, Fx32::ZERO),
+            (Fx32::from_f32(0.15), Fx32::ONE),
+            (Fx32::ONE, Fx32::from_f32(0.55)),
+        ]),
+
+        grip_curve: Curve(&[
+            (Fx32::ZERO, Fx32::from_int(10)),
+            (Fx32::from_f32(0.35), Fx32::from_int(8)),
+            (Fx32::from_f32(0.7), Fx32::from_int(3)),
+            (Fx32::ONE, Fx32::from_int(2)),
+        ]),
+        drift_grip: Fx32::from_f32(0.25),
+        drift_steer: Fx32::from_f32(1.4),
+        drift_min_speed: Fx32::from_int(12),
+
+        boost_accel: Fx32::from_int(40),
+        boost_speed: Fx32::from_int(15),
+        boost_curve: Curve(&[
+            (Fx32::ZERO, Fx32::ONE),
+            (Fx32::from_f32(0.6), Fx32::ONE),
+            (Fx32::ONE, Fx32::ZERO),
+        ]),
+
+        wall_bounce: Fx32::from_f32(0.3),
+        wall_scrub: Fx32::from_f32(0.2),
+    };
+}
+
+impl Default for VehicleParams {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Driver controls for one tick
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct VehicleInput {
+    /// Throttle (0 to 1) or brake/reverse (-1 to 0)
+    pub throttle: Fx32,
+    /// Steering (-1 to 1); positive increases heading
+    pub steer: Fx32,
+    /// Hold to drift (loosen grip and steer harder)
+    pub drift: bool,
+}
+
+/// Car state on the XZ plane
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Vehicle {
+    /// Position (`x` = world X, `y` = world Z)
+    pub position: Vec2,
+    /// Velocity in units per second
+    pub velocity: Vec2,
+    /// Heading in radians (0 faces +Z, increasing turns toward +X)
+    pub heading: Fx32,
+
+    /// Whether the last step was a drift
+    drifting: bool,
+    /// Ticks into the current boost
+    boost_elapsed: u32,
+    /// Length of the current boost in ticks (0 = not boosting)
+    boost_ticks: u32,
+}
+
+impl Vehicle {
+    /// Create a stopped car at `position` facing `heading`
+    pub const fn new(position: Vec2, heading: Fx32) -> Self {
+        Self {
+            position,
+            velocity: Vec2::ZERO,
+            heading,
+            drifting: false,
+            boost_elapsed: 0,
+            boost_ticks: 0,
+        }
+    }
+
+    /// Unit vector the car faces
+    pub fn forward(&self) -> Vec2 {
+        let (sin, cos) = self.heading.sin_cos();
+        Vec2::new(sin, cos)
+    }
+
+    /// Unit vector to the car's side (toward +X when facing +Z)
+    pub fn side(&self) -> Vec2 {
+        let (sin, cos) = self.heading.sin_cos();
+        Vec2::new(cos, -sin)
+    }
+
+    /// Speed along the heading (negative when reversing)
+    pub fn forward_speed(&self) -> Fx32 {
+        self.velocity.dot(self.forward())
+    }
+
+    /// Speed in any direction
+    pub fn speed(&self) -> Fx32 {
+        self.velocity.length()
+    }
+
+    /// How sideways the car is moving (0 = straight, 1 = fully sideways)
+    pub fn slip(&self) -> Fx32 {
+        let speed = self.speed();
+        if speed <= Fx32::EPSILON {
+            return Fx32::ZERO;
+        }
+        (self.velocity.dot(self.side()).abs() / speed).min(Fx32::ONE)
+    }
+
+    /// Whether the last step was a drift (for tire smoke and skid marks)
+    pub fn is_drifting(&self) -> bool {
+        self.drifting
+    }
+
+    /// Start a boost lasting `ticks`, replacing any boost in progress
+    pub fn boost(&mut self, ticks: u32) {
+        self.boost_elapsed = 0;
+        self.boost_ticks = ticks;
+    }
+
+    /// Whether a boost is in progress
+    pub fn is_boosting(&self) -> bool {
+        self.boost_elapsed < self.boost_ticks
+    }
+
+    /// Heading in degrees, for `push_rotate_y()`
+    pub fn heading_degrees(&self) -> f32 {
+        self.heading.to_f32().to_degrees()
+    }
+
+    /// Advance one tick of [`VEHICLE_DT`] (the 60 Hz shorthand for [`Self::step_dt`])
+    pub fn step(&mut self, params: &VehicleParams, input: &VehicleInput, surface: &Surface) {
+        self.step_dt(params, input, surface, VEHICLE_DT);
+    }
+
+    /// Advance by `dt` seconds, for games running at another tick rate
+    ///
+    /// Pass the tick length, e.g. `Fx32::from_ratio(1, 120)` at 120 Hz. Boosts
+    /// still last the number of steps given to [`Self::boost`].
+    pub fn step_dt(
+        &mut self,
+        params: &VehicleParams,
+        input: &VehicleInput,
+        surface: &Surface,
+        dt: Fx32,
+    ) {
+        let throttle = input.throttle.clamp(-Fx32::ONE, Fx32::ONE);
+        let steer = input.steer.clamp(-Fx32::ONE, Fx32::ONE);
+        let (forward, side) = (self.forward(), self.side());
+        let mut along = self.velocity.dot(forward);
+        let mut across = self.velocity.dot(side);
+
+        // Throttle, brake and reverse
+        if throttle > Fx32::ZERO {
+            if along < params.max_speed {
+                along += params.accel * throttle * dt;
+            }
+        } else if throttle < Fx32::ZERO {
+            if along > Fx32::ZERO {
+                along = (along + params.brake * throttle * dt).max(Fx32::ZERO);
+            } else if along > -params.reverse_speed {
+                along += params.accel * throttle * dt;
+            }
+        }
+
+        // Boost
+        let mut top_speed = params.max_speed;
+        if self.is_boosting() {
+            let progress = Fx32::from_ratio(self.boost_elapsed as i32, self.boost_ticks as i32);
+            let strength = params.boost_curve.sample(progress);
+            along += params.boost_accel * strength * dt;
+            top_speed += params.boost_speed * strength;
+            self.boost_elapsed += 1;
+        }
+
+        // Drag, then bleed off speed above the cap at the braking rate
+        along -= along * ((params.drag + surface.drag) * dt).min(Fx32::ONE);
+        if along > top_speed {
+            along = (along - params.brake * dt).max(top_speed);
+        } else if along < -params.reverse_speed {
+            along = (along + params.brake * dt).min(-params.reverse_speed);
+        }
+
+        // Tire grip pulls the velocity back in line with the heading
+        self.drifting = input.drift && along >= params.drift_min_speed;
+        let mut grip = params.grip_curve.sample(self.slip()) * surface.grip;
+        if self.drifting {
+            grip *= params.drift_grip;
+        }
+        across -= across * (grip * dt).min(Fx32::ONE);
+
+        self.velocity = forward * along + side * across;
+        self.position += self.velocity * dt;
+
+        // Steering turns the heading; the velocity catches up through grip
+        let max_speed = params.max_speed.max(Fx32::EPSILON);
+        let mut turn =
+            steer * params.steer_rate * params.steer_curve.sample(along.abs() / max_speed);
+        if self.drifting {
+            turn *= params.drift_steer;
+        }
+        if along < Fx32::ZERO {
+            turn = -turn;
+        }
+        self.heading += turn * dt;
+        if self.heading > Fx32::PI {
+            self.heading -= Fx32::TAU;
+        } else if self.heading < -Fx32::PI {
+            self.heading += Fx32::TAU;
+        }
+    }
+
+    /// Push the car out of a wall
+    ///
+    /// `normal` is the wall's unit normal pointing back toward open track
+    /// and `depth` how far the car has gone past it. Speed into the wall is
+    /// reflected by `wall_bounce` and speed along it scrubbed by `wall_scrub`.
+    pub fn collide(&mut self, params: &VehicleParams, normal: Vec2, depth: Fx32) {
+        if depth <= Fx32::ZERO {
+            return;
+        }
+        self.position += normal * depth;
+
+        let into = self.velocity.dot(normal);
+        if into < Fx32::ZERO {
+            let along = self.velocity - normal * into;
+            self.velocity =
+                along * (Fx32::ONE - params.wall_scrub) - normal * (into * params.wall_bounce);
+        }
+    }
+}
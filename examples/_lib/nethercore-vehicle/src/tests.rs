@@ -0,0 +1,183 @@
+use super::*;
+
+const PARAMS: VehicleParams = VehicleParams::DEFAULT;
+
+fn drive(car: &mut Vehicle, input: VehicleInput, surface: Surface, ticks: u32) {
+    for _ in 0..ticks {
+        car.step(&PARAMS, &input, &surface);
+    }
+}
+
+fn full_throttle() -> VehicleInput {
+    VehicleInput {
+        throttle: Fx32::ONE,
+        ..VehicleInput::default()
+    }
+}
+
+#[test]
+fn test_curve_sample() {
+    const RAMP: Curve = Curve(&[
+        (Fx32::ZERO, Fx32::ZERO),
+        (Fx32::ONE, Fx32::from_int(10)),
+        (Fx32::from_int(2), Fx32::from_int(10)),
+    ]);
+    let curve = RAMP;
+    assert_eq!(curve.sample(-Fx32::ONE), Fx32::ZERO);
+    assert_eq!(curve.sample(Fx32::HALF), Fx32::from_int(5));
+    assert_eq!(curve.sample(Fx32::from_f32(1.5)), Fx32::from_int(10));
+    assert_eq!(curve.sample(Fx32::from_int(5)), Fx32::from_int(10));
+    assert_eq!(Curve(&[]).sample(Fx32::ONE), Fx32::ZERO);
+}
+
+#[test]
+fn test_accelerates_to_top_speed() {
+    let mut car = Vehicle::default();
+    drive(&mut car, full_throttle(), Surface::ROAD, 600);
+
+    let speed = car.forward_speed();
+    assert!(
+        speed > Fx32::from_int(35) && speed <= PARAMS.max_speed,
+        "{:?}",
+        speed
+    );
+    assert_eq!(car.velocity.x, Fx32::ZERO);
+    assert!(car.position.y > Fx32::from_int(100));
+
+    // Grass drag holds the car well below road speed
+    let mut off_road = Vehicle::default();
+    drive(&mut off_road, full_throttle(), Surface::GRASS, 600);
+    assert!(off_road.forward_speed() < speed - Fx32::from_int(5));
+}
+
+#[test]
+fn test_120hz_steps_match_60hz() {
+    let input = VehicleInput {
+        throttle: Fx32::ONE,
+        steer: Fx32::from_ratio(1, 2),
+        ..VehicleInput::default()
+    };
+    let half_dt = Fx32::from_ratio(1, 120);
+
+    let mut at_60 = Vehicle::default();
+    let mut at_120 = Vehicle::default();
+    for _ in 0..60 {
+        at_60.step(&PARAMS, &input, &Surface::ROAD);
+        for _ in 0..2 {
+            at_120.step_dt(&PARAMS, &input, &Surface::ROAD, half_dt);
+        }
+    }
+
+    let close = |a: Fx32, b: Fx32| (a - b).abs() < Fx32::from_ratio(1, 8);
+    assert!(
+        close(at_60.speed(), at_120.speed()),
+        "{:?} {:?}",
+        at_60.speed(),
+        at_120.speed()
+    );
+    assert!(close(at_60.position.x, at_120.position.x));
+    assert!(close(at_60.position.y, at_120.position.y));
+    assert!(close(at_60.heading, at_120.heading));
+}
+
+#[test]
+fn test_brakes_then_reverses() {
+    let mut car = Vehicle::default();
+    drive(&mut car, full_throttle(), Surface::ROAD, 120);
+
+    let brake = VehicleInput {
+        throttle: -Fx32::ONE,
+        ..VehicleInput::default()
+    };
+    drive(&mut car, brake, Surface::ROAD, 60);
+    assert!(car.forward_speed() < Fx32::ZERO);
+
+    drive(&mut car, brake, Surface::ROAD, 600);
+    assert!(car.forward_speed() >= -PARAMS.reverse_speed);
+}
+
+#[test]
+fn test_drift_slides_further_than_grip() {
+    let corner = |drift: bool| {
+        let mut car = Vehicle::default();
+        drive(&mut car, full_throttle(), Surface::ROAD, 180);
+        let input = VehicleInput {
+            throttle: Fx32::ONE,
+            steer: Fx32::ONE,
+            drift,
+        };
+        drive(&mut car, input, Surface::ROAD, 30);
+        car
+    };
+
+    let grip = corner(false);
+    let drift = corner(true);
+    assert!(!grip.is_drifting());
+    assert!(drift.is_drifting());
+    assert!(drift.slip() > grip.slip());
+    assert!(drift.heading > grip.heading);
+
+    // Ice lets a grip turn slide as well
+    let mut icy = Vehicle::default();
+    drive(&mut icy, full_throttle(), Surface::ROAD, 180);
+    let input = VehicleInput {
+        throttle: Fx32::ONE,
+        steer: Fx32::ONE,
+        drift: false,
+    };
+    drive(&mut icy, input, Surface::ICE, 30);
+    assert!(icy.slip() > grip.slip());
+}
+
+#[test]
+fn test_boost_raises_top_speed_then_fades() {
+    let mut car = Vehicle::default();
+    drive(&mut car, full_throttle(), Surface::ROAD, 600);
+    let cruise = car.forward_speed();
+
+    car.boost(60);
+    drive(&mut car, full_throttle(), Surface::ROAD, 40);
+    assert!(car.is_boosting());
+    assert!(car.forward_speed() > PARAMS.max_speed);
+
+    drive(&mut car, full_throttle(), Surface::ROAD, 200);
+    assert!(!car.is_boosting());
+    assert_eq!(car.forward_speed(), cruise);
+}
+
+#[test]
+fn test_collide_pushes_back() {
+    let mut car = Vehicle::new(Vec2::new(Fx32::from_int(9), Fx32::ZERO), Fx32::ZERO);
+    car.velocity = Vec2::new(Fx32::from_int(10), Fx32::from_int(20));
+
+    let normal = Vec2::new(-Fx32::ONE, Fx32::ZERO);
+    car.collide(&PARAMS, normal, Fx32::ONE);
+    assert_eq!(car.position.x, Fx32::from_int(8));
+    assert!((car.velocity.x.to_f32() + 3.0).abs() < 0.001);
+    assert!((car.velocity.y.to_f32() - 16.0).abs() < 0.001);
+
+    // Moving away from the wall keeps its velocity
+    let before = car.velocity;
+    car.collide(&PARAMS, normal, Fx32::ONE);
+    assert_eq!(car.velocity, before);
+}
+
+#[test]
+fn test_deterministic() {
+    let run = || {
+        let mut car = Vehicle::default();
+        for tick in 0..600i32 {
+            let input = VehicleInput {
+                throttle: Fx32::from_ratio(tick % 7 - 2, 4),
+                steer: Fx32::from_ratio(tick % 11 - 5, 5),
+                drift: tick % 90 > 60,
+            };
+            if tick == 200 {
+                car.boost(45);
+            }
+            car.step(&PARAMS, &input, &Surface::ROAD);
+        }
+        car
+    };
+    assert_eq!(run(), run());
+}
@@ -0,0 +1,166 @@
+//! HUD widgets
+//!
+//! Provides reusable heads-up display helpers:
+//! - Drawing for the rolling (odometer-style) counter used for scores and
+//!   kill counts
+//! - Controller input and drawing for the selection grid
+//!
+//! The widgets' state lives in nethercore-hud and is re-exported here.
+
+use crate::ffi::*;
+use libm::{fabsf, floorf, fmodf};
+
+pub use nethercore_hud::{ease, RollingCounter};
+
+/// Drawing for `RollingCounter`
+pub trait RollingCounterExt {
+    /// Draw the counter with its top-left corner at (x, y)
+    ///
+    /// Each digit is drawn in its own clipped cell; a digit rolls up into the
+    /// next one only while every digit to its right is passing 9 → 0, like a
+    /// mechanical odometer. Leading digits beyond `min_digits` are left blank.
+    fn draw(&self, x: f32, y: f32, size: f32, color: u32);
+}
+
+impl RollingCounterExt for RollingCounter {
+    fn draw(&self, x: f32, y: f32, size: f32, color: u32) {
+        let value = self.displayed().max(0.0);
+        let whole = floorf(value) as u32;
+        let digits = digit_count(whole.max(self.target)).max(self.min_digits);
+        let shown_digits = digit_count(whole).max(self.min_digits);
+        let cell_width = unsafe { text_width(b"0".as_ptr(), 1, size) };
+
+        unsafe {
+            set_color(color);
+        }
+
+        let mut place = 1.0;
+        for i in 0..digits {
+            let digit = fmodf(floorf(value / place), 10.0) as u8;
+
+            // Roll progress toward the next digit: the ones digit rolls
+            // continuously, higher digits only while the rest read 9.x
+            let remainder = fmodf(value, place * 10.0) - digit as f32 * place;
+            let roll = (remainder - (place - 1.0)).clamp(0.0, 1.0);
+
+            let cell_x = x + (digits - 1 - i) as f32 * cell_width;
+            let offset = roll * size;
+            let blank = i >= shown_digits;
+
+            unsafe {
+                clip_push(cell_x, y, cell_width, size);
+                if !blank {
+                    draw_digit(digit, cell_x, y - offset, size);
+                }
+                if roll > 0.0 {
+                    draw_digit((digit + 1) % 10, cell_x, y + size - offset, size);
+                }
+                clip_pop();
+            }
+
+            place *= 10.0;
+        }
+    }
+}
+
+pub use nethercore_hud::{GridEvent, GridInput, GridLayout, SelectGrid, MAX_GRID_PLAYERS};
+
+/// Stick deflection that counts as a direction in `SelectGrid`
+const GRID_STICK_THRESHOLD: f32 = 0.5;
+
+/// Controller input and drawing for `SelectGrid`
+///
+/// Every player moves their own cursor with the D-pad or left stick, locks in
+/// an item with A and backs out with B.
+pub trait SelectGridExt {
+    /// Read a player's controller and move, confirm or unconfirm their cursor
+    fn update(&mut self, player: u32) -> GridEvent;
+
+    /// Outline the cell under each of the first `players` cursors
+    ///
+    /// `colors` holds one color per player. Cursors sharing a cell are drawn
+    /// inset inside each other so every player stays visible, and locked-in
+    /// items get a thicker outline.
+    fn draw_cursors(&self, players: u32, layout: &GridLayout, colors: &[u32]);
+}
+
+impl SelectGridExt for SelectGrid {
+    fn update(&mut self, player: u32) -> GridEvent {
+        if player as usize >= MAX_GRID_PLAYERS {
+            return GridEvent::None;
+        }
+        let pressed = unsafe { buttons_pressed(player) };
+        let input = GridInput {
+            confirm: pressed & (1 << A) != 0,
+            back: pressed & (1 << B) != 0,
+            direction: unsafe { held_direction(player) },
+        };
+        self.handle(player, input)
+    }
+
+    fn draw_cursors(&self, players: u32, layout: &GridLayout, colors: &[u32]) {
+        if self.count == 0 {
+            return;
+        }
+        let players = (players as usize).min(MAX_GRID_PLAYERS);
+        for (p, &color) in colors.iter().enumerate().take(players) {
+            let index = self.selected(p as u32);
+            // Players below this one on the same cell sit further out
+            let inset = (self.hovered_by(index) & ((1 << p) - 1)).count_ones() as f32 * 4.0;
+            let thickness = if self.is_confirmed(p as u32) {
+                4.0
+            } else {
+                2.0
+            };
+            let (cx, cy) = self.cell_position(index, layout);
+            let (x0, y0) = (cx + inset, cy + inset);
+            let (x1, y1) = (
+                cx + layout.cell_width - inset,
+                cy + layout.cell_height - inset,
+            );
+
+            unsafe {
+                set_color(color);
+                draw_line(x0, y0, x1, y0, thickness);
+                draw_line(x1, y0, x1, y1, thickness);
+                draw_line(x1, y1, x0, y1, thickness);
+                draw_line(x0, y1, x0, y0, thickness);
+            }
+        }
+    }
+}
+
+/// Direction a player is holding on the D-pad, or else the left stick's
+/// dominant axis (+Y down)
+unsafe fn held_direction(player: u32) -> (i32, i32) {
+    let held = unsafe { buttons_held(player) };
+    let axis = |negative: u32, positive: u32| {
+        ((held >> positive) & 1) as i32 - ((held >> negative) & 1) as i32
+    };
+    let dpad = (axis(LEFT, RIGHT), axis(UP, DOWN));
+    if dpad != (0, 0) {
+        return dpad;
+    }
+
+    // Stick +Y is up
+    let (x, y) = unsafe { (left_stick_x(player), -left_stick_y(player)) };
+    if fabsf(x).max(fabsf(y)) < GRID_STICK_THRESHOLD {
+        (0, 0)
+    } else if fabsf(x) >= fabsf(y) {
+        (if x > 0.0 { 1 } else { -1 }, 0)
+    } else {
+        (0, if y > 0.0 { 1 } else { -1 })
+    }
+}
+
+/// Number of decimal digits in `value` (at least 1)
+fn digit_count(value: u32) -> u32 {
+    value.checked_ilog10().unwrap_or(0) + 1
+}
+
+unsafe fn draw_digit(digit: u8, x: f32, y: f32, size: f32) {
+    let ch = [b'0' + digit];
+    unsafe {
+        draw_text(ch.as_ptr(), 1, x, y, size);
+    }
+}
@@ -7,6 +7,8 @@
 //! - Color utilities
 //! - Shape management
 //! - Texture utilities
+//! - HUD widgets (rolling counter and selection grid from nethercore-hud)
+//! - Arcade vehicle handling (re-exported from nethercore-vehicle)
 
 #![no_std]
 
@@ -17,6 +19,8 @@ pub mod shapes;
 pub mod color;
 pub mod debug;
 pub mod texture;
+pub mod hud;
+pub use nethercore_vehicle as vehicle;
 
 pub use ffi::*;
 pub use camera::*;
@@ -25,3 +29,5 @@ pub use shapes::*;
 pub use color::*;
 pub use debug::*;
 pub use texture::*;
+pub use hud::*;
+pub use vehicle::*;
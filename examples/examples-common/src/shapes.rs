@@ -153,7 +153,13 @@ pub unsafe fn register_shape_debug(
     color: *const u8,
 ) {
     debug_group_begin(b"shape".as_ptr(), 5);
-    debug_register_i32(b"index (0-2)".as_ptr(), 11, shape_index as *const u8);
+    debug_register_enum(
+        b"index".as_ptr(),
+        5,
+        shape_index as *const u8,
+        b"Sphere\0Cube\0Torus\0".as_ptr(),
+        3,
+    );
     debug_register_f32(b"rotation_speed".as_ptr(), 14, rotation_speed as *const u8);
     debug_register_color(b"color".as_ptr(), 5, color);
     debug_group_end();
@@ -107,12 +107,37 @@ NCZX_IMPORT void keyframe_bind(uint32_t handle, uint32_t index);
 /** Texture handle (>0) on success. Traps on failure. */
 NCZX_IMPORT uint32_t rom_texture(const uint8_t* id_ptr, uint32_t id_len);
 
+/** Load a texture atlas from ROM data pack by ID. */
+/**  */
+/** Uploads the packed atlas texture and its sprite layout for `atlas_bind()`. */
+/**  */
+/** # Returns */
+/** Atlas texture handle (>0) on success. Traps on failure. */
+NCZX_IMPORT uint32_t rom_atlas(const uint8_t* id_ptr, uint32_t id_len);
+
+/** Load a cubemap from ROM data pack by ID. */
+/**  */
+/** Uploads the six faces (+X, -X, +Y, -Y, +Z, -Z) for `sky_draw()`. */
+/**  */
+/** # Returns */
+/** Cubemap handle (>0) on success. Traps on failure. */
+NCZX_IMPORT uint32_t rom_cubemap(const uint8_t* id_ptr, uint32_t id_len);
+
 /** Load a mesh from ROM data pack by ID. */
 /**  */
 /** # Returns */
 /** Mesh handle (>0) on success. Traps on failure. */
 NCZX_IMPORT uint32_t rom_mesh(const uint8_t* id_ptr, uint32_t id_len);
 
+/** Look up a named socket on a mesh loaded with `rom_mesh()`. */
+/**  */
+/** Sockets come from glTF nodes named `socket_<name>`; pass the name */
+/** without the prefix. */
+/**  */
+/** # Returns */
+/** Socket ID (>0) for `push_socket()`. Traps on failure. */
+NCZX_IMPORT uint32_t rom_mesh_socket(uint32_t mesh, const uint8_t* name_ptr, uint32_t name_len);
+
 /** Load skeleton inverse bind matrices from ROM data pack by ID. */
 /**  */
 /** # Returns */
@@ -131,6 +156,32 @@ NCZX_IMPORT uint32_t rom_font(const uint8_t* id_ptr, uint32_t id_len);
 /** Sound handle (>0) on success. Traps on failure. */
 NCZX_IMPORT uint32_t rom_sound(const uint8_t* id_ptr, uint32_t id_len);
 
+/** Load a music stream from ROM data pack by ID. */
+/**  */
+/** Streams stay compressed and are decoded in chunks while playing, so */
+/** use them for long music tracks. Play with `stream_play()`. */
+/**  */
+/** # Returns */
+/** Stream handle (>0) on success. Traps on failure. */
+NCZX_IMPORT uint32_t rom_stream(const uint8_t* id_ptr, uint32_t id_len);
+
+/** Load a spline path from ROM data pack by ID. */
+/**  */
+/** Paths are authored as `[[assets.paths]]` control points in nether.toml. */
+/** Evaluate with `path_eval()` and `path_length()`. */
+/**  */
+/** # Returns */
+/** Path handle (>0) on success. Traps on failure. */
+NCZX_IMPORT uint32_t rom_path(const uint8_t* id_ptr, uint32_t id_len);
+
+/** Load a localized string table from ROM data pack by ID. */
+/**  */
+/** Tables are searched in load order by `str_get()`. */
+/**  */
+/** # Returns */
+/** Table handle (>0) on success. Traps on failure. */
+NCZX_IMPORT uint32_t rom_strings(const uint8_t* id_ptr, uint32_t id_len);
+
 /** Get the byte size of raw data in the ROM data pack. */
 /**  */
 /** Use this to allocate a buffer before calling `rom_data()`. */
@@ -150,6 +201,27 @@ NCZX_IMPORT uint32_t rom_data_len(const uint8_t* id_ptr, uint32_t id_len);
 /** Bytes written on success. Traps on failure. */
 NCZX_IMPORT uint32_t rom_data(const uint8_t* id_ptr, uint32_t id_len, const uint8_t* dst_ptr, uint32_t max_len);
 
+/** Report loading progress for the host's loading bar. */
+/**  */
+/** # Arguments */
+/** * `current` — Items loaded so far (clamped to `total`) */
+/** * `total` — Total items to load (0 hides the bar) */
+/**  */
+/** Init-only. The loading screen is shown between `init_step()` calls: export */
+/** `init_step() -> u32` to spread loading over several frames, returning */
+/** non-zero once done. Games that load everything in `init()` never show it. */
+NCZX_IMPORT void load_progress(uint32_t current, uint32_t total);
+
+/** Set the loading screen background texture. */
+/**  */
+/** # Arguments */
+/** * `texture` — Texture handle stretched over the whole screen (0 = clear color only) */
+/**  */
+/** Init-only. Textures are uploaded after the `init()` or `init_step()` call */
+/** that loads them, so load the background in `init()` to show it from the */
+/** first loading frame. */
+NCZX_IMPORT void load_background(uint32_t texture);
+
 // =============================================================================
 // Audio Functions
 // =============================================================================
@@ -166,6 +238,21 @@ NCZX_IMPORT uint32_t rom_data(const uint8_t* id_ptr, uint32_t id_len, const uint
 /** Sound handle for use with playback functions. */
 NCZX_IMPORT uint32_t load_sound(const int16_t* data_ptr, uint32_t byte_len);
 
+/** Synthesize a retro sound effect (sfxr-style). */
+/**  */
+/** Must be called during `init()`. The same parameters always produce the same sound. */
+/**  */
+/** # Arguments */
+/** * `params_ptr` — Pointer to a 48-byte parameter block: */
+/** waveform (u32, `sfx_wave::*`), duty (f32, 0 = 50% square, 1 = thinnest pulse), */
+/** frequency (f32, Hz), slide (f32, octaves/s), vibrato_depth (f32, semitones), */
+/** vibrato_rate (f32, Hz), attack, sustain, decay (3 × f32, seconds, up to 10 total), */
+/** punch (f32, 0-1), volume (f32, 0-1), seed (u32, noise seed) */
+/**  */
+/** # Returns */
+/** Sound handle for use with playback functions (0 if the parameters are invalid). */
+NCZX_IMPORT uint32_t synth_sfx(const uint8_t* params_ptr);
+
 /** Play sound on next available channel (fire-and-forget). */
 /**  */
 /** # Arguments */
@@ -173,6 +260,20 @@ NCZX_IMPORT uint32_t load_sound(const int16_t* data_ptr, uint32_t byte_len);
 /** * `pan` — -1.0 (left) to 1.0 (right), 0.0 = center */
 NCZX_IMPORT void play_sound(uint32_t sound, float volume, float pan);
 
+/** Play sound on next available channel, starting on an exact sample. */
+/**  */
+/** For engine loops, beat-synced hits and rapid fire. The start time is part */
+/** of the rollback state, so re-simulated ticks schedule the same start. */
+/**  */
+/** # Arguments */
+/** * `tick` — Tick whose audio the sound starts in (tick_count() = this tick; past ticks start now) */
+/** * `offset_samples` — Samples (22.05kHz) into that tick's audio, up to 65536 */
+/** * `volume` — 0.0 to 1.0 */
+/** * `pan` — -1.0 (left) to 1.0 (right), 0.0 = center */
+/**  */
+/** Ticks more than 256 ahead are dropped. */
+NCZX_IMPORT void play_sound_at(uint64_t tick, uint32_t offset_samples, uint32_t sound, float volume, float pan);
+
 /** Play sound on a specific channel (for managed/looping audio). */
 /**  */
 /** # Arguments */
@@ -186,6 +287,77 @@ NCZX_IMPORT void channel_set(uint32_t channel, float volume, float pan);
 /** Stop a channel. */
 NCZX_IMPORT void channel_stop(uint32_t channel);
 
+/** Create a named audio bus, or look up an existing one. */
+/**  */
+/** Must be called during `init()`. Buses "master", "music", "sfx", "ui" */
+/** and "voice" already exist (see `audio_bus::*`); every bus is a child of master. */
+/**  */
+/** # Arguments */
+/** * `name_ptr` — Pointer to UTF-8 bus name */
+/** * `name_len` — Length of name in bytes */
+/**  */
+/** # Returns */
+/** Bus index (0 = master, also returned if all 8 buses are in use). */
+NCZX_IMPORT uint32_t bus_create(const uint8_t* name_ptr, uint32_t name_len);
+
+/** Route a channel through a bus (channels start on `audio_bus::SFX`). */
+/**  */
+/** Music, tracker and streams always play on `audio_bus::MUSIC`. */
+NCZX_IMPORT void channel_set_bus(uint32_t channel, uint32_t bus);
+
+/** Set a bus volume (0.0 to 1.0). The master bus scales all other buses. */
+/**  */
+/** The player's own volume for the bus (host settings) applies on top. */
+NCZX_IMPORT void bus_volume(uint32_t bus, float volume);
+
+/** Pause or resume everything routed through a bus. */
+/**  */
+/** # Arguments */
+/** * `paused` — 1 = pause, 0 = resume */
+NCZX_IMPORT void bus_pause(uint32_t bus, uint32_t paused);
+
+/** Place a player's listener for positional sound and make it active. */
+/**  */
+/** Call every frame from `update()` with each split-screen player's camera. */
+/** With no listener set, sounds are heard from the origin facing -Z. */
+/** In netplay, voice chat is heard from the local player's listener and comes */
+/** from the other players' listeners (see `voice_set_positional()`). */
+/**  */
+/** # Arguments */
+/** * `player` — Player index (0-7; players 4-7 only place voice chat) */
+/** * `x`, `y`, `z` — Listener position (world space) */
+/** * `forward_x`, `forward_y`, `forward_z` — Facing direction (sets left/right) */
+NCZX_IMPORT void listener_set_n(uint32_t player, float x, float y, float z, float forward_x, float forward_y, float forward_z);
+
+/** Deactivate a player's listener (e.g. when they leave split-screen). */
+NCZX_IMPORT void listener_clear_n(uint32_t player);
+
+/** Choose how positional sounds are mixed between listeners. */
+/**  */
+/** # Arguments */
+/** * `policy` — `listener_policy::NEAREST` (default) or `listener_policy::MIX` */
+NCZX_IMPORT void listener_set_policy(uint32_t policy);
+
+/** Play a sound at a world position on the next available channel. */
+/**  */
+/** Volume and pan are computed from the listeners when the sound starts. */
+/**  */
+/** # Arguments */
+/** * `volume` — 0.0 to 1.0 at the listener */
+/** * `x`, `y`, `z` — Sound position (world space) */
+/** * `range` — Distance at which the sound fades to silence */
+NCZX_IMPORT void play_sound_3d(uint32_t sound, float volume, float x, float y, float z, float range);
+
+/** Update a channel's volume and pan from a world position. */
+/**  */
+/** Call every frame for moving or looping sounds started with `channel_play()`. */
+/**  */
+/** # Arguments */
+/** * `volume` — 0.0 to 1.0 at the listener */
+/** * `x`, `y`, `z` — Sound position (world space) */
+/** * `range` — Distance at which the sound fades to silence */
+NCZX_IMPORT void channel_set_3d(uint32_t channel, float volume, float x, float y, float z, float range);
+
 // =============================================================================
 // Camera Functions
 // =============================================================================
@@ -201,12 +373,52 @@ NCZX_IMPORT void camera_set(float x, float y, float z, float target_x, float tar
 /** * `fov_degrees` — Field of view in degrees (typically 45-90, default 60) */
 NCZX_IMPORT void camera_fov(float fov_degrees);
 
+/** Shake the current camera. Call after `camera_set()` each frame. */
+/**  */
+/** # Arguments */
+/** * `trauma` — Shake strength (0-1, clamped); the offset grows with its square */
+/** * `frequency` — How many times per second the shake changes direction (e.g. 15-30) */
+/**  */
+/** Keep `trauma` in your game state: raise it on hits and lower it over time. */
+/** The shake is smooth noise over the tick count, seeded by the current viewport, */
+/** so rolled-back frames shake identically and split-screen players independently. */
+NCZX_IMPORT void camera_shake(float trauma, float frequency);
+
 /** Push a custom view matrix (16 floats, column-major order). */
 NCZX_IMPORT void push_view_matrix(float m0, float m1, float m2, float m3, float m4, float m5, float m6, float m7, float m8, float m9, float m10, float m11, float m12, float m13, float m14, float m15);
 
 /** Push a custom projection matrix (16 floats, column-major order). */
 NCZX_IMPORT void push_projection_matrix(float m0, float m1, float m2, float m3, float m4, float m5, float m6, float m7, float m8, float m9, float m10, float m11, float m12, float m13, float m14, float m15);
 
+// =============================================================================
+// Cursor Functions
+// =============================================================================
+
+/** Show the host-drawn cursors. */
+/**  */
+/** Every player has a cursor moved by their left stick and D-pad (speeding up while held), or */
+/** by the mouse for player 0. Positions update every tick even while hidden, and are rolled */
+/** back, so set the style from `update()`. One cursor is drawn per connected player. */
+/**  */
+/** # Arguments */
+/** * `style` — `cursor_style::HIDDEN`, `ARROW`, `HAND` or `CROSSHAIR` */
+NCZX_IMPORT void cursor_show(uint32_t style);
+
+/** Get a player's cursor position in screen pixels (0-959, 0-539). */
+/**  */
+/** Cursors start in the center of the screen. Writes X and Y to the provided pointers. */
+NCZX_IMPORT void cursor_pos(uint32_t player, float* out_x, float* out_y);
+
+/** Set how fast sticks move the cursors. */
+/**  */
+/** # Arguments */
+/** * `speed` — Pixels per second at full tilt (default 600) */
+/** * `acceleration` — Speed multiplier gained per second of holding the stick, up to 3× */
+/** (default 2.0, 0 = constant speed) */
+/**  */
+/** The mouse always moves the cursor 1:1. */
+NCZX_IMPORT void cursor_speed(float speed, float acceleration);
+
 // =============================================================================
 // Debug Inspection System
 // =============================================================================
@@ -235,6 +447,12 @@ NCZX_IMPORT void debug_register_f32(const uint8_t* name_ptr, uint32_t name_len,
 /** Register a bool value for debug inspection. */
 NCZX_IMPORT void debug_register_bool(const uint8_t* name_ptr, uint32_t name_len, const uint8_t* ptr);
 
+/** Register an i32 enum shown as a dropdown of labels. */
+/**  */
+/** `labels_ptr` points to `count` NUL-terminated labels stored back to back */
+/** (e.g. "low\0medium\0high\0"); value n is displayed as the n-th label. */
+NCZX_IMPORT void debug_register_enum(const uint8_t* name_ptr, uint32_t name_len, const uint8_t* ptr, const uint8_t* labels_ptr, uint32_t count);
+
 /** Register an i32 with min/max range constraints. */
 NCZX_IMPORT void debug_register_i32_range(const uint8_t* name_ptr, uint32_t name_len, const uint8_t* ptr, int32_t min, int32_t max);
 
@@ -310,6 +528,18 @@ NCZX_IMPORT void debug_watch_rect(const uint8_t* name_ptr, uint32_t name_len, co
 /** Watch a Color value (read-only). */
 NCZX_IMPORT void debug_watch_color(const uint8_t* name_ptr, uint32_t name_len, const uint8_t* ptr);
 
+/** Record a value for the current tick in a debug inspector graph. */
+/**  */
+/** Call from `update()` or `render()`; the last few seconds are graphed. Names */
+/** with a `/` share a graph per prefix, e.g. `"boost/p1"` and `"boost/p2"` are */
+/** two lines on the `"boost"` graph. */
+/**  */
+/** # Parameters */
+/** - `name_ptr`: Pointer to graph name string */
+/** - `name_len`: Length of graph name */
+/** - `value`: Value for this tick */
+NCZX_IMPORT void stat_plot(const uint8_t* name_ptr, uint32_t name_len, float value);
+
 /** Begin a collapsible group in the debug UI. */
 NCZX_IMPORT void debug_group_begin(const uint8_t* name_ptr, uint32_t name_len);
 
@@ -371,6 +601,40 @@ NCZX_IMPORT int32_t debug_is_paused(void);
 /** 1.0 = normal, 0.5 = half-speed, 2.0 = double-speed, etc. */
 NCZX_IMPORT float debug_get_time_scale(void);
 
+/** Draw a world-space line on top of the scene. */
+/**  */
+/** # Parameters */
+/** - `x1`, `y1`, `z1`: Start point in world units */
+/** - `x2`, `y2`, `z2`: End point in world units */
+/** - `color`: Line color (0xRRGGBBAA) */
+NCZX_IMPORT void debug_line3d(float x1, float y1, float z1, float x2, float y2, float z2, uint32_t color);
+
+/** Draw a wireframe sphere (three great circles). */
+/**  */
+/** # Parameters */
+/** - `x`, `y`, `z`: Center in world units */
+/** - `radius`: Radius in world units */
+/** - `color`: Line color (0xRRGGBBAA) */
+NCZX_IMPORT void debug_sphere(float x, float y, float z, float radius, uint32_t color);
+
+/** Draw a wireframe axis-aligned box. */
+/**  */
+/** # Parameters */
+/** - `min_x`, `min_y`, `min_z`: Minimum corner in world units */
+/** - `max_x`, `max_y`, `max_z`: Maximum corner in world units */
+/** - `color`: Line color (0xRRGGBBAA) */
+NCZX_IMPORT void debug_aabb(float min_x, float min_y, float min_z, float max_x, float max_y, float max_z, uint32_t color);
+
+/** Draw a text label anchored at a world-space position. */
+/**  */
+/** # Parameters */
+/** - `ptr`: Pointer to UTF-8 string data */
+/** - `len`: Length of string in bytes */
+/** - `x`, `y`, `z`: Label anchor in world units */
+/** - `size`: Font size in pixels */
+/** - `color`: Text color (0xRRGGBBAA) */
+NCZX_IMPORT void debug_text3d(const uint8_t* ptr, uint32_t len, float x, float y, float z, float size, uint32_t color);
+
 // =============================================================================
 // Immediate Mode 3D Drawing & Billboards
 // =============================================================================
@@ -408,6 +672,108 @@ NCZX_IMPORT void draw_billboard(float w, float h, uint32_t mode);
 /** * `mode` — 1=spherical, 2=cylindrical Y, 3=cylindrical X, 4=cylindrical Z */
 NCZX_IMPORT void draw_billboard_region(float w, float h, float src_x, float src_y, float src_w, float src_h, uint32_t mode);
 
+/** Create a particle system (init-only). */
+/**  */
+/** Particles are simulated host-side and drawn as spherical billboards. */
+/** They are presentation-only and not rolled back. */
+/**  */
+/** # Arguments */
+/** * `max` — Maximum live particles (1-65536) */
+/**  */
+/** # Returns */
+/** Particle system handle (>0) on success, 0 on failure. */
+NCZX_IMPORT uint32_t particles_create(uint32_t max);
+
+/** Emit a burst of particles. */
+/**  */
+/** Call from `render()` so rollback replay does not emit twice. */
+/** Emission is deterministically seeded per particle system. */
+/**  */
+/** # Arguments */
+/** * `handle` — Particle system handle */
+/** * `count` — Particles to spawn (clamped to free capacity) */
+/** * `x`, `y`, `z` — Spawn position (world space) */
+/** * `vel_spread` — Maximum initial speed (random direction) */
+/** * `life` — Lifetime in seconds */
+/** * `size_start`, `size_end` — Size curve (world units) */
+/** * `color_start`, `color_end` — Color gradient (0xRRGGBBAA) */
+/**  */
+/** # Returns */
+/** Number of particles actually spawned. */
+NCZX_IMPORT uint32_t particles_emit(uint32_t handle, uint32_t count, float x, float y, float z, float vel_spread, float life, float size_start, float size_end, uint32_t color_start, uint32_t color_end);
+
+/** Simulate and draw a particle system. */
+/**  */
+/** Steps the simulation once per elapsed tick, then draws every live */
+/** particle with the bound texture. Does not change `set_color()`. */
+NCZX_IMPORT void particles_draw(uint32_t handle);
+
+/** Get the number of live particles in a system. */
+NCZX_IMPORT uint32_t particles_count(uint32_t handle);
+
+/** Spawn a decal projected onto nearby static meshes. */
+/**  */
+/** Decals are presentation-only and not rolled back. Spawning the same */
+/** decal again on the same tick (rollback replay of `update()`) is ignored. */
+/** Up to 256 decals are live at once; spawning more replaces the oldest. */
+/**  */
+/** # Arguments */
+/** * `texture` — Texture handle (alpha cuts out the decal's shape) */
+/** * `x`, `y`, `z` — Point on the surface (world space) */
+/** * `normal_x`, `normal_y`, `normal_z` — Surface normal to project along */
+/** * `size` — Width and height in world units */
+/** * `rotation_deg` — Rotation about the normal (counter-clockwise) */
+/** * `lifetime` — Seconds until gone, fading over the last quarter (0 = permanent) */
+/**  */
+/** # Returns */
+/** 1 if spawned, 0 if duplicate or invalid. */
+NCZX_IMPORT uint32_t decal_spawn(uint32_t texture, float x, float y, float z, float normal_x, float normal_y, float normal_z, float size, float rotation_deg, float lifetime);
+
+/** Draw all live decals. */
+/**  */
+/** Call from `render()` after drawing the level, once per viewport. Decals */
+/** are projected onto static meshes drawn earlier in the frame; until one */
+/** is near, they draw as flat quads. Preserves the transform, bound */
+/** texture and `uniform_alpha()`. */
+NCZX_IMPORT void decals_draw(void);
+
+/** Remove all decals (e.g. when loading a new level). */
+NCZX_IMPORT void decals_clear(void);
+
+/** Create a water plane animated by Gerstner waves (init-only). */
+/**  */
+/** The plane is a square grid centered on the origin at y = 0. Waves travel */
+/** roughly along a seeded wind direction, each shorter and lower than the last. */
+/** Up to 16 planes can be created. */
+/**  */
+/** # Arguments */
+/** * `size` — Side length of the plane */
+/** * `wave_count` — Number of summed waves (1-8) */
+/** * `amplitude` — Combined crest height of all waves above rest */
+/** * `seed` — Picks the wind direction, wavelengths and phases */
+/**  */
+/** # Returns */
+/** Water handle (>0), or 0 on failure. */
+NCZX_IMPORT uint32_t water_plane(float size, uint32_t wave_count, float amplitude, uint32_t seed);
+
+/** Draw a water plane at the current tick. */
+/**  */
+/** Uses the current transform, bound textures and render state. UVs span 0-1 */
+/** across the plane and normals follow the waves. */
+NCZX_IMPORT void water_draw(uint32_t handle);
+
+/** Get the water surface height above a point at the current tick. */
+/**  */
+/** Deterministic, so it is safe for buoyancy in `update()`. */
+/**  */
+/** # Arguments */
+/** * `handle` — Water handle from water_plane() */
+/** * `x`, `z` — Point in the plane's own space */
+/**  */
+/** # Returns */
+/** Height above the plane's rest level, or 0 for invalid handles. */
+NCZX_IMPORT float water_height(uint32_t handle, float x, float z);
+
 // =============================================================================
 // Embedded Asset API
 // =============================================================================
@@ -489,10 +855,10 @@ NCZX_IMPORT uint32_t load_zskeleton(const uint8_t* data_ptr, uint32_t data_len);
 /** - 0x06: PATCHES (bounds source) */
 /** - 0x07: APERTURE (bounds modifier) */
 /** - 0x08: DECAL (sharp SDF shape) */
-/** - 0x09: GRID (repeating lines/panels) */
+/** - 0x09: GRID (perspective line grids) */
 /** - 0x0A: SCATTER (point field) */
 /** - 0x0B: FLOW (animated noise/streaks) */
-/** - 0x0C..0x13: radiance opcodes (TRACE/VEIL/ATMOSPHERE/PLANE/CELESTIAL/PORTAL/LOBE_RADIANCE/BAND_RADIANCE) */
+/** - 0x0C..0x14: radiance opcodes (TRACE/VEIL/ATMOSPHERE/PLANE/CELESTIAL/PORTAL/LOBE_RADIANCE/BAND_RADIANCE/FLASH) */
 /**  */
 /** # Blend Modes */
 /** - 0: ADD (dst + src * a) */
@@ -527,6 +893,7 @@ NCZX_IMPORT uint32_t load_zskeleton(const uint8_t* data_ptr, uint32_t data_len);
 /** # Notes */
 /** - The EPU compute pass runs automatically before rendering */
 /** - To set up multiple environments in a frame: call `environment_index(env_id)`, then `epu_set(config_ptr)` */
+/** (or use `epu_set_slot(slot, config_ptr)` + `epu_bind(slot)` for split-screen) */
 /** - Determinism: the EPU has no host-managed time; animate by changing instruction parameters from the game */
 NCZX_IMPORT void epu_set(const uint64_t* config_ptr);
 
@@ -539,6 +906,184 @@ NCZX_IMPORT void epu_set(const uint64_t* config_ptr);
 /** once per viewport/pass where you want an environment background. */
 NCZX_IMPORT void draw_epu(void);
 
+/** Draw a cubemap sky as the background for the current viewport/pass. */
+/**  */
+/** Used instead of `draw_epu()`: for each viewport and pass, whichever of the */
+/** two is called last is drawn. The sky follows the camera's rotation and */
+/** `texture_filter(...)`. */
+/**  */
+/** With `sky_ambient(...)` above 0, the sky also replaces the diffuse ambient */
+/** of the current `environment_index(...)` this frame. Reflections still come */
+/** from the EPU. */
+/**  */
+/** # Arguments */
+/** * `cubemap` — Cubemap handle from `rom_cubemap()` */
+/** * `rotation` — Rotation of the sky about the world Y axis in degrees */
+NCZX_IMPORT void sky_draw(uint32_t cubemap, float rotation);
+
+/** Set how strongly `sky_draw()` skies light the scene. */
+/**  */
+/** Applies to `sky_draw()` calls after it in the current frame; resets to 0 */
+/** every frame. */
+/**  */
+/** # Arguments */
+/** * `intensity` — Ambient multiplier (0 = keep the EPU ambient, 1 = sky as authored) */
+NCZX_IMPORT void sky_ambient(float intensity);
+
+/** Store an EPU configuration (128-byte) for an explicit environment slot. */
+/**  */
+/** Unlike `epu_set(...)`, this does not depend on (or change) the current */
+/** `environment_index(...)`. Use it to upload one environment per viewport */
+/** up front, then select between them with `epu_bind(slot)`. */
+/**  */
+/** # Arguments */
+/** * `slot` — Environment slot (`env_id`), clamped to 0..255 */
+/** * `config_ptr` — Pointer to 16 u64 values (128 bytes total) in WASM memory */
+/**  */
+/** # Example (per-player environments in split-screen) */
+/** ```rust,ignore */
+/** fn render() { */
+/** epu_set_slot(0, DAY_TRACK.as_ptr()); */
+/** epu_set_slot(1, NIGHT_TRACK.as_ptr()); */
+/**  */
+/** for p in 0..2 { */
+/** viewport(p * 480, 0, 480, 540); */
+/** epu_bind(p); */
+/** draw_mesh(track); */
+/** draw_epu(); */
+/** } */
+/** } */
+/** ``` */
+NCZX_IMPORT void epu_set_slot(uint32_t slot, const uint64_t* config_ptr);
+
+/** Bind an environment slot for subsequent draw calls. */
+/**  */
+/** Alias of `environment_index(slot)`, named to pair with `epu_set_slot()`: */
+/** selects the environment used by `draw_epu()` and by ambient/reflection */
+/** lighting of subsequent draws. */
+/** Call inside the per-player render loop after `viewport(...)`. */
+/**  */
+/** # Arguments */
+/** * `slot` — Environment slot (`env_id`), clamped to 0..255 */
+NCZX_IMPORT void epu_bind(uint32_t slot);
+
+/** Crossfade an environment slot to a new EPU configuration over time. */
+/**  */
+/** Colors, alphas, intensities and directions are interpolated field-wise from */
+/** the slot's current config to the target; opcodes and params switch over */
+/** halfway. Layers present on only one side fade in or out. */
+/**  */
+/** While the transition runs, configs pushed for `slot` are replaced by the */
+/** blend, so keep calling `epu_set(...)` / `epu_set_slot(...)` with the new */
+/** config every frame. */
+/**  */
+/** # Arguments */
+/** * `slot` — Environment slot (`env_id`), clamped to 0..255 */
+/** * `config_ptr` — Pointer to the target config (16 u64 values, 128 bytes) */
+/** * `duration_ticks` — Length of the crossfade in ticks (0 = switch immediately) */
+/** * `easing` — Easing curve (see `easing::*` constants) */
+/**  */
+/** # Example (day → night over 5 seconds at 60 ticks/s) */
+/** ```rust,ignore */
+/** fn render() { */
+/** if NIGHT_STARTED_THIS_FRAME { */
+/** epu_transition(0, NIGHT.as_ptr(), 300, easing::EASE_IN_OUT); */
+/** } */
+/** epu_set(if IS_NIGHT { NIGHT.as_ptr() } else { DAY.as_ptr() }); */
+/** draw_epu(); */
+/** } */
+/** ``` */
+NCZX_IMPORT void epu_transition(uint32_t slot, const uint64_t* config_ptr, uint32_t duration_ticks, uint32_t easing);
+
+/** Create a lighting probe at a world position (init-only). */
+/**  */
+/** The probe captures the environment selected with `environment_index(...)` */
+/** when it is created (0 = global sky) plus every light that reaches its */
+/** sphere. Up to 16 probes; they use environment slots 240..255. */
+/**  */
+/** # Arguments */
+/** * `x`, `y`, `z` — Capture position in world units */
+/** * `radius` — Capture radius in world units */
+/**  */
+/** # Returns */
+/** Probe handle (>0), or 0 on error. */
+NCZX_IMPORT uint32_t probe_create(float x, float y, float z, float radius);
+
+/** Use a probe's captured lighting for ambient and reflections of subsequent draws. */
+/**  */
+/** The probe is recaptured every 8 ticks from its source environment and the */
+/** lights set at the time of the call. Call `environment_index(...)` to return */
+/** to a regular environment. */
+/**  */
+/** # Example */
+/** ```rust,ignore */
+/** fn render() { */
+/** epu_set(SKY.as_ptr()); */
+/** environment_index(TUNNEL_ENV); */
+/** epu_set(TUNNEL.as_ptr()); */
+/**  */
+/** for car in cars() { */
+/** if car.in_tunnel { material_probe(TUNNEL_PROBE); } else { environment_index(0); } */
+/** draw_mesh(CAR_MESH); */
+/** } */
+/** } */
+/** ``` */
+NCZX_IMPORT void material_probe(uint32_t handle);
+
+// =============================================================================
+// Hitboxes
+// =============================================================================
+
+/** Register a move's frame data (init-only). */
+/**  */
+/** Each box is active on a range of animation frames. Box coordinates are in */
+/** actor space: +X is forward at yaw 0, +Y is up. */
+/**  */
+/** # Arguments */
+/** * `boxes_ptr` — Pointer to `count` 52-byte boxes: first_frame (u32), last_frame (u32, */
+/** inclusive), kind (u32, `hitbox_kind::*`), priority (u32), group (u32), */
+/** flags (u32, `hitbox_flag::*`), user_data (u32), center (3 × f32), half-extents (3 × f32) */
+/** * `count` — Number of boxes (1..=32) */
+/**  */
+/** # Returns */
+/** Move handle (>0) on success, 0 on invalid input. */
+NCZX_IMPORT uint32_t hitbox_move(const uint8_t* boxes_ptr, uint32_t count);
+
+/** Place an actor for this tick. */
+/**  */
+/** Switching moves, or going back to an earlier frame of the same move, starts a */
+/** new attack that may hit everyone again. Actor state is rolled back, so call from `update()`. */
+/**  */
+/** # Arguments */
+/** * `actor` — Actor slot (0..32) */
+/** * `move_id` — Move handle from `hitbox_move()`, or 0 to remove the actor */
+/** * `frame` — Current animation frame of the move */
+/** * `x`, `y`, `z` — Actor position */
+/** * `yaw` — Facing around +Y in radians (0 faces +X) */
+/** * `team` — Actors on the same non-zero team never interact */
+NCZX_IMPORT void hitbox_actor(uint32_t actor, uint32_t move_id, uint32_t frame, float x, float y, float z, float yaw, uint32_t team);
+
+/** Overlap every actor's active boxes and queue hit and clash events. */
+/**  */
+/** Clashing hitboxes cancel the lower priority attack (equal priorities trade). */
+/** Each hit group hits a defender at most once per move. */
+NCZX_IMPORT void hitbox_resolve(void);
+
+/** Read the next event from the last `hitbox_resolve()`. */
+/**  */
+/** # Arguments */
+/** * `out_ptr` — Pointer to a 36-byte event: kind (u32, `hitbox_event::*`), attacker (u32), */
+/** defender (u32), attacker_box (u32), defender_box (u32), user_data (u32), position (3 × f32) */
+/**  */
+/** # Returns */
+/** 1 if an event was written, 0 when there are no more events. */
+NCZX_IMPORT uint32_t hitbox_next_event(uint8_t* out_ptr);
+
+/** Draw every active box as a wireframe (F4 debug inspector only). */
+/**  */
+/** Hurtboxes are blue, hitboxes red. */
+NCZX_IMPORT void hitbox_debug_draw(void);
+
 // =============================================================================
 // Input Functions
 // =============================================================================
@@ -602,6 +1147,18 @@ NCZX_IMPORT float trigger_left(uint32_t player);
 /** Get right trigger value (0.0 to 1.0). */
 NCZX_IMPORT float trigger_right(uint32_t player);
 
+/** Get the step between stick axis values (1/127). */
+/**  */
+/** Sticks are quantized to whole steps before they reach the game, and */
+/** every peer sees the same steps. Axes read 0.0 exactly when centered and */
+/** ±1.0 exactly at full tilt. */
+NCZX_IMPORT float stick_step(void);
+
+/** Get the step between trigger values (1/255). */
+/**  */
+/** Triggers are quantized to whole steps, like sticks. */
+NCZX_IMPORT float trigger_step(void);
+
 // =============================================================================
 // Lighting Functions (Mode 2/3)
 // =============================================================================
@@ -651,6 +1208,71 @@ NCZX_IMPORT void light_set_point(uint32_t index, float x, float y, float z);
 /** Only affects point lights (ignored for directional). */
 NCZX_IMPORT void light_range(uint32_t index, float range);
 
+/** Configure a directional light from an environment's sun. */
+/**  */
+/** # Arguments */
+/** * `index` — Light index (0-3) */
+/** * `slot` — Environment slot (env_id) to read */
+/**  */
+/** Copies the direction, color and intensity of the brightest sky */
+/** LOBE_RADIANCE or DECAL layer into the light and enables it. Call after */
+/** `epu_set()` so the light tracks the sky as it changes. */
+/**  */
+/** # Returns */
+/** 1 if a sun was found, 0 otherwise (the light is left unchanged). */
+NCZX_IMPORT uint32_t light_from_env(uint32_t index, uint32_t slot);
+
+/** Add a point light to the scene for this frame. */
+/**  */
+/** # Arguments */
+/** * `x`, `y`, `z` — World-space position */
+/** * `color` — Light color (0xRRGGBBAA, alpha ignored) */
+/** * `intensity` — Brightness multiplier (0.0-8.0) */
+/** * `range` — Distance at which the light reaches zero */
+/**  */
+/** Scene lights add to the 4 lights above and light every draw in modes 0, 2 */
+/** and 3. Up to 256 per frame; each surface only pays for the lights whose */
+/** range reaches it. Call every frame — the list is cleared after rendering. */
+NCZX_IMPORT void light_add_point(float x, float y, float z, uint32_t color, float intensity, float range);
+
+/** Add a spot light to the scene for this frame. */
+/**  */
+/** # Arguments */
+/** * `x`, `y`, `z` — World-space position */
+/** * `dir_x`, `dir_y`, `dir_z` — Direction the spot points (normalized) */
+/** * `color` — Light color (0xRRGGBBAA, alpha ignored) */
+/** * `intensity` — Brightness multiplier (0.0-8.0) */
+/** * `range` — Distance at which the light reaches zero */
+/** * `inner_angle` — Cone half-angle in radians with full brightness */
+/** * `outer_angle` — Cone half-angle in radians where the light reaches zero */
+/**  */
+/** Otherwise behaves like `light_add_point()`. */
+NCZX_IMPORT void light_add_spot(float x, float y, float z, float dir_x, float dir_y, float dir_z, uint32_t color, float intensity, float range, float inner_angle, float outer_angle);
+
+/** Bake the current lights into static meshes (init-only). */
+/**  */
+/** # Arguments */
+/** * `mesh_handles_ptr` — Pointer to an array of u32 mesh handles (must have normals) */
+/** * `mesh_count` — Number of mesh handles */
+/** * `light_set` — Bitmask of lights to bake (bit N = light N, 0-3) */
+/**  */
+/** Irradiance is computed per vertex with the current transform applied. */
+/** Draw the meshes with `material_lightmap()` and disable the baked lights */
+/** so they cost nothing at runtime. */
+/**  */
+/** # Returns */
+/** Lightmap handle (>0) on success, 0 on failure. */
+NCZX_IMPORT uint32_t lightmap_bake(const uint32_t* mesh_handles_ptr, uint32_t mesh_count, uint32_t light_set);
+
+/** Apply a baked lightmap to subsequent mesh draws. */
+/**  */
+/** # Arguments */
+/** * `lightmap` — Lightmap handle from `lightmap_bake()`, or 0 to disable */
+/**  */
+/** Only meshes baked into the lightmap receive its lighting. Baked light is */
+/** added in modes 0, 2 and 3 (matcap ignores it). */
+NCZX_IMPORT void material_lightmap(uint32_t lightmap);
+
 // =============================================================================
 // Material Functions (Mode 2/3)
 // =============================================================================
@@ -696,6 +1318,22 @@ NCZX_IMPORT void material_emissive(float value);
 /** * `power` — Falloff sharpness (0.0-32.0, higher = tighter) */
 NCZX_IMPORT void material_rim(float intensity, float power);
 
+/** Set the number of lit diffuse bands for toon shading (Mode 4 only). */
+/**  */
+/** # Arguments */
+/** * `count` — Bands above the shadow side (1-16, 1 = two-tone). Default: 3 */
+NCZX_IMPORT void material_bands(uint32_t count);
+
+/** Set the inked outline drawn around meshes (Mode 4 only). */
+/**  */
+/** Meshes with normals are drawn again pushed out along their normals with */
+/** front faces culled, leaving a solid shell around the silhouette. */
+/**  */
+/** # Arguments */
+/** * `width` — Outline thickness in world units (0.0 = no outline, default) */
+/** * `color` — Outline color (0xRRGGBBAA, alpha uses dither transparency) */
+NCZX_IMPORT void material_outline(float width, uint32_t color);
+
 /** Enable/disable uniform color override. */
 /**  */
 /** When enabled, uses the last set_color() value for all subsequent draws, */
@@ -809,8 +1447,77 @@ NCZX_IMPORT uint32_t load_mesh_packed(const uint8_t* data_ptr, uint32_t vertex_c
 NCZX_IMPORT uint32_t load_mesh_indexed_packed(const uint8_t* data_ptr, uint32_t vertex_count, const uint16_t* index_ptr, uint32_t index_count, uint32_t format);
 
 /** Draw a retained mesh with current transform and render state. */
+/**  */
+/** Meshes whose bounds are entirely outside the camera's view are skipped */
+/** (see `render_stats()`); skinned and morphed meshes are always drawn. */
 NCZX_IMPORT void draw_mesh(uint32_t handle);
 
+/** Set the weight of one of a mesh's morph targets. */
+/**  */
+/** Targets are numbered in the order they were exported (up to 8 per mesh). */
+/** Weights stay set until changed and apply to every later draw of the mesh. */
+/**  */
+/** # Arguments */
+/** * `handle` — Mesh handle from rom_mesh */
+/** * `target_index` — Morph target index */
+/** * `weight` — How much of the target to apply (0 = none, 1 = full) */
+NCZX_IMPORT void mesh_morph_weight(uint32_t handle, uint32_t target_index, float weight);
+
+/** Merge placed copies of meshes into one mesh (init-only). */
+/**  */
+/** Bakes static props into a few large meshes so they draw in a few calls. */
+/** Meshes with different vertex formats, or more than 65536 vertices in */
+/** total, become several parts that `draw_mesh()` draws together. Source */
+/** meshes must be loaded earlier in init(), must not be skinned, and stay */
+/** usable on their own. */
+/**  */
+/** # Arguments */
+/** * `handles_ptr` — Pointer to `count` mesh handles (a handle may repeat) */
+/** * `transforms_ptr` — Pointer to `count` 4x4 column-major matrices (16 floats each) */
+/** * `count` — Number of meshes (1-4096) */
+/**  */
+/** Returns a mesh handle (>0) on success, 0 on failure. */
+NCZX_IMPORT uint32_t mesh_merge(const uint32_t* handles_ptr, const float* transforms_ptr, uint32_t count);
+
+/** Register a destructible prop (init-only). */
+/**  */
+/** Each connected part of `pieces_mesh` becomes one debris piece (up to 64). */
+/** Both meshes must share a local space and be loaded earlier in init(). */
+/** Debris settles on the lowest point of `pieces_mesh`. */
+/**  */
+/** # Arguments */
+/** * `mesh` — Mesh drawn while the prop is intact */
+/** * `pieces_mesh` — Pre-fractured version of the prop */
+/** * `health` — Starting health (> 0) */
+/**  */
+/** # Returns */
+/** Destructible handle (>0) on success, 0 on failure. */
+NCZX_IMPORT uint32_t destructible_register(uint32_t mesh, uint32_t pieces_mesh, float health);
+
+/** Damage a destructible prop (call from update(), rolled back). */
+/**  */
+/** # Arguments */
+/** * `handle` — Destructible handle from `destructible_register()` */
+/** * `amount` — Health to remove */
+/** * `impulse_x`, `impulse_y`, `impulse_z` — Velocity added to the debris if */
+/** this hit breaks the prop */
+/**  */
+/** # Returns */
+/** 1 if this hit broke the prop, 0 otherwise. */
+NCZX_IMPORT uint32_t destructible_damage(uint32_t handle, float amount, float impulse_x, float impulse_y, float impulse_z);
+
+/** Draw a destructible prop with the current transform. */
+/**  */
+/** Draws the intact mesh, or the flying debris once broken. */
+/** Debris disappears 5 seconds after the break. */
+NCZX_IMPORT void destructible_draw(uint32_t handle);
+
+/** Get the remaining health of a destructible prop (0.0 once broken). */
+NCZX_IMPORT float destructible_health(uint32_t handle);
+
+/** Restore a destructible prop to full health. */
+NCZX_IMPORT void destructible_reset(uint32_t handle);
+
 // =============================================================================
 // Unified Music API (PCM + Tracker)
 // =============================================================================
@@ -879,6 +1586,62 @@ NCZX_IMPORT uint32_t music_is_playing(void);
 /** 0 = none, 1 = PCM, 2 = tracker */
 NCZX_IMPORT uint32_t music_type(void);
 
+/** Play layered music made of up to 4 stems that stay in lockstep. */
+/**  */
+/** Stems share one playhead, so fading layers in and out never puts them */
+/** out of sync. Layer 0 starts at full volume, the others start silent. */
+/** All stems should be the same length as layer 0. */
+/**  */
+/** # Arguments */
+/** * `sounds_ptr` — Pointer to an array of sound handles (from load_sound) */
+/** * `count` — Number of stems (1-4) */
+/** * `volume` — Overall music volume, 0.0 to 1.0 */
+/** * `looping` — 1 = loop, 0 = play once */
+NCZX_IMPORT void music_play_layers(const uint32_t* sounds_ptr, uint32_t count, float volume, uint32_t looping);
+
+/** Fade a stem of layered music to a new volume. */
+/**  */
+/** Use to ramp intensity with game state (wave number, race position, etc). */
+/** A new call replaces the layer's current fade. */
+/**  */
+/** # Arguments */
+/** * `layer` — Stem index (0-3) */
+/** * `volume` — Target volume, 0.0 to 1.0 */
+/** * `fade_ticks` — Length of the fade in ticks (0 = instant) */
+NCZX_IMPORT void music_layer_volume(uint32_t layer, float volume, uint32_t fade_ticks);
+
+/** Configure the music beat clock. */
+/**  */
+/** Tracker music has a beat every `rows_per_beat` rows and follows tempo */
+/** changes in the song. PCM music has no tempo, so it has no beats until */
+/** `bpm` is set. Settings persist across music changes. */
+/**  */
+/** # Arguments */
+/** * `bpm` — Tempo of PCM music (0 = no beats for PCM music) */
+/** * `beats_per_bar` — Beats per bar (0 = default 4) */
+/** * `rows_per_beat` — Tracker rows per beat (0 = default 4) */
+NCZX_IMPORT void music_beat_config(float bpm, uint32_t beats_per_bar, uint32_t rows_per_beat);
+
+/** Get the beat index since the music started. */
+/**  */
+/** Each new beat is also queued as an `event_kind::MUSIC_BEAT` event. */
+/**  */
+/** # Returns */
+/** Beat index (0 = first beat), or 0 if no music with a beat clock is playing */
+NCZX_IMPORT uint32_t music_beat(void);
+
+/** Get the bar index since the music started. */
+/**  */
+/** # Returns */
+/** music_beat() / beats per bar */
+NCZX_IMPORT uint32_t music_bar(void);
+
+/** Get the progress through the current beat, for pulsing visuals. */
+/**  */
+/** # Returns */
+/** 0.0 on the beat, rising toward 1.0 just before the next beat */
+NCZX_IMPORT float music_beat_phase(void);
+
 /** Jump to a specific position (tracker only, no-op for PCM). */
 /**  */
 /** Use for dynamic music systems (e.g., jump to outro pattern). */
@@ -944,6 +1707,129 @@ NCZX_IMPORT uint32_t music_info(uint32_t handle);
 /** Actual length written (0 if PCM or invalid handle). */
 NCZX_IMPORT uint32_t music_name(uint32_t handle, uint8_t* out_ptr, uint32_t max_len);
 
+/** Play a stream (from rom_stream) from the beginning. */
+/**  */
+/** Streams play on their own voice, independent of `music_play()`. */
+/** Replaces any stream already playing. */
+/**  */
+/** # Arguments */
+/** * `handle` — Stream handle from rom_stream */
+/** * `volume` — 0.0 to 1.0 */
+/** * `looping` — 1 = loop, 0 = play once */
+NCZX_IMPORT void stream_play(uint32_t handle, float volume, uint32_t looping);
+
+/** Stop the stream. */
+NCZX_IMPORT void stream_stop(void);
+
+/** Pause or resume the stream. */
+/**  */
+/** # Arguments */
+/** * `paused` — 1 = pause, 0 = resume */
+NCZX_IMPORT void stream_pause(uint32_t paused);
+
+/** Move the stream playhead. */
+/**  */
+/** # Arguments */
+/** * `seconds` — Position from the start of the stream */
+NCZX_IMPORT void stream_seek(float seconds);
+
+/** Get the stream playhead. */
+/**  */
+/** # Returns */
+/** Position in seconds from the start of the stream (0.0 if no stream). */
+NCZX_IMPORT float stream_position(void);
+
+// =============================================================================
+// Navigation (Pathfinding)
+// =============================================================================
+
+/** Bake a navigation mesh from a loaded mesh (init-only). */
+/**  */
+/** Call after loading the mesh. Triangles steeper than 45° are not walkable, */
+/** and gaps narrower than the agent's diameter are treated as walls. */
+/**  */
+/** # Arguments */
+/** * `mesh_handle` — Mesh handle from `load_mesh*()`, `rom_mesh()` or a mesh generator */
+/** * `agent_radius` — Radius of the agents that will walk the mesh */
+/**  */
+/** # Returns */
+/** Navmesh handle (>0) on success, 0 on failure. */
+NCZX_IMPORT uint32_t navmesh_bake(uint32_t mesh_handle, float agent_radius);
+
+/** Find an obstacle-aware path across a navmesh. */
+/**  */
+/** Start and end are snapped onto the navmesh. The path includes both */
+/** snapped endpoints and is truncated to `max_points`. Deterministic, so it */
+/** is safe to call from `update()`. */
+/**  */
+/** # Arguments */
+/** * `navmesh` — Navmesh handle from `navmesh_bake()` */
+/** * `start_x`, `start_y`, `start_z` — Start position */
+/** * `end_x`, `end_y`, `end_z` — Goal position */
+/** * `out_points` — Output buffer of `max_points` × (x, y, z) f32 */
+/** * `max_points` — Capacity of the output buffer in points */
+/**  */
+/** # Returns */
+/** Number of points written, or 0 if no path exists. */
+NCZX_IMPORT uint32_t nav_path(uint32_t navmesh, float start_x, float start_y, float start_z, float end_x, float end_y, float end_z, float* out_points, uint32_t max_points);
+
+/** Evaluate a spline path at a fraction of its length. */
+/**  */
+/** `t` follows arc length, so advancing it by `speed * dt / path_length()` */
+/** each tick moves at constant speed. Deterministic, so it is safe to call */
+/** from `update()`. */
+/**  */
+/** # Arguments */
+/** * `handle` — Path handle from `rom_path()` */
+/** * `t` — 0.0 (start) to 1.0 (end); clamped on open paths, wraps on closed ones */
+/** * `out_pos` — Output (x, y, z) f32 position, or null to skip */
+/** * `out_tangent` — Output (x, y, z) f32 unit direction of travel, or null to skip */
+/**  */
+/** # Returns */
+/** 1 on success, 0 if the handle or a pointer is invalid. */
+NCZX_IMPORT uint32_t path_eval(uint32_t handle, float t, float* out_pos, float* out_tangent);
+
+/** Get the length of a spline path in world units. */
+/**  */
+/** # Returns */
+/** Arc length, or 0.0 if the handle is invalid. */
+NCZX_IMPORT float path_length(uint32_t handle);
+
+/** Create the pathfinding grid, replacing any existing one. */
+/**  */
+/** Every cell starts with cost 1. The grid is rolled back with the game, so it */
+/** can be created and edited from `update()`. */
+/**  */
+/** # Arguments */
+/** * `width`, `height` — Size in cells (width × height at most 16384) */
+/**  */
+/** # Returns */
+/** 1 on success, 0 if the size is invalid. */
+NCZX_IMPORT uint32_t grid_create(uint32_t width, uint32_t height);
+
+/** Set the cost of entering a grid cell. */
+/**  */
+/** # Arguments */
+/** * `x`, `y` — Cell coordinates */
+/** * `cost` — 0 = blocked, 1-255 = relative cost (1 = open ground) */
+NCZX_IMPORT void grid_set_cost(uint32_t x, uint32_t y, uint32_t cost);
+
+/** Find the cheapest path between two grid cells. */
+/**  */
+/** Paths move in 8 directions without cutting the corners of blocked cells. */
+/** The path includes the start and goal cells and is truncated to `max_cells`. */
+/** Deterministic, so it is safe to call from `update()`. */
+/**  */
+/** # Arguments */
+/** * `start_x`, `start_y` — Start cell (may be blocked) */
+/** * `goal_x`, `goal_y` — Goal cell */
+/** * `out_cells` — Output buffer of `max_cells` × (x, y) u32 */
+/** * `max_cells` — Capacity of the output buffer in cells */
+/**  */
+/** # Returns */
+/** Number of cells written, or 0 if no path exists. */
+NCZX_IMPORT uint32_t grid_path(uint32_t start_x, uint32_t start_y, uint32_t goal_x, uint32_t goal_y, uint32_t* out_cells, uint32_t max_cells);
+
 // =============================================================================
 // Render Pass Functions (Execution Barriers & Depth/Stencil Control)
 // =============================================================================
@@ -1017,6 +1903,61 @@ NCZX_IMPORT void begin_pass_stencil_test(uint32_t ref_value, uint32_t clear_dept
 /** * `stencil_depth_fail_op` — Operation when depth test fails */
 NCZX_IMPORT void begin_pass_full(uint32_t depth_compare, uint32_t depth_write, uint32_t clear_depth, uint32_t stencil_compare, uint32_t stencil_ref, uint32_t stencil_pass_op, uint32_t stencil_fail_op, uint32_t stencil_depth_fail_op);
 
+// =============================================================================
+// 2D Physics
+// =============================================================================
+
+/** Create a host-simulated 2D physics body. */
+/**  */
+/** Bodies move by their velocity (plus gravity) on each `physics_step()` and are pushed */
+/** out of each other. The world is rolled back, so create bodies from `update()`. */
+/**  */
+/** # Arguments */
+/** * `shape` — `body_shape::AABB` or `body_shape::CIRCLE` */
+/** * `x`, `y` — Center position */
+/** * `w`, `h` — Box width and height; for circles `w` is the radius and `h` is ignored */
+/** * `flags` — `body_flag::*` (static bodies never move, sensors only report contacts) */
+/**  */
+/** # Returns */
+/** Body handle (>0) on success, 0 if the world (128 bodies) is full or the shape is invalid. */
+NCZX_IMPORT uint32_t body_create(uint32_t shape, float x, float y, float w, float h, uint32_t flags);
+
+/** Remove a physics body. */
+NCZX_IMPORT void body_destroy(uint32_t handle);
+
+/** Set a body's velocity in units per second. */
+/**  */
+/** Static bodies keep the velocity but never move. */
+NCZX_IMPORT void body_velocity(uint32_t handle, float vx, float vy);
+
+/** Move a body without changing its velocity (spawns, teleports, moving platforms). */
+NCZX_IMPORT void body_set_position(uint32_t handle, float x, float y);
+
+/** Get a body's center X position. */
+/**  */
+/** # Returns */
+/** X position, or 0.0 if the handle is invalid. */
+NCZX_IMPORT float body_x(uint32_t handle);
+
+/** Get a body's center Y position. */
+/**  */
+/** # Returns */
+/** Y position, or 0.0 if the handle is invalid. */
+NCZX_IMPORT float body_y(uint32_t handle);
+
+/** Get the number of live physics bodies. */
+NCZX_IMPORT uint32_t body_count(void);
+
+/** Set the acceleration applied to moving bodies in units per second² (default 0, 0). */
+NCZX_IMPORT void physics_gravity(float x, float y);
+
+/** Move every body by one tick and resolve overlaps. */
+/**  */
+/** Call once per `update()`; the step length follows the tick rate. Each touching pair is */
+/** passed to your `on_contact(a, b)` export (handles, `a < b`, in handle order) before this */
+/** returns, and queued as an `event_kind::BODY_CONTACT` event. */
+NCZX_IMPORT void physics_step(void);
+
 // =============================================================================
 // Procedural Mesh Generation (init-only)
 // =============================================================================
@@ -1106,6 +2047,104 @@ NCZX_IMPORT uint32_t cube_tangent(float size_x, float size_y, float size_z);
 /** Tangent follows the major circle direction. */
 NCZX_IMPORT uint32_t torus_tangent(float major_radius, float minor_radius, uint32_t major_segments, uint32_t minor_segments);
 
+/** Combine two meshes into one covering the space inside either. **Init-only.** */
+/**  */
+/** Both meshes must be created earlier in `init()` and should be closed. */
+/** The result is a new flat-shaded mesh without UVs; the inputs are unchanged. */
+/**  */
+/** Returns a mesh handle (>0), or 0 on failure. */
+NCZX_IMPORT uint32_t mesh_union(uint32_t a, uint32_t b);
+
+/** Carve mesh `b` out of mesh `a` (tunnels, damage holes). **Init-only.** */
+/**  */
+/** Both meshes must be created earlier in `init()` and should be closed. */
+/** The result is a new flat-shaded mesh without UVs; the inputs are unchanged. */
+/**  */
+/** Returns a mesh handle (>0), or 0 on failure. */
+NCZX_IMPORT uint32_t mesh_subtract(uint32_t a, uint32_t b);
+
+/** Keep only the space inside both meshes. **Init-only.** */
+/**  */
+/** Both meshes must be created earlier in `init()` and should be closed. */
+/** The result is a new flat-shaded mesh without UVs; the inputs are unchanged. */
+/**  */
+/** Returns a mesh handle (>0), or 0 on failure. */
+NCZX_IMPORT uint32_t mesh_intersect(uint32_t a, uint32_t b);
+
+/** Recompute a mesh's normals from its triangles. **Init-only.** */
+/**  */
+/** Edges where faces meet at more than `smooth_angle_deg` stay sharp */
+/** (0 = flat shading, 180 = smooth everywhere). Use after displacing vertices. */
+/** The mesh must be created earlier in `init()`; it gains normals if it had */
+/** none, and its tangents (if any) are regenerated to match. */
+/**  */
+/** Returns 1 on success, 0 on failure. */
+NCZX_IMPORT uint32_t mesh_recompute_normals(uint32_t handle, float smooth_angle_deg);
+
+/** Generate tangents from a mesh's UVs and normals for normal mapping. **Init-only.** */
+/**  */
+/** The mesh must be created earlier in `init()` and have UVs and normals. */
+/** Call after `mesh_recompute_normals()` if the mesh was displaced. */
+/**  */
+/** Returns 1 on success, 0 on failure. */
+NCZX_IMPORT uint32_t mesh_generate_tangents(uint32_t handle);
+
+// =============================================================================
+// Projectiles
+// =============================================================================
+
+/** Spawn a host-simulated projectile. */
+/**  */
+/** Projectiles fly in a straight line and are collided against the targets */
+/** passed to `projectile_step()`. The pool is rolled back, so spawn from `update()`. */
+/**  */
+/** # Arguments */
+/** * `desc_ptr` — Pointer to a 48-byte description: position (3 × f32), */
+/** velocity (3 × f32, units/s), radius (f32), lifetime in ticks (u32, 0 = until it hits), */
+/** flags (u32, `projectile_flag::*`), max_hits (u32), target_mask (u32), user_data (u32) */
+/**  */
+/** # Returns */
+/** Projectile handle (>0) on success, 0 if the pool (256) is full. */
+NCZX_IMPORT uint32_t projectile_spawn(const uint8_t* desc_ptr);
+
+/** Remove a projectile before it hits anything or expires. */
+NCZX_IMPORT void projectile_despawn(uint32_t handle);
+
+/** Move every projectile by one tick and collide it with targets. */
+/**  */
+/** Call once per `update()`, then read hits with `projectile_next_hit()`. */
+/** Each projectile hits at most one target per step, and never the same target twice in a row. */
+/**  */
+/** # Arguments */
+/** * `targets_ptr` — Pointer to `target_count` 20-byte targets: position (3 × f32), */
+/** radius (f32), layers (u32, matched against each projectile's target_mask) */
+/** * `target_count` — Number of targets (up to 4096) */
+NCZX_IMPORT void projectile_step(const uint8_t* targets_ptr, uint32_t target_count);
+
+/** Read the next hit from the last `projectile_step()`. */
+/**  */
+/** # Arguments */
+/** * `out_ptr` — Pointer to a 24-byte hit: projectile handle (u32), target index (u32), */
+/** user_data (u32), impact position (3 × f32) */
+/**  */
+/** # Returns */
+/** 1 if a hit was written, 0 when there are no more hits. */
+NCZX_IMPORT uint32_t projectile_next_hit(uint8_t* out_ptr);
+
+/** List live projectiles for drawing. */
+/**  */
+/** # Arguments */
+/** * `out_ptr` — Pointer to `max_count` 20-byte entries: handle (u32), user_data (u32), */
+/** position (3 × f32) */
+/** * `max_count` — Capacity of the output buffer in entries */
+/**  */
+/** # Returns */
+/** Number of entries written. */
+NCZX_IMPORT uint32_t projectile_list(uint8_t* out_ptr, uint32_t max_count);
+
+/** Get the number of live projectiles. */
+NCZX_IMPORT uint32_t projectile_count(void);
+
 // =============================================================================
 // Render State Functions
 // =============================================================================
@@ -1174,6 +2213,114 @@ NCZX_IMPORT void dither_offset(uint32_t x, uint32_t y);
 /** Default: 0 (resets each frame) */
 NCZX_IMPORT void z_index(uint32_t n);
 
+/** Set how 2D draws combine with what is already on screen. */
+/**  */
+/** # Arguments */
+/** * `mode` — `blend_mode::ALPHA` (default), `ADDITIVE`, `MULTIPLY`, `SCREEN` or `SUBTRACT` */
+/**  */
+/** Affects screen-space sprites, rects, lines and text until changed. */
+/** Blended draws composite over everything else at their z-index (3D and the */
+/** environment included) and never write depth. Alpha still dithers in every mode. */
+/** Default: alpha (resets each frame) */
+NCZX_IMPORT void blend_mode_2d(uint32_t mode);
+
+/** Set how 3D draws combine with what is already on screen. */
+/**  */
+/** # Arguments */
+/** * `mode` — `blend_mode::ALPHA` (default), `ADDITIVE`, `MULTIPLY`, `SCREEN` or `SUBTRACT` */
+/**  */
+/** Affects meshes and `draw_triangles()` until changed. Blended draws render after */
+/** the opaque scene and the environment, sorted back to front within each viewport, */
+/** and never write depth. Alpha still dithers in every mode. */
+/** Default: alpha (resets each frame) */
+NCZX_IMPORT void blend_mode_3d(uint32_t mode);
+
+/** Offset the sort distance of blended 3D draws. */
+/**  */
+/** # Arguments */
+/** * `bias` — World units added to the view distance (positive = further back, */
+/** negative = on top) */
+/**  */
+/** Use when automatic sorting picks the wrong order, e.g. a hazard circle under a */
+/** larger translucent dome. */
+/** Default: 0.0 (resets each frame) */
+NCZX_IMPORT void sort_bias(float bias);
+
+/** Get statistics for the last rendered frame. */
+/**  */
+/** Writes draw_calls, triangles, triangles_culled, texture_binds (4 × u32) */
+/** to `out_ptr`. `triangles_culled` counts `draw_mesh()` triangles skipped */
+/** by frustum culling. Peers render different frames, so use these for */
+/** display or profiling only, never for game logic. */
+/**  */
+/** Returns 1 on success, 0 if `out_ptr` is invalid. */
+NCZX_IMPORT uint32_t render_stats(uint32_t* out_ptr);
+
+/** Enable the screen-space ambient occlusion post pass. */
+/**  */
+/** Darkens creases and contact points of 3D geometry in every viewport that */
+/** drew meshes. Screen-space 2D and the sky are not affected. Persists across */
+/** frames until changed. */
+/**  */
+/** # Arguments */
+/** * `radius` — Sample radius in world units (how far creases reach) */
+/** * `intensity` — Occlusion strength (0 = none, 1 = full) */
+/** * `enabled` — Non-zero to enable, 0 to disable */
+NCZX_IMPORT void post_ssao(float radius, float intensity, uint32_t enabled);
+
+// =============================================================================
+// Teams & Scoreboard
+// =============================================================================
+
+/** Assign a player to a team. */
+/**  */
+/** Teams are rolled back with the game, so this is safe to call from `update()`. */
+/**  */
+/** # Arguments */
+/** * `player` — Player index (0-7) */
+/** * `team` — Team number (1-8), or 0 for no team */
+NCZX_IMPORT void team_set(uint32_t player, uint32_t team);
+
+/** Get a player's team. */
+/**  */
+/** # Returns */
+/** Team number (1-8), or 0 if the player has no team. */
+NCZX_IMPORT uint32_t team_get(uint32_t player);
+
+/** Get a team's score (the sum of its players' scores). */
+NCZX_IMPORT int32_t team_score(uint32_t team);
+
+/** Add to a player's score (negative values subtract). */
+/**  */
+/** Ignored once the match has ended. */
+NCZX_IMPORT void score_add(uint32_t player, int32_t delta);
+
+/** Set a player's score. */
+/**  */
+/** Ignored once the match has ended. */
+NCZX_IMPORT void score_set(uint32_t player, int32_t score);
+
+/** Get a player's score. */
+NCZX_IMPORT int32_t score_get(uint32_t player);
+
+/** End the match, freezing all scores. */
+NCZX_IMPORT void match_end(void);
+
+/** Check if the match has ended. */
+/**  */
+/** # Returns */
+/** 1 if `match_end()` has been called since the last reset, 0 otherwise. */
+NCZX_IMPORT uint32_t match_is_over(void);
+
+/** Reset all scores and start a new match. Team assignments are kept. */
+NCZX_IMPORT void match_reset(void);
+
+/** Draw the built-in scoreboard overlay. */
+/**  */
+/** Lists players ranked by team score, then player score, centered in the */
+/** current viewport. Call from `render()`; color and z-index are preserved. */
+NCZX_IMPORT void scoreboard_draw(void);
+
 // =============================================================================
 // GPU Skinning
 // =============================================================================
@@ -1238,6 +2385,70 @@ NCZX_IMPORT void set_bones(const float* matrices_ptr, uint32_t count);
 /** ``` */
 NCZX_IMPORT void set_bones_4x4(const float* matrices_ptr, uint32_t count);
 
+// =============================================================================
+// World Streaming
+// =============================================================================
+
+/** Set the slot pool streamed chunks are copied into (init-only). */
+/**  */
+/** The pool is a `slot_size * slot_count` byte buffer in game memory that the */
+/** game must not use for anything else. Call before `chunk_register()`. */
+/**  */
+/** # Arguments */
+/** * `ptr` — Start of the pool */
+/** * `slot_size` — Bytes per slot; every registered chunk must fit in one */
+/** * `slot_count` — Number of slots (1..=64) */
+/**  */
+/** # Returns */
+/** 1 on success, 0 on invalid input. */
+NCZX_IMPORT uint32_t chunk_pool(uint8_t* ptr, uint32_t slot_size, uint32_t slot_count);
+
+/** Set the chunk grid and streaming distances (init-only). */
+/**  */
+/** Cell (x, z) spans `x * cell_size` to `(x + 1) * cell_size` on the X and Z axes. */
+/** Chunks load when their center comes within `load_radius` of the focus and unload */
+/** once it is further than `unload_radius`, so crossing a border back and forth */
+/** doesn't reload anything. */
+/**  */
+/** # Arguments */
+/** * `cell_size` — World units per cell (default 64) */
+/** * `load_radius` — Load distance (default 96) */
+/** * `unload_radius` — Unload distance, at least `load_radius` (default 128) */
+/**  */
+/** # Returns */
+/** 1 on success, 0 on invalid input. */
+NCZX_IMPORT uint32_t chunk_grid(float cell_size, float load_radius, float unload_radius);
+
+/** Register the chunk at grid cell (x, z) (init-only). */
+/**  */
+/** # Arguments */
+/** * `x`, `z` — Grid cell */
+/** * `id_ptr` — Pointer to the ROM data asset ID holding the chunk's bytes */
+/** * `id_len` — Length of the asset ID */
+/**  */
+/** # Returns */
+/** Chunk handle (>0) on success, 0 if the asset is missing or larger than a slot. */
+NCZX_IMPORT uint32_t chunk_register(int32_t x, int32_t z, const uint8_t* id_ptr, uint32_t id_len);
+
+/** Stream chunks around a focus point (usually the camera or the local player). */
+/**  */
+/** Loads nearby chunks into free slots, nearest first, and frees slots of chunks */
+/** that moved out of range. Residency is rolled back, so call from `update()`. */
+/** Each load and unload is queued for `event_poll()` (`event_kind::CHUNK_*`). */
+/**  */
+/** # Returns */
+/** Number of loaded chunks. */
+NCZX_IMPORT uint32_t chunk_update(float x, float z);
+
+/** Get a loaded chunk's data. */
+/**  */
+/** # Returns */
+/** Pointer to the chunk's slot in the pool, or 0 if the cell isn't loaded. */
+NCZX_IMPORT uint32_t chunk_data(int32_t x, int32_t z);
+
+/** Get the number of loaded chunks. */
+NCZX_IMPORT uint32_t chunk_loaded_count(void);
+
 // =============================================================================
 // System Functions
 // =============================================================================
@@ -1275,6 +2486,24 @@ NCZX_IMPORT void log(const uint8_t* ptr, uint32_t len);
 /** Exits the game and returns to the library. */
 NCZX_IMPORT void quit(void);
 
+/** Writes the 32-byte content hash of the running ROM. */
+/**  */
+/** The hash is SHA-256 of the ROM file, so it identifies an exact build */
+/** (useful for showing a build identifier in menus). Netplay peers must */
+/** have matching hashes. */
+/**  */
+/** # Returns */
+/** 1 on success, 0 if the hash is unknown (nothing is written). */
+NCZX_IMPORT uint32_t rom_hash(uint8_t* out_ptr);
+
+/** Records an analytics event, e.g. `"difficulty"` = 2 or `"car"` = 5. */
+/**  */
+/** `name` must be 1-32 characters of `a-z`, `0-9`, `_`, `.` or `-`. */
+/** The host only keeps a count per (name, value) pair, and only sends the */
+/** totals to the developer if the player opted in to analytics in the */
+/** privacy settings. Always safe to call; there is no return value. */
+NCZX_IMPORT void analytics_event(const uint8_t* name_ptr, uint32_t name_len, int32_t value);
+
 /** Returns a deterministic random u32 from the host's seeded RNG. */
 /** Always use this instead of external random sources for rollback compatibility. */
 NCZX_IMPORT uint32_t random(void);
@@ -1291,7 +2520,76 @@ NCZX_IMPORT float random_f32(void);
 /** Uses host's seeded RNG for rollback compatibility. */
 NCZX_IMPORT float random_f32_range(float min, float max);
 
-/** Returns the number of players in the session (1-4). */
+/** Creates an independent RNG stream seeded with `seed`. */
+/** Draws from a stream never affect `random()` or other streams, so cosmetic */
+/** rolls can't shift gameplay rolls. Streams are restored by rollback; up to 16 can exist. */
+/**  */
+/** # Returns */
+/** Stream handle, or 0 if all streams are in use. */
+NCZX_IMPORT uint32_t rng_create(uint64_t seed);
+
+/** Returns the next random u32 from a stream (0 for an invalid handle). */
+NCZX_IMPORT uint32_t rng_next(uint32_t handle);
+
+/** Returns a random i32 in range [min, max) from a stream. */
+NCZX_IMPORT int32_t rng_range(uint32_t handle, int32_t min, int32_t max);
+
+/** Frees a stream so its slot can be reused. */
+/**  */
+/** # Returns */
+/** 1 if the stream existed, 0 otherwise. */
+NCZX_IMPORT uint32_t rng_free(uint32_t handle);
+
+/** Calls the game's exported `on_timer(callback_id)` once, `ticks` updates from now. */
+/**  */
+/** Timers run on the deterministic tick (before `update()`) and are restored */
+/** by rollback, so they are safe for netplay. Up to 32 timers can be pending. */
+/**  */
+/** # Returns */
+/** Timer handle for `timer_cancel()`, or 0 if all timers are in use. */
+NCZX_IMPORT uint32_t timer_after(uint32_t ticks, uint32_t callback_id);
+
+/** Calls the game's exported `on_timer(callback_id)` every `ticks` updates until cancelled. */
+/**  */
+/** # Returns */
+/** Timer handle for `timer_cancel()`, or 0 if all timers are in use. */
+NCZX_IMPORT uint32_t timer_every(uint32_t ticks, uint32_t callback_id);
+
+/** Cancels a pending timer. */
+/**  */
+/** # Returns */
+/** 1 if the timer was pending, 0 otherwise. */
+NCZX_IMPORT uint32_t timer_cancel(uint32_t handle);
+
+/** Pops up to `max` of the oldest unread host events into `out_ptr`. */
+/**  */
+/** Each event is a 36-byte record: `kind: u32`, `data: [u32; 5]`, */
+/** `position: [f32; 3]`. See `event_kind` for the kinds and their data. */
+/** Events are restored by rollback, so poll them from `update()`. Up to 64 */
+/** events are queued; older events are overwritten when the queue is full. */
+/**  */
+/** # Returns */
+/** The number of events written. */
+NCZX_IMPORT uint32_t event_poll(uint8_t* out_ptr, uint32_t max);
+
+/** Registers a region of game memory to be saved and restored on rollback. */
+/**  */
+/** By default rollback snapshots all of linear memory. Once any region is */
+/** registered, only registered regions are snapshotted, so keep every value */
+/** `update()` changes inside one. Init-only; up to 64 regions, which must not */
+/** overlap. */
+/**  */
+/** # Returns */
+/** 1 on success, 0 if the region is empty, out of bounds, overlaps another, or */
+/** init() has finished. */
+NCZX_IMPORT uint32_t state_register(const uint8_t* ptr, uint32_t len);
+
+/** Returns the number of players in the session (1-8). */
+/**  */
+/** Controllers connecting and disconnecting mid-game call the optional */
+/** `on_player_join(player)` / `on_player_leave(player)` exports at the start of */
+/** the tick, before `update()`. Controllers connected at startup join on the */
+/** first tick. */
 NCZX_IMPORT uint32_t player_count(void);
 
 /** Returns a bitmask of which players are local to this client. */
@@ -1299,6 +2597,173 @@ NCZX_IMPORT uint32_t player_count(void);
 /** Example: `(local_player_mask() & (1 << player_id)) != 0` checks if player is local. */
 NCZX_IMPORT uint32_t local_player_mask(void);
 
+/** Writes the name of a local player's controller to `out_ptr` (UTF-8). */
+/**  */
+/** Names are "Keyboard" or the name the gamepad reports. They are only known */
+/** for local players and differ between machines, so use them for display */
+/** ("P3 joined: DualSense") and never for game logic. */
+/**  */
+/** # Returns */
+/** Bytes written (at most `max`), or 0 for remote players and empty slots. */
+NCZX_IMPORT uint32_t controller_name(uint32_t player, uint8_t* out_ptr, uint32_t max);
+
+/** Sets how a remote player's input is predicted while it is in flight. */
+/**  */
+/** Rollback repeats a player's last input until their real input arrives, */
+/** which makes a missed dodge or dash replay for several ticks. Pick a */
+/** policy per player (see `predict_policy::*`): */
+/** * `HOLD` (default) — repeat the last input */
+/** * `NEUTRAL` — release every button and center the sticks */
+/** * `CUSTOM` — call the game's `predict_input(player, ticks) -> *const u8` */
+/** export, which returns a pointer to a predicted input in the console's */
+/** input layout, or 0 to keep holding. `ticks` counts ticks past the last */
+/** confirmed input (1 = first predicted tick); the player's held input is */
+/** readable through the normal input functions. */
+/**  */
+/** `predict_input()` must not change game state: it is not rolled back. */
+NCZX_IMPORT void predict_policy(uint32_t player, uint32_t policy);
+
+/** Enables (1) or disables (0) voice chat for a player. */
+/**  */
+/** For the local player this turns microphone capture and playback on or off; */
+/** for a remote player it controls whether their voice is played (on by default). */
+/** Voice is opt-in and presentation-only: it never affects the simulation, */
+/** so it is safe to call from `update()`. Has no effect outside netplay. */
+NCZX_IMPORT void voice_enable(uint32_t player, uint32_t on);
+
+/** Disables voice chat for the local player and drops any buffered audio. */
+NCZX_IMPORT void voice_disable(void);
+
+/** Returns 1 if voice chat is enabled for the local player, 0 otherwise. */
+NCZX_IMPORT uint32_t voice_is_enabled(void);
+
+/** Mutes (1) or unmutes (0) incoming voice from a player. */
+NCZX_IMPORT void voice_set_muted(uint32_t player, uint32_t muted);
+
+/** Returns 1 if the player is muted, 0 otherwise. */
+NCZX_IMPORT uint32_t voice_is_muted(uint32_t player);
+
+/** Returns 1 if voice from a remote player is currently playing, 0 otherwise. */
+/**  */
+/** Useful for drawing a speaking indicator next to the player. */
+NCZX_IMPORT uint32_t voice_is_speaking(uint32_t player);
+
+/** Enables positional attenuation: speakers fade out linearly with distance */
+/** and are silent beyond `max_distance` world units. Pass 0 to disable. */
+/**  */
+/** Positions follow `listener_set_n()`; the two calls below override them. */
+NCZX_IMPORT void voice_set_positional(float max_distance);
+
+/** Sets the listener position used for positional attenuation. */
+NCZX_IMPORT void voice_set_listener(float x, float y, float z);
+
+/** Sets a player's position used for positional attenuation. */
+NCZX_IMPORT void voice_set_player_position(uint32_t player, float x, float y, float z);
+
+/** Sends a UTF-8 text message to all players (truncated to 128 bytes). */
+/**  */
+/** Your own messages are echoed back through `chat_poll()`, so a chat log */
+/** renders the same way in local and online sessions. */
+NCZX_IMPORT void chat_send(const uint8_t* ptr, uint32_t len);
+
+/** Pops the oldest unread chat message into a 136-byte record. */
+/**  */
+/** Record layout: `sender: u32`, `len: u32`, `text: [u8; 128]` (UTF-8). */
+/** Returns 1 if a message was written, 0 if there are none. */
+NCZX_IMPORT uint32_t chat_poll(uint8_t* out_ptr);
+
+/** Sends a canned quick-chat message by index (0-7). */
+/**  */
+/** 0 Help!, 1 On my way!, 2 Wait for me!, 3 Go go go!, */
+/** 4 Nice!, 5 Thanks!, 6 Sorry!, 7 Good game! */
+NCZX_IMPORT void quick_chat(uint32_t id);
+
+/** Places a world-space ping visible to all players (see `ping_kind::*`). */
+NCZX_IMPORT void ping(float x, float y, float z, uint32_t kind);
+
+/** Pops the oldest unread ping into a 20-byte record. */
+/**  */
+/** Record layout: `sender: u32`, `kind: u32`, `x: f32`, `y: f32`, `z: f32`. */
+/** Returns 1 if a ping was written, 0 if there are none. */
+NCZX_IMPORT uint32_t ping_poll(uint8_t* out_ptr);
+
+/** Hosts an in-game lobby named by the UTF-8 string at `name_ptr`. */
+/**  */
+/** `max_players` is clamped between 2 and the ROM's `max_players`. Progress is */
+/** reported through `lobby_state()` and the optional `on_lobby_state(state)` */
+/** export. Lobbies are only available in the standalone player with a */
+/** rendezvous server configured; returns 1 if the request was queued. */
+NCZX_IMPORT uint32_t lobby_create(const uint8_t* name_ptr, uint32_t name_len, uint32_t max_players);
+
+/** Writes up to `max` open lobbies for this ROM as 40-byte records. */
+/**  */
+/** Record layout: `id: u32`, `players: u8`, `max_players: u8`, */
+/** `name_len: u8`, pad `u8`, `name: [u8; 32]` (UTF-8). Each call also */
+/** requests a fresh listing, so results lag by a moment. */
+/**  */
+/** # Returns */
+/** The number of lobbies written. */
+NCZX_IMPORT uint32_t lobby_list(uint8_t* out_ptr, uint32_t max);
+
+/** Joins a lobby by its id from `lobby_list()`. Returns 1 if queued. */
+NCZX_IMPORT uint32_t lobby_join(uint32_t id);
+
+/** Starts the match (host only, once another player has joined). */
+/**  */
+/** Every player's game then restarts from `init()` as a netplay session. */
+/** Returns 1 if queued, 0 if the local player isn't hosting. */
+NCZX_IMPORT uint32_t lobby_start(void);
+
+/** Leaves the current lobby (closes it when hosting). */
+NCZX_IMPORT void lobby_leave(void);
+
+/** Returns the current lobby state (see `lobby_state::*`). */
+NCZX_IMPORT uint32_t lobby_state(void);
+
+/** Returns the number of players in the current lobby, including you. */
+NCZX_IMPORT uint32_t lobby_player_count(void);
+
+/** Writes the tournament series standings into a 44-byte record. */
+/**  */
+/** Record layout (all `u32`): `best_of`, `round` (1-based), `champion` */
+/** (player index, or 0xFF while undecided), then `wins[8]` per player. */
+/** Returns 1 if the game is running as part of a series, 0 otherwise. */
+/**  */
+/** Standings only change between rounds; each round is a fresh session. */
+NCZX_IMPORT uint32_t series_state(uint8_t* out_ptr);
+
+/** Reports the winner of the current series round (0xFF for a draw). */
+/**  */
+/** The host loads the next round after this frame unless the series has */
+/** been decided, in which case the game keeps running with the final */
+/** standings. Only the first report per round counts. */
+NCZX_IMPORT void series_round_end(uint32_t winner);
+
+/** Returns the seed for today's daily challenge. */
+/**  */
+/** Every player gets the same seed on the same UTC day, so runs generated */
+/** from it are identical for everybody. Fixed when the game is loaded. */
+NCZX_IMPORT uint64_t daily_seed(void);
+
+/** Writes today's daily leaderboard category (`daily-YYYY-MM-DD`, 16 bytes). */
+/**  */
+/** Returns bytes written (at most `max_len`). */
+NCZX_IMPORT uint32_t daily_category(uint8_t* out_ptr, uint32_t max_len);
+
+/** Grants a cross-ROM unlock token to every game from this publisher. */
+/**  */
+/** The publisher is the registry author the ROM was downloaded from, so */
+/** games can only grant and read their own publisher's tokens. Grants are */
+/** permanent and saved immediately. Returns 1 if the token is now held, 0 if */
+/** tokens are unavailable (e.g. a sideloaded ROM). Always 1 in netplay. */
+NCZX_IMPORT uint32_t token_grant(uint32_t id);
+
+/** Checks whether any game from this publisher has granted a token. */
+/**  */
+/** Returns 1 if held, 0 otherwise. Always 0 in netplay, where each peer */
+/** has its own tokens. */
+NCZX_IMPORT uint32_t token_has(uint32_t id);
+
 /** Saves data to a slot. */
 /**  */
 /** Slot semantics: */
@@ -1340,12 +2805,102 @@ NCZX_IMPORT uint32_t load(uint32_t slot, uint8_t* data_ptr, uint32_t max_len);
 /** 0 on success, 1 if invalid slot. */
 NCZX_IMPORT uint32_t delete(uint32_t slot);
 
+/** Registers the transform the host records for ghosts. */
+/**  */
+/** `transform_ptr` points to 7 `f32` values in game memory: position */
+/** (x, y, z) followed by a rotation quaternion (x, y, z, w). While a */
+/** recording is active the host copies it after every `update()`. */
+/** Pass 0 to unregister. */
+NCZX_IMPORT void ghost_register(const float* transform_ptr);
+
+/** Starts recording the registered transform into a ghost slot. */
+/**  */
+/** # Arguments */
+/** * `slot` — Ghost slot (0-3) */
+/**  */
+/** # Returns */
+/** 0 on success, 1 if invalid slot, 2 if no transform is registered. */
+NCZX_IMPORT uint32_t ghost_record_begin(uint32_t slot);
+
+/** Stops recording. */
+/**  */
+/** If `save` is non-zero the recording replaces the ghost in its slot and */
+/** is written alongside the save data, so best-lap ghosts survive restarts. */
+/** Otherwise the recording is discarded. */
+/**  */
+/** # Returns */
+/** Number of recorded frames (0 if nothing was recording). */
+NCZX_IMPORT uint32_t ghost_record_end(uint32_t save);
+
+/** Returns the number of frames in a ghost slot (0 if empty). */
+NCZX_IMPORT uint32_t ghost_frames(uint32_t slot);
+
+/** Writes the transform recorded `tick` ticks into a ghost. */
+/**  */
+/** Writes 7 `f32` values to `out_ptr` in the same layout as */
+/** `ghost_register()`. */
+/**  */
+/** # Returns */
+/** 1 on success, 0 if the slot is empty or `tick` is past the end. */
+NCZX_IMPORT uint32_t ghost_playback(uint32_t slot, uint32_t tick, float* out_ptr);
+
+/** Capture the frame being drawn into a thumbnail slot. */
+/**  */
+/** The host shrinks the finished frame to 160x90 and stores it in the slot once */
+/** the current frame has been rendered, so call this from `render()` on the frame */
+/** you want kept (e.g. right after saving). Thumbnails are kept next to the */
+/** game's save data and are not rolled back. */
+/**  */
+/** # Arguments */
+/** * `slot` — Thumbnail slot (0-7) */
+/**  */
+/** # Returns */
+/** 0 on success, 1 if the slot is invalid. */
+NCZX_IMPORT uint32_t thumbnail_capture(uint32_t slot);
+
+/** Returns 1 if a thumbnail slot holds a capture, 0 if it is empty or invalid. */
+NCZX_IMPORT uint32_t thumbnail_exists(uint32_t slot);
+
+/** Empty a thumbnail slot (e.g. when its save is deleted). */
+/**  */
+/** # Returns */
+/** 0 on success, 1 if the slot is invalid. */
+NCZX_IMPORT uint32_t thumbnail_clear(uint32_t slot);
+
+/** Set the tick rate (how often `update()` runs). Must be called during `init()`. */
+/**  */
+/** Rollback windows and input delay are counted in ticks, so at 120 Hz the same */
+/** 8-tick rollback window covers half the latency it does at 60 Hz (and re-simulating */
+/** it costs twice the CPU per second); at 30 Hz it covers twice the latency. */
+/** Online sessions always run at the ROM's netplay tick rate; 24 Hz is local-only. */
+/**  */
+/** # Arguments */
+/** * `rate` — `tick_rate::HZ_24`, `HZ_30`, `HZ_60` (default) or `HZ_120` */
+NCZX_IMPORT void set_tick_rate(uint32_t rate);
+
 /** Set the clear/background color. Must be called during `init()`. */
 /**  */
 /** # Arguments */
 /** * `color` — Color in 0xRRGGBBAA format (default: black) */
 NCZX_IMPORT void set_clear_color(uint32_t color);
 
+/** Enable PS1-style retro rendering for all 3D meshes. Must be called during `init()`. */
+/**  */
+/** # Arguments */
+/** * `vertex_snap_bits` — 0 = off, N = snap vertices to a screen grid 2^N cells tall (0-10, 8 ≈ 455x256) */
+/** * `affine_textures` — 0 = perspective-correct (default), 1 = affine texture warping */
+NCZX_IMPORT void retro_mode(uint32_t vertex_snap_bits, uint32_t affine_textures);
+
+/** Constrain the final image to a palette. Must be called during `init()`. */
+/**  */
+/** Each pixel of the palette texture (row-major, alpha ignored) is one color, up to 256. */
+/** The texture must be uncompressed RGBA8 and loaded during `init()`. */
+/**  */
+/** # Arguments */
+/** * `texture` — Palette texture handle */
+/** * `dither` — 0 = nearest color, 1 = 4x4 ordered dithering */
+NCZX_IMPORT void palette_mode(uint32_t texture, uint32_t dither);
+
 // =============================================================================
 // 2D Drawing (Screen Space)
 // =============================================================================
@@ -1370,6 +2925,27 @@ NCZX_IMPORT void draw_sprite_region(float x, float y, float w, float h, float sr
 /** * `angle_deg` — Rotation angle in degrees (clockwise) */
 NCZX_IMPORT void draw_sprite_ex(float x, float y, float w, float h, float src_x, float src_y, float src_w, float src_h, float origin_x, float origin_y, float angle_deg);
 
+/** Draw a texture as a nine-slice panel (corners keep their size at any panel size). */
+/**  */
+/** # Arguments */
+/** * `texture` — Texture handle */
+/** * `left`, `top`, `right`, `bottom` — Border widths in texture pixels */
+/** * `color` — Tint color (0xRRGGBBAA) */
+/**  */
+/** Panels smaller than their borders shrink the borders to fit. */
+NCZX_IMPORT void draw_nine_slice(uint32_t texture, float x, float y, float w, float h, float left, float top, float right, float bottom, uint32_t color);
+
+/** Draw a save slot thumbnail captured with `thumbnail_capture()`. */
+/**  */
+/** # Arguments */
+/** * `slot` — Thumbnail slot (0-7) */
+/** * `x`, `y` — Top-left corner in pixels */
+/** * `w`, `h` — Size in pixels (thumbnails are 160x90) */
+/**  */
+/** Draws nothing if the slot is empty. Uses the current color and blend mode like */
+/** `draw_sprite()`; the bound texture is preserved. */
+NCZX_IMPORT void thumbnail_draw(uint32_t slot, float x, float y, float w, float h);
+
 /** Draw a solid color rectangle. */
 NCZX_IMPORT void draw_rect(float x, float y, float w, float h);
 
@@ -1381,6 +2957,21 @@ NCZX_IMPORT void draw_rect(float x, float y, float w, float h);
 /** * `size` — Font size in pixels */
 NCZX_IMPORT void draw_text(const uint8_t* ptr, uint32_t len, float x, float y, float size);
 
+/** Draw text along a path (arcs, rings, curved titles). */
+/**  */
+/** # Arguments */
+/** * `ptr` — Pointer to UTF-8 string data */
+/** * `len` — Length in bytes */
+/** * `points_ptr` — Pointer to `count` × 2 f32 (x, y) screen points forming a polyline */
+/** * `count` — Number of points (at least 2) */
+/** * `size` — Font size in pixels */
+/** * `color` — Text color (0xRRGGBBAA), for this call only */
+/**  */
+/** Glyphs follow the path from the first point, standing on its left side */
+/** (above it when the path runs left to right). Text past the end of the */
+/** path is cut off. */
+NCZX_IMPORT void draw_text_path(const uint8_t* ptr, uint32_t len, const float* points_ptr, uint32_t count, float size, uint32_t color);
+
 /** Measure the width of text when rendered. */
 /**  */
 /** # Arguments */
@@ -1419,6 +3010,68 @@ NCZX_IMPORT void draw_circle(float x, float y, float radius);
 /** Rendered as 16 line segments. */
 NCZX_IMPORT void draw_circle_outline(float x, float y, float radius, float thickness);
 
+/** Draw an edge-of-screen indicator pointing at an offscreen world position. */
+/**  */
+/** # Arguments */
+/** * `x`, `y`, `z` — Target position in world units */
+/** * `icon` — Texture handle drawn at the indicator (0 = arrow only) */
+/** * `color` — Icon tint and arrow color (0xRRGGBBAA) */
+/**  */
+/** Projects the target with the current camera. Nothing is drawn if it is */
+/** inside the current viewport; otherwise a 24px icon and an arrow are */
+/** clamped to the viewport edge facing the target (behind-camera targets */
+/** point the way to turn). Color and bound texture are preserved. */
+/**  */
+/** # Returns */
+/** 1 if the indicator was drawn, 0 if the target is on screen. */
+NCZX_IMPORT uint32_t offscreen_indicator(float x, float y, float z, uint32_t icon, uint32_t color);
+
+/** Draw an objective marker that follows a world position on and off screen. */
+/**  */
+/** # Arguments */
+/** * `x`, `y`, `z` — Target position in world units */
+/** * `icon` — Texture handle for the marker icon (0 = label only) */
+/** * `label_ptr` — Pointer to UTF-8 label text */
+/** * `label_len` — Label length in bytes (0 = no label) */
+/**  */
+/** On screen, the icon is centered on the target at 32px, shrinking with */
+/** distance down to 12px, with the label centered underneath in the bound */
+/** font. Offscreen, it draws an `offscreen_indicator()` at the screen edge */
+/** instead. Uses the current color; color and bound texture are preserved. */
+/**  */
+/** # Returns */
+/** 1 if the marker was drawn on screen, 0 if it was drawn as an edge indicator. */
+NCZX_IMPORT uint32_t marker_draw(float x, float y, float z, uint32_t icon, const uint8_t* label_ptr, uint32_t label_len);
+
+/** Show a toast notification in the corner of the screen. */
+/**  */
+/** # Arguments */
+/** * `ptr` — Pointer to UTF-8 text */
+/** * `len` — Text length in bytes (1-64) */
+/** * `icon` — Texture handle drawn left of the text (0 = none) */
+/** * `duration` — Seconds on screen including fades (clamped to 0.45-30) */
+/**  */
+/** Toasts are drawn by the host above everything else, stacked in the top-right */
+/** corner of the visible canvas with the oldest on top, and fade in and out. Up */
+/** to 4 are shown at once; later ones wait for a free slot. They are not rolled */
+/** back: a toast raised again on the same tick with the same text and icon (as */
+/** happens when update() is re-simulated) is ignored, and so are toasts past a */
+/** queue of 16. */
+NCZX_IMPORT void toast(const uint8_t* ptr, uint32_t len, uint32_t icon, float duration);
+
+/** Restrict 2D drawing to a rectangle until the matching clip_pop(). */
+/**  */
+/** # Arguments */
+/** * `x`, `y` — Top-left corner in pixels, relative to the current viewport */
+/** * `w`, `h` — Size in pixels */
+/**  */
+/** Affects sprites, rects, lines, and text. Nested clips are intersected */
+/** with the enclosing one (up to 16 deep). 3D drawing is not clipped. */
+NCZX_IMPORT void clip_push(float x, float y, float w, float h);
+
+/** Restore the clip rectangle from before the last clip_push(). */
+NCZX_IMPORT void clip_pop(void);
+
 /** Load a fixed-width bitmap font. */
 /**  */
 /** # Arguments */
@@ -1437,11 +3090,57 @@ NCZX_IMPORT uint32_t load_font(uint32_t texture, uint32_t char_width, uint32_t c
 /** * `widths_ptr` — Pointer to array of char_count u8 widths */
 NCZX_IMPORT uint32_t load_font_ex(uint32_t texture, const uint8_t* widths_ptr, uint32_t char_height, uint32_t first_codepoint, uint32_t char_count);
 
+/** Add another codepoint range to a loaded font (init-only). */
+/**  */
+/** Glyphs for the range follow the font's existing glyphs in the atlas grid, */
+/** so one texture can hold several pages (ASCII, Latin-1, Cyrillic, Kana...). */
+/** draw_text() decodes UTF-8 and looks each character up across all ranges. */
+/**  */
+/** # Arguments */
+/** * `font_handle` — Font from `load_font()` or `load_font_ex()` */
+/** * `first_codepoint` — Unicode codepoint of the range's first glyph */
+/** * `char_count` — Number of glyphs in the range */
+/** * `widths_ptr` — Pointer to char_count u8 widths, or null for the font's cell width */
+/**  */
+/** Ranges must not overlap. Widths may not exceed the font's cell width. */
+NCZX_IMPORT void font_add_range(uint32_t font_handle, uint32_t first_codepoint, uint32_t char_count, const uint8_t* widths_ptr);
+
 /** Bind a font for subsequent draw_text() calls. */
 /**  */
 /** Pass 0 for the built-in 8×8 monospace font. */
 NCZX_IMPORT void font_bind(uint32_t font_handle);
 
+/** Choose the language used by `str_get()`. */
+/**  */
+/** A code matches a table language exactly (ignoring case) or by its primary */
+/** subtag, so "pt-BR" also selects a "pt" column. */
+/**  */
+/** # Arguments */
+/** * `lang_ptr` — Pointer to a language code (e.g. "fr", "pt-BR") */
+/** * `lang_len` — Length of the language code */
+/**  */
+/** # Returns */
+/** 1 if a loaded table has the language, 0 otherwise (lookups then use each */
+/** table's first language). */
+NCZX_IMPORT uint32_t locale_set(const uint8_t* lang_ptr, uint32_t lang_len);
+
+/** Copy the text for a key in the current locale into a buffer. */
+/**  */
+/** Falls back to the table's first language when the key has no text in the */
+/** current one. Text longer than the buffer is cut at the last whole UTF-8 */
+/** character that fits; it is not NUL-terminated. */
+/**  */
+/** # Arguments */
+/** * `key_ptr` — Pointer to the key string (e.g. "menu.start") */
+/** * `key_len` — Length of the key */
+/** * `out_ptr` — Destination buffer */
+/** * `max_len` — Size of the destination buffer */
+/**  */
+/** # Returns */
+/** Full length of the text in bytes (more than `max_len` if it was cut), */
+/** or 0 if no loaded table has the key. */
+NCZX_IMPORT uint32_t str_get(const uint8_t* key_ptr, uint32_t key_len, uint8_t* out_ptr, uint32_t max_len);
+
 // =============================================================================
 // Texture Functions
 // =============================================================================
@@ -1459,6 +3158,61 @@ NCZX_IMPORT uint32_t load_texture(uint32_t width, uint32_t height, const uint8_t
 /** Bind a texture to slot 0 (albedo). */
 NCZX_IMPORT void texture_bind(uint32_t handle);
 
+/** Bind one sprite of a texture atlas to slot 0 (albedo). */
+/**  */
+/** # Arguments */
+/** * `handle` — Atlas handle from rom_atlas() */
+/** * `sprite_index` — Sprite index (order of `sprites` in nether.toml) */
+/**  */
+/** Sprite and billboard draws then sample only that sprite; region draws */
+/** are relative to it. Binding another texture to slot 0 clears it. */
+NCZX_IMPORT void atlas_bind(uint32_t handle, uint32_t sprite_index);
+
+/** Combine textures into a texture array. */
+/**  */
+/** # Arguments */
+/** * `handles_ptr` — Pointer to `count` texture handles, one per layer */
+/** * `count` — Number of layers (1-256) */
+/**  */
+/** Textures must be loaded in the same init() call and share one size and */
+/** format. Layers are copied, so they count against VRAM again. */
+/**  */
+/** # Returns */
+/** Texture array handle (>0) on success, 0 on failure. */
+NCZX_IMPORT uint32_t texture_array_create(const uint32_t* handles_ptr, uint32_t count);
+
+/** Bind one layer of a texture array to slot 0 (albedo). */
+/**  */
+/** # Arguments */
+/** * `handle` — Texture array handle from texture_array_create() */
+/** * `layer` — Layer index (order of the handles passed in) */
+/**  */
+/** Sprite and billboard draws then sample that layer. Switching layers of */
+/** one array doesn't start a new batch. Meshes don't sample texture arrays. */
+NCZX_IMPORT void texture_array_bind(uint32_t handle, uint32_t layer);
+
+/** Load a palette for indexed textures (init-only). */
+/**  */
+/** # Arguments */
+/** * `colors_ptr` — Pointer to an array of u32 colors (0xRRGGBBAA) */
+/** * `count` — Number of colors (1-256) */
+/**  */
+/** Color N is drawn for texels with index N. Pack textures with */
+/** `quality = "indexed"` to store palette indices. */
+/**  */
+/** # Returns */
+/** Palette handle (>0) on success, 0 on failure. */
+NCZX_IMPORT uint32_t palette_load(const uint32_t* colors_ptr, uint32_t count);
+
+/** Set the palette for subsequent draws of indexed textures. */
+/**  */
+/** # Arguments */
+/** * `palette` — Palette handle from `palette_load()`, or 0 to unbind */
+/**  */
+/** Only applies while an indexed texture is bound to slot 0; other textures */
+/** draw unchanged, so the palette can stay bound. */
+NCZX_IMPORT void palette_bind(uint32_t palette);
+
 /** Bind a texture to a specific slot. */
 /**  */
 /** # Arguments */
@@ -1522,6 +3276,16 @@ NCZX_IMPORT void push_scale(float x, float y, float z);
 /** Push a uniform scale transform. */
 NCZX_IMPORT void push_scale_uniform(float s);
 
+/** Push a mesh socket's transform. */
+/**  */
+/** Call after binding the mesh's pose (`set_bones()` or `keyframe_bind()`) */
+/** with the mesh's transform current. A socket on a bone follows it. */
+/**  */
+/** # Arguments */
+/** * `mesh` — Mesh handle from rom_mesh */
+/** * `socket` — Socket ID from rom_mesh_socket */
+NCZX_IMPORT void push_socket(uint32_t mesh, uint32_t socket);
+
 // =============================================================================
 // Viewport Functions (Split-Screen)
 // =============================================================================
@@ -1567,6 +3331,26 @@ NCZX_IMPORT void viewport(uint32_t x, uint32_t y, uint32_t width, uint32_t heigh
 /** coordinates for HUD elements or between frames. */
 NCZX_IMPORT void viewport_clear(void);
 
+/** Choose how the 960×540 canvas is scaled to the window. */
+/**  */
+/** # Arguments */
+/** * `mode` — `scale_mode::INTEGER` (pixel-perfect, black bars), */
+/** `scale_mode::FIT` (largest size with bars) or `scale_mode::STRETCH` */
+/** (fill the window, distorting the aspect ratio) */
+/**  */
+/** Overrides the player's scale setting until the game restarts. */
+/** Presentation-only, so it is safe to call from `update()` or `render()`. */
+NCZX_IMPORT void display_scale_mode(uint32_t mode);
+
+/** Get the part of the canvas that is visible in the window. */
+/**  */
+/** Writes x, y, width, height (4 × f32, canvas pixels) to `out_ptr`. This is */
+/** the full canvas unless integer scaling crops it in a window smaller than */
+/** 960×540. Keep HUD elements inside this rectangle. */
+/**  */
+/** Returns 1 on success, 0 if `out_ptr` is invalid. */
+NCZX_IMPORT uint32_t safe_area(float* out_ptr);
+
 // =============================================================================
 // Constants
 // =============================================================================
@@ -1641,6 +3425,109 @@ NCZX_IMPORT void viewport_clear(void);
 #define NCZX_STENCIL_OP_INCREMENT_WRAP 6
 #define NCZX_STENCIL_OP_DECREMENT_WRAP 7
 
+// ping_kind constants
+#define NCZX_PING_KIND_LOOK 0
+#define NCZX_PING_KIND_GO 1
+#define NCZX_PING_KIND_DANGER 2
+#define NCZX_PING_KIND_ITEM 3
+
+// easing constants
+#define NCZX_EASING_LINEAR 0
+#define NCZX_EASING_EASE_IN 1
+#define NCZX_EASING_EASE_OUT 2
+#define NCZX_EASING_EASE_IN_OUT 3
+
+// audio_bus constants
+#define NCZX_AUDIO_BUS_MASTER 0
+#define NCZX_AUDIO_BUS_MUSIC 1
+#define NCZX_AUDIO_BUS_SFX 2
+#define NCZX_AUDIO_BUS_UI 3
+#define NCZX_AUDIO_BUS_VOICE 4
+
+// listener_policy constants
+#define NCZX_LISTENER_POLICY_NEAREST 0
+#define NCZX_LISTENER_POLICY_MIX 1
+
+// sfx_wave constants
+#define NCZX_SFX_WAVE_SQUARE 0
+#define NCZX_SFX_WAVE_SAW 1
+#define NCZX_SFX_WAVE_SINE 2
+#define NCZX_SFX_WAVE_NOISE 3
+#define NCZX_SFX_WAVE_TRIANGLE 4
+
+// blend_mode constants
+#define NCZX_BLEND_MODE_ALPHA 0
+#define NCZX_BLEND_MODE_ADDITIVE 1
+#define NCZX_BLEND_MODE_MULTIPLY 2
+#define NCZX_BLEND_MODE_SCREEN 3
+#define NCZX_BLEND_MODE_SUBTRACT 4
+
+// scale_mode constants
+#define NCZX_SCALE_MODE_INTEGER 0
+#define NCZX_SCALE_MODE_FIT 1
+#define NCZX_SCALE_MODE_STRETCH 2
+
+// tick_rate constants
+#define NCZX_TICK_RATE_HZ_24 0
+#define NCZX_TICK_RATE_HZ_30 1
+#define NCZX_TICK_RATE_HZ_60 2
+#define NCZX_TICK_RATE_HZ_120 3
+
+// projectile_flag constants
+#define NCZX_PROJECTILE_FLAG_PIERCE 1
+#define NCZX_PROJECTILE_FLAG_BOUNCE 2
+
+// body_shape constants
+#define NCZX_BODY_SHAPE_AABB 0
+#define NCZX_BODY_SHAPE_CIRCLE 1
+
+// body_flag constants
+#define NCZX_BODY_FLAG_STATIC 1
+#define NCZX_BODY_FLAG_SENSOR 2
+
+// cursor_style constants
+#define NCZX_CURSOR_STYLE_HIDDEN 0
+#define NCZX_CURSOR_STYLE_ARROW 1
+#define NCZX_CURSOR_STYLE_HAND 2
+#define NCZX_CURSOR_STYLE_CROSSHAIR 3
+
+// hitbox_kind constants
+#define NCZX_HITBOX_KIND_HURT 0
+#define NCZX_HITBOX_KIND_HIT 1
+
+// hitbox_flag constants
+#define NCZX_HITBOX_FLAG_NO_CLASH 1
+
+// hitbox_event constants
+#define NCZX_HITBOX_EVENT_HIT 0
+#define NCZX_HITBOX_EVENT_CLASH 1
+
+// event_kind constants
+#define NCZX_EVENT_KIND_TIMER 1
+#define NCZX_EVENT_KIND_PLAYER_JOIN 2
+#define NCZX_EVENT_KIND_PLAYER_LEAVE 3
+#define NCZX_EVENT_KIND_PROJECTILE_HIT 0x100
+#define NCZX_EVENT_KIND_HITBOX_HIT 0x101
+#define NCZX_EVENT_KIND_HITBOX_CLASH 0x102
+#define NCZX_EVENT_KIND_DESTRUCTIBLE_BREAK 0x103
+#define NCZX_EVENT_KIND_CHUNK_LOADED 0x104
+#define NCZX_EVENT_KIND_CHUNK_UNLOADED 0x105
+#define NCZX_EVENT_KIND_MUSIC_BEAT 0x106
+#define NCZX_EVENT_KIND_BODY_CONTACT 0x107
+
+// lobby_state constants
+#define NCZX_LOBBY_STATE_IDLE 0
+#define NCZX_LOBBY_STATE_HOSTING 1
+#define NCZX_LOBBY_STATE_JOINING 2
+#define NCZX_LOBBY_STATE_IN_LOBBY 3
+#define NCZX_LOBBY_STATE_STARTING 4
+#define NCZX_LOBBY_STATE_FAILED 5
+
+// predict_policy constants
+#define NCZX_PREDICT_POLICY_HOLD 0
+#define NCZX_PREDICT_POLICY_NEUTRAL 1
+#define NCZX_PREDICT_POLICY_CUSTOM 2
+
 #ifdef __cplusplus
 }
 #endif
@@ -1692,9 +3579,32 @@ static inline float nczx_absf(float x) {
 
 // ROM loading helpers
 #define NCZX_ROM_TEXTURE(id) rom_texture((const uint8_t*)(id), (uint32_t)(sizeof(id) - 1))
+#define NCZX_ROM_ATLAS(id) rom_atlas((const uint8_t*)(id), (uint32_t)(sizeof(id) - 1))
+#define NCZX_ROM_CUBEMAP(id) rom_cubemap((const uint8_t*)(id), (uint32_t)(sizeof(id) - 1))
 #define NCZX_ROM_MESH(id) rom_mesh((const uint8_t*)(id), (uint32_t)(sizeof(id) - 1))
 #define NCZX_ROM_SOUND(id) rom_sound((const uint8_t*)(id), (uint32_t)(sizeof(id) - 1))
+#define NCZX_ROM_STREAM(id) rom_stream((const uint8_t*)(id), (uint32_t)(sizeof(id) - 1))
+#define NCZX_ROM_PATH(id) rom_path((const uint8_t*)(id), (uint32_t)(sizeof(id) - 1))
+#define NCZX_ROM_STRINGS(id) rom_strings((const uint8_t*)(id), (uint32_t)(sizeof(id) - 1))
 #define NCZX_ROM_FONT(id) rom_font((const uint8_t*)(id), (uint32_t)(sizeof(id) - 1))
 #define NCZX_ROM_SKELETON(id) rom_skeleton((const uint8_t*)(id), (uint32_t)(sizeof(id) - 1))
 
+// Update throttling helpers (deterministic, safe in update())
+// Bucket 0 updates every tick, 1 every 2 ticks, 2 every 4 ticks.
+// dist_sq is the squared distance to the nearest viewer from game state.
+static inline uint32_t nczx_update_bucket(float dist_sq, float near_dist, float far_dist) {
+    if (dist_sq <= near_dist * near_dist) return 0;
+    if (dist_sq <= far_dist * far_dist) return 1;
+    return 2;
+}
+
+static inline uint32_t nczx_update_interval(uint32_t bucket) {
+    return 1u << (bucket > 2 ? 2 : bucket);
+}
+
+// Entities are staggered by index so each tick runs a slice of every bucket
+static inline int nczx_update_due(uint32_t bucket, uint32_t index, uint64_t tick) {
+    return ((tick + index) % nczx_update_interval(bucket)) == 0;
+}
+
 #endif /* NETHERCORE_ZX_H */
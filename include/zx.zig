@@ -94,12 +94,37 @@ pub extern "C" fn keyframe_bind(handle: u32, index: u32) void;
 /// Texture handle (>0) on success. Traps on failure.
 pub extern "C" fn rom_texture(id_ptr: [*]const u8, id_len: u32) u32;
 
+/// Load a texture atlas from ROM data pack by ID.
+/// 
+/// Uploads the packed atlas texture and its sprite layout for `atlas_bind()`.
+/// 
+/// # Returns
+/// Atlas texture handle (>0) on success. Traps on failure.
+pub extern "C" fn rom_atlas(id_ptr: [*]const u8, id_len: u32) u32;
+
+/// Load a cubemap from ROM data pack by ID.
+/// 
+/// Uploads the six faces (+X, -X, +Y, -Y, +Z, -Z) for `sky_draw()`.
+/// 
+/// # Returns
+/// Cubemap handle (>0) on success. Traps on failure.
+pub extern "C" fn rom_cubemap(id_ptr: [*]const u8, id_len: u32) u32;
+
 /// Load a mesh from ROM data pack by ID.
 /// 
 /// # Returns
 /// Mesh handle (>0) on success. Traps on failure.
 pub extern "C" fn rom_mesh(id_ptr: [*]const u8, id_len: u32) u32;
 
+/// Look up a named socket on a mesh loaded with `rom_mesh()`.
+/// 
+/// Sockets come from glTF nodes named `socket_<name>`; pass the name
+/// without the prefix.
+/// 
+/// # Returns
+/// Socket ID (>0) for `push_socket()`. Traps on failure.
+pub extern "C" fn rom_mesh_socket(mesh: u32, name_ptr: [*]const u8, name_len: u32) u32;
+
 /// Load skeleton inverse bind matrices from ROM data pack by ID.
 /// 
 /// # Returns
@@ -118,6 +143,32 @@ pub extern "C" fn rom_font(id_ptr: [*]const u8, id_len: u32) u32;
 /// Sound handle (>0) on success. Traps on failure.
 pub extern "C" fn rom_sound(id_ptr: [*]const u8, id_len: u32) u32;
 
+/// Load a music stream from ROM data pack by ID.
+/// 
+/// Streams stay compressed and are decoded in chunks while playing, so
+/// use them for long music tracks. Play with `stream_play()`.
+/// 
+/// # Returns
+/// Stream handle (>0) on success. Traps on failure.
+pub extern "C" fn rom_stream(id_ptr: [*]const u8, id_len: u32) u32;
+
+/// Load a spline path from ROM data pack by ID.
+/// 
+/// Paths are authored as `[[assets.paths]]` control points in nether.toml.
+/// Evaluate with `path_eval()` and `path_length()`.
+/// 
+/// # Returns
+/// Path handle (>0) on success. Traps on failure.
+pub extern "C" fn rom_path(id_ptr: [*]const u8, id_len: u32) u32;
+
+/// Load a localized string table from ROM data pack by ID.
+/// 
+/// Tables are searched in load order by `str_get()`.
+/// 
+/// # Returns
+/// Table handle (>0) on success. Traps on failure.
+pub extern "C" fn rom_strings(id_ptr: [*]const u8, id_len: u32) u32;
+
 /// Get the byte size of raw data in the ROM data pack.
 /// 
 /// Use this to allocate a buffer before calling `rom_data()`.
@@ -137,6 +188,27 @@ pub extern "C" fn rom_data_len(id_ptr: [*]const u8, id_len: u32) u32;
 /// Bytes written on success. Traps on failure.
 pub extern "C" fn rom_data(id_ptr: [*]const u8, id_len: u32, dst_ptr: [*]const u8, max_len: u32) u32;
 
+/// Report loading progress for the host's loading bar.
+/// 
+/// # Arguments
+/// * `current` — Items loaded so far (clamped to `total`)
+/// * `total` — Total items to load (0 hides the bar)
+/// 
+/// Init-only. The loading screen is shown between `init_step()` calls: export
+/// `init_step() -> u32` to spread loading over several frames, returning
+/// non-zero once done. Games that load everything in `init()` never show it.
+pub extern "C" fn load_progress(current: u32, total: u32) void;
+
+/// Set the loading screen background texture.
+/// 
+/// # Arguments
+/// * `texture` — Texture handle stretched over the whole screen (0 = clear color only)
+/// 
+/// Init-only. Textures are uploaded after the `init()` or `init_step()` call
+/// that loads them, so load the background in `init()` to show it from the
+/// first loading frame.
+pub extern "C" fn load_background(texture: u32) void;
+
 // =============================================================================
 // Audio Functions
 // =============================================================================
@@ -153,6 +225,21 @@ pub extern "C" fn rom_data(id_ptr: [*]const u8, id_len: u32, dst_ptr: [*]const u
 /// Sound handle for use with playback functions.
 pub extern "C" fn load_sound(data_ptr: [*]const i16, byte_len: u32) u32;
 
+/// Synthesize a retro sound effect (sfxr-style).
+/// 
+/// Must be called during `init()`. The same parameters always produce the same sound.
+/// 
+/// # Arguments
+/// * `params_ptr` — Pointer to a 48-byte parameter block:
+/// waveform (u32, `sfx_wave::*`), duty (f32, 0 = 50% square, 1 = thinnest pulse),
+/// frequency (f32, Hz), slide (f32, octaves/s), vibrato_depth (f32, semitones),
+/// vibrato_rate (f32, Hz), attack, sustain, decay (3 × f32, seconds, up to 10 total),
+/// punch (f32, 0-1), volume (f32, 0-1), seed (u32, noise seed)
+/// 
+/// # Returns
+/// Sound handle for use with playback functions (0 if the parameters are invalid).
+pub extern "C" fn synth_sfx(params_ptr: [*]const u8) u32;
+
 /// Play sound on next available channel (fire-and-forget).
 /// 
 /// # Arguments
@@ -160,6 +247,20 @@ pub extern "C" fn load_sound(data_ptr: [*]const i16, byte_len: u32) u32;
 /// * `pan` — -1.0 (left) to 1.0 (right), 0.0 = center
 pub extern "C" fn play_sound(sound: u32, volume: f32, pan: f32) void;
 
+/// Play sound on next available channel, starting on an exact sample.
+/// 
+/// For engine loops, beat-synced hits and rapid fire. The start time is part
+/// of the rollback state, so re-simulated ticks schedule the same start.
+/// 
+/// # Arguments
+/// * `tick` — Tick whose audio the sound starts in (tick_count() = this tick; past ticks start now)
+/// * `offset_samples` — Samples (22.05kHz) into that tick's audio, up to 65536
+/// * `volume` — 0.0 to 1.0
+/// * `pan` — -1.0 (left) to 1.0 (right), 0.0 = center
+/// 
+/// Ticks more than 256 ahead are dropped.
+pub extern "C" fn play_sound_at(tick: u64, offset_samples: u32, sound: u32, volume: f32, pan: f32) void;
+
 /// Play sound on a specific channel (for managed/looping audio).
 /// 
 /// # Arguments
@@ -173,6 +274,77 @@ pub extern "C" fn channel_set(channel: u32, volume: f32, pan: f32) void;
 /// Stop a channel.
 pub extern "C" fn channel_stop(channel: u32) void;
 
+/// Create a named audio bus, or look up an existing one.
+/// 
+/// Must be called during `init()`. Buses "master", "music", "sfx", "ui"
+/// and "voice" already exist (see `audio_bus::*`); every bus is a child of master.
+/// 
+/// # Arguments
+/// * `name_ptr` — Pointer to UTF-8 bus name
+/// * `name_len` — Length of name in bytes
+/// 
+/// # Returns
+/// Bus index (0 = master, also returned if all 8 buses are in use).
+pub extern "C" fn bus_create(name_ptr: [*]const u8, name_len: u32) u32;
+
+/// Route a channel through a bus (channels start on `audio_bus::SFX`).
+/// 
+/// Music, tracker and streams always play on `audio_bus::MUSIC`.
+pub extern "C" fn channel_set_bus(channel: u32, bus: u32) void;
+
+/// Set a bus volume (0.0 to 1.0). The master bus scales all other buses.
+/// 
+/// The player's own volume for the bus (host settings) applies on top.
+pub extern "C" fn bus_volume(bus: u32, volume: f32) void;
+
+/// Pause or resume everything routed through a bus.
+/// 
+/// # Arguments
+/// * `paused` — 1 = pause, 0 = resume
+pub extern "C" fn bus_pause(bus: u32, paused: u32) void;
+
+/// Place a player's listener for positional sound and make it active.
+/// 
+/// Call every frame from `update()` with each split-screen player's camera.
+/// With no listener set, sounds are heard from the origin facing -Z.
+/// In netplay, voice chat is heard from the local player's listener and comes
+/// from the other players' listeners (see `voice_set_positional()`).
+/// 
+/// # Arguments
+/// * `player` — Player index (0-7; players 4-7 only place voice chat)
+/// * `x`, `y`, `z` — Listener position (world space)
+/// * `forward_x`, `forward_y`, `forward_z` — Facing direction (sets left/right)
+pub extern "C" fn listener_set_n(player: u32, x: f32, y: f32, z: f32, forward_x: f32, forward_y: f32, forward_z: f32) void;
+
+/// Deactivate a player's listener (e.g. when they leave split-screen).
+pub extern "C" fn listener_clear_n(player: u32) void;
+
+/// Choose how positional sounds are mixed between listeners.
+/// 
+/// # Arguments
+/// * `policy` — `listener_policy::NEAREST` (default) or `listener_policy::MIX`
+pub extern "C" fn listener_set_policy(policy: u32) void;
+
+/// Play a sound at a world position on the next available channel.
+/// 
+/// Volume and pan are computed from the listeners when the sound starts.
+/// 
+/// # Arguments
+/// * `volume` — 0.0 to 1.0 at the listener
+/// * `x`, `y`, `z` — Sound position (world space)
+/// * `range` — Distance at which the sound fades to silence
+pub extern "C" fn play_sound_3d(sound: u32, volume: f32, x: f32, y: f32, z: f32, range: f32) void;
+
+/// Update a channel's volume and pan from a world position.
+/// 
+/// Call every frame for moving or looping sounds started with `channel_play()`.
+/// 
+/// # Arguments
+/// * `volume` — 0.0 to 1.0 at the listener
+/// * `x`, `y`, `z` — Sound position (world space)
+/// * `range` — Distance at which the sound fades to silence
+pub extern "C" fn channel_set_3d(channel: u32, volume: f32, x: f32, y: f32, z: f32, range: f32) void;
+
 // =============================================================================
 // Camera Functions
 // =============================================================================
@@ -188,12 +360,52 @@ pub extern "C" fn camera_set(x: f32, y: f32, z: f32, target_x: f32, target_y: f3
 /// * `fov_degrees` — Field of view in degrees (typically 45-90, default 60)
 pub extern "C" fn camera_fov(fov_degrees: f32) void;
 
+/// Shake the current camera. Call after `camera_set()` each frame.
+/// 
+/// # Arguments
+/// * `trauma` — Shake strength (0-1, clamped); the offset grows with its square
+/// * `frequency` — How many times per second the shake changes direction (e.g. 15-30)
+/// 
+/// Keep `trauma` in your game state: raise it on hits and lower it over time.
+/// The shake is smooth noise over the tick count, seeded by the current viewport,
+/// so rolled-back frames shake identically and split-screen players independently.
+pub extern "C" fn camera_shake(trauma: f32, frequency: f32) void;
+
 /// Push a custom view matrix (16 floats, column-major order).
 pub extern "C" fn push_view_matrix(m0: f32, m1: f32, m2: f32, m3: f32, m4: f32, m5: f32, m6: f32, m7: f32, m8: f32, m9: f32, m10: f32, m11: f32, m12: f32, m13: f32, m14: f32, m15: f32) void;
 
 /// Push a custom projection matrix (16 floats, column-major order).
 pub extern "C" fn push_projection_matrix(m0: f32, m1: f32, m2: f32, m3: f32, m4: f32, m5: f32, m6: f32, m7: f32, m8: f32, m9: f32, m10: f32, m11: f32, m12: f32, m13: f32, m14: f32, m15: f32) void;
 
+// =============================================================================
+// Cursor Functions
+// =============================================================================
+
+/// Show the host-drawn cursors.
+/// 
+/// Every player has a cursor moved by their left stick and D-pad (speeding up while held), or
+/// by the mouse for player 0. Positions update every tick even while hidden, and are rolled
+/// back, so set the style from `update()`. One cursor is drawn per connected player.
+/// 
+/// # Arguments
+/// * `style` — `cursor_style::HIDDEN`, `ARROW`, `HAND` or `CROSSHAIR`
+pub extern "C" fn cursor_show(style: u32) void;
+
+/// Get a player's cursor position in screen pixels (0-959, 0-539).
+/// 
+/// Cursors start in the center of the screen. Writes X and Y to the provided pointers.
+pub extern "C" fn cursor_pos(player: u32, out_x: [*]f32, out_y: [*]f32) void;
+
+/// Set how fast sticks move the cursors.
+/// 
+/// # Arguments
+/// * `speed` — Pixels per second at full tilt (default 600)
+/// * `acceleration` — Speed multiplier gained per second of holding the stick, up to 3×
+/// (default 2.0, 0 = constant speed)
+/// 
+/// The mouse always moves the cursor 1:1.
+pub extern "C" fn cursor_speed(speed: f32, acceleration: f32) void;
+
 // =============================================================================
 // Debug Inspection System
 // =============================================================================
@@ -222,6 +434,12 @@ pub extern "C" fn debug_register_f32(name_ptr: [*]const u8, name_len: u32, ptr:
 /// Register a bool value for debug inspection.
 pub extern "C" fn debug_register_bool(name_ptr: [*]const u8, name_len: u32, ptr: [*]const u8) void;
 
+/// Register an i32 enum shown as a dropdown of labels.
+/// 
+/// `labels_ptr` points to `count` NUL-terminated labels stored back to back
+/// (e.g. "low\0medium\0high\0"); value n is displayed as the n-th label.
+pub extern "C" fn debug_register_enum(name_ptr: [*]const u8, name_len: u32, ptr: [*]const u8, labels_ptr: [*]const u8, count: u32) void;
+
 /// Register an i32 with min/max range constraints.
 pub extern "C" fn debug_register_i32_range(name_ptr: [*]const u8, name_len: u32, ptr: [*]const u8, min: i32, max: i32) void;
 
@@ -297,6 +515,18 @@ pub extern "C" fn debug_watch_rect(name_ptr: [*]const u8, name_len: u32, ptr: [*
 /// Watch a Color value (read-only).
 pub extern "C" fn debug_watch_color(name_ptr: [*]const u8, name_len: u32, ptr: [*]const u8) void;
 
+/// Record a value for the current tick in a debug inspector graph.
+/// 
+/// Call from `update()` or `render()`; the last few seconds are graphed. Names
+/// with a `/` share a graph per prefix, e.g. `"boost/p1"` and `"boost/p2"` are
+/// two lines on the `"boost"` graph.
+/// 
+/// # Parameters
+/// - `name_ptr`: Pointer to graph name string
+/// - `name_len`: Length of graph name
+/// - `value`: Value for this tick
+pub extern "C" fn stat_plot(name_ptr: [*]const u8, name_len: u32, value: f32) void;
+
 /// Begin a collapsible group in the debug UI.
 pub extern "C" fn debug_group_begin(name_ptr: [*]const u8, name_len: u32) void;
 
@@ -358,6 +588,40 @@ pub extern "C" fn debug_is_paused() i32;
 /// 1.0 = normal, 0.5 = half-speed, 2.0 = double-speed, etc.
 pub extern "C" fn debug_get_time_scale() f32;
 
+/// Draw a world-space line on top of the scene.
+/// 
+/// # Parameters
+/// - `x1`, `y1`, `z1`: Start point in world units
+/// - `x2`, `y2`, `z2`: End point in world units
+/// - `color`: Line color (0xRRGGBBAA)
+pub extern "C" fn debug_line3d(x1: f32, y1: f32, z1: f32, x2: f32, y2: f32, z2: f32, color: u32) void;
+
+/// Draw a wireframe sphere (three great circles).
+/// 
+/// # Parameters
+/// - `x`, `y`, `z`: Center in world units
+/// - `radius`: Radius in world units
+/// - `color`: Line color (0xRRGGBBAA)
+pub extern "C" fn debug_sphere(x: f32, y: f32, z: f32, radius: f32, color: u32) void;
+
+/// Draw a wireframe axis-aligned box.
+/// 
+/// # Parameters
+/// - `min_x`, `min_y`, `min_z`: Minimum corner in world units
+/// - `max_x`, `max_y`, `max_z`: Maximum corner in world units
+/// - `color`: Line color (0xRRGGBBAA)
+pub extern "C" fn debug_aabb(min_x: f32, min_y: f32, min_z: f32, max_x: f32, max_y: f32, max_z: f32, color: u32) void;
+
+/// Draw a text label anchored at a world-space position.
+/// 
+/// # Parameters
+/// - `ptr`: Pointer to UTF-8 string data
+/// - `len`: Length of string in bytes
+/// - `x`, `y`, `z`: Label anchor in world units
+/// - `size`: Font size in pixels
+/// - `color`: Text color (0xRRGGBBAA)
+pub extern "C" fn debug_text3d(ptr: [*]const u8, len: u32, x: f32, y: f32, z: f32, size: f32, color: u32) void;
+
 // =============================================================================
 // Immediate Mode 3D Drawing & Billboards
 // =============================================================================
@@ -395,6 +659,108 @@ pub extern "C" fn draw_billboard(w: f32, h: f32, mode: u32) void;
 /// * `mode` — 1=spherical, 2=cylindrical Y, 3=cylindrical X, 4=cylindrical Z
 pub extern "C" fn draw_billboard_region(w: f32, h: f32, src_x: f32, src_y: f32, src_w: f32, src_h: f32, mode: u32) void;
 
+/// Create a particle system (init-only).
+/// 
+/// Particles are simulated host-side and drawn as spherical billboards.
+/// They are presentation-only and not rolled back.
+/// 
+/// # Arguments
+/// * `max` — Maximum live particles (1-65536)
+/// 
+/// # Returns
+/// Particle system handle (>0) on success, 0 on failure.
+pub extern "C" fn particles_create(max: u32) u32;
+
+/// Emit a burst of particles.
+/// 
+/// Call from `render()` so rollback replay does not emit twice.
+/// Emission is deterministically seeded per particle system.
+/// 
+/// # Arguments
+/// * `handle` — Particle system handle
+/// * `count` — Particles to spawn (clamped to free capacity)
+/// * `x`, `y`, `z` — Spawn position (world space)
+/// * `vel_spread` — Maximum initial speed (random direction)
+/// * `life` — Lifetime in seconds
+/// * `size_start`, `size_end` — Size curve (world units)
+/// * `color_start`, `color_end` — Color gradient (0xRRGGBBAA)
+/// 
+/// # Returns
+/// Number of particles actually spawned.
+pub extern "C" fn particles_emit(handle: u32, count: u32, x: f32, y: f32, z: f32, vel_spread: f32, life: f32, size_start: f32, size_end: f32, color_start: u32, color_end: u32) u32;
+
+/// Simulate and draw a particle system.
+/// 
+/// Steps the simulation once per elapsed tick, then draws every live
+/// particle with the bound texture. Does not change `set_color()`.
+pub extern "C" fn particles_draw(handle: u32) void;
+
+/// Get the number of live particles in a system.
+pub extern "C" fn particles_count(handle: u32) u32;
+
+/// Spawn a decal projected onto nearby static meshes.
+/// 
+/// Decals are presentation-only and not rolled back. Spawning the same
+/// decal again on the same tick (rollback replay of `update()`) is ignored.
+/// Up to 256 decals are live at once; spawning more replaces the oldest.
+/// 
+/// # Arguments
+/// * `texture` — Texture handle (alpha cuts out the decal's shape)
+/// * `x`, `y`, `z` — Point on the surface (world space)
+/// * `normal_x`, `normal_y`, `normal_z` — Surface normal to project along
+/// * `size` — Width and height in world units
+/// * `rotation_deg` — Rotation about the normal (counter-clockwise)
+/// * `lifetime` — Seconds until gone, fading over the last quarter (0 = permanent)
+/// 
+/// # Returns
+/// 1 if spawned, 0 if duplicate or invalid.
+pub extern "C" fn decal_spawn(texture: u32, x: f32, y: f32, z: f32, normal_x: f32, normal_y: f32, normal_z: f32, size: f32, rotation_deg: f32, lifetime: f32) u32;
+
+/// Draw all live decals.
+/// 
+/// Call from `render()` after drawing the level, once per viewport. Decals
+/// are projected onto static meshes drawn earlier in the frame; until one
+/// is near, they draw as flat quads. Preserves the transform, bound
+/// texture and `uniform_alpha()`.
+pub extern "C" fn decals_draw() void;
+
+/// Remove all decals (e.g. when loading a new level).
+pub extern "C" fn decals_clear() void;
+
+/// Create a water plane animated by Gerstner waves (init-only).
+/// 
+/// The plane is a square grid centered on the origin at y = 0. Waves travel
+/// roughly along a seeded wind direction, each shorter and lower than the last.
+/// Up to 16 planes can be created.
+/// 
+/// # Arguments
+/// * `size` — Side length of the plane
+/// * `wave_count` — Number of summed waves (1-8)
+/// * `amplitude` — Combined crest height of all waves above rest
+/// * `seed` — Picks the wind direction, wavelengths and phases
+/// 
+/// # Returns
+/// Water handle (>0), or 0 on failure.
+pub extern "C" fn water_plane(size: f32, wave_count: u32, amplitude: f32, seed: u32) u32;
+
+/// Draw a water plane at the current tick.
+/// 
+/// Uses the current transform, bound textures and render state. UVs span 0-1
+/// across the plane and normals follow the waves.
+pub extern "C" fn water_draw(handle: u32) void;
+
+/// Get the water surface height above a point at the current tick.
+/// 
+/// Deterministic, so it is safe for buoyancy in `update()`.
+/// 
+/// # Arguments
+/// * `handle` — Water handle from water_plane()
+/// * `x`, `z` — Point in the plane's own space
+/// 
+/// # Returns
+/// Height above the plane's rest level, or 0 for invalid handles.
+pub extern "C" fn water_height(handle: u32, x: f32, z: f32) f32;
+
 // =============================================================================
 // Embedded Asset API
 // =============================================================================
@@ -476,10 +842,10 @@ pub extern "C" fn load_zskeleton(data_ptr: [*]const u8, data_len: u32) u32;
 /// - 0x06: PATCHES (bounds source)
 /// - 0x07: APERTURE (bounds modifier)
 /// - 0x08: DECAL (sharp SDF shape)
-/// - 0x09: GRID (repeating lines/panels)
+/// - 0x09: GRID (perspective line grids)
 /// - 0x0A: SCATTER (point field)
 /// - 0x0B: FLOW (animated noise/streaks)
-/// - 0x0C..0x13: radiance opcodes (TRACE/VEIL/ATMOSPHERE/PLANE/CELESTIAL/PORTAL/LOBE_RADIANCE/BAND_RADIANCE)
+/// - 0x0C..0x14: radiance opcodes (TRACE/VEIL/ATMOSPHERE/PLANE/CELESTIAL/PORTAL/LOBE_RADIANCE/BAND_RADIANCE/FLASH)
 /// 
 /// # Blend Modes
 /// - 0: ADD (dst + src * a)
@@ -514,6 +880,7 @@ pub extern "C" fn load_zskeleton(data_ptr: [*]const u8, data_len: u32) u32;
 /// # Notes
 /// - The EPU compute pass runs automatically before rendering
 /// - To set up multiple environments in a frame: call `environment_index(env_id)`, then `epu_set(config_ptr)`
+/// (or use `epu_set_slot(slot, config_ptr)` + `epu_bind(slot)` for split-screen)
 /// - Determinism: the EPU has no host-managed time; animate by changing instruction parameters from the game
 pub extern "C" fn epu_set(config_ptr: [*]const u64) void;
 
@@ -526,6 +893,184 @@ pub extern "C" fn epu_set(config_ptr: [*]const u64) void;
 /// once per viewport/pass where you want an environment background.
 pub extern "C" fn draw_epu() void;
 
+/// Draw a cubemap sky as the background for the current viewport/pass.
+/// 
+/// Used instead of `draw_epu()`: for each viewport and pass, whichever of the
+/// two is called last is drawn. The sky follows the camera's rotation and
+/// `texture_filter(...)`.
+/// 
+/// With `sky_ambient(...)` above 0, the sky also replaces the diffuse ambient
+/// of the current `environment_index(...)` this frame. Reflections still come
+/// from the EPU.
+/// 
+/// # Arguments
+/// * `cubemap` — Cubemap handle from `rom_cubemap()`
+/// * `rotation` — Rotation of the sky about the world Y axis in degrees
+pub extern "C" fn sky_draw(cubemap: u32, rotation: f32) void;
+
+/// Set how strongly `sky_draw()` skies light the scene.
+/// 
+/// Applies to `sky_draw()` calls after it in the current frame; resets to 0
+/// every frame.
+/// 
+/// # Arguments
+/// * `intensity` — Ambient multiplier (0 = keep the EPU ambient, 1 = sky as authored)
+pub extern "C" fn sky_ambient(intensity: f32) void;
+
+/// Store an EPU configuration (128-byte) for an explicit environment slot.
+/// 
+/// Unlike `epu_set(...)`, this does not depend on (or change) the current
+/// `environment_index(...)`. Use it to upload one environment per viewport
+/// up front, then select between them with `epu_bind(slot)`.
+/// 
+/// # Arguments
+/// * `slot` — Environment slot (`env_id`), clamped to 0..255
+/// * `config_ptr` — Pointer to 16 u64 values (128 bytes total) in WASM memory
+/// 
+/// # Example (per-player environments in split-screen)
+/// ```rust,ignore
+/// fn render() {
+/// epu_set_slot(0, DAY_TRACK.as_ptr());
+/// epu_set_slot(1, NIGHT_TRACK.as_ptr());
+/// 
+/// for p in 0..2 {
+/// viewport(p * 480, 0, 480, 540);
+/// epu_bind(p);
+/// draw_mesh(track);
+/// draw_epu();
+/// }
+/// }
+/// ```
+pub extern "C" fn epu_set_slot(slot: u32, config_ptr: [*]const u64) void;
+
+/// Bind an environment slot for subsequent draw calls.
+/// 
+/// Alias of `environment_index(slot)`, named to pair with `epu_set_slot()`:
+/// selects the environment used by `draw_epu()` and by ambient/reflection
+/// lighting of subsequent draws.
+/// Call inside the per-player render loop after `viewport(...)`.
+/// 
+/// # Arguments
+/// * `slot` — Environment slot (`env_id`), clamped to 0..255
+pub extern "C" fn epu_bind(slot: u32) void;
+
+/// Crossfade an environment slot to a new EPU configuration over time.
+/// 
+/// Colors, alphas, intensities and directions are interpolated field-wise from
+/// the slot's current config to the target; opcodes and params switch over
+/// halfway. Layers present on only one side fade in or out.
+/// 
+/// While the transition runs, configs pushed for `slot` are replaced by the
+/// blend, so keep calling `epu_set(...)` / `epu_set_slot(...)` with the new
+/// config every frame.
+/// 
+/// # Arguments
+/// * `slot` — Environment slot (`env_id`), clamped to 0..255
+/// * `config_ptr` — Pointer to the target config (16 u64 values, 128 bytes)
+/// * `duration_ticks` — Length of the crossfade in ticks (0 = switch immediately)
+/// * `easing` — Easing curve (see `easing::*` constants)
+/// 
+/// # Example (day → night over 5 seconds at 60 ticks/s)
+/// ```rust,ignore
+/// fn render() {
+/// if NIGHT_STARTED_THIS_FRAME {
+/// epu_transition(0, NIGHT.as_ptr(), 300, easing::EASE_IN_OUT);
+/// }
+/// epu_set(if IS_NIGHT { NIGHT.as_ptr() } else { DAY.as_ptr() });
+/// draw_epu();
+/// }
+/// ```
+pub extern "C" fn epu_transition(slot: u32, config_ptr: [*]const u64, duration_ticks: u32, easing: u32) void;
+
+/// Create a lighting probe at a world position (init-only).
+/// 
+/// The probe captures the environment selected with `environment_index(...)`
+/// when it is created (0 = global sky) plus every light that reaches its
+/// sphere. Up to 16 probes; they use environment slots 240..255.
+/// 
+/// # Arguments
+/// * `x`, `y`, `z` — Capture position in world units
+/// * `radius` — Capture radius in world units
+/// 
+/// # Returns
+/// Probe handle (>0), or 0 on error.
+pub extern "C" fn probe_create(x: f32, y: f32, z: f32, radius: f32) u32;
+
+/// Use a probe's captured lighting for ambient and reflections of subsequent draws.
+/// 
+/// The probe is recaptured every 8 ticks from its source environment and the
+/// lights set at the time of the call. Call `environment_index(...)` to return
+/// to a regular environment.
+/// 
+/// # Example
+/// ```rust,ignore
+/// fn render() {
+/// epu_set(SKY.as_ptr());
+/// environment_index(TUNNEL_ENV);
+/// epu_set(TUNNEL.as_ptr());
+/// 
+/// for car in cars() {
+/// if car.in_tunnel { material_probe(TUNNEL_PROBE); } else { environment_index(0); }
+/// draw_mesh(CAR_MESH);
+/// }
+/// }
+/// ```
+pub extern "C" fn material_probe(handle: u32) void;
+
+// =============================================================================
+// Hitboxes
+// =============================================================================
+
+/// Register a move's frame data (init-only).
+/// 
+/// Each box is active on a range of animation frames. Box coordinates are in
+/// actor space: +X is forward at yaw 0, +Y is up.
+/// 
+/// # Arguments
+/// * `boxes_ptr` — Pointer to `count` 52-byte boxes: first_frame (u32), last_frame (u32,
+/// inclusive), kind (u32, `hitbox_kind::*`), priority (u32), group (u32),
+/// flags (u32, `hitbox_flag::*`), user_data (u32), center (3 × f32), half-extents (3 × f32)
+/// * `count` — Number of boxes (1..=32)
+/// 
+/// # Returns
+/// Move handle (>0) on success, 0 on invalid input.
+pub extern "C" fn hitbox_move(boxes_ptr: [*]const u8, count: u32) u32;
+
+/// Place an actor for this tick.
+/// 
+/// Switching moves, or going back to an earlier frame of the same move, starts a
+/// new attack that may hit everyone again. Actor state is rolled back, so call from `update()`.
+/// 
+/// # Arguments
+/// * `actor` — Actor slot (0..32)
+/// * `move_id` — Move handle from `hitbox_move()`, or 0 to remove the actor
+/// * `frame` — Current animation frame of the move
+/// * `x`, `y`, `z` — Actor position
+/// * `yaw` — Facing around +Y in radians (0 faces +X)
+/// * `team` — Actors on the same non-zero team never interact
+pub extern "C" fn hitbox_actor(actor: u32, move_id: u32, frame: u32, x: f32, y: f32, z: f32, yaw: f32, team: u32) void;
+
+/// Overlap every actor's active boxes and queue hit and clash events.
+/// 
+/// Clashing hitboxes cancel the lower priority attack (equal priorities trade).
+/// Each hit group hits a defender at most once per move.
+pub extern "C" fn hitbox_resolve() void;
+
+/// Read the next event from the last `hitbox_resolve()`.
+/// 
+/// # Arguments
+/// * `out_ptr` — Pointer to a 36-byte event: kind (u32, `hitbox_event::*`), attacker (u32),
+/// defender (u32), attacker_box (u32), defender_box (u32), user_data (u32), position (3 × f32)
+/// 
+/// # Returns
+/// 1 if an event was written, 0 when there are no more events.
+pub extern "C" fn hitbox_next_event(out_ptr: [*]u8) u32;
+
+/// Draw every active box as a wireframe (F4 debug inspector only).
+/// 
+/// Hurtboxes are blue, hitboxes red.
+pub extern "C" fn hitbox_debug_draw() void;
+
 // =============================================================================
 // Input Functions
 // =============================================================================
@@ -589,6 +1134,18 @@ pub extern "C" fn trigger_left(player: u32) f32;
 /// Get right trigger value (0.0 to 1.0).
 pub extern "C" fn trigger_right(player: u32) f32;
 
+/// Get the step between stick axis values (1/127).
+/// 
+/// Sticks are quantized to whole steps before they reach the game, and
+/// every peer sees the same steps. Axes read 0.0 exactly when centered and
+/// ±1.0 exactly at full tilt.
+pub extern "C" fn stick_step() f32;
+
+/// Get the step between trigger values (1/255).
+/// 
+/// Triggers are quantized to whole steps, like sticks.
+pub extern "C" fn trigger_step() f32;
+
 // =============================================================================
 // Lighting Functions (Mode 2/3)
 // =============================================================================
@@ -638,6 +1195,71 @@ pub extern "C" fn light_set_point(index: u32, x: f32, y: f32, z: f32) void;
 /// Only affects point lights (ignored for directional).
 pub extern "C" fn light_range(index: u32, range: f32) void;
 
+/// Configure a directional light from an environment's sun.
+/// 
+/// # Arguments
+/// * `index` — Light index (0-3)
+/// * `slot` — Environment slot (env_id) to read
+/// 
+/// Copies the direction, color and intensity of the brightest sky
+/// LOBE_RADIANCE or DECAL layer into the light and enables it. Call after
+/// `epu_set()` so the light tracks the sky as it changes.
+/// 
+/// # Returns
+/// 1 if a sun was found, 0 otherwise (the light is left unchanged).
+pub extern "C" fn light_from_env(index: u32, slot: u32) u32;
+
+/// Add a point light to the scene for this frame.
+/// 
+/// # Arguments
+/// * `x`, `y`, `z` — World-space position
+/// * `color` — Light color (0xRRGGBBAA, alpha ignored)
+/// * `intensity` — Brightness multiplier (0.0-8.0)
+/// * `range` — Distance at which the light reaches zero
+/// 
+/// Scene lights add to the 4 lights above and light every draw in modes 0, 2
+/// and 3. Up to 256 per frame; each surface only pays for the lights whose
+/// range reaches it. Call every frame — the list is cleared after rendering.
+pub extern "C" fn light_add_point(x: f32, y: f32, z: f32, color: u32, intensity: f32, range: f32) void;
+
+/// Add a spot light to the scene for this frame.
+/// 
+/// # Arguments
+/// * `x`, `y`, `z` — World-space position
+/// * `dir_x`, `dir_y`, `dir_z` — Direction the spot points (normalized)
+/// * `color` — Light color (0xRRGGBBAA, alpha ignored)
+/// * `intensity` — Brightness multiplier (0.0-8.0)
+/// * `range` — Distance at which the light reaches zero
+/// * `inner_angle` — Cone half-angle in radians with full brightness
+/// * `outer_angle` — Cone half-angle in radians where the light reaches zero
+/// 
+/// Otherwise behaves like `light_add_point()`.
+pub extern "C" fn light_add_spot(x: f32, y: f32, z: f32, dir_x: f32, dir_y: f32, dir_z: f32, color: u32, intensity: f32, range: f32, inner_angle: f32, outer_angle: f32) void;
+
+/// Bake the current lights into static meshes (init-only).
+/// 
+/// # Arguments
+/// * `mesh_handles_ptr` — Pointer to an array of u32 mesh handles (must have normals)
+/// * `mesh_count` — Number of mesh handles
+/// * `light_set` — Bitmask of lights to bake (bit N = light N, 0-3)
+/// 
+/// Irradiance is computed per vertex with the current transform applied.
+/// Draw the meshes with `material_lightmap()` and disable the baked lights
+/// so they cost nothing at runtime.
+/// 
+/// # Returns
+/// Lightmap handle (>0) on success, 0 on failure.
+pub extern "C" fn lightmap_bake(mesh_handles_ptr: [*]const u32, mesh_count: u32, light_set: u32) u32;
+
+/// Apply a baked lightmap to subsequent mesh draws.
+/// 
+/// # Arguments
+/// * `lightmap` — Lightmap handle from `lightmap_bake()`, or 0 to disable
+/// 
+/// Only meshes baked into the lightmap receive its lighting. Baked light is
+/// added in modes 0, 2 and 3 (matcap ignores it).
+pub extern "C" fn material_lightmap(lightmap: u32) void;
+
 // =============================================================================
 // Material Functions (Mode 2/3)
 // =============================================================================
@@ -683,6 +1305,22 @@ pub extern "C" fn material_emissive(value: f32) void;
 /// * `power` — Falloff sharpness (0.0-32.0, higher = tighter)
 pub extern "C" fn material_rim(intensity: f32, power: f32) void;
 
+/// Set the number of lit diffuse bands for toon shading (Mode 4 only).
+/// 
+/// # Arguments
+/// * `count` — Bands above the shadow side (1-16, 1 = two-tone). Default: 3
+pub extern "C" fn material_bands(count: u32) void;
+
+/// Set the inked outline drawn around meshes (Mode 4 only).
+/// 
+/// Meshes with normals are drawn again pushed out along their normals with
+/// front faces culled, leaving a solid shell around the silhouette.
+/// 
+/// # Arguments
+/// * `width` — Outline thickness in world units (0.0 = no outline, default)
+/// * `color` — Outline color (0xRRGGBBAA, alpha uses dither transparency)
+pub extern "C" fn material_outline(width: f32, color: u32) void;
+
 /// Enable/disable uniform color override.
 /// 
 /// When enabled, uses the last set_color() value for all subsequent draws,
@@ -796,8 +1434,77 @@ pub extern "C" fn load_mesh_packed(data_ptr: [*]const u8, vertex_count: u32, for
 pub extern "C" fn load_mesh_indexed_packed(data_ptr: [*]const u8, vertex_count: u32, index_ptr: [*]const u16, index_count: u32, format: u32) u32;
 
 /// Draw a retained mesh with current transform and render state.
+/// 
+/// Meshes whose bounds are entirely outside the camera's view are skipped
+/// (see `render_stats()`); skinned and morphed meshes are always drawn.
 pub extern "C" fn draw_mesh(handle: u32) void;
 
+/// Set the weight of one of a mesh's morph targets.
+/// 
+/// Targets are numbered in the order they were exported (up to 8 per mesh).
+/// Weights stay set until changed and apply to every later draw of the mesh.
+/// 
+/// # Arguments
+/// * `handle` — Mesh handle from rom_mesh
+/// * `target_index` — Morph target index
+/// * `weight` — How much of the target to apply (0 = none, 1 = full)
+pub extern "C" fn mesh_morph_weight(handle: u32, target_index: u32, weight: f32) void;
+
+/// Merge placed copies of meshes into one mesh (init-only).
+/// 
+/// Bakes static props into a few large meshes so they draw in a few calls.
+/// Meshes with different vertex formats, or more than 65536 vertices in
+/// total, become several parts that `draw_mesh()` draws together. Source
+/// meshes must be loaded earlier in init(), must not be skinned, and stay
+/// usable on their own.
+/// 
+/// # Arguments
+/// * `handles_ptr` — Pointer to `count` mesh handles (a handle may repeat)
+/// * `transforms_ptr` — Pointer to `count` 4x4 column-major matrices (16 floats each)
+/// * `count` — Number of meshes (1-4096)
+/// 
+/// Returns a mesh handle (>0) on success, 0 on failure.
+pub extern "C" fn mesh_merge(handles_ptr: [*]const u32, transforms_ptr: [*]const f32, count: u32) u32;
+
+/// Register a destructible prop (init-only).
+/// 
+/// Each connected part of `pieces_mesh` becomes one debris piece (up to 64).
+/// Both meshes must share a local space and be loaded earlier in init().
+/// Debris settles on the lowest point of `pieces_mesh`.
+/// 
+/// # Arguments
+/// * `mesh` — Mesh drawn while the prop is intact
+/// * `pieces_mesh` — Pre-fractured version of the prop
+/// * `health` — Starting health (> 0)
+/// 
+/// # Returns
+/// Destructible handle (>0) on success, 0 on failure.
+pub extern "C" fn destructible_register(mesh: u32, pieces_mesh: u32, health: f32) u32;
+
+/// Damage a destructible prop (call from update(), rolled back).
+/// 
+/// # Arguments
+/// * `handle` — Destructible handle from `destructible_register()`
+/// * `amount` — Health to remove
+/// * `impulse_x`, `impulse_y`, `impulse_z` — Velocity added to the debris if
+/// this hit breaks the prop
+/// 
+/// # Returns
+/// 1 if this hit broke the prop, 0 otherwise.
+pub extern "C" fn destructible_damage(handle: u32, amount: f32, impulse_x: f32, impulse_y: f32, impulse_z: f32) u32;
+
+/// Draw a destructible prop with the current transform.
+/// 
+/// Draws the intact mesh, or the flying debris once broken.
+/// Debris disappears 5 seconds after the break.
+pub extern "C" fn destructible_draw(handle: u32) void;
+
+/// Get the remaining health of a destructible prop (0.0 once broken).
+pub extern "C" fn destructible_health(handle: u32) f32;
+
+/// Restore a destructible prop to full health.
+pub extern "C" fn destructible_reset(handle: u32) void;
+
 // =============================================================================
 // Unified Music API (PCM + Tracker)
 // =============================================================================
@@ -866,6 +1573,62 @@ pub extern "C" fn music_is_playing() u32;
 /// 0 = none, 1 = PCM, 2 = tracker
 pub extern "C" fn music_type() u32;
 
+/// Play layered music made of up to 4 stems that stay in lockstep.
+/// 
+/// Stems share one playhead, so fading layers in and out never puts them
+/// out of sync. Layer 0 starts at full volume, the others start silent.
+/// All stems should be the same length as layer 0.
+/// 
+/// # Arguments
+/// * `sounds_ptr` — Pointer to an array of sound handles (from load_sound)
+/// * `count` — Number of stems (1-4)
+/// * `volume` — Overall music volume, 0.0 to 1.0
+/// * `looping` — 1 = loop, 0 = play once
+pub extern "C" fn music_play_layers(sounds_ptr: [*]const u32, count: u32, volume: f32, looping: u32) void;
+
+/// Fade a stem of layered music to a new volume.
+/// 
+/// Use to ramp intensity with game state (wave number, race position, etc).
+/// A new call replaces the layer's current fade.
+/// 
+/// # Arguments
+/// * `layer` — Stem index (0-3)
+/// * `volume` — Target volume, 0.0 to 1.0
+/// * `fade_ticks` — Length of the fade in ticks (0 = instant)
+pub extern "C" fn music_layer_volume(layer: u32, volume: f32, fade_ticks: u32) void;
+
+/// Configure the music beat clock.
+/// 
+/// Tracker music has a beat every `rows_per_beat` rows and follows tempo
+/// changes in the song. PCM music has no tempo, so it has no beats until
+/// `bpm` is set. Settings persist across music changes.
+/// 
+/// # Arguments
+/// * `bpm` — Tempo of PCM music (0 = no beats for PCM music)
+/// * `beats_per_bar` — Beats per bar (0 = default 4)
+/// * `rows_per_beat` — Tracker rows per beat (0 = default 4)
+pub extern "C" fn music_beat_config(bpm: f32, beats_per_bar: u32, rows_per_beat: u32) void;
+
+/// Get the beat index since the music started.
+/// 
+/// Each new beat is also queued as an `event_kind::MUSIC_BEAT` event.
+/// 
+/// # Returns
+/// Beat index (0 = first beat), or 0 if no music with a beat clock is playing
+pub extern "C" fn music_beat() u32;
+
+/// Get the bar index since the music started.
+/// 
+/// # Returns
+/// music_beat() / beats per bar
+pub extern "C" fn music_bar() u32;
+
+/// Get the progress through the current beat, for pulsing visuals.
+/// 
+/// # Returns
+/// 0.0 on the beat, rising toward 1.0 just before the next beat
+pub extern "C" fn music_beat_phase() f32;
+
 /// Jump to a specific position (tracker only, no-op for PCM).
 /// 
 /// Use for dynamic music systems (e.g., jump to outro pattern).
@@ -931,6 +1694,129 @@ pub extern "C" fn music_info(handle: u32) u32;
 /// Actual length written (0 if PCM or invalid handle).
 pub extern "C" fn music_name(handle: u32, out_ptr: [*]u8, max_len: u32) u32;
 
+/// Play a stream (from rom_stream) from the beginning.
+/// 
+/// Streams play on their own voice, independent of `music_play()`.
+/// Replaces any stream already playing.
+/// 
+/// # Arguments
+/// * `handle` — Stream handle from rom_stream
+/// * `volume` — 0.0 to 1.0
+/// * `looping` — 1 = loop, 0 = play once
+pub extern "C" fn stream_play(handle: u32, volume: f32, looping: u32) void;
+
+/// Stop the stream.
+pub extern "C" fn stream_stop() void;
+
+/// Pause or resume the stream.
+/// 
+/// # Arguments
+/// * `paused` — 1 = pause, 0 = resume
+pub extern "C" fn stream_pause(paused: u32) void;
+
+/// Move the stream playhead.
+/// 
+/// # Arguments
+/// * `seconds` — Position from the start of the stream
+pub extern "C" fn stream_seek(seconds: f32) void;
+
+/// Get the stream playhead.
+/// 
+/// # Returns
+/// Position in seconds from the start of the stream (0.0 if no stream).
+pub extern "C" fn stream_position() f32;
+
+// =============================================================================
+// Navigation (Pathfinding)
+// =============================================================================
+
+/// Bake a navigation mesh from a loaded mesh (init-only).
+/// 
+/// Call after loading the mesh. Triangles steeper than 45° are not walkable,
+/// and gaps narrower than the agent's diameter are treated as walls.
+/// 
+/// # Arguments
+/// * `mesh_handle` — Mesh handle from `load_mesh*()`, `rom_mesh()` or a mesh generator
+/// * `agent_radius` — Radius of the agents that will walk the mesh
+/// 
+/// # Returns
+/// Navmesh handle (>0) on success, 0 on failure.
+pub extern "C" fn navmesh_bake(mesh_handle: u32, agent_radius: f32) u32;
+
+/// Find an obstacle-aware path across a navmesh.
+/// 
+/// Start and end are snapped onto the navmesh. The path includes both
+/// snapped endpoints and is truncated to `max_points`. Deterministic, so it
+/// is safe to call from `update()`.
+/// 
+/// # Arguments
+/// * `navmesh` — Navmesh handle from `navmesh_bake()`
+/// * `start_x`, `start_y`, `start_z` — Start position
+/// * `end_x`, `end_y`, `end_z` — Goal position
+/// * `out_points` — Output buffer of `max_points` × (x, y, z) f32
+/// * `max_points` — Capacity of the output buffer in points
+/// 
+/// # Returns
+/// Number of points written, or 0 if no path exists.
+pub extern "C" fn nav_path(navmesh: u32, start_x: f32, start_y: f32, start_z: f32, end_x: f32, end_y: f32, end_z: f32, out_points: [*]f32, max_points: u32) u32;
+
+/// Evaluate a spline path at a fraction of its length.
+/// 
+/// `t` follows arc length, so advancing it by `speed * dt / path_length()`
+/// each tick moves at constant speed. Deterministic, so it is safe to call
+/// from `update()`.
+/// 
+/// # Arguments
+/// * `handle` — Path handle from `rom_path()`
+/// * `t` — 0.0 (start) to 1.0 (end); clamped on open paths, wraps on closed ones
+/// * `out_pos` — Output (x, y, z) f32 position, or null to skip
+/// * `out_tangent` — Output (x, y, z) f32 unit direction of travel, or null to skip
+/// 
+/// # Returns
+/// 1 on success, 0 if the handle or a pointer is invalid.
+pub extern "C" fn path_eval(handle: u32, t: f32, out_pos: ?[*]f32, out_tangent: ?[*]f32) u32;
+
+/// Get the length of a spline path in world units.
+/// 
+/// # Returns
+/// Arc length, or 0.0 if the handle is invalid.
+pub extern "C" fn path_length(handle: u32) f32;
+
+/// Create the pathfinding grid, replacing any existing one.
+/// 
+/// Every cell starts with cost 1. The grid is rolled back with the game, so it
+/// can be created and edited from `update()`.
+/// 
+/// # Arguments
+/// * `width`, `height` — Size in cells (width × height at most 16384)
+/// 
+/// # Returns
+/// 1 on success, 0 if the size is invalid.
+pub extern "C" fn grid_create(width: u32, height: u32) u32;
+
+/// Set the cost of entering a grid cell.
+/// 
+/// # Arguments
+/// * `x`, `y` — Cell coordinates
+/// * `cost` — 0 = blocked, 1-255 = relative cost (1 = open ground)
+pub extern "C" fn grid_set_cost(x: u32, y: u32, cost: u32) void;
+
+/// Find the cheapest path between two grid cells.
+/// 
+/// Paths move in 8 directions without cutting the corners of blocked cells.
+/// The path includes the start and goal cells and is truncated to `max_cells`.
+/// Deterministic, so it is safe to call from `update()`.
+/// 
+/// # Arguments
+/// * `start_x`, `start_y` — Start cell (may be blocked)
+/// * `goal_x`, `goal_y` — Goal cell
+/// * `out_cells` — Output buffer of `max_cells` × (x, y) u32
+/// * `max_cells` — Capacity of the output buffer in cells
+/// 
+/// # Returns
+/// Number of cells written, or 0 if no path exists.
+pub extern "C" fn grid_path(start_x: u32, start_y: u32, goal_x: u32, goal_y: u32, out_cells: [*]u32, max_cells: u32) u32;
+
 // =============================================================================
 // Render Pass Functions (Execution Barriers & Depth/Stencil Control)
 // =============================================================================
@@ -1004,6 +1890,61 @@ pub extern "C" fn begin_pass_stencil_test(ref_value: u32, clear_depth: u32) void
 /// * `stencil_depth_fail_op` — Operation when depth test fails
 pub extern "C" fn begin_pass_full(depth_compare: u32, depth_write: u32, clear_depth: u32, stencil_compare: u32, stencil_ref: u32, stencil_pass_op: u32, stencil_fail_op: u32, stencil_depth_fail_op: u32) void;
 
+// =============================================================================
+// 2D Physics
+// =============================================================================
+
+/// Create a host-simulated 2D physics body.
+/// 
+/// Bodies move by their velocity (plus gravity) on each `physics_step()` and are pushed
+/// out of each other. The world is rolled back, so create bodies from `update()`.
+/// 
+/// # Arguments
+/// * `shape` — `body_shape::AABB` or `body_shape::CIRCLE`
+/// * `x`, `y` — Center position
+/// * `w`, `h` — Box width and height; for circles `w` is the radius and `h` is ignored
+/// * `flags` — `body_flag::*` (static bodies never move, sensors only report contacts)
+/// 
+/// # Returns
+/// Body handle (>0) on success, 0 if the world (128 bodies) is full or the shape is invalid.
+pub extern "C" fn body_create(shape: u32, x: f32, y: f32, w: f32, h: f32, flags: u32) u32;
+
+/// Remove a physics body.
+pub extern "C" fn body_destroy(handle: u32) void;
+
+/// Set a body's velocity in units per second.
+/// 
+/// Static bodies keep the velocity but never move.
+pub extern "C" fn body_velocity(handle: u32, vx: f32, vy: f32) void;
+
+/// Move a body without changing its velocity (spawns, teleports, moving platforms).
+pub extern "C" fn body_set_position(handle: u32, x: f32, y: f32) void;
+
+/// Get a body's center X position.
+/// 
+/// # Returns
+/// X position, or 0.0 if the handle is invalid.
+pub extern "C" fn body_x(handle: u32) f32;
+
+/// Get a body's center Y position.
+/// 
+/// # Returns
+/// Y position, or 0.0 if the handle is invalid.
+pub extern "C" fn body_y(handle: u32) f32;
+
+/// Get the number of live physics bodies.
+pub extern "C" fn body_count() u32;
+
+/// Set the acceleration applied to moving bodies in units per second² (default 0, 0).
+pub extern "C" fn physics_gravity(x: f32, y: f32) void;
+
+/// Move every body by one tick and resolve overlaps.
+/// 
+/// Call once per `update()`; the step length follows the tick rate. Each touching pair is
+/// passed to your `on_contact(a, b)` export (handles, `a < b`, in handle order) before this
+/// returns, and queued as an `event_kind::BODY_CONTACT` event.
+pub extern "C" fn physics_step() void;
+
 // =============================================================================
 // Procedural Mesh Generation (init-only)
 // =============================================================================
@@ -1093,6 +2034,104 @@ pub extern "C" fn cube_tangent(size_x: f32, size_y: f32, size_z: f32) u32;
 /// Tangent follows the major circle direction.
 pub extern "C" fn torus_tangent(major_radius: f32, minor_radius: f32, major_segments: u32, minor_segments: u32) u32;
 
+/// Combine two meshes into one covering the space inside either. **Init-only.**
+/// 
+/// Both meshes must be created earlier in `init()` and should be closed.
+/// The result is a new flat-shaded mesh without UVs; the inputs are unchanged.
+/// 
+/// Returns a mesh handle (>0), or 0 on failure.
+pub extern "C" fn mesh_union(a: u32, b: u32) u32;
+
+/// Carve mesh `b` out of mesh `a` (tunnels, damage holes). **Init-only.**
+/// 
+/// Both meshes must be created earlier in `init()` and should be closed.
+/// The result is a new flat-shaded mesh without UVs; the inputs are unchanged.
+/// 
+/// Returns a mesh handle (>0), or 0 on failure.
+pub extern "C" fn mesh_subtract(a: u32, b: u32) u32;
+
+/// Keep only the space inside both meshes. **Init-only.**
+/// 
+/// Both meshes must be created earlier in `init()` and should be closed.
+/// The result is a new flat-shaded mesh without UVs; the inputs are unchanged.
+/// 
+/// Returns a mesh handle (>0), or 0 on failure.
+pub extern "C" fn mesh_intersect(a: u32, b: u32) u32;
+
+/// Recompute a mesh's normals from its triangles. **Init-only.**
+/// 
+/// Edges where faces meet at more than `smooth_angle_deg` stay sharp
+/// (0 = flat shading, 180 = smooth everywhere). Use after displacing vertices.
+/// The mesh must be created earlier in `init()`; it gains normals if it had
+/// none, and its tangents (if any) are regenerated to match.
+/// 
+/// Returns 1 on success, 0 on failure.
+pub extern "C" fn mesh_recompute_normals(handle: u32, smooth_angle_deg: f32) u32;
+
+/// Generate tangents from a mesh's UVs and normals for normal mapping. **Init-only.**
+/// 
+/// The mesh must be created earlier in `init()` and have UVs and normals.
+/// Call after `mesh_recompute_normals()` if the mesh was displaced.
+/// 
+/// Returns 1 on success, 0 on failure.
+pub extern "C" fn mesh_generate_tangents(handle: u32) u32;
+
+// =============================================================================
+// Projectiles
+// =============================================================================
+
+/// Spawn a host-simulated projectile.
+/// 
+/// Projectiles fly in a straight line and are collided against the targets
+/// passed to `projectile_step()`. The pool is rolled back, so spawn from `update()`.
+/// 
+/// # Arguments
+/// * `desc_ptr` — Pointer to a 48-byte description: position (3 × f32),
+/// velocity (3 × f32, units/s), radius (f32), lifetime in ticks (u32, 0 = until it hits),
+/// flags (u32, `projectile_flag::*`), max_hits (u32), target_mask (u32), user_data (u32)
+/// 
+/// # Returns
+/// Projectile handle (>0) on success, 0 if the pool (256) is full.
+pub extern "C" fn projectile_spawn(desc_ptr: [*]const u8) u32;
+
+/// Remove a projectile before it hits anything or expires.
+pub extern "C" fn projectile_despawn(handle: u32) void;
+
+/// Move every projectile by one tick and collide it with targets.
+/// 
+/// Call once per `update()`, then read hits with `projectile_next_hit()`.
+/// Each projectile hits at most one target per step, and never the same target twice in a row.
+/// 
+/// # Arguments
+/// * `targets_ptr` — Pointer to `target_count` 20-byte targets: position (3 × f32),
+/// radius (f32), layers (u32, matched against each projectile's target_mask)
+/// * `target_count` — Number of targets (up to 4096)
+pub extern "C" fn projectile_step(targets_ptr: [*]const u8, target_count: u32) void;
+
+/// Read the next hit from the last `projectile_step()`.
+/// 
+/// # Arguments
+/// * `out_ptr` — Pointer to a 24-byte hit: projectile handle (u32), target index (u32),
+/// user_data (u32), impact position (3 × f32)
+/// 
+/// # Returns
+/// 1 if a hit was written, 0 when there are no more hits.
+pub extern "C" fn projectile_next_hit(out_ptr: [*]u8) u32;
+
+/// List live projectiles for drawing.
+/// 
+/// # Arguments
+/// * `out_ptr` — Pointer to `max_count` 20-byte entries: handle (u32), user_data (u32),
+/// position (3 × f32)
+/// * `max_count` — Capacity of the output buffer in entries
+/// 
+/// # Returns
+/// Number of entries written.
+pub extern "C" fn projectile_list(out_ptr: [*]u8, max_count: u32) u32;
+
+/// Get the number of live projectiles.
+pub extern "C" fn projectile_count() u32;
+
 // =============================================================================
 // Render State Functions
 // =============================================================================
@@ -1161,6 +2200,114 @@ pub extern "C" fn dither_offset(x: u32, y: u32) void;
 /// Default: 0 (resets each frame)
 pub extern "C" fn z_index(n: u32) void;
 
+/// Set how 2D draws combine with what is already on screen.
+/// 
+/// # Arguments
+/// * `mode` — `blend_mode::ALPHA` (default), `ADDITIVE`, `MULTIPLY`, `SCREEN` or `SUBTRACT`
+/// 
+/// Affects screen-space sprites, rects, lines and text until changed.
+/// Blended draws composite over everything else at their z-index (3D and the
+/// environment included) and never write depth. Alpha still dithers in every mode.
+/// Default: alpha (resets each frame)
+pub extern "C" fn blend_mode_2d(mode: u32) void;
+
+/// Set how 3D draws combine with what is already on screen.
+/// 
+/// # Arguments
+/// * `mode` — `blend_mode::ALPHA` (default), `ADDITIVE`, `MULTIPLY`, `SCREEN` or `SUBTRACT`
+/// 
+/// Affects meshes and `draw_triangles()` until changed. Blended draws render after
+/// the opaque scene and the environment, sorted back to front within each viewport,
+/// and never write depth. Alpha still dithers in every mode.
+/// Default: alpha (resets each frame)
+pub extern "C" fn blend_mode_3d(mode: u32) void;
+
+/// Offset the sort distance of blended 3D draws.
+/// 
+/// # Arguments
+/// * `bias` — World units added to the view distance (positive = further back,
+/// negative = on top)
+/// 
+/// Use when automatic sorting picks the wrong order, e.g. a hazard circle under a
+/// larger translucent dome.
+/// Default: 0.0 (resets each frame)
+pub extern "C" fn sort_bias(bias: f32) void;
+
+/// Get statistics for the last rendered frame.
+/// 
+/// Writes draw_calls, triangles, triangles_culled, texture_binds (4 × u32)
+/// to `out_ptr`. `triangles_culled` counts `draw_mesh()` triangles skipped
+/// by frustum culling. Peers render different frames, so use these for
+/// display or profiling only, never for game logic.
+/// 
+/// Returns 1 on success, 0 if `out_ptr` is invalid.
+pub extern "C" fn render_stats(out_ptr: [*]u32) u32;
+
+/// Enable the screen-space ambient occlusion post pass.
+/// 
+/// Darkens creases and contact points of 3D geometry in every viewport that
+/// drew meshes. Screen-space 2D and the sky are not affected. Persists across
+/// frames until changed.
+/// 
+/// # Arguments
+/// * `radius` — Sample radius in world units (how far creases reach)
+/// * `intensity` — Occlusion strength (0 = none, 1 = full)
+/// * `enabled` — Non-zero to enable, 0 to disable
+pub extern "C" fn post_ssao(radius: f32, intensity: f32, enabled: u32) void;
+
+// =============================================================================
+// Teams & Scoreboard
+// =============================================================================
+
+/// Assign a player to a team.
+/// 
+/// Teams are rolled back with the game, so this is safe to call from `update()`.
+/// 
+/// # Arguments
+/// * `player` — Player index (0-7)
+/// * `team` — Team number (1-8), or 0 for no team
+pub extern "C" fn team_set(player: u32, team: u32) void;
+
+/// Get a player's team.
+/// 
+/// # Returns
+/// Team number (1-8), or 0 if the player has no team.
+pub extern "C" fn team_get(player: u32) u32;
+
+/// Get a team's score (the sum of its players' scores).
+pub extern "C" fn team_score(team: u32) i32;
+
+/// Add to a player's score (negative values subtract).
+/// 
+/// Ignored once the match has ended.
+pub extern "C" fn score_add(player: u32, delta: i32) void;
+
+/// Set a player's score.
+/// 
+/// Ignored once the match has ended.
+pub extern "C" fn score_set(player: u32, score: i32) void;
+
+/// Get a player's score.
+pub extern "C" fn score_get(player: u32) i32;
+
+/// End the match, freezing all scores.
+pub extern "C" fn match_end() void;
+
+/// Check if the match has ended.
+/// 
+/// # Returns
+/// 1 if `match_end()` has been called since the last reset, 0 otherwise.
+pub extern "C" fn match_is_over() u32;
+
+/// Reset all scores and start a new match. Team assignments are kept.
+pub extern "C" fn match_reset() void;
+
+/// Draw the built-in scoreboard overlay.
+/// 
+/// Lists players ranked by team score, then player score, centered in the
+/// current viewport. Call from `render()`; color and z-index are preserved.
+pub extern "C" fn scoreboard_draw() void;
+
 // =============================================================================
 // GPU Skinning
 // =============================================================================
@@ -1225,6 +2372,70 @@ pub extern "C" fn set_bones(matrices_ptr: [*]const f32, count: u32) void;
 /// ```
 pub extern "C" fn set_bones_4x4(matrices_ptr: [*]const f32, count: u32) void;
 
+// =============================================================================
+// World Streaming
+// =============================================================================
+
+/// Set the slot pool streamed chunks are copied into (init-only).
+/// 
+/// The pool is a `slot_size * slot_count` byte buffer in game memory that the
+/// game must not use for anything else. Call before `chunk_register()`.
+/// 
+/// # Arguments
+/// * `ptr` — Start of the pool
+/// * `slot_size` — Bytes per slot; every registered chunk must fit in one
+/// * `slot_count` — Number of slots (1..=64)
+/// 
+/// # Returns
+/// 1 on success, 0 on invalid input.
+pub extern "C" fn chunk_pool(ptr: [*]u8, slot_size: u32, slot_count: u32) u32;
+
+/// Set the chunk grid and streaming distances (init-only).
+/// 
+/// Cell (x, z) spans `x * cell_size` to `(x + 1) * cell_size` on the X and Z axes.
+/// Chunks load when their center comes within `load_radius` of the focus and unload
+/// once it is further than `unload_radius`, so crossing a border back and forth
+/// doesn't reload anything.
+/// 
+/// # Arguments
+/// * `cell_size` — World units per cell (default 64)
+/// * `load_radius` — Load distance (default 96)
+/// * `unload_radius` — Unload distance, at least `load_radius` (default 128)
+/// 
+/// # Returns
+/// 1 on success, 0 on invalid input.
+pub extern "C" fn chunk_grid(cell_size: f32, load_radius: f32, unload_radius: f32) u32;
+
+/// Register the chunk at grid cell (x, z) (init-only).
+/// 
+/// # Arguments
+/// * `x`, `z` — Grid cell
+/// * `id_ptr` — Pointer to the ROM data asset ID holding the chunk's bytes
+/// * `id_len` — Length of the asset ID
+/// 
+/// # Returns
+/// Chunk handle (>0) on success, 0 if the asset is missing or larger than a slot.
+pub extern "C" fn chunk_register(x: i32, z: i32, id_ptr: [*]const u8, id_len: u32) u32;
+
+/// Stream chunks around a focus point (usually the camera or the local player).
+/// 
+/// Loads nearby chunks into free slots, nearest first, and frees slots of chunks
+/// that moved out of range. Residency is rolled back, so call from `update()`.
+/// Each load and unload is queued for `event_poll()` (`event_kind::CHUNK_*`).
+/// 
+/// # Returns
+/// Number of loaded chunks.
+pub extern "C" fn chunk_update(x: f32, z: f32) u32;
+
+/// Get a loaded chunk's data.
+/// 
+/// # Returns
+/// Pointer to the chunk's slot in the pool, or 0 if the cell isn't loaded.
+pub extern "C" fn chunk_data(x: i32, z: i32) u32;
+
+/// Get the number of loaded chunks.
+pub extern "C" fn chunk_loaded_count() u32;
+
 // =============================================================================
 // System Functions
 // =============================================================================
@@ -1262,6 +2473,24 @@ pub extern "C" fn log(ptr: [*]const u8, len: u32) void;
 /// Exits the game and returns to the library.
 pub extern "C" fn quit() void;
 
+/// Writes the 32-byte content hash of the running ROM.
+/// 
+/// The hash is SHA-256 of the ROM file, so it identifies an exact build
+/// (useful for showing a build identifier in menus). Netplay peers must
+/// have matching hashes.
+/// 
+/// # Returns
+/// 1 on success, 0 if the hash is unknown (nothing is written).
+pub extern "C" fn rom_hash(out_ptr: [*]u8) u32;
+
+/// Records an analytics event, e.g. `"difficulty"` = 2 or `"car"` = 5.
+/// 
+/// `name` must be 1-32 characters of `a-z`, `0-9`, `_`, `.` or `-`.
+/// The host only keeps a count per (name, value) pair, and only sends the
+/// totals to the developer if the player opted in to analytics in the
+/// privacy settings. Always safe to call; there is no return value.
+pub extern "C" fn analytics_event(name_ptr: [*]const u8, name_len: u32, value: i32) void;
+
 /// Returns a deterministic random u32 from the host's seeded RNG.
 /// Always use this instead of external random sources for rollback compatibility.
 pub extern "C" fn random() u32;
@@ -1278,7 +2507,76 @@ pub extern "C" fn random_f32() f32;
 /// Uses host's seeded RNG for rollback compatibility.
 pub extern "C" fn random_f32_range(min: f32, max: f32) f32;
 
-/// Returns the number of players in the session (1-4).
+/// Creates an independent RNG stream seeded with `seed`.
+/// Draws from a stream never affect `random()` or other streams, so cosmetic
+/// rolls can't shift gameplay rolls. Streams are restored by rollback; up to 16 can exist.
+/// 
+/// # Returns
+/// Stream handle, or 0 if all streams are in use.
+pub extern "C" fn rng_create(seed: u64) u32;
+
+/// Returns the next random u32 from a stream (0 for an invalid handle).
+pub extern "C" fn rng_next(handle: u32) u32;
+
+/// Returns a random i32 in range [min, max) from a stream.
+pub extern "C" fn rng_range(handle: u32, min: i32, max: i32) i32;
+
+/// Frees a stream so its slot can be reused.
+/// 
+/// # Returns
+/// 1 if the stream existed, 0 otherwise.
+pub extern "C" fn rng_free(handle: u32) u32;
+
+/// Calls the game's exported `on_timer(callback_id)` once, `ticks` updates from now.
+/// 
+/// Timers run on the deterministic tick (before `update()`) and are restored
+/// by rollback, so they are safe for netplay. Up to 32 timers can be pending.
+/// 
+/// # Returns
+/// Timer handle for `timer_cancel()`, or 0 if all timers are in use.
+pub extern "C" fn timer_after(ticks: u32, callback_id: u32) u32;
+
+/// Calls the game's exported `on_timer(callback_id)` every `ticks` updates until cancelled.
+/// 
+/// # Returns
+/// Timer handle for `timer_cancel()`, or 0 if all timers are in use.
+pub extern "C" fn timer_every(ticks: u32, callback_id: u32) u32;
+
+/// Cancels a pending timer.
+/// 
+/// # Returns
+/// 1 if the timer was pending, 0 otherwise.
+pub extern "C" fn timer_cancel(handle: u32) u32;
+
+/// Pops up to `max` of the oldest unread host events into `out_ptr`.
+/// 
+/// Each event is a 36-byte record: `kind: u32`, `data: [u32; 5]`,
+/// `position: [f32; 3]`. See `event_kind` for the kinds and their data.
+/// Events are restored by rollback, so poll them from `update()`. Up to 64
+/// events are queued; older events are overwritten when the queue is full.
+/// 
+/// # Returns
+/// The number of events written.
+pub extern "C" fn event_poll(out_ptr: [*]u8, max: u32) u32;
+
+/// Registers a region of game memory to be saved and restored on rollback.
+/// 
+/// By default rollback snapshots all of linear memory. Once any region is
+/// registered, only registered regions are snapshotted, so keep every value
+/// `update()` changes inside one. Init-only; up to 64 regions, which must not
+/// overlap.
+/// 
+/// # Returns
+/// 1 on success, 0 if the region is empty, out of bounds, overlaps another, or
+/// init() has finished.
+pub extern "C" fn state_register(ptr: [*]const u8, len: u32) u32;
+
+/// Returns the number of players in the session (1-8).
+/// 
+/// Controllers connecting and disconnecting mid-game call the optional
+/// `on_player_join(player)` / `on_player_leave(player)` exports at the start of
+/// the tick, before `update()`. Controllers connected at startup join on the
+/// first tick.
 pub extern "C" fn player_count() u32;
 
 /// Returns a bitmask of which players are local to this client.
@@ -1286,6 +2584,173 @@ pub extern "C" fn player_count() u32;
 /// Example: `(local_player_mask() & (1 << player_id)) != 0` checks if player is local.
 pub extern "C" fn local_player_mask() u32;
 
+/// Writes the name of a local player's controller to `out_ptr` (UTF-8).
+/// 
+/// Names are "Keyboard" or the name the gamepad reports. They are only known
+/// for local players and differ between machines, so use them for display
+/// ("P3 joined: DualSense") and never for game logic.
+/// 
+/// # Returns
+/// Bytes written (at most `max`), or 0 for remote players and empty slots.
+pub extern "C" fn controller_name(player: u32, out_ptr: [*]u8, max: u32) u32;
+
+/// Sets how a remote player's input is predicted while it is in flight.
+/// 
+/// Rollback repeats a player's last input until their real input arrives,
+/// which makes a missed dodge or dash replay for several ticks. Pick a
+/// policy per player (see `predict_policy::*`):
+/// * `HOLD` (default) — repeat the last input
+/// * `NEUTRAL` — release every button and center the sticks
+/// * `CUSTOM` — call the game's `predict_input(player, ticks) -> *const u8`
+/// export, which returns a pointer to a predicted input in the console's
+/// input layout, or 0 to keep holding. `ticks` counts ticks past the last
+/// confirmed input (1 = first predicted tick); the player's held input is
+/// readable through the normal input functions.
+/// 
+/// `predict_input()` must not change game state: it is not rolled back.
+pub extern "C" fn predict_policy(player: u32, policy: u32) void;
+
+/// Enables (1) or disables (0) voice chat for a player.
+/// 
+/// For the local player this turns microphone capture and playback on or off;
+/// for a remote player it controls whether their voice is played (on by default).
+/// Voice is opt-in and presentation-only: it never affects the simulation,
+/// so it is safe to call from `update()`. Has no effect outside netplay.
+pub extern "C" fn voice_enable(player: u32, on: u32) void;
+
+/// Disables voice chat for the local player and drops any buffered audio.
+pub extern "C" fn voice_disable() void;
+
+/// Returns 1 if voice chat is enabled for the local player, 0 otherwise.
+pub extern "C" fn voice_is_enabled() u32;
+
+/// Mutes (1) or unmutes (0) incoming voice from a player.
+pub extern "C" fn voice_set_muted(player: u32, muted: u32) void;
+
+/// Returns 1 if the player is muted, 0 otherwise.
+pub extern "C" fn voice_is_muted(player: u32) u32;
+
+/// Returns 1 if voice from a remote player is currently playing, 0 otherwise.
+/// 
+/// Useful for drawing a speaking indicator next to the player.
+pub extern "C" fn voice_is_speaking(player: u32) u32;
+
+/// Enables positional attenuation: speakers fade out linearly with distance
+/// and are silent beyond `max_distance` world units. Pass 0 to disable.
+/// 
+/// Positions follow `listener_set_n()`; the two calls below override them.
+pub extern "C" fn voice_set_positional(max_distance: f32) void;
+
+/// Sets the listener position used for positional attenuation.
+pub extern "C" fn voice_set_listener(x: f32, y: f32, z: f32) void;
+
+/// Sets a player's position used for positional attenuation.
+pub extern "C" fn voice_set_player_position(player: u32, x: f32, y: f32, z: f32) void;
+
+/// Sends a UTF-8 text message to all players (truncated to 128 bytes).
+/// 
+/// Your own messages are echoed back through `chat_poll()`, so a chat log
+/// renders the same way in local and online sessions.
+pub extern "C" fn chat_send(ptr: [*]const u8, len: u32) void;
+
+/// Pops the oldest unread chat message into a 136-byte record.
+/// 
+/// Record layout: `sender: u32`, `len: u32`, `text: [u8; 128]` (UTF-8).
+/// Returns 1 if a message was written, 0 if there are none.
+pub extern "C" fn chat_poll(out_ptr: [*]u8) u32;
+
+/// Sends a canned quick-chat message by index (0-7).
+/// 
+/// 0 Help!, 1 On my way!, 2 Wait for me!, 3 Go go go!,
+/// 4 Nice!, 5 Thanks!, 6 Sorry!, 7 Good game!
+pub extern "C" fn quick_chat(id: u32) void;
+
+/// Places a world-space ping visible to all players (see `ping_kind::*`).
+pub extern "C" fn ping(x: f32, y: f32, z: f32, kind: u32) void;
+
+/// Pops the oldest unread ping into a 20-byte record.
+/// 
+/// Record layout: `sender: u32`, `kind: u32`, `x: f32`, `y: f32`, `z: f32`.
+/// Returns 1 if a ping was written, 0 if there are none.
+pub extern "C" fn ping_poll(out_ptr: [*]u8) u32;
+
+/// Hosts an in-game lobby named by the UTF-8 string at `name_ptr`.
+/// 
+/// `max_players` is clamped between 2 and the ROM's `max_players`. Progress is
+/// reported through `lobby_state()` and the optional `on_lobby_state(state)`
+/// export. Lobbies are only available in the standalone player with a
+/// rendezvous server configured; returns 1 if the request was queued.
+pub extern "C" fn lobby_create(name_ptr: [*]const u8, name_len: u32, max_players: u32) u32;
+
+/// Writes up to `max` open lobbies for this ROM as 40-byte records.
+/// 
+/// Record layout: `id: u32`, `players: u8`, `max_players: u8`,
+/// `name_len: u8`, pad `u8`, `name: [u8; 32]` (UTF-8). Each call also
+/// requests a fresh listing, so results lag by a moment.
+/// 
+/// # Returns
+/// The number of lobbies written.
+pub extern "C" fn lobby_list(out_ptr: [*]u8, max: u32) u32;
+
+/// Joins a lobby by its id from `lobby_list()`. Returns 1 if queued.
+pub extern "C" fn lobby_join(id: u32) u32;
+
+/// Starts the match (host only, once another player has joined).
+/// 
+/// Every player's game then restarts from `init()` as a netplay session.
+/// Returns 1 if queued, 0 if the local player isn't hosting.
+pub extern "C" fn lobby_start() u32;
+
+/// Leaves the current lobby (closes it when hosting).
+pub extern "C" fn lobby_leave() void;
+
+/// Returns the current lobby state (see `lobby_state::*`).
+pub extern "C" fn lobby_state() u32;
+
+/// Returns the number of players in the current lobby, including you.
+pub extern "C" fn lobby_player_count() u32;
+
+/// Writes the tournament series standings into a 44-byte record.
+/// 
+/// Record layout (all `u32`): `best_of`, `round` (1-based), `champion`
+/// (player index, or 0xFF while undecided), then `wins[8]` per player.
+/// Returns 1 if the game is running as part of a series, 0 otherwise.
+/// 
+/// Standings only change between rounds; each round is a fresh session.
+pub extern "C" fn series_state(out_ptr: [*]u8) u32;
+
+/// Reports the winner of the current series round (0xFF for a draw).
+/// 
+/// The host loads the next round after this frame unless the series has
+/// been decided, in which case the game keeps running with the final
+/// standings. Only the first report per round counts.
+pub extern "C" fn series_round_end(winner: u32) void;
+
+/// Returns the seed for today's daily challenge.
+/// 
+/// Every player gets the same seed on the same UTC day, so runs generated
+/// from it are identical for everybody. Fixed when the game is loaded.
+pub extern "C" fn daily_seed() u64;
+
+/// Writes today's daily leaderboard category (`daily-YYYY-MM-DD`, 16 bytes).
+/// 
+/// Returns bytes written (at most `max_len`).
+pub extern "C" fn daily_category(out_ptr: [*]u8, max_len: u32) u32;
+
+/// Grants a cross-ROM unlock token to every game from this publisher.
+/// 
+/// The publisher is the registry author the ROM was downloaded from, so
+/// games can only grant and read their own publisher's tokens. Grants are
+/// permanent and saved immediately. Returns 1 if the token is now held, 0 if
+/// tokens are unavailable (e.g. a sideloaded ROM). Always 1 in netplay.
+pub extern "C" fn token_grant(id: u32) u32;
+
+/// Checks whether any game from this publisher has granted a token.
+/// 
+/// Returns 1 if held, 0 otherwise. Always 0 in netplay, where each peer
+/// has its own tokens.
+pub extern "C" fn token_has(id: u32) u32;
+
 /// Saves data to a slot.
 /// 
 /// Slot semantics:
@@ -1327,12 +2792,102 @@ pub extern "C" fn load(slot: u32, data_ptr: [*]u8, max_len: u32) u32;
 /// 0 on success, 1 if invalid slot.
 pub extern "C" fn delete(slot: u32) u32;
 
+/// Registers the transform the host records for ghosts.
+/// 
+/// `transform_ptr` points to 7 `f32` values in game memory: position
+/// (x, y, z) followed by a rotation quaternion (x, y, z, w). While a
+/// recording is active the host copies it after every `update()`.
+/// Pass 0 to unregister.
+pub extern "C" fn ghost_register(transform_ptr: [*]const f32) void;
+
+/// Starts recording the registered transform into a ghost slot.
+/// 
+/// # Arguments
+/// * `slot` — Ghost slot (0-3)
+/// 
+/// # Returns
+/// 0 on success, 1 if invalid slot, 2 if no transform is registered.
+pub extern "C" fn ghost_record_begin(slot: u32) u32;
+
+/// Stops recording.
+/// 
+/// If `save` is non-zero the recording replaces the ghost in its slot and
+/// is written alongside the save data, so best-lap ghosts survive restarts.
+/// Otherwise the recording is discarded.
+/// 
+/// # Returns
+/// Number of recorded frames (0 if nothing was recording).
+pub extern "C" fn ghost_record_end(save: u32) u32;
+
+/// Returns the number of frames in a ghost slot (0 if empty).
+pub extern "C" fn ghost_frames(slot: u32) u32;
+
+/// Writes the transform recorded `tick` ticks into a ghost.
+/// 
+/// Writes 7 `f32` values to `out_ptr` in the same layout as
+/// `ghost_register()`.
+/// 
+/// # Returns
+/// 1 on success, 0 if the slot is empty or `tick` is past the end.
+pub extern "C" fn ghost_playback(slot: u32, tick: u32, out_ptr: [*]f32) u32;
+
+/// Capture the frame being drawn into a thumbnail slot.
+/// 
+/// The host shrinks the finished frame to 160x90 and stores it in the slot once
+/// the current frame has been rendered, so call this from `render()` on the frame
+/// you want kept (e.g. right after saving). Thumbnails are kept next to the
+/// game's save data and are not rolled back.
+/// 
+/// # Arguments
+/// * `slot` — Thumbnail slot (0-7)
+/// 
+/// # Returns
+/// 0 on success, 1 if the slot is invalid.
+pub extern "C" fn thumbnail_capture(slot: u32) u32;
+
+/// Returns 1 if a thumbnail slot holds a capture, 0 if it is empty or invalid.
+pub extern "C" fn thumbnail_exists(slot: u32) u32;
+
+/// Empty a thumbnail slot (e.g. when its save is deleted).
+/// 
+/// # Returns
+/// 0 on success, 1 if the slot is invalid.
+pub extern "C" fn thumbnail_clear(slot: u32) u32;
+
+/// Set the tick rate (how often `update()` runs). Must be called during `init()`.
+/// 
+/// Rollback windows and input delay are counted in ticks, so at 120 Hz the same
+/// 8-tick rollback window covers half the latency it does at 60 Hz (and re-simulating
+/// it costs twice the CPU per second); at 30 Hz it covers twice the latency.
+/// Online sessions always run at the ROM's netplay tick rate; 24 Hz is local-only.
+/// 
+/// # Arguments
+/// * `rate` — `tick_rate::HZ_24`, `HZ_30`, `HZ_60` (default) or `HZ_120`
+pub extern "C" fn set_tick_rate(rate: u32) void;
+
 /// Set the clear/background color. Must be called during `init()`.
 /// 
 /// # Arguments
 /// * `color` — Color in 0xRRGGBBAA format (default: black)
 pub extern "C" fn set_clear_color(color: u32) void;
 
+/// Enable PS1-style retro rendering for all 3D meshes. Must be called during `init()`.
+/// 
+/// # Arguments
+/// * `vertex_snap_bits` — 0 = off, N = snap vertices to a screen grid 2^N cells tall (0-10, 8 ≈ 455x256)
+/// * `affine_textures` — 0 = perspective-correct (default), 1 = affine texture warping
+pub extern "C" fn retro_mode(vertex_snap_bits: u32, affine_textures: u32) void;
+
+/// Constrain the final image to a palette. Must be called during `init()`.
+/// 
+/// Each pixel of the palette texture (row-major, alpha ignored) is one color, up to 256.
+/// The texture must be uncompressed RGBA8 and loaded during `init()`.
+/// 
+/// # Arguments
+/// * `texture` — Palette texture handle
+/// * `dither` — 0 = nearest color, 1 = 4x4 ordered dithering
+pub extern "C" fn palette_mode(texture: u32, dither: u32) void;
+
 // =============================================================================
 // 2D Drawing (Screen Space)
 // =============================================================================
@@ -1357,6 +2912,27 @@ pub extern "C" fn draw_sprite_region(x: f32, y: f32, w: f32, h: f32, src_x: f32,
 /// * `angle_deg` — Rotation angle in degrees (clockwise)
 pub extern "C" fn draw_sprite_ex(x: f32, y: f32, w: f32, h: f32, src_x: f32, src_y: f32, src_w: f32, src_h: f32, origin_x: f32, origin_y: f32, angle_deg: f32) void;
 
+/// Draw a texture as a nine-slice panel (corners keep their size at any panel size).
+/// 
+/// # Arguments
+/// * `texture` — Texture handle
+/// * `left`, `top`, `right`, `bottom` — Border widths in texture pixels
+/// * `color` — Tint color (0xRRGGBBAA)
+/// 
+/// Panels smaller than their borders shrink the borders to fit.
+pub extern "C" fn draw_nine_slice(texture: u32, x: f32, y: f32, w: f32, h: f32, left: f32, top: f32, right: f32, bottom: f32, color: u32) void;
+
+/// Draw a save slot thumbnail captured with `thumbnail_capture()`.
+/// 
+/// # Arguments
+/// * `slot` — Thumbnail slot (0-7)
+/// * `x`, `y` — Top-left corner in pixels
+/// * `w`, `h` — Size in pixels (thumbnails are 160x90)
+/// 
+/// Draws nothing if the slot is empty. Uses the current color and blend mode like
+/// `draw_sprite()`; the bound texture is preserved.
+pub extern "C" fn thumbnail_draw(slot: u32, x: f32, y: f32, w: f32, h: f32) void;
+
 /// Draw a solid color rectangle.
 pub extern "C" fn draw_rect(x: f32, y: f32, w: f32, h: f32) void;
 
@@ -1368,6 +2944,21 @@ pub extern "C" fn draw_rect(x: f32, y: f32, w: f32, h: f32) void;
 /// * `size` — Font size in pixels
 pub extern "C" fn draw_text(ptr: [*]const u8, len: u32, x: f32, y: f32, size: f32) void;
 
+/// Draw text along a path (arcs, rings, curved titles).
+/// 
+/// # Arguments
+/// * `ptr` — Pointer to UTF-8 string data
+/// * `len` — Length in bytes
+/// * `points_ptr` — Pointer to `count` × 2 f32 (x, y) screen points forming a polyline
+/// * `count` — Number of points (at least 2)
+/// * `size` — Font size in pixels
+/// * `color` — Text color (0xRRGGBBAA), for this call only
+/// 
+/// Glyphs follow the path from the first point, standing on its left side
+/// (above it when the path runs left to right). Text past the end of the
+/// path is cut off.
+pub extern "C" fn draw_text_path(ptr: [*]const u8, len: u32, points_ptr: [*]const f32, count: u32, size: f32, color: u32) void;
+
 /// Measure the width of text when rendered.
 /// 
 /// # Arguments
@@ -1406,6 +2997,68 @@ pub extern "C" fn draw_circle(x: f32, y: f32, radius: f32) void;
 /// Rendered as 16 line segments.
 pub extern "C" fn draw_circle_outline(x: f32, y: f32, radius: f32, thickness: f32) void;
 
+/// Draw an edge-of-screen indicator pointing at an offscreen world position.
+/// 
+/// # Arguments
+/// * `x`, `y`, `z` — Target position in world units
+/// * `icon` — Texture handle drawn at the indicator (0 = arrow only)
+/// * `color` — Icon tint and arrow color (0xRRGGBBAA)
+/// 
+/// Projects the target with the current camera. Nothing is drawn if it is
+/// inside the current viewport; otherwise a 24px icon and an arrow are
+/// clamped to the viewport edge facing the target (behind-camera targets
+/// point the way to turn). Color and bound texture are preserved.
+/// 
+/// # Returns
+/// 1 if the indicator was drawn, 0 if the target is on screen.
+pub extern "C" fn offscreen_indicator(x: f32, y: f32, z: f32, icon: u32, color: u32) u32;
+
+/// Draw an objective marker that follows a world position on and off screen.
+/// 
+/// # Arguments
+/// * `x`, `y`, `z` — Target position in world units
+/// * `icon` — Texture handle for the marker icon (0 = label only)
+/// * `label_ptr` — Pointer to UTF-8 label text
+/// * `label_len` — Label length in bytes (0 = no label)
+/// 
+/// On screen, the icon is centered on the target at 32px, shrinking with
+/// distance down to 12px, with the label centered underneath in the bound
+/// font. Offscreen, it draws an `offscreen_indicator()` at the screen edge
+/// instead. Uses the current color; color and bound texture are preserved.
+/// 
+/// # Returns
+/// 1 if the marker was drawn on screen, 0 if it was drawn as an edge indicator.
+pub extern "C" fn marker_draw(x: f32, y: f32, z: f32, icon: u32, label_ptr: [*]const u8, label_len: u32) u32;
+
+/// Show a toast notification in the corner of the screen.
+/// 
+/// # Arguments
+/// * `ptr` — Pointer to UTF-8 text
+/// * `len` — Text length in bytes (1-64)
+/// * `icon` — Texture handle drawn left of the text (0 = none)
+/// * `duration` — Seconds on screen including fades (clamped to 0.45-30)
+/// 
+/// Toasts are drawn by the host above everything else, stacked in the top-right
+/// corner of the visible canvas with the oldest on top, and fade in and out. Up
+/// to 4 are shown at once; later ones wait for a free slot. They are not rolled
+/// back: a toast raised again on the same tick with the same text and icon (as
+/// happens when update() is re-simulated) is ignored, and so are toasts past a
+/// queue of 16.
+pub extern "C" fn toast(ptr: [*]const u8, len: u32, icon: u32, duration: f32) void;
+
+/// Restrict 2D drawing to a rectangle until the matching clip_pop().
+/// 
+/// # Arguments
+/// * `x`, `y` — Top-left corner in pixels, relative to the current viewport
+/// * `w`, `h` — Size in pixels
+/// 
+/// Affects sprites, rects, lines, and text. Nested clips are intersected
+/// with the enclosing one (up to 16 deep). 3D drawing is not clipped.
+pub extern "C" fn clip_push(x: f32, y: f32, w: f32, h: f32) void;
+
+/// Restore the clip rectangle from before the last clip_push().
+pub extern "C" fn clip_pop() void;
+
 /// Load a fixed-width bitmap font.
 /// 
 /// # Arguments
@@ -1424,11 +3077,57 @@ pub extern "C" fn load_font(texture: u32, char_width: u32, char_height: u32, fir
 /// * `widths_ptr` — Pointer to array of char_count u8 widths
 pub extern "C" fn load_font_ex(texture: u32, widths_ptr: [*]const u8, char_height: u32, first_codepoint: u32, char_count: u32) u32;
 
+/// Add another codepoint range to a loaded font (init-only).
+/// 
+/// Glyphs for the range follow the font's existing glyphs in the atlas grid,
+/// so one texture can hold several pages (ASCII, Latin-1, Cyrillic, Kana...).
+/// draw_text() decodes UTF-8 and looks each character up across all ranges.
+/// 
+/// # Arguments
+/// * `font_handle` — Font from `load_font()` or `load_font_ex()`
+/// * `first_codepoint` — Unicode codepoint of the range's first glyph
+/// * `char_count` — Number of glyphs in the range
+/// * `widths_ptr` — Pointer to char_count u8 widths, or null for the font's cell width
+/// 
+/// Ranges must not overlap. Widths may not exceed the font's cell width.
+pub extern "C" fn font_add_range(font_handle: u32, first_codepoint: u32, char_count: u32, widths_ptr: ?[*]const u8) void;
+
 /// Bind a font for subsequent draw_text() calls.
 /// 
 /// Pass 0 for the built-in 8×8 monospace font.
 pub extern "C" fn font_bind(font_handle: u32) void;
 
+/// Choose the language used by `str_get()`.
+/// 
+/// A code matches a table language exactly (ignoring case) or by its primary
+/// subtag, so "pt-BR" also selects a "pt" column.
+/// 
+/// # Arguments
+/// * `lang_ptr` — Pointer to a language code (e.g. "fr", "pt-BR")
+/// * `lang_len` — Length of the language code
+/// 
+/// # Returns
+/// 1 if a loaded table has the language, 0 otherwise (lookups then use each
+/// table's first language).
+pub extern "C" fn locale_set(lang_ptr: [*]const u8, lang_len: u32) u32;
+
+/// Copy the text for a key in the current locale into a buffer.
+/// 
+/// Falls back to the table's first language when the key has no text in the
+/// current one. Text longer than the buffer is cut at the last whole UTF-8
+/// character that fits; it is not NUL-terminated.
+/// 
+/// # Arguments
+/// * `key_ptr` — Pointer to the key string (e.g. "menu.start")
+/// * `key_len` — Length of the key
+/// * `out_ptr` — Destination buffer
+/// * `max_len` — Size of the destination buffer
+/// 
+/// # Returns
+/// Full length of the text in bytes (more than `max_len` if it was cut),
+/// or 0 if no loaded table has the key.
+pub extern "C" fn str_get(key_ptr: [*]const u8, key_len: u32, out_ptr: [*]u8, max_len: u32) u32;
+
 // =============================================================================
 // Texture Functions
 // =============================================================================
@@ -1446,6 +3145,61 @@ pub extern "C" fn load_texture(width: u32, height: u32, pixels_ptr: [*]const u8)
 /// Bind a texture to slot 0 (albedo).
 pub extern "C" fn texture_bind(handle: u32) void;
 
+/// Bind one sprite of a texture atlas to slot 0 (albedo).
+/// 
+/// # Arguments
+/// * `handle` — Atlas handle from rom_atlas()
+/// * `sprite_index` — Sprite index (order of `sprites` in nether.toml)
+/// 
+/// Sprite and billboard draws then sample only that sprite; region draws
+/// are relative to it. Binding another texture to slot 0 clears it.
+pub extern "C" fn atlas_bind(handle: u32, sprite_index: u32) void;
+
+/// Combine textures into a texture array.
+/// 
+/// # Arguments
+/// * `handles_ptr` — Pointer to `count` texture handles, one per layer
+/// * `count` — Number of layers (1-256)
+/// 
+/// Textures must be loaded in the same init() call and share one size and
+/// format. Layers are copied, so they count against VRAM again.
+/// 
+/// # Returns
+/// Texture array handle (>0) on success, 0 on failure.
+pub extern "C" fn texture_array_create(handles_ptr: [*]const u32, count: u32) u32;
+
+/// Bind one layer of a texture array to slot 0 (albedo).
+/// 
+/// # Arguments
+/// * `handle` — Texture array handle from texture_array_create()
+/// * `layer` — Layer index (order of the handles passed in)
+/// 
+/// Sprite and billboard draws then sample that layer. Switching layers of
+/// one array doesn't start a new batch. Meshes don't sample texture arrays.
+pub extern "C" fn texture_array_bind(handle: u32, layer: u32) void;
+
+/// Load a palette for indexed textures (init-only).
+/// 
+/// # Arguments
+/// * `colors_ptr` — Pointer to an array of u32 colors (0xRRGGBBAA)
+/// * `count` — Number of colors (1-256)
+/// 
+/// Color N is drawn for texels with index N. Pack textures with
+/// `quality = "indexed"` to store palette indices.
+/// 
+/// # Returns
+/// Palette handle (>0) on success, 0 on failure.
+pub extern "C" fn palette_load(colors_ptr: [*]const u32, count: u32) u32;
+
+/// Set the palette for subsequent draws of indexed textures.
+/// 
+/// # Arguments
+/// * `palette` — Palette handle from `palette_load()`, or 0 to unbind
+/// 
+/// Only applies while an indexed texture is bound to slot 0; other textures
+/// draw unchanged, so the palette can stay bound.
+pub extern "C" fn palette_bind(palette: u32) void;
+
 /// Bind a texture to a specific slot.
 /// 
 /// # Arguments
@@ -1509,6 +3263,16 @@ pub extern "C" fn push_scale(x: f32, y: f32, z: f32) void;
 /// Push a uniform scale transform.
 pub extern "C" fn push_scale_uniform(s: f32) void;
 
+/// Push a mesh socket's transform.
+/// 
+/// Call after binding the mesh's pose (`set_bones()` or `keyframe_bind()`)
+/// with the mesh's transform current. A socket on a bone follows it.
+/// 
+/// # Arguments
+/// * `mesh` — Mesh handle from rom_mesh
+/// * `socket` — Socket ID from rom_mesh_socket
+pub extern "C" fn push_socket(mesh: u32, socket: u32) void;
+
 // =============================================================================
 // Viewport Functions (Split-Screen)
 // =============================================================================
@@ -1554,6 +3318,26 @@ pub extern "C" fn viewport(x: u32, y: u32, width: u32, height: u32) void;
 /// coordinates for HUD elements or between frames.
 pub extern "C" fn viewport_clear() void;
 
+/// Choose how the 960×540 canvas is scaled to the window.
+/// 
+/// # Arguments
+/// * `mode` — `scale_mode::INTEGER` (pixel-perfect, black bars),
+/// `scale_mode::FIT` (largest size with bars) or `scale_mode::STRETCH`
+/// (fill the window, distorting the aspect ratio)
+/// 
+/// Overrides the player's scale setting until the game restarts.
+/// Presentation-only, so it is safe to call from `update()` or `render()`.
+pub extern "C" fn display_scale_mode(mode: u32) void;
+
+/// Get the part of the canvas that is visible in the window.
+/// 
+/// Writes x, y, width, height (4 × f32, canvas pixels) to `out_ptr`. This is
+/// the full canvas unless integer scaling crops it in a window smaller than
+/// 960×540. Keep HUD elements inside this rectangle.
+/// 
+/// Returns 1 on success, 0 if `out_ptr` is invalid.
+pub extern "C" fn safe_area(out_ptr: [*]f32) u32;
+
 // =============================================================================
 // Constants
 // =============================================================================
@@ -1635,6 +3419,127 @@ pub const StencilOp = struct {
     pub const decrement_wrap: u32 = 7;
 };
 
+pub const PingKind = struct {
+    pub const look: u32 = 0;
+    pub const go: u32 = 1;
+    pub const danger: u32 = 2;
+    pub const item: u32 = 3;
+};
+
+pub const Easing = struct {
+    pub const linear: u32 = 0;
+    pub const ease_in: u32 = 1;
+    pub const ease_out: u32 = 2;
+    pub const ease_in_out: u32 = 3;
+};
+
+pub const AudioBus = struct {
+    pub const master: u32 = 0;
+    pub const music: u32 = 1;
+    pub const sfx: u32 = 2;
+    pub const ui: u32 = 3;
+    pub const voice: u32 = 4;
+};
+
+pub const ListenerPolicy = struct {
+    pub const nearest: u32 = 0;
+    pub const mix: u32 = 1;
+};
+
+pub const SfxWave = struct {
+    pub const square: u32 = 0;
+    pub const saw: u32 = 1;
+    pub const sine: u32 = 2;
+    pub const noise: u32 = 3;
+    pub const triangle: u32 = 4;
+};
+
+pub const BlendMode = struct {
+    pub const alpha: u32 = 0;
+    pub const additive: u32 = 1;
+    pub const multiply: u32 = 2;
+    pub const screen: u32 = 3;
+    pub const subtract: u32 = 4;
+};
+
+pub const ScaleMode = struct {
+    pub const integer: u32 = 0;
+    pub const fit: u32 = 1;
+    pub const stretch: u32 = 2;
+};
+
+pub const TickRate = struct {
+    pub const hz_24: u32 = 0;
+    pub const hz_30: u32 = 1;
+    pub const hz_60: u32 = 2;
+    pub const hz_120: u32 = 3;
+};
+
+pub const ProjectileFlag = struct {
+    pub const pierce: u32 = 1;
+    pub const bounce: u32 = 2;
+};
+
+pub const BodyShape = struct {
+    pub const aabb: u32 = 0;
+    pub const circle: u32 = 1;
+};
+
+pub const BodyFlag = struct {
+    pub const static: u32 = 1;
+    pub const sensor: u32 = 2;
+};
+
+pub const CursorStyle = struct {
+    pub const hidden: u32 = 0;
+    pub const arrow: u32 = 1;
+    pub const hand: u32 = 2;
+    pub const crosshair: u32 = 3;
+};
+
+pub const HitboxKind = struct {
+    pub const hurt: u32 = 0;
+    pub const hit: u32 = 1;
+};
+
+pub const HitboxFlag = struct {
+    pub const no_clash: u32 = 1;
+};
+
+pub const HitboxEvent = struct {
+    pub const hit: u32 = 0;
+    pub const clash: u32 = 1;
+};
+
+pub const EventKind = struct {
+    pub const timer: u32 = 1;
+    pub const player_join: u32 = 2;
+    pub const player_leave: u32 = 3;
+    pub const projectile_hit: u32 = 0x100;
+    pub const hitbox_hit: u32 = 0x101;
+    pub const hitbox_clash: u32 = 0x102;
+    pub const destructible_break: u32 = 0x103;
+    pub const chunk_loaded: u32 = 0x104;
+    pub const chunk_unloaded: u32 = 0x105;
+    pub const music_beat: u32 = 0x106;
+    pub const body_contact: u32 = 0x107;
+};
+
+pub const LobbyState = struct {
+    pub const idle: u32 = 0;
+    pub const hosting: u32 = 1;
+    pub const joining: u32 = 2;
+    pub const in_lobby: u32 = 3;
+    pub const starting: u32 = 4;
+    pub const failed: u32 = 5;
+};
+
+pub const PredictPolicy = struct {
+    pub const hold: u32 = 0;
+    pub const neutral: u32 = 1;
+    pub const custom: u32 = 2;
+};
+
 
 // =============================================================================
 // MANUALLY MAINTAINED HELPER FUNCTIONS
@@ -1674,6 +3579,14 @@ pub fn romTexture(id: []const u8) u32 {
     return rom_texture(id.ptr, @intCast(id.len));
 }
 
+pub fn romAtlas(id: []const u8) u32 {
+    return rom_atlas(id.ptr, @intCast(id.len));
+}
+
+pub fn romCubemap(id: []const u8) u32 {
+    return rom_cubemap(id.ptr, @intCast(id.len));
+}
+
 pub fn romMesh(id: []const u8) u32 {
     return rom_mesh(id.ptr, @intCast(id.len));
 }
@@ -1682,6 +3595,18 @@ pub fn romSound(id: []const u8) u32 {
     return rom_sound(id.ptr, @intCast(id.len));
 }
 
+pub fn romStream(id: []const u8) u32 {
+    return rom_stream(id.ptr, @intCast(id.len));
+}
+
+pub fn romPath(id: []const u8) u32 {
+    return rom_path(id.ptr, @intCast(id.len));
+}
+
+pub fn romStrings(id: []const u8) u32 {
+    return rom_strings(id.ptr, @intCast(id.len));
+}
+
 pub fn romFont(id: []const u8) u32 {
     return rom_font(id.ptr, @intCast(id.len));
 }
@@ -1689,3 +3614,21 @@ pub fn romFont(id: []const u8) u32 {
 pub fn romSkeleton(id: []const u8) u32 {
     return rom_skeleton(id.ptr, @intCast(id.len));
 }
+
+/// Update throttling helpers (deterministic, safe in update())
+/// Bucket 0 updates every tick, 1 every 2 ticks, 2 every 4 ticks.
+/// `dist_sq` is the squared distance to the nearest viewer from game state.
+pub fn updateBucket(dist_sq: f32, near: f32, far: f32) u32 {
+    if (dist_sq <= near * near) return 0;
+    if (dist_sq <= far * far) return 1;
+    return 2;
+}
+
+pub fn updateInterval(bucket: u32) u32 {
+    return @as(u32, 1) << @intCast(@min(bucket, 2));
+}
+
+/// Entities are staggered by index so each tick runs a slice of every bucket
+pub fn updateDue(bucket: u32, index: u32, tick: u64) bool {
+    return (tick +% index) % updateInterval(bucket) == 0;
+}
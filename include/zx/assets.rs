@@ -17,12 +17,37 @@ extern "C" {
     /// Texture handle (>0) on success. Traps on failure.
     pub fn rom_texture(id_ptr: *const u8, id_len: u32) -> u32;
 
+    /// Load a texture atlas from ROM data pack by ID.
+    ///
+    /// Uploads the packed atlas texture and its sprite layout for `atlas_bind()`.
+    ///
+    /// # Returns
+    /// Atlas texture handle (>0) on success. Traps on failure.
+    pub fn rom_atlas(id_ptr: *const u8, id_len: u32) -> u32;
+
+    /// Load a cubemap from ROM data pack by ID.
+    ///
+    /// Uploads the six faces (+X, -X, +Y, -Y, +Z, -Z) for `sky_draw()`.
+    ///
+    /// # Returns
+    /// Cubemap handle (>0) on success. Traps on failure.
+    pub fn rom_cubemap(id_ptr: *const u8, id_len: u32) -> u32;
+
     /// Load a mesh from ROM data pack by ID.
     ///
     /// # Returns
     /// Mesh handle (>0) on success. Traps on failure.
     pub fn rom_mesh(id_ptr: *const u8, id_len: u32) -> u32;
 
+    /// Look up a named socket on a mesh loaded with `rom_mesh()`.
+    ///
+    /// Sockets come from glTF nodes named `socket_<name>`; pass the name
+    /// without the prefix.
+    ///
+    /// # Returns
+    /// Socket ID (>0) for `push_socket()`. Traps on failure.
+    pub fn rom_mesh_socket(mesh: u32, name_ptr: *const u8, name_len: u32) -> u32;
+
     /// Load skeleton inverse bind matrices from ROM data pack by ID.
     ///
     /// # Returns
@@ -41,6 +66,32 @@ extern "C" {
     /// Sound handle (>0) on success. Traps on failure.
     pub fn rom_sound(id_ptr: *const u8, id_len: u32) -> u32;
 
+    /// Load a music stream from ROM data pack by ID.
+    ///
+    /// Streams stay compressed and are decoded in chunks while playing, so
+    /// use them for long music tracks. Play with `stream_play()`.
+    ///
+    /// # Returns
+    /// Stream handle (>0) on success. Traps on failure.
+    pub fn rom_stream(id_ptr: *const u8, id_len: u32) -> u32;
+
+    /// Load a spline path from ROM data pack by ID.
+    ///
+    /// Paths are authored as `[[assets.paths]]` control points in nether.toml.
+    /// Evaluate with `path_eval()` and `path_length()`.
+    ///
+    /// # Returns
+    /// Path handle (>0) on success. Traps on failure.
+    pub fn rom_path(id_ptr: *const u8, id_len: u32) -> u32;
+
+    /// Load a localized string table from ROM data pack by ID.
+    ///
+    /// Tables are searched in load order by `str_get()`.
+    ///
+    /// # Returns
+    /// Table handle (>0) on success. Traps on failure.
+    pub fn rom_strings(id_ptr: *const u8, id_len: u32) -> u32;
+
     /// Get the byte size of raw data in the ROM data pack.
     ///
     /// Use this to allocate a buffer before calling `rom_data()`.
@@ -59,4 +110,25 @@ extern "C" {
     /// # Returns
     /// Bytes written on success. Traps on failure.
     pub fn rom_data(id_ptr: *const u8, id_len: u32, dst_ptr: *const u8, max_len: u32) -> u32;
+
+    /// Report loading progress for the host's loading bar.
+    ///
+    /// # Arguments
+    /// * `current` — Items loaded so far (clamped to `total`)
+    /// * `total` — Total items to load (0 hides the bar)
+    ///
+    /// Init-only. The loading screen is shown between `init_step()` calls: export
+    /// `init_step() -> u32` to spread loading over several frames, returning
+    /// non-zero once done. Games that load everything in `init()` never show it.
+    pub fn load_progress(current: u32, total: u32);
+
+    /// Set the loading screen background texture.
+    ///
+    /// # Arguments
+    /// * `texture` — Texture handle stretched over the whole screen (0 = clear color only)
+    ///
+    /// Init-only. Textures are uploaded after the `init()` or `init_step()` call
+    /// that loads them, so load the background in `init()` to show it from the
+    /// first loading frame.
+    pub fn load_background(texture: u32);
 }
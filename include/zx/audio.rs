@@ -15,6 +15,21 @@ extern "C" {
     /// Sound handle for use with playback functions.
     pub fn load_sound(data_ptr: *const i16, byte_len: u32) -> u32;
 
+    /// Synthesize a retro sound effect (sfxr-style).
+    ///
+    /// Must be called during `init()`. The same parameters always produce the same sound.
+    ///
+    /// # Arguments
+    /// * `params_ptr` — Pointer to a 48-byte parameter block:
+    ///   waveform (u32, `sfx_wave::*`), duty (f32, 0 = 50% square, 1 = thinnest pulse),
+    ///   frequency (f32, Hz), slide (f32, octaves/s), vibrato_depth (f32, semitones),
+    ///   vibrato_rate (f32, Hz), attack, sustain, decay (3 × f32, seconds, up to 10 total),
+    ///   punch (f32, 0-1), volume (f32, 0-1), seed (u32, noise seed)
+    ///
+    /// # Returns
+    /// Sound handle for use with playback functions (0 if the parameters are invalid).
+    pub fn synth_sfx(params_ptr: *const u8) -> u32;
+
     /// Play sound on next available channel (fire-and-forget).
     ///
     /// # Arguments
@@ -22,6 +37,20 @@ extern "C" {
     /// * `pan` — -1.0 (left) to 1.0 (right), 0.0 = center
     pub fn play_sound(sound: u32, volume: f32, pan: f32);
 
+    /// Play sound on next available channel, starting on an exact sample.
+    ///
+    /// For engine loops, beat-synced hits and rapid fire. The start time is part
+    /// of the rollback state, so re-simulated ticks schedule the same start.
+    ///
+    /// # Arguments
+    /// * `tick` — Tick whose audio the sound starts in (tick_count() = this tick; past ticks start now)
+    /// * `offset_samples` — Samples (22.05kHz) into that tick's audio, up to 65536
+    /// * `volume` — 0.0 to 1.0
+    /// * `pan` — -1.0 (left) to 1.0 (right), 0.0 = center
+    ///
+    /// Ticks more than 256 ahead are dropped.
+    pub fn play_sound_at(tick: u64, offset_samples: u32, sound: u32, volume: f32, pan: f32);
+
     /// Play sound on a specific channel (for managed/looping audio).
     ///
     /// # Arguments
@@ -34,4 +63,83 @@ extern "C" {
 
     /// Stop a channel.
     pub fn channel_stop(channel: u32);
+
+    /// Create a named audio bus, or look up an existing one.
+    ///
+    /// Must be called during `init()`. Buses "master", "music", "sfx", "ui"
+    /// and "voice" already exist (see `audio_bus::*`); every bus is a child of master.
+    ///
+    /// # Arguments
+    /// * `name_ptr` — Pointer to UTF-8 bus name
+    /// * `name_len` — Length of name in bytes
+    ///
+    /// # Returns
+    /// Bus index (0 = master, also returned if all 8 buses are in use).
+    pub fn bus_create(name_ptr: *const u8, name_len: u32) -> u32;
+
+    /// Route a channel through a bus (channels start on `audio_bus::SFX`).
+    ///
+    /// Music, tracker and streams always play on `audio_bus::MUSIC`.
+    pub fn channel_set_bus(channel: u32, bus: u32);
+
+    /// Set a bus volume (0.0 to 1.0). The master bus scales all other buses.
+    ///
+    /// The player's own volume for the bus (host settings) applies on top.
+    pub fn bus_volume(bus: u32, volume: f32);
+
+    /// Pause or resume everything routed through a bus.
+    ///
+    /// # Arguments
+    /// * `paused` — 1 = pause, 0 = resume
+    pub fn bus_pause(bus: u32, paused: u32);
+
+    /// Place a player's listener for positional sound and make it active.
+    ///
+    /// Call every frame from `update()` with each split-screen player's camera.
+    /// With no listener set, sounds are heard from the origin facing -Z.
+    /// In netplay, voice chat is heard from the local player's listener and comes
+    /// from the other players' listeners (see `voice_set_positional()`).
+    ///
+    /// # Arguments
+    /// * `player` — Player index (0-7; players 4-7 only place voice chat)
+    /// * `x`, `y`, `z` — Listener position (world space)
+    /// * `forward_x`, `forward_y`, `forward_z` — Facing direction (sets left/right)
+    pub fn listener_set_n(
+        player: u32,
+        x: f32,
+        y: f32,
+        z: f32,
+        forward_x: f32,
+        forward_y: f32,
+        forward_z: f32,
+    );
+
+    /// Deactivate a player's listener (e.g. when they leave split-screen).
+    pub fn listener_clear_n(player: u32);
+
+    /// Choose how positional sounds are mixed between listeners.
+    ///
+    /// # Arguments
+    /// * `policy` — `listener_policy::NEAREST` (default) or `listener_policy::MIX`
+    pub fn listener_set_policy(policy: u32);
+
+    /// Play a sound at a world position on the next available channel.
+    ///
+    /// Volume and pan are computed from the listeners when the sound starts.
+    ///
+    /// # Arguments
+    /// * `volume` — 0.0 to 1.0 at the listener
+    /// * `x`, `y`, `z` — Sound position (world space)
+    /// * `range` — Distance at which the sound fades to silence
+    pub fn play_sound_3d(sound: u32, volume: f32, x: f32, y: f32, z: f32, range: f32);
+
+    /// Update a channel's volume and pan from a world position.
+    ///
+    /// Call every frame for moving or looping sounds started with `channel_play()`.
+    ///
+    /// # Arguments
+    /// * `volume` — 0.0 to 1.0 at the listener
+    /// * `x`, `y`, `z` — Sound position (world space)
+    /// * `range` — Distance at which the sound fades to silence
+    pub fn channel_set_3d(channel: u32, volume: f32, x: f32, y: f32, z: f32, range: f32);
 }
@@ -14,6 +14,17 @@ extern "C" {
     /// * `fov_degrees` — Field of view in degrees (typically 45-90, default 60)
     pub fn camera_fov(fov_degrees: f32);
 
+    /// Shake the current camera. Call after `camera_set()` each frame.
+    ///
+    /// # Arguments
+    /// * `trauma` — Shake strength (0-1, clamped); the offset grows with its square
+    /// * `frequency` — How many times per second the shake changes direction (e.g. 15-30)
+    ///
+    /// Keep `trauma` in your game state: raise it on hits and lower it over time.
+    /// The shake is smooth noise over the tick count, seeded by the current viewport,
+    /// so rolled-back frames shake identically and split-screen players independently.
+    pub fn camera_shake(trauma: f32, frequency: f32);
+
     /// Push a custom view matrix (16 floats, column-major order).
     pub fn push_view_matrix(
         m0: f32,
@@ -89,3 +89,195 @@ pub mod stencil_op {
     pub const INCREMENT_WRAP: u32 = 6;
     pub const DECREMENT_WRAP: u32 = 7;
 }
+
+/// Ping kinds for `ping()`
+pub mod ping_kind {
+    pub const LOOK: u32 = 0;
+    pub const GO: u32 = 1;
+    pub const DANGER: u32 = 2;
+    pub const ITEM: u32 = 3;
+}
+
+/// Easing curves for `epu_transition()`
+pub mod easing {
+    pub const LINEAR: u32 = 0;
+    pub const EASE_IN: u32 = 1;
+    pub const EASE_OUT: u32 = 2;
+    pub const EASE_IN_OUT: u32 = 3;
+}
+
+/// Built-in audio buses for `channel_set_bus()`, `bus_volume()` and `bus_pause()`
+pub mod audio_bus {
+    pub const MASTER: u32 = 0;
+    pub const MUSIC: u32 = 1;
+    pub const SFX: u32 = 2;
+    pub const UI: u32 = 3;
+    pub const VOICE: u32 = 4;
+}
+
+/// Listener mixing policies for `listener_set_policy()`
+pub mod listener_policy {
+    /// Each sound is heard by the nearest listener
+    pub const NEAREST: u32 = 0;
+    /// Each sound is heard by every listener
+    pub const MIX: u32 = 1;
+}
+
+/// Sound effect waveforms for `synth_sfx()`
+pub mod sfx_wave {
+    pub const SQUARE: u32 = 0;
+    pub const SAW: u32 = 1;
+    pub const SINE: u32 = 2;
+    pub const NOISE: u32 = 3;
+    pub const TRIANGLE: u32 = 4;
+}
+
+/// Blend modes for `blend_mode_2d()` and `blend_mode_3d()`
+pub mod blend_mode {
+    /// Replace (with dithered alpha)
+    pub const ALPHA: u32 = 0;
+    /// dst + src (glows, flashes)
+    pub const ADDITIVE: u32 = 1;
+    /// dst × src (darkness, tinting)
+    pub const MULTIPLY: u32 = 2;
+    /// dst + src × (1 − dst)
+    pub const SCREEN: u32 = 3;
+    /// dst − src
+    pub const SUBTRACT: u32 = 4;
+}
+
+/// Display scale modes for `display_scale_mode()`
+pub mod scale_mode {
+    pub const INTEGER: u32 = 0;
+    pub const FIT: u32 = 1;
+    pub const STRETCH: u32 = 2;
+}
+
+/// Tick rates for `set_tick_rate()`
+pub mod tick_rate {
+    /// 24 updates per second (cinematic, local play only)
+    pub const HZ_24: u32 = 0;
+    /// 30 updates per second
+    pub const HZ_30: u32 = 1;
+    /// 60 updates per second (default)
+    pub const HZ_60: u32 = 2;
+    /// 120 updates per second (fighting games, precision input)
+    pub const HZ_120: u32 = 3;
+}
+
+/// Projectile flags for `projectile_spawn()`
+pub mod projectile_flag {
+    /// Keep flying through targets until `max_hits` hits
+    pub const PIERCE: u32 = 1;
+    /// Reflect off targets until `max_hits` hits
+    pub const BOUNCE: u32 = 2;
+}
+
+/// Body shapes for `body_create()`
+pub mod body_shape {
+    /// Axis-aligned box (`w` × `h`, centered on the position)
+    pub const AABB: u32 = 0;
+    /// Circle (radius `w`)
+    pub const CIRCLE: u32 = 1;
+}
+
+/// Body flags for `body_create()`
+pub mod body_flag {
+    /// Never moves and isn't affected by gravity (walls, floors, platforms)
+    pub const STATIC: u32 = 1;
+    /// Reports contacts but doesn't push or get pushed (triggers, pickups)
+    pub const SENSOR: u32 = 2;
+}
+
+/// Cursor styles for `cursor_show()`
+pub mod cursor_style {
+    /// Not drawn (positions still update)
+    pub const HIDDEN: u32 = 0;
+    /// Arrow pointer
+    pub const ARROW: u32 = 1;
+    /// Pointing hand
+    pub const HAND: u32 = 2;
+    /// Crosshair
+    pub const CROSSHAIR: u32 = 3;
+}
+
+/// Box kinds for `hitbox_move()`
+pub mod hitbox_kind {
+    /// Vulnerable area, hit by other actors' hitboxes
+    pub const HURT: u32 = 0;
+    /// Attacking area, hits other actors' hurtboxes
+    pub const HIT: u32 = 1;
+}
+
+/// Box flags for `hitbox_move()`
+pub mod hitbox_flag {
+    /// Hitbox passes through other hitboxes instead of clashing
+    pub const NO_CLASH: u32 = 1;
+}
+
+/// Event kinds from `hitbox_next_event()`
+pub mod hitbox_event {
+    /// The attacker's hitbox touched the defender's hurtbox
+    pub const HIT: u32 = 0;
+    /// The attacker's hitbox beat (or traded with) the defender's hitbox
+    pub const CLASH: u32 = 1;
+}
+
+/// Event kinds from `event_poll()`
+///
+/// `data` and `position` meanings are listed per kind.
+pub mod event_kind {
+    /// A timer expired: `data` = [callback_id, 0, 0, 0, 0]
+    pub const TIMER: u32 = 1;
+    /// A player's controller connected: `data` = [player, 0, 0, 0, 0]
+    pub const PLAYER_JOIN: u32 = 2;
+    /// A player's controller disconnected: `data` = [player, 0, 0, 0, 0]
+    pub const PLAYER_LEAVE: u32 = 3;
+    /// A projectile hit a target:
+    /// `data` = [projectile, target, user_data, 0, 0], `position` = impact point
+    pub const PROJECTILE_HIT: u32 = 0x100;
+    /// A hitbox connected:
+    /// `data` = [attacker, defender, attacker_box, defender_box, user_data],
+    /// `position` = midpoint between the boxes
+    pub const HITBOX_HIT: u32 = 0x101;
+    /// Two hitboxes clashed (same layout as `HITBOX_HIT`)
+    pub const HITBOX_CLASH: u32 = 0x102;
+    /// A destructible prop broke: `data` = [handle, 0, 0, 0, 0]
+    pub const DESTRUCTIBLE_BREAK: u32 = 0x103;
+    /// A chunk was copied into the pool: `data` = [handle, slot, x, z, 0]
+    /// (x and z are the grid cell as i32 bits)
+    pub const CHUNK_LOADED: u32 = 0x104;
+    /// A chunk's slot was freed (same layout as `CHUNK_LOADED`)
+    pub const CHUNK_UNLOADED: u32 = 0x105;
+    /// The music reached a new beat: `data` = [beat, bar, beat_in_bar, 0, 0]
+    pub const MUSIC_BEAT: u32 = 0x106;
+    /// Two physics bodies touched: `data` = [a, b, 0, 0, 0] (handles, a < b),
+    /// `position` = contact point (x, y, 0)
+    pub const BODY_CONTACT: u32 = 0x107;
+}
+
+/// In-game lobby states from `lobby_state()` and `on_lobby_state(state)`
+pub mod lobby_state {
+    /// Not hosting or in a lobby
+    pub const IDLE: u32 = 0;
+    /// Hosting a lobby, waiting for other players
+    pub const HOSTING: u32 = 1;
+    /// Connecting to a host
+    pub const JOINING: u32 = 2;
+    /// In a lobby with at least one other player
+    pub const IN_LOBBY: u32 = 3;
+    /// The host started the match; connecting peers
+    pub const STARTING: u32 = 4;
+    /// Creating or joining failed, or the connection was lost
+    pub const FAILED: u32 = 5;
+}
+
+/// Input prediction policies for `predict_policy()`
+pub mod predict_policy {
+    /// Repeat the last received input (default)
+    pub const HOLD: u32 = 0;
+    /// Release every button and center the sticks
+    pub const NEUTRAL: u32 = 1;
+    /// Ask the game's `predict_input(player, ticks)` export
+    pub const CUSTOM: u32 = 2;
+}
@@ -0,0 +1,30 @@
+//! Shared Cursors (Stick and Mouse Pointing)
+
+#[cfg(target_arch = "wasm32")]
+#[link(wasm_import_module = "env")]
+extern "C" {
+    /// Show the host-drawn cursors.
+    ///
+    /// Every player has a cursor moved by their left stick and D-pad (speeding up while held), or
+    /// by the mouse for player 0. Positions update every tick even while hidden, and are rolled
+    /// back, so set the style from `update()`. One cursor is drawn per connected player.
+    ///
+    /// # Arguments
+    /// * `style` — `cursor_style::HIDDEN`, `ARROW`, `HAND` or `CROSSHAIR`
+    pub fn cursor_show(style: u32);
+
+    /// Get a player's cursor position in screen pixels (0-959, 0-539).
+    ///
+    /// Cursors start in the center of the screen. Writes X and Y to the provided pointers.
+    pub fn cursor_pos(player: u32, out_x: *mut f32, out_y: *mut f32);
+
+    /// Set how fast sticks move the cursors.
+    ///
+    /// # Arguments
+    /// * `speed` — Pixels per second at full tilt (default 600)
+    /// * `acceleration` — Speed multiplier gained per second of holding the stick, up to 3×
+    ///   (default 2.0, 0 = constant speed)
+    ///
+    /// The mouse always moves the cursor 1:1.
+    pub fn cursor_speed(speed: f32, acceleration: f32);
+}
@@ -26,6 +26,18 @@ extern "C" {
     /// Register a bool value for debug inspection.
     pub fn debug_register_bool(name_ptr: *const u8, name_len: u32, ptr: *const u8);
 
+    /// Register an i32 enum shown as a dropdown of labels.
+    ///
+    /// `labels_ptr` points to `count` NUL-terminated labels stored back to back
+    /// (e.g. "low\0medium\0high\0"); value n is displayed as the n-th label.
+    pub fn debug_register_enum(
+        name_ptr: *const u8,
+        name_len: u32,
+        ptr: *const u8,
+        labels_ptr: *const u8,
+        count: u32,
+    );
+
     // --- Range-Constrained Registration (Slider UI) ---
 
     /// Register an i32 with min/max range constraints.
@@ -118,6 +130,18 @@ extern "C" {
     /// Watch a Color value (read-only).
     pub fn debug_watch_color(name_ptr: *const u8, name_len: u32, ptr: *const u8);
 
+    /// Record a value for the current tick in a debug inspector graph.
+    ///
+    /// Call from `update()` or `render()`; the last few seconds are graphed. Names
+    /// with a `/` share a graph per prefix, e.g. `"boost/p1"` and `"boost/p2"` are
+    /// two lines on the `"boost"` graph.
+    ///
+    /// # Parameters
+    /// - `name_ptr`: Pointer to graph name string
+    /// - `name_len`: Length of graph name
+    /// - `value`: Value for this tick
+    pub fn stat_plot(name_ptr: *const u8, name_len: u32, value: f32);
+
     // --- Grouping Functions ---
 
     /// Begin a collapsible group in the debug UI.
@@ -193,4 +217,48 @@ extern "C" {
     /// # Returns
     /// 1.0 = normal, 0.5 = half-speed, 2.0 = double-speed, etc.
     pub fn debug_get_time_scale() -> f32;
+
+    // --- Gizmos (drawn only while the F4 inspector is open) ---
+
+    /// Draw a world-space line on top of the scene.
+    ///
+    /// # Parameters
+    /// - `x1`, `y1`, `z1`: Start point in world units
+    /// - `x2`, `y2`, `z2`: End point in world units
+    /// - `color`: Line color (0xRRGGBBAA)
+    pub fn debug_line3d(x1: f32, y1: f32, z1: f32, x2: f32, y2: f32, z2: f32, color: u32);
+
+    /// Draw a wireframe sphere (three great circles).
+    ///
+    /// # Parameters
+    /// - `x`, `y`, `z`: Center in world units
+    /// - `radius`: Radius in world units
+    /// - `color`: Line color (0xRRGGBBAA)
+    pub fn debug_sphere(x: f32, y: f32, z: f32, radius: f32, color: u32);
+
+    /// Draw a wireframe axis-aligned box.
+    ///
+    /// # Parameters
+    /// - `min_x`, `min_y`, `min_z`: Minimum corner in world units
+    /// - `max_x`, `max_y`, `max_z`: Maximum corner in world units
+    /// - `color`: Line color (0xRRGGBBAA)
+    pub fn debug_aabb(
+        min_x: f32,
+        min_y: f32,
+        min_z: f32,
+        max_x: f32,
+        max_y: f32,
+        max_z: f32,
+        color: u32,
+    );
+
+    /// Draw a text label anchored at a world-space position.
+    ///
+    /// # Parameters
+    /// - `ptr`: Pointer to UTF-8 string data
+    /// - `len`: Length of string in bytes
+    /// - `x`, `y`, `z`: Label anchor in world units
+    /// - `size`: Font size in pixels
+    /// - `color`: Text color (0xRRGGBBAA)
+    pub fn debug_text3d(ptr: *const u8, len: u32, x: f32, y: f32, z: f32, size: f32, color: u32);
 }
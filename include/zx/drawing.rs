@@ -1,4 +1,4 @@
-//! Immediate Mode 3D Drawing & Billboards
+//! Immediate Mode 3D Drawing, Billboards & Particles
 
 #[cfg(target_arch = "wasm32")]
 #[link(wasm_import_module = "env")]
@@ -49,4 +49,129 @@ extern "C" {
         src_h: f32,
         mode: u32,
     );
+
+    /// Create a particle system (init-only).
+    ///
+    /// Particles are simulated host-side and drawn as spherical billboards.
+    /// They are presentation-only and not rolled back.
+    ///
+    /// # Arguments
+    /// * `max` — Maximum live particles (1-65536)
+    ///
+    /// # Returns
+    /// Particle system handle (>0) on success, 0 on failure.
+    pub fn particles_create(max: u32) -> u32;
+
+    /// Emit a burst of particles.
+    ///
+    /// Call from `render()` so rollback replay does not emit twice.
+    /// Emission is deterministically seeded per particle system.
+    ///
+    /// # Arguments
+    /// * `handle` — Particle system handle
+    /// * `count` — Particles to spawn (clamped to free capacity)
+    /// * `x`, `y`, `z` — Spawn position (world space)
+    /// * `vel_spread` — Maximum initial speed (random direction)
+    /// * `life` — Lifetime in seconds
+    /// * `size_start`, `size_end` — Size curve (world units)
+    /// * `color_start`, `color_end` — Color gradient (0xRRGGBBAA)
+    ///
+    /// # Returns
+    /// Number of particles actually spawned.
+    pub fn particles_emit(
+        handle: u32,
+        count: u32,
+        x: f32,
+        y: f32,
+        z: f32,
+        vel_spread: f32,
+        life: f32,
+        size_start: f32,
+        size_end: f32,
+        color_start: u32,
+        color_end: u32,
+    ) -> u32;
+
+    /// Simulate and draw a particle system.
+    ///
+    /// Steps the simulation once per elapsed tick, then draws every live
+    /// particle with the bound texture. Does not change `set_color()`.
+    pub fn particles_draw(handle: u32);
+
+    /// Get the number of live particles in a system.
+    pub fn particles_count(handle: u32) -> u32;
+
+    /// Spawn a decal projected onto nearby static meshes.
+    ///
+    /// Decals are presentation-only and not rolled back. Spawning the same
+    /// decal again on the same tick (rollback replay of `update()`) is ignored.
+    /// Up to 256 decals are live at once; spawning more replaces the oldest.
+    ///
+    /// # Arguments
+    /// * `texture` — Texture handle (alpha cuts out the decal's shape)
+    /// * `x`, `y`, `z` — Point on the surface (world space)
+    /// * `normal_x`, `normal_y`, `normal_z` — Surface normal to project along
+    /// * `size` — Width and height in world units
+    /// * `rotation_deg` — Rotation about the normal (counter-clockwise)
+    /// * `lifetime` — Seconds until gone, fading over the last quarter (0 = permanent)
+    ///
+    /// # Returns
+    /// 1 if spawned, 0 if duplicate or invalid.
+    pub fn decal_spawn(
+        texture: u32,
+        x: f32,
+        y: f32,
+        z: f32,
+        normal_x: f32,
+        normal_y: f32,
+        normal_z: f32,
+        size: f32,
+        rotation_deg: f32,
+        lifetime: f32,
+    ) -> u32;
+
+    /// Draw all live decals.
+    ///
+    /// Call from `render()` after drawing the level, once per viewport. Decals
+    /// are projected onto static meshes drawn earlier in the frame; until one
+    /// is near, they draw as flat quads. Preserves the transform, bound
+    /// texture and `uniform_alpha()`.
+    pub fn decals_draw();
+
+    /// Remove all decals (e.g. when loading a new level).
+    pub fn decals_clear();
+
+    /// Create a water plane animated by Gerstner waves (init-only).
+    ///
+    /// The plane is a square grid centered on the origin at y = 0. Waves travel
+    /// roughly along a seeded wind direction, each shorter and lower than the last.
+    /// Up to 16 planes can be created.
+    ///
+    /// # Arguments
+    /// * `size` — Side length of the plane
+    /// * `wave_count` — Number of summed waves (1-8)
+    /// * `amplitude` — Combined crest height of all waves above rest
+    /// * `seed` — Picks the wind direction, wavelengths and phases
+    ///
+    /// # Returns
+    /// Water handle (>0), or 0 on failure.
+    pub fn water_plane(size: f32, wave_count: u32, amplitude: f32, seed: u32) -> u32;
+
+    /// Draw a water plane at the current tick.
+    ///
+    /// Uses the current transform, bound textures and render state. UVs span 0-1
+    /// across the plane and normals follow the waves.
+    pub fn water_draw(handle: u32);
+
+    /// Get the water surface height above a point at the current tick.
+    ///
+    /// Deterministic, so it is safe for buoyancy in `update()`.
+    ///
+    /// # Arguments
+    /// * `handle` — Water handle from water_plane()
+    /// * `x`, `z` — Point in the plane's own space
+    ///
+    /// # Returns
+    /// Height above the plane's rest level, or 0 for invalid handles.
+    pub fn water_height(handle: u32, x: f32, z: f32) -> f32;
 }
@@ -48,10 +48,10 @@ extern "C" {
     /// - 0x06: PATCHES (bounds source)
     /// - 0x07: APERTURE (bounds modifier)
     /// - 0x08: DECAL (sharp SDF shape)
-    /// - 0x09: GRID (repeating lines/panels)
+    /// - 0x09: GRID (perspective line grids)
     /// - 0x0A: SCATTER (point field)
     /// - 0x0B: FLOW (animated noise/streaks)
-    /// - 0x0C..0x13: radiance opcodes (TRACE/VEIL/ATMOSPHERE/PLANE/CELESTIAL/PORTAL/LOBE_RADIANCE/BAND_RADIANCE)
+    /// - 0x0C..0x14: radiance opcodes (TRACE/VEIL/ATMOSPHERE/PLANE/CELESTIAL/PORTAL/LOBE_RADIANCE/BAND_RADIANCE/FLASH)
     ///
     /// # Blend Modes
     /// - 0: ADD (dst + src * a)
@@ -86,6 +86,7 @@ extern "C" {
     /// # Notes
     /// - The EPU compute pass runs automatically before rendering
     /// - To set up multiple environments in a frame: call `environment_index(env_id)`, then `epu_set(config_ptr)`
+    ///   (or use `epu_set_slot(slot, config_ptr)` + `epu_bind(slot)` for split-screen)
     /// - Determinism: the EPU has no host-managed time; animate by changing instruction parameters from the game
     pub fn epu_set(config_ptr: *const u64);
 
@@ -97,4 +98,128 @@ extern "C" {
     /// For split-screen / multi-pass, set `viewport(...)` and call `draw_epu()`
     /// once per viewport/pass where you want an environment background.
     pub fn draw_epu();
+
+    /// Draw a cubemap sky as the background for the current viewport/pass.
+    ///
+    /// Used instead of `draw_epu()`: for each viewport and pass, whichever of the
+    /// two is called last is drawn. The sky follows the camera's rotation and
+    /// `texture_filter(...)`.
+    ///
+    /// With `sky_ambient(...)` above 0, the sky also replaces the diffuse ambient
+    /// of the current `environment_index(...)` this frame. Reflections still come
+    /// from the EPU.
+    ///
+    /// # Arguments
+    /// * `cubemap` — Cubemap handle from `rom_cubemap()`
+    /// * `rotation` — Rotation of the sky about the world Y axis in degrees
+    pub fn sky_draw(cubemap: u32, rotation: f32);
+
+    /// Set how strongly `sky_draw()` skies light the scene.
+    ///
+    /// Applies to `sky_draw()` calls after it in the current frame; resets to 0
+    /// every frame.
+    ///
+    /// # Arguments
+    /// * `intensity` — Ambient multiplier (0 = keep the EPU ambient, 1 = sky as authored)
+    pub fn sky_ambient(intensity: f32);
+
+    /// Store an EPU configuration (128-byte) for an explicit environment slot.
+    ///
+    /// Unlike `epu_set(...)`, this does not depend on (or change) the current
+    /// `environment_index(...)`. Use it to upload one environment per viewport
+    /// up front, then select between them with `epu_bind(slot)`.
+    ///
+    /// # Arguments
+    /// * `slot` — Environment slot (`env_id`), clamped to 0..255
+    /// * `config_ptr` — Pointer to 16 u64 values (128 bytes total) in WASM memory
+    ///
+    /// # Example (per-player environments in split-screen)
+    /// ```rust,ignore
+    /// fn render() {
+    ///     epu_set_slot(0, DAY_TRACK.as_ptr());
+    ///     epu_set_slot(1, NIGHT_TRACK.as_ptr());
+    ///
+    ///     for p in 0..2 {
+    ///         viewport(p * 480, 0, 480, 540);
+    ///         epu_bind(p);
+    ///         draw_mesh(track);
+    ///         draw_epu();
+    ///     }
+    /// }
+    /// ```
+    pub fn epu_set_slot(slot: u32, config_ptr: *const u64);
+
+    /// Bind an environment slot for subsequent draw calls.
+    ///
+    /// Alias of `environment_index(slot)`, named to pair with `epu_set_slot()`:
+    /// selects the environment used by `draw_epu()` and by ambient/reflection
+    /// lighting of subsequent draws.
+    /// Call inside the per-player render loop after `viewport(...)`.
+    ///
+    /// # Arguments
+    /// * `slot` — Environment slot (`env_id`), clamped to 0..255
+    pub fn epu_bind(slot: u32);
+
+    /// Crossfade an environment slot to a new EPU configuration over time.
+    ///
+    /// Colors, alphas, intensities and directions are interpolated field-wise from
+    /// the slot's current config to the target; opcodes and params switch over
+    /// halfway. Layers present on only one side fade in or out.
+    ///
+    /// While the transition runs, configs pushed for `slot` are replaced by the
+    /// blend, so keep calling `epu_set(...)` / `epu_set_slot(...)` with the new
+    /// config every frame.
+    ///
+    /// # Arguments
+    /// * `slot` — Environment slot (`env_id`), clamped to 0..255
+    /// * `config_ptr` — Pointer to the target config (16 u64 values, 128 bytes)
+    /// * `duration_ticks` — Length of the crossfade in ticks (0 = switch immediately)
+    /// * `easing` — Easing curve (see `easing::*` constants)
+    ///
+    /// # Example (day → night over 5 seconds at 60 ticks/s)
+    /// ```rust,ignore
+    /// fn render() {
+    ///     if NIGHT_STARTED_THIS_FRAME {
+    ///         epu_transition(0, NIGHT.as_ptr(), 300, easing::EASE_IN_OUT);
+    ///     }
+    ///     epu_set(if IS_NIGHT { NIGHT.as_ptr() } else { DAY.as_ptr() });
+    ///     draw_epu();
+    /// }
+    /// ```
+    pub fn epu_transition(slot: u32, config_ptr: *const u64, duration_ticks: u32, easing: u32);
+
+    /// Create a lighting probe at a world position (init-only).
+    ///
+    /// The probe captures the environment selected with `environment_index(...)`
+    /// when it is created (0 = global sky) plus every light that reaches its
+    /// sphere. Up to 16 probes; they use environment slots 240..255.
+    ///
+    /// # Arguments
+    /// * `x`, `y`, `z` — Capture position in world units
+    /// * `radius` — Capture radius in world units
+    ///
+    /// # Returns
+    /// Probe handle (>0), or 0 on error.
+    pub fn probe_create(x: f32, y: f32, z: f32, radius: f32) -> u32;
+
+    /// Use a probe's captured lighting for ambient and reflections of subsequent draws.
+    ///
+    /// The probe is recaptured every 8 ticks from its source environment and the
+    /// lights set at the time of the call. Call `environment_index(...)` to return
+    /// to a regular environment.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// fn render() {
+    ///     epu_set(SKY.as_ptr());
+    ///     environment_index(TUNNEL_ENV);
+    ///     epu_set(TUNNEL.as_ptr());
+    ///
+    ///     for car in cars() {
+    ///         if car.in_tunnel { material_probe(TUNNEL_PROBE); } else { environment_index(0); }
+    ///         draw_mesh(CAR_MESH);
+    ///     }
+    /// }
+    /// ```
+    pub fn material_probe(handle: u32);
 }
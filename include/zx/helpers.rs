@@ -1,8 +1,9 @@
 //! Helper Functions
 
 use super::{
-    draw_text, log, rom_data_len, rom_font, rom_keyframes, rom_mesh, rom_skeleton, rom_sound,
-    rom_texture, rom_tracker,
+    draw_text, log, rom_atlas, rom_cubemap, rom_data_len, rom_font, rom_keyframes, rom_mesh,
+    rom_mesh_socket, rom_path, rom_skeleton, rom_sound, rom_stream, rom_strings, rom_texture,
+    rom_tracker, screen, viewport,
 };
 
 /// Helper to log a string slice.
@@ -54,18 +55,54 @@ pub fn rom_texture_str(id: &str) -> u32 {
     unsafe { rom_texture(id.as_ptr(), id.len() as u32) }
 }
 
+/// Helper to load a ROM texture atlas by string literal.
+#[inline]
+pub fn rom_atlas_str(id: &str) -> u32 {
+    unsafe { rom_atlas(id.as_ptr(), id.len() as u32) }
+}
+
+/// Helper to load a ROM cubemap by string literal.
+#[inline]
+pub fn rom_cubemap_str(id: &str) -> u32 {
+    unsafe { rom_cubemap(id.as_ptr(), id.len() as u32) }
+}
+
 /// Helper to load a ROM mesh by string literal.
 #[inline]
 pub fn rom_mesh_str(id: &str) -> u32 {
     unsafe { rom_mesh(id.as_ptr(), id.len() as u32) }
 }
 
+/// Helper to look up a ROM mesh socket by string literal.
+#[inline]
+pub fn rom_mesh_socket_str(mesh: u32, name: &str) -> u32 {
+    unsafe { rom_mesh_socket(mesh, name.as_ptr(), name.len() as u32) }
+}
+
 /// Helper to load a ROM sound by string literal.
 #[inline]
 pub fn rom_sound_str(id: &str) -> u32 {
     unsafe { rom_sound(id.as_ptr(), id.len() as u32) }
 }
 
+/// Helper to load a ROM stream by string literal.
+#[inline]
+pub fn rom_stream_str(id: &str) -> u32 {
+    unsafe { rom_stream(id.as_ptr(), id.len() as u32) }
+}
+
+/// Helper to load a ROM spline path by string literal.
+#[inline]
+pub fn rom_path_str(id: &str) -> u32 {
+    unsafe { rom_path(id.as_ptr(), id.len() as u32) }
+}
+
+/// Helper to load a ROM string table by string literal.
+#[inline]
+pub fn rom_strings_str(id: &str) -> u32 {
+    unsafe { rom_strings(id.as_ptr(), id.len() as u32) }
+}
+
 /// Helper to load a ROM font by string literal.
 #[inline]
 pub fn rom_font_str(id: &str) -> u32 {
@@ -132,6 +169,20 @@ pub unsafe fn debug_bool(name: &str, ptr: &bool) {
     );
 }
 
+/// Helper to register an i32 enum debug value by string literal.
+///
+/// `labels` holds NUL-terminated labels back to back, e.g. `"low\0medium\0high\0"`.
+#[inline]
+pub unsafe fn debug_enum(name: &str, ptr: &i32, labels: &str) {
+    super::debug_register_enum(
+        name.as_ptr(),
+        name.len() as u32,
+        ptr as *const i32 as *const u8,
+        labels.as_ptr(),
+        labels.matches('\0').count() as u32,
+    );
+}
+
 /// Helper to begin a debug group by string literal.
 #[inline]
 pub fn debug_group(name: &str) {
@@ -147,3 +198,55 @@ pub fn debug_group_close() {
         super::debug_group_end();
     }
 }
+
+/// Compute the split-screen rectangle `(x, y, width, height)` for a player.
+///
+/// Layouts by player count:
+/// - 1: fullscreen
+/// - 2: side-by-side halves (480×540)
+/// - 3-4: 2×2 grid (480×270)
+/// - 5-6: 3×2 grid (320×270)
+/// - 7-8: 4×2 grid (240×270)
+///
+/// `index` is clamped to `count - 1`; `count` is clamped to 1-8.
+///
+/// # Example
+/// ```rust,ignore
+/// for p in 0..player_count() {
+///     let (x, y, w, h) = split_viewport_rect(player_count(), p);
+///     viewport(x, y, w, h);
+///     // ... per-player camera + draw
+/// }
+/// viewport_clear();
+/// ```
+pub const fn split_viewport_rect(count: u32, index: u32) -> (u32, u32, u32, u32) {
+    let count = if count == 0 {
+        1
+    } else if count > 8 {
+        8
+    } else {
+        count
+    };
+    let index = if index >= count { count - 1 } else { index };
+    let (cols, rows) = match count {
+        1 => (1, 1),
+        2 => (2, 1),
+        3 | 4 => (2, 2),
+        5 | 6 => (3, 2),
+        _ => (4, 2),
+    };
+    let w = screen::WIDTH / cols;
+    let h = screen::HEIGHT / rows;
+    ((index % cols) * w, (index / cols) * h, w, h)
+}
+
+/// Set the viewport to player `index`'s region of a `count`-way split.
+///
+/// See [`split_viewport_rect`] for the layouts.
+#[inline]
+pub fn viewport_split(count: u32, index: u32) {
+    let (x, y, w, h) = split_viewport_rect(count, index);
+    unsafe {
+        viewport(x, y, w, h);
+    }
+}
@@ -0,0 +1,64 @@
+//! Hitboxes (Frame Data, Clashes and Hit Events)
+
+#[cfg(target_arch = "wasm32")]
+#[link(wasm_import_module = "env")]
+extern "C" {
+    /// Register a move's frame data (init-only).
+    ///
+    /// Each box is active on a range of animation frames. Box coordinates are in
+    /// actor space: +X is forward at yaw 0, +Y is up.
+    ///
+    /// # Arguments
+    /// * `boxes_ptr` — Pointer to `count` 52-byte boxes: first_frame (u32), last_frame (u32,
+    ///   inclusive), kind (u32, `hitbox_kind::*`), priority (u32), group (u32),
+    ///   flags (u32, `hitbox_flag::*`), user_data (u32), center (3 × f32), half-extents (3 × f32)
+    /// * `count` — Number of boxes (1..=32)
+    ///
+    /// # Returns
+    /// Move handle (>0) on success, 0 on invalid input.
+    pub fn hitbox_move(boxes_ptr: *const u8, count: u32) -> u32;
+
+    /// Place an actor for this tick.
+    ///
+    /// Switching moves, or going back to an earlier frame of the same move, starts a
+    /// new attack that may hit everyone again. Actor state is rolled back, so call from `update()`.
+    ///
+    /// # Arguments
+    /// * `actor` — Actor slot (0..32)
+    /// * `move_id` — Move handle from `hitbox_move()`, or 0 to remove the actor
+    /// * `frame` — Current animation frame of the move
+    /// * `x`, `y`, `z` — Actor position
+    /// * `yaw` — Facing around +Y in radians (0 faces +X)
+    /// * `team` — Actors on the same non-zero team never interact
+    pub fn hitbox_actor(
+        actor: u32,
+        move_id: u32,
+        frame: u32,
+        x: f32,
+        y: f32,
+        z: f32,
+        yaw: f32,
+        team: u32,
+    );
+
+    /// Overlap every actor's active boxes and queue hit and clash events.
+    ///
+    /// Clashing hitboxes cancel the lower priority attack (equal priorities trade).
+    /// Each hit group hits a defender at most once per move.
+    pub fn hitbox_resolve();
+
+    /// Read the next event from the last `hitbox_resolve()`.
+    ///
+    /// # Arguments
+    /// * `out_ptr` — Pointer to a 36-byte event: kind (u32, `hitbox_event::*`), attacker (u32),
+    ///   defender (u32), attacker_box (u32), defender_box (u32), user_data (u32), position (3 × f32)
+    ///
+    /// # Returns
+    /// 1 if an event was written, 0 when there are no more events.
+    pub fn hitbox_next_event(out_ptr: *mut u8) -> u32;
+
+    /// Draw every active box as a wireframe (F4 debug inspector only).
+    ///
+    /// Hurtboxes are blue, hitboxes red.
+    pub fn hitbox_debug_draw();
+}
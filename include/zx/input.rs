@@ -61,4 +61,16 @@ extern "C" {
 
     /// Get right trigger value (0.0 to 1.0).
     pub fn trigger_right(player: u32) -> f32;
+
+    /// Get the step between stick axis values (1/127).
+    ///
+    /// Sticks are quantized to whole steps before they reach the game, and
+    /// every peer sees the same steps. Axes read 0.0 exactly when centered and
+    /// ±1.0 exactly at full tilt.
+    pub fn stick_step() -> f32;
+
+    /// Get the step between trigger values (1/255).
+    ///
+    /// Triggers are quantized to whole steps, like sticks.
+    pub fn trigger_step() -> f32;
 }
@@ -47,4 +47,81 @@ extern "C" {
     ///
     /// Only affects point lights (ignored for directional).
     pub fn light_range(index: u32, range: f32);
+
+    /// Configure a directional light from an environment's sun.
+    ///
+    /// # Arguments
+    /// * `index` — Light index (0-3)
+    /// * `slot` — Environment slot (env_id) to read
+    ///
+    /// Copies the direction, color and intensity of the brightest sky
+    /// LOBE_RADIANCE or DECAL layer into the light and enables it. Call after
+    /// `epu_set()` so the light tracks the sky as it changes.
+    ///
+    /// # Returns
+    /// 1 if a sun was found, 0 otherwise (the light is left unchanged).
+    pub fn light_from_env(index: u32, slot: u32) -> u32;
+
+    /// Add a point light to the scene for this frame.
+    ///
+    /// # Arguments
+    /// * `x`, `y`, `z` — World-space position
+    /// * `color` — Light color (0xRRGGBBAA, alpha ignored)
+    /// * `intensity` — Brightness multiplier (0.0-8.0)
+    /// * `range` — Distance at which the light reaches zero
+    ///
+    /// Scene lights add to the 4 lights above and light every draw in modes 0, 2
+    /// and 3. Up to 256 per frame; each surface only pays for the lights whose
+    /// range reaches it. Call every frame — the list is cleared after rendering.
+    pub fn light_add_point(x: f32, y: f32, z: f32, color: u32, intensity: f32, range: f32);
+
+    /// Add a spot light to the scene for this frame.
+    ///
+    /// # Arguments
+    /// * `x`, `y`, `z` — World-space position
+    /// * `dir_x`, `dir_y`, `dir_z` — Direction the spot points (normalized)
+    /// * `color` — Light color (0xRRGGBBAA, alpha ignored)
+    /// * `intensity` — Brightness multiplier (0.0-8.0)
+    /// * `range` — Distance at which the light reaches zero
+    /// * `inner_angle` — Cone half-angle in radians with full brightness
+    /// * `outer_angle` — Cone half-angle in radians where the light reaches zero
+    ///
+    /// Otherwise behaves like `light_add_point()`.
+    pub fn light_add_spot(
+        x: f32,
+        y: f32,
+        z: f32,
+        dir_x: f32,
+        dir_y: f32,
+        dir_z: f32,
+        color: u32,
+        intensity: f32,
+        range: f32,
+        inner_angle: f32,
+        outer_angle: f32,
+    );
+
+    /// Bake the current lights into static meshes (init-only).
+    ///
+    /// # Arguments
+    /// * `mesh_handles_ptr` — Pointer to an array of u32 mesh handles (must have normals)
+    /// * `mesh_count` — Number of mesh handles
+    /// * `light_set` — Bitmask of lights to bake (bit N = light N, 0-3)
+    ///
+    /// Irradiance is computed per vertex with the current transform applied.
+    /// Draw the meshes with `material_lightmap()` and disable the baked lights
+    /// so they cost nothing at runtime.
+    ///
+    /// # Returns
+    /// Lightmap handle (>0) on success, 0 on failure.
+    pub fn lightmap_bake(mesh_handles_ptr: *const u32, mesh_count: u32, light_set: u32) -> u32;
+
+    /// Apply a baked lightmap to subsequent mesh draws.
+    ///
+    /// # Arguments
+    /// * `lightmap` — Lightmap handle from `lightmap_bake()`, or 0 to disable
+    ///
+    /// Only meshes baked into the lightmap receive its lighting. Baked light is
+    /// added in modes 0, 2 and 3 (matcap ignores it).
+    pub fn material_lightmap(lightmap: u32);
 }
@@ -44,6 +44,22 @@ extern "C" {
     /// * `power` — Falloff sharpness (0.0-32.0, higher = tighter)
     pub fn material_rim(intensity: f32, power: f32);
 
+    /// Set the number of lit diffuse bands for toon shading (Mode 4 only).
+    ///
+    /// # Arguments
+    /// * `count` — Bands above the shadow side (1-16, 1 = two-tone). Default: 3
+    pub fn material_bands(count: u32);
+
+    /// Set the inked outline drawn around meshes (Mode 4 only).
+    ///
+    /// Meshes with normals are drawn again pushed out along their normals with
+    /// front faces culled, leaving a solid shell around the silhouette.
+    ///
+    /// # Arguments
+    /// * `width` — Outline thickness in world units (0.0 = no outline, default)
+    /// * `color` — Outline color (0xRRGGBBAA, alpha uses dither transparency)
+    pub fn material_outline(width: f32, color: u32);
+
     /// Enable/disable uniform color override.
     ///
     /// When enabled, uses the last set_color() value for all subsequent draws,
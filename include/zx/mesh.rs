@@ -40,5 +40,80 @@ extern "C" {
     ) -> u32;
 
     /// Draw a retained mesh with current transform and render state.
+    ///
+    /// Meshes whose bounds are entirely outside the camera's view are skipped
+    /// (see `render_stats()`); skinned and morphed meshes are always drawn.
     pub fn draw_mesh(handle: u32);
+
+    /// Set the weight of one of a mesh's morph targets.
+    ///
+    /// Targets are numbered in the order they were exported (up to 8 per mesh).
+    /// Weights stay set until changed and apply to every later draw of the mesh.
+    ///
+    /// # Arguments
+    /// * `handle` — Mesh handle from rom_mesh
+    /// * `target_index` — Morph target index
+    /// * `weight` — How much of the target to apply (0 = none, 1 = full)
+    pub fn mesh_morph_weight(handle: u32, target_index: u32, weight: f32);
+
+    /// Merge placed copies of meshes into one mesh (init-only).
+    ///
+    /// Bakes static props into a few large meshes so they draw in a few calls.
+    /// Meshes with different vertex formats, or more than 65536 vertices in
+    /// total, become several parts that `draw_mesh()` draws together. Source
+    /// meshes must be loaded earlier in init(), must not be skinned, and stay
+    /// usable on their own.
+    ///
+    /// # Arguments
+    /// * `handles_ptr` — Pointer to `count` mesh handles (a handle may repeat)
+    /// * `transforms_ptr` — Pointer to `count` 4x4 column-major matrices (16 floats each)
+    /// * `count` — Number of meshes (1-4096)
+    ///
+    /// Returns a mesh handle (>0) on success, 0 on failure.
+    pub fn mesh_merge(handles_ptr: *const u32, transforms_ptr: *const f32, count: u32) -> u32;
+
+    /// Register a destructible prop (init-only).
+    ///
+    /// Each connected part of `pieces_mesh` becomes one debris piece (up to 64).
+    /// Both meshes must share a local space and be loaded earlier in init().
+    /// Debris settles on the lowest point of `pieces_mesh`.
+    ///
+    /// # Arguments
+    /// * `mesh` — Mesh drawn while the prop is intact
+    /// * `pieces_mesh` — Pre-fractured version of the prop
+    /// * `health` — Starting health (> 0)
+    ///
+    /// # Returns
+    /// Destructible handle (>0) on success, 0 on failure.
+    pub fn destructible_register(mesh: u32, pieces_mesh: u32, health: f32) -> u32;
+
+    /// Damage a destructible prop (call from update(), rolled back).
+    ///
+    /// # Arguments
+    /// * `handle` — Destructible handle from `destructible_register()`
+    /// * `amount` — Health to remove
+    /// * `impulse_x`, `impulse_y`, `impulse_z` — Velocity added to the debris if
+    ///   this hit breaks the prop
+    ///
+    /// # Returns
+    /// 1 if this hit broke the prop, 0 otherwise.
+    pub fn destructible_damage(
+        handle: u32,
+        amount: f32,
+        impulse_x: f32,
+        impulse_y: f32,
+        impulse_z: f32,
+    ) -> u32;
+
+    /// Draw a destructible prop with the current transform.
+    ///
+    /// Draws the intact mesh, or the flying debris once broken.
+    /// Debris disappears 5 seconds after the break.
+    pub fn destructible_draw(handle: u32);
+
+    /// Get the remaining health of a destructible prop (0.0 once broken).
+    pub fn destructible_health(handle: u32) -> f32;
+
+    /// Restore a destructible prop to full health.
+    pub fn destructible_reset(handle: u32);
 }
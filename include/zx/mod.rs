@@ -48,24 +48,32 @@ mod audio;
 mod camera;
 mod colors;
 mod constants;
+mod cursor;
 mod debug;
 mod drawing;
 mod embedded;
 mod epu;
 mod helpers;
+mod hitbox;
 mod input;
 mod lighting;
 mod material;
 mod mesh;
 mod music;
+mod navigation;
 mod pass;
+mod physics;
 mod procedural;
+mod projectile;
 mod render;
+mod scoreboard;
 mod skeleton;
+mod streaming;
 mod stubs;
 mod system;
 mod text;
 mod texture;
+mod throttle;
 mod transform;
 mod viewport;
 
@@ -75,23 +83,31 @@ pub use audio::*;
 pub use camera::*;
 pub use colors::*;
 pub use constants::*;
+pub use cursor::*;
 pub use debug::*;
 pub use drawing::*;
 pub use embedded::*;
 pub use epu::*;
 pub use helpers::*;
+pub use hitbox::*;
 pub use input::*;
 pub use lighting::*;
 pub use material::*;
 pub use mesh::*;
 pub use music::*;
+pub use navigation::*;
 pub use pass::*;
+pub use physics::*;
 pub use procedural::*;
+pub use projectile::*;
 pub use render::*;
+pub use scoreboard::*;
 pub use skeleton::*;
+pub use streaming::*;
 pub use stubs::*;
 pub use system::*;
 pub use text::*;
 pub use texture::*;
+pub use throttle::*;
 pub use transform::*;
 pub use viewport::*;
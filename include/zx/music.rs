@@ -75,6 +75,62 @@ extern "C" {
     /// 0 = none, 1 = PCM, 2 = tracker
     pub fn music_type() -> u32;
 
+    /// Play layered music made of up to 4 stems that stay in lockstep.
+    ///
+    /// Stems share one playhead, so fading layers in and out never puts them
+    /// out of sync. Layer 0 starts at full volume, the others start silent.
+    /// All stems should be the same length as layer 0.
+    ///
+    /// # Arguments
+    /// * `sounds_ptr` — Pointer to an array of sound handles (from load_sound)
+    /// * `count` — Number of stems (1-4)
+    /// * `volume` — Overall music volume, 0.0 to 1.0
+    /// * `looping` — 1 = loop, 0 = play once
+    pub fn music_play_layers(sounds_ptr: *const u32, count: u32, volume: f32, looping: u32);
+
+    /// Fade a stem of layered music to a new volume.
+    ///
+    /// Use to ramp intensity with game state (wave number, race position, etc).
+    /// A new call replaces the layer's current fade.
+    ///
+    /// # Arguments
+    /// * `layer` — Stem index (0-3)
+    /// * `volume` — Target volume, 0.0 to 1.0
+    /// * `fade_ticks` — Length of the fade in ticks (0 = instant)
+    pub fn music_layer_volume(layer: u32, volume: f32, fade_ticks: u32);
+
+    /// Configure the music beat clock.
+    ///
+    /// Tracker music has a beat every `rows_per_beat` rows and follows tempo
+    /// changes in the song. PCM music has no tempo, so it has no beats until
+    /// `bpm` is set. Settings persist across music changes.
+    ///
+    /// # Arguments
+    /// * `bpm` — Tempo of PCM music (0 = no beats for PCM music)
+    /// * `beats_per_bar` — Beats per bar (0 = default 4)
+    /// * `rows_per_beat` — Tracker rows per beat (0 = default 4)
+    pub fn music_beat_config(bpm: f32, beats_per_bar: u32, rows_per_beat: u32);
+
+    /// Get the beat index since the music started.
+    ///
+    /// Each new beat is also queued as an `event_kind::MUSIC_BEAT` event.
+    ///
+    /// # Returns
+    /// Beat index (0 = first beat), or 0 if no music with a beat clock is playing
+    pub fn music_beat() -> u32;
+
+    /// Get the bar index since the music started.
+    ///
+    /// # Returns
+    /// music_beat() / beats per bar
+    pub fn music_bar() -> u32;
+
+    /// Get the progress through the current beat, for pulsing visuals.
+    ///
+    /// # Returns
+    /// 0.0 on the beat, rising toward 1.0 just before the next beat
+    pub fn music_beat_phase() -> f32;
+
     /// Jump to a specific position (tracker only, no-op for PCM).
     ///
     /// Use for dynamic music systems (e.g., jump to outro pattern).
@@ -139,4 +195,38 @@ extern "C" {
     /// # Returns
     /// Actual length written (0 if PCM or invalid handle).
     pub fn music_name(handle: u32, out_ptr: *mut u8, max_len: u32) -> u32;
+
+    // --- Streams (long music decoded while playing) ---
+
+    /// Play a stream (from rom_stream) from the beginning.
+    ///
+    /// Streams play on their own voice, independent of `music_play()`.
+    /// Replaces any stream already playing.
+    ///
+    /// # Arguments
+    /// * `handle` — Stream handle from rom_stream
+    /// * `volume` — 0.0 to 1.0
+    /// * `looping` — 1 = loop, 0 = play once
+    pub fn stream_play(handle: u32, volume: f32, looping: u32);
+
+    /// Stop the stream.
+    pub fn stream_stop();
+
+    /// Pause or resume the stream.
+    ///
+    /// # Arguments
+    /// * `paused` — 1 = pause, 0 = resume
+    pub fn stream_pause(paused: u32);
+
+    /// Move the stream playhead.
+    ///
+    /// # Arguments
+    /// * `seconds` — Position from the start of the stream
+    pub fn stream_seek(seconds: f32);
+
+    /// Get the stream playhead.
+    ///
+    /// # Returns
+    /// Position in seconds from the start of the stream (0.0 if no stream).
+    pub fn stream_position() -> f32;
 }
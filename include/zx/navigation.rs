@@ -0,0 +1,109 @@
+//! Navigation (Pathfinding and Spline Paths)
+
+#[cfg(target_arch = "wasm32")]
+#[link(wasm_import_module = "env")]
+extern "C" {
+    /// Bake a navigation mesh from a loaded mesh (init-only).
+    ///
+    /// Call after loading the mesh. Triangles steeper than 45° are not walkable,
+    /// and gaps narrower than the agent's diameter are treated as walls.
+    ///
+    /// # Arguments
+    /// * `mesh_handle` — Mesh handle from `load_mesh*()`, `rom_mesh()` or a mesh generator
+    /// * `agent_radius` — Radius of the agents that will walk the mesh
+    ///
+    /// # Returns
+    /// Navmesh handle (>0) on success, 0 on failure.
+    pub fn navmesh_bake(mesh_handle: u32, agent_radius: f32) -> u32;
+
+    /// Find an obstacle-aware path across a navmesh.
+    ///
+    /// Start and end are snapped onto the navmesh. The path includes both
+    /// snapped endpoints and is truncated to `max_points`. Deterministic, so it
+    /// is safe to call from `update()`.
+    ///
+    /// # Arguments
+    /// * `navmesh` — Navmesh handle from `navmesh_bake()`
+    /// * `start_x`, `start_y`, `start_z` — Start position
+    /// * `end_x`, `end_y`, `end_z` — Goal position
+    /// * `out_points` — Output buffer of `max_points` × (x, y, z) f32
+    /// * `max_points` — Capacity of the output buffer in points
+    ///
+    /// # Returns
+    /// Number of points written, or 0 if no path exists.
+    pub fn nav_path(
+        navmesh: u32,
+        start_x: f32,
+        start_y: f32,
+        start_z: f32,
+        end_x: f32,
+        end_y: f32,
+        end_z: f32,
+        out_points: *mut f32,
+        max_points: u32,
+    ) -> u32;
+
+    /// Evaluate a spline path at a fraction of its length.
+    ///
+    /// `t` follows arc length, so advancing it by `speed * dt / path_length()`
+    /// each tick moves at constant speed. Deterministic, so it is safe to call
+    /// from `update()`.
+    ///
+    /// # Arguments
+    /// * `handle` — Path handle from `rom_path()`
+    /// * `t` — 0.0 (start) to 1.0 (end); clamped on open paths, wraps on closed ones
+    /// * `out_pos` — Output (x, y, z) f32 position, or null to skip
+    /// * `out_tangent` — Output (x, y, z) f32 unit direction of travel, or null to skip
+    ///
+    /// # Returns
+    /// 1 on success, 0 if the handle or a pointer is invalid.
+    pub fn path_eval(handle: u32, t: f32, out_pos: *mut f32, out_tangent: *mut f32) -> u32;
+
+    /// Get the length of a spline path in world units.
+    ///
+    /// # Returns
+    /// Arc length, or 0.0 if the handle is invalid.
+    pub fn path_length(handle: u32) -> f32;
+
+    /// Create the pathfinding grid, replacing any existing one.
+    ///
+    /// Every cell starts with cost 1. The grid is rolled back with the game, so it
+    /// can be created and edited from `update()`.
+    ///
+    /// # Arguments
+    /// * `width`, `height` — Size in cells (width × height at most 16384)
+    ///
+    /// # Returns
+    /// 1 on success, 0 if the size is invalid.
+    pub fn grid_create(width: u32, height: u32) -> u32;
+
+    /// Set the cost of entering a grid cell.
+    ///
+    /// # Arguments
+    /// * `x`, `y` — Cell coordinates
+    /// * `cost` — 0 = blocked, 1-255 = relative cost (1 = open ground)
+    pub fn grid_set_cost(x: u32, y: u32, cost: u32);
+
+    /// Find the cheapest path between two grid cells.
+    ///
+    /// Paths move in 8 directions without cutting the corners of blocked cells.
+    /// The path includes the start and goal cells and is truncated to `max_cells`.
+    /// Deterministic, so it is safe to call from `update()`.
+    ///
+    /// # Arguments
+    /// * `start_x`, `start_y` — Start cell (may be blocked)
+    /// * `goal_x`, `goal_y` — Goal cell
+    /// * `out_cells` — Output buffer of `max_cells` × (x, y) u32
+    /// * `max_cells` — Capacity of the output buffer in cells
+    ///
+    /// # Returns
+    /// Number of cells written, or 0 if no path exists.
+    pub fn grid_path(
+        start_x: u32,
+        start_y: u32,
+        goal_x: u32,
+        goal_y: u32,
+        out_cells: *mut u32,
+        max_cells: u32,
+    ) -> u32;
+}
@@ -0,0 +1,56 @@
+//! 2D Physics (Host-Simulated Boxes and Circles)
+
+#[cfg(target_arch = "wasm32")]
+#[link(wasm_import_module = "env")]
+extern "C" {
+    /// Create a host-simulated 2D physics body.
+    ///
+    /// Bodies move by their velocity (plus gravity) on each `physics_step()` and are pushed
+    /// out of each other. The world is rolled back, so create bodies from `update()`.
+    ///
+    /// # Arguments
+    /// * `shape` — `body_shape::AABB` or `body_shape::CIRCLE`
+    /// * `x`, `y` — Center position
+    /// * `w`, `h` — Box width and height; for circles `w` is the radius and `h` is ignored
+    /// * `flags` — `body_flag::*` (static bodies never move, sensors only report contacts)
+    ///
+    /// # Returns
+    /// Body handle (>0) on success, 0 if the world (128 bodies) is full or the shape is invalid.
+    pub fn body_create(shape: u32, x: f32, y: f32, w: f32, h: f32, flags: u32) -> u32;
+
+    /// Remove a physics body.
+    pub fn body_destroy(handle: u32);
+
+    /// Set a body's velocity in units per second.
+    ///
+    /// Static bodies keep the velocity but never move.
+    pub fn body_velocity(handle: u32, vx: f32, vy: f32);
+
+    /// Move a body without changing its velocity (spawns, teleports, moving platforms).
+    pub fn body_set_position(handle: u32, x: f32, y: f32);
+
+    /// Get a body's center X position.
+    ///
+    /// # Returns
+    /// X position, or 0.0 if the handle is invalid.
+    pub fn body_x(handle: u32) -> f32;
+
+    /// Get a body's center Y position.
+    ///
+    /// # Returns
+    /// Y position, or 0.0 if the handle is invalid.
+    pub fn body_y(handle: u32) -> f32;
+
+    /// Get the number of live physics bodies.
+    pub fn body_count() -> u32;
+
+    /// Set the acceleration applied to moving bodies in units per second² (default 0, 0).
+    pub fn physics_gravity(x: f32, y: f32);
+
+    /// Move every body by one tick and resolve overlaps.
+    ///
+    /// Call once per `update()`; the step length follows the tick rate. Each touching pair is
+    /// passed to your `on_contact(a, b)` export (handles, `a < b`, in handle order) before this
+    /// returns, and queued as an `event_kind::BODY_CONTACT` event.
+    pub fn physics_step();
+}
@@ -110,4 +110,46 @@ extern "C" {
         major_segments: u32,
         minor_segments: u32,
     ) -> u32;
+
+    /// Combine two meshes into one covering the space inside either. **Init-only.**
+    ///
+    /// Both meshes must be created earlier in `init()` and should be closed.
+    /// The result is a new flat-shaded mesh without UVs; the inputs are unchanged.
+    ///
+    /// Returns a mesh handle (>0), or 0 on failure.
+    pub fn mesh_union(a: u32, b: u32) -> u32;
+
+    /// Carve mesh `b` out of mesh `a` (tunnels, damage holes). **Init-only.**
+    ///
+    /// Both meshes must be created earlier in `init()` and should be closed.
+    /// The result is a new flat-shaded mesh without UVs; the inputs are unchanged.
+    ///
+    /// Returns a mesh handle (>0), or 0 on failure.
+    pub fn mesh_subtract(a: u32, b: u32) -> u32;
+
+    /// Keep only the space inside both meshes. **Init-only.**
+    ///
+    /// Both meshes must be created earlier in `init()` and should be closed.
+    /// The result is a new flat-shaded mesh without UVs; the inputs are unchanged.
+    ///
+    /// Returns a mesh handle (>0), or 0 on failure.
+    pub fn mesh_intersect(a: u32, b: u32) -> u32;
+
+    /// Recompute a mesh's normals from its triangles. **Init-only.**
+    ///
+    /// Edges where faces meet at more than `smooth_angle_deg` stay sharp
+    /// (0 = flat shading, 180 = smooth everywhere). Use after displacing vertices.
+    /// The mesh must be created earlier in `init()`; it gains normals if it had
+    /// none, and its tangents (if any) are regenerated to match.
+    ///
+    /// Returns 1 on success, 0 on failure.
+    pub fn mesh_recompute_normals(handle: u32, smooth_angle_deg: f32) -> u32;
+
+    /// Generate tangents from a mesh's UVs and normals for normal mapping. **Init-only.**
+    ///
+    /// The mesh must be created earlier in `init()` and have UVs and normals.
+    /// Call after `mesh_recompute_normals()` if the mesh was displaced.
+    ///
+    /// Returns 1 on success, 0 on failure.
+    pub fn mesh_generate_tangents(handle: u32) -> u32;
 }
@@ -0,0 +1,57 @@
+//! Projectiles (Host-Simulated Movement and Collision)
+
+#[cfg(target_arch = "wasm32")]
+#[link(wasm_import_module = "env")]
+extern "C" {
+    /// Spawn a host-simulated projectile.
+    ///
+    /// Projectiles fly in a straight line and are collided against the targets
+    /// passed to `projectile_step()`. The pool is rolled back, so spawn from `update()`.
+    ///
+    /// # Arguments
+    /// * `desc_ptr` — Pointer to a 48-byte description: position (3 × f32),
+    ///   velocity (3 × f32, units/s), radius (f32), lifetime in ticks (u32, 0 = until it hits),
+    ///   flags (u32, `projectile_flag::*`), max_hits (u32), target_mask (u32), user_data (u32)
+    ///
+    /// # Returns
+    /// Projectile handle (>0) on success, 0 if the pool (256) is full.
+    pub fn projectile_spawn(desc_ptr: *const u8) -> u32;
+
+    /// Remove a projectile before it hits anything or expires.
+    pub fn projectile_despawn(handle: u32);
+
+    /// Move every projectile by one tick and collide it with targets.
+    ///
+    /// Call once per `update()`, then read hits with `projectile_next_hit()`.
+    /// Each projectile hits at most one target per step, and never the same target twice in a row.
+    ///
+    /// # Arguments
+    /// * `targets_ptr` — Pointer to `target_count` 20-byte targets: position (3 × f32),
+    ///   radius (f32), layers (u32, matched against each projectile's target_mask)
+    /// * `target_count` — Number of targets (up to 4096)
+    pub fn projectile_step(targets_ptr: *const u8, target_count: u32);
+
+    /// Read the next hit from the last `projectile_step()`.
+    ///
+    /// # Arguments
+    /// * `out_ptr` — Pointer to a 24-byte hit: projectile handle (u32), target index (u32),
+    ///   user_data (u32), impact position (3 × f32)
+    ///
+    /// # Returns
+    /// 1 if a hit was written, 0 when there are no more hits.
+    pub fn projectile_next_hit(out_ptr: *mut u8) -> u32;
+
+    /// List live projectiles for drawing.
+    ///
+    /// # Arguments
+    /// * `out_ptr` — Pointer to `max_count` 20-byte entries: handle (u32), user_data (u32),
+    ///   position (3 × f32)
+    /// * `max_count` — Capacity of the output buffer in entries
+    ///
+    /// # Returns
+    /// Number of entries written.
+    pub fn projectile_list(out_ptr: *mut u8, max_count: u32) -> u32;
+
+    /// Get the number of live projectiles.
+    pub fn projectile_count() -> u32;
+}
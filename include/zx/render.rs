@@ -66,4 +66,59 @@ extern "C" {
     /// Note: z_index only affects ordering within the same pass_id.
     /// Default: 0 (resets each frame)
     pub fn z_index(n: u32);
+
+    /// Set how 2D draws combine with what is already on screen.
+    ///
+    /// # Arguments
+    /// * `mode` — `blend_mode::ALPHA` (default), `ADDITIVE`, `MULTIPLY`, `SCREEN` or `SUBTRACT`
+    ///
+    /// Affects screen-space sprites, rects, lines and text until changed.
+    /// Blended draws composite over everything else at their z-index (3D and the
+    /// environment included) and never write depth. Alpha still dithers in every mode.
+    /// Default: alpha (resets each frame)
+    pub fn blend_mode_2d(mode: u32);
+
+    /// Set how 3D draws combine with what is already on screen.
+    ///
+    /// # Arguments
+    /// * `mode` — `blend_mode::ALPHA` (default), `ADDITIVE`, `MULTIPLY`, `SCREEN` or `SUBTRACT`
+    ///
+    /// Affects meshes and `draw_triangles()` until changed. Blended draws render after
+    /// the opaque scene and the environment, sorted back to front within each viewport,
+    /// and never write depth. Alpha still dithers in every mode.
+    /// Default: alpha (resets each frame)
+    pub fn blend_mode_3d(mode: u32);
+
+    /// Offset the sort distance of blended 3D draws.
+    ///
+    /// # Arguments
+    /// * `bias` — World units added to the view distance (positive = further back,
+    ///   negative = on top)
+    ///
+    /// Use when automatic sorting picks the wrong order, e.g. a hazard circle under a
+    /// larger translucent dome.
+    /// Default: 0.0 (resets each frame)
+    pub fn sort_bias(bias: f32);
+
+    /// Get statistics for the last rendered frame.
+    ///
+    /// Writes draw_calls, triangles, triangles_culled, texture_binds (4 × u32)
+    /// to `out_ptr`. `triangles_culled` counts `draw_mesh()` triangles skipped
+    /// by frustum culling. Peers render different frames, so use these for
+    /// display or profiling only, never for game logic.
+    ///
+    /// Returns 1 on success, 0 if `out_ptr` is invalid.
+    pub fn render_stats(out_ptr: *mut u32) -> u32;
+
+    /// Enable the screen-space ambient occlusion post pass.
+    ///
+    /// Darkens creases and contact points of 3D geometry in every viewport that
+    /// drew meshes. Screen-space 2D and the sky are not affected. Persists across
+    /// frames until changed.
+    ///
+    /// # Arguments
+    /// * `radius` — Sample radius in world units (how far creases reach)
+    /// * `intensity` — Occlusion strength (0 = none, 1 = full)
+    /// * `enabled` — Non-zero to enable, 0 to disable
+    pub fn post_ssao(radius: f32, intensity: f32, enabled: u32);
 }
@@ -0,0 +1,54 @@
+//! Teams & Scoreboard
+
+#[cfg(target_arch = "wasm32")]
+#[link(wasm_import_module = "env")]
+extern "C" {
+    /// Assign a player to a team.
+    ///
+    /// Teams are rolled back with the game, so this is safe to call from `update()`.
+    ///
+    /// # Arguments
+    /// * `player` — Player index (0-7)
+    /// * `team` — Team number (1-8), or 0 for no team
+    pub fn team_set(player: u32, team: u32);
+
+    /// Get a player's team.
+    ///
+    /// # Returns
+    /// Team number (1-8), or 0 if the player has no team.
+    pub fn team_get(player: u32) -> u32;
+
+    /// Get a team's score (the sum of its players' scores).
+    pub fn team_score(team: u32) -> i32;
+
+    /// Add to a player's score (negative values subtract).
+    ///
+    /// Ignored once the match has ended.
+    pub fn score_add(player: u32, delta: i32);
+
+    /// Set a player's score.
+    ///
+    /// Ignored once the match has ended.
+    pub fn score_set(player: u32, score: i32);
+
+    /// Get a player's score.
+    pub fn score_get(player: u32) -> i32;
+
+    /// End the match, freezing all scores.
+    pub fn match_end();
+
+    /// Check if the match has ended.
+    ///
+    /// # Returns
+    /// 1 if `match_end()` has been called since the last reset, 0 otherwise.
+    pub fn match_is_over() -> u32;
+
+    /// Reset all scores and start a new match. Team assignments are kept.
+    pub fn match_reset();
+
+    /// Draw the built-in scoreboard overlay.
+    ///
+    /// Lists players ranked by team score, then player score, centered in the
+    /// current viewport. Call from `render()`; color and z-index are preserved.
+    pub fn scoreboard_draw();
+}
@@ -0,0 +1,65 @@
+//! World Streaming (Chunked Level Loading)
+
+#[cfg(target_arch = "wasm32")]
+#[link(wasm_import_module = "env")]
+extern "C" {
+    /// Set the slot pool streamed chunks are copied into (init-only).
+    ///
+    /// The pool is a `slot_size * slot_count` byte buffer in game memory that the
+    /// game must not use for anything else. Call before `chunk_register()`.
+    ///
+    /// # Arguments
+    /// * `ptr` — Start of the pool
+    /// * `slot_size` — Bytes per slot; every registered chunk must fit in one
+    /// * `slot_count` — Number of slots (1..=64)
+    ///
+    /// # Returns
+    /// 1 on success, 0 on invalid input.
+    pub fn chunk_pool(ptr: *mut u8, slot_size: u32, slot_count: u32) -> u32;
+
+    /// Set the chunk grid and streaming distances (init-only).
+    ///
+    /// Cell (x, z) spans `x * cell_size` to `(x + 1) * cell_size` on the X and Z axes.
+    /// Chunks load when their center comes within `load_radius` of the focus and unload
+    /// once it is further than `unload_radius`, so crossing a border back and forth
+    /// doesn't reload anything.
+    ///
+    /// # Arguments
+    /// * `cell_size` — World units per cell (default 64)
+    /// * `load_radius` — Load distance (default 96)
+    /// * `unload_radius` — Unload distance, at least `load_radius` (default 128)
+    ///
+    /// # Returns
+    /// 1 on success, 0 on invalid input.
+    pub fn chunk_grid(cell_size: f32, load_radius: f32, unload_radius: f32) -> u32;
+
+    /// Register the chunk at grid cell (x, z) (init-only).
+    ///
+    /// # Arguments
+    /// * `x`, `z` — Grid cell
+    /// * `id_ptr` — Pointer to the ROM data asset ID holding the chunk's bytes
+    /// * `id_len` — Length of the asset ID
+    ///
+    /// # Returns
+    /// Chunk handle (>0) on success, 0 if the asset is missing or larger than a slot.
+    pub fn chunk_register(x: i32, z: i32, id_ptr: *const u8, id_len: u32) -> u32;
+
+    /// Stream chunks around a focus point (usually the camera or the local player).
+    ///
+    /// Loads nearby chunks into free slots, nearest first, and frees slots of chunks
+    /// that moved out of range. Residency is rolled back, so call from `update()`.
+    /// Each load and unload is queued for `event_poll()` (`event_kind::CHUNK_*`).
+    ///
+    /// # Returns
+    /// Number of loaded chunks.
+    pub fn chunk_update(x: f32, z: f32) -> u32;
+
+    /// Get a loaded chunk's data.
+    ///
+    /// # Returns
+    /// Pointer to the chunk's slot in the pool, or 0 if the cell isn't loaded.
+    pub fn chunk_data(x: i32, z: i32) -> u32;
+
+    /// Get the number of loaded chunks.
+    pub fn chunk_loaded_count() -> u32;
+}
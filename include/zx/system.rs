@@ -36,6 +36,24 @@ extern "C" {
     /// Exits the game and returns to the library.
     pub fn quit();
 
+    /// Writes the 32-byte content hash of the running ROM.
+    ///
+    /// The hash is SHA-256 of the ROM file, so it identifies an exact build
+    /// (useful for showing a build identifier in menus). Netplay peers must
+    /// have matching hashes.
+    ///
+    /// # Returns
+    /// 1 on success, 0 if the hash is unknown (nothing is written).
+    pub fn rom_hash(out_ptr: *mut u8) -> u32;
+
+    /// Records an analytics event, e.g. `"difficulty"` = 2 or `"car"` = 5.
+    ///
+    /// `name` must be 1-32 characters of `a-z`, `0-9`, `_`, `.` or `-`.
+    /// The host only keeps a count per (name, value) pair, and only sends the
+    /// totals to the developer if the player opted in to analytics in the
+    /// privacy settings. Always safe to call; there is no return value.
+    pub fn analytics_event(name_ptr: *const u8, name_len: u32, value: i32);
+
     /// Returns a deterministic random u32 from the host's seeded RNG.
     /// Always use this instead of external random sources for rollback compatibility.
     pub fn random() -> u32;
@@ -52,7 +70,76 @@ extern "C" {
     /// Uses host's seeded RNG for rollback compatibility.
     pub fn random_f32_range(min: f32, max: f32) -> f32;
 
-    /// Returns the number of players in the session (1-4).
+    /// Creates an independent RNG stream seeded with `seed`.
+    /// Draws from a stream never affect `random()` or other streams, so cosmetic
+    /// rolls can't shift gameplay rolls. Streams are restored by rollback; up to 16 can exist.
+    ///
+    /// # Returns
+    /// Stream handle, or 0 if all streams are in use.
+    pub fn rng_create(seed: u64) -> u32;
+
+    /// Returns the next random u32 from a stream (0 for an invalid handle).
+    pub fn rng_next(handle: u32) -> u32;
+
+    /// Returns a random i32 in range [min, max) from a stream.
+    pub fn rng_range(handle: u32, min: i32, max: i32) -> i32;
+
+    /// Frees a stream so its slot can be reused.
+    ///
+    /// # Returns
+    /// 1 if the stream existed, 0 otherwise.
+    pub fn rng_free(handle: u32) -> u32;
+
+    /// Calls the game's exported `on_timer(callback_id)` once, `ticks` updates from now.
+    ///
+    /// Timers run on the deterministic tick (before `update()`) and are restored
+    /// by rollback, so they are safe for netplay. Up to 32 timers can be pending.
+    ///
+    /// # Returns
+    /// Timer handle for `timer_cancel()`, or 0 if all timers are in use.
+    pub fn timer_after(ticks: u32, callback_id: u32) -> u32;
+
+    /// Calls the game's exported `on_timer(callback_id)` every `ticks` updates until cancelled.
+    ///
+    /// # Returns
+    /// Timer handle for `timer_cancel()`, or 0 if all timers are in use.
+    pub fn timer_every(ticks: u32, callback_id: u32) -> u32;
+
+    /// Cancels a pending timer.
+    ///
+    /// # Returns
+    /// 1 if the timer was pending, 0 otherwise.
+    pub fn timer_cancel(handle: u32) -> u32;
+
+    /// Pops up to `max` of the oldest unread host events into `out_ptr`.
+    ///
+    /// Each event is a 36-byte record: `kind: u32`, `data: [u32; 5]`,
+    /// `position: [f32; 3]`. See `event_kind` for the kinds and their data.
+    /// Events are restored by rollback, so poll them from `update()`. Up to 64
+    /// events are queued; older events are overwritten when the queue is full.
+    ///
+    /// # Returns
+    /// The number of events written.
+    pub fn event_poll(out_ptr: *mut u8, max: u32) -> u32;
+
+    /// Registers a region of game memory to be saved and restored on rollback.
+    ///
+    /// By default rollback snapshots all of linear memory. Once any region is
+    /// registered, only registered regions are snapshotted, so keep every value
+    /// `update()` changes inside one. Init-only; up to 64 regions, which must not
+    /// overlap.
+    ///
+    /// # Returns
+    /// 1 on success, 0 if the region is empty, out of bounds, overlaps another, or
+    /// init() has finished.
+    pub fn state_register(ptr: *const u8, len: u32) -> u32;
+
+    /// Returns the number of players in the session (1-8).
+    ///
+    /// Controllers connecting and disconnecting mid-game call the optional
+    /// `on_player_join(player)` / `on_player_leave(player)` exports at the start of
+    /// the tick, before `update()`. Controllers connected at startup join on the
+    /// first tick.
     pub fn player_count() -> u32;
 
     /// Returns a bitmask of which players are local to this client.
@@ -60,6 +147,173 @@ extern "C" {
     /// Example: `(local_player_mask() & (1 << player_id)) != 0` checks if player is local.
     pub fn local_player_mask() -> u32;
 
+    /// Writes the name of a local player's controller to `out_ptr` (UTF-8).
+    ///
+    /// Names are "Keyboard" or the name the gamepad reports. They are only known
+    /// for local players and differ between machines, so use them for display
+    /// ("P3 joined: DualSense") and never for game logic.
+    ///
+    /// # Returns
+    /// Bytes written (at most `max`), or 0 for remote players and empty slots.
+    pub fn controller_name(player: u32, out_ptr: *mut u8, max: u32) -> u32;
+
+    /// Sets how a remote player's input is predicted while it is in flight.
+    ///
+    /// Rollback repeats a player's last input until their real input arrives,
+    /// which makes a missed dodge or dash replay for several ticks. Pick a
+    /// policy per player (see `predict_policy::*`):
+    /// * `HOLD` (default) — repeat the last input
+    /// * `NEUTRAL` — release every button and center the sticks
+    /// * `CUSTOM` — call the game's `predict_input(player, ticks) -> *const u8`
+    /// export, which returns a pointer to a predicted input in the console's
+    /// input layout, or 0 to keep holding. `ticks` counts ticks past the last
+    /// confirmed input (1 = first predicted tick); the player's held input is
+    /// readable through the normal input functions.
+    ///
+    /// `predict_input()` must not change game state: it is not rolled back.
+    pub fn predict_policy(player: u32, policy: u32);
+
+    /// Enables (1) or disables (0) voice chat for a player.
+    ///
+    /// For the local player this turns microphone capture and playback on or off;
+    /// for a remote player it controls whether their voice is played (on by default).
+    /// Voice is opt-in and presentation-only: it never affects the simulation,
+    /// so it is safe to call from `update()`. Has no effect outside netplay.
+    pub fn voice_enable(player: u32, on: u32);
+
+    /// Disables voice chat for the local player and drops any buffered audio.
+    pub fn voice_disable();
+
+    /// Returns 1 if voice chat is enabled for the local player, 0 otherwise.
+    pub fn voice_is_enabled() -> u32;
+
+    /// Mutes (1) or unmutes (0) incoming voice from a player.
+    pub fn voice_set_muted(player: u32, muted: u32);
+
+    /// Returns 1 if the player is muted, 0 otherwise.
+    pub fn voice_is_muted(player: u32) -> u32;
+
+    /// Returns 1 if voice from a remote player is currently playing, 0 otherwise.
+    ///
+    /// Useful for drawing a speaking indicator next to the player.
+    pub fn voice_is_speaking(player: u32) -> u32;
+
+    /// Enables positional attenuation: speakers fade out linearly with distance
+    /// and are silent beyond `max_distance` world units. Pass 0 to disable.
+    ///
+    /// Positions follow `listener_set_n()`; the two calls below override them.
+    pub fn voice_set_positional(max_distance: f32);
+
+    /// Sets the listener position used for positional attenuation.
+    pub fn voice_set_listener(x: f32, y: f32, z: f32);
+
+    /// Sets a player's position used for positional attenuation.
+    pub fn voice_set_player_position(player: u32, x: f32, y: f32, z: f32);
+
+    /// Sends a UTF-8 text message to all players (truncated to 128 bytes).
+    ///
+    /// Your own messages are echoed back through `chat_poll()`, so a chat log
+    /// renders the same way in local and online sessions.
+    pub fn chat_send(ptr: *const u8, len: u32);
+
+    /// Pops the oldest unread chat message into a 136-byte record.
+    ///
+    /// Record layout: `sender: u32`, `len: u32`, `text: [u8; 128]` (UTF-8).
+    /// Returns 1 if a message was written, 0 if there are none.
+    pub fn chat_poll(out_ptr: *mut u8) -> u32;
+
+    /// Sends a canned quick-chat message by index (0-7).
+    ///
+    /// 0 Help!, 1 On my way!, 2 Wait for me!, 3 Go go go!,
+    /// 4 Nice!, 5 Thanks!, 6 Sorry!, 7 Good game!
+    pub fn quick_chat(id: u32);
+
+    /// Places a world-space ping visible to all players (see `ping_kind::*`).
+    pub fn ping(x: f32, y: f32, z: f32, kind: u32);
+
+    /// Pops the oldest unread ping into a 20-byte record.
+    ///
+    /// Record layout: `sender: u32`, `kind: u32`, `x: f32`, `y: f32`, `z: f32`.
+    /// Returns 1 if a ping was written, 0 if there are none.
+    pub fn ping_poll(out_ptr: *mut u8) -> u32;
+
+    /// Hosts an in-game lobby named by the UTF-8 string at `name_ptr`.
+    ///
+    /// `max_players` is clamped between 2 and the ROM's `max_players`. Progress is
+    /// reported through `lobby_state()` and the optional `on_lobby_state(state)`
+    /// export. Lobbies are only available in the standalone player with a
+    /// rendezvous server configured; returns 1 if the request was queued.
+    pub fn lobby_create(name_ptr: *const u8, name_len: u32, max_players: u32) -> u32;
+
+    /// Writes up to `max` open lobbies for this ROM as 40-byte records.
+    ///
+    /// Record layout: `id: u32`, `players: u8`, `max_players: u8`,
+    /// `name_len: u8`, pad `u8`, `name: [u8; 32]` (UTF-8). Each call also
+    /// requests a fresh listing, so results lag by a moment.
+    ///
+    /// # Returns
+    /// The number of lobbies written.
+    pub fn lobby_list(out_ptr: *mut u8, max: u32) -> u32;
+
+    /// Joins a lobby by its id from `lobby_list()`. Returns 1 if queued.
+    pub fn lobby_join(id: u32) -> u32;
+
+    /// Starts the match (host only, once another player has joined).
+    ///
+    /// Every player's game then restarts from `init()` as a netplay session.
+    /// Returns 1 if queued, 0 if the local player isn't hosting.
+    pub fn lobby_start() -> u32;
+
+    /// Leaves the current lobby (closes it when hosting).
+    pub fn lobby_leave();
+
+    /// Returns the current lobby state (see `lobby_state::*`).
+    pub fn lobby_state() -> u32;
+
+    /// Returns the number of players in the current lobby, including you.
+    pub fn lobby_player_count() -> u32;
+
+    /// Writes the tournament series standings into a 44-byte record.
+    ///
+    /// Record layout (all `u32`): `best_of`, `round` (1-based), `champion`
+    /// (player index, or 0xFF while undecided), then `wins[8]` per player.
+    /// Returns 1 if the game is running as part of a series, 0 otherwise.
+    ///
+    /// Standings only change between rounds; each round is a fresh session.
+    pub fn series_state(out_ptr: *mut u8) -> u32;
+
+    /// Reports the winner of the current series round (0xFF for a draw).
+    ///
+    /// The host loads the next round after this frame unless the series has
+    /// been decided, in which case the game keeps running with the final
+    /// standings. Only the first report per round counts.
+    pub fn series_round_end(winner: u32);
+
+    /// Returns the seed for today's daily challenge.
+    ///
+    /// Every player gets the same seed on the same UTC day, so runs generated
+    /// from it are identical for everybody. Fixed when the game is loaded.
+    pub fn daily_seed() -> u64;
+
+    /// Writes today's daily leaderboard category (`daily-YYYY-MM-DD`, 16 bytes).
+    ///
+    /// Returns bytes written (at most `max_len`).
+    pub fn daily_category(out_ptr: *mut u8, max_len: u32) -> u32;
+
+    /// Grants a cross-ROM unlock token to every game from this publisher.
+    ///
+    /// The publisher is the registry author the ROM was downloaded from, so
+    /// games can only grant and read their own publisher's tokens. Grants are
+    /// permanent and saved immediately. Returns 1 if the token is now held, 0 if
+    /// tokens are unavailable (e.g. a sideloaded ROM). Always 1 in netplay.
+    pub fn token_grant(id: u32) -> u32;
+
+    /// Checks whether any game from this publisher has granted a token.
+    ///
+    /// Returns 1 if held, 0 otherwise. Always 0 in netplay, where each peer
+    /// has its own tokens.
+    pub fn token_has(id: u32) -> u32;
+
     /// Saves data to a slot.
     ///
     /// Slot semantics:
@@ -101,9 +355,99 @@ extern "C" {
     /// 0 on success, 1 if invalid slot.
     pub fn delete(slot: u32) -> u32;
 
+    /// Registers the transform the host records for ghosts.
+    ///
+    /// `transform_ptr` points to 7 `f32` values in game memory: position
+    /// (x, y, z) followed by a rotation quaternion (x, y, z, w). While a
+    /// recording is active the host copies it after every `update()`.
+    /// Pass 0 to unregister.
+    pub fn ghost_register(transform_ptr: *const f32);
+
+    /// Starts recording the registered transform into a ghost slot.
+    ///
+    /// # Arguments
+    /// * `slot` — Ghost slot (0-3)
+    ///
+    /// # Returns
+    /// 0 on success, 1 if invalid slot, 2 if no transform is registered.
+    pub fn ghost_record_begin(slot: u32) -> u32;
+
+    /// Stops recording.
+    ///
+    /// If `save` is non-zero the recording replaces the ghost in its slot and
+    /// is written alongside the save data, so best-lap ghosts survive restarts.
+    /// Otherwise the recording is discarded.
+    ///
+    /// # Returns
+    /// Number of recorded frames (0 if nothing was recording).
+    pub fn ghost_record_end(save: u32) -> u32;
+
+    /// Returns the number of frames in a ghost slot (0 if empty).
+    pub fn ghost_frames(slot: u32) -> u32;
+
+    /// Writes the transform recorded `tick` ticks into a ghost.
+    ///
+    /// Writes 7 `f32` values to `out_ptr` in the same layout as
+    /// `ghost_register()`.
+    ///
+    /// # Returns
+    /// 1 on success, 0 if the slot is empty or `tick` is past the end.
+    pub fn ghost_playback(slot: u32, tick: u32, out_ptr: *mut f32) -> u32;
+
+    /// Capture the frame being drawn into a thumbnail slot.
+    ///
+    /// The host shrinks the finished frame to 160x90 and stores it in the slot once
+    /// the current frame has been rendered, so call this from `render()` on the frame
+    /// you want kept (e.g. right after saving). Thumbnails are kept next to the
+    /// game's save data and are not rolled back.
+    ///
+    /// # Arguments
+    /// * `slot` — Thumbnail slot (0-7)
+    ///
+    /// # Returns
+    /// 0 on success, 1 if the slot is invalid.
+    pub fn thumbnail_capture(slot: u32) -> u32;
+
+    /// Returns 1 if a thumbnail slot holds a capture, 0 if it is empty or invalid.
+    pub fn thumbnail_exists(slot: u32) -> u32;
+
+    /// Empty a thumbnail slot (e.g. when its save is deleted).
+    ///
+    /// # Returns
+    /// 0 on success, 1 if the slot is invalid.
+    pub fn thumbnail_clear(slot: u32) -> u32;
+
+    /// Set the tick rate (how often `update()` runs). Must be called during `init()`.
+    ///
+    /// Rollback windows and input delay are counted in ticks, so at 120 Hz the same
+    /// 8-tick rollback window covers half the latency it does at 60 Hz (and re-simulating
+    /// it costs twice the CPU per second); at 30 Hz it covers twice the latency.
+    /// Online sessions always run at the ROM's netplay tick rate; 24 Hz is local-only.
+    ///
+    /// # Arguments
+    /// * `rate` — `tick_rate::HZ_24`, `HZ_30`, `HZ_60` (default) or `HZ_120`
+    pub fn set_tick_rate(rate: u32);
+
     /// Set the clear/background color. Must be called during `init()`.
     ///
     /// # Arguments
     /// * `color` — Color in 0xRRGGBBAA format (default: black)
     pub fn set_clear_color(color: u32);
+
+    /// Enable PS1-style retro rendering for all 3D meshes. Must be called during `init()`.
+    ///
+    /// # Arguments
+    /// * `vertex_snap_bits` — 0 = off, N = snap vertices to a screen grid 2^N cells tall (0-10, 8 ≈ 455x256)
+    /// * `affine_textures` — 0 = perspective-correct (default), 1 = affine texture warping
+    pub fn retro_mode(vertex_snap_bits: u32, affine_textures: u32);
+
+    /// Constrain the final image to a palette. Must be called during `init()`.
+    ///
+    /// Each pixel of the palette texture (row-major, alpha ignored) is one color, up to 256.
+    /// The texture must be uncompressed RGBA8 and loaded during `init()`.
+    ///
+    /// # Arguments
+    /// * `texture` — Palette texture handle
+    /// * `dither` — 0 = nearest color, 1 = 4x4 ordered dithering
+    pub fn palette_mode(texture: u32, dither: u32);
 }
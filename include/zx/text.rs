@@ -44,6 +44,38 @@ extern "C" {
         angle_deg: f32,
     );
 
+    /// Draw a texture as a nine-slice panel (corners keep their size at any panel size).
+    ///
+    /// # Arguments
+    /// * `texture` — Texture handle
+    /// * `left`, `top`, `right`, `bottom` — Border widths in texture pixels
+    /// * `color` — Tint color (0xRRGGBBAA)
+    ///
+    /// Panels smaller than their borders shrink the borders to fit.
+    pub fn draw_nine_slice(
+        texture: u32,
+        x: f32,
+        y: f32,
+        w: f32,
+        h: f32,
+        left: f32,
+        top: f32,
+        right: f32,
+        bottom: f32,
+        color: u32,
+    );
+
+    /// Draw a save slot thumbnail captured with `thumbnail_capture()`.
+    ///
+    /// # Arguments
+    /// * `slot` — Thumbnail slot (0-7)
+    /// * `x`, `y` — Top-left corner in pixels
+    /// * `w`, `h` — Size in pixels (thumbnails are 160x90)
+    ///
+    /// Draws nothing if the slot is empty. Uses the current color and blend mode like
+    /// `draw_sprite()`; the bound texture is preserved.
+    pub fn thumbnail_draw(slot: u32, x: f32, y: f32, w: f32, h: f32);
+
     /// Draw a solid color rectangle.
     pub fn draw_rect(x: f32, y: f32, w: f32, h: f32);
 
@@ -55,6 +87,28 @@ extern "C" {
     /// * `size` — Font size in pixels
     pub fn draw_text(ptr: *const u8, len: u32, x: f32, y: f32, size: f32);
 
+    /// Draw text along a path (arcs, rings, curved titles).
+    ///
+    /// # Arguments
+    /// * `ptr` — Pointer to UTF-8 string data
+    /// * `len` — Length in bytes
+    /// * `points_ptr` — Pointer to `count` × 2 f32 (x, y) screen points forming a polyline
+    /// * `count` — Number of points (at least 2)
+    /// * `size` — Font size in pixels
+    /// * `color` — Text color (0xRRGGBBAA), for this call only
+    ///
+    /// Glyphs follow the path from the first point, standing on its left side
+    /// (above it when the path runs left to right). Text past the end of the
+    /// path is cut off.
+    pub fn draw_text_path(
+        ptr: *const u8,
+        len: u32,
+        points_ptr: *const f32,
+        count: u32,
+        size: f32,
+        color: u32,
+    );
+
     /// Measure the width of text when rendered.
     ///
     /// # Arguments
@@ -93,6 +147,75 @@ extern "C" {
     /// Rendered as 16 line segments.
     pub fn draw_circle_outline(x: f32, y: f32, radius: f32, thickness: f32);
 
+    /// Draw an edge-of-screen indicator pointing at an offscreen world position.
+    ///
+    /// # Arguments
+    /// * `x`, `y`, `z` — Target position in world units
+    /// * `icon` — Texture handle drawn at the indicator (0 = arrow only)
+    /// * `color` — Icon tint and arrow color (0xRRGGBBAA)
+    ///
+    /// Projects the target with the current camera. Nothing is drawn if it is
+    /// inside the current viewport; otherwise a 24px icon and an arrow are
+    /// clamped to the viewport edge facing the target (behind-camera targets
+    /// point the way to turn). Color and bound texture are preserved.
+    ///
+    /// # Returns
+    /// 1 if the indicator was drawn, 0 if the target is on screen.
+    pub fn offscreen_indicator(x: f32, y: f32, z: f32, icon: u32, color: u32) -> u32;
+
+    /// Draw an objective marker that follows a world position on and off screen.
+    ///
+    /// # Arguments
+    /// * `x`, `y`, `z` — Target position in world units
+    /// * `icon` — Texture handle for the marker icon (0 = label only)
+    /// * `label_ptr` — Pointer to UTF-8 label text
+    /// * `label_len` — Label length in bytes (0 = no label)
+    ///
+    /// On screen, the icon is centered on the target at 32px, shrinking with
+    /// distance down to 12px, with the label centered underneath in the bound
+    /// font. Offscreen, it draws an `offscreen_indicator()` at the screen edge
+    /// instead. Uses the current color; color and bound texture are preserved.
+    ///
+    /// # Returns
+    /// 1 if the marker was drawn on screen, 0 if it was drawn as an edge indicator.
+    pub fn marker_draw(
+        x: f32,
+        y: f32,
+        z: f32,
+        icon: u32,
+        label_ptr: *const u8,
+        label_len: u32,
+    ) -> u32;
+
+    /// Show a toast notification in the corner of the screen.
+    ///
+    /// # Arguments
+    /// * `ptr` — Pointer to UTF-8 text
+    /// * `len` — Text length in bytes (1-64)
+    /// * `icon` — Texture handle drawn left of the text (0 = none)
+    /// * `duration` — Seconds on screen including fades (clamped to 0.45-30)
+    ///
+    /// Toasts are drawn by the host above everything else, stacked in the top-right
+    /// corner of the visible canvas with the oldest on top, and fade in and out. Up
+    /// to 4 are shown at once; later ones wait for a free slot. They are not rolled
+    /// back: a toast raised again on the same tick with the same text and icon (as
+    /// happens when update() is re-simulated) is ignored, and so are toasts past a
+    /// queue of 16.
+    pub fn toast(ptr: *const u8, len: u32, icon: u32, duration: f32);
+
+    /// Restrict 2D drawing to a rectangle until the matching clip_pop().
+    ///
+    /// # Arguments
+    /// * `x`, `y` — Top-left corner in pixels, relative to the current viewport
+    /// * `w`, `h` — Size in pixels
+    ///
+    /// Affects sprites, rects, lines, and text. Nested clips are intersected
+    /// with the enclosing one (up to 16 deep). 3D drawing is not clipped.
+    pub fn clip_push(x: f32, y: f32, w: f32, h: f32);
+
+    /// Restore the clip rectangle from before the last clip_push().
+    pub fn clip_pop();
+
     /// Load a fixed-width bitmap font.
     ///
     /// # Arguments
@@ -123,8 +246,59 @@ extern "C" {
         char_count: u32,
     ) -> u32;
 
+    /// Add another codepoint range to a loaded font (init-only).
+    ///
+    /// Glyphs for the range follow the font's existing glyphs in the atlas grid,
+    /// so one texture can hold several pages (ASCII, Latin-1, Cyrillic, Kana...).
+    /// draw_text() decodes UTF-8 and looks each character up across all ranges.
+    ///
+    /// # Arguments
+    /// * `font_handle` — Font from `load_font()` or `load_font_ex()`
+    /// * `first_codepoint` — Unicode codepoint of the range's first glyph
+    /// * `char_count` — Number of glyphs in the range
+    /// * `widths_ptr` — Pointer to char_count u8 widths, or null for the font's cell width
+    ///
+    /// Ranges must not overlap. Widths may not exceed the font's cell width.
+    pub fn font_add_range(
+        font_handle: u32,
+        first_codepoint: u32,
+        char_count: u32,
+        widths_ptr: *const u8,
+    );
+
     /// Bind a font for subsequent draw_text() calls.
     ///
     /// Pass 0 for the built-in 8×8 monospace font.
     pub fn font_bind(font_handle: u32);
+
+    /// Choose the language used by `str_get()`.
+    ///
+    /// A code matches a table language exactly (ignoring case) or by its primary
+    /// subtag, so "pt-BR" also selects a "pt" column.
+    ///
+    /// # Arguments
+    /// * `lang_ptr` — Pointer to a language code (e.g. "fr", "pt-BR")
+    /// * `lang_len` — Length of the language code
+    ///
+    /// # Returns
+    /// 1 if a loaded table has the language, 0 otherwise (lookups then use each
+    /// table's first language).
+    pub fn locale_set(lang_ptr: *const u8, lang_len: u32) -> u32;
+
+    /// Copy the text for a key in the current locale into a buffer.
+    ///
+    /// Falls back to the table's first language when the key has no text in the
+    /// current one. Text longer than the buffer is cut at the last whole UTF-8
+    /// character that fits; it is not NUL-terminated.
+    ///
+    /// # Arguments
+    /// * `key_ptr` — Pointer to the key string (e.g. "menu.start")
+    /// * `key_len` — Length of the key
+    /// * `out_ptr` — Destination buffer
+    /// * `max_len` — Size of the destination buffer
+    ///
+    /// # Returns
+    /// Full length of the text in bytes (more than `max_len` if it was cut),
+    /// or 0 if no loaded table has the key.
+    pub fn str_get(key_ptr: *const u8, key_len: u32, out_ptr: *mut u8, max_len: u32) -> u32;
 }
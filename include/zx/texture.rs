@@ -16,6 +16,61 @@ extern "C" {
     /// Bind a texture to slot 0 (albedo).
     pub fn texture_bind(handle: u32);
 
+    /// Bind one sprite of a texture atlas to slot 0 (albedo).
+    ///
+    /// # Arguments
+    /// * `handle` — Atlas handle from rom_atlas()
+    /// * `sprite_index` — Sprite index (order of `sprites` in nether.toml)
+    ///
+    /// Sprite and billboard draws then sample only that sprite; region draws
+    /// are relative to it. Binding another texture to slot 0 clears it.
+    pub fn atlas_bind(handle: u32, sprite_index: u32);
+
+    /// Combine textures into a texture array.
+    ///
+    /// # Arguments
+    /// * `handles_ptr` — Pointer to `count` texture handles, one per layer
+    /// * `count` — Number of layers (1-256)
+    ///
+    /// Textures must be loaded in the same init() call and share one size and
+    /// format. Layers are copied, so they count against VRAM again.
+    ///
+    /// # Returns
+    /// Texture array handle (>0) on success, 0 on failure.
+    pub fn texture_array_create(handles_ptr: *const u32, count: u32) -> u32;
+
+    /// Bind one layer of a texture array to slot 0 (albedo).
+    ///
+    /// # Arguments
+    /// * `handle` — Texture array handle from texture_array_create()
+    /// * `layer` — Layer index (order of the handles passed in)
+    ///
+    /// Sprite and billboard draws then sample that layer. Switching layers of
+    /// one array doesn't start a new batch. Meshes don't sample texture arrays.
+    pub fn texture_array_bind(handle: u32, layer: u32);
+
+    /// Load a palette for indexed textures (init-only).
+    ///
+    /// # Arguments
+    /// * `colors_ptr` — Pointer to an array of u32 colors (0xRRGGBBAA)
+    /// * `count` — Number of colors (1-256)
+    ///
+    /// Color N is drawn for texels with index N. Pack textures with
+    /// `quality = "indexed"` to store palette indices.
+    ///
+    /// # Returns
+    /// Palette handle (>0) on success, 0 on failure.
+    pub fn palette_load(colors_ptr: *const u32, count: u32) -> u32;
+
+    /// Set the palette for subsequent draws of indexed textures.
+    ///
+    /// # Arguments
+    /// * `palette` — Palette handle from `palette_load()`, or 0 to unbind
+    ///
+    /// Only applies while an indexed texture is bound to slot 0; other textures
+    /// draw unchanged, so the palette can stay bound.
+    pub fn palette_bind(palette: u32);
+
     /// Bind a texture to a specific slot.
     ///
     /// # Arguments
@@ -0,0 +1,120 @@
+//! Update Throttling Helpers
+//!
+//! Spread the cost of large entity pools across ticks: entities near a viewer
+//! update every tick, mid-range ones every 2 ticks and far ones every 4.
+//! Scheduling depends only on positions, the tick and each entity's index, so
+//! it is deterministic and safe to use in `update()`.
+
+/// Number of update buckets (every tick, every 2 ticks, every 4 ticks).
+pub const UPDATE_BUCKET_COUNT: usize = 3;
+
+/// Distance thresholds for throttled entity updates.
+///
+/// Viewers must come from game state (player positions, a simulated camera),
+/// not from the render camera, or rollback replays will schedule differently.
+///
+/// # Example
+/// ```rust,ignore
+/// const THROTTLE: UpdateThrottle = UpdateThrottle::new(20.0, 60.0);
+///
+/// struct World {
+///     enemy_positions: [[f32; 3]; MAX_ENEMIES],
+///     enemies: [Enemy; MAX_ENEMIES],
+///     buckets: [u8; MAX_ENEMIES],
+/// }
+///
+/// fn update_enemies(world: &mut World) {
+///     let viewers = [player_pos(0), player_pos(1)];
+///     THROTTLE.partition(&world.enemy_positions, &viewers, &mut world.buckets);
+///     for (i, ticks) in UpdateThrottle::due(&world.buckets, tick_count()) {
+///         world.enemies[i].think(DT * ticks as f32);
+///     }
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct UpdateThrottle {
+    /// Entities within this distance of a viewer update every tick
+    pub near: f32,
+    /// Entities within this distance update every 2 ticks; farther ones every 4
+    pub far: f32,
+}
+
+impl UpdateThrottle {
+    /// Create a throttle with the given bucket distances.
+    pub const fn new(near: f32, far: f32) -> Self {
+        Self { near, far }
+    }
+
+    /// Bucket for an entity: 0 (every tick), 1 (every 2) or 2 (every 4).
+    ///
+    /// Uses the distance to the nearest viewer, so split-screen players each
+    /// keep their surroundings at full rate. With no viewers every entity is
+    /// in bucket 0.
+    pub fn bucket(&self, position: [f32; 3], viewers: &[[f32; 3]]) -> u8 {
+        if viewers.is_empty() {
+            return 0;
+        }
+        let mut nearest = f32::INFINITY;
+        for viewer in viewers {
+            let dx = position[0] - viewer[0];
+            let dy = position[1] - viewer[1];
+            let dz = position[2] - viewer[2];
+            let dist_sq = dx * dx + dy * dy + dz * dz;
+            if dist_sq < nearest {
+                nearest = dist_sq;
+            }
+        }
+        if nearest <= self.near * self.near {
+            0
+        } else if nearest <= self.far * self.far {
+            1
+        } else {
+            2
+        }
+    }
+
+    /// Assign a bucket to every entity and return the count in each bucket.
+    ///
+    /// `buckets[i]` receives the bucket of `positions[i]`; extra entries in
+    /// either slice are ignored.
+    pub fn partition(
+        &self,
+        positions: &[[f32; 3]],
+        viewers: &[[f32; 3]],
+        buckets: &mut [u8],
+    ) -> [u32; UPDATE_BUCKET_COUNT] {
+        let mut counts = [0; UPDATE_BUCKET_COUNT];
+        for (position, bucket) in positions.iter().zip(buckets.iter_mut()) {
+            *bucket = self.bucket(*position, viewers);
+            counts[*bucket as usize] += 1;
+        }
+        counts
+    }
+
+    /// Ticks between updates for a bucket: 1, 2 or 4.
+    pub const fn interval(bucket: u8) -> u32 {
+        let shift = if bucket > 2 { 2 } else { bucket };
+        1 << shift
+    }
+
+    /// Whether entity `index` in `bucket` updates on `tick`.
+    ///
+    /// Entities are staggered by index, so each tick runs half of bucket 1 and
+    /// a quarter of bucket 2 rather than all of them at once.
+    pub const fn is_due(bucket: u8, index: u32, tick: u64) -> bool {
+        tick.wrapping_add(index as u64)
+            .is_multiple_of(Self::interval(bucket) as u64)
+    }
+
+    /// Entities due on `tick`, as `(index, ticks)` in index order.
+    ///
+    /// `ticks` is the bucket's interval — scale time steps by it. An entity
+    /// that just changed bucket may be off by a few ticks once.
+    pub fn due(buckets: &[u8], tick: u64) -> impl Iterator<Item = (usize, u32)> + '_ {
+        buckets
+            .iter()
+            .enumerate()
+            .filter(move |&(i, &bucket)| Self::is_due(bucket, i as u32, tick))
+            .map(|(i, &bucket)| (i, Self::interval(bucket)))
+    }
+}
@@ -42,4 +42,14 @@ extern "C" {
 
     /// Push a uniform scale transform.
     pub fn push_scale_uniform(s: f32);
+
+    /// Push a mesh socket's transform.
+    ///
+    /// Call after binding the mesh's pose (`set_bones()` or `keyframe_bind()`)
+    /// with the mesh's transform current. A socket on a bone follows it.
+    ///
+    /// # Arguments
+    /// * `mesh` — Mesh handle from rom_mesh
+    /// * `socket` — Socket ID from rom_mesh_socket
+    pub fn push_socket(mesh: u32, socket: u32);
 }
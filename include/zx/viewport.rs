@@ -43,4 +43,24 @@ extern "C" {
     /// Call this at the end of split-screen rendering to restore full-screen
     /// coordinates for HUD elements or between frames.
     pub fn viewport_clear();
+
+    /// Choose how the 960×540 canvas is scaled to the window.
+    ///
+    /// # Arguments
+    /// * `mode` — `scale_mode::INTEGER` (pixel-perfect, black bars),
+    ///   `scale_mode::FIT` (largest size with bars) or `scale_mode::STRETCH`
+    ///   (fill the window, distorting the aspect ratio)
+    ///
+    /// Overrides the player's scale setting until the game restarts.
+    /// Presentation-only, so it is safe to call from `update()` or `render()`.
+    pub fn display_scale_mode(mode: u32);
+
+    /// Get the part of the canvas that is visible in the window.
+    ///
+    /// Writes x, y, width, height (4 × f32, canvas pixels) to `out_ptr`. This is
+    /// the full canvas unless integer scaling crops it in a window smaller than
+    /// 960×540. Keep HUD elements inside this rectangle.
+    ///
+    /// Returns 1 on success, 0 if `out_ptr` is invalid.
+    pub fn safe_area(out_ptr: *mut f32) -> u32;
 }
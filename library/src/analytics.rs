@@ -0,0 +1,104 @@
+//! Analytics upload
+//!
+//! Player processes write aggregated analytics batches to an outbox, but only
+//! when the user has opted in. The library uploads them in the background on
+//! startup, and clears the outbox if consent has since been withdrawn.
+
+use anyhow::{Context, Result};
+use nethercore_core::analytics::{AnalyticsBatch, outbox_dir};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// API base URL for production
+const API_BASE_URL: &str = "https://api.nethercore.systems";
+
+/// Timeout for each upload request
+const UPLOAD_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Batches uploaded per library launch (the rest wait for the next one)
+const MAX_UPLOADS_PER_LAUNCH: usize = 32;
+
+/// Upload or discard queued analytics in a background thread
+pub fn process_outbox(consent: bool) {
+    let Some(data_dir) = nethercore_core::app::config::data_dir() else {
+        return;
+    };
+    let outbox = outbox_dir(&data_dir);
+    if !outbox.exists() {
+        return;
+    }
+
+    if !consent {
+        discard_outbox(&outbox);
+        return;
+    }
+
+    std::thread::spawn(move || {
+        if let Err(e) = upload_outbox(&outbox) {
+            tracing::debug!("Analytics upload skipped: {:#}", e);
+        }
+    });
+}
+
+/// Delete every queued batch
+pub fn discard_outbox(outbox: &Path) {
+    for path in batch_files(outbox) {
+        if let Err(e) = std::fs::remove_file(&path) {
+            tracing::warn!("Failed to remove analytics batch {}: {}", path.display(), e);
+        }
+    }
+}
+
+/// Queued batch files, oldest first
+fn batch_files(outbox: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = std::fs::read_dir(outbox) else {
+        return Vec::new();
+    };
+    let mut files: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .collect();
+    files.sort();
+    files
+}
+
+fn upload_outbox(outbox: &Path) -> Result<()> {
+    let rt = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .context("Failed to create runtime")?;
+
+    rt.block_on(async {
+        let client = reqwest::Client::builder()
+            .timeout(UPLOAD_TIMEOUT)
+            .build()
+            .context("Failed to create HTTP client")?;
+
+        for path in batch_files(outbox).into_iter().take(MAX_UPLOADS_PER_LAUNCH) {
+            let bytes = std::fs::read(&path)
+                .with_context(|| format!("Failed to read {}", path.display()))?;
+            let Ok(batch) = serde_json::from_slice::<AnalyticsBatch>(&bytes) else {
+                // Corrupt batch: drop it rather than retrying forever
+                let _ = std::fs::remove_file(&path);
+                continue;
+            };
+
+            let url = format!("{}/api/games/{}/analytics", API_BASE_URL, batch.game_id);
+            let response = client
+                .post(&url)
+                .header(reqwest::header::CONTENT_TYPE, "application/json")
+                .body(bytes)
+                .send()
+                .await
+                .context("Network error")?;
+
+            // Keep the batch for the next launch unless the server took it
+            if !response.status().is_success() {
+                anyhow::bail!("HTTP {}", response.status());
+            }
+            std::fs::remove_file(&path)
+                .with_context(|| format!("Failed to remove {}", path.display()))?;
+        }
+        Ok(())
+    })
+}
@@ -3,6 +3,7 @@
 //! Holds the NchsSession during lobby phase and tracks UI state.
 
 use nethercore_core::library::LocalGame;
+use nethercore_core::net::CandidateKind;
 use nethercore_core::net::nchs::{
     JoinReject, JoinRejectReason, LobbyState, NchsError, NchsEvent, NchsRole, NchsSession,
     SessionStart,
@@ -22,6 +23,8 @@ pub struct LobbySession {
     pub error: Option<String>,
     /// Cached local IPs for host display
     pub local_ips: Vec<String>,
+    /// Public address discovered via STUN (host display)
+    pub public_addr: Option<String>,
 }
 
 /// Lobby UI phases
@@ -43,7 +46,14 @@ pub enum LobbyPhase {
 
 impl LobbySession {
     /// Create a new host lobby session
-    pub fn new_host(session: NchsSession, game: LocalGame) -> Self {
+    pub fn new_host(mut session: NchsSession, game: LocalGame) -> Self {
+        // Discover our public address before any guests connect
+        let public_addr = session
+            .gather_candidates()
+            .iter()
+            .find(|c| c.kind == CandidateKind::ServerReflexive)
+            .map(|c| c.addr.to_string());
+
         Self {
             session,
             game,
@@ -51,6 +61,7 @@ impl LobbySession {
             local_ready: false,
             error: None,
             local_ips: get_local_ips(),
+            public_addr,
         }
     }
 
@@ -63,6 +74,7 @@ impl LobbySession {
             local_ready: false,
             error: None,
             local_ips: Vec::new(),
+            public_addr: None,
         }
     }
 
@@ -11,6 +11,9 @@ pub mod lobby;
 pub use init::AppError;
 pub use lobby::{LobbyPhase, LobbySession};
 
+use std::process::Child;
+use std::time::{Duration, Instant};
+
 use eframe::egui;
 
 use crate::registry::{ConnectionMode, PlayerOptions};
@@ -18,7 +21,7 @@ use crate::ui::{LibraryUi, LobbyUi, MultiplayerDialog, UiAction};
 use nethercore_core::app::config::Config;
 use nethercore_core::library::{LocalGame, RomLoaderRegistry};
 use nethercore_core::net::nchs::{NchsConfig, NchsSession, NetworkConfig, PlayerInfo};
-use nethercore_shared::{MAX_ROM_BYTES, read_file_with_limit};
+use nethercore_shared::{MAX_ROM_BYTES, netplay_rom_hash, read_file_with_limit, rom_content_hash};
 use zx_common::ZXRom;
 
 /// Library application state
@@ -39,6 +42,10 @@ pub struct App {
     rom_loader_registry: RomLoaderRegistry,
     /// Last error message (for displaying in UI)
     last_error: Option<String>,
+    /// Last time the user interacted with the library
+    last_activity: Instant,
+    /// Running attract screen (idle screensaver) process
+    screensaver: Option<Child>,
 }
 
 impl Default for App {
@@ -51,6 +58,7 @@ impl App {
     /// Create a new library application
     pub fn new() -> Self {
         let config = nethercore_core::app::config::load();
+        crate::analytics::process_outbox(config.privacy.analytics);
         let rom_loader_registry = crate::registry::create_rom_loader_registry();
         let local_games = nethercore_core::library::get_local_games_with_loaders(
             &nethercore_core::library::DefaultDataDirProvider,
@@ -66,6 +74,50 @@ impl App {
             local_games,
             rom_loader_registry,
             last_error: None,
+            last_activity: Instant::now(),
+            screensaver: None,
+        }
+    }
+
+    /// Starts the attract screen once the library has sat idle for the
+    /// configured time. Never triggers while another window (e.g. a running
+    /// game) has focus or a lobby is open.
+    fn update_screensaver(&mut self, ctx: &egui::Context) {
+        if let Some(child) = &mut self.screensaver {
+            if let Ok(None) = child.try_wait() {
+                ctx.request_repaint_after(Duration::from_secs(1));
+                return;
+            }
+            self.screensaver = None;
+            self.last_activity = Instant::now();
+        }
+
+        let (active, focused) =
+            ctx.input(|i| (!i.events.is_empty() || i.pointer.is_moving(), i.focused));
+        if active || !focused || self.lobby.is_some() {
+            self.last_activity = Instant::now();
+        }
+
+        let minutes = self.config.video.screensaver_minutes;
+        if minutes == 0 {
+            return;
+        }
+        let timeout = Duration::from_secs(u64::from(minutes) * 60);
+        let idle = self.last_activity.elapsed();
+        if idle < timeout {
+            ctx.request_repaint_after(timeout - idle);
+            return;
+        }
+
+        match crate::registry::launch_attract_screen() {
+            Ok(child) => {
+                tracing::info!("Library idle for {} min, starting attract screen", minutes);
+                self.screensaver = Some(child);
+            }
+            Err(e) => {
+                tracing::warn!("{:#}", e);
+                self.last_activity = Instant::now();
+            }
         }
     }
 
@@ -211,6 +263,11 @@ impl App {
                     tracing::info!("Settings saved successfully");
                 }
 
+                // Withdrawn consent also drops anything not yet uploaded
+                if !self.config.privacy.analytics {
+                    crate::analytics::process_outbox(false);
+                }
+
                 // Apply fullscreen setting
                 let is_fullscreen = ctx.input(|i| i.viewport().fullscreen).unwrap_or(false);
                 if is_fullscreen != self.config.video.fullscreen {
@@ -380,6 +437,7 @@ impl App {
         let rom_bytes = read_file_with_limit(&game.rom_path, MAX_ROM_BYTES)?;
         let rom = ZXRom::from_bytes(&rom_bytes)?;
         let mut netplay = rom.metadata.netplay;
+        netplay.rom_hash = netplay_rom_hash(&rom_content_hash(&rom_bytes));
 
         // Override max_players with UI selection (capped at ROM max)
         netplay.max_players = max_players.min(netplay.max_players);
@@ -394,6 +452,8 @@ impl App {
             },
             network_config: NetworkConfig::default(),
             save_config: None,
+            daily: None,
+            nat: self.config.netplay.nat_config(),
         };
 
         // Create host session
@@ -411,7 +471,8 @@ impl App {
         // Load ROM to get netplay metadata
         let rom_bytes = read_file_with_limit(&game.rom_path, MAX_ROM_BYTES)?;
         let rom = ZXRom::from_bytes(&rom_bytes)?;
-        let netplay = rom.metadata.netplay;
+        let mut netplay = rom.metadata.netplay;
+        netplay.rom_hash = netplay_rom_hash(&rom_content_hash(&rom_bytes));
 
         // Create NCHS config
         let config = NchsConfig {
@@ -423,6 +484,8 @@ impl App {
             },
             network_config: NetworkConfig::default(),
             save_config: None,
+            daily: None,
+            nat: self.config.netplay.nat_config(),
         };
 
         // Create guest session
@@ -453,6 +516,7 @@ impl App {
             // Set the local player handle for this process
             let local_handle = lobby.session.local_handle().unwrap_or(0);
             session_config.local_player_handle = local_handle;
+            session_config.relayed_peers = lobby.session.relayed_peers();
 
             // Serialize session config to temp file
             let session_file =
@@ -500,6 +564,8 @@ impl eframe::App for App {
             let _ = nethercore_core::app::config::save(&self.config);
         }
 
+        self.update_screensaver(ctx);
+
         let mut ui_action = None;
 
         // Poll lobby session if active
@@ -0,0 +1,64 @@
+//! Daily challenge seeds
+//!
+//! Fetches today's daily challenge seed from the Nethercore API, so the
+//! platform can re-roll a day that turned out unwinnable. Players derive a
+//! seed from the date when none is passed, so a failed fetch never blocks
+//! the launch.
+
+use serde::Deserialize;
+use std::time::Duration;
+
+/// API base URL for production
+const API_BASE_URL: &str = "https://api.nethercore.systems";
+
+/// Timeout for the seed request
+const DAILY_FETCH_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// Response from the daily challenge endpoint
+#[derive(Debug, Deserialize)]
+struct DailyResponse {
+    /// Decimal u64 (a string, since JSON numbers lose precision past 2^53)
+    seed: String,
+}
+
+/// Fetch today's daily challenge seed, or `None` if the platform is unreachable
+pub fn fetch_daily_seed() -> Option<u64> {
+    let rt = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .ok()?;
+
+    match rt.block_on(fetch_daily_seed_async()) {
+        Ok(seed) => Some(seed),
+        Err(e) => {
+            tracing::info!("Daily seed fetch failed, deriving from the date: {}", e);
+            None
+        }
+    }
+}
+
+async fn fetch_daily_seed_async() -> Result<u64, String> {
+    let client = reqwest::Client::builder()
+        .timeout(DAILY_FETCH_TIMEOUT)
+        .build()
+        .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
+
+    let url = format!("{}/api/daily", API_BASE_URL);
+    let response = client
+        .get(&url)
+        .send()
+        .await
+        .map_err(|e| format!("Network error: {}", e))?;
+    if !response.status().is_success() {
+        return Err(format!("HTTP {}", response.status()));
+    }
+
+    let daily: DailyResponse = response
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse response: {}", e))?;
+    daily
+        .seed
+        .parse()
+        .map_err(|e| format!("Invalid seed {:?}: {}", daily.seed, e))
+}
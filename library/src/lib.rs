@@ -9,7 +9,9 @@
 //! - Clean separation of concerns
 //! - Easy addition of new console types
 
+pub mod analytics;
 pub mod app;
+pub mod daily;
 pub mod protocol;
 pub mod registry;
 pub mod ui;
@@ -13,6 +13,7 @@
 
 use anyhow::Result;
 use nethercore_core::library::{DataDirProvider, LocalGame, get_local_games, resolve_game_id};
+use nethercore_library::daily::fetch_daily_seed;
 use nethercore_library::registry::{ConnectionMode, ConsoleRegistry, PlayerOptions};
 use nethercore_library::update::check_and_prompt_for_update;
 use nethercore_shared::ROM_FORMATS;
@@ -215,6 +216,12 @@ fn parse_player_options(args: &[String]) -> PlayerOptions {
                     options.replay_script = Some(PathBuf::from(path));
                 }
             }
+            "--best-of" => {
+                if let Some(rounds) = iter.next().and_then(|n| n.parse().ok()) {
+                    options.best_of = Some(rounds);
+                }
+            }
+            "--daily" => options.daily_seed = fetch_daily_seed(),
             _ => {}
         }
     }
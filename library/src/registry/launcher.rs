@@ -41,6 +41,10 @@ pub struct PlayerOptions {
     pub preview_asset: Option<String>,
     /// Replay script path (.ncrs file)
     pub replay_script: Option<PathBuf>,
+    /// Best-of-N tournament series length (local sessions only)
+    pub best_of: Option<u32>,
+    /// Daily challenge seed fetched from the platform (None derives it from the date)
+    pub daily_seed: Option<u64>,
 }
 
 /// Target for the player launcher (ROM path or game reference)
@@ -158,6 +162,18 @@ impl PlayerLauncher {
         self
     }
 
+    /// Play a best-of-N series of local rounds.
+    pub fn best_of(mut self, rounds: u32) -> Self {
+        self.options.best_of = Some(rounds);
+        self
+    }
+
+    /// Use a platform-supplied seed for today's daily challenge.
+    pub fn daily_seed(mut self, seed: u64) -> Self {
+        self.options.daily_seed = Some(seed);
+        self
+    }
+
     /// Host a multiplayer game on the specified port.
     pub fn host(mut self, port: u16) -> Self {
         self.options.connection = Some(ConnectionMode::Host { port });
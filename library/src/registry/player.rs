@@ -1,7 +1,7 @@
 //! Player process launching and command building.
 
 use std::path::{Path, PathBuf};
-use std::process::Command;
+use std::process::{Child, Command};
 use std::{ffi::OsStr, io};
 
 use anyhow::{Context, Result};
@@ -121,6 +121,14 @@ fn build_cargo_run_player_command(
         cmd.arg("--players");
         cmd.arg(players.to_string());
     }
+    if let Some(best_of) = options.best_of {
+        cmd.arg("--best-of");
+        cmd.arg(best_of.to_string());
+    }
+    if let Some(seed) = options.daily_seed {
+        cmd.arg("--daily-seed");
+        cmd.arg(seed.to_string());
+    }
 
     if let Some(ref connection) = options.connection {
         match connection {
@@ -176,6 +184,14 @@ pub(crate) fn build_player_command(
         cmd.arg("--players");
         cmd.arg(players.to_string());
     }
+    if let Some(best_of) = options.best_of {
+        cmd.arg("--best-of");
+        cmd.arg(best_of.to_string());
+    }
+    if let Some(seed) = options.daily_seed {
+        cmd.arg("--daily-seed");
+        cmd.arg(seed.to_string());
+    }
 
     // Add multiplayer connection args
     if let Some(ref connection) = options.connection {
@@ -435,3 +451,14 @@ pub fn run_game_from_path_with_options(path: &Path, options: &PlayerOptions) ->
 
     run_player_with_options(path, console_type, options)
 }
+
+/// Start the ZX attract scene as the library's idle screensaver.
+///
+/// Returns the child process so the library can tell when it was dismissed.
+pub fn launch_attract_screen() -> Result<Child> {
+    let player = find_player_binary(ConsoleType::ZX);
+    Command::new(&player)
+        .args(["--attract", "--fullscreen"])
+        .spawn()
+        .with_context(|| format!("Failed to launch attract screen '{}'", player.display()))
+}
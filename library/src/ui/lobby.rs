@@ -84,6 +84,15 @@ impl LobbyUi {
                         ui.label(format!("Port: {}", port));
                     }
                 });
+                if let Some(public_addr) = &lobby.public_addr {
+                    ui.horizontal(|ui| {
+                        ui.label("Internet Address:");
+                        ui.monospace(public_addr);
+                        if ui.small_button("Copy").clicked() {
+                            action = Some(UiAction::CopyAddress(public_addr.clone()));
+                        }
+                    });
+                }
                 ui.small("Share this with friends to join!");
             }
 
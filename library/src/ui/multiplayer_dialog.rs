@@ -166,7 +166,7 @@ impl MultiplayerDialog {
         // Player count selection
         ui.horizontal(|ui| {
             ui.label("Players:");
-            for n in 2..=4 {
+            for n in 2..=8 {
                 ui.selectable_value(&mut self.players, n, format!("{}", n));
             }
         });
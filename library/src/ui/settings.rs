@@ -45,6 +45,7 @@ impl SettingsUi {
             SettingsAction::PreviewScaleMode(mode) => Some(UiAction::SetScaleMode(mode)),
             SettingsAction::ToggleFullscreen(_) => None, // Not exposed to library
             SettingsAction::SetVolume(_) => None,        // Not exposed to library
+            SettingsAction::SetBusVolume(..) => None,    // Not exposed to library
         }
     }
 }
@@ -4,6 +4,8 @@
 
 use anyhow::{Context, Result};
 use nethercore_core::library::LocalGame;
+use nethercore_shared::api::Author;
+use nethercore_shared::{rom_content_hash, rom_hash_hex};
 use serde::Deserialize;
 use std::path::Path;
 use std::time::Duration;
@@ -29,6 +31,12 @@ struct RomUrlResponse {
     expires_at: String,
 }
 
+/// Response from the game endpoint (only the fields the updater records)
+#[derive(Debug, Deserialize)]
+struct GameResponse {
+    author: Author,
+}
+
 /// Information about an available update
 #[derive(Debug, Clone)]
 pub struct UpdateInfo {
@@ -186,7 +194,18 @@ async fn download_update_async(update: &UpdateInfo, data_dir: &Path) -> Result<(
         .await
         .context("Failed to parse download URL response")?;
 
-    // 2. Download the ROM file
+    // 2. Look up the game's registry author, which keys its unlock tokens
+    let game_endpoint = format!("{}/api/games/{}", API_BASE_URL, update.game_id);
+    let game_response: GameResponse = client
+        .get(&game_endpoint)
+        .send()
+        .await
+        .context("Failed to get game info")?
+        .json()
+        .await
+        .context("Failed to parse game info response")?;
+
+    // 3. Download the ROM file
     tracing::info!("Downloading ROM update from presigned URL...");
     let rom_response = client
         .get(&url_response.url)
@@ -203,13 +222,13 @@ async fn download_update_async(update: &UpdateInfo, data_dir: &Path) -> Result<(
         .await
         .context("Failed to read ROM data")?;
 
-    // 3. Write the ROM file to the game directory
+    // 4. Write the ROM file to the game directory
     let game_dir = data_dir.join("games").join(&update.game_id);
     let rom_path = game_dir.join(&update.rom_filename);
 
     std::fs::write(&rom_path, &rom_bytes).context("Failed to write ROM file")?;
 
-    // 4. Update the manifest with the new version
+    // 5. Update the manifest with the new version and the author it was downloaded from
     let manifest_path = game_dir.join("manifest.json");
     if manifest_path.exists() {
         let manifest_content =
@@ -221,6 +240,9 @@ async fn download_update_async(update: &UpdateInfo, data_dir: &Path) -> Result<(
 
         manifest["version"] = serde_json::Value::String(update.remote_version.clone());
         manifest["updated_at"] = serde_json::Value::String(chrono::Utc::now().to_rfc3339());
+        manifest["registry_author_id"] = serde_json::Value::String(game_response.author.id);
+        manifest["registry_rom_sha256"] =
+            serde_json::Value::String(rom_hash_hex(&rom_content_hash(&rom_bytes)));
 
         let updated_manifest =
             serde_json::to_string_pretty(&manifest).context("Failed to serialize manifest")?;
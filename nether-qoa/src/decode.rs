@@ -72,122 +72,126 @@ pub fn decode_qoa(qoa_data: &[u8], total_samples: usize) -> Result<Vec<i16>, Qoa
 
     let mut output = Vec::with_capacity(total_samples);
     let mut data_idx = 0;
-    let mut lms_states = [QoaLms::new(); 8]; // Max 8 channels
 
     while data_idx + QOA_FRAME_HEADER_SIZE <= qoa_data.len() && output.len() < total_samples {
-        // Read frame header (5 bytes: channels + samples_in_frame + frame_size)
-        let channels = qoa_data[data_idx] as usize;
-        let samples_in_frame =
-            u16::from_be_bytes([qoa_data[data_idx + 1], qoa_data[data_idx + 2]]) as usize;
-        let _frame_size =
-            u16::from_be_bytes([qoa_data[data_idx + 3], qoa_data[data_idx + 4]]) as usize;
-
-        if channels == 0 || channels > 8 {
-            return Err(QoaError::InvalidChannelCount);
+        data_idx += decode_frame(&qoa_data[data_idx..], &mut output)?;
+    }
+
+    Ok(output)
+}
+
+/// Decode a single QOA frame, appending its samples to `output`
+///
+/// Every frame stores its own LMS state, so frames decode independently of
+/// each other. This lets long audio be decoded in chunks (or from any frame
+/// boundary) without keeping the whole clip in memory.
+///
+/// # Arguments
+/// * `frame_data` - QOA data starting at a frame header (may extend past the frame)
+/// * `output` - Buffer the decoded samples (mixed down to mono) are appended to
+///
+/// # Returns
+/// Number of bytes consumed from `frame_data`
+///
+/// # Errors
+/// Returns `QoaError` if the frame is invalid or truncated
+pub fn decode_frame(frame_data: &[u8], output: &mut Vec<i16>) -> Result<usize, QoaError> {
+    if frame_data.len() < QOA_FRAME_HEADER_SIZE {
+        return Err(QoaError::TruncatedData);
+    }
+
+    // Read frame header (5 bytes: channels + samples_in_frame + frame_size)
+    let channels = frame_data[0] as usize;
+    let samples_in_frame = u16::from_be_bytes([frame_data[1], frame_data[2]]) as usize;
+    let _frame_size = u16::from_be_bytes([frame_data[3], frame_data[4]]) as usize;
+
+    if channels == 0 || channels > 8 {
+        return Err(QoaError::InvalidChannelCount);
+    }
+
+    let mut data_idx = QOA_FRAME_HEADER_SIZE;
+    let mut lms_states = [QoaLms::new(); 8]; // Max 8 channels
+
+    // Read LMS state for each channel
+    for lms_state in lms_states.iter_mut().take(channels) {
+        if data_idx + 16 > frame_data.len() {
+            return Err(QoaError::TruncatedData);
         }
 
-        data_idx += QOA_FRAME_HEADER_SIZE;
+        // History (4 x i16, big-endian)
+        for i in 0..4 {
+            lms_state.history[i] = i16::from_be_bytes([
+                frame_data[data_idx + i * 2],
+                frame_data[data_idx + i * 2 + 1],
+            ]) as i32;
+        }
+        data_idx += 8;
+
+        // Weights (4 x i16, big-endian)
+        for i in 0..4 {
+            lms_state.weights[i] = i16::from_be_bytes([
+                frame_data[data_idx + i * 2],
+                frame_data[data_idx + i * 2 + 1],
+            ]) as i32;
+        }
+        data_idx += 8;
+    }
 
-        // Read LMS state for each channel
-        for lms_state in lms_states.iter_mut().take(channels) {
-            if data_idx + 16 > qoa_data.len() {
-                return Err(QoaError::TruncatedData);
-            }
+    // Decode slices
+    let slices_per_channel = samples_in_frame.div_ceil(QOA_SLICE_LEN);
 
-            // History (4 x i16, big-endian)
-            for i in 0..4 {
-                lms_state.history[i] = i16::from_be_bytes([
-                    qoa_data[data_idx + i * 2],
-                    qoa_data[data_idx + i * 2 + 1],
-                ]) as i32;
-            }
+    // For multi-channel, we need to interleave and mix
+    if channels == 1 {
+        // Mono: decode directly to output
+        for slice_idx in 0..slices_per_channel {
+            let slice = read_slice(frame_data, data_idx)?;
             data_idx += 8;
 
-            // Weights (4 x i16, big-endian)
-            for i in 0..4 {
-                lms_state.weights[i] = i16::from_be_bytes([
-                    qoa_data[data_idx + i * 2],
-                    qoa_data[data_idx + i * 2 + 1],
-                ]) as i32;
-            }
-            data_idx += 8;
+            let samples_remaining = samples_in_frame.saturating_sub(slice_idx * QOA_SLICE_LEN);
+            let samples_to_decode = samples_remaining.min(QOA_SLICE_LEN);
+
+            let mut temp = [0i16; QOA_SLICE_LEN];
+            decode_slice(slice, &mut lms_states[0], &mut temp[..samples_to_decode]);
+            output.extend_from_slice(&temp[..samples_to_decode]);
         }
+    } else {
+        // Multi-channel: decode each channel, then mix to mono
+        let mut channel_buffers: Vec<Vec<i16>> = vec![Vec::new(); channels];
 
-        // Decode slices
-        let slices_per_channel = samples_in_frame.div_ceil(QOA_SLICE_LEN);
-
-        // For multi-channel, we need to interleave and mix
-        if channels == 1 {
-            // Mono: decode directly to output
-            for slice_idx in 0..slices_per_channel {
-                if data_idx + 8 > qoa_data.len() {
-                    return Err(QoaError::TruncatedData);
-                }
-
-                let slice = u64::from_be_bytes([
-                    qoa_data[data_idx],
-                    qoa_data[data_idx + 1],
-                    qoa_data[data_idx + 2],
-                    qoa_data[data_idx + 3],
-                    qoa_data[data_idx + 4],
-                    qoa_data[data_idx + 5],
-                    qoa_data[data_idx + 6],
-                    qoa_data[data_idx + 7],
-                ]);
+        for slice_idx in 0..slices_per_channel {
+            for ch in 0..channels {
+                let slice = read_slice(frame_data, data_idx)?;
                 data_idx += 8;
 
                 let samples_remaining = samples_in_frame.saturating_sub(slice_idx * QOA_SLICE_LEN);
                 let samples_to_decode = samples_remaining.min(QOA_SLICE_LEN);
 
                 let mut temp = [0i16; QOA_SLICE_LEN];
-                decode_slice(slice, &mut lms_states[0], &mut temp[..samples_to_decode]);
-                output.extend_from_slice(&temp[..samples_to_decode]);
-            }
-        } else {
-            // Multi-channel: decode each channel, then mix to mono
-            let mut channel_buffers: Vec<Vec<i16>> = vec![Vec::new(); channels];
-
-            for slice_idx in 0..slices_per_channel {
-                for ch in 0..channels {
-                    if data_idx + 8 > qoa_data.len() {
-                        return Err(QoaError::TruncatedData);
-                    }
-
-                    let slice = u64::from_be_bytes([
-                        qoa_data[data_idx],
-                        qoa_data[data_idx + 1],
-                        qoa_data[data_idx + 2],
-                        qoa_data[data_idx + 3],
-                        qoa_data[data_idx + 4],
-                        qoa_data[data_idx + 5],
-                        qoa_data[data_idx + 6],
-                        qoa_data[data_idx + 7],
-                    ]);
-                    data_idx += 8;
-
-                    let samples_remaining =
-                        samples_in_frame.saturating_sub(slice_idx * QOA_SLICE_LEN);
-                    let samples_to_decode = samples_remaining.min(QOA_SLICE_LEN);
-
-                    let mut temp = [0i16; QOA_SLICE_LEN];
-                    decode_slice(slice, &mut lms_states[ch], &mut temp[..samples_to_decode]);
-                    channel_buffers[ch].extend_from_slice(&temp[..samples_to_decode]);
-                }
+                decode_slice(slice, &mut lms_states[ch], &mut temp[..samples_to_decode]);
+                channel_buffers[ch].extend_from_slice(&temp[..samples_to_decode]);
             }
+        }
 
-            // Mix channels to mono (average)
-            let frame_samples = channel_buffers[0].len();
-            for i in 0..frame_samples {
-                let mut sum: i32 = 0;
-                for ch_buf in &channel_buffers {
-                    sum += ch_buf[i] as i32;
-                }
-                output.push((sum / channels as i32) as i16);
+        // Mix channels to mono (average)
+        let frame_samples = channel_buffers[0].len();
+        for i in 0..frame_samples {
+            let mut sum: i32 = 0;
+            for ch_buf in &channel_buffers {
+                sum += ch_buf[i] as i32;
             }
+            output.push((sum / channels as i32) as i16);
         }
     }
 
-    Ok(output)
+    Ok(data_idx)
+}
+
+/// Read the big-endian 64-bit slice at `offset`
+fn read_slice(data: &[u8], offset: usize) -> Result<u64, QoaError> {
+    let bytes = data
+        .get(offset..offset + 8)
+        .ok_or(QoaError::TruncatedData)?;
+    Ok(u64::from_be_bytes(bytes.try_into().unwrap()))
 }
 
 #[cfg(test)]
@@ -247,4 +251,33 @@ mod tests {
         let result = decode_qoa(&[], 100);
         assert_eq!(result, Err(QoaError::FileTooSmall));
     }
+
+    #[test]
+    fn test_decode_frame_by_frame_matches_whole() {
+        let samples: Vec<i16> = (0..12000)
+            .map(|i| ((i as f32 * 0.05).sin() * 8000.0) as i16)
+            .collect();
+        let qoa_data = crate::encode_qoa(&samples);
+        let whole = decode_qoa(&qoa_data, samples.len()).unwrap();
+
+        let mut chunked = Vec::new();
+        let mut offset = 0;
+        let mut frames = 0;
+        while offset < qoa_data.len() {
+            offset += decode_frame(&qoa_data[offset..], &mut chunked).unwrap();
+            frames += 1;
+        }
+
+        assert_eq!(offset, qoa_data.len());
+        assert_eq!(frames, samples.len().div_ceil(crate::QOA_FRAME_SAMPLES));
+        assert_eq!(chunked, whole);
+    }
+
+    #[test]
+    fn test_decode_frame_truncated() {
+        let qoa_data = crate::encode_qoa(&[0i16; 100]);
+        let mut output = Vec::new();
+        let result = decode_frame(&qoa_data[..qoa_data.len() - 1], &mut output);
+        assert_eq!(result, Err(QoaError::TruncatedData));
+    }
 }
@@ -60,7 +60,7 @@ mod decode;
 mod encode;
 mod lms;
 
-pub use decode::{decode_qoa, decode_slice};
+pub use decode::{decode_frame, decode_qoa, decode_slice};
 pub use encode::{encode_qoa, encode_slice};
 pub use lms::QoaLms;
 
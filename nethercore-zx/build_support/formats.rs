@@ -30,7 +30,7 @@ impl FormatFlags {
 
 /// Get valid formats for a render mode
 /// Mode 0: all 32 formats (0-31)
-/// Modes 1-3: 16 formats each (must have NORMAL, optionally TANGENT)
+/// Modes 1-4: 16 formats each (must have NORMAL, optionally TANGENT)
 pub(crate) fn valid_formats_for_mode(mode: u8) -> Vec<u8> {
     // Tangent requires normal: filter out formats with TANGENT but without NORMAL
     let tangent_valid = |f: &u8| {
@@ -43,8 +43,8 @@ pub(crate) fn valid_formats_for_mode(mode: u8) -> Vec<u8> {
         // Mode 0: all combinations except invalid tangent-without-normal
         // Valid: 0-15 (no tangent), 20-23 (tangent+normal), 28-31 (tangent+normal+skinned)
         0 => (0..32).filter(tangent_valid).collect(),
-        // Modes 1-3: require NORMAL, plus tangent validation
-        1..=3 => (0..32)
+        // Modes 1-4: require NORMAL, plus tangent validation
+        1..=4 => (0..32)
             .filter(|&f| f & FORMAT_NORMAL != 0)
             .filter(tangent_valid)
             .collect(),
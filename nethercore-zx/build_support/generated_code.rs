@@ -16,14 +16,14 @@ pub fn get_pregenerated_shader(mode: u8, format: u8) -> Option<&'static str> {
     const FORMAT_TANGENT: u8 = 16;
 
     // Validate mode/format combination
-    if mode > 3 {
+    if mode > 4 {
         return None;
     }
     if format >= 32 {
         return None; // Invalid format
     }
     if mode > 0 && format & FORMAT_NORMAL == 0 {
-        return None; // Modes 1-3 require NORMAL
+        return None; // Modes 1-4 require NORMAL
     }
     if format & FORMAT_TANGENT != 0 && format & FORMAT_NORMAL == 0 {
         return None; // TANGENT requires NORMAL
@@ -34,7 +34,7 @@ pub fn get_pregenerated_shader(mode: u8, format: u8) -> Option<&'static str> {
     //   - 0-15: indices 0-15
     //   - 20-23: indices 16-19
     //   - 28-31: indices 20-23
-    // Modes 1-3: 16 formats each (4-7, 12-15, 20-23, 28-31)
+    // Modes 1-4: 16 formats each (4-7, 12-15, 20-23, 28-31)
     //   - Map format to 0-15 offset: (UV + COLOR*2) + SKINNED*4 + TANGENT*8
     let index = match mode {
         0 => {
@@ -70,6 +70,13 @@ pub fn get_pregenerated_shader(mode: u8, format: u8) -> Option<&'static str> {
                 + if format & FORMAT_TANGENT != 0 { 8 } else { 0 };
             base + offset
         }
+        4 => {
+            let base = 24 + 16 + 16 + 16; // After modes 0-3
+            let offset = (format & 0b0011) as usize
+                + if format & FORMAT_SKINNED != 0 { 4 } else { 0 }
+                + if format & FORMAT_TANGENT != 0 { 8 } else { 0 };
+            base + offset
+        }
         _ => return None,
     };
 
@@ -81,13 +88,13 @@ pub(crate) fn generate() -> Result<GeneratedShaders, Vec<String>> {
     let mut generated_code = String::new();
     generated_code.push_str("// Auto-generated by build.rs - DO NOT EDIT\n");
     generated_code
-        .push_str("// Contains all 72 pregenerated shader permutations (with tangent support)\n\n");
+        .push_str("// Contains all 88 pregenerated shader permutations (with tangent support)\n\n");
 
-    // Generate array of 72 shader sources
-    // Mode 0: 24 formats (0-15, 20-23, 28-31), Modes 1-3: 16 formats each = 24 + 16*3 = 72
+    // Generate array of 88 shader sources
+    // Mode 0: 24 formats (0-15, 20-23, 28-31), Modes 1-4: 16 formats each = 24 + 16*4 = 88
     generated_code
         .push_str("/// Pregenerated shader sources indexed by shader_index(mode, format)\n");
-    generated_code.push_str("pub const PREGENERATED_SHADERS: [&str; 72] = [\n");
+    generated_code.push_str("pub const PREGENERATED_SHADERS: [&str; 88] = [\n");
 
     let mut shader_count = 0usize;
     let mut errors: Vec<String> = Vec::new();
@@ -107,8 +114,8 @@ pub(crate) fn generate() -> Result<GeneratedShaders, Vec<String>> {
         }
     }
 
-    // Modes 1-3: only formats with NORMAL (4-7, 12-15, 20-23, 28-31)
-    for mode in 1u8..=3 {
+    // Modes 1-4: only formats with NORMAL (4-7, 12-15, 20-23, 28-31)
+    for mode in 1u8..=4 {
         for format in formats::valid_formats_for_mode(mode) {
             match generator::generate_shader(mode, format) {
                 Ok(source) => {
@@ -6,14 +6,15 @@ use super::{snippets, sources};
 /// Generate a shader for a specific mode and vertex format
 pub(crate) fn generate_shader(mode: u8, format: u8) -> Result<String, String> {
     // Validate mode
-    if mode > 3 {
-        return Err(format!("Invalid render mode: {} (must be 0-3)", mode));
+    if mode > 4 {
+        return Err(format!("Invalid render mode: {} (must be 0-4)", mode));
     }
 
     // Get the appropriate template
     let template = match mode {
         0 => sources::TEMPLATE_MODE0,
         1 => sources::TEMPLATE_MODE1,
+        4 => sources::TEMPLATE_MODE4,
         _ => "", // Modes 2-3 use BLINNPHONG_COMMON
     };
 
@@ -38,7 +39,7 @@ pub(crate) fn generate_shader(mode: u8, format: u8) -> Result<String, String> {
     shader.push_str(sources::COMMON);
     shader.push('\n');
 
-    if mode >= 2 {
+    if mode == 2 || mode == 3 {
         shader.push_str(sources::BLINNPHONG_COMMON);
     } else {
         shader.push_str(template);
@@ -119,7 +120,7 @@ pub(crate) fn generate_shader(mode: u8, format: u8) -> Result<String, String> {
     );
 
     // Camera position is needed for view-dependent reflection sampling.
-    // Modes 2-3 always need it; mode 0 needs it when normals are present (environment reflection blend).
+    // Modes 2-4 always need it; mode 0 needs it when normals are present (environment reflection blend).
     let needs_camera_pos = mode >= 2 || (mode == 0 && flags.has_normal);
     shader = shader.replace(
         "//VOUT_CAMERA_POS",
@@ -195,13 +196,19 @@ pub(crate) fn generate_shader(mode: u8, format: u8) -> Result<String, String> {
         shader = shader.replace("//VS_VIEW_POS", "");
     }
 
-    // Camera position extraction (modes 2-3, and mode 0 when normals are present)
+    // Camera position extraction (modes 2-4, and mode 0 when normals are present)
     if needs_camera_pos {
         shader = shader.replace("//VS_CAMERA_POS", snippets::VS_CAMERA_POS);
     } else {
         shader = shader.replace("//VS_CAMERA_POS", "");
     }
 
+    // Outline extrusion (mode 4 only, after the world normal is known)
+    shader = shader.replace(
+        "//VS_OUTLINE",
+        if mode == 4 { snippets::VS_OUTLINE } else { "" },
+    );
+
     // Handle skinning with nested replacements
     if flags.has_skinned {
         let mut skinned_code = snippets::VS_SKINNED.to_string();
@@ -261,6 +268,16 @@ pub(crate) fn generate_shader(mode: u8, format: u8) -> Result<String, String> {
         shader = shader.replace("//VS_POSITION", snippets::VS_POSITION_UNSKINNED);
     }
 
+    // Texture coordinate selection is shared by all modes
+    shader = shader.replace(
+        "//FS_TEXCOORD",
+        if flags.has_uv {
+            snippets::FS_TEXCOORD
+        } else {
+            ""
+        },
+    );
+
     // Replace fragment shader placeholders (mode-specific)
     match mode {
         0 => {
@@ -425,6 +442,31 @@ pub(crate) fn generate_shader(mode: u8, format: u8) -> Result<String, String> {
                 "let diffuse_fresnel = vec3<f32>(1.0);",
             );
         }
+        4 => {
+            shader = shader.replace(
+                "//FS_COLOR",
+                if flags.has_color {
+                    snippets::FS_ALBEDO_COLOR
+                } else {
+                    ""
+                },
+            );
+            shader = shader.replace(
+                "//FS_UV",
+                if flags.has_uv {
+                    snippets::FS_ALBEDO_UV
+                } else {
+                    ""
+                },
+            );
+            // Shading normal: use TBN+normal map if tangent data and UVs are present
+            let shading_normal = if flags.has_tangent && flags.has_uv {
+                snippets::FS_SHADING_NORMAL_TANGENT
+            } else {
+                snippets::FS_SHADING_NORMAL
+            };
+            shader = shader.replace("//FS_SHADING_NORMAL", shading_normal);
+        }
         _ => {}
     }
 
@@ -29,12 +29,12 @@ pub(crate) fn run() {
         }
     };
 
-    // Expected: 24 (mode 0) + 16*3 (modes 1-3) = 72 shaders
+    // Expected: 24 (mode 0) + 16*4 (modes 1-4) = 88 shaders
     // Mode 0: formats 0-15 + 20-23 + 28-31 (tangent requires normal)
-    // Modes 1-3: formats 4-7, 12-15, 20-23, 28-31 (all require normal)
+    // Modes 1-4: formats 4-7, 12-15, 20-23, 28-31 (all require normal)
     assert_eq!(
-        generated.shader_count, 72,
-        "Expected 72 shaders, got {}",
+        generated.shader_count, 88,
+        "Expected 88 shaders, got {}",
         generated.shader_count
     );
 
@@ -15,20 +15,29 @@ pub(crate) const VOUT_VIEW_NORMAL: &str = "@location(2) view_normal: vec3<f32>,"
 pub(crate) const VOUT_VIEW_POS: &str = "@location(4) view_position: vec3<f32>,";
 pub(crate) const VOUT_CAMERA_POS: &str =
     "@location(5) @interpolate(flat) camera_position: vec3<f32>,";
-pub(crate) const VOUT_UV: &str = "@location(10) uv: vec2<f32>,";
+// Affine UV copy (location 12) is interpolated in screen space for retro mode
+pub(crate) const VOUT_UV: &str =
+    "@location(10) uv: vec2<f32>,\n    @location(12) @interpolate(linear) uv_affine: vec2<f32>,";
 pub(crate) const VOUT_COLOR: &str = "@location(11) color: vec3<f32>,";
 // Tangent vertex outputs: world-space tangent (location 6) + bitangent sign (location 7)
 pub(crate) const VOUT_TANGENT: &str = "@location(6) world_tangent: vec3<f32>,\n    @location(7) @interpolate(flat) bitangent_sign: f32,";
 // Mode 1: Additional view-space tangent output for matcap normal mapping (location 8)
 pub(crate) const VOUT_VIEW_TANGENT: &str = "@location(8) view_tangent: vec3<f32>,";
 
-pub(crate) const VS_UV: &str = "out.uv = in.uv;";
+pub(crate) const VS_UV: &str = "out.uv = in.uv;\n    out.uv_affine = in.uv;";
 pub(crate) const VS_COLOR: &str = "out.color = in.color;";
-pub(crate) const VS_WORLD_NORMAL: &str = "let normal = unpack_octahedral(in.normal_packed);\n    let world_normal_raw = (model_matrix * vec4<f32>(normal, 0.0)).xyz;\n    out.world_normal = normalize(world_normal_raw);";
+pub(crate) const VS_WORLD_NORMAL: &str = "let normal = normalize(unpack_octahedral(in.normal_packed) + morph.normal);\n    let world_normal_raw = (model_matrix * vec4<f32>(normal, 0.0)).xyz;\n    out.world_normal = normalize(world_normal_raw);";
 pub(crate) const VS_VIEW_NORMAL: &str = "let view_rot = mat3x3<f32>(view_matrix[0].xyz, view_matrix[1].xyz, view_matrix[2].xyz);\n    out.view_normal = normalize(view_rot * out.world_normal);";
 pub(crate) const VS_VIEW_POS: &str = "out.view_position = (view_matrix * model_pos).xyz;";
 pub(crate) const VS_CAMERA_POS: &str =
     "out.camera_position = extract_camera_position(view_matrix);";
+// Mode 4: inverted-hull outline pass pushes the mesh out along its world normal
+pub(crate) const VS_OUTLINE: &str = r#"let outline_state = shading_states[shading_state_idx];
+    if has_flag(outline_state.flags, FLAG_OUTLINE) {
+        let outline_width = bitcast<f32>(outline_state.uniform_set_1);
+        model_pos = vec4<f32>(model_pos.xyz + out.world_normal * outline_width, model_pos.w);
+        out.world_position = model_pos.xyz;
+    }"#;
 
 // Skinned variants of normal outputs.
 pub(crate) const VS_WORLD_NORMAL_SKINNED: &str = "out.world_normal = normalize(final_normal);";
@@ -79,7 +88,7 @@ pub(crate) const VS_SKINNED: &str = r#"// GPU skinning: compute skinned position
                 bone_matrix = bone_matrix * inv_bind;
             }
 
-            skinned_pos += (bone_matrix * vec4<f32>(in.position, 1.0)).xyz * weight;
+            skinned_pos += (bone_matrix * vec4<f32>(vertex_position, 1.0)).xyz * weight;
             //VS_SKINNED_NORMAL
             //VS_SKINNED_TANGENT
         }
@@ -90,7 +99,7 @@ pub(crate) const VS_SKINNED: &str = r#"// GPU skinning: compute skinned position
     //VS_SKINNED_FINAL_TANGENT"#;
 
 pub(crate) const VS_SKINNED_UNPACK_NORMAL: &str =
-    "let input_normal = unpack_octahedral(in.normal_packed);";
+    "let input_normal = normalize(unpack_octahedral(in.normal_packed) + morph.normal);";
 pub(crate) const VS_SKINNED_NORMAL: &str =
     "skinned_normal += (bone_matrix * vec4<f32>(input_normal, 0.0)).xyz * weight;";
 pub(crate) const VS_SKINNED_FINAL_NORMAL: &str = "let final_normal = normalize(skinned_normal);";
@@ -105,13 +114,16 @@ pub(crate) const VS_SKINNED_FINAL_TANGENT: &str = r#"let final_tangent = normali
     let final_tangent_sign = input_tangent_sign;"#;
 
 pub(crate) const VS_POSITION_SKINNED: &str = "let world_pos = vec4<f32>(final_position, 1.0);";
-pub(crate) const VS_POSITION_UNSKINNED: &str = "let world_pos = vec4<f32>(in.position, 1.0);";
+pub(crate) const VS_POSITION_UNSKINNED: &str = "let world_pos = vec4<f32>(vertex_position, 1.0);";
 
 pub(crate) const FS_COLOR: &str = "color *= in.color;";
+// All modes: texture coordinate for this fragment (affine when retro mode asks for it)
+pub(crate) const FS_TEXCOORD: &str =
+    "let uv = select(in.uv, in.uv_affine, has_flag(shading.flags, FLAG_AFFINE_TEXTURES));";
 // Mode 0/1: Color/albedo from texture, with uniform color override support
 // When FLAG_USE_UNIFORM_COLOR is NOT set, use texture alpha for dithering
 pub(crate) const FS_UV: &str = r#"if !has_flag(shading.flags, FLAG_USE_UNIFORM_COLOR) {
-        let tex_sample = sample_filtered(slot0, shading.flags, in.uv);
+        let tex_sample = sample_albedo(shading.flags, uv);
         color *= tex_sample.rgb;
         base_alpha = tex_sample.a;
     }"#;
@@ -123,7 +135,7 @@ pub(crate) const FS_AMBIENT: &str = r#"let shading_normal = in.world_normal;
 // Mode 0 Lambert: ambient with tangent/normal map support
 pub(crate) const FS_AMBIENT_TANGENT: &str = r#"// Build TBN matrix and sample normal map
     let tbn = build_tbn(in.world_tangent, in.world_normal, in.bitangent_sign);
-    let shading_normal = sample_normal_map(slot3, in.uv, tbn, shading.flags);
+    let shading_normal = sample_normal_map(slot3, uv, tbn, shading.flags);
     let ambient = color * sample_epu_ambient(shading.environment_index, shading_normal);
     let albedo = color;"#;
 
@@ -139,13 +151,23 @@ pub(crate) const FS_NORMAL: &str = r#"var final_color = ambient;
         }
     }
 
+    // Scene lights whose range reaches this fragment's light grid cell
+    let scene_range = scene_light_range(in.world_position);
+    for (var i = scene_range.x; i < scene_range.y; i++) {
+        let light = compute_scene_light(scene_lights[light_grid[i]], in.world_position);
+        final_color += lambert_diffuse(shading_normal, light.direction, albedo, light.color);
+    }
+
+    // Baked lights (lightmap_bake)
+    final_color += albedo * in.baked_light;
+
     color = final_color;"#;
 
 pub(crate) const FS_ALBEDO_COLOR: &str = "albedo *= in.color;";
 // Mode 2/3: Albedo from texture, with uniform color override support
 // When FLAG_USE_UNIFORM_COLOR is NOT set, use texture alpha for dithering
 pub(crate) const FS_ALBEDO_UV: &str = r#"if !has_flag(shading.flags, FLAG_USE_UNIFORM_COLOR) {
-        let albedo_sample = sample_filtered(slot0, shading.flags, in.uv);
+        let albedo_sample = sample_albedo(shading.flags, uv);
         albedo *= albedo_sample.rgb;
         base_alpha = albedo_sample.a;
     }"#;
@@ -156,7 +178,7 @@ pub(crate) const FS_SHADING_NORMAL: &str = "let shading_normal = in.world_normal
 // Mode 2/3: Shading normal with tangent/normal map support
 pub(crate) const FS_SHADING_NORMAL_TANGENT: &str = r#"// Build TBN matrix and sample normal map
     let tbn = build_tbn(in.world_tangent, in.world_normal, in.bitangent_sign);
-    let shading_normal = sample_normal_map(slot3, in.uv, tbn, shading.flags);"#;
+    let shading_normal = sample_normal_map(slot3, uv, tbn, shading.flags);"#;
 
 // Mode 1 Matcap: Shading normal (both world and view space) - no tangent
 pub(crate) const FS_MATCAP_SHADING_NORMAL: &str = r#"let shading_world_normal = normalize(in.world_normal);
@@ -167,15 +189,15 @@ pub(crate) const FS_MATCAP_SHADING_NORMAL: &str = r#"let shading_world_normal =
 // Uses both world-space and view-space TBN for world and view normals
 pub(crate) const FS_MATCAP_SHADING_NORMAL_TANGENT: &str = r#"// Build world-space TBN and sample normal map
     let world_tbn = build_tbn(in.world_tangent, in.world_normal, in.bitangent_sign);
-    let shading_world_normal = sample_normal_map(slot3, in.uv, world_tbn, shading.flags);
+    let shading_world_normal = sample_normal_map(slot3, uv, world_tbn, shading.flags);
     // Build view-space TBN for matcap UV calculation (view_tangent passed from VS)
     let view_tbn = build_tbn(in.view_tangent, in.view_normal, in.bitangent_sign);
-    let shading_view_normal = sample_normal_map(slot3, in.uv, view_tbn, shading.flags);"#;
+    let shading_view_normal = sample_normal_map(slot3, uv, view_tbn, shading.flags);"#;
 
 // Mode 2/3: MRE/material texture sampling with override flag support
 // Shared between Mode 2 (MRE = metallic/roughness/emissive) and Mode 3 (SDE = spec_damping/shininess/emissive)
 // The variable name "mat_sample" is generic to work for both modes
-pub(crate) const FS_MODE2_3_TEXTURES_UV: &str = r#"let mat_sample = sample_filtered(slot1, shading.flags, in.uv);
+pub(crate) const FS_MODE2_3_TEXTURES_UV: &str = r#"let mat_sample = sample_filtered(slot1, shading.flags, uv);
     if !has_flag(shading.flags, FLAG_USE_UNIFORM_METALLIC) {
         value0 = mat_sample.r;
     }
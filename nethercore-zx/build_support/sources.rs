@@ -96,6 +96,10 @@ pub(crate) const COMMON: &str = concat!(
         env!("CARGO_MANIFEST_DIR"),
         "/shaders/epu/features/11_band_radiance.wgsl"
     )),
+    include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/shaders/epu/features/12_flash.wgsl"
+    )),
     // Layer dispatch
     include_str!(concat!(
         env!("CARGO_MANIFEST_DIR"),
@@ -127,6 +131,10 @@ pub(crate) const TEMPLATE_MODE1: &str = include_str!(concat!(
     env!("CARGO_MANIFEST_DIR"),
     "/shaders/mode1_matcap.wgsl"
 ));
+pub(crate) const TEMPLATE_MODE4: &str = include_str!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/shaders/mode4_toon.wgsl"
+));
 pub(crate) const ENV_TEMPLATE: &str = include_str!(concat!(
     env!("CARGO_MANIFEST_DIR"),
     "/shaders/env_template.wgsl"
@@ -145,6 +153,7 @@ const RERUN_IF_CHANGED_FILES: &[&str] = &[
     "shaders/blinnphong_common.wgsl",
     "shaders/mode0_lambert.wgsl",
     "shaders/mode1_matcap.wgsl",
+    "shaders/mode4_toon.wgsl",
     "shaders/env_template.wgsl",
     "shaders/quad_template.wgsl",
     // EPU shaders - common
@@ -171,6 +180,7 @@ const RERUN_IF_CHANGED_FILES: &[&str] = &[
     "shaders/epu/features/09_portal.wgsl",
     "shaders/epu/features/10_lobe_radiance.wgsl",
     "shaders/epu/features/11_band_radiance.wgsl",
+    "shaders/epu/features/12_flash.wgsl",
     // EPU compute shaders
     "shaders/epu/epu_compute_env.wgsl",
     "shaders/epu/epu_compute_blur.wgsl",
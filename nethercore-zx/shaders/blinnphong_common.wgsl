@@ -109,6 +109,7 @@ fn rim_lighting(
 @fragment
 fn fs(in: VertexOut) -> @location(0) vec4<f32> {
     let shading = shading_states[in.shading_state_index];
+    //FS_TEXCOORD
     let material_color = unpack_rgba8(shading.color_rgba8);
 
     // Unpack uniforms from uniform_set_0
@@ -221,6 +222,27 @@ fn fs(in: VertexOut) -> @location(0) vec4<f32> {
         }
     }
 
+    // Scene lights whose range reaches this fragment's light grid cell
+    let scene_range = scene_light_range(in.world_position);
+    for (var i = scene_range.x; i < scene_range.y; i++) {
+        let light = compute_scene_light(scene_lights[light_grid[i]], in.world_position);
+
+        let light_lum = dot(light.color, vec3<f32>(0.299, 0.587, 0.114));
+        if (light_lum > max_light_intensity) {
+            max_light_intensity = light_lum;
+            dominant_light_color = light.color;
+        }
+
+        final_color += lambert_diffuse(N, light.direction, albedo, light.color)
+                       * diffuse_factor * diffuse_fresnel;
+        final_color += normalized_blinn_phong_specular(
+            N, view_dir, light.direction, shininess, specular_color, light.color
+        );
+    }
+
+    // Baked lights (lightmap_bake): diffuse only, specular needs the view direction
+    final_color += albedo * in.baked_light * diffuse_factor * diffuse_fresnel;
+
     // Rim lighting (always uses rim_intensity from uniform, never from texture)
     // - Material character from specular_color (gold stays gold, holo stays magenta)
     // - Scene coherence from dominant light (brightest light tints the rim)
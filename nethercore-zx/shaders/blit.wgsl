@@ -1,10 +1,28 @@
 // Blit shader for scaling the offscreen render target to the window
-// Simple fullscreen quad with texture sampling
+// Simple fullscreen quad with texture sampling, plus optional palette quantization
 
 // Texture bindings
 @group(0) @binding(0) var render_target: texture_2d<f32>;
 @group(0) @binding(1) var tex_sampler: sampler;
 
+// Palette constraint (palette_mode): colors are sRGB-encoded, count 0 = off
+struct Palette {
+    count: u32,
+    dither: u32,
+    _pad0: u32,
+    _pad1: u32,
+    colors: array<vec4<f32>, 256>,
+}
+@group(0) @binding(2) var<uniform> palette: Palette;
+
+// 4x4 Bayer matrix for ordered dithering
+const BAYER_4X4: array<f32, 16> = array(
+     0.0/16.0,  8.0/16.0,  2.0/16.0, 10.0/16.0,
+    12.0/16.0,  4.0/16.0, 14.0/16.0,  6.0/16.0,
+     3.0/16.0, 11.0/16.0,  1.0/16.0,  9.0/16.0,
+    15.0/16.0,  7.0/16.0, 13.0/16.0,  5.0/16.0,
+);
+
 // Vertex shader output
 struct VertexOut {
     @builtin(position) position: vec4<f32>,
@@ -30,8 +48,47 @@ fn vs_main(@builtin(vertex_index) vertex_index: u32) -> VertexOut {
     return out;
 }
 
-// Fragment shader - simple texture sampling
+fn linear_to_srgb(c: vec3<f32>) -> vec3<f32> {
+    let lo = c * 12.92;
+    let hi = 1.055 * pow(c, vec3<f32>(1.0 / 2.4)) - 0.055;
+    return select(hi, lo, c <= vec3<f32>(0.0031308));
+}
+
+fn srgb_to_linear(c: vec3<f32>) -> vec3<f32> {
+    let lo = c / 12.92;
+    let hi = pow((c + 0.055) / 1.055, vec3<f32>(2.4));
+    return select(hi, lo, c <= vec3<f32>(0.04045));
+}
+
+// Fragment shader - texture sampling, quantized to the palette when one is set
 @fragment
 fn fs_main(in: VertexOut) -> @location(0) vec4<f32> {
-    return textureSample(render_target, tex_sampler, in.uv);
+    if (palette.count == 0u) {
+        return textureSample(render_target, tex_sampler, in.uv);
+    }
+
+    // Quantize per render target pixel so dithering stays on the game's pixel grid
+    let dims = textureDimensions(render_target);
+    let texel = min(vec2<u32>(in.uv * vec2<f32>(dims)), dims - 1u);
+    var color = linear_to_srgb(textureLoad(render_target, texel, 0).rgb);
+
+    if (palette.dither != 0u) {
+        // Dither amplitude follows the typical spacing between palette colors
+        let threshold = BAYER_4X4[(texel.y % 4u) * 4u + texel.x % 4u] + 0.5 / 16.0;
+        let spread = pow(f32(palette.count), -1.0 / 3.0);
+        color += (threshold - 0.5) * spread;
+    }
+
+    var best = palette.colors[0].rgb;
+    var best_dist = dot(color - best, color - best);
+    for (var i = 1u; i < palette.count; i++) {
+        let candidate = palette.colors[i].rgb;
+        let dist = dot(color - candidate, color - candidate);
+        if (dist < best_dist) {
+            best = candidate;
+            best_dist = dist;
+        }
+    }
+
+    return vec4<f32>(srgb_to_linear(best), 1.0);
 }
@@ -20,6 +20,8 @@
 // - Binding 6-7: EPU textures (env_radiance, sampler)
 // - Binding 8-9: EPU state + frame uniforms
 // - Binding 11: EPU SH9 (diffuse irradiance)
+// - Binding 12-13: Scene lights (scene_lights, light_grid)
+// - Binding 14: Morph targets (morph_data)
 
 // Binding 0: unified_transforms - all mat4x4 matrices [models | views | projs]
 // Indices are pre-computed on CPU to be absolute offsets into this array
@@ -37,23 +39,30 @@ struct PackedLight {
     data2: u32,  // Directional: unused (0), Point: position Z + range (f16x2)
 }
 
-// Unified per-draw shading state (80 bytes)
+// Unified per-draw shading state (104 bytes)
 struct PackedUnifiedShadingState {
     color_rgba8: u32,                // Material color (RGBA8 packed)
     uniform_set_0: u32,              // Mode-specific: [b0, b1, b2, rim_intensity]
     uniform_set_1: u32,              // Mode-specific: [b0, b1, b2, rim_power]
     flags: u32,                      // Bit 0: skinning_mode (0=raw, 1=inverse bind)
     lights: array<PackedLight, 4>,   // 48 bytes (4 × 12-byte lights)
-    // Animation system fields (8 bytes) + padding (4 bytes) + env_id (4 bytes)
+    // Animation system fields (8 bytes) + lightmap base (4 bytes) + env_id (4 bytes)
     keyframe_base: u32,              // Base offset into all_keyframes buffer
     inverse_bind_base: u32,          // Base offset into inverse_bind buffer
-    _pad: u32,                       // Unused padding for struct alignment
+    lightmap_base: u32,              // Lightmap entry of vertex 0 (minus base vertex)
     environment_index: u32,          // EPU environment ID (env_id)
+    // Morph targets (24 bytes)
+    morph_base: u32,                 // Morph entry of target 0, vertex 0 (minus base vertex)
+    morph_vertices: u32,             // Vertices per target (0 = no morph)
+    morph_weights: array<u32, 4>,    // 8 target weights (f16x2)
 }
 
 // Binding 2: shading_states - per-draw shading state array
 @group(0) @binding(2) var<storage, read> shading_states: array<PackedUnifiedShadingState>;
 
+// Binding 4: lightmap_data - baked per-vertex irradiance (f16 RGB, see lightmap_bake)
+@group(0) @binding(4) var<storage, read> lightmap_data: array<vec2<u32>>;
+
 // 3x4 bone matrix struct (row-major storage, 48 bytes per bone)
 // Implicit 4th row is [0, 0, 0, 1] (affine transform)
 struct BoneMatrix3x4 {
@@ -129,6 +138,34 @@ struct EpuSh9 {
 // Binding 11: EPU SH9 storage buffer (256 entries)
 @group(0) @binding(11) var<storage, read> epu_sh9: array<EpuSh9>;
 
+// ============================================================================
+// Scene Lights - Bindings 12-13
+// ============================================================================
+// Per-frame point/spot lights from light_add_point()/light_add_spot(), binned on
+// the CPU into a world-space grid so each fragment only visits nearby lights.
+
+// Scene light (64 bytes). Point lights have cos_outer = -2.0 (no cone).
+struct SceneLight {
+    position: vec3<f32>,
+    range: f32,
+    color: vec3<f32>,
+    intensity: f32,
+    direction: vec3<f32>,
+    cos_inner: f32,
+    cos_outer: f32,
+    _pad0: f32,
+    _pad1: f32,
+    _pad2: f32,
+}
+
+// Binding 12: scene_lights - this frame's scene lights (256 max)
+@group(0) @binding(12) var<storage, read> scene_lights: array<SceneLight>;
+
+// Binding 13: light_grid - [header (12 u32) | per-cell (first, count) | light indices]
+// Header: origin (f32 bits) at 0-2, light count at 3, inverse cell size (f32 bits)
+// at 4-6, cell counts at 8-10
+@group(0) @binding(13) var<storage, read> light_grid: array<u32>;
+
 // Helper to expand 3x4 bone matrix → 4x4 for skinning calculations
 // Input is row-major, output is column-major (WGSL mat4x4 convention)
 fn bone_to_mat4(bone: BoneMatrix3x4) -> mat4x4<f32> {
@@ -187,6 +224,115 @@ fn has_flag(flags: u32, flag: u32) -> bool {
     return (flags & flag) != 0u;
 }
 
+// ============================================================================
+// Retro Mode Constants and Helpers (bits 17-21)
+// ============================================================================
+
+// Vertex snap precision: 0 = off, N = grid 2^N cells tall (bits 17-20)
+const FLAG_VERTEX_SNAP_MASK: u32 = 0x1E0000u;
+const FLAG_VERTEX_SNAP_SHIFT: u32 = 17u;
+// Affine texture mapping: sample with screen-linear UVs (bit 21)
+const FLAG_AFFINE_TEXTURES: u32 = 0x200000u;
+
+// Render target aspect ratio (fixed 960x540), keeps snap cells square
+const SNAP_ASPECT: f32 = 16.0 / 9.0;
+
+// Snap a clip-space position to the retro vertex grid (PS1-style wobble)
+fn snap_vertex(clip: vec4<f32>, flags: u32) -> vec4<f32> {
+    let bits = (flags & FLAG_VERTEX_SNAP_MASK) >> FLAG_VERTEX_SNAP_SHIFT;
+    if (bits == 0u || clip.w <= 0.0) {
+        return clip;
+    }
+    // NDC spans 2 units, so half the row count gives cells per NDC unit
+    let rows = f32(1u << bits) * 0.5;
+    let grid = vec2<f32>(rows * SNAP_ASPECT, rows);
+    let ndc = round(clip.xy / clip.w * grid) / grid;
+    return vec4<f32>(ndc * clip.w, clip.zw);
+}
+
+// ============================================================================
+// Baked Lighting (bit 22)
+// ============================================================================
+
+// Draw reads baked irradiance from lightmap_data (bit 22)
+const FLAG_LIGHTMAPPED: u32 = 0x400000u;
+
+// Baked irradiance for a vertex, or black if the draw has no lightmap
+fn baked_light(state: PackedUnifiedShadingState, vertex_index: u32) -> vec3<f32> {
+    if (!has_flag(state.flags, FLAG_LIGHTMAPPED)) {
+        return vec3<f32>(0.0);
+    }
+    let packed = lightmap_data[state.lightmap_base + vertex_index];
+    return vec3<f32>(unpack2x16float(packed.x), unpack2x16float(packed.y).x);
+}
+
+// ============================================================================
+// Morph Targets (mesh_morph_weight)
+// ============================================================================
+
+// Binding 14: morph_data - per-vertex target displacements, 3 u32 per entry
+// [f16 px | py, pz | nx, ny | nz]; entry of (target t, vertex v) is
+// morph_base + t * morph_vertices + v
+@group(0) @binding(14) var<storage, read> morph_data: array<u32>;
+
+// Weighted sum of a vertex's morph target displacements
+struct MorphOffset {
+    position: vec3<f32>,
+    normal: vec3<f32>,
+}
+
+fn morph_offset(shading_state_idx: u32, vertex_index: u32) -> MorphOffset {
+    var offset = MorphOffset(vec3<f32>(0.0), vec3<f32>(0.0));
+    let vertices = shading_states[shading_state_idx].morph_vertices;
+    if (vertices == 0u) {
+        return offset;
+    }
+    let base = shading_states[shading_state_idx].morph_base + vertex_index;
+    for (var t = 0u; t < 8u; t++) {
+        let weight = unpack2x16float(shading_states[shading_state_idx].morph_weights[t / 2u])[t % 2u];
+        if (weight != 0.0) {
+            let i = (base + t * vertices) * 3u;
+            let a = unpack2x16float(morph_data[i]);
+            let b = unpack2x16float(morph_data[i + 1u]);
+            let c = unpack2x16float(morph_data[i + 2u]);
+            offset.position += vec3<f32>(a.x, a.y, b.x) * weight;
+            offset.normal += vec3<f32>(b.y, c.x, c.y) * weight;
+        }
+    }
+    return offset;
+}
+
+// ============================================================================
+// Toon Outline (bit 23, mode 4)
+// ============================================================================
+
+// Draw is the inverted-hull outline pass of a toon mesh (bit 23)
+// color_rgba8 holds the outline color and uniform_set_1 the width (f32 bits)
+const FLAG_OUTLINE: u32 = 0x800000u;
+
+// ============================================================================
+// Palette Swap (bits 24-31)
+// ============================================================================
+
+// Palette applied to an indexed slot 0 texture (bits 24-31, 0 = none)
+const FLAG_PALETTE_MASK: u32 = 0xFF000000u;
+const FLAG_PALETTE_SHIFT: u32 = 24u;
+const PALETTE_SIZE: u32 = 256u;
+
+// Binding 10: palette_data - palettes from palette_load (256 RGBA8 colors each)
+@group(0) @binding(10) var<storage, read> palette_data: array<u32>;
+
+// Extract the palette handle (0 = none)
+fn get_palette(flags: u32) -> u32 {
+    return (flags & FLAG_PALETTE_MASK) >> FLAG_PALETTE_SHIFT;
+}
+
+// Look up the palette color of an indexed texel (index stored in the red channel)
+fn palette_color(palette: u32, texel: vec4<f32>) -> vec4<f32> {
+    let index = u32(round(texel.r * 255.0));
+    return unpack_rgba8(palette_data[(palette - 1u) * PALETTE_SIZE + index]);
+}
+
 // ============================================================================
 // Dither Transparency Constants and Helpers (bits 8-15)
 // ============================================================================
@@ -254,3 +400,13 @@ fn sample_filtered(tex: texture_2d<f32>, flags: u32, uv: vec2<f32>) -> vec4<f32>
     }
     return textureSample(tex, sampler_nearest, uv);
 }
+
+// Sample the albedo texture in slot 0, through the bound palette if it is indexed
+// Indices never blend, so palette draws always sample the nearest texel
+fn sample_albedo(flags: u32, uv: vec2<f32>) -> vec4<f32> {
+    let palette = get_palette(flags);
+    if (palette != 0u) {
+        return palette_color(palette, textureSampleLevel(slot0, sampler_nearest, uv, 0.0));
+    }
+    return sample_filtered(slot0, flags, uv);
+}
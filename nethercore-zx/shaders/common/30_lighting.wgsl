@@ -105,3 +105,57 @@ fn compute_light(light: LightData, world_position: vec3<f32>) -> ComputedLight {
     return result;
 }
 
+// ============================================================================
+// Scene Lights
+// ============================================================================
+
+const LIGHT_GRID_HEADER: u32 = 12u;
+
+// Range of light_grid entries (first, end) listing the scene lights that reach
+// world_position. Empty outside the grid or when there are no scene lights.
+fn scene_light_range(world_position: vec3<f32>) -> vec2<u32> {
+    if (light_grid[3] == 0u) {
+        return vec2<u32>(0u);
+    }
+    let origin = vec3<f32>(
+        bitcast<f32>(light_grid[0]),
+        bitcast<f32>(light_grid[1]),
+        bitcast<f32>(light_grid[2])
+    );
+    let inv_cell_size = vec3<f32>(
+        bitcast<f32>(light_grid[4]),
+        bitcast<f32>(light_grid[5]),
+        bitcast<f32>(light_grid[6])
+    );
+    let dims = vec3<u32>(light_grid[8], light_grid[9], light_grid[10]);
+
+    let cell = floor((world_position - origin) * inv_cell_size);
+    if (any(cell < vec3<f32>(0.0)) || any(cell >= vec3<f32>(dims))) {
+        return vec2<u32>(0u);
+    }
+    let c = vec3<u32>(cell);
+    let entry = LIGHT_GRID_HEADER + 2u * (c.x + dims.x * (c.y + dims.y * c.z));
+    let first = light_grid[entry];
+    return vec2<u32>(first, first + light_grid[entry + 1u]);
+}
+
+// Compute direction and effective color of a scene light (point or spot)
+fn compute_scene_light(light: SceneLight, world_position: vec3<f32>) -> ComputedLight {
+    var result: ComputedLight;
+
+    let to_light = light.position - world_position;
+    let distance = length(to_light);
+    result.direction = -normalize(to_light);  // Negate: convention is "ray direction"
+    var attenuation = point_light_attenuation(distance, light.range);
+    if (light.cos_outer > -1.5) {
+        // Spot light: fade from the inner to the outer cone
+        attenuation *= smoothstep(
+            light.cos_outer,
+            light.cos_inner,
+            dot(light.direction, result.direction)
+        );
+    }
+    result.color = light.color * light.intensity * attenuation;
+
+    return result;
+}
@@ -21,6 +21,7 @@ struct VertexOut {
     //VOUT_CAMERA_POS
     //VOUT_TANGENT
     //VOUT_VIEW_TANGENT
+    @location(9) baked_light: vec3<f32>,
     //VOUT_UV
     //VOUT_COLOR
 }
@@ -44,7 +45,11 @@ fn extract_camera_position(view_matrix: mat4x4<f32>) -> vec3<f32> {
 }
 
 @vertex
-fn vs(in: VertexIn, @builtin(instance_index) instance_index: u32) -> VertexOut {
+fn vs(
+    in: VertexIn,
+    @builtin(vertex_index) vertex_index: u32,
+    @builtin(instance_index) instance_index: u32,
+) -> VertexOut {
     var out: VertexOut;
 
     // Extract indices first (needed by skinning code)
@@ -54,6 +59,10 @@ fn vs(in: VertexIn, @builtin(instance_index) instance_index: u32) -> VertexOut {
     let proj_idx = indices.z;
     let shading_state_idx = indices.w;
 
+    // Morph targets (mesh_morph_weight) displace the bind pose before skinning
+    let morph = morph_offset(shading_state_idx, vertex_index);
+    let vertex_position = in.position + morph.position;
+
     //VS_SKINNED
 
     // Access unified_transforms directly - indices are pre-offset by CPU
@@ -62,7 +71,7 @@ fn vs(in: VertexIn, @builtin(instance_index) instance_index: u32) -> VertexOut {
     let projection_matrix = unified_transforms[proj_idx];
 
     //VS_POSITION
-    let model_pos = model_matrix * world_pos;
+    var model_pos = model_matrix * world_pos;
     out.world_position = model_pos.xyz;
 
     //VS_WORLD_NORMAL
@@ -70,9 +79,12 @@ fn vs(in: VertexIn, @builtin(instance_index) instance_index: u32) -> VertexOut {
     //VS_VIEW_POS
     //VS_TANGENT
     //VS_VIEW_TANGENT
+    //VS_OUTLINE
 
-    out.clip_position = projection_matrix * view_matrix * model_pos;
+    let clip_position = projection_matrix * view_matrix * model_pos;
+    out.clip_position = snap_vertex(clip_position, shading_states[shading_state_idx].flags);
     out.shading_state_index = shading_state_idx;
+    out.baked_light = baked_light(shading_states[shading_state_idx], vertex_index);
 
     //VS_CAMERA_POS
 
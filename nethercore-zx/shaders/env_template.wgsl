@@ -1,6 +1,9 @@
 // Environment rendering shader template
 // Prepended with the common WGSL sources by build.rs
-// Renders a fullscreen procedural environment using the EPU
+// Renders a fullscreen procedural environment using the EPU, or a cubemap sky
+
+// Group 1 binding 6: slot 0 as a texture array (cubemap faces from rom_cubemap)
+@group(1) @binding(6) var slot0_array: texture_2d_array<f32>;
 
 // ============================================================================
 // Vertex and Fragment Shaders
@@ -42,12 +45,8 @@ fn vs(@builtin(vertex_index) vertex_index: u32, @builtin(instance_index) instanc
     return out;
 }
 
-@fragment
-fn fs(in: EnvVertexOut) -> @location(0) vec4<f32> {
-    // Get environment ID (env_id) from shading state
-    let shading = shading_states[in.shading_state_index];
-    let env_index = shading.environment_index;
-
+// World-space view ray through this pixel
+fn env_view_ray(in: EnvVertexOut) -> vec3<f32> {
     // Compute view ray per-pixel (not interpolated from vertices)
     // view_idx and proj_idx are pre-computed absolute indices by CPU
     let view_matrix = unified_transforms[in.view_idx];
@@ -68,10 +67,67 @@ fn fs(in: EnvVertexOut) -> @location(0) vec4<f32> {
     let view_ray_cam = normalize(vec3<f32>(view_ray_x, view_ray_y, -1.0));
 
     // Transform to world space
-    let view_ray = cam_right * view_ray_cam.x + cam_up * view_ray_cam.y + cam_back * view_ray_cam.z;
+    return normalize(cam_right * view_ray_cam.x + cam_up * view_ray_cam.y + cam_back * view_ray_cam.z);
+}
+
+@fragment
+fn fs(in: EnvVertexOut) -> @location(0) vec4<f32> {
+    // Get environment ID (env_id) from shading state
+    let shading = shading_states[in.shading_state_index];
+    let env_index = shading.environment_index;
 
     // Sample background from EPU precomputed octahedral map
-    let env_color = sample_epu_background(env_index, normalize(view_ray));
+    let env_color = sample_epu_background(env_index, env_view_ray(in));
 
     return env_color;
 }
+
+// Cubemap face (array layer, +X -X +Y -Y +Z -Z) and face UV for a direction
+fn cube_face_uv(dir: vec3<f32>) -> vec3<f32> {
+    let a = abs(dir);
+    var face: f32;
+    var sc: f32;
+    var tc: f32;
+    var ma: f32;
+    if (a.x >= a.y && a.x >= a.z) {
+        ma = a.x;
+        face = select(1.0, 0.0, dir.x >= 0.0);
+        sc = select(dir.z, -dir.z, dir.x >= 0.0);
+        tc = -dir.y;
+    } else if (a.y >= a.z) {
+        ma = a.y;
+        face = select(3.0, 2.0, dir.y >= 0.0);
+        sc = dir.x;
+        tc = select(-dir.z, dir.z, dir.y >= 0.0);
+    } else {
+        ma = a.z;
+        face = select(5.0, 4.0, dir.z >= 0.0);
+        sc = select(-dir.x, dir.x, dir.z >= 0.0);
+        tc = -dir.y;
+    }
+    return vec3<f32>(0.5 * (sc / ma + 1.0), 0.5 * (tc / ma + 1.0), face);
+}
+
+// Cubemap sky from sky_draw() (texture slot 0 as a 6-layer array). The sky's
+// rotation is folded into the view matrix on the CPU.
+@fragment
+fn fs_sky(in: EnvVertexOut) -> @location(0) vec4<f32> {
+    let shading = shading_states[in.shading_state_index];
+    let face = cube_face_uv(env_view_ray(in));
+    let layer = u32(face.z);
+
+    // The samplers repeat, so keep linear filtering from blending in the opposite edge
+    let half_texel = 0.5 / vec2<f32>(textureDimensions(slot0_array));
+    let uv = clamp(face.xy, half_texel, 1.0 - half_texel);
+    if ((shading.flags & FLAG_TEXTURE_FILTER_LINEAR) != 0u) {
+        return textureSampleLevel(slot0_array, sampler_linear, uv, layer, 0.0);
+    }
+    return textureSampleLevel(slot0_array, sampler_nearest, uv, layer, 0.0);
+}
+
+// Draw from the EPU background cache (texture slot 0), filled by `fs` on an
+// earlier frame while the environment and camera stayed the same
+@fragment
+fn fs_cached(in: EnvVertexOut) -> @location(0) vec4<f32> {
+    return textureLoad(slot0, vec2<i32>(in.clip_position.xy), 0);
+}
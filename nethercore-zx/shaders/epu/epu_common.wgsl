@@ -32,7 +32,7 @@ const OP_GRID: u32 = 0x09u;
 const OP_SCATTER: u32 = 0x0Au;
 const OP_FLOW: u32 = 0x0Bu;
 
-// Radiance opcodes (0x0C-0x13)
+// Radiance opcodes (0x0C-0x14)
 const OP_TRACE: u32 = 0x0Cu;        // Procedural line/crack patterns (lightning, cracks, lead lines, filaments)
 const OP_VEIL: u32 = 0x0Du;         // Curtain/ribbon effects (curtains, pillars, laser bars, rain wall, shards)
 const OP_ATMOSPHERE: u32 = 0x0Eu;   // Atmospheric absorption + scattering
@@ -41,7 +41,8 @@ const OP_CELESTIAL: u32 = 0x10u;    // Moon/sun/planet bodies
 const OP_PORTAL: u32 = 0x11u;       // Swirling vortex/portal effect
 const OP_LOBE_RADIANCE: u32 = 0x12u; // Region-masked directional glow
 const OP_BAND_RADIANCE: u32 = 0x13u; // Region-masked horizon band
-// 0x14..0x1F reserved for future radiance ops
+const OP_FLASH: u32 = 0x14u;        // Scheduled lightning flashes + bolts
+// 0x15..0x1F reserved for future radiance ops
 
 // ============================================================================
 // REGION MASK CONSTANTS (3-bit bitfield)
@@ -60,7 +60,7 @@ fn evaluate_layer(
         case OP_SCATTER: { return eval_scatter(dir, instr, region_w); }
         case OP_FLOW:    { return eval_flow(dir, instr, region_w); }
 
-        // Additional radiance opcodes (0x0C..0x13)
+        // Additional radiance opcodes (0x0C..0x14)
         case OP_TRACE: { return eval_trace(dir, instr, region_w); }
         case OP_VEIL: { return eval_veil(dir, instr, region_w); }
         case OP_ATMOSPHERE: {
@@ -81,6 +81,9 @@ fn evaluate_layer(
         case OP_BAND_RADIANCE: {
             return eval_band_radiance(dir, instr, region_w);
         }
+        case OP_FLASH: {
+            return eval_flash(dir, instr, region_w);
+        }
 
         default: { return LayerSample(vec3f(0.0), 0.0); }
     }
@@ -2,33 +2,72 @@
 // opcode = 0x09
 // name = GRID
 // kind = radiance
-// variants = []
+// variants = [FLOOR, TUBE, WALLS, SCANLINES]
 // domains = []
 // field intensity = { label="brightness", map="u8_01" }
-// field param_a = { label="scale", map="u8_lerp", min=1.0, max=64.0 }
-// field param_b = { label="thickness", map="u8_lerp", min=0.001, max=0.1 }
-// field param_c = { label="pat+scroll", map="u8_01" }
-// field param_d = { label="phase", map="u8_01" }
+// field param_a = { label="spacing", map="u8_lerp", min=0.05, max=4.0 }
+// field param_b = { label="width", map="u8_lerp", min=0.01, max=0.5 }
+// field param_c = { label="accent+curve", map="u8_01" }
+// field param_d = { label="scroll", map="u8_01" }
 // @epu_meta_end
 
 // ============================================================================
-// GRID - Repeating Lines / Panels
+// GRID - Perspective-Correct Line Grids
+// Opcode: 0x09
+// Role: Radiance (additive feature layer)
+//
 // Packed fields:
-//   color_a: Primary line color (RGB24)
-//   color_b: Reserved (set to 0)
+//   color_a: Line color (RGB24)
+//   color_b: Accent line color (RGB24)
 //   intensity: Brightness (0..255 -> 0..1)
-//   param_a: Scale (0..255 -> 1..64)
-//   param_b: Thickness (0..255 -> 0.001..0.1)
-//   param_c[7:4]: Pattern (0=stripes, 1=grid, 2=checker)
-//   param_c[3:0]: Scroll speed (0..15 -> 0..2)
-//   param_d: Phase (0..255 -> 0..1)
-//   direction: Orientation (reserved for future expansion)
+//   param_a: Line spacing in world units, eye height = 1 (0..255 -> 0.05..4.0)
+//   param_b: Line width as a fraction of the spacing (0..255 -> 0.01..0.5)
+//   param_c[7:4]: Accent every N lines (0 = no accents)
+//   param_c[3:0]: Curvature (0..15; FLOOR bends away like a planet, TUBE bends up)
+//   param_d: Scroll phase (0..255 -> 0..1 of one accent period, or one line if no accents)
+//   direction: Up axis (oct-u16); scrolling moves floor lines toward -forward
+//              and wall lines downward
+//   alpha_a: Line alpha (0..15 -> 0..1)
+//   alpha_b: Accent line alpha (0..15 -> 0..1)
+//
+// Meta (via meta5):
+//   domain_id: Ignored (each variant has its own projection)
+//   variant_id: 0 FLOOR, 1 TUBE, 2 WALLS, 3 SCANLINES
+//
+// Lines are box-filtered over the footprint of one env map texel, so they
+// thin out to their average coverage instead of aliasing, then fade into
+// the horizon where a texel covers more than a cell.
 // ============================================================================
 
-fn get_cyl_uv(dir: vec3f) -> vec2f {
-    let u = atan2(dir.x, dir.z) / TAU;        // [-0.5..0.5]
-    let v = dir.y * 0.5 + 0.5;                // [0..1]
-    return vec2f(u, v);
+// Variant IDs for GRID
+const GRID_VARIANT_FLOOR: u32 = 0u;      // Plane one unit below the eye
+const GRID_VARIANT_TUBE: u32 = 1u;       // Floor curving up the sides
+const GRID_VARIANT_WALLS: u32 = 2u;      // Cylinder one unit around the eye
+const GRID_VARIANT_SCANLINES: u32 = 3u;  // WALLS with horizontal lines only
+
+// Angular size of one texel of the default 128x128 octahedral env map
+const GRID_TEXEL_ANGLE: f32 = 0.028;
+
+// Curvature at param_c[3:0] = 15 for FLOOR and TUBE
+const GRID_PLANET_CURVATURE: f32 = 0.02;
+const GRID_TUBE_CURVATURE: f32 = 2.0;
+
+// Box-filtered coverage of lines `width` wide centered on every integer of
+// `x`, averaged over a filter `fw` wide. Converges to `width` as fw grows.
+fn grid_line_coverage(x: f32, width: f32, fw: f32) -> f32 {
+    let f = max(fw, 1e-4);
+    let x0 = x + width * 0.5 - f * 0.5;
+    let x1 = x0 + f;
+    let i0 = floor(x0) * width + min(fract(x0), width);
+    let i1 = floor(x1) * width + min(fract(x1), width);
+    return clamp((i1 - i0) / f, 0.0, 1.0);
+}
+
+// Per-axis footprint on a surface, given the footprint across the view
+// direction and how much it is stretched along the radial direction.
+fn grid_axis_footprint(across: f32, stretch: f32, radial_dot: f32) -> f32 {
+    let r2 = radial_dot * radial_dot;
+    return across * sqrt(1.0 - r2 + r2 * stretch * stretch);
 }
 
 fn eval_grid(
@@ -38,52 +77,98 @@ fn eval_grid(
 ) -> LayerSample {
     if region_w < 0.001 { return LayerSample(vec3f(0.0), 0.0); }
 
-    let scale = mix(1.0, 64.0, u8_to_01(instr_a(instr)));
-    let thickness = mix(0.001, 0.1, u8_to_01(instr_b(instr)));
+    let variant_id = instr_variant_id(instr);
 
+    let spacing = mix(0.05, 4.0, u8_to_01(instr_a(instr)));
+    let width = mix(0.01, 0.5, u8_to_01(instr_b(instr)));
     let pc = instr_c(instr);
-    let pattern_type = (pc >> 4u) & 0xFu;
-    let scroll_q = pc & 0xFu;
-    let scroll_speed = f32(scroll_q) / 15.0 * 2.0;
-    let scroll = u8_to_01(instr_d(instr)) * scroll_speed;
+    let accent_every = (pc >> 4u) & 0xFu;
+    let curve = u4_to_01(pc & 0xFu);
+    let phase = u8_to_01(instr_d(instr));
 
-    let uv0 = get_cyl_uv(dir);
-    let uv = vec2f(uv0.x + scroll, uv0.y);
+    // Up/right/forward basis (same construction as PLANE)
+    let up = decode_dir16(instr_dir16(instr));
+    let hint = select(vec3f(0.0, 1.0, 0.0), vec3f(1.0, 0.0, 0.0), abs(up.y) > 0.9);
+    let right = normalize(cross(hint, up));
+    let forward = cross(up, right);
 
-    // color_a = primary line color
-    let line_rgb = instr_color_a(instr);
+    let dy = dot(dir, up);
+    let dr = dot(dir, right);
+    let df = dot(dir, forward);
+    let h_len = sqrt(max(dr * dr + df * df, 1e-8));
 
-    var rgb = vec3f(0.0);
-    var pat: f32 = 0.0;
+    // Grid coordinates in cells, and their footprints in cells
+    var uv = vec2f(0.0);
+    var fw = vec2f(0.0);
+    let cylinder = variant_id == GRID_VARIANT_WALLS || variant_id == GRID_VARIANT_SCANLINES;
 
-    switch pattern_type {
-        case 0u: { // STRIPES
-            let fx = abs(fract(uv.x * scale) - 0.5);
-            pat = 1.0 - step(thickness, fx);
-            rgb = line_rgb;
-        }
-        case 1u: { // GRID
-            let fx = abs(fract(uv.x * scale) - 0.5);
-            let fy = abs(fract(uv.y * scale) - 0.5);
-            let h_line = 1.0 - step(thickness, fx);
-            let v_line = 1.0 - step(thickness, fy);
-            rgb = line_rgb;
-            pat = max(h_line, v_line);
+    if cylinder {
+        // Columns wrap evenly around the cylinder; rows are heights on it
+        let columns = max(1.0, round(TAU / spacing));
+        let azimuth = atan2(dr, df);
+        uv = vec2f(azimuth / TAU * columns, dy / h_len / spacing);
+        fw = vec2f(
+            GRID_TEXEL_ANGLE / h_len * columns / TAU,
+            GRID_TEXEL_ANGLE / (h_len * h_len) / spacing
+        );
+    } else {
+        // Ray hits the surface y = -1 - a*t^2: a > 0 bends away, a < 0 bends up
+        var a = 0.0;
+        if variant_id == GRID_VARIANT_TUBE {
+            a = -0.5 * curve * GRID_TUBE_CURVATURE * dr * dr;
+        } else {
+            a = 0.5 * curve * GRID_PLANET_CURVATURE * h_len * h_len;
         }
-        case 2u: { // CHECKER
-            let cell = floor(vec2f(uv.x, uv.y) * scale);
-            let checker = f32((i32(cell.x) + i32(cell.y)) & 1);
-            pat = 1.0;
-            rgb = mix(line_rgb * 0.6, line_rgb, checker);
-        }
-        default: {
-            let fx = abs(fract(uv.x * scale) - 0.5);
-            pat = 1.0 - step(thickness, fx);
-            rgb = line_rgb;
+        let disc = dy * dy - 4.0 * a;
+        if disc < 0.0 { return LayerSample(vec3f(0.0), 0.0); }
+        let denom = sqrt(disc) - dy;
+        if denom <= 1e-4 { return LayerSample(vec3f(0.0), 0.0); }
+        let t = 2.0 / denom;
+
+        uv = vec2f(dr, df) * (t / spacing);
+
+        // A texel covers angle*t across the view and is stretched by
+        // 1/cos(incidence) along it; approximate incidence as flat.
+        let across = GRID_TEXEL_ANGLE * t / spacing;
+        let stretch = 1.0 / max(abs(dy), 1e-3);
+        fw = vec2f(
+            grid_axis_footprint(across, stretch, dr / h_len),
+            grid_axis_footprint(across, stretch, df / h_len)
+        );
+    }
+
+    // Scroll over one full accent period so animation loops seamlessly
+    uv.y += phase * f32(max(accent_every, 1u));
+
+    // Lines (scanlines have no columns)
+    let cov_rows = grid_line_coverage(uv.y, width, fw.y);
+    var cov_cols = 0.0;
+    if variant_id != GRID_VARIANT_SCANLINES {
+        cov_cols = grid_line_coverage(uv.x, width, fw.x);
+    }
+    let line_cov = 1.0 - (1.0 - cov_rows) * (1.0 - cov_cols);
+
+    // Accent lines: every Nth line, same world width
+    var accent_cov = 0.0;
+    if accent_every > 0u {
+        let n = f32(accent_every);
+        let acc_rows = grid_line_coverage(uv.y / n, width / n, fw.y / n);
+        var acc_cols = 0.0;
+        if variant_id != GRID_VARIANT_SCANLINES {
+            acc_cols = grid_line_coverage(uv.x / n, width / n, fw.x / n);
         }
+        accent_cov = 1.0 - (1.0 - acc_rows) * (1.0 - acc_cols);
     }
 
+    // Fade into the horizon once a texel spans most of a cell
+    let fade = 1.0 - smoothstep(0.35, 1.5, max(fw.x, fw.y));
+
+    let accent_w = accent_cov * instr_alpha_b_f32(instr);
+    let line_w = max(line_cov - accent_cov, 0.0) * instr_alpha_a_f32(instr);
+    let total = accent_w + line_w;
+    if total < 1e-5 { return LayerSample(vec3f(0.0), 0.0); }
+
+    let rgb = (instr_color_a(instr) * line_w + instr_color_b(instr) * accent_w) / total;
     let intensity = u8_to_01(instr_intensity(instr));
-    let alpha = instr_alpha_a_f32(instr);
-    return LayerSample(rgb, pat * intensity * alpha * region_w);
+    return LayerSample(rgb, min(total, 1.0) * fade * intensity * region_w);
 }
@@ -3,7 +3,7 @@
 // name = CELESTIAL
 // kind = radiance
 // variants = [MOON, SUN, PLANET, GAS_GIANT, RINGED, BINARY, ECLIPSE]
-// domains = []
+// domains = [BODY, RINGS, OCCLUDED, RINGS_OCCLUDED]
 // field intensity = { label="brightness", map="u8_lerp", min=0.0, max=2.0 }
 // field param_a = { label="ang_size", map="u8_lerp", min=0.5, max=45.0, unit="deg" }
 // field param_b = { label="limb_dark", map="u8_lerp", min=0.5, max=4.0 }
@@ -23,13 +23,16 @@
 //   param_a: Angular size (0..255 -> 0.5..45 degrees)
 //   param_b: Limb darkening exponent (0..255 -> 0.5..4.0)
 //   param_c: Phase angle (0..255 -> 0..360 degrees) - MOON/PLANET
+//            With OCCLUDED: occluder offset instead (0 -> -2.2, 255 -> +2.2 radii)
 //   param_d: Variant-specific (corona extent, band count, ring tilt, etc.)
 //   direction: Body center (oct-u16)
 //   alpha_a: Body alpha (0..15 -> 0..1)
 //   alpha_b: Atmosphere/ring alpha (0..15 -> 0..1)
 //
 // Meta (via meta5):
-//   domain_id: Ignored (always DIRECT3D spherical)
+//   domain_id: Flags (always DIRECT3D spherical)
+//     bit 0 RINGS: Add tilted rings (color_b/alpha_b) to any variant
+//     bit 1 OCCLUDED: A dark secondary body crosses the disk along the phase axis
 //   variant_id: 0 MOON, 1 SUN, 2 PLANET, 3 GAS_GIANT, 4 RINGED, 5 BINARY, 6 ECLIPSE
 // ============================================================================
 
@@ -42,6 +45,15 @@ const CELESTIAL_VARIANT_RINGED: u32 = 4u;     // Saturn-like with tilted rings
 const CELESTIAL_VARIANT_BINARY: u32 = 5u;     // Two bodies (primary + secondary)
 const CELESTIAL_VARIANT_ECLIPSE: u32 = 6u;    // Solar eclipse with corona
 
+// Flag bits for CELESTIAL (stored in domain_id)
+const CELESTIAL_FLAG_RINGS: u32 = 1u;         // Rings around any body
+const CELESTIAL_FLAG_OCCLUDED: u32 = 2u;      // Secondary body in front of the disk
+
+// Ring tilt used by the RINGS flag (RINGED takes it from param_d)
+const CELESTIAL_FLAG_RING_TILT_DEG: f32 = 25.0;
+// Occluder travel either side of the body center (in body radii)
+const CELESTIAL_OCCLUDER_TRAVEL: f32 = 2.2;
+
 // Deterministic hash for celestial noise (2D -> 1D)
 fn celestial_hash21(p: vec2f) -> f32 {
     let p3 = fract(vec3f(p.xyx) * 0.1031);
@@ -225,18 +237,14 @@ fn eval_celestial_gas_giant(
     return LayerSample(rgb, w);
 }
 
-// RINGED variant: Saturn-like with tilted rings
-fn eval_celestial_ringed(
+// Ring coverage (x) and brightness (y) around a body; zero coverage inside the disk
+fn celestial_rings(
     dir: vec3f,
     body_dir: vec3f,
     r: f32,
     angular_size_rad: f32,
-    limb: f32,
-    color_a: vec3f,
-    color_b: vec3f,
-    ring_tilt_deg: f32,
-    alpha_b: f32
-) -> LayerSample {
+    ring_tilt_deg: f32
+) -> vec2f {
     // Ring geometry: rings are in a plane tilted from viewer
     // Ring tilt: 0 = edge-on, 90 = face-on
     let ring_tilt_rad = ring_tilt_deg * PI / 180.0;
@@ -272,17 +280,34 @@ fn eval_celestial_ringed(
     let cassini_gap = smoothstep(0.45, 0.5, ring_r_norm) * smoothstep(0.55, 0.5, ring_r_norm);
     let ring_brightness = ring_bands * (1.0 - cassini_gap * 0.8);
 
+    return vec2f(ring_visible, ring_brightness * tilt_factor);
+}
+
+// RINGED variant: Saturn-like with tilted rings
+fn eval_celestial_ringed(
+    dir: vec3f,
+    body_dir: vec3f,
+    r: f32,
+    angular_size_rad: f32,
+    limb: f32,
+    color_a: vec3f,
+    color_b: vec3f,
+    ring_tilt_deg: f32,
+    alpha_b: f32
+) -> LayerSample {
+    let rings = celestial_rings(dir, body_dir, r, angular_size_rad, ring_tilt_deg);
+
     // Planet disk (behind rings at some angles)
     let disk = smoothstep(1.05, 0.95, r);
     let planet_surface = color_a * limb * disk;
 
     // Ring color
-    let ring_color = color_b * ring_visible * ring_brightness * tilt_factor;
+    let ring_color = color_b * rings.x * rings.y;
 
     // Combine: rings can be in front of or behind the planet depending on geometry
     // Simplified: rings always rendered on top for visual clarity
     let rgb = planet_surface + ring_color * alpha_b;
-    let w = disk + ring_visible * alpha_b;
+    let w = disk + rings.x * alpha_b;
 
     return LayerSample(rgb, w);
 }
@@ -377,8 +402,10 @@ fn eval_celestial(
 ) -> LayerSample {
     if region_w < 0.001 { return LayerSample(vec3f(0.0), 0.0); }
 
-    // Extract variant (domain_id is ignored for CELESTIAL)
+    // Extract variant and flags (domain_id holds flags for CELESTIAL)
     let variant_id = instr_variant_id(instr);
+    let flags = instr_domain_id(instr);
+    let occluded = (flags & CELESTIAL_FLAG_OCCLUDED) != 0u;
 
     // Decode body direction
     let body_dir = decode_dir16(instr_dir16(instr));
@@ -395,8 +422,10 @@ fn eval_celestial(
     // param_b: Limb darkening exponent (0..255 -> 0.5..4.0)
     let limb_exp = mix(0.5, 4.0, u8_to_01(instr_b(instr)));
 
-    // param_c: Phase angle (0..255 -> 0..360 degrees)
-    let phase_deg = u8_to_01(instr_c(instr)) * 360.0;
+    // param_c: Phase angle (0..255 -> 0..360 degrees), or occluder offset
+    // when OCCLUDED is set (the body is then fully lit)
+    let param_c_raw = u8_to_01(instr_c(instr));
+    let phase_deg = select(param_c_raw * 360.0, 0.0, occluded);
     let phase_rad = phase_deg * PI / 180.0;
 
     // param_d: Variant-specific parameter
@@ -409,15 +438,21 @@ fn eval_celestial(
     let limb = pow(epu_saturate(1.0 - r), limb_exp);
 
     // Compute phase illumination (for MOON/PLANET)
-    // Phase: 0 = full, 90 = half, 180 = new
-    // Sun direction rotated from body_dir by phase angle
+    // Phase: 0 = full, 90 = half (lit along the phase axis), 180 = new
     let hint = select(vec3f(0.0, 1.0, 0.0), vec3f(1.0, 0.0, 0.0), abs(body_dir.y) > 0.9);
     let phase_axis = normalize(cross(hint, body_dir));
-    let sun_dir = normalize(body_dir * cos(phase_rad) + cross(phase_axis, body_dir) * sin(phase_rad));
 
-    // Local surface normal approximation (points outward from disk center)
-    let surface_normal = normalize(dir - body_dir * body_dot + body_dir * 0.3);
-    let phase_factor = epu_saturate(dot(surface_normal, sun_dir) * 0.5 + 0.5);
+    // Sphere normal on the visible hemisphere: x along the phase axis, z toward the viewer
+    let radial = dir - body_dir * body_dot;
+    let radial_len = length(radial);
+    let disk_r = min(r, 1.0);
+    let normal_x = select(0.0, dot(radial, phase_axis) / radial_len, radial_len > 1e-6) * disk_r;
+    let normal_z = sqrt(max(1.0 - disk_r * disk_r, 0.0));
+    let lit = normal_x * sin(phase_rad) + normal_z * cos(phase_rad);
+
+    // Crisp terminator with Lambert falloff; the night side keeps a faint earthshine
+    let lit_mask = smoothstep(-0.04, 0.04, lit);
+    let phase_factor = mix(0.03, 0.4 + 0.6 * epu_saturate(lit), lit_mask);
 
     // Compute surface UV for detail texturing
     let surface_uv = celestial_surface_uv(dir, body_dir, r);
@@ -472,6 +507,24 @@ fn eval_celestial(
         }
     }
 
+    // RINGS flag: add rings to any body (RINGED already has its own)
+    if (flags & CELESTIAL_FLAG_RINGS) != 0u && variant_id != CELESTIAL_VARIANT_RINGED {
+        let rings = celestial_rings(dir, body_dir, r, angular_size_rad, CELESTIAL_FLAG_RING_TILT_DEG);
+        sample.rgb += color_b * rings.x * rings.y * alpha_b;
+        sample.w += rings.x * alpha_b;
+    }
+
+    // OCCLUDED flag: a dark body the same size as the disk hides everything behind it,
+    // including corona and rings. param_c slides it across the disk along the phase axis.
+    if occluded {
+        let offset = (param_c_raw * 2.0 - 1.0) * CELESTIAL_OCCLUDER_TRAVEL * angular_size_rad;
+        let occluder_dir = normalize(body_dir * cos(offset) + phase_axis * sin(offset));
+        let occluder_r = acos(epu_saturate(dot(dir, occluder_dir))) / angular_size_rad;
+        let cover = smoothstep(1.02, 0.98, occluder_r);
+        sample.rgb *= 1.0 - cover;
+        sample.w *= 1.0 - cover;
+    }
+
     // Apply intensity and region weight
     let final_w = sample.w * intensity * alpha_a * region_w;
 
@@ -0,0 +1,163 @@
+// @epu_meta_begin
+// opcode = 0x14
+// name = FLASH
+// kind = radiance
+// variants = [FORK, SHEET]
+// domains = []
+// field intensity = { label="brightness", map="u8_01" }
+// field param_a = { label="period", map="u8_lerp", min=0.0, max=255.0, unit="steps" }
+// field param_b = { label="seed", map="u8_01" }
+// field param_c = { label="branches+width", map="u8_01" }
+// field param_d = { label="phase", map="u8_01" }
+// @epu_meta_end
+
+// ============================================================================
+// FLASH - Scheduled Lightning Strikes (Sky Flash + Branching Bolt)
+// Opcode: 0x14
+// Role: Radiance (additive feature layer)
+//
+// The phase is split into strike windows of `period` steps. Each window has
+// one strike whose start, position and bolt shape are hashed from the seed and
+// the window index, so every client that feeds the same phase and seed sees the
+// same strikes. The flash decays over a quarter of the window.
+//
+// Packed fields:
+//   color_a: Bolt color (RGB24)
+//   color_b: Sky flash color (RGB24)
+//   intensity: Brightness (0..255 -> 0..1)
+//   param_a: Strike period in phase steps (0..255, 0 treated as 1)
+//   param_b: Seed (0..255)
+//   param_c[7:4]: Branch count (0..15)
+//   param_c[3:0]: Bolt width (0..15 -> 0.004..0.04)
+//   param_d: Phase step (0..255); advance once per tick from the game
+//   direction: Storm center (oct-u16); strikes land within ~30 degrees of it
+//   alpha_a: Bolt alpha (0..15 -> 0..1)
+//   alpha_b: Sky flash alpha (0..15 -> 0..1)
+//
+// Meta (via meta5):
+//   domain_id: Ignored
+//   variant_id: 0 FORK (bolt + flash), 1 SHEET (flash only, cloud-lit)
+// ============================================================================
+
+// Variant IDs for FLASH
+const FLASH_VARIANT_FORK: u32 = 0u;     // Branching bolt with sky flash
+const FLASH_VARIANT_SHEET: u32 = 1u;    // Diffuse sky flash only
+
+// Integer hash shared with the CPU strike schedule (lowbias32)
+fn flash_hash(x_in: u32) -> u32 {
+    var x = x_in;
+    x ^= x >> 16u;
+    x *= 0x7feb352du;
+    x ^= x >> 15u;
+    x *= 0x846ca68bu;
+    x ^= x >> 16u;
+    return x;
+}
+
+// Distance to a branching bolt that starts at `origin` and falls in -v
+fn flash_bolt_dist(uv: vec2f, origin: vec2f, bolt_seed: f32, branches: u32) -> f32 {
+    var min_dist = 1000.0;
+    var prev = origin;
+
+    for (var s = 0u; s < 7u; s++) {
+        let seg_hash = trace_hash23(vec2f(bolt_seed + f32(s) * 7.1, f32(s) * 13.3 + 1.0));
+        let next = prev + vec2f((seg_hash.x - 0.5) * 0.12, -(0.06 + seg_hash.y * 0.06));
+        min_dist = min(min_dist, dist_to_segment(uv, prev, next));
+
+        // Forks split off to either side; branch count sets the fork probability
+        if s > 0u && seg_hash.z < f32(branches) / 15.0 {
+            let fork_hash = trace_hash23(vec2f(bolt_seed + f32(s) * 23.7, f32(s) * 41.3 + 2.0));
+            let side = select(-1.0, 1.0, fork_hash.x > 0.5);
+            let mid = prev + vec2f(side * (0.03 + fork_hash.y * 0.04), -(0.03 + fork_hash.z * 0.03));
+            let end = mid + vec2f(side * (0.02 + fork_hash.z * 0.04), -(0.04 + fork_hash.y * 0.04));
+            // Forks read thinner than the main channel
+            let fork_dist = min(dist_to_segment(uv, prev, mid), dist_to_segment(uv, mid, end));
+            min_dist = min(min_dist, fork_dist * 1.6);
+        }
+
+        prev = next;
+    }
+
+    return min_dist;
+}
+
+fn eval_flash(
+    dir: vec3f,
+    instr: vec4u,
+    region_w: f32
+) -> LayerSample {
+    if region_w < 0.001 { return LayerSample(vec3f(0.0), 0.0); }
+
+    let variant_id = instr_variant_id(instr);
+    if variant_id > FLASH_VARIANT_SHEET { return LayerSample(vec3f(0.0), 0.0); }
+
+    // 1. Strike schedule (must match the CPU-side FlashParams::strike_age)
+    let period = max(instr_a(instr), 1u);
+    let seed = instr_b(instr);
+    let phase = instr_d(instr);
+
+    let window = phase / period;
+    let local = phase % period;
+    let h = flash_hash((seed << 8u) | window);
+    let offset = h % ((period + 1u) / 2u);
+    if local < offset { return LayerSample(vec3f(0.0), 0.0); }
+    let age = f32(local - offset);
+
+    // 2. Flash envelope: fast decay with an optional re-strike two steps in
+    let decay = f32(max(period / 4u, 2u));
+    var env = exp(-3.0 * age / decay);
+    if (h & 0x10000u) != 0u && age >= 2.0 {
+        env = max(env, 0.7 * exp(-3.0 * (age - 2.0) / decay));
+    }
+    if env < 0.01 { return LayerSample(vec3f(0.0), 0.0); }
+
+    // 3. Tangent chart around the storm center (v points up the sky)
+    let center = decode_dir16(instr_dir16(instr));
+    let up = select(vec3f(0.0, 1.0, 0.0), vec3f(1.0, 0.0, 0.0), abs(center.y) > 0.9);
+    let t = normalize(cross(up, center));
+    let b = normalize(cross(center, t));
+
+    // Strike point within ~30 degrees of the center
+    let hp = flash_hash(h ^ 0x9e3779b9u);
+    let origin = vec2f(
+        (f32(hp & 0xFFFFu) / 65535.0 - 0.5) * 1.2,
+        0.1 + f32(hp >> 16u) / 65535.0 * 0.3
+    );
+    let strike_dir = normalize(center + t * origin.x + b * origin.y);
+
+    // 4. Sky flash: ambient lift plus a broad glow around the strike
+    let focus = pow(epu_saturate(dot(dir, strike_dir) * 0.5 + 0.5), 6.0);
+    let sheet_focus = select(focus, sqrt(focus), variant_id == FLASH_VARIANT_SHEET);
+    let flash_mask = env * (0.25 + 0.75 * sheet_focus);
+
+    // 5. Bolt: only for FORK, only in front of the chart, only while bright
+    var bolt_mask = 0.0;
+    let d = dot(dir, center);
+    if variant_id == FLASH_VARIANT_FORK && d > 0.0 && age < decay {
+        let proj = dir - center * d;
+        let uv = vec2f(dot(proj, t), dot(proj, b)) / d;
+
+        let pc = instr_c(instr);
+        let branches = (pc >> 4u) & 0xFu;
+        let width = mix(0.004, 0.04, u4_to_01(pc & 0xFu));
+
+        let dist = flash_bolt_dist(uv, origin, f32(hp & 0xFFu), branches);
+        let aa_width = 0.002;
+        let core = 1.0 - smoothstep(width - aa_width, width + aa_width, dist);
+        let glow = smoothstep(width * 4.0 + aa_width, width, dist) * (1.0 - core);
+        bolt_mask = (core + glow * 0.5) * env * smoothstep(0.1, 0.3, d);
+    }
+
+    // 6. Combine: bolt over flash
+    let alpha_a = instr_alpha_a_f32(instr);
+    let alpha_b = instr_alpha_b_f32(instr);
+    let bolt_w = bolt_mask * alpha_a;
+    let flash_w = flash_mask * alpha_b * (1.0 - epu_saturate(bolt_mask));
+    let rgb = instr_color_a(instr) * bolt_w + instr_color_b(instr) * flash_w;
+    let total_w = bolt_w + flash_w;
+
+    let intensity = u8_to_01(instr_intensity(instr));
+    let w = total_w * intensity * region_w;
+
+    return LayerSample(rgb / max(total_w, 1e-4), w);
+}
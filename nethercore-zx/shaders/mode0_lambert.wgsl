@@ -1,7 +1,7 @@
 // Mode 0: Lambert Shading
 // Supports all 16 vertex formats (0-15)
 // Without normals: flat color (no lighting)
-// With normals: Lambert shading using environment ambient + 4 dynamic lights
+// With normals: Lambert shading using environment ambient + 4 dynamic lights + scene lights
 
 // NOTE: Vertex shader (VertexIn/VertexOut structs and @vertex fn) is injected by the shader generator from the common WGSL sources.
 // NOTE: Common bindings, structures, and utilities are injected by the shader generator from the common WGSL sources.
@@ -14,6 +14,7 @@
 fn fs(in: VertexOut) -> @location(0) vec4<f32> {
     // Get shading state for this draw
     let shading = shading_states[in.shading_state_index];
+    //FS_TEXCOORD
     let material_color = unpack_rgba8(shading.color_rgba8);
 
     // Start with material color, base_alpha defaults to material alpha
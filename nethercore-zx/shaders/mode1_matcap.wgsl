@@ -128,6 +128,7 @@ fn blend_colors(base: vec3<f32>, blend: vec3<f32>, mode: u32) -> vec3<f32> {
 fn fs(in: VertexOut) -> @location(0) vec4<f32> {
     // Get shading state for this draw
     let shading = shading_states[in.shading_state_index];
+    //FS_TEXCOORD
     let material_color = unpack_rgba8(shading.color_rgba8);
 
     // Unpack matcap blend modes from uniform_set_0 (Mode 1 uses this for blend modes)
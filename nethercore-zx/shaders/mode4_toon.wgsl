@@ -0,0 +1,152 @@
+// Mode 4: Toon (Cel) Shading
+// Requires normals (formats with NORMAL flag)
+// Quantized diffuse bands, hard-edged specular and rim light, inverted-hull outlines
+
+// NOTE: Vertex shader (VertexIn/VertexOut structs and @vertex fn) is injected by the shader generator from the common WGSL sources.
+// NOTE: Common bindings, structures, and utilities are injected by the shader generator from the common WGSL sources.
+
+// ============================================================================
+// Toon Helpers
+// ============================================================================
+
+// Default number of lit bands when uniform_set_0 byte 0 is 0
+const TOON_DEFAULT_BANDS: f32 = 3.0;
+
+// Snap a 0-1 lighting term to `bands` flat steps above the unlit side
+// bands = 1 gives a classic two-tone (lit/shadow) look
+fn toon_band(n_dot_l: f32, bands: f32) -> f32 {
+    return ceil(clamp(n_dot_l, 0.0, 1.0) * bands) / bands;
+}
+
+// Banded Lambert diffuse
+// light_dir convention: direction rays travel (negated to get L)
+fn toon_diffuse(
+    N: vec3<f32>,
+    light_dir: vec3<f32>,
+    albedo: vec3<f32>,
+    light_color: vec3<f32>,
+    bands: f32,
+) -> vec3<f32> {
+    let n_dot_l = dot(N, -light_dir);
+    return albedo * light_color * toon_band(n_dot_l, bands);
+}
+
+// Hard-edged Blinn-Phong highlight (on or off, no gradient)
+fn toon_specular(
+    N: vec3<f32>,
+    V: vec3<f32>,
+    light_dir: vec3<f32>,
+    shininess: f32,
+    specular_color: vec3<f32>,
+    light_color: vec3<f32>,
+) -> vec3<f32> {
+    let L = -light_dir;
+    let H = normalize(L + V);
+    let spec = pow(max(dot(N, H), 0.0), shininess) * step(0.0, dot(N, L));
+    return specular_color * light_color * step(0.5, spec);
+}
+
+// ============================================================================
+// Fragment Shader
+// ============================================================================
+
+@fragment
+fn fs(in: VertexOut) -> @location(0) vec4<f32> {
+    let shading = shading_states[in.shading_state_index];
+    //FS_TEXCOORD
+    let material_color = unpack_rgba8(shading.color_rgba8);
+
+    // Outline pass: flat ink color, no lighting
+    if has_flag(shading.flags, FLAG_OUTLINE) {
+        if should_discard_dither(in.clip_position.xy, shading.flags, material_color.a) {
+            discard;
+        }
+        return material_color;
+    }
+
+    // Unpack uniforms from uniform_set_0: [bands, shininess, emissive, rim_intensity]
+    let band_count = f32(shading.uniform_set_0 & 0xFFu); // byte 0 (raw count)
+    let bands = select(TOON_DEFAULT_BANDS, band_count, band_count > 0.0);
+    let shininess = mix(1.0, 256.0, unpack_unorm8_from_u32((shading.uniform_set_0 >> 8u) & 0xFFu));
+    let emissive = unpack_unorm8_from_u32((shading.uniform_set_0 >> 16u) & 0xFFu);
+    let rim_intensity = unpack_unorm8_from_u32((shading.uniform_set_0 >> 24u) & 0xFFu);
+
+    // uniform_set_1: 0xRRGGBBRP (specular RGB, rim power in low byte)
+    let specular_color = vec3<f32>(
+        f32((shading.uniform_set_1 >> 24u) & 0xFFu) / 255.0,
+        f32((shading.uniform_set_1 >> 16u) & 0xFFu) / 255.0,
+        f32((shading.uniform_set_1 >> 8u) & 0xFFu) / 255.0
+    );
+    let rim_power = unpack_unorm8_from_u32(shading.uniform_set_1 & 0xFFu) * 32.0;
+
+    // Start with material albedo, base_alpha defaults to material alpha
+    var albedo = material_color.rgb;
+    var base_alpha = material_color.a;
+    //FS_COLOR
+    //FS_UV
+
+    // === Shading Normal (vertex normal or TBN-transformed normal map) ===
+    //FS_SHADING_NORMAL
+
+    let N = normalize(shading_normal);
+    let view_dir = normalize(in.camera_position - in.world_position);
+
+    // Ambient stays smooth: it is low-frequency and keeps shadows from going flat black
+    var final_color = albedo * sample_epu_ambient(shading.environment_index, N);
+    final_color += albedo * emissive;
+
+    // Track dominant light color for rim lighting coherence
+    var dominant_light_color = vec3<f32>(0.0);
+    var max_light_intensity = 0.0;
+
+    // 4 dynamic lights
+    for (var i = 0u; i < 4u; i++) {
+        let light_data = unpack_light(shading.lights[i]);
+        if (light_data.enabled) {
+            let light = compute_light(light_data, in.world_position);
+
+            let light_lum = dot(light.color, vec3<f32>(0.299, 0.587, 0.114));
+            if (light_lum > max_light_intensity) {
+                max_light_intensity = light_lum;
+                dominant_light_color = light.color;
+            }
+
+            final_color += toon_diffuse(N, light.direction, albedo, light.color, bands);
+            final_color += toon_specular(
+                N, view_dir, light.direction, shininess, specular_color, light.color
+            );
+        }
+    }
+
+    // Scene lights whose range reaches this fragment's light grid cell
+    let scene_range = scene_light_range(in.world_position);
+    for (var i = scene_range.x; i < scene_range.y; i++) {
+        let light = compute_scene_light(scene_lights[light_grid[i]], in.world_position);
+
+        let light_lum = dot(light.color, vec3<f32>(0.299, 0.587, 0.114));
+        if (light_lum > max_light_intensity) {
+            max_light_intensity = light_lum;
+            dominant_light_color = light.color;
+        }
+
+        final_color += toon_diffuse(N, light.direction, albedo, light.color, bands);
+        final_color += toon_specular(
+            N, view_dir, light.direction, shininess, specular_color, light.color
+        );
+    }
+
+    // Baked lights (lightmap_bake): diffuse only
+    final_color += albedo * in.baked_light;
+
+    // Hard rim light tinted by the brightest light (white if no lights enabled)
+    let scene_tint = select(vec3<f32>(1.0), dominant_light_color, max_light_intensity > 0.0);
+    let rim_factor = pow(1.0 - max(dot(N, view_dir), 0.0), rim_power);
+    final_color += scene_tint * rim_intensity * step(0.5, rim_factor);
+
+    // Dither transparency (two-layer: base_alpha × effect_alpha)
+    if should_discard_dither(in.clip_position.xy, shading.flags, base_alpha) {
+        discard;
+    }
+
+    return vec4<f32>(final_color, base_alpha);
+}
@@ -15,12 +15,31 @@ struct QuadInstance {
     shading_state_index: u32,
     view_index: u32,           // Absolute index into unified_transforms
     proj_index: u32,           // Absolute index into unified_transforms
-    _pad: u32,                 // Padding to maintain 64-byte alignment
+    array_layer: u32,          // Texture array layer + 1 (0 = sample slot0)
 }
 
 // Binding 5: quad_instances (only used by quad shader)
 @group(0) @binding(5) var<storage, read> quad_instances: array<QuadInstance>;
 
+// Group 1 binding 6: slot 0 as a texture array (from texture_array_bind)
+@group(1) @binding(6) var slot0_array: texture_2d_array<f32>;
+
+fn sample_filtered_array(tex: texture_2d_array<f32>, flags: u32, uv: vec2<f32>, layer: u32) -> vec4<f32> {
+    if ((flags & FLAG_TEXTURE_FILTER_LINEAR) != 0u) {
+        return textureSample(tex, sampler_linear, uv, layer);
+    }
+    return textureSample(tex, sampler_nearest, uv, layer);
+}
+
+// sample_albedo() for a slot 0 texture array layer
+fn sample_albedo_array(flags: u32, uv: vec2<f32>, layer: u32) -> vec4<f32> {
+    let palette = get_palette(flags);
+    if (palette != 0u) {
+        return palette_color(palette, textureSampleLevel(slot0_array, sampler_nearest, uv, layer, 0.0));
+    }
+    return sample_filtered_array(slot0_array, flags, uv, layer);
+}
+
 // Quad modes (bits 0-7 of mode_packed)
 const BILLBOARD_SPHERICAL: u32 = 0u;
 const BILLBOARD_CYLINDRICAL_Y: u32 = 1u;
@@ -98,6 +117,7 @@ struct QuadVertexOut {
     @location(1) uv: vec2<f32>,
     @location(2) @interpolate(flat) shading_state_index: u32,
     @location(3) @interpolate(flat) mode: u32,
+    @location(4) @interpolate(flat) array_layer: u32,
 }
 
 @vertex
@@ -144,6 +164,7 @@ fn vs(in: QuadVertexIn, @builtin(instance_index) instance_idx: u32) -> QuadVerte
     out.uv = mix(instance.uv.xy, instance.uv.zw, vertex_uv);
     out.shading_state_index = instance.shading_state_index;
     out.mode = mode;
+    out.array_layer = instance.array_layer;
     return out;
 }
 
@@ -151,7 +172,12 @@ fn vs(in: QuadVertexIn, @builtin(instance_index) instance_idx: u32) -> QuadVerte
 fn fs(in: QuadVertexOut) -> @location(0) vec4<f32> {
     let shading = shading_states[in.shading_state_index];
     let material_color = unpack_rgba8(shading.color_rgba8);
-    let tex_color = sample_filtered(slot0, shading.flags, in.uv);
+    var tex_color: vec4<f32>;
+    if (in.array_layer != 0u) {
+        tex_color = sample_albedo_array(shading.flags, in.uv, in.array_layer - 1u);
+    } else {
+        tex_color = sample_albedo(shading.flags, in.uv);
+    }
     let color = tex_color.rgb * material_color.rgb;
     let base_alpha = tex_color.a * material_color.a;
 
@@ -0,0 +1,137 @@
+// Screen-space ambient occlusion post pass (post_ssao)
+// One fullscreen triangle per 3D viewport; the result is multiplied into the
+// render target, so only the occlusion factor is written
+
+// Per-viewport parameters (matches SsaoViewUniforms in ssao.rs)
+struct SsaoView {
+    proj: mat4x4<f32>,
+    inv_proj: mat4x4<f32>,
+    // Viewport in render target pixels (x, y, width, height)
+    rect: vec4<f32>,
+    // x = radius (view units), y = intensity
+    params: vec4<f32>,
+}
+
+@group(0) @binding(0) var depth_texture: texture_depth_2d;
+@group(0) @binding(1) var<uniform> views: array<SsaoView, 8>;
+
+const SAMPLE_COUNT: u32 = 12u;
+
+// Hemisphere kernel around +Z, short samples near the normal and long ones
+// toward the horizon
+const KERNEL: array<vec3<f32>, 12> = array(
+    vec3<f32>( 0.042,  0.000,  0.150),
+    vec3<f32>(-0.059,  0.054,  0.154),
+    vec3<f32>( 0.010, -0.118,  0.165),
+    vec3<f32>( 0.100,  0.131,  0.180),
+    vec3<f32>(-0.219, -0.039,  0.197),
+    vec3<f32>( 0.248, -0.157,  0.213),
+    vec3<f32>(-0.098,  0.364,  0.225),
+    vec3<f32>(-0.219, -0.421,  0.231),
+    vec3<f32>( 0.550,  0.201,  0.228),
+    vec3<f32>(-0.655,  0.271,  0.213),
+    vec3<f32>( 0.358, -0.765,  0.184),
+    vec3<f32>( 0.296,  0.945,  0.137),
+);
+
+struct VertexOut {
+    @builtin(position) position: vec4<f32>,
+    @location(0) @interpolate(flat) view: u32,
+}
+
+// Fullscreen triangle; the instance index selects the viewport
+@vertex
+fn vs_main(
+    @builtin(vertex_index) vertex_index: u32,
+    @builtin(instance_index) instance_index: u32,
+) -> VertexOut {
+    var out: VertexOut;
+    let x = f32((vertex_index & 1u) << 2u) - 1.0;
+    let y = f32((vertex_index & 2u) << 1u) - 1.0;
+    out.position = vec4<f32>(x, y, 0.0, 1.0);
+    out.view = instance_index;
+    return out;
+}
+
+// Depth at a render target pixel, clamped to the viewport so split-screen
+// views never sample each other
+fn load_depth(view: SsaoView, pixel: vec2<f32>) -> f32 {
+    let texel = clamp(floor(pixel), view.rect.xy, view.rect.xy + view.rect.zw - 1.0);
+    return textureLoad(depth_texture, vec2<i32>(texel), 0);
+}
+
+// View-space position of a render target pixel at the given depth
+fn view_position(view: SsaoView, pixel: vec2<f32>, depth: f32) -> vec3<f32> {
+    let uv = (pixel - view.rect.xy) / view.rect.zw;
+    let p = view.inv_proj * vec4<f32>(uv.x * 2.0 - 1.0, 1.0 - uv.y * 2.0, depth, 1.0);
+    return p.xyz / p.w;
+}
+
+fn view_position_at(view: SsaoView, pixel: vec2<f32>) -> vec3<f32> {
+    return view_position(view, pixel, load_depth(view, pixel));
+}
+
+@fragment
+fn fs_main(in: VertexOut) -> @location(0) vec4<f32> {
+    let view = views[in.view];
+    let pixel = floor(in.position.xy) + 0.5;
+    let depth = load_depth(view, pixel);
+
+    // Sky (cleared depth) and screen-space 2D (depth 0) are not occluded
+    if (depth >= 1.0 || depth <= 0.0) {
+        return vec4<f32>(1.0);
+    }
+
+    // Normal from the neighbour on the flatter side, so silhouettes don't
+    // pick up the background's slope
+    let center = view_position(view, pixel, depth);
+    let right = view_position_at(view, pixel + vec2<f32>(1.0, 0.0));
+    let left = view_position_at(view, pixel - vec2<f32>(1.0, 0.0));
+    let down = view_position_at(view, pixel + vec2<f32>(0.0, 1.0));
+    let up = view_position_at(view, pixel - vec2<f32>(0.0, 1.0));
+    let dx = select(center - left, right - center, abs(right.z - center.z) < abs(center.z - left.z));
+    let dy = select(center - up, down - center, abs(down.z - center.z) < abs(center.z - up.z));
+    var normal = normalize(cross(dy, dx));
+    if (dot(normal, center) > 0.0) {
+        normal = -normal;
+    }
+
+    // Rotate the kernel per pixel in a 4x4 pattern to trade banding for noise
+    let cell = vec2<u32>(pixel) % 4u;
+    let angle = f32(cell.y * 4u + cell.x) * (6.2831853 / 16.0);
+    var tangent = vec3<f32>(cos(angle), sin(angle), 0.0);
+    tangent = tangent - normal * dot(tangent, normal);
+    if (dot(tangent, tangent) < 1e-4) {
+        tangent = vec3<f32>(0.0, 0.0, 1.0) - normal * normal.z;
+    }
+    tangent = normalize(tangent);
+    let bitangent = cross(normal, tangent);
+
+    let radius = view.params.x;
+    var occlusion = 0.0;
+    for (var i = 0u; i < SAMPLE_COUNT; i++) {
+        let k = KERNEL[i];
+        let sample_pos = center + (tangent * k.x + bitangent * k.y + normal * k.z) * radius;
+        let clip = view.proj * vec4<f32>(sample_pos, 1.0);
+        if (clip.w <= 0.0) {
+            continue;
+        }
+        let ndc = clip.xy / clip.w;
+        let sample_pixel = view.rect.xy + vec2<f32>(ndc.x * 0.5 + 0.5, 0.5 - ndc.y * 0.5) * view.rect.zw;
+        let scene_depth = load_depth(view, sample_pixel);
+        if (scene_depth <= 0.0) {
+            continue;
+        }
+
+        // Occluded when the visible surface is in front of the sample point;
+        // surfaces far in front (beyond the radius) fade out
+        let scene = view_position(view, sample_pixel, scene_depth);
+        let range = smoothstep(0.0, 1.0, radius / max(abs(center.z - scene.z), 1e-4));
+        if (scene.z >= sample_pos.z + 0.02 * radius) {
+            occlusion += range;
+        }
+    }
+
+    let ao = clamp(1.0 - occlusion / f32(SAMPLE_COUNT) * view.params.y, 0.0, 1.0);
+    return vec4<f32>(ao, ao, ao, 1.0);
+}
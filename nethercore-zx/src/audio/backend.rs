@@ -4,8 +4,16 @@ use std::sync::Arc;
 use tracing::warn;
 
 use super::Sound;
-use super::generation::{advance_audio_positions, generate_audio_frame_with_tracker};
+use super::generation::{advance_audio_positions, generate_audio_frame_with_stream};
 use super::output::{AudioOutput, OUTPUT_SAMPLE_RATE};
+use super::stream::{StreamMix, advance_stream_position};
+use super::voice::VoiceDevice;
+use crate::audio_thread::fill_fade_out;
+use crate::state::{MAX_AUDIO_BUSES, audio_bus};
+use nethercore_core::MixerBus;
+
+/// Length of the fade to silence when the frame debugger pauses (~1ms)
+const PAUSE_FADE_SAMPLES: usize = 44;
 
 /// Nethercore ZX audio backend
 ///
@@ -20,12 +28,21 @@ pub struct ZXAudio {
     cached_sounds: Option<Arc<Vec<Option<Sound>>>>,
     /// Master volume (0.0 - 1.0)
     master_volume: f32,
+    /// Player volume per bus from host settings (0.0 - 1.0)
+    bus_volumes: [f32; MAX_AUDIO_BUSES],
     /// Pre-allocated buffer for volume scaling (avoids allocation per push)
     scale_buffer: Vec<f32>,
     /// Pre-allocated buffer for audio frame generation (avoids allocation per frame)
     frame_buffer: Vec<f32>,
     /// Whether to use threaded audio generation
     use_threaded: bool,
+    /// Voice chat capture/playback streams (netplay only)
+    voice: Option<VoiceDevice>,
+    /// Whether the frame debugger has the game paused
+    paused: bool,
+    /// Send the next snapshot as a rollback (the game may have been
+    /// stepped or rewound while paused)
+    resync: bool,
 }
 
 impl ZXAudio {
@@ -37,9 +54,13 @@ impl ZXAudio {
                 threaded_output: None,
                 cached_sounds: None,
                 master_volume: 1.0,
+                bus_volumes: [1.0; MAX_AUDIO_BUSES],
                 scale_buffer: Vec::with_capacity(2048), // Pre-allocate for typical frame size
                 frame_buffer: Vec::with_capacity(2048), // ~735*2 stereo samples at 60fps
                 use_threaded: false,
+                voice: None,
+                paused: false,
+                resync: false,
             }),
             Err(e) => {
                 warn!("Failed to create audio output: {}. Audio disabled.", e);
@@ -48,9 +69,13 @@ impl ZXAudio {
                     threaded_output: None,
                     cached_sounds: None,
                     master_volume: 1.0,
+                    bus_volumes: [1.0; MAX_AUDIO_BUSES],
                     scale_buffer: Vec::new(),
                     frame_buffer: Vec::new(),
                     use_threaded: false,
+                    voice: None,
+                    paused: false,
+                    resync: false,
                 })
             }
         }
@@ -67,9 +92,13 @@ impl ZXAudio {
                 threaded_output: Some(output),
                 cached_sounds: None,
                 master_volume: 1.0,
+                bus_volumes: [1.0; MAX_AUDIO_BUSES],
                 scale_buffer: Vec::new(), // Not needed for threaded mode
                 frame_buffer: Vec::new(), // Not needed - uses lightweight advance
                 use_threaded: true,
+                voice: None,
+                paused: false,
+                resync: false,
             }),
             Err(e) => {
                 warn!(
@@ -81,9 +110,13 @@ impl ZXAudio {
                     threaded_output: None,
                     cached_sounds: None,
                     master_volume: 1.0,
+                    bus_volumes: [1.0; MAX_AUDIO_BUSES],
                     scale_buffer: Vec::new(),
                     frame_buffer: Vec::new(),
                     use_threaded: true,
+                    voice: None,
+                    paused: false,
+                    resync: false,
                 })
             }
         }
@@ -99,9 +132,22 @@ impl ZXAudio {
             threaded_output: None,
             cached_sounds: None,
             master_volume: 1.0,
+            bus_volumes: [1.0; MAX_AUDIO_BUSES],
             scale_buffer: Vec::new(),
             frame_buffer: Vec::new(),
             use_threaded: false,
+            voice: None,
+            paused: false,
+            resync: false,
+        }
+    }
+
+    /// Open voice chat devices for a netplay session
+    ///
+    /// Stub backends (no audio output) never open devices.
+    pub fn attach_voice(&mut self, voice: nethercore_core::net::voice::VoiceChannel) {
+        if self.output.is_some() || self.threaded_output.is_some() {
+            self.voice = Some(VoiceDevice::open(voice));
         }
     }
 
@@ -131,6 +177,16 @@ impl ZXAudio {
         self.master_volume
     }
 
+    /// Set the player's volume for a mixer bus (0.0 - 1.0)
+    pub fn set_bus_volume(&mut self, bus: MixerBus, volume: f32) {
+        self.bus_volumes[bus_index(bus)] = volume.clamp(0.0, 1.0);
+    }
+
+    /// Get the player's volume for a mixer bus
+    pub fn bus_volume(&self, bus: MixerBus) -> f32 {
+        self.bus_volumes[bus_index(bus)]
+    }
+
     /// Get the sample rate (or default if audio is disabled)
     pub fn sample_rate(&self) -> u32 {
         if let Some(ref output) = self.threaded_output {
@@ -160,6 +216,38 @@ impl ZXAudio {
         }
     }
 
+    /// Pause or resume output for the frame debugger
+    ///
+    /// Pausing fades out from the last generated sample instead of cutting
+    /// off. On resume the audio thread is resynced to the game's state.
+    pub fn set_paused(&mut self, paused: bool) {
+        if paused == self.paused {
+            return;
+        }
+        self.paused = paused;
+        if let Some(output) = &self.threaded_output {
+            output.set_paused(paused);
+        }
+        if paused {
+            if self.output.is_some() && self.frame_buffer.len() >= 2 {
+                let len = self.frame_buffer.len();
+                let last = (self.frame_buffer[len - 2], self.frame_buffer[len - 1]);
+                let mut fade = std::mem::take(&mut self.frame_buffer);
+                fill_fade_out(&mut fade, last, PAUSE_FADE_SAMPLES * 2, PAUSE_FADE_SAMPLES);
+                self.push_samples(&fade);
+                fade.clear();
+                self.frame_buffer = fade;
+            }
+        } else {
+            self.resync = true;
+        }
+    }
+
+    /// Check if output is paused by the frame debugger
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
     /// Get a reference to the sounds storage
     ///
     /// This is used to access loaded sounds for audio generation.
@@ -178,13 +266,28 @@ impl Default for ZXAudio {
             threaded_output: None,
             cached_sounds: None,
             master_volume: 1.0,
+            bus_volumes: [1.0; MAX_AUDIO_BUSES],
             scale_buffer: Vec::new(),
             frame_buffer: Vec::new(),
             use_threaded: false,
+            voice: None,
+            paused: false,
+            resync: false,
         })
     }
 }
 
+/// Built-in bus a host mixer bus maps to
+fn bus_index(bus: MixerBus) -> usize {
+    let index = match bus {
+        MixerBus::Music => audio_bus::MUSIC,
+        MixerBus::Sfx => audio_bus::SFX,
+        MixerBus::Ui => audio_bus::UI,
+        MixerBus::Voice => audio_bus::VOICE,
+    };
+    index as usize
+}
+
 /// Audio generator for Nethercore ZX
 ///
 /// Implements the AudioGenerator trait to enable console-agnostic audio generation
@@ -207,11 +310,17 @@ impl nethercore_core::AudioGenerator for ZXAudioGenerator {
         sample_rate: u32,
         output: &mut Vec<f32>,
     ) {
-        generate_audio_frame_with_tracker(
+        let stream = StreamMix {
+            state: &mut rollback_state.stream,
+            streams: &state.streams,
+            decoder: &mut state.stream_decoder,
+        };
+        generate_audio_frame_with_stream(
             &mut rollback_state.audio,
             &mut rollback_state.tracker,
             &mut state.tracker_engine,
             &state.sounds,
+            Some(stream),
             tick_rate,
             sample_rate,
             output,
@@ -249,15 +358,21 @@ impl nethercore_core::AudioGenerator for ZXAudioGenerator {
                 }
             };
 
+            let mut mix = rollback_state.audio;
+            mix.buses = mix.buses.with_player_volumes(&audio.bus_volumes);
             let snapshot = crate::audio_thread::AudioGenSnapshot {
-                audio: rollback_state.audio,
+                audio: mix,
                 tracker: rollback_state.tracker,
                 tracker_snapshot: state.tracker_engine.snapshot(),
                 sounds,
+                stream: rollback_state.stream,
+                streams: Arc::clone(&state.streams),
                 frame_number: 0, // frame_number not used currently
                 tick_rate,
                 sample_rate,
-                is_rollback: false, // is_rollback - main loop only calls this for confirmed frames
+                // Main loop only calls this for confirmed frames, but the
+                // game may have been stepped or rewound while paused
+                is_rollback: std::mem::take(&mut audio.resync),
             };
             audio.send_snapshot(snapshot);
 
@@ -271,12 +386,24 @@ impl nethercore_core::AudioGenerator for ZXAudioGenerator {
                 tick_rate,
                 sample_rate,
             );
+            if !rollback_state.audio.buses.is_paused(audio_bus::MUSIC) {
+                advance_stream_position(
+                    &mut rollback_state.stream,
+                    &state.streams,
+                    tick_rate,
+                    sample_rate,
+                );
+            }
         } else {
             // Synchronous mode: generate samples and push using reusable buffer
             // Note: We need to take the buffer out temporarily to avoid borrow conflicts
             let mut buffer = std::mem::take(&mut audio.frame_buffer);
             buffer.clear();
+            // Mix with the player's bus volumes, then restore the game's own
+            let buses = rollback_state.audio.buses;
+            rollback_state.audio.buses = buses.with_player_volumes(&audio.bus_volumes);
             Self::generate_frame(rollback_state, state, tick_rate, sample_rate, &mut buffer);
+            rollback_state.audio.buses = buses;
             audio.push_samples(&buffer);
             audio.frame_buffer = buffer;
         }
@@ -1,9 +1,13 @@
 //! Audio frame generation and position advancement
 
 use super::Sound;
-use super::mixing::{apply_pan, mix_channel, soft_clip};
+use super::mixing::{apply_pan, mix_channel, sample_stem, soft_clip};
 use super::output::SOURCE_SAMPLE_RATE;
-use crate::state::{AudioPlaybackState, ChannelState, TrackerState, tracker_flags};
+use super::stream::{StreamMix, mix_stream, stream_step};
+use crate::state::{
+    AudioPlaybackState, ChannelState, MAX_CHANNELS, MAX_MUSIC_LAYERS, TrackerState, audio_bus,
+    tracker_flags,
+};
 use crate::tracker::TrackerEngine;
 
 /// Generate one frame of audio samples with tracker support
@@ -28,6 +32,34 @@ pub fn generate_audio_frame_with_tracker(
     tick_rate: u32,
     sample_rate: u32,
     output: &mut Vec<f32>,
+) {
+    generate_audio_frame_with_stream(
+        playback_state,
+        tracker_state,
+        tracker_engine,
+        sounds,
+        None,
+        tick_rate,
+        sample_rate,
+        output,
+    );
+}
+
+/// Generate one frame of audio samples with tracker and streamed music support
+///
+/// Same as `generate_audio_frame_with_tracker`, plus the streamed music voice
+/// (if `stream` is given), which is decoded in chunks as it plays. The stream
+/// is mixed centered on top of the channels and music.
+#[allow(clippy::too_many_arguments)]
+pub fn generate_audio_frame_with_stream(
+    playback_state: &mut AudioPlaybackState,
+    tracker_state: &mut TrackerState,
+    tracker_engine: &mut TrackerEngine,
+    sounds: &[Option<Sound>],
+    mut stream: Option<StreamMix<'_>>,
+    tick_rate: u32,
+    sample_rate: u32,
+    output: &mut Vec<f32>,
 ) {
     // Calculate how many output samples per frame
     // At 60fps with 44100Hz: 44100/60 = 735 samples per frame
@@ -39,30 +71,66 @@ pub fn generate_audio_frame_with_tracker(
 
     // Calculate resampling ratio (source is 22050Hz, output is usually 44100Hz)
     let resample_ratio = SOURCE_SAMPLE_RATE as f32 / sample_rate as f32;
+    let stream_advance = stream_step(sample_rate);
+
+    // Music, tracker and stream all route through the music bus
+    let buses = playback_state.buses;
+    let music_paused = buses.is_paused(audio_bus::MUSIC);
+    let music_gain = buses.gain(audio_bus::MUSIC);
 
     // Check if tracker is active (mutually exclusive with PCM music)
     let tracker_active = tracker_state.handle != 0
         && (tracker_state.flags & tracker_flags::PLAYING) != 0
-        && (tracker_state.flags & tracker_flags::PAUSED) == 0;
+        && (tracker_state.flags & tracker_flags::PAUSED) == 0
+        && !music_paused;
 
     // Sync tracker engine to state at start of frame
     if tracker_active {
         tracker_engine.sync_to_state(tracker_state, sounds);
     }
 
+    // Stem layer volumes ramp smoothly across the frame toward this tick's fade step
+    let stems_active =
+        !tracker_active && playback_state.music_layers.is_active(&playback_state.music);
+    let layers_start = playback_state.music_layers;
+    let mut layers_end = layers_start;
+    if stems_active && !music_paused {
+        layers_end.step_fades();
+    }
+
+    // Scheduled channels stay silent for the start of the frame (or all of it)
+    let mut start = [0u32; MAX_CHANNELS];
+    for (index, channel) in playback_state.channels.iter().enumerate() {
+        if channel.sound != 0 && !buses.is_paused(buses.channel_bus(index)) {
+            start[index] =
+                playback_state
+                    .schedule
+                    .begin_frame(index, samples_per_frame, resample_ratio);
+        }
+    }
+
     // Generate each output sample
-    for _ in 0..samples_per_frame {
+    for i in 0..samples_per_frame {
         let mut left = 0.0f32;
         let mut right = 0.0f32;
 
         // Mix all active SFX channels
-        for channel in playback_state.channels.iter_mut() {
+        for (index, channel) in playback_state.channels.iter_mut().enumerate() {
             if channel.sound == 0 {
                 continue; // Channel is silent
             }
 
+            let bus = buses.channel_bus(index);
+            if buses.is_paused(bus) {
+                continue; // Bus is paused, hold the playhead
+            }
+
+            if i < start[index] {
+                continue; // Not scheduled to start yet
+            }
+
             if let Some(sample) = mix_channel(channel, sounds, resample_ratio) {
-                let (l, r) = apply_pan(sample, channel.pan, channel.volume);
+                let (l, r) = apply_pan(sample, channel.pan, channel.volume * buses.gain(bus));
                 left += l;
                 right += r;
             }
@@ -73,17 +141,47 @@ pub fn generate_audio_frame_with_tracker(
             // Mix tracker output and advance tracker state
             let (tracker_l, tracker_r) =
                 tracker_engine.render_sample_and_advance(tracker_state, sounds, sample_rate);
-            left += tracker_l;
-            right += tracker_r;
+            left += tracker_l * music_gain;
+            right += tracker_r * music_gain;
+        } else if stems_active && !music_paused && playback_state.music.sound != 0 {
+            // Mix every stem at the music playhead, then advance it once
+            let t = i as f32 / samples_per_frame as f32;
+            let clock = playback_state.music;
+            let mut mixed = 0.0;
+            for layer in 0..MAX_MUSIC_LAYERS {
+                let start = layers_start.volume[layer];
+                let layer_vol = start + (layers_end.volume[layer] - start) * t;
+                let sample = if layer == 0 {
+                    mix_channel(&mut playback_state.music, sounds, resample_ratio)
+                } else if layers_start.sounds[layer] != 0 {
+                    sample_stem(&clock, layers_start.sounds[layer], sounds)
+                } else {
+                    None
+                };
+                mixed += sample.unwrap_or(0.0) * layer_vol;
+            }
+            let vol = clock.volume * music_gain;
+            left += mixed * vol;
+            right += mixed * vol;
         } else if playback_state.music.sound != 0
+            && !music_paused
             && let Some(sample) = mix_channel(&mut playback_state.music, sounds, resample_ratio)
         {
             // Mix PCM music (centered, no pan)
-            let vol = playback_state.music.volume;
+            let vol = playback_state.music.volume * music_gain;
             left += sample * vol;
             right += sample * vol;
         }
 
+        // Mix streamed music (centered, volume already applied)
+        if !music_paused
+            && let Some(mix) = stream.as_mut()
+            && let Some(sample) = mix_stream(mix, stream_advance)
+        {
+            left += sample * music_gain;
+            right += sample * music_gain;
+        }
+
         // Soft clamp to prevent harsh clipping
         left = soft_clip(left);
         right = soft_clip(right);
@@ -91,6 +189,8 @@ pub fn generate_audio_frame_with_tracker(
         output.push(left);
         output.push(right);
     }
+
+    playback_state.music_layers.volume = layers_end.volume;
 }
 
 /// Advance audio playback positions without generating samples
@@ -112,26 +212,39 @@ pub fn advance_audio_positions(
     let samples_per_frame = sample_rate / tick_rate;
     let resample_ratio = SOURCE_SAMPLE_RATE as f32 / sample_rate as f32;
 
+    let buses = playback_state.buses;
+    let music_paused = buses.is_paused(audio_bus::MUSIC);
+
     // Check if tracker is active
     let tracker_active = tracker_state.handle != 0
         && (tracker_state.flags & tracker_flags::PLAYING) != 0
-        && (tracker_state.flags & tracker_flags::PAUSED) == 0;
+        && (tracker_state.flags & tracker_flags::PAUSED) == 0
+        && !music_paused;
 
     // Sync tracker engine to state at start of frame
     if tracker_active {
         tracker_engine.sync_to_state(tracker_state, sounds);
     }
 
-    // Advance SFX channel positions
-    for channel in playback_state.channels.iter_mut() {
-        if channel.sound == 0 {
+    // Advance SFX channel positions (paused buses hold their playheads, and
+    // scheduled channels only play the part of the frame after they start)
+    for (i, channel) in playback_state.channels.iter_mut().enumerate() {
+        if channel.sound == 0 || buses.is_paused(buses.channel_bus(i)) {
             continue;
         }
-        advance_channel_position(channel, sounds, resample_ratio, samples_per_frame);
+        let start = playback_state
+            .schedule
+            .begin_frame(i, samples_per_frame, resample_ratio);
+        if start < samples_per_frame {
+            advance_channel_position(channel, sounds, resample_ratio, samples_per_frame - start);
+        }
     }
 
-    // Advance music channel position (if not using tracker)
-    if !tracker_active && playback_state.music.sound != 0 {
+    // Advance music channel position and stem fades (if not using tracker)
+    if !tracker_active && !music_paused && playback_state.music.sound != 0 {
+        if playback_state.music_layers.is_active(&playback_state.music) {
+            playback_state.music_layers.step_fades();
+        }
         advance_channel_position(
             &mut playback_state.music,
             sounds,
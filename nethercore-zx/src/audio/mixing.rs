@@ -72,6 +72,20 @@ pub fn mix_channel(
     Some(sample)
 }
 
+/// Sample a music stem at the music channel's playhead without advancing it
+///
+/// Stems are expected to match the length of the base track; a stem that is
+/// missing or shorter than the playhead is silent.
+pub fn sample_stem(clock: &ChannelState, sound: u32, sounds: &[Option<Sound>]) -> Option<f32> {
+    let data = &sounds.get(sound as usize)?.as_ref()?.data;
+    let (source_idx, frac) = clock.get_position();
+    let sample1 = *data.get(source_idx)? as f32 / 32768.0;
+    let sample2 = data
+        .get(source_idx + 1)
+        .map_or(sample1, |&s| s as f32 / 32768.0);
+    Some(sample1 + (sample2 - sample1) * frac)
+}
+
 /// 17-point quarter-sine lookup table (cos values for left channel).
 /// Values are cos(i * PI/32) for i = 0..16, scaled to 0-255.
 const PAN_COS_LUT: [u8; 17] = [
@@ -19,14 +19,22 @@ mod backend;
 mod generation;
 mod mixing;
 mod output;
+mod stream;
+mod synth;
+mod voice;
 
 #[cfg(test)]
 mod tests;
 
 // Re-export public API
 pub use backend::{ZXAudio, ZXAudioGenerator};
-pub use generation::{advance_audio_positions, generate_audio_frame_with_tracker};
+pub use generation::{
+    advance_audio_positions, generate_audio_frame_with_stream, generate_audio_frame_with_tracker,
+};
 pub use output::{AudioOutput, OUTPUT_SAMPLE_RATE, SOURCE_SAMPLE_RATE};
+pub use stream::{Stream, StreamDecoder, StreamMix, advance_stream_position};
+pub use synth::{MAX_SFX_SECONDS, SfxError, SfxParams, sfx_wave, synthesize_sfx};
+pub use voice::VoiceDevice;
 
 /// Sound data (raw PCM)
 #[derive(Clone, Debug)]
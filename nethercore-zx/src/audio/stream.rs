@@ -0,0 +1,218 @@
+//! Streamed audio playback
+//!
+//! Long music tracks stay QOA-compressed in memory. The mixer decodes one QOA
+//! frame (5120 samples, ~0.23 s at 22050 Hz) at a time into a small cache, so
+//! memory use no longer grows with track length the way `rom_sound` does.
+//!
+//! Only the playhead (`StreamState`) is rolled back. Every QOA frame carries
+//! its own predictor state, so the decoder can jump to any frame after a seek
+//! or rollback without replaying the track from the start.
+
+use std::sync::Arc;
+
+use nether_qoa::{QOA_FRAME_HEADER_SIZE, QOA_FRAME_SAMPLES, decode_frame};
+use tracing::warn;
+
+use super::output::SOURCE_SAMPLE_RATE;
+use crate::state::{StreamState, stream_flags};
+
+/// Compressed stream data (loaded via `rom_stream`)
+#[derive(Clone, Debug)]
+pub struct Stream {
+    /// QOA frame data (22.05kHz mono)
+    pub data: Arc<[u8]>,
+    /// Byte offset of each QOA frame in `data`
+    pub frame_offsets: Arc<[u32]>,
+    /// Total decoded sample count
+    pub total_samples: u32,
+}
+
+impl Stream {
+    /// Index the frames of QOA data
+    ///
+    /// Returns `None` if the frame headers are malformed or the frames hold
+    /// fewer than `total_samples` samples.
+    pub fn new(data: Vec<u8>, total_samples: u32) -> Option<Self> {
+        let mut frame_offsets = Vec::new();
+        let mut offset = 0usize;
+        let mut samples = 0usize;
+
+        while samples < total_samples as usize {
+            let header = data.get(offset..offset + QOA_FRAME_HEADER_SIZE)?;
+            let frame_samples = u16::from_be_bytes([header[1], header[2]]) as usize;
+            let frame_size = u16::from_be_bytes([header[3], header[4]]) as usize;
+            // Sample lookup assumes full frames everywhere but the end
+            if frame_size <= QOA_FRAME_HEADER_SIZE
+                || (frame_samples != QOA_FRAME_SAMPLES
+                    && samples + frame_samples < total_samples as usize)
+            {
+                return None;
+            }
+
+            frame_offsets.push(offset as u32);
+            offset += frame_size;
+            samples += frame_samples;
+        }
+
+        if offset > data.len() {
+            return None;
+        }
+
+        Some(Self {
+            data: data.into(),
+            frame_offsets: frame_offsets.into(),
+            total_samples,
+        })
+    }
+
+    /// Duration in seconds (at 22050Hz)
+    pub fn duration_seconds(&self) -> f32 {
+        self.total_samples as f32 / SOURCE_SAMPLE_RATE as f32
+    }
+}
+
+/// Decoded-frame cache for one stream voice
+///
+/// Each mixer (the audio thread, or the main thread in synchronous mode) owns
+/// its own decoder. Holds two decoded QOA frames (even and odd) so
+/// interpolating across a frame boundary doesn't decode the same frame twice.
+#[derive(Debug, Default)]
+pub struct StreamDecoder {
+    slots: [DecodedFrame; 2],
+}
+
+#[derive(Debug, Default)]
+struct DecodedFrame {
+    /// (stream handle, frame index) currently held in `samples`
+    loaded: Option<(u32, usize)>,
+    samples: Vec<i16>,
+}
+
+impl StreamDecoder {
+    /// Create an empty decoder
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get sample `index` of `stream`, decoding its frame if it isn't cached
+    fn sample(&mut self, handle: u32, stream: &Stream, index: u32) -> i16 {
+        let frame = index as usize / QOA_FRAME_SAMPLES;
+        let slot = &mut self.slots[frame % 2];
+        if slot.loaded != Some((handle, frame)) {
+            slot.samples.clear();
+            slot.loaded = Some((handle, frame));
+
+            let Some(&offset) = stream.frame_offsets.get(frame) else {
+                return 0;
+            };
+            if let Err(e) = decode_frame(&stream.data[offset as usize..], &mut slot.samples) {
+                warn!("stream {}: failed to decode frame {}: {}", handle, frame, e);
+            }
+        }
+        slot.samples
+            .get(index as usize % QOA_FRAME_SAMPLES)
+            .copied()
+            .unwrap_or(0)
+    }
+}
+
+/// Streamed music to mix alongside the channels
+pub struct StreamMix<'a> {
+    /// Stream playback state (will be mutated to advance the playhead)
+    pub state: &'a mut StreamState,
+    /// Loaded streams (handle N is at index N-1)
+    pub streams: &'a [Stream],
+    /// Decoder cache owned by the mixer
+    pub decoder: &'a mut StreamDecoder,
+}
+
+/// Playhead advance per output sample, in 32.32 fixed-point source samples
+#[inline]
+pub fn stream_step(sample_rate: u32) -> u64 {
+    ((SOURCE_SAMPLE_RATE as u64) << 32) / sample_rate.max(1) as u64
+}
+
+/// Look up the stream for `state`, stopping playback if the handle is invalid
+fn active_stream<'a>(state: &mut StreamState, streams: &'a [Stream]) -> Option<&'a Stream> {
+    if !state.is_active() {
+        return None;
+    }
+    let stream = streams.get(state.handle as usize - 1);
+    if stream.is_none() {
+        warn!("stream handle {} out of bounds", state.handle);
+        state.flags &= !stream_flags::PLAYING;
+    }
+    stream
+}
+
+/// Wrap or stop a playhead that ran past the end of the stream
+///
+/// Returns `false` if playback stopped.
+fn handle_stream_end(state: &mut StreamState, total_samples: u32) -> bool {
+    if state.sample < total_samples {
+        return true;
+    }
+    if state.flags & stream_flags::LOOPING != 0 && total_samples > 0 {
+        let length = (total_samples as u64) << 32;
+        state.set_position(state.position() % length);
+        true
+    } else {
+        state.flags &= !stream_flags::PLAYING;
+        state.set_position(0);
+        false
+    }
+}
+
+/// Mix one output sample of the stream and advance its playhead
+///
+/// Returns the sample scaled by the stream volume, or `None` if nothing is
+/// playing.
+pub fn mix_stream(mix: &mut StreamMix<'_>, step: u64) -> Option<f32> {
+    let stream = active_stream(mix.state, mix.streams)?;
+    if !handle_stream_end(mix.state, stream.total_samples) {
+        return None;
+    }
+
+    let handle = mix.state.handle;
+    let index = mix.state.sample;
+    let frac = mix.state.frac as f32 / 4_294_967_296.0;
+
+    // Linear interpolation for smoother resampling
+    let sample1 = mix.decoder.sample(handle, stream, index) as f32 / 32768.0;
+    let next = if index + 1 < stream.total_samples {
+        Some(index + 1)
+    } else if mix.state.flags & stream_flags::LOOPING != 0 {
+        Some(0)
+    } else {
+        None
+    };
+    let sample2 = match next {
+        Some(next) => mix.decoder.sample(handle, stream, next) as f32 / 32768.0,
+        None => sample1,
+    };
+    let sample = sample1 + (sample2 - sample1) * frac;
+
+    mix.state
+        .set_position(mix.state.position().wrapping_add(step));
+
+    Some(sample * mix.state.volume)
+}
+
+/// Advance the stream playhead by one frame without decoding
+///
+/// Used on the main thread in threaded audio mode, where the audio thread
+/// does the decoding and mixing.
+pub fn advance_stream_position(
+    state: &mut StreamState,
+    streams: &[Stream],
+    tick_rate: u32,
+    sample_rate: u32,
+) {
+    let Some(stream) = active_stream(state, streams) else {
+        return;
+    };
+    let samples_per_frame = (sample_rate / tick_rate) as u64;
+    let advance = stream_step(sample_rate) * samples_per_frame;
+    state.set_position(state.position().wrapping_add(advance));
+    handle_stream_end(state, stream.total_samples);
+}
@@ -0,0 +1,194 @@
+//! Retro sound effect synthesis
+//!
+//! An sfxr-style generator: one oscillator with a pitch slide and vibrato,
+//! shaped by an attack/sustain/decay envelope. Games describe each effect with
+//! a 48-byte [`SfxParams`] and get back ordinary PCM, so a whole set of hits,
+//! jumps and pickups costs a few bytes of ROM instead of a WAV each.
+//!
+//! Output depends only on the parameters (noise uses its own seeded
+//! generator), so the same parameters always produce the same samples.
+
+use std::f32::consts::TAU;
+
+use bytemuck::{Pod, Zeroable};
+
+use super::output::SOURCE_SAMPLE_RATE;
+
+/// Oscillator waveforms for [`SfxParams::waveform`]
+pub mod sfx_wave {
+    pub const SQUARE: u32 = 0;
+    pub const SAW: u32 = 1;
+    pub const SINE: u32 = 2;
+    pub const NOISE: u32 = 3;
+    pub const TRIANGLE: u32 = 4;
+}
+
+/// Longest effect that can be synthesized, in seconds
+pub const MAX_SFX_SECONDS: f32 = 10.0;
+
+/// Lowest and highest oscillator frequencies (Hz) once slide and vibrato apply
+const MIN_FREQUENCY: f32 = 20.0;
+const MAX_FREQUENCY: f32 = SOURCE_SAMPLE_RATE as f32 / 2.0;
+
+/// Noise values per oscillator period (refreshed each period, like sfxr)
+const NOISE_STEPS: usize = 32;
+
+/// Sound effect description, read from WASM memory (48 bytes)
+#[repr(C)]
+#[derive(Clone, Copy, Default, Debug, PartialEq, Pod, Zeroable)]
+pub struct SfxParams {
+    /// `sfx_wave` oscillator
+    pub waveform: u32,
+    /// Square pulse width: 0 = 50% square, 1 = thinnest pulse
+    pub duty: f32,
+    /// Starting pitch (Hz)
+    pub frequency: f32,
+    /// Pitch slide in octaves per second (negative falls)
+    pub slide: f32,
+    /// Vibrato depth in semitones
+    pub vibrato_depth: f32,
+    /// Vibrato speed (Hz)
+    pub vibrato_rate: f32,
+    /// Fade-in time (seconds)
+    pub attack: f32,
+    /// Full-volume time (seconds)
+    pub sustain: f32,
+    /// Fade-out time (seconds)
+    pub decay: f32,
+    /// Extra volume at the start of the sustain, fading over it (0-1)
+    pub punch: f32,
+    /// Output volume (0-1)
+    pub volume: f32,
+    /// Noise seed
+    pub seed: u32,
+}
+
+/// Why a set of parameters was rejected
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SfxError {
+    /// Waveform is not one of the `sfx_wave` constants
+    Waveform(u32),
+    /// A parameter is NaN or infinite
+    NotFinite,
+    /// Frequency is not positive
+    Frequency,
+    /// A time is negative, all are zero, or the effect is too long
+    Length,
+}
+
+impl std::fmt::Display for SfxError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Waveform(waveform) => write!(f, "invalid waveform {}", waveform),
+            Self::NotFinite => write!(f, "parameters must be finite"),
+            Self::Frequency => write!(f, "frequency must be positive"),
+            Self::Length => write!(
+                f,
+                "attack, sustain and decay must be non-negative and total 0-{} seconds",
+                MAX_SFX_SECONDS
+            ),
+        }
+    }
+}
+
+/// Synthesize an effect as 22.05 kHz mono PCM
+pub fn synthesize_sfx(params: &SfxParams) -> Result<Vec<i16>, SfxError> {
+    if params.waveform > sfx_wave::TRIANGLE {
+        return Err(SfxError::Waveform(params.waveform));
+    }
+    let floats = [
+        params.duty,
+        params.frequency,
+        params.slide,
+        params.vibrato_depth,
+        params.vibrato_rate,
+        params.attack,
+        params.sustain,
+        params.decay,
+        params.punch,
+        params.volume,
+    ];
+    if !floats.iter().all(|v| v.is_finite()) {
+        return Err(SfxError::NotFinite);
+    }
+    if params.frequency <= 0.0 {
+        return Err(SfxError::Frequency);
+    }
+    let length = params.attack + params.sustain + params.decay;
+    let times_valid = params.attack >= 0.0 && params.sustain >= 0.0 && params.decay >= 0.0;
+    if !times_valid || length <= 0.0 || length > MAX_SFX_SECONDS {
+        return Err(SfxError::Length);
+    }
+
+    let rate = SOURCE_SAMPLE_RATE as f32;
+    let count = (length * rate).ceil() as usize;
+    let duty = 0.5 - 0.45 * params.duty.clamp(0.0, 1.0);
+    let punch = params.punch.clamp(0.0, 1.0);
+    let volume = params.volume.clamp(0.0, 1.0);
+
+    let mut rng = params.seed;
+    let mut noise = [0.0f32; NOISE_STEPS];
+    refill_noise(&mut noise, &mut rng);
+
+    let mut phase = 0.0f32;
+    let mut samples = Vec::with_capacity(count);
+    for i in 0..count {
+        let t = i as f32 / rate;
+
+        let vibrato = params.vibrato_depth / 12.0 * (TAU * params.vibrato_rate * t).sin();
+        let frequency = (params.frequency * (params.slide * t + vibrato).exp2())
+            .clamp(MIN_FREQUENCY, MAX_FREQUENCY);
+
+        let wave = match params.waveform {
+            sfx_wave::SQUARE => {
+                if phase < duty {
+                    1.0
+                } else {
+                    -1.0
+                }
+            }
+            sfx_wave::SAW => 1.0 - 2.0 * phase,
+            sfx_wave::SINE => (TAU * phase).sin(),
+            sfx_wave::NOISE => noise[(phase * NOISE_STEPS as f32) as usize % NOISE_STEPS],
+            _ => 4.0 * (phase - 0.5).abs() - 1.0,
+        };
+
+        let sample = wave * envelope(params, punch, t) * volume;
+        samples.push((sample.clamp(-1.0, 1.0) * i16::MAX as f32) as i16);
+
+        phase += frequency / rate;
+        if phase >= 1.0 {
+            phase = phase.fract();
+            if params.waveform == sfx_wave::NOISE {
+                refill_noise(&mut noise, &mut rng);
+            }
+        }
+    }
+    Ok(samples)
+}
+
+/// Envelope level `t` seconds in (punch boosts the start of the sustain)
+fn envelope(params: &SfxParams, punch: f32, t: f32) -> f32 {
+    if t < params.attack {
+        return t / params.attack;
+    }
+    let t = t - params.attack;
+    if t < params.sustain {
+        return 1.0 + punch * (1.0 - t / params.sustain);
+    }
+    let t = t - params.sustain;
+    if params.decay > 0.0 {
+        (1.0 - t / params.decay).max(0.0)
+    } else {
+        0.0
+    }
+}
+
+/// Fill one period of noise from a linear congruential generator
+fn refill_noise(noise: &mut [f32; NOISE_STEPS], state: &mut u32) {
+    for value in noise.iter_mut() {
+        *state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+        // High bits of an LCG are the random ones
+        *value = (*state >> 8) as f32 / (1u32 << 23) as f32 - 1.0;
+    }
+}
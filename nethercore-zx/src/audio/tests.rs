@@ -2,7 +2,7 @@
 
 use super::mixing::{apply_pan, soft_clip};
 use super::*;
-use crate::state::{AudioPlaybackState, ChannelState, TrackerState};
+use crate::state::{AudioPlaybackState, ChannelState, TrackerState, audio_bus};
 use crate::tracker::TrackerEngine;
 
 #[test]
@@ -304,3 +304,398 @@ fn test_advance_positions_sound_finishes_correctly() {
         "Channel sound should be cleared when sound finishes (advance_positions)"
     );
 }
+
+// ============================================================================
+// Streamed music
+// ============================================================================
+
+fn test_stream(samples: &[i16]) -> Stream {
+    Stream::new(nether_qoa::encode_qoa(samples), samples.len() as u32).unwrap()
+}
+
+fn playing_stream(looping: bool) -> crate::state::StreamState {
+    use crate::state::stream_flags;
+    crate::state::StreamState {
+        handle: 1,
+        flags: stream_flags::PLAYING | if looping { stream_flags::LOOPING } else { 0 },
+        volume: 1.0,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_stream_indexes_frames() {
+    let samples: Vec<i16> = (0..12000).map(|i| (i % 200) as i16 * 50).collect();
+    let stream = test_stream(&samples);
+    assert_eq!(stream.frame_offsets.len(), 3);
+    assert_eq!(stream.frame_offsets[0], 0);
+    assert!((stream.duration_seconds() - 12000.0 / 22050.0).abs() < 1e-6);
+
+    // Fewer frames than claimed samples
+    let data = nether_qoa::encode_qoa(&samples);
+    assert!(Stream::new(data, 20000).is_none());
+}
+
+#[test]
+fn test_stream_decodes_in_chunks() {
+    // Crosses several QOA frame boundaries in one tick
+    let samples: Vec<i16> = (0..16000)
+        .map(|i| ((i as f32 * 0.03).sin() * 10000.0) as i16)
+        .collect();
+    let streams = vec![test_stream(&samples)];
+    let expected =
+        nether_qoa::decode_qoa(&nether_qoa::encode_qoa(&samples), samples.len()).unwrap();
+
+    let mut state = playing_stream(false);
+    state.set_position(5100 << 32);
+    let mut decoder = StreamDecoder::new();
+    let mut mix = StreamMix {
+        state: &mut state,
+        streams: &streams,
+        decoder: &mut decoder,
+    };
+
+    // Step one whole source sample per output sample: output == decoded PCM
+    for &expected in &expected[5100..5140] {
+        let sample = super::stream::mix_stream(&mut mix, 1 << 32).unwrap();
+        assert!((sample - expected as f32 / 32768.0).abs() < 1e-6);
+    }
+}
+
+#[test]
+fn test_stream_stops_or_loops_at_end() {
+    let streams = vec![test_stream(&[1000i16; 100])];
+
+    let mut state = playing_stream(false);
+    advance_stream_position(&mut state, &streams, 60, 44100);
+    assert!(!state.is_active(), "Non-looping stream should stop");
+    assert_eq!(state.position(), 0);
+
+    let mut state = playing_stream(true);
+    advance_stream_position(&mut state, &streams, 60, 44100);
+    assert!(state.is_active(), "Looping stream should keep playing");
+    // 735 output samples at half speed = 367.5 source samples, wrapped
+    assert_eq!(state.sample, 367 % 100);
+}
+
+#[test]
+fn test_stream_advance_matches_generate_frame() {
+    let samples: Vec<i16> = (0..30000).map(|i| (i % 1000) as i16).collect();
+    let streams = vec![test_stream(&samples)];
+
+    let mut mixed = playing_stream(false);
+    let mut advanced = mixed;
+    let mut decoder = StreamDecoder::new();
+    let mut output = Vec::new();
+
+    for _ in 0..10 {
+        generate_audio_frame_with_stream(
+            &mut AudioPlaybackState::default(),
+            &mut TrackerState::default(),
+            &mut TrackerEngine::new(),
+            &[],
+            Some(StreamMix {
+                state: &mut mixed,
+                streams: &streams,
+                decoder: &mut decoder,
+            }),
+            60,
+            44100,
+            &mut output,
+        );
+        advance_stream_position(&mut advanced, &streams, 60, 44100);
+        assert_eq!(mixed.position(), advanced.position());
+    }
+    assert!(output.iter().any(|&s| s != 0.0));
+}
+
+#[test]
+fn test_bus_volume_scales_channels() {
+    let sound = Sound {
+        data: Arc::new(vec![16000i16; 22050]),
+    };
+    let sounds: Vec<Option<Sound>> = vec![None, Some(sound)];
+
+    let mut full = AudioPlaybackState::default();
+    full.channels[0].sound = 1;
+    full.channels[0].volume = 1.0;
+    let mut ducked = full;
+    ducked.buses.volume[audio_bus::SFX as usize] = 0.5;
+    ducked.buses.volume[audio_bus::MASTER as usize] = 0.5;
+
+    let mut full_out = Vec::new();
+    let mut ducked_out = Vec::new();
+    for (state, output) in [(&mut full, &mut full_out), (&mut ducked, &mut ducked_out)] {
+        generate_audio_frame_with_tracker(
+            state,
+            &mut TrackerState::default(),
+            &mut TrackerEngine::new(),
+            &sounds,
+            60,
+            44100,
+            output,
+        );
+    }
+
+    // SFX bus at 0.5 under master at 0.5 plays at a quarter volume
+    let last = full_out.len() - 1;
+    assert!((ducked_out[last] - full_out[last] * 0.25).abs() < 0.001);
+    assert_eq!(full.channels[0].position, ducked.channels[0].position);
+}
+
+#[test]
+fn test_paused_bus_holds_position() {
+    let sound = Sound {
+        data: Arc::new(vec![1000i16; 22050]),
+    };
+    let sounds: Vec<Option<Sound>> = vec![None, Some(sound)];
+
+    let custom = audio_bus::BUILTIN_COUNT;
+    let mut state = AudioPlaybackState::default();
+    for channel in &mut state.channels[..2] {
+        channel.sound = 1;
+        channel.volume = 1.0;
+    }
+    state.buses.channel_bus[1] = custom as u8;
+    state.buses.paused = 1 << custom;
+    let mut advanced = state;
+
+    let mut output = Vec::new();
+    generate_audio_frame_with_tracker(
+        &mut state,
+        &mut TrackerState::default(),
+        &mut TrackerEngine::new(),
+        &sounds,
+        60,
+        44100,
+        &mut output,
+    );
+    advance_audio_positions(
+        &mut advanced,
+        &mut TrackerState::default(),
+        &mut TrackerEngine::new(),
+        &sounds,
+        60,
+        44100,
+    );
+
+    // Channel 0 (sfx) plays, channel 1 (paused bus) holds its playhead
+    assert!(state.channels[0].position > 0);
+    assert_eq!(state.channels[1].position, 0);
+    assert_eq!(state.channels[1].sound, 1);
+    assert_eq!(advanced.channels[0].position, state.channels[0].position);
+    assert_eq!(advanced.channels[1].position, 0);
+
+    // Pausing master holds everything
+    state.buses.paused = 1 << audio_bus::MASTER;
+    let position = state.channels[0].position;
+    generate_audio_frame_with_tracker(
+        &mut state,
+        &mut TrackerState::default(),
+        &mut TrackerEngine::new(),
+        &sounds,
+        60,
+        44100,
+        &mut output,
+    );
+    assert_eq!(state.channels[0].position, position);
+    assert!(output.iter().all(|&s| s == 0.0));
+}
+
+#[test]
+fn test_music_layers_mix_in_lockstep() {
+    let base = Sound {
+        data: Arc::new(vec![8000i16; 22050]),
+    };
+    let stem = Sound {
+        data: Arc::new(vec![4000i16; 22050]),
+    };
+    let sounds: Vec<Option<Sound>> = vec![None, Some(base), Some(stem)];
+
+    let mut state = AudioPlaybackState::default();
+    state.music.sound = 1;
+    state.music.volume = 1.0;
+    state.music_layers.sounds[..2].copy_from_slice(&[1, 2]);
+    state.music_layers.set_volume(0, 1.0, 0);
+    let mut advanced = state;
+
+    // Only the base layer is audible until the stem is raised
+    let mut output = Vec::new();
+    generate_audio_frame_with_tracker(
+        &mut state,
+        &mut TrackerState::default(),
+        &mut TrackerEngine::new(),
+        &sounds,
+        60,
+        44100,
+        &mut output,
+    );
+    assert!((output[0] - 8000.0 / 32768.0).abs() < 0.001);
+
+    // Fade the stem in over 2 ticks: half way after one tick, full after two
+    for s in [&mut state, &mut advanced] {
+        s.music_layers.set_volume(1, 1.0, 2);
+    }
+    for _ in 0..2 {
+        generate_audio_frame_with_tracker(
+            &mut state,
+            &mut TrackerState::default(),
+            &mut TrackerEngine::new(),
+            &sounds,
+            60,
+            44100,
+            &mut output,
+        );
+    }
+    for _ in 0..3 {
+        advance_audio_positions(
+            &mut advanced,
+            &mut TrackerState::default(),
+            &mut TrackerEngine::new(),
+            &sounds,
+            60,
+            44100,
+        );
+    }
+    let last = output.len() - 1;
+    assert!((output[last] - 12000.0 / 32768.0).abs() < 0.001);
+    assert_eq!(state.music_layers.volume, advanced.music_layers.volume);
+    assert_eq!(state.music.position, advanced.music.position);
+}
+
+#[test]
+fn test_scheduled_sound_starts_on_offset() {
+    let sound_data: Vec<i16> = vec![10_000; 22050];
+    let sounds: Vec<Option<Sound>> = vec![
+        None,
+        Some(Sound {
+            data: Arc::new(sound_data),
+        }),
+    ];
+
+    // Start one tick from now, 100 source samples (200 output samples) in
+    let mut state1 = AudioPlaybackState::default();
+    state1.channels[0].sound = 1;
+    state1.channels[0].volume = 1.0;
+    state1.schedule.set(0, 1, 100);
+    let mut state2 = state1;
+
+    let mut tracker_state = TrackerState::default();
+    let mut tracker_engine = TrackerEngine::new();
+    let mut output = Vec::new();
+
+    // Waiting tick: silent, nothing consumed
+    generate_audio_frame_with_tracker(
+        &mut state1,
+        &mut tracker_state,
+        &mut tracker_engine,
+        &sounds,
+        60,
+        44100,
+        &mut output,
+    );
+    assert!(output.iter().all(|&s| s == 0.0));
+    assert_eq!(state1.channels[0].position, 0);
+
+    // Start tick: silent up to the offset, then playing
+    generate_audio_frame_with_tracker(
+        &mut state1,
+        &mut tracker_state,
+        &mut tracker_engine,
+        &sounds,
+        60,
+        44100,
+        &mut output,
+    );
+    assert!(output[..200 * 2].iter().all(|&s| s == 0.0));
+    assert!(output[200 * 2..].iter().all(|&s| s != 0.0));
+    assert!(!state1.schedule.is_waiting(0));
+
+    // The position-only path lands in the same place
+    let mut tracker_state = TrackerState::default();
+    let mut tracker_engine = TrackerEngine::new();
+    for _ in 0..2 {
+        advance_audio_positions(
+            &mut state2,
+            &mut tracker_state,
+            &mut tracker_engine,
+            &sounds,
+            60,
+            44100,
+        );
+    }
+    assert_eq!(state1.channels[0].position, state2.channels[0].position);
+    assert_eq!(state1.schedule.samples, state2.schedule.samples);
+}
+
+fn coin_sfx() -> SfxParams {
+    SfxParams {
+        waveform: sfx_wave::SQUARE,
+        frequency: 880.0,
+        slide: 2.0,
+        sustain: 0.05,
+        decay: 0.1,
+        punch: 0.5,
+        volume: 0.5,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_synth_sfx_length_and_envelope() {
+    let pcm = synthesize_sfx(&coin_sfx()).unwrap();
+    assert_eq!(
+        pcm.len(),
+        (0.15 * SOURCE_SAMPLE_RATE as f32).ceil() as usize
+    );
+
+    // Punch starts above the sustain volume, and the decay ends near silence
+    let peak =
+        |range: std::ops::Range<usize>| pcm[range].iter().map(|s| s.unsigned_abs()).max().unwrap();
+    assert!(peak(0..100) > 20_000);
+    assert!(peak(1000..1100) < 20_000);
+    assert!(peak(pcm.len() - 50..pcm.len()) < 1000);
+}
+
+#[test]
+fn test_synth_sfx_is_deterministic() {
+    let noise = SfxParams {
+        waveform: sfx_wave::NOISE,
+        seed: 7,
+        ..coin_sfx()
+    };
+    assert_eq!(synthesize_sfx(&noise), synthesize_sfx(&noise));
+    assert_ne!(
+        synthesize_sfx(&noise),
+        synthesize_sfx(&SfxParams { seed: 8, ..noise })
+    );
+}
+
+#[test]
+fn test_synth_sfx_rejects_invalid_params() {
+    let sfx = coin_sfx();
+    let invalid = [
+        SfxParams { waveform: 5, ..sfx },
+        SfxParams {
+            frequency: 0.0,
+            ..sfx
+        },
+        SfxParams {
+            slide: f32::NAN,
+            ..sfx
+        },
+        SfxParams { decay: -1.0, ..sfx },
+        SfxParams {
+            sustain: MAX_SFX_SECONDS,
+            ..sfx
+        },
+        SfxParams {
+            sustain: 0.0,
+            decay: 0.0,
+            ..sfx
+        },
+    ];
+    for params in invalid {
+        assert!(synthesize_sfx(&params).is_err(), "{:?}", params);
+    }
+}
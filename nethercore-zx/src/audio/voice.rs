@@ -0,0 +1,114 @@
+//! Voice chat device I/O
+//!
+//! Voice uses its own cpal streams rather than the game audio ring buffer:
+//! game audio is generated per confirmed frame, while voice must flow
+//! continuously regardless of rollback or frame pacing.
+
+use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
+use nethercore_core::net::voice::VoiceChannel;
+use tracing::{error, info, warn};
+
+/// Microphone capture and voice playback streams
+///
+/// Dropping this stops both streams.
+pub struct VoiceDevice {
+    _input: Option<cpal::Stream>,
+    _output: Option<cpal::Stream>,
+}
+
+impl VoiceDevice {
+    /// Open the default input and output devices for `voice`
+    ///
+    /// Missing or unsupported devices are logged and skipped, so a machine
+    /// without a microphone can still hear other players.
+    pub fn open(voice: VoiceChannel) -> Self {
+        let input = open_input(voice.clone())
+            .inspect_err(|e| warn!("Voice capture unavailable: {}", e))
+            .ok();
+        let output = open_output(voice)
+            .inspect_err(|e| warn!("Voice playback unavailable: {}", e))
+            .ok();
+
+        info!(
+            capture = input.is_some(),
+            playback = output.is_some(),
+            "Voice devices opened"
+        );
+
+        Self {
+            _input: input,
+            _output: output,
+        }
+    }
+}
+
+fn open_input(voice: VoiceChannel) -> Result<cpal::Stream, String> {
+    let device = cpal::default_host()
+        .default_input_device()
+        .ok_or_else(|| "No audio input device available".to_string())?;
+
+    let config = device
+        .default_input_config()
+        .map_err(|e| format!("Failed to get default input config: {}", e))?;
+
+    if config.sample_format() != cpal::SampleFormat::F32 {
+        return Err(format!(
+            "Unsupported input sample format: {:?}",
+            config.sample_format()
+        ));
+    }
+
+    let channels = config.channels() as usize;
+    let sample_rate = config.sample_rate().0;
+    let stream = device
+        .build_input_stream(
+            &config.into(),
+            move |data: &[f32], _: &cpal::InputCallbackInfo| {
+                voice.push_capture(data, channels, sample_rate);
+            },
+            |err| error!("Voice input stream error: {}", err),
+            None,
+        )
+        .map_err(|e| format!("Failed to build voice input stream: {}", e))?;
+
+    stream
+        .play()
+        .map_err(|e| format!("Failed to start voice input stream: {}", e))?;
+    Ok(stream)
+}
+
+fn open_output(voice: VoiceChannel) -> Result<cpal::Stream, String> {
+    let device = cpal::default_host()
+        .default_output_device()
+        .ok_or_else(|| "No audio output device available".to_string())?;
+
+    let config = device
+        .default_output_config()
+        .map_err(|e| format!("Failed to get default output config: {}", e))?;
+
+    if config.sample_format() != cpal::SampleFormat::F32 {
+        return Err(format!(
+            "Unsupported output sample format: {:?}",
+            config.sample_format()
+        ));
+    }
+
+    let channels = config.channels() as usize;
+    let sample_rate = config.sample_rate().0;
+    let stream = device
+        .build_output_stream(
+            &config.into(),
+            move |data: &mut [f32], _: &cpal::OutputCallbackInfo| {
+                data.fill(0.0);
+                voice.mix_into(data, channels, sample_rate);
+            },
+            |err| error!("Voice output stream error: {}", err),
+            None,
+        )
+        .map_err(|e| format!("Failed to build voice output stream: {}", e))?;
+
+    stream
+        .play()
+        .map_err(|e| format!("Failed to start voice output stream: {}", e))?;
+    Ok(stream)
+}
@@ -2,6 +2,7 @@
 //!
 //! Provides a handle for sending snapshots to the audio thread and managing its lifecycle.
 
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::mpsc::{SyncSender, TrySendError};
 use std::sync::{Arc, Condvar, Mutex};
 use std::thread::JoinHandle;
@@ -24,6 +25,9 @@ pub struct AudioGenHandle {
     /// Condition variable for signaling from cpal callback
     /// Shared with audio thread - cpal notifies when buffer space available
     pub(crate) condvar: Arc<(Mutex<bool>, Condvar)>,
+
+    /// Pause flag shared with audio thread (fades to silence while set)
+    pub(super) paused: Arc<AtomicBool>,
 }
 
 impl AudioGenHandle {
@@ -50,6 +54,14 @@ impl AudioGenHandle {
         }
     }
 
+    /// Pause or resume generation
+    ///
+    /// While paused the thread fades out and outputs silence without
+    /// advancing its predicted state.
+    pub fn set_paused(&self, paused: bool) {
+        self.paused.store(paused, Ordering::Relaxed);
+    }
+
     /// Check if the audio thread is still running
     pub fn is_alive(&self) -> bool {
         self.handle
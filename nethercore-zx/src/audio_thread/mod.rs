@@ -35,10 +35,11 @@ mod thread;
 // Re-export public API
 pub use output::ThreadedAudioOutput;
 pub use snapshot::AudioGenSnapshot;
+pub(crate) use thread::fill_fade_out;
 
 // Re-export for internal use by tests
 #[cfg(test)]
-use thread::AudioGenThread;
+use thread::{AudioGenThread, merge_stream};
 
 #[cfg(test)]
 mod tests;
@@ -176,6 +176,11 @@ impl ThreadedAudioOutput {
         self.gen_handle.send_snapshot(snapshot)
     }
 
+    /// Pause or resume generation (fades out to silence while paused)
+    pub fn set_paused(&self, paused: bool) {
+        self.gen_handle.set_paused(paused);
+    }
+
     /// Get the output sample rate
     pub fn sample_rate(&self) -> u32 {
         self.sample_rate
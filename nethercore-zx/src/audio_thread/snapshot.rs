@@ -4,8 +4,8 @@
 
 use std::sync::Arc;
 
-use crate::audio::Sound;
-use crate::state::{AudioPlaybackState, TrackerState};
+use crate::audio::{Sound, Stream};
+use crate::state::{AudioPlaybackState, StreamState, TrackerState};
 use crate::tracker::TrackerEngineSnapshot;
 
 /// Snapshot of audio state sent from main thread to audio generation thread
@@ -26,6 +26,12 @@ pub struct AudioGenSnapshot {
     /// Sound data - Arc for sharing without copying
     pub sounds: Arc<Vec<Option<Sound>>>,
 
+    /// Streamed music playhead
+    pub stream: StreamState,
+
+    /// Compressed stream data - Arc for sharing without copying
+    pub streams: Arc<Vec<Stream>>,
+
     /// Frame identifier for ordering and debugging
     pub frame_number: i32,
 
@@ -56,6 +62,8 @@ impl AudioGenSnapshot {
             tracker,
             tracker_snapshot,
             sounds,
+            stream: StreamState::default(),
+            streams: Arc::default(),
             frame_number,
             tick_rate,
             sample_rate,
@@ -485,6 +485,56 @@ fn test_audio_thread_processes_snapshots() {
     drop(handle);
 }
 
+#[test]
+fn test_paused_thread_outputs_silence() {
+    use ringbuf::HeapRb;
+    use ringbuf::traits::{Consumer, Split};
+
+    let ring = HeapRb::<f32>::new(8192);
+    let (producer, mut consumer) = ring.split();
+
+    let handle = AudioGenThread::spawn(producer, 44100);
+    handle.set_paused(true);
+
+    let snapshot = AudioGenSnapshot::new(
+        AudioPlaybackState::default(),
+        TrackerState::default(),
+        TrackerEngine::new().snapshot(),
+        Arc::new(Vec::new()),
+        0,
+        60,
+        44100,
+        false,
+    );
+    assert!(handle.send_snapshot(snapshot));
+
+    std::thread::sleep(Duration::from_millis(50));
+
+    // Still fed so the device doesn't underrun, but silent
+    let mut samples_received = 0;
+    while let Some(sample) = consumer.try_pop() {
+        assert_eq!(sample, 0.0);
+        samples_received += 1;
+    }
+    assert!(samples_received >= 735 * 2);
+
+    drop(handle);
+}
+
+#[test]
+fn test_fade_out_ramps_to_silence() {
+    let mut output = vec![0.7; 4];
+    fill_fade_out(&mut output, (0.8, -0.4), 20, 4);
+
+    assert_eq!(output.len(), 20);
+    assert_eq!((output[0], output[1]), (0.8, -0.4));
+    for i in 1..4 {
+        assert!(output[i * 2].abs() < output[(i - 1) * 2].abs());
+        assert!(output[i * 2 + 1].abs() < output[(i - 1) * 2 + 1].abs());
+    }
+    assert!(output[8..].iter().all(|&s| s == 0.0));
+}
+
 #[test]
 fn test_crossfade_application() {
     // Test that crossfade smooths transitions
@@ -2065,3 +2115,48 @@ fn test_first_tracker_start_no_crossfade() {
         "First tracker should NOT trigger crossfade"
     );
 }
+
+#[test]
+fn test_stream_merge_keeps_predicted_playhead() {
+    use crate::state::{StreamState, stream_flags};
+
+    let mut current = StreamState {
+        handle: 1,
+        flags: stream_flags::PLAYING,
+        volume: 1.0,
+        cue: 1,
+        ..Default::default()
+    };
+    current.set_position(5000 << 32);
+
+    // Main thread is behind (normal drift) and changed the volume
+    let mut snapshot = current;
+    snapshot.set_position(4000 << 32);
+    snapshot.volume = 0.5;
+    assert!(!merge_stream(&mut current, &snapshot));
+    assert_eq!(current.sample, 5000, "Playhead should not be reset");
+    assert_eq!(current.volume, 0.5);
+
+    // Pausing is a flag change, not a jump
+    snapshot.flags |= stream_flags::PAUSED;
+    assert!(!merge_stream(&mut current, &snapshot));
+    assert!(!current.is_active());
+
+    // Seek bumps the cue: take the main thread's playhead
+    snapshot.flags = stream_flags::PLAYING;
+    snapshot.cue += 1;
+    snapshot.set_position(100 << 32);
+    assert!(
+        !merge_stream(&mut current, &snapshot),
+        "Was paused, no crossfade"
+    );
+    assert_eq!(current.sample, 100);
+
+    snapshot.cue += 1;
+    snapshot.set_position(200 << 32);
+    assert!(
+        merge_stream(&mut current, &snapshot),
+        "Audible jump crossfades"
+    );
+    assert_eq!(current.sample, 200);
+}
@@ -2,6 +2,7 @@
 //!
 //! Runs audio generation on a separate thread using predictive generation.
 
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::mpsc;
 use std::sync::{Arc, Condvar, Mutex};
 use std::thread;
@@ -11,8 +12,8 @@ use ringbuf::HeapProd;
 use ringbuf::traits::{Observer, Producer};
 use tracing::{debug, trace, warn};
 
-use crate::audio::generate_audio_frame_with_tracker;
-use crate::state::{AudioPlaybackState, TrackerState};
+use crate::audio::{StreamDecoder, StreamMix, generate_audio_frame_with_stream};
+use crate::state::{AudioPlaybackState, StreamState, TrackerState};
 use crate::tracker::TrackerEngine;
 
 use super::handle::AudioGenHandle;
@@ -37,6 +38,9 @@ pub(super) struct AudioGenThread {
     /// When cpal consumes samples, it notifies this condvar to wake the audio thread
     condvar: Arc<(Mutex<bool>, Condvar)>,
 
+    /// Set while the frame debugger has the game paused
+    paused: Arc<AtomicBool>,
+
     /// Pre-allocated output buffer (reused each frame)
     output_buffer: Vec<f32>,
 
@@ -53,6 +57,12 @@ pub(super) struct AudioGenThread {
     /// Current tracker state - continuously advanced by audio thread
     gen_tracker: TrackerState,
 
+    /// Current streamed music state - continuously advanced by audio thread
+    gen_stream: StreamState,
+
+    /// Decoded-chunk cache for streamed music
+    stream_decoder: StreamDecoder,
+
     /// Last confirmed snapshot from main thread (authoritative for game state)
     /// Used for sound data reference and tick rate
     last_snapshot: Option<AudioGenSnapshot>,
@@ -94,6 +104,8 @@ impl AudioGenThread {
         // cpal notifies when it consumes samples, waking the audio thread to generate more
         let condvar = Arc::new((Mutex::new(false), Condvar::new()));
         let condvar_clone = condvar.clone();
+        let paused = Arc::new(AtomicBool::new(false));
+        let paused_clone = paused.clone();
 
         let handle = thread::Builder::new()
             .name("audio-gen".into())
@@ -102,12 +114,15 @@ impl AudioGenThread {
                     rx,
                     producer,
                     condvar: condvar_clone,
+                    paused: paused_clone,
                     output_buffer: Vec::with_capacity(2048),
                     tracker_engine: TrackerEngine::new(),
                     sample_rate,
                     // Predictive state - starts empty, filled by first snapshot
                     gen_audio: AudioPlaybackState::default(),
                     gen_tracker: TrackerState::default(),
+                    gen_stream: StreamState::default(),
+                    stream_decoder: StreamDecoder::new(),
                     last_snapshot: None,
                     samples_since_snapshot: 0,
                     has_state: false,
@@ -126,6 +141,7 @@ impl AudioGenThread {
             tx: Some(tx),
             handle: Some(handle),
             condvar,
+            paused,
         }
     }
 
@@ -164,7 +180,9 @@ impl AudioGenThread {
                     self.metrics.buffer_underruns += 1;
                 }
 
-                if self.has_state {
+                if self.paused.load(Ordering::Relaxed) {
+                    self.generate_paused();
+                } else if self.has_state {
                     self.generate_frame();
                 } else {
                     self.generate_silence();
@@ -211,6 +229,7 @@ impl AudioGenThread {
         if !self.has_state {
             self.gen_audio = snapshot.audio;
             self.gen_tracker = snapshot.tracker;
+            self.gen_stream = snapshot.stream;
             self.tracker_engine
                 .apply_snapshot(&snapshot.tracker_snapshot);
             self.last_snapshot = Some(snapshot);
@@ -233,7 +252,12 @@ impl AudioGenThread {
         // Merge new SFX that started (sound != 0 with position == 0 OR sound ID changed)
         for (i, snap_channel) in snapshot.audio.channels.iter().enumerate() {
             let sound_changed = snap_channel.sound != self.gen_audio.channels[i].sound;
-            if snap_channel.sound != 0 && (snap_channel.position == 0 || sound_changed) {
+            if snapshot.audio.schedule.is_waiting(i) && !sound_changed {
+                // Scheduled SFX we already merged - we're counting down (or
+                // already playing) on our own timeline, so only take volume/pan
+                self.gen_audio.channels[i].volume = snap_channel.volume;
+                self.gen_audio.channels[i].pan = snap_channel.pan;
+            } else if snap_channel.sound != 0 && (snap_channel.position == 0 || sound_changed) {
                 // New SFX started OR switched to different sound - start it fresh
                 // Use crossfade if we were already playing something (sound changed mid-playback)
                 if sound_changed && self.gen_audio.channels[i].sound != 0 {
@@ -245,6 +269,8 @@ impl AudioGenThread {
                     );
                 }
                 self.gen_audio.channels[i] = *snap_channel;
+                self.gen_audio.schedule.ticks[i] = snapshot.audio.schedule.ticks[i];
+                self.gen_audio.schedule.samples[i] = snapshot.audio.schedule.samples[i];
                 trace!(
                     "Merged new SFX on channel {}: sound {}",
                     i, snap_channel.sound
@@ -252,6 +278,7 @@ impl AudioGenThread {
             } else if snap_channel.sound == 0 && self.gen_audio.channels[i].sound != 0 {
                 // SFX was stopped by game - stop it (instant, no pop needed for stop)
                 self.gen_audio.channels[i].sound = 0;
+                self.gen_audio.schedule.clear(i);
                 trace!("Stopped SFX on channel {}", i);
             } else if snap_channel.sound != 0 {
                 // Existing SFX - update volume/pan (cosmetic, no position change)
@@ -286,6 +313,12 @@ impl AudioGenThread {
             self.gen_audio.music.pan = snapshot.audio.music.pan;
         }
 
+        // Bus volumes, pauses and routing are plain mix settings - take them as-is
+        self.gen_audio.buses = snapshot.audio.buses;
+
+        // Stem layers follow the music playhead, so only their volumes matter
+        self.gen_audio.music_layers = snapshot.audio.music_layers;
+
         // Tracker: detect module change (new song) and merge controllable values
         let tracker_changed = snapshot.tracker.handle != self.gen_tracker.handle;
         if tracker_changed && snapshot.tracker.handle != 0 {
@@ -317,6 +350,16 @@ impl AudioGenThread {
             self.gen_tracker.speed = snapshot.tracker.speed;
         }
 
+        // Streamed music: only play/seek move the playhead
+        if merge_stream(&mut self.gen_stream, &snapshot.stream) {
+            self.crossfade_active = true;
+            self.crossfade_from = self.prev_frame_last;
+            trace!(
+                "Stream jump (handle {}), scheduling crossfade",
+                snapshot.stream.handle
+            );
+        }
+
         // Update reference snapshot (for sound data access) and reset counter
         self.last_snapshot = Some(snapshot);
         self.samples_since_snapshot = 0;
@@ -337,6 +380,7 @@ impl AudioGenThread {
         // Full reset to rollback state
         self.gen_audio = snapshot.audio;
         self.gen_tracker = snapshot.tracker;
+        self.gen_stream = snapshot.stream;
         self.tracker_engine
             .apply_snapshot(&snapshot.tracker_snapshot);
         self.last_snapshot = Some(snapshot);
@@ -361,11 +405,17 @@ impl AudioGenThread {
 
         // Generate samples using our predictive state
         self.output_buffer.clear();
-        generate_audio_frame_with_tracker(
+        let stream = StreamMix {
+            state: &mut self.gen_stream,
+            streams: &snapshot.streams,
+            decoder: &mut self.stream_decoder,
+        };
+        generate_audio_frame_with_stream(
             &mut self.gen_audio,
             &mut self.gen_tracker,
             &mut self.tracker_engine,
             &snapshot.sounds,
+            Some(stream),
             snapshot.tick_rate,
             snapshot.sample_rate,
             &mut self.output_buffer,
@@ -455,6 +505,22 @@ impl AudioGenThread {
         trace!("Applied crossfade over {} stereo samples", fade_len);
     }
 
+    /// Fade out and hold silence while the game is paused
+    ///
+    /// Predicted state doesn't advance, so playback picks up where it
+    /// stopped (or wherever the resync snapshot puts it) on resume.
+    fn generate_paused(&mut self) {
+        let frame_samples = (self.sample_rate as usize / 60) * 2; // stereo
+        fill_fade_out(
+            &mut self.output_buffer,
+            self.prev_frame_last,
+            frame_samples,
+            self.crossfade_samples,
+        );
+        self.prev_frame_last = (0.0, 0.0);
+        self.producer.push_slice(&self.output_buffer);
+    }
+
     /// Generate silence to prevent ring buffer underrun
     fn generate_silence(&mut self) {
         // Generate ~16ms of silence at current sample rate
@@ -468,3 +534,39 @@ impl AudioGenThread {
         }
     }
 }
+
+/// Fill `output` with `len` samples of silence, faded out from `from`
+///
+/// The first `fade_len` stereo pairs ramp linearly from `from` to zero so
+/// pausing mid-waveform doesn't click.
+pub(crate) fn fill_fade_out(output: &mut Vec<f32>, from: (f32, f32), len: usize, fade_len: usize) {
+    output.clear();
+    output.resize(len, 0.0);
+    let fade_len = fade_len.min(len / 2);
+    for i in 0..fade_len {
+        let t = 1.0 - i as f32 / fade_len as f32;
+        output[i * 2] = from.0 * t;
+        output[i * 2 + 1] = from.1 * t;
+    }
+}
+
+/// Merge the main thread's stream state into the predicted one
+///
+/// The audio thread keeps its own playhead during normal playback. The main
+/// thread's playhead is taken only when it jumped (`stream_play`/`stream_seek`
+/// bumped `cue`, or another stream started). Volume and flags always follow
+/// the snapshot.
+///
+/// Returns `true` if the playhead jumped while a stream was audible, so the
+/// caller can crossfade.
+pub(super) fn merge_stream(current: &mut StreamState, snapshot: &StreamState) -> bool {
+    if snapshot.cue != current.cue || snapshot.handle != current.handle {
+        let was_audible = current.is_active();
+        *current = *snapshot;
+        was_audible
+    } else {
+        current.volume = snapshot.volume;
+        current.flags = snapshot.flags;
+        false
+    }
+}
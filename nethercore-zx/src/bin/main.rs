@@ -8,8 +8,16 @@
 //! nethercore-zx path/to/game.nczx
 //! nethercore-zx game.nczx --fullscreen
 //! nethercore-zx game.nczx --debug
+//! nethercore-zx game.nczx --players 2 --best-of 3
+//! nethercore-zx game.nczx --override-pack retro-skin.nczxmod
+//! nethercore-zx game-1.0.nczx --patch game-1.0-to-1.1.ncpatch
+//! nethercore-zx game.nczx --render-replay 0 --render-output run.mp4 --render-resolution 1920x1080
+//! nethercore-zx game.nczx --record-inputs run.ncrp
+//! nethercore-zx game.nczx --play-inputs run.ncrp --headless --ticks 600
 //! nethercore-zx game.nczx --preview
 //! nethercore-zx game.nczx --preview --asset textures/player
+//! nethercore-zx --epu-bench
+//! nethercore-zx --attract --fullscreen
 //! ```
 //!
 //! # Keyboard Shortcuts
@@ -22,11 +30,14 @@
 
 use std::path::PathBuf;
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 use clap::Parser;
 
+use nethercore_core::app::HeadlessPlayConfig;
+use nethercore_core::replay::{ReplayRenderConfig, parse_resolution};
 use nethercore_core::rollback::ConnectionMode;
-use nethercore_zx::player::{PlayerConfig, run};
+use nethercore_zx::epu_bench::{EpuBenchConfig, EpuBenchMode, run as run_epu_bench};
+use nethercore_zx::player::{PlayerConfig, run, run_headless};
 use nethercore_zx::preview::{PreviewConfig, run as run_preview};
 
 #[derive(Parser)]
@@ -38,7 +49,8 @@ use nethercore_zx::preview::{PreviewConfig, run as run_preview};
 )]
 struct Args {
     /// ROM file to play (.nczx or .wasm)
-    rom: PathBuf,
+    #[arg(required_unless_present_any = ["epu_bench", "attract"])]
+    rom: Option<PathBuf>,
 
     /// Start in fullscreen mode (borderless window, scales to fit)
     #[arg(long, short = 'f')]
@@ -99,11 +111,64 @@ struct Args {
     #[arg(long, value_name = "FILE")]
     session: Option<PathBuf>,
 
+    // === Tournament Series ===
+    /// Play a best-of-N series of local rounds (e.g. --best-of 3)
+    #[arg(long, value_name = "N")]
+    best_of: Option<u32>,
+
+    // === Daily Challenge ===
+    /// Use this seed for today's daily challenge instead of deriving it
+    /// from the date (the library passes the seed fetched from the platform)
+    #[arg(long, value_name = "SEED")]
+    daily_seed: Option<u64>,
+
+    // === Override Packs ===
+    /// Sideload an override pack (.nczxmod) replacing ROM assets by ID
+    /// (can be specified multiple times; later packs win)
+    #[arg(long = "override-pack", value_name = "FILE")]
+    override_packs: Vec<PathBuf>,
+
+    // === ROM Patches ===
+    /// Apply a delta patch (.ncpatch) to the ROM before loading
+    /// (can be specified multiple times; applied in order)
+    #[arg(long = "patch", value_name = "FILE")]
+    patches: Vec<PathBuf>,
+
     // === Replay Mode ===
     /// Run a replay script (.ncrs) for automated playback and screenshots
     #[arg(long, value_name = "FILE")]
     replay: Option<PathBuf>,
 
+    /// Re-simulate a recorded replay offline and encode it to a video:
+    /// a slot number (0 = most recent local session) or an .ncrp file
+    #[arg(long, value_name = "SLOT|FILE", requires = "render_output")]
+    render_replay: Option<String>,
+
+    /// Output for --render-replay (.mp4/.webm via ffmpeg, .gif, or a directory for PNG frames)
+    #[arg(long, value_name = "PATH")]
+    render_output: Option<PathBuf>,
+
+    /// Output resolution for --render-replay (e.g. 1920x1080; default: native)
+    #[arg(long, value_name = "WxH")]
+    render_resolution: Option<String>,
+
+    // === Input Recording ===
+    /// Write this session's inputs to an .ncrp file on exit (local play only)
+    #[arg(long, value_name = "FILE")]
+    record_inputs: Option<PathBuf>,
+
+    /// Play inputs from an .ncrp file (requires --headless)
+    #[arg(long, value_name = "FILE", requires = "headless")]
+    play_inputs: Option<PathBuf>,
+
+    /// Run without a window and print the final state checksum to stdout
+    #[arg(long, requires = "play_inputs")]
+    headless: bool,
+
+    /// Ticks to simulate with --play-inputs (default: length of the recording)
+    #[arg(long, value_name = "N", requires = "play_inputs")]
+    ticks: Option<u64>,
+
     // === Preview Mode ===
     /// Run in preview mode to inspect ROM assets
     #[arg(long)]
@@ -112,20 +177,62 @@ struct Args {
     /// Specific asset to focus on in preview mode (e.g., "textures/player")
     #[arg(long)]
     asset: Option<String>,
+
+    // === Built-in Scenes (no ROM) ===
+    /// Run the built-in EPU benchmark: cycles every opcode and stress scene
+    /// with on-screen timings, then logs a report
+    #[arg(long, conflicts_with_all = ["rom", "attract"])]
+    epu_bench: bool,
+
+    /// Number of benchmark passes (0 = loop until closed, for burn-in testing)
+    #[arg(long, default_value = "1", requires = "epu_bench")]
+    passes: u32,
+
+    /// Run the EPU attract scene (idle screensaver); any input exits
+    #[arg(long, conflicts_with = "rom")]
+    attract: bool,
 }
 
 fn main() -> Result<()> {
     let args = Args::parse();
 
+    if args.epu_bench || args.attract {
+        let mode = if args.attract {
+            EpuBenchMode::Attract
+        } else {
+            EpuBenchMode::Benchmark {
+                passes: args.passes,
+            }
+        };
+        return run_epu_bench(EpuBenchConfig {
+            mode,
+            fullscreen: args.fullscreen,
+        });
+    }
+
+    let rom = args.rom.context("ROM file required")?;
+
     // Validate ROM path exists
-    if !args.rom.exists() {
-        anyhow::bail!("ROM file not found: {}", args.rom.display());
+    if !rom.exists() {
+        anyhow::bail!("ROM file not found: {}", rom.display());
+    }
+
+    // Headless input playback: only the checksum goes to stdout so CI can diff it
+    if let Some(inputs) = args.play_inputs {
+        let checksum = run_headless(&HeadlessPlayConfig {
+            rom_path: rom,
+            rom_patches: args.patches,
+            inputs,
+            ticks: args.ticks,
+        })?;
+        println!("{:016x}", checksum);
+        return Ok(());
     }
 
     // Handle preview mode
     if args.preview {
         let config = PreviewConfig {
-            rom_path: args.rom,
+            rom_path: rom,
             asset_path: args.asset,
             scale: args.scale,
         };
@@ -142,6 +249,42 @@ fn main() -> Result<()> {
         anyhow::bail!("Input delay must be between 0 and 10");
     }
 
+    // Validate series length
+    if let Some(best_of) = args.best_of
+        && !(1..=nethercore_core::series::MAX_BEST_OF).contains(&best_of)
+    {
+        anyhow::bail!(
+            "Series length must be between 1 and {}",
+            nethercore_core::series::MAX_BEST_OF
+        );
+    }
+
+    let replay_render = match args.render_replay {
+        Some(source) => {
+            if args.session.is_some()
+                || args.join.is_some()
+                || args.host.is_some()
+                || args.p2p
+                || args.sync_test
+                || args.replay.is_some()
+            {
+                anyhow::bail!("--render-replay can only be used on its own (local playback)");
+            }
+            Some(ReplayRenderConfig {
+                source: source.parse()?,
+                output: args
+                    .render_output
+                    .context("--render-replay requires --render-output")?,
+                resolution: args
+                    .render_resolution
+                    .as_deref()
+                    .map(parse_resolution)
+                    .transpose()?,
+            })
+        }
+        None => None,
+    };
+
     // Determine connection mode from arguments
     // Priority: session > join > host > p2p > sync_test > local
     let connection_mode = if let Some(session_file) = args.session {
@@ -167,7 +310,7 @@ fn main() -> Result<()> {
     };
 
     let config = PlayerConfig {
-        rom_path: args.rom,
+        rom_path: rom,
         fullscreen: args.fullscreen,
         scale: args.scale,
         debug: args.debug,
@@ -175,6 +318,12 @@ fn main() -> Result<()> {
         input_delay: args.input_delay,
         connection_mode,
         replay_script: args.replay,
+        best_of: args.best_of,
+        daily_seed: args.daily_seed,
+        override_packs: args.override_packs,
+        rom_patches: args.patches,
+        replay_render,
+        record_inputs: args.record_inputs,
     };
 
     run(config)
@@ -10,8 +10,9 @@ use wasmtime::Linker;
 use winit::window::Window;
 
 use nethercore_core::{
-    console::{Audio, Console, ConsoleInput, ConsoleSpecs, RawInput, SoundHandle},
+    console::{Audio, Console, ConsoleInput, ConsoleSpecs, MixerBus, RawInput, SoundHandle},
     debug::DebugStat,
+    net::voice::VoiceChannel,
     wasm::WasmGameContext,
 };
 use zx_common::ZXDataPack;
@@ -31,12 +32,46 @@ pub use nethercore_shared::{
     NETHERCORE_ZX_VRAM_LIMIT as VRAM_LIMIT,
 };
 
-/// Maximum value for analog stick conversion (i8 range: -128 to 127)
+/// Steps per unit of stick travel: axes take the values -127..=127
 pub const STICK_SCALE: f32 = 127.0;
 
-/// Maximum value for trigger conversion (u8 range: 0 to 255)
+/// Steps per unit of trigger travel: triggers take the values 0..=255
 pub const TRIGGER_SCALE: f32 = 255.0;
 
+/// Quantize a stick axis (-1.0 to 1.0) to the nearest step of the analog grid
+///
+/// Analog values are quantized once, when the host maps device input, so
+/// drivers that report 0.29999998 and 0.3 send the same packet. Rounding
+/// (rather than truncating) keeps values that have been through the grid on
+/// it, and snaps noise within half a step of center to 0. NaN maps to 0.
+#[inline]
+pub fn quantize_stick(value: f32) -> i8 {
+    (value.clamp(-1.0, 1.0) * STICK_SCALE).round() as i8
+}
+
+/// Quantize a trigger (0.0 to 1.0) to the nearest step of the analog grid
+///
+/// See [`quantize_stick`]. NaN maps to 0.
+#[inline]
+pub fn quantize_trigger(value: f32) -> u8 {
+    (value.clamp(0.0, 1.0) * TRIGGER_SCALE).round() as u8
+}
+
+/// Stick axis value of a grid step
+///
+/// -128 is off the grid (older replays and peers can still send it) and
+/// reads as -1.0, so axes stay symmetric.
+#[inline]
+pub fn stick_value(step: i8) -> f32 {
+    step.max(-127) as f32 / STICK_SCALE
+}
+
+/// Trigger value of a grid step
+#[inline]
+pub fn trigger_value(step: u8) -> f32 {
+    step as f32 / TRIGGER_SCALE
+}
+
 /// Maximum valid button index (0-13, corresponding to Button enum)
 pub const MAX_BUTTON_INDEX: u32 = 13;
 
@@ -82,25 +117,41 @@ impl Button {
 pub struct ZInput {
     /// Button bitmask: D-pad + A/B/X/Y + L/R bumpers + L3/R3 + Start/Select
     /// Bit layout: UP(0), DOWN(1), LEFT(2), RIGHT(3), A(4), B(5), X(6), Y(7),
-    ///             LB(8), RB(9), L3(10), R3(11), START(12), SELECT(13)
+    ///             LB(8), RB(9), L3(10), R3(11), START(12), SELECT(13),
+    ///             mouse over the game (14, see [`ZInput::POINTER`]),
+    ///             controller connected (15, see [`ZInput::CONNECTED`])
     pub buttons: u16,
-    /// Left stick X axis (-128 to 127, mapped to -1.0 to 1.0)
+    /// Left stick X axis (-127 to 127, mapped to -1.0 to 1.0)
     pub left_stick_x: i8,
-    /// Left stick Y axis (-128 to 127, mapped to -1.0 to 1.0)
+    /// Left stick Y axis (-127 to 127, mapped to -1.0 to 1.0)
     pub left_stick_y: i8,
-    /// Right stick X axis (-128 to 127, mapped to -1.0 to 1.0)
+    /// Right stick X axis (-127 to 127, mapped to -1.0 to 1.0)
     pub right_stick_x: i8,
-    /// Right stick Y axis (-128 to 127, mapped to -1.0 to 1.0)
+    /// Right stick Y axis (-127 to 127, mapped to -1.0 to 1.0)
     pub right_stick_y: i8,
     /// Left trigger (0 to 255, mapped to 0.0 to 1.0)
     pub left_trigger: u8,
     /// Right trigger (0 to 255, mapped to 0.0 to 1.0)
     pub right_trigger: u8,
+    /// Mouse X in screen pixels (valid while [`ZInput::POINTER`] is set)
+    pub pointer_x: i16,
+    /// Mouse Y in screen pixels (valid while [`ZInput::POINTER`] is set)
+    pub pointer_y: i16,
 }
 
 // Public API helpers for ZInput - used by tests and available for console-side code.
 // WASM games access input via FFI, not these Rust methods directly.
 impl ZInput {
+    /// `buttons` bit set while a controller drives this player
+    pub const CONNECTED: u16 = 1 << 15;
+
+    /// `buttons` bit set while the mouse is over the game (player 0 only)
+    pub const POINTER: u16 = 1 << 14;
+
+    /// `buttons` bits that are real buttons (excludes [`ZInput::POINTER`] and
+    /// [`ZInput::CONNECTED`])
+    pub const BUTTON_MASK: u16 = (1 << 14) - 1;
+
     /// Check if a button is held
     #[inline]
     pub fn button_held(&self, button: Button) -> bool {
@@ -110,41 +161,54 @@ impl ZInput {
     /// Get left stick X as float (-1.0 to 1.0)
     #[inline]
     pub fn left_stick_x_f32(&self) -> f32 {
-        self.left_stick_x as f32 / STICK_SCALE
+        stick_value(self.left_stick_x)
     }
 
     /// Get left stick Y as float (-1.0 to 1.0)
     #[inline]
     pub fn left_stick_y_f32(&self) -> f32 {
-        self.left_stick_y as f32 / STICK_SCALE
+        stick_value(self.left_stick_y)
     }
 
     /// Get right stick X as float (-1.0 to 1.0)
     #[inline]
     pub fn right_stick_x_f32(&self) -> f32 {
-        self.right_stick_x as f32 / STICK_SCALE
+        stick_value(self.right_stick_x)
     }
 
     /// Get right stick Y as float (-1.0 to 1.0)
     #[inline]
     pub fn right_stick_y_f32(&self) -> f32 {
-        self.right_stick_y as f32 / STICK_SCALE
+        stick_value(self.right_stick_y)
     }
 
     /// Get left trigger as float (0.0 to 1.0)
     #[inline]
     pub fn left_trigger_f32(&self) -> f32 {
-        self.left_trigger as f32 / TRIGGER_SCALE
+        trigger_value(self.left_trigger)
     }
 
     /// Get right trigger as float (0.0 to 1.0)
     #[inline]
     pub fn right_trigger_f32(&self) -> f32 {
-        self.right_trigger as f32 / TRIGGER_SCALE
+        trigger_value(self.right_trigger)
     }
 }
 
-impl ConsoleInput for ZInput {}
+impl ConsoleInput for ZInput {
+    fn connected(&self) -> bool {
+        self.buttons & Self::CONNECTED != 0
+    }
+
+    fn neutral(&self) -> Self {
+        Self {
+            buttons: self.buttons & (Self::CONNECTED | Self::POINTER),
+            pointer_x: self.pointer_x,
+            pointer_y: self.pointer_y,
+            ..Self::default()
+        }
+    }
+}
 
 // ZXGraphics is implemented in graphics.rs.
 
@@ -165,6 +229,10 @@ impl Audio for ZXAudio {
         ZXAudio::set_master_volume(self, volume);
     }
 
+    fn set_bus_volume(&mut self, bus: MixerBus, volume: f32) {
+        ZXAudio::set_bus_volume(self, bus, volume);
+    }
+
     fn sample_rate(&self) -> u32 {
         ZXAudio::sample_rate(self)
     }
@@ -172,6 +240,14 @@ impl Audio for ZXAudio {
     fn push_samples(&mut self, samples: &[f32]) {
         ZXAudio::push_samples(self, samples);
     }
+
+    fn attach_voice(&mut self, voice: VoiceChannel) {
+        ZXAudio::attach_voice(self, voice);
+    }
+
+    fn set_paused(&mut self, paused: bool) {
+        ZXAudio::set_paused(self, paused);
+    }
 }
 
 /// Nethercore ZX fantasy console
@@ -184,7 +260,7 @@ impl Audio for ZXAudio {
 pub struct NethercoreZX {
     /// Optional datapack for ROM assets (textures, meshes, sounds)
     data_pack: Option<Arc<ZXDataPack>>,
-    /// Default render mode for this ROM (0-3).
+    /// Default render mode for this ROM (0-4).
     ///
     /// Stored on the host side and applied to `ZXFFIState.init_config` before `init()` runs.
     render_mode: u8,
@@ -218,7 +294,7 @@ impl NethercoreZX {
     ) -> Self {
         Self {
             data_pack,
-            render_mode: render_mode.min(3),
+            render_mode: render_mode.min(4),
             epu_debug_panel: crate::debug::epu_panel::EpuDebugPanel::new(),
         }
     }
@@ -322,16 +398,28 @@ impl Console for NethercoreZX {
         if raw.select {
             buttons |= Button::Select.mask();
         }
+        if raw.connected {
+            buttons |= ZInput::CONNECTED;
+        }
+
+        // Map mouse position (whole screen pixels)
+        let (pointer_x, pointer_y) = match raw.pointer {
+            Some([x, y]) => {
+                buttons |= ZInput::POINTER;
+                (x as i16, y as i16)
+            }
+            None => (0, 0),
+        };
 
-        // Map analog sticks (f32 -1.0..1.0 to i8 -128..127)
-        let left_stick_x = (raw.left_stick_x.clamp(-1.0, 1.0) * STICK_SCALE) as i8;
-        let left_stick_y = (raw.left_stick_y.clamp(-1.0, 1.0) * STICK_SCALE) as i8;
-        let right_stick_x = (raw.right_stick_x.clamp(-1.0, 1.0) * STICK_SCALE) as i8;
-        let right_stick_y = (raw.right_stick_y.clamp(-1.0, 1.0) * STICK_SCALE) as i8;
+        // Quantize analog sticks (f32 -1.0..1.0 to i8 -127..127)
+        let left_stick_x = quantize_stick(raw.left_stick_x);
+        let left_stick_y = quantize_stick(raw.left_stick_y);
+        let right_stick_x = quantize_stick(raw.right_stick_x);
+        let right_stick_y = quantize_stick(raw.right_stick_y);
 
-        // Map triggers (f32 0.0..1.0 to u8 0..255)
-        let left_trigger = (raw.left_trigger.clamp(0.0, 1.0) * TRIGGER_SCALE) as u8;
-        let right_trigger = (raw.right_trigger.clamp(0.0, 1.0) * TRIGGER_SCALE) as u8;
+        // Quantize triggers (f32 0.0..1.0 to u8 0..255)
+        let left_trigger = quantize_trigger(raw.left_trigger);
+        let right_trigger = quantize_trigger(raw.right_trigger);
 
         ZInput {
             buttons,
@@ -341,6 +429,8 @@ impl Console for NethercoreZX {
             right_stick_y,
             left_trigger,
             right_trigger,
+            pointer_x,
+            pointer_y,
         }
     }
 
@@ -348,6 +438,10 @@ impl Console for NethercoreZX {
         crate::replay::ZxInputLayout::bytes_to_zinput(bytes)
     }
 
+    fn encode_replay_bytes(&self, input: &Self::Input) -> Vec<u8> {
+        crate::replay::ZxInputLayout::zinput_to_bytes(input).to_vec()
+    }
+
     fn replay_input_layout(&self) -> Option<Box<dyn nethercore_core::replay::script::InputLayout>> {
         Some(Box::new(crate::replay::ZxInputLayout))
     }
@@ -358,7 +452,10 @@ impl Console for NethercoreZX {
 
     fn debug_stats(&self, state: &ZXFFIState) -> Vec<DebugStat> {
         vec![
-            DebugStat::number("Draw Calls", state.render_pass.commands().len()),
+            DebugStat::number("Draw Calls", state.render_stats.draw_calls),
+            DebugStat::number("Triangles", state.render_stats.triangles),
+            DebugStat::number("Triangles Culled", state.render_stats.triangles_culled),
+            DebugStat::number("Texture Binds", state.render_stats.texture_binds),
             DebugStat::number("Textures", state.next_texture_handle.saturating_sub(1)),
             DebugStat::number("Meshes", state.next_mesh_handle.saturating_sub(1)),
             DebugStat::number("Skeletons", state.next_skeleton_handle.saturating_sub(1)),
@@ -381,10 +478,25 @@ impl Console for NethercoreZX {
         crate::ffi::unpack_rgba(state.init_config.clear_color)
     }
 
+    fn tick_rate_from_state(state: &Self::State) -> Option<u32> {
+        let config = &state.init_config;
+        config
+            .tick_rate_set
+            .then(|| TICK_RATES[config.tick_rate_index as usize])
+    }
+
     fn clear_frame_state(state: &mut Self::State) {
         state.clear_frame();
     }
 
+    fn draw_loading_screen(state: &mut Self::State) {
+        crate::ffi::draw_loading_screen(state);
+    }
+
+    fn draw_overlays(state: &mut Self::State, rollback: &Self::RollbackState) {
+        crate::ffi::draw_cursors(state, &rollback.cursor);
+    }
+
     fn render_debug_ui(&mut self, ctx: &egui::Context, visible: bool) {
         if visible {
             self.epu_debug_panel.set_visible(true);
@@ -450,9 +562,9 @@ mod tests {
             ..Default::default()
         };
 
-        // Stick values
-        assert!((input.left_stick_x_f32() - 1.0).abs() < 0.01);
-        assert!((input.left_stick_y_f32() - (-1.008)).abs() < 0.01);
+        // Stick values (-128 is off the grid and reads as -1.0)
+        assert_eq!(input.left_stick_x_f32(), 1.0);
+        assert_eq!(input.left_stick_y_f32(), -1.0);
 
         // Trigger values
         assert!((input.left_trigger_f32() - 1.0).abs() < 0.01);
@@ -474,8 +586,64 @@ mod tests {
         assert!(mapped.button_held(Button::Up));
         assert!(mapped.button_held(Button::A));
         assert!(!mapped.button_held(Button::Down));
-        assert_eq!(mapped.left_stick_x, 63); // 0.5 * 127 ≈ 63
-        assert_eq!(mapped.left_trigger, 191); // 0.75 * 255 ≈ 191
+        assert_eq!(mapped.left_stick_x, 64); // 0.5 * 127 = 63.5, rounded
+        assert_eq!(mapped.left_trigger, 191); // 0.75 * 255 = 191.25, rounded
+        assert!(!mapped.connected());
+
+        let connected = console.map_input(&RawInput {
+            connected: true,
+            ..Default::default()
+        });
+        assert!(connected.connected());
+        assert_eq!(connected.buttons & ZInput::BUTTON_MASK, 0);
+    }
+
+    #[test]
+    fn test_analog_quantization() {
+        // Nearby driver values land on the same step
+        assert_eq!(quantize_stick(0.29999998), quantize_stick(0.3));
+        assert_eq!(quantize_stick(-0.003), 0);
+        assert_eq!(quantize_stick(0.003), 0);
+
+        // The grid is symmetric and saturates at ±127
+        assert_eq!(quantize_stick(-1.0), -127);
+        assert_eq!(quantize_stick(-3.0), -127);
+        assert_eq!(quantize_stick(1.0), 127);
+        assert_eq!(quantize_trigger(1.5), 255);
+        assert_eq!(quantize_trigger(-0.2), 0);
+        assert_eq!(quantize_stick(f32::NAN), 0);
+        assert_eq!(quantize_trigger(f32::NAN), 0);
+
+        // Values already on the grid stay on it
+        for step in -127..=127i8 {
+            assert_eq!(quantize_stick(stick_value(step)), step);
+        }
+        for step in 0..=255u8 {
+            assert_eq!(quantize_trigger(trigger_value(step)), step);
+        }
+    }
+
+    #[test]
+    fn test_neutral_input() {
+        let input = ZInput {
+            buttons: Button::A.mask() | ZInput::CONNECTED | ZInput::POINTER,
+            left_stick_x: 90,
+            right_trigger: 200,
+            pointer_x: 120,
+            pointer_y: 40,
+            ..Default::default()
+        };
+
+        // Buttons and analog controls reset; connection and mouse stay
+        assert_eq!(
+            input.neutral(),
+            ZInput {
+                buttons: ZInput::CONNECTED | ZInput::POINTER,
+                pointer_x: 120,
+                pointer_y: 40,
+                ..Default::default()
+            }
+        );
     }
 
     #[test]
@@ -491,4 +659,16 @@ mod tests {
         assert_eq!(specs.rom_limit, 16 * 1024 * 1024);
         assert_eq!(specs.cpu_budget_us, 4000);
     }
+
+    #[test]
+    fn test_tick_rate_from_state() {
+        let mut state = ZXFFIState::default();
+        assert_eq!(NethercoreZX::tick_rate_from_state(&state), None);
+
+        state.init_config.tick_rate_set = true;
+        state.init_config.tick_rate_index = 3;
+        assert_eq!(NethercoreZX::tick_rate_from_state(&state), Some(120));
+        state.init_config.tick_rate_index = 1;
+        assert_eq!(NethercoreZX::tick_rate_from_state(&state), Some(30));
+    }
 }
@@ -7,10 +7,10 @@
 #![allow(dead_code)]
 
 /// Total number of defined opcodes
-pub const OPCODE_COUNT: usize = 19;
+pub const OPCODE_COUNT: usize = 20;
 
 /// Highest opcode number (for array sizing)
-pub const MAX_OPCODE: usize = 19;
+pub const MAX_OPCODE: usize = 20;
 
 /// Kind of EPU opcode
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -86,7 +86,7 @@ pub static OPCODES: [Option<OpcodeInfo>; 32] = [
     Some(OpcodeInfo { code: 0x11, name: "PORTAL", kind: OpcodeKind::Radiance }),
     Some(OpcodeInfo { code: 0x12, name: "LOBE", kind: OpcodeKind::Radiance }),
     Some(OpcodeInfo { code: 0x13, name: "BAND", kind: OpcodeKind::Radiance }),
-    None,
+    Some(OpcodeInfo { code: 0x14, name: "FLASH", kind: OpcodeKind::Radiance }),
     None,
     None,
     None,
@@ -111,7 +111,7 @@ pub static VARIANTS: [&[&str]; 32] = [
     &["BLOBS", "ISLANDS", "DEBRIS", "MEMBRANE", "STATIC", "STREAKS"],
     &["CIRCLE", "RECT", "ROUNDED_RECT", "ARCH", "BARS", "MULTI", "IRREGULAR"],
     &[],
-    &[],
+    &["FLOOR", "TUBE", "WALLS", "SCANLINES"],
     &["STARS", "DUST", "WINDOWS", "BUBBLES", "EMBERS", "RAIN", "SNOW"],
     &[],
     &["LIGHTNING", "CRACKS", "LEAD_LINES", "FILAMENTS"],
@@ -122,7 +122,7 @@ pub static VARIANTS: [&[&str]; 32] = [
     &["CIRCLE", "RECT", "TEAR", "VORTEX", "CRACK", "RIFT"],
     &[],
     &[],
-    &[],
+    &["FORK", "SHEET"],
     &[],
     &[],
     &[],
@@ -154,7 +154,7 @@ pub static DOMAINS: [&[&str]; 32] = [
     &["DIRECT3D", "AXIS_CYL", "AXIS_POLAR", "TANGENT_LOCAL"],
     &[],
     &[],
-    &[],
+    &["BODY", "RINGS", "OCCLUDED", "RINGS_OCCLUDED"],
     &[],
     &[],
     &[],
@@ -230,10 +230,10 @@ static FIELDS_08: [FieldSpec; 5] = [
 ];
 static FIELDS_09: [FieldSpec; 5] = [
     FieldSpec { name: "intensity", label: "brightness", unit: None, map: MapKind::U8_01, min: 0.000000, max: 1.000000 },
-    FieldSpec { name: "param_a", label: "scale", unit: None, map: MapKind::U8Lerp, min: 1.000000, max: 64.000000 },
-    FieldSpec { name: "param_b", label: "thickness", unit: None, map: MapKind::U8Lerp, min: 0.001000, max: 0.100000 },
-    FieldSpec { name: "param_c", label: "pat+scroll", unit: None, map: MapKind::U8_01, min: 0.000000, max: 1.000000 },
-    FieldSpec { name: "param_d", label: "phase", unit: None, map: MapKind::U8_01, min: 0.000000, max: 1.000000 },
+    FieldSpec { name: "param_a", label: "spacing", unit: None, map: MapKind::U8Lerp, min: 0.050000, max: 4.000000 },
+    FieldSpec { name: "param_b", label: "width", unit: None, map: MapKind::U8Lerp, min: 0.010000, max: 0.500000 },
+    FieldSpec { name: "param_c", label: "accent+curve", unit: None, map: MapKind::U8_01, min: 0.000000, max: 1.000000 },
+    FieldSpec { name: "param_d", label: "scroll", unit: None, map: MapKind::U8_01, min: 0.000000, max: 1.000000 },
 ];
 static FIELDS_0A: [FieldSpec; 5] = [
     FieldSpec { name: "intensity", label: "brightness", unit: None, map: MapKind::U8_01, min: 0.000000, max: 1.000000 },
@@ -305,6 +305,13 @@ static FIELDS_13: [FieldSpec; 5] = [
     FieldSpec { name: "param_c", label: "softness", unit: None, map: MapKind::U8_01, min: 0.000000, max: 1.000000 },
     FieldSpec { name: "param_d", label: "phase", unit: None, map: MapKind::U8_01, min: 0.000000, max: 1.000000 },
 ];
+static FIELDS_14: [FieldSpec; 5] = [
+    FieldSpec { name: "intensity", label: "brightness", unit: None, map: MapKind::U8_01, min: 0.000000, max: 1.000000 },
+    FieldSpec { name: "param_a", label: "period", unit: Some("steps"), map: MapKind::U8Lerp, min: 0.000000, max: 255.000000 },
+    FieldSpec { name: "param_b", label: "seed", unit: None, map: MapKind::U8_01, min: 0.000000, max: 1.000000 },
+    FieldSpec { name: "param_c", label: "branches+width", unit: None, map: MapKind::U8_01, min: 0.000000, max: 1.000000 },
+    FieldSpec { name: "param_d", label: "phase", unit: None, map: MapKind::U8_01, min: 0.000000, max: 1.000000 },
+];
 
 /// Field specifications per opcode
 pub static FIELD_SPECS: [&[FieldSpec]; 32] = [
@@ -328,7 +335,7 @@ pub static FIELD_SPECS: [&[FieldSpec]; 32] = [
     &FIELDS_11,
     &FIELDS_12,
     &FIELDS_13,
-    &[],
+    &FIELDS_14,
     &[],
     &[],
     &[],
@@ -104,7 +104,7 @@ impl LayerEditState {
     /// Convert edit state back to an EPU layer.
     pub fn to_layer(&self) -> EpuLayer {
         EpuLayer {
-            opcode: epu_opcode_from_u8(self.opcode),
+            opcode: EpuOpcode::from_u8(self.opcode),
             region_mask: self.region_mask,
             blend: self.blend,
             meta5: pack_meta5(self.domain_id, self.variant_id),
@@ -122,33 +122,6 @@ impl LayerEditState {
     }
 }
 
-/// Convert u8 to EpuOpcode (with bounds check).
-fn epu_opcode_from_u8(code: u8) -> EpuOpcode {
-    match code {
-        0x00 => EpuOpcode::Nop,
-        0x01 => EpuOpcode::Ramp,
-        0x02 => EpuOpcode::Sector,
-        0x03 => EpuOpcode::Silhouette,
-        0x04 => EpuOpcode::Split,
-        0x05 => EpuOpcode::Cell,
-        0x06 => EpuOpcode::Patches,
-        0x07 => EpuOpcode::Aperture,
-        0x08 => EpuOpcode::Decal,
-        0x09 => EpuOpcode::Grid,
-        0x0A => EpuOpcode::Scatter,
-        0x0B => EpuOpcode::Flow,
-        0x0C => EpuOpcode::Trace,
-        0x0D => EpuOpcode::Veil,
-        0x0E => EpuOpcode::Atmosphere,
-        0x0F => EpuOpcode::Plane,
-        0x10 => EpuOpcode::Celestial,
-        0x11 => EpuOpcode::Portal,
-        0x12 => EpuOpcode::LobeRadiance,
-        0x13 => EpuOpcode::BandRadiance,
-        _ => EpuOpcode::Nop,
-    }
-}
-
 /// EPU semantic editor state.
 ///
 /// Provides metadata-driven UI for editing EPU configurations with semantic
@@ -918,7 +891,7 @@ fn decode_packed_layer(packed: [u64; 2]) -> EpuLayer {
     };
 
     EpuLayer {
-        opcode: epu_opcode_from_u8(opcode_raw),
+        opcode: EpuOpcode::from_u8(opcode_raw),
         region_mask,
         blend,
         meta5,
@@ -357,6 +357,13 @@ fn generate_layer_summary(
             // BAND
             format!("Adds {} horizontal band to {}", color_desc, region_str)
         }
+        0x14 => {
+            // FLASH
+            format!(
+                "Strikes {} lightning{} on {}",
+                color_desc, variant_str, region_str
+            )
+        }
         _ => {
             // Unknown/generic
             format!(
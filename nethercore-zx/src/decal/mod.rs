@@ -0,0 +1,245 @@
+//! Decal projection
+//!
+//! A decal is a square texture projected along a surface normal onto nearby
+//! geometry. The projection volume is a cube of the decal's size centered on
+//! the spawn point; triangles of static meshes drawn inside it are clipped to
+//! the cube and given UVs across its face, so a scorch mark wraps over a curb
+//! or a stair edge instead of floating as a flat quad.
+//!
+//! Projection runs once, the first time a decal is drawn near a receiver, and
+//! the clipped world-space triangles are kept. Decals therefore stay where the
+//! geometry was at that moment; they don't follow moving meshes.
+
+#[cfg(test)]
+mod tests;
+
+use glam::{Mat4, Quat, Vec3};
+
+/// Maximum triangles a single decal is clipped into
+pub const MAX_DECAL_TRIANGLES: usize = 128;
+
+/// Distance decal geometry is lifted off the surface to avoid z-fighting
+pub const DECAL_SURFACE_OFFSET: f32 = 0.01;
+
+/// Triangles whose normal is within ~84° of the decal normal receive it
+const MIN_FACING: f32 = 0.1;
+
+/// CPU copy of a static mesh's triangles, kept for decal projection
+#[derive(Clone, Debug)]
+pub struct DecalSurface {
+    pub positions: Vec<Vec3>,
+    pub triangles: Vec<[u32; 3]>,
+    /// Object-space bounding box
+    pub bounds_min: Vec3,
+    pub bounds_max: Vec3,
+}
+
+impl DecalSurface {
+    /// Build a surface from positions and a triangle list
+    ///
+    /// Out-of-range indices and a trailing partial triangle are dropped.
+    /// Returns None if no triangles remain.
+    pub fn new(positions: Vec<Vec3>, indices: &[u32]) -> Option<Self> {
+        let triangles: Vec<[u32; 3]> = indices
+            .chunks_exact(3)
+            .map(|t| [t[0], t[1], t[2]])
+            .filter(|t| t.iter().all(|&i| (i as usize) < positions.len()))
+            .collect();
+        if triangles.is_empty() {
+            return None;
+        }
+        let (bounds_min, bounds_max) = positions.iter().fold(
+            (Vec3::splat(f32::INFINITY), Vec3::splat(f32::NEG_INFINITY)),
+            |(min, max), &p| (min.min(p), max.max(p)),
+        );
+        Some(Self {
+            positions,
+            triangles,
+            bounds_min,
+            bounds_max,
+        })
+    }
+}
+
+/// One projected decal vertex (world space)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DecalVertex {
+    pub position: Vec3,
+    pub uv: [f32; 2],
+    /// Normal of the surface the vertex lies on
+    pub normal: Vec3,
+}
+
+/// Projection volume of a decal
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DecalBox {
+    pub center: Vec3,
+    /// Unit normal the decal is projected along (faces away from the surface)
+    pub normal: Vec3,
+    /// Unit direction of the texture's +U axis
+    pub tangent: Vec3,
+    /// Unit direction of the texture's top edge
+    pub bitangent: Vec3,
+    pub half_size: f32,
+}
+
+impl DecalBox {
+    /// Create a projection volume
+    ///
+    /// With `rotation` 0 the texture's top points along -Z on floors and
+    /// along +Y on walls; positive rotations turn it counter-clockwise as
+    /// seen from the normal side. Returns None for a zero or non-finite
+    /// normal or a size that isn't positive.
+    pub fn new(center: Vec3, normal: Vec3, size: f32, rotation: f32) -> Option<Self> {
+        let normal = normal.try_normalize()?;
+        if !center.is_finite() || !size.is_finite() || size <= 0.0 || !rotation.is_finite() {
+            return None;
+        }
+        let reference = if normal.y.abs() > 0.99 {
+            Vec3::NEG_Z
+        } else {
+            Vec3::Y
+        };
+        let tangent = Quat::from_axis_angle(normal, rotation) * reference.cross(normal).normalize();
+        Some(Self {
+            center,
+            normal,
+            tangent,
+            bitangent: normal.cross(tangent),
+            half_size: size * 0.5,
+        })
+    }
+
+    /// World-space bounding box of the volume
+    pub fn world_bounds(&self) -> (Vec3, Vec3) {
+        let extent =
+            (self.tangent.abs() + self.bitangent.abs() + self.normal.abs()) * self.half_size;
+        (self.center - extent, self.center + extent)
+    }
+
+    /// Position in volume space (each axis -1.0 to 1.0 inside the volume)
+    fn local(&self, p: Vec3) -> Vec3 {
+        let d = (p - self.center) / self.half_size;
+        Vec3::new(
+            d.dot(self.tangent),
+            d.dot(self.bitangent),
+            d.dot(self.normal),
+        )
+    }
+
+    /// Decal vertex for a volume-space position, lifted off the surface
+    fn vertex(&self, local: Vec3, normal: Vec3) -> DecalVertex {
+        let position = self.center
+            + (self.tangent * local.x + self.bitangent * local.y + self.normal * local.z)
+                * self.half_size
+            + self.normal * DECAL_SURFACE_OFFSET;
+        DecalVertex {
+            position,
+            uv: [local.x * 0.5 + 0.5, 0.5 - local.y * 0.5],
+            normal,
+        }
+    }
+
+    /// Flat quad through the center, facing along the normal (2 triangles)
+    ///
+    /// Used when no receiver geometry is near the decal.
+    pub fn quad(&self) -> [DecalVertex; 6] {
+        let corner = |x: f32, y: f32| self.vertex(Vec3::new(x, y, 0.0), self.normal);
+        let (a, b, c, d) = (
+            corner(-1.0, -1.0),
+            corner(1.0, -1.0),
+            corner(1.0, 1.0),
+            corner(-1.0, 1.0),
+        );
+        [a, b, c, a, c, d]
+    }
+
+    /// Clip a mesh drawn with `model` against the volume, appending triangles
+    ///
+    /// Only triangles facing the decal normal receive it. Stops once `out`
+    /// holds [`MAX_DECAL_TRIANGLES`] triangles. Returns the number of
+    /// triangles appended.
+    pub fn project(
+        &self,
+        surface: &DecalSurface,
+        model: &Mat4,
+        out: &mut Vec<DecalVertex>,
+    ) -> usize {
+        let start = out.len();
+        let (min, max) = self.world_bounds();
+        let (mesh_min, mesh_max) = transform_bounds(model, surface.bounds_min, surface.bounds_max);
+        if mesh_min.cmpgt(max).any() || mesh_max.cmplt(min).any() {
+            return 0;
+        }
+
+        // Mirrored transforms flip triangle winding
+        let mirrored = model.determinant() < 0.0;
+        let mut polygon = Vec::with_capacity(9);
+        let mut scratch = Vec::with_capacity(9);
+        for triangle in &surface.triangles {
+            if out.len() >= MAX_DECAL_TRIANGLES * 3 {
+                break;
+            }
+            let mut world = triangle.map(|i| model.transform_point3(surface.positions[i as usize]));
+            if mirrored {
+                world.swap(1, 2);
+            }
+            let Some(face_normal) = (world[1] - world[0])
+                .cross(world[2] - world[0])
+                .try_normalize()
+            else {
+                continue;
+            };
+            if face_normal.dot(self.normal) < MIN_FACING {
+                continue;
+            }
+
+            polygon.clear();
+            polygon.extend(world.map(|p| self.local(p)));
+            for axis in 0..3 {
+                for sign in [1.0, -1.0] {
+                    clip_polygon(&polygon, axis, sign, &mut scratch);
+                    std::mem::swap(&mut polygon, &mut scratch);
+                }
+            }
+            // Fan-triangulate the clipped (convex) polygon
+            for i in 1..polygon.len().saturating_sub(1) {
+                if out.len() >= MAX_DECAL_TRIANGLES * 3 {
+                    break;
+                }
+                out.extend(
+                    [polygon[0], polygon[i], polygon[i + 1]].map(|p| self.vertex(p, face_normal)),
+                );
+            }
+        }
+        (out.len() - start) / 3
+    }
+}
+
+/// Keep the part of a convex polygon where `sign * p[axis] <= 1`
+fn clip_polygon(input: &[Vec3], axis: usize, sign: f32, out: &mut Vec<Vec3>) {
+    out.clear();
+    let distance = |p: Vec3| 1.0 - sign * p[axis];
+    for (i, &current) in input.iter().enumerate() {
+        let previous = input[(i + input.len() - 1) % input.len()];
+        let (d_current, d_previous) = (distance(current), distance(previous));
+        // Strict signs so vertices on the plane aren't emitted twice
+        if (d_current > 0.0 && d_previous < 0.0) || (d_current < 0.0 && d_previous > 0.0) {
+            let t = d_previous / (d_previous - d_current);
+            out.push(previous.lerp(current, t));
+        }
+        if d_current >= 0.0 {
+            out.push(current);
+        }
+    }
+}
+
+/// World-space bounding box of an object-space box under `model`
+fn transform_bounds(model: &Mat4, min: Vec3, max: Vec3) -> (Vec3, Vec3) {
+    let center = model.transform_point3((min + max) * 0.5);
+    let half = (max - min) * 0.5;
+    let extent = model.x_axis.truncate().abs() * half.x
+        + model.y_axis.truncate().abs() * half.y
+        + model.z_axis.truncate().abs() * half.z;
+    (center - extent, center + extent)
+}
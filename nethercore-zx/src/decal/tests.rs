@@ -0,0 +1,149 @@
+//! Tests for decal projection
+
+use super::*;
+
+/// A flat floor at y = 0 spanning -10..10 on X and Z (two triangles)
+fn floor() -> DecalSurface {
+    let positions = vec![
+        Vec3::new(-10.0, 0.0, -10.0),
+        Vec3::new(-10.0, 0.0, 10.0),
+        Vec3::new(10.0, 0.0, 10.0),
+        Vec3::new(10.0, 0.0, -10.0),
+    ];
+    DecalSurface::new(positions, &[0, 1, 2, 0, 2, 3]).unwrap()
+}
+
+fn area(vertices: &[DecalVertex]) -> f32 {
+    vertices
+        .chunks_exact(3)
+        .map(|t| {
+            (t[1].position - t[0].position)
+                .cross(t[2].position - t[0].position)
+                .length()
+                * 0.5
+        })
+        .sum()
+}
+
+#[test]
+fn test_floor_decal_is_clipped_to_its_square() {
+    let decal = DecalBox::new(Vec3::new(1.0, 0.0, 2.0), Vec3::Y, 2.0, 0.0).unwrap();
+    let mut out = Vec::new();
+    assert!(decal.project(&floor(), &Mat4::IDENTITY, &mut out) > 0);
+
+    assert!((area(&out) - 4.0).abs() < 1e-4);
+    for v in &out {
+        assert!((v.position.y - DECAL_SURFACE_OFFSET).abs() < 1e-5);
+        assert!((-1e-5..=2.0 + 1e-5).contains(&v.position.x));
+        assert!((1.0 - 1e-5..=3.0 + 1e-5).contains(&v.position.z));
+        assert!(v.uv.iter().all(|c| (-1e-5..=1.0 + 1e-5).contains(c)));
+        // Top of the texture (v = 0) points along -Z on floors
+        assert!((v.uv[1] - (v.position.z - 1.0) * 0.5).abs() < 1e-4);
+    }
+    // Triangles stay front-facing (up)
+    for t in out.chunks_exact(3) {
+        let normal = (t[1].position - t[0].position).cross(t[2].position - t[0].position);
+        assert!(normal.y > 0.0);
+    }
+}
+
+#[test]
+fn test_rotation_turns_texture_about_normal() {
+    let decal = DecalBox::new(Vec3::ZERO, Vec3::Y, 2.0, std::f32::consts::FRAC_PI_2).unwrap();
+    // A quarter turn counter-clockwise seen from above: +U now points along -Z
+    assert!(decal.tangent.distance(Vec3::NEG_Z) < 1e-5);
+    assert!(decal.bitangent.distance(Vec3::NEG_X) < 1e-5);
+}
+
+#[test]
+fn test_decal_wraps_over_step() {
+    // Floor at y = 0 for x < 0, a wall facing -X at x = 0, then floor at y = 1
+    let positions = vec![
+        Vec3::new(-5.0, 0.0, -5.0),
+        Vec3::new(-5.0, 0.0, 5.0),
+        Vec3::new(0.0, 0.0, 5.0),
+        Vec3::new(0.0, 0.0, -5.0),
+        Vec3::new(0.0, 1.0, 5.0),
+        Vec3::new(0.0, 1.0, -5.0),
+        Vec3::new(5.0, 1.0, 5.0),
+        Vec3::new(5.0, 1.0, -5.0),
+    ];
+    let indices = [0, 1, 2, 0, 2, 3, 3, 2, 4, 3, 4, 5, 5, 4, 6, 5, 6, 7];
+    let step = DecalSurface::new(positions, &indices).unwrap();
+
+    // Diagonal normal so the floor, riser and upper floor all face the decal
+    let decal = DecalBox::new(
+        Vec3::new(0.0, 0.5, 0.0),
+        Vec3::new(-1.0, 1.0, 0.0),
+        3.0,
+        0.0,
+    )
+    .unwrap();
+    let mut out = Vec::new();
+    decal.project(&step, &Mat4::IDENTITY, &mut out);
+
+    let on_lower = out
+        .iter()
+        .any(|v| v.position.x < -0.1 && v.position.y < 0.1);
+    let on_upper = out.iter().any(|v| v.position.x > 0.1 && v.position.y > 0.9);
+    assert!(on_lower && on_upper);
+}
+
+#[test]
+fn test_back_facing_and_distant_geometry_is_skipped() {
+    let mut out = Vec::new();
+
+    // Projecting down onto the floor's underside
+    let below = DecalBox::new(Vec3::ZERO, Vec3::NEG_Y, 2.0, 0.0).unwrap();
+    assert_eq!(below.project(&floor(), &Mat4::IDENTITY, &mut out), 0);
+
+    // Floor moved far away
+    let decal = DecalBox::new(Vec3::ZERO, Vec3::Y, 2.0, 0.0).unwrap();
+    let far = Mat4::from_translation(Vec3::new(0.0, 50.0, 0.0));
+    assert_eq!(decal.project(&floor(), &far, &mut out), 0);
+    assert!(out.is_empty());
+}
+
+#[test]
+fn test_mirrored_transform_keeps_winding() {
+    let decal = DecalBox::new(Vec3::ZERO, Vec3::Y, 2.0, 0.0).unwrap();
+    let mirror = Mat4::from_scale(Vec3::new(-1.0, 1.0, 1.0));
+    let mut out = Vec::new();
+    assert!(decal.project(&floor(), &mirror, &mut out) > 0);
+    for t in out.chunks_exact(3) {
+        let normal = (t[1].position - t[0].position).cross(t[2].position - t[0].position);
+        assert!(normal.y > 0.0);
+    }
+}
+
+#[test]
+fn test_triangle_cap() {
+    // A dense 32 x 32 grid of quads (2048 triangles) under one decal
+    let n = 33;
+    let positions: Vec<Vec3> = (0..n * n)
+        .map(|i| Vec3::new((i % n) as f32 * 0.1 - 1.6, 0.0, (i / n) as f32 * 0.1 - 1.6))
+        .collect();
+    let mut indices = Vec::new();
+    for z in 0..n - 1 {
+        for x in 0..n - 1 {
+            let i = z * n + x;
+            indices.extend([i, i + n, i + n + 1, i, i + n + 1, i + 1]);
+        }
+    }
+    let grid = DecalSurface::new(positions, &indices).unwrap();
+
+    let decal = DecalBox::new(Vec3::ZERO, Vec3::Y, 3.0, 0.0).unwrap();
+    let mut out = Vec::new();
+    assert_eq!(
+        decal.project(&grid, &Mat4::IDENTITY, &mut out),
+        MAX_DECAL_TRIANGLES
+    );
+}
+
+#[test]
+fn test_invalid_decals() {
+    assert!(DecalBox::new(Vec3::ZERO, Vec3::ZERO, 1.0, 0.0).is_none());
+    assert!(DecalBox::new(Vec3::ZERO, Vec3::Y, 0.0, 0.0).is_none());
+    assert!(DecalBox::new(Vec3::ZERO, Vec3::Y, f32::NAN, 0.0).is_none());
+    assert!(DecalSurface::new(vec![Vec3::ZERO], &[0, 1, 2]).is_none());
+}
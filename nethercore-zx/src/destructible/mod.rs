@@ -0,0 +1,220 @@
+//! Destructible props
+//!
+//! A destructible is drawn as its intact mesh until its health runs out, then
+//! as a set of pre-fractured pieces that burst outward, fall under gravity and
+//! settle on the prop's floor (the lowest point of the pieces mesh).
+//!
+//! The pieces are the connected parts of a single "pieces" mesh, split once
+//! during `init()`. Health and the moment of breaking are rolled back (see
+//! `DestructibleState`); debris motion is a closed-form function of the time
+//! since the break, so re-simulated ticks and replays draw identical debris
+//! without any per-frame simulation state.
+
+#[cfg(test)]
+mod tests;
+
+use glam::{Mat4, Quat, Vec3};
+use hashbrown::HashMap;
+
+/// Maximum number of pieces a destructible can break into
+pub const MAX_DEBRIS_PIECES: usize = 64;
+
+/// Gravity applied to debris (world units / s²)
+pub const DEBRIS_GRAVITY: f32 = 9.81;
+
+/// Outward speed of the break, before jitter and impulse (world units / s)
+pub const DEBRIS_BURST_SPEED: f32 = 2.0;
+
+/// Seconds after breaking that debris stays visible
+pub const DEBRIS_LIFETIME: f32 = 5.0;
+
+/// Grid used to weld coincident vertices when finding pieces (1/1000 world unit)
+const WELD_SCALE: f32 = 1000.0;
+
+/// A registered destructible prop (host-side, never changes after `init()`)
+#[derive(Clone, Debug)]
+pub struct Destructible {
+    /// Mesh drawn while intact
+    pub mesh: u32,
+    /// Health restored by `destructible_reset()`
+    pub max_health: f32,
+    /// Debris pieces, each with its own retained mesh
+    pub pieces: Vec<DebrisPiece>,
+    /// Height debris settles on (local space)
+    pub floor_y: f32,
+}
+
+/// One pre-fractured piece and its (deterministic) flight parameters
+#[derive(Clone, Debug)]
+pub struct DebrisPiece {
+    /// Retained mesh holding only this piece (same local space as the prop)
+    pub mesh: u32,
+    /// Center of the piece's vertices
+    pub centroid: Vec3,
+    /// Height of the centroid above the piece's lowest point
+    pub rest_height: f32,
+    /// Outward velocity of the break (before impulse)
+    pub burst: Vec3,
+    /// Tumble axis (unit length)
+    pub spin_axis: Vec3,
+    /// Tumble speed (radians / s)
+    pub spin_rate: f32,
+}
+
+impl DebrisPiece {
+    /// Compute a piece's flight parameters
+    ///
+    /// `positions` are the piece's vertex positions, `center` the center of
+    /// the whole prop. `seed` makes the jitter reproducible.
+    pub fn new(mesh: u32, positions: &[Vec3], center: Vec3, seed: u64) -> Self {
+        let count = positions.len().max(1) as f32;
+        let centroid = positions.iter().copied().sum::<Vec3>() / count;
+        let min_y = positions
+            .iter()
+            .map(|p| p.y)
+            .fold(f32::INFINITY, f32::min)
+            .min(centroid.y);
+
+        let mut rng = seed;
+        let jitter = Vec3::new(
+            next_signed(&mut rng),
+            next_signed(&mut rng),
+            next_signed(&mut rng),
+        );
+
+        // Fly away from the center and slightly upward
+        let outward = (centroid - center + Vec3::Y * 0.5 + jitter * 0.25).normalize_or(Vec3::Y);
+        let speed = DEBRIS_BURST_SPEED * (0.75 + 0.25 * next_signed(&mut rng));
+
+        let spin_axis = jitter.normalize_or(Vec3::X);
+        let spin_rate = 2.0 + 3.0 * (next_signed(&mut rng) + 1.0);
+
+        Self {
+            mesh,
+            centroid,
+            rest_height: centroid.y - min_y,
+            burst: outward * speed,
+            spin_axis,
+            spin_rate,
+        }
+    }
+
+    /// Local transform of the piece `time` seconds after the break
+    ///
+    /// The piece follows a ballistic arc from its original position and
+    /// freezes where its lowest point reaches `floor_y`.
+    pub fn transform(&self, time: f32, impulse: Vec3, floor_y: f32) -> Mat4 {
+        let velocity = self.burst + impulse;
+        let t = time.clamp(0.0, self.landing_time(velocity, floor_y));
+
+        let position = self.centroid + velocity * t + Vec3::NEG_Y * (0.5 * DEBRIS_GRAVITY * t * t);
+        let rotation = Quat::from_axis_angle(self.spin_axis, self.spin_rate * t);
+
+        Mat4::from_translation(position)
+            * Mat4::from_quat(rotation)
+            * Mat4::from_translation(-self.centroid)
+    }
+
+    /// Seconds until the piece lands
+    fn landing_time(&self, velocity: Vec3, floor_y: f32) -> f32 {
+        // Solve centroid.y + v.y*t - g/2*t² = floor_y + rest_height
+        let drop = (self.centroid.y - self.rest_height - floor_y).max(0.0);
+        let vy = velocity.y;
+        (vy + (vy * vy + 2.0 * DEBRIS_GRAVITY * drop).sqrt()) / DEBRIS_GRAVITY
+    }
+}
+
+/// Split a triangle list into connected pieces
+///
+/// Triangles sharing a vertex position (welded to 1/1000 unit) belong to the
+/// same piece. Pieces are returned in order of their first triangle, each as
+/// a list of triangles (indices into `positions`).
+pub fn split_pieces(positions: &[Vec3], indices: &[u32]) -> Vec<Vec<[u32; 3]>> {
+    // Weld vertices by quantized position
+    let mut welded: HashMap<[i32; 3], u32> = HashMap::new();
+    let weld_ids: Vec<u32> = positions
+        .iter()
+        .map(|p| {
+            let key = (*p * WELD_SCALE).round().as_ivec3().to_array();
+            let next = welded.len() as u32;
+            *welded.entry(key).or_insert(next)
+        })
+        .collect();
+
+    // Union-find over welded vertices
+    let mut parent: Vec<u32> = (0..welded.len() as u32).collect();
+    fn find(parent: &mut [u32], mut i: u32) -> u32 {
+        while parent[i as usize] != i {
+            parent[i as usize] = parent[parent[i as usize] as usize];
+            i = parent[i as usize];
+        }
+        i
+    }
+
+    let triangles: Vec<[u32; 3]> = indices
+        .chunks_exact(3)
+        .map(|t| [t[0], t[1], t[2]])
+        .filter(|t| t.iter().all(|&i| (i as usize) < positions.len()))
+        .collect();
+
+    for tri in &triangles {
+        let a = find(&mut parent, weld_ids[tri[0] as usize]);
+        for &v in &tri[1..] {
+            let b = find(&mut parent, weld_ids[v as usize]);
+            if a != b {
+                parent[b as usize] = a;
+            }
+        }
+    }
+
+    // Group triangles by root, in order of first appearance
+    let mut piece_of_root: HashMap<u32, usize> = HashMap::new();
+    let mut pieces: Vec<Vec<[u32; 3]>> = Vec::new();
+    for tri in triangles {
+        let root = find(&mut parent, weld_ids[tri[0] as usize]);
+        let piece = *piece_of_root.entry(root).or_insert_with(|| {
+            pieces.push(Vec::new());
+            pieces.len() - 1
+        });
+        pieces[piece].push(tri);
+    }
+    pieces
+}
+
+/// Copy the vertices used by `triangles` out of interleaved vertex data
+///
+/// `stride` is in elements of `T`. Returns the piece's vertex data and its
+/// triangle indices remapped to the new vertex order.
+pub fn extract_piece<T: Copy>(
+    vertex_data: &[T],
+    stride: usize,
+    triangles: &[[u32; 3]],
+) -> (Vec<T>, Vec<u16>) {
+    let mut remap: HashMap<u32, u16> = HashMap::new();
+    let mut vertices = Vec::new();
+    let mut indices = Vec::with_capacity(triangles.len() * 3);
+
+    for &index in triangles.iter().flatten() {
+        let new_index = *remap.entry(index).or_insert_with(|| {
+            let start = index as usize * stride;
+            vertices.extend_from_slice(&vertex_data[start..start + stride]);
+            (vertices.len() / stride - 1) as u16
+        });
+        indices.push(new_index);
+    }
+    (vertices, indices)
+}
+
+/// SplitMix64 step, for per-piece jitter
+fn next_u64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+/// Uniform float in [-1, 1)
+fn next_signed(state: &mut u64) -> f32 {
+    (next_u64(state) >> 40) as f32 / (1u32 << 23) as f32 - 1.0
+}
@@ -0,0 +1,97 @@
+//! Tests for destructible piece splitting and debris motion
+
+use glam::Vec3;
+
+use super::*;
+
+/// Two quads (4 non-indexed triangles) with a gap between them
+fn two_quads() -> (Vec<Vec3>, Vec<u32>) {
+    let quad = |x: f32| {
+        [
+            Vec3::new(x, 0.0, 0.0),
+            Vec3::new(x + 1.0, 0.0, 0.0),
+            Vec3::new(x + 1.0, 1.0, 0.0),
+            Vec3::new(x, 0.0, 0.0),
+            Vec3::new(x + 1.0, 1.0, 0.0),
+            Vec3::new(x, 1.0, 0.0),
+        ]
+    };
+    let positions: Vec<Vec3> = quad(0.0).into_iter().chain(quad(3.0)).collect();
+    let indices = (0..positions.len() as u32).collect();
+    (positions, indices)
+}
+
+#[test]
+fn test_split_welds_shared_positions() {
+    let (positions, indices) = two_quads();
+    let pieces = split_pieces(&positions, &indices);
+
+    // Triangles of each quad share positions but not vertices
+    assert_eq!(pieces.len(), 2);
+    assert_eq!(pieces[0], vec![[0, 1, 2], [3, 4, 5]]);
+    assert_eq!(pieces[1], vec![[6, 7, 8], [9, 10, 11]]);
+}
+
+#[test]
+fn test_split_ignores_out_of_range_indices() {
+    let (positions, _) = two_quads();
+    let pieces = split_pieces(&positions, &[0, 1, 2, 0, 1, 99]);
+    assert_eq!(pieces, vec![vec![[0, 1, 2]]]);
+}
+
+#[test]
+fn test_extract_piece_remaps_vertices() {
+    // Two floats per vertex
+    let data = [0.0, 0.5, 1.0, 1.5, 2.0, 2.5, 3.0, 3.5];
+    let (vertices, indices) = extract_piece(&data, 2, &[[3, 1, 3]]);
+    assert_eq!(vertices, vec![3.0, 3.5, 1.0, 1.5]);
+    assert_eq!(indices, vec![0, 1, 0]);
+}
+
+#[test]
+fn test_piece_starts_in_place() {
+    let (positions, _) = two_quads();
+    let piece = DebrisPiece::new(1, &positions[..6], Vec3::new(2.0, 0.5, 0.0), 7);
+    let corner = Vec3::new(1.0, 1.0, 0.0);
+
+    let moved = piece
+        .transform(0.0, Vec3::ZERO, 0.0)
+        .transform_point3(corner);
+    assert!(moved.distance(corner) < 1e-5);
+}
+
+#[test]
+fn test_piece_lands_on_floor_and_stops() {
+    let (positions, _) = two_quads();
+    let piece = DebrisPiece::new(1, &positions[..6], Vec3::new(2.0, 0.5, 0.0), 7);
+    let impulse = Vec3::new(0.0, 3.0, -1.0);
+
+    let landed = piece.transform(3.0, impulse, -2.0);
+    assert_eq!(landed, piece.transform(DEBRIS_LIFETIME, impulse, -2.0));
+
+    // Centroid rests its own height above the floor
+    let centroid = landed.transform_point3(piece.centroid);
+    assert!((centroid.y - (-2.0 + piece.rest_height)).abs() < 1e-4);
+
+    // Still in the air halfway up the arc
+    let rising = piece
+        .transform(0.1, impulse, -2.0)
+        .transform_point3(piece.centroid);
+    assert!(rising.y > piece.centroid.y);
+}
+
+#[test]
+fn test_piece_flight_is_deterministic() {
+    let (positions, _) = two_quads();
+    let center = Vec3::new(2.0, 0.5, 0.0);
+    let a = DebrisPiece::new(1, &positions[6..], center, 42);
+    let b = DebrisPiece::new(1, &positions[6..], center, 42);
+    let c = DebrisPiece::new(1, &positions[6..], center, 43);
+
+    assert_eq!(a.burst, b.burst);
+    assert_eq!(a.spin_axis, b.spin_axis);
+    assert_ne!(a.burst, c.burst);
+
+    // Pieces right of center fly right
+    assert!(a.burst.x > 0.0);
+}
@@ -0,0 +1,507 @@
+//! Window/event-loop side of the EPU benchmark
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+use glam::{Mat4, Vec3};
+use winit::event::{ElementState, WindowEvent};
+use winit::event_loop::ActiveEventLoop;
+use winit::keyboard::{KeyCode, PhysicalKey};
+use winit::window::{Fullscreen, Window};
+
+use nethercore_core::app::{ConsoleApp, InputManager, RuntimeError};
+use nethercore_core::console::{ConsoleResourceManager, Graphics, RawInput};
+
+use crate::console::NethercoreZX;
+use crate::graphics::ZXGraphics;
+use crate::graphics::epu::EpuConfig;
+use crate::resource_manager::ZResourceManager;
+use crate::state::ZXFFIState;
+
+use super::scenes::{BenchScene, bench_scenes};
+use super::{EpuBenchConfig, EpuBenchMode, SceneTimings};
+
+const TICK: Duration = Duration::from_micros(16_667);
+
+/// Camera field of view (degrees)
+const FOV_DEG: f32 = 70.0;
+
+/// Camera orbit speed (radians per second)
+const ORBIT_SPEED: f32 = 0.2;
+
+/// Cursor travel (pixels) that wakes the attract screen
+const WAKE_CURSOR_DISTANCE: f64 = 24.0;
+
+/// Host-only app that renders the benchmark scenes without a game
+pub struct EpuBenchApp {
+    config: EpuBenchConfig,
+    scenes: Vec<BenchScene>,
+
+    window: Option<Arc<Window>>,
+    graphics: Option<ZXGraphics>,
+    resources: ZResourceManager,
+    state: ZXFFIState,
+    input: InputManager,
+
+    egui_ctx: egui::Context,
+    egui_state: Option<egui_winit::State>,
+    egui_renderer: Option<egui_wgpu::Renderer>,
+
+    scene_index: usize,
+    scene_started: Instant,
+    started: Instant,
+    /// Completed passes over the scene list
+    passes: u32,
+    timings: Vec<SceneTimings>,
+    last_epu_ms: f32,
+    last_frame_ms: f32,
+    last_frame: Option<Instant>,
+    cursor_origin: Option<(f64, f64)>,
+
+    next_tick: Instant,
+    needs_redraw: bool,
+    should_exit: bool,
+}
+
+impl EpuBenchApp {
+    pub fn new(config: EpuBenchConfig) -> Self {
+        let scenes = bench_scenes();
+        let timings = vec![SceneTimings::default(); scenes.len()];
+        let now = Instant::now();
+        Self {
+            config,
+            scenes,
+            window: None,
+            graphics: None,
+            resources: ZResourceManager::new(),
+            state: ZXFFIState::default(),
+            input: InputManager::new(nethercore_core::app::config::load().input),
+            egui_ctx: egui::Context::default(),
+            egui_state: None,
+            egui_renderer: None,
+            scene_index: 0,
+            scene_started: now,
+            started: now,
+            passes: 0,
+            timings,
+            last_epu_ms: 0.0,
+            last_frame_ms: 0.0,
+            last_frame: None,
+            cursor_origin: None,
+            next_tick: now,
+            needs_redraw: true,
+            should_exit: false,
+        }
+    }
+
+    fn is_attract(&self) -> bool {
+        self.config.mode == EpuBenchMode::Attract
+    }
+
+    /// Moves to the next scene, finishing the pass after the last one
+    fn next_scene(&mut self) {
+        self.scene_index += 1;
+        self.scene_started = Instant::now();
+        if self.scene_index < self.scenes.len() {
+            return;
+        }
+
+        self.scene_index = 0;
+        self.passes += 1;
+        if let EpuBenchMode::Benchmark { passes } = self.config.mode {
+            self.log_report();
+            self.timings.fill(SceneTimings::default());
+            if passes != 0 && self.passes >= passes {
+                self.should_exit = true;
+            }
+        }
+    }
+
+    fn log_report(&self) {
+        tracing::info!("EPU benchmark pass {} complete", self.passes);
+        tracing::info!(
+            "{:<28} {:>7} {:>9} {:>9} {:>11} {:>11}",
+            "scene",
+            "frames",
+            "epu avg",
+            "epu max",
+            "frame avg",
+            "frame max"
+        );
+        for (scene, t) in self.scenes.iter().zip(&self.timings) {
+            tracing::info!(
+                "{:<28} {:>7} {:>6.2} ms {:>6.2} ms {:>8.2} ms {:>8.2} ms",
+                scene.name,
+                t.frames,
+                t.epu_avg_ms(),
+                t.epu_max_ms,
+                t.frame_avg_ms(),
+                t.frame_max_ms
+            );
+        }
+    }
+
+    /// Records this frame's EPU requests into the FFI state, exactly as a game
+    /// calling `epu_set_slot()` + `environment_index()` + `draw_epu()` would
+    fn build_frame_state(&mut self) {
+        let scene = &self.scenes[self.scene_index];
+        let t = self.scene_started.elapsed().as_secs_f32();
+        let yaw = self.started.elapsed().as_secs_f32() * ORBIT_SPEED;
+        let forward = Vec3::new(yaw.sin(), 0.3 * (yaw * 0.7).sin(), -yaw.cos());
+
+        let state = &mut self.state;
+        state.clear_frame();
+        for i in 0..scene.envs.len() {
+            let env_id = i as u32;
+            let viewport = scene.viewport(i);
+            state.current_viewport = viewport;
+            state.update_environment_index(env_id);
+            state.current_view_matrix = Some(Mat4::look_at_rh(Vec3::ZERO, forward, Vec3::Y));
+            state.current_proj_matrix = Some(Mat4::perspective_rh(
+                FOV_DEG.to_radians(),
+                viewport.aspect_ratio(),
+                0.1,
+                1000.0,
+            ));
+            state.epu_frame_configs.insert(env_id, scene.config(i, t));
+            let mvp_index = state.add_mvp_shading_state();
+            state
+                .epu_frame_draws
+                .insert((viewport, state.current_pass_id), mvp_index);
+        }
+    }
+
+    /// Builds this frame's environments on their own submission and waits for
+    /// the GPU, returning the build time in milliseconds.
+    ///
+    /// The EPU cache is cleared first so every frame pays for a full rebuild;
+    /// the main render pass then finds the environments already cached.
+    fn time_epu_build(graphics: &mut ZXGraphics, state: &ZXFFIState, env_count: usize) -> f32 {
+        let configs: Vec<(u32, &EpuConfig)> = (0..env_count as u32)
+            .filter_map(|id| state.epu_frame_configs.get(&id).map(|c| (id, c)))
+            .collect();
+
+        // Let the previous frame drain so it isn't billed to this build
+        let _ = graphics.device().poll(wgpu::PollType::wait_indefinitely());
+        graphics.epu_runtime_mut().invalidate_all_caches();
+        let start = Instant::now();
+        let mut encoder =
+            graphics
+                .device()
+                .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                    label: Some("EPU Bench Build"),
+                });
+        graphics.build_epu_environments(&mut encoder, &configs);
+        graphics.queue().submit(std::iter::once(encoder.finish()));
+        if let Err(e) = graphics.device().poll(wgpu::PollType::wait_indefinitely()) {
+            tracing::warn!("EPU bench: device poll failed: {}", e);
+        }
+        start.elapsed().as_secs_f32() * 1000.0
+    }
+
+    fn overlay_text(&self) -> String {
+        let scene = &self.scenes[self.scene_index];
+        let t = &self.timings[self.scene_index];
+        let pass = match self.config.mode {
+            EpuBenchMode::Benchmark { passes: 0 } => format!("pass {}", self.passes + 1),
+            EpuBenchMode::Benchmark { passes } => format!("pass {}/{}", self.passes + 1, passes),
+            EpuBenchMode::Attract => String::new(),
+        };
+        let fps = if self.last_frame_ms > 0.0 {
+            1000.0 / self.last_frame_ms
+        } else {
+            0.0
+        };
+        format!(
+            "{}  [{}/{}] {}\n\
+             environments: {}\n\
+             EPU build: {:.2} ms  (avg {:.2}, max {:.2})\n\
+             frame: {:.2} ms  ({:.0} fps, max {:.2})",
+            scene.name,
+            self.scene_index + 1,
+            self.scenes.len(),
+            pass,
+            scene.envs.len(),
+            self.last_epu_ms,
+            t.epu_avg_ms(),
+            t.epu_max_ms,
+            self.last_frame_ms,
+            fps,
+            t.frame_max_ms,
+        )
+    }
+
+    fn render_frame(&mut self) {
+        let now = Instant::now();
+        let frame_ms = self
+            .last_frame
+            .map(|last| (now - last).as_secs_f32() * 1000.0);
+        self.last_frame = Some(now);
+
+        self.build_frame_state();
+        let env_count = self.scenes[self.scene_index].envs.len();
+        let Some(graphics) = self.graphics.as_mut() else {
+            return;
+        };
+        let epu_ms = Self::time_epu_build(graphics, &self.state, env_count);
+
+        self.last_epu_ms = epu_ms;
+        if let Some(frame_ms) = frame_ms {
+            self.last_frame_ms = frame_ms;
+            self.timings[self.scene_index].record(epu_ms, frame_ms);
+        }
+        let overlay = self.overlay_text();
+
+        let Some(graphics) = self.graphics.as_mut() else {
+            return;
+        };
+        let surface_texture = match graphics.get_current_texture() {
+            Ok(tex) => tex,
+            Err(e) => {
+                tracing::warn!("Failed to get surface texture: {}", e);
+                return;
+            }
+        };
+        let view = surface_texture
+            .texture
+            .create_view(&wgpu::TextureViewDescriptor::default());
+        let mut encoder =
+            graphics
+                .device()
+                .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                    label: Some("EPU Bench Frame"),
+                });
+
+        self.resources.render_game_to_target(
+            graphics,
+            &mut encoder,
+            &self.state,
+            [0.0, 0.0, 0.0, 1.0],
+        );
+        graphics.blit_to_window(&mut encoder, &view);
+
+        if let (Some(window), Some(egui_state), Some(egui_renderer)) =
+            (&self.window, &mut self.egui_state, &mut self.egui_renderer)
+        {
+            let raw_input = egui_state.take_egui_input(window);
+            let full_output = self.egui_ctx.run(raw_input, |ctx| {
+                egui::Area::new(egui::Id::new("epu_bench_timings"))
+                    .anchor(egui::Align2::LEFT_TOP, [10.0, 10.0])
+                    .show(ctx, |ui| {
+                        egui::Frame::popup(ui.style()).show(ui, |ui| {
+                            ui.monospace(&overlay);
+                        });
+                    });
+            });
+            egui_state.handle_platform_output(window, full_output.platform_output);
+
+            let screen_descriptor = egui_wgpu::ScreenDescriptor {
+                size_in_pixels: [graphics.width(), graphics.height()],
+                pixels_per_point: window.scale_factor() as f32,
+            };
+            let tris = self
+                .egui_ctx
+                .tessellate(full_output.shapes, full_output.pixels_per_point);
+            for (id, delta) in &full_output.textures_delta.set {
+                egui_renderer.update_texture(graphics.device(), graphics.queue(), *id, delta);
+            }
+            egui_renderer.update_buffers(
+                graphics.device(),
+                graphics.queue(),
+                &mut encoder,
+                &tris,
+                &screen_descriptor,
+            );
+            {
+                let render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                    label: Some("EPU Bench Overlay"),
+                    color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                        view: &view,
+                        resolve_target: None,
+                        ops: wgpu::Operations {
+                            load: wgpu::LoadOp::Load,
+                            store: wgpu::StoreOp::Store,
+                        },
+                        depth_slice: None,
+                    })],
+                    depth_stencil_attachment: None,
+                    timestamp_writes: None,
+                    occlusion_query_set: None,
+                });
+                let mut render_pass = render_pass.forget_lifetime();
+                egui_renderer.render(&mut render_pass, &tris, &screen_descriptor);
+            }
+            for id in &full_output.textures_delta.free {
+                egui_renderer.free_texture(id);
+            }
+        }
+
+        graphics.queue().submit(std::iter::once(encoder.finish()));
+        surface_texture.present();
+    }
+}
+
+/// True when a controller is being used (wakes the attract screen)
+fn input_active(input: &RawInput) -> bool {
+    input.dpad_up
+        || input.dpad_down
+        || input.dpad_left
+        || input.dpad_right
+        || input.button_a
+        || input.button_b
+        || input.button_x
+        || input.button_y
+        || input.left_bumper
+        || input.right_bumper
+        || input.start
+        || input.select
+        || input.left_stick_x.abs() > 0.5
+        || input.left_stick_y.abs() > 0.5
+        || input.right_stick_x.abs() > 0.5
+        || input.right_stick_y.abs() > 0.5
+}
+
+impl ConsoleApp<NethercoreZX> for EpuBenchApp {
+    fn on_window_created(
+        &mut self,
+        window: Arc<Window>,
+        _event_loop: &ActiveEventLoop,
+    ) -> Result<()> {
+        window.set_title(match self.config.mode {
+            EpuBenchMode::Benchmark { .. } => "Nethercore ZX - EPU Benchmark",
+            EpuBenchMode::Attract => "Nethercore ZX",
+        });
+        if self.config.fullscreen {
+            window.set_fullscreen(Some(Fullscreen::Borderless(None)));
+        }
+        if self.is_attract() {
+            window.set_cursor_visible(false);
+        }
+
+        let mut graphics = ZXGraphics::new_blocking(window.clone())?;
+        graphics.set_scale_mode(nethercore_core::app::config::load().video.scale_mode);
+
+        let egui_state = egui_winit::State::new(
+            self.egui_ctx.clone(),
+            egui::ViewportId::ROOT,
+            &window,
+            Some(window.scale_factor() as f32),
+            None,
+            None,
+        );
+        let egui_renderer = egui_wgpu::Renderer::new(
+            graphics.device(),
+            graphics.surface_format(),
+            egui_wgpu::RendererOptions::default(),
+        );
+
+        self.graphics = Some(graphics);
+        self.egui_state = Some(egui_state);
+        self.egui_renderer = Some(egui_renderer);
+        self.window = Some(window);
+
+        let now = Instant::now();
+        self.started = now;
+        self.scene_started = now;
+        self.next_tick = now;
+        tracing::info!(
+            "EPU scene started: {} scenes, {:?} each",
+            self.scenes.len(),
+            self.config.scene_duration()
+        );
+        Ok(())
+    }
+
+    fn on_window_event(&mut self, event: &WindowEvent) -> bool {
+        let attract = self.is_attract();
+        match event {
+            WindowEvent::Resized(size) => {
+                if let Some(graphics) = &mut self.graphics {
+                    graphics.resize(size.width, size.height);
+                }
+                self.needs_redraw = true;
+            }
+            WindowEvent::KeyboardInput { event, .. } if event.state == ElementState::Pressed => {
+                let escape = event.physical_key == PhysicalKey::Code(KeyCode::Escape);
+                if attract || escape {
+                    self.should_exit = true;
+                }
+            }
+            WindowEvent::MouseInput {
+                state: ElementState::Pressed,
+                ..
+            } if attract => {
+                self.should_exit = true;
+            }
+            WindowEvent::CursorMoved { position, .. } if attract => {
+                let (x0, y0) = *self.cursor_origin.get_or_insert((position.x, position.y));
+                if (position.x - x0).hypot(position.y - y0) > WAKE_CURSOR_DISTANCE {
+                    self.should_exit = true;
+                }
+            }
+            _ => {}
+        }
+        false
+    }
+
+    fn next_tick(&self) -> Instant {
+        self.next_tick
+    }
+
+    fn advance_simulation(&mut self) {
+        self.input.update();
+        if self.is_attract() && self.input.get_all_inputs().iter().any(input_active) {
+            self.should_exit = true;
+        }
+
+        if self.scene_started.elapsed() >= self.config.scene_duration() {
+            self.next_scene();
+        }
+        self.needs_redraw = true;
+    }
+
+    fn update_next_tick(&mut self) {
+        self.next_tick += TICK;
+        // Don't try to catch up after a stall (e.g. a slow stress scene)
+        let now = Instant::now();
+        if self.next_tick < now {
+            self.next_tick = now;
+        }
+    }
+
+    fn render(&mut self) {
+        self.render_frame();
+    }
+
+    fn needs_redraw(&self) -> bool {
+        self.needs_redraw
+    }
+
+    fn mark_needs_redraw(&mut self) {
+        self.needs_redraw = true;
+    }
+
+    fn clear_needs_redraw(&mut self) {
+        self.needs_redraw = false;
+    }
+
+    fn on_runtime_error(&mut self, error: RuntimeError) {
+        tracing::error!("EPU bench error: {}", error.0);
+        self.should_exit = true;
+    }
+
+    fn should_exit(&self) -> bool {
+        self.should_exit
+    }
+
+    fn request_exit(&mut self) {
+        self.should_exit = true;
+    }
+
+    fn request_redraw(&self) {
+        if let Some(window) = &self.window {
+            window.request_redraw();
+        }
+    }
+}
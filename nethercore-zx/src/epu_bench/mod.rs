@@ -0,0 +1,118 @@
+//! Built-in EPU benchmark and attract scene
+//!
+//! A host-side demo that needs no ROM: it cycles every EPU opcode and a set
+//! of stress configurations under an orbiting camera, timing the environment
+//! build and the full frame for each scene.
+//!
+//! # Usage
+//!
+//! ```bash
+//! nethercore-zx --epu-bench               # one pass, then print a report
+//! nethercore-zx --epu-bench --passes 0    # loop until closed (burn-in)
+//! nethercore-zx --attract --fullscreen    # idle screensaver, any input exits
+//! ```
+
+mod app;
+mod scenes;
+
+pub use app::EpuBenchApp;
+pub use scenes::{BenchScene, bench_scenes};
+
+use std::time::Duration;
+
+use anyhow::Result;
+
+/// How the scene runs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EpuBenchMode {
+    /// Cycle all scenes `passes` times (0 = forever), logging a report after each pass
+    Benchmark { passes: u32 },
+    /// Loop forever with slower scene changes; any input exits
+    Attract,
+}
+
+/// Configuration for the EPU benchmark / attract scene
+#[derive(Debug, Clone)]
+pub struct EpuBenchConfig {
+    pub mode: EpuBenchMode,
+    /// Start in fullscreen mode
+    pub fullscreen: bool,
+}
+
+impl EpuBenchConfig {
+    /// Time spent on each scene
+    pub fn scene_duration(&self) -> Duration {
+        match self.mode {
+            EpuBenchMode::Benchmark { .. } => Duration::from_secs(4),
+            EpuBenchMode::Attract => Duration::from_secs(12),
+        }
+    }
+}
+
+/// Accumulated timings for one scene
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SceneTimings {
+    pub frames: u32,
+    /// Sum of EPU build times (ms)
+    pub epu_total_ms: f32,
+    /// Slowest EPU build (ms)
+    pub epu_max_ms: f32,
+    /// Sum of frame times (ms)
+    pub frame_total_ms: f32,
+    /// Slowest frame (ms)
+    pub frame_max_ms: f32,
+}
+
+impl SceneTimings {
+    /// Records one frame
+    pub fn record(&mut self, epu_ms: f32, frame_ms: f32) {
+        self.frames += 1;
+        self.epu_total_ms += epu_ms;
+        self.epu_max_ms = self.epu_max_ms.max(epu_ms);
+        self.frame_total_ms += frame_ms;
+        self.frame_max_ms = self.frame_max_ms.max(frame_ms);
+    }
+
+    pub fn epu_avg_ms(&self) -> f32 {
+        self.epu_total_ms / self.frames.max(1) as f32
+    }
+
+    pub fn frame_avg_ms(&self) -> f32 {
+        self.frame_total_ms / self.frames.max(1) as f32
+    }
+}
+
+/// Run the EPU benchmark / attract scene
+pub fn run(config: EpuBenchConfig) -> Result<()> {
+    use nethercore_core::app::event_loop;
+
+    tracing_subscriber::fmt()
+        .with_env_filter(
+            tracing_subscriber::EnvFilter::from_default_env()
+                .add_directive(tracing::Level::INFO.into()),
+        )
+        .init();
+
+    let app = EpuBenchApp::new(config);
+    event_loop::run(app)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn timings_track_average_and_max() {
+        let mut timings = SceneTimings::default();
+        assert_eq!(timings.epu_avg_ms(), 0.0);
+
+        timings.record(1.0, 10.0);
+        timings.record(3.0, 20.0);
+
+        assert_eq!(timings.frames, 2);
+        assert_eq!(timings.epu_avg_ms(), 2.0);
+        assert_eq!(timings.epu_max_ms, 3.0);
+        assert_eq!(timings.frame_avg_ms(), 15.0);
+        assert_eq!(timings.frame_max_ms, 20.0);
+    }
+}
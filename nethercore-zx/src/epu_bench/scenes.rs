@@ -0,0 +1,209 @@
+//! Scene list for the EPU benchmark / attract mode
+//!
+//! One scene per defined opcode (layered over a plain RAMP so bounds
+//! modifiers have something to carve), followed by stress scenes that fill
+//! every layer slot and every active environment slot.
+
+use glam::{Quat, Vec3};
+
+use crate::debug::epu_meta_gen::{OpcodeKind, is_defined, opcode_kind, opcode_name, variant_count};
+use crate::graphics::Viewport;
+use crate::graphics::epu::{
+    EpuBlend, EpuConfig, EpuLayer, EpuOpcode, MAX_ACTIVE_ENVS, REGION_ALL, encode_direction_u16,
+    pack_meta5, pack_thresholds,
+};
+
+/// A single benchmark scene: one or more environments tiled across the screen
+#[derive(Clone, Debug)]
+pub struct BenchScene {
+    /// Display name shown in the overlay and report
+    pub name: String,
+    /// Viewport grid (columns, rows); environment `i` draws into cell `i`
+    pub grid: (u32, u32),
+    /// Layer stacks, one per environment
+    pub envs: Vec<[EpuLayer; 8]>,
+}
+
+impl BenchScene {
+    /// Packs environment `index` for time `t` (seconds into the scene).
+    ///
+    /// Radiance layers slowly rotate and step through their variants, so
+    /// every frame is a fresh EPU build and every variant gets exercised.
+    pub fn config(&self, index: usize, t: f32) -> EpuConfig {
+        let rotation = Quat::from_rotation_y(t * 0.35);
+        let mut config = EpuConfig::default();
+        for (slot, layer) in self.envs[index].iter().enumerate() {
+            let mut layer = *layer;
+            let code = layer.opcode as u8;
+            if opcode_kind(code) == Some(OpcodeKind::Radiance) {
+                layer.direction = encode_direction_u16(rotation * SHOWCASE_DIR);
+            }
+            let variants = variant_count(code) as u32;
+            if variants > 1 {
+                let variant = (t / VARIANT_SECONDS) as u32 % variants;
+                layer.meta5 = pack_meta5(0, variant as u8);
+            }
+            config.layers[slot] = layer.encode();
+        }
+        config
+    }
+
+    /// Viewport of environment `index` within the native screen
+    pub fn viewport(&self, index: usize) -> Viewport {
+        let (cols, rows) = self.grid;
+        let full = Viewport::FULLSCREEN;
+        let (w, h) = (full.width / cols, full.height / rows);
+        let index = index as u32;
+        Viewport {
+            x: (index % cols) * w,
+            y: (index / cols) * h,
+            width: w,
+            height: h,
+        }
+    }
+}
+
+/// Seconds each variant of a layer stays on screen
+const VARIANT_SECONDS: f32 = 1.5;
+
+/// Default direction for directional layers (sun, lobes, decals)
+const SHOWCASE_DIR: Vec3 = Vec3::new(0.35, 0.55, -0.75);
+
+/// All benchmark scenes in playback order
+pub fn bench_scenes() -> Vec<BenchScene> {
+    let mut scenes: Vec<BenchScene> = (1..=0x1Fu8)
+        .filter(|&code| is_defined(code))
+        .map(|code| {
+            let mut layers = [EpuLayer::nop(); 8];
+            layers[0] = base_ramp(0);
+            let slot = match opcode_kind(code) {
+                Some(OpcodeKind::Bounds) => 1,
+                _ => 4,
+            };
+            if code != EpuOpcode::Ramp as u8 {
+                layers[slot] = showcase_layer(code, 0);
+            }
+            BenchScene {
+                name: opcode_name(code).to_string(),
+                grid: (1, 1),
+                envs: vec![layers],
+            }
+        })
+        .collect();
+
+    scenes.push(BenchScene {
+        name: "STRESS: 8 layers".to_string(),
+        grid: (1, 1),
+        envs: vec![full_stack(0)],
+    });
+    scenes.push(BenchScene {
+        name: "STRESS: 4 viewports".to_string(),
+        grid: (2, 2),
+        envs: (0..4).map(full_stack).collect(),
+    });
+    scenes.push(BenchScene {
+        name: format!("STRESS: {} environments", MAX_ACTIVE_ENVS),
+        grid: (8, MAX_ACTIVE_ENVS / 8),
+        envs: (0..MAX_ACTIVE_ENVS as usize).map(full_stack).collect(),
+    });
+
+    scenes
+}
+
+/// Sky/wall/floor gradient every scene builds on, hue-shifted by `seed`
+fn base_ramp(seed: usize) -> EpuLayer {
+    let shift = (seed as u8).wrapping_mul(37);
+    EpuLayer {
+        opcode: EpuOpcode::Ramp,
+        color_a: [60u8.wrapping_add(shift), 120, 200],
+        color_b: [40, 36, 32u8.wrapping_add(shift)],
+        intensity: 180,
+        param_a: 110,
+        param_b: 100u8.wrapping_add(shift / 2),
+        param_c: 96,
+        param_d: pack_thresholds(10, 5),
+        direction: encode_direction_u16(Vec3::Y),
+        ..EpuLayer::nop()
+    }
+}
+
+/// A mid-range, clearly visible instance of `code` with every parameter at 50%
+fn showcase_layer(code: u8, seed: usize) -> EpuLayer {
+    let shift = (seed as u8).wrapping_mul(53);
+    EpuLayer {
+        opcode: EpuOpcode::from_u8(code),
+        region_mask: REGION_ALL,
+        blend: EpuBlend::Add,
+        color_a: [255, 180u8.wrapping_add(shift), 80],
+        color_b: [60u8.wrapping_add(shift), 120, 255],
+        alpha_a: 15,
+        alpha_b: 12,
+        intensity: 200,
+        param_a: 128,
+        param_b: 128,
+        param_c: 128,
+        param_d: 128,
+        direction: encode_direction_u16(SHOWCASE_DIR),
+        ..EpuLayer::nop()
+    }
+}
+
+/// Every slot filled with the most expensive opcodes of each kind
+fn full_stack(seed: usize) -> [EpuLayer; 8] {
+    [
+        base_ramp(seed),
+        showcase_layer(EpuOpcode::Sector as u8, seed),
+        showcase_layer(EpuOpcode::Cell as u8, seed),
+        showcase_layer(EpuOpcode::Patches as u8, seed),
+        showcase_layer(EpuOpcode::Flow as u8, seed),
+        showcase_layer(EpuOpcode::Scatter as u8, seed),
+        showcase_layer(EpuOpcode::Trace as u8, seed),
+        showcase_layer(EpuOpcode::Veil as u8, seed),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::debug::epu_meta_gen::OPCODE_COUNT;
+
+    #[test]
+    fn every_opcode_has_a_scene() {
+        let scenes = bench_scenes();
+        for code in (1..=0x1Fu8).filter(|&c| is_defined(c)) {
+            let used = scenes.iter().any(|scene| {
+                scene
+                    .envs
+                    .iter()
+                    .flatten()
+                    .any(|layer| layer.opcode as u8 == code)
+            });
+            assert!(used, "opcode {} is never benchmarked", opcode_name(code));
+        }
+        assert!(scenes.len() > OPCODE_COUNT);
+    }
+
+    #[test]
+    fn scene_grids_fit_the_screen() {
+        let full = Viewport::FULLSCREEN;
+        for scene in bench_scenes() {
+            let (cols, rows) = scene.grid;
+            assert!(scene.envs.len() as u32 <= cols * rows, "{}", scene.name);
+            assert!(scene.envs.len() as u32 <= MAX_ACTIVE_ENVS, "{}", scene.name);
+            for i in 0..scene.envs.len() {
+                let vp = scene.viewport(i);
+                assert!(vp.width > 0 && vp.height > 0);
+                assert!(vp.x + vp.width <= full.width && vp.y + vp.height <= full.height);
+            }
+        }
+    }
+
+    #[test]
+    fn configs_change_over_time() {
+        let scene = &bench_scenes()[EpuOpcode::Celestial as usize - 1];
+        assert_eq!(scene.name, "CELESTIAL");
+        let a = scene.config(0, 0.0);
+        let b = scene.config(0, 2.0);
+        assert_ne!(a.state_hash(), b.state_hash());
+    }
+}
@@ -287,6 +287,7 @@ fn load_ztex(mut caller: Caller<'_, ZXGameContext>, data_ptr: u32, data_len: u32
         height,
         format: TextureFormat::Rgba8,
         data: pixel_data,
+        etc2_data: Vec::new(),
     });
 
     info!(
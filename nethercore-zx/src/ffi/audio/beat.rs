@@ -0,0 +1,87 @@
+//! Music beat clock FFI functions
+//!
+//! Beat and bar queries for rhythm-reactive games. Beats are read from the
+//! music playhead, so they line up with what the player hears and roll back
+//! with the rest of the audio state. Each new beat is also queued as a
+//! `MUSIC_BEAT` event for `event_poll()`.
+
+use anyhow::Result;
+use tracing::warn;
+use wasmtime::{Caller, Linker};
+
+use super::super::ZXGameContext;
+
+/// Highest PCM tempo accepted by music_beat_config()
+const MAX_BPM: f32 = 999.0;
+
+/// Register beat clock FFI functions
+pub(super) fn register(linker: &mut Linker<ZXGameContext>) -> Result<()> {
+    linker.func_wrap("env", "music_beat_config", music_beat_config)?;
+    linker.func_wrap("env", "music_beat", music_beat)?;
+    linker.func_wrap("env", "music_bar", music_bar)?;
+    linker.func_wrap("env", "music_beat_phase", music_beat_phase)?;
+    Ok(())
+}
+
+/// Configure the beat clock
+///
+/// Tracker music has a beat every `rows_per_beat` rows, so it follows tempo
+/// and speed changes in the song. PCM music has no tempo information, so it
+/// has no beats until `bpm` is set. The settings persist across music changes.
+///
+/// # Parameters
+/// - `bpm`: Tempo of PCM music (0 = no beats for PCM music)
+/// - `beats_per_bar`: Beats per bar (0 = default 4)
+/// - `rows_per_beat`: Tracker rows per beat (0 = default 4)
+fn music_beat_config(
+    mut caller: Caller<'_, ZXGameContext>,
+    bpm: f32,
+    beats_per_bar: u32,
+    rows_per_beat: u32,
+) {
+    if !(0.0..=MAX_BPM).contains(&bpm) {
+        warn!(
+            "music_beat_config: bpm {} out of range (0-{})",
+            bpm, MAX_BPM
+        );
+        return;
+    }
+    let clock = &mut caller.data_mut().rollback.music_clock;
+    clock.bpm = bpm;
+    clock.beats_per_bar = beats_per_bar;
+    clock.rows_per_beat = rows_per_beat;
+}
+
+/// Beats since the music started
+///
+/// # Returns
+/// Beat index (0 = first beat), or 0 if no music with a beat clock is playing
+fn music_beat(caller: Caller<'_, ZXGameContext>) -> u32 {
+    let rollback = &caller.data().rollback;
+    rollback
+        .music_clock
+        .position(&rollback.audio.music, &rollback.tracker)
+        .map_or(0, |beats| beats as u32)
+}
+
+/// Bars since the music started
+///
+/// # Returns
+/// Bar index (music_beat() / beats per bar)
+fn music_bar(caller: Caller<'_, ZXGameContext>) -> u32 {
+    let beats_per_bar = caller.data().rollback.music_clock.beats_per_bar();
+    music_beat(caller) / beats_per_bar
+}
+
+/// Progress through the current beat, for pulsing visuals
+///
+/// # Returns
+/// 0.0 on the beat, rising toward 1.0 just before the next one
+/// (0.0 if no music with a beat clock is playing)
+fn music_beat_phase(caller: Caller<'_, ZXGameContext>) -> f32 {
+    let rollback = &caller.data().rollback;
+    rollback
+        .music_clock
+        .position(&rollback.audio.music, &rollback.tracker)
+        .map_or(0.0, |beats| beats.fract() as f32)
+}
@@ -0,0 +1,130 @@
+//! Audio bus mixing
+//!
+//! Buses group channels for volume and pause control. The built-in buses are
+//! master, music, sfx, ui and voice; every other bus is a child of master.
+//! Bus names are fixed at init, while volumes, pauses and routing live in
+//! ZRollbackState.audio.buses. The player's volumes for the built-in buses
+//! come from host settings and are applied by the audio backend.
+
+use anyhow::Result;
+use tracing::{info, warn};
+use wasmtime::{Caller, Linker};
+
+use crate::state::{MAX_AUDIO_BUSES, MAX_CHANNELS};
+
+use super::super::{ZXGameContext, guards::guard_init_only, helpers::read_wasm_bytes};
+use super::clamp_safe;
+
+/// Register audio bus FFI functions
+pub(super) fn register(linker: &mut Linker<ZXGameContext>) -> Result<()> {
+    linker.func_wrap("env", "bus_create", bus_create)?;
+    linker.func_wrap("env", "channel_set_bus", channel_set_bus)?;
+    linker.func_wrap("env", "bus_volume", bus_volume)?;
+    linker.func_wrap("env", "bus_pause", bus_pause)?;
+    Ok(())
+}
+
+/// Create a named audio bus (or look up an existing one)
+///
+/// Must be called during `init()`. The built-in buses are named "master",
+/// "music", "sfx", "ui" and "voice", so creating one of those returns its index.
+///
+/// # Parameters
+/// - `name_ptr`: Pointer to UTF-8 bus name in WASM memory
+/// - `name_len`: Length of the name in bytes
+///
+/// # Returns
+/// Bus index, or 0 (master) if the name is invalid or all buses are in use
+fn bus_create(mut caller: Caller<'_, ZXGameContext>, name_ptr: u32, name_len: u32) -> u32 {
+    guard_init_only!(caller, "bus_create");
+
+    let Some(bytes) = read_wasm_bytes(&caller, name_ptr, name_len as usize, "bus_create") else {
+        return 0;
+    };
+    let Ok(name) = String::from_utf8(bytes) else {
+        warn!("bus_create: name is not valid UTF-8");
+        return 0;
+    };
+
+    let names = &mut caller.data_mut().ffi.audio_bus_names;
+    if let Some(index) = names.iter().position(|n| *n == name) {
+        return index as u32;
+    }
+
+    if names.len() >= MAX_AUDIO_BUSES {
+        warn!(
+            "bus_create: all {} buses in use, \"{}\" not created",
+            MAX_AUDIO_BUSES, name
+        );
+        return 0;
+    }
+
+    let index = names.len() as u32;
+    info!("Created audio bus {} \"{}\"", index, name);
+    names.push(name);
+    index
+}
+
+/// Route a sound effect channel through a bus
+///
+/// Channels start on the sfx bus. Music always plays on the music bus.
+///
+/// # Parameters
+/// - `channel`: 0-15
+/// - `bus`: Bus index from bus_create() or `audio_bus::*`
+fn channel_set_bus(mut caller: Caller<'_, ZXGameContext>, channel: u32, bus: u32) {
+    let channel_idx = channel as usize;
+    if channel_idx >= MAX_CHANNELS {
+        warn!("channel_set_bus: invalid channel {}", channel);
+        return;
+    }
+
+    let ctx = caller.data_mut();
+    if bus as usize >= ctx.ffi.audio_bus_names.len() {
+        warn!("channel_set_bus: invalid bus {}", bus);
+        return;
+    }
+
+    ctx.rollback.audio.buses.channel_bus[channel_idx] = bus as u8;
+}
+
+/// Set a bus volume
+///
+/// The master bus scales every other bus. The player's own volume for a
+/// built-in bus is applied on top by the host.
+///
+/// # Parameters
+/// - `bus`: Bus index from bus_create() or `audio_bus::*`
+/// - `volume`: 0.0 to 1.0
+fn bus_volume(mut caller: Caller<'_, ZXGameContext>, bus: u32, volume: f32) {
+    let ctx = caller.data_mut();
+    if bus as usize >= ctx.ffi.audio_bus_names.len() {
+        warn!("bus_volume: invalid bus {}", bus);
+        return;
+    }
+
+    ctx.rollback.audio.buses.volume[bus as usize] = clamp_safe(volume, 0.0, 1.0);
+}
+
+/// Pause or resume everything routed through a bus
+///
+/// Paused sounds hold their position and continue from it when resumed.
+/// Pausing the master bus pauses all audio.
+///
+/// # Parameters
+/// - `bus`: Bus index from bus_create() or `audio_bus::*`
+/// - `paused`: 1 = pause, 0 = resume
+fn bus_pause(mut caller: Caller<'_, ZXGameContext>, bus: u32, paused: u32) {
+    let ctx = caller.data_mut();
+    if bus as usize >= ctx.ffi.audio_bus_names.len() {
+        warn!("bus_pause: invalid bus {}", bus);
+        return;
+    }
+
+    let buses = &mut ctx.rollback.audio.buses;
+    if paused != 0 {
+        buses.paused |= 1 << bus;
+    } else {
+        buses.paused &= !(1 << bus);
+    }
+}
@@ -0,0 +1,214 @@
+//! Audio listeners and positional sound
+//!
+//! Each split-screen player can have a listener. Positional sounds are
+//! spatialized against the active listeners when they are played or moved,
+//! and the resulting volume and pan are written to the channel like any other
+//! sound. Listeners live in ZRollbackState.audio.listeners, so spatialization
+//! is re-applied identically during rollback.
+
+use anyhow::Result;
+use glam::Vec3;
+use tracing::warn;
+use wasmtime::{Caller, Linker};
+
+use nethercore_core::wasm::MAX_PLAYERS;
+
+use crate::state::{MAX_CHANNELS, MAX_LISTENERS, ZRollbackState, listener_policy};
+
+use super::super::ZXGameContext;
+use super::clamp_safe;
+
+/// Register listener and positional sound FFI functions
+pub(super) fn register(linker: &mut Linker<ZXGameContext>) -> Result<()> {
+    linker.func_wrap("env", "listener_set_n", listener_set_n)?;
+    linker.func_wrap("env", "listener_clear_n", listener_clear_n)?;
+    linker.func_wrap("env", "listener_set_policy", listener_set_policy)?;
+    linker.func_wrap("env", "play_sound_3d", play_sound_3d)?;
+    linker.func_wrap("env", "channel_set_3d", channel_set_3d)?;
+    Ok(())
+}
+
+/// Place a player's listener and make it active
+///
+/// Call every frame from `update()` with each player's camera. In netplay
+/// the listeners also place voice chat (see `voice_set_positional`).
+///
+/// # Parameters
+/// - `player`: 0-7 (only 0-3 hear sounds; 4-7 just place voice chat)
+/// - `x`, `y`, `z`: Listener position (world space)
+/// - `forward_x`, `forward_y`, `forward_z`: Facing direction (sets left/right)
+fn listener_set_n(
+    mut caller: Caller<'_, ZXGameContext>,
+    player: u32,
+    x: f32,
+    y: f32,
+    z: f32,
+    forward_x: f32,
+    forward_y: f32,
+    forward_z: f32,
+) {
+    let player_idx = player as usize;
+    if player_idx >= MAX_PLAYERS {
+        warn!("listener_set_n: invalid player {}", player);
+        return;
+    }
+    let position = [x, y, z];
+    let forward = [forward_x, forward_y, forward_z];
+    if !position.iter().chain(&forward).all(|v| v.is_finite()) {
+        warn!("listener_set_n: position and forward must be finite");
+        return;
+    }
+
+    place_listener(caller.data_mut(), player_idx, position, forward);
+}
+
+/// Store a player's listener and place their voice chat from it
+fn place_listener(ctx: &mut ZXGameContext, player: usize, position: [f32; 3], forward: [f32; 3]) {
+    // Split-screen has at most MAX_LISTENERS players, so the rest of an
+    // 8-player session only needs voice placement
+    if player < MAX_LISTENERS {
+        let listeners = &mut ctx.rollback.audio.listeners;
+        listeners.listeners[player].position = position;
+        listeners.listeners[player].forward = forward;
+        listeners.active |= 1 << player;
+    }
+
+    // Positional voice chat is heard from the local player's listener and
+    // comes from each remote player's listener
+    if player == ctx.voice.local_handle() as usize {
+        ctx.voice.set_listener(Vec3::from(position));
+    } else {
+        ctx.voice.set_player_position(player, Vec3::from(position));
+    }
+}
+
+/// Deactivate a player's listener (e.g. when they leave split-screen)
+///
+/// # Parameters
+/// - `player`: 0-3
+fn listener_clear_n(mut caller: Caller<'_, ZXGameContext>, player: u32) {
+    if player as usize >= MAX_LISTENERS {
+        warn!("listener_clear_n: invalid player {}", player);
+        return;
+    }
+    caller.data_mut().rollback.audio.listeners.active &= !(1 << player);
+}
+
+/// Choose how positional sounds are mixed between listeners
+///
+/// # Parameters
+/// - `policy`: `listener_policy::NEAREST` (default) or `listener_policy::MIX`
+fn listener_set_policy(mut caller: Caller<'_, ZXGameContext>, policy: u32) {
+    if policy > listener_policy::MIX {
+        warn!("listener_set_policy: invalid policy {}", policy);
+        return;
+    }
+    caller.data_mut().rollback.audio.listeners.policy = policy;
+}
+
+/// Volume and pan of a sound at a world position
+fn spatialize(rollback: &ZRollbackState, volume: f32, position: Vec3, range: f32) -> (f32, f32) {
+    let (gain, pan) = rollback.audio.listeners.spatialize(position, range);
+    (clamp_safe(volume, 0.0, 1.0) * gain, pan)
+}
+
+/// Play a sound at a world position on the next available channel
+///
+/// Volume and pan are set from the listeners when the sound starts.
+///
+/// # Parameters
+/// - `sound`: Sound handle from load_sound()
+/// - `volume`: 0.0 to 1.0 at the listener
+/// - `x`, `y`, `z`: Sound position (world space)
+/// - `range`: Distance at which the sound fades to silence
+fn play_sound_3d(
+    mut caller: Caller<'_, ZXGameContext>,
+    sound: u32,
+    volume: f32,
+    x: f32,
+    y: f32,
+    z: f32,
+    range: f32,
+) {
+    let ctx = caller.data_mut();
+    let (volume, pan) = spatialize(&ctx.rollback, volume, Vec3::new(x, y, z), range);
+    if volume <= 0.0 {
+        return;
+    }
+
+    // Find first free channel (sound == 0 means channel is available)
+    let audio = &mut ctx.rollback.audio;
+    for (i, channel) in audio.channels.iter_mut().enumerate() {
+        if channel.sound == 0 {
+            channel.sound = sound;
+            channel.reset_position();
+            channel.looping = 0;
+            channel.volume = volume;
+            channel.pan = pan;
+            audio.schedule.clear(i);
+            return;
+        }
+    }
+
+    // All channels busy - sound is dropped
+    warn!("play_sound_3d: all channels busy, sound {} dropped", sound);
+}
+
+/// Update a channel's volume and pan from a world position
+///
+/// Call every frame for moving or looping sounds started with channel_play().
+///
+/// # Parameters
+/// - `channel`: 0-15
+/// - `volume`: 0.0 to 1.0 at the listener
+/// - `x`, `y`, `z`: Sound position (world space)
+/// - `range`: Distance at which the sound fades to silence
+fn channel_set_3d(
+    mut caller: Caller<'_, ZXGameContext>,
+    channel: u32,
+    volume: f32,
+    x: f32,
+    y: f32,
+    z: f32,
+    range: f32,
+) {
+    let channel_idx = channel as usize;
+    if channel_idx >= MAX_CHANNELS {
+        warn!("channel_set_3d: invalid channel {}", channel);
+        return;
+    }
+
+    let ctx = caller.data_mut();
+    let (volume, pan) = spatialize(&ctx.rollback, volume, Vec3::new(x, y, z), range);
+    let ch = &mut ctx.rollback.audio.channels[channel_idx];
+    ch.volume = volume;
+    ch.pan = pan;
+}
+
+#[cfg(test)]
+mod tests {
+    use nethercore_core::net::VoiceChannel;
+
+    use super::*;
+
+    #[test]
+    fn test_listeners_place_voice_chat() {
+        let mut ctx = ZXGameContext::new();
+        ctx.voice = VoiceChannel::new(1);
+
+        // The local player's listener is where voice is heard from
+        place_listener(&mut ctx, 1, [1.0, 2.0, 3.0], [0.0, 0.0, -1.0]);
+        assert_eq!(ctx.voice.listener(), Vec3::new(1.0, 2.0, 3.0));
+        assert_eq!(ctx.voice.player_position(1), None);
+        assert_eq!(ctx.rollback.audio.listeners.active, 0b10);
+
+        // Remote players' listeners are where their voice comes from
+        place_listener(&mut ctx, 0, [4.0, 0.0, 0.0], [0.0, 0.0, -1.0]);
+        assert_eq!(ctx.voice.player_position(0), Some(Vec3::new(4.0, 0.0, 0.0)));
+
+        // Players beyond the split-screen listeners still place their voice
+        place_listener(&mut ctx, 6, [0.0, 0.0, 9.0], [0.0, 0.0, -1.0]);
+        assert_eq!(ctx.voice.player_position(6), Some(Vec3::new(0.0, 0.0, 9.0)));
+        assert_eq!(ctx.rollback.audio.listeners.active, 0b11);
+    }
+}
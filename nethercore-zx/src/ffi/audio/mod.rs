@@ -1,6 +1,8 @@
 //! Audio FFI functions
 //!
-//! Functions for loading sounds and controlling playback via channels and music.
+//! Functions for loading and synthesizing sounds and controlling playback via channels, music,
+//! streams and mix buses, plus listeners for positional sound and the music
+//! beat clock.
 //!
 //! Audio state is stored in ZRollbackState.audio, which is automatically rolled back
 //! during netcode rollback. FFI functions directly modify this state rather than
@@ -11,8 +13,13 @@ use wasmtime::Linker;
 
 use super::ZXGameContext;
 
+pub mod beat;
+pub mod bus;
+pub mod listener;
 pub mod music;
 pub mod sound;
+pub mod stream;
+pub mod synth;
 pub mod tracker;
 
 /// Music type constants for music_type() return value
@@ -35,7 +42,12 @@ pub(crate) fn clamp_safe(value: f32, min: f32, max: f32) -> f32 {
 /// Register audio FFI functions
 pub fn register(linker: &mut Linker<ZXGameContext>) -> Result<()> {
     sound::register(linker)?;
+    synth::register(linker)?;
     music::register(linker)?;
+    beat::register(linker)?;
+    stream::register(linker)?;
     tracker::register(linker)?;
+    bus::register(linker)?;
+    listener::register(linker)?;
     Ok(())
 }
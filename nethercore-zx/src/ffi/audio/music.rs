@@ -3,12 +3,13 @@
 //! Provides a unified interface for playing both PCM sounds and tracker modules as music.
 
 use anyhow::Result;
+use tracing::warn;
 use wasmtime::{Caller, Linker};
 
-use crate::state::tracker_flags;
+use crate::state::{MAX_MUSIC_LAYERS, MusicLayerState, tracker_flags};
 use crate::tracker::is_tracker_handle;
 
-use super::super::ZXGameContext;
+use super::super::{ZXGameContext, helpers::read_wasm_bytes};
 use super::{clamp_safe, music_type};
 
 /// Register music FFI functions
@@ -19,6 +20,8 @@ pub(super) fn register(linker: &mut Linker<ZXGameContext>) -> Result<()> {
     linker.func_wrap("env", "music_set_volume", music_set_volume)?;
     linker.func_wrap("env", "music_is_playing", music_is_playing)?;
     linker.func_wrap("env", "music_type", music_type_fn)?;
+    linker.func_wrap("env", "music_play_layers", music_play_layers)?;
+    linker.func_wrap("env", "music_layer_volume", music_layer_volume)?;
     Ok(())
 }
 
@@ -38,6 +41,7 @@ fn music_play(mut caller: Caller<'_, ZXGameContext>, handle: u32, volume: f32, l
         // Tracker music - stop PCM music first
         ctx.rollback.audio.music.sound = 0;
         ctx.rollback.audio.music.reset_position();
+        ctx.rollback.audio.music_layers = MusicLayerState::default();
 
         // Set up tracker state with raw handle (strip flag)
         let raw_handle = crate::tracker::raw_tracker_handle(handle);
@@ -61,6 +65,7 @@ fn music_play(mut caller: Caller<'_, ZXGameContext>, handle: u32, volume: f32, l
         tracker.bpm = initial_tempo;
         tracker.volume = (clamp_safe(volume, 0.0, 1.0) * 256.0) as u16;
         tracker.tick_sample_pos = 0;
+        tracker.rows_played = 0;
 
         let mut flags = tracker_flags::PLAYING;
         if looping != 0 {
@@ -89,6 +94,7 @@ fn music_play(mut caller: Caller<'_, ZXGameContext>, handle: u32, volume: f32, l
         music.looping = looping;
         music.volume = clamp_safe(volume, 0.0, 1.0);
         music.pan = 0.0; // Music is always centered
+        ctx.rollback.audio.music_layers = MusicLayerState::default();
     }
 }
 
@@ -101,6 +107,7 @@ fn music_stop(mut caller: Caller<'_, ZXGameContext>) {
     music.sound = 0;
     music.reset_position();
     music.looping = 0;
+    ctx.rollback.audio.music_layers = MusicLayerState::default();
 
     // Stop tracker music
     let tracker = &mut ctx.rollback.tracker;
@@ -184,3 +191,88 @@ fn music_type_fn(caller: Caller<'_, ZXGameContext>) -> u32 {
 
     music_type::NONE
 }
+
+/// Play layered PCM music made of stems that stay in lockstep
+///
+/// The stems share the music channel's playhead, so fading layers in and out
+/// with music_layer_volume() never puts them out of sync. Layer 0 starts at
+/// full volume and the other layers start silent. Stems should all be the same
+/// length as layer 0. Stops any other music, like music_play().
+///
+/// # Parameters
+/// - `sounds_ptr`: Pointer to an array of sound handles from load_sound()
+/// - `count`: Number of stems (1-4)
+/// - `volume`: Overall music volume, 0.0 to 1.0
+/// - `looping`: 1 = loop, 0 = play once
+fn music_play_layers(
+    mut caller: Caller<'_, ZXGameContext>,
+    sounds_ptr: u32,
+    count: u32,
+    volume: f32,
+    looping: u32,
+) {
+    let count = count as usize;
+    if count == 0 || count > MAX_MUSIC_LAYERS {
+        warn!(
+            "music_play_layers: count {} out of range (1-{})",
+            count, MAX_MUSIC_LAYERS
+        );
+        return;
+    }
+    let Some(bytes) = read_wasm_bytes(&caller, sounds_ptr, count * 4, "music_play_layers") else {
+        return;
+    };
+    let stems: Vec<u32> = bytes
+        .chunks_exact(4)
+        .map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+        .collect();
+    if stems[0] == 0 || stems.iter().any(|&s| is_tracker_handle(s)) {
+        warn!("music_play_layers: stems must be sound handles from load_sound()");
+        return;
+    }
+
+    let ctx = caller.data_mut();
+
+    // Stop tracker music
+    ctx.rollback.tracker.handle = 0;
+    ctx.rollback.tracker.flags = 0;
+
+    let music = &mut ctx.rollback.audio.music;
+    music.sound = stems[0];
+    music.reset_position();
+    music.looping = looping;
+    music.volume = clamp_safe(volume, 0.0, 1.0);
+    music.pan = 0.0;
+
+    let mut layers = MusicLayerState::default();
+    layers.sounds[..count].copy_from_slice(&stems);
+    layers.set_volume(0, 1.0, 0);
+    ctx.rollback.audio.music_layers = layers;
+}
+
+/// Fade a stem of layered music to a new volume
+///
+/// Fades advance once per tick with the music, so they roll back with game
+/// state. A new call replaces the layer's current fade.
+///
+/// # Parameters
+/// - `layer`: 0-3 (index into the stems passed to music_play_layers())
+/// - `volume`: Target volume, 0.0 to 1.0
+/// - `fade_ticks`: Length of the fade in ticks (0 = instant)
+fn music_layer_volume(
+    mut caller: Caller<'_, ZXGameContext>,
+    layer: u32,
+    volume: f32,
+    fade_ticks: u32,
+) {
+    let layer_idx = layer as usize;
+    if layer_idx >= MAX_MUSIC_LAYERS {
+        warn!("music_layer_volume: invalid layer {}", layer);
+        return;
+    }
+    caller.data_mut().rollback.audio.music_layers.set_volume(
+        layer_idx,
+        clamp_safe(volume, 0.0, 1.0),
+        fade_ticks,
+    );
+}
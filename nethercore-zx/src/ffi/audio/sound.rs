@@ -7,15 +7,22 @@ use tracing::{info, warn};
 use wasmtime::{Caller, Linker};
 
 use crate::audio::Sound;
-use crate::state::MAX_CHANNELS;
+use crate::state::{MAX_CHANNELS, ZXFFIState};
 
 use super::super::{ZXGameContext, guards::guard_init_only, helpers::read_wasm_i16s};
 use super::clamp_safe;
 
+/// Furthest ahead play_sound_at() can schedule, in ticks (about 4 seconds at 60 Hz)
+const MAX_SCHEDULE_TICKS: u32 = 256;
+
+/// Largest play_sound_at() offset within a tick, in samples (about 3 seconds)
+const MAX_SCHEDULE_OFFSET: u32 = 65_536;
+
 /// Register sound FFI functions
 pub(super) fn register(linker: &mut Linker<ZXGameContext>) -> Result<()> {
     linker.func_wrap("env", "load_sound", load_sound)?;
     linker.func_wrap("env", "play_sound", play_sound)?;
+    linker.func_wrap("env", "play_sound_at", play_sound_at)?;
     linker.func_wrap("env", "channel_play", channel_play)?;
     linker.func_wrap("env", "channel_set", channel_set)?;
     linker.func_wrap("env", "channel_stop", channel_stop)?;
@@ -48,9 +55,13 @@ fn load_sound(mut caller: Caller<'_, ZXGameContext>, data_ptr: u32, byte_len: u3
         return 0;
     };
 
-    let state = &mut caller.data_mut().ffi;
+    let handle = add_sound(&mut caller.data_mut().ffi, pcm_data);
+    info!("Loaded sound {} ({} samples)", handle, sample_count);
+    handle
+}
 
-    // Create Sound and add to sounds vec
+/// Store PCM data as a new sound and return its handle
+pub(super) fn add_sound(state: &mut ZXFFIState, pcm_data: Vec<i16>) -> u32 {
     let sound = Sound {
         data: std::sync::Arc::new(pcm_data),
     };
@@ -63,8 +74,6 @@ fn load_sound(mut caller: Caller<'_, ZXGameContext>, data_ptr: u32, byte_len: u3
         state.sounds.resize(handle as usize + 1, None);
     }
     state.sounds[handle as usize] = Some(sound);
-
-    info!("Loaded sound {} ({} samples)", handle, sample_count);
     handle
 }
 
@@ -80,13 +89,15 @@ fn play_sound(mut caller: Caller<'_, ZXGameContext>, sound: u32, volume: f32, pa
     let ctx = caller.data_mut();
 
     // Find first free channel (sound == 0 means channel is available)
-    for channel in ctx.rollback.audio.channels.iter_mut() {
+    let audio = &mut ctx.rollback.audio;
+    for (i, channel) in audio.channels.iter_mut().enumerate() {
         if channel.sound == 0 {
             channel.sound = sound;
             channel.reset_position();
             channel.looping = 0;
             channel.volume = clamp_safe(volume, 0.0, 1.0);
             channel.pan = clamp_safe(pan, -1.0, 1.0);
+            audio.schedule.clear(i);
             return;
         }
     }
@@ -95,6 +106,59 @@ fn play_sound(mut caller: Caller<'_, ZXGameContext>, sound: u32, volume: f32, pa
     warn!("play_sound: all channels busy, sound {} dropped", sound);
 }
 
+/// Play sound on next available channel, starting on an exact sample
+///
+/// For sounds that must line up precisely: engine loops, beat-synced hits,
+/// rapid fire. The start time is part of the rollback state, so a
+/// re-simulated tick schedules the same start.
+///
+/// # Parameters
+/// - `tick`: Tick whose audio the sound starts in (see tick_count()); past ticks start now
+/// - `offset_samples`: Samples (22.05kHz) into that tick's audio to start at
+/// - `sound`: Sound handle from load_sound()
+/// - `volume`: 0.0 to 1.0
+/// - `pan`: -1.0 (left) to 1.0 (right), 0.0 = center
+fn play_sound_at(
+    mut caller: Caller<'_, ZXGameContext>,
+    tick: u64,
+    offset_samples: u32,
+    sound: u32,
+    volume: f32,
+    pan: f32,
+) {
+    let ctx = caller.data_mut();
+    let ticks = tick.saturating_sub(ctx.game.tick_count);
+    if ticks > MAX_SCHEDULE_TICKS as u64 || offset_samples > MAX_SCHEDULE_OFFSET {
+        warn!(
+            "play_sound_at: start too far ahead (max {} ticks + {} samples), sound {} dropped",
+            MAX_SCHEDULE_TICKS, MAX_SCHEDULE_OFFSET, sound
+        );
+        return;
+    }
+
+    // Find first free channel (sound == 0 means channel is available)
+    let audio = &mut ctx.rollback.audio;
+    for (i, channel) in audio.channels.iter_mut().enumerate() {
+        if channel.sound == 0 {
+            channel.sound = sound;
+            channel.reset_position();
+            channel.looping = 0;
+            channel.volume = clamp_safe(volume, 0.0, 1.0);
+            channel.pan = clamp_safe(pan, -1.0, 1.0);
+            if ticks == 0 && tick < ctx.game.tick_count {
+                // Late: start now rather than skip into the sound
+                audio.schedule.clear(i);
+            } else {
+                audio.schedule.set(i, ticks as u32, offset_samples);
+            }
+            return;
+        }
+    }
+
+    // All channels busy - sound is dropped
+    warn!("play_sound_at: all channels busy, sound {} dropped", sound);
+}
+
 /// Play sound on specific channel
 ///
 /// For managed channels (positional/looping: engines, ambient, footsteps)
@@ -135,6 +199,7 @@ fn channel_play(
     ch.looping = looping;
     ch.volume = clamp_safe(volume, 0.0, 1.0);
     ch.pan = clamp_safe(pan, -1.0, 1.0);
+    ctx.rollback.audio.schedule.clear(channel_idx);
 }
 
 /// Update channel parameters (call every frame for positional audio)
@@ -172,4 +237,5 @@ fn channel_stop(mut caller: Caller<'_, ZXGameContext>, channel: u32) {
     ch.sound = 0;
     ch.reset_position();
     ch.looping = 0;
+    ctx.rollback.audio.schedule.clear(channel_idx);
 }
@@ -0,0 +1,126 @@
+//! Streamed music playback
+//!
+//! Plays long tracks loaded with `rom_stream()`. The stream is decoded in
+//! chunks by the mixer; only the playhead lives in ZRollbackState.
+
+use anyhow::Result;
+use tracing::warn;
+use wasmtime::{Caller, Linker};
+
+use crate::audio::SOURCE_SAMPLE_RATE;
+use crate::state::stream_flags;
+
+use super::super::ZXGameContext;
+use super::clamp_safe;
+
+/// Register stream FFI functions
+pub(super) fn register(linker: &mut Linker<ZXGameContext>) -> Result<()> {
+    linker.func_wrap("env", "stream_play", stream_play)?;
+    linker.func_wrap("env", "stream_stop", stream_stop)?;
+    linker.func_wrap("env", "stream_pause", stream_pause)?;
+    linker.func_wrap("env", "stream_seek", stream_seek)?;
+    linker.func_wrap("env", "stream_position", stream_position)?;
+    Ok(())
+}
+
+/// Play a stream from the beginning
+///
+/// Replaces any stream already playing. Streams play on their own voice, so
+/// stop `music_play()` music first if the stream replaces it.
+///
+/// # Parameters
+/// - `handle`: Stream handle from rom_stream()
+/// - `volume`: 0.0 to 1.0
+/// - `looping`: 1 = loop, 0 = play once
+fn stream_play(mut caller: Caller<'_, ZXGameContext>, handle: u32, volume: f32, looping: u32) {
+    let ctx = caller.data_mut();
+    if handle == 0 || handle as usize > ctx.ffi.streams.len() {
+        warn!("stream_play: invalid stream handle {}", handle);
+        return;
+    }
+
+    let stream = &mut ctx.rollback.stream;
+    let mut flags = stream_flags::PLAYING;
+    if looping != 0 {
+        flags |= stream_flags::LOOPING;
+    }
+
+    // If the same stream is already playing, just update volume and looping
+    if stream.handle == handle && stream.flags & stream_flags::PLAYING != 0 {
+        stream.volume = clamp_safe(volume, 0.0, 1.0);
+        stream.flags = flags | (stream.flags & stream_flags::PAUSED);
+        return;
+    }
+
+    stream.handle = handle;
+    stream.flags = flags;
+    stream.set_position(0);
+    stream.volume = clamp_safe(volume, 0.0, 1.0);
+    stream.cue = stream.cue.wrapping_add(1);
+}
+
+/// Stop the stream
+fn stream_stop(mut caller: Caller<'_, ZXGameContext>) {
+    let stream = &mut caller.data_mut().rollback.stream;
+    stream.handle = 0;
+    stream.flags = 0;
+    stream.set_position(0);
+    stream.cue = stream.cue.wrapping_add(1);
+}
+
+/// Pause or resume the stream
+///
+/// # Parameters
+/// - `paused`: 1 = pause, 0 = resume
+fn stream_pause(mut caller: Caller<'_, ZXGameContext>, paused: u32) {
+    let stream = &mut caller.data_mut().rollback.stream;
+
+    if paused != 0 {
+        stream.flags |= stream_flags::PAUSED;
+    } else {
+        stream.flags &= !stream_flags::PAUSED;
+    }
+}
+
+/// Move the stream playhead
+///
+/// Seeking past the end jumps to the end (the stream then stops, or wraps to
+/// the start if looping). Resumes a stream that had finished playing.
+///
+/// # Parameters
+/// - `seconds`: Position from the start of the stream
+fn stream_seek(mut caller: Caller<'_, ZXGameContext>, seconds: f32) {
+    let ctx = caller.data_mut();
+    let stream = &mut ctx.rollback.stream;
+
+    let Some(total_samples) = stream
+        .handle
+        .checked_sub(1)
+        .and_then(|i| ctx.ffi.streams.get(i as usize))
+        .map(|s| s.total_samples)
+    else {
+        warn!("stream_seek: no stream playing");
+        return;
+    };
+
+    let samples = clamp_safe(
+        seconds * SOURCE_SAMPLE_RATE as f32,
+        0.0,
+        total_samples as f32,
+    );
+    stream.set_position((samples as f64 * 4_294_967_296.0) as u64);
+    stream.flags |= stream_flags::PLAYING;
+    stream.cue = stream.cue.wrapping_add(1);
+}
+
+/// Get the stream playhead
+///
+/// # Returns
+/// Position in seconds from the start of the stream (0.0 if no stream)
+fn stream_position(caller: Caller<'_, ZXGameContext>) -> f32 {
+    let stream = &caller.data().rollback.stream;
+    if stream.handle == 0 {
+        return 0.0;
+    }
+    (stream.position() as f64 / 4_294_967_296.0 / SOURCE_SAMPLE_RATE as f64) as f32
+}
@@ -0,0 +1,58 @@
+//! Retro sound effect synthesis
+//!
+//! Generates sfxr-style effects from a small parameter block, so games can
+//! build their whole SFX set in `init()` instead of shipping a WAV per sound.
+
+use anyhow::Result;
+use tracing::{info, warn};
+use wasmtime::{Caller, Linker};
+
+use crate::audio::{SfxParams, synthesize_sfx};
+
+use super::super::{ZXGameContext, guards::guard_init_only, helpers::read_wasm_bytes};
+use super::sound::add_sound;
+
+/// Register sound synthesis FFI functions
+pub(super) fn register(linker: &mut Linker<ZXGameContext>) -> Result<()> {
+    linker.func_wrap("env", "synth_sfx", synth_sfx)?;
+    Ok(())
+}
+
+/// Synthesize a retro sound effect
+///
+/// Must be called during `init()`. The same parameters always produce the
+/// same sound.
+///
+/// # Parameters
+/// - `params_ptr`: Pointer to a 48-byte `SfxParams` (waveform, duty, frequency,
+///   slide, vibrato depth and rate, attack, sustain, decay, punch, volume, seed)
+///
+/// # Returns
+/// Sound handle for use with play_sound, channel_play, music_play (0 on invalid parameters)
+fn synth_sfx(mut caller: Caller<'_, ZXGameContext>, params_ptr: u32) -> u32 {
+    const FN_NAME: &str = "synth_sfx";
+    guard_init_only!(caller, FN_NAME);
+
+    let Some(bytes) = read_wasm_bytes(
+        &caller,
+        params_ptr,
+        std::mem::size_of::<SfxParams>(),
+        FN_NAME,
+    ) else {
+        return 0;
+    };
+    let params: SfxParams = bytemuck::pod_read_unaligned(&bytes);
+
+    let pcm_data = match synthesize_sfx(&params) {
+        Ok(pcm_data) => pcm_data,
+        Err(e) => {
+            warn!("{}: {}", FN_NAME, e);
+            return 0;
+        }
+    };
+
+    let sample_count = pcm_data.len();
+    let handle = add_sound(&mut caller.data_mut().ffi, pcm_data);
+    info!("Synthesized sound {} ({} samples)", handle, sample_count);
+    handle
+}
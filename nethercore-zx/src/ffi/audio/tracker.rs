@@ -247,6 +247,8 @@ fn load_tracker(mut caller: Caller<'_, ZXGameContext>, data_ptr: u32, data_len:
 
 /// Jump to a specific position (tracker only, no-op for PCM)
 ///
+/// The beat clock restarts counting from `row`.
+///
 /// # Parameters
 /// - `order`: Order position (0-based)
 /// - `row`: Row within the pattern (0-based)
@@ -258,6 +260,7 @@ fn music_jump(mut caller: Caller<'_, ZXGameContext>, order: u32, row: u32) {
     tracker.row = row as u16;
     tracker.tick = 0;
     tracker.tick_sample_pos = 0;
+    tracker.rows_played = row;
 }
 
 /// Get current music position
@@ -92,7 +92,7 @@ fn draw_billboard(mut caller: Caller<'_, ZXGameContext>, w: f32, h: f32, mode: u
         w,
         h,
         quad_mode,
-        [0.0, 0.0, 1.0, 1.0], // Full texture
+        state.atlas_uv([0.0, 0.0, 1.0, 1.0]), // Full texture (or atlas sprite)
         shading_state_index.0,
         view_idx,
     );
@@ -112,6 +112,7 @@ fn draw_billboard(mut caller: Caller<'_, ZXGameContext>, w: f32, h: f32, mode: u
 /// * `mode` — Billboard mode (1=spherical, 2=cylindrical Y, 3=cylindrical X, 4=cylindrical Z)
 ///
 /// This allows drawing a region of a sprite sheet as a billboard.
+/// After `atlas_bind()`, the source region is relative to the bound atlas sprite.
 /// Use `set_color()` to tint the billboard before drawing.
 fn draw_billboard_region(
     mut caller: Caller<'_, ZXGameContext>,
@@ -177,7 +178,7 @@ fn draw_billboard_region(
         w,
         h,
         quad_mode,
-        [src_x, src_y, src_x + src_w, src_y + src_h], // UV rect
+        state.atlas_uv([src_x, src_y, src_x + src_w, src_y + src_h]), // UV rect
         shading_state_index.0,
         view_idx,
     );
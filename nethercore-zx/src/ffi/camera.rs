@@ -1,18 +1,30 @@
 //! Camera FFI functions
 //!
-//! Functions for setting camera position and field of view.
+//! Functions for setting camera position and field of view, and shaking the
+//! camera with a trauma model.
 
 use anyhow::Result;
-use glam::{Mat4, Vec3};
+use glam::{EulerRot, Mat4, Vec3};
 use tracing::warn;
 use wasmtime::{Caller, Linker};
 
 use super::ZXGameContext;
+use crate::graphics::Viewport;
+
+/// Largest yaw and pitch offset at full trauma (radians)
+const MAX_SHAKE_TURN: f32 = 3.0 * std::f32::consts::PI / 180.0;
+
+/// Largest roll offset at full trauma (radians)
+const MAX_SHAKE_ROLL: f32 = 6.0 * std::f32::consts::PI / 180.0;
+
+/// Largest sideways and vertical offset at full trauma (world units)
+const MAX_SHAKE_OFFSET: f32 = 0.25;
 
 /// Register camera FFI functions
 pub fn register(linker: &mut Linker<ZXGameContext>) -> Result<()> {
     linker.func_wrap("env", "camera_set", camera_set)?;
     linker.func_wrap("env", "camera_fov", camera_fov)?;
+    linker.func_wrap("env", "camera_shake", camera_shake)?;
     linker.func_wrap("env", "push_view_matrix", push_view_matrix)?;
     linker.func_wrap("env", "push_projection_matrix", push_projection_matrix)?;
     Ok(())
@@ -77,6 +89,92 @@ fn camera_fov(mut caller: Caller<'_, ZXGameContext>, fov_degrees: f32) {
     state.current_proj_matrix = Some(proj);
 }
 
+/// Shake the current camera
+///
+/// # Arguments
+/// * `trauma` — Shake strength (0-1, clamped); the offset grows with its square
+/// * `frequency` — How many times per second the shake changes direction
+///
+/// Call after `camera_set()` each frame. The game owns the trauma value:
+/// raise it on hits and lower it over time, so it rolls back with everything
+/// else. The offsets come from smooth noise over the tick count, seeded by the
+/// current viewport, so re-simulated frames shake identically and split-screen
+/// players shake independently.
+fn camera_shake(mut caller: Caller<'_, ZXGameContext>, trauma: f32, frequency: f32) {
+    let game = &caller.data().game;
+    let time = game.tick_count as f64 * game.delta_time as f64;
+
+    if !trauma.is_finite() || !frequency.is_finite() || frequency <= 0.0 {
+        warn!(
+            "camera_shake: trauma must be finite and frequency positive (got {}, {})",
+            trauma, frequency
+        );
+        return;
+    }
+    if trauma <= 0.0 {
+        return;
+    }
+
+    let state = &mut caller.data_mut().ffi;
+    let view = state
+        .current_view_matrix
+        .or_else(|| state.view_matrices.last().copied())
+        .unwrap_or(Mat4::IDENTITY);
+    let seed = viewport_seed(&state.current_viewport);
+    state.current_view_matrix = Some(shake_view(view, trauma, frequency, time, seed));
+}
+
+/// Offset a view matrix by trauma-scaled noise sampled at `time` seconds
+///
+/// The offsets are applied in camera space, so the camera turns and slides
+/// around its own axes whichever way it faces.
+fn shake_view(view: Mat4, trauma: f32, frequency: f32, time: f64, seed: u32) -> Mat4 {
+    let shake = trauma.clamp(0.0, 1.0).powi(2);
+    // Keep the sample point small so long sessions stay precise
+    let t = (time * frequency as f64).rem_euclid(NOISE_PERIOD as f64) as f32;
+    let axis = |i: u32| shake * value_noise(seed.wrapping_add(i.wrapping_mul(0x9E37_79B9)), t);
+
+    let rotation = Mat4::from_euler(
+        EulerRot::YXZ,
+        axis(0) * MAX_SHAKE_TURN,
+        axis(1) * MAX_SHAKE_TURN,
+        axis(2) * MAX_SHAKE_ROLL,
+    );
+    let offset = Vec3::new(axis(3), axis(4), 0.0) * MAX_SHAKE_OFFSET;
+    rotation * Mat4::from_translation(offset) * view
+}
+
+/// Noise lattice points before the pattern repeats
+const NOISE_PERIOD: u32 = 4096;
+
+/// Smooth 1D value noise in -1..1, with a random value at every integer `t`
+fn value_noise(seed: u32, t: f32) -> f32 {
+    let cell = t.floor();
+    let f = t - cell;
+    let i = cell as u32;
+    let a = lattice(seed, i % NOISE_PERIOD);
+    let b = lattice(seed, (i + 1) % NOISE_PERIOD);
+    // Smoothstep so the camera eases between lattice points
+    let s = f * f * (3.0 - 2.0 * f);
+    a + (b - a) * s
+}
+
+/// Hash a lattice point to -1..1
+fn lattice(seed: u32, i: u32) -> f32 {
+    let mut h = i.wrapping_mul(0x27D4_EB2D) ^ seed;
+    h ^= h >> 15;
+    h = h.wrapping_mul(0x85EB_CA6B);
+    h ^= h >> 13;
+    h = h.wrapping_mul(0xC2B2_AE35);
+    h ^= h >> 16;
+    (h >> 8) as f32 / (1u32 << 23) as f32 - 1.0
+}
+
+/// Seed that differs for each split-screen viewport
+fn viewport_seed(vp: &Viewport) -> u32 {
+    vp.x.wrapping_mul(0x0001_0193) ^ vp.y.wrapping_mul(0x0100_0193) ^ 0x5348_4B45
+}
+
 /// Push a custom view matrix to the pool, returning its index
 ///
 /// For advanced rendering techniques (multiple cameras, render-to-texture, etc.)
@@ -151,3 +249,64 @@ fn push_projection_matrix(
 
     state.current_proj_matrix = Some(matrix);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shake_is_deterministic_and_scales_with_trauma() {
+        let view = Mat4::look_at_rh(Vec3::new(0.0, 2.0, 5.0), Vec3::ZERO, Vec3::Y);
+
+        // Same tick, same viewport: identical shake
+        let a = shake_view(view, 0.8, 20.0, 1.25, 7);
+        assert_eq!(a, shake_view(view, 0.8, 20.0, 1.25, 7));
+        assert_ne!(a, view);
+
+        // No trauma: no shake
+        assert_eq!(shake_view(view, 0.0, 20.0, 1.25, 7), view);
+
+        // Half trauma shakes a quarter as far (camera position offset)
+        let origin = |m: Mat4| m.inverse().transform_point3(Vec3::ZERO);
+        let full = origin(shake_view(view, 1.0, 20.0, 1.25, 7)) - origin(view);
+        let half = origin(shake_view(view, 0.5, 20.0, 1.25, 7)) - origin(view);
+        assert!(full.length() > 0.0);
+        assert!((half.length() * 4.0 - full.length()).abs() < 1e-3);
+        assert!(full.length() <= MAX_SHAKE_OFFSET * 2f32.sqrt() + 1e-3);
+    }
+
+    #[test]
+    fn test_shake_differs_per_viewport() {
+        let left = viewport_seed(&Viewport {
+            x: 0,
+            y: 0,
+            width: 480,
+            height: 540,
+        });
+        let right = viewport_seed(&Viewport {
+            x: 480,
+            y: 0,
+            width: 480,
+            height: 540,
+        });
+        assert_ne!(left, right);
+        assert_ne!(
+            shake_view(Mat4::IDENTITY, 1.0, 20.0, 0.5, left),
+            shake_view(Mat4::IDENTITY, 1.0, 20.0, 0.5, right)
+        );
+    }
+
+    #[test]
+    fn test_value_noise_is_smooth_and_bounded() {
+        let mut prev = value_noise(3, 0.0);
+        for step in 1..2000 {
+            let n = value_noise(3, step as f32 * 0.01);
+            assert!((-1.0..=1.0).contains(&n));
+            assert!((n - prev).abs() < 0.05);
+            prev = n;
+        }
+        // Lattice wraps without a jump
+        let end = value_noise(3, NOISE_PERIOD as f32 - 1e-3);
+        assert!((end - value_noise(3, 0.0)).abs() < 0.01);
+    }
+}
@@ -0,0 +1,261 @@
+//! Chunk streaming FFI functions
+//!
+//! Register world chunks backed by ROM data during init(), then call
+//! `chunk_update()` from `update()` with the camera or player position. The
+//! host copies nearby chunks into a slot pool in game memory and frees slots
+//! that fall out of range. Residency is rolled back in ZRollbackState.
+
+use anyhow::Result;
+use tracing::warn;
+use wasmtime::{Caller, Linker};
+
+use super::ZXGameContext;
+use super::guards::guard_init_only;
+use super::helpers::{checked_mul, get_memory};
+use super::rom::read_string_id;
+use crate::state::{ChunkDef, MAX_CHUNK_SLOTS, MAX_CHUNKS};
+
+/// Register chunk streaming FFI functions
+pub fn register(linker: &mut Linker<ZXGameContext>) -> Result<()> {
+    linker.func_wrap("env", "chunk_pool", chunk_pool)?;
+    linker.func_wrap("env", "chunk_grid", chunk_grid)?;
+    linker.func_wrap("env", "chunk_register", chunk_register)?;
+    linker.func_wrap("env", "chunk_update", chunk_update)?;
+    linker.func_wrap("env", "chunk_data", chunk_data)?;
+    linker.func_wrap("env", "chunk_loaded_count", chunk_loaded_count)?;
+    Ok(())
+}
+
+/// Set the slot pool streamed chunks are copied into (init-only)
+///
+/// # Arguments
+/// * `ptr` — Start of a `slot_size * slot_count` byte buffer in game memory
+/// * `slot_size` — Bytes per slot; every registered chunk must fit in one
+/// * `slot_count` — Number of slots (1-64)
+///
+/// Must be called before `chunk_register()`. The game must not use the buffer
+/// for anything else. Returns 1 on success, 0 on failure.
+fn chunk_pool(
+    mut caller: Caller<'_, ZXGameContext>,
+    ptr: u32,
+    slot_size: u32,
+    slot_count: u32,
+) -> u32 {
+    const FN_NAME: &str = "chunk_pool";
+
+    guard_init_only!(caller, FN_NAME);
+
+    if slot_size == 0 || slot_count == 0 || slot_count as usize > MAX_CHUNK_SLOTS {
+        warn!(
+            "{}: need slot_size > 0 and 1-{} slots (got {} x {})",
+            FN_NAME, MAX_CHUNK_SLOTS, slot_count, slot_size
+        );
+        return 0;
+    }
+    if !caller.data().ffi.chunk_grid.chunks.is_empty() {
+        warn!("{}: must be called before chunk_register()", FN_NAME);
+        return 0;
+    }
+
+    let Some(memory) = get_memory(&caller, FN_NAME) else {
+        return 0;
+    };
+    let Some(size) = checked_mul(slot_size, slot_count, FN_NAME, "pool size") else {
+        return 0;
+    };
+    let end = ptr as usize + size as usize;
+    if end > memory.data_size(&caller) {
+        warn!(
+            "{}: pool ({} bytes at {}) exceeds memory size {}",
+            FN_NAME,
+            size,
+            ptr,
+            memory.data_size(&caller)
+        );
+        return 0;
+    }
+
+    let grid = &mut caller.data_mut().ffi.chunk_grid;
+    grid.pool_ptr = ptr;
+    grid.slot_size = slot_size;
+    grid.slot_count = slot_count;
+    1
+}
+
+/// Set the chunk grid and streaming distances (init-only)
+///
+/// # Arguments
+/// * `cell_size` — World units per grid cell (default 64); cell (x, z) spans
+///   `x * cell_size` to `(x + 1) * cell_size` on the X and Z axes
+/// * `load_radius` — Chunks whose center comes this close to the focus are
+///   loaded (default 96)
+/// * `unload_radius` — Loaded chunks further than this are unloaded
+///   (default 128, at least `load_radius`)
+///
+/// Returns 1 on success, 0 on failure.
+fn chunk_grid(
+    mut caller: Caller<'_, ZXGameContext>,
+    cell_size: f32,
+    load_radius: f32,
+    unload_radius: f32,
+) -> u32 {
+    const FN_NAME: &str = "chunk_grid";
+
+    guard_init_only!(caller, FN_NAME);
+
+    let valid = cell_size.is_finite()
+        && cell_size > 0.0
+        && load_radius.is_finite()
+        && load_radius >= 0.0
+        && unload_radius.is_finite()
+        && unload_radius >= load_radius;
+    if !valid {
+        warn!(
+            "{}: need cell_size > 0 and 0 <= load_radius <= unload_radius (got {}, {}, {})",
+            FN_NAME, cell_size, load_radius, unload_radius
+        );
+        return 0;
+    }
+
+    let grid = &mut caller.data_mut().ffi.chunk_grid;
+    grid.cell_size = cell_size;
+    grid.load_radius = load_radius;
+    grid.unload_radius = unload_radius;
+    1
+}
+
+/// Register the chunk at grid cell (x, z) (init-only)
+///
+/// # Arguments
+/// * `x`, `z` — Grid cell
+/// * `id_ptr`, `id_len` — ROM data asset ID holding the chunk's bytes
+///
+/// The asset must fit in one pool slot. Returns a chunk handle (>0) on
+/// success, 0 on failure.
+fn chunk_register(
+    mut caller: Caller<'_, ZXGameContext>,
+    x: i32,
+    z: i32,
+    id_ptr: u32,
+    id_len: u32,
+) -> u32 {
+    const FN_NAME: &str = "chunk_register";
+
+    guard_init_only!(caller, FN_NAME);
+
+    let Some(id) = read_string_id(&caller, id_ptr, id_len) else {
+        warn!("{}: failed to read asset ID", FN_NAME);
+        return 0;
+    };
+
+    let state = &mut caller.data_mut().ffi;
+    let grid = &state.chunk_grid;
+    if grid.slot_count == 0 {
+        warn!("{}: call chunk_pool() first", FN_NAME);
+        return 0;
+    }
+    if grid.chunks.len() >= MAX_CHUNKS {
+        warn!("{}: maximum chunk count {} exceeded", FN_NAME, MAX_CHUNKS);
+        return 0;
+    }
+    if grid.find(x, z).is_some() {
+        warn!("{}: cell ({}, {}) already registered", FN_NAME, x, z);
+        return 0;
+    }
+
+    let Some(data) = state
+        .data_pack
+        .as_ref()
+        .and_then(|data_pack| data_pack.find_data(&id))
+    else {
+        warn!("{}: data '{}' not found in data pack", FN_NAME, id);
+        return 0;
+    };
+    if data.data.len() > grid.slot_size as usize {
+        warn!(
+            "{}: data '{}' is {} bytes, slots hold {}",
+            FN_NAME,
+            id,
+            data.data.len(),
+            grid.slot_size
+        );
+        return 0;
+    }
+
+    state.chunk_grid.chunks.push(ChunkDef { x, z, data_id: id });
+    state.chunk_grid.chunks.len() as u32
+}
+
+/// Stream chunks around a focus point
+///
+/// # Arguments
+/// * `x`, `z` — Focus position in world space (usually the camera or the
+///   local player)
+///
+/// Call from `update()` so every client streams the same chunks on the same
+/// tick. Chunks are copied into their slots before this returns; each load
+/// and unload is also queued for `event_poll()`.
+///
+/// Returns the number of loaded chunks.
+fn chunk_update(mut caller: Caller<'_, ZXGameContext>, x: f32, z: f32) -> u32 {
+    const FN_NAME: &str = "chunk_update";
+
+    if !x.is_finite() || !z.is_finite() {
+        warn!("{}: focus must be finite", FN_NAME);
+        return 0;
+    }
+    let Some(memory) = get_memory(&caller, FN_NAME) else {
+        return 0;
+    };
+
+    let (mem, ctx) = memory.data_and_store_mut(&mut caller);
+    let grid = &ctx.ffi.chunk_grid;
+    if grid.slot_count == 0 {
+        warn!("{}: call chunk_pool() during init() first", FN_NAME);
+        return 0;
+    }
+
+    for event in ctx.rollback.chunks.update(grid, [x, z]) {
+        if event.loaded {
+            let id = &grid.chunks[event.handle as usize - 1].data_id;
+            let Some(data) = ctx
+                .ffi
+                .data_pack
+                .as_ref()
+                .and_then(|data_pack| data_pack.find_data(id))
+            else {
+                warn!("{}: data '{}' not found in data pack", FN_NAME, id);
+                continue;
+            };
+            let start = grid.slot_ptr(event.slot) as usize;
+            let Some(slot) = mem.get_mut(start..start + data.data.len()) else {
+                warn!("{}: slot {} out of bounds", FN_NAME, event.slot);
+                continue;
+            };
+            slot.copy_from_slice(&data.data);
+        }
+        ctx.game.events.push(event.into());
+    }
+
+    ctx.rollback.chunks.loaded_count()
+}
+
+/// Get a loaded chunk's data
+///
+/// # Arguments
+/// * `x`, `z` — Grid cell
+///
+/// Returns a pointer to the chunk's slot in the pool, or 0 if no chunk is
+/// registered at the cell or it isn't loaded.
+fn chunk_data(caller: Caller<'_, ZXGameContext>, x: i32, z: i32) -> u32 {
+    let ctx = caller.data();
+    let grid = &ctx.ffi.chunk_grid;
+    grid.find(x, z)
+        .and_then(|handle| ctx.rollback.chunks.slot_of(handle))
+        .map_or(0, |slot| grid.slot_ptr(slot))
+}
+
+/// Get the number of loaded chunks
+fn chunk_loaded_count(caller: Caller<'_, ZXGameContext>) -> u32 {
+    caller.data().rollback.chunks.loaded_count()
+}
@@ -9,21 +9,31 @@
 use anyhow::{Result, bail};
 use tracing::warn;
 use wasmtime::{Caller, Linker};
+use zx_common::TextureFormat;
 
 use super::ZXGameContext;
 
 use crate::console::TICK_RATES;
+use crate::state::MAX_PALETTE_COLORS;
+
+/// Highest vertex snap precision accepted by retro_mode() (1024 rows, finer than 540p)
+const MAX_VERTEX_SNAP_BITS: u32 = 10;
 
 /// Register configuration FFI functions
 pub fn register(linker: &mut Linker<ZXGameContext>) -> Result<()> {
     linker.func_wrap("env", "set_tick_rate", set_tick_rate)?;
     linker.func_wrap("env", "set_clear_color", set_clear_color)?;
+    linker.func_wrap("env", "retro_mode", retro_mode)?;
+    linker.func_wrap("env", "palette_mode", palette_mode)?;
     Ok(())
 }
 
 /// Set the tick rate (frames per second for update loop)
 ///
-/// Valid indices: 0=24fps, 1=30fps, 2=60fps (default), 3=120fps
+/// Valid indices (`tick_rate::*` in the SDK): 0=24fps, 1=30fps, 2=60fps (default), 3=120fps
+///
+/// Applied to local sessions once init() returns; online sessions keep the
+/// tick rate all peers agreed on.
 ///
 /// **Init-only:** Must be called during `init()`. Calls outside init are ignored.
 /// **Single-call:** Can only be called once. Second call traps with an error.
@@ -88,3 +98,117 @@ fn set_clear_color(mut caller: Caller<'_, ZXGameContext>, color: u32) -> Result<
 
     Ok(())
 }
+
+/// Enable PS1-style retro rendering for all 3D meshes
+///
+/// - vertex_snap_bits: 0 = off, N = snap screen positions to a grid 2^N cells tall
+///   (square cells, so 8 gives roughly 455x256)
+/// - affine_textures: 0 = perspective-correct (default), 1 = affine texture mapping
+///
+/// **Init-only:** Must be called during `init()`. Calls outside init are ignored.
+/// **Single-call:** Can only be called once. Second call traps with an error.
+fn retro_mode(
+    mut caller: Caller<'_, ZXGameContext>,
+    vertex_snap_bits: u32,
+    affine_textures: u32,
+) -> Result<()> {
+    // Check if we're in init phase
+    if !caller.data().game.in_init {
+        warn!("retro_mode() called outside init() - ignored");
+        return Ok(());
+    }
+
+    let state = &mut caller.data_mut().ffi;
+
+    // Check for duplicate call
+    if state.init_config.retro_mode_set {
+        bail!(
+            "retro_mode() called twice - each config function can only be called once during init()"
+        );
+    }
+    state.init_config.retro_mode_set = true;
+
+    if vertex_snap_bits > MAX_VERTEX_SNAP_BITS {
+        bail!(
+            "retro_mode({}) invalid vertex_snap_bits - must be 0-{}",
+            vertex_snap_bits,
+            MAX_VERTEX_SNAP_BITS
+        );
+    }
+
+    let snap_bits = vertex_snap_bits as u8;
+    let affine = affine_textures != 0;
+    state.init_config.vertex_snap_bits = snap_bits;
+    state.init_config.affine_textures = affine;
+    state.init_config.modified = true;
+
+    // Retro flags live in the shading state so every later draw inherits them
+    state.update_retro_mode(snap_bits, affine);
+
+    Ok(())
+}
+
+/// Constrain the final image to a palette read from a texture
+///
+/// Every pixel of the texture (row-major, alpha ignored) becomes one palette
+/// color, up to 256. Each presented pixel is replaced by the nearest palette
+/// color, optionally with 4x4 ordered dithering.
+///
+/// - texture: RGBA8 texture handle loaded during init (0 traps)
+/// - dither: 0 = nearest color only, 1 = ordered dithering
+///
+/// **Init-only:** Must be called during `init()`. Calls outside init are ignored.
+/// **Single-call:** Can only be called once. Second call traps with an error.
+fn palette_mode(mut caller: Caller<'_, ZXGameContext>, texture: u32, dither: u32) -> Result<()> {
+    // Check if we're in init phase
+    if !caller.data().game.in_init {
+        warn!("palette_mode() called outside init() - ignored");
+        return Ok(());
+    }
+
+    let state = &mut caller.data_mut().ffi;
+
+    // Check for duplicate call
+    if state.init_config.palette_set {
+        bail!(
+            "palette_mode() called twice - each config function can only be called once during init()"
+        );
+    }
+    state.init_config.palette_set = true;
+
+    // Textures loaded during init are still pending, so their pixels are on hand
+    let Some(pending) = state.pending_textures.iter().find(|t| t.handle == texture) else {
+        bail!(
+            "palette_mode({}) invalid - texture must be loaded during init()",
+            texture
+        );
+    };
+    if pending.format != TextureFormat::Rgba8 {
+        bail!(
+            "palette_mode({}) invalid - palette texture must be uncompressed RGBA8 (set quality = \"lossless\" on it)",
+            texture
+        );
+    }
+
+    let pixel_count = (pending.width * pending.height) as usize;
+    if pixel_count == 0 || pixel_count > MAX_PALETTE_COLORS {
+        bail!(
+            "palette_mode({}) invalid - palette has {} colors, must be 1-{}",
+            texture,
+            pixel_count,
+            MAX_PALETTE_COLORS
+        );
+    }
+
+    let palette = pending
+        .data
+        .chunks_exact(4)
+        .map(|px| u32::from_be_bytes([px[0], px[1], px[2], 0xFF]))
+        .collect();
+
+    state.init_config.palette = palette;
+    state.init_config.palette_dither = dither != 0;
+    state.init_config.modified = true;
+
+    Ok(())
+}
@@ -0,0 +1,215 @@
+//! Shared cursor FFI functions
+//!
+//! Every player has a cursor moved by their left stick and D-pad, or by the
+//! mouse for player 0. Positions are part of the rollback state and update
+//! every tick whether or not the cursor is shown, so menus and RTS-style
+//! pointing behave the same in every game and on every input device.
+
+use anyhow::Result;
+use tracing::warn;
+use wasmtime::{Caller, Linker};
+
+use super::ZXGameContext;
+use super::draw_2d::{push_line, push_rect};
+use super::helpers::get_memory;
+use crate::graphics::{BlendMode, PassConfig, Viewport};
+use crate::state::{CursorState, ZXFFIState, cursor_style};
+use nethercore_core::wasm::MAX_PLAYERS;
+
+/// Highest accepted stick speed, in pixels per second
+const MAX_SPEED: f32 = 10_000.0;
+
+/// Cursor fill color per player (0xRRGGBBAA)
+const PLAYER_COLORS: [u32; MAX_PLAYERS] = [
+    0xFFFFFFFF, 0xFF6060FF, 0x60A0FFFF, 0x60E060FF, 0xFFD040FF, 0xD070FFFF, 0x40E0E0FF, 0xFFA060FF,
+];
+
+/// Outline color drawn under the fill
+const OUTLINE_COLOR: u32 = 0x000000FF;
+
+/// Register cursor FFI functions
+pub fn register(linker: &mut Linker<ZXGameContext>) -> Result<()> {
+    linker.func_wrap("env", "cursor_show", cursor_show)?;
+    linker.func_wrap("env", "cursor_pos", cursor_pos)?;
+    linker.func_wrap("env", "cursor_speed", cursor_speed)?;
+    Ok(())
+}
+
+/// Show the host-drawn cursors
+///
+/// # Arguments
+/// * `style` — Cursor style (0=hidden, 1=arrow, 2=hand, 3=crosshair)
+///
+/// One cursor is drawn per connected player, tinted by player, over
+/// everything the game draws. The style is rollback state, so set it from
+/// `update()`.
+fn cursor_show(mut caller: Caller<'_, ZXGameContext>, style: u32) {
+    if style > cursor_style::CROSSHAIR {
+        warn!("cursor_show: invalid style {}", style);
+        return;
+    }
+    caller.data_mut().rollback.cursor.style = style;
+}
+
+/// Get a player's cursor position
+///
+/// # Arguments
+/// * `player` — Player index (0-7)
+/// * `out_x` — Pointer to write X (screen pixels, 0-959)
+/// * `out_y` — Pointer to write Y (screen pixels, 0-539)
+///
+/// Cursors start in the center of the screen.
+fn cursor_pos(mut caller: Caller<'_, ZXGameContext>, player: u32, out_x: u32, out_y: u32) {
+    const FN_NAME: &str = "cursor_pos";
+
+    let Some(cursor) = caller.data().rollback.cursor.cursors.get(player as usize) else {
+        warn!(
+            "{}: invalid player {} (max {})",
+            FN_NAME,
+            player,
+            MAX_PLAYERS - 1
+        );
+        return;
+    };
+    let [x, y] = cursor.position;
+    let Some(memory) = get_memory(&caller, FN_NAME) else {
+        return;
+    };
+
+    let data = memory.data_mut(&mut caller);
+    let (x_ptr, y_ptr) = (out_x as usize, out_y as usize);
+    if x_ptr + 4 > data.len() || y_ptr + 4 > data.len() {
+        warn!("{}: output pointers out of bounds", FN_NAME);
+        return;
+    }
+    data[x_ptr..x_ptr + 4].copy_from_slice(&x.to_le_bytes());
+    data[y_ptr..y_ptr + 4].copy_from_slice(&y.to_le_bytes());
+}
+
+/// Set how fast sticks move the cursors
+///
+/// # Arguments
+/// * `speed` — Pixels per second at full tilt (default 600, max 10000)
+/// * `acceleration` — Speed multiplier gained per second of holding the
+///   stick, up to 3× (default 2.0, 0 = constant speed)
+///
+/// The mouse always moves the cursor 1:1.
+fn cursor_speed(mut caller: Caller<'_, ZXGameContext>, speed: f32, acceleration: f32) {
+    if !(speed.is_finite() && speed > 0.0 && speed <= MAX_SPEED) {
+        warn!("cursor_speed: invalid speed {} (0-{})", speed, MAX_SPEED);
+        return;
+    }
+    if !(acceleration.is_finite() && acceleration >= 0.0) {
+        warn!("cursor_speed: invalid acceleration {}", acceleration);
+        return;
+    }
+    let cursor = &mut caller.data_mut().rollback.cursor;
+    cursor.speed = speed;
+    cursor.acceleration = acceleration;
+}
+
+/// Record the cursors of every connected player
+///
+/// Called by the host after the game's `render()`. Draws in a new pass over
+/// the whole canvas; color, bound texture and 2D state are preserved.
+pub(crate) fn draw_cursors(state: &mut ZXFFIState, cursors: &CursorState) {
+    if cursors.style == cursor_style::HIDDEN || cursors.players == 0 {
+        return;
+    }
+
+    state.current_pass_id += 1;
+    state.pass_configs.push(PassConfig::standard(false));
+
+    let saved_color = state.current_shading_state.color_rgba8;
+    let saved_texture = state.bound_textures[0];
+    let saved_atlas_sprite = state.bound_atlas_sprite;
+    let saved_z_index = state.current_z_index;
+    let saved_viewport = state.current_viewport;
+    let saved_blend_mode = state.current_blend_mode_2d;
+    let saved_clip_stack = std::mem::take(&mut state.clip_stack);
+    state.current_z_index = u32::MAX;
+    state.current_viewport = Viewport::FULLSCREEN;
+    state.current_blend_mode_2d = BlendMode::Alpha;
+
+    // Player 0 last, so it stays on top
+    for (player, cursor) in cursors.cursors.iter().enumerate().rev() {
+        if !cursors.is_connected(player) {
+            continue;
+        }
+        let [x, y] = cursor.position;
+        let color = PLAYER_COLORS[player];
+        match cursors.style {
+            cursor_style::ARROW => draw_arrow(state, x, y, color),
+            cursor_style::HAND => draw_hand(state, x, y, color),
+            _ => draw_crosshair(state, x, y, color),
+        }
+    }
+
+    state.update_color(saved_color);
+    state.bound_textures[0] = saved_texture;
+    state.bound_atlas_sprite = saved_atlas_sprite;
+    state.current_z_index = saved_z_index;
+    state.current_viewport = saved_viewport;
+    state.current_blend_mode_2d = saved_blend_mode;
+    state.clip_stack = saved_clip_stack;
+}
+
+/// Outlined line segments (drawn thick in the outline color, then thin in `color`)
+fn draw_outlined(state: &mut ZXFFIState, segments: &[[f32; 4]], color: u32) {
+    for (pass_color, thickness) in [(OUTLINE_COLOR, 4.0), (color, 2.0)] {
+        state.update_color(pass_color);
+        for &[x1, y1, x2, y2] in segments {
+            push_line(state, x1, y1, x2, y2, thickness);
+        }
+    }
+}
+
+/// Arrow with its tip on the cursor position
+fn draw_arrow(state: &mut ZXFFIState, x: f32, y: f32, color: u32) {
+    let segments = [
+        [x, y, x, y + 16.0],
+        [x, y + 16.0, x + 4.0, y + 12.0],
+        [x + 4.0, y + 12.0, x + 11.0, y + 11.0],
+        [x + 11.0, y + 11.0, x, y],
+        // Filled-looking center
+        [x + 2.0, y + 5.0, x + 2.0, y + 12.0],
+        [x + 2.0, y + 5.0, x + 7.0, y + 10.0],
+    ];
+    draw_outlined(state, &segments, color);
+}
+
+/// Pointing finger with its tip on the cursor position
+fn draw_hand(state: &mut ZXFFIState, x: f32, y: f32, color: u32) {
+    for (rect_color, grow) in [(OUTLINE_COLOR, 1.0), (color, 0.0)] {
+        state.update_color(rect_color);
+        // Finger
+        push_rect(
+            state,
+            x - 2.0 - grow,
+            y - grow,
+            4.0 + grow * 2.0,
+            8.0 + grow * 2.0,
+        );
+        // Palm
+        push_rect(
+            state,
+            x - 4.0 - grow,
+            y + 7.0 - grow,
+            11.0 + grow * 2.0,
+            9.0 + grow * 2.0,
+        );
+    }
+}
+
+/// Crosshair centered on the cursor position
+fn draw_crosshair(state: &mut ZXFFIState, x: f32, y: f32, color: u32) {
+    let segments = [
+        [x - 9.0, y, x - 3.0, y],
+        [x + 3.0, y, x + 9.0, y],
+        [x, y - 9.0, x, y - 3.0],
+        [x, y + 3.0, x, y + 9.0],
+    ];
+    draw_outlined(state, &segments, color);
+    state.update_color(color);
+    push_rect(state, x - 1.0, y - 1.0, 2.0, 2.0);
+}
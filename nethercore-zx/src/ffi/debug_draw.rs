@@ -0,0 +1,331 @@
+//! Debug draw FFI functions (gizmos)
+//!
+//! World-space lines, spheres, boxes and labels for visualizing hitboxes,
+//! trigger volumes and other invisible game state. Everything is a no-op
+//! unless the F4 debug inspector is open, so calls can stay in release builds
+//! without affecting what players see.
+
+use anyhow::Result;
+use glam::{Mat4, Vec2, Vec3, Vec4};
+use tracing::warn;
+use wasmtime::{Caller, Linker};
+
+use super::ZXGameContext;
+use super::draw_2d::{push_line, push_text};
+use super::helpers::read_wasm_bytes;
+use crate::state::ZXFFIState;
+
+/// Z-index for gizmos: above game content, below host overlays like the scoreboard
+const DEBUG_DRAW_Z_INDEX: u32 = u32::MAX - 1;
+
+/// Gizmo line thickness in pixels
+const LINE_THICKNESS: f32 = 1.0;
+
+/// Segments per great circle in `debug_sphere()`
+const SPHERE_SEGMENTS: u32 = 24;
+
+/// Points closer than this to the camera plane (in clip-space w) are clipped
+pub(super) const NEAR_EPSILON: f32 = 1e-4;
+
+/// Register debug draw FFI functions
+pub fn register(linker: &mut Linker<ZXGameContext>) -> Result<()> {
+    linker.func_wrap("env", "debug_line3d", debug_line3d)?;
+    linker.func_wrap("env", "debug_sphere", debug_sphere)?;
+    linker.func_wrap("env", "debug_aabb", debug_aabb)?;
+    linker.func_wrap("env", "debug_text3d", debug_text3d)?;
+    Ok(())
+}
+
+/// Projects world-space points into pixels of the current viewport
+pub(super) struct Projector {
+    view_proj: Mat4,
+    width: f32,
+    height: f32,
+}
+
+impl Projector {
+    /// Uses the current (or last pushed) camera and the current viewport
+    pub(super) fn from_state(state: &ZXFFIState) -> Self {
+        let view = state
+            .current_view_matrix
+            .or_else(|| state.view_matrices.last().copied())
+            .unwrap_or(Mat4::IDENTITY);
+        let proj = state
+            .current_proj_matrix
+            .or_else(|| state.proj_matrices.last().copied())
+            .unwrap_or(Mat4::IDENTITY);
+        let vp = state.current_viewport;
+        Self {
+            view_proj: proj * view,
+            width: vp.width as f32,
+            height: vp.height as f32,
+        }
+    }
+
+    /// Viewport size in pixels
+    pub(super) fn size(&self) -> Vec2 {
+        Vec2::new(self.width, self.height)
+    }
+
+    /// Clip-space position of `p`
+    pub(super) fn clip(&self, p: Vec3) -> Vec4 {
+        self.view_proj * p.extend(1.0)
+    }
+
+    /// Viewport pixels of a clip-space position in front of the camera
+    pub(super) fn to_screen(&self, clip: Vec4) -> Vec2 {
+        let ndc = clip.truncate() / clip.w;
+        Vec2::new(
+            (ndc.x * 0.5 + 0.5) * self.width,
+            (0.5 - ndc.y * 0.5) * self.height,
+        )
+    }
+
+    /// Screen position of `p`, or `None` if it is behind the camera
+    fn point(&self, p: Vec3) -> Option<Vec2> {
+        let clip = self.clip(p);
+        (clip.w > NEAR_EPSILON).then(|| self.to_screen(clip))
+    }
+
+    /// Screen endpoints of segment `a`-`b`, clipped against the camera plane
+    fn segment(&self, a: Vec3, b: Vec3) -> Option<(Vec2, Vec2)> {
+        let mut ca = self.view_proj * a.extend(1.0);
+        let mut cb = self.view_proj * b.extend(1.0);
+        match (ca.w > NEAR_EPSILON, cb.w > NEAR_EPSILON) {
+            (false, false) => return None,
+            (true, false) => cb = ca + (cb - ca) * ((ca.w - NEAR_EPSILON) / (ca.w - cb.w)),
+            (false, true) => ca = cb + (ca - cb) * ((cb.w - NEAR_EPSILON) / (cb.w - ca.w)),
+            (true, true) => {}
+        }
+        Some((self.to_screen(ca), self.to_screen(cb)))
+    }
+}
+
+/// Runs `draw` with the gizmo color and z-index, restoring both afterwards
+///
+/// Does nothing unless the debug inspector is open.
+pub(super) fn with_gizmos(
+    caller: &mut Caller<'_, ZXGameContext>,
+    color: u32,
+    draw: impl FnOnce(&mut ZXFFIState, &Projector),
+) {
+    if !caller.data().game.debug_inspector_open {
+        return;
+    }
+    let state = &mut caller.data_mut().ffi;
+
+    let saved_color = state.current_shading_state.color_rgba8;
+    let saved_z_index = state.current_z_index;
+    state.current_z_index = DEBUG_DRAW_Z_INDEX;
+    state.update_color(color);
+
+    let projector = Projector::from_state(state);
+    draw(state, &projector);
+
+    state.update_color(saved_color);
+    state.current_z_index = saved_z_index;
+}
+
+fn push_segment(state: &mut ZXFFIState, projector: &Projector, a: Vec3, b: Vec3) {
+    if let Some((sa, sb)) = projector.segment(a, b) {
+        push_line(state, sa.x, sa.y, sb.x, sb.y, LINE_THICKNESS);
+    }
+}
+
+/// Push the 12 edges of a box given its corners, indexed by axis bits
+/// (bit 0 = +X, bit 1 = +Y, bit 2 = +Z)
+pub(super) fn push_box_edges(
+    state: &mut ZXFFIState,
+    projector: &Projector,
+    corner: impl Fn(usize) -> Vec3,
+) {
+    // Every edge joins two corners that differ in exactly one axis bit
+    for i in 0..8 {
+        for bit in [1, 2, 4] {
+            if i & bit == 0 {
+                push_segment(state, projector, corner(i), corner(i | bit));
+            }
+        }
+    }
+}
+
+/// Draw a world-space line (debug inspector only)
+///
+/// # Arguments
+/// * `x1`, `y1`, `z1` — Start point in world units
+/// * `x2`, `y2`, `z2` — End point in world units
+/// * `color` — Line color (0xRRGGBBAA)
+///
+/// Drawn on top of the scene with the current camera. Ignored unless the F4
+/// inspector is open.
+fn debug_line3d(
+    mut caller: Caller<'_, ZXGameContext>,
+    x1: f32,
+    y1: f32,
+    z1: f32,
+    x2: f32,
+    y2: f32,
+    z2: f32,
+    color: u32,
+) {
+    with_gizmos(&mut caller, color, |state, projector| {
+        push_segment(
+            state,
+            projector,
+            Vec3::new(x1, y1, z1),
+            Vec3::new(x2, y2, z2),
+        );
+    });
+}
+
+/// Draw a wireframe sphere (debug inspector only)
+///
+/// # Arguments
+/// * `x`, `y`, `z` — Center in world units
+/// * `radius` — Radius in world units
+/// * `color` — Line color (0xRRGGBBAA)
+///
+/// Drawn as three axis-aligned great circles. Ignored unless the F4 inspector
+/// is open.
+fn debug_sphere(
+    mut caller: Caller<'_, ZXGameContext>,
+    x: f32,
+    y: f32,
+    z: f32,
+    radius: f32,
+    color: u32,
+) {
+    if radius <= 0.0 {
+        return;
+    }
+    with_gizmos(&mut caller, color, |state, projector| {
+        let center = Vec3::new(x, y, z);
+        let step = std::f32::consts::TAU / SPHERE_SEGMENTS as f32;
+        for (u, v) in [(Vec3::X, Vec3::Y), (Vec3::Y, Vec3::Z), (Vec3::Z, Vec3::X)] {
+            let at = |i: u32| {
+                let (sin, cos) = (i as f32 * step).sin_cos();
+                center + (u * cos + v * sin) * radius
+            };
+            for i in 0..SPHERE_SEGMENTS {
+                push_segment(state, projector, at(i), at(i + 1));
+            }
+        }
+    });
+}
+
+/// Draw a wireframe axis-aligned box (debug inspector only)
+///
+/// # Arguments
+/// * `min_x`, `min_y`, `min_z` — Minimum corner in world units
+/// * `max_x`, `max_y`, `max_z` — Maximum corner in world units
+/// * `color` — Line color (0xRRGGBBAA)
+///
+/// Draws the 12 box edges. Ignored unless the F4 inspector is open.
+fn debug_aabb(
+    mut caller: Caller<'_, ZXGameContext>,
+    min_x: f32,
+    min_y: f32,
+    min_z: f32,
+    max_x: f32,
+    max_y: f32,
+    max_z: f32,
+    color: u32,
+) {
+    with_gizmos(&mut caller, color, |state, projector| {
+        let corner = |i: usize| {
+            Vec3::new(
+                if i & 1 == 0 { min_x } else { max_x },
+                if i & 2 == 0 { min_y } else { max_y },
+                if i & 4 == 0 { min_z } else { max_z },
+            )
+        };
+        push_box_edges(state, projector, corner);
+    });
+}
+
+/// Draw a text label at a world-space position (debug inspector only)
+///
+/// # Arguments
+/// * `ptr` — Pointer to UTF-8 string data
+/// * `len` — Length of string in bytes
+/// * `x`, `y`, `z` — Label anchor (top-left) in world units
+/// * `size` — Font size in pixels
+/// * `color` — Text color (0xRRGGBBAA)
+///
+/// Uses the built-in font at a constant screen size. Ignored unless the F4
+/// inspector is open, or when the anchor is behind the camera.
+fn debug_text3d(
+    mut caller: Caller<'_, ZXGameContext>,
+    ptr: u32,
+    len: u32,
+    x: f32,
+    y: f32,
+    z: f32,
+    size: f32,
+    color: u32,
+) {
+    if !caller.data().game.debug_inspector_open || len == 0 {
+        return;
+    }
+    let Some(bytes) = read_wasm_bytes(&caller, ptr, len as usize, "debug_text3d") else {
+        return;
+    };
+    let Ok(text) = std::str::from_utf8(&bytes) else {
+        warn!("debug_text3d: invalid UTF-8 string");
+        return;
+    };
+
+    with_gizmos(&mut caller, color, |state, projector| {
+        if let Some(at) = projector.point(Vec3::new(x, y, z)) {
+            let saved_font = state.current_font;
+            state.current_font = 0; // Built-in font
+            push_text(state, text, at.x, at.y, size);
+            state.current_font = saved_font;
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn projector() -> Projector {
+        let view = Mat4::look_at_rh(Vec3::new(0.0, 0.0, 5.0), Vec3::ZERO, Vec3::Y);
+        let proj = Mat4::perspective_rh(60f32.to_radians(), 2.0, 0.1, 100.0);
+        Projector {
+            view_proj: proj * view,
+            width: 200.0,
+            height: 100.0,
+        }
+    }
+
+    #[test]
+    fn projects_into_viewport_pixels() {
+        let p = projector();
+        let center = p.point(Vec3::ZERO).unwrap();
+        assert!((center - Vec2::new(100.0, 50.0)).length() < 1e-3);
+
+        // +Y in the world is up on screen (smaller pixel Y)
+        let up = p.point(Vec3::Y).unwrap();
+        assert!(up.y < center.y);
+
+        // Behind the camera
+        assert!(p.point(Vec3::new(0.0, 0.0, 10.0)).is_none());
+    }
+
+    #[test]
+    fn segments_are_clipped_at_the_camera() {
+        let p = projector();
+        assert!(
+            p.segment(Vec3::new(0.0, 0.0, 6.0), Vec3::new(1.0, 0.0, 8.0))
+                .is_none()
+        );
+
+        // Crosses the camera plane: the visible end is kept as-is
+        let (a, b) = p
+            .segment(Vec3::new(1.0, 0.0, 0.0), Vec3::new(1.0, 0.0, 10.0))
+            .unwrap();
+        assert_eq!(a, p.point(Vec3::new(1.0, 0.0, 0.0)).unwrap());
+        assert!(b.x.is_finite() && b.y.is_finite());
+    }
+}
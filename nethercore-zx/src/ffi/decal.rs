@@ -0,0 +1,221 @@
+//! Decal FFI functions
+//!
+//! Decals (scorch marks, tire skids, bullet holes) are textures projected
+//! onto the static meshes drawn around them. They are presentation-only and
+//! not rolled back; spawning the same decal again on the same tick, as
+//! happens when `update()` is re-simulated, is ignored.
+
+use anyhow::Result;
+use glam::{Mat4, Vec3};
+use hashbrown::HashMap;
+use tracing::warn;
+use wasmtime::{Caller, Linker};
+
+use super::ZXGameContext;
+use super::helpers::pending_mesh_geometry;
+use crate::decal::{DecalBox, DecalSurface};
+use crate::graphics::{
+    FLAG_UNIFORM_ALPHA_MASK, FLAG_UNIFORM_ALPHA_SHIFT, FORMAT_NORMAL, FORMAT_SKINNED, FORMAT_UV,
+    MeshBlend,
+};
+use crate::state::{Decal, ZXFFIState};
+
+/// Vertex format decals are drawn with (position, UV, normal)
+const DECAL_FORMAT: u8 = FORMAT_UV | FORMAT_NORMAL;
+
+/// Register decal FFI functions
+pub fn register(linker: &mut Linker<ZXGameContext>) -> Result<()> {
+    linker.func_wrap("env", "decal_spawn", decal_spawn)?;
+    linker.func_wrap("env", "decals_draw", decals_draw)?;
+    linker.func_wrap("env", "decals_clear", decals_clear)?;
+    Ok(())
+}
+
+/// Spawn a decal
+///
+/// # Arguments
+/// * `texture` — Texture handle (alpha cuts out the decal's shape)
+/// * `x`, `y`, `z` — Point on the surface (world space)
+/// * `normal_x`, `normal_y`, `normal_z` — Surface normal to project along
+/// * `size` — Width and height of the decal in world units; geometry within
+///   `size / 2` of the point along the normal receives it
+/// * `rotation_deg` — Rotation about the normal in degrees (counter-clockwise)
+/// * `lifetime` — Seconds until the decal is gone, fading out over the last
+///   quarter (0 = until replaced or cleared)
+///
+/// Up to 256 decals are live at once; spawning more replaces the oldest.
+///
+/// Returns 1 if the decal was spawned, 0 if it was a duplicate or invalid.
+fn decal_spawn(
+    mut caller: Caller<'_, ZXGameContext>,
+    texture: u32,
+    x: f32,
+    y: f32,
+    z: f32,
+    normal_x: f32,
+    normal_y: f32,
+    normal_z: f32,
+    size: f32,
+    rotation_deg: f32,
+    lifetime: f32,
+) -> u32 {
+    if texture == 0 {
+        warn!("decal_spawn: invalid texture handle 0");
+        return 0;
+    }
+    if !lifetime.is_finite() {
+        warn!("decal_spawn: lifetime must be finite");
+        return 0;
+    }
+    let Some(volume) = DecalBox::new(
+        Vec3::new(x, y, z),
+        Vec3::new(normal_x, normal_y, normal_z),
+        size,
+        rotation_deg.to_radians(),
+    ) else {
+        warn!(
+            "decal_spawn: position, normal and size must be finite, with a non-zero normal and positive size"
+        );
+        return 0;
+    };
+
+    let tick = caller.data().game.tick_count;
+    caller
+        .data_mut()
+        .ffi
+        .decals
+        .spawn(tick, texture, volume, lifetime) as u32
+}
+
+/// Draw all live decals with the current camera and viewport
+///
+/// Call from `render()` after drawing the level, once per viewport. Decals
+/// are projected onto the static meshes drawn so far this frame the first
+/// time they overlap one; until then they draw as flat quads. The current
+/// transform, texture binding and `uniform_alpha()` level are preserved.
+fn decals_draw(mut caller: Caller<'_, ZXGameContext>) {
+    let tick = caller.data().game.tick_count;
+    let dt = caller.data().game.delta_time;
+    let state = &mut caller.data_mut().ffi;
+
+    state.decals.expire(tick, dt);
+    if state.decals.is_empty() {
+        return;
+    }
+
+    // Take the decals out so the FFI state can be borrowed for drawing
+    let mut decals = std::mem::take(&mut state.decals);
+    for decal in decals.iter_mut().filter(|d| d.geometry.is_empty()) {
+        project_decal(decal, &state.decal_surfaces, &state.decal_receivers);
+    }
+
+    let saved_model = state
+        .current_model_matrix
+        .or_else(|| state.model_matrices.last().copied())
+        .unwrap_or(Mat4::IDENTITY);
+    let saved_texture = state.bound_textures[0];
+    let saved_alpha = ((state.current_shading_state.flags & FLAG_UNIFORM_ALPHA_MASK)
+        >> FLAG_UNIFORM_ALPHA_SHIFT) as u8;
+
+    // Decal geometry is already in world space
+    state.current_model_matrix = Some(Mat4::IDENTITY);
+    let mut vertex_data = Vec::new();
+    for decal in decals.iter_mut() {
+        let alpha = (decal.opacity(decal.age(tick, dt)) * 15.0).round() as u8;
+        if alpha == 0 {
+            continue;
+        }
+
+        let quad;
+        let vertices = if decal.geometry.is_empty() {
+            quad = decal.volume.quad();
+            &quad[..]
+        } else {
+            &decal.geometry[..]
+        };
+        vertex_data.clear();
+        for v in vertices {
+            vertex_data.extend_from_slice(&v.position.to_array());
+            vertex_data.extend_from_slice(&v.uv);
+            vertex_data.extend_from_slice(&v.normal.to_array());
+        }
+
+        state.bound_textures[0] = decal.texture;
+        state.update_uniform_alpha(alpha);
+        let buffer_index = state.add_mvp_shading_state();
+        state.render_pass.record_triangles(
+            DECAL_FORMAT,
+            &vertex_data,
+            buffer_index,
+            state.bound_textures,
+            state.cull_mode,
+            MeshBlend::default(),
+            state.current_viewport,
+            state.current_pass_id,
+        );
+    }
+
+    state.current_model_matrix = Some(saved_model);
+    state.bound_textures[0] = saved_texture;
+    state.update_uniform_alpha(saved_alpha);
+    state.decals = decals;
+}
+
+/// Remove all decals (e.g. when loading a new level)
+fn decals_clear(mut caller: Caller<'_, ZXGameContext>) {
+    caller.data_mut().ffi.decals.clear();
+}
+
+/// Project a decal onto the receivers drawn this frame
+///
+/// Leaves `decal.geometry` empty if nothing nearby faces the decal, so the
+/// next draw tries again.
+fn project_decal(
+    decal: &mut Decal,
+    surfaces: &HashMap<u32, DecalSurface>,
+    receivers: &[(u32, Mat4)],
+) {
+    // A mesh drawn in several viewports is listed once per draw
+    let mut projected: Vec<(u32, Mat4)> = Vec::new();
+    for receiver in receivers {
+        if projected.contains(receiver) {
+            continue;
+        }
+        let (handle, model) = receiver;
+        let Some(surface) = surfaces.get(handle) else {
+            continue;
+        };
+        if decal.volume.project(surface, model, &mut decal.geometry) > 0 {
+            projected.push(*receiver);
+        }
+    }
+}
+
+/// Keep CPU copies of the static meshes created during init
+///
+/// Called before pending meshes are uploaded (and their CPU data dropped).
+/// Skinned meshes are skipped since their drawn shape isn't known host-side.
+pub(crate) fn collect_decal_surfaces(state: &mut ZXFFIState) {
+    let handles: Vec<u32> = state
+        .pending_meshes
+        .iter()
+        .map(|m| (m.handle, m.format))
+        .chain(
+            state
+                .pending_meshes_packed
+                .iter()
+                .map(|m| (m.handle, m.format)),
+        )
+        .filter(|&(_, format)| format & FORMAT_SKINNED == 0)
+        .map(|(handle, _)| handle)
+        .collect();
+
+    for handle in handles {
+        let Some((positions, indices)) = pending_mesh_geometry(state, handle) else {
+            continue;
+        };
+        if let Some(surface) = DecalSurface::new(positions, &indices) {
+            state.decal_surfaces.insert(handle, surface);
+        }
+    }
+}
@@ -0,0 +1,296 @@
+//! Destructible prop FFI functions
+//!
+//! Register a prop with an intact mesh and a pre-fractured pieces mesh during
+//! init(), damage it from `update()`, and draw it from `render()`. Health and
+//! the break tick are rolled back in ZRollbackState; debris motion is derived
+//! from them (see `crate::destructible`).
+
+use anyhow::Result;
+use glam::{Mat4, Vec3};
+use nethercore_core::event::Event;
+use tracing::warn;
+use wasmtime::{Caller, Linker};
+
+use super::ZXGameContext;
+use super::guards::guard_init_only;
+use super::helpers::pending_mesh_geometry;
+use super::mesh::push_mesh;
+use crate::destructible::{
+    DEBRIS_LIFETIME, DebrisPiece, Destructible, MAX_DEBRIS_PIECES, extract_piece, split_pieces,
+};
+use crate::graphics::{vertex_stride, vertex_stride_packed};
+use crate::state::{
+    DestructibleState, MAX_DESTRUCTIBLES, PendingMesh, PendingMeshPacked, ZXFFIState, zx_event_kind,
+};
+
+/// Register destructible prop FFI functions
+pub fn register(linker: &mut Linker<ZXGameContext>) -> Result<()> {
+    linker.func_wrap("env", "destructible_register", destructible_register)?;
+    linker.func_wrap("env", "destructible_damage", destructible_damage)?;
+    linker.func_wrap("env", "destructible_draw", destructible_draw)?;
+    linker.func_wrap("env", "destructible_health", destructible_health)?;
+    linker.func_wrap("env", "destructible_reset", destructible_reset)?;
+    Ok(())
+}
+
+/// Register a destructible prop (init-only)
+///
+/// # Arguments
+/// * `mesh` — Mesh drawn while the prop is intact
+/// * `pieces_mesh` — Pre-fractured mesh in the same local space; each
+///   connected part becomes one debris piece (up to 64)
+/// * `health` — Starting health (> 0)
+///
+/// Debris settles on the lowest point of `pieces_mesh`. Both meshes must be
+/// loaded earlier in init().
+///
+/// Returns a destructible handle (>0) on success, 0 on failure.
+fn destructible_register(
+    mut caller: Caller<'_, ZXGameContext>,
+    mesh: u32,
+    pieces_mesh: u32,
+    health: f32,
+) -> u32 {
+    const FN_NAME: &str = "destructible_register";
+
+    guard_init_only!(caller, FN_NAME);
+
+    if !(health.is_finite() && health > 0.0) {
+        warn!("{}: health must be positive (got {})", FN_NAME, health);
+        return 0;
+    }
+
+    let ctx = caller.data_mut();
+    let state = &mut ctx.ffi;
+    if state.destructibles.len() >= MAX_DESTRUCTIBLES {
+        warn!(
+            "{}: maximum destructible count {} exceeded",
+            FN_NAME, MAX_DESTRUCTIBLES
+        );
+        return 0;
+    }
+    if pending_mesh_geometry(state, mesh).is_none() {
+        warn!("{}: mesh {} not found", FN_NAME, mesh);
+        return 0;
+    }
+    let Some((positions, indices)) = pending_mesh_geometry(state, pieces_mesh) else {
+        warn!("{}: pieces mesh {} not found", FN_NAME, pieces_mesh);
+        return 0;
+    };
+
+    let pieces = split_pieces(&positions, &indices);
+    if pieces.is_empty() || pieces.len() > MAX_DEBRIS_PIECES {
+        warn!(
+            "{}: pieces mesh {} has {} pieces (1-{})",
+            FN_NAME,
+            pieces_mesh,
+            pieces.len(),
+            MAX_DEBRIS_PIECES
+        );
+        return 0;
+    }
+
+    let (min, max) = positions.iter().fold(
+        (Vec3::splat(f32::INFINITY), Vec3::splat(f32::NEG_INFINITY)),
+        |(min, max), &p| (min.min(p), max.max(p)),
+    );
+    let center = (min + max) * 0.5;
+
+    let handle = state.destructibles.len() as u32 + 1;
+    let debris = pieces
+        .iter()
+        .enumerate()
+        .map(|(i, triangles)| {
+            let piece_positions: Vec<Vec3> = triangles
+                .iter()
+                .flatten()
+                .map(|&v| positions[v as usize])
+                .collect();
+            let piece_mesh = add_piece_mesh(state, pieces_mesh, triangles);
+            let seed = (u64::from(handle) << 32) | i as u64;
+            DebrisPiece::new(piece_mesh, &piece_positions, center, seed)
+        })
+        .collect();
+
+    state.destructibles.push(Destructible {
+        mesh,
+        max_health: health,
+        pieces: debris,
+        floor_y: min.y,
+    });
+    ctx.rollback.destructibles[handle as usize - 1] = DestructibleState {
+        health,
+        ..Default::default()
+    };
+    handle
+}
+
+/// Copy one piece of a pending mesh into a new pending mesh, returning its handle
+fn add_piece_mesh(state: &mut ZXFFIState, source: u32, triangles: &[[u32; 3]]) -> u32 {
+    let handle = state.next_mesh_handle;
+
+    if let Some(mesh) = state.pending_meshes.iter().find(|m| m.handle == source) {
+        let stride = vertex_stride(mesh.format) as usize / 4;
+        let (vertex_data, index_data) = extract_piece(&mesh.vertex_data, stride, triangles);
+        let format = mesh.format;
+        state.pending_meshes.push(PendingMesh {
+            handle,
+            format,
+            vertex_data,
+            index_data: Some(index_data),
+        });
+    } else if let Some(mesh) = state
+        .pending_meshes_packed
+        .iter()
+        .find(|m| m.handle == source)
+    {
+        let stride = vertex_stride_packed(mesh.format) as usize;
+        let (vertex_data, index_data) = extract_piece(&mesh.vertex_data, stride, triangles);
+        let format = mesh.format;
+        state.pending_meshes_packed.push(PendingMeshPacked {
+            handle,
+            format,
+            vertex_data,
+            index_data: Some(index_data),
+        });
+    }
+
+    state.next_mesh_handle += 1;
+    handle
+}
+
+/// Damage a destructible prop
+///
+/// # Arguments
+/// * `handle` — Destructible handle from `destructible_register()`
+/// * `amount` — Health to remove
+/// * `impulse_x`, `impulse_y`, `impulse_z` — Velocity added to every piece if
+///   this hit breaks the prop (local space, units per second)
+///
+/// Call from `update()` so the damage is rolled back with the game.
+///
+/// Returns 1 if this hit broke the prop, 0 otherwise. A break is also queued
+/// for `event_poll()`.
+fn destructible_damage(
+    mut caller: Caller<'_, ZXGameContext>,
+    handle: u32,
+    amount: f32,
+    impulse_x: f32,
+    impulse_y: f32,
+    impulse_z: f32,
+) -> u32 {
+    let tick = caller.data().game.tick_count;
+    let Some(prop) = prop_state_mut(&mut caller, handle) else {
+        warn!("destructible_damage: invalid handle {}", handle);
+        return 0;
+    };
+    if prop.is_broken() || !(amount.is_finite() && amount > 0.0) {
+        return 0;
+    }
+
+    prop.health -= amount;
+    if prop.health > 0.0 {
+        return 0;
+    }
+
+    let impulse = [impulse_x, impulse_y, impulse_z];
+    prop.health = 0.0;
+    prop.broken_at = tick as u32 + 1;
+    prop.impulse = impulse.map(|v| if v.is_finite() { v } else { 0.0 });
+    caller.data_mut().game.events.push(Event::new(
+        zx_event_kind::DESTRUCTIBLE_BREAK,
+        [handle, 0, 0, 0, 0],
+    ));
+    1
+}
+
+/// Draw a destructible prop with the current transform
+///
+/// Draws the intact mesh, or the debris pieces once broken. Debris disappears
+/// 5 seconds after the break.
+fn destructible_draw(mut caller: Caller<'_, ZXGameContext>, handle: u32) {
+    let ctx = caller.data_mut();
+    let Some(index) = (handle as usize)
+        .checked_sub(1)
+        .filter(|&i| i < ctx.ffi.destructibles.len())
+    else {
+        warn!("destructible_draw: invalid handle {}", handle);
+        return;
+    };
+    let prop_state = ctx.rollback.destructibles[index];
+    let state = &mut ctx.ffi;
+
+    if !prop_state.is_broken() {
+        let mesh = state.destructibles[index].mesh;
+        push_mesh(state, mesh);
+        return;
+    }
+
+    let ticks = ctx
+        .game
+        .tick_count
+        .saturating_sub(u64::from(prop_state.broken_at - 1));
+    let time = ticks as f32 * ctx.game.delta_time;
+    if time >= DEBRIS_LIFETIME {
+        return;
+    }
+
+    // Take the pieces out so the FFI state can be borrowed for drawing
+    let pieces = std::mem::take(&mut state.destructibles[index].pieces);
+    let floor_y = state.destructibles[index].floor_y;
+    let impulse = Vec3::from_array(prop_state.impulse);
+
+    let base = state
+        .current_model_matrix
+        .or_else(|| state.model_matrices.last().copied())
+        .unwrap_or(Mat4::IDENTITY);
+    for piece in &pieces {
+        state.current_model_matrix = Some(base * piece.transform(time, impulse, floor_y));
+        push_mesh(state, piece.mesh);
+    }
+    state.current_model_matrix = Some(base);
+
+    state.destructibles[index].pieces = pieces;
+}
+
+/// Get the remaining health of a destructible prop
+///
+/// Returns 0.0 for broken props and invalid handles.
+fn destructible_health(caller: Caller<'_, ZXGameContext>, handle: u32) -> f32 {
+    let ctx = caller.data();
+    (handle as usize)
+        .checked_sub(1)
+        .filter(|&i| i < ctx.ffi.destructibles.len())
+        .map_or(0.0, |i| ctx.rollback.destructibles[i].health)
+}
+
+/// Restore a destructible prop to full health
+fn destructible_reset(mut caller: Caller<'_, ZXGameContext>, handle: u32) {
+    let Some(max_health) = (handle as usize)
+        .checked_sub(1)
+        .and_then(|i| caller.data().ffi.destructibles.get(i))
+        .map(|prop| prop.max_health)
+    else {
+        warn!("destructible_reset: invalid handle {}", handle);
+        return;
+    };
+    if let Some(prop) = prop_state_mut(&mut caller, handle) {
+        *prop = DestructibleState {
+            health: max_health,
+            ..Default::default()
+        };
+    }
+}
+
+/// Look up the rolled-back state of a registered destructible
+fn prop_state_mut<'a>(
+    caller: &'a mut Caller<'_, ZXGameContext>,
+    handle: u32,
+) -> Option<&'a mut DestructibleState> {
+    let index = (handle as usize).checked_sub(1)?;
+    let ctx = caller.data_mut();
+    if index >= ctx.ffi.destructibles.len() {
+        return None;
+    }
+    ctx.rollback.destructibles.get_mut(index)
+}
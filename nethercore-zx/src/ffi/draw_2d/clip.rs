@@ -0,0 +1,79 @@
+//! Clip rectangle functions
+//!
+//! Scissor rectangles for 2D drawing (scrollable lists, minimaps, panels).
+
+use anyhow::Result;
+use tracing::warn;
+use wasmtime::{Caller, Linker};
+
+use crate::ffi::ZXGameContext;
+use crate::graphics::Viewport;
+use crate::state::ZXFFIState;
+
+/// Maximum nesting depth of `clip_push()`
+pub(crate) const MAX_CLIP_DEPTH: usize = 16;
+
+/// Register clip FFI functions
+pub(super) fn register(linker: &mut Linker<ZXGameContext>) -> Result<()> {
+    linker.func_wrap("env", "clip_push", clip_push)?;
+    linker.func_wrap("env", "clip_pop", clip_pop)?;
+    Ok(())
+}
+
+/// Restrict 2D drawing to a rectangle
+///
+/// # Arguments
+/// * `x` — Left edge in pixels, relative to the current viewport
+/// * `y` — Top edge in pixels, relative to the current viewport
+/// * `w` — Width in pixels
+/// * `h` — Height in pixels
+///
+/// Affects every screen-space draw (sprites, rects, lines, text) until the
+/// matching `clip_pop()`. Nested clips are intersected with the enclosing one,
+/// so a child can never draw outside its parent. 3D geometry and billboards
+/// are not clipped. The stack is cleared at the end of each frame.
+fn clip_push(mut caller: Caller<'_, ZXGameContext>, x: f32, y: f32, w: f32, h: f32) {
+    push_clip(&mut caller.data_mut().ffi, x, y, w, h);
+}
+
+/// Restore the clip rectangle from before the last `clip_push()`
+fn clip_pop(mut caller: Caller<'_, ZXGameContext>) {
+    if caller.data_mut().ffi.clip_stack.pop().is_none() {
+        warn!("clip_pop: no clip rectangle to pop");
+    }
+}
+
+/// Push a viewport-relative clip rectangle onto the clip stack
+///
+/// The rectangle is rounded to whole pixels and intersected with the current
+/// clip (or the viewport if there is none). Returns `false` if the stack is full.
+pub(crate) fn push_clip(state: &mut ZXFFIState, x: f32, y: f32, w: f32, h: f32) -> bool {
+    if state.clip_stack.len() >= MAX_CLIP_DEPTH {
+        warn!("clip_push: clip stack full (max {})", MAX_CLIP_DEPTH);
+        return false;
+    }
+
+    // Offset by viewport origin for split-screen support
+    let vp = state.current_viewport;
+    let to_pixel = |v: f32| {
+        if v.is_finite() {
+            v.round().max(0.0) as u32
+        } else {
+            0
+        }
+    };
+    let left = to_pixel(vp.x as f32 + x);
+    let top = to_pixel(vp.y as f32 + y);
+    let right = to_pixel(vp.x as f32 + x + w);
+    let bottom = to_pixel(vp.y as f32 + y + h);
+
+    let rect = Viewport {
+        x: left,
+        y: top,
+        width: right.saturating_sub(left),
+        height: bottom.saturating_sub(top),
+    };
+    let parent = state.clip_stack.last().copied().unwrap_or(vp);
+    state.clip_stack.push(rect.intersect(&parent));
+    true
+}
@@ -0,0 +1,152 @@
+//! Offscreen indicators
+//!
+//! Edge-of-screen markers that point toward world positions the camera can't
+//! see, such as bosses, objectives or enemies closing in from behind.
+
+use anyhow::Result;
+use glam::{Vec2, Vec3};
+use wasmtime::{Caller, Linker};
+
+use crate::ffi::ZXGameContext;
+use crate::ffi::debug_draw::{NEAR_EPSILON, Projector};
+use crate::state::ZXFFIState;
+
+use super::{SCREEN_SPACE_DEPTH, push_line};
+
+/// Icon width and height in pixels
+const ICON_SIZE: f32 = 24.0;
+
+/// Arrow length past the icon edge in pixels
+const ARROW_SIZE: f32 = 8.0;
+
+/// Arrow line thickness in pixels
+const ARROW_THICKNESS: f32 = 2.0;
+
+/// Gap between the arrow tip and the viewport edge in pixels
+const EDGE_PADDING: f32 = 4.0;
+
+/// Register offscreen indicator FFI functions
+pub(super) fn register(linker: &mut Linker<ZXGameContext>) -> Result<()> {
+    linker.func_wrap("env", "offscreen_indicator", offscreen_indicator)?;
+    Ok(())
+}
+
+/// Draw an edge-of-screen indicator pointing at a world position
+///
+/// # Arguments
+/// * `x`, `y`, `z` — Target position in world units
+/// * `icon` — Texture handle drawn at the indicator (0 = arrow only)
+/// * `color` — Icon tint and arrow color (0xRRGGBBAA)
+///
+/// Projects the target with the current camera. If it's inside the current
+/// viewport nothing is drawn; otherwise the icon is clamped to the viewport
+/// edge on the line from the center toward the target, with an arrow pointing
+/// out. Targets behind the camera point the way the player has to turn.
+/// Color, bound texture and z-index are preserved.
+///
+/// # Returns
+/// 1 if the indicator was drawn (target offscreen), 0 if the target is visible
+fn offscreen_indicator(
+    mut caller: Caller<'_, ZXGameContext>,
+    x: f32,
+    y: f32,
+    z: f32,
+    icon: u32,
+    color: u32,
+) -> u32 {
+    let state = &mut caller.data_mut().ffi;
+    push_offscreen_indicator(state, Vec3::new(x, y, z), icon, color) as u32
+}
+
+/// Record an offscreen indicator for `target`, returning whether one was drawn
+pub(super) fn push_offscreen_indicator(
+    state: &mut ZXFFIState,
+    target: Vec3,
+    icon: u32,
+    color: u32,
+) -> bool {
+    let projector = Projector::from_state(state);
+    let Some((center, dir)) = indicator_placement(&projector, target) else {
+        return false;
+    };
+
+    let saved_color = state.current_shading_state.color_rgba8;
+    let saved_texture = state.bound_textures[0];
+    let saved_atlas_sprite = state.bound_atlas_sprite;
+    state.update_color(color);
+
+    // Arrow: a chevron whose tip sits just past the icon, pointing at the target
+    let tip = center + dir * (ICON_SIZE * 0.5 + ARROW_SIZE);
+    let back = tip - dir * ARROW_SIZE;
+    let side = dir.perp() * ARROW_SIZE;
+    for wing in [back + side, back - side] {
+        push_line(state, wing.x, wing.y, tip.x, tip.y, ARROW_THICKNESS);
+    }
+
+    if icon != 0 {
+        push_icon(state, center, ICON_SIZE, icon);
+    }
+
+    state.bound_textures[0] = saved_texture;
+    state.bound_atlas_sprite = saved_atlas_sprite;
+    state.update_color(saved_color);
+    true
+}
+
+/// Record a square `icon` quad of `size` pixels centered at `center`
+///
+/// Coordinates are relative to the current viewport. Binds `icon` and clears
+/// the atlas sprite without restoring them.
+pub(super) fn push_icon(state: &mut ZXFFIState, center: Vec2, size: f32, icon: u32) {
+    state.bound_textures[0] = icon;
+    state.bound_atlas_sprite = None;
+
+    let vp = state.current_viewport;
+    let shading_state_index = state.add_shading_state();
+    let instance = crate::graphics::QuadInstance::sprite(
+        vp.x as f32 + center.x - size * 0.5,
+        vp.y as f32 + center.y - size * 0.5,
+        SCREEN_SPACE_DEPTH,
+        size,
+        size,
+        0.0,
+        [0.0, 0.0, 1.0, 1.0],
+        shading_state_index.0,
+        (state.view_matrices.len() - 1) as u32,
+    );
+    state.add_quad_instance(instance, state.current_z_index);
+}
+
+/// Indicator center (viewport pixels) and unit direction toward `target`
+///
+/// Returns `None` if the target projects inside the viewport.
+fn indicator_placement(projector: &Projector, target: Vec3) -> Option<(Vec2, Vec2)> {
+    let clip = projector.clip(target);
+    if clip.w > NEAR_EPSILON {
+        let ndc = Vec2::new(clip.x, clip.y) / clip.w;
+        if ndc.x.abs() <= 1.0 && ndc.y.abs() <= 1.0 {
+            return None;
+        }
+    }
+
+    // Clip-space x/y keep the sign of the view-space offset even behind the
+    // camera, so they give the turn direction there too. Straight behind
+    // has no sideways offset; point down.
+    let half = projector.size() * 0.5;
+    let dir = Vec2::new(clip.x * half.x, -clip.y * half.y)
+        .try_normalize()
+        .unwrap_or(Vec2::Y);
+
+    // Slide from the viewport center along `dir` until the arrow tip meets the edge
+    let reach = (half - Vec2::splat(ICON_SIZE * 0.5 + ARROW_SIZE + EDGE_PADDING)).max(Vec2::ZERO);
+    let scale = |reach: f32, d: f32| {
+        if d.abs() > f32::EPSILON {
+            reach / d.abs()
+        } else {
+            f32::INFINITY
+        }
+    };
+    let t = scale(reach.x, dir.x).min(scale(reach.y, dir.y));
+
+    Some((half + dir * t, dir))
+}
@@ -0,0 +1,114 @@
+//! Objective markers
+//!
+//! Waypoint icons that float over a world position while it's on screen and
+//! turn into an edge-of-screen indicator when it isn't, such as the next
+//! checkpoint in a race or a downed teammate.
+
+use anyhow::Result;
+use glam::Vec3;
+use tracing::warn;
+use wasmtime::{Caller, Linker};
+
+use crate::ffi::ZXGameContext;
+use crate::ffi::debug_draw::Projector;
+use crate::ffi::helpers::read_wasm_bytes;
+use crate::state::ZXFFIState;
+
+use super::indicator::{push_icon, push_offscreen_indicator};
+use super::text::{measure_text, push_text};
+
+/// Icon size in pixels at [`REFERENCE_DEPTH`] and closer
+const MAX_ICON_SIZE: f32 = 32.0;
+
+/// Smallest icon size in pixels, however far away the target is
+const MIN_ICON_SIZE: f32 = 12.0;
+
+/// View depth in world units up to which the icon is drawn at full size
+const REFERENCE_DEPTH: f32 = 8.0;
+
+/// Label font size in pixels
+const LABEL_SIZE: f32 = 8.0;
+
+/// Gap between the icon and its label in pixels
+const LABEL_GAP: f32 = 2.0;
+
+/// Register objective marker FFI functions
+pub(super) fn register(linker: &mut Linker<ZXGameContext>) -> Result<()> {
+    linker.func_wrap("env", "marker_draw", marker_draw)?;
+    Ok(())
+}
+
+/// Draw an objective marker at a world position
+///
+/// # Arguments
+/// * `x`, `y`, `z` — Target position in world units
+/// * `icon` — Texture handle for the marker icon (0 = label only)
+/// * `label_ptr` — Pointer to UTF-8 label text
+/// * `label_len` — Label length in bytes (0 = no label)
+///
+/// While the target is on screen the icon is centered on it, shrinking with
+/// distance from `MAX_ICON_SIZE` down to `MIN_ICON_SIZE`, with the label
+/// centered underneath in the bound font. Offscreen, it draws the same edge
+/// indicator as `offscreen_indicator()` (without the label). Uses the current
+/// color; color, bound texture and z-index are preserved.
+///
+/// # Returns
+/// 1 if the marker was drawn on screen, 0 if it was drawn as an edge indicator
+fn marker_draw(
+    mut caller: Caller<'_, ZXGameContext>,
+    x: f32,
+    y: f32,
+    z: f32,
+    icon: u32,
+    label_ptr: u32,
+    label_len: u32,
+) -> u32 {
+    let label = if label_len == 0 {
+        String::new()
+    } else {
+        read_wasm_bytes(&caller, label_ptr, label_len as usize, "marker_draw")
+            .and_then(|bytes| {
+                String::from_utf8(bytes)
+                    .inspect_err(|_| warn!("marker_draw: invalid UTF-8 label"))
+                    .ok()
+            })
+            .unwrap_or_default()
+    };
+
+    let state = &mut caller.data_mut().ffi;
+    push_marker(state, Vec3::new(x, y, z), icon, &label) as u32
+}
+
+/// Record a marker for `target`, returning whether it was drawn on screen
+pub(super) fn push_marker(state: &mut ZXFFIState, target: Vec3, icon: u32, label: &str) -> bool {
+    let color = state.current_shading_state.color_rgba8;
+    if push_offscreen_indicator(state, target, icon, color) {
+        return false;
+    }
+
+    let projector = Projector::from_state(state);
+    let clip = projector.clip(target);
+    let center = projector.to_screen(clip);
+    let size = icon_size(clip.w);
+
+    let saved_texture = state.bound_textures[0];
+    let saved_atlas_sprite = state.bound_atlas_sprite;
+
+    if icon != 0 {
+        push_icon(state, center, size, icon);
+    }
+    if !label.is_empty() {
+        let width = measure_text(state, label, LABEL_SIZE);
+        let top = center.y + size * 0.5 + LABEL_GAP;
+        push_text(state, label, center.x - width * 0.5, top, LABEL_SIZE);
+    }
+
+    state.bound_textures[0] = saved_texture;
+    state.bound_atlas_sprite = saved_atlas_sprite;
+    true
+}
+
+/// Icon size in pixels for a target at view depth `depth`
+fn icon_size(depth: f32) -> f32 {
+    (MAX_ICON_SIZE * REFERENCE_DEPTH / depth.max(f32::EPSILON)).clamp(MIN_ICON_SIZE, MAX_ICON_SIZE)
+}
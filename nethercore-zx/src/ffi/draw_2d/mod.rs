@@ -1,19 +1,31 @@
 //! 2D drawing FFI functions (screen space)
 //!
-//! Functions for drawing sprites, rectangles, and text in screen space.
+//! Functions for drawing sprites, nine-slice panels, rectangles, and text in
+//! screen space, clipping them to rectangles, marking objectives and offscreen targets,
+//! drawing save slot thumbnails, and showing host-drawn toast notifications.
 
 use anyhow::Result;
 use wasmtime::Linker;
 
 use super::ZXGameContext;
 
+mod clip;
+mod indicator;
+mod marker;
+mod nine_slice;
 mod shapes;
 mod sprites;
 mod text;
+mod thumbnail;
+mod toast;
 
 #[cfg(test)]
 mod tests;
 
+pub(crate) use shapes::{push_line, push_rect};
+pub(crate) use text::push_text;
+pub(crate) use toast::draw_toasts;
+
 /// Depth value for all screen-space 2D quads
 ///
 /// All 2D UI elements render at depth 0.0 (near plane) for early-z optimization.
@@ -29,7 +41,13 @@ pub(crate) const SCREEN_SPACE_DEPTH: f32 = 0.0;
 /// Register 2D drawing FFI functions
 pub fn register(linker: &mut Linker<ZXGameContext>) -> Result<()> {
     sprites::register(linker)?;
+    nine_slice::register(linker)?;
     shapes::register(linker)?;
     text::register(linker)?;
+    thumbnail::register(linker)?;
+    clip::register(linker)?;
+    indicator::register(linker)?;
+    marker::register(linker)?;
+    toast::register(linker)?;
     Ok(())
 }
@@ -0,0 +1,161 @@
+//! Nine-slice panel drawing
+//!
+//! A nine-slice texture is split by four border lines into corners, edges and
+//! a center. Corners keep their texture size, edges stretch along their
+//! length and the center fills the rest, so one small frame texture draws
+//! crisp panels of any size.
+
+use anyhow::Result;
+use tracing::warn;
+use wasmtime::{Caller, Linker};
+
+use crate::ffi::ZXGameContext;
+use crate::state::ZXFFIState;
+
+use super::SCREEN_SPACE_DEPTH;
+
+/// Register nine-slice drawing FFI functions
+pub(super) fn register(linker: &mut Linker<ZXGameContext>) -> Result<()> {
+    linker.func_wrap("env", "draw_nine_slice", draw_nine_slice)?;
+    Ok(())
+}
+
+/// Draw a texture as a nine-slice panel
+///
+/// # Arguments
+/// * `texture` — Texture handle
+/// * `x` — Screen X coordinate in pixels (0 = left edge)
+/// * `y` — Screen Y coordinate in pixels (0 = top edge)
+/// * `w` — Panel width in pixels
+/// * `h` — Panel height in pixels
+/// * `left`, `top`, `right`, `bottom` — Border widths in texture pixels
+/// * `color` — Tint color (0xRRGGBBAA)
+///
+/// Borders are drawn at their texture size; a panel smaller than its
+/// borders shrinks them proportionally. Uses the current blend mode; color,
+/// bound texture and atlas sprite are preserved.
+fn draw_nine_slice(
+    mut caller: Caller<'_, ZXGameContext>,
+    texture: u32,
+    x: f32,
+    y: f32,
+    w: f32,
+    h: f32,
+    left: f32,
+    top: f32,
+    right: f32,
+    bottom: f32,
+    color: u32,
+) {
+    if w <= 0.0 || h <= 0.0 {
+        return;
+    }
+    if [left, top, right, bottom]
+        .iter()
+        .any(|b| !b.is_finite() || *b < 0.0)
+    {
+        warn!("draw_nine_slice: borders must be finite and non-negative");
+        return;
+    }
+
+    let state = &mut caller.data_mut().ffi;
+    let Some(&(tex_w, tex_h)) = state.texture_sizes.get(&texture) else {
+        warn!("draw_nine_slice: invalid texture handle {}", texture);
+        return;
+    };
+    if left + right > tex_w as f32 || top + bottom > tex_h as f32 {
+        warn!(
+            "draw_nine_slice: borders exceed the {}x{} texture",
+            tex_w, tex_h
+        );
+        return;
+    }
+
+    push_nine_slice(
+        state,
+        texture,
+        [x, y, w, h],
+        [left, top, right, bottom],
+        (tex_w, tex_h),
+        color,
+    );
+}
+
+/// Record the quads of a nine-slice panel
+///
+/// `rect` is (x, y, w, h) relative to the current viewport, `borders` is
+/// (left, top, right, bottom) in texture pixels and `texture_size` the
+/// texture's size in pixels. Empty cells are skipped.
+pub(super) fn push_nine_slice(
+    state: &mut ZXFFIState,
+    texture: u32,
+    rect: [f32; 4],
+    borders: [f32; 4],
+    texture_size: (u32, u32),
+    color: u32,
+) {
+    let [x, y, w, h] = rect;
+    let [left, top, right, bottom] = borders;
+    let (tex_w, tex_h) = (texture_size.0 as f32, texture_size.1 as f32);
+
+    let columns = slice_axis(x, w, left, right, tex_w);
+    let rows = slice_axis(y, h, top, bottom, tex_h);
+
+    let saved_color = state.current_shading_state.color_rgba8;
+    let saved_texture = state.bound_textures[0];
+    let saved_atlas_sprite = state.bound_atlas_sprite;
+    state.update_color(color);
+    state.bound_textures[0] = texture;
+    state.bound_atlas_sprite = None;
+
+    let vp = state.current_viewport;
+    let shading_state_index = state.add_shading_state();
+    let view_idx = (state.view_matrices.len() - 1) as u32;
+    for &(py, ph, v0, v1) in &rows {
+        for &(px, pw, u0, u1) in &columns {
+            if pw <= 0.0 || ph <= 0.0 {
+                continue;
+            }
+            let instance = crate::graphics::QuadInstance::sprite(
+                vp.x as f32 + px,
+                vp.y as f32 + py,
+                SCREEN_SPACE_DEPTH,
+                pw,
+                ph,
+                0.0,
+                [u0, v0, u1, v1],
+                shading_state_index.0,
+                view_idx,
+            );
+            state.add_quad_instance(instance, state.current_z_index);
+        }
+    }
+
+    state.bound_textures[0] = saved_texture;
+    state.bound_atlas_sprite = saved_atlas_sprite;
+    state.update_color(saved_color);
+}
+
+/// Split one axis into (position, size, uv start, uv end) for the start
+/// border, middle and end border
+fn slice_axis(
+    pos: f32,
+    size: f32,
+    start: f32,
+    end: f32,
+    tex_size: f32,
+) -> [(f32, f32, f32, f32); 3] {
+    let scale = if start + end > size {
+        size / (start + end)
+    } else {
+        1.0
+    };
+    let (start_px, end_px) = (start * scale, end * scale);
+    let (uv_start, uv_end) = (start / tex_size, 1.0 - end / tex_size);
+
+    [
+        (pos, start_px, 0.0, uv_start),
+        (pos + start_px, size - start_px - end_px, uv_start, uv_end),
+        (pos + size - end_px, end_px, uv_end, 1.0),
+    ]
+}
@@ -6,6 +6,7 @@ use anyhow::Result;
 use wasmtime::{Caller, Linker};
 
 use crate::ffi::ZXGameContext;
+use crate::state::ZXFFIState;
 
 use super::SCREEN_SPACE_DEPTH;
 
@@ -32,8 +33,14 @@ pub(super) fn register(linker: &mut Linker<ZXGameContext>) -> Result<()> {
 /// Draws an untextured quad. Useful for UI backgrounds, health bars, etc.
 /// Uses color from set_color().
 fn draw_rect(mut caller: Caller<'_, ZXGameContext>, x: f32, y: f32, w: f32, h: f32) {
-    let state = &mut caller.data_mut().ffi;
+    push_rect(&mut caller.data_mut().ffi, x, y, w, h);
+}
 
+/// Record a solid rectangle quad
+///
+/// Shared by `draw_rect()` and host-drawn overlays. Coordinates are relative
+/// to the current viewport, and the color comes from the current shading state.
+pub(crate) fn push_rect(state: &mut ZXFFIState, x: f32, y: f32, w: f32, h: f32) {
     // Offset by viewport origin for split-screen support
     let vp = state.current_viewport;
     let screen_x = vp.x as f32 + x;
@@ -82,8 +89,21 @@ fn draw_line(
     y2: f32,
     thickness: f32,
 ) {
-    let state = &mut caller.data_mut().ffi;
+    push_line(&mut caller.data_mut().ffi, x1, y1, x2, y2, thickness);
+}
 
+/// Record a line quad between two points
+///
+/// Shared by `draw_line()` and the debug-draw gizmos. Coordinates are relative
+/// to the current viewport, and the color comes from the current shading state.
+pub(crate) fn push_line(
+    state: &mut ZXFFIState,
+    x1: f32,
+    y1: f32,
+    x2: f32,
+    y2: f32,
+    thickness: f32,
+) {
     // Offset by viewport origin for split-screen support
     let vp = state.current_viewport;
     let screen_x1 = vp.x as f32 + x1;
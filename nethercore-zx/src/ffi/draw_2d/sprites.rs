@@ -25,7 +25,8 @@ pub(super) fn register(linker: &mut Linker<ZXGameContext>) -> Result<()> {
 /// * `w` — Sprite width in pixels
 /// * `h` — Sprite height in pixels
 ///
-/// Draws the full texture (UV 0,0 to 1,1) as a quad in screen space.
+/// Draws the full texture (UV 0,0 to 1,1) as a quad in screen space, or the
+/// whole sprite selected with `atlas_bind()`.
 /// Uses current blend mode, bound texture (slot 0), and color from set_color().
 fn draw_sprite(mut caller: Caller<'_, ZXGameContext>, x: f32, y: f32, w: f32, h: f32) {
     let state = &mut caller.data_mut().ffi;
@@ -51,8 +52,8 @@ fn draw_sprite(mut caller: Caller<'_, ZXGameContext>, x: f32, y: f32, w: f32, h:
         depth,
         w,
         h,
-        0.0,                  // No rotation
-        [0.0, 0.0, 1.0, 1.0], // Full texture UV
+        0.0,                                  // No rotation
+        state.atlas_uv([0.0, 0.0, 1.0, 1.0]), // Full texture (or atlas sprite) UV
         shading_state_index.0,
         view_idx,
     );
@@ -73,6 +74,7 @@ fn draw_sprite(mut caller: Caller<'_, ZXGameContext>, x: f32, y: f32, w: f32, h:
 /// * `src_h` — Source texture height (0.0-1.0)
 ///
 /// Useful for sprite sheets and texture atlases. Uses color from set_color().
+/// After `atlas_bind()`, the source region is relative to the bound atlas sprite.
 fn draw_sprite_region(
     mut caller: Caller<'_, ZXGameContext>,
     x: f32,
@@ -110,8 +112,8 @@ fn draw_sprite_region(
         depth,
         w,
         h,
-        0.0,              // No rotation
-        [u0, v0, u1, v1], // Texture UV region
+        0.0,                              // No rotation
+        state.atlas_uv([u0, v0, u1, v1]), // Texture UV region
         shading_state_index.0,
         (state.view_matrices.len() - 1) as u32,
     );
@@ -179,7 +181,7 @@ fn draw_sprite_ex(
         w,
         h,
         angle_deg.to_radians(), // Convert degrees to radians
-        [u0, v0, u1, v1],
+        state.atlas_uv([u0, v0, u1, v1]),
         shading_state_index.0,
         (state.view_matrices.len() - 1) as u32,
     );
@@ -100,3 +100,455 @@ fn test_color_optimization() {
         "Should be dirty when color changes"
     );
 }
+
+/// Test that clip rectangles are viewport-relative and nest by intersection
+#[test]
+fn test_clip_push_nests_within_viewport() {
+    use super::clip::push_clip;
+    use crate::graphics::Viewport;
+
+    let mut state = ZXFFIState::new();
+    state.current_viewport = Viewport {
+        x: 480,
+        y: 0,
+        width: 480,
+        height: 540,
+    };
+
+    assert!(push_clip(&mut state, 10.0, 20.0, 100.0, 50.0));
+    assert_eq!(
+        state.clip_stack.last(),
+        Some(&Viewport {
+            x: 490,
+            y: 20,
+            width: 100,
+            height: 50,
+        })
+    );
+
+    // Child extends past its parent on the right and bottom
+    assert!(push_clip(&mut state, 60.0, 40.0, 200.0, 200.0));
+    assert_eq!(
+        state.clip_stack.last(),
+        Some(&Viewport {
+            x: 540,
+            y: 40,
+            width: 50,
+            height: 30,
+        })
+    );
+
+    // Popping restores the parent
+    state.clip_stack.pop();
+    assert_eq!(state.clip_stack.last().map(|c| c.x), Some(490));
+
+    // A clip outside the viewport is clamped to it
+    state.clip_stack.clear();
+    assert!(push_clip(&mut state, -50.0, 500.0, 100.0, 100.0));
+    assert_eq!(
+        state.clip_stack.last(),
+        Some(&Viewport {
+            x: 480,
+            y: 500,
+            width: 50,
+            height: 40,
+        })
+    );
+}
+
+/// Test that clipped quads get their own batch and empty clips draw nothing
+#[test]
+fn test_clip_splits_quad_batches() {
+    use super::clip::push_clip;
+    use super::push_rect;
+
+    let mut state = ZXFFIState::new();
+
+    push_rect(&mut state, 0.0, 0.0, 10.0, 10.0);
+    push_clip(&mut state, 0.0, 0.0, 100.0, 100.0);
+    push_rect(&mut state, 0.0, 0.0, 10.0, 10.0);
+    push_rect(&mut state, 20.0, 0.0, 10.0, 10.0);
+
+    let batches = state.quad_batches();
+    assert_eq!(batches.len(), 2);
+    assert_eq!(batches[0].clip, None);
+    assert_eq!(batches[1].clip.map(|c| c.width), Some(100));
+    assert_eq!(batches[1].instances.len(), 2);
+
+    // Disjoint nested clip leaves nothing visible
+    push_clip(&mut state, 200.0, 200.0, 10.0, 10.0);
+    push_rect(&mut state, 0.0, 0.0, 10.0, 10.0);
+    assert_eq!(state.quad_batches().len(), 2);
+
+    // Unbalanced pushes don't leak into the next frame
+    state.clear_frame();
+    assert!(state.clip_stack.is_empty());
+}
+
+/// Test that blend mode changes split 2D batches and reset each frame
+#[test]
+fn test_blend_mode_splits_quad_batches() {
+    use super::push_rect;
+    use crate::graphics::BlendMode;
+
+    let mut state = ZXFFIState::new();
+
+    push_rect(&mut state, 0.0, 0.0, 10.0, 10.0);
+    state.current_blend_mode_2d = BlendMode::Additive;
+    push_rect(&mut state, 0.0, 0.0, 10.0, 10.0);
+    push_rect(&mut state, 20.0, 0.0, 10.0, 10.0);
+
+    let batches = state.quad_batches();
+    assert_eq!(batches.len(), 2);
+    assert_eq!(batches[0].blend_mode, BlendMode::Alpha);
+    assert_eq!(batches[1].blend_mode, BlendMode::Additive);
+    assert_eq!(batches[1].instances.len(), 2);
+
+    state.clear_frame();
+    assert_eq!(state.current_blend_mode_2d, BlendMode::Alpha);
+}
+
+/// Test that path text follows each segment and stops at the end of the path
+#[test]
+fn test_text_path_follows_segments() {
+    use super::text::push_text_path;
+    use glam::Vec2;
+
+    let mut state = ZXFFIState::new();
+
+    // 20px right, then 40px down; built-in glyphs are 8px at size 8
+    let path = [
+        Vec2::new(0.0, 100.0),
+        Vec2::new(20.0, 100.0),
+        Vec2::new(20.0, 140.0),
+    ];
+    push_text_path(&mut state, "ABCDEFGHI", &path, 8.0);
+
+    let instances = &state.quad_batches()[0].instances;
+    // 9th glyph is centered 68px along a 60px path
+    assert_eq!(instances.len(), 8);
+
+    // Along the first segment glyphs stand on top of the path
+    assert_eq!(instances[0].position[..2], [0.0, 92.0]);
+    assert_eq!(instances[0].rotation, 0.0);
+    assert_eq!(instances[2].position[..2], [16.0, 92.0]);
+
+    // Going down the second segment they lean out to the path's left (+X)
+    let turned = &instances[3];
+    assert_eq!(turned.position[..2], [20.0, 104.0]);
+    assert!((turned.rotation - std::f32::consts::FRAC_PI_2).abs() < 1e-6);
+}
+
+/// Test that multi-range fonts lay out UTF-8 text across all glyph pages
+#[test]
+fn test_font_ranges_decode_utf8() {
+    use super::text::push_text;
+    use crate::state::{Font, FontRange};
+
+    let mut state = ZXFFIState::new();
+    // 128×128 atlas of 8×8 cells: 16 glyphs per row
+    state.fonts.push(Font {
+        texture: 1,
+        atlas_width: 128,
+        atlas_height: 128,
+        char_width: 8,
+        char_height: 8,
+        ranges: vec![
+            FontRange {
+                first_codepoint: 'A' as u32,
+                char_count: 26,
+                first_glyph: 0,
+            },
+            FontRange {
+                first_codepoint: 0x30A2, // ア
+                char_count: 4,
+                first_glyph: 26,
+            },
+        ],
+        char_widths: None,
+    });
+    state.current_font = 1;
+
+    let font = &state.fonts[0];
+    assert_eq!(font.glyph_count(), 30);
+    assert_eq!(font.glyph_index('B' as u32), Some(1));
+    assert_eq!(font.glyph_index(0x30A3), Some(27));
+    assert_eq!(font.glyph_index('é' as u32), None);
+
+    // 'é' has no glyph and is skipped; 'ア' is glyph 26 (row 1, column 10)
+    push_text(&mut state, "AéBア", 0.0, 0.0, 8.0);
+    let instances = &state.quad_batches()[0].instances;
+    assert_eq!(instances.len(), 3);
+    assert_eq!(instances[2].position[0], 16.0);
+    assert_eq!(instances[2].uv[..2], [80.0 / 128.0, 8.0 / 128.0]);
+}
+
+/// Test that offscreen indicators clamp to the viewport edge facing the target
+#[test]
+fn test_offscreen_indicator_clamps_to_edge() {
+    use super::indicator::push_offscreen_indicator;
+    use glam::{Mat4, Vec3};
+
+    let mut state = ZXFFIState::new();
+    state.current_view_matrix = Some(Mat4::look_at_rh(
+        Vec3::new(0.0, 0.0, 5.0),
+        Vec3::ZERO,
+        Vec3::Y,
+    ));
+    state.current_proj_matrix = Some(Mat4::perspective_rh(
+        60f32.to_radians(),
+        960.0 / 540.0,
+        0.1,
+        100.0,
+    ));
+    state.bound_textures[0] = 7;
+
+    // Visible targets draw nothing
+    assert!(!push_offscreen_indicator(
+        &mut state,
+        Vec3::ZERO,
+        3,
+        0xFF0000FF
+    ));
+    assert_eq!(state.quad_batches().len(), 0);
+
+    // Far to the right: arrow (2 lines) + icon, centered vertically on the right edge
+    assert!(push_offscreen_indicator(
+        &mut state,
+        Vec3::new(100.0, 0.0, 0.0),
+        3,
+        0xFF0000FF
+    ));
+    let batches = state.quad_batches();
+    assert_eq!(batches.len(), 2);
+    assert_eq!(batches[0].instances.len(), 2);
+    assert_eq!(batches[1].textures[0], 3);
+    let icon = &batches[1].instances[0];
+    assert!((icon.position[0] - (960.0 - 4.0 - 8.0 - 24.0)).abs() < 0.5);
+    assert!((icon.position[1] - (270.0 - 12.0)).abs() < 0.5);
+
+    // Behind and to the left still points left
+    assert!(push_offscreen_indicator(
+        &mut state,
+        Vec3::new(-3.0, 0.0, 10.0),
+        0,
+        0xFF0000FF
+    ));
+    let behind = &state.quad_batches()[2].instances[0];
+    assert!(behind.position[0] < 100.0);
+
+    // Bound texture and color are restored
+    assert_eq!(state.bound_textures[0], 7);
+    assert_eq!(state.current_shading_state.color_rgba8, 0xFFFFFFFF);
+}
+
+/// Test that markers scale with depth on screen and fall back to edge indicators
+#[test]
+fn test_marker_scales_and_switches_to_indicator() {
+    use super::marker::push_marker;
+    use glam::{Mat4, Vec3};
+
+    let mut state = ZXFFIState::new();
+    state.current_view_matrix = Some(Mat4::look_at_rh(
+        Vec3::new(0.0, 0.0, 5.0),
+        Vec3::ZERO,
+        Vec3::Y,
+    ));
+    state.current_proj_matrix = Some(Mat4::perspective_rh(
+        60f32.to_radians(),
+        960.0 / 540.0,
+        0.1,
+        100.0,
+    ));
+    state.bound_textures[0] = 7;
+
+    // Close target: full-size icon centered on screen, label centered below it
+    assert!(push_marker(&mut state, Vec3::ZERO, 3, "GO"));
+    let batches = state.quad_batches();
+    assert_eq!(batches.len(), 2);
+    let icon = &batches[0].instances[0];
+    assert_eq!(icon.size, [32.0, 32.0]);
+    assert!((icon.position[0] - (480.0 - 16.0)).abs() < 0.5);
+    assert!((icon.position[1] - (270.0 - 16.0)).abs() < 0.5);
+    let label = &batches[1].instances;
+    assert_eq!(label.len(), 2);
+    assert!((label[0].position[0] - (480.0 - 8.0)).abs() < 0.5);
+    assert!((label[0].position[1] - (270.0 + 16.0 + 2.0)).abs() < 0.5);
+
+    // Distant target: icon shrinks to half size at twice the reference depth
+    assert!(push_marker(&mut state, Vec3::new(0.0, 0.0, -11.0), 3, ""));
+    let far = state
+        .quad_batches()
+        .last()
+        .unwrap()
+        .instances
+        .last()
+        .unwrap();
+    assert_eq!(far.size, [16.0, 16.0]);
+
+    // Offscreen target: drawn as an edge indicator instead
+    assert!(!push_marker(
+        &mut state,
+        Vec3::new(100.0, 0.0, 0.0),
+        3,
+        "GO"
+    ));
+
+    // Bound texture is restored
+    assert_eq!(state.bound_textures[0], 7);
+}
+
+/// Test that toasts stack in the top-right corner in their own pass
+#[test]
+fn test_toasts_stack_in_corner() {
+    use super::draw_toasts;
+    use crate::graphics::Viewport;
+
+    let mut state = ZXFFIState::new();
+    state.current_viewport = Viewport {
+        x: 480,
+        y: 0,
+        width: 480,
+        height: 540,
+    };
+    state.current_z_index = 3;
+    state.bound_textures[0] = 7;
+    state.update_color(0xFF0000FF);
+
+    // No toasts: nothing is drawn and no pass is added
+    draw_toasts(&mut state);
+    assert!(state.quad_batches().is_empty());
+    assert_eq!(state.pass_configs.len(), 1);
+
+    assert!(state.toasts.push(0, "P2 DOWNED", 0, 3.0));
+    assert!(state.toasts.push(0, "LAP", 5, 3.0));
+    draw_toasts(&mut state);
+
+    let batches = state.quad_batches();
+    assert!(
+        batches
+            .iter()
+            .all(|b| b.pass_id == 1 && b.z_index == u32::MAX)
+    );
+    assert!(batches.iter().all(|b| b.viewport == Viewport::FULLSCREEN));
+
+    // First toast: panel flush with the right margin, text inside it
+    let panel = &batches[0].instances[0];
+    assert_eq!(panel.position[0] + panel.size[0], 960.0 - 8.0);
+    assert_eq!(panel.position[1], 8.0);
+    assert_eq!(panel.size[1], 28.0);
+    assert_eq!(batches[1].instances.len(), 9);
+
+    // Second toast sits below the first, with its icon left of the text
+    let panel = &batches[2].instances[0];
+    assert_eq!(panel.position[1], 8.0 + 28.0 + 4.0);
+    assert_eq!(batches[3].textures[0], 5);
+    assert_eq!(batches[3].instances[0].size, [16.0, 16.0]);
+
+    // Game drawing state is restored
+    assert_eq!(state.current_viewport.x, 480);
+    assert_eq!(state.current_z_index, 3);
+    assert_eq!(state.bound_textures[0], 7);
+    assert_eq!(state.current_shading_state.color_rgba8, 0xFF0000FF);
+}
+
+/// Test that thumbnails get a texture handle once and re-upload only on change
+#[test]
+fn test_thumbnail_uploads_on_new_capture() {
+    use super::thumbnail::push_thumbnail;
+    use nethercore_core::thumbnail::{THUMBNAIL_SIZE, ThumbnailStore};
+
+    let mut state = ZXFFIState::new();
+    let mut store = ThumbnailStore::default();
+    state.bound_textures[0] = 7;
+    let first_handle = state.next_texture_handle;
+
+    // Empty slot: nothing drawn, no handle reserved
+    assert!(!push_thumbnail(
+        &mut state, &store, 2, 0.0, 0.0, 160.0, 90.0
+    ));
+    assert!(state.quad_batches().is_empty());
+    assert_eq!(state.next_texture_handle, first_handle);
+
+    store.set_slot(2, vec![128; THUMBNAIL_SIZE]);
+    assert!(push_thumbnail(
+        &mut state, &store, 2, 10.0, 20.0, 160.0, 90.0
+    ));
+    assert_eq!(state.thumbnail_handles[2], first_handle);
+    assert_eq!(state.next_texture_handle, first_handle + 1);
+    assert_eq!(state.pending_thumbnails.len(), 1);
+    assert_eq!(state.pending_thumbnails[0].handle, first_handle);
+
+    let batch = &state.quad_batches()[0];
+    assert_eq!(batch.textures[0], first_handle);
+    assert_eq!(batch.instances[0].position[0], 10.0);
+    assert_eq!(batch.instances[0].size, [160.0, 90.0]);
+
+    // Same capture: drawn again without another upload
+    assert!(push_thumbnail(&mut state, &store, 2, 0.0, 0.0, 80.0, 45.0));
+    assert_eq!(state.pending_thumbnails.len(), 1);
+
+    // New capture: re-uploaded into the same handle
+    store.set_slot(2, vec![64; THUMBNAIL_SIZE]);
+    assert!(push_thumbnail(&mut state, &store, 2, 0.0, 0.0, 80.0, 45.0));
+    assert_eq!(state.pending_thumbnails.len(), 2);
+    assert_eq!(state.pending_thumbnails[1].handle, first_handle);
+    assert_eq!(state.next_texture_handle, first_handle + 1);
+
+    // Bound texture is restored
+    assert_eq!(state.bound_textures[0], 7);
+}
+
+/// Test that nine-slice panels keep their borders and shrink them when too small
+#[test]
+fn test_nine_slice_keeps_corners() {
+    use super::nine_slice::push_nine_slice;
+
+    let mut state = ZXFFIState::new();
+    state.bound_textures[0] = 7;
+
+    // 32x32 texture with 8px borders stretched to a 200x100 panel
+    push_nine_slice(
+        &mut state,
+        3,
+        [10.0, 20.0, 200.0, 100.0],
+        [8.0, 8.0, 8.0, 8.0],
+        (32, 32),
+        0xFF0000FF,
+    );
+    let batch = &state.quad_batches()[0];
+    assert_eq!(batch.textures[0], 3);
+    assert_eq!(batch.instances.len(), 9);
+
+    let corner = &batch.instances[0];
+    assert_eq!(corner.position[..2], [10.0, 20.0]);
+    assert_eq!(corner.size, [8.0, 8.0]);
+    assert_eq!(corner.uv, [0.0, 0.0, 0.25, 0.25]);
+
+    let center = &batch.instances[4];
+    assert_eq!(center.position[..2], [18.0, 28.0]);
+    assert_eq!(center.size, [184.0, 84.0]);
+    assert_eq!(center.uv, [0.25, 0.25, 0.75, 0.75]);
+
+    let last = &batch.instances[8];
+    assert_eq!(last.position[..2], [202.0, 112.0]);
+    assert_eq!(last.uv, [0.75, 0.75, 1.0, 1.0]);
+
+    // Narrower than its borders: borders shrink to fit and the middle column is skipped
+    push_nine_slice(
+        &mut state,
+        3,
+        [0.0, 0.0, 8.0, 100.0],
+        [8.0, 8.0, 8.0, 8.0],
+        (32, 32),
+        0xFF0000FF,
+    );
+    let instances = &state.quad_batches()[0].instances[9..];
+    assert_eq!(instances.len(), 6);
+    assert_eq!(instances[0].size, [4.0, 8.0]);
+    assert_eq!(instances[1].position[0], 4.0);
+
+    // Bound texture and color are restored
+    assert_eq!(state.bound_textures[0], 7);
+    assert_eq!(state.current_shading_state.color_rgba8, 0xFFFFFFFF);
+}
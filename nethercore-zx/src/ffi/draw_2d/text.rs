@@ -3,26 +3,27 @@
 //! Functions for drawing text and managing custom fonts.
 
 use anyhow::Result;
+use glam::Vec2;
 use tracing::warn;
 use wasmtime::{Caller, Linker};
 
 use crate::ffi::ZXGameContext;
-use crate::state::Font;
+use crate::ffi::helpers::{read_wasm_bytes, read_wasm_floats};
+use crate::state::{Font, FontRange, ZXFFIState};
 
 use super::SCREEN_SPACE_DEPTH;
 
-/// Custom font data: (texture, atlas_width, atlas_height, char_width, char_height, first_char, char_count, char_widths)
-type CustomFontData = (u32, u32, u32, u8, u8, u32, u32, Option<Vec<u8>>);
-
 /// Default font texture size used when texture dimensions cannot be determined.
 const DEFAULT_FONT_TEXTURE_SIZE: (u32, u32) = (1024, 1024);
 
 /// Register text and font FFI functions
 pub(super) fn register(linker: &mut Linker<ZXGameContext>) -> Result<()> {
     linker.func_wrap("env", "draw_text", draw_text)?;
+    linker.func_wrap("env", "draw_text_path", draw_text_path)?;
     linker.func_wrap("env", "text_width", text_width)?;
     linker.func_wrap("env", "load_font", load_font)?;
     linker.func_wrap("env", "load_font_ex", load_font_ex)?;
+    linker.func_wrap("env", "font_add_range", font_add_range)?;
     linker.func_wrap("env", "font_bind", font_bind)?;
     Ok(())
 }
@@ -79,13 +80,166 @@ fn draw_text(mut caller: Caller<'_, ZXGameContext>, ptr: u32, len: u32, x: f32,
         return;
     }
 
+    push_text(&mut caller.data_mut().ffi, &text_str, x, y, size);
+}
+
+/// Draw text along a path
+///
+/// # Arguments
+/// * `ptr` — Pointer to UTF-8 string data
+/// * `len` — Length of string in bytes
+/// * `points_ptr` — Pointer to `count` × 2 f32 (x, y) screen points forming a polyline
+/// * `count` — Number of points (at least 2)
+/// * `size` — Font size in pixels
+/// * `color` — Text color (0xRRGGBBAA)
+///
+/// Text starts at the first point and follows the path, each glyph rotated to
+/// the segment under it. Glyphs sit on the path and lean out to its left (above
+/// it when the path runs left to right). Text past the end of the path is cut off.
+/// The color applies to this call only; `set_color()` is left unchanged.
+fn draw_text_path(
+    mut caller: Caller<'_, ZXGameContext>,
+    ptr: u32,
+    len: u32,
+    points_ptr: u32,
+    count: u32,
+    size: f32,
+    color: u32,
+) {
+    const FN_NAME: &str = "draw_text_path";
+
+    if count < 2 {
+        warn!("{}: path needs at least 2 points, got {}", FN_NAME, count);
+        return;
+    }
+
+    let Some(bytes) = read_wasm_bytes(&caller, ptr, len as usize, FN_NAME) else {
+        return;
+    };
+    let Ok(text) = String::from_utf8(bytes) else {
+        warn!("{}: invalid UTF-8 string", FN_NAME);
+        return;
+    };
+    if text.is_empty() {
+        return;
+    }
+
+    let Some(coords) = read_wasm_floats(&caller, points_ptr, count as usize * 2, FN_NAME) else {
+        return;
+    };
+    let path: Vec<Vec2> = coords.chunks_exact(2).map(Vec2::from_slice).collect();
+
     let state = &mut caller.data_mut().ffi;
+    let previous_color = state.current_shading_state.color_rgba8;
+    state.update_color(color);
+    push_text_path(state, &text, &path, size);
+    state.update_color(previous_color);
+}
 
+/// Record text quads for `text` using the currently bound font
+///
+/// Shared by `draw_text()` and host-drawn overlays. Coordinates are relative
+/// to the current viewport, and the color comes from the current shading state.
+pub(crate) fn push_text(state: &mut ZXFFIState, text: &str, x: f32, y: f32, size: f32) {
     // Offset by viewport origin for split-screen support
     let vp = state.current_viewport;
     let screen_x = vp.x as f32 + x;
     let screen_y = vp.y as f32 + y;
 
+    let (shading_state_index, view_idx, glyphs) = prepare_text(state, text, size);
+
+    // Generate quad instances for each character
+    let mut cursor_x = screen_x;
+    for glyph in glyphs {
+        let instance = crate::graphics::QuadInstance::sprite(
+            cursor_x,
+            screen_y,
+            SCREEN_SPACE_DEPTH,
+            glyph.width,
+            glyph.height,
+            0.0, // no rotation
+            glyph.uv,
+            shading_state_index,
+            view_idx,
+        );
+        state.add_quad_instance(instance, state.current_z_index);
+
+        cursor_x += glyph.width;
+    }
+}
+
+/// Record text quads for `text` laid out along a polyline
+///
+/// Each glyph is centered on the path at its distance from the first point and
+/// rotated to the segment it lands on. Glyphs stand on the left side of the
+/// path (above it when the path runs left to right); glyphs past the end of the
+/// path are dropped. Coordinates are relative to the current viewport.
+pub(crate) fn push_text_path(state: &mut ZXFFIState, text: &str, path: &[Vec2], size: f32) {
+    if path.len() < 2 {
+        return;
+    }
+
+    // Offset by viewport origin for split-screen support
+    let vp = state.current_viewport;
+    let origin = Vec2::new(vp.x as f32, vp.y as f32);
+
+    let (shading_state_index, view_idx, glyphs) = prepare_text(state, text, size);
+
+    let mut segments = path.windows(2).map(|w| (w[0], w[1]));
+    let Some((mut start, mut end)) = segments.next() else {
+        return;
+    };
+    // Distance along the path where the current segment starts
+    let mut segment_start = 0.0;
+    let mut cursor = 0.0;
+
+    for glyph in glyphs {
+        let center_distance = cursor + glyph.width * 0.5;
+        cursor += glyph.width;
+
+        // Advance to the segment containing the glyph's center
+        while center_distance > segment_start + start.distance(end) {
+            segment_start += start.distance(end);
+            match segments.next() {
+                Some(segment) => (start, end) = segment,
+                None => return,
+            }
+        }
+
+        let direction = (end - start).normalize_or(Vec2::X);
+        let on_path = start + direction * (center_distance - segment_start);
+        // Screen space is y-down, so the left-hand normal points "up" the glyph
+        let up = Vec2::new(direction.y, -direction.x);
+        let center = origin + on_path + up * (glyph.height * 0.5);
+
+        let instance = crate::graphics::QuadInstance::sprite(
+            center.x - glyph.width * 0.5,
+            center.y - glyph.height * 0.5,
+            SCREEN_SPACE_DEPTH,
+            glyph.width,
+            glyph.height,
+            direction.y.atan2(direction.x),
+            glyph.uv,
+            shading_state_index,
+            view_idx,
+        );
+        state.add_quad_instance(instance, state.current_z_index);
+    }
+}
+
+/// Size and atlas UV of one laid-out glyph
+struct Glyph {
+    width: f32,
+    height: f32,
+    uv: [f32; 4],
+}
+
+/// Set up text rendering state and lay out the glyphs of `text`
+///
+/// Binds the current font, forces nearest filtering, and returns the shading
+/// state index, view index and glyphs (characters missing from a custom font
+/// are skipped).
+fn prepare_text(state: &mut ZXFFIState, text: &str, size: f32) -> (u32, u32, Vec<Glyph>) {
     // Text always uses nearest filtering (crisp pixels, no blurry interpolation)
     state.texture_filter = crate::graphics::TextureFilter::Nearest;
     state.update_texture_filter(false);
@@ -99,108 +253,62 @@ fn draw_text(mut caller: Caller<'_, ZXGameContext>, ptr: u32, len: u32, x: f32,
     }
     let view_idx = (state.view_matrices.len() - 1) as u32;
 
-    // Convert layer to depth for ordering
-    let depth = SCREEN_SPACE_DEPTH;
-
     // Determine which font to use
     let font_handle = state.current_font;
 
-    // Extract font data into local variables to avoid cloning the entire Font struct.
-    // Only the char_widths Vec is cloned (if present), all other fields are Copy types.
-    let custom_font_data: Option<CustomFontData> = if font_handle == 0 {
-        None
-    } else {
-        let font_index = (font_handle - 1) as usize;
-        state.fonts.get(font_index).map(|font| {
-            (
-                font.texture,
-                font.atlas_width,
-                font.atlas_height,
-                font.char_width,
-                font.char_height,
-                font.first_codepoint,
-                font.char_count,
-                font.char_widths.clone(), // Only clone the widths Vec, not the whole Font
-            )
-        })
+    let custom_font = match font_handle {
+        0 => None,
+        handle => state.fonts.get((handle - 1) as usize),
     };
 
     // Bind the appropriate font texture to slot 0
-    if let Some((texture, ..)) = custom_font_data {
-        state.bound_textures[0] = texture;
-    } else {
+    state.bound_textures[0] = match custom_font {
+        Some(font) => font.texture,
         // For built-in font, use reserved handle (u32::MAX - 1)
         // This handle is mapped to the actual built-in font texture at startup
-        state.bound_textures[0] = u32::MAX - 1;
-    }
+        None => u32::MAX - 1,
+    };
 
-    // Generate quad instances for each character
-    let mut cursor_x = screen_x;
+    let mut glyphs = Vec::with_capacity(text.len());
 
-    if let Some((
-        _texture,
-        atlas_width,
-        atlas_height,
-        char_width,
-        char_height,
-        first_codepoint,
-        char_count,
-        ref char_widths,
-    )) = custom_font_data
-    {
+    if let Some(font) = custom_font {
         // Custom font rendering
-        let scale = size / char_height as f32;
+        let scale = size / font.char_height as f32;
         let glyph_height = size;
 
         // Use stored atlas dimensions
-        let texture_width = atlas_width;
-        let texture_height = atlas_height;
-
-        let max_glyph_width = char_width as u32;
-        let glyphs_per_row = texture_width / max_glyph_width.max(1);
+        let texture_width = font.atlas_width;
+        let texture_height = font.atlas_height;
 
-        for ch in text_str.chars() {
-            let char_code = ch as u32;
+        let max_glyph_width = font.char_width as u32;
+        let glyphs_per_row = (texture_width / max_glyph_width.max(1)).max(1) as usize;
+        let char_height = font.char_height as usize;
 
-            // Calculate glyph index
-            if char_code < first_codepoint || char_code >= first_codepoint + char_count {
-                // Character not in font, skip or use replacement
+        for ch in text.chars() {
+            // Character not in any of the font's ranges, skip it
+            let Some(glyph_index) = font.glyph_index(ch as u32) else {
                 continue;
-            }
-            let glyph_index = (char_code - first_codepoint) as usize;
+            };
 
             // Get glyph width (variable or fixed)
-            let glyph_width_px = char_widths
-                .as_ref()
-                .and_then(|widths| widths.get(glyph_index).copied())
-                .unwrap_or(char_width);
+            let glyph_width_px = font.glyph_width(glyph_index);
             let glyph_width = glyph_width_px as f32 * scale;
 
             // Calculate UV coordinates
-            let col = glyph_index % glyphs_per_row as usize;
-            let row = glyph_index / glyphs_per_row as usize;
+            let col = glyph_index % glyphs_per_row;
+            let row = glyph_index / glyphs_per_row;
 
             let u0 = (col * max_glyph_width as usize) as f32 / texture_width as f32;
-            let v0 = (row * char_height as usize) as f32 / texture_height as f32;
+            let v0 = (row * char_height) as f32 / texture_height as f32;
             let u1 = ((col * max_glyph_width as usize) + glyph_width_px as usize) as f32
                 / texture_width as f32;
-            let v1 = ((row + 1) * char_height as usize) as f32 / texture_height as f32;
-
-            // Create quad instance for this glyph
-            let instance = crate::graphics::QuadInstance::sprite(
-                cursor_x,
-                screen_y,
-                depth,
-                glyph_width,
-                glyph_height,
-                0.0, // no rotation
-                [u0, v0, u1, v1],
-                shading_state_index.0,
-                view_idx,
-            );
-            state.add_quad_instance(instance, state.current_z_index);
+            let v1 = ((row + 1) * char_height) as f32 / texture_height as f32;
 
-            cursor_x += glyph_width;
+            glyphs.push(Glyph {
+                width: glyph_width,
+                height: glyph_height,
+                uv: [u0, v0, u1, v1],
+            });
         }
     } else {
         // Built-in font rendering
@@ -208,29 +316,19 @@ fn draw_text(mut caller: Caller<'_, ZXGameContext>, ptr: u32, len: u32, x: f32,
         let glyph_width = crate::font::GLYPH_WIDTH as f32 * scale;
         let glyph_height = crate::font::GLYPH_HEIGHT as f32 * scale;
 
-        for ch in text_str.chars() {
-            let char_code = ch as u32;
-
+        for ch in text.chars() {
             // Get UV coordinates for this character
-            let (u0, v0, u1, v1) = crate::font::get_glyph_uv(char_code);
-
-            // Create quad instance for this glyph
-            let instance = crate::graphics::QuadInstance::sprite(
-                cursor_x,
-                screen_y,
-                depth,
-                glyph_width,
-                glyph_height,
-                0.0, // no rotation
-                [u0, v0, u1, v1],
-                shading_state_index.0,
-                view_idx,
-            );
-            state.add_quad_instance(instance, state.current_z_index);
+            let (u0, v0, u1, v1) = crate::font::get_glyph_uv(ch as u32);
 
-            cursor_x += glyph_width;
+            glyphs.push(Glyph {
+                width: glyph_width,
+                height: glyph_height,
+                uv: [u0, v0, u1, v1],
+            });
         }
     }
+
+    (shading_state_index.0, view_idx, glyphs)
 }
 
 /// Load a fixed-width bitmap font from a texture atlas
@@ -312,8 +410,11 @@ fn load_font(
         atlas_height,
         char_width: char_width as u8,
         char_height: char_height as u8,
-        first_codepoint,
-        char_count,
+        ranges: vec![FontRange {
+            first_codepoint,
+            char_count,
+            first_glyph: 0,
+        }],
         char_widths: None, // Fixed-width
     };
 
@@ -425,8 +526,11 @@ fn load_font_ex(
         atlas_height,
         char_width: max_char_width, // Max width for grid calculations
         char_height: char_height as u8,
-        first_codepoint,
-        char_count,
+        ranges: vec![FontRange {
+            first_codepoint,
+            char_count,
+            first_glyph: 0,
+        }],
         char_widths: Some(widths),
     };
 
@@ -434,6 +538,110 @@ fn load_font_ex(
     handle
 }
 
+/// Add another codepoint range to a loaded font
+///
+/// Glyphs for the new range continue in the atlas grid right after the glyphs
+/// of the font's existing ranges, so one texture can hold several glyph pages
+/// (e.g. ASCII, then Latin-1, then Katakana).
+///
+/// # Arguments
+/// * `font_handle` — Font handle from load_font() or load_font_ex()
+/// * `first_codepoint` — Unicode codepoint of the range's first glyph
+/// * `char_count` — Number of glyphs in the range
+/// * `widths_ptr` — Pointer to char_count u8 widths, or 0 to use the font's cell width
+///
+/// # Notes
+/// - Call this in `init()` - font loading is not allowed during gameplay
+/// - Ranges must not overlap the font's existing ranges
+/// - Widths may not exceed the font's cell width (the widest glyph at load time)
+fn font_add_range(
+    mut caller: Caller<'_, ZXGameContext>,
+    font_handle: u32,
+    first_codepoint: u32,
+    char_count: u32,
+    widths_ptr: u32,
+) {
+    const FN_NAME: &str = "font_add_range";
+
+    // Only allow during init
+    if !caller.data().game.in_init {
+        warn!("{}: can only be called during init()", FN_NAME);
+        return;
+    }
+
+    if char_count == 0 {
+        warn!("{}: char_count must be > 0", FN_NAME);
+        return;
+    }
+    let Some(last_codepoint) = first_codepoint.checked_add(char_count - 1) else {
+        warn!("{}: range overflows the codepoint space", FN_NAME);
+        return;
+    };
+
+    let widths = if widths_ptr == 0 {
+        None
+    } else {
+        match read_wasm_bytes(&caller, widths_ptr, char_count as usize, FN_NAME) {
+            Some(widths) => Some(widths),
+            None => return,
+        }
+    };
+
+    let state = &mut caller.data_mut().ffi;
+    let Some(font) = font_handle
+        .checked_sub(1)
+        .and_then(|index| state.fonts.get_mut(index as usize))
+    else {
+        warn!("{}: invalid font handle {}", FN_NAME, font_handle);
+        return;
+    };
+
+    let overlaps = font.ranges.iter().any(|range| {
+        first_codepoint < range.first_codepoint + range.char_count
+            && range.first_codepoint <= last_codepoint
+    });
+    if overlaps {
+        warn!(
+            "{}: range U+{:04X}..=U+{:04X} overlaps an existing range of font {}",
+            FN_NAME, first_codepoint, last_codepoint, font_handle
+        );
+        return;
+    }
+
+    if let Some(widths) = &widths
+        && widths.iter().any(|&w| w > font.char_width)
+    {
+        warn!(
+            "{}: glyph widths must not exceed the font's cell width ({})",
+            FN_NAME, font.char_width
+        );
+        return;
+    }
+
+    let first_glyph = font.glyph_count();
+
+    // Widths are only tracked once any range needs them; a fixed-width font
+    // becomes variable-width with its existing glyphs at the cell width.
+    match (&mut font.char_widths, widths) {
+        (Some(existing), Some(widths)) => existing.extend(widths),
+        (Some(existing), None) => {
+            existing.extend(std::iter::repeat_n(font.char_width, char_count as usize))
+        }
+        (None, Some(widths)) => {
+            let mut all = vec![font.char_width; first_glyph as usize];
+            all.extend(widths);
+            font.char_widths = Some(all);
+        }
+        (None, None) => {}
+    }
+
+    font.ranges.push(FontRange {
+        first_codepoint,
+        char_count,
+        first_glyph,
+    });
+}
+
 /// Bind a font for subsequent draw_text() calls
 ///
 /// # Arguments
@@ -494,7 +702,11 @@ fn text_width(caller: Caller<'_, ZXGameContext>, ptr: u32, len: u32, size: f32)
         return 0.0;
     }
 
-    let state = &caller.data().ffi;
+    measure_text(&caller.data().ffi, &text_str, size)
+}
+
+/// Width in pixels of `text` in the currently bound font at `size`
+pub(super) fn measure_text(state: &ZXFFIState, text: &str, size: f32) -> f32 {
     let font_handle = state.current_font;
 
     // Calculate width based on font type
@@ -502,7 +714,7 @@ fn text_width(caller: Caller<'_, ZXGameContext>, ptr: u32, len: u32, size: f32)
         // Built-in font: 8x8 fixed-width
         let scale = size / crate::font::GLYPH_HEIGHT as f32;
         let glyph_width = crate::font::GLYPH_WIDTH as f32 * scale;
-        text_str.chars().count() as f32 * glyph_width
+        text.chars().count() as f32 * glyph_width
     } else {
         // Custom font
         let font_index = (font_handle - 1) as usize;
@@ -510,23 +722,10 @@ fn text_width(caller: Caller<'_, ZXGameContext>, ptr: u32, len: u32, size: f32)
             let scale = size / font.char_height as f32;
 
             let mut total_width = 0.0f32;
-            for ch in text_str.chars() {
-                let char_code = ch as u32;
-
-                if char_code < font.first_codepoint
-                    || char_code >= font.first_codepoint + font.char_count
-                {
-                    continue;
+            for ch in text.chars() {
+                if let Some(glyph_index) = font.glyph_index(ch as u32) {
+                    total_width += font.glyph_width(glyph_index) as f32 * scale;
                 }
-                let glyph_index = (char_code - font.first_codepoint) as usize;
-
-                let glyph_width_px = font
-                    .char_widths
-                    .as_ref()
-                    .and_then(|widths| widths.get(glyph_index).copied())
-                    .unwrap_or(font.char_width);
-
-                total_width += glyph_width_px as f32 * scale;
             }
             total_width
         } else {
@@ -0,0 +1,103 @@
+//! Save slot thumbnail drawing
+//!
+//! Thumbnails are captured and stored by the host (see the common
+//! `thumbnail_capture()`); each slot gets a texture handle the first time it
+//! is drawn, and is re-uploaded whenever a newer capture replaces it.
+
+use anyhow::Result;
+use wasmtime::{Caller, Linker};
+
+use nethercore_core::thumbnail::{MAX_THUMBNAIL_SLOTS, ThumbnailStore};
+
+use crate::ffi::ZXGameContext;
+use crate::state::{PendingThumbnail, ZXFFIState};
+
+use super::SCREEN_SPACE_DEPTH;
+
+/// Register thumbnail drawing FFI functions
+pub(super) fn register(linker: &mut Linker<ZXGameContext>) -> Result<()> {
+    linker.func_wrap("env", "thumbnail_draw", thumbnail_draw)?;
+    Ok(())
+}
+
+/// Draw a save slot thumbnail
+///
+/// # Arguments
+/// * `slot` — Thumbnail slot (0-7)
+/// * `x` — Screen X coordinate in pixels (0 = left edge)
+/// * `y` — Screen Y coordinate in pixels (0 = top edge)
+/// * `w` — Width in pixels
+/// * `h` — Height in pixels
+///
+/// Draws nothing if the slot is empty (check with `thumbnail_exists()`).
+/// Uses current blend mode and color from set_color(), like `draw_sprite()`.
+fn thumbnail_draw(
+    mut caller: Caller<'_, ZXGameContext>,
+    slot: u32,
+    x: f32,
+    y: f32,
+    w: f32,
+    h: f32,
+) {
+    let slot = slot as usize;
+    if slot >= MAX_THUMBNAIL_SLOTS {
+        tracing::warn!("thumbnail_draw: invalid slot {}", slot);
+        return;
+    }
+
+    let ctx = caller.data_mut();
+    push_thumbnail(&mut ctx.ffi, &ctx.thumbnails.store, slot, x, y, w, h);
+}
+
+/// Queue a thumbnail quad, uploading the slot first if its capture changed
+///
+/// Returns false if the slot is empty.
+pub(super) fn push_thumbnail(
+    state: &mut ZXFFIState,
+    store: &ThumbnailStore,
+    slot: usize,
+    x: f32,
+    y: f32,
+    w: f32,
+    h: f32,
+) -> bool {
+    let Some(pixels) = store.slot(slot) else {
+        return false;
+    };
+
+    // Reserve a texture handle for the slot and upload newer captures
+    if state.thumbnail_handles[slot] == 0 {
+        state.thumbnail_handles[slot] = state.next_texture_handle;
+        state.next_texture_handle += 1;
+    }
+    let handle = state.thumbnail_handles[slot];
+    let revision = store.revision(slot);
+    if state.thumbnail_revisions[slot] != revision {
+        state.thumbnail_revisions[slot] = revision;
+        state.pending_thumbnails.push(PendingThumbnail {
+            handle,
+            pixels: pixels.to_vec(),
+        });
+    }
+
+    let vp = state.current_viewport;
+    let saved_texture = state.bound_textures[0];
+    state.bound_textures[0] = handle;
+
+    let shading_state_index = state.add_shading_state();
+    let instance = crate::graphics::QuadInstance::sprite(
+        vp.x as f32 + x,
+        vp.y as f32 + y,
+        SCREEN_SPACE_DEPTH,
+        w,
+        h,
+        0.0,
+        [0.0, 0.0, 1.0, 1.0],
+        shading_state_index.0,
+        (state.view_matrices.len() - 1) as u32,
+    );
+    state.add_quad_instance(instance, state.current_z_index);
+
+    state.bound_textures[0] = saved_texture;
+    true
+}
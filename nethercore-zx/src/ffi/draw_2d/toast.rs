@@ -0,0 +1,169 @@
+//! Toast notifications
+//!
+//! Short messages such as "P2 DOWNED" or "LAP RECORD!" that the host stacks in
+//! the top-right corner of the visible canvas, above everything the game
+//! draws, so they never collide with the game's own HUD.
+
+use std::time::Instant;
+
+use anyhow::Result;
+use glam::Vec2;
+use tracing::warn;
+use wasmtime::{Caller, Linker};
+
+use crate::ffi::ZXGameContext;
+use crate::ffi::helpers::read_wasm_bytes;
+use crate::graphics::{BlendMode, PassConfig, Viewport};
+use crate::state::{MAX_TOAST_LEN, ZXFFIState};
+
+use super::indicator::push_icon;
+use super::shapes::push_rect;
+use super::text::{measure_text, push_text};
+
+/// Longest duration accepted by `toast()`, in seconds
+const MAX_DURATION: f32 = 30.0;
+
+/// Text size in pixels
+const TEXT_SIZE: f32 = 16.0;
+
+/// Icon size in pixels
+const ICON_SIZE: f32 = 16.0;
+
+/// Space between the panel edge and its contents, and between icon and text
+const PADDING: f32 = 6.0;
+
+/// Distance from the corner of the visible canvas in pixels
+const MARGIN: f32 = 8.0;
+
+/// Vertical gap between stacked toasts in pixels
+const SPACING: f32 = 4.0;
+
+/// Panel background color (0xRRGGBBAA at full opacity)
+const PANEL_COLOR: u32 = 0x101018E0;
+
+/// Text color (0xRRGGBBAA at full opacity)
+const TEXT_COLOR: u32 = 0xFFFFFFFF;
+
+/// Register toast FFI functions
+pub(super) fn register(linker: &mut Linker<ZXGameContext>) -> Result<()> {
+    linker.func_wrap("env", "toast", toast)?;
+    Ok(())
+}
+
+/// Show a toast notification
+///
+/// # Arguments
+/// * `ptr` — Pointer to UTF-8 text
+/// * `len` — Text length in bytes (1-64)
+/// * `icon` — Texture handle drawn left of the text (0 = none)
+/// * `duration` — Seconds on screen including fades (clamped to 0.45-30)
+///
+/// Toasts stack in the top-right corner of the visible canvas with the oldest
+/// on top, fading in and out. Up to 4 are shown at once; later ones wait for a
+/// free slot. They're presentation-only and not rolled back; a toast raised
+/// again on the same tick with the same text and icon (as happens when
+/// `update()` is re-simulated) is ignored, and so are toasts past a queue of 16.
+/// Nothing is returned: the queue isn't rolled back, so whether a toast was
+/// queued can differ between a tick and its re-simulation.
+fn toast(mut caller: Caller<'_, ZXGameContext>, ptr: u32, len: u32, icon: u32, duration: f32) {
+    if len == 0 || len as usize > MAX_TOAST_LEN {
+        warn!(
+            "toast: invalid length {} (must be 1-{})",
+            len, MAX_TOAST_LEN
+        );
+        return;
+    }
+    if !duration.is_finite() {
+        warn!("toast: invalid duration {}", duration);
+        return;
+    }
+    let Some(bytes) = read_wasm_bytes(&caller, ptr, len as usize, "toast") else {
+        return;
+    };
+    let Ok(text) = String::from_utf8(bytes) else {
+        warn!("toast: invalid UTF-8 text");
+        return;
+    };
+
+    let tick = caller.data().game.tick_count;
+    let toasts = &mut caller.data_mut().ffi.toasts;
+    toasts.push(tick, &text, icon, duration.min(MAX_DURATION));
+}
+
+/// Age the toast queue and record the visible toasts for this frame
+///
+/// Called by the host after the game's `render()`. Draws in a new pass over
+/// the whole canvas; color, font, bound texture and 2D state are preserved.
+pub(crate) fn draw_toasts(state: &mut ZXFFIState) {
+    state.toasts.update(Instant::now());
+    if state.toasts.is_empty() {
+        return;
+    }
+
+    state.current_pass_id += 1;
+    state.pass_configs.push(PassConfig::standard(false));
+
+    let saved_color = state.current_shading_state.color_rgba8;
+    let saved_font = state.current_font;
+    let saved_texture = state.bound_textures[0];
+    let saved_atlas_sprite = state.bound_atlas_sprite;
+    let saved_z_index = state.current_z_index;
+    let saved_viewport = state.current_viewport;
+    let saved_blend_mode = state.current_blend_mode_2d;
+    let saved_clip_stack = std::mem::take(&mut state.clip_stack);
+    state.current_font = 0; // Built-in font
+    state.current_z_index = u32::MAX;
+    state.current_viewport = Viewport::FULLSCREEN;
+    state.current_blend_mode_2d = BlendMode::Alpha;
+
+    let [area_x, area_y, area_width, _] = state.safe_area;
+    let right = area_x + area_width - MARGIN;
+    let mut top = area_y + MARGIN;
+    let panel_height = TEXT_SIZE + PADDING * 2.0;
+
+    let toasts: Vec<_> = state.toasts.visible().cloned().collect();
+    for toast in &toasts {
+        let opacity = toast.opacity();
+        let text_width = measure_text(state, &toast.text, TEXT_SIZE);
+        let icon_width = if toast.icon != 0 {
+            ICON_SIZE + PADDING
+        } else {
+            0.0
+        };
+        let panel_width = text_width + icon_width + PADDING * 2.0;
+        let left = right - panel_width;
+
+        state.update_color(fade(PANEL_COLOR, opacity));
+        push_rect(state, left, top, panel_width, panel_height);
+
+        state.update_color(fade(TEXT_COLOR, opacity));
+        if toast.icon != 0 {
+            let center = Vec2::new(left + PADDING + ICON_SIZE * 0.5, top + panel_height * 0.5);
+            push_icon(state, center, ICON_SIZE, toast.icon);
+        }
+        push_text(
+            state,
+            &toast.text,
+            left + PADDING + icon_width,
+            top + PADDING,
+            TEXT_SIZE,
+        );
+
+        top += panel_height + SPACING;
+    }
+
+    state.update_color(saved_color);
+    state.current_font = saved_font;
+    state.bound_textures[0] = saved_texture;
+    state.bound_atlas_sprite = saved_atlas_sprite;
+    state.current_z_index = saved_z_index;
+    state.current_viewport = saved_viewport;
+    state.current_blend_mode_2d = saved_blend_mode;
+    state.clip_stack = saved_clip_stack;
+}
+
+/// Scale the alpha of an 0xRRGGBBAA color by `opacity`
+fn fade(color: u32, opacity: f32) -> u32 {
+    let alpha = ((color & 0xFF) as f32 * opacity).round() as u32;
+    (color & 0xFFFF_FF00) | alpha.min(0xFF)
+}
@@ -3,12 +3,13 @@
 //! Functions for drawing 3D triangles immediately (buffered on CPU, flushed at frame end).
 
 use anyhow::Result;
+use glam::Vec3;
 use tracing::warn;
 use wasmtime::{Caller, Linker};
 
 use super::ZXGameContext;
 use super::helpers::{checked_mul, read_wasm_floats, read_wasm_u16s, validate_vertex_format};
-use crate::graphics::vertex_stride;
+use crate::graphics::{BlendMode, MeshBlend, vertex_stride};
 
 /// Register immediate mode 3D drawing FFI functions
 pub fn register(linker: &mut Linker<ZXGameContext>) -> Result<()> {
@@ -71,6 +72,14 @@ fn draw_triangles(
 
     let cull_mode = state.cull_mode;
 
+    // Blended draws sort back to front by the center of their vertices
+    let blend = if state.current_blend_mode_3d == BlendMode::Alpha {
+        MeshBlend::default()
+    } else {
+        let (min, max) = position_bounds(&vertex_data, format);
+        state.mesh_blend(min, max)
+    };
+
     // Capture current viewport for split-screen rendering
     let viewport = state.current_viewport;
 
@@ -87,9 +96,15 @@ fn draw_triangles(
         buffer_index,
         textures,
         cull_mode,
+        blend,
         viewport,
         pass_id,
     );
+
+    // Mode 4 inked silhouette: repeat the draw as an outline pass
+    if let Some(outline_index) = state.add_outline_mvp_shading_state(format) {
+        state.render_pass.record_outline(outline_index);
+    }
 }
 
 /// Draw indexed triangles immediately
@@ -167,6 +182,14 @@ fn draw_triangles_indexed(
 
     let cull_mode = state.cull_mode;
 
+    // Blended draws sort back to front by the center of their vertices
+    let blend = if state.current_blend_mode_3d == BlendMode::Alpha {
+        MeshBlend::default()
+    } else {
+        let (min, max) = position_bounds(&vertex_data, format);
+        state.mesh_blend(min, max)
+    };
+
     // Capture current viewport for split-screen rendering
     let viewport = state.current_viewport;
 
@@ -184,7 +207,25 @@ fn draw_triangles_indexed(
         buffer_index,
         textures,
         cull_mode,
+        blend,
         viewport,
         pass_id,
     );
+
+    // Mode 4 inked silhouette: repeat the draw as an outline pass
+    if let Some(outline_index) = state.add_outline_mvp_shading_state(format) {
+        state.render_pass.record_outline(outline_index);
+    }
+}
+
+/// Bounding box of the positions in float vertex data
+fn position_bounds(vertex_data: &[f32], format: u8) -> (Vec3, Vec3) {
+    let stride = vertex_stride(format) as usize / 4;
+    vertex_data.chunks_exact(stride).fold(
+        (Vec3::splat(f32::INFINITY), Vec3::splat(f32::NEG_INFINITY)),
+        |(min, max), v| {
+            let p = Vec3::new(v[0], v[1], v[2]);
+            (min.min(p), max.max(p))
+        },
+    )
 }
@@ -15,7 +15,8 @@ use std::sync::atomic::{AtomicU32, Ordering};
 
 use crate::ffi::ZXGameContext;
 use crate::ffi::helpers::get_memory;
-use crate::graphics::epu::{EpuConfig, MAX_ENV_STATES};
+use crate::graphics::epu::{EpuConfig, EpuEasing, EpuTransition, MAX_ENV_STATES};
+use crate::state::ZXFFIState;
 
 static EPU_SET_DEBUG_COUNT: AtomicU32 = AtomicU32::new(0);
 
@@ -82,6 +83,7 @@ pub(crate) fn epu_set(mut caller: Caller<'_, ZXGameContext>, config_ptr: u32) {
         return;
     };
 
+    let tick = caller.data().game.tick_count;
     let state = &mut caller.data_mut().ffi;
 
     // Store config for the current env_id (selected via environment_index()).
@@ -89,6 +91,136 @@ pub(crate) fn epu_set(mut caller: Caller<'_, ZXGameContext>, config_ptr: u32) {
         .current_shading_state
         .environment_index
         .min(MAX_ENV_STATES.saturating_sub(1));
+
+    store_epu_config(state, env_id, config, tick, "epu_set");
+}
+
+/// Store an EPU config for an explicit environment slot.
+///
+/// Unlike `epu_set`, this does not consult or change the current
+/// `environment_index(...)` selection. Split-screen games can upload one config
+/// per viewport up front and then pick between them with `epu_bind(slot)`
+/// inside the per-player render loop.
+///
+/// # Arguments
+/// * `slot` - Environment slot (env_id), clamped to 0..MAX_ENV_STATES-1
+/// * `config_ptr` - Pointer to 16 u64 values (128 bytes) in WASM memory
+pub(crate) fn epu_set_slot(mut caller: Caller<'_, ZXGameContext>, slot: u32, config_ptr: u32) {
+    let Some(config) = read_epu_config(&caller, config_ptr, "epu_set_slot") else {
+        return;
+    };
+
+    let env_id = clamp_slot(slot, "epu_set_slot");
+    let tick = caller.data().game.tick_count;
+    let state = &mut caller.data_mut().ffi;
+    store_epu_config(state, env_id, config, tick, "epu_set_slot");
+}
+
+/// Crossfade an environment slot to a new EPU config over time.
+///
+/// Colors, alphas, intensities and directions are interpolated field-wise
+/// from the slot's current config to `config_ptr`; opcodes and params switch
+/// over halfway. Layers that only exist on one side fade in or out.
+///
+/// The starting config is the in-flight blend if a transition is already
+/// running, else the config pushed for `slot` this frame (or last frame).
+/// While the transition runs, configs pushed for `slot` with `epu_set(...)`
+/// are replaced by the blend, so keep pushing the new config every frame.
+///
+/// # Arguments
+/// * `slot` - Environment slot (env_id), clamped to 0..MAX_ENV_STATES-1
+/// * `config_ptr` - Pointer to the target config (16 u64 values, 128 bytes)
+/// * `duration_ticks` - Length of the crossfade in ticks (0 = switch immediately)
+/// * `easing` - 0 = linear, 1 = ease in, 2 = ease out, 3 = ease in-out
+pub(crate) fn epu_transition(
+    mut caller: Caller<'_, ZXGameContext>,
+    slot: u32,
+    config_ptr: u32,
+    duration_ticks: u32,
+    easing: u32,
+) {
+    const FN_NAME: &str = "epu_transition";
+
+    let Some(to) = read_epu_config(&caller, config_ptr, FN_NAME) else {
+        return;
+    };
+    let easing = EpuEasing::from_u32(easing).unwrap_or_else(|| {
+        warn!("{FN_NAME}: unknown easing {easing} - using linear");
+        EpuEasing::Linear
+    });
+
+    let env_id = clamp_slot(slot, FN_NAME);
+    let tick = caller.data().game.tick_count;
+    let state = &mut caller.data_mut().ffi;
+
+    let from = state
+        .epu_transitions
+        .get(&env_id)
+        .map(|transition| transition.sample(tick))
+        .or_else(|| state.epu_frame_configs.get(&env_id).copied())
+        .or_else(|| state.epu_last_configs.get(&env_id).copied());
+
+    match from {
+        Some(from) if duration_ticks > 0 => {
+            state.epu_transitions.insert(
+                env_id,
+                EpuTransition {
+                    from,
+                    to,
+                    start_tick: tick,
+                    duration_ticks,
+                    easing,
+                },
+            );
+            state.epu_frame_configs.insert(env_id, from);
+        }
+        // Nothing to fade from (or no duration): switch immediately
+        _ => {
+            state.epu_transitions.remove(&env_id);
+            state.epu_frame_configs.insert(env_id, to);
+        }
+    }
+}
+
+/// Bind an environment slot for subsequent draws.
+///
+/// Alias of `environment_index(slot)`, named to pair with `epu_set_slot`:
+/// selects the environment used by `draw_epu()` and for ambient/reflection
+/// lighting of subsequent draw calls.
+pub(crate) fn epu_bind(mut caller: Caller<'_, ZXGameContext>, slot: u32) {
+    let env_id = clamp_slot(slot, "epu_bind");
+    let state = &mut caller.data_mut().ffi;
+    state.update_environment_index(env_id);
+}
+
+/// Clamp a game-provided environment slot to the supported range.
+fn clamp_slot(slot: u32, fn_name: &str) -> u32 {
+    let max = MAX_ENV_STATES.saturating_sub(1);
+    if slot > max {
+        warn!("{fn_name}: slot {slot} out of range - clamped to {max}");
+    }
+    slot.min(max)
+}
+
+/// Record `config` for `env_id` in this frame's EPU config table.
+///
+/// If a transition is running for `env_id`, its blend at `tick` is stored
+/// instead; finished transitions are dropped.
+fn store_epu_config(
+    state: &mut ZXFFIState,
+    env_id: u32,
+    mut config: EpuConfig,
+    tick: u64,
+    fn_name: &str,
+) {
+    if let Some(transition) = state.epu_transitions.get(&env_id) {
+        if transition.is_finished(tick) {
+            state.epu_transitions.remove(&env_id);
+        } else {
+            config = transition.sample(tick);
+        }
+    }
+
     let layers = config.layers;
 
     if std::env::var("NETHERCORE_EPU_DEBUG_SET").as_deref() == Ok("1") {
@@ -105,7 +237,8 @@ pub(crate) fn epu_set(mut caller: Caller<'_, ZXGameContext>, config_ptr: u32) {
             let op6 = ((layers[6][0] >> 59) & 0x1F) as u8;
 
             tracing::info!(
-                "epu_set debug: call={}, env_id={}, (op,d)[0]=({},{}), [3]=({},{}), [4]=({},{}), [6]=({},{}), state_hash=0x{:016x}",
+                "{} debug: call={}, env_id={}, (op,d)[0]=({},{}), [3]=({},{}), [4]=({},{}), [6]=({},{}), state_hash=0x{:016x}",
+                fn_name,
                 n,
                 env_id,
                 op0,
@@ -125,7 +258,7 @@ pub(crate) fn epu_set(mut caller: Caller<'_, ZXGameContext>, config_ptr: u32) {
         && prev.layers != layers
     {
         warn!(
-            "epu_set: multiple different configs pushed for env_id {} in the same frame; last call wins",
+            "{fn_name}: multiple different configs pushed for env_id {} in the same frame; last call wins",
             env_id
         );
     }
@@ -151,5 +284,76 @@ pub(crate) fn draw_epu(mut caller: Caller<'_, ZXGameContext>) {
     let mvp_index = state.add_mvp_shading_state();
 
     // Record (and overwrite) the draw request for this viewport/pass.
+    state.sky_frame_draws.remove(&(viewport, pass_id));
     state.epu_frame_draws.insert((viewport, pass_id), mvp_index);
 }
+
+#[cfg(test)]
+mod tests {
+    use wasmtime::{Engine, Linker, Module, Store};
+
+    use super::*;
+
+    #[test]
+    fn test_epu_slots_from_wasm() {
+        let engine = Engine::default();
+        let mut linker: Linker<ZXGameContext> = Linker::new(&engine);
+        crate::ffi::environment::register(&mut linker).unwrap();
+
+        // Three configs whose first byte tells them apart: 0x11 at 0, 0x22
+        // at 128 and 0x33 at 256
+        let wat = r#"
+            (module
+                (import "env" "epu_set_slot" (func $epu_set_slot (param i32 i32)))
+                (import "env" "epu_bind" (func $epu_bind (param i32)))
+                (import "env" "epu_set" (func $epu_set (param i32)))
+                (memory (export "memory") 1)
+                (data (i32.const 0) "\11")
+                (data (i32.const 128) "\22")
+                (data (i32.const 256) "\33")
+                (func (export "upload")
+                    i32.const 0
+                    i32.const 0
+                    call $epu_set_slot
+                    i32.const 1
+                    i32.const 128
+                    call $epu_set_slot
+                )
+                (func (export "bind")
+                    i32.const 1
+                    call $epu_bind
+                    i32.const 256
+                    call $epu_set
+                )
+            )
+        "#;
+        let module = Module::new(&engine, wat).unwrap();
+        let mut store = Store::new(&engine, ZXGameContext::new());
+        let instance = linker.instantiate(&mut store, &module).unwrap();
+        store.data_mut().game.memory = instance.get_memory(&mut store, "memory");
+        let first_byte = |ctx: &ZXGameContext, slot: u32| {
+            ctx.ffi.epu_frame_configs.get(&slot).map(|c| c.layers[0][0])
+        };
+
+        // Each viewport's slot keeps its own config, and uploading doesn't
+        // change the bound environment
+        let upload = instance
+            .get_typed_func::<(), ()>(&mut store, "upload")
+            .unwrap();
+        upload.call(&mut store, ()).unwrap();
+        assert_eq!(first_byte(store.data(), 0), Some(0x11));
+        assert_eq!(first_byte(store.data(), 1), Some(0x22));
+        let bound = store.data().ffi.current_shading_state.environment_index;
+        assert_eq!(bound, 0);
+
+        // epu_bind selects the slot that draws (and epu_set) use
+        let bind = instance
+            .get_typed_func::<(), ()>(&mut store, "bind")
+            .unwrap();
+        bind.call(&mut store, ()).unwrap();
+        let bound = store.data().ffi.current_shading_state.environment_index;
+        assert_eq!(bound, 1);
+        assert_eq!(first_byte(store.data(), 0), Some(0x11));
+        assert_eq!(first_byte(store.data(), 1), Some(0x33));
+    }
+}
@@ -6,6 +6,8 @@
 
 mod draw;
 mod epu;
+mod probe;
+mod sky;
 
 use anyhow::Result;
 use wasmtime::Linker;
@@ -14,7 +16,9 @@ use super::ZXGameContext;
 
 // Re-export functions for registration
 pub(crate) use draw::matcap_set;
-pub(crate) use epu::{draw_epu, epu_set};
+pub(crate) use epu::{draw_epu, epu_bind, epu_set, epu_set_slot, epu_transition};
+pub(crate) use probe::{material_probe, probe_create};
+pub(crate) use sky::{sky_ambient, sky_draw};
 
 /// Register EPU FFI functions
 pub fn register(linker: &mut Linker<ZXGameContext>) -> Result<()> {
@@ -22,6 +26,21 @@ pub fn register(linker: &mut Linker<ZXGameContext>) -> Result<()> {
     linker.func_wrap("env", "epu_set", epu_set)?;
     linker.func_wrap("env", "draw_epu", draw_epu)?;
 
+    // Per-slot configs for split-screen (one environment per viewport)
+    linker.func_wrap("env", "epu_set_slot", epu_set_slot)?;
+    linker.func_wrap("env", "epu_bind", epu_bind)?;
+
+    // Timed crossfades between configs
+    linker.func_wrap("env", "epu_transition", epu_transition)?;
+
+    // Localized lighting probes
+    linker.func_wrap("env", "probe_create", probe_create)?;
+    linker.func_wrap("env", "material_probe", material_probe)?;
+
+    // Cubemap skies (instead of draw_epu)
+    linker.func_wrap("env", "sky_draw", sky_draw)?;
+    linker.func_wrap("env", "sky_ambient", sky_ambient)?;
+
     // Matcap controls (Mode 1)
     linker.func_wrap("env", "matcap_set", matcap_set)?;
 
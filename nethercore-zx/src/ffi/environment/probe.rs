@@ -0,0 +1,92 @@
+//! Environment probe FFI functions
+//!
+//! Probes capture localized lighting (EPU environment + scene lights) at a
+//! point in the world so nearby objects can use it instead of the global sky.
+
+use glam::Vec3;
+use tracing::warn;
+use wasmtime::Caller;
+
+use crate::ffi::ZXGameContext;
+use crate::ffi::guards::guard_init_only;
+use crate::graphics::epu::{EnvProbe, MAX_PROBES};
+
+/// Create an environment probe (init-only).
+///
+/// The probe starts from the environment currently selected with
+/// `environment_index(...)` (0 = the global sky), so select and configure a
+/// dedicated environment first for enclosed spaces like tunnels. Every light
+/// whose range reaches the probe's sphere is added on top when the probe is
+/// captured.
+///
+/// # Arguments
+/// * `x`, `y`, `z` - Capture position in world units
+/// * `radius` - Capture radius in world units
+///
+/// # Returns
+/// Probe handle (>0), or 0 on error.
+pub(crate) fn probe_create(
+    mut caller: Caller<'_, ZXGameContext>,
+    x: f32,
+    y: f32,
+    z: f32,
+    radius: f32,
+) -> u32 {
+    guard_init_only!(caller, "probe_create");
+
+    if !(radius >= 0.0 && radius.is_finite()) {
+        warn!("probe_create: invalid radius {radius}");
+        return 0;
+    }
+
+    let state = &mut caller.data_mut().ffi;
+    let index = state.env_probes.len() as u32;
+    if index >= MAX_PROBES {
+        warn!("probe_create: probe limit ({MAX_PROBES}) reached");
+        return 0;
+    }
+
+    let source_env = state.current_shading_state.environment_index;
+    state
+        .env_probes
+        .push(EnvProbe::new(index, Vec3::new(x, y, z), radius, source_env));
+    index + 1
+}
+
+/// Light subsequent draws with a probe's captured environment.
+///
+/// Selects the probe's environment slot for ambient and reflections, like
+/// `environment_index(...)`. The probe is recaptured at most every
+/// `PROBE_REFRESH_TICKS` ticks from its source environment config and the
+/// lights set at the time of the call, so configure lights and call
+/// `epu_set(...)` before binding probes. Call `environment_index(...)` to go
+/// back to a regular environment.
+///
+/// # Arguments
+/// * `handle` - Probe handle from `probe_create()`
+pub(crate) fn material_probe(mut caller: Caller<'_, ZXGameContext>, handle: u32) {
+    let tick = caller.data().game.tick_count;
+    let state = &mut caller.data_mut().ffi;
+
+    let Some(index) = handle
+        .checked_sub(1)
+        .filter(|&i| (i as usize) < state.env_probes.len())
+    else {
+        warn!("material_probe: invalid probe handle {handle}");
+        return;
+    };
+    let probe = state.env_probes[index as usize];
+
+    let config = match probe.captured {
+        Some((config, _)) if !probe.needs_capture(tick) => config,
+        _ => {
+            let base = state.env_config(probe.source_env);
+            let config = probe.capture(&base, &state.current_shading_state.lights);
+            state.env_probes[index as usize].captured = Some((config, tick));
+            config
+        }
+    };
+
+    state.epu_frame_configs.insert(probe.env_id, config);
+    state.update_environment_index(probe.env_id);
+}
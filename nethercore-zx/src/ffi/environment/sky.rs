@@ -0,0 +1,85 @@
+//! Cubemap sky functions
+//!
+//! Skies authored as images (loaded with `rom_cubemap()`) are drawn in place
+//! of the EPU background, and can optionally light the scene in place of the
+//! EPU's diffuse ambient.
+
+use glam::Mat4;
+use tracing::warn;
+use wasmtime::Caller;
+
+use crate::ffi::ZXGameContext;
+use crate::graphics::epu::MAX_ENV_STATES;
+
+/// Draw a cubemap sky as the background for the current viewport/pass.
+///
+/// Used instead of `draw_epu()`: for each viewport and pass, whichever of the
+/// two is called last is drawn. Like `draw_epu()`, call it at the end of
+/// `render()` so it only fills background pixels. The sky follows the
+/// camera's rotation and `texture_filter()`.
+///
+/// With `sky_ambient(...)` set above 0, the sky also replaces the diffuse
+/// ambient of the current `environment_index(...)` this frame. Reflections
+/// still come from the EPU.
+///
+/// # Arguments
+/// * `cubemap` - Cubemap handle from `rom_cubemap()`
+/// * `rotation` - Rotation of the sky about the world Y axis in degrees
+pub(crate) fn sky_draw(mut caller: Caller<'_, ZXGameContext>, cubemap: u32, rotation: f32) {
+    let state = &mut caller.data_mut().ffi;
+
+    let Some(probe) = state.cubemaps.get(&cubemap) else {
+        warn!("sky_draw: invalid cubemap handle {}", cubemap);
+        return;
+    };
+    if !rotation.is_finite() {
+        warn!("sky_draw: rotation must be finite (got {})", rotation);
+        return;
+    }
+
+    if state.sky_ambient > 0.0 {
+        let env_id = state
+            .current_shading_state
+            .environment_index
+            .min(MAX_ENV_STATES.saturating_sub(1));
+        let sh9 = probe.irradiance(rotation, state.sky_ambient);
+        state.sky_frame_ambient.insert(env_id, sh9);
+    }
+
+    // Capture current viewport/pass for split-screen + pass ordering
+    let viewport = state.current_viewport;
+    let pass_id = state.current_pass_id;
+
+    // Fold the sky's rotation into the view matrix of this draw only
+    let view = state
+        .current_view_matrix
+        .or_else(|| state.view_matrices.last().copied())
+        .unwrap_or(Mat4::IDENTITY);
+    state.current_view_matrix = Some(view * Mat4::from_rotation_y(rotation.to_radians()));
+    let mvp_index = state.add_mvp_shading_state();
+    state.current_view_matrix = Some(view);
+
+    // Record (and overwrite) the background for this viewport/pass
+    state.epu_frame_draws.remove(&(viewport, pass_id));
+    state
+        .sky_frame_draws
+        .insert((viewport, pass_id), (mvp_index, cubemap));
+}
+
+/// Set how strongly `sky_draw()` skies light the scene.
+///
+/// Applies to `sky_draw()` calls after it in the current frame, and resets
+/// to 0 every frame.
+///
+/// # Arguments
+/// * `intensity` - Ambient multiplier (0 = keep the EPU ambient, 1 = sky as authored)
+pub(crate) fn sky_ambient(mut caller: Caller<'_, ZXGameContext>, intensity: f32) {
+    if !intensity.is_finite() || intensity < 0.0 {
+        warn!(
+            "sky_ambient: intensity must be finite and >= 0 (got {})",
+            intensity
+        );
+        return;
+    }
+    caller.data_mut().ffi.sky_ambient = intensity;
+}
@@ -0,0 +1,133 @@
+//! Grid pathfinding FFI functions
+//!
+//! A* over a 2D cost grid for overhead and 2D games (see
+//! `crate::state::PathGrid`). The grid lives in ZRollbackState, so it can be
+//! created and edited from `update()` and paths are safe to query at any time.
+//! It is allocated by `grid_create()`, so games without a grid don't carry
+//! it in their snapshots.
+
+use anyhow::Result;
+use tracing::warn;
+use wasmtime::{Caller, Linker};
+
+use super::ZXGameContext;
+use super::helpers::get_memory;
+use crate::state::{MAX_GRID_CELLS, PathGrid};
+
+/// Size of one path cell written to WASM memory (x, y as u32)
+const PATH_CELL_SIZE: usize = 8;
+
+/// Register grid pathfinding FFI functions
+pub fn register(linker: &mut Linker<ZXGameContext>) -> Result<()> {
+    linker.func_wrap("env", "grid_create", grid_create)?;
+    linker.func_wrap("env", "grid_set_cost", grid_set_cost)?;
+    linker.func_wrap("env", "grid_path", grid_path)?;
+    Ok(())
+}
+
+/// Create the pathfinding grid, replacing any existing one
+///
+/// # Arguments
+/// * `width`, `height` — Size in cells (width × height at most 16384)
+///
+/// Every cell starts with cost 1.
+///
+/// Returns 1 on success, 0 if the size is invalid.
+fn grid_create(mut caller: Caller<'_, ZXGameContext>, width: u32, height: u32) -> u32 {
+    let mut grid = Box::<PathGrid>::default();
+    if !grid.create(width, height) {
+        warn!(
+            "grid_create: invalid size {}x{} (1 to {} cells)",
+            width, height, MAX_GRID_CELLS
+        );
+        return 0;
+    }
+    caller.data_mut().rollback.path_grid = Some(grid);
+    1
+}
+
+/// Set the cost of entering a grid cell
+///
+/// # Arguments
+/// * `x`, `y` — Cell coordinates
+/// * `cost` — 0 = blocked, 1-255 = relative cost (1 = open ground)
+fn grid_set_cost(mut caller: Caller<'_, ZXGameContext>, x: u32, y: u32, cost: u32) {
+    let Ok(cost) = u8::try_from(cost) else {
+        warn!("grid_set_cost: cost {} out of range (0-255)", cost);
+        return;
+    };
+    let Some(grid) = caller.data_mut().rollback.path_grid.as_deref_mut() else {
+        warn!("grid_set_cost: no grid (call grid_create() first)");
+        return;
+    };
+    if !grid.set_cost(x, y, cost) {
+        warn!(
+            "grid_set_cost: cell ({}, {}) outside {}x{} grid",
+            x, y, grid.width, grid.height
+        );
+    }
+}
+
+/// Find the cheapest path between two grid cells
+///
+/// # Arguments
+/// * `start_x`, `start_y` — Start cell (may be blocked)
+/// * `goal_x`, `goal_y` — Goal cell
+/// * `out_cells` — Pointer to output buffer of `max_cells` × 2 u32 (x, y)
+/// * `max_cells` — Capacity of the output buffer in cells
+///
+/// Paths move in 8 directions without cutting the corners of blocked cells.
+/// The path includes the start and goal cells. Longer paths are truncated to
+/// `max_cells`.
+///
+/// Returns the number of cells written, or 0 if no path exists.
+fn grid_path(
+    mut caller: Caller<'_, ZXGameContext>,
+    start_x: u32,
+    start_y: u32,
+    goal_x: u32,
+    goal_y: u32,
+    out_cells: u32,
+    max_cells: u32,
+) -> u32 {
+    const FN_NAME: &str = "grid_path";
+
+    if max_cells == 0 {
+        return 0;
+    }
+
+    let Some(grid) = caller.data().rollback.path_grid.as_deref() else {
+        warn!("{}: no grid (call grid_create() first)", FN_NAME);
+        return 0;
+    };
+    let Some(path) = grid.find_path((start_x, start_y), (goal_x, goal_y)) else {
+        return 0;
+    };
+
+    let Some(memory) = get_memory(&caller, FN_NAME) else {
+        return 0;
+    };
+    let count = path.len().min(max_cells as usize);
+    let out_start = out_cells as usize;
+    let out_end = out_start + count * PATH_CELL_SIZE;
+
+    let data = memory.data_mut(&mut caller);
+    if out_end > data.len() {
+        warn!(
+            "{}: output buffer out of bounds ({}-{}, memory size {})",
+            FN_NAME,
+            out_start,
+            out_end,
+            data.len()
+        );
+        return 0;
+    }
+
+    for (i, &(x, y)) in path.iter().take(count).enumerate() {
+        let offset = out_start + i * PATH_CELL_SIZE;
+        data[offset..offset + 4].copy_from_slice(&x.to_le_bytes());
+        data[offset + 4..offset + 8].copy_from_slice(&y.to_le_bytes());
+    }
+
+    count as u32
+}
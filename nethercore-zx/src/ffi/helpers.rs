@@ -3,10 +3,16 @@
 //! These helpers reduce code duplication across FFI modules by providing
 //! standardized patterns for WASM memory access and parameter validation.
 
+use glam::{Vec2, Vec3};
 use tracing::warn;
 use wasmtime::{Caller, Memory};
 
 use super::ZXGameContext;
+use crate::graphics::{
+    FORMAT_COLOR, FORMAT_NORMAL, FORMAT_UV, unpack_f16, unpack_octahedral_u32, vertex_stride,
+    vertex_stride_packed,
+};
+use crate::state::ZXFFIState;
 
 // ============================================================================
 // Memory Helpers
@@ -124,6 +130,152 @@ pub(crate) fn read_wasm_i16s(
     Some(values.to_vec())
 }
 
+// ============================================================================
+// Mesh Helpers
+// ============================================================================
+
+/// Positions and triangle indices of a mesh created during `init()`.
+///
+/// Mesh data stays CPU-side until it is uploaded after `init()`, so this only
+/// finds meshes queued in the current init. Non-indexed meshes get sequential
+/// indices.
+pub(crate) fn pending_mesh_geometry(
+    state: &ZXFFIState,
+    handle: u32,
+) -> Option<(Vec<Vec3>, Vec<u32>)> {
+    let (positions, index_data) = if let Some(mesh) =
+        state.pending_meshes.iter().find(|m| m.handle == handle)
+    {
+        let stride = vertex_stride(mesh.format) as usize / 4;
+        let positions: Vec<Vec3> = mesh
+            .vertex_data
+            .chunks_exact(stride)
+            .map(|v| Vec3::new(v[0], v[1], v[2]))
+            .collect();
+        (positions, mesh.index_data.as_ref())
+    } else if let Some(mesh) = state
+        .pending_meshes_packed
+        .iter()
+        .find(|m| m.handle == handle)
+    {
+        let stride = vertex_stride_packed(mesh.format) as usize;
+        let positions: Vec<Vec3> = mesh
+            .vertex_data
+            .chunks_exact(stride)
+            .map(|v| {
+                let component = |i: usize| unpack_f16(u16::from_le_bytes([v[i * 2], v[i * 2 + 1]]));
+                Vec3::new(component(0), component(1), component(2))
+            })
+            .collect();
+        (positions, mesh.index_data.as_ref())
+    } else {
+        return None;
+    };
+
+    let indices: Vec<u32> = match index_data {
+        Some(indices) => indices.iter().copied().map(u32::from).collect(),
+        None => (0..positions.len() as u32).collect(),
+    };
+    Some((positions, indices))
+}
+
+/// Vertex normals of a mesh created during `init()`.
+///
+/// Like [`pending_mesh_geometry`], only meshes queued in the current init are
+/// found. Returns `None` if the mesh is not pending or has no normals.
+pub(crate) fn pending_mesh_normals(state: &ZXFFIState, handle: u32) -> Option<Vec<Vec3>> {
+    if let Some(mesh) = state.pending_meshes.iter().find(|m| m.handle == handle) {
+        if mesh.format & FORMAT_NORMAL == 0 {
+            return None;
+        }
+        // Float layout: position(3) [uv(2)] [color(3)] normal(3) ...
+        let mut offset = 3;
+        if mesh.format & FORMAT_UV != 0 {
+            offset += 2;
+        }
+        if mesh.format & FORMAT_COLOR != 0 {
+            offset += 3;
+        }
+        let stride = vertex_stride(mesh.format) as usize / 4;
+        Some(
+            mesh.vertex_data
+                .chunks_exact(stride)
+                .map(|v| Vec3::new(v[offset], v[offset + 1], v[offset + 2]))
+                .collect(),
+        )
+    } else {
+        let mesh = state
+            .pending_meshes_packed
+            .iter()
+            .find(|m| m.handle == handle)?;
+        if mesh.format & FORMAT_NORMAL == 0 {
+            return None;
+        }
+        // Packed layout: position(8) [uv(4)] [color(4)] normal(4) ...
+        let mut offset = 8;
+        if mesh.format & FORMAT_UV != 0 {
+            offset += 4;
+        }
+        if mesh.format & FORMAT_COLOR != 0 {
+            offset += 4;
+        }
+        let stride = vertex_stride_packed(mesh.format) as usize;
+        Some(
+            mesh.vertex_data
+                .chunks_exact(stride)
+                .map(|v| {
+                    let packed = u32::from_le_bytes([
+                        v[offset],
+                        v[offset + 1],
+                        v[offset + 2],
+                        v[offset + 3],
+                    ]);
+                    unpack_octahedral_u32(packed)
+                })
+                .collect(),
+        )
+    }
+}
+
+/// Vertex UVs of a mesh created during `init()`.
+///
+/// Like [`pending_mesh_geometry`], only meshes queued in the current init are
+/// found. Returns `None` if the mesh is not pending or has no UVs.
+pub(crate) fn pending_mesh_uvs(state: &ZXFFIState, handle: u32) -> Option<Vec<Vec2>> {
+    if let Some(mesh) = state.pending_meshes.iter().find(|m| m.handle == handle) {
+        if mesh.format & FORMAT_UV == 0 {
+            return None;
+        }
+        // Float layout: position(3) uv(2) ...
+        let stride = vertex_stride(mesh.format) as usize / 4;
+        Some(
+            mesh.vertex_data
+                .chunks_exact(stride)
+                .map(|v| Vec2::new(v[3], v[4]))
+                .collect(),
+        )
+    } else {
+        let mesh = state
+            .pending_meshes_packed
+            .iter()
+            .find(|m| m.handle == handle)?;
+        if mesh.format & FORMAT_UV == 0 {
+            return None;
+        }
+        // Packed layout: position(8) uv(unorm16x2) ...
+        let stride = vertex_stride_packed(mesh.format) as usize;
+        Some(
+            mesh.vertex_data
+                .chunks_exact(stride)
+                .map(|v| {
+                    let unorm = |i: usize| u16::from_le_bytes([v[i], v[i + 1]]) as f32 / 65535.0;
+                    Vec2::new(unorm(8), unorm(10))
+                })
+                .collect(),
+        )
+    }
+}
+
 // ============================================================================
 // Validation Helpers
 // ============================================================================
@@ -0,0 +1,211 @@
+//! Hitbox FFI functions
+//!
+//! Frame-data hitboxes and hurtboxes for fighting and action games. Moves are
+//! registered during `init()`; actor poses and hit tracking live in
+//! ZRollbackState, so place actors, resolve and poll events from `update()`.
+
+use anyhow::Result;
+use glam::Vec3;
+use tracing::warn;
+use wasmtime::{Caller, Linker};
+
+use super::ZXGameContext;
+use super::debug_draw::{push_box_edges, with_gizmos};
+use super::guards::guard_init_only;
+use super::helpers::{get_memory, read_wasm_bytes};
+use crate::state::{HitboxDesc, HitboxEvent, MAX_MOVE_BOXES, hitbox_kind};
+
+/// Maximum number of moves that can be registered
+const MAX_HITBOX_MOVES: usize = 256;
+
+/// Debug colors for `hitbox_debug_draw()` (0xRRGGBBAA)
+const HURTBOX_COLOR: u32 = 0x40A0FFFF;
+const HITBOX_COLOR: u32 = 0xFF4040FF;
+
+/// Register hitbox FFI functions
+pub fn register(linker: &mut Linker<ZXGameContext>) -> Result<()> {
+    linker.func_wrap("env", "hitbox_move", hitbox_move)?;
+    linker.func_wrap("env", "hitbox_actor", hitbox_actor)?;
+    linker.func_wrap("env", "hitbox_resolve", hitbox_resolve)?;
+    linker.func_wrap("env", "hitbox_next_event", hitbox_next_event)?;
+    linker.func_wrap("env", "hitbox_debug_draw", hitbox_debug_draw)?;
+    Ok(())
+}
+
+/// Register a move's frame data (init-only)
+///
+/// # Arguments
+/// * `boxes_ptr` — Pointer to `count` 52-byte `HitboxDesc` entries
+///   (first_frame, last_frame, kind, priority, group, flags, user_data,
+///   center xyz, half-extents xyz)
+/// * `count` — Number of boxes (1..=32)
+///
+/// Box coordinates are in actor space: +X is forward at yaw 0, +Y is up.
+/// Returns a move handle (>0) on success, 0 on invalid input.
+fn hitbox_move(mut caller: Caller<'_, ZXGameContext>, boxes_ptr: u32, count: u32) -> u32 {
+    const FN_NAME: &str = "hitbox_move";
+
+    guard_init_only!(caller, FN_NAME);
+
+    let count = count as usize;
+    if count == 0 || count > MAX_MOVE_BOXES {
+        warn!(
+            "{}: box count {} must be 1..={}",
+            FN_NAME, count, MAX_MOVE_BOXES
+        );
+        return 0;
+    }
+    if caller.data().ffi.hitbox_moves.len() >= MAX_HITBOX_MOVES {
+        warn!(
+            "{}: maximum move count ({}) exceeded",
+            FN_NAME, MAX_HITBOX_MOVES
+        );
+        return 0;
+    }
+
+    let size = count * std::mem::size_of::<HitboxDesc>();
+    let Some(bytes) = read_wasm_bytes(&caller, boxes_ptr, size, FN_NAME) else {
+        return 0;
+    };
+    let boxes: Vec<HitboxDesc> = bytes
+        .chunks_exact(std::mem::size_of::<HitboxDesc>())
+        .map(bytemuck::pod_read_unaligned)
+        .collect();
+
+    for (i, desc) in boxes.iter().enumerate() {
+        if desc.kind > hitbox_kind::HIT {
+            warn!("{}: box {} has invalid kind {}", FN_NAME, i, desc.kind);
+            return 0;
+        }
+        if desc.first_frame > desc.last_frame {
+            warn!(
+                "{}: box {} frame range {}..={} is empty",
+                FN_NAME, i, desc.first_frame, desc.last_frame
+            );
+            return 0;
+        }
+        let valid = desc.center.iter().all(|v| v.is_finite())
+            && desc.half_extents.iter().all(|v| v.is_finite() && *v >= 0.0);
+        if !valid {
+            warn!(
+                "{}: box {} must have a finite center and non-negative half-extents",
+                FN_NAME, i
+            );
+            return 0;
+        }
+    }
+
+    let moves = &mut caller.data_mut().ffi.hitbox_moves;
+    moves.push(boxes);
+    moves.len() as u32
+}
+
+/// Place an actor for this tick
+///
+/// # Arguments
+/// * `actor` — Actor slot (0..32)
+/// * `move_id` — Move handle from `hitbox_move()`, or 0 to remove the actor
+/// * `frame` — Current animation frame of the move
+/// * `x`, `y`, `z` — Actor position in world units
+/// * `yaw` — Facing around +Y in radians (0 faces +X)
+/// * `team` — Actors on the same non-zero team never interact
+///
+/// Switching moves, or setting an earlier frame of the same move, starts a
+/// new attack that may hit every defender again.
+fn hitbox_actor(
+    mut caller: Caller<'_, ZXGameContext>,
+    actor: u32,
+    move_id: u32,
+    frame: u32,
+    x: f32,
+    y: f32,
+    z: f32,
+    yaw: f32,
+    team: u32,
+) {
+    const FN_NAME: &str = "hitbox_actor";
+
+    let move_count = caller.data().ffi.hitbox_moves.len();
+    if move_id as usize > move_count {
+        warn!("{}: invalid move handle {}", FN_NAME, move_id);
+        return;
+    }
+    if ![x, y, z, yaw].iter().all(|v| v.is_finite()) {
+        warn!("{}: position and yaw must be finite", FN_NAME);
+        return;
+    }
+
+    let placed = caller.data_mut().rollback.hitboxes.set_actor(
+        actor as usize,
+        move_id,
+        frame,
+        [x, y, z],
+        yaw,
+        team,
+    );
+    if !placed {
+        warn!("{}: invalid actor slot {}", FN_NAME, actor);
+    }
+}
+
+/// Overlap every actor's active boxes and queue hit and clash events
+///
+/// Call once per tick after placing actors. Unread events from the previous
+/// resolve are discarded. Events are also queued for `event_poll()`.
+fn hitbox_resolve(mut caller: Caller<'_, ZXGameContext>) {
+    let ctx = caller.data_mut();
+    ctx.rollback.hitboxes.resolve(&ctx.ffi.hitbox_moves);
+    for &event in ctx.rollback.hitboxes.events() {
+        ctx.game.events.push(event.into());
+    }
+}
+
+/// Read the next event from the last `hitbox_resolve()`
+///
+/// # Arguments
+/// * `out_ptr` — Pointer to a 36-byte `HitboxEvent` (kind, attacker,
+///   defender, attacker_box, defender_box, user_data, x, y, z)
+///
+/// Returns 1 if an event was written, 0 when there are no more events.
+fn hitbox_next_event(mut caller: Caller<'_, ZXGameContext>, out_ptr: u32) -> u32 {
+    const FN_NAME: &str = "hitbox_next_event";
+
+    let Some(memory) = get_memory(&caller, FN_NAME) else {
+        return 0;
+    };
+    let out_start = out_ptr as usize;
+    let out_end = out_start + std::mem::size_of::<HitboxEvent>();
+    if out_end > memory.data_size(&caller) {
+        warn!("{}: output pointer {} out of bounds", FN_NAME, out_ptr);
+        return 0;
+    }
+
+    let Some(event) = caller.data_mut().rollback.hitboxes.next_event() else {
+        return 0;
+    };
+    memory.data_mut(&mut caller)[out_start..out_end].copy_from_slice(bytemuck::bytes_of(&event));
+    1
+}
+
+/// Draw every active box as a wireframe (debug inspector only)
+///
+/// Hurtboxes are blue and hitboxes red.
+fn hitbox_debug_draw(mut caller: Caller<'_, ZXGameContext>) {
+    if !caller.data().game.debug_inspector_open {
+        return;
+    }
+    let ctx = caller.data();
+    let placed = ctx.rollback.hitboxes.placed_boxes(&ctx.ffi.hitbox_moves);
+
+    for (kind, color) in [
+        (hitbox_kind::HURT, HURTBOX_COLOR),
+        (hitbox_kind::HIT, HITBOX_COLOR),
+    ] {
+        with_gizmos(&mut caller, color, |state, projector| {
+            for placed in placed.iter().filter(|b| b.desc.kind == kind) {
+                let corners: [Vec3; 8] = placed.world.corners();
+                push_box_edges(state, projector, |i| corners[i]);
+            }
+        });
+    }
+}
@@ -4,12 +4,15 @@
 //! - Button queries: held, pressed, released (individual and bulk)
 //! - Analog stick queries: X/Y axes, bulk read
 //! - Trigger queries: left and right analog triggers
+//! - Analog grid queries: step between quantized stick and trigger values
 
 use tracing::warn;
 use wasmtime::Caller;
 
 use super::ZXGameContext;
-use crate::console::{MAX_BUTTON_INDEX, STICK_SCALE, TRIGGER_SCALE};
+use crate::console::{
+    MAX_BUTTON_INDEX, STICK_SCALE, TRIGGER_SCALE, ZInput, stick_value, trigger_value,
+};
 use nethercore_core::wasm::MAX_PLAYERS;
 
 // ============================================================================
@@ -54,7 +57,7 @@ fn validate_button(button: u32, func_name: &str) -> bool {
 /// Check if a button is currently held for a player
 ///
 /// # Arguments
-/// * `player` — Player index (0-3)
+/// * `player` — Player index (0-7)
 /// * `button` — Button index (see Button enum: UP=0, DOWN=1, ..., SELECT=13)
 ///
 /// Returns 1 if held, 0 otherwise.
@@ -75,7 +78,7 @@ pub fn button_held(caller: Caller<'_, ZXGameContext>, player: u32, button: u32)
 /// Check if a button was just pressed this tick
 ///
 /// # Arguments
-/// * `player` — Player index (0-3)
+/// * `player` — Player index (0-7)
 /// * `button` — Button index (see Button enum)
 ///
 /// Returns 1 if just pressed (not held last tick, held this tick), 0 otherwise.
@@ -99,7 +102,7 @@ pub fn button_pressed(caller: Caller<'_, ZXGameContext>, player: u32, button: u3
 /// Check if a button was just released this tick
 ///
 /// # Arguments
-/// * `player` — Player index (0-3)
+/// * `player` — Player index (0-7)
 /// * `button` — Button index (see Button enum)
 ///
 /// Returns 1 if just released (held last tick, not held this tick), 0 otherwise.
@@ -123,7 +126,7 @@ pub fn button_released(caller: Caller<'_, ZXGameContext>, player: u32, button: u
 /// Get bitmask of all held buttons for a player
 ///
 /// # Arguments
-/// * `player` — Player index (0-3)
+/// * `player` — Player index (0-7)
 ///
 /// Returns a bitmask where each bit represents a button state.
 #[inline]
@@ -131,13 +134,13 @@ pub fn buttons_held(caller: Caller<'_, ZXGameContext>, player: u32) -> u32 {
     let Some(player_idx) = validate_player(player, "buttons_held") else {
         return 0;
     };
-    caller.data().game.input_curr[player_idx].buttons as u32
+    (caller.data().game.input_curr[player_idx].buttons & ZInput::BUTTON_MASK) as u32
 }
 
 /// Get bitmask of all buttons just pressed this tick
 ///
 /// # Arguments
-/// * `player` — Player index (0-3)
+/// * `player` — Player index (0-7)
 ///
 /// Returns a bitmask of buttons that are held now but were not held last tick.
 #[inline]
@@ -146,8 +149,8 @@ pub fn buttons_pressed(caller: Caller<'_, ZXGameContext>, player: u32) -> u32 {
         return 0;
     };
     let state = &caller.data().game;
-    let prev = state.input_prev[player_idx].buttons;
-    let curr = state.input_curr[player_idx].buttons;
+    let prev = state.input_prev[player_idx].buttons & ZInput::BUTTON_MASK;
+    let curr = state.input_curr[player_idx].buttons & ZInput::BUTTON_MASK;
 
     // Pressed = held now AND not held before
     (curr & !prev) as u32
@@ -156,7 +159,7 @@ pub fn buttons_pressed(caller: Caller<'_, ZXGameContext>, player: u32) -> u32 {
 /// Get bitmask of all buttons just released this tick
 ///
 /// # Arguments
-/// * `player` — Player index (0-3)
+/// * `player` — Player index (0-7)
 ///
 /// Returns a bitmask of buttons that were held last tick but are not held now.
 #[inline]
@@ -165,8 +168,8 @@ pub fn buttons_released(caller: Caller<'_, ZXGameContext>, player: u32) -> u32 {
         return 0;
     };
     let state = &caller.data().game;
-    let prev = state.input_prev[player_idx].buttons;
-    let curr = state.input_curr[player_idx].buttons;
+    let prev = state.input_prev[player_idx].buttons & ZInput::BUTTON_MASK;
+    let curr = state.input_curr[player_idx].buttons & ZInput::BUTTON_MASK;
 
     // Released = held before AND not held now
     (prev & !curr) as u32
@@ -179,7 +182,7 @@ pub fn buttons_released(caller: Caller<'_, ZXGameContext>, player: u32) -> u32 {
 /// Get left stick X axis value
 ///
 /// # Arguments
-/// * `player` — Player index (0-3)
+/// * `player` — Player index (0-7)
 ///
 /// Returns value from -1.0 to 1.0 (0.0 if invalid player).
 #[inline]
@@ -187,13 +190,13 @@ pub fn left_stick_x(caller: Caller<'_, ZXGameContext>, player: u32) -> f32 {
     let Some(player_idx) = validate_player(player, "left_stick_x") else {
         return 0.0;
     };
-    caller.data().game.input_curr[player_idx].left_stick_x as f32 / STICK_SCALE
+    stick_value(caller.data().game.input_curr[player_idx].left_stick_x)
 }
 
 /// Get left stick Y axis value
 ///
 /// # Arguments
-/// * `player` — Player index (0-3)
+/// * `player` — Player index (0-7)
 ///
 /// Returns value from -1.0 to 1.0 (0.0 if invalid player).
 #[inline]
@@ -201,13 +204,13 @@ pub fn left_stick_y(caller: Caller<'_, ZXGameContext>, player: u32) -> f32 {
     let Some(player_idx) = validate_player(player, "left_stick_y") else {
         return 0.0;
     };
-    caller.data().game.input_curr[player_idx].left_stick_y as f32 / STICK_SCALE
+    stick_value(caller.data().game.input_curr[player_idx].left_stick_y)
 }
 
 /// Get right stick X axis value
 ///
 /// # Arguments
-/// * `player` — Player index (0-3)
+/// * `player` — Player index (0-7)
 ///
 /// Returns value from -1.0 to 1.0 (0.0 if invalid player).
 #[inline]
@@ -215,13 +218,13 @@ pub fn right_stick_x(caller: Caller<'_, ZXGameContext>, player: u32) -> f32 {
     let Some(player_idx) = validate_player(player, "right_stick_x") else {
         return 0.0;
     };
-    caller.data().game.input_curr[player_idx].right_stick_x as f32 / STICK_SCALE
+    stick_value(caller.data().game.input_curr[player_idx].right_stick_x)
 }
 
 /// Get right stick Y axis value
 ///
 /// # Arguments
-/// * `player` — Player index (0-3)
+/// * `player` — Player index (0-7)
 ///
 /// Returns value from -1.0 to 1.0 (0.0 if invalid player).
 #[inline]
@@ -229,13 +232,13 @@ pub fn right_stick_y(caller: Caller<'_, ZXGameContext>, player: u32) -> f32 {
     let Some(player_idx) = validate_player(player, "right_stick_y") else {
         return 0.0;
     };
-    caller.data().game.input_curr[player_idx].right_stick_y as f32 / STICK_SCALE
+    stick_value(caller.data().game.input_curr[player_idx].right_stick_y)
 }
 
 /// Get both left stick axes at once
 ///
 /// # Arguments
-/// * `player` — Player index (0-3)
+/// * `player` — Player index (0-7)
 /// * `out_x` — Pointer to write X axis value (-1.0 to 1.0)
 /// * `out_y` — Pointer to write Y axis value (-1.0 to 1.0)
 ///
@@ -246,8 +249,8 @@ pub fn left_stick(mut caller: Caller<'_, ZXGameContext>, player: u32, out_x: u32
         Some(player_idx) => {
             let input = &caller.data().game.input_curr[player_idx];
             (
-                input.left_stick_x as f32 / STICK_SCALE,
-                input.left_stick_y as f32 / STICK_SCALE,
+                stick_value(input.left_stick_x),
+                stick_value(input.left_stick_y),
             )
         }
         None => (0.0f32, 0.0f32),
@@ -278,7 +281,7 @@ pub fn left_stick(mut caller: Caller<'_, ZXGameContext>, player: u32, out_x: u32
 /// Get both right stick axes at once
 ///
 /// # Arguments
-/// * `player` — Player index (0-3)
+/// * `player` — Player index (0-7)
 /// * `out_x` — Pointer to write X axis value (-1.0 to 1.0)
 /// * `out_y` — Pointer to write Y axis value (-1.0 to 1.0)
 ///
@@ -289,8 +292,8 @@ pub fn right_stick(mut caller: Caller<'_, ZXGameContext>, player: u32, out_x: u3
         Some(player_idx) => {
             let input = &caller.data().game.input_curr[player_idx];
             (
-                input.right_stick_x as f32 / STICK_SCALE,
-                input.right_stick_y as f32 / STICK_SCALE,
+                stick_value(input.right_stick_x),
+                stick_value(input.right_stick_y),
             )
         }
         None => (0.0f32, 0.0f32),
@@ -325,7 +328,7 @@ pub fn right_stick(mut caller: Caller<'_, ZXGameContext>, player: u32, out_x: u3
 /// Get left trigger value
 ///
 /// # Arguments
-/// * `player` — Player index (0-3)
+/// * `player` — Player index (0-7)
 ///
 /// Returns value from 0.0 to 1.0 (0.0 if invalid player).
 #[inline]
@@ -333,13 +336,13 @@ pub fn trigger_left(caller: Caller<'_, ZXGameContext>, player: u32) -> f32 {
     let Some(player_idx) = validate_player(player, "trigger_left") else {
         return 0.0;
     };
-    caller.data().game.input_curr[player_idx].left_trigger as f32 / TRIGGER_SCALE
+    trigger_value(caller.data().game.input_curr[player_idx].left_trigger)
 }
 
 /// Get right trigger value
 ///
 /// # Arguments
-/// * `player` — Player index (0-3)
+/// * `player` — Player index (0-7)
 ///
 /// Returns value from 0.0 to 1.0 (0.0 if invalid player).
 #[inline]
@@ -347,7 +350,28 @@ pub fn trigger_right(caller: Caller<'_, ZXGameContext>, player: u32) -> f32 {
     let Some(player_idx) = validate_player(player, "trigger_right") else {
         return 0.0;
     };
-    caller.data().game.input_curr[player_idx].right_trigger as f32 / TRIGGER_SCALE
+    trigger_value(caller.data().game.input_curr[player_idx].right_trigger)
+}
+
+// ============================================================================
+// Analog Grid Functions
+// ============================================================================
+
+/// Get the step between stick axis values
+///
+/// Stick axes are quantized to multiples of this step (1/127) before they
+/// reach the game, so every peer simulates from the same values.
+#[inline]
+pub fn stick_step(_caller: Caller<'_, ZXGameContext>) -> f32 {
+    1.0 / STICK_SCALE
+}
+
+/// Get the step between trigger values
+///
+/// Triggers are quantized to multiples of this step (1/255).
+#[inline]
+pub fn trigger_step(_caller: Caller<'_, ZXGameContext>) -> f32 {
+    1.0 / TRIGGER_SCALE
 }
 
 // ============================================================================
@@ -373,5 +397,7 @@ pub fn register(linker: &mut Linker<ZXGameContext>) -> Result<()> {
     linker.func_wrap("env", "right_stick", right_stick)?;
     linker.func_wrap("env", "trigger_left", trigger_left)?;
     linker.func_wrap("env", "trigger_right", trigger_right)?;
+    linker.func_wrap("env", "stick_step", stick_step)?;
+    linker.func_wrap("env", "trigger_step", trigger_step)?;
     Ok(())
 }
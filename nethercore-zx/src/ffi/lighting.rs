@@ -1,13 +1,16 @@
 //! Lighting FFI functions (Mode 2 PBR)
 //!
-//! Functions for configuring directional and point lights in PBR mode.
+//! Functions for configuring directional and point lights in PBR mode, plus
+//! the per-frame scene light list (`light_add_point()` / `light_add_spot()`).
 
 use anyhow::Result;
+use glam::Vec3;
 use tracing::warn;
 use wasmtime::{Caller, Linker};
 
 use super::ZXGameContext;
-use crate::graphics::LightType;
+use crate::graphics::epu::{EnvSun, MAX_ENV_STATES};
+use crate::graphics::{LightType, MAX_SCENE_LIGHTS, PackedSceneLight};
 
 /// Register lighting FFI functions
 pub fn register(linker: &mut Linker<ZXGameContext>) -> Result<()> {
@@ -21,6 +24,13 @@ pub fn register(linker: &mut Linker<ZXGameContext>) -> Result<()> {
     // Point light functions
     linker.func_wrap("env", "light_set_point", light_set_point)?;
     linker.func_wrap("env", "light_range", light_range)?;
+
+    // Environment-driven lights
+    linker.func_wrap("env", "light_from_env", light_from_env)?;
+
+    // Scene lights (per frame, beyond the 4 per-draw lights)
+    linker.func_wrap("env", "light_add_point", light_add_point)?;
+    linker.func_wrap("env", "light_add_spot", light_add_spot)?;
     Ok(())
 }
 
@@ -315,3 +325,132 @@ fn light_range(mut caller: Caller<'_, ZXGameContext>, index: u32, range: f32) {
 
     state.update_point_light(index as usize, position, color, intensity, range, enabled);
 }
+
+/// Configure a directional light from an environment's sun
+///
+/// # Arguments
+/// * `index` — Light index (0-3)
+/// * `slot` — Environment slot (env_id) to read
+///
+/// Finds the brightest LOBE_RADIANCE or DECAL layer in the slot's sky and sets
+/// the light's direction, color and intensity to match it, enabling it as a
+/// directional light. Uses this frame's `epu_set()` config for the slot, else
+/// last frame's, falling back to environment 0.
+///
+/// Returns 1 if a sun was found, 0 otherwise (the light is left unchanged).
+fn light_from_env(mut caller: Caller<'_, ZXGameContext>, index: u32, slot: u32) -> u32 {
+    if index > 3 {
+        warn!(
+            "light_from_env: invalid light index {} (must be 0-3)",
+            index
+        );
+        return 0;
+    }
+    if slot >= MAX_ENV_STATES {
+        warn!(
+            "light_from_env: invalid environment slot {} (must be 0-{})",
+            slot,
+            MAX_ENV_STATES - 1
+        );
+        return 0;
+    }
+
+    let state = &mut caller.data_mut().ffi;
+    let Some(sun) = EnvSun::from_config(&state.env_config(slot)) else {
+        return 0;
+    };
+    state.update_light(
+        index as usize,
+        sun.direction.to_array(),
+        sun.color,
+        sun.intensity,
+        true,
+    );
+    1
+}
+
+/// Add a point light to the scene for this frame
+///
+/// # Arguments
+/// * `x`, `y`, `z` — World-space position
+/// * `color` — Light color (0xRRGGBBAA, alpha ignored)
+/// * `intensity` — Brightness multiplier (0.0-8.0)
+/// * `range` — Distance at which the light reaches zero
+///
+/// Scene lights light every draw in modes 0, 2 and 3, on top of the 4 lights
+/// set with `light_set()` / `light_set_point()`. Up to 256 can be added per
+/// frame; each surface only pays for the lights whose range reaches it.
+/// Call every frame from `render()` — the list is cleared after each frame.
+fn light_add_point(
+    mut caller: Caller<'_, ZXGameContext>,
+    x: f32,
+    y: f32,
+    z: f32,
+    color: u32,
+    intensity: f32,
+    range: f32,
+) {
+    let light = PackedSceneLight::point(
+        Vec3::new(x, y, z),
+        Vec3::from(super::unpack_rgb(color)),
+        intensity,
+        range,
+    );
+    add_scene_light(&mut caller, "light_add_point", light);
+}
+
+/// Add a spot light to the scene for this frame
+///
+/// # Arguments
+/// * `x`, `y`, `z` — World-space position
+/// * `dir_x`, `dir_y`, `dir_z` — Direction the spot points (will be normalized)
+/// * `color` — Light color (0xRRGGBBAA, alpha ignored)
+/// * `intensity` — Brightness multiplier (0.0-8.0)
+/// * `range` — Distance at which the light reaches zero
+/// * `inner_angle` — Cone half-angle in radians with full brightness
+/// * `outer_angle` — Cone half-angle in radians where the light reaches zero
+///
+/// Otherwise behaves like `light_add_point()`.
+fn light_add_spot(
+    mut caller: Caller<'_, ZXGameContext>,
+    x: f32,
+    y: f32,
+    z: f32,
+    dir_x: f32,
+    dir_y: f32,
+    dir_z: f32,
+    color: u32,
+    intensity: f32,
+    range: f32,
+    inner_angle: f32,
+    outer_angle: f32,
+) {
+    let light = PackedSceneLight::spot(
+        Vec3::new(x, y, z),
+        Vec3::new(dir_x, dir_y, dir_z),
+        Vec3::from(super::unpack_rgb(color)),
+        intensity,
+        range,
+        inner_angle,
+        outer_angle,
+    );
+    add_scene_light(&mut caller, "light_add_spot", light);
+}
+
+/// Validate and queue a scene light
+fn add_scene_light(caller: &mut Caller<'_, ZXGameContext>, fn_name: &str, light: PackedSceneLight) {
+    if !Vec3::from(light.position).is_finite() || !light.range.is_finite() {
+        warn!("{}: position and range must be finite", fn_name);
+        return;
+    }
+    if light.range <= 0.0 {
+        warn!("{}: range must be positive (got {})", fn_name, light.range);
+        return;
+    }
+    if !caller.data_mut().ffi.add_scene_light(light) {
+        warn!(
+            "{}: scene light limit reached ({} per frame)",
+            fn_name, MAX_SCENE_LIGHTS
+        );
+    }
+}
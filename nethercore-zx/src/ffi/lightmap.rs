@@ -0,0 +1,132 @@
+//! Baked lighting FFI functions
+//!
+//! Bake the current lights into static meshes during init(), then select the
+//! lightmap per draw with `material_lightmap()`. Baked lights can be disabled
+//! afterwards so they cost nothing at runtime.
+
+use anyhow::Result;
+use glam::Mat4;
+use tracing::warn;
+use wasmtime::{Caller, Linker};
+
+use super::ZXGameContext;
+use super::guards::guard_init_only;
+use super::helpers::{pending_mesh_geometry, pending_mesh_normals, read_wasm_bytes};
+use crate::lightmap::{MAX_LIGHTMAPS, bake_vertices};
+
+/// Register baked lighting FFI functions
+pub fn register(linker: &mut Linker<ZXGameContext>) -> Result<()> {
+    linker.func_wrap("env", "lightmap_bake", lightmap_bake)?;
+    linker.func_wrap("env", "material_lightmap", material_lightmap)?;
+    Ok(())
+}
+
+/// Bake the current lights into static meshes (init-only)
+///
+/// # Arguments
+/// * `mesh_handles_ptr` — Pointer to an array of u32 mesh handles (must have normals)
+/// * `mesh_count` — Number of mesh handles
+/// * `light_set` — Bitmask of lights to bake (bit N = light N, 0-3)
+///
+/// Irradiance is computed per vertex with the current transform applied, so
+/// set the transform the meshes will be drawn with first. Disabled lights in
+/// the set are skipped.
+///
+/// Returns a lightmap handle (>0) on success, 0 on failure.
+fn lightmap_bake(
+    mut caller: Caller<'_, ZXGameContext>,
+    mesh_handles_ptr: u32,
+    mesh_count: u32,
+    light_set: u32,
+) -> u32 {
+    const FN_NAME: &str = "lightmap_bake";
+
+    guard_init_only!(caller, FN_NAME);
+
+    if mesh_count == 0 {
+        warn!("{}: mesh_count cannot be 0", FN_NAME);
+        return 0;
+    }
+
+    let Some(bytes) = read_wasm_bytes(&caller, mesh_handles_ptr, mesh_count as usize * 4, FN_NAME)
+    else {
+        return 0;
+    };
+    let handles: Vec<u32> = bytes
+        .chunks_exact(4)
+        .map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+        .collect();
+
+    let state = &mut caller.data_mut().ffi;
+    if state.lightmaps.lightmaps.len() >= MAX_LIGHTMAPS {
+        warn!(
+            "{}: maximum lightmap count {} exceeded",
+            FN_NAME, MAX_LIGHTMAPS
+        );
+        return 0;
+    }
+
+    let lights: Vec<_> = state
+        .current_shading_state
+        .lights
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| light_set & (1 << i) != 0)
+        .map(|(_, light)| *light)
+        .collect();
+    let model = state
+        .current_model_matrix
+        .or_else(|| state.model_matrices.last().copied())
+        .unwrap_or(Mat4::IDENTITY);
+
+    let mut baked = Vec::with_capacity(handles.len());
+    for handle in handles {
+        let Some((positions, _)) = pending_mesh_geometry(state, handle) else {
+            warn!(
+                "{}: mesh {} not found (bake must run in init() after the mesh is loaded)",
+                FN_NAME, handle
+            );
+            return 0;
+        };
+        let Some(normals) = pending_mesh_normals(state, handle) else {
+            warn!("{}: mesh {} has no normals", FN_NAME, handle);
+            return 0;
+        };
+        baked.push((handle, bake_vertices(&positions, &normals, model, &lights)));
+    }
+
+    let vertex_count: usize = baked.iter().map(|(_, v)| v.len()).sum();
+    let Some(lightmap) = state.lightmaps.add(baked) else {
+        warn!(
+            "{}: baking {} vertices exceeds the lightmap vertex limit",
+            FN_NAME, vertex_count
+        );
+        return 0;
+    };
+
+    tracing::debug!(
+        "{}: baked {} lights into {} vertices",
+        FN_NAME,
+        lights.len(),
+        vertex_count
+    );
+
+    lightmap
+}
+
+/// Apply a baked lightmap to subsequent mesh draws
+///
+/// # Arguments
+/// * `lightmap` — Lightmap handle from `lightmap_bake()`, or 0 to disable
+///
+/// Only meshes baked into the lightmap receive its lighting; other meshes are
+/// drawn unchanged. Baked light is added to the dynamic lights in modes 0, 2
+/// and 3 (matcap ignores it).
+fn material_lightmap(mut caller: Caller<'_, ZXGameContext>, lightmap: u32) {
+    let state = &mut caller.data_mut().ffi;
+    if lightmap as usize > state.lightmaps.lightmaps.len() {
+        warn!("material_lightmap: invalid lightmap handle {}", lightmap);
+        return;
+    }
+    state.current_lightmap = lightmap;
+}
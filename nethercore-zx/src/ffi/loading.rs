@@ -0,0 +1,155 @@
+//! Loading screen FFI functions
+//!
+//! Games that split their initialization across frames with an `init_step()`
+//! export get a host-drawn loading screen between steps. These functions set
+//! its progress bar and background.
+//!
+//! **Init-only:** Calls outside of init() and init_step() are ignored with a
+//! warning.
+
+use anyhow::Result;
+use tracing::warn;
+use wasmtime::{Caller, Linker};
+
+use super::ZXGameContext;
+use super::draw_2d::{SCREEN_SPACE_DEPTH, push_rect};
+use crate::console::RESOLUTION;
+use crate::state::ZXFFIState;
+
+/// Progress bar size in pixels
+const BAR_WIDTH: f32 = 480.0;
+const BAR_HEIGHT: f32 = 8.0;
+
+/// Distance from the bottom of the screen to the bar in pixels
+const BAR_BOTTOM: f32 = 64.0;
+
+/// Width of the frame around the bar in pixels
+const BAR_BORDER: f32 = 2.0;
+
+/// Bar frame and track color (0xRRGGBBAA)
+const FRAME_COLOR: u32 = 0x101018E0;
+/// Bar fill color
+const FILL_COLOR: u32 = 0xFFFFFFFF;
+
+/// Register loading screen FFI functions
+pub fn register(linker: &mut Linker<ZXGameContext>) -> Result<()> {
+    linker.func_wrap("env", "load_progress", load_progress)?;
+    linker.func_wrap("env", "load_background", load_background)?;
+    Ok(())
+}
+
+/// Report loading progress for the loading bar
+///
+/// # Arguments
+/// * `current` — Items loaded so far (clamped to `total`)
+/// * `total` — Total items to load (0 hides the bar)
+///
+/// The bar is shown between `init_step()` calls. Games that load everything
+/// in `init()` never show a loading screen.
+fn load_progress(mut caller: Caller<'_, ZXGameContext>, current: u32, total: u32) {
+    if !caller.data().game.in_init {
+        warn!("load_progress() called outside init() - ignored");
+        return;
+    }
+    caller.data_mut().ffi.load_progress = [current.min(total), total];
+}
+
+/// Set the loading screen background
+///
+/// # Arguments
+/// * `texture` — Texture handle stretched over the whole screen (0 = clear color only)
+///
+/// Textures are uploaded after the `init()` or `init_step()` call that loads
+/// them, so load the background in `init()` to show it from the first
+/// loading frame.
+fn load_background(mut caller: Caller<'_, ZXGameContext>, texture: u32) {
+    if !caller.data().game.in_init {
+        warn!("load_background() called outside init() - ignored");
+        return;
+    }
+    caller.data_mut().ffi.load_background = texture;
+}
+
+/// Record the loading screen: the background and the progress bar
+///
+/// Called by the host in place of `render()` while the game runs
+/// `init_step()`. Color and bound texture are preserved.
+pub(crate) fn draw_loading_screen(state: &mut ZXFFIState) {
+    let saved_color = state.current_shading_state.color_rgba8;
+    let saved_texture = state.bound_textures[0];
+    let saved_atlas_sprite = state.bound_atlas_sprite;
+    let (width, height) = (RESOLUTION.0 as f32, RESOLUTION.1 as f32);
+
+    if state.load_background != 0 {
+        state.bound_textures[0] = state.load_background;
+        state.bound_atlas_sprite = None;
+        state.update_color(0xFFFFFFFF);
+        let shading_state_index = state.add_shading_state();
+        let instance = crate::graphics::QuadInstance::sprite(
+            0.0,
+            0.0,
+            SCREEN_SPACE_DEPTH,
+            width,
+            height,
+            0.0,
+            [0.0, 0.0, 1.0, 1.0],
+            shading_state_index.0,
+            (state.view_matrices.len() - 1) as u32,
+        );
+        state.add_quad_instance(instance, state.current_z_index);
+    }
+
+    let [current, total] = state.load_progress;
+    if total > 0 {
+        let x = (width - BAR_WIDTH) * 0.5;
+        let y = height - BAR_BOTTOM - BAR_HEIGHT;
+        state.update_color(FRAME_COLOR);
+        push_rect(
+            state,
+            x - BAR_BORDER,
+            y - BAR_BORDER,
+            BAR_WIDTH + BAR_BORDER * 2.0,
+            BAR_HEIGHT + BAR_BORDER * 2.0,
+        );
+
+        let fill = current.min(total) as f32 / total as f32;
+        if fill > 0.0 {
+            state.update_color(FILL_COLOR);
+            push_rect(state, x, y, BAR_WIDTH * fill, BAR_HEIGHT);
+        }
+    }
+
+    state.update_color(saved_color);
+    state.bound_textures[0] = saved_texture;
+    state.bound_atlas_sprite = saved_atlas_sprite;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_loading_screen_bar_fill() {
+        let mut state = ZXFFIState::new();
+        state.update_color(0xFF0000FF);
+
+        // Nothing to draw without a background or a total
+        draw_loading_screen(&mut state);
+        assert!(state.quad_batches().is_empty());
+
+        state.load_background = 3;
+        state.load_progress = [1, 4];
+        draw_loading_screen(&mut state);
+
+        let batches = state.quad_batches();
+        assert_eq!(batches.len(), 2);
+        assert_eq!(batches[0].textures[0], 3);
+        assert_eq!(batches[0].instances[0].size, [960.0, 540.0]);
+        let fill = &batches[1].instances[1];
+        assert_eq!(fill.size, [120.0, BAR_HEIGHT]);
+        assert_eq!(fill.position[0], 240.0);
+
+        assert_eq!(state.current_shading_state.color_rgba8, 0xFF0000FF);
+        assert_eq!(state.bound_textures[0], 0);
+    }
+}
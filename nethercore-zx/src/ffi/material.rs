@@ -32,6 +32,10 @@ pub fn register(linker: &mut Linker<ZXGameContext>) -> Result<()> {
     linker.func_wrap("env", "material_spec_damping", material_specular_damping)?; // Short alias
     linker.func_wrap("env", "material_specular_color", material_specular_color)?;
 
+    // Mode 4 (toon)
+    linker.func_wrap("env", "material_bands", material_bands)?;
+    linker.func_wrap("env", "material_outline", material_outline)?;
+
     // Material override flags (see material-overrides-spec.md)
     linker.func_wrap("env", "use_uniform_color", use_uniform_color)?;
     linker.func_wrap("env", "use_uniform_metallic", use_uniform_metallic)?;
@@ -192,7 +196,7 @@ fn material_rim(mut caller: Caller<'_, ZXGameContext>, intensity: f32, power: f3
     state.update_material_rim_power(power_clamped);
 }
 
-/// Set shininess (Mode 3 only, alias for material_roughness)
+/// Set shininess (Modes 3-4, alias for material_roughness)
 ///
 /// # Arguments
 /// * `value` — Shininess value 0.0-1.0 (mapped to 1-256 range internally)
@@ -204,7 +208,7 @@ fn material_shininess(caller: Caller<'_, ZXGameContext>, value: f32) {
     material_roughness(caller, value);
 }
 
-/// Set specular color (Mode 3 only)
+/// Set specular color (Modes 3-4)
 /// color: RGBA8 packed u32 (RGB used, A ignored)
 /// Format: 0xRRGGBBAA (R in highest byte, A in lowest)
 /// 0xFFFFFFFF = white (neutral specular - highlights match light color)
@@ -246,6 +250,57 @@ fn material_specular_color(mut caller: Caller<'_, ZXGameContext>, r: f32, g: f32
     state.update_specular_color(r.clamp(0.0, 1.0), g.clamp(0.0, 1.0), b.clamp(0.0, 1.0));
 }
 
+// ============================================================================
+// Mode 4 (Toon)
+// ============================================================================
+
+/// Maximum number of lit bands for toon shading
+const MAX_TOON_BANDS: u32 = 16;
+
+/// Set the number of diffuse light bands (Mode 4 only)
+///
+/// # Arguments
+/// * `count` — Lit bands above the shadow side, 1-16 (1 = two-tone cel look)
+///
+/// Default is 3. Stored in uniform_set_0 byte 0 (the metallic slot in Mode 2).
+fn material_bands(mut caller: Caller<'_, ZXGameContext>, count: u32) {
+    let state = &mut caller.data_mut().ffi;
+    let clamped = count.clamp(1, MAX_TOON_BANDS);
+
+    if clamped != count {
+        warn!(
+            "material_bands: count {} out of range, clamped to {}",
+            count, clamped
+        );
+    }
+
+    state.update_toon_bands(clamped as u8);
+}
+
+/// Set the inked outline drawn around meshes (Mode 4 only)
+///
+/// # Arguments
+/// * `width` — Outline thickness in world units (0.0 = no outline, default)
+/// * `color` — Outline color as 0xRRGGBBAA (alpha uses dither transparency)
+///
+/// Each draw_mesh()/draw_triangles() with normals is drawn a second time,
+/// pushed out along its normals with front faces culled, so only the shell
+/// around the silhouette shows. Stays set until changed, like other material state.
+fn material_outline(mut caller: Caller<'_, ZXGameContext>, width: f32, color: u32) {
+    let state = &mut caller.data_mut().ffi;
+
+    if !width.is_finite() || width < 0.0 {
+        warn!(
+            "material_outline: width {} must be finite and non-negative, using 0.0",
+            width
+        );
+        state.outline_width = 0.0;
+    } else {
+        state.outline_width = width;
+    }
+    state.outline_color = color;
+}
+
 // ============================================================================
 // Material Override Flag Functions
 // See material-overrides-spec.md for details
@@ -3,16 +3,18 @@
 //! Functions for loading and drawing retained meshes.
 
 use anyhow::Result;
+use glam::{BVec3, Mat4, Vec3};
 use tracing::warn;
 use wasmtime::{Caller, Linker};
 
+use super::debug_draw::Projector;
 use super::helpers::{
     checked_mul, read_wasm_bytes, read_wasm_floats, read_wasm_u16s, validate_count_nonzero,
     validate_vertex_format,
 };
 use super::{ZXGameContext, guards::guard_init_only};
-use crate::graphics::{vertex_stride, vertex_stride_packed};
-use crate::state::{PendingMesh, PendingMeshPacked};
+use crate::graphics::{FORMAT_SKINNED, vertex_stride, vertex_stride_packed};
+use crate::state::{PendingMesh, PendingMeshPacked, ZXFFIState};
 
 /// Register mesh FFI functions
 pub fn register(linker: &mut Linker<ZXGameContext>) -> Result<()> {
@@ -26,6 +28,7 @@ pub fn register(linker: &mut Linker<ZXGameContext>) -> Result<()> {
 
     // Mesh drawing
     linker.func_wrap("env", "draw_mesh", draw_mesh)?;
+    linker.func_wrap("env", "mesh_morph_weight", mesh_morph_weight)?;
     Ok(())
 }
 
@@ -327,23 +330,89 @@ fn load_mesh_indexed_packed(
 ///
 /// The mesh is drawn using the current transform (from transform_* functions)
 /// and render state (color, textures, depth test, cull mode, blend mode).
+/// Meshes whose bounds are entirely outside the camera's view are skipped;
+/// skinned meshes are always drawn.
 fn draw_mesh(mut caller: Caller<'_, ZXGameContext>, handle: u32) {
-    if handle == 0 {
-        warn!("draw_mesh: invalid handle 0");
-        return;
+    if !push_mesh(&mut caller.data_mut().ffi, handle) {
+        warn!("draw_mesh: invalid handle {}", handle);
     }
+}
 
+/// Set the weight of one of a mesh's morph targets
+///
+/// # Arguments
+/// * `handle` — Mesh handle from rom_mesh
+/// * `target_index` — Morph target index, in the order the targets were exported
+/// * `weight` — How much of the target to apply (0 = none, 1 = full; other
+///   values extrapolate)
+///
+/// Weights stay set until changed and apply to every later draw of the mesh,
+/// so one mesh can be drawn several times per frame with different weights.
+fn mesh_morph_weight(
+    mut caller: Caller<'_, ZXGameContext>,
+    handle: u32,
+    target_index: u32,
+    weight: f32,
+) {
+    if !weight.is_finite() {
+        warn!("mesh_morph_weight: weight must be finite");
+        return;
+    }
     let state = &mut caller.data_mut().ffi;
+    if !state.morphs.set_weight(handle, target_index, weight) {
+        warn!(
+            "mesh_morph_weight: mesh {} has no morph target {}",
+            handle, target_index
+        );
+    }
+}
 
-    // Look up mesh
-    let mesh = match state.mesh_map.get(&handle) {
-        Some(m) => m,
-        None => {
-            warn!("draw_mesh: invalid handle {}", handle);
-            return;
+/// Record a draw of a retained mesh
+///
+/// Shared by `draw_mesh()` and destructible props. Uses the current transform
+/// and render state. Returns `false` if `handle` is not a loaded mesh.
+pub(crate) fn push_mesh(state: &mut ZXFFIState, handle: u32) -> bool {
+    // Merged meshes split across several parts draw every part
+    if let Some(&(first, count)) = state.mesh_groups.get(&handle) {
+        for part in first..first + count {
+            push_mesh(state, part);
         }
+        return true;
+    }
+
+    // Remember where static meshes are drawn so decals can be projected onto
+    // them, even when they're culled from this view
+    if state.decal_surfaces.contains_key(&handle) {
+        let model = state
+            .current_model_matrix
+            .or_else(|| state.model_matrices.last().copied())
+            .unwrap_or(Mat4::IDENTITY);
+        state.decal_receivers.push((handle, model));
+    }
+
+    // Look up mesh
+    let Some(mesh) = state.mesh_map.get(&handle) else {
+        return false;
     };
 
+    // Morph targets are per mesh like lightmaps; only applied with a nonzero weight
+    let morph = state.morphs.get(handle).filter(|m| m.is_active()).copied();
+
+    // Frustum culling (skinned and morphed vertices can move outside the bind
+    // pose bounds)
+    if mesh.format & FORMAT_SKINNED == 0
+        && morph.is_none()
+        && outside_frustum(state, mesh.bounds_min, mesh.bounds_max)
+    {
+        let triangles = if mesh.index_count > 0 {
+            mesh.index_count / 3
+        } else {
+            mesh.vertex_count / 3
+        };
+        state.triangles_culled = state.triangles_culled.saturating_add(triangles);
+        return true;
+    }
+
     // Extract mesh data
     let mesh_format = mesh.format;
     let mesh_vertex_count = mesh.vertex_count;
@@ -357,15 +426,41 @@ fn draw_mesh(mut caller: Caller<'_, ZXGameContext>, handle: u32) {
 
     let cull_mode = state.cull_mode;
 
+    // Blended meshes sort back to front by the center of their bounds
+    let blend = state.mesh_blend(mesh.bounds_min, mesh.bounds_max);
+
     // Capture current viewport for split-screen rendering
     let viewport = state.current_viewport;
 
     // Capture current pass_id for render pass ordering
     let pass_id = state.current_pass_id;
 
+    // Baked lighting is per mesh: point the shader at this mesh's lightmap
+    // vertices (vertex_index includes base_vertex, so subtract it here)
+    let base_vertex = (mesh_vertex_offset / vertex_stride_packed(mesh_format) as u64) as u32;
+    let lightmap_base = state
+        .lightmaps
+        .offset(state.current_lightmap, handle)
+        .map(|offset| offset.wrapping_sub(base_vertex));
+    if lightmap_base.is_some() {
+        state.update_lightmap(lightmap_base);
+    }
+    if let Some(morph) = morph {
+        state.update_morph(Some((
+            morph.base.wrapping_sub(base_vertex),
+            morph.vertex_count,
+            morph.packed_weights(),
+        )));
+    }
+
     // Allocate combined MVP+shading buffer index (lazy allocation with deduplication)
     let buffer_index = state.add_mvp_shading_state();
 
+    // Other draws must not inherit this mesh's lightmap
+    if lightmap_base.is_some() {
+        state.update_lightmap(None);
+    }
+
     // Record draw command directly
     state.render_pass.record_mesh(
         mesh_format,
@@ -376,7 +471,105 @@ fn draw_mesh(mut caller: Caller<'_, ZXGameContext>, handle: u32) {
         buffer_index,
         textures,
         cull_mode,
+        blend,
         viewport,
         pass_id,
     );
+
+    // Mode 4 inked silhouette: repeat the draw as an outline pass
+    if let Some(outline_index) = state.add_outline_mvp_shading_state(mesh_format) {
+        state.render_pass.record_outline(outline_index);
+    }
+
+    // Other draws must not inherit this mesh's morph weights (the outline
+    // pass above keeps them so it follows the morphed surface)
+    if morph.is_some() {
+        state.update_morph(None);
+    }
+    true
+}
+
+/// Whether the box `min`-`max` under the current model transform is entirely
+/// outside the current camera's view frustum
+fn outside_frustum(state: &ZXFFIState, min: Vec3, max: Vec3) -> bool {
+    let model = state
+        .current_model_matrix
+        .or_else(|| state.model_matrices.last().copied())
+        .unwrap_or(Mat4::IDENTITY);
+    let projector = Projector::from_state(state);
+
+    // Outside if all eight corners are beyond the same clip plane
+    let mut outside = [true; 6];
+    for i in 0..8 {
+        let corner = Vec3::select(BVec3::new(i & 1 != 0, i & 2 != 0, i & 4 != 0), max, min);
+        let clip = projector.clip(model.transform_point3(corner));
+        outside[0] &= clip.x < -clip.w;
+        outside[1] &= clip.x > clip.w;
+        outside[2] &= clip.y < -clip.w;
+        outside[3] &= clip.y > clip.w;
+        outside[4] &= clip.z < 0.0;
+        outside[5] &= clip.z > clip.w;
+    }
+    outside.contains(&true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graphics::RetainedMesh;
+
+    fn cube_state() -> ZXFFIState {
+        let mut state = ZXFFIState::new();
+        state.mesh_map.insert(
+            1,
+            RetainedMesh {
+                format: 0,
+                vertex_count: 8,
+                index_count: 36,
+                vertex_offset: 0,
+                index_offset: 0,
+                bounds_min: Vec3::splat(-0.5),
+                bounds_max: Vec3::splat(0.5),
+            },
+        );
+        state
+    }
+
+    #[test]
+    fn test_push_mesh_frustum_culling() {
+        // Default camera sits at +5 Z looking at the origin
+        let mut state = cube_state();
+        assert!(push_mesh(&mut state, 1));
+        assert_eq!(state.render_pass.commands().len(), 1);
+        assert_eq!(state.triangles_culled, 0);
+
+        for offset in [Vec3::new(0.0, 0.0, 10.0), Vec3::new(100.0, 0.0, 0.0)] {
+            state.current_model_matrix = Some(Mat4::from_translation(offset));
+            assert!(push_mesh(&mut state, 1));
+        }
+        assert_eq!(state.render_pass.commands().len(), 1);
+        assert_eq!(state.triangles_culled, 24);
+
+        // Skinned meshes are never culled
+        state.mesh_map.get_mut(&1).unwrap().format = FORMAT_SKINNED;
+        assert!(push_mesh(&mut state, 1));
+        assert_eq!(state.render_pass.commands().len(), 2);
+    }
+
+    #[test]
+    fn test_push_mesh_records_decal_receivers() {
+        let mut state = cube_state();
+        push_mesh(&mut state, 1);
+        assert!(state.decal_receivers.is_empty());
+
+        let positions = vec![Vec3::ZERO, Vec3::X, Vec3::Y];
+        let surface = crate::decal::DecalSurface::new(positions, &[0, 1, 2]).unwrap();
+        state.decal_surfaces.insert(1, surface);
+
+        // Receivers are recorded even when the mesh itself is culled
+        let offset = Mat4::from_translation(Vec3::new(100.0, 0.0, 0.0));
+        state.current_model_matrix = Some(offset);
+        push_mesh(&mut state, 1);
+        assert_eq!(state.decal_receivers, vec![(1, offset)]);
+    }
 }
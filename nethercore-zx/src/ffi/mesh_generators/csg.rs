@@ -0,0 +1,129 @@
+//! Mesh CSG boolean operations (FORMAT_NORMAL)
+//!
+//! Combine two meshes created during init() into a new flat-shaded mesh.
+//! The source meshes are left untouched.
+
+use tracing::{info, warn};
+use wasmtime::Caller;
+
+use crate::ffi::ZXGameContext;
+use crate::ffi::guards::guard_init_only;
+use crate::ffi::helpers::pending_mesh_geometry;
+use crate::graphics::FORMAT_NORMAL;
+use crate::procedural::{self, CsgOp, MAX_CSG_TRIANGLES, MeshData};
+use crate::state::PendingMeshPacked;
+
+/// Combine mesh `a` with mesh `b` (shared by the three FFI entry points)
+fn mesh_csg(
+    caller: &mut Caller<'_, ZXGameContext>,
+    fn_name: &str,
+    op: CsgOp,
+    a: u32,
+    b: u32,
+) -> u32 {
+    let state = &mut caller.data_mut().ffi;
+
+    let mut operands = Vec::with_capacity(2);
+    for handle in [a, b] {
+        let Some((positions, indices)) = pending_mesh_geometry(state, handle) else {
+            warn!(
+                "{}: mesh {} not found (both meshes must be created earlier in init())",
+                fn_name, handle
+            );
+            return 0;
+        };
+        if indices.len() / 3 > MAX_CSG_TRIANGLES {
+            warn!(
+                "{}: mesh {} has {} triangles (max {})",
+                fn_name,
+                handle,
+                indices.len() / 3,
+                MAX_CSG_TRIANGLES
+            );
+            return 0;
+        }
+        operands.push((positions, indices));
+    }
+    let (a_positions, a_indices) = &operands[0];
+    let (b_positions, b_indices) = &operands[1];
+
+    let triangles = procedural::csg_triangles(op, a_positions, a_indices, b_positions, b_indices);
+    if triangles.is_empty() {
+        warn!("{}: result of meshes {} and {} is empty", fn_name, a, b);
+        return 0;
+    }
+    let Some(mesh_data) = procedural::build_flat_mesh::<MeshData>(&triangles) else {
+        warn!(
+            "{}: result has {} triangles, exceeding the 16-bit index limit",
+            fn_name,
+            triangles.len()
+        );
+        return 0;
+    };
+
+    let handle = state.next_mesh_handle;
+    state.next_mesh_handle += 1;
+
+    state.pending_meshes_packed.push(PendingMeshPacked {
+        handle,
+        format: FORMAT_NORMAL,
+        vertex_data: mesh_data.vertices,
+        index_data: Some(mesh_data.indices),
+    });
+
+    info!(
+        "{}: created mesh {} from meshes {} and {} ({} triangles, PACKED)",
+        fn_name,
+        handle,
+        a,
+        b,
+        triangles.len()
+    );
+    handle
+}
+
+/// Combine two meshes into one covering the space inside either
+///
+/// # Arguments
+/// * `a`, `b` - Mesh handles created earlier in `init()`
+///
+/// Returns mesh handle (>0) on success, 0 on failure.
+///
+/// Works best on closed meshes. The result is flat-shaded with no UVs.
+///
+/// **Init-only**: Must be called during `init()`.
+pub fn mesh_union(mut caller: Caller<'_, ZXGameContext>, a: u32, b: u32) -> u32 {
+    guard_init_only!(caller, "mesh_union");
+    mesh_csg(&mut caller, "mesh_union", CsgOp::Union, a, b)
+}
+
+/// Carve mesh `b` out of mesh `a`
+///
+/// # Arguments
+/// * `a` - Mesh handle to carve from
+/// * `b` - Mesh handle to remove
+///
+/// Returns mesh handle (>0) on success, 0 on failure.
+///
+/// Works best on closed meshes. The result is flat-shaded with no UVs.
+///
+/// **Init-only**: Must be called during `init()`.
+pub fn mesh_subtract(mut caller: Caller<'_, ZXGameContext>, a: u32, b: u32) -> u32 {
+    guard_init_only!(caller, "mesh_subtract");
+    mesh_csg(&mut caller, "mesh_subtract", CsgOp::Subtract, a, b)
+}
+
+/// Keep only the space inside both meshes
+///
+/// # Arguments
+/// * `a`, `b` - Mesh handles created earlier in `init()`
+///
+/// Returns mesh handle (>0) on success, 0 on failure.
+///
+/// Works best on closed meshes. The result is flat-shaded with no UVs.
+///
+/// **Init-only**: Must be called during `init()`.
+pub fn mesh_intersect(mut caller: Caller<'_, ZXGameContext>, a: u32, b: u32) -> u32 {
+    guard_init_only!(caller, "mesh_intersect");
+    mesh_csg(&mut caller, "mesh_intersect", CsgOp::Intersect, a, b)
+}
@@ -8,10 +8,18 @@
 //! - _uv variants (sphere_uv, etc.): Format 5 (POS_UV_NORMAL) - textured rendering
 //! - _tangent variants (sphere_tangent, etc.): Format 21 (POS_UV_NORMAL_TANGENT) - normal mapped
 //!
+//! CSG operations (mesh_union, mesh_subtract, mesh_intersect) combine existing
+//! meshes into a new Format 4 mesh.
+//!
+//! mesh_recompute_normals and mesh_generate_tangents fix up the lighting of an
+//! existing mesh in place (for meshes displaced after generation).
+//!
 //! **IMPORTANT**: All procedural mesh functions are init-only. They queue meshes
 //! for GPU upload, which must happen during init() to ensure deterministic rollback.
 
 mod base_shapes;
+mod csg;
+mod shading;
 mod tangent_shapes;
 mod uv_shapes;
 
@@ -44,5 +52,22 @@ pub fn register(linker: &mut Linker<ZXGameContext>) -> Result<()> {
     linker.func_wrap("env", "cube_tangent", tangent_shapes::cube_tangent)?;
     linker.func_wrap("env", "torus_tangent", tangent_shapes::torus_tangent)?;
 
+    // CSG boolean operations (FORMAT_NORMAL - flat shaded)
+    linker.func_wrap("env", "mesh_union", csg::mesh_union)?;
+    linker.func_wrap("env", "mesh_subtract", csg::mesh_subtract)?;
+    linker.func_wrap("env", "mesh_intersect", csg::mesh_intersect)?;
+
+    // Normal and tangent recomputation (in place)
+    linker.func_wrap(
+        "env",
+        "mesh_recompute_normals",
+        shading::mesh_recompute_normals,
+    )?;
+    linker.func_wrap(
+        "env",
+        "mesh_generate_tangents",
+        shading::mesh_generate_tangents,
+    )?;
+
     Ok(())
 }
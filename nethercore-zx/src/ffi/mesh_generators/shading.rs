@@ -0,0 +1,399 @@
+//! Normal and tangent recomputation for meshes created during init()
+//!
+//! Games that displace a generated or loaded mesh (terrain noise, dents,
+//! wobbly props) call these on the same handle to fix its lighting before it
+//! is uploaded. The vertex format gains FORMAT_NORMAL / FORMAT_TANGENT as
+//! needed; every other attribute is kept.
+
+use glam::{Vec3, Vec4};
+use tracing::warn;
+use wasmtime::Caller;
+
+use crate::ffi::ZXGameContext;
+use crate::ffi::guards::guard_init_only;
+use crate::ffi::helpers::{pending_mesh_geometry, pending_mesh_normals, pending_mesh_uvs};
+use crate::graphics::{
+    FORMAT_COLOR, FORMAT_NORMAL, FORMAT_TANGENT, FORMAT_UV, pack_normal_octahedral, pack_tangent,
+    vertex_stride, vertex_stride_packed,
+};
+use crate::procedural;
+use crate::state::ZXFFIState;
+
+/// Sizes of the attributes in front of the normal, in vertex buffer elements
+/// (floats for f32 meshes, bytes for packed meshes)
+struct Layout {
+    position: usize,
+    uv: usize,
+    color: usize,
+    normal: usize,
+    tangent: usize,
+}
+
+const F32_LAYOUT: Layout = Layout {
+    position: 3,
+    uv: 2,
+    color: 3,
+    normal: 3,
+    tangent: 4,
+};
+
+const PACKED_LAYOUT: Layout = Layout {
+    position: 8,
+    uv: 4,
+    color: 4,
+    normal: 4,
+    tangent: 4,
+};
+
+impl Layout {
+    /// Offset of the normal (where it is, or would be inserted)
+    fn normal_offset(&self, format: u8) -> usize {
+        let mut offset = self.position;
+        if format & FORMAT_UV != 0 {
+            offset += self.uv;
+        }
+        if format & FORMAT_COLOR != 0 {
+            offset += self.color;
+        }
+        offset
+    }
+
+    /// Rebuild vertex data in `new_format`
+    ///
+    /// Output vertex `i` copies source vertex `vertices[i]`, with `normals[i]`
+    /// and `tangents[i]` written over (or in place of missing) normals and
+    /// tangents. Without replacements the source's own are kept.
+    #[allow(clippy::too_many_arguments)]
+    fn rebuild<T: Copy>(
+        &self,
+        data: &[T],
+        stride: usize,
+        format: u8,
+        new_format: u8,
+        vertices: &[u32],
+        normals: Option<&[Vec3]>,
+        tangents: Option<&[Vec4]>,
+        write_normal: impl Fn(&mut Vec<T>, Vec3),
+        write_tangent: impl Fn(&mut Vec<T>, Vec4),
+    ) -> Vec<T> {
+        let normal_at = self.normal_offset(format);
+        let mut tangent_at = normal_at;
+        if format & FORMAT_NORMAL != 0 {
+            tangent_at += self.normal;
+        }
+        let mut rest_at = tangent_at;
+        if format & FORMAT_TANGENT != 0 {
+            rest_at += self.tangent;
+        }
+
+        let mut out = Vec::with_capacity(vertices.len() * stride);
+        for (i, &v) in vertices.iter().enumerate() {
+            let vertex = &data[v as usize * stride..][..stride];
+            out.extend_from_slice(&vertex[..normal_at]);
+            match normals {
+                Some(normals) => write_normal(&mut out, normals[i]),
+                None => out.extend_from_slice(&vertex[normal_at..tangent_at]),
+            }
+            if new_format & FORMAT_TANGENT != 0 {
+                match tangents {
+                    Some(tangents) => write_tangent(&mut out, tangents[i]),
+                    None => out.extend_from_slice(&vertex[tangent_at..rest_at]),
+                }
+            }
+            out.extend_from_slice(&vertex[rest_at..]);
+        }
+        out
+    }
+}
+
+/// Replace a pending mesh's vertex data (see [`Layout::rebuild`])
+fn rewrite_mesh(
+    state: &mut ZXFFIState,
+    handle: u32,
+    new_format: u8,
+    vertices: &[u32],
+    normals: Option<&[Vec3]>,
+    tangents: Option<&[Vec4]>,
+    indices: Option<Vec<u16>>,
+) {
+    if let Some(mesh) = state.pending_meshes.iter_mut().find(|m| m.handle == handle) {
+        mesh.vertex_data = F32_LAYOUT.rebuild(
+            &mesh.vertex_data,
+            vertex_stride(mesh.format) as usize / 4,
+            mesh.format,
+            new_format,
+            vertices,
+            normals,
+            tangents,
+            |out, n| out.extend_from_slice(&n.to_array()),
+            |out, t| out.extend_from_slice(&t.to_array()),
+        );
+        mesh.format = new_format;
+        if indices.is_some() {
+            mesh.index_data = indices;
+        }
+    } else if let Some(mesh) = state
+        .pending_meshes_packed
+        .iter_mut()
+        .find(|m| m.handle == handle)
+    {
+        mesh.vertex_data = PACKED_LAYOUT.rebuild(
+            &mesh.vertex_data,
+            vertex_stride_packed(mesh.format) as usize,
+            mesh.format,
+            new_format,
+            vertices,
+            normals,
+            tangents,
+            |out, n| out.extend_from_slice(&pack_normal_octahedral(n.x, n.y, n.z).to_le_bytes()),
+            |out, t| {
+                out.extend_from_slice(&pack_tangent(t.truncate().to_array(), t.w).to_le_bytes())
+            },
+        );
+        mesh.format = new_format;
+        if indices.is_some() {
+            mesh.index_data = indices;
+        }
+    }
+}
+
+/// Pending mesh's vertex format
+fn pending_format(state: &ZXFFIState, handle: u32) -> Option<u8> {
+    state
+        .pending_meshes
+        .iter()
+        .find(|m| m.handle == handle)
+        .map(|m| m.format)
+        .or_else(|| {
+            state
+                .pending_meshes_packed
+                .iter()
+                .find(|m| m.handle == handle)
+                .map(|m| m.format)
+        })
+}
+
+/// Recompute a mesh's normals from its triangles
+///
+/// # Arguments
+/// * `handle` - Mesh handle created earlier in `init()`
+/// * `smooth_angle_deg` - Edges where faces meet at more than this angle stay
+///   sharp (0 = flat shading, 180 = smooth everywhere)
+///
+/// Vertices along sharp edges are split, so the mesh becomes indexed and may
+/// grow. Meshes without normals gain them. Meshes with tangents get their
+/// tangents regenerated to match.
+///
+/// Returns 1 on success, 0 on failure.
+///
+/// **Init-only**: Must be called during `init()`.
+pub fn mesh_recompute_normals(
+    mut caller: Caller<'_, ZXGameContext>,
+    handle: u32,
+    smooth_angle_deg: f32,
+) -> u32 {
+    const FN_NAME: &str = "mesh_recompute_normals";
+
+    guard_init_only!(caller, FN_NAME);
+
+    if !smooth_angle_deg.is_finite() {
+        warn!("{}: invalid smoothing angle {}", FN_NAME, smooth_angle_deg);
+        return 0;
+    }
+
+    let state = &mut caller.data_mut().ffi;
+    let (Some(format), Some((positions, indices))) = (
+        pending_format(state, handle),
+        pending_mesh_geometry(state, handle),
+    ) else {
+        warn!(
+            "{}: mesh {} not found (it must be created earlier in init())",
+            FN_NAME, handle
+        );
+        return 0;
+    };
+
+    let smoothed = procedural::smooth_normals(&positions, &indices, smooth_angle_deg);
+    if smoothed.vertices.len() > u16::MAX as usize + 1 {
+        warn!(
+            "{}: mesh {} needs {} vertices after splitting sharp edges, exceeding the 16-bit index limit",
+            FN_NAME,
+            handle,
+            smoothed.vertices.len()
+        );
+        return 0;
+    }
+
+    // Old tangents were built around the old normals
+    let tangents = if format & FORMAT_TANGENT != 0 {
+        pending_mesh_uvs(state, handle).map(|uvs| {
+            let positions: Vec<Vec3> = smoothed
+                .vertices
+                .iter()
+                .map(|&v| positions[v as usize])
+                .collect();
+            let uvs: Vec<_> = smoothed.vertices.iter().map(|&v| uvs[v as usize]).collect();
+            procedural::generate_tangents(&positions, &uvs, &smoothed.normals, &smoothed.indices)
+        })
+    } else {
+        None
+    };
+
+    let indices = smoothed.indices.iter().map(|&i| i as u16).collect();
+    rewrite_mesh(
+        state,
+        handle,
+        format | FORMAT_NORMAL,
+        &smoothed.vertices,
+        Some(&smoothed.normals),
+        tangents.as_deref(),
+        Some(indices),
+    );
+    1
+}
+
+/// Generate tangents for normal mapping from a mesh's UVs and normals
+///
+/// # Arguments
+/// * `handle` - Mesh handle created earlier in `init()`, with UVs and normals
+///
+/// The mesh gains FORMAT_TANGENT (or has its tangents replaced), so normal
+/// maps bound with `material_normal()` apply to it.
+/// Call after `mesh_recompute_normals()` if the mesh was displaced.
+///
+/// Returns 1 on success, 0 on failure.
+///
+/// **Init-only**: Must be called during `init()`.
+pub fn mesh_generate_tangents(mut caller: Caller<'_, ZXGameContext>, handle: u32) -> u32 {
+    const FN_NAME: &str = "mesh_generate_tangents";
+
+    guard_init_only!(caller, FN_NAME);
+
+    let state = &mut caller.data_mut().ffi;
+    let (Some(format), Some((positions, indices))) = (
+        pending_format(state, handle),
+        pending_mesh_geometry(state, handle),
+    ) else {
+        warn!(
+            "{}: mesh {} not found (it must be created earlier in init())",
+            FN_NAME, handle
+        );
+        return 0;
+    };
+    let (Some(uvs), Some(normals)) = (
+        pending_mesh_uvs(state, handle),
+        pending_mesh_normals(state, handle),
+    ) else {
+        warn!(
+            "{}: mesh {} needs UVs and normals (call mesh_recompute_normals() first if it has none)",
+            FN_NAME, handle
+        );
+        return 0;
+    };
+
+    let tangents = procedural::generate_tangents(&positions, &uvs, &normals, &indices);
+    let vertices: Vec<u32> = (0..positions.len() as u32).collect();
+    rewrite_mesh(
+        state,
+        handle,
+        format | FORMAT_TANGENT,
+        &vertices,
+        None,
+        Some(&tangents),
+        None,
+    );
+    1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graphics::{pack_vertex_data, unpack_octahedral_u32, unpack_tangent};
+    use crate::state::{PendingMesh, PendingMeshPacked};
+
+    /// Triangle with UVs and colors but no normals, in the XZ plane
+    fn triangle() -> Vec<f32> {
+        #[rustfmt::skip]
+        let data = vec![
+            0.0, 0.0, 0.0,   0.0, 0.0,   1.0, 0.0, 0.0,
+            1.0, 0.0, 0.0,   1.0, 0.0,   0.0, 1.0, 0.0,
+            0.0, 0.0, -1.0,  0.0, 1.0,   0.0, 0.0, 1.0,
+        ];
+        data
+    }
+
+    #[test]
+    fn test_rewrite_inserts_normals_between_color_and_skinning() {
+        let mut state = ZXFFIState::new();
+        state.pending_meshes.push(PendingMesh {
+            handle: 1,
+            format: FORMAT_UV | FORMAT_COLOR,
+            vertex_data: triangle(),
+            index_data: None,
+        });
+
+        let (positions, indices) = pending_mesh_geometry(&state, 1).unwrap();
+        let smoothed = procedural::smooth_normals(&positions, &indices, 0.0);
+        let indices = smoothed.indices.iter().map(|&i| i as u16).collect();
+        rewrite_mesh(
+            &mut state,
+            1,
+            FORMAT_UV | FORMAT_COLOR | FORMAT_NORMAL,
+            &smoothed.vertices,
+            Some(&smoothed.normals),
+            None,
+            Some(indices),
+        );
+
+        let mesh = &state.pending_meshes[0];
+        assert_eq!(mesh.format, FORMAT_UV | FORMAT_COLOR | FORMAT_NORMAL);
+        assert_eq!(mesh.vertex_data.len(), 3 * 11);
+        assert_eq!(mesh.index_data.as_deref(), Some(&[0, 1, 2][..]));
+        // Color of the second vertex survives, followed by the +Y normal
+        assert_eq!(
+            &mesh.vertex_data[11 + 5..11 + 11],
+            &[0.0, 1.0, 0.0, 0.0, 1.0, 0.0]
+        );
+    }
+
+    #[test]
+    fn test_rewrite_adds_packed_tangents() {
+        let mut data = triangle();
+        for vertex in data.chunks_exact_mut(8) {
+            // Color -> normal, so the packed mesh is POS_UV_NORMAL
+            vertex[5..8].copy_from_slice(&[0.0, 1.0, 0.0]);
+        }
+        let format = FORMAT_UV | FORMAT_NORMAL;
+        let mut state = ZXFFIState::new();
+        state.pending_meshes_packed.push(PendingMeshPacked {
+            handle: 1,
+            format,
+            vertex_data: pack_vertex_data(&data, format),
+            index_data: None,
+        });
+
+        let (positions, indices) = pending_mesh_geometry(&state, 1).unwrap();
+        let uvs = pending_mesh_uvs(&state, 1).unwrap();
+        let normals = pending_mesh_normals(&state, 1).unwrap();
+        let tangents = procedural::generate_tangents(&positions, &uvs, &normals, &indices);
+        rewrite_mesh(
+            &mut state,
+            1,
+            format | FORMAT_TANGENT,
+            &[0, 1, 2],
+            None,
+            Some(&tangents),
+            None,
+        );
+
+        let mesh = &state.pending_meshes_packed[0];
+        let stride = vertex_stride_packed(mesh.format) as usize;
+        assert_eq!(mesh.vertex_data.len(), 3 * stride);
+        for vertex in mesh.vertex_data.chunks_exact(stride) {
+            let word = |at: usize| u32::from_le_bytes(vertex[at..at + 4].try_into().unwrap());
+            assert!(unpack_octahedral_u32(word(12)).dot(Vec3::Y) > 0.99);
+            let (tangent, _) = unpack_tangent(word(16));
+            assert!(tangent.dot(Vec3::X) > 0.99);
+        }
+    }
+}
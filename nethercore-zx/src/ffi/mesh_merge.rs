@@ -0,0 +1,157 @@
+//! Static mesh merging FFI functions
+//!
+//! Combine placed copies of meshes loaded earlier in init() into a few large
+//! meshes, so scenery built from many props draws in a few calls (see
+//! `crate::mesh_merge`).
+
+use std::borrow::Cow;
+
+use anyhow::Result;
+use glam::Mat4;
+use tracing::warn;
+use wasmtime::{Caller, Linker};
+
+use super::ZXGameContext;
+use super::guards::guard_init_only;
+use super::helpers::{read_wasm_bytes, read_wasm_floats, validate_count_nonzero};
+use crate::graphics::pack_vertex_data;
+use crate::mesh_merge::{MAX_MERGE_SOURCES, MergeSource, merge_meshes};
+use crate::state::{PendingMeshPacked, ZXFFIState};
+
+/// Register mesh merging FFI functions
+pub fn register(linker: &mut Linker<ZXGameContext>) -> Result<()> {
+    linker.func_wrap("env", "mesh_merge", mesh_merge)?;
+    Ok(())
+}
+
+/// Merge placed copies of meshes into one mesh (init-only)
+///
+/// # Arguments
+/// * `handles_ptr` — Pointer to `count` mesh handles (u32); a handle may repeat
+/// * `transforms_ptr` — Pointer to `count` 4x4 matrices (16 f32 each,
+///   column-major) placing each mesh in the merged mesh's local space
+/// * `count` — Number of meshes (1-4096)
+///
+/// Meshes must be loaded earlier in init() and must not be skinned. Meshes
+/// with different vertex formats, or more than 65536 vertices in total, are
+/// stored as several parts that `draw_mesh()` draws together. The source
+/// meshes stay loaded and can still be drawn on their own.
+///
+/// Returns a mesh handle (>0) on success, 0 on failure.
+fn mesh_merge(
+    mut caller: Caller<'_, ZXGameContext>,
+    handles_ptr: u32,
+    transforms_ptr: u32,
+    count: u32,
+) -> u32 {
+    const FN_NAME: &str = "mesh_merge";
+
+    guard_init_only!(caller, FN_NAME);
+
+    if !validate_count_nonzero(count, FN_NAME, "count") {
+        return 0;
+    }
+    let count = count as usize;
+    if count > MAX_MERGE_SOURCES {
+        warn!(
+            "{}: count {} exceeds maximum {}",
+            FN_NAME, count, MAX_MERGE_SOURCES
+        );
+        return 0;
+    }
+
+    let Some(handle_bytes) = read_wasm_bytes(&caller, handles_ptr, count * 4, FN_NAME) else {
+        return 0;
+    };
+    let Some(matrices) = read_wasm_floats(&caller, transforms_ptr, count * 16, FN_NAME) else {
+        return 0;
+    };
+    if matrices.iter().any(|v| !v.is_finite()) {
+        warn!("{}: transforms must be finite", FN_NAME);
+        return 0;
+    }
+
+    let state = &mut caller.data_mut().ffi;
+    let mut meshes = Vec::with_capacity(count);
+    for bytes in handle_bytes.chunks_exact(4) {
+        let handle = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+        let Some(mesh) = pending_mesh_packed(state, handle) else {
+            warn!(
+                "{}: mesh {} not found (meshes must be loaded earlier in init())",
+                FN_NAME, handle
+            );
+            return 0;
+        };
+        meshes.push(mesh);
+    }
+
+    let sources: Vec<MergeSource> = meshes
+        .iter()
+        .zip(matrices.chunks_exact(16))
+        .map(|(mesh, matrix)| MergeSource {
+            format: mesh.format,
+            vertex_data: &mesh.vertex_data,
+            index_data: mesh.index_data,
+            transform: Mat4::from_cols_slice(matrix),
+        })
+        .collect();
+    let parts = match merge_meshes(&sources) {
+        Ok(parts) => parts,
+        Err(e) => {
+            warn!("{}: {}", FN_NAME, e);
+            return 0;
+        }
+    };
+    if parts.iter().any(|part| part.index_data.is_empty()) {
+        warn!("{}: meshes have no triangles", FN_NAME);
+        return 0;
+    }
+
+    let first = state.next_mesh_handle;
+    let part_count = parts.len() as u32;
+    for (i, part) in parts.into_iter().enumerate() {
+        state.pending_meshes_packed.push(PendingMeshPacked {
+            handle: first + i as u32,
+            format: part.format,
+            vertex_data: part.vertex_data,
+            index_data: Some(part.index_data),
+        });
+    }
+    state.next_mesh_handle += part_count;
+    if part_count == 1 {
+        return first;
+    }
+
+    let handle = state.next_mesh_handle;
+    state.next_mesh_handle += 1;
+    state.mesh_groups.insert(handle, (first, part_count));
+    handle
+}
+
+/// A mesh queued earlier in init(), with its vertices in packed form
+struct SourceMesh<'a> {
+    format: u8,
+    vertex_data: Cow<'a, [u8]>,
+    index_data: Option<&'a [u16]>,
+}
+
+/// Look up a pending mesh, packing float vertex data if needed
+fn pending_mesh_packed(state: &ZXFFIState, handle: u32) -> Option<SourceMesh<'_>> {
+    if let Some(mesh) = state.pending_meshes.iter().find(|m| m.handle == handle) {
+        Some(SourceMesh {
+            format: mesh.format,
+            vertex_data: Cow::Owned(pack_vertex_data(&mesh.vertex_data, mesh.format)),
+            index_data: mesh.index_data.as_deref(),
+        })
+    } else {
+        let mesh = state
+            .pending_meshes_packed
+            .iter()
+            .find(|m| m.handle == handle)?;
+        Some(SourceMesh {
+            format: mesh.format,
+            vertex_data: Cow::Borrowed(&mesh.vertex_data),
+            index_data: mesh.index_data.as_deref(),
+        })
+    }
+}
@@ -52,23 +52,42 @@ pub(crate) fn get_wasm_memory<T>(caller: &mut Caller<'_, T>) -> Option<Memory> {
 mod audio;
 mod billboard;
 mod camera;
+mod chunk;
 mod config;
+mod cursor;
+mod debug_draw;
+mod decal;
+mod destructible;
 mod draw_2d;
 mod draw_3d;
 mod environment;
+mod grid;
 pub(crate) mod guards;
+mod hitbox;
 pub mod input;
 mod keyframes;
 mod lighting;
+mod lightmap;
+mod loading;
 mod material;
 mod mesh;
 mod mesh_generators;
+mod mesh_merge;
+mod navmesh;
+mod palette;
+mod particles;
+mod path;
+mod physics;
+mod projectile;
 mod render_state;
 mod rom;
+mod scoreboard;
 mod skinning;
+mod strings;
 mod texture;
 mod transform;
 mod viewport;
+mod water;
 
 use anyhow::Result;
 use wasmtime::{Caller, Extern, Linker, Memory};
@@ -78,6 +97,11 @@ use nethercore_core::wasm::WasmGameContext;
 use crate::console::ZInput;
 use crate::state::{ZRollbackState, ZXFFIState};
 
+pub(crate) use cursor::draw_cursors;
+pub(crate) use decal::collect_decal_surfaces;
+pub(crate) use draw_2d::draw_toasts;
+pub(crate) use loading::draw_loading_screen;
+
 /// Type alias for Nethercore ZX WASM game context
 pub type ZXGameContext = WasmGameContext<ZInput, ZXFFIState, ZRollbackState>;
 
@@ -86,6 +110,9 @@ pub fn register_zx_ffi(linker: &mut Linker<ZXGameContext>) -> Result<()> {
     // Configuration functions (init-only)
     config::register(linker)?;
 
+    // Loading screen shown between init_step() calls (init-only)
+    loading::register(linker)?;
+
     // Camera functions
     camera::register(linker)?;
 
@@ -95,6 +122,9 @@ pub fn register_zx_ffi(linker: &mut Linker<ZXGameContext>) -> Result<()> {
     // Input functions (from input submodule)
     input::register(linker)?;
 
+    // Shared stick/mouse cursors
+    cursor::register(linker)?;
+
     // Render state functions
     render_state::register(linker)?;
 
@@ -110,15 +140,57 @@ pub fn register_zx_ffi(linker: &mut Linker<ZXGameContext>) -> Result<()> {
     // Procedural mesh generation
     mesh_generators::register(linker)?;
 
+    // Static mesh merging
+    mesh_merge::register(linker)?;
+
     // Immediate mode 3D drawing
     draw_3d::register(linker)?;
 
     // Billboard drawing
     billboard::register(linker)?;
 
+    // Particle systems (host-simulated billboards)
+    particles::register(linker)?;
+
+    // Decals (textures projected onto static meshes)
+    decal::register(linker)?;
+
+    // Water planes (Gerstner waves animated from the tick count)
+    water::register(linker)?;
+
+    // Navigation meshes and pathfinding
+    navmesh::register(linker)?;
+
+    // Spline paths (authored curves evaluated by arc length)
+    path::register(linker)?;
+
+    // Destructible props (pre-fractured debris)
+    destructible::register(linker)?;
+
+    // Projectiles (host-simulated movement and collision)
+    projectile::register(linker)?;
+
+    // 2D physics (boxes and circles with contact callbacks)
+    physics::register(linker)?;
+
+    // Grid pathfinding (A* over a rolled-back cost grid)
+    grid::register(linker)?;
+
+    // Hitboxes and hurtboxes (frame data, clashes, hit events)
+    hitbox::register(linker)?;
+
+    // World streaming (chunks copied from ROM data around a focus point)
+    chunk::register(linker)?;
+
+    // Teams, scores and the scoreboard overlay
+    scoreboard::register(linker)?;
+
     // 2D drawing (screen space)
     draw_2d::register(linker)?;
 
+    // Debug gizmos (drawn only while the F4 inspector is open)
+    debug_draw::register(linker)?;
+
     // Environment system (EPU)
     // Includes epu_set/draw_epu and matcap_set
     environment::register(linker)?;
@@ -129,6 +201,12 @@ pub fn register_zx_ffi(linker: &mut Linker<ZXGameContext>) -> Result<()> {
     // Lighting functions (Mode 2 PBR)
     lighting::register(linker)?;
 
+    // Baked lighting for static meshes
+    lightmap::register(linker)?;
+
+    // Palette swapping for indexed textures
+    palette::register(linker)?;
+
     // GPU skinning
     skinning::register(linker)?;
 
@@ -144,5 +222,8 @@ pub fn register_zx_ffi(linker: &mut Linker<ZXGameContext>) -> Result<()> {
     // ROM data pack loading (rom_texture, rom_mesh, rom_sound, etc.)
     rom::register(linker)?;
 
+    // Localized text from rom_strings() tables
+    strings::register(linker)?;
+
     Ok(())
 }
@@ -0,0 +1,151 @@
+//! Navigation mesh FFI functions
+//!
+//! Bake navmeshes from retained mesh geometry during init(), then query
+//! obstacle-aware paths at any time. Queries are deterministic and safe to
+//! call from `update()`.
+
+use anyhow::Result;
+use glam::Vec3;
+use tracing::warn;
+use wasmtime::{Caller, Linker};
+
+use super::ZXGameContext;
+use super::guards::guard_init_only;
+use super::helpers::{get_memory, pending_mesh_geometry};
+use crate::navmesh::{MAX_NAVMESHES, NavMesh};
+
+/// Size of one path point written to WASM memory (x, y, z as f32)
+const PATH_POINT_SIZE: usize = 12;
+
+/// Register navigation mesh FFI functions
+pub fn register(linker: &mut Linker<ZXGameContext>) -> Result<()> {
+    linker.func_wrap("env", "navmesh_bake", navmesh_bake)?;
+    linker.func_wrap("env", "nav_path", nav_path)?;
+    Ok(())
+}
+
+/// Bake a navmesh from a loaded mesh (init-only)
+///
+/// # Arguments
+/// * `mesh_handle` — Mesh handle from `load_mesh*()`, `rom_mesh()` or a mesh generator
+/// * `agent_radius` — Radius of the agents that will walk the mesh
+///
+/// Triangles steeper than 45° are not walkable, and gaps narrower than the
+/// agent's diameter are treated as walls.
+///
+/// Returns a navmesh handle (>0) on success, 0 on failure.
+fn navmesh_bake(mut caller: Caller<'_, ZXGameContext>, mesh_handle: u32, agent_radius: f32) -> u32 {
+    const FN_NAME: &str = "navmesh_bake";
+
+    guard_init_only!(caller, FN_NAME);
+
+    let state = &mut caller.data_mut().ffi;
+    if state.navmeshes.len() >= MAX_NAVMESHES {
+        warn!(
+            "{}: maximum navmesh count {} exceeded",
+            FN_NAME, MAX_NAVMESHES
+        );
+        return 0;
+    }
+
+    let Some((positions, indices)) = pending_mesh_geometry(state, mesh_handle) else {
+        warn!(
+            "{}: mesh {} not found (bake must run in init() after the mesh is loaded)",
+            FN_NAME, mesh_handle
+        );
+        return 0;
+    };
+
+    let navmesh = NavMesh::bake(&positions, &indices, agent_radius);
+    if navmesh.triangle_count() == 0 {
+        warn!(
+            "{}: mesh {} has no walkable triangles",
+            FN_NAME, mesh_handle
+        );
+        return 0;
+    }
+
+    tracing::debug!(
+        "{}: baked mesh {} into {} walkable triangles",
+        FN_NAME,
+        mesh_handle,
+        navmesh.triangle_count()
+    );
+
+    state.navmeshes.push(navmesh);
+    state.navmeshes.len() as u32
+}
+
+/// Find a path across a navmesh
+///
+/// # Arguments
+/// * `navmesh` — Navmesh handle from `navmesh_bake()`
+/// * `start_x`, `start_y`, `start_z` — Start position (snapped onto the navmesh)
+/// * `end_x`, `end_y`, `end_z` — Goal position (snapped onto the navmesh)
+/// * `out_points` — Pointer to output buffer of `max_points` × 3 f32 (x, y, z)
+/// * `max_points` — Capacity of the output buffer in points
+///
+/// The path includes the snapped start and end points. Longer paths are
+/// truncated to `max_points`.
+///
+/// Returns the number of points written, or 0 if no path exists.
+fn nav_path(
+    mut caller: Caller<'_, ZXGameContext>,
+    navmesh: u32,
+    start_x: f32,
+    start_y: f32,
+    start_z: f32,
+    end_x: f32,
+    end_y: f32,
+    end_z: f32,
+    out_points: u32,
+    max_points: u32,
+) -> u32 {
+    const FN_NAME: &str = "nav_path";
+
+    if max_points == 0 {
+        return 0;
+    }
+
+    let Some(mesh) = (navmesh as usize)
+        .checked_sub(1)
+        .and_then(|index| caller.data().ffi.navmeshes.get(index))
+    else {
+        warn!("{}: invalid navmesh handle {}", FN_NAME, navmesh);
+        return 0;
+    };
+
+    let start = Vec3::new(start_x, start_y, start_z);
+    let end = Vec3::new(end_x, end_y, end_z);
+    let Some(path) = mesh.find_path(start, end) else {
+        return 0;
+    };
+
+    let Some(memory) = get_memory(&caller, FN_NAME) else {
+        return 0;
+    };
+    let count = path.len().min(max_points as usize);
+    let out_start = out_points as usize;
+    let out_end = out_start + count * PATH_POINT_SIZE;
+
+    let data = memory.data_mut(&mut caller);
+    if out_end > data.len() {
+        warn!(
+            "{}: output buffer out of bounds ({}-{}, memory size {})",
+            FN_NAME,
+            out_start,
+            out_end,
+            data.len()
+        );
+        return 0;
+    }
+
+    for (i, point) in path.iter().take(count).enumerate() {
+        let offset = out_start + i * PATH_POINT_SIZE;
+        for (j, value) in point.to_array().iter().enumerate() {
+            data[offset + j * 4..offset + j * 4 + 4].copy_from_slice(&value.to_le_bytes());
+        }
+    }
+
+    count as u32
+}
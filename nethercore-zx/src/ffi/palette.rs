@@ -0,0 +1,88 @@
+//! Palette swap FFI functions
+//!
+//! Load color palettes during init(), then pick one per draw with
+//! `palette_bind()`. Textures packed with `quality = "indexed"` store palette
+//! indices instead of colors, so one texture can be drawn with any palette
+//! (player 2 recolors, damage flashes) without a second copy in VRAM.
+
+use anyhow::Result;
+use tracing::warn;
+use wasmtime::{Caller, Linker};
+
+use super::ZXGameContext;
+use super::guards::guard_init_only;
+use super::helpers::read_wasm_bytes;
+use crate::state::MAX_PALETTE_COLORS;
+
+/// Maximum number of palettes (handles fit in 8 shading state bits)
+pub(crate) const MAX_PALETTES: usize = 255;
+
+/// Register palette swap FFI functions
+pub fn register(linker: &mut Linker<ZXGameContext>) -> Result<()> {
+    linker.func_wrap("env", "palette_load", palette_load)?;
+    linker.func_wrap("env", "palette_bind", palette_bind)?;
+    Ok(())
+}
+
+/// Load a palette (init-only)
+///
+/// # Arguments
+/// * `colors_ptr` — Pointer to an array of u32 colors (0xRRGGBBAA)
+/// * `count` — Number of colors (1-256)
+///
+/// Color N is drawn for texels with index N. Indices past `count` draw
+/// transparent black.
+///
+/// Returns a palette handle (>0) on success, 0 on failure.
+fn palette_load(mut caller: Caller<'_, ZXGameContext>, colors_ptr: u32, count: u32) -> u32 {
+    const FN_NAME: &str = "palette_load";
+
+    guard_init_only!(caller, FN_NAME);
+
+    if count == 0 || count as usize > MAX_PALETTE_COLORS {
+        warn!(
+            "{}: count {} out of range (1-{})",
+            FN_NAME, count, MAX_PALETTE_COLORS
+        );
+        return 0;
+    }
+
+    let Some(bytes) = read_wasm_bytes(&caller, colors_ptr, count as usize * 4, FN_NAME) else {
+        return 0;
+    };
+
+    let state = &mut caller.data_mut().ffi;
+    let loaded = state.palettes.len() / MAX_PALETTE_COLORS;
+    if loaded >= MAX_PALETTES {
+        warn!(
+            "{}: maximum palette count {} exceeded",
+            FN_NAME, MAX_PALETTES
+        );
+        return 0;
+    }
+
+    let mut colors = vec![0u32; MAX_PALETTE_COLORS];
+    for (color, b) in colors.iter_mut().zip(bytes.chunks_exact(4)) {
+        *color = u32::from_le_bytes([b[0], b[1], b[2], b[3]]);
+    }
+    state.palettes.extend_from_slice(&colors);
+
+    (loaded + 1) as u32
+}
+
+/// Set the palette for subsequent draws of indexed textures
+///
+/// # Arguments
+/// * `palette` — Palette handle from `palette_load()`, or 0 to unbind
+///
+/// Only applies while an indexed texture is bound to slot 0; other textures
+/// draw unchanged, so the palette can stay bound. Indexed textures drawn
+/// without a palette show their raw indices in the red channel.
+fn palette_bind(mut caller: Caller<'_, ZXGameContext>, palette: u32) {
+    let state = &mut caller.data_mut().ffi;
+    if palette as usize > state.palettes.len() / MAX_PALETTE_COLORS {
+        warn!("palette_bind: invalid palette handle {}", palette);
+        return;
+    }
+    state.bound_palette = palette;
+}
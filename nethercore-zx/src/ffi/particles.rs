@@ -0,0 +1,176 @@
+//! Particle system FFI functions
+//!
+//! Host-side particle pools drawn as spherical billboards. Particles are
+//! presentation-only and are not rolled back, so emit them from `render()`
+//! (or from gameplay code that does not re-run during rollback).
+
+use anyhow::Result;
+use glam::Vec3;
+use tracing::warn;
+use wasmtime::{Caller, Linker};
+
+use super::ZXGameContext;
+use super::guards::guard_init_only;
+use crate::state::{MAX_PARTICLE_SYSTEMS, MAX_PARTICLES_PER_SYSTEM, ParticleEmit, ParticleSystem};
+
+/// Layer value for particle billboards (depth-tested like other billboards)
+const PARTICLE_LAYER: u32 = 0;
+
+/// Maximum simulation steps taken by a single `particles_draw()` call
+const MAX_STEPS_PER_DRAW: u32 = 8;
+
+/// Register particle system FFI functions
+pub fn register(linker: &mut Linker<ZXGameContext>) -> Result<()> {
+    linker.func_wrap("env", "particles_create", particles_create)?;
+    linker.func_wrap("env", "particles_emit", particles_emit)?;
+    linker.func_wrap("env", "particles_draw", particles_draw)?;
+    linker.func_wrap("env", "particles_count", particles_count)?;
+    Ok(())
+}
+
+/// Look up a particle system by 1-indexed handle
+fn system_mut<'a>(
+    caller: &'a mut Caller<'_, ZXGameContext>,
+    handle: u32,
+) -> Option<&'a mut ParticleSystem> {
+    let index = (handle as usize).checked_sub(1)?;
+    caller.data_mut().ffi.particle_systems.get_mut(index)
+}
+
+/// Create a particle system (init-only)
+///
+/// # Arguments
+/// * `max` — Maximum number of live particles (1 to 65536)
+///
+/// Returns a particle system handle (>0) on success, 0 on failure.
+fn particles_create(mut caller: Caller<'_, ZXGameContext>, max: u32) -> u32 {
+    guard_init_only!(caller, "particles_create");
+
+    if max == 0 || max > MAX_PARTICLES_PER_SYSTEM {
+        warn!(
+            "particles_create: max {} out of range (1-{})",
+            max, MAX_PARTICLES_PER_SYSTEM
+        );
+        return 0;
+    }
+
+    let systems = &mut caller.data_mut().ffi.particle_systems;
+    if systems.len() >= MAX_PARTICLE_SYSTEMS {
+        warn!(
+            "particles_create: maximum particle system count {} exceeded",
+            MAX_PARTICLE_SYSTEMS
+        );
+        return 0;
+    }
+
+    let handle = systems.len() as u32 + 1;
+    systems.push(ParticleSystem::new(handle, max));
+    handle
+}
+
+/// Emit a burst of particles
+///
+/// # Arguments
+/// * `handle` — Particle system handle from `particles_create()`
+/// * `count` — Number of particles to spawn (clamped to free capacity)
+/// * `x`, `y`, `z` — Spawn position (world space)
+/// * `vel_spread` — Maximum initial speed; directions are uniformly random
+/// * `life` — Lifetime in seconds
+/// * `size_start`, `size_end` — Billboard size at birth and death
+/// * `color_start`, `color_end` — Color at birth and death (0xRRGGBBAA)
+///
+/// Returns the number of particles actually spawned.
+fn particles_emit(
+    mut caller: Caller<'_, ZXGameContext>,
+    handle: u32,
+    count: u32,
+    x: f32,
+    y: f32,
+    z: f32,
+    vel_spread: f32,
+    life: f32,
+    size_start: f32,
+    size_end: f32,
+    color_start: u32,
+    color_end: u32,
+) -> u32 {
+    let Some(system) = system_mut(&mut caller, handle) else {
+        warn!("particles_emit: invalid handle {}", handle);
+        return 0;
+    };
+
+    system.emit(
+        count,
+        &ParticleEmit {
+            position: Vec3::new(x, y, z),
+            vel_spread,
+            life,
+            size: [size_start, size_end],
+            color: [color_start, color_end],
+        },
+    )
+}
+
+/// Simulate and draw a particle system
+///
+/// Advances the simulation by one fixed step per tick since the last draw,
+/// then draws every live particle as a spherical billboard using the
+/// currently bound texture. The current `set_color()` value is preserved.
+fn particles_draw(mut caller: Caller<'_, ZXGameContext>, handle: u32) {
+    let tick = caller.data().game.tick_count;
+    let dt = caller.data().game.delta_time;
+
+    let ctx = caller.data_mut();
+    let Some(system) = (handle as usize)
+        .checked_sub(1)
+        .and_then(|index| ctx.ffi.particle_systems.get_mut(index))
+    else {
+        warn!("particles_draw: invalid handle {}", handle);
+        return;
+    };
+    system.advance_to(tick, dt, MAX_STEPS_PER_DRAW);
+
+    // Take the particles out so the FFI state can be borrowed for drawing
+    let particles = std::mem::take(&mut system.particles);
+    let state = &mut ctx.ffi;
+
+    // Force lazy push of view/projection matrices (see draw_billboard)
+    if let Some(mat) = state.current_view_matrix.take() {
+        state.view_matrices.push(mat);
+    }
+    if let Some(mat) = state.current_proj_matrix.take() {
+        state.proj_matrices.push(mat);
+    }
+    let view_idx = (state.view_matrices.len() - 1) as u32;
+
+    let saved_color = state.current_shading_state.color_rgba8;
+    for particle in &particles {
+        state.update_color(particle.current_color());
+        let shading_state_index = state.add_shading_state();
+        let size = particle.current_size();
+
+        let instance = crate::graphics::QuadInstance::billboard(
+            particle.position.to_array(),
+            size,
+            size,
+            crate::graphics::QuadMode::BillboardSpherical,
+            [0.0, 0.0, 1.0, 1.0],
+            shading_state_index.0,
+            view_idx,
+        );
+        state.add_quad_instance(instance, PARTICLE_LAYER);
+    }
+    state.update_color(saved_color);
+
+    state.particle_systems[handle as usize - 1].particles = particles;
+}
+
+/// Get the number of live particles in a system
+///
+/// Returns 0 for invalid handles.
+fn particles_count(caller: Caller<'_, ZXGameContext>, handle: u32) -> u32 {
+    (handle as usize)
+        .checked_sub(1)
+        .and_then(|index| caller.data().ffi.particle_systems.get(index))
+        .map_or(0, |system| system.particles.len() as u32)
+}
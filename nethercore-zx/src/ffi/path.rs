@@ -0,0 +1,102 @@
+//! Spline path FFI functions
+//!
+//! Evaluate paths loaded with `rom_path()` — track layouts, enemy flight paths
+//! and camera rails authored in nether.toml. Evaluation is deterministic and
+//! safe to call from `update()`.
+
+use anyhow::Result;
+use glam::Vec3;
+use tracing::warn;
+use wasmtime::{Caller, Linker};
+use zx_common::PathCurve;
+
+use super::ZXGameContext;
+use super::helpers::get_memory;
+
+/// Size of one vector written to WASM memory (x, y, z as f32)
+const VEC3_SIZE: usize = 12;
+
+/// Register spline path FFI functions
+pub fn register(linker: &mut Linker<ZXGameContext>) -> Result<()> {
+    linker.func_wrap("env", "path_eval", path_eval)?;
+    linker.func_wrap("env", "path_length", path_length)?;
+    Ok(())
+}
+
+/// Look up a path by handle
+fn get_path<'a>(caller: &'a Caller<'_, ZXGameContext>, handle: u32) -> Option<&'a PathCurve> {
+    (handle as usize)
+        .checked_sub(1)
+        .and_then(|index| caller.data().ffi.paths.get(index))
+}
+
+/// Evaluate a path at a fraction of its length
+///
+/// # Arguments
+/// * `handle` — Path handle from `rom_path()`
+/// * `t` — Position along the path, 0.0 (start) to 1.0 (end), by arc length.
+///   Clamped on open paths; wraps on closed paths.
+/// * `out_pos` — Pointer to 3 f32 (x, y, z) for the position, or 0 to skip
+/// * `out_tangent` — Pointer to 3 f32 for the unit direction of travel, or 0
+///   to skip
+///
+/// Because `t` follows arc length, advancing it by `speed * dt / path_length()`
+/// each tick moves at constant speed.
+///
+/// Returns 1 on success, 0 if the handle is invalid or a pointer is out of
+/// bounds.
+fn path_eval(
+    mut caller: Caller<'_, ZXGameContext>,
+    handle: u32,
+    t: f32,
+    out_pos: u32,
+    out_tangent: u32,
+) -> u32 {
+    const FN_NAME: &str = "path_eval";
+
+    let Some(path) = get_path(&caller, handle) else {
+        warn!("{}: invalid path handle {}", FN_NAME, handle);
+        return 0;
+    };
+    let (pos, tangent) = path.eval(t);
+
+    let Some(memory) = get_memory(&caller, FN_NAME) else {
+        return 0;
+    };
+    let data = memory.data_mut(&mut caller);
+    for (ptr, value) in [(out_pos, pos), (out_tangent, tangent)] {
+        if ptr != 0 && !write_vec3(data, ptr, value) {
+            warn!("{}: output pointer 0x{:08X} out of bounds", FN_NAME, ptr);
+            return 0;
+        }
+    }
+    1
+}
+
+/// Get the length of a path in world units
+///
+/// # Arguments
+/// * `handle` — Path handle from `rom_path()`
+///
+/// Returns the arc length, or 0.0 if the handle is invalid.
+fn path_length(caller: Caller<'_, ZXGameContext>, handle: u32) -> f32 {
+    match get_path(&caller, handle) {
+        Some(path) => path.length(),
+        None => {
+            warn!("path_length: invalid path handle {}", handle);
+            0.0
+        }
+    }
+}
+
+/// Write a vector as 3 little-endian f32; false if out of bounds
+fn write_vec3(data: &mut [u8], ptr: u32, value: Vec3) -> bool {
+    let start = ptr as usize;
+    let Some(out) = data.get_mut(start..start + VEC3_SIZE) else {
+        return false;
+    };
+    for (chunk, component) in out.chunks_exact_mut(4).zip(value.to_array()) {
+        chunk.copy_from_slice(&component.to_le_bytes());
+    }
+    true
+}
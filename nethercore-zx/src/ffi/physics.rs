@@ -0,0 +1,204 @@
+//! 2D physics FFI functions
+//!
+//! Host-simulated boxes and circles with contact resolution. The world lives
+//! in ZRollbackState, so create bodies and call `physics_step()` from
+//! `update()`. It is allocated by the first `body_create()` or
+//! `physics_gravity()`. Contacts are delivered to the game's `on_contact(a, b)` export
+//! (if it has one) before `physics_step()` returns.
+
+use anyhow::{Context, Result};
+use glam::Vec2;
+use tracing::warn;
+use wasmtime::{Caller, Linker};
+
+use super::ZXGameContext;
+use crate::state::{Body, body_shape};
+
+/// Register 2D physics FFI functions
+pub fn register(linker: &mut Linker<ZXGameContext>) -> Result<()> {
+    linker.func_wrap("env", "body_create", body_create)?;
+    linker.func_wrap("env", "body_destroy", body_destroy)?;
+    linker.func_wrap("env", "body_velocity", body_velocity)?;
+    linker.func_wrap("env", "body_set_position", body_set_position)?;
+    linker.func_wrap("env", "body_x", body_x)?;
+    linker.func_wrap("env", "body_y", body_y)?;
+    linker.func_wrap("env", "body_count", body_count)?;
+    linker.func_wrap("env", "physics_gravity", physics_gravity)?;
+    linker.func_wrap("env", "physics_step", physics_step)?;
+    Ok(())
+}
+
+/// Create a physics body
+///
+/// # Arguments
+/// * `shape` — Body shape (0=AABB, 1=circle)
+/// * `x`, `y` — Center position
+/// * `w`, `h` — Box width and height; for circles `w` is the radius and `h`
+///   is ignored
+/// * `flags` — Body flags (1=static, 2=sensor)
+///
+/// Returns a body handle (>0) on success, 0 if the world is full or the
+/// arguments are invalid.
+fn body_create(
+    mut caller: Caller<'_, ZXGameContext>,
+    shape: u32,
+    x: f32,
+    y: f32,
+    w: f32,
+    h: f32,
+    flags: u32,
+) -> u32 {
+    const FN_NAME: &str = "body_create";
+
+    if shape > body_shape::CIRCLE {
+        warn!("{}: invalid shape {}", FN_NAME, shape);
+        return 0;
+    }
+    if ![x, y, w, h].iter().all(|v| v.is_finite()) {
+        warn!("{}: position and size must be finite", FN_NAME);
+        return 0;
+    }
+
+    match caller
+        .data_mut()
+        .rollback
+        .physics
+        .get_or_insert_with(Box::default)
+        .create(shape, Vec2::new(x, y), Vec2::new(w, h), flags)
+    {
+        Some(handle) => handle,
+        None => {
+            warn!("{}: physics world full", FN_NAME);
+            0
+        }
+    }
+}
+
+/// Remove a body
+fn body_destroy(mut caller: Caller<'_, ZXGameContext>, handle: u32) {
+    let destroyed = caller
+        .data_mut()
+        .rollback
+        .physics
+        .as_deref_mut()
+        .is_some_and(|world| world.destroy(handle));
+    if !destroyed {
+        warn!("body_destroy: invalid handle {}", handle);
+    }
+}
+
+/// Set a body's velocity (units per second)
+///
+/// Static bodies keep the velocity but never move.
+fn body_velocity(mut caller: Caller<'_, ZXGameContext>, handle: u32, vx: f32, vy: f32) {
+    if !vx.is_finite() || !vy.is_finite() {
+        warn!("body_velocity: velocity must be finite");
+        return;
+    }
+    match body_mut(&mut caller, handle) {
+        Some(body) => body.velocity = [vx, vy],
+        None => warn!("body_velocity: invalid handle {}", handle),
+    }
+}
+
+/// Move a body without changing its velocity (spawns, teleports, moving platforms)
+fn body_set_position(mut caller: Caller<'_, ZXGameContext>, handle: u32, x: f32, y: f32) {
+    if !x.is_finite() || !y.is_finite() {
+        warn!("body_set_position: position must be finite");
+        return;
+    }
+    match body_mut(&mut caller, handle) {
+        Some(body) => body.position = [x, y],
+        None => warn!("body_set_position: invalid handle {}", handle),
+    }
+}
+
+/// Get a body's center X position (0.0 for invalid handles)
+fn body_x(caller: Caller<'_, ZXGameContext>, handle: u32) -> f32 {
+    caller
+        .data()
+        .rollback
+        .physics
+        .as_deref()
+        .and_then(|world| world.get(handle))
+        .map_or(0.0, |body| body.position[0])
+}
+
+/// Get a body's center Y position (0.0 for invalid handles)
+fn body_y(caller: Caller<'_, ZXGameContext>, handle: u32) -> f32 {
+    caller
+        .data()
+        .rollback
+        .physics
+        .as_deref()
+        .and_then(|world| world.get(handle))
+        .map_or(0.0, |body| body.position[1])
+}
+
+/// Get the number of live bodies
+fn body_count(caller: Caller<'_, ZXGameContext>) -> u32 {
+    caller
+        .data()
+        .rollback
+        .physics
+        .as_ref()
+        .map_or(0, |world| world.active_count()) as u32
+}
+
+/// Set the acceleration applied to moving bodies (units per second², default 0, 0)
+fn physics_gravity(mut caller: Caller<'_, ZXGameContext>, x: f32, y: f32) {
+    if !x.is_finite() || !y.is_finite() {
+        warn!("physics_gravity: gravity must be finite");
+        return;
+    }
+    let world = caller
+        .data_mut()
+        .rollback
+        .physics
+        .get_or_insert_with(Box::default);
+    world.gravity = [x, y];
+}
+
+/// Move every body by one tick and resolve overlaps
+///
+/// Steps by the tick length (see `set_tick_rate()`), so call it once per
+/// `update()`. Each touching pair is passed to the game's
+/// `on_contact(a, b)` export in handle order, with `a < b`, and also queued
+/// for `event_poll()`. The callback may create, move or destroy bodies;
+/// changes take effect from the next step.
+fn physics_step(mut caller: Caller<'_, ZXGameContext>) -> Result<()> {
+    let ctx = caller.data_mut();
+    let dt = ctx.game.delta_time;
+    let Some(world) = ctx.rollback.physics.as_deref_mut() else {
+        return Ok(());
+    };
+    world.step(dt);
+    let contacts = world.contacts().to_vec();
+    for &contact in &contacts {
+        ctx.game.events.push(contact.into());
+    }
+
+    let Some(on_contact) = caller
+        .get_export("on_contact")
+        .and_then(|export| export.into_func())
+    else {
+        return Ok(());
+    };
+    let on_contact = on_contact
+        .typed::<(u32, u32), ()>(&caller)
+        .context("physics_step: on_contact must take (u32, u32) and return nothing")?;
+    for contact in contacts {
+        on_contact.call(&mut caller, (contact.a, contact.b))?;
+    }
+    Ok(())
+}
+
+/// Look up a live body for modification
+fn body_mut<'a>(caller: &'a mut Caller<'_, ZXGameContext>, handle: u32) -> Option<&'a mut Body> {
+    caller
+        .data_mut()
+        .rollback
+        .physics
+        .as_deref_mut()
+        .and_then(|world| world.get_mut(handle))
+}
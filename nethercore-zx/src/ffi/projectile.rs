@@ -0,0 +1,226 @@
+//! Projectile FFI functions
+//!
+//! Host-simulated projectiles with swept collision against game-supplied
+//! targets. The pool lives in ZRollbackState, so spawn, step and poll hits
+//! from `update()`. It is allocated by the first `projectile_spawn()`.
+
+use anyhow::Result;
+use tracing::warn;
+use wasmtime::{Caller, Linker};
+
+use super::ZXGameContext;
+use super::helpers::{get_memory, read_wasm_bytes};
+use crate::state::{ProjectileDesc, ProjectileHit, ProjectileTarget};
+
+/// Maximum number of targets accepted by one `projectile_step()`
+const MAX_PROJECTILE_TARGETS: usize = 4096;
+
+/// Size of one `projectile_list()` entry (handle, user_data, x, y, z)
+const LIST_ENTRY_SIZE: usize = 20;
+
+/// Register projectile FFI functions
+pub fn register(linker: &mut Linker<ZXGameContext>) -> Result<()> {
+    linker.func_wrap("env", "projectile_spawn", projectile_spawn)?;
+    linker.func_wrap("env", "projectile_despawn", projectile_despawn)?;
+    linker.func_wrap("env", "projectile_step", projectile_step)?;
+    linker.func_wrap("env", "projectile_next_hit", projectile_next_hit)?;
+    linker.func_wrap("env", "projectile_list", projectile_list)?;
+    linker.func_wrap("env", "projectile_count", projectile_count)?;
+    Ok(())
+}
+
+/// Spawn a projectile
+///
+/// # Arguments
+/// * `desc_ptr` — Pointer to a 48-byte `ProjectileDesc` (position, velocity,
+///   radius, lifetime in ticks, flags, max_hits, target_mask, user_data)
+///
+/// Returns a projectile handle (>0) on success, 0 if the pool is full or the
+/// description is invalid.
+fn projectile_spawn(mut caller: Caller<'_, ZXGameContext>, desc_ptr: u32) -> u32 {
+    const FN_NAME: &str = "projectile_spawn";
+
+    let Some(bytes) = read_wasm_bytes(
+        &caller,
+        desc_ptr,
+        std::mem::size_of::<ProjectileDesc>(),
+        FN_NAME,
+    ) else {
+        return 0;
+    };
+    let desc: ProjectileDesc = bytemuck::pod_read_unaligned(&bytes);
+
+    let finite = desc
+        .position
+        .iter()
+        .chain(&desc.velocity)
+        .all(|v| v.is_finite());
+    if !finite || !desc.radius.is_finite() {
+        warn!("{}: position, velocity and radius must be finite", FN_NAME);
+        return 0;
+    }
+
+    let pool = caller
+        .data_mut()
+        .rollback
+        .projectiles
+        .get_or_insert_with(Box::default);
+    match pool.spawn(&desc) {
+        Some(handle) => handle,
+        None => {
+            warn!("{}: projectile pool full", FN_NAME);
+            0
+        }
+    }
+}
+
+/// Remove a projectile before it hits anything or expires
+fn projectile_despawn(mut caller: Caller<'_, ZXGameContext>, handle: u32) {
+    let despawned = caller
+        .data_mut()
+        .rollback
+        .projectiles
+        .as_deref_mut()
+        .is_some_and(|pool| pool.despawn(handle));
+    if !despawned {
+        warn!("projectile_despawn: invalid handle {}", handle);
+    }
+}
+
+/// Move every projectile by one tick and collide it with targets
+///
+/// # Arguments
+/// * `targets_ptr` — Pointer to `target_count` 20-byte targets (x, y, z,
+///   radius, layers)
+/// * `target_count` — Number of targets (up to 4096)
+///
+/// Hits are reported by target index, so pass targets in a stable order (for
+/// example by enemy slot). Unread hits from the previous step are discarded.
+/// Hits are also queued for `event_poll()`.
+fn projectile_step(mut caller: Caller<'_, ZXGameContext>, targets_ptr: u32, target_count: u32) {
+    const FN_NAME: &str = "projectile_step";
+
+    let count = target_count as usize;
+    if count > MAX_PROJECTILE_TARGETS {
+        warn!(
+            "{}: target count {} exceeds maximum {}",
+            FN_NAME, count, MAX_PROJECTILE_TARGETS
+        );
+        return;
+    }
+
+    let targets: Vec<ProjectileTarget> = if count == 0 {
+        Vec::new()
+    } else {
+        let size = count * std::mem::size_of::<ProjectileTarget>();
+        let Some(bytes) = read_wasm_bytes(&caller, targets_ptr, size, FN_NAME) else {
+            return;
+        };
+        bytes
+            .chunks_exact(std::mem::size_of::<ProjectileTarget>())
+            .map(bytemuck::pod_read_unaligned)
+            .collect()
+    };
+
+    let ctx = caller.data_mut();
+    let Some(pool) = ctx.rollback.projectiles.as_deref_mut() else {
+        return;
+    };
+    pool.step(ctx.game.delta_time, &targets);
+    for &hit in pool.hits() {
+        ctx.game.events.push(hit.into());
+    }
+}
+
+/// Read the next hit from the last `projectile_step()`
+///
+/// # Arguments
+/// * `out_ptr` — Pointer to a 24-byte `ProjectileHit` (projectile handle,
+///   target index, user_data, x, y, z)
+///
+/// Returns 1 if a hit was written, 0 when there are no more hits.
+fn projectile_next_hit(mut caller: Caller<'_, ZXGameContext>, out_ptr: u32) -> u32 {
+    const FN_NAME: &str = "projectile_next_hit";
+
+    let Some(memory) = get_memory(&caller, FN_NAME) else {
+        return 0;
+    };
+    let out_start = out_ptr as usize;
+    let out_end = out_start + std::mem::size_of::<ProjectileHit>();
+    if out_end > memory.data_size(&caller) {
+        warn!("{}: output pointer {} out of bounds", FN_NAME, out_ptr);
+        return 0;
+    }
+
+    let Some(hit) = caller
+        .data_mut()
+        .rollback
+        .projectiles
+        .as_deref_mut()
+        .and_then(|pool| pool.next_hit())
+    else {
+        return 0;
+    };
+    memory.data_mut(&mut caller)[out_start..out_end].copy_from_slice(bytemuck::bytes_of(&hit));
+    1
+}
+
+/// List live projectiles for drawing
+///
+/// # Arguments
+/// * `out_ptr` — Pointer to `max_count` 20-byte entries (handle, user_data,
+///   x, y, z)
+/// * `max_count` — Capacity of the output buffer in entries
+///
+/// Returns the number of entries written, in handle order.
+fn projectile_list(mut caller: Caller<'_, ZXGameContext>, out_ptr: u32, max_count: u32) -> u32 {
+    const FN_NAME: &str = "projectile_list";
+
+    let Some(memory) = get_memory(&caller, FN_NAME) else {
+        return 0;
+    };
+    let entries: Vec<(u32, u32, [f32; 3])> = caller
+        .data()
+        .rollback
+        .projectiles
+        .iter()
+        .flat_map(|pool| pool.iter())
+        .take(max_count as usize)
+        .map(|(handle, p)| (handle, p.user_data, p.position))
+        .collect();
+
+    let count = entries.len();
+    let out_start = out_ptr as usize;
+    let out_end = out_start + count * LIST_ENTRY_SIZE;
+
+    let data = memory.data_mut(&mut caller);
+    if out_end > data.len() {
+        warn!(
+            "{}: output buffer out of bounds ({}-{}, memory size {})",
+            FN_NAME,
+            out_start,
+            out_end,
+            data.len()
+        );
+        return 0;
+    }
+
+    for (i, (handle, user_data, position)) in entries.iter().enumerate() {
+        let offset = out_start + i * LIST_ENTRY_SIZE;
+        data[offset..offset + 4].copy_from_slice(&handle.to_le_bytes());
+        data[offset + 4..offset + 8].copy_from_slice(&user_data.to_le_bytes());
+        data[offset + 8..offset + 20].copy_from_slice(bytemuck::cast_slice(position));
+    }
+
+    count as u32
+}
+
+/// Get the number of live projectiles
+fn projectile_count(caller: Caller<'_, ZXGameContext>) -> u32 {
+    caller
+        .data()
+        .rollback
+        .projectiles
+        .as_ref()
+        .map_or(0, |pool| pool.active_count()) as u32
+}
@@ -7,7 +7,8 @@ use tracing::warn;
 use wasmtime::{Caller, Linker};
 
 use super::ZXGameContext;
-use crate::graphics::{CullMode, PassConfig, TextureFilter};
+use super::helpers::get_memory;
+use crate::graphics::{BlendMode, CullMode, PassConfig, RenderStats, SsaoSettings, TextureFilter};
 
 /// Register render state FFI functions
 pub fn register(linker: &mut Linker<ZXGameContext>) -> Result<()> {
@@ -18,6 +19,11 @@ pub fn register(linker: &mut Linker<ZXGameContext>) -> Result<()> {
     linker.func_wrap("env", "uniform_alpha", uniform_alpha)?;
     linker.func_wrap("env", "dither_offset", dither_offset)?;
     linker.func_wrap("env", "z_index", z_index)?;
+    linker.func_wrap("env", "blend_mode_2d", blend_mode_2d)?;
+    linker.func_wrap("env", "blend_mode_3d", blend_mode_3d)?;
+    linker.func_wrap("env", "sort_bias", sort_bias)?;
+    linker.func_wrap("env", "render_stats", render_stats)?;
+    linker.func_wrap("env", "post_ssao", post_ssao)?;
     // Render pass functions for execution barriers and depth/stencil control
     linker.func_wrap("env", "begin_pass", begin_pass)?;
     linker.func_wrap("env", "begin_pass_stencil_write", begin_pass_stencil_write)?;
@@ -161,6 +167,130 @@ fn z_index(mut caller: Caller<'_, ZXGameContext>, n: u32) {
     state.current_z_index = n;
 }
 
+/// Set how 2D draws combine with what is already on screen
+///
+/// # Arguments
+/// * `mode` — 0=alpha (default), 1=additive, 2=multiply, 3=screen, 4=subtract
+///
+/// Affects screen-space sprites, rects, lines and text until changed. Blended
+/// draws composite over everything else at their z-index (3D and the
+/// environment included) and never write depth. Alpha still dithers in every
+/// mode. Billboards are unaffected.
+///
+/// Default: alpha (resets each frame)
+fn blend_mode_2d(mut caller: Caller<'_, ZXGameContext>, mode: u32) {
+    let state = &mut caller.data_mut().ffi;
+
+    state.current_blend_mode_2d = BlendMode::from_u32(mode).unwrap_or_else(|| {
+        warn!(
+            "blend_mode_2d({}) invalid - must be 0-4, using 0 (alpha)",
+            mode
+        );
+        BlendMode::Alpha
+    });
+}
+
+/// Set how 3D draws combine with what is already on screen
+///
+/// # Arguments
+/// * `mode` — 0=alpha (default), 1=additive, 2=multiply, 3=screen, 4=subtract
+///
+/// Affects meshes and `draw_triangles()` until changed. Blended draws render
+/// after the opaque scene and the environment, sorted back to front within
+/// each viewport by the distance to the center of their bounds, and never
+/// write depth. Alpha still dithers in every mode.
+///
+/// Default: alpha (resets each frame)
+fn blend_mode_3d(mut caller: Caller<'_, ZXGameContext>, mode: u32) {
+    let state = &mut caller.data_mut().ffi;
+
+    state.current_blend_mode_3d = BlendMode::from_u32(mode).unwrap_or_else(|| {
+        warn!(
+            "blend_mode_3d({}) invalid - must be 0-4, using 0 (alpha)",
+            mode
+        );
+        BlendMode::Alpha
+    });
+}
+
+/// Offset the sort distance of blended 3D draws
+///
+/// # Arguments
+/// * `bias` — World units added to the view distance (positive = drawn
+///   further back, negative = drawn on top)
+///
+/// Use this when automatic sorting picks the wrong order, e.g. a hazard
+/// circle lying under a larger translucent dome.
+///
+/// Default: 0.0 (resets each frame)
+fn sort_bias(mut caller: Caller<'_, ZXGameContext>, bias: f32) {
+    let state = &mut caller.data_mut().ffi;
+
+    if !bias.is_finite() {
+        warn!("sort_bias({}) invalid - must be finite, using 0.0", bias);
+        state.current_sort_bias = 0.0;
+        return;
+    }
+    state.current_sort_bias = bias;
+}
+
+/// Get statistics for the last rendered frame
+///
+/// # Arguments
+/// * `out_ptr` — Pointer to a 16-byte `RenderStats` (draw_calls, triangles,
+///   triangles_culled, texture_binds; 4 × u32)
+///
+/// Rendering is not part of rollback state and peers render different frames,
+/// so use these for display or profiling only, never for game logic.
+///
+/// Returns 1 on success, 0 if `out_ptr` is out of bounds.
+fn render_stats(mut caller: Caller<'_, ZXGameContext>, out_ptr: u32) -> u32 {
+    const FN_NAME: &str = "render_stats";
+
+    let Some(memory) = get_memory(&caller, FN_NAME) else {
+        return 0;
+    };
+    let out_start = out_ptr as usize;
+    let out_end = out_start + std::mem::size_of::<RenderStats>();
+    if out_end > memory.data_size(&caller) {
+        warn!("{}: output pointer {} out of bounds", FN_NAME, out_ptr);
+        return 0;
+    }
+
+    let stats = caller.data().ffi.render_stats;
+    memory.data_mut(&mut caller)[out_start..out_end].copy_from_slice(bytemuck::bytes_of(&stats));
+    1
+}
+
+/// Enable or disable the screen-space ambient occlusion post pass
+///
+/// # Arguments
+/// * `radius` — Sample radius in world units (how far creases reach)
+/// * `intensity` — Occlusion strength (0 = none, 1 = full; higher darkens more)
+/// * `enabled` — Non-zero to enable, 0 to disable (radius and intensity ignored)
+///
+/// Darkens creases and contact points of 3D geometry after the frame is drawn,
+/// in every viewport that drew meshes. Screen-space 2D and the sky are not
+/// affected.
+///
+/// Default: off (persists across frames)
+fn post_ssao(mut caller: Caller<'_, ZXGameContext>, radius: f32, intensity: f32, enabled: u32) {
+    let state = &mut caller.data_mut().ffi;
+
+    if enabled == 0 {
+        state.ssao = None;
+        return;
+    }
+    if !radius.is_finite() || radius <= 0.0 || !intensity.is_finite() || intensity < 0.0 {
+        warn!(
+            "post_ssao({}, {}) invalid - radius must be > 0 and intensity >= 0",
+            radius, intensity
+        );
+        return;
+    }
+    state.ssao = Some(SsaoSettings { radius, intensity });
+}
+
 // ============================================================================
 // Render Pass Functions
 // ============================================================================
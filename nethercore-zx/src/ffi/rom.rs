@@ -13,9 +13,13 @@ use tracing::warn;
 use wasmtime::{Caller, Linker};
 
 use super::{ZXGameContext, guards::check_init_only};
-use crate::audio::Sound;
-use crate::state::{MAX_SKELETONS, PendingMeshPacked, PendingSkeleton, PendingTexture};
-use zx_common::TextureFormat;
+use crate::audio::{Sound, Stream};
+use crate::graphics::epu::SkyProbe;
+use crate::graphics::vertex_stride_packed;
+use crate::state::{
+    MAX_SKELETONS, PendingMeshPacked, PendingSkeleton, PendingTexture, PendingTextureArray,
+};
+use zx_common::{CUBEMAP_FACES, PathCurve, StringTable, TextureFormat};
 
 /// Register ROM data pack FFI functions
 pub fn register(linker: &mut Linker<ZXGameContext>) -> Result<()> {
@@ -24,10 +28,18 @@ pub fn register(linker: &mut Linker<ZXGameContext>) -> Result<()> {
 
     // GPU resources (return handles, uploaded to VRAM)
     linker.func_wrap("env", "rom_texture", rom_texture)?;
+    linker.func_wrap("env", "rom_atlas", rom_atlas)?;
+    linker.func_wrap("env", "rom_cubemap", rom_cubemap)?;
     linker.func_wrap("env", "rom_mesh", rom_mesh)?;
+    linker.func_wrap("env", "rom_mesh_socket", rom_mesh_socket)?;
     linker.func_wrap("env", "rom_skeleton", rom_skeleton)?;
     linker.func_wrap("env", "rom_font", rom_font)?;
     linker.func_wrap("env", "rom_sound", rom_sound)?;
+    linker.func_wrap("env", "rom_stream", rom_stream)?;
+
+    // Host-side data (evaluated by the host, never copied to WASM memory)
+    linker.func_wrap("env", "rom_path", rom_path)?;
+    linker.func_wrap("env", "rom_strings", rom_strings)?;
 
     // Raw data (copies into WASM linear memory)
     linker.func_wrap("env", "rom_data_len", rom_data_len)?;
@@ -37,7 +49,11 @@ pub fn register(linker: &mut Linker<ZXGameContext>) -> Result<()> {
 }
 
 /// Read a string ID from WASM memory
-fn read_string_id(caller: &Caller<'_, ZXGameContext>, id_ptr: u32, id_len: u32) -> Option<String> {
+pub(super) fn read_string_id(
+    caller: &Caller<'_, ZXGameContext>,
+    id_ptr: u32,
+    id_len: u32,
+) -> Option<String> {
     let memory = caller.data().game.memory?;
     let data = memory.data(caller);
 
@@ -101,7 +117,7 @@ fn rom_texture(mut caller: Caller<'_, ZXGameContext>, id_ptr: u32, id_len: u32)
     })?;
 
     // Extract texture data from data pack (read-only access)
-    let (width, height, format, data) = {
+    let (width, height, format, data, etc2_data) = {
         let state = &caller.data().ffi;
         let data_pack = state
             .data_pack
@@ -115,6 +131,7 @@ fn rom_texture(mut caller: Caller<'_, ZXGameContext>, id_ptr: u32, id_len: u32)
             texture.height as u32,
             texture.format,
             texture.data.clone(),
+            texture.etc2_data.clone(),
         )
     };
 
@@ -123,17 +140,155 @@ fn rom_texture(mut caller: Caller<'_, ZXGameContext>, id_ptr: u32, id_len: u32)
     let handle = state.next_texture_handle;
     state.next_texture_handle += 1;
 
+    if format.is_indexed() {
+        state.indexed_textures.insert(handle);
+    }
     state.pending_textures.push(PendingTexture {
         handle,
         width,
         height,
         format,
         data,
+        etc2_data,
     });
 
     Ok(handle)
 }
 
+/// Load a cubemap sky from ROM data pack by ID
+///
+/// Uploads the six faces as a texture array for `sky_draw()`. The builder
+/// packs either six face images or an equirectangular panorama.
+///
+/// # Arguments
+/// * `id_ptr` — Pointer to asset ID string in WASM memory
+/// * `id_len` — Length of asset ID string
+///
+/// # Returns
+/// Cubemap texture handle (>0) on success. Traps on failure.
+///
+/// **Init-only:** Can only be called during `init()`.
+fn rom_cubemap(mut caller: Caller<'_, ZXGameContext>, id_ptr: u32, id_len: u32) -> Result<u32> {
+    check_init_only(&caller, "rom_cubemap")?;
+
+    let id = read_string_id(&caller, id_ptr, id_len).ok_or_else(|| {
+        anyhow::anyhow!(
+            "rom_cubemap: failed to read asset ID at ptr=0x{:08X}, len={}",
+            id_ptr,
+            id_len
+        )
+    })?;
+
+    let (size, data) = {
+        let state = &caller.data().ffi;
+        let data_pack = state
+            .data_pack
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("rom_cubemap: no data pack loaded"))?;
+        let cubemap = data_pack.find_cubemap(&id).ok_or_else(|| {
+            anyhow::anyhow!("rom_cubemap: cubemap '{}' not found in data pack", id)
+        })?;
+        if cubemap.size == 0 || cubemap.data.len() != cubemap.face_len() * CUBEMAP_FACES {
+            bail!(
+                "rom_cubemap: cubemap '{}' has {} bytes, expected six {}x{} RGBA8 faces",
+                id,
+                cubemap.data.len(),
+                cubemap.size,
+                cubemap.size
+            );
+        }
+        (cubemap.size as u32, cubemap.data.clone())
+    };
+
+    let state = &mut caller.data_mut().ffi;
+    let handle = state.next_texture_handle;
+    state.next_texture_handle += 1;
+
+    state.cubemaps.insert(handle, SkyProbe::new(size, &data));
+    state.pending_texture_arrays.push(PendingTextureArray {
+        handle,
+        width: size,
+        height: size,
+        layers: CUBEMAP_FACES as u32,
+        format: TextureFormat::Rgba8,
+        data,
+        etc2_data: Vec::new(),
+    });
+
+    Ok(handle)
+}
+
+/// Load a texture atlas from ROM data pack by ID
+///
+/// Uploads the packed atlas texture and remembers its sprite layout, so
+/// `atlas_bind(handle, sprite_index)` can select individual sprites.
+///
+/// # Arguments
+/// * `id_ptr` — Pointer to asset ID string in WASM memory
+/// * `id_len` — Length of asset ID string
+///
+/// # Returns
+/// Texture handle (>0) on success. Traps on failure.
+///
+/// **Init-only:** Can only be called during `init()`.
+fn rom_atlas(mut caller: Caller<'_, ZXGameContext>, id_ptr: u32, id_len: u32) -> Result<u32> {
+    check_init_only(&caller, "rom_atlas")?;
+
+    let id = read_string_id(&caller, id_ptr, id_len).ok_or_else(|| {
+        anyhow::anyhow!(
+            "rom_atlas: failed to read asset ID at ptr=0x{:08X}, len={}",
+            id_ptr,
+            id_len
+        )
+    })?;
+
+    // Extract atlas layout and texture data from data pack (read-only access)
+    let (width, height, format, data, etc2_data, uv_rects) = {
+        let state = &caller.data().ffi;
+        let data_pack = state
+            .data_pack
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("rom_atlas: no data pack loaded"))?;
+        let atlas = data_pack
+            .find_atlas(&id)
+            .ok_or_else(|| anyhow::anyhow!("rom_atlas: atlas '{}' not found in data pack", id))?;
+        let texture = data_pack.find_texture(&id).ok_or_else(|| {
+            anyhow::anyhow!(
+                "rom_atlas: texture for atlas '{}' not found in data pack",
+                id
+            )
+        })?;
+        let uv_rects: Vec<[f32; 4]> = (0..atlas.sprite_count())
+            .filter_map(|i| atlas.uv_rect(i))
+            .collect();
+        (
+            texture.width as u32,
+            texture.height as u32,
+            texture.format,
+            texture.data.clone(),
+            texture.etc2_data.clone(),
+            uv_rects,
+        )
+    };
+
+    // Now mutate state to allocate handle and queue upload
+    let state = &mut caller.data_mut().ffi;
+    let handle = state.next_texture_handle;
+    state.next_texture_handle += 1;
+
+    state.pending_textures.push(PendingTexture {
+        handle,
+        width,
+        height,
+        format,
+        data,
+        etc2_data,
+    });
+    state.atlases.insert(handle, uv_rects);
+
+    Ok(handle)
+}
+
 /// Load a mesh from ROM data pack by ID
 ///
 /// # Arguments
@@ -156,7 +311,7 @@ fn rom_mesh(mut caller: Caller<'_, ZXGameContext>, id_ptr: u32, id_len: u32) ->
     })?;
 
     // Extract mesh data from data pack (read-only access)
-    let (format, vertex_data, index_data) = {
+    let (format, vertex_data, index_data, sockets, morph_targets) = {
         let state = &caller.data().ffi;
         let data_pack = state
             .data_pack
@@ -169,12 +324,21 @@ fn rom_mesh(mut caller: Caller<'_, ZXGameContext>, id_ptr: u32, id_len: u32) ->
             mesh.format,
             mesh.vertex_data.clone(),
             mesh.index_data.clone(),
+            mesh.sockets.clone(),
+            mesh.morph_targets.clone(),
         )
     };
 
     // Now mutate state to allocate handle and queue upload
     let state = &mut caller.data_mut().ffi;
     let handle = state.next_mesh_handle;
+    if !morph_targets.is_empty() {
+        let vertex_count = (vertex_data.len() / vertex_stride_packed(format) as usize) as u32;
+        state
+            .morphs
+            .add(handle, vertex_count, &morph_targets)
+            .map_err(|e| anyhow::anyhow!("rom_mesh: mesh '{}': {}", id, e))?;
+    }
     state.next_mesh_handle += 1;
 
     state.pending_meshes_packed.push(PendingMeshPacked {
@@ -183,10 +347,56 @@ fn rom_mesh(mut caller: Caller<'_, ZXGameContext>, id_ptr: u32, id_len: u32) ->
         vertex_data,
         index_data: Some(index_data),
     });
+    if !sockets.is_empty() {
+        state.mesh_sockets.insert(handle, sockets);
+    }
 
     Ok(handle)
 }
 
+/// Look up a named socket on a mesh loaded with `rom_mesh()`
+///
+/// # Arguments
+/// * `mesh` — Mesh handle from rom_mesh
+/// * `name_ptr` — Pointer to socket name string in WASM memory (without the
+///   `socket_` node prefix)
+/// * `name_len` — Length of socket name string
+///
+/// # Returns
+/// Socket ID (>0) for `push_socket()`. Traps on failure.
+///
+/// **Init-only:** Can only be called during `init()`.
+fn rom_mesh_socket(
+    caller: Caller<'_, ZXGameContext>,
+    mesh: u32,
+    name_ptr: u32,
+    name_len: u32,
+) -> Result<u32> {
+    check_init_only(&caller, "rom_mesh_socket")?;
+
+    let name = read_string_id(&caller, name_ptr, name_len).ok_or_else(|| {
+        anyhow::anyhow!(
+            "rom_mesh_socket: failed to read socket name at ptr=0x{:08X}, len={}",
+            name_ptr,
+            name_len
+        )
+    })?;
+
+    let state = &caller.data().ffi;
+    if mesh == 0 || mesh >= state.next_mesh_handle {
+        bail!("rom_mesh_socket: invalid mesh handle {}", mesh);
+    }
+    let index = state
+        .mesh_sockets
+        .get(&mesh)
+        .and_then(|sockets| sockets.iter().position(|socket| socket.name == name))
+        .ok_or_else(|| {
+            anyhow::anyhow!("rom_mesh_socket: mesh {} has no socket '{}'", mesh, name)
+        })?;
+
+    Ok(index as u32 + 1)
+}
+
 /// Load skeleton inverse bind matrices from ROM data pack by ID
 ///
 /// # Arguments
@@ -302,6 +512,7 @@ fn rom_font(mut caller: Caller<'_, ZXGameContext>, id_ptr: u32, id_len: u32) ->
         height: atlas_height,
         format: TextureFormat::Rgba8,
         data: atlas_data,
+        etc2_data: Vec::new(),
     });
 
     // Return the atlas texture handle
@@ -366,6 +577,143 @@ fn rom_sound(mut caller: Caller<'_, ZXGameContext>, id_ptr: u32, id_len: u32) ->
     Ok(handle)
 }
 
+/// Load a music stream from ROM data pack by ID
+///
+/// Streams stay QOA-compressed in memory and are decoded in chunks while they
+/// play, so long tracks don't have to fit in audio memory as PCM.
+///
+/// # Arguments
+/// * `id_ptr` — Pointer to asset ID string in WASM memory
+/// * `id_len` — Length of asset ID string
+///
+/// # Returns
+/// Stream handle (>0) for `stream_play()`. Traps on failure.
+///
+/// **Init-only:** Can only be called during `init()`.
+fn rom_stream(mut caller: Caller<'_, ZXGameContext>, id_ptr: u32, id_len: u32) -> Result<u32> {
+    check_init_only(&caller, "rom_stream")?;
+
+    let id = read_string_id(&caller, id_ptr, id_len).ok_or_else(|| {
+        anyhow::anyhow!(
+            "rom_stream: failed to read asset ID at ptr=0x{:08X}, len={}",
+            id_ptr,
+            id_len
+        )
+    })?;
+
+    let stream = {
+        let state = &caller.data().ffi;
+        let data_pack = state
+            .data_pack
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("rom_stream: no data pack loaded"))?;
+        let packed = data_pack
+            .find_stream(&id)
+            .ok_or_else(|| anyhow::anyhow!("rom_stream: stream '{}' not found in data pack", id))?;
+        Stream::new(packed.data.clone(), packed.total_samples)
+            .ok_or_else(|| anyhow::anyhow!("rom_stream: stream '{}' has invalid QOA data", id))?
+    };
+
+    // Streams are only added during init, so this never copies in practice
+    let streams = Arc::make_mut(&mut caller.data_mut().ffi.streams);
+    streams.push(stream);
+    Ok(streams.len() as u32)
+}
+
+/// Load a spline path from ROM data pack by ID
+///
+/// Builds the path's arc-length table on the host for `path_eval()` and
+/// `path_length()`.
+///
+/// # Arguments
+/// * `id_ptr` — Pointer to asset ID string in WASM memory
+/// * `id_len` — Length of asset ID string
+///
+/// # Returns
+/// Path handle (>0) on success. Traps on failure.
+///
+/// **Init-only:** Can only be called during `init()`.
+fn rom_path(mut caller: Caller<'_, ZXGameContext>, id_ptr: u32, id_len: u32) -> Result<u32> {
+    check_init_only(&caller, "rom_path")?;
+
+    let id = read_string_id(&caller, id_ptr, id_len).ok_or_else(|| {
+        anyhow::anyhow!(
+            "rom_path: failed to read asset ID at ptr=0x{:08X}, len={}",
+            id_ptr,
+            id_len
+        )
+    })?;
+
+    let curve = {
+        let state = &caller.data().ffi;
+        let data_pack = state
+            .data_pack
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("rom_path: no data pack loaded"))?;
+        let packed = data_pack
+            .find_path(&id)
+            .ok_or_else(|| anyhow::anyhow!("rom_path: path '{}' not found in data pack", id))?;
+        PathCurve::new(packed).ok_or_else(|| {
+            anyhow::anyhow!(
+                "rom_path: path '{}' has invalid control points ({} for {:?})",
+                id,
+                packed.points.len(),
+                packed.kind
+            )
+        })?
+    };
+
+    let paths = &mut caller.data_mut().ffi.paths;
+    paths.push(curve);
+    Ok(paths.len() as u32)
+}
+
+/// Load a localized string table from the ROM data pack by ID
+///
+/// # Arguments
+/// * `id_ptr` — Pointer to asset ID string in WASM memory
+/// * `id_len` — Length of asset ID string
+///
+/// # Returns
+/// Table handle (>0) on success. Traps on failure.
+///
+/// Loaded tables are searched in load order by `str_get()`.
+fn rom_strings(mut caller: Caller<'_, ZXGameContext>, id_ptr: u32, id_len: u32) -> Result<u32> {
+    check_init_only(&caller, "rom_strings")?;
+
+    let id = read_string_id(&caller, id_ptr, id_len).ok_or_else(|| {
+        anyhow::anyhow!(
+            "rom_strings: failed to read asset ID at ptr=0x{:08X}, len={}",
+            id_ptr,
+            id_len
+        )
+    })?;
+
+    let table = {
+        let state = &caller.data().ffi;
+        let data_pack = state
+            .data_pack
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("rom_strings: no data pack loaded"))?;
+        let packed = data_pack.find_strings(&id).ok_or_else(|| {
+            anyhow::anyhow!("rom_strings: string table '{}' not found in data pack", id)
+        })?;
+        StringTable::new(packed.clone()).ok_or_else(|| {
+            anyhow::anyhow!(
+                "rom_strings: string table '{}' has {} values for {} keys x {} languages",
+                id,
+                packed.values.len(),
+                packed.keys.len(),
+                packed.languages.len()
+            )
+        })?
+    };
+
+    let tables = &mut caller.data_mut().ffi.string_tables;
+    tables.push(table);
+    Ok(tables.len() as u32)
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // RAW DATA (copies into WASM linear memory)
 // ═══════════════════════════════════════════════════════════════════════════
@@ -0,0 +1,229 @@
+//! Team and scoreboard FFI functions
+//!
+//! Host-managed team assignment and score tracking for versus games. The
+//! state lives in `ZRollbackState`, so changes made in `update()` roll back
+//! with the rest of the simulation. `scoreboard_draw()` renders a built-in
+//! standings overlay from that state.
+
+use anyhow::Result;
+use nethercore_core::MAX_PLAYERS;
+use tracing::warn;
+use wasmtime::{Caller, Linker};
+
+use super::ZXGameContext;
+use super::draw_2d::{push_rect, push_text};
+use crate::state::{MAX_TEAMS, match_flags};
+
+/// Z-index for the scoreboard overlay (drawn above game UI)
+const SCOREBOARD_Z_INDEX: u32 = u32::MAX;
+
+/// Overlay layout (pixels, relative to the current viewport)
+const PANEL_WIDTH: f32 = 480.0;
+const TITLE_SIZE: f32 = 32.0;
+const ROW_SIZE: f32 = 24.0;
+const ROW_SPACING: f32 = 32.0;
+const PADDING: f32 = 24.0;
+
+/// Panel background color (0xRRGGBBAA)
+const PANEL_COLOR: u32 = 0x101018E0;
+/// Title and unassigned-player color
+const TEXT_COLOR: u32 = 0xFFFFFFFF;
+/// Row colors for teams 1..=MAX_TEAMS
+const TEAM_COLORS: [u32; MAX_TEAMS as usize] = [
+    0xFF5555FF, 0x5599FFFF, 0x55DD55FF, 0xFFDD44FF, 0xDD66FFFF, 0x44DDDDFF, 0xFF9944FF, 0xBBBBBBFF,
+];
+
+/// Register team and scoreboard FFI functions
+pub fn register(linker: &mut Linker<ZXGameContext>) -> Result<()> {
+    linker.func_wrap("env", "team_set", team_set)?;
+    linker.func_wrap("env", "team_get", team_get)?;
+    linker.func_wrap("env", "team_score", team_score)?;
+    linker.func_wrap("env", "score_add", score_add)?;
+    linker.func_wrap("env", "score_set", score_set)?;
+    linker.func_wrap("env", "score_get", score_get)?;
+    linker.func_wrap("env", "match_end", match_end)?;
+    linker.func_wrap("env", "match_is_over", match_is_over)?;
+    linker.func_wrap("env", "match_reset", match_reset)?;
+    linker.func_wrap("env", "scoreboard_draw", scoreboard_draw)?;
+    Ok(())
+}
+
+/// Validate a player index, logging a warning if out of range
+fn valid_player(fn_name: &str, player: u32) -> Option<usize> {
+    if (player as usize) < MAX_PLAYERS {
+        Some(player as usize)
+    } else {
+        warn!(
+            "{}: invalid player {} (must be 0-{})",
+            fn_name,
+            player,
+            MAX_PLAYERS - 1
+        );
+        None
+    }
+}
+
+/// Assign a player to a team
+///
+/// # Arguments
+/// * `player` — Player index (0-7)
+/// * `team` — Team number (1-8), or 0 to remove the player from their team
+fn team_set(mut caller: Caller<'_, ZXGameContext>, player: u32, team: u32) {
+    let Some(player) = valid_player("team_set", player) else {
+        return;
+    };
+    if team > MAX_TEAMS {
+        warn!("team_set: invalid team {} (must be 0-{})", team, MAX_TEAMS);
+        return;
+    }
+    caller.data_mut().rollback.match_state.teams[player] = team as u8;
+}
+
+/// Get a player's team
+///
+/// Returns the team number (1-8), or 0 if the player has no team.
+fn team_get(caller: Caller<'_, ZXGameContext>, player: u32) -> u32 {
+    valid_player("team_get", player).map_or(0, |player| {
+        caller.data().rollback.match_state.teams[player] as u32
+    })
+}
+
+/// Get a team's score (the sum of its players' scores)
+fn team_score(caller: Caller<'_, ZXGameContext>, team: u32) -> i32 {
+    if team == 0 || team > MAX_TEAMS {
+        warn!(
+            "team_score: invalid team {} (must be 1-{})",
+            team, MAX_TEAMS
+        );
+        return 0;
+    }
+    caller.data().rollback.match_state.team_score(team)
+}
+
+/// Add to a player's score (negative values subtract)
+///
+/// Ignored once the match has ended.
+fn score_add(mut caller: Caller<'_, ZXGameContext>, player: u32, delta: i32) {
+    let Some(player) = valid_player("score_add", player) else {
+        return;
+    };
+    let state = &mut caller.data_mut().rollback.match_state;
+    if !state.is_over() {
+        state.scores[player] = state.scores[player].wrapping_add(delta);
+    }
+}
+
+/// Set a player's score
+///
+/// Ignored once the match has ended.
+fn score_set(mut caller: Caller<'_, ZXGameContext>, player: u32, score: i32) {
+    let Some(player) = valid_player("score_set", player) else {
+        return;
+    };
+    let state = &mut caller.data_mut().rollback.match_state;
+    if !state.is_over() {
+        state.scores[player] = score;
+    }
+}
+
+/// Get a player's score
+fn score_get(caller: Caller<'_, ZXGameContext>, player: u32) -> i32 {
+    valid_player("score_get", player).map_or(0, |player| {
+        caller.data().rollback.match_state.scores[player]
+    })
+}
+
+/// End the match, freezing all scores
+fn match_end(mut caller: Caller<'_, ZXGameContext>) {
+    caller.data_mut().rollback.match_state.flags |= match_flags::OVER;
+}
+
+/// Check if the match has ended
+///
+/// Returns 1 if `match_end()` has been called since the last reset, 0 otherwise.
+fn match_is_over(caller: Caller<'_, ZXGameContext>) -> u32 {
+    caller.data().rollback.match_state.is_over() as u32
+}
+
+/// Reset all scores and start a new match
+///
+/// Team assignments are kept.
+fn match_reset(mut caller: Caller<'_, ZXGameContext>) {
+    let state = &mut caller.data_mut().rollback.match_state;
+    state.scores = [0; MAX_PLAYERS];
+    state.flags = 0;
+}
+
+/// Draw the scoreboard overlay
+///
+/// Lists every player in the session ranked by team score, then player
+/// score, centered in the current viewport and drawn above other 2D content.
+/// The title reads "MATCH OVER" once `match_end()` has been called.
+///
+/// Call from `render()`. The current color and z-index are preserved.
+fn scoreboard_draw(mut caller: Caller<'_, ZXGameContext>) {
+    let player_count = caller.data().game.player_count.max(1) as usize;
+    let ctx = caller.data_mut();
+    let match_state = ctx.rollback.match_state;
+    let state = &mut ctx.ffi;
+
+    let saved_color = state.current_shading_state.color_rgba8;
+    let saved_z_index = state.current_z_index;
+    let saved_font = state.current_font;
+    state.current_z_index = SCOREBOARD_Z_INDEX;
+    state.current_font = 0; // Built-in font
+
+    let standings = match_state.standings(player_count);
+    let panel_height = PADDING * 2.0 + TITLE_SIZE + ROW_SPACING * standings.len() as f32;
+    let vp = state.current_viewport;
+    let panel_x = (vp.width as f32 - PANEL_WIDTH) * 0.5;
+    let panel_y = (vp.height as f32 - panel_height) * 0.5;
+
+    state.update_color(PANEL_COLOR);
+    push_rect(state, panel_x, panel_y, PANEL_WIDTH, panel_height);
+
+    let title = if match_state.is_over() {
+        "MATCH OVER"
+    } else {
+        "SCOREBOARD"
+    };
+    state.update_color(TEXT_COLOR);
+    push_text(
+        state,
+        title,
+        panel_x + PADDING,
+        panel_y + PADDING,
+        TITLE_SIZE,
+    );
+
+    let mut row_y = panel_y + PADDING + TITLE_SIZE + (ROW_SPACING - ROW_SIZE);
+    for player in standings {
+        let team = match_state.teams[player] as u32;
+        let color = match team {
+            0 => TEXT_COLOR,
+            t => TEAM_COLORS[(t - 1) as usize],
+        };
+        state.update_color(color);
+
+        let name = format!("P{}", player + 1);
+        push_text(state, &name, panel_x + PADDING, row_y, ROW_SIZE);
+        if team != 0 {
+            let label = format!("TEAM {}", team);
+            push_text(state, &label, panel_x + PADDING + 96.0, row_y, ROW_SIZE);
+        }
+        let score = match_state.scores[player].to_string();
+        push_text(
+            state,
+            &score,
+            panel_x + PANEL_WIDTH - PADDING - 144.0,
+            row_y,
+            ROW_SIZE,
+        );
+
+        row_y += ROW_SPACING;
+    }
+
+    state.update_color(saved_color);
+    state.current_z_index = saved_z_index;
+    state.current_font = saved_font;
+}
@@ -0,0 +1,108 @@
+//! Localized string FFI functions
+//!
+//! Look up text from string tables loaded with `rom_strings()` in the
+//! language chosen with `locale_set()`. Every loaded table is searched in
+//! load order, and a key without text in the current language falls back to
+//! the table's first language.
+
+use anyhow::Result;
+use tracing::warn;
+use wasmtime::{Caller, Linker};
+
+use super::ZXGameContext;
+use super::helpers::get_memory;
+use super::rom::read_string_id;
+
+/// Register localized string FFI functions
+pub fn register(linker: &mut Linker<ZXGameContext>) -> Result<()> {
+    linker.func_wrap("env", "locale_set", locale_set)?;
+    linker.func_wrap("env", "str_get", str_get)?;
+    Ok(())
+}
+
+/// Choose the language used by `str_get()`
+///
+/// # Arguments
+/// * `lang_ptr` — Pointer to a language code (e.g. "fr", "pt-BR")
+/// * `lang_len` — Length of the language code
+///
+/// A code matches a table language exactly (ignoring case) or by its primary
+/// subtag, so "pt-BR" also selects a "pt" column. The locale is host-side
+/// state that survives rollback; pick it from settings, not gameplay.
+///
+/// Returns 1 if a loaded table has the language, 0 otherwise (the locale is
+/// still set, and lookups fall back to each table's first language).
+fn locale_set(mut caller: Caller<'_, ZXGameContext>, lang_ptr: u32, lang_len: u32) -> u32 {
+    let Some(locale) = read_string_id(&caller, lang_ptr, lang_len) else {
+        warn!("locale_set: failed to read language code");
+        return 0;
+    };
+
+    let state = &mut caller.data_mut().ffi;
+    let found = state
+        .string_tables
+        .iter()
+        .any(|table| table.language_index(&locale).is_some());
+    state.locale = locale;
+    found as u32
+}
+
+/// Copy the text for a key in the current locale into WASM memory
+///
+/// # Arguments
+/// * `key_ptr` — Pointer to the key string (e.g. "menu.start")
+/// * `key_len` — Length of the key
+/// * `out_ptr` — Destination buffer for UTF-8 text (not NUL-terminated)
+/// * `max_len` — Size of the destination buffer
+///
+/// Text longer than the buffer is cut at the last whole character that
+/// fits.
+///
+/// Returns the full length of the text in bytes (larger than `max_len` when
+/// it was cut), or 0 if no table has the key.
+fn str_get(
+    mut caller: Caller<'_, ZXGameContext>,
+    key_ptr: u32,
+    key_len: u32,
+    out_ptr: u32,
+    max_len: u32,
+) -> u32 {
+    const FN_NAME: &str = "str_get";
+
+    let Some(key) = read_string_id(&caller, key_ptr, key_len) else {
+        warn!("{}: failed to read key", FN_NAME);
+        return 0;
+    };
+
+    let text = {
+        let state = &caller.data().ffi;
+        let Some(text) = state
+            .string_tables
+            .iter()
+            .find_map(|table| table.get(&key, &state.locale))
+        else {
+            return 0;
+        };
+        text.to_string()
+    };
+
+    let mut copy_len = text.len().min(max_len as usize);
+    while !text.is_char_boundary(copy_len) {
+        copy_len -= 1;
+    }
+
+    let Some(memory) = get_memory(&caller, FN_NAME) else {
+        return 0;
+    };
+    let data = memory.data_mut(&mut caller);
+    let start = out_ptr as usize;
+    let Some(out) = data.get_mut(start..start + copy_len) else {
+        warn!(
+            "{}: output buffer ({} bytes at 0x{:08X}) out of bounds",
+            FN_NAME, copy_len, out_ptr
+        );
+        return 0;
+    };
+    out.copy_from_slice(&text.as_bytes()[..copy_len]);
+    text.len() as u32
+}
@@ -1,6 +1,6 @@
 //! Texture FFI functions
 //!
-//! Functions for loading and binding textures.
+//! Functions for loading and binding textures and texture arrays.
 
 use anyhow::Result;
 use tracing::warn;
@@ -9,14 +9,20 @@ use wasmtime::{Caller, Linker};
 use super::helpers::{checked_mul, read_wasm_bytes, validate_dimensions_nonzero};
 use super::{ZXGameContext, guards::guard_init_only};
 use crate::graphics::MatcapBlendMode;
-use crate::state::PendingTexture;
+use crate::state::{PendingTexture, PendingTextureArray};
 use zx_common::TextureFormat;
 
+/// Maximum layers in one texture array (the GPU's array layer limit)
+const MAX_TEXTURE_ARRAY_LAYERS: u32 = 256;
+
 /// Register texture FFI functions
 pub fn register(linker: &mut Linker<ZXGameContext>) -> Result<()> {
     linker.func_wrap("env", "load_texture", load_texture)?;
     linker.func_wrap("env", "texture_bind", texture_bind)?;
     linker.func_wrap("env", "texture_bind_slot", texture_bind_slot)?;
+    linker.func_wrap("env", "atlas_bind", atlas_bind)?;
+    linker.func_wrap("env", "texture_array_create", texture_array_create)?;
+    linker.func_wrap("env", "texture_array_bind", texture_array_bind)?;
     linker.func_wrap("env", "matcap_blend_mode", matcap_blend_mode)?;
     Ok(())
 }
@@ -73,6 +79,7 @@ fn load_texture(
         height,
         format: TextureFormat::Rgba8,
         data: pixel_data,
+        etc2_data: Vec::new(),
     });
 
     handle
@@ -87,6 +94,175 @@ fn load_texture(
 fn texture_bind(mut caller: Caller<'_, ZXGameContext>, handle: u32) {
     let state = &mut caller.data_mut().ffi;
     state.bound_textures[0] = handle;
+    state.bound_atlas_sprite = None;
+    // A texture array bound this way draws its first layer
+    state.bound_array_layer = state
+        .texture_arrays
+        .contains_key(&handle)
+        .then_some((handle, 0));
+}
+
+/// Bind one sprite of a texture atlas to slot 0 (albedo)
+///
+/// # Arguments
+/// * `handle` — Atlas handle from rom_atlas
+/// * `sprite_index` — Sprite index (order of `sprites` in nether.toml)
+///
+/// Sprite and billboard draws then sample only that sprite: full-texture
+/// draws cover the whole sprite and region draws are relative to it.
+/// Binding another texture to slot 0 clears the selection.
+fn atlas_bind(mut caller: Caller<'_, ZXGameContext>, handle: u32, sprite_index: u32) {
+    let state = &mut caller.data_mut().ffi;
+    let Some(uv_rects) = state.atlases.get(&handle) else {
+        warn!("atlas_bind: invalid atlas handle {}", handle);
+        return;
+    };
+    let Some(&uv) = uv_rects.get(sprite_index as usize) else {
+        warn!(
+            "atlas_bind: invalid sprite index {} (atlas has {})",
+            sprite_index,
+            uv_rects.len()
+        );
+        return;
+    };
+
+    state.bound_textures[0] = handle;
+    state.bound_atlas_sprite = Some((handle, uv));
+    state.bound_array_layer = None;
+}
+
+/// Combine textures into a texture array
+///
+/// # Arguments
+/// * `handles_ptr` — Pointer to `count` u32 texture handles (one per layer, in order)
+/// * `count` — Number of layers (1-256)
+///
+/// Every texture must be loaded in the same `init()` (or `init_step()`) call
+/// and share one size and format. The layers are copied, so the textures stay
+/// usable on their own and count against VRAM again.
+///
+/// Returns a texture handle (>0) for `texture_array_bind()`, or 0 on failure.
+fn texture_array_create(
+    mut caller: Caller<'_, ZXGameContext>,
+    handles_ptr: u32,
+    count: u32,
+) -> u32 {
+    const FN_NAME: &str = "texture_array_create";
+
+    guard_init_only!(caller, FN_NAME);
+
+    if !(1..=MAX_TEXTURE_ARRAY_LAYERS).contains(&count) {
+        warn!(
+            "{}: count {} out of range (1-{})",
+            FN_NAME, count, MAX_TEXTURE_ARRAY_LAYERS
+        );
+        return 0;
+    }
+
+    let Some(bytes) = read_wasm_bytes(&caller, handles_ptr, count as usize * 4, FN_NAME) else {
+        return 0;
+    };
+    let handles: Vec<u32> = bytes
+        .chunks_exact(4)
+        .map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+        .collect();
+
+    let state = &mut caller.data_mut().ffi;
+
+    // Textures loaded during this init call are still pending, so their data is on hand
+    let mut layers = Vec::with_capacity(handles.len());
+    for &handle in &handles {
+        let Some(pending) = state.pending_textures.iter().find(|t| t.handle == handle) else {
+            warn!(
+                "{}: texture {} must be loaded in the same init() call",
+                FN_NAME, handle
+            );
+            return 0;
+        };
+        layers.push(pending);
+    }
+
+    let first = layers[0];
+    if let Some(mismatch) = layers
+        .iter()
+        .find(|t| (t.width, t.height, t.format) != (first.width, first.height, first.format))
+    {
+        warn!(
+            "{}: texture {} is {}x{} {:?}, but texture {} is {}x{} {:?} - layers must match",
+            FN_NAME,
+            mismatch.handle,
+            mismatch.width,
+            mismatch.height,
+            mismatch.format,
+            first.handle,
+            first.width,
+            first.height,
+            first.format
+        );
+        return 0;
+    }
+
+    let (width, height, format) = (first.width, first.height, first.format);
+    let data: Vec<u8> = layers.iter().flat_map(|t| t.data.iter().copied()).collect();
+    // The ETC2 copy is only usable if every layer has one
+    let etc2_data: Vec<u8> = if layers.iter().all(|t| !t.etc2_data.is_empty()) {
+        layers
+            .iter()
+            .flat_map(|t| t.etc2_data.iter().copied())
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    let handle = state.next_texture_handle;
+    state.next_texture_handle += 1;
+
+    if format.is_indexed() {
+        state.indexed_textures.insert(handle);
+    }
+    state.pending_texture_arrays.push(PendingTextureArray {
+        handle,
+        width,
+        height,
+        layers: count,
+        format,
+        data,
+        etc2_data,
+    });
+    state.texture_arrays.insert(handle, count);
+
+    handle
+}
+
+/// Bind one layer of a texture array to slot 0 (albedo)
+///
+/// # Arguments
+/// * `handle` — Texture array handle from texture_array_create
+/// * `layer` — Layer index (order of the handles passed to texture_array_create)
+///
+/// Sprite and billboard draws then sample that layer. Switching layers of
+/// the same array doesn't start a new batch, so sprites from many sheets
+/// draw together. Meshes don't sample texture arrays.
+fn texture_array_bind(mut caller: Caller<'_, ZXGameContext>, handle: u32, layer: u32) {
+    let state = &mut caller.data_mut().ffi;
+    let Some(&layers) = state.texture_arrays.get(&handle) else {
+        warn!(
+            "texture_array_bind: invalid texture array handle {}",
+            handle
+        );
+        return;
+    };
+    if layer >= layers {
+        warn!(
+            "texture_array_bind: invalid layer {} (array has {})",
+            layer, layers
+        );
+        return;
+    }
+
+    state.bound_textures[0] = handle;
+    state.bound_atlas_sprite = None;
+    state.bound_array_layer = Some((handle, layer));
 }
 
 /// Bind a texture to a specific slot
@@ -4,6 +4,7 @@
 
 use anyhow::Result;
 use glam::{Mat4, Vec3};
+use tracing::warn;
 use wasmtime::{Caller, Linker};
 
 use super::ZXGameContext;
@@ -20,6 +21,7 @@ pub fn register(linker: &mut Linker<ZXGameContext>) -> Result<()> {
     linker.func_wrap("env", "push_rotate", push_rotate)?;
     linker.func_wrap("env", "push_scale", push_scale)?;
     linker.func_wrap("env", "push_scale_uniform", push_scale_uniform)?;
+    linker.func_wrap("env", "push_socket", push_socket)?;
     Ok(())
 }
 
@@ -195,3 +197,32 @@ fn push_scale_uniform(mut caller: Caller<'_, ZXGameContext>, s: f32) {
     let new_matrix = current * Mat4::from_scale(Vec3::splat(s));
     state.current_model_matrix = Some(new_matrix);
 }
+
+/// Push a mesh socket's transform onto the stack
+///
+/// # Arguments
+/// * `mesh` — Mesh handle from rom_mesh
+/// * `socket` — Socket ID from rom_mesh_socket
+///
+/// Call after binding the pose the mesh is drawn with (`set_bones()` or
+/// `keyframe_bind()`, plus `skeleton_bind()` if used) and before pushing
+/// anything else, so the current transform is the mesh's. A socket on a bone
+/// then follows that bone; with no pose bound it sits at its bind-pose
+/// position. Draws made afterwards (e.g. a sword mesh) are attached to the socket.
+fn push_socket(mut caller: Caller<'_, ZXGameContext>, mesh: u32, socket: u32) {
+    let state = &mut caller.data_mut().ffi;
+    let Some(socket_matrix) = state.socket_matrix(mesh, socket) else {
+        warn!("push_socket: invalid socket {} for mesh {}", socket, mesh);
+        return;
+    };
+
+    let current = state.current_model_matrix.unwrap_or_else(|| {
+        state
+            .model_matrices
+            .last()
+            .copied()
+            .unwrap_or(Mat4::IDENTITY)
+    });
+    let new_matrix = current * socket_matrix;
+    state.current_model_matrix = Some(new_matrix);
+}
@@ -2,18 +2,28 @@
 //!
 //! Provides functions to set the viewport rectangle for subsequent draw calls.
 //! Each viewport can have its own camera and all 2D coordinates become viewport-relative.
+//!
+//! Also controls how the 960×540 canvas is scaled to the window, and reports
+//! which part of the canvas is visible so HUDs can stay on screen.
 
 use anyhow::Result;
+use nethercore_core::app::config::ScaleMode;
 use tracing::warn;
 use wasmtime::{Caller, Linker};
 
 use super::ZXGameContext;
+use super::helpers::get_memory;
 use crate::console::RESOLUTION;
 
+/// Size of the rectangle written by `safe_area()` (x, y, width, height as f32)
+const SAFE_AREA_SIZE: usize = 16;
+
 /// Register viewport FFI functions
 pub fn register(linker: &mut Linker<ZXGameContext>) -> Result<()> {
     linker.func_wrap("env", "viewport", viewport)?;
     linker.func_wrap("env", "viewport_clear", viewport_clear)?;
+    linker.func_wrap("env", "display_scale_mode", display_scale_mode)?;
+    linker.func_wrap("env", "safe_area", safe_area)?;
     Ok(())
 }
 
@@ -95,6 +105,53 @@ fn viewport_clear(mut caller: Caller<'_, ZXGameContext>) {
     state.current_viewport = crate::graphics::Viewport::FULLSCREEN;
 }
 
+/// Choose how the canvas is scaled to the window
+///
+/// # Arguments
+/// * `mode` — 0 = integer scaling (black bars), 1 = fit with bars, 2 = stretch
+///
+/// Overrides the player's scale setting until the game is restarted.
+/// Presentation-only, so it is safe to call at any time.
+fn display_scale_mode(mut caller: Caller<'_, ZXGameContext>, mode: u32) {
+    let mode = match mode {
+        0 => ScaleMode::PixelPerfect,
+        1 => ScaleMode::Fit,
+        2 => ScaleMode::Stretch,
+        _ => {
+            warn!("display_scale_mode: invalid mode {} (must be 0-2)", mode);
+            return;
+        }
+    };
+    caller.data_mut().ffi.display_scale_mode = Some(mode);
+}
+
+/// Get the part of the canvas that is visible in the window
+///
+/// Writes x, y, width, height (4 × f32, canvas pixels) to `out_ptr`. This is
+/// the full 960×540 canvas unless integer scaling crops it in a small
+/// window. Updated after each rendered frame.
+///
+/// Returns 1 on success, 0 if `out_ptr` is out of bounds.
+fn safe_area(mut caller: Caller<'_, ZXGameContext>, out_ptr: u32) -> u32 {
+    const FN_NAME: &str = "safe_area";
+
+    let area = caller.data().ffi.safe_area;
+    let Some(memory) = get_memory(&caller, FN_NAME) else {
+        return 0;
+    };
+
+    let start = out_ptr as usize;
+    let data = memory.data_mut(&mut caller);
+    let Some(out) = data.get_mut(start..start + SAFE_AREA_SIZE) else {
+        warn!("{}: output buffer out of bounds", FN_NAME);
+        return 0;
+    };
+    for (chunk, value) in out.chunks_exact_mut(4).zip(area) {
+        chunk.copy_from_slice(&value.to_le_bytes());
+    }
+    1
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
@@ -0,0 +1,132 @@
+//! Water surface FFI functions
+//!
+//! Water planes are grids displaced by Gerstner waves. The waves are a pure
+//! function of the tick count, so the surface animates by itself each tick
+//! without any rollback state, and `water_height()` gives deterministic
+//! buoyancy in `update()`.
+
+use anyhow::Result;
+use tracing::warn;
+use wasmtime::{Caller, Linker};
+
+use super::ZXGameContext;
+use super::guards::guard_init_only;
+use crate::graphics::{FORMAT_NORMAL, FORMAT_UV, MeshBlend};
+use crate::water::{MAX_WATER_PLANES, MAX_WATER_WAVES, WaterPlane};
+
+/// Vertex format water is drawn with (position, UV, normal)
+const WATER_FORMAT: u8 = FORMAT_UV | FORMAT_NORMAL;
+
+/// Register water FFI functions
+pub fn register(linker: &mut Linker<ZXGameContext>) -> Result<()> {
+    linker.func_wrap("env", "water_plane", water_plane)?;
+    linker.func_wrap("env", "water_draw", water_draw)?;
+    linker.func_wrap("env", "water_height", water_height)?;
+    Ok(())
+}
+
+/// Create a water plane animated by Gerstner waves (init-only)
+///
+/// # Arguments
+/// * `size` — Side length of the square plane, centered on the origin at y = 0
+/// * `wave_count` — Number of summed waves (1-8); more waves look choppier
+/// * `amplitude` — Combined crest height of all waves above rest
+/// * `seed` — Picks the wind direction, wavelengths and phases
+///
+/// Waves travel roughly along one wind direction, each shorter and lower than
+/// the last, with sharpened crests.
+///
+/// Returns a water handle (>0) on success, 0 on failure.
+fn water_plane(
+    mut caller: Caller<'_, ZXGameContext>,
+    size: f32,
+    wave_count: u32,
+    amplitude: f32,
+    seed: u32,
+) -> u32 {
+    const FN_NAME: &str = "water_plane";
+
+    guard_init_only!(caller, FN_NAME);
+
+    let state = &mut caller.data_mut().ffi;
+    if state.water_planes.len() >= MAX_WATER_PLANES {
+        warn!(
+            "{}: maximum water plane count {} exceeded",
+            FN_NAME, MAX_WATER_PLANES
+        );
+        return 0;
+    }
+
+    let Some(plane) = WaterPlane::new(size, wave_count, amplitude, seed) else {
+        warn!(
+            "{}: size must be positive, amplitude non-negative and wave_count 1-{}",
+            FN_NAME, MAX_WATER_WAVES
+        );
+        return 0;
+    };
+
+    state.water_planes.push(plane);
+    state.water_planes.len() as u32
+}
+
+/// Draw a water plane at the current tick
+///
+/// Uses the current transform, bound texture (UVs span 0-1 across the plane)
+/// and render state, like `draw_mesh()`. Normals follow the waves, so lit
+/// render modes shade the swell.
+fn water_draw(mut caller: Caller<'_, ZXGameContext>, handle: u32) {
+    let time = game_time(&caller);
+    let state = &mut caller.data_mut().ffi;
+    let Some(plane) = (handle as usize)
+        .checked_sub(1)
+        .and_then(|index| state.water_planes.get(index))
+    else {
+        warn!("water_draw: invalid handle {}", handle);
+        return;
+    };
+
+    // Reuse one vertex buffer across draws (the grid is rebuilt every frame)
+    let mut vertex_data = std::mem::take(&mut state.water_vertices);
+    plane.build_vertices(time, &mut vertex_data);
+
+    let buffer_index = state.add_mvp_shading_state();
+    state.render_pass.record_triangles_indexed(
+        WATER_FORMAT,
+        &vertex_data,
+        &state.water_planes[handle as usize - 1].indices,
+        buffer_index,
+        state.bound_textures,
+        state.cull_mode,
+        MeshBlend::default(),
+        state.current_viewport,
+        state.current_pass_id,
+    );
+    state.water_vertices = vertex_data;
+}
+
+/// Get the water surface height at a point at the current tick
+///
+/// # Arguments
+/// * `handle` — Water handle from `water_plane()`
+/// * `x`, `z` — Point in the plane's own space (before its draw transform)
+///
+/// Deterministic, so it is safe for buoyancy in `update()`.
+///
+/// Returns the height above the plane's rest level, or 0 for invalid handles.
+fn water_height(caller: Caller<'_, ZXGameContext>, handle: u32, x: f32, z: f32) -> f32 {
+    if !x.is_finite() || !z.is_finite() {
+        warn!("water_height: point must be finite");
+        return 0.0;
+    }
+    let time = game_time(&caller);
+    (handle as usize)
+        .checked_sub(1)
+        .and_then(|index| caller.data().ffi.water_planes.get(index))
+        .map_or(0.0, |plane| plane.height(x, z, time))
+}
+
+/// Seconds of game time at the current tick
+fn game_time(caller: &Caller<'_, ZXGameContext>) -> f64 {
+    let game = &caller.data().game;
+    game.tick_count as f64 * game.delta_time as f64
+}
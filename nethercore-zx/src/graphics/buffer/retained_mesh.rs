@@ -7,7 +7,9 @@ use crate::graphics::vertex::{
     VERTEX_FORMAT_COUNT, VertexFormatInfo, vertex_stride, vertex_stride_packed,
 };
 use anyhow::Result;
+use glam::Vec3;
 use std::borrow::Cow;
+use zx_common::f16_to_f32;
 
 /// Handle to a retained mesh
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -26,6 +28,27 @@ pub struct RetainedMesh {
     pub vertex_offset: u64,
     /// Byte offset into the format's index buffer (if indexed)
     pub index_offset: u64,
+    /// Minimum corner of the object-space bounding box
+    pub bounds_min: Vec3,
+    /// Maximum corner of the object-space bounding box
+    pub bounds_max: Vec3,
+}
+
+/// Object-space bounding box of packed vertex data
+///
+/// Uses the f16 positions the GPU sees rather than the original f32 values.
+pub(super) fn packed_bounds(data: &[u8], format: u8) -> (Vec3, Vec3) {
+    let stride = vertex_stride_packed(format) as usize;
+    let mut min = Vec3::splat(f32::INFINITY);
+    let mut max = Vec3::splat(f32::NEG_INFINITY);
+    for vertex in data.chunks_exact(stride) {
+        let position = Vec3::from_array(std::array::from_fn(|i| {
+            f16_to_f32(u16::from_le_bytes([vertex[i * 2], vertex[i * 2 + 1]]))
+        }));
+        min = min.min(position);
+        max = max.max(position);
+    }
+    (min, max)
 }
 
 /// Mesh loading and management operations
@@ -101,12 +124,15 @@ impl<'a> MeshLoader<'a> {
         let handle = MeshHandle(*self.next_mesh_id);
         *self.next_mesh_id += 1;
 
+        let (bounds_min, bounds_max) = packed_bounds(&packed_data, format);
         let mesh = RetainedMesh {
             format,
             vertex_count: vertex_count as u32,
             index_count: 0,
             vertex_offset,
             index_offset: 0,
+            bounds_min,
+            bounds_max,
         };
 
         tracing::debug!(
@@ -190,12 +216,15 @@ impl<'a> MeshLoader<'a> {
         let handle = MeshHandle(*self.next_mesh_id);
         *self.next_mesh_id += 1;
 
+        let (bounds_min, bounds_max) = packed_bounds(&packed_data, format);
         let mesh = RetainedMesh {
             format,
             vertex_count: vertex_count as u32,
             index_count: indices.len() as u32,
             vertex_offset,
             index_offset,
+            bounds_min,
+            bounds_max,
         };
 
         tracing::debug!(
@@ -247,12 +276,15 @@ impl<'a> MeshLoader<'a> {
         let handle = MeshHandle(*self.next_mesh_id);
         *self.next_mesh_id += 1;
 
+        let (bounds_min, bounds_max) = packed_bounds(data, format);
         let mesh = RetainedMesh {
             format,
             vertex_count: vertex_count as u32,
             index_count: 0,
             vertex_offset,
             index_offset: 0,
+            bounds_min,
+            bounds_max,
         };
 
         tracing::debug!(
@@ -324,12 +356,15 @@ impl<'a> MeshLoader<'a> {
         let handle = MeshHandle(*self.next_mesh_id);
         *self.next_mesh_id += 1;
 
+        let (bounds_min, bounds_max) = packed_bounds(data, format);
         let mesh = RetainedMesh {
             format,
             vertex_count: vertex_count as u32,
             index_count: indices.len() as u32,
             vertex_offset,
             index_offset,
+            bounds_min,
+            bounds_max,
         };
 
         tracing::debug!(
@@ -2,6 +2,7 @@
 
 use super::*;
 use crate::graphics::vertex::{FORMAT_COLOR, FORMAT_NORMAL, FORMAT_UV};
+use glam::Vec3;
 use std::borrow::Cow;
 
 #[test]
@@ -12,6 +13,8 @@ fn test_retained_mesh_non_indexed() {
         index_count: 0,
         vertex_offset: 1024,
         index_offset: 0,
+        bounds_min: Vec3::ZERO,
+        bounds_max: Vec3::ONE,
     };
     assert_eq!(mesh.format, FORMAT_UV | FORMAT_NORMAL);
     assert_eq!(mesh.index_count, 0);
@@ -25,11 +28,30 @@ fn test_retained_mesh_indexed() {
         index_count: 36,
         vertex_offset: 0,
         index_offset: 512,
+        bounds_min: Vec3::ZERO,
+        bounds_max: Vec3::ONE,
     };
     assert_eq!(mesh.vertex_count, 8);
     assert_eq!(mesh.index_count, 36);
 }
 
+#[test]
+fn test_packed_bounds() {
+    let format = FORMAT_UV;
+    // Position + UV per vertex
+    let vertices = [
+        [-1.0, 0.5, 2.0, 0.0, 0.0],
+        [3.0, -2.0, 0.25, 1.0, 1.0],
+        [0.0, 4.0, -1.5, 0.5, 0.5],
+    ]
+    .concat();
+    let packed = zx_common::pack_vertex_data(&vertices, format);
+
+    let (min, max) = super::retained_mesh::packed_bounds(&packed, format);
+    assert_eq!(min, Vec3::new(-1.0, -2.0, -1.5));
+    assert_eq!(max, Vec3::new(3.0, 4.0, 2.0));
+}
+
 /// Test that index data alignment padding works correctly for wgpu COPY_BUFFER_ALIGNMENT
 #[test]
 fn test_index_data_alignment_padding() {
@@ -5,7 +5,7 @@
 //! representation between FFI commands and GPU execution.
 
 use super::Viewport;
-use super::render_state::{CullMode, TextureHandle};
+use super::render_state::{BlendMode, CullMode, TextureHandle};
 use super::vertex::{VERTEX_FORMAT_COUNT, vertex_stride, vertex_stride_packed};
 use std::sync::OnceLock;
 use std::time::Instant;
@@ -22,7 +22,9 @@ const NO_Z_INDEX: u32 = 0;
 /// Determines rendering order and which pipeline to use:
 /// - Quad: Screen-space 2D UI (renders first for early-z optimization)
 /// - Mesh: 3D geometry (renders second, culled behind UI)
-/// - Environment: Procedural background (renders last, fills gaps)
+/// - Environment: Procedural background (renders after meshes, fills gaps)
+/// - BlendedMesh: 3D with a `blend_mode_3d()` mode (back to front over the finished scene)
+/// - BlendedQuad: Screen-space 2D with a `blend_mode_2d()` mode (composites over everything)
 #[repr(u8)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub enum RenderType {
@@ -45,6 +47,27 @@ pub enum RenderType {
     /// depth == 1.0 (clear value) pass, avoiding expensive environment shader invocations
     /// for pixels already covered by geometry.
     Environment = 2,
+
+    /// 3D meshes with a non-alpha blend mode (additive, multiply…)
+    ///
+    /// Renders after the environment, farthest first, so each mesh blends over
+    /// everything behind it. Depth tested but never written.
+    BlendedMesh = 3,
+
+    /// Screen-space quads with a non-alpha blend mode (additive, multiply…)
+    ///
+    /// Renders after the environment so it blends with the finished image at its
+    /// z-index, and never writes depth.
+    BlendedQuad = 4,
+}
+
+/// Blending of a 3D draw and its place among other blended draws
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct MeshBlend {
+    /// Blend mode from `blend_mode_3d()` (Alpha is the opaque dithered path)
+    pub mode: BlendMode,
+    /// Distance along the camera's view direction plus `sort_bias()`
+    pub sort_depth: f32,
 }
 
 /// Specifies which buffer the geometry data comes from
@@ -82,6 +105,8 @@ pub enum VRPCommand {
         /// Resolved to TextureHandle at render time via texture_table.
         textures: [u32; 4],
         cull_mode: CullMode,
+        /// Blend mode from `blend_mode_3d()` (captured at command creation)
+        blend_mode: BlendMode,
         /// Viewport for split-screen rendering (captured at command creation)
         viewport: Viewport,
         /// Pass ID for render pass ordering (execution barrier)
@@ -100,6 +125,8 @@ pub enum VRPCommand {
         /// Resolved to TextureHandle at render time via texture_table.
         textures: [u32; 4],
         cull_mode: CullMode,
+        /// Blend mode from `blend_mode_3d()` (captured at command creation)
+        blend_mode: BlendMode,
         /// Viewport for split-screen rendering (captured at command creation)
         viewport: Viewport,
         /// Pass ID for render pass ordering (execution barrier)
@@ -118,6 +145,10 @@ pub enum VRPCommand {
         cull_mode: CullMode,
         /// Viewport for split-screen rendering (captured at command creation)
         viewport: Viewport,
+        /// Scissor rectangle from `clip_push()` (None = clip to viewport)
+        clip: Option<Viewport>,
+        /// Blend mode for screen-space quads (Alpha for billboards)
+        blend_mode: BlendMode,
         /// Pass ID for render pass ordering (execution barrier)
         pass_id: u32,
         /// Z-index for 2D ordering within a pass (higher = closer to camera)
@@ -127,7 +158,7 @@ pub enum VRPCommand {
         /// Cached sort key computed at command creation time
         sort_key: CommandSortKey,
     },
-    /// EPU environment draw (fullscreen procedural background)
+    /// Environment draw (fullscreen EPU or cubemap background)
     EpuEnvironment {
         /// Index into `mvp_shading_indices` (instance_index) so the environment shader uses the
         /// correct view/proj + shading state.
@@ -136,6 +167,10 @@ pub enum VRPCommand {
         viewport: Viewport,
         /// Pass ID for render pass ordering (execution barrier)
         pass_id: u32,
+        /// True to draw from the EPU background cache instead of evaluating the EPU
+        cached: bool,
+        /// Cubemap from `sky_draw()`, drawn instead of the EPU background
+        sky: Option<TextureHandle>,
         /// Cached sort key computed at command creation time
         sort_key: CommandSortKey,
     },
@@ -151,6 +186,17 @@ impl VRPCommand {
             | VRPCommand::EpuEnvironment { sort_key, .. } => *sort_key,
         }
     }
+
+    /// Number of triangles this command draws
+    pub fn triangle_count(&self) -> u32 {
+        match self {
+            VRPCommand::Mesh { vertex_count, .. } => vertex_count / 3,
+            VRPCommand::IndexedMesh { index_count, .. } => index_count / 3,
+            VRPCommand::Quad { instance_count, .. } => instance_count.saturating_mul(2),
+            // Fullscreen triangle
+            VRPCommand::EpuEnvironment { .. } => 1,
+        }
+    }
 }
 
 /// Sort key for draw command ordering
@@ -159,9 +205,11 @@ impl VRPCommand {
 /// 1. Pass ID (preserves render pass ordering - execution barriers)
 /// 2. Viewport (split-screen regions)
 /// 3. Z-index (2D ordering for quads - higher values render on top)
-/// 4. Render type (Quad → Mesh → Environment for optimal early-z)
-/// 5. Render state (cull mode)
-/// 6. Textures (minimize bind calls)
+/// 4. Render type (Quad → Mesh → Environment for optimal early-z, then
+///    BlendedMesh and BlendedQuad)
+/// 5. Depth (blended meshes only - farthest first)
+/// 6. Render state (cull mode)
+/// 7. Textures (minimize bind calls)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub struct CommandSortKey {
     /// Pass ID (highest priority - preserves render pass sequence)
@@ -171,8 +219,10 @@ pub struct CommandSortKey {
     pub viewport: Viewport,
     /// Z-index for 2D ordering (only used for quads, 0 for other commands)
     pub z_index: u32,
-    /// Render type (Quad=0, Mesh=1, Environment=2)
+    /// Render type (Quad=0, Mesh=1, Environment=2, BlendedMesh=3, BlendedQuad=4)
     pub render_type: RenderType,
+    /// Back-to-front order of blended meshes (0 for other commands)
+    pub depth: u32,
     /// Vertex format (for regular pipelines)
     pub vertex_format: u8,
     /// Cull mode (none=0, back=1, front=2)
@@ -189,6 +239,7 @@ impl CommandSortKey {
             viewport,
             z_index: NO_Z_INDEX,
             render_type: RenderType::Environment,
+            depth: 0,
             vertex_format: 0,
             cull_mode: 0,
             textures: [0; 4],
@@ -196,18 +247,27 @@ impl CommandSortKey {
     }
 
     /// Create sort key for a mesh command
+    ///
+    /// Blended meshes sort after the environment, farthest first.
     pub fn mesh(
         pass_id: u32,
         viewport: Viewport,
         vertex_format: u8,
         cull_mode: CullMode,
         textures: [u32; 4],
+        blend: MeshBlend,
     ) -> Self {
+        let (render_type, depth) = if blend.mode == BlendMode::Alpha {
+            (RenderType::Mesh, 0)
+        } else {
+            (RenderType::BlendedMesh, far_to_near(blend.sort_depth))
+        };
         Self {
             pass_id,
             viewport,
             z_index: NO_Z_INDEX,
-            render_type: RenderType::Mesh,
+            render_type,
+            depth,
             vertex_format,
             cull_mode: cull_mode as u8,
             textures,
@@ -215,12 +275,25 @@ impl CommandSortKey {
     }
 
     /// Create sort key for a quad command
-    pub fn quad(pass_id: u32, viewport: Viewport, z_index: u32, textures: [u32; 4]) -> Self {
+    ///
+    /// Blended quads sort after every other command at the same z-index.
+    pub fn quad(
+        pass_id: u32,
+        viewport: Viewport,
+        z_index: u32,
+        blend_mode: BlendMode,
+        textures: [u32; 4],
+    ) -> Self {
         Self {
             pass_id,
             viewport,
             z_index,
-            render_type: RenderType::Quad,
+            render_type: if blend_mode == BlendMode::Alpha {
+                RenderType::Quad
+            } else {
+                RenderType::BlendedQuad
+            },
+            depth: 0,
             vertex_format: 0,
             cull_mode: 0,
             textures,
@@ -228,6 +301,20 @@ impl CommandSortKey {
     }
 }
 
+/// Sort value that orders larger depths first
+///
+/// Maps the float's bits to an unsigned integer with the same ordering, then
+/// inverts it.
+fn far_to_near(depth: f32) -> u32 {
+    let bits = depth.to_bits();
+    let ordered = if bits & 0x8000_0000 != 0 {
+        !bits
+    } else {
+        bits | 0x8000_0000
+    };
+    !ordered
+}
+
 /// Virtual Render Pass for batching immediate-mode draws
 ///
 /// Accumulates draw commands and vertex/index data during the frame,
@@ -302,6 +389,7 @@ impl VirtualRenderPass {
         buffer_index: u32,
         textures: [u32; 4],
         cull_mode: CullMode,
+        blend: MeshBlend,
         viewport: Viewport,
         pass_id: u32,
     ) {
@@ -329,9 +417,10 @@ impl VirtualRenderPass {
             buffer_index,
             textures,
             cull_mode,
+            blend_mode: blend.mode,
             viewport,
             pass_id,
-            sort_key: CommandSortKey::mesh(pass_id, viewport, format, cull_mode, textures),
+            sort_key: CommandSortKey::mesh(pass_id, viewport, format, cull_mode, textures, blend),
         });
     }
 
@@ -348,6 +437,7 @@ impl VirtualRenderPass {
         buffer_index: u32,
         textures: [u32; 4],
         cull_mode: CullMode,
+        blend: MeshBlend,
         viewport: Viewport,
         pass_id: u32,
     ) {
@@ -380,9 +470,10 @@ impl VirtualRenderPass {
             buffer_index,
             textures,
             cull_mode,
+            blend_mode: blend.mode,
             viewport,
             pass_id,
-            sort_key: CommandSortKey::mesh(pass_id, viewport, format, cull_mode, textures),
+            sort_key: CommandSortKey::mesh(pass_id, viewport, format, cull_mode, textures, blend),
         });
     }
 
@@ -401,6 +492,7 @@ impl VirtualRenderPass {
         buffer_index: u32,
         textures: [u32; 4],
         cull_mode: CullMode,
+        blend: MeshBlend,
         viewport: Viewport,
         pass_id: u32,
     ) {
@@ -419,9 +511,17 @@ impl VirtualRenderPass {
                 buffer_index,
                 textures,
                 cull_mode,
+                blend_mode: blend.mode,
                 viewport,
                 pass_id,
-                sort_key: CommandSortKey::mesh(pass_id, viewport, mesh_format, cull_mode, textures),
+                sort_key: CommandSortKey::mesh(
+                    pass_id,
+                    viewport,
+                    mesh_format,
+                    cull_mode,
+                    textures,
+                    blend,
+                ),
             });
         } else {
             self.commands.push(VRPCommand::Mesh {
@@ -431,13 +531,51 @@ impl VirtualRenderPass {
                 buffer_index,
                 textures,
                 cull_mode,
+                blend_mode: blend.mode,
                 viewport,
                 pass_id,
-                sort_key: CommandSortKey::mesh(pass_id, viewport, mesh_format, cull_mode, textures),
+                sort_key: CommandSortKey::mesh(
+                    pass_id,
+                    viewport,
+                    mesh_format,
+                    cull_mode,
+                    textures,
+                    blend,
+                ),
             });
         }
     }
 
+    /// Repeat the last mesh draw as a toon outline pass
+    ///
+    /// The copy reuses the same vertex and index ranges with the outline's
+    /// shading state, culling front faces so only the extruded back shell shows.
+    pub fn record_outline(&mut self, outline_buffer_index: u32) {
+        let Some(mut cmd) = self.commands.last().cloned() else {
+            return;
+        };
+        match &mut cmd {
+            VRPCommand::Mesh {
+                buffer_index,
+                cull_mode,
+                sort_key,
+                ..
+            }
+            | VRPCommand::IndexedMesh {
+                buffer_index,
+                cull_mode,
+                sort_key,
+                ..
+            } => {
+                *buffer_index = outline_buffer_index;
+                *cull_mode = CullMode::Front;
+                sort_key.cull_mode = CullMode::Front as u8;
+            }
+            _ => return,
+        }
+        self.commands.push(cmd);
+    }
+
     /// Get vertex data for a format
     pub fn vertex_data(&self, format: u8) -> &[u8] {
         &self.vertex_data[format as usize]
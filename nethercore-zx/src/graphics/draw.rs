@@ -111,6 +111,8 @@ impl ZXGraphics {
                     textures: batch.textures,
                     is_screen_space: batch.is_screen_space,
                     viewport: batch.viewport,
+                    clip: batch.clip,
+                    blend_mode: batch.blend_mode,
                     pass_id: batch.pass_id,
                     z_index: batch.z_index,
                 });
@@ -145,6 +147,8 @@ impl ZXGraphics {
                         texture_slots,
                         cull_mode: z_state.cull_mode,
                         viewport: batch.viewport,
+                        clip: batch.clip,
+                        blend_mode: batch.blend_mode,
                         pass_id: batch.pass_id,
                         z_index: batch.z_index,
                         is_screen_space: batch.is_screen_space,
@@ -152,6 +156,7 @@ impl ZXGraphics {
                             batch.pass_id,
                             batch.viewport,
                             batch.z_index,
+                            batch.blend_mode,
                             [
                                 texture_slots[0].0,
                                 texture_slots[1].0,
@@ -167,15 +172,47 @@ impl ZXGraphics {
         // The game calls draw_epu() during render(); we capture requests keyed by
         // (viewport, pass_id) so split-screen and multi-pass rendering can draw an environment
         // per pass. Only the last call per key is used.
-        for ((viewport, pass_id), mvp_index) in &z_state.epu_frame_draws {
+        //
+        // Backgrounds whose config and camera match the previous evaluation for their
+        // viewport are drawn from the background cache; changed ones are refilled first.
+        let requests: Vec<_> = z_state
+            .epu_frame_draws
+            .iter()
+            .map(|(&key, &mvp_index)| (key, mvp_index))
+            .collect();
+        let keys: Vec<_> = requests
+            .iter()
+            .map(|&((viewport, _), mvp_index)| (viewport, super::epu::draw_key(z_state, mvp_index)))
+            .collect();
+        let plan = self.epu_background_regions.plan(&keys);
+
+        self.epu_background_fills.clear();
+        for (((viewport, pass_id), mvp_index), draw) in requests.into_iter().zip(plan) {
+            if draw == super::epu::BackgroundDraw::Fill {
+                self.epu_background_fills.push((viewport, mvp_index));
+            }
+            self.command_buffer
+                .add_command(super::command_buffer::VRPCommand::EpuEnvironment {
+                    mvp_index,
+                    viewport,
+                    pass_id,
+                    cached: draw != super::epu::BackgroundDraw::Direct,
+                    sky: None,
+                    sort_key: super::command_buffer::CommandSortKey::environment(pass_id, viewport),
+                });
+        }
+
+        // Cubemap skies from sky_draw() take the EPU background's place for their
+        // (viewport, pass_id), sampling the cubemap instead.
+        for (&(viewport, pass_id), &(mvp_index, cubemap)) in &z_state.sky_frame_draws {
             self.command_buffer
                 .add_command(super::command_buffer::VRPCommand::EpuEnvironment {
-                    mvp_index: *mvp_index,
-                    viewport: *viewport,
-                    pass_id: *pass_id,
-                    sort_key: super::command_buffer::CommandSortKey::environment(
-                        *pass_id, *viewport,
-                    ),
+                    mvp_index,
+                    viewport,
+                    pass_id,
+                    cached: false,
+                    sky: Some(texture_table.resolve(cubemap)),
+                    sort_key: super::command_buffer::CommandSortKey::environment(pass_id, viewport),
                 });
         }
 
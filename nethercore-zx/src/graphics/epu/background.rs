@@ -0,0 +1,276 @@
+//! Cached EPU backgrounds for static screens.
+//!
+//! Drawing the environment background evaluates every EPU layer for every
+//! background pixel, even when nothing about it changed since the last frame
+//! (menus, pause screens, fixed cameras). This module keeps the evaluated
+//! background of each viewport in a render-target-sized texture, keyed by the
+//! environment config, env_id and camera it was drawn with. Unchanged
+//! viewports copy their pixels from the cache; changed ones re-evaluate into
+//! it first.
+
+use glam::Mat4;
+use hashbrown::HashMap;
+
+use super::EpuConfig;
+use crate::graphics::{ShadingStateIndex, Viewport};
+use crate::state::ZXFFIState;
+
+/// How one `draw_epu()` request is drawn this frame
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum BackgroundDraw {
+    /// Evaluate the EPU directly (viewport overlaps another background draw)
+    Direct,
+    /// Re-evaluate into the cache, then draw from it
+    Fill,
+    /// Draw from the cache as evaluated on an earlier frame
+    Cached,
+}
+
+/// Identifies what a cached background region shows.
+///
+/// Hashes the environment config (which includes layer phases), the env_id it
+/// is stored under, and the camera view and projection.
+pub(crate) fn background_key(config: &EpuConfig, env_id: u32, view: &Mat4, proj: &Mat4) -> u64 {
+    use std::hash::{Hash, Hasher};
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    config.layers.hash(&mut hasher);
+    env_id.hash(&mut hasher);
+    for value in view.to_cols_array().iter().chain(&proj.to_cols_array()) {
+        value.to_bits().hash(&mut hasher);
+    }
+    hasher.finish()
+}
+
+/// Key for the `draw_epu()` request recorded at `mvp_index`
+///
+/// Resolves the config the same way the EPU build does: the request's env_id,
+/// else env_id 0, else the built-in default environment.
+pub(crate) fn draw_key(state: &ZXFFIState, mvp_index: u32) -> u64 {
+    let indices = state
+        .mvp_shading_states
+        .get(mvp_index as usize)
+        .copied()
+        .unwrap_or_default();
+    let view = state
+        .view_matrices
+        .get(indices.view_idx as usize)
+        .copied()
+        .unwrap_or(Mat4::IDENTITY);
+    let proj = state
+        .proj_matrices
+        .get(indices.proj_idx as usize)
+        .copied()
+        .unwrap_or(Mat4::IDENTITY);
+    let env_id = state
+        .shading_pool
+        .get(ShadingStateIndex(indices.shading_idx))
+        .map_or(0, |shading| shading.environment_index);
+    let config = state
+        .epu_frame_configs
+        .get(&env_id)
+        .or_else(|| state.epu_frame_configs.get(&0))
+        .copied()
+        .unwrap_or_else(crate::resource_manager::default_environment);
+    background_key(&config, env_id, &view, &proj)
+}
+
+/// Tracks which background each viewport region of the cache texture holds
+#[derive(Default)]
+pub(crate) struct BackgroundRegions {
+    regions: HashMap<Viewport, u64>,
+}
+
+impl BackgroundRegions {
+    /// Decide how to draw this frame's backgrounds, given each request's
+    /// viewport and key, and record the regions that will be refilled.
+    ///
+    /// Requests whose viewport overlaps another request's (including the same
+    /// viewport in another pass) would fight over the same cache pixels, so
+    /// they are evaluated directly.
+    pub fn plan(&mut self, draws: &[(Viewport, u64)]) -> Vec<BackgroundDraw> {
+        let mut plan = Vec::with_capacity(draws.len());
+        for (i, &(viewport, key)) in draws.iter().enumerate() {
+            let shared = draws
+                .iter()
+                .enumerate()
+                .any(|(j, (other, _))| i != j && overlaps(&viewport, other));
+            if shared {
+                plan.push(BackgroundDraw::Direct);
+            } else if self.regions.get(&viewport) == Some(&key) {
+                plan.push(BackgroundDraw::Cached);
+            } else {
+                // Filling overwrites any other cached region sharing these pixels
+                self.regions
+                    .retain(|cached, _| *cached == viewport || !overlaps(cached, &viewport));
+                self.regions.insert(viewport, key);
+                plan.push(BackgroundDraw::Fill);
+            }
+        }
+        plan
+    }
+}
+
+fn overlaps(a: &Viewport, b: &Viewport) -> bool {
+    a.x < b.x + b.width && b.x < a.x + a.width && a.y < b.y + b.height && b.y < a.y + a.height
+}
+
+/// GPU resources for the background cache
+pub(crate) struct BackgroundCacheTarget {
+    _texture: wgpu::Texture,
+    pub view: wgpu::TextureView,
+    /// Texture bind group with the cache in slot 0, for drawing from it
+    pub bind_group: wgpu::BindGroup,
+}
+
+impl BackgroundCacheTarget {
+    pub fn new(
+        device: &wgpu::Device,
+        layout: &wgpu::BindGroupLayout,
+        fallback: &wgpu::TextureView,
+        fallback_array: &wgpu::TextureView,
+        samplers: [&wgpu::Sampler; 2],
+        size: (u32, u32),
+        format: wgpu::TextureFormat,
+    ) -> Self {
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("EPU Background Cache"),
+            size: wgpu::Extent3d {
+                width: size.0,
+                height: size.1,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING,
+            view_formats: &[],
+        });
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("EPU Background Cache Bind Group"),
+            layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::TextureView(&view),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::TextureView(fallback),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: wgpu::BindingResource::TextureView(fallback),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 3,
+                    resource: wgpu::BindingResource::TextureView(fallback),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 4,
+                    resource: wgpu::BindingResource::Sampler(samplers[0]),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 5,
+                    resource: wgpu::BindingResource::Sampler(samplers[1]),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 6,
+                    resource: wgpu::BindingResource::TextureView(fallback_array),
+                },
+            ],
+        });
+
+        Self {
+            _texture: texture,
+            view,
+            bind_group,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LEFT: Viewport = Viewport {
+        x: 0,
+        y: 0,
+        width: 480,
+        height: 540,
+    };
+    const RIGHT: Viewport = Viewport {
+        x: 480,
+        y: 0,
+        width: 480,
+        height: 540,
+    };
+
+    #[test]
+    fn test_static_background_is_reused() {
+        let mut regions = BackgroundRegions::default();
+        let draws = [(Viewport::FULLSCREEN, 7)];
+
+        assert_eq!(regions.plan(&draws), [BackgroundDraw::Fill]);
+        assert_eq!(regions.plan(&draws), [BackgroundDraw::Cached]);
+
+        // Camera or config change refills
+        assert_eq!(
+            regions.plan(&[(Viewport::FULLSCREEN, 8)]),
+            [BackgroundDraw::Fill]
+        );
+        assert_eq!(
+            regions.plan(&[(Viewport::FULLSCREEN, 8)]),
+            [BackgroundDraw::Cached]
+        );
+    }
+
+    #[test]
+    fn test_split_screen_viewports_cache_independently() {
+        let mut regions = BackgroundRegions::default();
+        regions.plan(&[(LEFT, 1), (RIGHT, 2)]);
+
+        // Only the player whose camera moved is re-evaluated
+        assert_eq!(
+            regions.plan(&[(LEFT, 1), (RIGHT, 3)]),
+            [BackgroundDraw::Cached, BackgroundDraw::Fill]
+        );
+    }
+
+    #[test]
+    fn test_overlapping_draws_evaluate_directly() {
+        let mut regions = BackgroundRegions::default();
+
+        // Same viewport drawn in two passes
+        assert_eq!(
+            regions.plan(&[(LEFT, 1), (LEFT, 2)]),
+            [BackgroundDraw::Direct, BackgroundDraw::Direct]
+        );
+
+        // A fullscreen fill overwrites the cached halves
+        regions.plan(&[(LEFT, 1)]);
+        regions.plan(&[(Viewport::FULLSCREEN, 5)]);
+        assert_eq!(regions.plan(&[(LEFT, 1)]), [BackgroundDraw::Fill]);
+    }
+
+    #[test]
+    fn test_background_key_tracks_camera_and_config() {
+        let config = EpuConfig {
+            layers: [[1, 2]; 8],
+        };
+        let view = Mat4::look_at_rh(glam::Vec3::Z, glam::Vec3::ZERO, glam::Vec3::Y);
+        let proj = Mat4::perspective_rh(1.0, 16.0 / 9.0, 0.1, 100.0);
+        let key = background_key(&config, 0, &view, &proj);
+
+        assert_eq!(key, background_key(&config, 0, &view, &proj));
+        assert_ne!(key, background_key(&config, 1, &view, &proj));
+        assert_ne!(key, background_key(&config, 0, &Mat4::IDENTITY, &proj));
+        assert_ne!(key, background_key(&config, 0, &view, &Mat4::IDENTITY));
+        let phased = EpuConfig {
+            layers: [[1, 3]; 8],
+        };
+        assert_ne!(key, background_key(&phased, 0, &view, &proj));
+    }
+}
@@ -4,10 +4,10 @@
 //! with semantic methods for adding bounds and feature layers.
 
 use super::{
-    ApertureParams, AtmosphereParams, BandRadianceParams, CellParams, DecalParams, EpuBlend,
-    EpuConfig, EpuLayer, EpuOpcode, FlowParams, GridParams, LobeRadianceParams, PatchesParams,
-    REGION_ALL, RampParams, ScatterParams, SectorParams, SilhouetteParams, SplitParams,
-    encode_direction_u16, pack_meta5, pack_thresholds,
+    ApertureParams, AtmosphereParams, BandRadianceParams, CelestialParams, CellParams, DecalParams,
+    EpuBlend, EpuConfig, EpuLayer, EpuOpcode, FlashParams, FlowParams, GridParams,
+    LobeRadianceParams, PatchesParams, REGION_ALL, RampParams, ScatterParams, SectorParams,
+    SilhouetteParams, SplitParams, encode_direction_u16, pack_meta5, pack_thresholds,
 };
 
 // =============================================================================
@@ -276,24 +276,24 @@ impl EpuBuilder {
         });
     }
 
-    /// Add a grid pattern (GRID).
+    /// Add a perspective-correct line grid (GRID).
     pub fn grid(&mut self, p: GridParams) {
-        let param_c = ((p.pattern as u8) << 4) | (p.scroll_q & 0x0F);
+        let param_c = ((p.accent_every & 0x0F) << 4) | (p.curvature & 0x0F);
         self.push_feature(EpuLayer {
             opcode: EpuOpcode::Grid,
             region_mask: p.region.to_mask(),
             blend: p.blend,
-            meta5: 0,
+            meta5: pack_meta5(0, p.variant_id),
             color_a: p.color,
-            color_b: [0, 0, 0],
-            alpha_a: 15,
-            alpha_b: 15,
+            color_b: p.accent_color,
+            alpha_a: p.alpha,
+            alpha_b: p.accent_alpha,
             intensity: p.intensity,
-            param_a: p.scale,
-            param_b: p.thickness,
+            param_a: p.spacing,
+            param_b: p.width,
             param_c,
             param_d: p.phase,
-            direction: 0,
+            direction: encode_direction_u16(p.up),
         });
     }
 
@@ -377,4 +377,46 @@ impl EpuBuilder {
             direction: encode_direction_u16(p.sun_dir),
         });
     }
+
+    /// Add a sun, moon or planet (CELESTIAL).
+    pub fn celestial(&mut self, p: CelestialParams) {
+        let flags = (p.rings as u8) | ((p.occluded as u8) << 1);
+        self.push_feature(EpuLayer {
+            opcode: EpuOpcode::Celestial,
+            region_mask: p.region.to_mask(),
+            blend: p.blend,
+            meta5: pack_meta5(flags, p.variant_id),
+            color_a: p.body_color,
+            color_b: p.glow_color,
+            alpha_a: p.alpha,
+            alpha_b: p.glow_alpha,
+            intensity: p.intensity,
+            param_a: p.size,
+            param_b: p.limb_darkening,
+            param_c: p.phase,
+            param_d: p.variant_param,
+            direction: encode_direction_u16(p.dir),
+        });
+    }
+
+    /// Add scheduled lightning strikes (FLASH).
+    pub fn flash(&mut self, p: FlashParams) {
+        let param_c = ((p.branches & 0x0F) << 4) | (p.width & 0x0F);
+        self.push_feature(EpuLayer {
+            opcode: EpuOpcode::Flash,
+            region_mask: p.region.to_mask(),
+            blend: p.blend,
+            meta5: pack_meta5(0, p.variant_id),
+            color_a: p.bolt_color,
+            color_b: p.flash_color,
+            alpha_a: p.alpha,
+            alpha_b: p.flash_alpha,
+            intensity: p.intensity,
+            param_a: p.period,
+            param_b: p.seed,
+            param_c,
+            param_d: p.phase,
+            direction: encode_direction_u16(p.dir),
+        });
+    }
 }
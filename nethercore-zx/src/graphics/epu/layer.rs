@@ -59,6 +59,37 @@ pub enum EpuOpcode {
     LobeRadiance = 0x12,
     /// Region-masked horizon band
     BandRadiance = 0x13,
+    /// Scheduled lightning flashes and bolts
+    Flash = 0x14,
+}
+
+impl EpuOpcode {
+    /// Decode a 5-bit opcode number; undefined opcodes decode as `Nop`.
+    pub fn from_u8(code: u8) -> Self {
+        match code {
+            0x01 => Self::Ramp,
+            0x02 => Self::Sector,
+            0x03 => Self::Silhouette,
+            0x04 => Self::Split,
+            0x05 => Self::Cell,
+            0x06 => Self::Patches,
+            0x07 => Self::Aperture,
+            0x08 => Self::Decal,
+            0x09 => Self::Grid,
+            0x0A => Self::Scatter,
+            0x0B => Self::Flow,
+            0x0C => Self::Trace,
+            0x0D => Self::Veil,
+            0x0E => Self::Atmosphere,
+            0x0F => Self::Plane,
+            0x10 => Self::Celestial,
+            0x11 => Self::Portal,
+            0x12 => Self::LobeRadiance,
+            0x13 => Self::BandRadiance,
+            0x14 => Self::Flash,
+            _ => Self::Nop,
+        }
+    }
 }
 
 // =============================================================================
@@ -334,6 +365,27 @@ pub fn encode_direction_u16(dir: Vec3) -> u16 {
     (u & 0xFF) | ((v & 0xFF) << 8)
 }
 
+/// Decode an octahedral u16 direction back to a unit vector.
+///
+/// Inverse of [`encode_direction_u16`], mirroring the WGSL `decode_dir16`
+/// function in the EPU compute shader.
+#[inline]
+pub fn decode_direction_u16(encoded: u16) -> Vec3 {
+    let u = (encoded & 0xFF) as f32 / 255.0 * 2.0 - 1.0;
+    let v = ((encoded >> 8) & 0xFF) as f32 / 255.0 * 2.0 - 1.0;
+
+    let z = 1.0 - u.abs() - v.abs();
+    let (x, y) = if z < 0.0 {
+        let sign_x = if u >= 0.0 { 1.0 } else { -1.0 };
+        let sign_y = if v >= 0.0 { 1.0 } else { -1.0 };
+        ((1.0 - v.abs()) * sign_x, (1.0 - u.abs()) * sign_y)
+    } else {
+        (u, v)
+    };
+
+    Vec3::new(x, y, z).normalize()
+}
+
 /// Pack ceiling and floor Y thresholds into a single byte.
 ///
 /// Each threshold is a 4-bit value (0..15) that maps to [-1, 1].
@@ -29,20 +29,26 @@
 //! ```
 
 // Submodules for organized runtime code
+mod background;
 mod builder;
 mod cache;
 mod layer;
 mod params;
 mod pipelines;
+mod probe;
 pub mod runtime;
 mod settings;
 mod shaders;
+mod sky;
+mod sun;
+mod transition;
 mod types;
 
 #[cfg(test)]
 mod tests;
 
 // Re-export runtime types
+pub(crate) use background::{BackgroundCacheTarget, BackgroundDraw, BackgroundRegions, draw_key};
 pub use cache::{ActiveEnvList, collect_active_envs};
 pub use runtime::EpuRuntime;
 pub use settings::{
@@ -53,15 +59,27 @@ pub use types::EpuSh9;
 // Re-export layer types (core types, opcodes, enums, encoding utilities)
 pub use layer::{
     EpuBlend, EpuConfig, EpuLayer, EpuOpcode, EpuRegion, REGION_ALL, REGION_FLOOR, REGION_NONE,
-    REGION_SKY, REGION_WALLS, encode_direction_u16, pack_meta5, pack_thresholds,
+    REGION_SKY, REGION_WALLS, decode_direction_u16, encode_direction_u16, pack_meta5,
+    pack_thresholds,
 };
 
+// Re-export transition types
+pub use transition::{EpuEasing, EpuTransition};
+
+// Re-export probe types
+pub use probe::{EnvProbe, MAX_PROBES, PROBE_ENV_BASE, PROBE_REFRESH_TICKS};
+
+// Re-export environment-driven lighting
+pub use sky::{SKY_PROBE_SIZE, SkyProbe};
+pub use sun::EnvSun;
+
 // Re-export builder API
 pub use builder::{EpuBuilder, epu_begin, epu_finish};
 
 // Re-export parameter structs and shape/pattern enums
 pub use params::{
-    ApertureParams, AtmosphereParams, BandRadianceParams, CellParams, DecalParams, DecalShape,
-    FlowParams, FlowPattern, GridParams, GridPattern, LobeRadianceParams, PatchesParams,
-    PhaseWaveform, RampParams, ScatterParams, SectorParams, SilhouetteParams, SplitParams,
+    ApertureParams, AtmosphereParams, BandRadianceParams, CelestialParams, CellParams, DecalParams,
+    DecalShape, FlashParams, FlowParams, FlowPattern, GridParams, LobeRadianceParams,
+    PatchesParams, PhaseWaveform, RampParams, ScatterParams, SectorParams, SilhouetteParams,
+    SplitParams,
 };
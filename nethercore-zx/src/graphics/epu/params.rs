@@ -27,19 +27,6 @@ pub enum DecalShape {
     Line = 3,
 }
 
-/// Grid pattern types
-#[repr(u8)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
-pub enum GridPattern {
-    /// Vertical stripes
-    #[default]
-    Stripes = 0,
-    /// Crosshatch grid
-    Grid = 1,
-    /// Checkerboard
-    Checker = 2,
-}
-
 /// Flow pattern types
 #[repr(u8)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
@@ -388,36 +375,53 @@ pub struct GridParams {
     pub region: EpuRegion,
     /// Blend mode
     pub blend: EpuBlend,
-    /// RGB color for lines
+    /// Up axis; floor lines scroll toward -forward, wall lines downward
+    pub up: Vec3,
+    /// RGB line color
     pub color: [u8; 3],
+    /// RGB accent line color
+    pub accent_color: [u8; 3],
     /// Brightness (0..255)
     pub intensity: u8,
-    /// Grid scale (0..255 maps to 1..64)
-    pub scale: u8,
-    /// Line thickness (0..255 maps to 0.001..0.1)
-    pub thickness: u8,
-    /// Pattern type
-    pub pattern: GridPattern,
-    /// Scroll speed (0..15 maps to 0..2)
-    pub scroll_q: u8,
-    /// Looping animation phase (0..255 maps to 0..1).
+    /// Line spacing in world units with the eye 1 unit above the floor
+    /// (0..255 maps to 0.05..4.0)
+    pub spacing: u8,
+    /// Line width as a fraction of the spacing (0..255 maps to 0.01..0.5)
+    pub width: u8,
+    /// Draw every Nth line in the accent color (0..15, 0 = no accents)
+    pub accent_every: u8,
+    /// Curvature (0..15); FLOOR bends away like a planet, TUBE bends up the sides
+    pub curvature: u8,
+    /// Looping scroll phase (0..255 maps to one accent period, or one line
+    /// without accents).
     ///
     /// Advance this from your game (deterministic) to animate scrolling.
     pub phase: u8,
+    /// Line alpha (0-15)
+    pub alpha: u8,
+    /// Accent line alpha (0-15)
+    pub accent_alpha: u8,
+    /// 0 FLOOR, 1 TUBE, 2 WALLS (cylinder around the eye), 3 SCANLINES (WALLS rows only)
+    pub variant_id: u8,
 }
 
 impl Default for GridParams {
     fn default() -> Self {
         Self {
-            region: EpuRegion::Walls,
+            region: EpuRegion::Floor,
             blend: EpuBlend::Add,
+            up: Vec3::Y,
             color: [64, 64, 64],
+            accent_color: [128, 128, 128],
             intensity: 128,
-            scale: 32,
-            thickness: 20,
-            pattern: GridPattern::Grid,
-            scroll_q: 0,
+            spacing: 32,
+            width: 20,
+            accent_every: 0,
+            curvature: 0,
             phase: 0,
+            alpha: 15,
+            accent_alpha: 15,
+            variant_id: 0,
         }
     }
 }
@@ -576,3 +580,145 @@ impl Default for AtmosphereParams {
         }
     }
 }
+
+/// Parameters for CELESTIAL feature.
+#[derive(Clone, Copy, Debug)]
+pub struct CelestialParams {
+    /// Region mask
+    pub region: EpuRegion,
+    /// Blend mode
+    pub blend: EpuBlend,
+    /// Body center direction
+    pub dir: Vec3,
+    /// RGB body surface color
+    pub body_color: [u8; 3],
+    /// RGB atmosphere/corona/ring color
+    pub glow_color: [u8; 3],
+    /// Brightness (0..255 maps to 0..2)
+    pub intensity: u8,
+    /// Angular size (0..255 maps to 0.5..45 degrees)
+    pub size: u8,
+    /// Limb darkening exponent (0..255 maps to 0.5..4.0)
+    pub limb_darkening: u8,
+    /// Phase angle (0..255 maps to 0..360 degrees; 0 = full, 128 = new).
+    ///
+    /// With `occluded` set this positions the occluder instead
+    /// (0 and 255 = clear of the disk, 128 = centered).
+    pub phase: u8,
+    /// Variant-specific (corona extent, band count, ring tilt, ...)
+    pub variant_param: u8,
+    /// Body alpha (0-15)
+    pub alpha: u8,
+    /// Atmosphere/ring alpha (0-15)
+    pub glow_alpha: u8,
+    /// 0 MOON, 1 SUN, 2 PLANET, 3 GAS_GIANT, 4 RINGED, 5 BINARY, 6 ECLIPSE
+    pub variant_id: u8,
+    /// Add tilted rings around the body
+    pub rings: bool,
+    /// Pass a dark secondary body in front of the disk
+    pub occluded: bool,
+}
+
+impl Default for CelestialParams {
+    fn default() -> Self {
+        Self {
+            region: EpuRegion::Sky,
+            blend: EpuBlend::Add,
+            dir: Vec3::Y,
+            body_color: [255, 255, 255],
+            glow_color: [0, 0, 0],
+            intensity: 128,
+            size: 16,
+            limb_darkening: 64,
+            phase: 0,
+            variant_param: 0,
+            alpha: 15,
+            glow_alpha: 15,
+            variant_id: 0,
+            rings: false,
+            occluded: false,
+        }
+    }
+}
+
+/// Parameters for FLASH feature.
+#[derive(Clone, Copy, Debug)]
+pub struct FlashParams {
+    /// Region mask
+    pub region: EpuRegion,
+    /// Blend mode
+    pub blend: EpuBlend,
+    /// Storm center; strikes land within ~30 degrees of it
+    pub dir: Vec3,
+    /// RGB bolt color
+    pub bolt_color: [u8; 3],
+    /// RGB sky flash color
+    pub flash_color: [u8; 3],
+    /// Brightness (0..255 maps to 0..1)
+    pub intensity: u8,
+    /// Strike window length in phase steps (0 is treated as 1)
+    pub period: u8,
+    /// Strike pattern seed
+    pub seed: u8,
+    /// Bolt fork count (0..15)
+    pub branches: u8,
+    /// Bolt width (0..15 maps to 0.004..0.04)
+    pub width: u8,
+    /// Phase step (0..255).
+    ///
+    /// Advance this once per tick from your game (deterministic); the pattern
+    /// repeats every 256 steps, so change `seed` each cycle for variety.
+    pub phase: u8,
+    /// Bolt alpha (0-15)
+    pub alpha: u8,
+    /// Sky flash alpha (0-15)
+    pub flash_alpha: u8,
+    /// 0 FORK (bolt + flash), 1 SHEET (flash only)
+    pub variant_id: u8,
+}
+
+impl Default for FlashParams {
+    fn default() -> Self {
+        Self {
+            region: EpuRegion::Sky,
+            blend: EpuBlend::Add,
+            dir: Vec3::new(0.0, 0.5, 1.0).normalize(),
+            bolt_color: [255, 255, 255],
+            flash_color: [180, 190, 255],
+            intensity: 255,
+            period: 64,
+            seed: 0,
+            branches: 6,
+            width: 4,
+            phase: 0,
+            alpha: 15,
+            flash_alpha: 12,
+            variant_id: 0,
+        }
+    }
+}
+
+impl FlashParams {
+    /// Steps since the strike in the current window, or `None` before it.
+    ///
+    /// Mirrors the shader's strike schedule, so games can start thunder on the
+    /// step where this returns `Some(0)`.
+    pub fn strike_age(&self) -> Option<u8> {
+        let period = u32::from(self.period.max(1));
+        let phase = u32::from(self.phase);
+        let window = phase / period;
+        let local = phase % period;
+        let offset = flash_hash((u32::from(self.seed) << 8) | window) % period.div_ceil(2);
+        local.checked_sub(offset).map(|age| age as u8)
+    }
+}
+
+/// Integer hash shared with the FLASH shader (lowbias32).
+fn flash_hash(mut x: u32) -> u32 {
+    x ^= x >> 16;
+    x = x.wrapping_mul(0x7feb_352d);
+    x ^= x >> 15;
+    x = x.wrapping_mul(0x846c_a68b);
+    x ^= x >> 16;
+    x
+}
@@ -308,7 +308,10 @@ pub(super) fn create_irrad_pipeline(
     let sh9_buffer = device.create_buffer(&wgpu::BufferDescriptor {
         label: Some("EPU SH9"),
         size: (MAX_ENV_STATES as usize * std::mem::size_of::<EpuSh9>()) as u64,
-        usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_SRC,
+        // COPY_DST: sky_draw() ambient replaces the extracted coefficients
+        usage: wgpu::BufferUsages::STORAGE
+            | wgpu::BufferUsages::COPY_SRC
+            | wgpu::BufferUsages::COPY_DST,
         mapped_at_creation: false,
     });
 
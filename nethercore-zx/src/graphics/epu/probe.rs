@@ -0,0 +1,139 @@
+//! Dynamic environment probes.
+//!
+//! A probe is a point in the world with its own environment slot. Each
+//! capture starts from a source EPU config (the environment selected when the
+//! probe was created) and adds a LOBE_RADIANCE layer for every light that
+//! reaches the probe, so objects bound to it pick up local lighting instead
+//! of the global sky.
+//!
+//! Captures are refreshed every `PROBE_REFRESH_TICKS` ticks. Between
+//! refreshes the previous capture is reused, which keeps the packed config
+//! (and therefore the EPU cache) stable while nothing changes.
+
+use glam::Vec3;
+
+use super::layer::{EpuBlend, EpuConfig, EpuLayer, EpuOpcode, REGION_ALL, encode_direction_u16};
+use super::settings::MAX_ENV_STATES;
+use crate::graphics::{LightType, PackedLight};
+
+/// Maximum number of probes a game can create.
+pub const MAX_PROBES: u32 = 16;
+
+/// Environment slot used by the first probe; probes fill the top of the slot range.
+pub const PROBE_ENV_BASE: u32 = MAX_ENV_STATES - MAX_PROBES;
+
+/// Ticks between captures of the same probe.
+pub const PROBE_REFRESH_TICKS: u64 = 8;
+
+/// Light intensity (0-8) that maps to a full-brightness lobe.
+pub(super) const FULL_LOBE_INTENSITY: f32 = 4.0;
+
+/// Lobe sharpness for captured lights.
+const LOBE_EXPONENT: u8 = 48;
+/// Lobe falloff for captured lights.
+const LOBE_FALLOFF: u8 = 64;
+
+/// A localized lighting probe.
+#[derive(Clone, Copy, Debug)]
+pub struct EnvProbe {
+    /// Capture position in world units
+    pub position: Vec3,
+    /// Capture radius in world units (point lights reaching this sphere contribute)
+    pub radius: f32,
+    /// Environment the capture starts from
+    pub source_env: u32,
+    /// Environment slot the capture is written to
+    pub env_id: u32,
+    /// Last capture and the tick it was taken on
+    pub captured: Option<(EpuConfig, u64)>,
+}
+
+impl EnvProbe {
+    /// Create the probe with index `index` (0-based).
+    pub fn new(index: u32, position: Vec3, radius: f32, source_env: u32) -> Self {
+        Self {
+            position,
+            radius,
+            source_env,
+            env_id: PROBE_ENV_BASE + index,
+            captured: None,
+        }
+    }
+
+    /// Whether the probe has never been captured or its capture is stale at `tick`.
+    pub fn needs_capture(&self, tick: u64) -> bool {
+        match self.captured {
+            None => true,
+            Some((_, at)) => tick < at || tick - at >= PROBE_REFRESH_TICKS,
+        }
+    }
+
+    /// Capture the lighting at the probe: `base` plus one lobe per light that reaches it.
+    ///
+    /// Lobes go into empty (NOP) layer slots, last slot first. Lights that don't
+    /// fit are dropped.
+    pub fn capture(&self, base: &EpuConfig, lights: &[PackedLight; 4]) -> EpuConfig {
+        let mut config = *base;
+        let free: Vec<usize> = (0..config.layers.len())
+            .rev()
+            .filter(|&slot| opcode(config.layers[slot]) == EpuOpcode::Nop as u8)
+            .collect();
+        let mut free_slots = free.into_iter();
+
+        for light in lights.iter().filter(|light| light.is_enabled()) {
+            let Some(lobe) = self.light_lobe(light) else {
+                continue;
+            };
+            let Some(slot) = free_slots.next() else {
+                break;
+            };
+            config.layers[slot] = lobe.encode();
+        }
+        config
+    }
+
+    /// The lobe a light contributes at the probe, or `None` if it doesn't reach.
+    fn light_lobe(&self, light: &PackedLight) -> Option<EpuLayer> {
+        let (toward_light, strength) = match light.get_type() {
+            // Directional lights store the ray direction; the lobe points back at the source
+            LightType::Directional => (-Vec3::from(light.get_direction()), light.get_intensity()),
+            LightType::Point => {
+                let offset = Vec3::from(light.get_position()) - self.position;
+                let range = light.get_range();
+                let distance = (offset.length() - self.radius).max(0.0);
+                if range <= 0.0 || distance >= range {
+                    return None;
+                }
+                let falloff = 1.0 - distance / range;
+                let dir = offset.try_normalize().unwrap_or(Vec3::Y);
+                (dir, light.get_intensity() * falloff * falloff)
+            }
+        };
+
+        let intensity = (strength / FULL_LOBE_INTENSITY * 255.0).clamp(0.0, 255.0) as u8;
+        if intensity == 0 {
+            return None;
+        }
+
+        let color = light.get_color().map(|c| (c.clamp(0.0, 1.0) * 255.0) as u8);
+        Some(EpuLayer {
+            opcode: EpuOpcode::LobeRadiance,
+            region_mask: REGION_ALL,
+            blend: EpuBlend::Add,
+            color_a: color,
+            color_b: [0, 0, 0],
+            alpha_a: 15,
+            alpha_b: 0,
+            intensity,
+            param_a: LOBE_EXPONENT,
+            param_b: LOBE_FALLOFF,
+            direction: encode_direction_u16(toward_light),
+            ..EpuLayer::nop()
+        })
+    }
+}
+
+/// Opcode of a packed layer (`[hi, lo]`).
+fn opcode(layer: [u64; 2]) -> u8 {
+    ((layer[0] >> 59) & 0x1F) as u8
+}
@@ -31,9 +31,10 @@ use super::settings::{
     EPU_INITIAL_LAYERS, EPU_IRRAD_TARGET_SIZE, EpuRuntimeSettings, MAX_ACTIVE_ENVS, MAX_ENV_STATES,
     calc_mip_sizes, choose_irrad_mip_level,
 };
-use super::types::{FrameUniforms, GpuEnvironmentState, IrradUniforms};
+use super::types::{EpuSh9, FrameUniforms, GpuEnvironmentState, IrradUniforms};
 
 use std::sync::atomic::{AtomicU32, Ordering};
+use wgpu::util::DeviceExt;
 
 static EPU_BUILD_DEBUG_COUNT: AtomicU32 = AtomicU32::new(0);
 
@@ -97,6 +98,9 @@ pub struct EpuRuntime {
 
     // Dirty-state cache for skipping unchanged static environments
     cache: EpuCache,
+
+    // Env IDs whose SH9 was replaced by a sky_draw() ambient last frame
+    sky_ambient_envs: Vec<u32>,
 }
 
 impl EpuRuntime {
@@ -168,6 +172,7 @@ impl EpuRuntime {
             irrad_uniforms_buffer,
             irrad_bind_group_layout,
             cache: EpuCache::new(),
+            sky_ambient_envs: Vec::new(),
         }
     }
 
@@ -611,6 +616,46 @@ impl EpuRuntime {
         self.dispatch_irrad_pass(device, queue, encoder, active_count);
     }
 
+    /// Replace the diffuse irradiance of environments with sky ambients.
+    ///
+    /// The copies are recorded into `encoder`, so call this after
+    /// `build_envs()` to land after the SH9 extraction. Environments that had
+    /// a sky ambient last frame but not this one are rebuilt from their EPU
+    /// config on the next `build_envs()`.
+    pub fn apply_sky_ambient(
+        &mut self,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        ambients: &[(u32, EpuSh9)],
+    ) {
+        for &env_id in &self.sky_ambient_envs {
+            if !ambients.iter().any(|(id, _)| *id == env_id) {
+                self.cache.invalidate(env_id);
+            }
+        }
+        self.sky_ambient_envs.clear();
+
+        let size = std::mem::size_of::<EpuSh9>() as u64;
+        for (env_id, sh9) in ambients {
+            if *env_id >= MAX_ENV_STATES {
+                continue;
+            }
+            let source = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("EPU Sky Ambient SH9"),
+                contents: bytemuck::bytes_of(sh9),
+                usage: wgpu::BufferUsages::COPY_SRC,
+            });
+            encoder.copy_buffer_to_buffer(
+                &source,
+                0,
+                &self.sh9_buffer,
+                *env_id as u64 * size,
+                size,
+            );
+            self.sky_ambient_envs.push(*env_id);
+        }
+    }
+
     // =========================================================================
     // Accessors
     // =========================================================================
@@ -43,7 +43,7 @@ pub(super) const EPU_BOUNDS: &str = concat!(
     )),
 );
 
-/// EPU feature opcodes (DECAL, GRID, SCATTER, FLOW, TRACE, VEIL, ATMOSPHERE, PLANE, CELESTIAL, PORTAL, LOBE_RADIANCE, BAND_RADIANCE) + dispatch entry.
+/// EPU feature opcodes (DECAL, GRID, SCATTER, FLOW, TRACE, VEIL, ATMOSPHERE, PLANE, CELESTIAL, PORTAL, LOBE_RADIANCE, BAND_RADIANCE, FLASH) + dispatch entry.
 pub(super) const EPU_FEATURES: &str = concat!(
     include_str!(concat!(
         env!("CARGO_MANIFEST_DIR"),
@@ -93,6 +93,10 @@ pub(super) const EPU_FEATURES: &str = concat!(
         env!("CARGO_MANIFEST_DIR"),
         "/shaders/epu/features/11_band_radiance.wgsl"
     )),
+    include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/shaders/epu/features/12_flash.wgsl"
+    )),
     include_str!(concat!(
         env!("CARGO_MANIFEST_DIR"),
         "/shaders/epu/epu_dispatch.wgsl"
@@ -0,0 +1,129 @@
+//! Cubemap skies as an ambient light source.
+//!
+//! `sky_draw()` can light the scene from the sky it draws instead of from the
+//! EPU. Each cubemap is reduced to a few texels per face when it is loaded;
+//! those texels are projected onto SH9 in the sky's rotation, using the same
+//! basis and Lambertian kernel as the EPU irradiance pass, and the result
+//! replaces that pass's output for the environment slot.
+
+use glam::{Mat3, Vec3};
+use zx_common::{CUBEMAP_FACES, cube_face_direction};
+
+use super::types::EpuSh9;
+
+/// Face size of the reduced cubemap used for ambient lighting
+pub const SKY_PROBE_SIZE: u32 = 8;
+
+/// Low-resolution copy of a cubemap for ambient lighting
+#[derive(Clone, Debug)]
+pub struct SkyProbe {
+    /// Face width and height in texels
+    size: u32,
+    /// Average color of each texel (0-1), face by face, top row first
+    texels: Vec<Vec3>,
+}
+
+impl SkyProbe {
+    /// Box-filter six RGBA8 faces of `face_size` pixels down to [`SKY_PROBE_SIZE`]
+    ///
+    /// `data` must hold all six faces back to back.
+    pub fn new(face_size: u32, data: &[u8]) -> Self {
+        let size = SKY_PROBE_SIZE.min(face_size).max(1);
+        let face_len = (face_size * face_size * 4) as usize;
+        let mut texels = Vec::with_capacity(size as usize * size as usize * CUBEMAP_FACES);
+
+        for face in data.chunks_exact(face_len).take(CUBEMAP_FACES) {
+            for ty in 0..size {
+                for tx in 0..size {
+                    let (x0, x1) = (tx * face_size / size, (tx + 1) * face_size / size);
+                    let (y0, y1) = (ty * face_size / size, (ty + 1) * face_size / size);
+                    let mut sum = Vec3::ZERO;
+                    for y in y0..y1 {
+                        for x in x0..x1 {
+                            let i = ((y * face_size + x) * 4) as usize;
+                            sum +=
+                                Vec3::new(face[i] as f32, face[i + 1] as f32, face[i + 2] as f32);
+                        }
+                    }
+                    let count = ((x1 - x0) * (y1 - y0)).max(1) as f32;
+                    texels.push(sum / (count * 255.0));
+                }
+            }
+        }
+
+        Self { size, texels }
+    }
+
+    /// Diffuse irradiance of the sky rotated `yaw_deg` about +Y, scaled by `intensity`
+    pub fn irradiance(&self, yaw_deg: f32, intensity: f32) -> EpuSh9 {
+        let rotation = Mat3::from_rotation_y(yaw_deg.to_radians());
+        let size = self.size as f32;
+
+        let mut coeffs = [Vec3::ZERO; 9];
+        let mut total_weight = 0.0;
+        for (i, &radiance) in self.texels.iter().enumerate() {
+            let face = i / (self.size * self.size) as usize;
+            let texel = i as u32 % (self.size * self.size);
+            let u = ((texel % self.size) as f32 + 0.5) / size;
+            let v = ((texel / self.size) as f32 + 0.5) / size;
+
+            // Solid angle of the texel on the unit cube
+            let (s, t) = (2.0 * u - 1.0, 2.0 * v - 1.0);
+            let weight = (4.0 / (size * size)) / (1.0 + s * s + t * t).powf(1.5);
+            total_weight += weight;
+
+            let dir = rotation * cube_face_direction(face, u, v);
+            for (c, basis) in coeffs.iter_mut().zip(sh9_basis(dir)) {
+                *c += radiance * (basis * weight);
+            }
+        }
+        if total_weight <= 0.0 {
+            return bytemuck::Zeroable::zeroed();
+        }
+
+        // Normalize the texel weights to the full sphere, then apply the
+        // Lambertian convolution per band (matches epu_compute_irrad.wgsl)
+        let norm = 4.0 * std::f32::consts::PI / total_weight * intensity;
+        let band = [
+            std::f32::consts::PI,
+            2.0 * std::f32::consts::PI / 3.0,
+            std::f32::consts::PI / 4.0,
+        ];
+        let scale = |k: usize| {
+            let l = match k {
+                0 => 0,
+                1..=3 => 1,
+                _ => 2,
+            };
+            (coeffs[k] * norm * band[l]).to_array()
+        };
+
+        let mut sh9: EpuSh9 = bytemuck::Zeroable::zeroed();
+        sh9.c0 = scale(0);
+        sh9.c1 = scale(1);
+        sh9.c2 = scale(2);
+        sh9.c3 = scale(3);
+        sh9.c4 = scale(4);
+        sh9.c5 = scale(5);
+        sh9.c6 = scale(6);
+        sh9.c7 = scale(7);
+        sh9.c8 = scale(8);
+        sh9
+    }
+}
+
+/// Real SH basis (L2) in EPU order: Y00, Y1-1, Y10, Y11, Y2-2, Y2-1, Y20, Y21, Y22
+pub(super) fn sh9_basis(dir: Vec3) -> [f32; 9] {
+    let Vec3 { x, y, z } = dir;
+    [
+        0.282095,
+        0.488603 * y,
+        0.488603 * z,
+        0.488603 * x,
+        1.092548 * x * y,
+        1.092548 * y * z,
+        0.315392 * (3.0 * z * z - 1.0),
+        1.092548 * x * z,
+        0.546274 * (x * x - y * y),
+    ]
+}
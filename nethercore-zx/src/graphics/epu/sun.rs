@@ -0,0 +1,79 @@
+//! Sun extraction for environment-driven lighting.
+//!
+//! Finds the brightest directional feature in the sky of a packed config (a
+//! LOBE_RADIANCE glow or a DECAL sun disk) and turns it into a directional
+//! light, so `light_from_env()` can keep scene lighting in step with the
+//! background. Intensity uses the same scale probes use when they turn lights
+//! into lobes, so a light captured into a probe maps back to itself.
+
+use glam::Vec3;
+
+use super::layer::{EpuBlend, EpuConfig, EpuOpcode, REGION_SKY, decode_direction_u16};
+use super::probe::FULL_LOBE_INTENSITY;
+
+/// A directional light derived from an environment.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EnvSun {
+    /// Light ray direction (from the sun toward the scene, normalized)
+    pub direction: Vec3,
+    /// Light color, scaled so the brightest channel is 1.0
+    pub color: [f32; 3],
+    /// Intensity multiplier (0-4)
+    pub intensity: f32,
+}
+
+impl EnvSun {
+    /// The sun of `config`: the brightest LOBE_RADIANCE or DECAL layer that
+    /// adds light to the sky region.
+    ///
+    /// Brightness is intensity x primary alpha x brightest color channel. Ties
+    /// go to the earlier layer. Returns `None` if no layer qualifies.
+    pub fn from_config(config: &EpuConfig) -> Option<Self> {
+        let mut best: Option<(f32, Self)> = None;
+
+        for &[hi, lo] in &config.layers {
+            let opcode = EpuOpcode::from_u8(((hi >> 59) & 0x1F) as u8);
+            let region = ((hi >> 56) & 0x7) as u8;
+            let blend = ((hi >> 53) & 0x7) as u8;
+            if !matches!(opcode, EpuOpcode::LobeRadiance | EpuOpcode::Decal)
+                || region & REGION_SKY == 0
+                || !emits_light(blend)
+            {
+                continue;
+            }
+
+            let color_a = (hi >> 24) & 0xFF_FFFF;
+            let rgb = [16, 8, 0].map(|shift| ((color_a >> shift) & 0xFF) as f32 / 255.0);
+            let peak = rgb[0].max(rgb[1]).max(rgb[2]);
+            let intensity = (lo >> 56) as f32 / 255.0;
+            let alpha = ((lo >> 4) & 0xF) as f32 / 15.0;
+            let brightness = intensity * alpha * peak;
+            if brightness <= 0.0 || best.is_some_and(|(b, _)| brightness <= b) {
+                continue;
+            }
+
+            // Layers store the direction toward the feature; lights store the ray direction
+            let toward = decode_direction_u16(((lo >> 8) & 0xFFFF) as u16);
+            let sun = Self {
+                direction: -toward,
+                color: rgb.map(|c| c / peak),
+                intensity: brightness * FULL_LOBE_INTENSITY,
+            };
+            best = Some((brightness, sun));
+        }
+
+        best.map(|(_, sun)| sun)
+    }
+}
+
+/// Whether a layer with this blend mode brightens what's behind it
+fn emits_light(blend: u8) -> bool {
+    [
+        EpuBlend::Add,
+        EpuBlend::Max,
+        EpuBlend::Lerp,
+        EpuBlend::Screen,
+    ]
+    .iter()
+    .any(|&b| b as u8 == blend)
+}
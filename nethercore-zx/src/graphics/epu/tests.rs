@@ -1,6 +1,7 @@
 //! Unit tests for EPU Rust API (128-bit format)
 
 use super::*;
+use crate::graphics::PackedLight;
 use glam::Vec3;
 
 // =============================================================================
@@ -522,6 +523,37 @@ fn test_builder_decal() {
     assert_eq!(size, 12);
 }
 
+#[test]
+fn test_builder_celestial_flags() {
+    let mut builder = epu_begin();
+    builder.celestial(CelestialParams {
+        dir: Vec3::new(0.3, 0.6, -0.7),
+        body_color: [230, 235, 245],
+        size: 40,
+        phase: 96,
+        variant_id: 0, // MOON
+        rings: true,
+        occluded: true,
+        ..Default::default()
+    });
+    let config = epu_finish(builder);
+
+    let [hi, lo] = config.layers[4];
+
+    let opcode = (hi >> 59) & 0x1F;
+    assert_eq!(opcode, EpuOpcode::Celestial as u64);
+
+    // Flags live in the domain bits, variant in the low bits
+    let meta5 = (hi >> 48) & 0x1F;
+    assert_eq!(meta5 >> 3, 0b11);
+    assert_eq!(meta5 & 0x7, 0);
+
+    let param_a = (lo >> 48) & 0xFF;
+    let param_c = (lo >> 32) & 0xFF;
+    assert_eq!(param_a, 40);
+    assert_eq!(param_c, 96);
+}
+
 #[test]
 fn test_builder_scatter() {
     let mut builder = epu_begin();
@@ -560,14 +592,14 @@ fn test_builder_grid() {
     let mut builder = epu_begin();
     builder.grid(GridParams {
         region: EpuRegion::Walls,
-        blend: EpuBlend::Add,
-        color: [64, 64, 64],
-        intensity: 128,
-        scale: 32,
-        thickness: 20,
-        pattern: GridPattern::Grid,
-        scroll_q: 5,
-        phase: 0,
+        accent_color: [255, 0, 255],
+        spacing: 32,
+        width: 20,
+        accent_every: 4,
+        curvature: 5,
+        accent_alpha: 9,
+        variant_id: 1, // TUBE
+        ..Default::default()
     });
     let config = epu_finish(builder);
 
@@ -579,13 +611,24 @@ fn test_builder_grid() {
     let region = (hi >> 56) & 0x7;
     assert_eq!(region, REGION_WALLS as u64);
 
+    let meta5 = (hi >> 48) & 0x1F;
+    assert_eq!(meta5 & 0x7, 1);
+
+    // Accent color in color_b
+    assert_eq!(hi & 0xFFFFFF, 0xFF00FF);
+
     // Check param_c packing
     let param_c = (lo >> 32) & 0xFF;
-    let pattern = (param_c >> 4) & 0x0F;
-    let scroll = param_c & 0x0F;
+    let accent_every = (param_c >> 4) & 0x0F;
+    let curvature = param_c & 0x0F;
 
-    assert_eq!(pattern, GridPattern::Grid as u64);
-    assert_eq!(scroll, 5);
+    assert_eq!(accent_every, 4);
+    assert_eq!(curvature, 5);
+
+    // Up axis in the direction field, accent alpha in alpha_b
+    let direction = ((lo >> 8) & 0xFFFF) as u16;
+    assert!(decode_direction_u16(direction).dot(Vec3::Y) > 0.99);
+    assert_eq!(lo & 0xF, 9);
 }
 
 #[test]
@@ -626,6 +669,75 @@ fn test_builder_flow() {
     assert_eq!(pattern, FlowPattern::Caustic as u64);
 }
 
+#[test]
+fn test_builder_flash() {
+    let mut builder = epu_begin();
+    builder.flash(FlashParams {
+        seed: 7,
+        branches: 9,
+        width: 3,
+        phase: 40,
+        variant_id: 1,
+        ..Default::default()
+    });
+    let config = epu_finish(builder);
+
+    let [hi, lo] = config.layers[4];
+
+    let opcode = (hi >> 59) & 0x1F;
+    assert_eq!(opcode, EpuOpcode::Flash as u64);
+    assert_eq!(EpuOpcode::from_u8(0x14), EpuOpcode::Flash);
+
+    // variant_id in meta5
+    let meta5 = (hi >> 48) & 0x1F;
+    assert_eq!(meta5, 1);
+
+    assert_eq!((lo >> 48) & 0xFF, 64); // period
+    assert_eq!((lo >> 40) & 0xFF, 7); // seed
+    assert_eq!((lo >> 32) & 0xFF, 0x93); // branches | width
+    assert_eq!((lo >> 24) & 0xFF, 40); // phase
+}
+
+#[test]
+fn test_flash_strike_schedule() {
+    let params = |seed, phase| FlashParams {
+        period: 32,
+        seed,
+        phase,
+        ..Default::default()
+    };
+
+    // One strike per window, in the first half, counting up one step at a time
+    for window in 0..8u8 {
+        let start = window * 32;
+        let strikes: Vec<u8> = (start..=start + 31)
+            .filter(|&phase| params(3, phase).strike_age() == Some(0))
+            .collect();
+        assert_eq!(strikes.len(), 1);
+        assert!(strikes[0] - start < 16);
+
+        let end = params(3, start + 31).strike_age().unwrap();
+        assert_eq!(u32::from(end), u32::from(start + 31 - strikes[0]));
+    }
+
+    // Same inputs, same strikes; different seeds move them
+    let schedule = |seed| -> Vec<Option<u8>> {
+        (0..=255)
+            .map(|phase| params(seed, phase).strike_age())
+            .collect()
+    };
+    assert_eq!(schedule(3), schedule(3));
+    assert_ne!(schedule(3), schedule(4));
+
+    // Period 0 behaves like period 1: a strike on every step
+    let every_step = FlashParams {
+        period: 0,
+        phase: 17,
+        ..Default::default()
+    };
+    assert_eq!(every_step.strike_age(), Some(0));
+}
+
 #[test]
 fn test_builder_slot_allocation() {
     let mut builder = epu_begin();
@@ -928,3 +1040,280 @@ fn test_blend_mode_values() {
     assert_eq!(EpuBlend::Min as u8, 6);
     assert_eq!(EpuBlend::Overlay as u8, 7);
 }
+
+// =============================================================================
+// Transition Tests
+// =============================================================================
+
+fn lobe_layer(color: [u8; 3], intensity: u8, dir: Vec3) -> EpuLayer {
+    EpuLayer {
+        opcode: EpuOpcode::LobeRadiance,
+        color_a: color,
+        color_b: color,
+        intensity,
+        param_a: 40,
+        direction: encode_direction_u16(dir),
+        ..EpuLayer::nop()
+    }
+}
+
+fn config_with(layer: EpuLayer) -> EpuConfig {
+    let mut layers = [EpuLayer::nop().encode(); 8];
+    layers[4] = layer.encode();
+    EpuConfig { layers }
+}
+
+#[test]
+fn test_config_lerp_endpoints() {
+    let a = config_with(lobe_layer([255, 128, 0], 200, Vec3::Y));
+    let b = config_with(lobe_layer([0, 64, 255], 50, Vec3::X));
+
+    assert_eq!(a.lerp(&b, 0.0).layers, a.layers);
+    assert_eq!(a.lerp(&b, 1.0).layers, b.layers);
+}
+
+#[test]
+fn test_config_lerp_midpoint_fields() {
+    let a = config_with(lobe_layer([200, 0, 100], 200, Vec3::Y));
+    let b = config_with(lobe_layer([0, 200, 100], 100, Vec3::Y));
+
+    let [hi, lo] = a.lerp(&b, 0.5).layers[4];
+    assert_eq!((hi >> 24) & 0xFF_FFFF, 0x646464); // color_a
+    assert_eq!(hi & 0xFF_FFFF, 0x646464); // color_b
+    assert_eq!(lo >> 56, 150); // intensity
+    assert_eq!((lo >> 48) & 0xFF, 40); // param_a unchanged
+    assert_eq!((hi >> 59) & 0x1F, EpuOpcode::LobeRadiance as u64);
+}
+
+#[test]
+fn test_config_lerp_direction_stays_on_sphere() {
+    let a = config_with(lobe_layer([255; 3], 255, Vec3::X));
+    let b = config_with(lobe_layer([255; 3], 255, Vec3::Y));
+
+    let [_, lo] = a.lerp(&b, 0.5).layers[4];
+    let dir = decode_direction_u16(((lo >> 8) & 0xFFFF) as u16);
+    let expected = Vec3::new(1.0, 1.0, 0.0).normalize();
+    assert!(dir.dot(expected) > 0.99, "got {:?}", dir);
+}
+
+#[test]
+fn test_config_lerp_fades_in_new_layer() {
+    let a = config_with(EpuLayer::nop());
+    let b = config_with(lobe_layer([255; 3], 255, Vec3::Y));
+
+    let [hi, lo] = a.lerp(&b, 0.2).layers[4];
+    // Takes the new layer's shape immediately, with reduced alpha
+    assert_eq!((hi >> 59) & 0x1F, EpuOpcode::LobeRadiance as u64);
+    assert_eq!((lo >> 4) & 0xF, 3);
+    assert_eq!(lo & 0xF, 3);
+}
+
+#[test]
+fn test_decode_direction_roundtrip() {
+    for dir in [Vec3::X, Vec3::Y, Vec3::Z, -Vec3::X, -Vec3::Y, -Vec3::Z] {
+        let decoded = decode_direction_u16(encode_direction_u16(dir));
+        assert!(decoded.dot(dir) > 0.99, "{:?} -> {:?}", dir, decoded);
+    }
+}
+
+#[test]
+fn test_transition_progress_and_easing() {
+    let from = config_with(lobe_layer([0; 3], 0, Vec3::Y));
+    let to = config_with(lobe_layer([255; 3], 255, Vec3::Y));
+    let transition = EpuTransition {
+        from,
+        to,
+        start_tick: 100,
+        duration_ticks: 10,
+        easing: EpuEasing::EaseInOut,
+    };
+
+    assert_eq!(transition.progress(90), 0.0);
+    assert_eq!(transition.progress(105), 0.5);
+    assert!(transition.progress(102) < 0.2);
+    assert!(!transition.is_finished(109));
+    assert!(transition.is_finished(110));
+    assert_eq!(transition.sample(100).layers, from.layers);
+    assert_eq!(transition.sample(200).layers, to.layers);
+
+    assert_eq!(EpuEasing::from_u32(3), Some(EpuEasing::EaseInOut));
+    assert_eq!(EpuEasing::from_u32(4), None);
+}
+
+// =============================================================================
+// Probe Tests
+// =============================================================================
+
+fn probe_lights(first: PackedLight) -> [PackedLight; 4] {
+    [
+        first,
+        PackedLight::disabled(),
+        PackedLight::disabled(),
+        PackedLight::disabled(),
+    ]
+}
+
+fn layer_opcode(layer: [u64; 2]) -> u8 {
+    ((layer[0] >> 59) & 0x1F) as u8
+}
+
+#[test]
+fn test_probe_slots_and_refresh() {
+    let probe = EnvProbe::new(2, Vec3::ZERO, 1.0, 0);
+    assert_eq!(probe.env_id, PROBE_ENV_BASE + 2);
+    const { assert!(PROBE_ENV_BASE + MAX_PROBES <= MAX_ENV_STATES) };
+    assert!(probe.needs_capture(0));
+
+    let captured = EnvProbe {
+        captured: Some((EpuConfig::default(), 10)),
+        ..probe
+    };
+    assert!(!captured.needs_capture(10 + PROBE_REFRESH_TICKS - 1));
+    assert!(captured.needs_capture(10 + PROBE_REFRESH_TICKS));
+}
+
+#[test]
+fn test_probe_captures_point_light_in_range() {
+    let base = config_with(lobe_layer([10, 10, 10], 100, Vec3::Y));
+    let probe = EnvProbe::new(0, Vec3::ZERO, 1.0, 0);
+    let light = PackedLight::point(Vec3::new(3.0, 0.0, 0.0), Vec3::ONE, 4.0, 4.0, true);
+
+    let config = probe.capture(&base, &probe_lights(light));
+
+    // Existing layers are kept, the light goes into the last free slot
+    assert_eq!(config.layers[4], base.layers[4]);
+    assert_eq!(
+        layer_opcode(config.layers[7]),
+        EpuOpcode::LobeRadiance as u8
+    );
+    let dir = decode_direction_u16(((config.layers[7][1] >> 8) & 0xFFFF) as u16);
+    assert!(dir.dot(Vec3::X) > 0.95, "{:?}", dir);
+}
+
+#[test]
+fn test_probe_ignores_distant_and_disabled_lights() {
+    let base = config_with(lobe_layer([10, 10, 10], 100, Vec3::Y));
+    let probe = EnvProbe::new(0, Vec3::ZERO, 1.0, 0);
+
+    let far = PackedLight::point(Vec3::new(20.0, 0.0, 0.0), Vec3::ONE, 4.0, 4.0, true);
+    assert_eq!(probe.capture(&base, &probe_lights(far)).layers, base.layers);
+
+    let off = PackedLight::directional(-Vec3::Y, Vec3::ONE, 4.0, false);
+    assert_eq!(probe.capture(&base, &probe_lights(off)).layers, base.layers);
+
+    // A full stack has no room for lights
+    let full = EpuConfig {
+        layers: [lobe_layer([10, 10, 10], 100, Vec3::Y).encode(); 8],
+    };
+    let sun = PackedLight::directional(-Vec3::Y, Vec3::ONE, 4.0, true);
+    assert_eq!(probe.capture(&full, &probe_lights(sun)).layers, full.layers);
+}
+
+// =============================================================================
+// Sun Tests
+// =============================================================================
+
+#[test]
+fn test_env_sun_picks_brightest_sky_layer() {
+    let mut layers = [EpuLayer::nop().encode(); 8];
+    layers[4] = lobe_layer([60, 60, 60], 100, Vec3::X).encode();
+    layers[5] = lobe_layer([255, 128, 0], 200, Vec3::new(0.0, 1.0, 1.0)).encode();
+    // Brighter, but floor-only (e.g. a lava glow)
+    layers[6] = lobe_layer([255, 255, 255], 255, -Vec3::Y)
+        .with_region(EpuRegion::Floor)
+        .encode();
+    // Brighter, but absorbs light
+    layers[7] = EpuLayer {
+        blend: EpuBlend::Multiply,
+        ..lobe_layer([255, 255, 255], 255, Vec3::Y)
+    }
+    .encode();
+
+    let sun = EnvSun::from_config(&EpuConfig { layers }).unwrap();
+    let expected = -Vec3::new(0.0, 1.0, 1.0).normalize();
+    assert!(sun.direction.dot(expected) > 0.99, "{:?}", sun.direction);
+    assert_eq!(sun.color[0], 1.0);
+    assert!((sun.color[1] - 128.0 / 255.0).abs() < 1e-6);
+    assert!((sun.intensity - 200.0 / 255.0 * 4.0).abs() < 1e-4);
+}
+
+#[test]
+fn test_env_sun_none_without_sky_features() {
+    assert_eq!(EnvSun::from_config(&EpuConfig::default()), None);
+
+    let mut builder = epu_begin();
+    builder.ramp_bounds(RampParams::default());
+    assert_eq!(EnvSun::from_config(&epu_finish(builder)), None);
+}
+
+#[test]
+fn test_env_sun_roundtrips_probe_light() {
+    // A directional light captured into a probe maps back to itself
+    let probe = EnvProbe::new(0, Vec3::ZERO, 1.0, 0);
+    let ray = Vec3::new(1.0, -2.0, 0.5).normalize();
+    let light = PackedLight::directional(ray, Vec3::new(1.0, 0.5, 0.25), 2.0, true);
+    let config = probe.capture(&EpuConfig::default(), &probe_lights(light));
+
+    let sun = EnvSun::from_config(&config).unwrap();
+    assert!(sun.direction.dot(ray) > 0.99, "{:?}", sun.direction);
+    assert!((sun.intensity - 2.0).abs() < 0.05, "{}", sun.intensity);
+    assert!((sun.color[1] - 0.5).abs() < 0.02, "{:?}", sun.color);
+}
+
+// =============================================================================
+// Sky Ambient Tests
+// =============================================================================
+
+/// Evaluate SH9 irradiance at a normal (as the shading shaders do)
+fn eval_sh9(sh9: &EpuSh9, n: Vec3) -> Vec3 {
+    let c = [
+        sh9.c0, sh9.c1, sh9.c2, sh9.c3, sh9.c4, sh9.c5, sh9.c6, sh9.c7, sh9.c8,
+    ];
+    c.iter()
+        .zip(sky::sh9_basis(n))
+        .map(|(c, basis)| Vec3::from(*c) * basis)
+        .sum()
+}
+
+/// Six `size` x `size` RGBA8 faces, each filled with one gray level
+fn cube_faces(size: u32, levels: [u8; 6]) -> Vec<u8> {
+    levels
+        .iter()
+        .flat_map(|&level| [level, level, level, 255].repeat((size * size) as usize))
+        .collect()
+}
+
+#[test]
+fn test_sky_probe_uniform_sky() {
+    // A uniform sky of radiance 1 gives irradiance pi from every direction
+    let probe = SkyProbe::new(32, &cube_faces(32, [255; 6]));
+    let sh9 = probe.irradiance(0.0, 1.0);
+    for n in [
+        Vec3::Y,
+        -Vec3::Y,
+        Vec3::X,
+        Vec3::new(1.0, 1.0, -1.0).normalize(),
+    ] {
+        let e = eval_sh9(&sh9, n);
+        assert!((e.x - std::f32::consts::PI).abs() < 0.05, "{n}: {e}");
+    }
+
+    // Intensity scales linearly
+    let half = eval_sh9(&probe.irradiance(0.0, 0.5), Vec3::Y);
+    assert!((half.x - std::f32::consts::PI * 0.5).abs() < 0.05);
+}
+
+#[test]
+fn test_sky_probe_follows_rotation() {
+    // Only the +X face is lit
+    let probe = SkyProbe::new(4, &cube_faces(4, [255, 0, 0, 0, 0, 0]));
+
+    let sh9 = probe.irradiance(0.0, 1.0);
+    assert!(eval_sh9(&sh9, Vec3::X).x > eval_sh9(&sh9, -Vec3::X).x * 4.0);
+
+    // Turning the sky 90 degrees about +Y moves +X to -Z
+    let rotated = probe.irradiance(90.0, 1.0);
+    let toward = eval_sh9(&rotated, -Vec3::Z).x;
+    assert!(toward > eval_sh9(&rotated, Vec3::X).x * 2.0);
+    assert!((toward - eval_sh9(&sh9, Vec3::X).x).abs() < 0.05);
+}
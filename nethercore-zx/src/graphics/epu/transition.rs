@@ -0,0 +1,206 @@
+//! Timed crossfades between EPU configurations.
+//!
+//! A transition blends two packed configs layer by layer. Colors, alphas and
+//! intensity are interpolated per channel and directions are interpolated on
+//! the sphere, so a sun can sweep across the sky while its tint shifts. Fields
+//! that have no meaningful in-between (opcode, region, blend, meta and the
+//! opcode-specific params) switch over at the halfway point.
+//!
+//! Layers that only exist on one side (the other side is a NOP) fade in or out
+//! through zero alpha instead of popping.
+
+use super::layer::{EpuConfig, EpuOpcode, decode_direction_u16, encode_direction_u16};
+
+// Packed field positions (see `EpuLayer::encode`)
+const HI_HEADER_MASK: u64 = 0xFFFF_0000_0000_0000;
+const HI_COLOR_A_SHIFT: u32 = 24;
+const HI_COLOR_MASK: u64 = 0xFF_FFFF;
+const LO_INTENSITY_SHIFT: u32 = 56;
+const LO_PARAMS_MASK: u64 = 0x00FF_FFFF_FF00_0000;
+const LO_DIRECTION_SHIFT: u32 = 8;
+const LO_ALPHA_A_SHIFT: u32 = 4;
+
+/// Easing curve applied to transition progress.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum EpuEasing {
+    /// Constant speed
+    #[default]
+    Linear = 0,
+    /// Start slow, end fast (quadratic)
+    EaseIn = 1,
+    /// Start fast, end slow (quadratic)
+    EaseOut = 2,
+    /// Slow at both ends (smoothstep)
+    EaseInOut = 3,
+}
+
+impl EpuEasing {
+    /// Convert from the FFI value, returning `None` for unknown curves
+    pub fn from_u32(value: u32) -> Option<Self> {
+        match value {
+            0 => Some(Self::Linear),
+            1 => Some(Self::EaseIn),
+            2 => Some(Self::EaseOut),
+            3 => Some(Self::EaseInOut),
+            _ => None,
+        }
+    }
+
+    /// Map linear progress `t` (0-1) through the curve
+    #[inline]
+    pub fn apply(self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Self::Linear => t,
+            Self::EaseIn => t * t,
+            Self::EaseOut => t * (2.0 - t),
+            Self::EaseInOut => t * t * (3.0 - 2.0 * t),
+        }
+    }
+}
+
+/// An in-flight crossfade for one environment slot.
+#[derive(Clone, Copy, Debug)]
+pub struct EpuTransition {
+    /// Config at the start of the transition
+    pub from: EpuConfig,
+    /// Config at the end of the transition
+    pub to: EpuConfig,
+    /// Tick the transition started on
+    pub start_tick: u64,
+    /// Length of the transition in ticks (at least 1)
+    pub duration_ticks: u32,
+    /// Easing curve
+    pub easing: EpuEasing,
+}
+
+impl EpuTransition {
+    /// Eased progress (0-1) at `tick`
+    pub fn progress(&self, tick: u64) -> f32 {
+        let elapsed = tick.saturating_sub(self.start_tick);
+        let t = elapsed as f32 / self.duration_ticks.max(1) as f32;
+        self.easing.apply(t)
+    }
+
+    /// Whether the transition has reached its target at `tick`
+    pub fn is_finished(&self, tick: u64) -> bool {
+        tick.saturating_sub(self.start_tick) >= self.duration_ticks as u64
+    }
+
+    /// Blended config at `tick`
+    pub fn sample(&self, tick: u64) -> EpuConfig {
+        if self.is_finished(tick) {
+            return self.to;
+        }
+        self.from.lerp(&self.to, self.progress(tick))
+    }
+}
+
+impl EpuConfig {
+    /// Interpolate field-wise towards `to` (`t` = 0 is `self`, 1 is `to`).
+    pub fn lerp(&self, to: &EpuConfig, t: f32) -> EpuConfig {
+        if t <= 0.0 {
+            return *self;
+        }
+        if t >= 1.0 {
+            return *to;
+        }
+        let mut layers = [[0u64; 2]; 8];
+        for (out, (a, b)) in layers
+            .iter_mut()
+            .zip(self.layers.iter().zip(to.layers.iter()))
+        {
+            *out = lerp_layer(*a, *b, t);
+        }
+        EpuConfig { layers }
+    }
+}
+
+/// Opcode of a packed layer
+#[inline]
+fn opcode(layer: [u64; 2]) -> u8 {
+    ((layer[0] >> 59) & 0x1F) as u8
+}
+
+/// Copy of a packed layer with both alphas cleared (contributes nothing)
+#[inline]
+fn faded_out(layer: [u64; 2]) -> [u64; 2] {
+    [layer[0], layer[1] & !0xFF]
+}
+
+/// Interpolate one packed 128-bit layer
+fn lerp_layer(a: [u64; 2], b: [u64; 2], t: f32) -> [u64; 2] {
+    if a == b {
+        return a;
+    }
+
+    // A layer that is only present on one side fades from/to transparent
+    let nop = EpuOpcode::Nop as u8;
+    let (a, b) = match (opcode(a) == nop, opcode(b) == nop) {
+        (true, false) => (faded_out(b), b),
+        (false, true) => (a, faded_out(a)),
+        _ => (a, b),
+    };
+
+    let lerp_u8 = |x: u64, y: u64| -> u64 {
+        let (x, y) = (x as f32, y as f32);
+        (x + (y - x) * t).round().clamp(0.0, 255.0) as u64
+    };
+    let lerp_rgb = |x: u64, y: u64| -> u64 {
+        (0..3).fold(0, |acc, i| {
+            let shift = i * 8;
+            acc | (lerp_u8((x >> shift) & 0xFF, (y >> shift) & 0xFF) << shift)
+        })
+    };
+
+    // Discrete fields switch over halfway through
+    let (snap_hi, snap_lo) = if t < 0.5 { (a[0], a[1]) } else { (b[0], b[1]) };
+
+    let header = snap_hi & HI_HEADER_MASK;
+    let color_a = lerp_rgb(
+        (a[0] >> HI_COLOR_A_SHIFT) & HI_COLOR_MASK,
+        (b[0] >> HI_COLOR_A_SHIFT) & HI_COLOR_MASK,
+    );
+    let color_b = lerp_rgb(a[0] & HI_COLOR_MASK, b[0] & HI_COLOR_MASK);
+    let hi = header | (color_a << HI_COLOR_A_SHIFT) | color_b;
+
+    let intensity = lerp_u8(a[1] >> LO_INTENSITY_SHIFT, b[1] >> LO_INTENSITY_SHIFT);
+    let params = snap_lo & LO_PARAMS_MASK;
+    let direction = lerp_direction(
+        ((a[1] >> LO_DIRECTION_SHIFT) & 0xFFFF) as u16,
+        ((b[1] >> LO_DIRECTION_SHIFT) & 0xFFFF) as u16,
+        t,
+    );
+    let lerp_u4 = |x: u64, y: u64| -> u64 {
+        let (x, y) = (x as f32, y as f32);
+        (x + (y - x) * t).round().clamp(0.0, 15.0) as u64
+    };
+    let alpha_a = lerp_u4(
+        (a[1] >> LO_ALPHA_A_SHIFT) & 0xF,
+        (b[1] >> LO_ALPHA_A_SHIFT) & 0xF,
+    );
+    let alpha_b = lerp_u4(a[1] & 0xF, b[1] & 0xF);
+    let lo = (intensity << LO_INTENSITY_SHIFT)
+        | params
+        | ((direction as u64) << LO_DIRECTION_SHIFT)
+        | (alpha_a << LO_ALPHA_A_SHIFT)
+        | alpha_b;
+
+    [hi, lo]
+}
+
+/// Interpolate two octahedral-encoded directions along the sphere
+fn lerp_direction(a: u16, b: u16, t: f32) -> u16 {
+    if a == b {
+        return a;
+    }
+    let from = decode_direction_u16(a);
+    let to = decode_direction_u16(b);
+    let dir = from.lerp(to, t);
+    // Opposite directions pass through zero; fall back to snapping
+    if dir.length_squared() < 1e-6 {
+        return if t < 0.5 { a } else { b };
+    }
+    encode_direction_u16(dir)
+}
@@ -3,68 +3,159 @@
 //! Handles viewport scaling modes (Stretch, Fit, PixelPerfect) and
 //! final presentation to the window surface.
 
+use nethercore_core::app::config::ScaleMode;
+
 use super::super::ZXGraphics;
+use crate::state::MAX_PALETTE_COLORS;
+
+/// Palette uniforms matching the WGSL `Palette` struct in blit.wgsl
+///
+/// Colors are sRGB-encoded; the blit shader matches and dithers in sRGB space.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+pub(crate) struct PaletteUniforms {
+    /// Number of colors in use (0 = palette constraint off)
+    pub count: u32,
+    /// Ordered dithering (0 = off, 1 = on)
+    pub dither: u32,
+    pub _pad0: u32,
+    pub _pad1: u32,
+    pub colors: [[f32; 4]; MAX_PALETTE_COLORS],
+}
+
+impl PaletteUniforms {
+    /// Build uniforms from 0xRRGGBBAA colors (extra colors beyond the max are dropped)
+    pub fn new(colors: &[u32], dither: bool) -> Self {
+        let mut uniforms = Self {
+            count: colors.len().min(MAX_PALETTE_COLORS) as u32,
+            dither: dither as u32,
+            _pad0: 0,
+            _pad1: 0,
+            colors: [[0.0; 4]; MAX_PALETTE_COLORS],
+        };
+        for (slot, &color) in uniforms.colors.iter_mut().zip(colors) {
+            *slot = crate::ffi::unpack_rgba(color);
+        }
+        uniforms
+    }
+}
+
+/// Window-space rectangle (x, y, width, height) the render target is drawn to
+pub(crate) fn scaled_viewport(mode: ScaleMode, render: (f32, f32), window: (f32, f32)) -> [f32; 4] {
+    let (render_width, render_height) = render;
+    let (window_width, window_height) = window;
+
+    let scale = match mode {
+        // Stretch to fill window (may distort aspect ratio)
+        ScaleMode::Stretch => return [0.0, 0.0, window_width, window_height],
+        // Maintain aspect ratio, scale to fill as much as possible
+        ScaleMode::Fit => (window_width / render_width).min(window_height / render_height),
+        // Largest integer scale that fits BOTH dimensions (at least 1x)
+        ScaleMode::PixelPerfect => (window_width / render_width)
+            .floor()
+            .min((window_height / render_height).floor())
+            .max(1.0),
+    };
+
+    // Center the viewport (letterbox/pillarbox)
+    let scaled_width = render_width * scale;
+    let scaled_height = render_height * scale;
+    let x = (window_width - scaled_width) / 2.0;
+    let y = (window_height - scaled_height) / 2.0;
+
+    [x, y, scaled_width, scaled_height]
+}
+
+/// Canvas-space rectangle (x, y, width, height) that is visible in the window
+///
+/// This is the whole canvas unless the viewport overflows the window (integer
+/// scaling at 1x in a window smaller than the canvas).
+pub(crate) fn visible_canvas_area(
+    viewport: [f32; 4],
+    render: (f32, f32),
+    window: (f32, f32),
+) -> [f32; 4] {
+    let [vx, vy, vw, vh] = viewport;
+    let to_canvas_x = |wx: f32| (wx - vx) * render.0 / vw;
+    let to_canvas_y = |wy: f32| (wy - vy) * render.1 / vh;
+
+    let x0 = to_canvas_x(vx.max(0.0));
+    let x1 = to_canvas_x((vx + vw).min(window.0));
+    let y0 = to_canvas_y(vy.max(0.0));
+    let y1 = to_canvas_y((vy + vh).min(window.1));
+
+    [x0, y0, (x1 - x0).max(0.0), (y1 - y0).max(0.0)]
+}
+
+/// Canvas position under a window position, or None outside the viewport
+pub(crate) fn window_to_canvas(
+    viewport: [f32; 4],
+    render: (f32, f32),
+    point: (f32, f32),
+) -> Option<[f32; 2]> {
+    let [vx, vy, vw, vh] = viewport;
+    let x = (point.0 - vx) * render.0 / vw;
+    let y = (point.1 - vy) * render.1 / vh;
+    let inside = (0.0..render.0).contains(&x) && (0.0..render.1).contains(&y);
+    inside.then_some([x, y])
+}
 
 impl ZXGraphics {
+    /// Scale mode used for presentation (the game's request, else the player's setting)
+    pub fn effective_scale_mode(&self) -> ScaleMode {
+        self.game_scale_mode.unwrap_or(self.scale_mode)
+    }
+
+    /// Window-space rectangle the render target is blitted to
+    pub fn window_viewport(&self) -> [f32; 4] {
+        scaled_viewport(
+            self.effective_scale_mode(),
+            (
+                self.render_target.width as f32,
+                self.render_target.height as f32,
+            ),
+            (self.config.width as f32, self.config.height as f32),
+        )
+    }
+
+    /// Canvas-space rectangle currently visible in the window
+    pub fn visible_canvas_area(&self) -> [f32; 4] {
+        visible_canvas_area(
+            self.window_viewport(),
+            (
+                self.render_target.width as f32,
+                self.render_target.height as f32,
+            ),
+            (self.config.width as f32, self.config.height as f32),
+        )
+    }
+
+    /// Canvas position under a window position (None outside the game image)
+    pub fn window_to_canvas(&self, x: f32, y: f32) -> Option<[f32; 2]> {
+        window_to_canvas(
+            self.window_viewport(),
+            (
+                self.render_target.width as f32,
+                self.render_target.height as f32,
+            ),
+            (x, y),
+        )
+    }
+
+    /// Constrain the presented image to a palette (empty = full color)
+    pub fn set_palette(&self, colors: &[u32], dither: bool) {
+        let uniforms = PaletteUniforms::new(colors, dither);
+        self.queue.write_buffer(
+            &self.blit_palette_buffer,
+            0,
+            bytemuck::cast_slice(&[uniforms]),
+        );
+    }
+
     /// Blit the render target to the window surface
     /// Call this every frame to display the last rendered content
     pub fn blit_to_window(&self, encoder: &mut wgpu::CommandEncoder, view: &wgpu::TextureView) {
-        // Calculate viewport based on scale mode
-        let (viewport_x, viewport_y, viewport_width, viewport_height) = match self.scale_mode {
-            nethercore_core::app::config::ScaleMode::Stretch => {
-                // Stretch to fill window (may distort aspect ratio)
-                (
-                    0.0,
-                    0.0,
-                    self.config.width as f32,
-                    self.config.height as f32,
-                )
-            }
-            nethercore_core::app::config::ScaleMode::Fit => {
-                // Maintain aspect ratio, scale to fill as much as possible
-                let render_width = self.render_target.width as f32;
-                let render_height = self.render_target.height as f32;
-                let window_width = self.config.width as f32;
-                let window_height = self.config.height as f32;
-
-                // Calculate scale factor that fits within window while maintaining aspect ratio
-                let scale_x = window_width / render_width;
-                let scale_y = window_height / render_height;
-                let scale = scale_x.min(scale_y);
-
-                // Calculate scaled dimensions
-                let scaled_width = render_width * scale;
-                let scaled_height = render_height * scale;
-
-                // Center the viewport (letterbox/pillarbox)
-                let x = (window_width - scaled_width) / 2.0;
-                let y = (window_height - scaled_height) / 2.0;
-
-                (x, y, scaled_width, scaled_height)
-            }
-            nethercore_core::app::config::ScaleMode::PixelPerfect => {
-                // Integer scaling with letterboxing (pixel-perfect)
-                let render_width = self.render_target.width as f32;
-                let render_height = self.render_target.height as f32;
-                let window_width = self.config.width as f32;
-                let window_height = self.config.height as f32;
-
-                // Calculate largest integer scale that fits BOTH dimensions
-                let scale_x = (window_width / render_width).floor();
-                let scale_y = (window_height / render_height).floor();
-                let scale = scale_x.min(scale_y).max(1.0); // At least 1x
-
-                // Calculate scaled dimensions
-                let scaled_width = render_width * scale;
-                let scaled_height = render_height * scale;
-
-                // Center the viewport
-                let x = (window_width - scaled_width) / 2.0;
-                let y = (window_height - scaled_height) / 2.0;
-
-                (x, y, scaled_width, scaled_height)
-            }
-        };
+        let [viewport_x, viewport_y, viewport_width, viewport_height] = self.window_viewport();
 
         // Blit to window
         {
@@ -100,3 +191,78 @@ impl ZXGraphics {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CANVAS: (f32, f32) = (960.0, 540.0);
+
+    #[test]
+    fn test_palette_uniforms_unpack_colors() {
+        let uniforms = PaletteUniforms::new(&[0x000000FF, 0xFF8000FF], true);
+        assert_eq!(uniforms.count, 2);
+        assert_eq!(uniforms.dither, 1);
+        assert_eq!(uniforms.colors[1], [1.0, 128.0 / 255.0, 0.0, 1.0]);
+        assert_eq!(uniforms.colors[2], [0.0; 4]);
+    }
+
+    #[test]
+    fn test_empty_palette_is_off() {
+        let uniforms = PaletteUniforms::new(&[], true);
+        assert_eq!(uniforms.count, 0);
+        // Uniform size must match the WGSL struct (16-byte header + vec4 array)
+        assert_eq!(
+            std::mem::size_of::<PaletteUniforms>(),
+            16 + 16 * MAX_PALETTE_COLORS
+        );
+    }
+
+    #[test]
+    fn test_fit_pillarboxes_ultrawide() {
+        let vp = scaled_viewport(ScaleMode::Fit, CANVAS, (3440.0, 1440.0));
+        assert_eq!(vp, [440.0, 0.0, 2560.0, 1440.0]);
+        assert_eq!(
+            visible_canvas_area(vp, CANVAS, (3440.0, 1440.0)),
+            [0.0, 0.0, 960.0, 540.0]
+        );
+    }
+
+    #[test]
+    fn test_integer_scaling_centers_and_crops() {
+        let vp = scaled_viewport(ScaleMode::PixelPerfect, CANVAS, (2560.0, 1440.0));
+        assert_eq!(vp, [320.0, 180.0, 1920.0, 1080.0]);
+
+        // Window smaller than the canvas: 1x, cropped on all sides
+        let window = (800.0, 500.0);
+        let vp = scaled_viewport(ScaleMode::PixelPerfect, CANVAS, window);
+        assert_eq!(vp, [-80.0, -20.0, 960.0, 540.0]);
+        assert_eq!(
+            visible_canvas_area(vp, CANVAS, window),
+            [80.0, 20.0, 800.0, 500.0]
+        );
+    }
+
+    #[test]
+    fn test_stretch_fills_window() {
+        let window = (2560.0, 1080.0);
+        let vp = scaled_viewport(ScaleMode::Stretch, CANVAS, window);
+        assert_eq!(vp, [0.0, 0.0, 2560.0, 1080.0]);
+        assert_eq!(
+            visible_canvas_area(vp, CANVAS, window),
+            [0.0, 0.0, 960.0, 540.0]
+        );
+    }
+
+    #[test]
+    fn test_window_to_canvas_skips_letterbox() {
+        let vp = scaled_viewport(ScaleMode::Fit, CANVAS, (3440.0, 1440.0));
+        assert_eq!(window_to_canvas(vp, CANVAS, (440.0, 0.0)), Some([0.0, 0.0]));
+        assert_eq!(
+            window_to_canvas(vp, CANVAS, (1720.0, 720.0)),
+            Some([480.0, 270.0])
+        );
+        assert_eq!(window_to_canvas(vp, CANVAS, (100.0, 720.0)), None);
+        assert_eq!(window_to_canvas(vp, CANVAS, (3000.0, 720.0)), None);
+    }
+}
@@ -6,7 +6,9 @@
 //! - Shading states
 //! - MVP indices with absolute offsets
 //! - Bone matrices for animation
+//! - Scene lights and their light grid
 
+use super::super::MAX_SCENE_LIGHTS;
 use super::super::ZXGraphics;
 use super::super::vertex::VERTEX_FORMAT_COUNT;
 use crate::state::ZXFFIState;
@@ -166,4 +168,22 @@ impl ZXGraphics {
 
         t0.map(|t| t.elapsed().as_nanos() as u64)
     }
+
+    /// Bin scene lights into the light grid and upload both.
+    ///
+    /// Always writes the grid header, so a frame without scene lights clears
+    /// the previous frame's light count.
+    pub(super) fn upload_scene_lights(&mut self, z_state: &ZXFFIState) {
+        let lights = &z_state.scene_lights[..z_state.scene_lights.len().min(MAX_SCENE_LIGHTS)];
+        self.light_grid.build(lights);
+        if !lights.is_empty() {
+            self.queue
+                .write_buffer(&self.scene_lights_buffer, 0, bytemuck::cast_slice(lights));
+        }
+        self.queue.write_buffer(
+            &self.light_grid_buffer,
+            0,
+            bytemuck::cast_slice(self.light_grid.words()),
+        );
+    }
 }
@@ -5,6 +5,9 @@
 //! - Buffer capacities change (buffers are recreated)
 //! - Render mode changes (different bind group layout)
 //! - EPU resources are recreated
+//! - Baked lightmaps are uploaded after init
+//! - Morph targets are uploaded after init
+//! - Palettes are uploaded after init
 
 use super::super::ZXGraphics;
 use super::super::command_buffer::VRPCommand;
@@ -21,7 +24,7 @@ impl ZXGraphics {
     ) -> Option<wgpu::BindGroup> {
         let first_cmd = self.command_buffer.commands().first()?;
 
-        // The bind group layout is shared across pipelines. However, in render modes 1-3 the
+        // The bind group layout is shared across pipelines. However, in render modes 1-4 the
         // mesh pipeline requires normals; the first sorted command is often a Quad or
         // EpuEnvironment which uses a non-normal vertex format. Use a safe mesh format when
         // creating a pipeline solely to obtain the bind group layout.
@@ -88,6 +91,7 @@ impl ZXGraphics {
         let first_state = RenderState {
             depth_test: pass_config.depth_write,
             cull_mode,
+            ..Default::default()
         };
         // Use default PassConfig for bind group layout - all pipelines share the same layout
         let pipeline_entry = self.pipeline_cache.get_or_create(
@@ -103,10 +107,14 @@ impl ZXGraphics {
         // 0-1: Transforms (unified_transforms, mvp_indices)
         // 2: Shading (shading_states)
         // 3: Animation (unified_animation)
+        // 4: Baked lighting (lightmap_data)
         // 5: Quad rendering (quad_instances)
         // 6-7: EPU textures (env_radiance, sampler)
         // 8-9: EPU state + frame uniforms
+        // 10: Palette swap (palette_data)
         // 11: EPU SH9 (diffuse irradiance)
+        // 12-13: Scene lights (scene_lights, light_grid)
+        // 14: Morph targets (morph_data)
         let bind_group = self.device.create_bind_group(&wgpu::BindGroupDescriptor {
             label: Some("Frame Bind Group (Unified)"),
             layout: &pipeline_entry.bind_group_layout_frame,
@@ -127,6 +135,10 @@ impl ZXGraphics {
                     binding: 3,
                     resource: self.unified_animation_buffer.as_entire_binding(),
                 },
+                wgpu::BindGroupEntry {
+                    binding: 4,
+                    resource: self.lightmap_buffer.as_entire_binding(),
+                },
                 wgpu::BindGroupEntry {
                     binding: 5,
                     resource: self
@@ -152,10 +164,26 @@ impl ZXGraphics {
                     binding: 9,
                     resource: self.epu_runtime.frame_uniforms_buffer().as_entire_binding(),
                 },
+                wgpu::BindGroupEntry {
+                    binding: 10,
+                    resource: self.palette_buffer.as_entire_binding(),
+                },
                 wgpu::BindGroupEntry {
                     binding: 11,
                     resource: self.epu_runtime.sh9_buffer().as_entire_binding(),
                 },
+                wgpu::BindGroupEntry {
+                    binding: 12,
+                    resource: self.scene_lights_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 13,
+                    resource: self.light_grid_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 14,
+                    resource: self.morph_buffer.as_entire_binding(),
+                },
             ],
         });
         self.cached_frame_bind_group = Some(bind_group.clone());
@@ -14,7 +14,11 @@ mod frame_bind_group;
 mod pass_execution;
 mod perf_tracking;
 mod render_frame;
+mod ssao;
 
 // Re-export public items from submodules
-// All items are already implemented as impl blocks on ZXGraphics,
-// so no additional re-exports are needed.
+// Most items are implemented as impl blocks on ZXGraphics; the blit
+// palette uniforms and SSAO resources are also needed at init.
+pub(super) use blit::PaletteUniforms;
+pub(super) use ssao::SsaoResources;
+pub use ssao::SsaoSettings;
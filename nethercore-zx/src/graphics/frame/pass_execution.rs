@@ -8,14 +8,16 @@
 use super::super::TextureHandleTable;
 use super::super::ZXGraphics;
 use super::super::command_buffer::{BufferSource, VRPCommand};
-use super::super::pipeline::{PipelineEntry, PipelineKey};
-use super::super::render_state::{RenderState, TextureHandle};
+use super::super::epu::BackgroundCacheTarget;
+use super::super::pipeline::{EnvironmentSource, PipelineEntry, PipelineKey};
+use super::super::render_state::{BlendMode, RenderState, TextureHandle};
 use crate::state::ZXFFIState;
 use hashbrown::HashMap;
 
 /// State tracked during render pass execution to minimize redundant GPU state changes.
 struct RenderPassState {
     current_viewport: Option<super::super::Viewport>,
+    current_scissor: Option<super::super::Viewport>,
     current_pass_id: Option<u32>,
     bound_pipeline: Option<PipelineKey>,
     bound_texture_slots: Option<[TextureHandle; 4]>,
@@ -27,6 +29,7 @@ impl RenderPassState {
     fn new() -> Self {
         Self {
             current_viewport: None,
+            current_scissor: None,
             current_pass_id: None,
             bound_pipeline: None,
             bound_texture_slots: None,
@@ -75,6 +78,81 @@ impl ZXGraphics {
         });
     }
 
+    /// Evaluate this frame's changed EPU backgrounds into the background cache.
+    ///
+    /// Runs before the game passes so cached environment draws can copy the
+    /// freshly filled regions.
+    pub(super) fn execute_epu_background_fills(
+        &mut self,
+        encoder: &mut wgpu::CommandEncoder,
+        frame_bind_group: &wgpu::BindGroup,
+        texture_bind_groups: &mut HashMap<[TextureHandle; 4], wgpu::BindGroup>,
+    ) {
+        if self.epu_background_fills.is_empty() {
+            return;
+        }
+
+        self.pipeline_cache
+            .get_or_create_environment_fill(&self.device, self.config.format);
+        let pipeline_entry = self
+            .pipeline_cache
+            .get_by_key(&PipelineKey::EnvironmentFill)
+            .expect("Pipeline should exist after get_or_create");
+
+        if self.epu_background.is_none() {
+            let target = BackgroundCacheTarget::new(
+                &self.device,
+                &pipeline_entry.bind_group_layout_textures,
+                self.get_fallback_white_view(),
+                self.get_fallback_white_array_view(),
+                [&self.sampler_nearest, &self.sampler_linear],
+                (self.render_target.width, self.render_target.height),
+                self.config.format,
+            );
+            self.epu_background = Some(target);
+        }
+        let Some(background) = &self.epu_background else {
+            return;
+        };
+
+        let slots = [TextureHandle::INVALID; 4];
+        let texture_bind_group = texture_bind_groups
+            .entry(slots)
+            .or_insert_with(|| self.create_texture_bind_group(slots, pipeline_entry));
+
+        let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: Some("EPU Background Fill Pass"),
+            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                view: &background.view,
+                resolve_target: None,
+                ops: wgpu::Operations {
+                    load: wgpu::LoadOp::Load,
+                    store: wgpu::StoreOp::Store,
+                },
+                depth_slice: None,
+            })],
+            depth_stencil_attachment: None,
+            timestamp_writes: None,
+            occlusion_query_set: None,
+        });
+        render_pass.set_pipeline(&pipeline_entry.pipeline);
+        render_pass.set_bind_group(0, frame_bind_group, &[]);
+        render_pass.set_bind_group(1, &*texture_bind_group, &[]);
+
+        for &(viewport, mvp_index) in &self.epu_background_fills {
+            render_pass.set_viewport(
+                viewport.x as f32,
+                viewport.y as f32,
+                viewport.width as f32,
+                viewport.height as f32,
+                0.0,
+                1.0,
+            );
+            render_pass.set_scissor_rect(viewport.x, viewport.y, viewport.width, viewport.height);
+            render_pass.draw(0..3, mvp_index..mvp_index + 1);
+        }
+    }
+
     /// Execute all render passes for the frame.
     /// Commands are processed in segments, restarting render pass when depth_clear is needed.
     pub(super) fn execute_render_passes(
@@ -248,14 +326,23 @@ impl ZXGraphics {
                         *is_screen_space,
                     ),
                     VRPCommand::EpuEnvironment {
-                        viewport, pass_id, ..
+                        viewport,
+                        pass_id,
+                        sky,
+                        ..
                     } => (
                         *viewport,
                         *pass_id,
                         self.unit_quad_format, // EPU environment uses unit quad mesh
                         super::super::render_state::CullMode::None,
-                        [TextureHandle::INVALID; 4], // Default textures (unused)
-                        BufferSource::Quad,          // Environment renders as a fullscreen quad
+                        // Slot 0 holds the sky cubemap (other slots unused)
+                        [
+                            sky.unwrap_or(TextureHandle::INVALID),
+                            TextureHandle::INVALID,
+                            TextureHandle::INVALID,
+                            TextureHandle::INVALID,
+                        ],
+                        BufferSource::Quad, // Environment renders as a fullscreen quad
                         false,
                         true, // is_environment = true for EPU
                         false,
@@ -279,7 +366,7 @@ impl ZXGraphics {
                     break;
                 }
 
-                // Set viewport and scissor rect if changed (split-screen support)
+                // Set viewport if changed (split-screen support)
                 if state.current_viewport != Some(cmd_viewport) {
                     render_pass.set_viewport(
                         cmd_viewport.x as f32,
@@ -289,13 +376,24 @@ impl ZXGraphics {
                         0.0,
                         1.0,
                     );
+                    state.current_viewport = Some(cmd_viewport);
+                }
+
+                // Set scissor rect if changed (viewport, or clip_push() rect for 2D quads)
+                let cmd_scissor = match cmd {
+                    VRPCommand::Quad {
+                        clip: Some(clip), ..
+                    } => *clip,
+                    _ => cmd_viewport,
+                };
+                if state.current_scissor != Some(cmd_scissor) {
                     render_pass.set_scissor_rect(
-                        cmd_viewport.x,
-                        cmd_viewport.y,
-                        cmd_viewport.width,
-                        cmd_viewport.height,
+                        cmd_scissor.x,
+                        cmd_scissor.y,
+                        cmd_scissor.width,
+                        cmd_scissor.height,
                     );
-                    state.current_viewport = Some(cmd_viewport);
+                    state.current_scissor = Some(cmd_scissor);
                 }
 
                 // Set stencil reference if pass changed
@@ -307,10 +405,29 @@ impl ZXGraphics {
                     state.current_pass_id = Some(cmd_pass_id);
                 }
 
+                // Blend mode from blend_mode_2d() / blend_mode_3d()
+                let blend_mode = match cmd {
+                    VRPCommand::Quad { blend_mode, .. }
+                    | VRPCommand::Mesh { blend_mode, .. }
+                    | VRPCommand::IndexedMesh { blend_mode, .. } => *blend_mode,
+                    _ => BlendMode::Alpha,
+                };
+
                 // Create render state from command (depth_test derived from PassConfig)
                 let render_state = RenderState {
                     depth_test: cmd_pass_config.depth_write,
                     cull_mode,
+                    blend_mode,
+                };
+
+                // Static EPU backgrounds are copied from the background cache
+                let cached_environment =
+                    matches!(cmd, VRPCommand::EpuEnvironment { cached: true, .. })
+                        && self.epu_background.is_some();
+                let environment_source = match cmd {
+                    VRPCommand::EpuEnvironment { sky: Some(_), .. } => EnvironmentSource::Sky,
+                    _ if cached_environment => EnvironmentSource::Cached,
+                    _ => EnvironmentSource::Epu,
                 };
 
                 // Get/create pipeline - use environment/quad/regular pipeline based on command type
@@ -320,6 +437,7 @@ impl ZXGraphics {
                         &self.device,
                         self.config.format,
                         &cmd_pass_config,
+                        environment_source,
                     );
                 } else if is_quad {
                     // Quad rendering: Ensure quad pipeline exists
@@ -330,6 +448,7 @@ impl ZXGraphics {
                         self.config.format,
                         &cmd_pass_config,
                         is_screen_space_quad,
+                        blend_mode,
                     );
                 } else {
                     // Regular mesh rendering: Ensure format-specific pipeline exists
@@ -352,9 +471,9 @@ impl ZXGraphics {
 
                 // Now get immutable reference to pipeline entry (avoiding borrow issues)
                 let pipeline_key = if is_environment {
-                    PipelineKey::environment(&cmd_pass_config)
+                    PipelineKey::environment(&cmd_pass_config, environment_source)
                 } else if is_quad {
-                    PipelineKey::quad(&cmd_pass_config, is_screen_space_quad)
+                    PipelineKey::quad(&cmd_pass_config, is_screen_space_quad, blend_mode)
                 } else {
                     PipelineKey::new(
                         self.current_render_mode,
@@ -391,9 +510,18 @@ impl ZXGraphics {
                 }
 
                 // Set texture bind group (only if changed)
-                if state.bound_texture_slots != Some(texture_slots) {
+                if let Some(background) =
+                    self.epu_background.as_ref().filter(|_| cached_environment)
+                {
+                    render_pass.set_bind_group(1, &background.bind_group, &[]);
+                    state.bound_texture_slots = None;
+                    self.render_stats.texture_binds =
+                        self.render_stats.texture_binds.saturating_add(1);
+                } else if state.bound_texture_slots != Some(texture_slots) {
                     render_pass.set_bind_group(1, &*texture_bind_group, &[]);
                     state.bound_texture_slots = Some(texture_slots);
+                    self.render_stats.texture_binds =
+                        self.render_stats.texture_binds.saturating_add(1);
                 }
 
                 // Set vertex buffer (only if format or buffer source changed)
@@ -417,6 +545,7 @@ impl ZXGraphics {
 
                 // Execute the draw command
                 self.execute_draw_command(cmd, &mut render_pass, buffer_source, format);
+                self.render_stats.record_draw(cmd);
 
                 // Move to next command
                 cmd_idx += 1;
@@ -460,6 +589,10 @@ impl ZXGraphics {
             self.get_texture_view(texture_slots[3])
                 .unwrap_or_else(|| self.get_fallback_checkerboard_view())
         };
+        // Texture arrays only bind as arrays; slot 0's plain view falls back to the checkerboard
+        let tex_array_view_0 = self
+            .get_texture_array_view(texture_slots[0])
+            .unwrap_or_else(|| self.get_fallback_white_array_view());
 
         self.device.create_bind_group(&wgpu::BindGroupDescriptor {
             label: Some("Texture Bind Group"),
@@ -490,6 +623,10 @@ impl ZXGraphics {
                     binding: 5,
                     resource: wgpu::BindingResource::Sampler(&self.sampler_linear),
                 },
+                wgpu::BindGroupEntry {
+                    binding: 6,
+                    resource: wgpu::BindingResource::TextureView(tex_array_view_0),
+                },
             ],
         })
     }
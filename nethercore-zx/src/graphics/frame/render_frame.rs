@@ -5,13 +5,20 @@
 //! - GPU buffer uploads (buffer_upload)
 //! - Frame bind group management (frame_bind_group)
 //! - Render pass execution (pass_execution)
+//! - The SSAO post pass (ssao)
 
+use super::super::RenderStats;
 use super::super::TextureHandleTable;
 use super::super::ZXGraphics;
 use super::super::command_buffer::VRPCommand;
 use std::time::Instant;
 
 impl ZXGraphics {
+    /// Statistics for the last rendered frame
+    pub fn render_stats(&self) -> RenderStats {
+        self.render_stats
+    }
+
     pub fn render_frame(
         &mut self,
         encoder: &mut wgpu::CommandEncoder,
@@ -27,6 +34,12 @@ impl ZXGraphics {
             self.collect_frame_perf_metrics();
         }
 
+        // Draw calls, triangles and binds are counted as the passes execute
+        self.render_stats = RenderStats {
+            triangles_culled: z_state.triangles_culled,
+            ..RenderStats::default()
+        };
+
         // If no commands, just clear render target
         // (blit is handled separately via blit_to_window())
         if self.command_buffer.commands().is_empty() {
@@ -84,6 +97,9 @@ impl ZXGraphics {
             self.perf.upload_bones_ns = self.perf.upload_bones_ns.wrapping_add(elapsed);
         }
 
+        // 5. Bin scene lights into the world-space light grid and upload both
+        self.upload_scene_lights(z_state);
+
         // NOTE: Inverse bind matrices are uploaded once during init via upload_static_inverse_bind()
         // They live in unified_animation[0..inverse_bind_end]
 
@@ -102,6 +118,7 @@ impl ZXGraphics {
 
         // Execute render passes
         let encode_t0 = perf_enabled.then(Instant::now);
+        self.execute_epu_background_fills(encoder, &frame_bind_group, &mut texture_bind_groups);
         self.execute_render_passes(
             encoder,
             z_state,
@@ -111,6 +128,7 @@ impl ZXGraphics {
             &mut texture_bind_groups,
             perf_enabled,
         );
+        self.execute_ssao_pass(encoder, z_state);
         if let Some(t0) = encode_t0 {
             self.perf.encode_ns = self
                 .perf
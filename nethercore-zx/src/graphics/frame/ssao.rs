@@ -0,0 +1,319 @@
+//! Screen-space ambient occlusion post pass (`post_ssao`)
+//!
+//! Runs after the game's passes. Each viewport that drew 3D meshes gets a
+//! fullscreen triangle that reads the depth buffer, rebuilds view-space
+//! positions with that viewport's projection and multiplies the occlusion
+//! into the render target. Depth reads are clamped to the viewport, so
+//! split-screen views never darken each other, and pixels at depth 0
+//! (screen-space 2D) or 1 (sky) are left untouched.
+
+use glam::Mat4;
+
+use super::super::ZXGraphics;
+use super::super::command_buffer::VRPCommand;
+use super::super::init::RenderTarget;
+use super::super::viewport::Viewport;
+use crate::state::ZXFFIState;
+
+/// Maximum viewports the pass shades per frame (matches ssao.wgsl)
+pub(crate) const MAX_SSAO_VIEWPORTS: usize = 8;
+
+/// Ambient occlusion settings from `post_ssao()`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SsaoSettings {
+    /// Sample radius in view-space units
+    pub radius: f32,
+    /// Occlusion strength (0 = none, 1 = full)
+    pub intensity: f32,
+}
+
+/// Per-viewport uniforms matching the WGSL `SsaoView` struct
+#[repr(C)]
+#[derive(Clone, Copy, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+pub(crate) struct SsaoViewUniforms {
+    pub proj: [[f32; 4]; 4],
+    pub inv_proj: [[f32; 4]; 4],
+    /// Viewport in render target pixels (x, y, width, height)
+    pub rect: [f32; 4],
+    /// radius, intensity, unused, unused
+    pub params: [f32; 4],
+}
+
+impl SsaoViewUniforms {
+    pub fn new(viewport: Viewport, proj: Mat4, settings: SsaoSettings) -> Self {
+        Self {
+            proj: proj.to_cols_array_2d(),
+            inv_proj: proj.inverse().to_cols_array_2d(),
+            rect: [
+                viewport.x as f32,
+                viewport.y as f32,
+                viewport.width as f32,
+                viewport.height as f32,
+            ],
+            params: [settings.radius, settings.intensity, 0.0, 0.0],
+        }
+    }
+}
+
+/// GPU resources for the SSAO pass
+pub(crate) struct SsaoResources {
+    pipeline: wgpu::RenderPipeline,
+    bind_group: wgpu::BindGroup,
+    uniforms: wgpu::Buffer,
+}
+
+impl SsaoResources {
+    pub fn new(
+        device: &wgpu::Device,
+        surface_format: wgpu::TextureFormat,
+        render_target: &RenderTarget,
+    ) -> Self {
+        let uniforms = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("SSAO Uniforms"),
+            size: (std::mem::size_of::<SsaoViewUniforms>() * MAX_SSAO_VIEWPORTS) as u64,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        // Depth aspect only; the stencil aspect can't be sampled alongside it
+        let depth_view = render_target
+            .depth_texture
+            .create_view(&wgpu::TextureViewDescriptor {
+                label: Some("SSAO Depth View"),
+                aspect: wgpu::TextureAspect::DepthOnly,
+                ..Default::default()
+            });
+
+        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("SSAO Shader"),
+            source: wgpu::ShaderSource::Wgsl(include_str!("../../../shaders/ssao.wgsl").into()),
+        });
+
+        let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("SSAO Bind Group Layout"),
+            entries: &[
+                // Render target depth
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Texture {
+                        sample_type: wgpu::TextureSampleType::Depth,
+                        view_dimension: wgpu::TextureViewDimension::D2,
+                        multisampled: false,
+                    },
+                    count: None,
+                },
+                // Per-viewport uniforms
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+            ],
+        });
+
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("SSAO Bind Group"),
+            layout: &bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::TextureView(&depth_view),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: uniforms.as_entire_binding(),
+                },
+            ],
+        });
+
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("SSAO Pipeline Layout"),
+            bind_group_layouts: &[&bind_group_layout],
+            push_constant_ranges: &[],
+        });
+
+        // Multiply: color = ao * destination, alpha untouched
+        let blend = wgpu::BlendState {
+            color: wgpu::BlendComponent {
+                src_factor: wgpu::BlendFactor::Dst,
+                dst_factor: wgpu::BlendFactor::Zero,
+                operation: wgpu::BlendOperation::Add,
+            },
+            alpha: wgpu::BlendComponent {
+                src_factor: wgpu::BlendFactor::Zero,
+                dst_factor: wgpu::BlendFactor::One,
+                operation: wgpu::BlendOperation::Add,
+            },
+        };
+
+        let pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("SSAO Pipeline"),
+            layout: Some(&pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: Some("vs_main"),
+                buffers: &[],
+                compilation_options: Default::default(),
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: Some("fs_main"),
+                targets: &[Some(wgpu::ColorTargetState {
+                    format: surface_format,
+                    blend: Some(blend),
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+                compilation_options: Default::default(),
+            }),
+            primitive: wgpu::PrimitiveState::default(),
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState::default(),
+            multiview: None,
+            cache: None,
+        });
+
+        Self {
+            pipeline,
+            bind_group,
+            uniforms,
+        }
+    }
+}
+
+/// Viewports that drew 3D meshes this frame, each with the projection of its
+/// first mesh draw (commands are sorted by pass, so this is the main camera
+/// rather than e.g. a first-person weapon pass)
+fn ssao_viewports(commands: &[VRPCommand], z_state: &ZXFFIState) -> Vec<(Viewport, Mat4)> {
+    let mut viewports: Vec<(Viewport, Mat4)> = Vec::new();
+    for command in commands {
+        let (viewport, buffer_index) = match command {
+            VRPCommand::Mesh {
+                viewport,
+                buffer_index,
+                ..
+            }
+            | VRPCommand::IndexedMesh {
+                viewport,
+                buffer_index,
+                ..
+            } => (*viewport, *buffer_index),
+            _ => continue,
+        };
+        if viewports.len() == MAX_SSAO_VIEWPORTS || viewports.iter().any(|(v, _)| *v == viewport) {
+            continue;
+        }
+        let proj = z_state
+            .mvp_shading_states
+            .get(buffer_index as usize)
+            .and_then(|indices| z_state.proj_matrices.get(indices.proj_idx as usize));
+        if let Some(proj) = proj {
+            viewports.push((viewport, *proj));
+        }
+    }
+    viewports
+}
+
+impl ZXGraphics {
+    /// Multiply ambient occlusion into the render target (no-op unless
+    /// `post_ssao()` enabled it and the frame drew 3D meshes)
+    pub(super) fn execute_ssao_pass(
+        &mut self,
+        encoder: &mut wgpu::CommandEncoder,
+        z_state: &ZXFFIState,
+    ) {
+        let Some(settings) = z_state.ssao else {
+            return;
+        };
+        let viewports = ssao_viewports(self.command_buffer.commands(), z_state);
+        if viewports.is_empty() {
+            return;
+        }
+
+        let uniforms: Vec<SsaoViewUniforms> = viewports
+            .iter()
+            .map(|&(viewport, proj)| SsaoViewUniforms::new(viewport, proj, settings))
+            .collect();
+        self.queue
+            .write_buffer(&self.ssao.uniforms, 0, bytemuck::cast_slice(&uniforms));
+
+        let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: Some("SSAO Pass"),
+            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                view: &self.render_target.color_view,
+                resolve_target: None,
+                ops: wgpu::Operations {
+                    load: wgpu::LoadOp::Load,
+                    store: wgpu::StoreOp::Store,
+                },
+                depth_slice: None,
+            })],
+            depth_stencil_attachment: None,
+            timestamp_writes: None,
+            occlusion_query_set: None,
+        });
+        pass.set_pipeline(&self.ssao.pipeline);
+        pass.set_bind_group(0, &self.ssao.bind_group, &[]);
+        for (i, (viewport, _)) in viewports.iter().enumerate() {
+            pass.set_viewport(
+                viewport.x as f32,
+                viewport.y as f32,
+                viewport.width as f32,
+                viewport.height as f32,
+                0.0,
+                1.0,
+            );
+            pass.set_scissor_rect(viewport.x, viewport.y, viewport.width, viewport.height);
+            pass.draw(0..3, i as u32..i as u32 + 1);
+        }
+        self.render_stats.draw_calls = self
+            .render_stats
+            .draw_calls
+            .saturating_add(viewports.len() as u32);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_uniform_layout_matches_wgsl() {
+        // 2 mat4 + 2 vec4; array stride in WGSL is the same 160 bytes
+        assert_eq!(std::mem::size_of::<SsaoViewUniforms>(), 160);
+    }
+
+    #[test]
+    fn test_inverse_projection_round_trips() {
+        let proj = Mat4::perspective_rh(60f32.to_radians(), 16.0 / 9.0, 0.1, 100.0);
+        let settings = SsaoSettings {
+            radius: 0.5,
+            intensity: 1.0,
+        };
+        let uniforms = SsaoViewUniforms::new(Viewport::FULLSCREEN, proj, settings);
+        let inv = Mat4::from_cols_array_2d(&uniforms.inv_proj);
+
+        let point = glam::Vec4::new(1.0, -2.0, -10.0, 1.0);
+        let clip = proj * point;
+        let back = inv * clip;
+        assert!((back.truncate() / back.w - point.truncate()).length() < 1e-4);
+        assert_eq!(uniforms.params, [0.5, 1.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_shader_is_valid_wgsl() {
+        let source = include_str!("../../../shaders/ssao.wgsl");
+        let module = naga::front::wgsl::parse_str(source).expect("ssao.wgsl should parse");
+        naga::valid::Validator::new(
+            naga::valid::ValidationFlags::all(),
+            naga::valid::Capabilities::all(),
+        )
+        .validate(&module)
+        .expect("ssao.wgsl should validate");
+    }
+}
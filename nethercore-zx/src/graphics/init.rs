@@ -35,7 +35,7 @@ use crate::console::RESOLUTION;
 pub(crate) struct RenderTarget {
     pub(super) color_texture: wgpu::Texture,
     pub(super) color_view: wgpu::TextureView,
-    pub(super) _depth_texture: wgpu::Texture,
+    pub(super) depth_texture: wgpu::Texture,
     pub(super) depth_view: wgpu::TextureView,
     pub(super) width: u32,
     pub(super) height: u32,
@@ -73,15 +73,27 @@ impl ZXGraphics {
 
         tracing::info!("Using GPU adapter: {:?}", adapter.get_info().name);
 
-        // Check for BC texture compression support (required for BC7)
+        // Check for BC (desktop) and ETC2 (mobile/WebGL) texture compression support.
+        // ROMs packed for both carry each compressed texture in both formats.
         let adapter_features = adapter.features();
         let mut required_features = wgpu::Features::empty();
 
         if adapter_features.contains(wgpu::Features::TEXTURE_COMPRESSION_BC) {
             required_features |= wgpu::Features::TEXTURE_COMPRESSION_BC;
             tracing::info!("BC texture compression enabled");
-        } else {
-            tracing::warn!("BC texture compression not supported - BC7 textures will fail to load");
+        }
+        if adapter_features.contains(wgpu::Features::TEXTURE_COMPRESSION_ETC2) {
+            required_features |= wgpu::Features::TEXTURE_COMPRESSION_ETC2;
+            tracing::info!("ETC2 texture compression enabled");
+        }
+        if required_features.is_empty() {
+            tracing::warn!(
+                "No texture compression supported - compressed textures will fail to load"
+            );
+        } else if !required_features.contains(wgpu::Features::TEXTURE_COMPRESSION_BC) {
+            tracing::warn!(
+                "BC texture compression not supported - BC7 textures without an ETC2 copy will fail to load"
+            );
         }
 
         // Request device and queue with default limits
@@ -179,6 +191,44 @@ impl ZXGraphics {
             mapped_at_creation: false,
         });
 
+        // Lightmap buffer: placeholder until lightmaps are uploaded after init
+        let lightmap_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Lightmap Data (@binding(4))"),
+            size: 8, // One [u32; 2] entry (storage bindings must not be empty)
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        // Palette buffer: placeholder until palettes are uploaded after init
+        let palette_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Palette Data (@binding(10))"),
+            size: 8, // Storage bindings must not be empty
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        // Morph buffer: placeholder until morph targets are uploaded after init
+        let morph_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Morph Data (@binding(14))"),
+            size: 12, // One [u32; 3] entry (storage bindings must not be empty)
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        // Scene lights and light grid: fixed size, rewritten each frame
+        let scene_lights_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Scene Lights (@binding(12))"),
+            size: (super::MAX_SCENE_LIGHTS * std::mem::size_of::<super::PackedSceneLight>()) as u64,
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        let light_grid_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Light Grid (@binding(13))"),
+            size: (super::LIGHT_GRID_CAPACITY * std::mem::size_of::<u32>()) as u64,
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
         // Create MVP indices buffer (4 × u32 per entry: absolute model/view/proj/shading indices)
         let mvp_indices_capacity = 1024;
         let mvp_indices_buffer = device.create_buffer(&wgpu::BufferDescriptor {
@@ -222,9 +272,12 @@ impl ZXGraphics {
             Self::create_render_target(&device, target_width, target_height, surface_format);
 
         // Create blit pipeline for scaling render target to window
-        let (blit_pipeline, blit_bind_group, _blit_sampler) =
+        let (blit_pipeline, blit_bind_group, blit_palette_buffer) =
             Self::create_blit_pipeline(&device, surface_format, &render_target);
 
+        // Create SSAO post pass (reads the render target's depth)
+        let ssao = super::frame::SsaoResources::new(&device, surface_format, &render_target);
+
         // Create static unit quad mesh for GPU-instanced rendering
         // Format: POS_UV_COLOR (format bits: UV | COLOR = 0b011 = 3)
         let unit_quad_format = FORMAT_UV | FORMAT_COLOR;
@@ -280,6 +333,8 @@ impl ZXGraphics {
             render_target,
             blit_pipeline,
             blit_bind_group,
+            blit_palette_buffer,
+            ssao,
             depth_texture,
             depth_view,
             texture_manager,
@@ -292,6 +347,12 @@ impl ZXGraphics {
             unified_animation_capacity,
             inverse_bind_end: 0, // Set when inverse bind matrices are uploaded
             animation_static_end: 0, // Set when keyframes are uploaded
+            lightmap_buffer,
+            palette_buffer,
+            morph_buffer,
+            scene_lights_buffer,
+            light_grid_buffer,
+            light_grid: super::LightGrid::default(),
             mvp_indices_buffer,
             mvp_indices_capacity,
             shading_state_buffer,
@@ -306,6 +367,7 @@ impl ZXGraphics {
             pipeline_cache: super::pipeline::PipelineCache::new(),
             current_render_mode: 0, // Default to Mode 0 (Lambert)
             scale_mode: nethercore_core::app::config::ScaleMode::default(), // PixelPerfect by default
+            game_scale_mode: None,
             unit_quad_format,
             unit_quad_base_vertex,
             unit_quad_first_index,
@@ -314,7 +376,11 @@ impl ZXGraphics {
             mvp_indices_scratch: Vec::new(),
             epu_runtime,
             epu_sampler,
+            epu_background: None,
+            epu_background_regions: Default::default(),
+            epu_background_fills: Vec::new(),
             perf: super::zx_graphics::ZXPerf::new(),
+            render_stats: Default::default(),
         };
 
         Ok(graphics)
@@ -387,7 +453,8 @@ impl ZXGraphics {
             sample_count: 1,
             dimension: wgpu::TextureDimension::D2,
             format: wgpu::TextureFormat::Depth24PlusStencil8,
-            usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+            // Sampled by the SSAO post pass
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING,
             view_formats: &[],
         });
         let depth_view = depth_texture.create_view(&wgpu::TextureViewDescriptor::default());
@@ -395,7 +462,7 @@ impl ZXGraphics {
         RenderTarget {
             color_texture,
             color_view,
-            _depth_texture: depth_texture,
+            depth_texture,
             depth_view,
             width,
             height,
@@ -407,7 +474,7 @@ impl ZXGraphics {
         device: &wgpu::Device,
         surface_format: wgpu::TextureFormat,
         render_target: &RenderTarget,
-    ) -> (wgpu::RenderPipeline, wgpu::BindGroup, wgpu::Sampler) {
+    ) -> (wgpu::RenderPipeline, wgpu::BindGroup, wgpu::Buffer) {
         // Create sampler for render target (nearest neighbor for pixel-perfect scaling)
         let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
             label: Some("Blit Sampler"),
@@ -420,6 +487,14 @@ impl ZXGraphics {
             ..Default::default()
         });
 
+        // Palette uniforms start zeroed (count 0 = palette constraint off)
+        let palette_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Blit Palette Uniforms"),
+            size: std::mem::size_of::<super::frame::PaletteUniforms>() as u64,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
         // Load blit shader
         let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
             label: Some("Blit Shader"),
@@ -448,6 +523,17 @@ impl ZXGraphics {
                     ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
                     count: None,
                 },
+                // Palette uniforms
+                wgpu::BindGroupLayoutEntry {
+                    binding: 2,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
             ],
         });
 
@@ -464,6 +550,10 @@ impl ZXGraphics {
                     binding: 1,
                     resource: wgpu::BindingResource::Sampler(&sampler),
                 },
+                wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: palette_buffer.as_entire_binding(),
+                },
             ],
         });
 
@@ -513,6 +603,6 @@ impl ZXGraphics {
             cache: None,
         });
 
-        (pipeline, bind_group, sampler)
+        (pipeline, bind_group, palette_buffer)
     }
 }
@@ -1,7 +1,7 @@
 /// Unpacked MVP + shading indices for GPU upload (16 bytes, vec4<u32> in WGSL)
 /// Uses all 4 × u32 fields naturally without bit-packing
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
 pub struct MvpShadingIndices {
     pub model_idx: u32,
     pub view_idx: u32,
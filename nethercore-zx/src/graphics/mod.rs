@@ -22,6 +22,8 @@ mod matrix_packing;
 mod pipeline;
 mod quad_instance;
 mod render_state;
+mod render_stats;
+mod scene_lights;
 mod texture_handle_table;
 mod texture_manager;
 mod trait_impls;
@@ -40,22 +42,28 @@ pub use zx_common::{
 
 // Re-export public types from submodules
 pub use buffer::{BufferManager, GrowableBuffer, MeshHandle, RetainedMesh};
-pub use command_buffer::{CommandSortKey, VRPCommand, VirtualRenderPass};
+pub use command_buffer::{CommandSortKey, MeshBlend, VRPCommand, VirtualRenderPass};
+pub use frame::SsaoSettings;
 pub use matrix_packing::MvpShadingIndices;
 pub use quad_instance::{QuadInstance, QuadMode};
 pub use render_state::{
-    CullMode, MatcapBlendMode, PassConfig, RenderState, TextureFilter, TextureHandle,
+    BlendMode, CullMode, MatcapBlendMode, PassConfig, RenderState, TextureFilter, TextureHandle,
+};
+pub use render_stats::RenderStats;
+pub use scene_lights::{
+    LIGHT_GRID_CAPACITY, LightGrid, MAX_LIGHTS_PER_CELL, MAX_SCENE_LIGHTS, PackedSceneLight,
 };
 pub use texture_handle_table::TextureHandleTable;
 pub use unified_shading_state::{
-    DEFAULT_FLAGS, FLAG_DITHER_OFFSET_X_MASK, FLAG_DITHER_OFFSET_X_SHIFT,
-    FLAG_DITHER_OFFSET_Y_MASK, FLAG_DITHER_OFFSET_Y_SHIFT, FLAG_SKINNING_MODE,
-    FLAG_SKIP_NORMAL_MAP, FLAG_TEXTURE_FILTER_LINEAR, FLAG_UNIFORM_ALPHA_MASK,
-    FLAG_UNIFORM_ALPHA_SHIFT, FLAG_USE_MATCAP_REFLECTION, FLAG_USE_UNIFORM_COLOR,
-    FLAG_USE_UNIFORM_EMISSIVE, FLAG_USE_UNIFORM_METALLIC, FLAG_USE_UNIFORM_ROUGHNESS,
-    FLAG_USE_UNIFORM_SPECULAR, LightType, PackedLight, PackedUnifiedShadingState,
-    ShadingStateIndex, pack_f16, pack_f16x2, pack_matcap_blend_modes, pack_rgb8, pack_unorm8,
-    unpack_f16, unpack_f16x2, unpack_matcap_blend_modes, update_u32_byte,
+    DEFAULT_FLAGS, FLAG_AFFINE_TEXTURES, FLAG_DITHER_OFFSET_X_MASK, FLAG_DITHER_OFFSET_X_SHIFT,
+    FLAG_DITHER_OFFSET_Y_MASK, FLAG_DITHER_OFFSET_Y_SHIFT, FLAG_LIGHTMAPPED, FLAG_OUTLINE,
+    FLAG_PALETTE_MASK, FLAG_PALETTE_SHIFT, FLAG_SKINNING_MODE, FLAG_SKIP_NORMAL_MAP,
+    FLAG_TEXTURE_FILTER_LINEAR, FLAG_UNIFORM_ALPHA_MASK, FLAG_UNIFORM_ALPHA_SHIFT,
+    FLAG_USE_MATCAP_REFLECTION, FLAG_USE_UNIFORM_COLOR, FLAG_USE_UNIFORM_EMISSIVE,
+    FLAG_USE_UNIFORM_METALLIC, FLAG_USE_UNIFORM_ROUGHNESS, FLAG_USE_UNIFORM_SPECULAR,
+    FLAG_VERTEX_SNAP_MASK, FLAG_VERTEX_SNAP_SHIFT, LightType, PackedLight,
+    PackedUnifiedShadingState, ShadingStateIndex, pack_f16, pack_f16x2, pack_matcap_blend_modes,
+    pack_rgb8, pack_unorm8, unpack_f16, unpack_f16x2, unpack_matcap_blend_modes, update_u32_byte,
 };
 pub use vertex::{FORMAT_ALL, VERTEX_FORMAT_COUNT, VertexFormatInfo};
 pub use viewport::Viewport;
@@ -82,6 +90,10 @@ pub(crate) struct QuadBatchInfo {
     pub is_screen_space: bool,
     /// Viewport for this batch
     pub viewport: Viewport,
+    /// Scissor rectangle (None = clip to viewport)
+    pub clip: Option<Viewport>,
+    /// Blend mode (Alpha unless set with `blend_mode_2d()`)
+    pub blend_mode: BlendMode,
     /// Pass ID for render pass ordering (execution barrier)
     pub pass_id: u32,
     /// Z-index for 2D ordering within a pass (higher = closer to camera)
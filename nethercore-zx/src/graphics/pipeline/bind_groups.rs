@@ -8,10 +8,14 @@
 /// - Binding 0-1: Transforms (unified_transforms, mvp_indices)
 /// - Binding 2: Shading (shading_states)
 /// - Binding 3: Animation (unified_animation)
+/// - Binding 4: Baked lighting (lightmap_data)
 /// - Binding 5: Quad rendering (quad_instances)
 /// - Binding 6-7: EPU textures (env_radiance, sampler)
 /// - Binding 8-9: EPU state + frame uniforms
+/// - Binding 10: Palette swap (palette_data)
 /// - Binding 11: EPU SH9 (diffuse irradiance)
+/// - Binding 12-13: Scene lights (scene_lights, light_grid)
+/// - Binding 14: Morph targets (morph_data)
 ///
 /// CPU pre-computes absolute indices into unified_transforms (no frame_offsets needed).
 /// Screen dimensions eliminated - resolution_index packed into QuadInstance.mode.
@@ -79,6 +83,21 @@ pub(crate) fn create_frame_bind_group_layout(
             count: None,
         },
         // =====================================================================
+        // BAKED LIGHTING (binding 4)
+        // =====================================================================
+
+        // Binding 4: lightmap_data - baked per-vertex irradiance [f16 rg, f16 b_]
+        wgpu::BindGroupLayoutEntry {
+            binding: 4,
+            visibility: wgpu::ShaderStages::VERTEX,
+            ty: wgpu::BindingType::Buffer {
+                ty: wgpu::BufferBindingType::Storage { read_only: true },
+                has_dynamic_offset: false,
+                min_binding_size: None,
+            },
+            count: None,
+        },
+        // =====================================================================
         // QUAD RENDERING (binding 5)
         // =====================================================================
 
@@ -145,6 +164,21 @@ pub(crate) fn create_frame_bind_group_layout(
             count: None,
         },
         // =====================================================================
+        // PALETTE SWAP (binding 10)
+        // =====================================================================
+
+        // Binding 10: palettes from palette_load (256 RGBA8 colors each)
+        wgpu::BindGroupLayoutEntry {
+            binding: 10,
+            visibility: wgpu::ShaderStages::FRAGMENT,
+            ty: wgpu::BindingType::Buffer {
+                ty: wgpu::BufferBindingType::Storage { read_only: true },
+                has_dynamic_offset: false,
+                min_binding_size: None,
+            },
+            count: None,
+        },
+        // =====================================================================
         // EPU SH9 (binding 11)
         // =====================================================================
 
@@ -160,6 +194,47 @@ pub(crate) fn create_frame_bind_group_layout(
             },
             count: None,
         },
+        // =====================================================================
+        // SCENE LIGHTS (bindings 12-13)
+        // =====================================================================
+
+        // Binding 12: scene_lights - per-frame point/spot lights (256 max, 64 bytes each)
+        wgpu::BindGroupLayoutEntry {
+            binding: 12,
+            visibility: wgpu::ShaderStages::FRAGMENT,
+            ty: wgpu::BindingType::Buffer {
+                ty: wgpu::BufferBindingType::Storage { read_only: true },
+                has_dynamic_offset: false,
+                min_binding_size: None,
+            },
+            count: None,
+        },
+        // Binding 13: light_grid - world-space cells listing the scene lights that reach them
+        wgpu::BindGroupLayoutEntry {
+            binding: 13,
+            visibility: wgpu::ShaderStages::FRAGMENT,
+            ty: wgpu::BindingType::Buffer {
+                ty: wgpu::BufferBindingType::Storage { read_only: true },
+                has_dynamic_offset: false,
+                min_binding_size: None,
+            },
+            count: None,
+        },
+        // =====================================================================
+        // MORPH TARGETS (binding 14)
+        // =====================================================================
+
+        // Binding 14: morph_data - per-vertex target displacements [f16 pxy, pz nx, nyz]
+        wgpu::BindGroupLayoutEntry {
+            binding: 14,
+            visibility: wgpu::ShaderStages::VERTEX,
+            ty: wgpu::BindingType::Buffer {
+                ty: wgpu::BufferBindingType::Storage { read_only: true },
+                has_dynamic_offset: false,
+                min_binding_size: None,
+            },
+            count: None,
+        },
     ];
 
     device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
@@ -231,6 +306,17 @@ pub(crate) fn create_texture_bind_group_layout(device: &wgpu::Device) -> wgpu::B
                 ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
                 count: None,
             },
+            // Slot 0 as a texture array - quads drawn with texture_array_bind()
+            wgpu::BindGroupLayoutEntry {
+                binding: 6,
+                visibility: wgpu::ShaderStages::FRAGMENT,
+                ty: wgpu::BindingType::Texture {
+                    sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                    view_dimension: wgpu::TextureViewDimension::D2Array,
+                    multisampled: false,
+                },
+                count: None,
+            },
         ],
     })
 }
@@ -5,11 +5,12 @@
 use hashbrown::HashMap;
 use hashbrown::hash_map::Entry;
 
-use super::super::render_state::{PassConfig, RenderState};
+use super::super::render_state::{BlendMode, PassConfig, RenderState};
 use super::pipeline_creation::{
-    PipelineEntry, create_environment_pipeline, create_pipeline, create_quad_pipeline,
+    PipelineEntry, create_environment_fill_pipeline, create_environment_pipeline, create_pipeline,
+    create_quad_pipeline,
 };
-use super::pipeline_key::PipelineKey;
+use super::pipeline_key::{EnvironmentSource, PipelineKey};
 
 /// Cache for render pipelines
 ///
@@ -63,7 +64,7 @@ impl PipelineCache {
     /// compile successfully. Panics on any shader compilation failure, indicating
     /// a bug in shader generation.
     pub fn precompile_shaders_for_render_mode(&mut self, device: &wgpu::Device, render_mode: u8) {
-        let render_mode = render_mode.min(3);
+        let render_mode = render_mode.min(4);
         let mask = 1u8 << render_mode;
         if self.precompiled_render_modes & mask != 0 {
             return;
@@ -107,7 +108,7 @@ impl PipelineCache {
         use crate::graphics::FORMAT_NORMAL;
         use crate::shader_gen::generate_shader;
 
-        let render_mode = render_mode.min(3);
+        let render_mode = render_mode.min(4);
         let key = (render_mode, format);
         match shader_modules.entry(key) {
             Entry::Occupied(entry) => entry.into_mut(),
@@ -238,21 +239,25 @@ impl PipelineCache {
     /// `is_screen_space` determines depth behavior:
     /// - true (screen-space): always writes depth at 0 for early-z optimization
     /// - false (billboard): uses PassConfig depth settings
+    ///
+    /// `blend_mode` other than Alpha enables fixed-function blending and disables depth writes.
     pub fn get_or_create_quad(
         &mut self,
         device: &wgpu::Device,
         surface_format: wgpu::TextureFormat,
         pass_config: &PassConfig,
         is_screen_space: bool,
+        blend_mode: BlendMode,
     ) -> &PipelineEntry {
-        let key = PipelineKey::quad(pass_config, is_screen_space);
+        let key = PipelineKey::quad(pass_config, is_screen_space, blend_mode);
 
         match self.pipelines.entry(key) {
             Entry::Occupied(entry) => entry.into_mut(),
             Entry::Vacant(entry) => {
                 tracing::debug!(
-                    "Creating quad pipeline: is_screen_space={}, pass_config={:?}",
+                    "Creating quad pipeline: is_screen_space={}, blend_mode={:?}, pass_config={:?}",
                     is_screen_space,
+                    blend_mode,
                     pass_config
                 );
 
@@ -264,6 +269,7 @@ impl PipelineCache {
                     shader_module,
                     pass_config,
                     is_screen_space,
+                    blend_mode,
                 );
 
                 entry.insert(pipeline)
@@ -279,29 +285,60 @@ impl PipelineCache {
         device: &wgpu::Device,
         surface_format: wgpu::TextureFormat,
         pass_config: &PassConfig,
+        source: EnvironmentSource,
     ) -> &PipelineEntry {
-        let key = PipelineKey::environment(pass_config);
+        let key = PipelineKey::environment(pass_config, source);
 
         match self.pipelines.entry(key) {
             Entry::Occupied(entry) => entry.into_mut(),
             Entry::Vacant(entry) => {
                 tracing::debug!(
-                    "Creating environment pipeline: pass_config={:?}",
-                    pass_config
+                    "Creating environment pipeline: pass_config={:?}, source={:?}",
+                    pass_config,
+                    source
                 );
 
                 let shader_module = Self::get_or_create_environment_shader_module(
                     &mut self.environment_shader_module,
                     device,
                 );
-                let pipeline =
-                    create_environment_pipeline(device, surface_format, shader_module, pass_config);
+                let pipeline = create_environment_pipeline(
+                    device,
+                    surface_format,
+                    shader_module,
+                    pass_config,
+                    source,
+                );
 
                 entry.insert(pipeline)
             }
         }
     }
 
+    /// Get or create the pipeline that evaluates the environment into the background cache
+    pub fn get_or_create_environment_fill(
+        &mut self,
+        device: &wgpu::Device,
+        surface_format: wgpu::TextureFormat,
+    ) -> &PipelineEntry {
+        match self.pipelines.entry(PipelineKey::EnvironmentFill) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => {
+                tracing::debug!("Creating environment fill pipeline");
+
+                let shader_module = Self::get_or_create_environment_shader_module(
+                    &mut self.environment_shader_module,
+                    device,
+                );
+                entry.insert(create_environment_fill_pipeline(
+                    device,
+                    surface_format,
+                    shader_module,
+                ))
+            }
+        }
+    }
+
     /// Get a pipeline by key (works for both Regular and Quad)
     pub fn get_by_key(&self, key: &PipelineKey) -> Option<&PipelineEntry> {
         self.pipelines.get(key)
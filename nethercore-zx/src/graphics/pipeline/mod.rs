@@ -13,4 +13,4 @@ pub use cache::PipelineCache;
 
 // Re-export internal types used by graphics module
 pub(crate) use pipeline_creation::PipelineEntry;
-pub(crate) use pipeline_key::PipelineKey;
+pub(crate) use pipeline_key::{EnvironmentSource, PipelineKey};
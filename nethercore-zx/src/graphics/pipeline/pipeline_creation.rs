@@ -2,9 +2,10 @@
 //!
 //! Functions for creating render pipelines for different rendering modes.
 
-use super::super::render_state::{PassConfig, RenderState};
+use super::super::render_state::{BlendMode, PassConfig, RenderState};
 use super::super::vertex::VertexFormatInfo;
 use super::bind_groups::{create_frame_bind_group_layout, create_texture_bind_group_layout};
+use super::pipeline_key::EnvironmentSource;
 
 /// Cached pipeline entry with bind group layouts
 pub(crate) struct PipelineEntry {
@@ -19,6 +20,9 @@ pub(crate) struct PipelineEntry {
 // - PassConfig depth_compare and depth_write fields
 
 /// Create a new pipeline for the given vertex format and render state
+///
+/// A `blend_mode` other than Alpha turns on fixed-function blending and never
+/// writes depth, so blended meshes don't hide each other.
 pub(crate) fn create_pipeline(
     device: &wgpu::Device,
     surface_format: wgpu::TextureFormat,
@@ -42,6 +46,8 @@ pub(crate) fn create_pipeline(
     // Get vertex format info
     let vertex_info = VertexFormatInfo::for_format(format);
 
+    let blend = state.blend_mode.to_wgpu();
+
     // Create render pipeline
     let pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
         label: Some(&format!("Pipeline Mode{} Format{}", render_mode, format)),
@@ -57,7 +63,8 @@ pub(crate) fn create_pipeline(
             entry_point: Some("fs"),
             targets: &[Some(wgpu::ColorTargetState {
                 format: surface_format,
-                blend: None, // All rendering is opaque (dithering used for transparency)
+                // Opaque unless blend_mode_3d() is set (dithering used for transparency)
+                blend,
                 write_mask: pass_config.color_write_mask(),
             })],
             compilation_options: Default::default(),
@@ -73,7 +80,7 @@ pub(crate) fn create_pipeline(
         },
         depth_stencil: Some(wgpu::DepthStencilState {
             format: wgpu::TextureFormat::Depth24PlusStencil8,
-            depth_write_enabled: pass_config.depth_write,
+            depth_write_enabled: pass_config.depth_write && blend.is_none(),
             depth_compare: pass_config.depth_compare,
             stencil: pass_config.to_wgpu_stencil_state(),
             bias: wgpu::DepthBiasState::default(),
@@ -99,12 +106,16 @@ pub(crate) fn create_pipeline(
 /// `is_screen_space` determines depth behavior:
 /// - true: Screen-space quads use Always compare; depth write respects PassConfig
 /// - false: Billboards use PassConfig depth settings (they're 3D positioned)
+///
+/// `blend_mode` other than Alpha turns on fixed-function blending and never writes
+/// depth, so the quad composites over whatever is drawn underneath it.
 pub(crate) fn create_quad_pipeline(
     device: &wgpu::Device,
     surface_format: wgpu::TextureFormat,
     shader_module: &wgpu::ShaderModule,
     pass_config: &PassConfig,
     is_screen_space: bool,
+    blend_mode: BlendMode,
 ) -> PipelineEntry {
     // Create bind group layouts (same as regular pipelines)
     let bind_group_layout_frame = create_frame_bind_group_layout(device, 0);
@@ -135,13 +146,15 @@ pub(crate) fn create_quad_pipeline(
     } else {
         (pass_config.depth_write, pass_config.depth_compare)
     };
+    let blend = blend_mode.to_wgpu();
+    let depth_write_enabled = depth_write_enabled && blend.is_none();
 
     // Create render pipeline
     let pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
-        label: Some(if is_screen_space {
-            "Screen-Space Quad Pipeline"
-        } else {
-            "Billboard Pipeline"
+        label: Some(match (is_screen_space, blend.is_some()) {
+            (true, false) => "Screen-Space Quad Pipeline",
+            (true, true) => "Blended Screen-Space Quad Pipeline",
+            (false, _) => "Billboard Pipeline",
         }),
         layout: Some(&pipeline_layout),
         vertex: wgpu::VertexState {
@@ -155,7 +168,8 @@ pub(crate) fn create_quad_pipeline(
             entry_point: Some("fs"),
             targets: &[Some(wgpu::ColorTargetState {
                 format: surface_format,
-                blend: None, // All rendering is opaque (dithering used for transparency)
+                // Opaque unless blend_mode_2d() is set (dithering used for transparency)
+                blend,
                 write_mask: pass_config.color_write_mask(),
             })],
             compilation_options: Default::default(),
@@ -193,11 +207,15 @@ pub(crate) fn create_quad_pipeline(
 }
 
 /// Create environment rendering pipeline for fullscreen procedural environment
+///
+/// `source` picks the fragment shader: the EPU, the background cache (texture
+/// slot 0) or a cubemap sky (texture slot 0 as a 6-layer array).
 pub(crate) fn create_environment_pipeline(
     device: &wgpu::Device,
     surface_format: wgpu::TextureFormat,
     shader_module: &wgpu::ShaderModule,
     pass_config: &PassConfig,
+    source: EnvironmentSource,
 ) -> PipelineEntry {
     // Create bind group layouts (same as other pipelines)
     let bind_group_layout_frame = create_frame_bind_group_layout(device, 0);
@@ -222,7 +240,7 @@ pub(crate) fn create_environment_pipeline(
         },
         fragment: Some(wgpu::FragmentState {
             module: shader_module,
-            entry_point: Some("fs"),
+            entry_point: Some(source.entry_point()),
             targets: &[Some(wgpu::ColorTargetState {
                 format: surface_format,
                 blend: None, // No blending - opaque background
@@ -261,3 +279,58 @@ pub(crate) fn create_environment_pipeline(
         bind_group_layout_textures,
     }
 }
+
+/// Create the pipeline that evaluates the environment into the background cache
+///
+/// Same shader as the environment pipeline, but without depth/stencil: the
+/// whole viewport is evaluated so later frames can copy it.
+pub(crate) fn create_environment_fill_pipeline(
+    device: &wgpu::Device,
+    surface_format: wgpu::TextureFormat,
+    shader_module: &wgpu::ShaderModule,
+) -> PipelineEntry {
+    let bind_group_layout_frame = create_frame_bind_group_layout(device, 0);
+    let bind_group_layout_textures = create_texture_bind_group_layout(device);
+
+    let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+        label: Some("Environment Fill Pipeline Layout"),
+        bind_group_layouts: &[&bind_group_layout_frame, &bind_group_layout_textures],
+        push_constant_ranges: &[],
+    });
+
+    let pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+        label: Some("Environment Fill Pipeline"),
+        layout: Some(&pipeline_layout),
+        vertex: wgpu::VertexState {
+            module: shader_module,
+            entry_point: Some("vs"),
+            buffers: &[],
+            compilation_options: Default::default(),
+        },
+        fragment: Some(wgpu::FragmentState {
+            module: shader_module,
+            entry_point: Some("fs"),
+            targets: &[Some(wgpu::ColorTargetState {
+                format: surface_format,
+                blend: None,
+                write_mask: wgpu::ColorWrites::ALL,
+            })],
+            compilation_options: Default::default(),
+        }),
+        primitive: wgpu::PrimitiveState {
+            topology: wgpu::PrimitiveTopology::TriangleList,
+            cull_mode: None,
+            ..Default::default()
+        },
+        depth_stencil: None,
+        multisample: wgpu::MultisampleState::default(),
+        multiview: None,
+        cache: None,
+    });
+
+    PipelineEntry {
+        pipeline,
+        bind_group_layout_frame,
+        bind_group_layout_textures,
+    }
+}
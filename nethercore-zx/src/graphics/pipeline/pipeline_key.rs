@@ -3,7 +3,7 @@
 //! Pipeline keys uniquely identify a pipeline configuration for caching purposes.
 
 use super::super::RenderState;
-use super::super::render_state::PassConfig;
+use super::super::render_state::{BlendMode, PassConfig};
 
 /// Key for pipeline cache lookup
 ///
@@ -17,6 +17,8 @@ pub(crate) enum PipelineKey {
         render_mode: u8,
         vertex_format: u8,
         cull_mode: u8,
+        /// Blend mode (blended meshes never write depth)
+        blend_mode: BlendMode,
         /// Hash of PassConfig fields that affect pipeline state
         pass_config_hash: u64,
     },
@@ -26,12 +28,40 @@ pub(crate) enum PipelineKey {
         pass_config_hash: u64,
         /// True for screen-space quads (always write depth), false for billboards (use PassConfig)
         is_screen_space: bool,
+        /// Blend mode (screen-space only; blended quads never write depth)
+        blend_mode: BlendMode,
     },
-    /// Procedural environment rendering pipeline (always renders behind)
+    /// Environment background pipeline (always renders behind)
     Environment {
         /// Hash of PassConfig fields that affect pipeline state
         pass_config_hash: u64,
+        /// Where the background comes from
+        source: EnvironmentSource,
     },
+    /// Evaluates the environment into the background cache (no depth/stencil)
+    EnvironmentFill,
+}
+
+/// Where an environment pipeline's background comes from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum EnvironmentSource {
+    /// Evaluate the EPU per pixel
+    Epu,
+    /// Copy the EPU background cache
+    Cached,
+    /// Sample the cubemap passed to `sky_draw()`
+    Sky,
+}
+
+impl EnvironmentSource {
+    /// Fragment shader entry point in the environment shader
+    pub fn entry_point(self) -> &'static str {
+        match self {
+            Self::Epu => "fs",
+            Self::Cached => "fs_cached",
+            Self::Sky => "fs_sky",
+        }
+    }
 }
 
 /// Compute a hash of PassConfig fields that affect pipeline state
@@ -57,22 +87,25 @@ impl PipelineKey {
             render_mode,
             vertex_format: format,
             cull_mode: state.cull_mode as u8,
+            blend_mode: state.blend_mode,
             pass_config_hash: pass_config_hash(pass_config),
         }
     }
 
     /// Create a quad pipeline key
-    pub fn quad(pass_config: &PassConfig, is_screen_space: bool) -> Self {
+    pub fn quad(pass_config: &PassConfig, is_screen_space: bool, blend_mode: BlendMode) -> Self {
         Self::Quad {
             pass_config_hash: pass_config_hash(pass_config),
             is_screen_space,
+            blend_mode,
         }
     }
 
     /// Create an environment pipeline key
-    pub fn environment(pass_config: &PassConfig) -> Self {
+    pub fn environment(pass_config: &PassConfig, source: EnvironmentSource) -> Self {
         Self::Environment {
             pass_config_hash: pass_config_hash(pass_config),
+            source,
         }
     }
 }
@@ -27,7 +27,7 @@ pub enum QuadMode {
 /// Used with a static unit quad mesh for instanced rendering.
 ///
 /// IMPORTANT: position is [f32; 4] to match WGSL vec4<f32> 16-byte alignment naturally
-/// IMPORTANT: Array elements in WGSL must be 16-byte aligned, so we need final padding to reach 64 bytes!
+/// IMPORTANT: Array elements in WGSL must be 16-byte aligned, so the struct must stay exactly 64 bytes!
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 pub struct QuadInstance {
@@ -58,8 +58,9 @@ pub struct QuadInstance {
     /// (Set by GPU upload transform, computed from logical view index)
     pub proj_index: u32, // 4 bytes
 
-    /// Padding to maintain 64-byte size and 16-byte alignment
-    pub _pad: u32, // 4 bytes
+    /// Texture array layer + 1 for quads drawn with `texture_array_bind()`
+    /// (0 = sample the slot 0 texture directly)
+    pub array_layer: u32, // 4 bytes
 }
 
 // Safety: QuadInstance is repr(C) with only primitive types and explicit padding
@@ -85,7 +86,7 @@ impl QuadInstance {
             shading_state_index,
             view_index,
             proj_index: 0, // Set by GPU upload transform
-            _pad: 0,
+            array_layer: 0,
         }
     }
 
@@ -135,7 +136,7 @@ impl QuadInstance {
             shading_state_index,
             view_index,
             proj_index: 0, // Set by GPU upload transform
-            _pad: 0,
+            array_layer: 0,
         }
     }
 }
@@ -161,7 +162,7 @@ mod tests {
         // offset 48: shading_state_index u32 (4 bytes) = 4 bytes
         // offset 52: view_index u32 (4 bytes) = 4 bytes
         // offset 56: proj_index u32 (4 bytes) = 4 bytes
-        // offset 60: _pad u32 (4 bytes) = 4 bytes
+        // offset 60: array_layer u32 (4 bytes) = 4 bytes
         // Total: 64 bytes (16-byte aligned)
 
         assert_eq!(
@@ -232,9 +233,9 @@ mod tests {
         );
 
         assert_eq!(
-            &instance._pad as *const _ as usize - base_ptr,
+            &instance.array_layer as *const _ as usize - base_ptr,
             60,
-            "_pad must be at offset 60"
+            "array_layer must be at offset 60"
         );
     }
 
@@ -89,6 +89,80 @@ impl TextureFilter {
     }
 }
 
+/// Blend mode for screen-space 2D quads
+///
+/// Alpha is the regular dithered path. The other modes composite the quad's
+/// color with what is already on screen; pixels discarded by the alpha dither
+/// still leave the framebuffer untouched.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[repr(u8)]
+pub enum BlendMode {
+    /// Replace (with dithered alpha), the default
+    #[default]
+    Alpha = 0,
+    /// dst + src (glows, flashes)
+    Additive = 1,
+    /// dst × src (darkness, tinting)
+    Multiply = 2,
+    /// dst + src × (1 − dst) (soft lightening)
+    Screen = 3,
+    /// dst − src
+    Subtract = 4,
+}
+
+impl BlendMode {
+    pub fn from_u32(value: u32) -> Option<Self> {
+        match value {
+            0 => Some(BlendMode::Alpha),
+            1 => Some(BlendMode::Additive),
+            2 => Some(BlendMode::Multiply),
+            3 => Some(BlendMode::Screen),
+            4 => Some(BlendMode::Subtract),
+            _ => None,
+        }
+    }
+
+    /// Fixed-function blend state (None for the opaque dithered path)
+    ///
+    /// Destination alpha is always preserved.
+    pub fn to_wgpu(self) -> Option<wgpu::BlendState> {
+        use wgpu::{BlendComponent, BlendFactor, BlendOperation};
+
+        let component = |src_factor, dst_factor, operation| BlendComponent {
+            src_factor,
+            dst_factor,
+            operation,
+        };
+        let color = match self {
+            BlendMode::Alpha => return None,
+            BlendMode::Additive => {
+                component(BlendFactor::One, BlendFactor::One, BlendOperation::Add)
+            }
+            BlendMode::Multiply => {
+                component(BlendFactor::Dst, BlendFactor::Zero, BlendOperation::Add)
+            }
+            BlendMode::Screen => component(
+                BlendFactor::OneMinusDst,
+                BlendFactor::One,
+                BlendOperation::Add,
+            ),
+            BlendMode::Subtract => component(
+                BlendFactor::One,
+                BlendFactor::One,
+                BlendOperation::ReverseSubtract,
+            ),
+        };
+        Some(wgpu::BlendState {
+            color,
+            alpha: BlendComponent {
+                src_factor: BlendFactor::Zero,
+                dst_factor: BlendFactor::One,
+                operation: BlendOperation::Add,
+            },
+        })
+    }
+}
+
 /// Matcap blend mode (Mode 1 only, slots 1-3)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 #[repr(u8)]
@@ -261,6 +335,8 @@ pub struct RenderState {
     pub depth_test: bool,
     /// Face culling mode
     pub cull_mode: CullMode,
+    /// Blend mode of 3D draws (non-alpha modes never write depth)
+    pub blend_mode: BlendMode,
 }
 
 impl Default for RenderState {
@@ -268,6 +344,7 @@ impl Default for RenderState {
         Self {
             depth_test: true,
             cull_mode: CullMode::None,
+            blend_mode: BlendMode::Alpha,
         }
     }
 }
@@ -281,6 +358,7 @@ mod tests {
         let state = RenderState::default();
         assert!(state.depth_test);
         assert_eq!(state.cull_mode, CullMode::None);
+        assert_eq!(state.blend_mode, BlendMode::Alpha);
         // Note: texture_filter is not part of RenderState
         // It's in PackedUnifiedShadingState.flags (bit 1)
     }
@@ -307,6 +385,29 @@ mod tests {
         assert_eq!(TextureFilter::Linear.to_wgpu(), wgpu::FilterMode::Linear);
     }
 
+    #[test]
+    fn test_blend_mode_conversion() {
+        assert_eq!(BlendMode::from_u32(0), Some(BlendMode::Alpha));
+        assert_eq!(BlendMode::from_u32(4), Some(BlendMode::Subtract));
+        assert_eq!(BlendMode::from_u32(5), None);
+
+        assert!(BlendMode::Alpha.to_wgpu().is_none());
+        let additive = BlendMode::Additive.to_wgpu().unwrap();
+        assert_eq!(additive.color.dst_factor, wgpu::BlendFactor::One);
+        let subtract = BlendMode::Subtract.to_wgpu().unwrap();
+        assert_eq!(
+            subtract.color.operation,
+            wgpu::BlendOperation::ReverseSubtract
+        );
+        // Destination alpha is never modified
+        for mode in [BlendMode::Additive, BlendMode::Multiply, BlendMode::Screen] {
+            assert_eq!(
+                mode.to_wgpu().unwrap().alpha.dst_factor,
+                wgpu::BlendFactor::One
+            );
+        }
+    }
+
     #[test]
     fn test_render_state_depth_test_toggle() {
         let mut state = RenderState::default();
@@ -0,0 +1,30 @@
+//! Per-frame render statistics
+//!
+//! Counted while the frame's draw commands are executed and reported to games
+//! through `render_stats()` and to the debug inspector.
+
+use bytemuck::{Pod, Zeroable};
+
+use super::command_buffer::VRPCommand;
+
+/// Render statistics for one frame, written to WASM memory (16 bytes)
+#[repr(C)]
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq, Pod, Zeroable)]
+pub struct RenderStats {
+    /// GPU draw calls issued
+    pub draw_calls: u32,
+    /// Triangles submitted to the GPU
+    pub triangles: u32,
+    /// Triangles skipped because their mesh was outside the view frustum
+    pub triangles_culled: u32,
+    /// Texture bind group changes
+    pub texture_binds: u32,
+}
+
+impl RenderStats {
+    /// Count one executed draw command
+    pub(super) fn record_draw(&mut self, cmd: &VRPCommand) {
+        self.draw_calls = self.draw_calls.saturating_add(1);
+        self.triangles = self.triangles.saturating_add(cmd.triangle_count());
+    }
+}
@@ -0,0 +1,279 @@
+//! Scene lights and the world-space light grid
+//!
+//! Scene lights (`light_add_point()` / `light_add_spot()`) are collected per
+//! frame, separately from the 4 lights in each shading state. Before upload
+//! they are binned into a world-space cluster grid around their bounds, so a
+//! fragment only evaluates the lights whose range reaches its cell. The grid
+//! doesn't depend on the camera, so every view and viewport shares it.
+
+use bytemuck::{Pod, Zeroable};
+use glam::{UVec3, Vec3};
+
+/// Maximum scene lights per frame
+pub const MAX_SCENE_LIGHTS: usize = 256;
+
+/// Maximum grid cells along each axis
+pub const LIGHT_GRID_MAX_DIM: u32 = 16;
+
+/// Maximum lights evaluated in one cell (extra overlapping lights are skipped)
+pub const MAX_LIGHTS_PER_CELL: usize = 32;
+
+/// u32 words before the cell table: origin (3 × f32), light count,
+/// inverse cell size (3 × f32), padding, dimensions (3 × u32), padding
+pub const LIGHT_GRID_HEADER: usize = 12;
+
+/// Maximum grid cells
+const LIGHT_GRID_MAX_CELLS: usize =
+    (LIGHT_GRID_MAX_DIM * LIGHT_GRID_MAX_DIM * LIGHT_GRID_MAX_DIM) as usize;
+
+/// Size of the light grid buffer in u32 words (header + cell table + indices)
+pub const LIGHT_GRID_CAPACITY: usize =
+    LIGHT_GRID_HEADER + LIGHT_GRID_MAX_CELLS * (2 + MAX_LIGHTS_PER_CELL);
+
+/// `cos_outer` of point lights (no cone)
+const NO_SPOT_CONE: f32 = -2.0;
+
+/// One scene light as uploaded to the GPU (64 bytes)
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable)]
+pub struct PackedSceneLight {
+    pub position: [f32; 3],
+    /// Distance where the light fades to zero
+    pub range: f32,
+    /// Linear RGB (0.0-1.0)
+    pub color: [f32; 3],
+    /// Intensity multiplier (0.0-8.0)
+    pub intensity: f32,
+    /// Unit direction the spot points (unused for point lights)
+    pub direction: [f32; 3],
+    /// Cosine of the inner cone half-angle (full intensity inside)
+    pub cos_inner: f32,
+    /// Cosine of the outer cone half-angle (no light outside)
+    pub cos_outer: f32,
+    pub _pad: [f32; 3],
+}
+
+impl PackedSceneLight {
+    /// Create a point light
+    pub fn point(position: Vec3, color: Vec3, intensity: f32, range: f32) -> Self {
+        Self {
+            position: position.to_array(),
+            range,
+            color: color.to_array(),
+            intensity: intensity.clamp(0.0, 8.0),
+            direction: [0.0, -1.0, 0.0],
+            cos_inner: NO_SPOT_CONE,
+            cos_outer: NO_SPOT_CONE,
+            _pad: [0.0; 3],
+        }
+    }
+
+    /// Create a spot light
+    ///
+    /// Angles are cone half-angles in radians; light fades from full at
+    /// `inner_angle` to zero at `outer_angle`.
+    pub fn spot(
+        position: Vec3,
+        direction: Vec3,
+        color: Vec3,
+        intensity: f32,
+        range: f32,
+        inner_angle: f32,
+        outer_angle: f32,
+    ) -> Self {
+        let outer_angle = outer_angle.clamp(0.0, std::f32::consts::PI);
+        let cos_outer = outer_angle.cos();
+        // Keep the fade non-empty so the shader's smoothstep stays defined
+        let cos_inner = inner_angle
+            .clamp(0.0, outer_angle)
+            .cos()
+            .max(cos_outer + 1e-4);
+        Self {
+            direction: direction.normalize_or(Vec3::NEG_Y).to_array(),
+            cos_inner,
+            cos_outer,
+            ..Self::point(position, color, intensity, range)
+        }
+    }
+
+    /// True for spot lights
+    pub fn is_spot(&self) -> bool {
+        self.cos_outer > NO_SPOT_CONE
+    }
+}
+
+/// World-space light grid, rebuilt each frame from the scene lights
+///
+/// Layout (u32 words): header, then per-cell `[first index, count]` pairs,
+/// then the light indices the cells point into.
+#[derive(Debug, Default)]
+pub struct LightGrid {
+    words: Vec<u32>,
+    /// Scratch (cell, light) pairs, reused between frames
+    pairs: Vec<(u32, u32)>,
+}
+
+impl LightGrid {
+    /// Grid data for the light grid buffer
+    pub fn words(&self) -> &[u32] {
+        &self.words
+    }
+
+    /// Bin lights into grid cells covering their combined bounds
+    ///
+    /// Cells are sized so the longest axis has `LIGHT_GRID_MAX_DIM` cells. A
+    /// light is listed in every cell its range sphere touches, in light order,
+    /// up to `MAX_LIGHTS_PER_CELL` per cell.
+    pub fn build(&mut self, lights: &[PackedSceneLight]) {
+        self.words.clear();
+        self.words.resize(LIGHT_GRID_HEADER, 0);
+        self.pairs.clear();
+        if lights.is_empty() {
+            return;
+        }
+
+        let (min, max) = lights.iter().fold(
+            (Vec3::splat(f32::INFINITY), Vec3::splat(f32::NEG_INFINITY)),
+            |(min, max), light| {
+                let center = Vec3::from(light.position);
+                (min.min(center - light.range), max.max(center + light.range))
+            },
+        );
+        let extent = (max - min).max(Vec3::splat(1e-3));
+        let cell = extent.max_element() / LIGHT_GRID_MAX_DIM as f32;
+        let dims = (extent / cell)
+            .ceil()
+            .as_uvec3()
+            .clamp(UVec3::ONE, UVec3::splat(LIGHT_GRID_MAX_DIM));
+        let cell_size = extent / dims.as_vec3();
+        let inv_cell_size = dims.as_vec3() / extent;
+
+        for (index, light) in lights.iter().enumerate() {
+            let center = Vec3::from(light.position);
+            let radius = light.range;
+            let lo = ((center - radius - min) * inv_cell_size)
+                .floor()
+                .as_uvec3()
+                .min(dims - 1);
+            let hi = ((center + radius - min) * inv_cell_size)
+                .floor()
+                .as_uvec3()
+                .min(dims - 1);
+            for z in lo.z..=hi.z {
+                for y in lo.y..=hi.y {
+                    for x in lo.x..=hi.x {
+                        let cell_min = min + UVec3::new(x, y, z).as_vec3() * cell_size;
+                        let closest = center.clamp(cell_min, cell_min + cell_size);
+                        if closest.distance_squared(center) <= radius * radius {
+                            let cell_index = x + dims.x * (y + dims.y * z);
+                            self.pairs.push((cell_index, index as u32));
+                        }
+                    }
+                }
+            }
+        }
+        // Stable sort keeps light order within each cell
+        self.pairs.sort_by_key(|&(cell_index, _)| cell_index);
+
+        let cell_count = (dims.x * dims.y * dims.z) as usize;
+        self.words[0..3].copy_from_slice(&min.to_array().map(f32::to_bits));
+        self.words[3] = lights.len() as u32;
+        self.words[4..7].copy_from_slice(&inv_cell_size.to_array().map(f32::to_bits));
+        self.words[8..11].copy_from_slice(&dims.to_array());
+        self.words.resize(LIGHT_GRID_HEADER + cell_count * 2, 0);
+
+        for run in self.pairs.chunk_by(|a, b| a.0 == b.0) {
+            let count = run.len().min(MAX_LIGHTS_PER_CELL);
+            let entry = LIGHT_GRID_HEADER + run[0].0 as usize * 2;
+            self.words[entry] = self.words.len() as u32;
+            self.words[entry + 1] = count as u32;
+            self.words
+                .extend(run[..count].iter().map(|&(_, light_index)| light_index));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Lights listed in the cell containing `point`
+    fn lights_at(grid: &LightGrid, point: Vec3) -> Vec<u32> {
+        let words = grid.words();
+        let origin = Vec3::from_array([0, 1, 2].map(|i| f32::from_bits(words[i])));
+        let inv_cell_size = Vec3::from_array([4, 5, 6].map(|i| f32::from_bits(words[i])));
+        let dims = UVec3::new(words[8], words[9], words[10]);
+        let cell = ((point - origin) * inv_cell_size).floor();
+        if cell.min_element() < 0.0 || cell.cmpge(dims.as_vec3()).any() {
+            return Vec::new();
+        }
+        let cell = cell.as_uvec3();
+        let entry = LIGHT_GRID_HEADER + 2 * (cell.x + dims.x * (cell.y + dims.y * cell.z)) as usize;
+        let (first, count) = (words[entry] as usize, words[entry + 1] as usize);
+        words[first..first + count].to_vec()
+    }
+
+    fn point(x: f32, z: f32, range: f32) -> PackedSceneLight {
+        PackedSceneLight::point(Vec3::new(x, 0.0, z), Vec3::ONE, 1.0, range)
+    }
+
+    #[test]
+    fn test_scene_light_size() {
+        assert_eq!(std::mem::size_of::<PackedSceneLight>(), 64);
+    }
+
+    #[test]
+    fn test_empty_grid_has_no_lights() {
+        let mut grid = LightGrid::default();
+        grid.build(&[]);
+        assert_eq!(grid.words().len(), LIGHT_GRID_HEADER);
+        assert_eq!(grid.words()[3], 0);
+    }
+
+    #[test]
+    fn test_cells_list_only_nearby_lights() {
+        // A street of lights 20 units apart, each reaching 4 units
+        let lights: Vec<_> = (0..8).map(|i| point(i as f32 * 20.0, 0.0, 4.0)).collect();
+        let mut grid = LightGrid::default();
+        grid.build(&lights);
+
+        assert_eq!(grid.words()[3], 8);
+        assert_eq!(lights_at(&grid, Vec3::new(60.0, 0.0, 0.0)), [3]);
+        assert_eq!(lights_at(&grid, Vec3::new(141.0, 0.0, 1.0)), [7]);
+        // Between two lights, out of range of both
+        assert!(lights_at(&grid, Vec3::new(10.0, 0.0, 0.0)).is_empty());
+        // Outside the grid
+        assert!(lights_at(&grid, Vec3::new(-50.0, 0.0, 0.0)).is_empty());
+    }
+
+    #[test]
+    fn test_overlapping_lights_keep_order_and_cap() {
+        let lights: Vec<_> = (0..MAX_LIGHTS_PER_CELL + 8)
+            .map(|i| point(i as f32 * 0.01, 0.0, 5.0))
+            .collect();
+        let mut grid = LightGrid::default();
+        grid.build(&lights);
+
+        let listed = lights_at(&grid, Vec3::new(0.1, 0.0, 0.0));
+        let expected: Vec<u32> = (0..MAX_LIGHTS_PER_CELL as u32).collect();
+        assert_eq!(listed, expected);
+        assert!(grid.words().len() <= LIGHT_GRID_CAPACITY);
+    }
+
+    #[test]
+    fn test_spot_cone_stays_ordered() {
+        let spot = PackedSceneLight::spot(
+            Vec3::ZERO,
+            Vec3::new(0.0, -2.0, 0.0),
+            Vec3::ONE,
+            1.0,
+            10.0,
+            0.5,
+            0.3,
+        );
+        assert!(spot.is_spot());
+        assert_eq!(spot.direction, [0.0, -1.0, 0.0]);
+        assert!(spot.cos_inner > spot.cos_outer);
+        assert!(!point(0.0, 0.0, 1.0).is_spot());
+    }
+}
@@ -1,7 +1,8 @@
 //! Texture management for Nethercore ZX graphics.
 //!
 //! Handles texture loading, VRAM tracking, and fallback textures.
-//! Supports RGBA8 (uncompressed), BC7 (compressed RGBA), and BC5 (compressed RG for normal maps).
+//! Supports RGBA8 (uncompressed), BC7 (compressed RGBA), and BC5 (compressed RG for normal maps),
+//! plus their ETC2/EAC RG11 counterparts, as single textures or as texture arrays.
 
 use hashbrown::HashMap;
 
@@ -17,12 +18,39 @@ pub use super::render_state::TextureHandle;
 ///
 /// Fields tracked for debugging and VRAM accounting.
 pub(crate) struct TextureEntry {
-    pub _texture: wgpu::Texture,
+    pub texture: wgpu::Texture,
     pub view: wgpu::TextureView,
     pub _width: u32,
     pub _height: u32,
     /// Size in bytes (for VRAM tracking)
     pub size_bytes: usize,
+    /// True if the view is a 2D array (bound separately from plain textures)
+    pub is_array: bool,
+}
+
+/// wgpu format a ROM texture format uploads as
+pub(crate) fn wgpu_texture_format(format: TextureFormat) -> wgpu::TextureFormat {
+    match format {
+        TextureFormat::Rgba8 => wgpu::TextureFormat::Rgba8Unorm,
+        TextureFormat::Bc7 => wgpu::TextureFormat::Bc7RgbaUnorm,
+        TextureFormat::Bc5 => wgpu::TextureFormat::Bc5RgUnorm,
+        TextureFormat::Etc2 => wgpu::TextureFormat::Etc2Rgba8Unorm,
+        TextureFormat::EacRg11 => wgpu::TextureFormat::EacRg11Unorm,
+        TextureFormat::Indexed8 => wgpu::TextureFormat::R8Unorm,
+    }
+}
+
+/// Whether a device with `features` can sample textures of `format`
+pub(crate) fn texture_format_supported(features: wgpu::Features, format: TextureFormat) -> bool {
+    match format {
+        TextureFormat::Rgba8 | TextureFormat::Indexed8 => true,
+        TextureFormat::Bc7 | TextureFormat::Bc5 => {
+            features.contains(wgpu::Features::TEXTURE_COMPRESSION_BC)
+        }
+        TextureFormat::Etc2 | TextureFormat::EacRg11 => {
+            features.contains(wgpu::Features::TEXTURE_COMPRESSION_ETC2)
+        }
+    }
 }
 
 /// Configuration for loading block-compressed textures
@@ -35,12 +63,22 @@ struct TextureBcConfig<'a> {
     track_vram: bool,
 }
 
+/// Configuration for loading texture arrays
+pub(crate) struct TextureArrayConfig<'a> {
+    pub width: u32,
+    pub height: u32,
+    pub layers: u32,
+    /// Every layer back to back, each laid out as for a single texture
+    pub data: &'a [u8],
+    pub format: TextureFormat,
+}
+
 /// Manages game textures, VRAM budget, and fallback textures.
 ///
 /// This struct owns all texture resources and handles:
 /// - Loading textures from RGBA8 pixel data
 /// - VRAM budget tracking and enforcement
-/// - Fallback textures (checkerboard, white, font)
+/// - Fallback textures (checkerboard, white, white array, font)
 pub struct TextureManager {
     textures: HashMap<u32, TextureEntry>,
     next_texture_id: u32,
@@ -49,6 +87,8 @@ pub struct TextureManager {
     // Fallback textures
     fallback_checkerboard: TextureHandle,
     fallback_white: TextureHandle,
+    /// 1×1 single-layer white array, bound when slot 0 is not a texture array
+    fallback_white_array: TextureHandle,
 
     // Built-in font texture
     font_texture: TextureHandle,
@@ -57,7 +97,7 @@ pub struct TextureManager {
 impl TextureManager {
     /// Create a new TextureManager with fallback textures.
     ///
-    /// This creates the checkerboard, white, and font textures.
+    /// This creates the checkerboard, white, white array, and font textures.
     pub fn new(device: &wgpu::Device, queue: &wgpu::Queue) -> Result<Self> {
         let mut manager = Self {
             textures: HashMap::new(),
@@ -65,6 +105,7 @@ impl TextureManager {
             vram_used: 0,
             fallback_checkerboard: TextureHandle::INVALID,
             fallback_white: TextureHandle::INVALID,
+            fallback_white_array: TextureHandle::INVALID,
             font_texture: TextureHandle::INVALID,
         };
 
@@ -73,7 +114,7 @@ impl TextureManager {
         Ok(manager)
     }
 
-    /// Create fallback textures (checkerboard, white, and font)
+    /// Create fallback textures (checkerboard, white, white array, and font)
     fn create_fallback_textures(
         &mut self,
         device: &wgpu::Device,
@@ -109,6 +150,20 @@ impl TextureManager {
         self.fallback_white = self
             .load_texture_internal(device, queue, 1, 1, &white_data, false)
             .map_err(|e| anyhow::anyhow!("Failed to create white fallback texture: {}", e))?;
+        self.fallback_white_array = self
+            .load_texture_array_internal(
+                device,
+                queue,
+                TextureArrayConfig {
+                    width: 1,
+                    height: 1,
+                    layers: 1,
+                    data: &white_data,
+                    format: TextureFormat::Rgba8,
+                },
+                false,
+            )
+            .map_err(|e| anyhow::anyhow!("Failed to create white fallback array: {}", e))?;
 
         // Load built-in font texture
         use crate::font;
@@ -146,11 +201,13 @@ impl TextureManager {
         self.load_texture_internal(device, queue, width, height, pixels, true)
     }
 
-    /// Load a texture with explicit format (RGBA8, BC7, or BC5).
+    /// Load a texture with explicit format (RGBA8, BC7, BC5, ETC2, EAC RG11 or Indexed8).
     ///
     /// This is the main entry point for loading textures from ROM data packs.
     /// BC7 textures provide 4× compression compared to RGBA8.
     /// BC5 textures are 2-channel (RG) for normal maps where Z is reconstructed.
+    /// ETC2 and EAC RG11 are their counterparts for GPUs without BC support.
+    /// Indexed8 textures hold one palette index per pixel (see `palette_bind()`).
     pub fn load_texture_with_format(
         &mut self,
         device: &wgpu::Device,
@@ -164,36 +221,146 @@ impl TextureManager {
             TextureFormat::Rgba8 => {
                 self.load_texture_internal(device, queue, width, height, data, true)
             }
-            TextureFormat::Bc7 => self.load_texture_bc_internal(
+            _ => self.load_texture_bc_internal(
                 device,
                 queue,
                 TextureBcConfig {
                     width,
                     height,
                     data,
-                    wgpu_format: wgpu::TextureFormat::Bc7RgbaUnorm,
-                    format_name: "BC7",
+                    wgpu_format: wgpu_texture_format(format),
+                    format_name: format.short_name(),
                     track_vram: true,
                 },
             ),
-            TextureFormat::Bc5 => self.load_texture_bc_internal(
-                device,
-                queue,
-                TextureBcConfig {
+        }
+    }
+
+    /// Load a texture array (RGBA8 or any compressed format).
+    ///
+    /// Every layer shares one size and format, so sprites drawn from different
+    /// layers need no texture rebind between them.
+    pub(crate) fn load_texture_array_with_format(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        config: TextureArrayConfig,
+    ) -> Result<TextureHandle> {
+        self.load_texture_array_internal(device, queue, config, true)
+    }
+
+    /// Internal texture array loading (optionally tracks VRAM)
+    fn load_texture_array_internal(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        config: TextureArrayConfig,
+        track_vram: bool,
+    ) -> Result<TextureHandle> {
+        let TextureArrayConfig {
+            width,
+            height,
+            layers,
+            data,
+            format,
+        } = config;
+        let (wgpu_format, layer_size) = match format {
+            TextureFormat::Rgba8 => (wgpu::TextureFormat::Rgba8Unorm, width * height * 4),
+            TextureFormat::Indexed8 => (wgpu::TextureFormat::R8Unorm, width * height),
+            // Compressed formats: 4×4 blocks, 16 bytes per block
+            _ => (
+                wgpu_texture_format(format),
+                width.div_ceil(4) * height.div_ceil(4) * 16,
+            ),
+        };
+        let expected_size = layer_size as usize * layers as usize;
+        if data.len() != expected_size {
+            anyhow::bail!(
+                "Texture array data size mismatch: expected {} bytes for {} {}x{} layers, got {}",
+                expected_size,
+                layers,
+                width,
+                height,
+                data.len()
+            );
+        }
+
+        let size_bytes = expected_size;
+
+        // Check VRAM budget
+        if track_vram && self.vram_used + size_bytes > VRAM_LIMIT {
+            anyhow::bail!(
+                "VRAM budget exceeded: {} + {} > {} bytes",
+                self.vram_used,
+                size_bytes,
+                VRAM_LIMIT
+            );
+        }
+
+        let texture = device.create_texture_with_data(
+            queue,
+            &wgpu::TextureDescriptor {
+                label: Some("Texture Array"),
+                size: wgpu::Extent3d {
                     width,
                     height,
-                    data,
-                    wgpu_format: wgpu::TextureFormat::Bc5RgUnorm,
-                    format_name: "BC5",
-                    track_vram: true,
+                    depth_or_array_layers: layers,
                 },
-            ),
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: wgpu::TextureDimension::D2,
+                format: wgpu_format,
+                usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+                view_formats: &[],
+            },
+            wgpu::util::TextureDataOrder::LayerMajor,
+            data,
+        );
+
+        // Explicit dimension: a single-layer texture would default to a D2 view
+        let view = texture.create_view(&wgpu::TextureViewDescriptor {
+            dimension: Some(wgpu::TextureViewDimension::D2Array),
+            ..Default::default()
+        });
+
+        let handle = TextureHandle(self.next_texture_id);
+        self.next_texture_id += 1;
+
+        self.textures.insert(
+            handle.0,
+            TextureEntry {
+                texture,
+                view,
+                _width: width,
+                _height: height,
+                size_bytes,
+                is_array: true,
+            },
+        );
+
+        if track_vram {
+            self.vram_used += size_bytes;
         }
+
+        tracing::debug!(
+            "Loaded texture array {}: {}x{} × {} layers, {} bytes (VRAM: {}/{})",
+            handle.0,
+            width,
+            height,
+            layers,
+            size_bytes,
+            self.vram_used,
+            VRAM_LIMIT
+        );
+
+        Ok(handle)
     }
 
-    /// Internal block-compressed texture loading (BC7 or BC5)
+    /// Internal texture loading for formats uploaded as-is (BC7, BC5, ETC2,
+    /// EAC RG11 or Indexed8)
     ///
-    /// Both BC7 and BC5 use 4×4 blocks with 16 bytes per block.
+    /// The compressed formats use 4×4 blocks with 16 bytes per block; Indexed8
+    /// is one byte per pixel.
     fn load_texture_bc_internal(
         &mut self,
         device: &wgpu::Device,
@@ -208,10 +375,11 @@ impl TextureManager {
             format_name,
             track_vram,
         } = config;
-        // BC7/BC5: 4×4 blocks, 16 bytes per block
-        let blocks_x = width.div_ceil(4);
-        let blocks_y = height.div_ceil(4);
-        let expected_size = (blocks_x * blocks_y * 16) as usize;
+        let (block_width, block_height) = wgpu_format.block_dimensions();
+        let block_size = wgpu_format.block_copy_size(None).unwrap_or(16);
+        let blocks_x = width.div_ceil(block_width);
+        let blocks_y = height.div_ceil(block_height);
+        let expected_size = (blocks_x * blocks_y * block_size) as usize;
 
         if data.len() != expected_size {
             anyhow::bail!(
@@ -226,7 +394,7 @@ impl TextureManager {
             );
         }
 
-        // VRAM size is the compressed size
+        // VRAM size is the uploaded size
         let size_bytes = expected_size;
 
         // Check VRAM budget
@@ -239,7 +407,7 @@ impl TextureManager {
             );
         }
 
-        // Create texture with block-compressed format
+        // Create texture in the ROM's format
         let texture = device.create_texture_with_data(
             queue,
             &wgpu::TextureDescriptor {
@@ -268,11 +436,12 @@ impl TextureManager {
         self.textures.insert(
             handle.0,
             TextureEntry {
-                _texture: texture,
+                texture,
                 view,
                 _width: width,
                 _height: height,
                 size_bytes,
+                is_array: false,
             },
         );
 
@@ -294,6 +463,57 @@ impl TextureManager {
         Ok(handle)
     }
 
+    /// Create or refresh a host-owned RGBA8 texture outside the VRAM budget
+    ///
+    /// Used for save slot thumbnails, which change while the game runs. Pass
+    /// `TextureHandle::INVALID` to create the texture; later calls with the
+    /// returned handle overwrite its pixels in place.
+    pub fn write_host_texture(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        handle: TextureHandle,
+        width: u32,
+        height: u32,
+        pixels: &[u8],
+    ) -> Result<TextureHandle> {
+        let Some(entry) = self.textures.get(&handle.0) else {
+            return self.load_texture_internal(device, queue, width, height, pixels, false);
+        };
+        if (entry._width, entry._height) != (width, height) || pixels.len() != entry.size_bytes {
+            anyhow::bail!(
+                "Host texture {} is {}x{}, got {} bytes for {}x{}",
+                handle.0,
+                entry._width,
+                entry._height,
+                pixels.len(),
+                width,
+                height
+            );
+        }
+
+        queue.write_texture(
+            wgpu::TexelCopyTextureInfo {
+                texture: &entry.texture,
+                mip_level: 0,
+                origin: wgpu::Origin3d::ZERO,
+                aspect: wgpu::TextureAspect::All,
+            },
+            pixels,
+            wgpu::TexelCopyBufferLayout {
+                offset: 0,
+                bytes_per_row: Some(width * 4),
+                rows_per_image: Some(height),
+            },
+            wgpu::Extent3d {
+                width,
+                height,
+                depth_or_array_layers: 1,
+            },
+        );
+        Ok(handle)
+    }
+
     /// Internal texture loading (optionally tracks VRAM)
     fn load_texture_internal(
         &mut self,
@@ -354,11 +574,12 @@ impl TextureManager {
         self.textures.insert(
             handle.0,
             TextureEntry {
-                _texture: texture,
+                texture,
                 view,
                 _width: width,
                 _height: height,
                 size_bytes,
+                is_array: false,
             },
         );
 
@@ -379,9 +600,20 @@ impl TextureManager {
         Ok(handle)
     }
 
-    /// Get texture view by handle
+    /// Get texture view by handle (None for texture arrays)
     pub fn get_texture_view(&self, handle: TextureHandle) -> Option<&wgpu::TextureView> {
-        self.textures.get(&handle.0).map(|t| &t.view)
+        self.textures
+            .get(&handle.0)
+            .filter(|t| !t.is_array)
+            .map(|t| &t.view)
+    }
+
+    /// Get texture array view by handle (None for plain textures)
+    pub fn get_texture_array_view(&self, handle: TextureHandle) -> Option<&wgpu::TextureView> {
+        self.textures
+            .get(&handle.0)
+            .filter(|t| t.is_array)
+            .map(|t| &t.view)
     }
 
     /// Get fallback checkerboard texture view
@@ -394,6 +626,11 @@ impl TextureManager {
         &self.textures[&self.fallback_white.0].view
     }
 
+    /// Get fallback white texture array view
+    pub fn get_fallback_white_array_view(&self) -> &wgpu::TextureView {
+        &self.textures[&self.fallback_white_array.0].view
+    }
+
     /// Get font texture handle
     pub fn font_texture(&self) -> TextureHandle {
         self.font_texture
@@ -425,17 +662,19 @@ impl TextureManager {
     /// loading a new game rather than when exiting. This handles crashes/failed
     /// init gracefully since the next game load will clear stale state.
     pub fn clear_game_textures(&mut self) {
-        // Keep only the built-in textures (checkerboard, white, font)
-        let checkerboard_id = self.fallback_checkerboard.0;
-        let white_id = self.fallback_white.0;
-        let font_id = self.font_texture.0;
+        // Keep only the built-in textures (checkerboard, white, white array, font)
+        let builtin_ids = [
+            self.fallback_checkerboard.0,
+            self.fallback_white.0,
+            self.fallback_white_array.0,
+            self.font_texture.0,
+        ];
 
-        self.textures
-            .retain(|&id, _| id == checkerboard_id || id == white_id || id == font_id);
+        self.textures.retain(|id, _| builtin_ids.contains(id));
 
         // Reset next_texture_id to after the built-in textures
-        // The built-in textures have IDs 1, 2, 3 (0 is INVALID)
-        self.next_texture_id = 4;
+        // The built-in textures have IDs 1-4 (0 is INVALID)
+        self.next_texture_id = 5;
 
         // Recalculate vram_used from remaining textures
         self.vram_used = self.textures.values().map(|e| e.size_bytes).sum();
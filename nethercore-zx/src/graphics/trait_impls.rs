@@ -102,4 +102,8 @@ impl nethercore_core::app::StandaloneGraphicsSupport for ZXGraphics {
     fn set_scale_mode(&mut self, mode: nethercore_core::app::config::ScaleMode) {
         self.scale_mode = mode;
     }
+
+    fn window_to_canvas(&self, x: f32, y: f32) -> Option<[f32; 2]> {
+        ZXGraphics::window_to_canvas(self, x, y)
+    }
 }
@@ -32,10 +32,11 @@ pub use quantization::{
 pub use quantization::pack_snorm16;
 
 pub use shading_state::{
-    DEFAULT_FLAGS, FLAG_DITHER_OFFSET_X_MASK, FLAG_DITHER_OFFSET_X_SHIFT,
-    FLAG_DITHER_OFFSET_Y_MASK, FLAG_DITHER_OFFSET_Y_SHIFT, FLAG_SKINNING_MODE,
-    FLAG_SKIP_NORMAL_MAP, FLAG_TEXTURE_FILTER_LINEAR, FLAG_UNIFORM_ALPHA_MASK,
-    FLAG_UNIFORM_ALPHA_SHIFT, FLAG_USE_MATCAP_REFLECTION, FLAG_USE_UNIFORM_COLOR,
-    FLAG_USE_UNIFORM_EMISSIVE, FLAG_USE_UNIFORM_METALLIC, FLAG_USE_UNIFORM_ROUGHNESS,
-    FLAG_USE_UNIFORM_SPECULAR, PackedUnifiedShadingState, ShadingStateIndex,
+    DEFAULT_FLAGS, FLAG_AFFINE_TEXTURES, FLAG_DITHER_OFFSET_X_MASK, FLAG_DITHER_OFFSET_X_SHIFT,
+    FLAG_DITHER_OFFSET_Y_MASK, FLAG_DITHER_OFFSET_Y_SHIFT, FLAG_LIGHTMAPPED, FLAG_OUTLINE,
+    FLAG_PALETTE_MASK, FLAG_PALETTE_SHIFT, FLAG_SKINNING_MODE, FLAG_SKIP_NORMAL_MAP,
+    FLAG_TEXTURE_FILTER_LINEAR, FLAG_UNIFORM_ALPHA_MASK, FLAG_UNIFORM_ALPHA_SHIFT,
+    FLAG_USE_MATCAP_REFLECTION, FLAG_USE_UNIFORM_COLOR, FLAG_USE_UNIFORM_EMISSIVE,
+    FLAG_USE_UNIFORM_METALLIC, FLAG_USE_UNIFORM_ROUGHNESS, FLAG_USE_UNIFORM_SPECULAR,
+    FLAG_VERTEX_SNAP_MASK, FLAG_VERTEX_SNAP_SHIFT, PackedUnifiedShadingState, ShadingStateIndex,
 };
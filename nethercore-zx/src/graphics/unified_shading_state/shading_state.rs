@@ -4,8 +4,9 @@ use super::light::PackedLight;
 use super::quantization::{pack_matcap_blend_modes, pack_uniform_set_0, pack_unorm8};
 use crate::graphics::render_state::MatcapBlendMode;
 
-/// Unified per-draw shading state (80 bytes, POD, hashable)
+/// Unified per-draw shading state (104 bytes, POD, hashable)
 /// Size breakdown: 16 bytes (header) + 48 bytes (lights) + 16 bytes (animation/environment)
+/// + 24 bytes (morph targets)
 ///
 /// # Mode-Specific Field Interpretation
 ///
@@ -20,6 +21,7 @@ use crate::graphics::render_state::MatcapBlendMode;
 /// | 1    | [BlendMode0, BlendMode1, BlendMode2, BlendMode3] | [unused, unused, unused, unused]         |
 /// | 2    | [Metallic, Roughness, Emissive, Rim Intensity]   | [unused, unused, unused, Rim Power]      |
 /// | 3    | [SpecDamping*, Shininess, Emissive, RimIntens]   | [Spec R, Spec G, Spec B, Rim Power]      |
+/// | 4    | [Bands, Shininess, Emissive, Rim Intensity]      | [Spec R, Spec G, Spec B, Rim Power]      |
 ///
 /// *SpecDamping is INVERTED: 0=full specular (default), 255=no specular.
 /// This is beginner-friendly since the default of 0 gives visible highlights.
@@ -34,6 +36,7 @@ pub struct PackedUnifiedShadingState {
     /// - Mode 1: [blend_mode_0, blend_mode_1, blend_mode_2, blend_mode_3]
     /// - Mode 2: [metallic, roughness, emissive, rim_intensity]
     /// - Mode 3: [spec_damping*, shininess, emissive, rim_intensity]
+    /// - Mode 4: [bands, shininess, emissive, rim_intensity]
     /// * spec_damping is inverted: 0=full specular, 255=no specular
     pub uniform_set_0: u32,
 
@@ -42,6 +45,7 @@ pub struct PackedUnifiedShadingState {
     /// - Mode 1: unused
     /// - Mode 2: [unused, unused, unused, rim_power]
     /// - Mode 3: [spec_r, spec_g, spec_b, rim_power]
+    /// - Mode 4: [spec_r, spec_g, spec_b, rim_power] (outline width when FLAG_OUTLINE is set)
     pub uniform_set_1: u32,
 
     /// Flags and reserved bits
@@ -62,11 +66,23 @@ pub struct PackedUnifiedShadingState {
     /// 0 = no skeleton bound (raw bone mode)
     pub inverse_bind_base: u32,
 
-    /// Padding for struct alignment (animation_flags slot unused)
-    pub _pad: u32,
+    /// Offset added to the vertex index to find baked lighting in the
+    /// lightmap buffer (only read when FLAG_LIGHTMAPPED is set; wraps)
+    pub lightmap_base: u32,
 
     /// EPU environment ID (`env_id`) used for EnvRadiance / SH9 sampling.
     pub environment_index: u32,
+
+    // Morph target fields (24 bytes)
+    /// Offset added to the vertex index to find target 0 displacements in
+    /// the morph buffer (wraps); target N starts `N * morph_vertices` later
+    pub morph_base: u32,
+
+    /// Vertices per morph target (0 = no morph targets applied)
+    pub morph_vertices: u32,
+
+    /// Morph target weights (8 × f16, 2 per u32)
+    pub morph_weights: [u32; 4],
 }
 
 impl Default for PackedUnifiedShadingState {
@@ -85,8 +101,11 @@ impl Default for PackedUnifiedShadingState {
             // Animation system fields (default to no animation)
             keyframe_base: 0,     // No keyframes bound
             inverse_bind_base: 0, // No skeleton bound (raw bone mode)
-            _pad: 0,
+            lightmap_base: 0,
             environment_index: 0, // Index 0 = default environment
+            morph_base: 0,
+            morph_vertices: 0, // No morph targets applied
+            morph_weights: [0; 4],
         }
     }
 }
@@ -179,6 +198,48 @@ pub const DEFAULT_FLAGS: u32 = 0xF << 8;
 /// This is an opt-out flag - normal mapping is enabled by default when tangent data exists
 pub const FLAG_SKIP_NORMAL_MAP: u32 = 1 << 16;
 
+// ============================================================================
+// Retro Mode Flags (Bits 17-21)
+// ============================================================================
+
+/// Mask for vertex snap precision in flags (bits 17-20)
+/// 0 = off, N = snap screen positions to a grid 2^N cells tall
+pub const FLAG_VERTEX_SNAP_MASK: u32 = 0xF << 17;
+/// Bit shift for vertex snap precision
+pub const FLAG_VERTEX_SNAP_SHIFT: u32 = 17;
+
+/// Flag bit for affine (non perspective-correct) texture mapping (bit 21)
+pub const FLAG_AFFINE_TEXTURES: u32 = 1 << 21;
+
+// ============================================================================
+// Baked Lighting Flag (Bit 22)
+// ============================================================================
+
+/// Flag bit for baked vertex lighting in PackedUnifiedShadingState.flags
+/// When set, the vertex shader reads irradiance from the lightmap buffer at
+/// `lightmap_base + vertex_index` and adds it as diffuse light
+pub const FLAG_LIGHTMAPPED: u32 = 1 << 22;
+
+// ============================================================================
+// Toon Outline Flag (Bit 23)
+// ============================================================================
+
+/// Flag bit for the inverted-hull outline pass of a Mode 4 draw
+/// When set, the vertex shader pushes the mesh out along its normals by the
+/// width stored in `uniform_set_1` (f32 bits) and the fragment shader fills it
+/// with `color_rgba8`
+pub const FLAG_OUTLINE: u32 = 1 << 23;
+
+// ============================================================================
+// Palette Swap Field (Bits 24-31)
+// ============================================================================
+
+/// Mask for the palette handle in flags (bits 24-31)
+/// 0 = none, N = draw the indexed slot 0 texture through palette N
+pub const FLAG_PALETTE_MASK: u32 = 0xFF << 24;
+/// Bit shift for the palette handle
+pub const FLAG_PALETTE_SHIFT: u32 = 24;
+
 impl PackedUnifiedShadingState {
     /// Create from all f32 parameters (used during FFI calls)
     /// For Mode 2: metallic, roughness, emissive packed into uniform_set_0
@@ -211,8 +272,11 @@ impl PackedUnifiedShadingState {
             // Animation system fields - defaults
             keyframe_base: 0,
             inverse_bind_base: 0,
-            _pad: 0,
+            lightmap_base: 0,
             environment_index,
+            morph_base: 0,
+            morph_vertices: 0,
+            morph_weights: [0; 4],
         }
     }
 
@@ -7,7 +7,7 @@ mod tests {
     #[test]
     fn test_packed_sizes() {
         assert_eq!(std::mem::size_of::<PackedLight>(), 12); // 12 bytes for point light support
-        assert_eq!(std::mem::size_of::<PackedUnifiedShadingState>(), 80); // 16 (header) + 48 (lights) + 16 (animation/env)
+        assert_eq!(std::mem::size_of::<PackedUnifiedShadingState>(), 104); // 16 (header) + 48 (lights) + 16 (animation/env) + 24 (morph)
     }
 
     #[test]
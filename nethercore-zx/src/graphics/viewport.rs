@@ -57,4 +57,20 @@ impl Viewport {
     pub fn is_valid(&self) -> bool {
         self.width > 0 && self.height > 0
     }
+
+    /// Overlap of two rectangles
+    ///
+    /// Returns a zero-sized rectangle (see `is_valid()`) if they don't overlap.
+    pub fn intersect(&self, other: &Viewport) -> Viewport {
+        let x = self.x.max(other.x);
+        let y = self.y.max(other.y);
+        let right = (self.x + self.width).min(other.x + other.width);
+        let bottom = (self.y + self.height).min(other.y + other.height);
+        Viewport {
+            x,
+            y,
+            width: right.saturating_sub(x),
+            height: bottom.saturating_sub(y),
+        }
+    }
 }
@@ -6,13 +6,14 @@ use std::fmt::Write as _;
 use std::time::{Duration, Instant};
 
 use crate::graphics::{
-    BufferManager, MeshHandle, MvpShadingIndices, QuadBatchInfo, QuadInstance, RetainedMesh,
-    TextureHandle, VirtualRenderPass, epu::EpuRuntime,
+    BufferManager, LightGrid, MeshHandle, MvpShadingIndices, QuadBatchInfo, QuadInstance,
+    RenderStats, RetainedMesh, TextureHandle, Viewport, VirtualRenderPass,
+    epu::{BackgroundCacheTarget, BackgroundRegions, EpuRuntime},
 };
 
 use super::init::RenderTarget;
 use super::pipeline::PipelineCache;
-use super::texture_manager::TextureManager;
+use super::texture_manager::{TextureArrayConfig, TextureManager, texture_format_supported};
 
 /// Nethercore ZX graphics backend
 ///
@@ -32,6 +33,10 @@ pub struct ZXGraphics {
     // Blit pipeline (for scaling render target to window)
     pub(super) blit_pipeline: wgpu::RenderPipeline,
     pub(super) blit_bind_group: wgpu::BindGroup,
+    pub(super) blit_palette_buffer: wgpu::Buffer,
+
+    // SSAO post pass (multiplied into the render target after the game's passes)
+    pub(super) ssao: super::frame::SsaoResources,
 
     // Depth buffer (for window-sized UI rendering, no longer used for game content)
     pub(super) depth_texture: wgpu::Texture,
@@ -65,6 +70,22 @@ pub struct ZXGraphics {
     /// Where static data ends in unified_animation (pub for state sync)
     pub animation_static_end: usize,
 
+    // Baked lighting (@binding(4)): per-vertex irradiance, static, uploaded once after init
+    pub(super) lightmap_buffer: wgpu::Buffer,
+
+    // Palette swap (@binding(10)): palettes from palette_load, static, uploaded once after init
+    pub(super) palette_buffer: wgpu::Buffer,
+
+    // Morph targets (@binding(14)): per-vertex displacements, static, uploaded once after init
+    pub(super) morph_buffer: wgpu::Buffer,
+
+    // Scene lights (@binding(12)) and their world-space light grid (@binding(13)),
+    // fixed size, uploaded each frame
+    pub(super) scene_lights_buffer: wgpu::Buffer,
+    pub(super) light_grid_buffer: wgpu::Buffer,
+    /// Light grid builder (reuses its allocations between frames)
+    pub(super) light_grid: LightGrid,
+
     // MVP indices buffer (@binding(1)) - absolute indices pre-computed by CPU
     pub(super) mvp_indices_buffer: wgpu::Buffer,
     pub(super) mvp_indices_capacity: usize,
@@ -99,6 +120,8 @@ pub struct ZXGraphics {
 
     // Scaling mode for render target to window
     pub(super) scale_mode: nethercore_core::app::config::ScaleMode,
+    // Scaling mode requested by the game via display_scale_mode() (overrides scale_mode)
+    pub(super) game_scale_mode: Option<nethercore_core::app::config::ScaleMode>,
 
     // Unit quad mesh for GPU-instanced rendering (billboards, sprites, etc.)
     pub(super) unit_quad_format: u8,
@@ -116,9 +139,19 @@ pub struct ZXGraphics {
     pub(super) epu_runtime: EpuRuntime,
     /// EPU sampler for environment map sampling (linear filtering)
     pub(super) epu_sampler: wgpu::Sampler,
+    /// Evaluated EPU backgrounds, reused while a viewport's config and camera are static
+    /// (created on the first `draw_epu()`)
+    pub(super) epu_background: Option<BackgroundCacheTarget>,
+    /// Which background each viewport region of `epu_background` holds
+    pub(super) epu_background_regions: BackgroundRegions,
+    /// Backgrounds to evaluate into `epu_background` this frame (viewport, mvp_index)
+    pub(super) epu_background_fills: Vec<(Viewport, u32)>,
 
     /// Optional per-second perf logging (render thread only)
     pub(super) perf: ZXPerf,
+
+    /// Statistics for the last rendered frame
+    pub(super) render_stats: RenderStats,
 }
 
 impl ZXGraphics {
@@ -133,6 +166,14 @@ impl ZXGraphics {
         self.scale_mode = scale_mode;
     }
 
+    /// Set the scaling mode requested by the game (`None` = use the player's setting)
+    pub fn set_game_scale_mode(
+        &mut self,
+        scale_mode: Option<nethercore_core::app::config::ScaleMode>,
+    ) {
+        self.game_scale_mode = scale_mode;
+    }
+
     /// Invalidate cached frame bind group, forcing recreation on next frame.
     /// Call this when buffers are recreated (e.g., after init animation data upload).
     pub fn invalidate_frame_bind_group(&mut self) {
@@ -154,7 +195,33 @@ impl ZXGraphics {
             .load_texture(&self.device, &self.queue, width, height, pixels)
     }
 
-    /// Load a texture with explicit format (RGBA8 or BC7)
+    /// Create or refresh a host-owned RGBA8 texture (save slot thumbnails)
+    ///
+    /// Pass `TextureHandle::INVALID` to create it; later calls with the
+    /// returned handle overwrite its pixels. Not counted against VRAM.
+    pub fn write_host_texture(
+        &mut self,
+        handle: TextureHandle,
+        width: u32,
+        height: u32,
+        pixels: &[u8],
+    ) -> Result<TextureHandle> {
+        self.texture_manager.write_host_texture(
+            &self.device,
+            &self.queue,
+            handle,
+            width,
+            height,
+            pixels,
+        )
+    }
+
+    /// Whether this GPU can sample textures of `format`
+    pub fn supports_texture_format(&self, format: zx_common::TextureFormat) -> bool {
+        texture_format_supported(self.device.features(), format)
+    }
+
+    /// Load a texture with explicit format (RGBA8, BC7, BC5, ETC2 or EAC RG11)
     pub fn load_texture_with_format(
         &mut self,
         width: u32,
@@ -172,10 +239,36 @@ impl ZXGraphics {
         )
     }
 
+    /// Load a texture array with explicit format (layers stored back to back)
+    pub fn load_texture_array_with_format(
+        &mut self,
+        width: u32,
+        height: u32,
+        layers: u32,
+        data: &[u8],
+        format: zx_common::TextureFormat,
+    ) -> Result<TextureHandle> {
+        self.texture_manager.load_texture_array_with_format(
+            &self.device,
+            &self.queue,
+            TextureArrayConfig {
+                width,
+                height,
+                layers,
+                data,
+                format,
+            },
+        )
+    }
+
     pub fn get_texture_view(&self, handle: TextureHandle) -> Option<&wgpu::TextureView> {
         self.texture_manager.get_texture_view(handle)
     }
 
+    pub fn get_texture_array_view(&self, handle: TextureHandle) -> Option<&wgpu::TextureView> {
+        self.texture_manager.get_texture_array_view(handle)
+    }
+
     pub fn get_fallback_checkerboard_view(&self) -> &wgpu::TextureView {
         self.texture_manager.get_fallback_checkerboard_view()
     }
@@ -184,6 +277,10 @@ impl ZXGraphics {
         self.texture_manager.get_fallback_white_view()
     }
 
+    pub fn get_fallback_white_array_view(&self) -> &wgpu::TextureView {
+        self.texture_manager.get_fallback_white_array_view()
+    }
+
     pub fn font_texture(&self) -> TextureHandle {
         self.texture_manager.font_texture()
     }
@@ -422,6 +519,22 @@ impl ZXGraphics {
             .build_envs(&self.device, &self.queue, encoder, configs);
     }
 
+    /// Replace the diffuse ambient of environments lit by `sky_draw()` skies.
+    ///
+    /// Call after `build_epu_environments()` so the EPU's own SH9 is overwritten.
+    ///
+    /// # Arguments
+    /// * `encoder` - Command encoder to record the buffer copies
+    /// * `ambients` - Slice of (env_id, irradiance) pairs
+    pub fn apply_sky_ambient(
+        &mut self,
+        encoder: &mut wgpu::CommandEncoder,
+        ambients: &[(u32, crate::graphics::epu::EpuSh9)],
+    ) {
+        self.epu_runtime
+            .apply_sky_ambient(&self.device, encoder, ambients);
+    }
+
     // =================================================================
     // UNIFIED BUFFER: STATIC UPLOAD METHODS
     // =================================================================
@@ -475,6 +588,79 @@ impl ZXGraphics {
         );
     }
 
+    /// Upload baked per-vertex lighting to the lightmap buffer
+    ///
+    /// Called once after init() when all lightmaps have been baked.
+    /// Each entry is f16 RGB irradiance packed as [r | g, b | 0].
+    pub fn upload_static_lightmaps(&mut self, data: &[[u32; 2]]) {
+        if data.is_empty() {
+            return;
+        }
+
+        let bytes: &[u8] = bytemuck::cast_slice(data);
+        self.lightmap_buffer = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Lightmap Data (@binding(4))"),
+            size: bytes.len() as u64,
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        self.queue.write_buffer(&self.lightmap_buffer, 0, bytes);
+        // Invalidate cached bind group since buffer was recreated
+        self.invalidate_frame_bind_group();
+
+        tracing::debug!("Uploaded {} baked lightmap vertices", data.len());
+    }
+
+    /// Upload palettes from palette_load() to the palette buffer
+    ///
+    /// Called once after init() when all palettes have been loaded.
+    /// Each palette is 256 RGBA8 colors (0xRRGGBBAA).
+    pub fn upload_static_palettes(&mut self, colors: &[u32]) {
+        if colors.is_empty() {
+            return;
+        }
+
+        let bytes: &[u8] = bytemuck::cast_slice(colors);
+        self.palette_buffer = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Palette Data (@binding(10))"),
+            size: bytes.len() as u64,
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        self.queue.write_buffer(&self.palette_buffer, 0, bytes);
+        // Invalidate cached bind group since buffer was recreated
+        self.invalidate_frame_bind_group();
+
+        tracing::debug!(
+            "Uploaded {} palettes",
+            colors.len() / crate::state::MAX_PALETTE_COLORS
+        );
+    }
+
+    /// Upload morph target displacements to the morph buffer
+    ///
+    /// Called once after init() when all ROM meshes have been loaded.
+    /// Each entry is an f16 position and normal displacement packed as
+    /// [px | py, pz | nx, ny | nz].
+    pub fn upload_static_morphs(&mut self, data: &[[u32; 3]]) {
+        if data.is_empty() {
+            return;
+        }
+
+        let bytes: &[u8] = bytemuck::cast_slice(data);
+        self.morph_buffer = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Morph Data (@binding(14))"),
+            size: bytes.len() as u64,
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        self.queue.write_buffer(&self.morph_buffer, 0, bytes);
+        // Invalidate cached bind group since buffer was recreated
+        self.invalidate_frame_bind_group();
+
+        tracing::debug!("Uploaded {} morph target vertices", data.len());
+    }
+
     /// Upload all pre-decoded keyframe matrices to the unified animation buffer
     ///
     /// Called once after init() when all keyframes have been loaded and decoded.
@@ -7,11 +7,18 @@ pub mod audio;
 pub mod audio_thread;
 pub mod console;
 pub mod debug;
+pub mod decal;
+pub mod destructible;
+pub mod epu_bench;
 pub mod ffi;
 mod font;
 pub mod graphics;
 pub mod input;
 pub mod library;
+pub mod lightmap;
+pub mod mesh_merge;
+pub mod morph;
+pub mod navmesh;
 pub mod player;
 pub mod preview;
 pub mod procedural;
@@ -20,3 +27,4 @@ pub mod resource_manager;
 mod shader_gen;
 pub mod state;
 pub mod tracker;
+pub mod water;
@@ -0,0 +1,121 @@
+//! Baked vertex lighting for static geometry
+//!
+//! `lightmap_bake()` evaluates the current dynamic lights once per vertex of
+//! static meshes during init and stores the resulting irradiance. Draws that
+//! select the lightmap with `material_lightmap()` read it back in the vertex
+//! shader, so the baked lights can be disabled at runtime and big levels only
+//! pay for the lights that actually move.
+//!
+//! Baking uses the same Lambert and point-light falloff as the shaders, so a
+//! baked light matches its dynamic counterpart. There is no shadowing.
+
+#[cfg(test)]
+mod tests;
+
+use glam::{Mat4, Vec3};
+use hashbrown::HashMap;
+
+use crate::graphics::{LightType, PackedLight, pack_f16x2};
+
+/// Maximum number of lightmaps that can be baked
+pub const MAX_LIGHTMAPS: usize = 16;
+
+/// Maximum number of baked vertices across all lightmaps (8 bytes each)
+pub const MAX_LIGHTMAP_VERTICES: usize = 1 << 18;
+
+/// Baked lighting for a set of meshes
+#[derive(Clone, Debug, Default)]
+pub struct Lightmap {
+    /// Mesh handle → first vertex in [`LightmapStore::data`]
+    pub offsets: HashMap<u32, u32>,
+}
+
+/// All baked lighting, laid out as the GPU lightmap buffer
+#[derive(Clone, Debug, Default)]
+pub struct LightmapStore {
+    /// Baked lightmaps (handle = index + 1)
+    pub lightmaps: Vec<Lightmap>,
+    /// Packed irradiance per vertex: [f16 r | f16 g, f16 b | 0]
+    pub data: Vec<[u32; 2]>,
+}
+
+impl LightmapStore {
+    /// Store baked irradiance for `(mesh, per-vertex irradiance)` pairs
+    ///
+    /// Returns the lightmap handle (>0), or `None` if the lightmap or vertex
+    /// limits would be exceeded.
+    pub fn add(&mut self, meshes: Vec<(u32, Vec<Vec3>)>) -> Option<u32> {
+        let vertex_count: usize = meshes.iter().map(|(_, v)| v.len()).sum();
+        if self.lightmaps.len() >= MAX_LIGHTMAPS
+            || self.data.len() + vertex_count > MAX_LIGHTMAP_VERTICES
+        {
+            return None;
+        }
+
+        let mut lightmap = Lightmap::default();
+        for (mesh, irradiance) in meshes {
+            lightmap.offsets.insert(mesh, self.data.len() as u32);
+            self.data
+                .extend(irradiance.into_iter().map(pack_irradiance));
+        }
+        self.lightmaps.push(lightmap);
+        Some(self.lightmaps.len() as u32)
+    }
+
+    /// First baked vertex of `mesh` in `lightmap`, if the mesh was baked into it
+    pub fn offset(&self, lightmap: u32, mesh: u32) -> Option<u32> {
+        let index = lightmap.checked_sub(1)? as usize;
+        self.lightmaps.get(index)?.offsets.get(&mesh).copied()
+    }
+}
+
+/// Irradiance of every vertex, lit by `lights` with `model` applied
+///
+/// Positions and normals are in mesh space; normals are transformed the same
+/// way the vertex shader does.
+pub fn bake_vertices(
+    positions: &[Vec3],
+    normals: &[Vec3],
+    model: Mat4,
+    lights: &[PackedLight],
+) -> Vec<Vec3> {
+    positions
+        .iter()
+        .zip(normals)
+        .map(|(&position, &normal)| {
+            let world_position = model.transform_point3(position);
+            let world_normal = model.transform_vector3(normal).normalize_or_zero();
+            irradiance(world_position, world_normal, lights)
+        })
+        .collect()
+}
+
+/// Lambert irradiance at a surface point (matches `compute_light` in the shaders)
+pub fn irradiance(position: Vec3, normal: Vec3, lights: &[PackedLight]) -> Vec3 {
+    let mut total = Vec3::ZERO;
+    for light in lights.iter().filter(|l| l.is_enabled()) {
+        let color = Vec3::from(light.get_color()) * light.get_intensity();
+        let (direction, attenuation) = match light.get_type() {
+            LightType::Directional => (Vec3::from(light.get_direction()), 1.0),
+            LightType::Point => {
+                let to_light = Vec3::from(light.get_position()) - position;
+                let range = light.get_range();
+                let attenuation = if range > 0.0 {
+                    let t = (to_light.length() / range).clamp(0.0, 1.0);
+                    (1.0 - t) * (1.0 - t)
+                } else {
+                    0.0
+                };
+                (-to_light.normalize_or_zero(), attenuation)
+            }
+        };
+        // Light directions are ray directions, so the surface faces -direction
+        total += color * attenuation * normal.dot(-direction).max(0.0);
+    }
+    total
+}
+
+/// Pack irradiance as f16 RGB (read with `unpack2x16float` in the shader)
+fn pack_irradiance(rgb: Vec3) -> [u32; 2] {
+    [pack_f16x2(rgb.x, rgb.y), pack_f16x2(rgb.z, 0.0)]
+}
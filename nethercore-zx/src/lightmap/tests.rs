@@ -0,0 +1,76 @@
+use glam::{Mat4, Vec3};
+
+use super::*;
+use crate::graphics::unpack_f16x2;
+
+#[test]
+fn test_directional_light_follows_lambert() {
+    let sun = PackedLight::directional(Vec3::NEG_Y, Vec3::ONE, 2.0, true);
+    let up = irradiance(Vec3::ZERO, Vec3::Y, &[sun]);
+    let side = irradiance(Vec3::ZERO, Vec3::X, &[sun]);
+    let down = irradiance(Vec3::ZERO, Vec3::NEG_Y, &[sun]);
+
+    assert!((up.x - sun.get_intensity()).abs() < 1e-3);
+    assert!(side.x.abs() < 1e-3);
+    assert_eq!(down, Vec3::ZERO);
+}
+
+#[test]
+fn test_point_light_falls_off_to_range() {
+    let lamp = PackedLight::point(Vec3::new(0.0, 4.0, 0.0), Vec3::ONE, 1.0, 8.0, true);
+    let near = irradiance(Vec3::new(0.0, 2.0, 0.0), Vec3::Y, &[lamp]);
+    let far = irradiance(Vec3::new(0.0, -4.0, 0.0), Vec3::Y, &[lamp]);
+
+    // 2 units away in an 8-unit range: (1 - 0.25)^2
+    assert!((near.x - lamp.get_intensity() * 0.5625).abs() < 1e-2);
+    assert_eq!(far, Vec3::ZERO);
+}
+
+#[test]
+fn test_disabled_lights_are_skipped() {
+    let off = PackedLight::directional(Vec3::NEG_Y, Vec3::ONE, 2.0, false);
+    assert_eq!(irradiance(Vec3::ZERO, Vec3::Y, &[off]), Vec3::ZERO);
+}
+
+#[test]
+fn test_bake_applies_model_transform() {
+    let sun = PackedLight::directional(Vec3::NEG_Y, Vec3::ONE, 1.0, true);
+    // A floor normal rotated to face +X no longer faces the sun
+    let model = Mat4::from_rotation_z(-std::f32::consts::FRAC_PI_2);
+    let baked = bake_vertices(&[Vec3::ZERO], &[Vec3::Y], model, &[sun]);
+    assert!(baked[0].x.abs() < 1e-3);
+
+    let baked = bake_vertices(&[Vec3::ZERO], &[Vec3::Y], Mat4::IDENTITY, &[sun]);
+    assert!(baked[0].x > 0.9);
+}
+
+#[test]
+fn test_store_packs_meshes_back_to_back() {
+    let mut store = LightmapStore::default();
+    let first = store
+        .add(vec![
+            (3, vec![Vec3::new(0.5, 1.0, 2.0); 4]),
+            (7, vec![Vec3::ONE; 2]),
+        ])
+        .unwrap();
+    let second = store.add(vec![(3, vec![Vec3::ZERO])]).unwrap();
+
+    assert_eq!((first, second), (1, 2));
+    assert_eq!(store.offset(first, 3), Some(0));
+    assert_eq!(store.offset(first, 7), Some(4));
+    assert_eq!(store.offset(second, 3), Some(6));
+    assert_eq!(store.offset(second, 7), None);
+    assert_eq!(store.offset(0, 3), None);
+
+    let [rg, b] = store.data[0];
+    assert_eq!(unpack_f16x2(rg), (0.5, 1.0));
+    assert_eq!(unpack_f16x2(b).0, 2.0);
+}
+
+#[test]
+fn test_store_rejects_oversized_bakes() {
+    let mut store = LightmapStore::default();
+    let too_big = vec![(1, vec![Vec3::ZERO; MAX_LIGHTMAP_VERTICES + 1])];
+    assert_eq!(store.add(too_big), None);
+    assert!(store.data.is_empty());
+}
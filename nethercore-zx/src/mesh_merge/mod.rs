@@ -0,0 +1,185 @@
+//! Static mesh merging (`mesh_merge`)
+//!
+//! Bakes placed copies of static meshes into a few large meshes, so a level's
+//! props cost a handful of draw calls instead of one each. Vertices are
+//! transformed in their packed form (positions re-rounded to f16, normals and
+//! tangents re-encoded) and sources are grouped by vertex format, since each
+//! format is drawn with its own pipeline. A group is split into several parts
+//! whenever it would outgrow 16-bit indices.
+
+#[cfg(test)]
+mod tests;
+
+use std::collections::BTreeMap;
+
+use bytemuck::cast_slice;
+use glam::{Mat3, Mat4, Vec3};
+
+use crate::graphics::{
+    FORMAT_COLOR, FORMAT_NORMAL, FORMAT_SKINNED, FORMAT_TANGENT, FORMAT_UV, pack_octahedral_u32,
+    pack_position_f16, pack_tangent, unpack_f16, unpack_octahedral_u32, unpack_tangent,
+    vertex_stride_packed,
+};
+
+/// Maximum meshes one `mesh_merge()` call can combine
+pub const MAX_MERGE_SOURCES: usize = 4096;
+
+/// Maximum vertices in one merged part (16-bit indices)
+pub const MAX_PART_VERTICES: usize = u16::MAX as usize + 1;
+
+/// One placed copy of a mesh to merge
+#[derive(Clone, Copy, Debug)]
+pub struct MergeSource<'a> {
+    /// Vertex format flags
+    pub format: u8,
+    /// Packed vertex data (`vertex_stride_packed(format)` bytes per vertex)
+    pub vertex_data: &'a [u8],
+    /// Triangle indices, or `None` for a non-indexed mesh
+    pub index_data: Option<&'a [u16]>,
+    /// Placement in the merged mesh's local space
+    pub transform: Mat4,
+}
+
+/// One merged part, ready to be queued as a packed indexed mesh
+#[derive(Clone, Debug, PartialEq)]
+pub struct MergedMesh {
+    pub format: u8,
+    pub vertex_data: Vec<u8>,
+    pub index_data: Vec<u16>,
+}
+
+impl MergedMesh {
+    fn new(format: u8) -> Self {
+        Self {
+            format,
+            vertex_data: Vec::new(),
+            index_data: Vec::new(),
+        }
+    }
+
+    /// Number of vertices in the part
+    pub fn vertex_count(&self) -> usize {
+        self.vertex_data.len() / vertex_stride_packed(self.format) as usize
+    }
+}
+
+/// Merge `sources` into as few meshes as their vertex formats and the 16-bit
+/// index limit allow
+///
+/// Parts are ordered by vertex format, then by source order. Mirrored
+/// transforms keep their triangles front-facing. Returns an error message
+/// naming the first source that is skinned, has a singular transform, has
+/// too many vertices or has out-of-range indices.
+pub fn merge_meshes(sources: &[MergeSource]) -> Result<Vec<MergedMesh>, String> {
+    let mut groups: BTreeMap<u8, Vec<MergedMesh>> = BTreeMap::new();
+
+    for (i, source) in sources.iter().enumerate() {
+        if source.format & FORMAT_SKINNED != 0 {
+            return Err(format!("mesh {} is skinned", i));
+        }
+        let determinant = Mat3::from_mat4(source.transform).determinant();
+        if !determinant.is_normal() {
+            return Err(format!("transform {} is singular", i));
+        }
+
+        let stride = vertex_stride_packed(source.format) as usize;
+        let vertex_count = source.vertex_data.len() / stride;
+        if vertex_count > MAX_PART_VERTICES {
+            return Err(format!(
+                "mesh {} has {} vertices (at most {})",
+                i, vertex_count, MAX_PART_VERTICES
+            ));
+        }
+        let indices: Vec<u16> = match source.index_data {
+            Some(indices) => indices.to_vec(),
+            None => (0..vertex_count).map(|v| v as u16).collect(),
+        };
+        if indices.iter().any(|&index| index as usize >= vertex_count) {
+            return Err(format!("mesh {} has out-of-range indices", i));
+        }
+
+        let parts = groups.entry(source.format).or_default();
+        if parts
+            .last()
+            .is_none_or(|part| part.vertex_count() + vertex_count > MAX_PART_VERTICES)
+        {
+            parts.push(MergedMesh::new(source.format));
+        }
+        let part = parts.last_mut().expect("a part was just pushed");
+
+        let base = part.vertex_count() as u16;
+        let start = part.vertex_data.len();
+        part.vertex_data
+            .extend_from_slice(&source.vertex_data[..vertex_count * stride]);
+        transform_packed_vertices(
+            &mut part.vertex_data[start..],
+            source.format,
+            source.transform,
+        );
+
+        // A mirroring transform reverses winding; swap two corners to undo it
+        let mirrored = determinant < 0.0;
+        for triangle in indices.chunks_exact(3) {
+            let [a, b, c] = [triangle[0], triangle[1], triangle[2]];
+            let ordered = if mirrored { [a, c, b] } else { [a, b, c] };
+            part.index_data.extend(ordered.map(|index| base + index));
+        }
+    }
+
+    Ok(groups.into_values().flatten().collect())
+}
+
+/// Transform packed vertices in place
+///
+/// Positions use the full transform, normals its inverse transpose and
+/// tangents its linear part. Tangent handedness flips with a mirroring
+/// transform so normal maps keep their orientation.
+pub fn transform_packed_vertices(vertex_data: &mut [u8], format: u8, transform: Mat4) {
+    let linear = Mat3::from_mat4(transform);
+    let normal_matrix = linear.inverse().transpose();
+    let handedness = linear.determinant().signum();
+
+    // Packed layout: position(8) [uv(4)] [color(4)] [normal(4)] [tangent(4)] ...
+    let mut offset = 8;
+    if format & FORMAT_UV != 0 {
+        offset += 4;
+    }
+    if format & FORMAT_COLOR != 0 {
+        offset += 4;
+    }
+    let normal_offset = (format & FORMAT_NORMAL != 0).then_some(offset);
+    if normal_offset.is_some() {
+        offset += 4;
+    }
+    let tangent_offset = (format & FORMAT_TANGENT != 0).then_some(offset);
+
+    let stride = vertex_stride_packed(format) as usize;
+    for v in vertex_data.chunks_exact_mut(stride) {
+        let component = |i: usize| unpack_f16(u16::from_le_bytes([v[i * 2], v[i * 2 + 1]]));
+        let position =
+            transform.transform_point3(Vec3::new(component(0), component(1), component(2)));
+        let packed = pack_position_f16(position.x, position.y, position.z);
+        v[..8].copy_from_slice(cast_slice(&packed));
+
+        if let Some(o) = normal_offset {
+            let normal = unpack_octahedral_u32(read_u32(v, o));
+            let normal = (normal_matrix * normal).normalize_or_zero();
+            v[o..o + 4].copy_from_slice(&pack_octahedral_u32(normal).to_le_bytes());
+        }
+        if let Some(o) = tangent_offset {
+            let (tangent, sign) = unpack_tangent(read_u32(v, o));
+            let tangent = (linear * tangent).normalize_or_zero();
+            let packed = pack_tangent(tangent.to_array(), sign * handedness);
+            v[o..o + 4].copy_from_slice(&packed.to_le_bytes());
+        }
+    }
+}
+
+fn read_u32(bytes: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes([
+        bytes[offset],
+        bytes[offset + 1],
+        bytes[offset + 2],
+        bytes[offset + 3],
+    ])
+}
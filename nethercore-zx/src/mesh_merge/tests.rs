@@ -0,0 +1,140 @@
+use super::*;
+use crate::graphics::pack_vertex_data;
+
+/// Packed positions of a merged part
+fn positions(mesh: &MergedMesh) -> Vec<Vec3> {
+    let stride = vertex_stride_packed(mesh.format) as usize;
+    mesh.vertex_data
+        .chunks_exact(stride)
+        .map(|v| {
+            let component = |i: usize| unpack_f16(u16::from_le_bytes([v[i * 2], v[i * 2 + 1]]));
+            Vec3::new(component(0), component(1), component(2))
+        })
+        .collect()
+}
+
+/// One triangle facing +Z: position(3) normal(3) per vertex
+fn triangle_with_normals() -> Vec<u8> {
+    #[rustfmt::skip]
+    let data = [
+        0.0, 0.0, 0.0, 0.0, 0.0, 1.0,
+        1.0, 0.0, 0.0, 0.0, 0.0, 1.0,
+        0.0, 1.0, 0.0, 0.0, 0.0, 1.0,
+    ];
+    pack_vertex_data(&data, FORMAT_NORMAL)
+}
+
+fn source(format: u8, vertex_data: &[u8], transform: Mat4) -> MergeSource<'_> {
+    MergeSource {
+        format,
+        vertex_data,
+        index_data: None,
+        transform,
+    }
+}
+
+#[test]
+fn test_merge_offsets_indices_and_transforms_positions() {
+    let triangle = triangle_with_normals();
+    let quad_indices = [0u16, 1, 2, 2, 1, 0];
+    let sources = [
+        source(FORMAT_NORMAL, &triangle, Mat4::IDENTITY),
+        MergeSource {
+            index_data: Some(&quad_indices),
+            ..source(
+                FORMAT_NORMAL,
+                &triangle,
+                Mat4::from_translation(Vec3::new(4.0, 0.0, 0.0)),
+            )
+        },
+    ];
+
+    let merged = merge_meshes(&sources).unwrap();
+    assert_eq!(merged.len(), 1);
+    assert_eq!(merged[0].index_data, vec![0, 1, 2, 3, 4, 5, 5, 4, 3]);
+
+    let positions = positions(&merged[0]);
+    assert_eq!(positions.len(), 6);
+    assert_eq!(positions[3], Vec3::new(4.0, 0.0, 0.0));
+    assert_eq!(positions[4], Vec3::new(5.0, 0.0, 0.0));
+}
+
+#[test]
+fn test_merge_groups_by_vertex_format() {
+    let lit = triangle_with_normals();
+    let unlit = pack_vertex_data(&[0.0; 9], 0);
+    let sources = [
+        source(FORMAT_NORMAL, &lit, Mat4::IDENTITY),
+        source(0, &unlit, Mat4::IDENTITY),
+        source(FORMAT_NORMAL, &lit, Mat4::IDENTITY),
+    ];
+
+    let merged = merge_meshes(&sources).unwrap();
+    let formats: Vec<u8> = merged.iter().map(|m| m.format).collect();
+    assert_eq!(formats, vec![0, FORMAT_NORMAL]);
+    assert_eq!(merged[0].vertex_count(), 3);
+    assert_eq!(merged[1].vertex_count(), 6);
+}
+
+#[test]
+fn test_merge_splits_parts_at_index_limit() {
+    let big = pack_vertex_data(&vec![0.0; 40_000 * 3], 0);
+    let sources = [
+        source(0, &big, Mat4::IDENTITY),
+        source(0, &big, Mat4::IDENTITY),
+    ];
+
+    let merged = merge_meshes(&sources).unwrap();
+    assert_eq!(merged.len(), 2);
+    assert!(merged.iter().all(|m| m.vertex_count() == 40_000));
+    assert_eq!(merged[1].index_data[..3], [0, 1, 2]);
+}
+
+#[test]
+fn test_mirrored_transform_keeps_front_faces() {
+    let triangle = triangle_with_normals();
+    let mirror = Mat4::from_scale(Vec3::new(1.0, 1.0, -1.0));
+    let merged = merge_meshes(&[source(FORMAT_NORMAL, &triangle, mirror)]).unwrap();
+
+    // Winding reversed to compensate for the mirror
+    assert_eq!(merged[0].index_data, vec![0, 2, 1]);
+
+    let normal = unpack_octahedral_u32(read_u32(&merged[0].vertex_data, 8));
+    assert!((normal - Vec3::NEG_Z).length() < 1e-3);
+}
+
+#[test]
+fn test_transform_rotates_normals_and_tangents() {
+    let data = [0.0, 0.0, 0.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0];
+    let format = FORMAT_NORMAL | FORMAT_TANGENT;
+    let mut packed = pack_vertex_data(&data, format);
+
+    let rotate = Mat4::from_rotation_y(std::f32::consts::FRAC_PI_2);
+    transform_packed_vertices(&mut packed, format, rotate);
+
+    let normal = unpack_octahedral_u32(read_u32(&packed, 8));
+    assert!((normal - Vec3::X).length() < 1e-3);
+    let (tangent, sign) = unpack_tangent(read_u32(&packed, 12));
+    assert!((tangent - Vec3::NEG_Z).length() < 1e-3);
+    assert_eq!(sign, 1.0);
+
+    transform_packed_vertices(
+        &mut packed,
+        format,
+        Mat4::from_scale(Vec3::new(-1.0, 1.0, 1.0)),
+    );
+    assert_eq!(unpack_tangent(read_u32(&packed, 12)).1, -1.0);
+}
+
+#[test]
+fn test_merge_rejects_skinned_and_singular_sources() {
+    let triangle = triangle_with_normals();
+    let skinned = [source(FORMAT_NORMAL | FORMAT_SKINNED, &[], Mat4::IDENTITY)];
+    assert_eq!(merge_meshes(&skinned).unwrap_err(), "mesh 0 is skinned");
+
+    let flat = [
+        source(FORMAT_NORMAL, &triangle, Mat4::IDENTITY),
+        source(FORMAT_NORMAL, &triangle, Mat4::from_scale(Vec3::ZERO)),
+    ];
+    assert_eq!(merge_meshes(&flat).unwrap_err(), "transform 1 is singular");
+}
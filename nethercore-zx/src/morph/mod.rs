@@ -0,0 +1,135 @@
+//! Morph targets (blend shapes) for ROM meshes
+//!
+//! `rom_mesh()` stores each mesh's morph targets as per-vertex displacements
+//! in one static buffer. `mesh_morph_weight()` sets how much of each target a
+//! mesh shows, and every draw of the mesh passes the current weights in its
+//! shading state, so the vertex shader adds the weighted displacements to the
+//! bind pose before skinning in every render mode.
+
+#[cfg(test)]
+mod tests;
+
+use hashbrown::HashMap;
+use zx_common::{MAX_MORPH_TARGETS, MorphTarget};
+
+use crate::graphics::pack_f16x2;
+
+/// Maximum number of displacement entries across all meshes (12 bytes each)
+///
+/// A mesh uses one entry per vertex per target.
+pub const MAX_MORPH_ENTRIES: usize = 1 << 20;
+
+/// Morph targets of one mesh
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MeshMorph {
+    /// First entry in [`MorphStore::data`] (target 0, vertex 0)
+    pub base: u32,
+    /// Vertices per target
+    pub vertex_count: u32,
+    /// Number of targets
+    pub target_count: u32,
+    /// Current weight of each target (0 = not applied)
+    pub weights: [f32; MAX_MORPH_TARGETS],
+}
+
+impl MeshMorph {
+    /// Check if any target is applied
+    pub fn is_active(&self) -> bool {
+        self.weights.iter().any(|&w| w != 0.0)
+    }
+
+    /// Weights packed as f16 pairs for the shading state
+    pub fn packed_weights(&self) -> [u32; MAX_MORPH_TARGETS / 2] {
+        std::array::from_fn(|i| pack_f16x2(self.weights[i * 2], self.weights[i * 2 + 1]))
+    }
+}
+
+/// All morph targets, laid out as the GPU morph buffer
+#[derive(Clone, Debug, Default)]
+pub struct MorphStore {
+    /// Mesh handle → its targets
+    pub meshes: HashMap<u32, MeshMorph>,
+    /// Displacement per (target, vertex): [f16 px | py, pz | nx, ny | nz]
+    pub data: Vec<[u32; 3]>,
+}
+
+impl MorphStore {
+    /// Store the morph targets of `mesh`, which has `vertex_count` vertices
+    ///
+    /// Targets must have one position (and zero or one normal) displacement
+    /// per vertex. Returns an error message if the targets are malformed or
+    /// the entry limit would be exceeded.
+    pub fn add(
+        &mut self,
+        mesh: u32,
+        vertex_count: u32,
+        targets: &[MorphTarget],
+    ) -> Result<(), String> {
+        if targets.len() > MAX_MORPH_TARGETS {
+            return Err(format!(
+                "{} morph targets (at most {})",
+                targets.len(),
+                MAX_MORPH_TARGETS
+            ));
+        }
+        let vertices = vertex_count as usize;
+        if let Some(index) = targets.iter().position(|t| {
+            t.positions.len() != vertices || !(t.normals.is_empty() || t.normals.len() == vertices)
+        }) {
+            return Err(format!(
+                "morph target {} doesn't have {} vertices",
+                index, vertex_count
+            ));
+        }
+        if self.data.len() + targets.len() * vertices > MAX_MORPH_ENTRIES {
+            return Err(format!(
+                "morph targets exceed {} vertices in total",
+                MAX_MORPH_ENTRIES
+            ));
+        }
+
+        let base = self.data.len() as u32;
+        for target in targets {
+            self.data.extend((0..vertices).map(|v| {
+                let normal = target.normals.get(v).copied().unwrap_or_default();
+                pack_displacement(target.positions[v], normal)
+            }));
+        }
+        self.meshes.insert(
+            mesh,
+            MeshMorph {
+                base,
+                vertex_count,
+                target_count: targets.len() as u32,
+                weights: [0.0; MAX_MORPH_TARGETS],
+            },
+        );
+        Ok(())
+    }
+
+    /// Morph targets of `mesh`, if it has any
+    pub fn get(&self, mesh: u32) -> Option<&MeshMorph> {
+        self.meshes.get(&mesh)
+    }
+
+    /// Set the weight of one target. Returns `false` if the mesh has no such
+    /// target.
+    pub fn set_weight(&mut self, mesh: u32, target: u32, weight: f32) -> bool {
+        match self.meshes.get_mut(&mesh) {
+            Some(morph) if target < morph.target_count => {
+                morph.weights[target as usize] = weight;
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Pack a displacement as f16 (read with `unpack2x16float` in the shader)
+fn pack_displacement(position: [f32; 3], normal: [f32; 3]) -> [u32; 3] {
+    [
+        pack_f16x2(position[0], position[1]),
+        pack_f16x2(position[2], normal[0]),
+        pack_f16x2(normal[1], normal[2]),
+    ]
+}
@@ -0,0 +1,59 @@
+use super::*;
+use crate::graphics::unpack_f16x2;
+
+fn target(offset: f32, vertices: usize) -> MorphTarget {
+    MorphTarget {
+        positions: vec![[0.0, offset, 0.0]; vertices],
+        normals: vec![],
+    }
+}
+
+#[test]
+fn test_add_lays_out_targets_per_vertex() {
+    let mut store = MorphStore::default();
+    store.add(1, 3, &[target(1.0, 3), target(2.0, 3)]).unwrap();
+    store.add(2, 2, &[target(0.5, 2)]).unwrap();
+
+    assert_eq!(store.data.len(), 8);
+    let first = store.get(1).unwrap();
+    assert_eq!(
+        (first.base, first.vertex_count, first.target_count),
+        (0, 3, 2)
+    );
+    assert_eq!(store.get(2).unwrap().base, 6);
+
+    // Target 1, vertex 0 of mesh 1: y displacement of 2
+    let entry = store.data[(first.base + first.vertex_count) as usize];
+    assert_eq!(unpack_f16x2(entry[0]), (0.0, 2.0));
+    assert_eq!(unpack_f16x2(entry[2]), (0.0, 0.0));
+}
+
+#[test]
+fn test_add_rejects_bad_targets() {
+    let mut store = MorphStore::default();
+    assert!(store.add(1, 3, &[target(1.0, 2)]).is_err());
+    let too_many = vec![target(1.0, 3); MAX_MORPH_TARGETS + 1];
+    assert!(store.add(1, 3, &too_many).is_err());
+    let bad_normals = MorphTarget {
+        positions: vec![[0.0; 3]; 3],
+        normals: vec![[0.0; 3]; 1],
+    };
+    assert!(store.add(1, 3, &[bad_normals]).is_err());
+    assert!(store.data.is_empty());
+    assert!(store.get(1).is_none());
+}
+
+#[test]
+fn test_weights() {
+    let mut store = MorphStore::default();
+    store.add(1, 3, &[target(1.0, 3), target(2.0, 3)]).unwrap();
+    assert!(!store.get(1).unwrap().is_active());
+
+    assert!(store.set_weight(1, 1, 0.5));
+    assert!(!store.set_weight(1, 2, 1.0));
+    assert!(!store.set_weight(9, 0, 1.0));
+
+    let morph = store.get(1).unwrap();
+    assert!(morph.is_active());
+    assert_eq!(unpack_f16x2(morph.packed_weights()[0]), (0.0, 0.5));
+}
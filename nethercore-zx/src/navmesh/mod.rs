@@ -0,0 +1,141 @@
+//! Navigation meshes for obstacle-aware pathfinding
+//!
+//! A navmesh is baked once from retained mesh geometry: triangles that are
+//! flat enough to stand on are welded into a polygon graph, and edges too
+//! narrow for the agent are cut. Path queries run A* over that graph and
+//! straighten the result with a funnel pass.
+//!
+//! Everything here is plain f32 math with deterministic iteration order, so
+//! queries are safe to run from `update()` under rollback.
+
+mod path;
+
+#[cfg(test)]
+mod tests;
+
+use glam::Vec3;
+use hashbrown::HashMap;
+
+/// Maximum number of navmeshes that can be baked
+pub const MAX_NAVMESHES: usize = 16;
+
+/// Minimum up-facing component of a triangle normal to be walkable (45°)
+pub const WALKABLE_SLOPE_COS: f32 = std::f32::consts::FRAC_1_SQRT_2;
+
+/// Grid used to weld coincident vertices (1/1000 world unit)
+const WELD_SCALE: f32 = 1000.0;
+
+/// Sentinel for "no neighbor" in [`NavMesh::neighbors`]
+pub const NO_NEIGHBOR: u32 = u32::MAX;
+
+/// Baked navigation mesh
+#[derive(Clone, Debug, Default)]
+pub struct NavMesh {
+    /// Welded vertex positions
+    pub vertices: Vec<Vec3>,
+    /// Walkable triangles (indices into `vertices`)
+    pub triangles: Vec<[u32; 3]>,
+    /// Neighbor across edge `e` (`v[e]` → `v[(e + 1) % 3]`), or [`NO_NEIGHBOR`]
+    pub neighbors: Vec<[u32; 3]>,
+    /// Agent radius the mesh was baked for
+    pub agent_radius: f32,
+}
+
+impl NavMesh {
+    /// Bake a navmesh from triangle soup
+    ///
+    /// `indices` is a triangle list into `positions`. Steep triangles are
+    /// discarded, and shared edges shorter than `2 * agent_radius` are not
+    /// traversable. Winding order does not matter.
+    pub fn bake(positions: &[Vec3], indices: &[u32], agent_radius: f32) -> Self {
+        let agent_radius = agent_radius.max(0.0);
+
+        // Weld duplicated vertices (meshes split vertices along UV/normal seams)
+        let mut vertices = Vec::new();
+        let mut weld: HashMap<[i32; 3], u32> = HashMap::new();
+        let remap: Vec<u32> = positions
+            .iter()
+            .map(|p| {
+                let key = (*p * WELD_SCALE).round().as_ivec3().to_array();
+                *weld.entry(key).or_insert_with(|| {
+                    vertices.push(*p);
+                    vertices.len() as u32 - 1
+                })
+            })
+            .collect();
+
+        let mut triangles = Vec::new();
+        for tri in indices.chunks_exact(3) {
+            let Some(t) = tri
+                .iter()
+                .map(|&i| remap.get(i as usize).copied())
+                .collect::<Option<Vec<u32>>>()
+            else {
+                continue;
+            };
+            let t = [t[0], t[1], t[2]];
+            if t[0] == t[1] || t[1] == t[2] || t[0] == t[2] {
+                continue;
+            }
+
+            let [a, b, c] = t.map(|i| vertices[i as usize]);
+            let normal = (b - a).cross(c - a);
+            let len = normal.length();
+            if len <= f32::EPSILON || (normal.y / len).abs() < WALKABLE_SLOPE_COS {
+                continue;
+            }
+            triangles.push(t);
+        }
+
+        // Link triangles that share an edge
+        let mut edges: HashMap<(u32, u32), Vec<(u32, usize)>> = HashMap::new();
+        for (ti, t) in triangles.iter().enumerate() {
+            for e in 0..3 {
+                let (a, b) = (t[e], t[(e + 1) % 3]);
+                edges
+                    .entry((a.min(b), a.max(b)))
+                    .or_default()
+                    .push((ti as u32, e));
+            }
+        }
+
+        let mut neighbors = vec![[NO_NEIGHBOR; 3]; triangles.len()];
+        for (&(a, b), shared) in &edges {
+            // Non-manifold edges (more than two triangles) are treated as walls
+            let [(t0, e0), (t1, e1)] = shared.as_slice() else {
+                continue;
+            };
+            let width = vertices[a as usize].distance(vertices[b as usize]);
+            if width <= agent_radius * 2.0 {
+                continue;
+            }
+            neighbors[*t0 as usize][*e0] = *t1;
+            neighbors[*t1 as usize][*e1] = *t0;
+        }
+
+        Self {
+            vertices,
+            triangles,
+            neighbors,
+            agent_radius,
+        }
+    }
+
+    /// Number of walkable triangles
+    pub fn triangle_count(&self) -> usize {
+        self.triangles.len()
+    }
+
+    /// Corner positions of a triangle
+    #[inline]
+    fn corners(&self, tri: u32) -> [Vec3; 3] {
+        self.triangles[tri as usize].map(|i| self.vertices[i as usize])
+    }
+
+    /// Centroid of a triangle
+    #[inline]
+    fn centroid(&self, tri: u32) -> Vec3 {
+        let [a, b, c] = self.corners(tri);
+        (a + b + c) / 3.0
+    }
+}
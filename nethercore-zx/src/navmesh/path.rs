@@ -0,0 +1,274 @@
+//! Path queries (A* over triangles + funnel straightening)
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use glam::Vec3;
+
+use super::{NO_NEIGHBOR, NavMesh};
+
+/// Tolerance for point-in-triangle tests in the XZ plane
+const CONTAINS_EPSILON: f32 = 1e-4;
+
+impl NavMesh {
+    /// Find a path from `start` to `end`
+    ///
+    /// Both points are snapped onto the navmesh first. Returns the corner
+    /// points of the straightened path including the snapped start and end,
+    /// or `None` if the mesh is empty or the points are not connected.
+    pub fn find_path(&self, start: Vec3, end: Vec3) -> Option<Vec<Vec3>> {
+        let (start_tri, start) = self.locate(start)?;
+        let (end_tri, end) = self.locate(end)?;
+
+        if start_tri == end_tri {
+            return Some(vec![start, end]);
+        }
+
+        let corridor = self.search(start_tri, end_tri, end)?;
+        let portals = self.portals(&corridor, start, end);
+        Some(string_pull(&portals))
+    }
+
+    /// Find the triangle under (or nearest to) `point`
+    ///
+    /// Returns the triangle index and `point` snapped onto its surface.
+    pub fn locate(&self, point: Vec3) -> Option<(u32, Vec3)> {
+        // Prefer the vertically closest triangle whose XZ footprint contains the point
+        let mut best: Option<(u32, Vec3, f32)> = None;
+        for tri in 0..self.triangles.len() as u32 {
+            let [a, b, c] = self.corners(tri);
+            let Some(y) = height_in_triangle(point, a, b, c) else {
+                continue;
+            };
+            let dist = (point.y - y).abs();
+            if best.is_none_or(|(_, _, d)| dist < d) {
+                best = Some((tri, Vec3::new(point.x, y, point.z), dist));
+            }
+        }
+        if let Some((tri, snapped, _)) = best {
+            return Some((tri, snapped));
+        }
+
+        // Off the mesh: clamp to the closest point on any triangle
+        let mut best: Option<(u32, Vec3, f32)> = None;
+        for tri in 0..self.triangles.len() as u32 {
+            let [a, b, c] = self.corners(tri);
+            let closest = closest_point_on_triangle(point, a, b, c);
+            let dist = closest.distance_squared(point);
+            if best.is_none_or(|(_, _, d)| dist < d) {
+                best = Some((tri, closest, dist));
+            }
+        }
+        best.map(|(tri, snapped, _)| (tri, snapped))
+    }
+
+    /// A* over the triangle graph, returning the triangle corridor
+    fn search(&self, start: u32, goal: u32, goal_point: Vec3) -> Option<Vec<u32>> {
+        let count = self.triangles.len();
+        let mut cost = vec![f32::INFINITY; count];
+        let mut parent = vec![NO_NEIGHBOR; count];
+        let mut closed = vec![false; count];
+        let mut open = BinaryHeap::new();
+
+        cost[start as usize] = 0.0;
+        // Costs are non-negative, so their bit patterns order like the floats.
+        // Ties break on triangle index, keeping the search deterministic.
+        open.push(Reverse((0u32, start)));
+
+        while let Some(Reverse((_, tri))) = open.pop() {
+            if tri == goal {
+                let mut corridor = vec![goal];
+                let mut node = goal;
+                while parent[node as usize] != NO_NEIGHBOR {
+                    node = parent[node as usize];
+                    corridor.push(node);
+                }
+                corridor.reverse();
+                return Some(corridor);
+            }
+            if std::mem::replace(&mut closed[tri as usize], true) {
+                continue;
+            }
+
+            let here = self.centroid(tri);
+            for &next in &self.neighbors[tri as usize] {
+                if next == NO_NEIGHBOR || closed[next as usize] {
+                    continue;
+                }
+                let there = self.centroid(next);
+                let g = cost[tri as usize] + here.distance(there);
+                if g < cost[next as usize] {
+                    cost[next as usize] = g;
+                    parent[next as usize] = tri;
+                    let f = g + there.distance(goal_point);
+                    open.push(Reverse((f.to_bits(), next)));
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Build (left, right) portals along a corridor, narrowed by the agent radius
+    fn portals(&self, corridor: &[u32], start: Vec3, end: Vec3) -> Vec<(Vec3, Vec3)> {
+        let mut portals = Vec::with_capacity(corridor.len() + 1);
+        portals.push((start, start));
+
+        for pair in corridor.windows(2) {
+            let (from, to) = (pair[0], pair[1]);
+            let tri = self.triangles[from as usize];
+            let Some(e) = self.neighbors[from as usize].iter().position(|&n| n == to) else {
+                continue;
+            };
+            let mut p = self.vertices[tri[e] as usize];
+            let mut q = self.vertices[tri[(e + 1) % 3] as usize];
+
+            // Keep the agent's body clear of the portal corners
+            let edge = q - p;
+            let len = edge.length();
+            if len > 0.0 {
+                let inset = edge / len * self.agent_radius.min(len * 0.5);
+                p += inset;
+                q -= inset;
+            }
+
+            // Orient so the funnel sees a consistent left/right regardless of winding
+            if triarea2(self.centroid(from), p, q) < 0.0 {
+                portals.push((q, p));
+            } else {
+                portals.push((p, q));
+            }
+        }
+
+        portals.push((end, end));
+        portals
+    }
+}
+
+/// Twice the signed area of triangle (a, b, c) in the XZ plane
+#[inline]
+fn triarea2(a: Vec3, b: Vec3, c: Vec3) -> f32 {
+    let ax = b.x - a.x;
+    let az = b.z - a.z;
+    let bx = c.x - a.x;
+    let bz = c.z - a.z;
+    bx * az - ax * bz
+}
+
+#[inline]
+fn same_xz(a: Vec3, b: Vec3) -> bool {
+    (a.x - b.x).abs() < 1e-6 && (a.z - b.z).abs() < 1e-6
+}
+
+/// Simple stupid funnel algorithm over (left, right) portals
+fn string_pull(portals: &[(Vec3, Vec3)]) -> Vec<Vec3> {
+    let mut path = vec![portals[0].0];
+    let (mut apex, mut left, mut right) = (portals[0].0, portals[0].0, portals[0].1);
+    let (mut left_index, mut right_index) = (0, 0);
+
+    let mut i = 1;
+    while i < portals.len() {
+        let (new_left, new_right) = portals[i];
+
+        // Try to narrow the right side
+        if triarea2(apex, right, new_right) <= 0.0 {
+            if same_xz(apex, right) || triarea2(apex, left, new_right) > 0.0 {
+                right = new_right;
+                right_index = i;
+            } else {
+                // Right crossed over left: left corner becomes the new apex
+                path.push(left);
+                apex = left;
+                let apex_index = left_index;
+                right = apex;
+                right_index = apex_index;
+                i = apex_index + 1;
+                continue;
+            }
+        }
+
+        // Try to narrow the left side
+        if triarea2(apex, left, new_left) >= 0.0 {
+            if same_xz(apex, left) || triarea2(apex, right, new_left) < 0.0 {
+                left = new_left;
+                left_index = i;
+            } else {
+                // Left crossed over right: right corner becomes the new apex
+                path.push(right);
+                apex = right;
+                let apex_index = right_index;
+                left = apex;
+                left_index = apex_index;
+                i = apex_index + 1;
+                continue;
+            }
+        }
+
+        i += 1;
+    }
+
+    let end = portals[portals.len() - 1].0;
+    if path.last().is_none_or(|&last| last != end) {
+        path.push(end);
+    }
+    path
+}
+
+/// Height of the triangle's plane under `p`, if `p` lies inside its XZ footprint
+fn height_in_triangle(p: Vec3, a: Vec3, b: Vec3, c: Vec3) -> Option<f32> {
+    let denom = (b.z - c.z) * (a.x - c.x) + (c.x - b.x) * (a.z - c.z);
+    if denom.abs() <= f32::EPSILON {
+        return None;
+    }
+    let u = ((b.z - c.z) * (p.x - c.x) + (c.x - b.x) * (p.z - c.z)) / denom;
+    let v = ((c.z - a.z) * (p.x - c.x) + (a.x - c.x) * (p.z - c.z)) / denom;
+    let w = 1.0 - u - v;
+    if u < -CONTAINS_EPSILON || v < -CONTAINS_EPSILON || w < -CONTAINS_EPSILON {
+        return None;
+    }
+    Some(u * a.y + v * b.y + w * c.y)
+}
+
+/// Closest point on triangle (a, b, c) to `p` (Ericson, Real-Time Collision Detection)
+fn closest_point_on_triangle(p: Vec3, a: Vec3, b: Vec3, c: Vec3) -> Vec3 {
+    let ab = b - a;
+    let ac = c - a;
+    let ap = p - a;
+    let d1 = ab.dot(ap);
+    let d2 = ac.dot(ap);
+    if d1 <= 0.0 && d2 <= 0.0 {
+        return a;
+    }
+
+    let bp = p - b;
+    let d3 = ab.dot(bp);
+    let d4 = ac.dot(bp);
+    if d3 >= 0.0 && d4 <= d3 {
+        return b;
+    }
+
+    let vc = d1 * d4 - d3 * d2;
+    if vc <= 0.0 && d1 >= 0.0 && d3 <= 0.0 {
+        return a + ab * (d1 / (d1 - d3));
+    }
+
+    let cp = p - c;
+    let d5 = ab.dot(cp);
+    let d6 = ac.dot(cp);
+    if d6 >= 0.0 && d5 <= d6 {
+        return c;
+    }
+
+    let vb = d5 * d2 - d1 * d6;
+    if vb <= 0.0 && d2 >= 0.0 && d6 <= 0.0 {
+        return a + ac * (d2 / (d2 - d6));
+    }
+
+    let va = d3 * d6 - d5 * d4;
+    if va <= 0.0 && (d4 - d3) >= 0.0 && (d5 - d6) >= 0.0 {
+        return b + (c - b) * ((d4 - d3) / ((d4 - d3) + (d5 - d6)));
+    }
+
+    let denom = 1.0 / (va + vb + vc);
+    a + ab * (vb * denom) + ac * (vc * denom)
+}
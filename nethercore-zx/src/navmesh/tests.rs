@@ -0,0 +1,162 @@
+use glam::Vec3;
+
+use super::*;
+
+/// Build a floor of unit cells on the XZ plane, skipping cells in `holes`
+///
+/// Each cell has its own four vertices so baking has to weld them.
+fn grid(width: u32, depth: u32, holes: &[(u32, u32)]) -> (Vec<Vec3>, Vec<u32>) {
+    let mut positions = Vec::new();
+    let mut indices = Vec::new();
+    for z in 0..depth {
+        for x in 0..width {
+            if holes.contains(&(x, z)) {
+                continue;
+            }
+            let base = positions.len() as u32;
+            let (fx, fz) = (x as f32, z as f32);
+            positions.extend([
+                Vec3::new(fx, 0.0, fz),
+                Vec3::new(fx + 1.0, 0.0, fz),
+                Vec3::new(fx + 1.0, 0.0, fz + 1.0),
+                Vec3::new(fx, 0.0, fz + 1.0),
+            ]);
+            indices.extend([base, base + 2, base + 1, base, base + 3, base + 2]);
+        }
+    }
+    (positions, indices)
+}
+
+fn path_length(path: &[Vec3]) -> f32 {
+    path.windows(2).map(|w| w[0].distance(w[1])).sum()
+}
+
+#[test]
+fn test_bake_welds_and_links_cells() {
+    let (positions, indices) = grid(2, 2, &[]);
+    let mesh = NavMesh::bake(&positions, &indices, 0.0);
+
+    assert_eq!(mesh.vertices.len(), 9);
+    assert_eq!(mesh.triangle_count(), 8);
+    let links: usize = mesh
+        .neighbors
+        .iter()
+        .flatten()
+        .filter(|&&n| n != NO_NEIGHBOR)
+        .count();
+    // 4 diagonals + 4 cell borders, counted from both sides
+    assert_eq!(links, 16);
+}
+
+#[test]
+fn test_bake_discards_steep_triangles() {
+    let positions = [
+        Vec3::new(0.0, 0.0, 0.0),
+        Vec3::new(1.0, 0.0, 0.0),
+        Vec3::new(0.0, 1.0, 0.0),
+    ];
+    let mesh = NavMesh::bake(&positions, &[0, 1, 2], 0.0);
+    assert_eq!(mesh.triangle_count(), 0);
+    assert!(mesh.find_path(Vec3::ZERO, Vec3::X).is_none());
+}
+
+#[test]
+fn test_straight_path_in_open_area() {
+    let (positions, indices) = grid(4, 4, &[]);
+    let mesh = NavMesh::bake(&positions, &indices, 0.0);
+
+    let start = Vec3::new(0.5, 1.0, 0.3);
+    let end = Vec3::new(3.5, 1.0, 2.6);
+    let path = mesh.find_path(start, end).unwrap();
+
+    assert_eq!(
+        path,
+        vec![Vec3::new(0.5, 0.0, 0.3), Vec3::new(3.5, 0.0, 2.6)]
+    );
+}
+
+#[test]
+fn test_path_goes_around_obstacle() {
+    let (positions, indices) = grid(3, 3, &[(1, 1)]);
+    let mesh = NavMesh::bake(&positions, &indices, 0.0);
+
+    let path = mesh
+        .find_path(Vec3::new(0.5, 0.0, 1.5), Vec3::new(2.5, 0.0, 1.5))
+        .unwrap();
+
+    assert!(path.len() >= 4, "expected corners, got {:?}", path);
+    assert!(path_length(&path) > 2.0);
+    for point in &path {
+        let inside_hole = point.x > 1.0 + 1e-3
+            && point.x < 2.0 - 1e-3
+            && point.z > 1.0 + 1e-3
+            && point.z < 2.0 - 1e-3;
+        assert!(!inside_hole, "path point {:?} inside obstacle", point);
+    }
+}
+
+#[test]
+fn test_agent_radius_keeps_clear_of_corners() {
+    let (positions, indices) = grid(3, 3, &[(1, 1)]);
+    let mesh = NavMesh::bake(&positions, &indices, 0.25);
+
+    let path = mesh
+        .find_path(Vec3::new(0.5, 0.0, 1.5), Vec3::new(2.5, 0.0, 1.5))
+        .unwrap();
+    let hole_corners = [
+        Vec3::new(1.0, 0.0, 1.0),
+        Vec3::new(2.0, 0.0, 1.0),
+        Vec3::new(1.0, 0.0, 2.0),
+        Vec3::new(2.0, 0.0, 2.0),
+    ];
+    for point in &path {
+        for corner in &hole_corners {
+            assert!(point.distance(*corner) >= 0.25 - 1e-4);
+        }
+    }
+}
+
+#[test]
+fn test_narrow_gap_blocks_large_agent() {
+    // Two floors joined by a single 1-unit-wide cell
+    let (positions, indices) = grid(3, 3, &[(0, 1), (2, 1)]);
+
+    let small = NavMesh::bake(&positions, &indices, 0.25);
+    assert!(
+        small
+            .find_path(Vec3::new(0.5, 0.0, 0.5), Vec3::new(0.5, 0.0, 2.5))
+            .is_some_and(|p| p.len() >= 2)
+    );
+
+    let large = NavMesh::bake(&positions, &indices, 0.6);
+    assert!(
+        large
+            .find_path(Vec3::new(0.5, 0.0, 0.5), Vec3::new(0.5, 0.0, 2.5))
+            .is_none()
+    );
+}
+
+#[test]
+fn test_off_mesh_points_are_clamped() {
+    let (positions, indices) = grid(2, 2, &[]);
+    let mesh = NavMesh::bake(&positions, &indices, 0.0);
+
+    let path = mesh
+        .find_path(Vec3::new(-5.0, 0.0, 1.0), Vec3::new(1.5, 0.0, 1.5))
+        .unwrap();
+    assert!(path[0].distance(Vec3::new(0.0, 0.0, 1.0)) < 1e-5);
+}
+
+#[test]
+fn test_path_is_deterministic() {
+    let (positions, indices) = grid(6, 6, &[(2, 1), (2, 2), (2, 3), (4, 2), (4, 3), (4, 4)]);
+    let mesh = NavMesh::bake(&positions, &indices, 0.1);
+
+    let start = Vec3::new(0.5, 0.0, 2.5);
+    let end = Vec3::new(5.5, 0.0, 3.5);
+    let first = mesh.find_path(start, end).unwrap();
+    for _ in 0..8 {
+        let again = NavMesh::bake(&positions, &indices, 0.1);
+        assert_eq!(again.find_path(start, end).unwrap(), first);
+    }
+}
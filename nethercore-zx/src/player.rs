@@ -6,19 +6,26 @@
 //! - `nether run` command (development)
 //! - Library process spawning
 
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::time::Instant;
 
 use anyhow::{Context, Result};
+use rayon::prelude::*;
 
 use nethercore_core::Console;
 use nethercore_core::app::player::sanitize_game_id;
-use nethercore_core::app::{LoadedRom, RomLoader, StandaloneConfig, run_standalone};
+use nethercore_core::app::{
+    HeadlessPlayConfig, LoadedRom, RomLoader, StandaloneConfig, run_headless as run_headless_with,
+    run_standalone,
+};
+use nethercore_shared::capabilities::Capabilities;
 use nethercore_shared::local::LocalGameManifest;
 use nethercore_shared::{
-    MAX_ROM_BYTES, MAX_WASM_BYTES, ZX_ROM_FORMAT, is_safe_game_id, read_file_with_limit,
+    MAX_ROM_BYTES, MAX_WASM_BYTES, RomPatch, ZX_ROM_FORMAT, is_safe_game_id, netplay_rom_hash,
+    read_file_with_limit, rom_content_hash,
 };
-use zx_common::{ZXDataPack, ZXRom};
+use zx_common::{ZXDataPack, ZXMetadata, ZXOverridePack, ZXRom};
 
 use crate::console::NethercoreZX;
 
@@ -36,6 +43,14 @@ impl RomLoader for ZXRomLoader {
     type Console = NethercoreZX;
 
     fn load_rom(path: &Path) -> Result<LoadedRom<NethercoreZX>> {
+        Self::load_rom_patched(path, &[], &[])
+    }
+
+    fn load_rom_patched(
+        path: &Path,
+        rom_patches: &[PathBuf],
+        override_packs: &[PathBuf],
+    ) -> Result<LoadedRom<NethercoreZX>> {
         // Fallback name from file stem
         let fallback_name = path
             .file_stem()
@@ -44,10 +59,24 @@ impl RomLoader for ZXRomLoader {
             .to_string();
 
         if path.extension().and_then(|e| e.to_str()) == Some(ZX_ROM_FORMAT.extension) {
-            let rom_bytes = read_file_with_limit(path, MAX_ROM_BYTES)
+            let started = Instant::now();
+            // Read into memory rather than mapping the file: decoding and hashing
+            // must see the same bytes even if the file is rewritten meanwhile
+            let rom_file = read_file_with_limit(path, MAX_ROM_BYTES)
                 .context("Failed to read Nethercore ZX ROM file")?;
+            let mut patched: Option<Vec<u8>> = None;
+            for patch_path in rom_patches {
+                let rom_bytes = patched.as_deref().unwrap_or(&rom_file);
+                patched = Some(apply_rom_patch(rom_bytes, patch_path)?);
+            }
+            let rom_bytes = patched.as_deref().unwrap_or(&rom_file);
 
-            let rom = ZXRom::from_bytes(&rom_bytes).context("Failed to parse Nethercore ZX ROM")?;
+            // Decoding and hashing each read the whole ROM, so run them side by side
+            let (rom, rom_hash) = rayon::join(
+                || ZXRom::from_bytes(rom_bytes),
+                || rom_content_hash(rom_bytes),
+            );
+            let rom = rom.context("Failed to parse Nethercore ZX ROM")?;
 
             // Use metadata title, fall back to file stem if empty
             let game_name = if rom.metadata.title.is_empty() {
@@ -62,43 +91,151 @@ impl RomLoader for ZXRomLoader {
                 sanitize_game_id(&fallback_name)
             };
 
+            let mut data_pack = rom.data_pack;
+            if let Some(data_pack) = &mut data_pack {
+                apply_override_packs(&rom.metadata, data_pack, override_packs);
+            } else if !override_packs.is_empty() {
+                tracing::warn!("ROM has no bundled assets; ignoring override packs");
+            }
+
             // Create console with datapack
-            let data_pack: Option<Arc<ZXDataPack>> = rom.data_pack.map(Arc::new);
-            let render_mode = rom.metadata.render_mode.unwrap_or(0).min(3) as u8;
+            let data_pack: Option<Arc<ZXDataPack>> = data_pack.map(Arc::new);
+            let render_mode = rom.metadata.render_mode.unwrap_or(0).min(4) as u8;
             let console = NethercoreZX::with_datapack_and_render_mode(data_pack, render_mode);
 
+            // Unlock tokens are shared between games by the same verified author
+            let publisher = registry_publisher(path, &rom_hash);
+
+            let mut netplay = rom.metadata.netplay;
+            netplay.rom_hash = netplay_rom_hash(&rom_hash);
+
+            tracing::info!("Loaded ROM {} in {:.1?}", path.display(), started.elapsed());
+
             Ok(LoadedRom {
                 code: rom.code,
                 console,
                 game_name,
                 game_id,
+                publisher,
+                capabilities: rom.metadata.capabilities,
+                rom_hash,
+                netplay: Some(netplay),
             })
         } else {
             // Raw WASM file - use file stem as name
+            if !rom_patches.is_empty() {
+                anyhow::bail!("ROM patches can only be applied to .nczx ROMs");
+            }
+            if !override_packs.is_empty() {
+                tracing::warn!("Raw WASM files have no bundled assets; ignoring override packs");
+            }
             let wasm =
                 read_file_with_limit(path, MAX_WASM_BYTES).context("Failed to read WASM file")?;
 
             let fallback_game_id = sanitize_game_id(&fallback_name);
             let game_id = wasm_game_id_from_path(path).unwrap_or(fallback_game_id);
 
+            let rom_hash = rom_content_hash(&wasm);
+
             Ok(LoadedRom {
                 code: wasm,
                 console: NethercoreZX::new(),
                 game_name: fallback_name,
                 game_id,
+                publisher: registry_publisher(path, &rom_hash),
+                capabilities: Capabilities::ALL,
+                rom_hash,
+                netplay: None,
             })
         }
     }
 }
 
+/// Apply one delta patch to raw ROM bytes
+fn apply_rom_patch(rom_bytes: &[u8], patch_path: &Path) -> Result<Vec<u8>> {
+    let patch = read_file_with_limit(patch_path, MAX_ROM_BYTES)
+        .and_then(|bytes| RomPatch::from_bytes(&bytes))
+        .with_context(|| format!("Failed to load ROM patch: {}", patch_path.display()))?;
+    let patched = patch
+        .apply(rom_bytes)
+        .with_context(|| format!("Failed to apply ROM patch: {}", patch_path.display()))?;
+    tracing::info!(
+        "Applied ROM patch {} {} -> {}",
+        patch.game_id,
+        patch.from_version,
+        patch.to_version
+    );
+    Ok(patched)
+}
+
+/// Apply sideloaded override packs to a ROM's data pack, in order
+///
+/// Packs are skipped (with a warning) if the ROM doesn't allow overrides, or
+/// if a pack is unreadable or targets a different game.
+fn apply_override_packs(metadata: &ZXMetadata, data_pack: &mut ZXDataPack, packs: &[PathBuf]) {
+    if packs.is_empty() {
+        return;
+    }
+    if !metadata.allow_override_packs {
+        tracing::warn!(
+            "{} does not allow override packs; ignoring {} pack(s)",
+            metadata.id,
+            packs.len()
+        );
+        return;
+    }
+
+    // Packs are independent until applied, so read and decode them in parallel
+    let loaded: Vec<Result<ZXOverridePack>> = packs
+        .par_iter()
+        .map(|path| {
+            read_file_with_limit(path, MAX_ROM_BYTES)
+                .and_then(|bytes| ZXOverridePack::from_bytes(&bytes))
+        })
+        .collect();
+
+    for (path, pack) in packs.iter().zip(loaded) {
+        let pack = match pack {
+            Ok(pack) => pack,
+            Err(e) => {
+                tracing::warn!("Failed to load override pack {}: {:#}", path.display(), e);
+                continue;
+            }
+        };
+        if pack.game_id != metadata.id {
+            tracing::warn!(
+                "Override pack {} targets '{}', not '{}'; skipping",
+                path.display(),
+                pack.game_id,
+                metadata.id
+            );
+            continue;
+        }
+
+        let report = data_pack.apply_overrides(&pack.data_pack);
+        tracing::info!(
+            "Applied override pack '{}' by {}: {} asset(s) replaced",
+            pack.title,
+            pack.author,
+            report.replaced
+        );
+        if !report.unmatched.is_empty() {
+            tracing::warn!(
+                "Override pack '{}' has assets not in the ROM (ignored): {}",
+                pack.title,
+                report.unmatched.join(", ")
+            );
+        }
+    }
+}
+
 fn wasm_game_id_from_path(path: &Path) -> Option<String> {
     let parent = path.parent()?;
 
     // Prefer manifest.json next to the ROM.
     let manifest_path = parent.join("manifest.json");
     if manifest_path.is_file() {
-        let bytes = read_file_with_limit(&manifest_path, MAX_MANIFEST_BYTES).ok()?;
-        let manifest = serde_json::from_slice::<LocalGameManifest>(&bytes).ok()?;
+        let manifest = read_local_manifest(path)?;
         if !manifest.id.is_empty() && is_safe_game_id(&manifest.id) {
             return Some(manifest.id);
         }
@@ -116,6 +253,23 @@ fn wasm_game_id_from_path(path: &Path) -> Option<String> {
     Some(sanitize_game_id(file_stem))
 }
 
+/// Read the library's manifest.json next to a ROM, if there is one
+fn read_local_manifest(path: &Path) -> Option<LocalGameManifest> {
+    let bytes =
+        read_file_with_limit(&path.parent()?.join("manifest.json"), MAX_MANIFEST_BYTES).ok()?;
+    serde_json::from_slice(&bytes).ok()
+}
+
+/// Token publisher for a ROM: the registry author the library recorded when
+/// it downloaded this exact build
+///
+/// The ROM's own `author` field is free text that any ROM can claim, so it is
+/// never used. Sideloaded and patched ROMs get no publisher (and no tokens).
+fn registry_publisher(path: &Path, rom_hash: &[u8; 32]) -> Option<String> {
+    let manifest = read_local_manifest(path)?;
+    manifest.verified_author_id(rom_hash).map(sanitize_game_id)
+}
+
 /// Run the standalone player
 pub fn run(config: PlayerConfig) -> Result<()> {
     // Initialize tracing
@@ -129,15 +283,111 @@ pub fn run(config: PlayerConfig) -> Result<()> {
     run_standalone::<NethercoreZX, ZXRomLoader>(config)
 }
 
+/// Play recorded inputs without a window and return the final state checksum
+///
+/// Logs go to stderr so stdout carries only the checksum.
+pub fn run_headless(config: &HeadlessPlayConfig) -> Result<u64> {
+    tracing_subscriber::fmt()
+        .with_env_filter(
+            tracing_subscriber::EnvFilter::from_default_env()
+                .add_directive(tracing::Level::INFO.into()),
+        )
+        .with_writer(std::io::stderr)
+        .init();
+
+    run_headless_with::<ZXRomLoader>(config)
+}
+
 #[cfg(test)]
 mod tests {
     use std::fs;
 
     use nethercore_core::app::RomLoader;
     use nethercore_shared::local::LocalGameManifest;
+    use nethercore_shared::{NetplayMetadata, rom_content_hash, rom_hash_hex};
     use tempfile::tempdir;
+    use zx_common::formats::PackedTexture;
+    use zx_common::{OVERRIDE_PACK_VERSION, ZXDataPack, ZXMetadata, ZXOverridePack, ZXRom};
 
-    use super::ZXRomLoader;
+    use super::{ZXRomLoader, apply_override_packs};
+
+    fn test_metadata() -> ZXMetadata {
+        ZXMetadata {
+            id: "neon-drift".to_string(),
+            title: "Neon Drift".to_string(),
+            author: "Nethercore".to_string(),
+            version: "1.0.0".to_string(),
+            description: String::new(),
+            tags: vec![],
+            platform_game_id: None,
+            platform_author_id: None,
+            created_at: "2026-01-01T00:00:00Z".to_string(),
+            tool_version: "0.1.0".to_string(),
+            render_mode: None,
+            default_resolution: None,
+            target_fps: None,
+            allow_override_packs: true,
+            capabilities: Default::default(),
+            netplay: NetplayMetadata::default(),
+        }
+    }
+
+    fn texture_pack(id: &str, pixel: u8) -> ZXDataPack {
+        let mut data_pack = ZXDataPack::new();
+        data_pack
+            .textures
+            .push(PackedTexture::new(id, 1, 1, vec![pixel; 4]));
+        data_pack
+    }
+
+    #[test]
+    fn zx_loader_hashes_mapped_rom() {
+        let tmp = tempdir().unwrap();
+        let rom = ZXRom {
+            version: nethercore_shared::ZX_ROM_FORMAT.version,
+            metadata: test_metadata(),
+            code: b"\0asm\x01\x00\x00\x00".to_vec(),
+            data_pack: Some(texture_pack("car", 1)),
+            thumbnail: None,
+            screenshots: vec![],
+        };
+        let bytes = rom.to_bytes().unwrap();
+        let rom_path = tmp.path().join("neon-drift.nczx");
+        fs::write(&rom_path, &bytes).unwrap();
+
+        let loaded = ZXRomLoader::load_rom(&rom_path).unwrap();
+        assert_eq!(loaded.game_id, "neon-drift");
+        assert_eq!(loaded.code, rom.code);
+        assert_eq!(loaded.rom_hash, rom_content_hash(&bytes));
+    }
+
+    #[test]
+    fn override_packs_apply_in_order_after_parallel_load() {
+        let tmp = tempdir().unwrap();
+        let metadata = test_metadata();
+        let write_pack = |name: &str, game_id: &str, pixel: u8| {
+            let pack = ZXOverridePack {
+                version: OVERRIDE_PACK_VERSION,
+                game_id: game_id.to_string(),
+                title: name.to_string(),
+                author: "Modder".to_string(),
+                data_pack: texture_pack("car", pixel),
+            };
+            let path = tmp.path().join(format!("{name}.nczxmod"));
+            fs::write(&path, pack.to_bytes()).unwrap();
+            path
+        };
+        let packs = [
+            write_pack("first", "neon-drift", 2),
+            write_pack("second", "neon-drift", 3),
+            write_pack("other-game", "other-game", 4),
+            tmp.path().join("missing.nczxmod"),
+        ];
+
+        let mut data_pack = texture_pack("car", 1);
+        apply_override_packs(&metadata, &mut data_pack, &packs);
+        assert_eq!(data_pack.find_texture("car").unwrap().data, vec![3; 4]);
+    }
 
     #[test]
     fn zx_loader_prefers_manifest_game_id_for_rom_wasm() {
@@ -156,11 +406,57 @@ mod tests {
             version: "0.0.0".to_string(),
             downloaded_at: "2026-01-26T00:00:00Z".to_string(),
             console_type: "zx".to_string(),
+            registry_author_id: None,
+            registry_rom_sha256: None,
         };
         let manifest_json = serde_json::to_vec(&manifest).unwrap();
         fs::write(game_dir.join("manifest.json"), manifest_json).unwrap();
 
         let loaded = ZXRomLoader::load_rom(&wasm_path).unwrap();
         assert_eq!(loaded.game_id, manifest_id);
+        assert_eq!(loaded.publisher, None);
+    }
+
+    #[test]
+    fn zx_loader_takes_publisher_from_registry_manifest_only() {
+        let tmp = tempdir().unwrap();
+        let game_dir = tmp.path().join("games").join("some-game");
+        fs::create_dir_all(&game_dir).unwrap();
+
+        let wasm = [0_u8, 1, 2, 3];
+        let wasm_path = game_dir.join("rom.wasm");
+        fs::write(&wasm_path, wasm).unwrap();
+
+        let mut manifest = LocalGameManifest {
+            id: "some-game".to_string(),
+            title: "Some Game".to_string(),
+            author: "Someone Else".to_string(),
+            version: "0.0.0".to_string(),
+            downloaded_at: "2026-01-26T00:00:00Z".to_string(),
+            console_type: "zx".to_string(),
+            registry_author_id: Some("0b7e3c9a-5d2f-4c61-9e8a-2f4d6b1c8e07".to_string()),
+            registry_rom_sha256: Some(rom_hash_hex(&rom_content_hash(&wasm))),
+        };
+        fs::write(
+            game_dir.join("manifest.json"),
+            serde_json::to_vec(&manifest).unwrap(),
+        )
+        .unwrap();
+
+        let loaded = ZXRomLoader::load_rom(&wasm_path).unwrap();
+        assert_eq!(
+            loaded.publisher.as_deref(),
+            Some("0b7e3c9a-5d2f-4c61-9e8a-2f4d6b1c8e07")
+        );
+
+        // A ROM that no longer matches the recorded hash loses its publisher
+        manifest.registry_rom_sha256 = Some(rom_hash_hex(&rom_content_hash(b"other")));
+        fs::write(
+            game_dir.join("manifest.json"),
+            serde_json::to_vec(&manifest).unwrap(),
+        )
+        .unwrap();
+        let loaded = ZXRomLoader::load_rom(&wasm_path).unwrap();
+        assert_eq!(loaded.publisher, None);
     }
 }
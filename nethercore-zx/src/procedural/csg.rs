@@ -0,0 +1,355 @@
+//! Constructive solid geometry (union, subtract, intersect)
+//!
+//! BSP-tree CSG in the style of csg.js: each operand is turned into a BSP
+//! tree of convex polygons, the trees clip each other, and the surviving
+//! polygons form the result. Operands should be closed meshes with CCW
+//! winding (as produced by the mesh generators); open meshes still work but
+//! may leave stray faces.
+//!
+//! Results are flat-shaded (one normal per face) and deterministic.
+
+use glam::Vec3;
+
+use crate::procedural::types::MeshBuilder;
+
+/// Distance below which a point is considered on a plane
+const EPSILON: f32 = 1e-5;
+
+/// Maximum triangles per operand (bounds BSP build time and depth)
+pub const MAX_CSG_TRIANGLES: usize = 8192;
+
+/// Boolean operation between two solids
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CsgOp {
+    /// Space inside either solid
+    Union,
+    /// Space inside the first solid but not the second
+    Subtract,
+    /// Space inside both solids
+    Intersect,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Plane {
+    normal: Vec3,
+    w: f32,
+}
+
+impl Plane {
+    /// Plane through three points (None for degenerate triangles)
+    fn from_points(a: Vec3, b: Vec3, c: Vec3) -> Option<Self> {
+        let normal = (b - a).cross(c - a).try_normalize()?;
+        Some(Self {
+            normal,
+            w: normal.dot(a),
+        })
+    }
+
+    fn flip(&mut self) {
+        self.normal = -self.normal;
+        self.w = -self.w;
+    }
+
+    /// Sort `polygon` into the lists by which side of the plane it lies on,
+    /// splitting it if it spans the plane
+    fn split_polygon(
+        &self,
+        polygon: Polygon,
+        coplanar_front: &mut Vec<Polygon>,
+        coplanar_back: &mut Vec<Polygon>,
+        front: &mut Vec<Polygon>,
+        back: &mut Vec<Polygon>,
+    ) {
+        const COPLANAR: u8 = 0;
+        const FRONT: u8 = 1;
+        const BACK: u8 = 2;
+        const SPANNING: u8 = 3;
+
+        let mut polygon_type = COPLANAR;
+        let types: Vec<u8> = polygon
+            .vertices
+            .iter()
+            .map(|&v| {
+                let t = self.normal.dot(v) - self.w;
+                let vertex_type = if t < -EPSILON {
+                    BACK
+                } else if t > EPSILON {
+                    FRONT
+                } else {
+                    COPLANAR
+                };
+                polygon_type |= vertex_type;
+                vertex_type
+            })
+            .collect();
+
+        match polygon_type {
+            COPLANAR => {
+                if self.normal.dot(polygon.plane.normal) > 0.0 {
+                    coplanar_front.push(polygon);
+                } else {
+                    coplanar_back.push(polygon);
+                }
+            }
+            FRONT => front.push(polygon),
+            BACK => back.push(polygon),
+            _ => {
+                let count = polygon.vertices.len();
+                let mut f = Vec::with_capacity(count + 1);
+                let mut b = Vec::with_capacity(count + 1);
+                for i in 0..count {
+                    let j = (i + 1) % count;
+                    let (ti, tj) = (types[i], types[j]);
+                    let (vi, vj) = (polygon.vertices[i], polygon.vertices[j]);
+                    if ti != BACK {
+                        f.push(vi);
+                    }
+                    if ti != FRONT {
+                        b.push(vi);
+                    }
+                    if (ti | tj) == SPANNING {
+                        let t = (self.w - self.normal.dot(vi)) / self.normal.dot(vj - vi);
+                        let v = vi.lerp(vj, t);
+                        f.push(v);
+                        b.push(v);
+                    }
+                }
+                if f.len() >= 3 {
+                    front.push(Polygon {
+                        vertices: f,
+                        plane: polygon.plane,
+                    });
+                }
+                if b.len() >= 3 {
+                    back.push(Polygon {
+                        vertices: b,
+                        plane: polygon.plane,
+                    });
+                }
+            }
+        }
+    }
+}
+
+/// Convex polygon with CCW winding
+#[derive(Clone, Debug)]
+struct Polygon {
+    vertices: Vec<Vec3>,
+    plane: Plane,
+}
+
+impl Polygon {
+    fn flip(&mut self) {
+        self.vertices.reverse();
+        self.plane.flip();
+    }
+}
+
+#[derive(Default)]
+struct Node {
+    plane: Option<Plane>,
+    front: Option<Box<Node>>,
+    back: Option<Box<Node>>,
+    polygons: Vec<Polygon>,
+}
+
+impl Node {
+    fn new(polygons: Vec<Polygon>) -> Self {
+        let mut node = Self::default();
+        node.build(polygons);
+        node
+    }
+
+    /// Swap solid and empty space
+    fn invert(&mut self) {
+        for polygon in &mut self.polygons {
+            polygon.flip();
+        }
+        if let Some(plane) = &mut self.plane {
+            plane.flip();
+        }
+        if let Some(front) = &mut self.front {
+            front.invert();
+        }
+        if let Some(back) = &mut self.back {
+            back.invert();
+        }
+        std::mem::swap(&mut self.front, &mut self.back);
+    }
+
+    /// Remove the parts of `polygons` inside this tree
+    fn clip_polygons(&self, polygons: Vec<Polygon>) -> Vec<Polygon> {
+        let Some(plane) = self.plane else {
+            return polygons;
+        };
+
+        let (mut coplanar_front, mut coplanar_back) = (Vec::new(), Vec::new());
+        let (mut front, mut back) = (Vec::new(), Vec::new());
+        for polygon in polygons {
+            plane.split_polygon(
+                polygon,
+                &mut coplanar_front,
+                &mut coplanar_back,
+                &mut front,
+                &mut back,
+            );
+        }
+        front.append(&mut coplanar_front);
+        back.append(&mut coplanar_back);
+
+        let mut front = match &self.front {
+            Some(node) => node.clip_polygons(front),
+            None => front,
+        };
+        if let Some(node) = &self.back {
+            front.extend(node.clip_polygons(back));
+        }
+        front
+    }
+
+    /// Remove the parts of this tree's polygons inside `bsp`
+    fn clip_to(&mut self, bsp: &Node) {
+        self.polygons = bsp.clip_polygons(std::mem::take(&mut self.polygons));
+        if let Some(front) = &mut self.front {
+            front.clip_to(bsp);
+        }
+        if let Some(back) = &mut self.back {
+            back.clip_to(bsp);
+        }
+    }
+
+    fn all_polygons(&self, out: &mut Vec<Polygon>) {
+        out.extend(self.polygons.iter().cloned());
+        if let Some(front) = &self.front {
+            front.all_polygons(out);
+        }
+        if let Some(back) = &self.back {
+            back.all_polygons(out);
+        }
+    }
+
+    fn build(&mut self, polygons: Vec<Polygon>) {
+        let Some(first) = polygons.first() else {
+            return;
+        };
+        let plane = *self.plane.get_or_insert(first.plane);
+
+        let (mut coplanar_front, mut coplanar_back) = (Vec::new(), Vec::new());
+        let (mut front, mut back) = (Vec::new(), Vec::new());
+        for polygon in polygons {
+            plane.split_polygon(
+                polygon,
+                &mut coplanar_front,
+                &mut coplanar_back,
+                &mut front,
+                &mut back,
+            );
+        }
+        self.polygons.append(&mut coplanar_front);
+        self.polygons.append(&mut coplanar_back);
+
+        if !front.is_empty() {
+            self.front.get_or_insert_default().build(front);
+        }
+        if !back.is_empty() {
+            self.back.get_or_insert_default().build(back);
+        }
+    }
+}
+
+/// Convert indexed triangles into polygons, dropping degenerate ones
+fn to_polygons(positions: &[Vec3], indices: &[u32]) -> Vec<Polygon> {
+    indices
+        .chunks_exact(3)
+        .filter_map(|tri| {
+            let vertices: Vec<Vec3> = tri
+                .iter()
+                .map(|&i| positions.get(i as usize).copied())
+                .collect::<Option<_>>()?;
+            let plane = Plane::from_points(vertices[0], vertices[1], vertices[2])?;
+            Some(Polygon { vertices, plane })
+        })
+        .collect()
+}
+
+/// Combine two indexed triangle meshes
+///
+/// Returns the result as a flat list of CCW triangles.
+pub fn csg_triangles(
+    op: CsgOp,
+    a_positions: &[Vec3],
+    a_indices: &[u32],
+    b_positions: &[Vec3],
+    b_indices: &[u32],
+) -> Vec<[Vec3; 3]> {
+    let mut a = Node::new(to_polygons(a_positions, a_indices));
+    let mut b = Node::new(to_polygons(b_positions, b_indices));
+    let mut b_polygons = Vec::new();
+
+    match op {
+        CsgOp::Union => {
+            a.clip_to(&b);
+            b.clip_to(&a);
+            b.invert();
+            b.clip_to(&a);
+            b.invert();
+            b.all_polygons(&mut b_polygons);
+            a.build(b_polygons);
+        }
+        CsgOp::Subtract => {
+            a.invert();
+            a.clip_to(&b);
+            b.clip_to(&a);
+            b.invert();
+            b.clip_to(&a);
+            b.invert();
+            b.all_polygons(&mut b_polygons);
+            a.build(b_polygons);
+            a.invert();
+        }
+        CsgOp::Intersect => {
+            a.invert();
+            b.clip_to(&a);
+            b.invert();
+            a.clip_to(&b);
+            b.clip_to(&a);
+            b.all_polygons(&mut b_polygons);
+            a.build(b_polygons);
+            a.invert();
+        }
+    }
+
+    let mut polygons = Vec::new();
+    a.all_polygons(&mut polygons);
+
+    // Fan-triangulate the convex polygons
+    let mut triangles = Vec::new();
+    for polygon in &polygons {
+        let v = &polygon.vertices;
+        for i in 1..v.len() - 1 {
+            if (v[i] - v[0]).cross(v[i + 1] - v[0]).length_squared() > EPSILON * EPSILON {
+                triangles.push([v[0], v[i], v[i + 1]]);
+            }
+        }
+    }
+    triangles
+}
+
+/// Build a flat-shaded mesh from triangles
+///
+/// Returns `None` if the mesh would exceed the 16-bit index limit.
+pub fn build_flat_mesh<M: MeshBuilder>(triangles: &[[Vec3; 3]]) -> Option<M> {
+    if triangles.len() * 3 > u16::MAX as usize + 1 {
+        return None;
+    }
+
+    let mut mesh = M::default();
+    for &[a, b, c] in triangles {
+        let normal = (b - a).cross(c - a).normalize_or_zero();
+        let i0 = mesh.add_vertex(a, normal);
+        let i1 = mesh.add_vertex(b, normal);
+        let i2 = mesh.add_vertex(c, normal);
+        mesh.add_triangle(i0, i1, i2);
+    }
+    Some(mesh)
+}
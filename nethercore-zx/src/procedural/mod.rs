@@ -6,10 +6,12 @@
 //! - Format 4 (POS_NORMAL): 12 bytes/vertex (f16x4 + octahedral u32)
 //! - Format 5 (POS_UV_NORMAL): 16 bytes/vertex (f16x4 + unorm16x2 + octahedral u32)
 
+mod csg;
 mod export;
 mod primitives;
 mod primitives_tangent;
 mod primitives_uv;
+mod shading;
 mod types;
 
 #[cfg(test)]
@@ -22,6 +24,12 @@ pub use types::{
     UnpackedMesh,
 };
 
+// Re-export CSG boolean operations
+pub use csg::{CsgOp, MAX_CSG_TRIANGLES, build_flat_mesh, csg_triangles};
+
+// Re-export normal and tangent recomputation
+pub use shading::{SmoothedNormals, generate_tangents, smooth_normals};
+
 // Re-export OBJ export
 pub use export::write_obj;
 
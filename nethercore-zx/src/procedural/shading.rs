@@ -0,0 +1,158 @@
+//! Normal and tangent recomputation
+//!
+//! Displacing a mesh's vertices leaves it with the normals of the shape it
+//! started as, so lighting no longer follows the surface. These rebuild
+//! normals from the triangles, splitting vertices along creases sharper than
+//! a smoothing angle, and derive tangents from the UVs for normal mapping.
+//!
+//! Both are deterministic: output order follows the input triangles.
+
+use std::collections::HashMap;
+
+use glam::{Vec2, Vec3, Vec4};
+
+/// Normals rebuilt from a mesh's triangles
+#[derive(Debug)]
+pub struct SmoothedNormals {
+    /// Source vertex each output vertex copies its other attributes from
+    pub vertices: Vec<u32>,
+    /// Normal of each output vertex
+    pub normals: Vec<Vec3>,
+    /// Triangle indices into the output vertices
+    pub indices: Vec<u32>,
+}
+
+/// Rebuild normals from the triangles of a mesh
+///
+/// Each triangle corner averages the normals of the triangles touching the
+/// same position whose normals are within `smooth_angle_deg` of its own,
+/// weighted by their angle at that position. Corners that end up with
+/// different normals get separate vertices, so 0 gives flat shading and 180
+/// smooths across every edge. Vertices are matched by position, so triangle
+/// soups and UV seams smooth too.
+pub fn smooth_normals(
+    positions: &[Vec3],
+    indices: &[u32],
+    smooth_angle_deg: f32,
+) -> SmoothedNormals {
+    let triangles: Vec<[u32; 3]> = indices
+        .chunks_exact(3)
+        .map(|t| [t[0], t[1], t[2]])
+        .collect();
+
+    // Unit face normals, and the angle of each triangle at each corner
+    let unit: Vec<Vec3> = triangles
+        .iter()
+        .map(|t| {
+            let [a, b, c] = t.map(|v| positions[v as usize]);
+            (b - a).cross(c - a).normalize_or_zero()
+        })
+        .collect();
+    let corner_angles: Vec<[f32; 3]> = triangles
+        .iter()
+        .map(|t| {
+            let p = t.map(|v| positions[v as usize]);
+            std::array::from_fn(|k| {
+                let (prev, next) = (p[(k + 2) % 3] - p[k], p[(k + 1) % 3] - p[k]);
+                prev.angle_between(next)
+            })
+        })
+        .collect();
+
+    // Triangle corners at each distinct position
+    let mut groups: HashMap<[u32; 3], usize> = HashMap::new();
+    let group_of: Vec<usize> = positions
+        .iter()
+        .map(|p| {
+            let next = groups.len();
+            *groups.entry(p.to_array().map(f32::to_bits)).or_insert(next)
+        })
+        .collect();
+    let mut group_corners: Vec<Vec<(usize, usize)>> = vec![Vec::new(); groups.len()];
+    for (face, t) in triangles.iter().enumerate() {
+        for (k, &v) in t.iter().enumerate() {
+            group_corners[group_of[v as usize]].push((face, k));
+        }
+    }
+
+    // Small slack so coplanar faces merge despite rounding at 0 degrees
+    let min_dot = smooth_angle_deg.clamp(0.0, 180.0).to_radians().cos() - 1e-5;
+
+    let mut out = SmoothedNormals {
+        vertices: Vec::new(),
+        normals: Vec::new(),
+        indices: Vec::with_capacity(triangles.len() * 3),
+    };
+    let mut seen: HashMap<(u32, [u32; 3]), u32> = HashMap::new();
+    for (face, t) in triangles.iter().enumerate() {
+        for &v in t {
+            let own = unit[face];
+            let sum: Vec3 = group_corners[group_of[v as usize]]
+                .iter()
+                .filter(|&&(other, _)| own == Vec3::ZERO || own.dot(unit[other]) >= min_dot)
+                .map(|&(other, k)| unit[other] * corner_angles[other][k])
+                .sum();
+            let normal = sum.try_normalize().unwrap_or(Vec3::Y);
+
+            let key = (v, normal.to_array().map(f32::to_bits));
+            let index = *seen.entry(key).or_insert_with(|| {
+                out.vertices.push(v);
+                out.normals.push(normal);
+                out.vertices.len() as u32 - 1
+            });
+            out.indices.push(index);
+        }
+    }
+    out
+}
+
+/// Per-vertex tangents (xyz + bitangent handedness) from UVs and normals
+///
+/// Tangents point along +U, orthogonalized against each vertex normal. The
+/// handedness is -1 where the UVs are mirrored. Vertices without usable UVs
+/// get an arbitrary tangent perpendicular to the normal.
+pub fn generate_tangents(
+    positions: &[Vec3],
+    uvs: &[Vec2],
+    normals: &[Vec3],
+    indices: &[u32],
+) -> Vec<Vec4> {
+    let mut tangents = vec![Vec3::ZERO; positions.len()];
+    let mut bitangents = vec![Vec3::ZERO; positions.len()];
+
+    for t in indices.chunks_exact(3) {
+        let [a, b, c] = [t[0], t[1], t[2]].map(|v| v as usize);
+        let e1 = positions[b] - positions[a];
+        let e2 = positions[c] - positions[a];
+        let d1 = uvs[b] - uvs[a];
+        let d2 = uvs[c] - uvs[a];
+
+        let det = d1.x * d2.y - d2.x * d1.y;
+        if det.abs() < 1e-12 {
+            continue;
+        }
+        let r = 1.0 / det;
+        let tangent = (e1 * d2.y - e2 * d1.y) * r;
+        let bitangent = (e2 * d1.x - e1 * d2.x) * r;
+        for v in [a, b, c] {
+            tangents[v] += tangent;
+            bitangents[v] += bitangent;
+        }
+    }
+
+    normals
+        .iter()
+        .zip(tangents.iter().zip(&bitangents))
+        .map(|(&n, (&t, &b))| {
+            let tangent = (t - n * n.dot(t))
+                .try_normalize()
+                .unwrap_or_else(|| n.any_orthonormal_vector());
+            let handedness = if n.cross(tangent).dot(b) < 0.0 {
+                -1.0
+            } else {
+                1.0
+            };
+            tangent.extend(handedness)
+        })
+        .collect()
+}
@@ -657,3 +657,148 @@ fn test_winding_all_shapes_simple() {
         }
     }
 }
+
+// ============================================================================
+// CSG Tests
+// ============================================================================
+
+/// Positions and indices of a unit-half-extent cube shifted by `offset`
+fn cube_geometry(offset: glam::Vec3) -> (Vec<glam::Vec3>, Vec<u32>) {
+    let mesh: UnpackedMesh = generate_cube(1.0, 1.0, 1.0);
+    let positions = mesh
+        .positions
+        .iter()
+        .map(|&p| glam::Vec3::from(p) + offset)
+        .collect();
+    let indices = mesh.indices.iter().copied().map(u32::from).collect();
+    (positions, indices)
+}
+
+/// Enclosed volume of a closed CCW triangle list (divergence theorem)
+fn signed_volume(triangles: &[[glam::Vec3; 3]]) -> f32 {
+    triangles
+        .iter()
+        .map(|[a, b, c]| a.dot(b.cross(*c)) / 6.0)
+        .sum()
+}
+
+fn csg_volume(op: CsgOp, offset: glam::Vec3) -> f32 {
+    let (a_pos, a_idx) = cube_geometry(glam::Vec3::ZERO);
+    let (b_pos, b_idx) = cube_geometry(offset);
+    signed_volume(&csg_triangles(op, &a_pos, &a_idx, &b_pos, &b_idx))
+}
+
+#[test]
+fn test_csg_overlapping_cubes_volume() {
+    // Two 2×2×2 cubes overlapping by half along X
+    let offset = glam::Vec3::new(1.0, 0.0, 0.0);
+    assert!((csg_volume(CsgOp::Union, offset) - 12.0).abs() < 1e-3);
+    assert!((csg_volume(CsgOp::Subtract, offset) - 4.0).abs() < 1e-3);
+    assert!((csg_volume(CsgOp::Intersect, offset) - 4.0).abs() < 1e-3);
+}
+
+#[test]
+fn test_csg_disjoint_cubes() {
+    let offset = glam::Vec3::new(5.0, 0.0, 0.0);
+    assert!((csg_volume(CsgOp::Union, offset) - 16.0).abs() < 1e-3);
+    assert!((csg_volume(CsgOp::Subtract, offset) - 8.0).abs() < 1e-3);
+
+    let (a_pos, a_idx) = cube_geometry(glam::Vec3::ZERO);
+    let (b_pos, b_idx) = cube_geometry(offset);
+    assert!(csg_triangles(CsgOp::Intersect, &a_pos, &a_idx, &b_pos, &b_idx).is_empty());
+}
+
+#[test]
+fn test_csg_flat_mesh_packing() {
+    let (a_pos, a_idx) = cube_geometry(glam::Vec3::ZERO);
+    let (b_pos, b_idx) = cube_geometry(glam::Vec3::new(0.5, 0.5, 0.5));
+    let triangles = csg_triangles(CsgOp::Subtract, &a_pos, &a_idx, &b_pos, &b_idx);
+
+    let mesh: MeshData = build_flat_mesh(&triangles).unwrap();
+    assert_eq!(mesh.indices.len(), triangles.len() * 3);
+    assert_eq!(mesh.vertices.len(), triangles.len() * 3 * 12);
+
+    let too_many = vec![triangles[0]; 30_000];
+    assert!(build_flat_mesh::<MeshData>(&too_many).is_none());
+}
+
+// ============================================================================
+// Normal and Tangent Recomputation Tests
+// ============================================================================
+
+#[test]
+fn test_smooth_normals_cube_crease() {
+    let (positions, indices) = cube_geometry(glam::Vec3::ZERO);
+
+    // Cube edges are 90°: below that every face keeps its own normal
+    let flat = smooth_normals(&positions, &indices, 30.0);
+    assert_eq!(flat.vertices.len(), 24);
+    assert_eq!(flat.indices.len(), indices.len());
+    for (&v, n) in flat.vertices.iter().zip(&flat.normals) {
+        let axis = n.abs().max_element();
+        assert!((axis - 1.0).abs() < 1e-5, "flat normal {n} at vertex {v}");
+    }
+
+    // Above it each corner's vertices (kept apart for their other
+    // attributes) share one diagonal normal
+    let smooth = smooth_normals(&positions, &indices, 100.0);
+    assert_eq!(smooth.vertices.len(), 24);
+    for (&v, n) in smooth.vertices.iter().zip(&smooth.normals) {
+        let expected = positions[v as usize].normalize();
+        assert!(n.dot(expected) > 0.999, "smooth normal {n} at vertex {v}");
+    }
+}
+
+#[test]
+fn test_smooth_normals_welds_triangle_soup() {
+    // Two non-indexed triangles of a bent quad, 20° apart
+    let fold = 20f32.to_radians();
+    let positions = vec![
+        glam::Vec3::new(0.0, 0.0, 0.0),
+        glam::Vec3::new(1.0, 0.0, 0.0),
+        glam::Vec3::new(0.0, 0.0, -1.0),
+        glam::Vec3::new(1.0, 0.0, 0.0),
+        glam::Vec3::new(1.0, fold.sin(), -fold.cos()),
+        glam::Vec3::new(0.0, 0.0, -1.0),
+    ];
+    let indices: Vec<u32> = (0..6).collect();
+
+    let smooth = smooth_normals(&positions, &indices, 30.0);
+    let shared: Vec<_> = smooth
+        .vertices
+        .iter()
+        .zip(&smooth.normals)
+        .filter(|&(&v, _)| v == 1 || v == 3)
+        .map(|(_, n)| *n)
+        .collect();
+    assert_eq!(shared[0], shared[1]);
+
+    let sharp = smooth_normals(&positions, &indices, 10.0);
+    assert_eq!(sharp.vertices.len(), 6);
+}
+
+#[test]
+fn test_generate_tangents_follow_u() {
+    let mesh: UnpackedMesh = generate_plane(2.0, 2.0, 1, 1);
+    let positions: Vec<glam::Vec3> = mesh.positions.iter().map(|&p| p.into()).collect();
+    let indices: Vec<u32> = mesh.indices.iter().copied().map(u32::from).collect();
+    let normals = vec![glam::Vec3::Y; positions.len()];
+    // U along +X, V along +Z
+    let uvs: Vec<glam::Vec2> = positions
+        .iter()
+        .map(|p| glam::Vec2::new(p.x, p.z))
+        .collect();
+
+    let tangents = generate_tangents(&positions, &uvs, &normals, &indices);
+    for t in &tangents {
+        assert!(t.truncate().dot(glam::Vec3::X) > 0.999, "tangent {t}");
+        assert!(t.w == 1.0 || t.w == -1.0);
+    }
+
+    // Mirroring V flips the handedness
+    let mirrored: Vec<glam::Vec2> = uvs.iter().map(|uv| glam::Vec2::new(uv.x, -uv.y)).collect();
+    let flipped = generate_tangents(&positions, &mirrored, &normals, &indices);
+    for (a, b) in tangents.iter().zip(&flipped) {
+        assert_eq!(a.w, -b.w);
+    }
+}
@@ -5,35 +5,37 @@
 
 use std::borrow::Cow;
 
-/// Maximum value for stick axis conversion (-128 to 127 -> -1.0 to 1.0)
-const STICK_SCALE: f32 = 127.0;
-
-/// Maximum value for trigger conversion (0-255 -> 0.0 to 1.0)
-const TRIGGER_SCALE: f32 = 255.0;
-
 /// Threshold below which analog values are considered zero
 const ANALOG_DEADZONE: f32 = 0.01;
 
 use nethercore_core::replay::{InputLayout, StructuredInput};
 
-use crate::console::{Button, ZInput};
+use crate::console::{
+    Button, ZInput, quantize_stick, quantize_trigger, stick_value, trigger_value,
+};
 
 /// Input layout for Nethercore ZX console
 ///
 /// Handles encoding/decoding between structured input (symbolic button names,
 /// analog values) and raw ZX input bytes.
 ///
-/// # Input Format (8 bytes)
+/// # Input Format (12 bytes)
 ///
 /// | Offset | Size | Field           | Description                    |
 /// |--------|------|-----------------|--------------------------------|
 /// | 0      | 2    | buttons         | u16 bitmask (little-endian)    |
-/// | 2      | 1    | left_stick_x    | i8 (-128 to 127)               |
-/// | 3      | 1    | left_stick_y    | i8 (-128 to 127)               |
-/// | 4      | 1    | right_stick_x   | i8 (-128 to 127)               |
-/// | 5      | 1    | right_stick_y   | i8 (-128 to 127)               |
+/// | 2      | 1    | left_stick_x    | i8 (-127 to 127)               |
+/// | 3      | 1    | left_stick_y    | i8 (-127 to 127)               |
+/// | 4      | 1    | right_stick_x   | i8 (-127 to 127)               |
+/// | 5      | 1    | right_stick_y   | i8 (-127 to 127)               |
 /// | 6      | 1    | left_trigger    | u8 (0 to 255)                  |
 /// | 7      | 1    | right_trigger   | u8 (0 to 255)                  |
+/// | 8      | 2    | pointer_x       | i16 mouse X (little-endian)    |
+/// | 10     | 2    | pointer_y       | i16 mouse Y (little-endian)    |
+///
+/// Replays recorded before the pointer fields existed have 8-byte inputs and
+/// decode with no mouse. Scripts can't move the mouse, so encoded script
+/// inputs always leave the pointer fields zero.
 ///
 /// # Button Mapping
 ///
@@ -63,8 +65,8 @@ impl ZxInputLayout {
     }
 
     /// Convert a ZInput struct to raw bytes
-    pub fn zinput_to_bytes(input: &ZInput) -> [u8; 8] {
-        let mut bytes = [0u8; 8];
+    pub fn zinput_to_bytes(input: &ZInput) -> [u8; 12] {
+        let mut bytes = [0u8; 12];
         bytes[0] = (input.buttons & 0xFF) as u8;
         bytes[1] = ((input.buttons >> 8) & 0xFF) as u8;
         bytes[2] = input.left_stick_x as u8;
@@ -73,6 +75,8 @@ impl ZxInputLayout {
         bytes[5] = input.right_stick_y as u8;
         bytes[6] = input.left_trigger;
         bytes[7] = input.right_trigger;
+        bytes[8..10].copy_from_slice(&input.pointer_x.to_le_bytes());
+        bytes[10..12].copy_from_slice(&input.pointer_y.to_le_bytes());
         bytes
     }
 
@@ -97,6 +101,10 @@ impl ZxInputLayout {
         if bytes.len() >= 8 {
             input.right_trigger = bytes[7];
         }
+        if bytes.len() >= 12 {
+            input.pointer_x = i16::from_le_bytes([bytes[8], bytes[9]]);
+            input.pointer_y = i16::from_le_bytes([bytes[10], bytes[11]]);
+        }
 
         input
     }
@@ -126,32 +134,32 @@ impl InputLayout for ZxInputLayout {
             }
         }
 
-        let mut bytes = vec![0u8; 8];
+        let mut bytes = vec![0u8; 12];
         bytes[0] = (buttons & 0xFF) as u8;
         bytes[1] = ((buttons >> 8) & 0xFF) as u8;
 
-        // Left stick: convert -1.0..1.0 to -128..127
+        // Left stick: quantize -1.0..1.0 to -127..127
         if let Some([x, _]) = input.lstick {
-            bytes[2] = (x.clamp(-1.0, 1.0) * STICK_SCALE) as i8 as u8;
+            bytes[2] = quantize_stick(x) as u8;
         }
         if let Some([_, y]) = input.lstick {
-            bytes[3] = (y.clamp(-1.0, 1.0) * STICK_SCALE) as i8 as u8;
+            bytes[3] = quantize_stick(y) as u8;
         }
 
-        // Right stick: convert -1.0..1.0 to -128..127
+        // Right stick: quantize -1.0..1.0 to -127..127
         if let Some([x, _]) = input.rstick {
-            bytes[4] = (x.clamp(-1.0, 1.0) * STICK_SCALE) as i8 as u8;
+            bytes[4] = quantize_stick(x) as u8;
         }
         if let Some([_, y]) = input.rstick {
-            bytes[5] = (y.clamp(-1.0, 1.0) * STICK_SCALE) as i8 as u8;
+            bytes[5] = quantize_stick(y) as u8;
         }
 
-        // Triggers: convert 0.0..1.0 to 0..255
+        // Triggers: quantize 0.0..1.0 to 0..255
         if let Some(lt) = input.lt {
-            bytes[6] = (lt.clamp(0.0, 1.0) * TRIGGER_SCALE) as u8;
+            bytes[6] = quantize_trigger(lt);
         }
         if let Some(rt) = input.rt {
-            bytes[7] = (rt.clamp(0.0, 1.0) * TRIGGER_SCALE) as u8;
+            bytes[7] = quantize_trigger(rt);
         }
 
         bytes
@@ -209,8 +217,8 @@ impl InputLayout for ZxInputLayout {
 
         // Left stick: convert -128..127 to -1.0..1.0
         if bytes.len() >= 4 {
-            let lx = bytes[2] as i8 as f32 / STICK_SCALE;
-            let ly = bytes[3] as i8 as f32 / STICK_SCALE;
+            let lx = stick_value(bytes[2] as i8);
+            let ly = stick_value(bytes[3] as i8);
             if lx.abs() > ANALOG_DEADZONE || ly.abs() > ANALOG_DEADZONE {
                 input.lstick = Some([lx, ly]);
             }
@@ -218,8 +226,8 @@ impl InputLayout for ZxInputLayout {
 
         // Right stick: convert -128..127 to -1.0..1.0
         if bytes.len() >= 6 {
-            let rx = bytes[4] as i8 as f32 / STICK_SCALE;
-            let ry = bytes[5] as i8 as f32 / STICK_SCALE;
+            let rx = stick_value(bytes[4] as i8);
+            let ry = stick_value(bytes[5] as i8);
             if rx.abs() > ANALOG_DEADZONE || ry.abs() > ANALOG_DEADZONE {
                 input.rstick = Some([rx, ry]);
             }
@@ -227,17 +235,17 @@ impl InputLayout for ZxInputLayout {
 
         // Triggers: convert 0..255 to 0.0..1.0
         if bytes.len() >= 7 && bytes[6] > 0 {
-            input.lt = Some(bytes[6] as f32 / TRIGGER_SCALE);
+            input.lt = Some(trigger_value(bytes[6]));
         }
         if bytes.len() >= 8 && bytes[7] > 0 {
-            input.rt = Some(bytes[7] as f32 / TRIGGER_SCALE);
+            input.rt = Some(trigger_value(bytes[7]));
         }
 
         input
     }
 
     fn input_size(&self) -> usize {
-        8
+        12
     }
 
     fn console_id(&self) -> u8 {
@@ -321,6 +329,9 @@ mod tests {
         // Check triggers
         assert!((decoded.lt.unwrap() - 0.75).abs() < 0.01);
         assert!((decoded.rt.unwrap() - 0.25).abs() < 0.01);
+
+        // Decoded values are on the analog grid, so they re-encode exactly
+        assert_eq!(layout.encode_input(&decoded), bytes);
     }
 
     #[test]
@@ -333,6 +344,8 @@ mod tests {
             right_stick_y: 0,
             left_trigger: 128,
             right_trigger: 0,
+            pointer_x: 300,
+            pointer_y: -2,
         };
 
         let bytes = ZxInputLayout::zinput_to_bytes(&input);
@@ -342,5 +355,14 @@ mod tests {
         assert_eq!(decoded.left_stick_x, input.left_stick_x);
         assert_eq!(decoded.left_stick_y, input.left_stick_y);
         assert_eq!(decoded.left_trigger, input.left_trigger);
+        assert_eq!(decoded, input);
+    }
+
+    #[test]
+    fn test_short_input_has_no_pointer() {
+        // Replays recorded before the pointer fields were added
+        let decoded = ZxInputLayout::bytes_to_zinput(&[0x10, 0x00, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(decoded.buttons, 0x0010);
+        assert_eq!((decoded.pointer_x, decoded.pointer_y), (0, 0));
     }
 }
@@ -10,6 +10,9 @@ use crate::state::{
     ZXFFIState,
 };
 use nethercore_core::console::{Audio, ConsoleResourceManager};
+use nethercore_core::thumbnail::{THUMBNAIL_HEIGHT, THUMBNAIL_WIDTH};
+use rayon::prelude::*;
+use zx_common::TextureFormat;
 use zx_common::formats::{
     BoneTransform, PLATFORM_BONE_KEYFRAME_SIZE, PlatformBoneKeyframe, decode_bone_transform,
 };
@@ -62,6 +65,52 @@ fn bone_transform_to_matrix(t: &BoneTransform) -> BoneMatrix3x4 {
     }
 }
 
+/// Pick texture data this GPU can sample
+///
+/// BC textures packed with `texture_target = "both"` carry an ETC2 copy,
+/// which is used instead when the GPU lacks BC support.
+fn select_texture_data<'a>(
+    graphics: &ZXGraphics,
+    format: TextureFormat,
+    data: &'a [u8],
+    etc2_data: &'a [u8],
+) -> (TextureFormat, &'a [u8]) {
+    match format.etc2_counterpart() {
+        Some(etc2)
+            if !etc2_data.is_empty()
+                && !graphics.supports_texture_format(format)
+                && graphics.supports_texture_format(etc2) =>
+        {
+            (etc2, etc2_data)
+        }
+        _ => (format, data),
+    }
+}
+
+/// Decode a keyframe collection into bone matrices, frame by frame
+fn decode_keyframe_matrices(kf: &LoadedKeyframeCollection) -> Vec<BoneMatrix3x4> {
+    let bone_count = kf.bone_count as usize;
+    let mut matrices = Vec::with_capacity(kf.frame_count as usize * bone_count);
+
+    for frame_idx in 0..kf.frame_count as usize {
+        let frame_start = frame_idx * bone_count * PLATFORM_BONE_KEYFRAME_SIZE;
+
+        for bone_idx in 0..bone_count {
+            let kf_offset = frame_start + bone_idx * PLATFORM_BONE_KEYFRAME_SIZE;
+            let kf_bytes = &kf.data[kf_offset..kf_offset + PLATFORM_BONE_KEYFRAME_SIZE];
+
+            // Decode platform keyframe to BoneTransform
+            let platform_kf = PlatformBoneKeyframe::from_bytes(kf_bytes);
+            let transform = decode_bone_transform(&platform_kf);
+
+            // Convert to BoneMatrix3x4
+            matrices.push(bone_transform_to_matrix(&transform));
+        }
+    }
+
+    matrices
+}
+
 /// Default environment configuration for the resource manager.
 ///
 /// A simple cyan sky with gray walls and dark floor. This is used as a fallback
@@ -69,7 +118,7 @@ fn bone_transform_to_matrix(t: &BoneTransform) -> BoneMatrix3x4 {
 ///
 /// Format: Layer 0 is a RAMP bounds, layers 1-7 are empty.
 /// For preset examples showing full EPU capabilities, see the epu-showcase example.
-fn default_environment() -> EpuConfig {
+pub(crate) fn default_environment() -> EpuConfig {
     use glam::Vec3;
 
     let mut e = epu_begin();
@@ -127,26 +176,54 @@ impl ConsoleResourceManager for ZResourceManager {
         _audio: &mut dyn Audio,
         state: &mut Self::State,
     ) {
-        // Process pending textures (RGBA8 or BC7)
+        // Process pending textures (RGBA8, BC7, or the ETC2 copy on GPUs without BC)
         for pending in state.pending_textures.drain(..) {
-            let result = graphics.load_texture_with_format(
+            let (format, data) =
+                select_texture_data(graphics, pending.format, &pending.data, &pending.etc2_data);
+            let result =
+                graphics.load_texture_with_format(pending.width, pending.height, data, format);
+            match result {
+                Ok(handle) => {
+                    self.texture_table.insert(pending.handle, handle);
+                    state
+                        .texture_sizes
+                        .insert(pending.handle, (pending.width, pending.height));
+                    tracing::debug!(
+                        "Loaded texture: game_handle={} -> graphics_handle={:?} ({:?})",
+                        pending.handle,
+                        handle,
+                        format,
+                    );
+                }
+                Err(e) => {
+                    tracing::warn!("Failed to load texture {}: {}", pending.handle, e);
+                }
+            }
+        }
+
+        // Process pending texture arrays (layers copied from textures loaded above)
+        for pending in state.pending_texture_arrays.drain(..) {
+            let (format, data) =
+                select_texture_data(graphics, pending.format, &pending.data, &pending.etc2_data);
+            let result = graphics.load_texture_array_with_format(
                 pending.width,
                 pending.height,
-                &pending.data,
-                pending.format,
+                pending.layers,
+                data,
+                format,
             );
             match result {
                 Ok(handle) => {
                     self.texture_table.insert(pending.handle, handle);
                     tracing::debug!(
-                        "Loaded texture: game_handle={} -> graphics_handle={:?} ({:?})",
+                        "Loaded texture array: game_handle={} -> graphics_handle={:?} ({} layers)",
                         pending.handle,
                         handle,
-                        pending.format,
+                        pending.layers,
                     );
                 }
                 Err(e) => {
-                    tracing::warn!("Failed to load texture {}: {}", pending.handle, e);
+                    tracing::warn!("Failed to load texture array {}: {}", pending.handle, e);
                 }
             }
         }
@@ -158,12 +235,19 @@ impl ConsoleResourceManager for ZResourceManager {
         self.texture_table
             .insert(u32::MAX - 1, graphics.font_texture());
 
-        // Process pending unpacked meshes (f32 convenience API)
-        // Convert to packed format before GPU upload for 37.5% memory savings
-        for pending in state.pending_meshes.drain(..) {
-            // Convert f32 vertex data to packed bytes
-            let packed_data = pack_vertex_data(&pending.vertex_data, pending.format);
+        // Keep CPU copies of static meshes for decal projection before the
+        // pending mesh data is uploaded and dropped
+        crate::ffi::collect_decal_surfaces(state);
 
+        // Process pending unpacked meshes (f32 convenience API)
+        // Convert to packed format before GPU upload for 37.5% memory savings.
+        // Packing runs on worker threads; uploads stay on this thread.
+        let pending_meshes = std::mem::take(&mut state.pending_meshes);
+        let packed_meshes: Vec<Vec<u8>> = pending_meshes
+            .par_iter()
+            .map(|pending| pack_vertex_data(&pending.vertex_data, pending.format))
+            .collect();
+        for (pending, packed_data) in pending_meshes.into_iter().zip(packed_meshes) {
             let result = if let Some(ref indices) = pending.index_data {
                 graphics.load_mesh_indexed_packed(&packed_data, indices, pending.format)
             } else {
@@ -249,6 +333,9 @@ impl ConsoleResourceManager for ZResourceManager {
         // Process pending keyframes (move to finalized storage)
         // Keyframes are stored by handle order (handle N is at index N-1)
         let had_keyframes = !state.pending_keyframes.is_empty();
+        // Keyframes sit after the inverse bind matrices, so new skeletons from a
+        // later init_step() move them too
+        let upload_keyframes = had_keyframes || (had_skeletons && !state.keyframes.is_empty());
         for pending in state.pending_keyframes.drain(..) {
             let index = pending.handle as usize - 1;
             while state.keyframes.len() <= index {
@@ -311,52 +398,40 @@ impl ConsoleResourceManager for ZResourceManager {
         // If we had skeletons but no keyframes, animation_static_end = inverse_bind_end
         // IMPORTANT: Must sync to graphics.animation_static_end so frame.rs uploads
         // immediate bones to the correct offset that matches what the shader expects
-        if had_skeletons && !had_keyframes {
+        if had_skeletons && !upload_keyframes {
             state.animation_static_end = state.inverse_bind_end;
             graphics.animation_static_end = graphics.inverse_bind_end;
         }
 
         // Decode and upload all keyframe bone matrices to GPU
-        if had_keyframes {
-            let mut all_keyframes: Vec<BoneMatrix3x4> = Vec::new();
-
+        if upload_keyframes {
             // Ensure gpu_info vec is large enough
             while state.keyframe_gpu_info.len() < state.keyframes.len() {
                 state.keyframe_gpu_info.push(KeyframeGpuInfo::default());
             }
 
+            let mut base_offset = 0u32;
             for (i, kf) in state.keyframes.iter().enumerate() {
                 if kf.frame_count == 0 {
                     continue; // Skip empty placeholders
                 }
 
-                let base_offset = all_keyframes.len() as u32;
                 state.keyframe_gpu_info[i] = KeyframeGpuInfo {
                     keyframe_base_offset: base_offset,
                     bone_count: kf.bone_count,
                     frame_count: kf.frame_count,
                 };
-
-                // Decode all frames for this animation
-                for frame_idx in 0..kf.frame_count as usize {
-                    let frame_start =
-                        frame_idx * kf.bone_count as usize * PLATFORM_BONE_KEYFRAME_SIZE;
-
-                    for bone_idx in 0..kf.bone_count as usize {
-                        let kf_offset = frame_start + bone_idx * PLATFORM_BONE_KEYFRAME_SIZE;
-                        let kf_bytes = &kf.data[kf_offset..kf_offset + PLATFORM_BONE_KEYFRAME_SIZE];
-
-                        // Decode platform keyframe to BoneTransform
-                        let platform_kf = PlatformBoneKeyframe::from_bytes(kf_bytes);
-                        let transform = decode_bone_transform(&platform_kf);
-
-                        // Convert to BoneMatrix3x4
-                        let matrix = bone_transform_to_matrix(&transform);
-                        all_keyframes.push(matrix);
-                    }
-                }
+                base_offset += kf.frame_count as u32 * kf.bone_count as u32;
             }
 
+            // Collections decode independently, so spread them over worker threads
+            let all_keyframes: Vec<BoneMatrix3x4> = state
+                .keyframes
+                .par_iter()
+                .map(decode_keyframe_matrices)
+                .collect::<Vec<_>>()
+                .concat();
+
             if !all_keyframes.is_empty() {
                 graphics.upload_static_keyframes(&all_keyframes);
                 // Sync animation_static_end to state for offset computation
@@ -371,19 +446,53 @@ impl ConsoleResourceManager for ZResourceManager {
         }
 
         // Invalidate frame bind group cache if buffers may have changed
-        if had_skeletons || had_keyframes {
+        if had_skeletons || upload_keyframes {
             graphics.invalidate_frame_bind_group();
         }
 
+        // Upload baked lighting (static, never changes after init)
+        graphics.upload_static_lightmaps(&state.lightmaps.data);
+
+        // Upload palettes for indexed textures (static, never change after init)
+        graphics.upload_static_palettes(&state.palettes);
+
+        // Upload morph target displacements (static, never change after init)
+        graphics.upload_static_morphs(&state.morphs.data);
+
         // Apply init config to graphics (render mode from game's init() phase)
         // Resolution is fixed at 540p
         graphics.set_render_mode(state.init_config.render_mode);
+        graphics.set_palette(&state.init_config.palette, state.init_config.palette_dither);
         graphics.update_resolution();
     }
 
     fn execute_draw_commands(&mut self, graphics: &mut Self::Graphics, state: &mut Self::State) {
+        // Toast notifications draw over everything the game recorded
+        crate::ffi::draw_toasts(state);
+
+        // Save slot thumbnails captured since they were last drawn
+        for pending in state.pending_thumbnails.drain(..) {
+            let existing = self.texture_table.resolve(pending.handle);
+            match graphics.write_host_texture(
+                existing,
+                THUMBNAIL_WIDTH,
+                THUMBNAIL_HEIGHT,
+                &pending.pixels,
+            ) {
+                Ok(handle) => self.texture_table.insert(pending.handle, handle),
+                Err(e) => tracing::warn!("Failed to upload thumbnail {}: {}", pending.handle, e),
+            }
+        }
+
         // Process draw commands - ZXGraphics consumes draw commands directly
         graphics.process_draw_commands(state, &self.texture_table);
+
+        // Apply the game's scale mode and report the visible area back for safe_area()
+        graphics.set_game_scale_mode(state.display_scale_mode);
+        state.safe_area = graphics.visible_canvas_area();
+
+        // Report the previous frame's statistics for render_stats()
+        state.render_stats = graphics.render_stats();
     }
 
     fn render_game_to_target(
@@ -435,6 +544,14 @@ impl ConsoleResourceManager for ZResourceManager {
             graphics.build_epu_environments(encoder, &config_refs);
         }
 
+        // Skies drawn with sky_ambient() light their environments instead of the EPU
+        let sky_ambients: Vec<_> = state
+            .sky_frame_ambient
+            .iter()
+            .map(|(&env_id, &sh9)| (env_id, sh9))
+            .collect();
+        graphics.apply_sky_ambient(encoder, &sky_ambients);
+
         // =====================================================================
         // Main Render Pass
         // =====================================================================
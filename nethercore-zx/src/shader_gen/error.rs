@@ -3,7 +3,7 @@ use std::fmt;
 /// Error type for shader generation failures
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ShaderGenError {
-    /// Invalid render mode (must be 0-3)
+    /// Invalid render mode (must be 0-4)
     InvalidRenderMode(u8),
     /// Render mode requires NORMAL flag but format doesn't have it
     MissingNormalFlag { mode: u8, format: u8 },
@@ -13,7 +13,7 @@ impl fmt::Display for ShaderGenError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             ShaderGenError::InvalidRenderMode(mode) => {
-                write!(f, "Invalid render mode: {} (must be 0-3)", mode)
+                write!(f, "Invalid render mode: {} (must be 0-4)", mode)
             }
             ShaderGenError::MissingNormalFlag { mode, format } => {
                 write!(
@@ -7,6 +7,7 @@ pub fn mode_name(mode: u8) -> &'static str {
         1 => "Matcap",
         2 => "MR-Blinn-Phong",
         3 => "Blinn-Phong",
+        4 => "Toon",
         _ => "Unknown",
     }
 }
@@ -17,8 +18,8 @@ pub fn shader_count_for_mode(mode: u8) -> usize {
     match mode {
         // Mode 0: 16 (no tangent) + 8 (tangent+normal) = 24
         0 => 24,
-        // Modes 1-3: 8 (normal, no tangent) + 8 (normal+tangent) = 16
-        1..=3 => 16,
+        // Modes 1-4: 8 (normal, no tangent) + 8 (normal+tangent) = 16
+        1..=4 => 16,
         _ => 0,
     }
 }
@@ -36,9 +37,9 @@ pub fn valid_formats_for_mode(mode: u8) -> Vec<u8> {
         // Mode 0: all combinations except invalid tangent-without-normal
         // Valid: 0-15 (no tangent), 20-23 (tangent+normal), 28-31 (tangent+normal+skinned)
         0 => (0..32).filter(tangent_valid).collect(),
-        // Modes 1-3: require NORMAL, plus tangent validation
+        // Modes 1-4: require NORMAL, plus tangent validation
         // Valid: 4-7, 12-15, 20-23, 28-31
-        1..=3 => (0..32)
+        1..=4 => (0..32)
             .filter(|&f| f & FORMAT_NORMAL != 0)
             .filter(tangent_valid)
             .collect(),
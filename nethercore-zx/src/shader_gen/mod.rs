@@ -1,14 +1,14 @@
 //! Shader generation system for Nethercore ZX
 //!
-//! All 72 shader permutations are pregenerated at build time by `build.rs` and validated
+//! All 88 shader permutations are pregenerated at build time by `build.rs` and validated
 //! with naga. This module provides access to the pregenerated shaders.
 //!
-//! - Render mode (0-3): Lambert, Matcap, MR-Blinn-Phong, Blinn-Phong
+//! - Render mode (0-4): Lambert, Matcap, MR-Blinn-Phong, Blinn-Phong, Toon
 //! - Vertex format flags (UV, COLOR, NORMAL, SKINNED, TANGENT)
 //!
-//! Total shader count: 72
+//! Total shader count: 88
 //! - Mode 0: 24 shaders (all vertex formats, tangent requires normal)
-//! - Modes 1-3: 16 shaders each (formats with NORMAL, optionally TANGENT)
+//! - Modes 1-4: 16 shaders each (formats with NORMAL, optionally TANGENT)
 //!
 //! TANGENT flag (bit 4) is only valid when NORMAL flag (bit 2) is also set,
 //! as tangent-space normal mapping requires vertex normals for TBN construction.
@@ -43,16 +43,16 @@ use crate::graphics::FORMAT_NORMAL;
 ///
 /// # Errors
 ///
-/// Returns `ShaderGenError::InvalidRenderMode` if mode is not 0-3.
-/// Returns `ShaderGenError::MissingNormalFlag` if modes 1-3 are used without NORMAL flag.
+/// Returns `ShaderGenError::InvalidRenderMode` if mode is not 0-4.
+/// Returns `ShaderGenError::MissingNormalFlag` if modes 1-4 are used without NORMAL flag.
 pub fn generate_shader(mode: u8, format: u8) -> Result<&'static str, ShaderGenError> {
     // Validate mode
-    if mode > 3 {
+    if mode > 4 {
         return Err(ShaderGenError::InvalidRenderMode(mode));
     }
 
     // Check if this format is valid for the mode
-    // Modes 1-3 require normals
+    // Modes 1-4 require normals
     let has_normal = format & FORMAT_NORMAL != 0;
     if mode > 0 && !has_normal {
         return Err(ShaderGenError::MissingNormalFlag { mode, format });
@@ -13,6 +13,10 @@ const TEMPLATE_MODE1: &str = include_str!(concat!(
     env!("CARGO_MANIFEST_DIR"),
     "/shaders/mode1_matcap.wgsl"
 ));
+const TEMPLATE_MODE4: &str = include_str!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/shaders/mode4_toon.wgsl"
+));
 
 /// Get the template for a given render mode (for debugging/inspection)
 ///
@@ -20,13 +24,14 @@ const TEMPLATE_MODE1: &str = include_str!(concat!(
 ///
 /// # Errors
 ///
-/// Returns `ShaderGenError::InvalidRenderMode` if mode is not 0-3.
+/// Returns `ShaderGenError::InvalidRenderMode` if mode is not 0-4.
 pub fn get_template(mode: u8) -> Result<&'static str, ShaderGenError> {
     match mode {
         0 => Ok(TEMPLATE_MODE0),
         1 => Ok(TEMPLATE_MODE1),
         2 => Ok(BLINNPHONG_COMMON), // Generated from common files
         3 => Ok(BLINNPHONG_COMMON), // Generated from common files
+        4 => Ok(TEMPLATE_MODE4),
         _ => Err(ShaderGenError::InvalidRenderMode(mode)),
     }
 }
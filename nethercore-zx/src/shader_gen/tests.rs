@@ -1,5 +1,5 @@
 use super::*;
-use crate::graphics::{FORMAT_COLOR, FORMAT_UV};
+use crate::graphics::{FORMAT_COLOR, FORMAT_NORMAL, FORMAT_UV};
 
 #[test]
 fn test_shader_generation_mode0() {
@@ -36,9 +36,9 @@ fn test_mode1_without_normals_returns_error() {
 #[test]
 fn test_invalid_render_mode_returns_error() {
     // Invalid render modes should return an error
-    let result = generate_shader(4, 0);
+    let result = generate_shader(5, 0);
     assert!(result.is_err());
-    assert_eq!(result.unwrap_err(), ShaderGenError::InvalidRenderMode(4));
+    assert_eq!(result.unwrap_err(), ShaderGenError::InvalidRenderMode(5));
 
     let result = generate_shader(255, 0);
     assert!(result.is_err());
@@ -48,10 +48,10 @@ fn test_invalid_render_mode_returns_error() {
 #[test]
 fn test_get_template_returns_error_for_invalid_mode() {
     assert!(get_template(0).is_ok());
-    assert!(get_template(3).is_ok());
+    assert!(get_template(4).is_ok());
     assert_eq!(
-        get_template(4).unwrap_err(),
-        ShaderGenError::InvalidRenderMode(4)
+        get_template(5).unwrap_err(),
+        ShaderGenError::InvalidRenderMode(5)
     );
     assert_eq!(
         get_template(100).unwrap_err(),
@@ -69,18 +69,46 @@ fn test_placeholder_replacement() {
     assert!(!shader.contains("//VIN_COLOR"));
 }
 
+#[test]
+fn test_retro_mode_support() {
+    // Every shader snaps vertices; textured shaders carry the affine UV copy
+    for mode in 0u8..=4 {
+        for format in valid_formats_for_mode(mode) {
+            let shader = generate_shader(mode, format).unwrap();
+            assert!(shader.contains("snap_vertex(clip_position"));
+            let has_uv = format & FORMAT_UV != 0;
+            assert_eq!(shader.contains("@interpolate(linear) uv_affine"), has_uv);
+            assert_eq!(shader.contains("FLAG_AFFINE_TEXTURES));"), has_uv);
+        }
+    }
+}
+
+#[test]
+fn test_baked_lighting_support() {
+    // Every shader passes baked light through; lit modes except matcap add it
+    for mode in 0u8..=4 {
+        for format in valid_formats_for_mode(mode) {
+            let shader = generate_shader(mode, format).unwrap();
+            assert!(shader.contains("out.baked_light = baked_light("));
+            let lit = mode != 1 && (mode != 0 || format & FORMAT_NORMAL != 0);
+            assert_eq!(shader.contains("* in.baked_light"), lit);
+        }
+    }
+}
+
 #[test]
 fn test_shader_counts() {
     assert_eq!(shader_count_for_mode(0), 24);
     assert_eq!(shader_count_for_mode(1), 16);
     assert_eq!(shader_count_for_mode(2), 16);
     assert_eq!(shader_count_for_mode(3), 16);
+    assert_eq!(shader_count_for_mode(4), 16);
 }
 
 #[test]
 fn test_total_shader_count() {
-    let total: usize = (0..4).map(shader_count_for_mode).sum();
-    assert_eq!(total, 72); // 24 + 16 + 16 + 16 = 72
+    let total: usize = (0..5).map(shader_count_for_mode).sum();
+    assert_eq!(total, 88); // 24 + 16 + 16 + 16 + 16 = 88
 }
 
 // =============================================================================
@@ -121,11 +149,11 @@ fn compile_and_validate_shader(mode: u8, format: u8) -> Result<(), String> {
 }
 
 #[test]
-fn test_compile_all_72_shaders() {
+fn test_compile_all_88_shaders() {
     let mut errors = Vec::new();
 
     // All modes: iterate through valid formats
-    for mode in 0u8..=3 {
+    for mode in 0u8..=4 {
         for format in valid_formats_for_mode(mode) {
             if let Err(e) = compile_and_validate_shader(mode, format) {
                 errors.push(e);
@@ -142,6 +170,27 @@ fn test_compile_all_72_shaders() {
     }
 }
 
+#[test]
+fn test_compile_environment_shader() {
+    let module = naga::front::wgsl::parse_str(ENVIRONMENT_SHADER)
+        .unwrap_or_else(|e| panic!("WGSL parse error for environment shader: {:?}", e));
+    naga::valid::Validator::new(
+        naga::valid::ValidationFlags::all(),
+        naga::valid::Capabilities::all(),
+    )
+    .validate(&module)
+    .unwrap_or_else(|e| panic!("Validation error for environment shader: {:?}", e));
+
+    // Evaluating and cached-background entry points
+    for name in ["vs", "fs", "fs_cached"] {
+        assert!(
+            module.entry_points.iter().any(|ep| ep.name == name),
+            "environment shader is missing entry point {}",
+            name
+        );
+    }
+}
+
 #[test]
 fn test_compile_mode0_all_formats() {
     for format in valid_formats_for_mode(0) {
@@ -170,6 +219,40 @@ fn test_compile_mode3_blinnphong() {
     }
 }
 
+#[test]
+fn test_compile_mode4_toon() {
+    for format in valid_formats_for_mode(4) {
+        compile_and_validate_shader(4, format).unwrap_or_else(|e| panic!("{}", e));
+    }
+}
+
+#[test]
+fn test_outline_extrusion_only_in_toon_mode() {
+    for mode in 0u8..=4 {
+        for format in valid_formats_for_mode(mode) {
+            let shader = generate_shader(mode, format).unwrap();
+            assert_eq!(
+                shader.contains("model_pos.xyz + out.world_normal * outline_width"),
+                mode == 4,
+                "Mode {} format {}",
+                mode,
+                format
+            );
+        }
+    }
+}
+
+#[test]
+fn test_mode4_without_normals_returns_error() {
+    assert_eq!(
+        generate_shader(4, FORMAT_UV).unwrap_err(),
+        ShaderGenError::MissingNormalFlag {
+            mode: 4,
+            format: FORMAT_UV
+        }
+    );
+}
+
 #[test]
 fn test_compile_skinned_variants() {
     // Test all skinned formats: 8-15 (no tangent) and 28-31 (with tangent+normal)
@@ -180,8 +263,8 @@ fn test_compile_skinned_variants() {
         }
     }
 
-    // Modes 1-3: skinned formats with NORMAL (12-15, 28-31)
-    for mode in 1u8..=3 {
+    // Modes 1-4: skinned formats with NORMAL (12-15, 28-31)
+    for mode in 1u8..=4 {
         for format in valid_formats_for_mode(mode) {
             if format >= 8 {
                 compile_and_validate_shader(mode, format).unwrap_or_else(|e| panic!("{}", e));
@@ -192,7 +275,7 @@ fn test_compile_skinned_variants() {
 
 #[test]
 fn test_shader_has_vertex_entry() {
-    for mode in 0u8..=3 {
+    for mode in 0u8..=4 {
         for format in valid_formats_for_mode(mode) {
             let shader = generate_shader(mode, format).unwrap();
             assert!(
@@ -207,7 +290,7 @@ fn test_shader_has_vertex_entry() {
 
 #[test]
 fn test_shader_has_fragment_entry() {
-    for mode in 0u8..=3 {
+    for mode in 0u8..=4 {
         for format in valid_formats_for_mode(mode) {
             let shader = generate_shader(mode, format).unwrap();
             assert!(
@@ -220,9 +303,24 @@ fn test_shader_has_fragment_entry() {
     }
 }
 
+#[test]
+fn test_shaders_apply_morph_targets() {
+    for mode in 0u8..=4 {
+        for format in valid_formats_for_mode(mode) {
+            let shader = generate_shader(mode, format).unwrap();
+            assert!(
+                shader.contains("morph_offset(") && shader.contains("in.position + morph.position"),
+                "Mode {} format {} does not apply morph targets",
+                mode,
+                format
+            );
+        }
+    }
+}
+
 #[test]
 fn test_no_unreplaced_placeholders() {
-    for mode in 0u8..=3 {
+    for mode in 0u8..=4 {
         for format in valid_formats_for_mode(mode) {
             let shader = generate_shader(mode, format).unwrap();
             let placeholders = [
@@ -246,12 +344,15 @@ fn test_no_unreplaced_placeholders() {
                 "//VS_SKINNED",
                 "//VS_TANGENT",
                 "//VS_VIEW_TANGENT",
+                "//VS_OUTLINE",
                 "//VS_POSITION",
                 "//FS_COLOR",
+                "//FS_TEXCOORD",
                 "//FS_UV",
                 "//FS_AMBIENT",
                 "//FS_NORMAL",
                 "//FS_MRE",
+                "//FS_SHADING_NORMAL",
                 "//FS_MODE2_3_DIFFUSE_FACTOR",
                 "//FS_MODE2_3_SHININESS",
                 "//FS_MODE2_3_SPECULAR_COLOR",
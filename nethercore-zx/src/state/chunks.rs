@@ -0,0 +1,305 @@
+//! Chunked world streaming
+//!
+//! A game registers its world as a grid of chunks, each backed by a ROM data
+//! asset, and hands the host a pool of fixed-size slots in its own memory.
+//! Every `chunk_update()` the host copies chunks near the focus point into free
+//! slots and frees slots whose chunks have moved out of range. Chunks load
+//! inside `load_radius` and unload past `unload_radius`, so walking back and
+//! forth across a chunk border doesn't reload it every tick.
+//!
+//! The grid is configured during init() and lives in FFI state. Which chunk
+//! occupies which slot is POD and lives in ZRollbackState; the slot contents
+//! are WASM memory, so both roll back together.
+
+use bytemuck::{Pod, Zeroable};
+
+/// Maximum number of slots in the chunk pool
+pub const MAX_CHUNK_SLOTS: usize = 64;
+
+/// Maximum number of registered chunks
+pub const MAX_CHUNKS: usize = 65536;
+
+/// A registered chunk
+#[derive(Clone, Debug)]
+pub struct ChunkDef {
+    /// Grid cell
+    pub x: i32,
+    pub z: i32,
+    /// ROM data asset holding the chunk's bytes
+    pub data_id: String,
+}
+
+/// Chunk grid and slot pool, configured during init()
+#[derive(Clone, Debug)]
+pub struct ChunkGrid {
+    /// World units per grid cell (cell (x, z) spans `x * cell_size ..`)
+    pub cell_size: f32,
+    /// Chunks whose center is within this distance of the focus are loaded
+    pub load_radius: f32,
+    /// Loaded chunks whose center is further than this are unloaded
+    pub unload_radius: f32,
+    /// Start of the slot pool in WASM memory
+    pub pool_ptr: u32,
+    /// Bytes per slot (largest chunk the pool can hold)
+    pub slot_size: u32,
+    /// Number of usable slots (0 = no pool yet)
+    pub slot_count: u32,
+    /// Registered chunks (handle N at index N-1)
+    pub chunks: Vec<ChunkDef>,
+}
+
+impl Default for ChunkGrid {
+    fn default() -> Self {
+        Self {
+            cell_size: 64.0,
+            load_radius: 96.0,
+            unload_radius: 128.0,
+            pool_ptr: 0,
+            slot_size: 0,
+            slot_count: 0,
+            chunks: Vec::new(),
+        }
+    }
+}
+
+impl ChunkGrid {
+    /// Handle of the chunk registered at cell (x, z)
+    pub fn find(&self, x: i32, z: i32) -> Option<u32> {
+        self.chunks
+            .iter()
+            .position(|chunk| chunk.x == x && chunk.z == z)
+            .map(|index| index as u32 + 1)
+    }
+
+    /// Horizontal distance from `focus` to the center of chunk `handle`
+    pub fn distance(&self, handle: u32, focus: [f32; 2]) -> f32 {
+        let chunk = &self.chunks[handle as usize - 1];
+        let cx = (chunk.x as f32 + 0.5) * self.cell_size;
+        let cz = (chunk.z as f32 + 0.5) * self.cell_size;
+        (cx - focus[0]).hypot(cz - focus[1])
+    }
+
+    /// Address of `slot` in WASM memory
+    pub fn slot_ptr(&self, slot: u32) -> u32 {
+        self.pool_ptr + slot * self.slot_size
+    }
+}
+
+/// A chunk entering or leaving a slot
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ChunkEvent {
+    /// Chunk handle
+    pub handle: u32,
+    /// Slot index in the pool
+    pub slot: u32,
+    /// Grid cell
+    pub x: i32,
+    pub z: i32,
+    /// True when the chunk was loaded, false when it was unloaded
+    pub loaded: bool,
+}
+
+/// Which chunk occupies each pool slot (256 bytes, POD)
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct ChunkResidency {
+    /// Chunk handle per slot (0 = free)
+    pub slots: [u32; MAX_CHUNK_SLOTS],
+}
+
+impl Default for ChunkResidency {
+    fn default() -> Self {
+        Self::zeroed()
+    }
+}
+
+impl ChunkResidency {
+    /// Slot holding chunk `handle`, if it's loaded
+    pub fn slot_of(&self, handle: u32) -> Option<u32> {
+        if handle == 0 {
+            return None;
+        }
+        self.slots
+            .iter()
+            .position(|&h| h == handle)
+            .map(|slot| slot as u32)
+    }
+
+    /// Number of loaded chunks
+    pub fn loaded_count(&self) -> u32 {
+        self.slots.iter().filter(|&&h| h != 0).count() as u32
+    }
+
+    /// Stream chunks around `focus` (world x, z)
+    ///
+    /// Unloads chunks past `unload_radius`, then loads unloaded chunks within
+    /// `load_radius`, nearest first. When the pool is full, a chunk inside
+    /// `load_radius` evicts the furthest loaded chunk that is outside it.
+    /// Ties are broken by handle, so the result is deterministic.
+    ///
+    /// Returns the changes in order; unloads come before the loads that reuse
+    /// their slots. The caller copies chunk data for each load.
+    pub fn update(&mut self, grid: &ChunkGrid, focus: [f32; 2]) -> Vec<ChunkEvent> {
+        let slot_count = (grid.slot_count as usize).min(MAX_CHUNK_SLOTS);
+        let mut events = Vec::new();
+
+        for slot in 0..slot_count {
+            let handle = self.slots[slot];
+            if handle != 0 && grid.distance(handle, focus) > grid.unload_radius {
+                self.evict(grid, slot, &mut events);
+            }
+        }
+
+        let mut wanted: Vec<(f32, u32)> = (1..=grid.chunks.len() as u32)
+            .map(|handle| (grid.distance(handle, focus), handle))
+            .filter(|&(distance, handle)| {
+                distance <= grid.load_radius && self.slot_of(handle).is_none()
+            })
+            .collect();
+        wanted.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.cmp(&b.1)));
+
+        for (_, handle) in wanted {
+            let slot = match self.slots[..slot_count].iter().position(|&h| h == 0) {
+                Some(slot) => slot,
+                None => {
+                    // Only chunks in the hysteresis band can make room
+                    let victim = (0..slot_count)
+                        .map(|slot| (grid.distance(self.slots[slot], focus), slot))
+                        .filter(|&(distance, _)| distance > grid.load_radius)
+                        .max_by(|a, b| a.0.total_cmp(&b.0).then(b.1.cmp(&a.1)));
+                    let Some((_, slot)) = victim else {
+                        break;
+                    };
+                    self.evict(grid, slot, &mut events);
+                    slot
+                }
+            };
+
+            let chunk = &grid.chunks[handle as usize - 1];
+            self.slots[slot] = handle;
+            events.push(ChunkEvent {
+                handle,
+                slot: slot as u32,
+                x: chunk.x,
+                z: chunk.z,
+                loaded: true,
+            });
+        }
+
+        events
+    }
+
+    fn evict(&mut self, grid: &ChunkGrid, slot: usize, events: &mut Vec<ChunkEvent>) {
+        let handle = std::mem::take(&mut self.slots[slot]);
+        let chunk = &grid.chunks[handle as usize - 1];
+        events.push(ChunkEvent {
+            handle,
+            slot: slot as u32,
+            x: chunk.x,
+            z: chunk.z,
+            loaded: false,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A row of chunks along +x, 10 units wide
+    fn row(count: i32, slots: u32) -> ChunkGrid {
+        ChunkGrid {
+            cell_size: 10.0,
+            load_radius: 12.0,
+            unload_radius: 20.0,
+            pool_ptr: 1000,
+            slot_size: 100,
+            slot_count: slots,
+            chunks: (0..count)
+                .map(|x| ChunkDef {
+                    x,
+                    z: 0,
+                    data_id: format!("chunk_{}", x),
+                })
+                .collect(),
+        }
+    }
+
+    fn loaded(events: &[ChunkEvent]) -> Vec<u32> {
+        events
+            .iter()
+            .filter(|e| e.loaded)
+            .map(|e| e.handle)
+            .collect()
+    }
+
+    fn unloaded(events: &[ChunkEvent]) -> Vec<u32> {
+        events
+            .iter()
+            .filter(|e| !e.loaded)
+            .map(|e| e.handle)
+            .collect()
+    }
+
+    #[test]
+    fn test_chunk_residency_size() {
+        assert_eq!(std::mem::size_of::<ChunkResidency>(), MAX_CHUNK_SLOTS * 4);
+    }
+
+    #[test]
+    fn test_loads_nearest_chunks_first() {
+        let grid = row(8, 4);
+        let mut residency = ChunkResidency::default();
+
+        // Focus at the center of chunk 3 (handle 4): neighbours are 10 away
+        let events = residency.update(&grid, [35.0, 5.0]);
+        assert_eq!(loaded(&events), vec![4, 3, 5]);
+        assert_eq!(residency.slot_of(4), Some(0));
+        assert_eq!(residency.loaded_count(), 3);
+        assert_eq!(grid.slot_ptr(2), 1200);
+
+        // Nothing changes while the focus stays put
+        assert!(residency.update(&grid, [35.0, 5.0]).is_empty());
+    }
+
+    #[test]
+    fn test_hysteresis_keeps_chunks_until_unload_radius() {
+        let grid = row(8, 8);
+        let mut residency = ChunkResidency::default();
+        residency.update(&grid, [35.0, 5.0]);
+
+        // Chunk 2 (handle 3) is now 15 away: outside load, inside unload
+        let events = residency.update(&grid, [40.0, 5.0]);
+        assert!(unloaded(&events).is_empty());
+        assert!(residency.slot_of(3).is_some());
+
+        // 25 away: past the unload radius
+        let events = residency.update(&grid, [50.0, 5.0]);
+        assert_eq!(unloaded(&events), vec![3]);
+        assert_eq!(residency.slot_of(3), None);
+    }
+
+    #[test]
+    fn test_full_pool_evicts_chunks_in_hysteresis_band() {
+        let grid = row(8, 3);
+        let mut residency = ChunkResidency::default();
+        residency.update(&grid, [35.0, 5.0]);
+
+        // Chunk 5 (handle 6) comes within 11; chunk 2 (handle 3) is 19 away,
+        // inside the unload radius but the only slot that can make room
+        let events = residency.update(&grid, [44.0, 5.0]);
+        assert_eq!(unloaded(&events), vec![3]);
+        assert_eq!(loaded(&events), vec![6]);
+        assert_eq!(residency.slot_of(6), Some(1));
+    }
+
+    #[test]
+    fn test_full_pool_never_evicts_chunks_in_load_radius() {
+        let grid = row(8, 1);
+        let mut residency = ChunkResidency::default();
+
+        let events = residency.update(&grid, [35.0, 5.0]);
+        assert_eq!(loaded(&events), vec![4]);
+        assert!(residency.update(&grid, [36.0, 5.0]).is_empty());
+    }
+}
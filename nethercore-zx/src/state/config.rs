@@ -1,5 +1,8 @@
 //! Init-time configuration for Nethercore ZX
 
+/// Maximum number of colors in a palette_mode() palette
+pub const MAX_PALETTE_COLORS: usize = 256;
+
 /// Init-time configuration for Nethercore ZX
 ///
 /// All config functions are **init-only** and **single-call** — calling the same
@@ -10,8 +13,16 @@ pub struct ZXInitConfig {
     pub tick_rate_index: u32,
     /// Clear/background color (RGBA: 0xRRGGBBAA)
     pub clear_color: u32,
-    /// Render mode (0-3: Lambert, Matcap, PBR, Hybrid)
+    /// Render mode (0-4: Lambert, Matcap, PBR, Hybrid, Toon)
     pub render_mode: u8,
+    /// Vertex snap precision (0 = off, N = grid 2^N cells tall)
+    pub vertex_snap_bits: u8,
+    /// Whether textures use affine (non perspective-correct) mapping
+    pub affine_textures: bool,
+    /// Output palette colors (0xRRGGBBAA, empty = no palette constraint)
+    pub palette: Vec<u32>,
+    /// Whether palette quantization uses ordered dithering
+    pub palette_dither: bool,
     /// Whether any config was changed during init
     pub modified: bool,
 
@@ -20,6 +31,10 @@ pub struct ZXInitConfig {
     pub tick_rate_set: bool,
     /// Whether set_clear_color() has been called
     pub clear_color_set: bool,
+    /// Whether retro_mode() has been called
+    pub retro_mode_set: bool,
+    /// Whether palette_mode() has been called
+    pub palette_set: bool,
 }
 
 impl Default for ZXInitConfig {
@@ -28,10 +43,16 @@ impl Default for ZXInitConfig {
             tick_rate_index: 2,      // Default 60 fps
             clear_color: 0x000000FF, // Black, fully opaque
             render_mode: 0,          // Lambert
+            vertex_snap_bits: 0,     // No snapping
+            affine_textures: false,  // Perspective-correct
+            palette: Vec::new(),     // Full color
+            palette_dither: false,
             modified: false,
             // No config functions called yet
             tick_rate_set: false,
             clear_color_set: false,
+            retro_mode_set: false,
+            palette_set: false,
         }
     }
 }
@@ -0,0 +1,276 @@
+//! Shared pointer cursors
+//!
+//! One cursor per player, moved by the left stick and D-pad with an
+//! acceleration ramp, or by the mouse for player 0. Games read positions with
+//! `cursor_pos()` for menus and RTS-style pointing, and the host draws the
+//! cursors over the game while a style is shown.
+//!
+//! Cursors move only from synchronized inputs and live in ZRollbackState, so
+//! every peer sees the same positions and re-simulated ticks move them the
+//! same way.
+
+use bytemuck::{Pod, Zeroable};
+use nethercore_core::MAX_PLAYERS;
+
+use crate::console::{Button, RESOLUTION, ZInput, stick_value};
+
+/// Cursor styles drawn by the host
+pub mod cursor_style {
+    /// Not drawn (positions still update)
+    pub const HIDDEN: u32 = 0;
+    /// Arrow pointer
+    pub const ARROW: u32 = 1;
+    /// Pointing hand
+    pub const HAND: u32 = 2;
+    /// Crosshair
+    pub const CROSSHAIR: u32 = 3;
+}
+
+/// Default stick speed at full tilt, in pixels per second
+pub const DEFAULT_CURSOR_SPEED: f32 = 600.0;
+
+/// Default speed multiplier gained per second of holding the stick
+pub const DEFAULT_CURSOR_ACCELERATION: f32 = 2.0;
+
+/// Highest speed multiplier acceleration can reach
+pub const MAX_CURSOR_BOOST: f32 = 3.0;
+
+/// Mouse position stored while the mouse is outside the game
+const NO_POINTER: [i16; 2] = [i16::MIN, i16::MIN];
+
+/// One player's cursor (16 bytes)
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct PlayerCursor {
+    /// Position in screen pixels
+    pub position: [f32; 2],
+    /// Speed multiplier from holding the stick (1 up to [`MAX_CURSOR_BOOST`])
+    pub boost: f32,
+    /// Mouse position last tick, to tell when the mouse moves
+    pub pointer: [i16; 2],
+}
+
+impl Default for PlayerCursor {
+    fn default() -> Self {
+        Self {
+            position: [RESOLUTION.0 as f32 * 0.5, RESOLUTION.1 as f32 * 0.5],
+            boost: 1.0,
+            pointer: NO_POINTER,
+        }
+    }
+}
+
+/// Cursors for every player plus the shared settings (144 bytes)
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct CursorState {
+    pub cursors: [PlayerCursor; MAX_PLAYERS],
+    /// `cursor_style` drawn for every player's cursor
+    pub style: u32,
+    /// Stick speed at full tilt, in pixels per second
+    pub speed: f32,
+    /// Speed multiplier gained per second of holding the stick (0 = constant speed)
+    pub acceleration: f32,
+    /// Bit per player whose controller or mouse was connected last tick
+    pub players: u32,
+}
+
+impl Default for CursorState {
+    fn default() -> Self {
+        Self {
+            cursors: [PlayerCursor::default(); MAX_PLAYERS],
+            style: cursor_style::HIDDEN,
+            speed: DEFAULT_CURSOR_SPEED,
+            acceleration: DEFAULT_CURSOR_ACCELERATION,
+            players: 0,
+        }
+    }
+}
+
+impl CursorState {
+    /// Move every cursor by one tick of input
+    ///
+    /// A mouse that moved since last tick places player 0's cursor directly.
+    /// Otherwise the left stick and D-pad move it, with a squared response
+    /// for fine control near the center and speed ramping up while held.
+    pub fn advance(&mut self, inputs: &[ZInput], dt: f32) {
+        let max = [RESOLUTION.0 as f32 - 1.0, RESOLUTION.1 as f32 - 1.0];
+        self.players = 0;
+
+        for (player, (cursor, input)) in self.cursors.iter_mut().zip(inputs).enumerate() {
+            if input.buttons & (ZInput::CONNECTED | ZInput::POINTER) != 0 {
+                self.players |= 1 << player;
+            }
+
+            let pointer = if input.buttons & ZInput::POINTER != 0 {
+                [input.pointer_x, input.pointer_y]
+            } else {
+                NO_POINTER
+            };
+            let mouse_moved = pointer != NO_POINTER && pointer != cursor.pointer;
+            cursor.pointer = pointer;
+            if mouse_moved {
+                cursor.position = [pointer[0] as f32, pointer[1] as f32];
+                cursor.boost = 1.0;
+                continue;
+            }
+
+            let [mut x, mut y] = stick_direction(input);
+            let magnitude = (x * x + y * y).sqrt();
+            if magnitude == 0.0 {
+                cursor.boost = 1.0;
+                continue;
+            }
+            if magnitude > 1.0 {
+                x /= magnitude;
+                y /= magnitude;
+            }
+
+            // Squared response: scale the (at most unit) direction by its length
+            let response = magnitude.min(1.0);
+            let step = self.speed * cursor.boost * response * dt;
+            cursor.position[0] = (cursor.position[0] + x * step).clamp(0.0, max[0]);
+            cursor.position[1] = (cursor.position[1] + y * step).clamp(0.0, max[1]);
+            cursor.boost = (cursor.boost + self.acceleration * dt).min(MAX_CURSOR_BOOST);
+        }
+    }
+
+    /// Whether a player's controller or mouse was connected last tick
+    #[inline]
+    pub fn is_connected(&self, player: usize) -> bool {
+        self.players & (1 << player) != 0
+    }
+}
+
+/// Left stick plus D-pad, in screen directions (+Y down)
+fn stick_direction(input: &ZInput) -> [f32; 2] {
+    let mut x = stick_value(input.left_stick_x);
+    // Stick +Y is up
+    let mut y = -stick_value(input.left_stick_y);
+    if input.button_held(Button::Left) {
+        x -= 1.0;
+    }
+    if input.button_held(Button::Right) {
+        x += 1.0;
+    }
+    if input.button_held(Button::Up) {
+        y -= 1.0;
+    }
+    if input.button_held(Button::Down) {
+        y += 1.0;
+    }
+    [x, y]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DT: f32 = 1.0 / 60.0;
+
+    fn stick(x: i8, y: i8) -> ZInput {
+        ZInput {
+            buttons: ZInput::CONNECTED,
+            left_stick_x: x,
+            left_stick_y: y,
+            ..Default::default()
+        }
+    }
+
+    fn mouse(x: i16, y: i16) -> ZInput {
+        ZInput {
+            buttons: ZInput::CONNECTED | ZInput::POINTER,
+            pointer_x: x,
+            pointer_y: y,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_cursor_state_size() {
+        assert_eq!(std::mem::size_of::<PlayerCursor>(), 16);
+        assert_eq!(std::mem::size_of::<CursorState>(), 16 * MAX_PLAYERS + 16);
+    }
+
+    #[test]
+    fn test_starts_centered() {
+        let state = CursorState::default();
+        assert_eq!(state.cursors[3].position, [480.0, 270.0]);
+        assert_eq!(state.style, cursor_style::HIDDEN);
+    }
+
+    #[test]
+    fn test_stick_moves_and_accelerates() {
+        let mut state = CursorState::default();
+        state.advance(&[stick(127, 127)], DT);
+
+        let [x, y] = state.cursors[0].position;
+        let first_step = x - 480.0;
+        assert!(first_step > 0.0);
+        // Stick up moves the cursor up the screen
+        assert!(y < 270.0);
+        assert!(state.is_connected(0));
+        assert!(!state.is_connected(1));
+
+        for _ in 0..30 {
+            state.advance(&[stick(127, 0)], DT);
+        }
+        let before = state.cursors[0].position[0];
+        state.advance(&[stick(127, 0)], DT);
+        assert!(state.cursors[0].position[0] - before > first_step);
+
+        // Releasing the stick resets the ramp
+        state.advance(&[stick(0, 0)], DT);
+        assert_eq!(state.cursors[0].boost, 1.0);
+    }
+
+    #[test]
+    fn test_half_tilt_is_slower_than_half_speed() {
+        let mut full = CursorState::default();
+        let mut half = CursorState::default();
+        full.advance(&[stick(127, 0)], DT);
+        half.advance(&[stick(64, 0)], DT);
+        let full_step = full.cursors[0].position[0] - 480.0;
+        let half_step = half.cursors[0].position[0] - 480.0;
+        assert!(half_step < full_step * 0.3);
+    }
+
+    #[test]
+    fn test_dpad_moves_at_full_speed() {
+        let mut state = CursorState {
+            acceleration: 0.0,
+            ..Default::default()
+        };
+        let input = ZInput {
+            buttons: ZInput::CONNECTED | Button::Down.mask(),
+            ..Default::default()
+        };
+        state.advance(&[input], 1.0);
+        assert_eq!(state.cursors[0].position, [480.0, 539.0]);
+    }
+
+    #[test]
+    fn test_mouse_places_cursor_until_it_stops() {
+        let mut state = CursorState::default();
+        state.advance(&[mouse(100, 50)], DT);
+        assert_eq!(state.cursors[0].position, [100.0, 50.0]);
+
+        // A still mouse lets the stick take over
+        let mut input = mouse(100, 50);
+        input.left_stick_x = 127;
+        state.advance(&[input], DT);
+        assert!(state.cursors[0].position[0] > 100.0);
+
+        state.advance(&[mouse(200, 60)], DT);
+        assert_eq!(state.cursors[0].position, [200.0, 60.0]);
+    }
+
+    #[test]
+    fn test_clamped_to_screen() {
+        let mut state = CursorState::default();
+        for _ in 0..120 {
+            state.advance(&[stick(-128, -128)], DT);
+        }
+        assert_eq!(state.cursors[0].position, [0.0, 539.0]);
+    }
+}
@@ -0,0 +1,155 @@
+//! Host-side decals
+//!
+//! Decals are presentation-only: they live in FFI state, are never rolled
+//! back, and fade out at the end of their lifetime. Ages are measured in
+//! ticks so decals spawned from `update()` fade in step with the game, and a
+//! spawn repeated on the same tick (rollback re-simulation) is ignored.
+
+use std::collections::VecDeque;
+
+use crate::decal::{DecalBox, DecalVertex};
+
+/// Maximum number of live decals (spawning more replaces the oldest)
+pub const MAX_DECALS: usize = 256;
+
+/// Fraction of a decal's lifetime spent fading out
+pub const DECAL_FADE_FRACTION: f32 = 0.25;
+
+/// A live decal
+#[derive(Clone, Debug)]
+pub struct Decal {
+    /// Texture handle
+    pub texture: u32,
+    pub volume: DecalBox,
+    /// Lifetime in seconds (0 = until replaced or cleared)
+    pub lifetime: f32,
+    /// Tick the decal was spawned on
+    pub spawn_tick: u64,
+    /// Projected world-space triangles (empty until projected onto a receiver)
+    pub geometry: Vec<DecalVertex>,
+}
+
+impl Decal {
+    /// Seconds since the decal was spawned
+    pub fn age(&self, tick: u64, dt: f32) -> f32 {
+        tick.saturating_sub(self.spawn_tick) as f32 * dt
+    }
+
+    /// Opacity from 0.0 to 1.0 at `age` seconds
+    pub fn opacity(&self, age: f32) -> f32 {
+        if self.lifetime <= 0.0 {
+            return 1.0;
+        }
+        ((self.lifetime - age) / (self.lifetime * DECAL_FADE_FRACTION)).clamp(0.0, 1.0)
+    }
+}
+
+/// Live decals, oldest first
+#[derive(Clone, Debug, Default)]
+pub struct DecalPool {
+    decals: VecDeque<Decal>,
+}
+
+impl DecalPool {
+    /// Spawn a decal on `tick`, replacing the oldest if the pool is full
+    ///
+    /// Returns false if an identical decal was already spawned on the same
+    /// tick (update() re-running during rollback).
+    pub fn spawn(&mut self, tick: u64, texture: u32, volume: DecalBox, lifetime: f32) -> bool {
+        if self
+            .decals
+            .iter()
+            .any(|d| d.spawn_tick == tick && d.texture == texture && d.volume == volume)
+        {
+            return false;
+        }
+        if self.decals.len() >= MAX_DECALS {
+            self.decals.pop_front();
+        }
+        self.decals.push_back(Decal {
+            texture,
+            volume,
+            lifetime: lifetime.max(0.0),
+            spawn_tick: tick,
+            geometry: Vec::new(),
+        });
+        true
+    }
+
+    /// Drop decals whose lifetime has run out by `tick`
+    pub fn expire(&mut self, tick: u64, dt: f32) {
+        self.decals
+            .retain(|d| d.lifetime <= 0.0 || d.age(tick, dt) < d.lifetime);
+    }
+
+    /// Remove every decal
+    pub fn clear(&mut self) {
+        self.decals.clear();
+    }
+
+    /// Live decals, oldest first
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut Decal> {
+        self.decals.iter_mut()
+    }
+
+    /// Number of live decals
+    pub fn len(&self) -> usize {
+        self.decals.len()
+    }
+
+    /// True if there are no live decals
+    pub fn is_empty(&self) -> bool {
+        self.decals.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use glam::Vec3;
+
+    fn volume(x: f32) -> DecalBox {
+        DecalBox::new(Vec3::new(x, 0.0, 0.0), Vec3::Y, 1.0, 0.0).unwrap()
+    }
+
+    #[test]
+    fn test_same_tick_spawn_is_ignored() {
+        let mut pool = DecalPool::default();
+        assert!(pool.spawn(10, 1, volume(0.0), 2.0));
+        assert!(!pool.spawn(10, 1, volume(0.0), 2.0));
+        assert!(pool.spawn(10, 1, volume(1.0), 2.0));
+        assert!(pool.spawn(11, 1, volume(0.0), 2.0));
+        assert_eq!(pool.len(), 3);
+    }
+
+    #[test]
+    fn test_full_pool_replaces_oldest() {
+        let mut pool = DecalPool::default();
+        for i in 0..MAX_DECALS + 2 {
+            assert!(pool.spawn(i as u64, 1, volume(0.0), 0.0));
+        }
+        assert_eq!(pool.len(), MAX_DECALS);
+        assert_eq!(pool.iter_mut().next().unwrap().spawn_tick, 2);
+    }
+
+    #[test]
+    fn test_fade_and_expiry() {
+        let dt = 1.0 / 60.0;
+        let mut pool = DecalPool::default();
+        pool.spawn(0, 1, volume(0.0), 4.0);
+        pool.spawn(0, 1, volume(1.0), 0.0);
+
+        let decal = pool.iter_mut().next().unwrap().clone();
+        assert_eq!(decal.opacity(decal.age(60, dt)), 1.0);
+        assert!((decal.opacity(3.5) - 0.5).abs() < 1e-5);
+
+        pool.expire(239, dt);
+        assert_eq!(pool.len(), 2);
+        pool.expire(240, dt);
+        assert_eq!(pool.len(), 1);
+
+        // Permanent decals never fade
+        let permanent = pool.iter_mut().next().unwrap();
+        assert_eq!(permanent.opacity(1000.0), 1.0);
+    }
+}
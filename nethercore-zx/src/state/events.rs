@@ -0,0 +1,92 @@
+//! ZX event kinds for the core `event_poll()` bus
+//!
+//! Projectile hits, hitbox events, body contacts and destructible breaks are
+//! queued on the shared event bus in addition to their own polling functions, so a game can
+//! handle everything from one `event_poll()` loop. Chunk streaming reports
+//! loads and unloads, and the music beat clock reports beats, only through
+//! the bus.
+
+use nethercore_core::event::Event;
+
+use super::{BodyContact, ChunkEvent, HitboxEvent, ProjectileHit, hitbox_event};
+
+/// ZX event kinds (console range, after `nethercore_core::event::event_kind`)
+pub mod zx_event_kind {
+    use nethercore_core::event::event_kind::CONSOLE_BASE;
+
+    /// A projectile hit a target:
+    /// `data` = [projectile, target, user_data, 0, 0], `position` = impact point
+    pub const PROJECTILE_HIT: u32 = CONSOLE_BASE;
+    /// A hitbox connected:
+    /// `data` = [attacker, defender, attacker_box, defender_box, user_data],
+    /// `position` = midpoint between the boxes
+    pub const HITBOX_HIT: u32 = CONSOLE_BASE + 1;
+    /// Two hitboxes clashed (same layout as `HITBOX_HIT`)
+    pub const HITBOX_CLASH: u32 = CONSOLE_BASE + 2;
+    /// A destructible prop broke: `data` = [handle, 0, 0, 0, 0]
+    pub const DESTRUCTIBLE_BREAK: u32 = CONSOLE_BASE + 3;
+    /// A chunk was copied into the pool: `data` = [handle, slot, x, z, 0]
+    /// (x and z are the grid cell as i32 bits)
+    pub const CHUNK_LOADED: u32 = CONSOLE_BASE + 4;
+    /// A chunk's slot was freed (same layout as `CHUNK_LOADED`)
+    pub const CHUNK_UNLOADED: u32 = CONSOLE_BASE + 5;
+    /// The music reached a new beat: `data` = [beat, bar, beat_in_bar, 0, 0]
+    pub const MUSIC_BEAT: u32 = CONSOLE_BASE + 6;
+    /// Two physics bodies touched: `data` = [a, b, 0, 0, 0] (handles, a < b),
+    /// `position` = contact point (x, y, 0)
+    pub const BODY_CONTACT: u32 = CONSOLE_BASE + 7;
+}
+
+impl From<ProjectileHit> for Event {
+    fn from(hit: ProjectileHit) -> Self {
+        Event::at(
+            zx_event_kind::PROJECTILE_HIT,
+            [hit.projectile, hit.target, hit.user_data, 0, 0],
+            hit.position,
+        )
+    }
+}
+
+impl From<HitboxEvent> for Event {
+    fn from(event: HitboxEvent) -> Self {
+        let kind = match event.kind {
+            hitbox_event::CLASH => zx_event_kind::HITBOX_CLASH,
+            _ => zx_event_kind::HITBOX_HIT,
+        };
+        Event::at(
+            kind,
+            [
+                event.attacker,
+                event.defender,
+                event.attacker_box,
+                event.defender_box,
+                event.user_data,
+            ],
+            event.position,
+        )
+    }
+}
+
+impl From<BodyContact> for Event {
+    fn from(contact: BodyContact) -> Self {
+        Event::at(
+            zx_event_kind::BODY_CONTACT,
+            [contact.a, contact.b, 0, 0, 0],
+            [contact.point[0], contact.point[1], 0.0],
+        )
+    }
+}
+
+impl From<ChunkEvent> for Event {
+    fn from(event: ChunkEvent) -> Self {
+        let kind = if event.loaded {
+            zx_event_kind::CHUNK_LOADED
+        } else {
+            zx_event_kind::CHUNK_UNLOADED
+        };
+        Event::new(
+            kind,
+            [event.handle, event.slot, event.x as u32, event.z as u32, 0],
+        )
+    }
+}
@@ -65,6 +65,17 @@ impl ZXFFIState {
         }
     }
 
+    /// Toon band count is stored raw in uniform_set_0 byte 0 (Mode 4, 0 = default)
+    pub fn update_toon_bands(&mut self, bands: u8) {
+        use crate::graphics::update_u32_byte;
+        let current_byte = (self.current_shading_state.uniform_set_0 & 0xFF) as u8;
+        if current_byte != bands {
+            self.current_shading_state.uniform_set_0 =
+                update_u32_byte(self.current_shading_state.uniform_set_0, 0, bands);
+            self.shading_state_dirty = true;
+        }
+    }
+
     /// Update a directional light in current shading state (with quantization)
     pub fn update_light(
         &mut self,
@@ -211,6 +222,82 @@ impl ZXFFIState {
         }
     }
 
+    /// Update retro mode in current shading state
+    ///
+    /// - snap_bits: 0 = off, 1-15 = vertex snap grid precision
+    /// - affine: true = affine (PS1-style) texture mapping
+    pub fn update_retro_mode(&mut self, snap_bits: u8, affine: bool) {
+        use crate::graphics::{
+            FLAG_AFFINE_TEXTURES, FLAG_VERTEX_SNAP_MASK, FLAG_VERTEX_SNAP_SHIFT,
+        };
+
+        let snap = (snap_bits.min(15) as u32) << FLAG_VERTEX_SNAP_SHIFT;
+        let mut new_flags =
+            (self.current_shading_state.flags & !FLAG_VERTEX_SNAP_MASK & !FLAG_AFFINE_TEXTURES)
+                | snap;
+        if affine {
+            new_flags |= FLAG_AFFINE_TEXTURES;
+        }
+
+        if self.current_shading_state.flags != new_flags {
+            self.current_shading_state.flags = new_flags;
+            self.shading_state_dirty = true;
+        }
+    }
+
+    /// Update baked lighting in current shading state
+    ///
+    /// - Some(base): read irradiance at `base + vertex_index` in the lightmap buffer
+    /// - None: no baked lighting
+    pub fn update_lightmap(&mut self, base: Option<u32>) {
+        use crate::graphics::FLAG_LIGHTMAPPED;
+
+        let (flags, lightmap_base) = match base {
+            Some(base) => (self.current_shading_state.flags | FLAG_LIGHTMAPPED, base),
+            None => (self.current_shading_state.flags & !FLAG_LIGHTMAPPED, 0),
+        };
+
+        if self.current_shading_state.flags != flags
+            || self.current_shading_state.lightmap_base != lightmap_base
+        {
+            self.current_shading_state.flags = flags;
+            self.current_shading_state.lightmap_base = lightmap_base;
+            self.shading_state_dirty = true;
+        }
+    }
+
+    /// Update morph targets in current shading state
+    ///
+    /// - Some(morph): read displacements at `base + target * vertex_count +
+    ///   vertex_index` in the morph buffer, scaled by the packed weights
+    /// - None: no morph targets
+    pub fn update_morph(&mut self, morph: Option<(u32, u32, [u32; 4])>) {
+        let (base, vertices, weights) = morph.unwrap_or_default();
+        let shading = &mut self.current_shading_state;
+        if shading.morph_base != base
+            || shading.morph_vertices != vertices
+            || shading.morph_weights != weights
+        {
+            shading.morph_base = base;
+            shading.morph_vertices = vertices;
+            shading.morph_weights = weights;
+            self.shading_state_dirty = true;
+        }
+    }
+
+    /// Update the palette applied to the indexed slot 0 texture (0 = none)
+    pub fn update_palette(&mut self, palette: u32) {
+        use crate::graphics::{FLAG_PALETTE_MASK, FLAG_PALETTE_SHIFT};
+
+        let new_flags = (self.current_shading_state.flags & !FLAG_PALETTE_MASK)
+            | ((palette << FLAG_PALETTE_SHIFT) & FLAG_PALETTE_MASK);
+
+        if self.current_shading_state.flags != new_flags {
+            self.current_shading_state.flags = new_flags;
+            self.shading_state_dirty = true;
+        }
+    }
+
     /// Update uniform alpha level in current shading state (dither transparency)
     /// - 0: fully transparent (all pixels discarded)
     /// - 15: fully opaque (no pixels discarded, default)
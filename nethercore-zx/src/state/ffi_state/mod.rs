@@ -3,17 +3,19 @@
 use std::sync::Arc;
 
 use glam::{Mat4, Vec3};
-use hashbrown::HashMap;
+use hashbrown::{HashMap, HashSet};
 
-use zx_common::ZXDataPack;
+use nethercore_core::thumbnail::MAX_THUMBNAIL_SLOTS;
+use zx_common::{MeshSocket, ZXDataPack};
 
 use super::{
     BoneMatrix3x4, Font, KeyframeGpuInfo, KeyframeSource, LoadedKeyframeCollection,
     PendingKeyframes, PendingMesh, PendingMeshPacked, PendingSkeleton, PendingTexture,
-    SkeletonData, SkeletonGpuInfo, StatePool, ZXInitConfig,
+    PendingTextureArray, PendingThumbnail, SkeletonData, SkeletonGpuInfo, StatePool, ZXInitConfig,
 };
 
-use crate::graphics::epu::EpuConfig;
+use crate::console::RESOLUTION;
+use crate::graphics::epu::{EnvProbe, EpuConfig, EpuSh9, EpuTransition, SkyProbe};
 
 // Re-export submodules
 mod material;
@@ -46,10 +48,39 @@ pub struct ZXFFIState {
     pub cull_mode: crate::graphics::CullMode,
     pub texture_filter: crate::graphics::TextureFilter,
     pub bound_textures: [u32; 4],
+    /// Sprite selected with `atlas_bind()`: (atlas texture handle, UV rect).
+    /// Only applies while that texture is still bound to slot 0.
+    pub bound_atlas_sprite: Option<(u32, [f32; 4])>,
+    /// Layer selected with `texture_array_bind()`: (array texture handle, layer).
+    /// Only applies while that texture is still bound to slot 0.
+    pub bound_array_layer: Option<(u32, u32)>,
     /// Current z-index for 2D draw ordering (higher = closer to camera)
     pub current_z_index: u32,
+    /// Current blend mode for screen-space quads (set by `blend_mode_2d()`)
+    pub current_blend_mode_2d: crate::graphics::BlendMode,
+    /// Current blend mode for 3D draws (set by `blend_mode_3d()`)
+    pub current_blend_mode_3d: crate::graphics::BlendMode,
+    /// View depth added to blended 3D draws when sorting (set by `sort_bias()`)
+    pub current_sort_bias: f32,
     /// Current viewport for split-screen rendering (default: fullscreen)
     pub current_viewport: crate::graphics::Viewport,
+    /// Clip rectangles from `clip_push()` in screen pixels, each already
+    /// intersected with its parent. Screen-space quads are scissored to the last one.
+    pub clip_stack: Vec<crate::graphics::Viewport>,
+    /// Scale mode requested via `display_scale_mode()` (None = player's setting)
+    pub display_scale_mode: Option<nethercore_core::app::config::ScaleMode>,
+    /// Canvas area visible in the window (x, y, width, height), refreshed by
+    /// the host after each rendered frame
+    pub safe_area: [f32; 4],
+
+    /// Statistics for the last rendered frame, refreshed by the host before
+    /// each frame is rendered
+    pub render_stats: crate::graphics::RenderStats,
+
+    /// Loading bar progress from `load_progress()` (current, total)
+    pub load_progress: [u32; 2],
+    /// Loading screen background texture from `load_background()` (0 = none)
+    pub load_background: u32,
 
     // Render pass system (replaces stencil_mode/stencil_group/depth_test)
     /// Current pass ID (increments on each begin_pass_*() call)
@@ -57,6 +88,10 @@ pub struct ZXFFIState {
     /// Pass configurations (indexed by pass_id)
     pub pass_configs: Vec<crate::graphics::PassConfig>,
 
+    /// Point and spot lights from `light_add_point()` / `light_add_spot()`,
+    /// shared by every draw this frame
+    pub scene_lights: Vec<crate::graphics::PackedSceneLight>,
+
     // GPU skinning (3x4 matrices for 25% memory savings)
     pub bone_matrices: Vec<BoneMatrix3x4>,
     pub bone_count: u32,
@@ -101,6 +136,7 @@ pub struct ZXFFIState {
 
     // Pending resource uploads (processed after init())
     pub pending_textures: Vec<PendingTexture>,
+    pub pending_texture_arrays: Vec<PendingTextureArray>,
     pub pending_meshes: Vec<PendingMesh>,
     pub pending_meshes_packed: Vec<PendingMeshPacked>,
     pub pending_skeletons: Vec<PendingSkeleton>,
@@ -110,6 +146,32 @@ pub struct ZXFFIState {
     pub next_mesh_handle: u32,
     pub next_font_handle: u32,
 
+    /// Texture handle per save slot thumbnail, reserved on first draw (0 = none)
+    pub thumbnail_handles: [u32; MAX_THUMBNAIL_SLOTS],
+    /// Thumbnail store revision each slot's texture was last uploaded from
+    pub thumbnail_revisions: [u32; MAX_THUMBNAIL_SLOTS],
+    /// Thumbnails to upload before this frame is drawn
+    pub pending_thumbnails: Vec<PendingThumbnail>,
+
+    /// Atlas sprite UV rects from `rom_atlas()`, keyed by texture handle
+    pub atlases: HashMap<u32, Vec<[f32; 4]>>,
+
+    /// Layer counts of texture arrays from `texture_array_create()`, keyed by texture handle
+    pub texture_arrays: HashMap<u32, u32>,
+
+    /// Texture sizes in pixels, keyed by texture handle (for `draw_nine_slice()`)
+    pub texture_sizes: HashMap<u32, (u32, u32)>,
+
+    /// Cubemaps from `rom_cubemap()`, reduced for sky ambient lighting, keyed by texture handle
+    pub cubemaps: HashMap<u32, SkyProbe>,
+
+    /// Mesh sockets from `rom_mesh()`, keyed by mesh handle (socket N at index N-1)
+    pub mesh_sockets: HashMap<u32, Vec<MeshSocket>>,
+
+    /// Meshes from `mesh_merge()` that needed several parts, keyed by the
+    /// returned handle: (first part handle, part count), parts are consecutive
+    pub mesh_groups: HashMap<u32, (u32, u32)>,
+
     // Font system
     pub fonts: Vec<Font>,
     pub current_font: u32,
@@ -119,6 +181,66 @@ pub struct ZXFFIState {
     pub next_sound_handle: u32,
     /// Sound ID -> handle mapping (for tracker sample resolution)
     pub sound_id_to_handle: HashMap<String, u32>,
+    /// Audio bus names from `bus_create()` (bus N at index N, built-ins first)
+    pub audio_bus_names: Vec<String>,
+    /// Compressed music streams from `rom_stream()` (handle N at index N-1)
+    pub streams: Arc<Vec<crate::audio::Stream>>,
+    /// Stream decoder for synchronous audio mode (the audio thread has its own)
+    pub stream_decoder: crate::audio::StreamDecoder,
+
+    // Particle systems (presentation-only, handles are 1-indexed)
+    pub particle_systems: Vec<super::ParticleSystem>,
+
+    // Toast notifications from `toast()` (presentation-only, drawn by the host)
+    pub toasts: super::ToastQueue,
+
+    // Decals from `decal_spawn()` (presentation-only, drawn by `decals_draw()`)
+    pub decals: super::DecalPool,
+    /// CPU copies of static meshes for decal projection (kept after init)
+    pub decal_surfaces: HashMap<u32, crate::decal::DecalSurface>,
+    /// Meshes with decal surfaces drawn this frame, with their model matrices
+    pub decal_receivers: Vec<(u32, Mat4)>,
+
+    // Gerstner water planes (created during init, animated from the tick count, 1-indexed)
+    pub water_planes: Vec<crate::water::WaterPlane>,
+    /// Scratch vertex buffer reused by `water_draw()`
+    pub water_vertices: Vec<f32>,
+
+    // Navigation meshes (baked during init, handles are 1-indexed)
+    pub navmeshes: Vec<crate::navmesh::NavMesh>,
+
+    // Spline paths from rom_path() (loaded during init, handles are 1-indexed)
+    pub paths: Vec<zx_common::PathCurve>,
+
+    // String tables from rom_strings() (loaded during init, searched in order)
+    pub string_tables: Vec<zx_common::StringTable>,
+    /// Language code set by `locale_set()` (empty = each table's first language)
+    pub locale: String,
+
+    // Baked vertex lighting (baked during init, handles are 1-indexed)
+    pub lightmaps: crate::lightmap::LightmapStore,
+    /// Lightmap applied to subsequent mesh draws (0 = none)
+    pub current_lightmap: u32,
+
+    // Morph targets of ROM meshes (loaded during init) and their current weights
+    pub morphs: crate::morph::MorphStore,
+
+    // Palettes from palette_load() (loaded during init, handles are 1-indexed)
+    /// Colors of every palette, `MAX_PALETTE_COLORS` each (palette N starts at N-1)
+    pub palettes: Vec<u32>,
+    /// Palette applied to subsequent draws of indexed textures (0 = none)
+    pub bound_palette: u32,
+    /// Handles of Indexed8 textures and texture arrays
+    pub indexed_textures: HashSet<u32>,
+
+    // Destructible props (split during init, health in ZRollbackState, 1-indexed)
+    pub destructibles: Vec<crate::destructible::Destructible>,
+
+    // Hitbox moves (registered during init, actors in ZRollbackState, 1-indexed)
+    pub hitbox_moves: Vec<Vec<super::HitboxDesc>>,
+
+    // Streamed world chunks (grid set during init, residency in ZRollbackState)
+    pub chunk_grid: super::ChunkGrid,
 
     // Tracker system (XM module playback, state in ZRollbackState, engine here)
     pub tracker_engine: crate::tracker::TrackerEngine,
@@ -147,6 +269,10 @@ pub struct ZXFFIState {
         StatePool<crate::graphics::PackedUnifiedShadingState, crate::graphics::ShadingStateIndex>,
     pub current_shading_state: crate::graphics::PackedUnifiedShadingState,
     pub shading_state_dirty: bool,
+    /// Outline width in world units set by `material_outline()` (Mode 4, 0 = off)
+    pub outline_width: f32,
+    /// Outline color (RGBA8) set by `material_outline()`
+    pub outline_color: u32,
 
     // GPU-instanced quad rendering (batched by texture)
     quad_batches: Vec<super::QuadBatch>,
@@ -155,6 +281,8 @@ pub struct ZXFFIState {
     // Diagnostics (reset each frame)
     pub mvp_shading_overflowed_this_frame: bool,
     pub mvp_shading_overflow_count: u32,
+    /// Triangles of meshes skipped by frustum culling
+    pub triangles_culled: u32,
 
     // EPU (Environment Processing Unit) state - instruction-based API (push-only)
     /// EPU configs pushed for this frame, keyed by `env_id`.
@@ -175,6 +303,36 @@ pub struct ZXFFIState {
     /// The value is an index into `mvp_shading_indices` (instance_index) so the
     /// environment shader uses the correct view/proj + shading state.
     pub epu_frame_draws: HashMap<(crate::graphics::Viewport, u32), u32>,
+    /// Sky draw requests for this frame from `sky_draw()`.
+    ///
+    /// Keyed like `epu_frame_draws`, and a key holds either an EPU or a sky
+    /// draw (whichever was requested last). The value is the
+    /// `mvp_shading_indices` index and the cubemap texture handle.
+    pub sky_frame_draws: HashMap<(crate::graphics::Viewport, u32), (u32, u32)>,
+    /// Ambient strength for `sky_draw()` calls from `sky_ambient()` (0 = EPU ambient)
+    pub sky_ambient: f32,
+    /// Ambient occlusion post pass from `post_ssao()` (None = off, persists
+    /// across frames)
+    pub ssao: Option<crate::graphics::SsaoSettings>,
+    /// Diffuse irradiance from this frame's `sky_draw()` calls, keyed by `env_id`.
+    ///
+    /// Replaces the EPU's SH9 for those environments after they are built.
+    pub sky_frame_ambient: HashMap<u32, EpuSh9>,
+    /// EPU configs pushed during the previous frame, keyed by `env_id`.
+    ///
+    /// Used as the starting point when `epu_transition()` is called before
+    /// this frame's `epu_set(...)`.
+    pub epu_last_configs: HashMap<u32, EpuConfig>,
+    /// In-flight EPU crossfades, keyed by `env_id`.
+    ///
+    /// Persists across frames. While a transition is running, configs pushed
+    /// for its `env_id` are replaced by the blended config for the current tick.
+    pub epu_transitions: HashMap<u32, EpuTransition>,
+    /// Environment probes created with `probe_create()` (handle = index + 1).
+    ///
+    /// Persists across frames. Each probe owns an environment slot starting at
+    /// `PROBE_ENV_BASE`, filled from its last capture by `material_probe()`.
+    pub env_probes: Vec<EnvProbe>,
     // NOTE: epu_ambient_cubes was removed - GPU readback would break rollback determinism
 }
 
@@ -207,11 +365,23 @@ impl Default for ZXFFIState {
             cull_mode: crate::graphics::CullMode::None,
             texture_filter: crate::graphics::TextureFilter::Nearest,
             bound_textures: [0; 4],
+            bound_atlas_sprite: None,
+            bound_array_layer: None,
             current_z_index: DEFAULT_Z_INDEX,
+            current_blend_mode_2d: crate::graphics::BlendMode::Alpha,
+            current_blend_mode_3d: crate::graphics::BlendMode::Alpha,
+            current_sort_bias: 0.0,
             current_viewport: crate::graphics::Viewport::FULLSCREEN,
+            clip_stack: Vec::new(),
+            display_scale_mode: None,
+            safe_area: [0.0, 0.0, RESOLUTION.0 as f32, RESOLUTION.1 as f32],
+            render_stats: crate::graphics::RenderStats::default(),
+            load_progress: [0; 2],
+            load_background: 0,
             // Render pass system - pass 0 is always the default pass
             current_pass_id: 0,
             pass_configs: vec![crate::graphics::PassConfig::default()],
+            scene_lights: Vec::new(),
             bone_matrices: Vec::new(),
             bone_count: 0,
             skeletons: Vec::new(),
@@ -229,17 +399,52 @@ impl Default for ZXFFIState {
             render_pass: crate::graphics::VirtualRenderPass::new(),
             mesh_map: hashbrown::HashMap::new(),
             pending_textures: Vec::new(),
+            pending_texture_arrays: Vec::new(),
             pending_meshes: Vec::new(),
             pending_meshes_packed: Vec::new(),
             pending_skeletons: Vec::new(),
             next_texture_handle: 1, // 0 reserved for invalid
+            thumbnail_handles: [0; MAX_THUMBNAIL_SLOTS],
+            thumbnail_revisions: [0; MAX_THUMBNAIL_SLOTS],
+            pending_thumbnails: Vec::new(),
             next_mesh_handle: 1,
             next_font_handle: 1,
+            atlases: HashMap::new(),
+            texture_arrays: HashMap::new(),
+            texture_sizes: HashMap::new(),
+            cubemaps: HashMap::new(),
+            mesh_sockets: HashMap::new(),
+            mesh_groups: HashMap::new(),
             fonts: Vec::new(),
             current_font: 0, // 0 = built-in font
             sounds: Vec::new(),
             next_sound_handle: 1, // 0 reserved for invalid
             sound_id_to_handle: HashMap::new(),
+            audio_bus_names: ["master", "music", "sfx", "ui", "voice"]
+                .map(String::from)
+                .to_vec(),
+            streams: Arc::new(Vec::new()),
+            stream_decoder: crate::audio::StreamDecoder::new(),
+            particle_systems: Vec::new(),
+            toasts: super::ToastQueue::default(),
+            decals: super::DecalPool::default(),
+            decal_surfaces: HashMap::new(),
+            decal_receivers: Vec::new(),
+            water_planes: Vec::new(),
+            water_vertices: Vec::new(),
+            navmeshes: Vec::new(),
+            paths: Vec::new(),
+            string_tables: Vec::new(),
+            locale: String::new(),
+            lightmaps: crate::lightmap::LightmapStore::default(),
+            current_lightmap: 0,
+            morphs: crate::morph::MorphStore::default(),
+            palettes: Vec::new(),
+            bound_palette: 0,
+            indexed_textures: HashSet::new(),
+            destructibles: Vec::new(),
+            hitbox_moves: Vec::new(),
+            chunk_grid: super::ChunkGrid::default(),
             tracker_engine: crate::tracker::TrackerEngine::new(),
             init_config: ZXInitConfig::default(),
             model_matrices,
@@ -253,13 +458,23 @@ impl Default for ZXFFIState {
             shading_pool: StatePool::new("Shading state", 65536),
             current_shading_state: crate::graphics::PackedUnifiedShadingState::default(),
             shading_state_dirty: true, // Start dirty so first draw creates state 0
+            outline_width: 0.0,
+            outline_color: 0x000000FF,
             quad_batches: Vec::new(),
             quad_batches_used: 0,
             mvp_shading_overflowed_this_frame: false,
             mvp_shading_overflow_count: 0,
+            triangles_culled: 0,
             // EPU (instruction-based) state (push-only)
             epu_frame_configs: HashMap::new(),
             epu_frame_draws: HashMap::new(),
+            sky_frame_draws: HashMap::new(),
+            sky_ambient: 0.0,
+            ssao: None,
+            sky_frame_ambient: HashMap::new(),
+            epu_last_configs: HashMap::new(),
+            epu_transitions: HashMap::new(),
+            env_probes: Vec::new(),
         }
     }
 }
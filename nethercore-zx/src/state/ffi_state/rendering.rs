@@ -1,6 +1,13 @@
 //! Rendering state management methods for ZXFFIState
 
-use super::{SkeletonData, ZXFFIState};
+use glam::{Mat4, Quat, Vec3};
+use zx_common::formats::{
+    PLATFORM_BONE_KEYFRAME_SIZE, PlatformBoneKeyframe, decode_bone_transform,
+};
+
+use super::{BoneMatrix3x4, EpuConfig, SkeletonData, ZXFFIState};
+use crate::graphics::{BlendMode, MeshBlend};
+use crate::resource_manager::default_environment;
 
 impl ZXFFIState {
     /// Sync animation state (Unified Buffer) to current_shading_state
@@ -36,6 +43,40 @@ impl ZXFFIState {
         // Note: _pad field is unused - shader uses unified_animation with pre-computed offsets
     }
 
+    /// Apply the bound palette if the slot 0 texture is indexed
+    ///
+    /// Other textures (fonts, RGBA8 sprites, untextured draws) ignore the
+    /// bound palette, so it can stay bound while the rest of the frame draws.
+    pub fn sync_palette(&mut self) {
+        let palette = if self.indexed_textures.contains(&self.bound_textures[0]) {
+            self.bound_palette
+        } else {
+            0
+        };
+        self.update_palette(palette);
+    }
+
+    /// Blend mode and sort depth of a 3D draw with local bounds `min..max`
+    ///
+    /// The depth is the view-space distance to the center of the bounds plus
+    /// the current `sort_bias()`. Opaque draws don't sort by depth and skip it.
+    pub fn mesh_blend(&self, min: Vec3, max: Vec3) -> MeshBlend {
+        let mode = self.current_blend_mode_3d;
+        if mode == BlendMode::Alpha {
+            return MeshBlend::default();
+        }
+        let model = self.current_model_matrix.unwrap_or(Mat4::IDENTITY);
+        let view = self
+            .current_view_matrix
+            .or_else(|| self.view_matrices.last().copied())
+            .unwrap_or(Mat4::IDENTITY);
+        let center = (view * model).transform_point3((min + max) * 0.5);
+        MeshBlend {
+            mode,
+            sort_depth: -center.z + self.current_sort_bias,
+        }
+    }
+
     /// Check if a skeleton is currently bound (inverse bind mode enabled)
     pub fn is_skeleton_bound(&self) -> bool {
         self.bound_skeleton != 0
@@ -50,13 +91,122 @@ impl ZXFFIState {
         self.skeletons.get(index)
     }
 
+    /// Skinning matrix the next skinned draw applies to vertices of `bone`
+    ///
+    /// Reads the bone from the current keyframe source (`set_bones()` or
+    /// `keyframe_bind()`) and, if a skeleton is bound, multiplies in its
+    /// inverse bind matrix, matching the vertex shader. Returns None when no
+    /// bound pose has that bone.
+    pub fn bone_skin_matrix(&self, bone: u32) -> Option<Mat4> {
+        use super::KeyframeSource;
+
+        if bone >= self.bone_count {
+            return None;
+        }
+
+        let pose = match self.current_keyframe_source {
+            KeyframeSource::Immediate { offset } => {
+                bone_to_mat4(self.bone_matrices.get((offset + bone) as usize)?)
+            }
+            KeyframeSource::Static { offset } => {
+                // Static offsets index the decoded frames of every collection, back to back
+                let (info, collection) =
+                    self.keyframe_gpu_info
+                        .iter()
+                        .zip(&self.keyframes)
+                        .find(|(info, _)| {
+                            let len = info.frame_count as u32 * info.bone_count as u32;
+                            (info.keyframe_base_offset..info.keyframe_base_offset + len)
+                                .contains(&offset)
+                        })?;
+                let start = (offset - info.keyframe_base_offset + bone) as usize
+                    * PLATFORM_BONE_KEYFRAME_SIZE;
+                let bytes = collection
+                    .data
+                    .get(start..start + PLATFORM_BONE_KEYFRAME_SIZE)?;
+                let transform = decode_bone_transform(&PlatformBoneKeyframe::from_bytes(bytes));
+                Mat4::from_scale_rotation_translation(
+                    Vec3::from_array(transform.scale),
+                    Quat::from_array(transform.rotation),
+                    Vec3::from_array(transform.position),
+                )
+            }
+        };
+
+        match self.get_bound_skeleton() {
+            Some(skeleton) => Some(pose * bone_to_mat4(skeleton.inverse_bind.get(bone as usize)?)),
+            None => Some(pose),
+        }
+    }
+
+    /// Transform of `mesh`'s socket `socket` (1-based) relative to the mesh
+    ///
+    /// Posed by `bone_skin_matrix()` when the socket's bone is in the bound
+    /// pose, otherwise the socket's bind-pose placement.
+    pub fn socket_matrix(&self, mesh: u32, socket: u32) -> Option<Mat4> {
+        let def = self
+            .mesh_sockets
+            .get(&mesh)?
+            .get(socket.checked_sub(1)? as usize)?;
+        let skin = self.bone_skin_matrix(def.bone).unwrap_or(Mat4::IDENTITY);
+        Some(skin * bone_to_mat4(&def.transform))
+    }
+
+    /// EPU config currently in effect for `env_id`
+    ///
+    /// This frame's config, else last frame's (so it also works before this
+    /// frame's `epu_set()`), falling back to environment 0 and then the
+    /// built-in default.
+    pub fn env_config(&self, env_id: u32) -> EpuConfig {
+        [env_id, 0]
+            .iter()
+            .find_map(|id| {
+                self.epu_frame_configs
+                    .get(id)
+                    .or_else(|| self.epu_last_configs.get(id))
+                    .copied()
+            })
+            .unwrap_or_else(default_environment)
+    }
+
+    /// Map a UV rect into the sprite selected with `atlas_bind()`
+    ///
+    /// `uv` is `[u0, v0, u1, v1]` relative to the sprite. Returned unchanged
+    /// unless the atlas texture is still bound to slot 0.
+    pub fn atlas_uv(&self, uv: [f32; 4]) -> [f32; 4] {
+        match self.bound_atlas_sprite {
+            Some((handle, [u0, v0, u1, v1])) if self.bound_textures[0] == handle => {
+                let (w, h) = (u1 - u0, v1 - v0);
+                [
+                    u0 + uv[0] * w,
+                    v0 + uv[1] * h,
+                    u0 + uv[2] * w,
+                    v0 + uv[3] * h,
+                ]
+            }
+            _ => uv,
+        }
+    }
+
+    /// Texture array layer for new quads, as stored in `QuadInstance::array_layer`
+    ///
+    /// The layer selected with `texture_array_bind()` plus one, or 0 unless the
+    /// array texture is still bound to slot 0.
+    pub fn quad_array_layer(&self) -> u32 {
+        match self.bound_array_layer {
+            Some((handle, layer)) if self.bound_textures[0] == handle => layer + 1,
+            _ => 0,
+        }
+    }
+
     /// Add current shading state to the pool if dirty, returning its index
     ///
     /// Uses deduplication via StatePool - if this exact state already exists, returns existing index.
     /// Otherwise adds a new entry.
     pub fn add_shading_state(&mut self) -> crate::graphics::ShadingStateIndex {
-        // Sync animation state before checking
+        // Sync animation and palette state before checking
         self.sync_animation_state();
+        self.sync_palette();
 
         // If not dirty, return the last added state
         if !self.shading_state_dirty && !self.shading_pool.is_empty() {
@@ -137,4 +287,39 @@ impl ZXFFIState {
 
         buffer_idx
     }
+
+    /// Add the outline pass shading state for a Mode 4 draw, returning its buffer index
+    ///
+    /// Returns None unless the render mode is 4, `material_outline()` set a
+    /// positive width and `format` has normals to extrude along. Call right
+    /// after `add_mvp_shading_state()` for the draw being outlined so both
+    /// share the same transforms.
+    pub fn add_outline_mvp_shading_state(&mut self, format: u8) -> Option<u32> {
+        use crate::graphics::{FLAG_OUTLINE, FORMAT_NORMAL};
+
+        if self.init_config.render_mode != 4
+            || self.outline_width <= 0.0
+            || format & FORMAT_NORMAL == 0
+        {
+            return None;
+        }
+
+        // Swap in the outline state, keeping skinning, dither and lights
+        let saved = self.current_shading_state;
+        self.current_shading_state.color_rgba8 = self.outline_color;
+        self.current_shading_state.uniform_set_1 = self.outline_width.to_bits();
+        self.current_shading_state.flags |= FLAG_OUTLINE;
+        self.shading_state_dirty = true;
+
+        let buffer_index = self.add_mvp_shading_state();
+
+        self.current_shading_state = saved;
+        self.shading_state_dirty = true;
+        Some(buffer_index)
+    }
+}
+
+/// Expand a row-major 3x4 bone matrix to a Mat4
+fn bone_to_mat4(m: &BoneMatrix3x4) -> Mat4 {
+    Mat4::from_cols_array_2d(&[m.row0, m.row1, m.row2, [0.0, 0.0, 0.0, 1.0]]).transpose()
 }
@@ -8,27 +8,42 @@ impl ZXFFIState {
     /// Add a quad instance to the appropriate batch (auto-batches by texture and viewport)
     ///
     /// This automatically groups quads by texture, viewport, z-index, and pass to minimize draw calls.
-    /// When bound_textures, current_viewport, z_index, or pass_id changes, a new batch is created.
-    pub fn add_quad_instance(&mut self, instance: crate::graphics::QuadInstance, z_index: u32) {
+    /// When bound_textures, current_viewport, clip, blend mode, z_index, or pass_id changes, a new batch is created.
+    pub fn add_quad_instance(&mut self, mut instance: crate::graphics::QuadInstance, z_index: u32) {
+        // Quads sharing a texture array batch together whatever layer they sample
+        instance.array_layer = self.quad_array_layer();
+
         // Determine if this is a screen-space quad (2D)
         let is_screen_space = instance.mode == crate::graphics::QuadMode::ScreenSpace as u32;
 
+        // Only 2D quads are clipped and blended; skip them entirely if the clip is empty
+        let (clip, blend_mode) = if is_screen_space {
+            (self.clip_stack.last().copied(), self.current_blend_mode_2d)
+        } else {
+            (None, crate::graphics::BlendMode::Alpha)
+        };
+        if clip.is_some_and(|clip| !clip.is_valid()) {
+            return;
+        }
+
         // Check if we can add to the current batch or need a new one
         if self.quad_batches_used > 0 {
             let last_batch = &mut self.quad_batches[self.quad_batches_used - 1];
             if last_batch.textures == self.bound_textures
                 && last_batch.is_screen_space == is_screen_space
                 && last_batch.viewport == self.current_viewport
+                && last_batch.clip == clip
+                && last_batch.blend_mode == blend_mode
                 && last_batch.pass_id == self.current_pass_id
                 && last_batch.z_index == z_index
             {
-                // Same textures, mode, viewport, pass, and z_index - add to current batch
+                // Same textures, mode, viewport, clip, blend, pass, and z_index - add to current batch
                 last_batch.instances.push(instance);
                 return;
             }
         }
 
-        // Need a new batch (first batch, textures changed, mode changed, viewport changed, pass changed, or z_index changed)
+        // Need a new batch (first batch, textures changed, mode changed, viewport changed, clip changed, blend changed, pass changed, or z_index changed)
         let batch = if self.quad_batches_used < self.quad_batches.len() {
             let batch = &mut self.quad_batches[self.quad_batches_used];
             batch.instances.clear();
@@ -39,6 +54,8 @@ impl ZXFFIState {
                 textures: self.bound_textures,
                 instances: Vec::new(),
                 viewport: self.current_viewport,
+                clip,
+                blend_mode,
                 pass_id: self.current_pass_id,
                 z_index,
             });
@@ -48,6 +65,8 @@ impl ZXFFIState {
         batch.is_screen_space = is_screen_space;
         batch.textures = self.bound_textures;
         batch.viewport = self.current_viewport;
+        batch.clip = clip;
+        batch.blend_mode = blend_mode;
         batch.pass_id = self.current_pass_id;
         batch.z_index = z_index;
         batch.instances.push(instance);
@@ -55,6 +74,17 @@ impl ZXFFIState {
         self.quad_batches_used += 1;
     }
 
+    /// Add a scene light for this frame
+    ///
+    /// Returns false (dropping the light) once `MAX_SCENE_LIGHTS` have been added.
+    pub fn add_scene_light(&mut self, light: crate::graphics::PackedSceneLight) -> bool {
+        if self.scene_lights.len() >= crate::graphics::MAX_SCENE_LIGHTS {
+            return false;
+        }
+        self.scene_lights.push(light);
+        true
+    }
+
     /// Clear all per-frame commands and reset for next frame
     ///
     /// Called once per frame in app.rs after render_frame() completes.
@@ -101,6 +131,7 @@ impl ZXFFIState {
         self.mvp_shading_map.clear();
         self.mvp_shading_overflowed_this_frame = false;
         self.mvp_shading_overflow_count = 0;
+        self.triangles_culled = 0;
 
         // Reset shading state pool for next frame
         self.shading_pool.clear();
@@ -116,11 +147,21 @@ impl ZXFFIState {
         // The bone_matrices buffer accumulates during the frame and must be reset
         self.bone_matrices.clear();
 
+        // Scene lights are immediate-mode: re-added every frame
+        self.scene_lights.clear();
+
+        // Decal receivers are collected from this frame's mesh draws
+        self.decal_receivers.clear();
+
         // Reset render state to defaults each frame (immediate-mode consistency)
         self.cull_mode = crate::graphics::CullMode::None;
         self.texture_filter = crate::graphics::TextureFilter::Nearest;
         self.current_z_index = DEFAULT_Z_INDEX; // Reset z-index to background
+        self.current_blend_mode_2d = crate::graphics::BlendMode::Alpha; // Reset 2D blending
+        self.current_blend_mode_3d = crate::graphics::BlendMode::Alpha; // Reset 3D blending
+        self.current_sort_bias = 0.0;
         self.current_viewport = crate::graphics::Viewport::FULLSCREEN; // Reset viewport to fullscreen
+        self.clip_stack.clear(); // Unbalanced clip_push() calls don't leak into the next frame
 
         // Reset render pass system - pass 0 is always the default pass
         self.current_pass_id = 0;
@@ -128,9 +169,13 @@ impl ZXFFIState {
         self.pass_configs
             .push(crate::graphics::PassConfig::default());
 
-        // Clear EPU per-frame requests
+        // Clear EPU per-frame requests (keeping the configs as transition sources)
+        std::mem::swap(&mut self.epu_last_configs, &mut self.epu_frame_configs);
         self.epu_frame_configs.clear();
         self.epu_frame_draws.clear();
+        self.sky_frame_draws.clear();
+        self.sky_frame_ambient.clear();
+        self.sky_ambient = 0.0;
 
         // Note: color and shading state already rebuild each frame via add_shading_state()
     }
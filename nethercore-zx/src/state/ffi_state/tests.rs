@@ -406,3 +406,256 @@ fn test_clear_frame_clears_epu_frame_requests() {
     assert!(state.epu_frame_configs.is_empty());
     assert!(state.epu_frame_draws.is_empty());
 }
+
+#[test]
+fn test_clear_frame_clears_sky_requests() {
+    use crate::graphics::Viewport;
+
+    let mut state = ZXFFIState {
+        sky_ambient: 1.0,
+        ..Default::default()
+    };
+    state
+        .sky_frame_draws
+        .insert((Viewport::FULLSCREEN, 0), (0, 1));
+    state
+        .sky_frame_ambient
+        .insert(0, bytemuck::Zeroable::zeroed());
+
+    state.clear_frame();
+
+    assert!(state.sky_frame_draws.is_empty());
+    assert!(state.sky_frame_ambient.is_empty());
+    assert_eq!(state.sky_ambient, 0.0);
+}
+
+#[test]
+fn test_clear_frame_keeps_last_epu_configs() {
+    let mut state = ZXFFIState::default();
+
+    let config = crate::graphics::epu::EpuConfig {
+        layers: [[1u64; 2]; 8],
+    };
+    state.epu_frame_configs.insert(3, config);
+
+    // Last frame's configs remain available as transition sources
+    state.clear_frame();
+    assert_eq!(
+        state.epu_last_configs.get(&3).map(|c| c.layers),
+        Some(config.layers)
+    );
+
+    // ...but only for one frame
+    state.clear_frame();
+    assert!(state.epu_last_configs.is_empty());
+}
+
+#[test]
+fn test_atlas_uv_maps_into_bound_sprite() {
+    let mut state = ZXFFIState::default();
+    let full = [0.0, 0.0, 1.0, 1.0];
+    assert_eq!(state.atlas_uv(full), full);
+
+    state.bound_textures[0] = 5;
+    state.bound_atlas_sprite = Some((5, [0.5, 0.0, 1.0, 0.25]));
+    assert_eq!(state.atlas_uv(full), [0.5, 0.0, 1.0, 0.25]);
+    assert_eq!(state.atlas_uv([0.5, 0.0, 1.0, 1.0]), [0.75, 0.0, 1.0, 0.25]);
+
+    // Another texture in slot 0 (e.g. from draw_rect) disables the mapping
+    state.bound_textures[0] = u32::MAX;
+    assert_eq!(state.atlas_uv(full), full);
+}
+
+#[test]
+fn test_texture_array_layers_share_a_batch() {
+    let mut state = ZXFFIState::default();
+    let sprite = |state: &ZXFFIState| {
+        crate::graphics::QuadInstance::sprite(
+            0.0,
+            0.0,
+            0.0,
+            16.0,
+            16.0,
+            0.0,
+            [0.0, 0.0, 1.0, 1.0],
+            0,
+            (state.view_matrices.len() - 1) as u32,
+        )
+    };
+
+    state.bound_textures[0] = 7;
+    for layer in [2, 0] {
+        state.bound_array_layer = Some((7, layer));
+        state.add_quad_instance(sprite(&state), 0);
+    }
+    assert_eq!(state.quad_batches().len(), 1);
+    let layers: Vec<u32> = state.quad_batches()[0]
+        .instances
+        .iter()
+        .map(|q| q.array_layer)
+        .collect();
+    assert_eq!(layers, [3, 1]);
+
+    // Another texture in slot 0 samples it directly
+    state.bound_textures[0] = u32::MAX;
+    state.add_quad_instance(sprite(&state), 0);
+    assert_eq!(state.quad_batches()[1].instances[0].array_layer, 0);
+}
+
+#[test]
+fn test_scene_lights_cap_and_clear_each_frame() {
+    use crate::graphics::{MAX_SCENE_LIGHTS, PackedSceneLight};
+
+    let mut state = ZXFFIState::default();
+    let light = PackedSceneLight::point(Vec3::ZERO, Vec3::ONE, 1.0, 5.0);
+
+    for _ in 0..MAX_SCENE_LIGHTS {
+        assert!(state.add_scene_light(light));
+    }
+    assert!(!state.add_scene_light(light));
+    assert_eq!(state.scene_lights.len(), MAX_SCENE_LIGHTS);
+
+    state.clear_frame();
+    assert!(state.scene_lights.is_empty());
+}
+
+#[test]
+fn test_socket_matrix_follows_bound_bone() {
+    let mut state = ZXFFIState::default();
+    state.mesh_sockets.insert(
+        1,
+        vec![MeshSocket {
+            name: "hand_r".to_string(),
+            bone: 1,
+            transform: BoneMatrix3x4::from_rows(
+                [1.0, 0.0, 0.0, 0.0],
+                [0.0, 1.0, 0.0, 0.0],
+                [0.0, 0.0, 1.0, 2.0],
+            ),
+        }],
+    );
+    let origin = |m: Mat4| m.transform_point3(Vec3::ZERO);
+
+    // No pose bound: the socket sits at its bind-pose placement
+    let m = state.socket_matrix(1, 1).unwrap();
+    assert!(origin(m).abs_diff_eq(Vec3::new(0.0, 0.0, 2.0), 1e-6));
+
+    // Immediate bones: the socket moves with bone 1
+    state.bone_matrices = vec![
+        BoneMatrix3x4::IDENTITY,
+        BoneMatrix3x4::from_rows(
+            [1.0, 0.0, 0.0, 5.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0],
+        ),
+    ];
+    state.bone_count = 2;
+    state.current_keyframe_source = KeyframeSource::Immediate { offset: 0 };
+    let m = state.socket_matrix(1, 1).unwrap();
+    assert!(origin(m).abs_diff_eq(Vec3::new(5.0, 0.0, 2.0), 1e-6));
+
+    // Socket IDs are 1-based and per mesh
+    assert!(state.socket_matrix(1, 0).is_none());
+    assert!(state.socket_matrix(1, 2).is_none());
+    assert!(state.socket_matrix(2, 1).is_none());
+}
+
+#[test]
+fn test_outline_state_only_in_toon_mode() {
+    use crate::graphics::{FLAG_OUTLINE, FORMAT_NORMAL, FORMAT_UV, ShadingStateIndex};
+
+    let mut state = ZXFFIState {
+        outline_width: 0.05,
+        outline_color: 0x101020FF,
+        ..Default::default()
+    };
+    state.current_shading_state.color_rgba8 = 0xFF8040FF;
+    state.add_mvp_shading_state();
+
+    // Other render modes, meshes without normals and zero width get no outline
+    assert!(state.add_outline_mvp_shading_state(FORMAT_NORMAL).is_none());
+    state.init_config.render_mode = 4;
+    assert!(state.add_outline_mvp_shading_state(FORMAT_UV).is_none());
+    state.outline_width = 0.0;
+    assert!(state.add_outline_mvp_shading_state(FORMAT_NORMAL).is_none());
+    state.outline_width = 0.05;
+
+    let idx = state.add_outline_mvp_shading_state(FORMAT_NORMAL).unwrap();
+    assert_eq!(idx, 1);
+    let indices = state.mvp_shading_states[idx as usize];
+    assert_eq!(indices.model_idx, state.mvp_shading_states[0].model_idx);
+    let outline = state
+        .shading_pool
+        .get(ShadingStateIndex(indices.shading_idx))
+        .unwrap();
+    assert_eq!(outline.color_rgba8, 0x101020FF);
+    assert_eq!(f32::from_bits(outline.uniform_set_1), 0.05);
+    assert_ne!(outline.flags & FLAG_OUTLINE, 0);
+
+    // The material state is restored for the next draw
+    assert_eq!(state.current_shading_state.color_rgba8, 0xFF8040FF);
+    assert_eq!(state.current_shading_state.flags & FLAG_OUTLINE, 0);
+    assert_eq!(state.add_mvp_shading_state(), 0);
+}
+
+#[test]
+fn test_palette_applies_only_to_indexed_textures() {
+    use crate::graphics::{FLAG_PALETTE_MASK, FLAG_PALETTE_SHIFT};
+
+    let mut state = ZXFFIState::default();
+    let palette = |state: &ZXFFIState| {
+        (state.current_shading_state.flags & FLAG_PALETTE_MASK) >> FLAG_PALETTE_SHIFT
+    };
+    state.indexed_textures.insert(4);
+    state.bound_palette = 2;
+
+    state.bound_textures[0] = 4;
+    state.add_shading_state();
+    assert_eq!(palette(&state), 2);
+
+    // RGBA8 textures (fonts, draw_rect) ignore the bound palette
+    state.bound_textures[0] = 5;
+    state.add_shading_state();
+    assert_eq!(palette(&state), 0);
+
+    state.bound_textures[0] = 4;
+    state.bound_palette = 0;
+    state.add_shading_state();
+    assert_eq!(palette(&state), 0);
+}
+
+#[test]
+fn test_blended_meshes_sort_back_to_front() {
+    use crate::graphics::{BlendMode, CommandSortKey, CullMode, MeshBlend, Viewport};
+
+    let mut state = ZXFFIState {
+        current_view_matrix: Some(Mat4::look_at_rh(Vec3::ZERO, -Vec3::Z, Vec3::Y)),
+        ..Default::default()
+    };
+    let key = |blend: MeshBlend| {
+        CommandSortKey::mesh(0, Viewport::FULLSCREEN, 0, CullMode::None, [0; 4], blend)
+    };
+    let blend_at = |state: &mut ZXFFIState, z: f32| {
+        state.current_model_matrix = Some(Mat4::from_translation(Vec3::new(0.0, 0.0, z)));
+        state.mesh_blend(Vec3::splat(-1.0), Vec3::splat(1.0))
+    };
+
+    // Opaque draws don't sort by depth
+    assert_eq!(blend_at(&mut state, -5.0), MeshBlend::default());
+
+    state.current_blend_mode_3d = BlendMode::Additive;
+    let near = blend_at(&mut state, -2.0);
+    let far = blend_at(&mut state, -10.0);
+    assert_eq!(near.sort_depth, 2.0);
+    assert!(key(far) < key(near));
+    assert!(key(MeshBlend::default()) < key(far));
+
+    // A negative bias pulls the far draw in front of the near one
+    state.current_sort_bias = -9.0;
+    let biased = blend_at(&mut state, -10.0);
+    assert!(key(near) < key(biased));
+
+    state.clear_frame();
+    assert_eq!(state.current_blend_mode_3d, BlendMode::Alpha);
+    assert_eq!(state.current_sort_bias, 0.0);
+}
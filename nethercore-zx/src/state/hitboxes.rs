@@ -0,0 +1,615 @@
+//! Frame-data hitboxes and hurtboxes
+//!
+//! Moves are lists of boxes, each active on a range of animation frames and
+//! registered once during init. Every tick the game places its actors (move,
+//! frame, position, facing) and calls `hitbox_resolve()`, which overlaps the
+//! active boxes of every pair of actors and queues hit and clash events.
+//!
+//! Actor poses, per-move hit tracking and unread events are POD and live in
+//! ZRollbackState, so resolving is deterministic and re-simulated ticks produce
+//! the same events.
+
+use bytemuck::{Pod, Zeroable};
+use glam::{Quat, Vec3};
+
+/// Maximum number of actors
+pub const MAX_HITBOX_ACTORS: usize = 32;
+
+/// Maximum number of events queued per resolve (extra events still apply but
+/// are not reported)
+pub const MAX_HITBOX_EVENTS: usize = 32;
+
+/// Maximum number of boxes in one move
+pub const MAX_MOVE_BOXES: usize = 32;
+
+/// Box kinds
+pub mod hitbox_kind {
+    /// Vulnerable area, hit by other actors' hitboxes
+    pub const HURT: u32 = 0;
+    /// Attacking area, hits other actors' hurtboxes
+    pub const HIT: u32 = 1;
+}
+
+/// Box flags
+pub mod hitbox_flags {
+    /// Hitbox passes through other hitboxes instead of clashing
+    pub const NO_CLASH: u32 = 1 << 0;
+}
+
+/// Event kinds
+pub mod hitbox_event {
+    /// The attacker's hitbox touched the defender's hurtbox
+    pub const HIT: u32 = 0;
+    /// The attacker's hitbox beat (or traded with) the defender's hitbox,
+    /// cancelling the defender's attack against the attacker
+    pub const CLASH: u32 = 1;
+}
+
+/// One box of a move, read from WASM memory (52 bytes)
+#[repr(C)]
+#[derive(Clone, Copy, Default, Debug, PartialEq, Pod, Zeroable)]
+pub struct HitboxDesc {
+    /// First animation frame the box is active on
+    pub first_frame: u32,
+    /// Last animation frame the box is active on (inclusive)
+    pub last_frame: u32,
+    /// `hitbox_kind` value
+    pub kind: u32,
+    /// Higher priority hitboxes win clashes and are preferred for hits
+    pub priority: u32,
+    /// Hits are tracked per group: a group hits each defender at most once
+    pub group: u32,
+    /// `hitbox_flags` bits
+    pub flags: u32,
+    /// Game-defined value reported with events (damage, hitstun…)
+    pub user_data: u32,
+    /// Box center in actor space (+X forward when yaw is 0)
+    pub center: [f32; 3],
+    /// Half size along each actor-space axis
+    pub half_extents: [f32; 3],
+}
+
+impl HitboxDesc {
+    /// Whether the box is active on `frame`
+    #[inline]
+    pub fn is_active(&self, frame: u32) -> bool {
+        (self.first_frame..=self.last_frame).contains(&frame)
+    }
+
+    /// Whether this is a hitbox that clashes with other hitboxes
+    #[inline]
+    fn clashes(&self) -> bool {
+        self.kind == hitbox_kind::HIT && self.flags & hitbox_flags::NO_CLASH == 0
+    }
+}
+
+/// An event, written to WASM memory (36 bytes)
+#[repr(C)]
+#[derive(Clone, Copy, Default, Debug, PartialEq, Pod, Zeroable)]
+pub struct HitboxEvent {
+    /// `hitbox_event` value
+    pub kind: u32,
+    /// Actor whose hitbox connected
+    pub attacker: u32,
+    /// Actor that was hit, or whose attack was cancelled
+    pub defender: u32,
+    /// Index of the attacker's box within its move
+    pub attacker_box: u32,
+    /// Index of the defender's box within its move
+    pub defender_box: u32,
+    /// The attacker box's `user_data`
+    pub user_data: u32,
+    /// Midpoint between the two box centers
+    pub position: [f32; 3],
+}
+
+/// Pose and hit tracking of one actor (36 bytes, inactive when `move_id` is 0)
+#[repr(C)]
+#[derive(Clone, Copy, Default, Debug, Pod, Zeroable)]
+pub struct HitboxActor {
+    /// Current move handle (0 = no boxes)
+    pub move_id: u32,
+    /// Current animation frame of the move
+    pub frame: u32,
+    pub position: [f32; 3],
+    /// Facing, as a rotation around +Y in radians
+    pub yaw: f32,
+    /// Actors on the same non-zero team never interact
+    pub team: u32,
+    /// Group of the hitbox that hit most recently in this move
+    pub hit_group: u32,
+    /// Actors already hit by `hit_group` (bit per actor)
+    pub hit_mask: u32,
+}
+
+impl HitboxActor {
+    /// Whether this slot holds an actor
+    #[inline]
+    pub fn is_active(&self) -> bool {
+        self.move_id != 0
+    }
+
+    /// Whether `group` already hit actor `other` during this move
+    #[inline]
+    fn has_hit(&self, group: u32, other: usize) -> bool {
+        self.hit_group == group && self.hit_mask & (1 << other) != 0
+    }
+
+    /// Record that `group` hit actor `other`
+    fn mark_hit(&mut self, group: u32, other: usize) {
+        if self.hit_group != group {
+            self.hit_group = group;
+            self.hit_mask = 0;
+        }
+        self.hit_mask |= 1 << other;
+    }
+}
+
+/// A box placed in the world: an oriented box that only rotates around +Y
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WorldBox {
+    pub center: Vec3,
+    pub half_extents: Vec3,
+    pub rotation: Quat,
+}
+
+impl WorldBox {
+    /// Place an actor-space box at an actor's position and facing
+    pub fn new(desc: &HitboxDesc, position: Vec3, yaw: f32) -> Self {
+        let rotation = Quat::from_rotation_y(yaw);
+        Self {
+            center: position + rotation * Vec3::from_array(desc.center),
+            half_extents: Vec3::from_array(desc.half_extents).abs(),
+            rotation,
+        }
+    }
+
+    /// The 8 corners, for drawing
+    pub fn corners(&self) -> [Vec3; 8] {
+        std::array::from_fn(|i| {
+            let sign = Vec3::new(
+                if i & 1 == 0 { -1.0 } else { 1.0 },
+                if i & 2 == 0 { -1.0 } else { 1.0 },
+                if i & 4 == 0 { -1.0 } else { 1.0 },
+            );
+            self.center + self.rotation * (sign * self.half_extents)
+        })
+    }
+
+    /// Whether two boxes overlap (touching counts)
+    ///
+    /// Both boxes share the Y axis, so this is an interval test on Y plus a
+    /// separating-axis test of two rectangles on the XZ plane.
+    pub fn overlaps(&self, other: &WorldBox) -> bool {
+        let d = other.center - self.center;
+        if d.y.abs() > self.half_extents.y + other.half_extents.y {
+            return false;
+        }
+
+        let axes = [
+            self.rotation * Vec3::X,
+            self.rotation * Vec3::Z,
+            other.rotation * Vec3::X,
+            other.rotation * Vec3::Z,
+        ];
+        let radius = |b: &WorldBox, axis: Vec3| {
+            b.half_extents.x * (b.rotation * Vec3::X).dot(axis).abs()
+                + b.half_extents.z * (b.rotation * Vec3::Z).dot(axis).abs()
+        };
+        axes.iter()
+            .all(|&axis| d.dot(axis).abs() <= radius(self, axis) + radius(other, axis) + 1e-5)
+    }
+}
+
+/// An active box of an actor, placed in the world
+#[derive(Clone, Copy, Debug)]
+pub struct PlacedBox {
+    /// Actor index
+    pub actor: usize,
+    /// Index of the box within the actor's move
+    pub index: usize,
+    pub desc: HitboxDesc,
+    pub world: WorldBox,
+}
+
+/// Actors and event queue (rolled back)
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct HitboxWorld {
+    actors: [HitboxActor; MAX_HITBOX_ACTORS],
+    events: [HitboxEvent; MAX_HITBOX_EVENTS],
+    /// Events queued by the last resolve
+    event_count: u32,
+    /// Events already returned by `next_event()`
+    event_read: u32,
+}
+
+impl Default for HitboxWorld {
+    fn default() -> Self {
+        Self::zeroed()
+    }
+}
+
+impl HitboxWorld {
+    /// Place an actor. Returns `false` if `index` is out of range.
+    ///
+    /// Starting a different move, or going back to an earlier frame, begins a
+    /// new move instance that may hit everyone again. `move_id` 0 removes the
+    /// actor.
+    pub fn set_actor(
+        &mut self,
+        index: usize,
+        move_id: u32,
+        frame: u32,
+        position: [f32; 3],
+        yaw: f32,
+        team: u32,
+    ) -> bool {
+        let Some(actor) = self.actors.get_mut(index) else {
+            return false;
+        };
+        let (hit_group, hit_mask) = if actor.move_id == move_id && frame >= actor.frame {
+            (actor.hit_group, actor.hit_mask)
+        } else {
+            (0, 0)
+        };
+        *actor = HitboxActor {
+            move_id,
+            frame,
+            position,
+            yaw,
+            team,
+            hit_group,
+            hit_mask,
+        };
+        true
+    }
+
+    /// Look up an actor
+    pub fn actor(&self, index: usize) -> Option<&HitboxActor> {
+        self.actors.get(index).filter(|a| a.is_active())
+    }
+
+    /// Every active box of every actor, in actor then box order
+    ///
+    /// `moves[i]` holds the boxes of move handle `i + 1`.
+    pub fn placed_boxes(&self, moves: &[Vec<HitboxDesc>]) -> Vec<PlacedBox> {
+        let mut placed = Vec::new();
+        for (actor_index, actor) in self.actors.iter().enumerate() {
+            let Some(boxes) = (actor.move_id as usize)
+                .checked_sub(1)
+                .and_then(|i| moves.get(i))
+            else {
+                continue;
+            };
+            let position = Vec3::from_array(actor.position);
+            for (index, desc) in boxes.iter().enumerate() {
+                if desc.is_active(actor.frame) {
+                    placed.push(PlacedBox {
+                        actor: actor_index,
+                        index,
+                        desc: *desc,
+                        world: WorldBox::new(desc, position, actor.yaw),
+                    });
+                }
+            }
+        }
+        placed
+    }
+
+    /// Overlap every pair of actors and queue events
+    ///
+    /// Clashes are resolved first: when hitboxes of two actors overlap, the
+    /// higher priority one cancels the other's attack against its owner for
+    /// the rest of that hit group (equal priorities cancel each other). Then
+    /// each actor's highest priority hitbox touching another actor's hurtbox
+    /// hits it, once per hit group. Events left unread from the previous
+    /// resolve are discarded.
+    pub fn resolve(&mut self, moves: &[Vec<HitboxDesc>]) {
+        self.event_count = 0;
+        self.event_read = 0;
+
+        let placed = self.placed_boxes(moves);
+        let boxes_of = |actor: usize| placed.iter().filter(move |b| b.actor == actor);
+
+        // Clashes between each pair of actors
+        for a in 0..MAX_HITBOX_ACTORS {
+            for b in a + 1..MAX_HITBOX_ACTORS {
+                if !self.can_interact(a, b) {
+                    continue;
+                }
+                let mut best: Option<(&PlacedBox, &PlacedBox)> = None;
+                let mut best_b: Option<(&PlacedBox, &PlacedBox)> = None;
+                for box_a in boxes_of(a).filter(|x| x.desc.clashes()) {
+                    for box_b in boxes_of(b).filter(|x| x.desc.clashes()) {
+                        if !box_a.world.overlaps(&box_b.world) {
+                            continue;
+                        }
+                        if best.is_none_or(|(x, _)| box_a.desc.priority > x.desc.priority) {
+                            best = Some((box_a, box_b));
+                        }
+                        if best_b.is_none_or(|(_, y)| box_b.desc.priority > y.desc.priority) {
+                            best_b = Some((box_a, box_b));
+                        }
+                    }
+                }
+                let (Some((win_a, lose_b)), Some((lose_a, win_b))) = (best, best_b) else {
+                    continue;
+                };
+                if win_a.desc.priority >= win_b.desc.priority {
+                    self.cancel(win_a, lose_b);
+                }
+                if win_b.desc.priority >= win_a.desc.priority {
+                    self.cancel(win_b, lose_a);
+                }
+            }
+        }
+
+        // Hits, each attacker against each defender
+        for a in 0..MAX_HITBOX_ACTORS {
+            for d in 0..MAX_HITBOX_ACTORS {
+                if a == d || !self.can_interact(a, d) {
+                    continue;
+                }
+                let mut best: Option<(&PlacedBox, &PlacedBox)> = None;
+                for hit in boxes_of(a).filter(|x| x.desc.kind == hitbox_kind::HIT) {
+                    if self.actors[a].has_hit(hit.desc.group, d)
+                        || best.is_some_and(|(x, _)| hit.desc.priority <= x.desc.priority)
+                    {
+                        continue;
+                    }
+                    if let Some(hurt) = boxes_of(d)
+                        .filter(|x| x.desc.kind == hitbox_kind::HURT)
+                        .find(|x| hit.world.overlaps(&x.world))
+                    {
+                        best = Some((hit, hurt));
+                    }
+                }
+                if let Some((hit, hurt)) = best {
+                    self.actors[a].mark_hit(hit.desc.group, d);
+                    self.push_event(hitbox_event::HIT, hit, hurt);
+                }
+            }
+        }
+    }
+
+    /// Every event from the last resolve, read or not
+    pub fn events(&self) -> &[HitboxEvent] {
+        &self.events[..self.event_count as usize]
+    }
+
+    /// Pop the next unread event from the last resolve
+    pub fn next_event(&mut self) -> Option<HitboxEvent> {
+        if self.event_read >= self.event_count {
+            return None;
+        }
+        let event = self.events[self.event_read as usize];
+        self.event_read += 1;
+        Some(event)
+    }
+
+    /// Whether two active actors may hit each other
+    fn can_interact(&self, a: usize, b: usize) -> bool {
+        let (a, b) = (&self.actors[a], &self.actors[b]);
+        a.is_active() && b.is_active() && (a.team == 0 || a.team != b.team)
+    }
+
+    /// `winner`'s hitbox cancels `loser`'s attack against the winner
+    fn cancel(&mut self, winner: &PlacedBox, loser: &PlacedBox) {
+        let loser_actor = &mut self.actors[loser.actor];
+        if loser_actor.has_hit(loser.desc.group, winner.actor) {
+            return;
+        }
+        loser_actor.mark_hit(loser.desc.group, winner.actor);
+        self.push_event(hitbox_event::CLASH, winner, loser);
+    }
+
+    fn push_event(&mut self, kind: u32, attacker: &PlacedBox, defender: &PlacedBox) {
+        if (self.event_count as usize) < MAX_HITBOX_EVENTS {
+            self.events[self.event_count as usize] = HitboxEvent {
+                kind,
+                attacker: attacker.actor as u32,
+                defender: defender.actor as u32,
+                attacker_box: attacker.index as u32,
+                defender_box: defender.index as u32,
+                user_data: attacker.desc.user_data,
+                position: ((attacker.world.center + defender.world.center) * 0.5).to_array(),
+            };
+            self.event_count += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hurt(x: f32) -> HitboxDesc {
+        HitboxDesc {
+            last_frame: u32::MAX,
+            kind: hitbox_kind::HURT,
+            center: [x, 1.0, 0.0],
+            half_extents: [0.4, 1.0, 0.4],
+            ..Default::default()
+        }
+    }
+
+    fn hit(x: f32, frames: (u32, u32), priority: u32) -> HitboxDesc {
+        HitboxDesc {
+            first_frame: frames.0,
+            last_frame: frames.1,
+            kind: hitbox_kind::HIT,
+            priority,
+            user_data: 10 + priority,
+            center: [x, 1.0, 0.0],
+            half_extents: [0.3, 0.2, 0.3],
+            ..Default::default()
+        }
+    }
+
+    /// Move 1: standing (hurtbox only). Move 2: jab reaching 1 unit forward on
+    /// frames 3-5. Move 3: strong jab, same reach, higher priority.
+    fn moves() -> Vec<Vec<HitboxDesc>> {
+        vec![
+            vec![hurt(0.0)],
+            vec![hurt(0.0), hit(1.0, (3, 5), 1)],
+            vec![hurt(0.0), hit(1.0, (3, 5), 2)],
+        ]
+    }
+
+    fn drain(world: &mut HitboxWorld) -> Vec<HitboxEvent> {
+        std::iter::from_fn(|| world.next_event()).collect()
+    }
+
+    #[test]
+    fn test_hitbox_sizes() {
+        assert_eq!(std::mem::size_of::<HitboxDesc>(), 52);
+        assert_eq!(std::mem::size_of::<HitboxEvent>(), 36);
+        assert_eq!(std::mem::size_of::<HitboxActor>(), 36);
+        assert_eq!(
+            std::mem::size_of::<HitboxWorld>(),
+            MAX_HITBOX_ACTORS * 36 + MAX_HITBOX_EVENTS * 36 + 8
+        );
+    }
+
+    #[test]
+    fn test_world_box_facing_and_overlap() {
+        let jab = hit(1.0, (0, 0), 0);
+        let forward = WorldBox::new(&jab, Vec3::ZERO, 0.0);
+        let mirrored = WorldBox::new(&jab, Vec3::ZERO, std::f32::consts::PI);
+        assert!((forward.center.x - 1.0).abs() < 1e-5);
+        assert!((mirrored.center.x + 1.0).abs() < 1e-5);
+
+        // A box rotated 45 degrees reaches further along the diagonal
+        let target = WorldBox::new(&hurt(0.0), Vec3::new(1.55, -1.0, 0.0), 0.0);
+        let square = HitboxDesc {
+            center: [0.0, 0.0, 0.0],
+            half_extents: [1.0, 1.0, 1.0],
+            ..Default::default()
+        };
+        assert!(!WorldBox::new(&square, Vec3::ZERO, 0.0).overlaps(&target));
+        assert!(WorldBox::new(&square, Vec3::ZERO, std::f32::consts::FRAC_PI_4).overlaps(&target));
+
+        // Separated vertically
+        let high = WorldBox::new(&hurt(0.0), Vec3::new(0.0, 5.0, 0.0), 0.0);
+        assert!(!WorldBox::new(&hurt(0.0), Vec3::ZERO, 0.0).overlaps(&high));
+    }
+
+    #[test]
+    fn test_hit_only_on_active_frames_and_once_per_move() {
+        let moves = moves();
+        let mut world = HitboxWorld::default();
+        let mut events = Vec::new();
+        for frame in 0..8 {
+            world.set_actor(0, 2, frame, [0.0; 3], 0.0, 1);
+            world.set_actor(1, 1, 0, [1.5, 0.0, 0.0], 0.0, 2);
+            world.resolve(&moves);
+            events.extend(drain(&mut world).into_iter().map(|e| (frame, e)));
+        }
+
+        assert_eq!(events.len(), 1);
+        let (frame, event) = events[0];
+        assert_eq!(frame, 3);
+        assert_eq!(event.kind, hitbox_event::HIT);
+        assert_eq!((event.attacker, event.defender), (0, 1));
+        assert_eq!((event.attacker_box, event.defender_box), (1, 0));
+        assert_eq!(event.user_data, 11);
+
+        // Restarting the move can hit again
+        world.set_actor(0, 2, 3, [0.0; 3], 0.0, 1);
+        world.resolve(&moves);
+        assert_eq!(drain(&mut world).len(), 1);
+    }
+
+    #[test]
+    fn test_teams_and_facing() {
+        let moves = moves();
+        let mut world = HitboxWorld::default();
+
+        // Same team: no hit
+        world.set_actor(0, 2, 3, [0.0; 3], 0.0, 1);
+        world.set_actor(1, 1, 0, [1.5, 0.0, 0.0], 0.0, 1);
+        world.resolve(&moves);
+        assert!(drain(&mut world).is_empty());
+
+        // Facing away: no hit
+        world.set_actor(1, 1, 0, [1.5, 0.0, 0.0], 0.0, 2);
+        world.set_actor(0, 2, 4, [0.0; 3], std::f32::consts::PI, 1);
+        world.resolve(&moves);
+        assert!(drain(&mut world).is_empty());
+    }
+
+    #[test]
+    fn test_clash_priority() {
+        let moves = moves();
+        let mut world = HitboxWorld::default();
+
+        // Strong jab (priority 2) against jab (priority 1), facing each other
+        world.set_actor(0, 3, 3, [0.0; 3], 0.0, 0);
+        world.set_actor(1, 2, 3, [2.0, 0.0, 0.0], std::f32::consts::PI, 0);
+        world.resolve(&moves);
+        let events = drain(&mut world);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].kind, hitbox_event::CLASH);
+        assert_eq!((events[0].attacker, events[0].defender), (0, 1));
+
+        // Equal priority trades: both attacks cancelled
+        let mut world = HitboxWorld::default();
+        world.set_actor(0, 2, 3, [0.0; 3], 0.0, 0);
+        world.set_actor(1, 2, 3, [2.0, 0.0, 0.0], std::f32::consts::PI, 0);
+        world.resolve(&moves);
+        let kinds: Vec<(u32, u32)> = drain(&mut world)
+            .iter()
+            .map(|e| (e.kind, e.attacker))
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![(hitbox_event::CLASH, 0), (hitbox_event::CLASH, 1)]
+        );
+    }
+
+    #[test]
+    fn test_clash_winner_still_hits() {
+        let moves = moves();
+        let mut world = HitboxWorld::default();
+
+        // Closer together, so the strong jab also reaches the hurtbox
+        world.set_actor(0, 3, 3, [0.0; 3], 0.0, 0);
+        world.set_actor(1, 2, 3, [1.5, 0.0, 0.0], std::f32::consts::PI, 0);
+        world.resolve(&moves);
+        let kinds: Vec<(u32, u32)> = drain(&mut world)
+            .iter()
+            .map(|e| (e.kind, e.attacker))
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![(hitbox_event::CLASH, 0), (hitbox_event::HIT, 0)]
+        );
+
+        // The loser stays cancelled for the rest of the move
+        world.set_actor(0, 3, 4, [0.0; 3], 0.0, 0);
+        world.set_actor(1, 2, 4, [1.5, 0.0, 0.0], std::f32::consts::PI, 0);
+        world.resolve(&moves);
+        assert!(drain(&mut world).is_empty());
+    }
+
+    #[test]
+    fn test_hit_groups_allow_multi_hit() {
+        let multi = vec![vec![hurt(0.0)], {
+            let mut first = hit(1.0, (0, 1), 0);
+            let mut second = hit(1.0, (2, 3), 0);
+            first.group = 1;
+            second.group = 2;
+            vec![first, second]
+        }];
+        let mut world = HitboxWorld::default();
+        let mut hits = 0;
+        for frame in 0..4 {
+            world.set_actor(0, 2, frame, [0.0; 3], 0.0, 0);
+            world.set_actor(1, 1, 0, [1.5, 0.0, 0.0], 0.0, 0);
+            world.resolve(&multi);
+            hits += drain(&mut world).len();
+        }
+        assert_eq!(hits, 2);
+    }
+}
@@ -4,21 +4,63 @@
 //! This state is rebuilt each frame from FFI calls and consumed by ZXGraphics.
 //! It is NOT part of rollback state - only GameState is rolled back.
 
+mod chunks;
 mod config;
+mod cursor;
+mod decals;
+mod events;
 mod ffi_state;
+mod hitboxes;
+mod particles;
+mod path_grid;
+mod physics;
 mod pool;
+mod projectiles;
 mod resources;
 mod rollback_state;
+mod toasts;
 
-pub use config::ZXInitConfig;
+pub use chunks::{ChunkDef, ChunkEvent, ChunkGrid, ChunkResidency, MAX_CHUNK_SLOTS, MAX_CHUNKS};
+pub use config::{MAX_PALETTE_COLORS, ZXInitConfig};
+pub use cursor::{
+    CursorState, DEFAULT_CURSOR_ACCELERATION, DEFAULT_CURSOR_SPEED, MAX_CURSOR_BOOST, PlayerCursor,
+    cursor_style,
+};
+pub use decals::{DECAL_FADE_FRACTION, Decal, DecalPool, MAX_DECALS};
+pub use events::zx_event_kind;
 pub use ffi_state::ZXFFIState;
+pub use hitboxes::{
+    HitboxActor, HitboxDesc, HitboxEvent, HitboxWorld, MAX_HITBOX_ACTORS, MAX_HITBOX_EVENTS,
+    MAX_MOVE_BOXES, PlacedBox, WorldBox, hitbox_event, hitbox_flags, hitbox_kind,
+};
+pub use particles::{
+    COLOR_GRADIENT_STEPS, MAX_PARTICLE_SYSTEMS, MAX_PARTICLES_PER_SYSTEM, Particle, ParticleEmit,
+    ParticleSystem,
+};
+pub use path_grid::{MAX_GRID_CELLS, PathGrid};
+pub use physics::{
+    Body, BodyContact, MAX_BODIES, MAX_BODY_CONTACTS, PhysicsWorld, body_flags, body_shape,
+};
 pub use pool::{PoolIndex, StatePool};
+pub use projectiles::{
+    MAX_PROJECTILE_HITS, MAX_PROJECTILES, Projectile, ProjectileDesc, ProjectileHit,
+    ProjectilePool, ProjectileTarget, projectile_flags,
+};
 pub use resources::{
-    Font, KeyframeGpuInfo, KeyframeSource, PendingKeyframes, PendingMesh, PendingMeshPacked,
-    PendingSkeleton, PendingTexture, SkeletonGpuInfo,
+    Font, FontRange, KeyframeGpuInfo, KeyframeSource, PendingKeyframes, PendingMesh,
+    PendingMeshPacked, PendingSkeleton, PendingTexture, PendingTextureArray, PendingThumbnail,
+    SkeletonGpuInfo,
 };
 pub use rollback_state::{
-    AudioPlaybackState, ChannelState, MAX_CHANNELS, TrackerState, ZRollbackState, tracker_flags,
+    AudioBusState, AudioListenerState, AudioPlaybackState, ChannelScheduleState, ChannelState,
+    DestructibleState, ListenerState, MAX_AUDIO_BUSES, MAX_CHANNELS, MAX_DESTRUCTIBLES,
+    MAX_LISTENERS, MAX_MUSIC_LAYERS, MAX_TEAMS, MatchState, MusicClockState, MusicLayerState,
+    StreamState, TrackerState, ZRollbackState, audio_bus, listener_policy, match_flags,
+    stream_flags, tracker_flags,
+};
+pub use toasts::{
+    MAX_QUEUED_TOASTS, MAX_TOAST_LEN, MAX_VISIBLE_TOASTS, TOAST_FADE_IN, TOAST_FADE_OUT, Toast,
+    ToastQueue,
 };
 
 /// Maximum number of bones for GPU skinning
@@ -71,6 +113,10 @@ pub struct QuadBatch {
     pub instances: Vec<crate::graphics::QuadInstance>,
     /// Viewport for this batch (snapshot of current_viewport when batch was created)
     pub viewport: crate::graphics::Viewport,
+    /// Scissor rectangle from `clip_push()` (screen pixels, None = clip to viewport)
+    pub clip: Option<crate::graphics::Viewport>,
+    /// Blend mode from `blend_mode_2d()` (always Alpha for billboards)
+    pub blend_mode: crate::graphics::BlendMode,
     /// Pass ID for render pass ordering (execution barrier)
     pub pass_id: u32,
     /// Z-index for 2D ordering within a pass (higher = closer to camera)
@@ -0,0 +1,264 @@
+//! Host-side particle simulation
+//!
+//! Particle systems are presentation-only: they live in FFI state, are never
+//! rolled back, and draw as camera-facing billboards. Each system owns its own
+//! seeded RNG so emission is reproducible across machines without touching the
+//! game's `random()` stream.
+
+use glam::Vec3;
+
+/// Maximum number of particle systems that can be created
+pub const MAX_PARTICLE_SYSTEMS: usize = 64;
+
+/// Maximum number of live particles per system
+pub const MAX_PARTICLES_PER_SYSTEM: u32 = 65536;
+
+/// Number of discrete colors a gradient is quantized to when drawn
+///
+/// Keeps the number of shading states per system bounded regardless of
+/// particle count.
+pub const COLOR_GRADIENT_STEPS: u32 = 16;
+
+/// Parameters for a single `particles_emit()` burst
+#[derive(Clone, Copy, Debug)]
+pub struct ParticleEmit {
+    /// Spawn position (world space)
+    pub position: Vec3,
+    /// Maximum initial speed; velocity is uniform within a sphere of this radius
+    pub vel_spread: f32,
+    /// Lifetime in seconds
+    pub life: f32,
+    /// Size at birth and at death (world units)
+    pub size: [f32; 2],
+    /// Color at birth and at death (0xRRGGBBAA)
+    pub color: [u32; 2],
+}
+
+/// A single live particle
+#[derive(Clone, Copy, Debug)]
+pub struct Particle {
+    pub position: Vec3,
+    pub velocity: Vec3,
+    pub age: f32,
+    pub life: f32,
+    pub size: [f32; 2],
+    pub color: [u32; 2],
+}
+
+impl Particle {
+    /// Normalized age (0.0 at birth, 1.0 at death)
+    #[inline]
+    pub fn progress(&self) -> f32 {
+        (self.age / self.life).clamp(0.0, 1.0)
+    }
+
+    /// Current billboard size
+    #[inline]
+    pub fn current_size(&self) -> f32 {
+        let t = self.progress();
+        self.size[0] + (self.size[1] - self.size[0]) * t
+    }
+
+    /// Current color, quantized to [`COLOR_GRADIENT_STEPS`]
+    pub fn current_color(&self) -> u32 {
+        let steps = COLOR_GRADIENT_STEPS - 1;
+        let step = (self.progress() * steps as f32).round() as u32;
+        lerp_rgba(self.color[0], self.color[1], step, steps)
+    }
+}
+
+/// A pool of particles with a fixed capacity
+#[derive(Clone, Debug)]
+pub struct ParticleSystem {
+    /// Capacity (emits beyond this are dropped)
+    pub max: u32,
+    /// Live particles
+    pub particles: Vec<Particle>,
+    /// Tick the simulation was last advanced to
+    pub last_tick: Option<u64>,
+    rng: u64,
+}
+
+impl ParticleSystem {
+    /// Create an empty system seeded from its handle
+    pub fn new(handle: u32, max: u32) -> Self {
+        Self {
+            max,
+            particles: Vec::with_capacity(max.min(1024) as usize),
+            last_tick: None,
+            rng: 0x853c_49e6_748f_ea9b ^ (handle as u64).wrapping_mul(0x9e37_79b9_7f4a_7c15),
+        }
+    }
+
+    /// Spawn up to `count` particles
+    ///
+    /// Returns the number actually spawned (limited by remaining capacity).
+    pub fn emit(&mut self, count: u32, params: &ParticleEmit) -> u32 {
+        let free = self.max.saturating_sub(self.particles.len() as u32);
+        let count = count.min(free);
+        if params.life <= 0.0 {
+            return 0;
+        }
+
+        for _ in 0..count {
+            let velocity = self.random_in_sphere() * params.vel_spread;
+            self.particles.push(Particle {
+                position: params.position,
+                velocity,
+                age: 0.0,
+                life: params.life,
+                size: params.size,
+                color: params.color,
+            });
+        }
+        count
+    }
+
+    /// Advance every particle by `dt` seconds and drop the expired ones
+    pub fn step(&mut self, dt: f32) {
+        self.particles.retain_mut(|p| {
+            p.age += dt;
+            p.position += p.velocity * dt;
+            p.age < p.life
+        });
+    }
+
+    /// Advance the simulation to `tick`, one fixed step per elapsed tick
+    ///
+    /// The first call only records the tick. At most `max_steps` steps are
+    /// taken so a long stall does not stall the frame further.
+    pub fn advance_to(&mut self, tick: u64, dt: f32, max_steps: u32) {
+        if let Some(last) = self.last_tick {
+            let steps = tick.saturating_sub(last).min(max_steps as u64);
+            for _ in 0..steps {
+                self.step(dt);
+            }
+        }
+        self.last_tick = Some(tick);
+    }
+
+    /// PCG32 step
+    fn next_u32(&mut self) -> u32 {
+        let old = self.rng;
+        self.rng = old
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        let xorshifted = (((old >> 18) ^ old) >> 27) as u32;
+        let rot = (old >> 59) as u32;
+        xorshifted.rotate_right(rot)
+    }
+
+    /// Uniform float in [-1, 1)
+    fn next_signed(&mut self) -> f32 {
+        (self.next_u32() >> 8) as f32 / (1u32 << 23) as f32 - 1.0
+    }
+
+    /// Uniform point inside the unit sphere (rejection sampled)
+    fn random_in_sphere(&mut self) -> Vec3 {
+        loop {
+            let v = Vec3::new(self.next_signed(), self.next_signed(), self.next_signed());
+            if v.length_squared() <= 1.0 {
+                return v;
+            }
+        }
+    }
+}
+
+/// Linearly interpolate two 0xRRGGBBAA colors at `step / steps`
+fn lerp_rgba(a: u32, b: u32, step: u32, steps: u32) -> u32 {
+    if steps == 0 {
+        return a;
+    }
+    let mut out = 0u32;
+    for shift in [24, 16, 8, 0] {
+        let ca = ((a >> shift) & 0xFF) as i32;
+        let cb = ((b >> shift) & 0xFF) as i32;
+        let c = ca + (cb - ca) * step as i32 / steps as i32;
+        out |= (c as u32 & 0xFF) << shift;
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn burst() -> ParticleEmit {
+        ParticleEmit {
+            position: Vec3::new(1.0, 2.0, 3.0),
+            vel_spread: 4.0,
+            life: 0.45,
+            size: [1.0, 0.0],
+            color: [0xFF0000FF, 0x0000FF00],
+        }
+    }
+
+    #[test]
+    fn test_emit_respects_capacity() {
+        let mut system = ParticleSystem::new(1, 10);
+        assert_eq!(system.emit(8, &burst()), 8);
+        assert_eq!(system.emit(8, &burst()), 2);
+        assert_eq!(system.particles.len(), 10);
+    }
+
+    #[test]
+    fn test_emission_is_deterministic_per_handle() {
+        let mut a = ParticleSystem::new(3, 100);
+        let mut b = ParticleSystem::new(3, 100);
+        let mut c = ParticleSystem::new(4, 100);
+        a.emit(50, &burst());
+        b.emit(50, &burst());
+        c.emit(50, &burst());
+
+        for (pa, pb) in a.particles.iter().zip(&b.particles) {
+            assert_eq!(pa.velocity, pb.velocity);
+        }
+        assert!(
+            a.particles
+                .iter()
+                .zip(&c.particles)
+                .any(|(pa, pc)| pa.velocity != pc.velocity)
+        );
+    }
+
+    #[test]
+    fn test_velocity_within_spread() {
+        let mut system = ParticleSystem::new(1, 1000);
+        system.emit(1000, &burst());
+        assert!(
+            system
+                .particles
+                .iter()
+                .all(|p| p.velocity.length() <= 4.0 + 1e-4)
+        );
+    }
+
+    #[test]
+    fn test_particles_expire() {
+        let mut system = ParticleSystem::new(1, 10);
+        system.emit(10, &burst());
+        system.advance_to(0, 0.1, 8);
+        system.advance_to(4, 0.1, 8);
+        assert_eq!(system.particles.len(), 10);
+        system.advance_to(5, 0.1, 8);
+        assert!(system.particles.is_empty());
+    }
+
+    #[test]
+    fn test_size_and_color_curves() {
+        let mut p = Particle {
+            position: Vec3::ZERO,
+            velocity: Vec3::ZERO,
+            age: 0.0,
+            life: 1.0,
+            size: [2.0, 0.0],
+            color: [0xFF0000FF, 0x00FF00FF],
+        };
+        assert_eq!(p.current_size(), 2.0);
+        assert_eq!(p.current_color(), 0xFF0000FF);
+
+        p.age = 1.0;
+        assert_eq!(p.current_size(), 0.0);
+        assert_eq!(p.current_color(), 0x00FF00FF);
+    }
+}
@@ -0,0 +1,294 @@
+//! Host-side A* pathfinding on a 2D cost grid
+//!
+//! One grid of up to 16384 cells for overhead and 2D games. Every cell has a
+//! cost for entering it (0 = blocked), and paths move in 8 directions without
+//! cutting the corners of blocked cells.
+//!
+//! The grid is POD and lives in ZRollbackState, so costs changed from
+//! `update()` (towers placed, doors opened) roll back with the game. Searches
+//! expand neighbours in a fixed order and break ties on the cell index, so
+//! re-simulated ticks always find the same path.
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use bytemuck::{Pod, Zeroable};
+
+/// Maximum number of cells in the grid (width × height)
+pub const MAX_GRID_CELLS: usize = 16384;
+
+/// Step cost of a straight move into a cost-1 cell
+const STRAIGHT_STEP: u32 = 10;
+
+/// Step cost of a diagonal move into a cost-1 cell (≈ 10√2)
+const DIAGONAL_STEP: u32 = 14;
+
+/// Neighbour offsets, straight moves first
+const NEIGHBOURS: [(i32, i32); 8] = [
+    (1, 0),
+    (-1, 0),
+    (0, 1),
+    (0, -1),
+    (1, 1),
+    (-1, 1),
+    (1, -1),
+    (-1, -1),
+];
+
+/// Pathfinding cost grid (rolled back)
+///
+/// Inactive (no grid) while `width` is 0.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct PathGrid {
+    pub width: u32,
+    pub height: u32,
+    /// Entry cost per cell, row-major (0 = blocked)
+    costs: [u8; MAX_GRID_CELLS],
+}
+
+impl Default for PathGrid {
+    fn default() -> Self {
+        Self::zeroed()
+    }
+}
+
+impl PathGrid {
+    /// Replace the grid with a `width` × `height` grid of cost-1 cells.
+    /// Returns `false` if either side is 0 or the grid is too large.
+    pub fn create(&mut self, width: u32, height: u32) -> bool {
+        let cells = width as usize * height as usize;
+        if cells == 0 || cells > MAX_GRID_CELLS {
+            return false;
+        }
+        *self = Self::zeroed();
+        self.width = width;
+        self.height = height;
+        self.costs[..cells].fill(1);
+        true
+    }
+
+    /// Whether a grid has been created
+    #[inline]
+    pub fn is_active(&self) -> bool {
+        self.width != 0
+    }
+
+    /// Row-major index of a cell, if it's on the grid
+    fn index(&self, x: i32, y: i32) -> Option<usize> {
+        let on_grid = x >= 0 && y >= 0 && (x as u32) < self.width && (y as u32) < self.height;
+        on_grid.then(|| y as usize * self.width as usize + x as usize)
+    }
+
+    /// Entry cost of a cell (0 = blocked), or `None` off the grid
+    pub fn cost(&self, x: u32, y: u32) -> Option<u8> {
+        let index = self.index(x.try_into().ok()?, y.try_into().ok()?)?;
+        Some(self.costs[index])
+    }
+
+    /// Set a cell's entry cost (0 = blocked). Returns `false` off the grid.
+    pub fn set_cost(&mut self, x: u32, y: u32, cost: u8) -> bool {
+        let (Ok(x), Ok(y)) = (x.try_into(), y.try_into()) else {
+            return false;
+        };
+        let Some(index) = self.index(x, y) else {
+            return false;
+        };
+        self.costs[index] = cost;
+        true
+    }
+
+    /// Whether a cell is on the grid and can be entered
+    fn passable(&self, x: i32, y: i32) -> bool {
+        self.index(x, y).is_some_and(|i| self.costs[i] != 0)
+    }
+
+    /// Cheapest path from `start` to `goal`, including both cells
+    ///
+    /// The start cell may be blocked (the walker is already on it); the goal
+    /// must not be. Returns `None` if either cell is off the grid or the goal
+    /// can't be reached.
+    pub fn find_path(&self, start: (u32, u32), goal: (u32, u32)) -> Option<Vec<(u32, u32)>> {
+        let start_index = self.index(start.0.try_into().ok()?, start.1.try_into().ok()?)?;
+        let goal_index = self.index(goal.0.try_into().ok()?, goal.1.try_into().ok()?)?;
+        if self.costs[goal_index] == 0 {
+            return None;
+        }
+
+        let width = self.width as usize;
+        let cells = width * self.height as usize;
+        let (goal_x, goal_y) = (goal.0 as i32, goal.1 as i32);
+        // Octile distance: admissible because every cell costs at least 1
+        let heuristic = |x: i32, y: i32| {
+            let dx = (x - goal_x).unsigned_abs();
+            let dy = (y - goal_y).unsigned_abs();
+            STRAIGHT_STEP * dx.max(dy) + (DIAGONAL_STEP - STRAIGHT_STEP) * dx.min(dy)
+        };
+
+        let mut cost_so_far = vec![u32::MAX; cells];
+        let mut came_from = vec![usize::MAX; cells];
+        let mut closed = vec![false; cells];
+        let mut open = BinaryHeap::new();
+
+        cost_so_far[start_index] = 0;
+        let h = heuristic(start.0 as i32, start.1 as i32);
+        open.push(Reverse((h, h, start_index)));
+
+        while let Some(Reverse((_, _, current))) = open.pop() {
+            if current == goal_index {
+                let mut path = Vec::new();
+                let mut cell = current;
+                while cell != usize::MAX {
+                    path.push(((cell % width) as u32, (cell / width) as u32));
+                    cell = came_from[cell];
+                }
+                path.reverse();
+                return Some(path);
+            }
+            if closed[current] {
+                continue;
+            }
+            closed[current] = true;
+
+            let (x, y) = ((current % width) as i32, (current / width) as i32);
+            for (dx, dy) in NEIGHBOURS {
+                let (nx, ny) = (x + dx, y + dy);
+                let Some(next) = self.index(nx, ny) else {
+                    continue;
+                };
+                if self.costs[next] == 0 || closed[next] {
+                    continue;
+                }
+                let diagonal = dx != 0 && dy != 0;
+                // No squeezing diagonally past a blocked corner
+                if diagonal && !(self.passable(x + dx, y) && self.passable(x, y + dy)) {
+                    continue;
+                }
+
+                let step = if diagonal {
+                    DIAGONAL_STEP
+                } else {
+                    STRAIGHT_STEP
+                };
+                let cost = cost_so_far[current] + step * self.costs[next] as u32;
+                if cost < cost_so_far[next] {
+                    cost_so_far[next] = cost;
+                    came_from[next] = current;
+                    let h = heuristic(nx, ny);
+                    open.push(Reverse((cost + h, h, next)));
+                }
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid(width: u32, height: u32) -> PathGrid {
+        let mut grid = PathGrid::default();
+        assert!(grid.create(width, height));
+        grid
+    }
+
+    #[test]
+    fn test_path_grid_size() {
+        assert_eq!(std::mem::size_of::<PathGrid>(), MAX_GRID_CELLS + 8);
+    }
+
+    #[test]
+    fn test_create_rejects_empty_and_oversized_grids() {
+        let mut grid = PathGrid::default();
+        assert!(!grid.is_active());
+        assert!(!grid.create(0, 10));
+        assert!(!grid.create(200, 100));
+        assert!(grid.create(128, 128));
+        assert_eq!(grid.cost(127, 127), Some(1));
+        assert_eq!(grid.cost(128, 0), None);
+        assert!(!grid.set_cost(0, 128, 0));
+    }
+
+    #[test]
+    fn test_open_grid_paths_take_diagonals() {
+        let grid = grid(8, 8);
+        assert_eq!(grid.find_path((2, 2), (2, 2)), Some(vec![(2, 2)]));
+
+        let path = grid.find_path((0, 0), (3, 3)).unwrap();
+        assert_eq!(path, vec![(0, 0), (1, 1), (2, 2), (3, 3)]);
+    }
+
+    #[test]
+    fn test_path_goes_around_wall_without_cutting_corners() {
+        // Wall at x = 2 from y = 0 to 3, leaving a gap at y = 4
+        let mut grid = grid(5, 5);
+        for y in 0..4 {
+            grid.set_cost(2, y, 0);
+        }
+
+        let path = grid.find_path((0, 0), (4, 0)).unwrap();
+        assert_eq!(path.first(), Some(&(0, 0)));
+        assert_eq!(path.last(), Some(&(4, 0)));
+        assert!(path.iter().all(|&(x, y)| grid.cost(x, y) != Some(0)));
+        // The wall's end (2, 3) can't be cut diagonally, so the path passes (2, 4)
+        assert!(path.contains(&(2, 4)));
+        for pair in path.windows(2) {
+            let (a, b) = (pair[0], pair[1]);
+            if a.0 != b.0 && a.1 != b.1 {
+                assert_ne!(grid.cost(b.0, a.1), Some(0));
+                assert_ne!(grid.cost(a.0, b.1), Some(0));
+            }
+        }
+    }
+
+    #[test]
+    fn test_path_prefers_cheap_cells() {
+        // A straight row through mud (cost 9) vs a detour over cost-1 cells
+        let mut grid = grid(7, 3);
+        for x in 1..6 {
+            grid.set_cost(x, 1, 9);
+        }
+
+        let path = grid.find_path((0, 1), (6, 1)).unwrap();
+        assert!(path.iter().all(|&(x, y)| y != 1 || x == 0 || x == 6));
+    }
+
+    #[test]
+    fn test_no_path_to_blocked_or_enclosed_goal() {
+        let mut grid = grid(5, 5);
+        grid.set_cost(4, 4, 0);
+        assert_eq!(grid.find_path((0, 0), (4, 4)), None);
+
+        // Enclose (2, 2)
+        for (x, y) in [
+            (1, 1),
+            (2, 1),
+            (3, 1),
+            (1, 2),
+            (3, 2),
+            (1, 3),
+            (2, 3),
+            (3, 3),
+        ] {
+            grid.set_cost(x, y, 0);
+        }
+        assert_eq!(grid.find_path((0, 0), (2, 2)), None);
+        assert_eq!(grid.find_path((0, 0), (5, 0)), None);
+
+        // A walker standing on a blocked cell can still leave it
+        assert!(grid.find_path((1, 1), (0, 0)).is_some());
+    }
+
+    #[test]
+    fn test_paths_are_deterministic() {
+        let mut grid = grid(16, 16);
+        for y in 2..14 {
+            grid.set_cost(8, y, 0);
+        }
+        let first = grid.find_path((0, 8), (15, 8));
+        for _ in 0..4 {
+            assert_eq!(grid.find_path((0, 8), (15, 8)), first);
+        }
+    }
+}
@@ -0,0 +1,487 @@
+//! Host-simulated 2D physics
+//!
+//! Axis-aligned boxes and circles with velocity and gravity, stepped once per
+//! `physics_step()`. Overlapping bodies are pushed apart and stop moving into
+//! each other, and every touching pair is reported as a contact the game
+//! handles in `on_contact()` during the same tick.
+//!
+//! The whole world (bodies, gravity and the last step's contacts) is POD and
+//! lives in ZRollbackState. Bodies are integrated and tested in slot order, so
+//! re-simulated ticks produce the same positions and contacts.
+
+use bytemuck::{Pod, Zeroable};
+use glam::Vec2;
+
+/// Maximum number of live bodies
+pub const MAX_BODIES: usize = 128;
+
+/// Maximum number of contacts reported per step (extra contacts still
+/// resolve but are not reported)
+pub const MAX_BODY_CONTACTS: usize = 64;
+
+/// Body shapes
+pub mod body_shape {
+    /// Axis-aligned box (`w` × `h`, centered on the position)
+    pub const AABB: u32 = 0;
+    /// Circle (radius `w`, `h` is ignored)
+    pub const CIRCLE: u32 = 1;
+}
+
+/// Body behaviour flags
+pub mod body_flags {
+    /// Never moves and isn't affected by gravity (walls, floors, platforms)
+    pub const STATIC: u32 = 1 << 0;
+    /// Reports contacts but doesn't push or get pushed (triggers, pickups)
+    pub const SENSOR: u32 = 1 << 1;
+}
+
+/// A touching pair of bodies (28 bytes)
+#[repr(C)]
+#[derive(Clone, Copy, Default, Debug, PartialEq, Pod, Zeroable)]
+pub struct BodyContact {
+    /// Lower body handle
+    pub a: u32,
+    /// Higher body handle
+    pub b: u32,
+    /// Unit normal pointing from `a` to `b`
+    pub normal: [f32; 2],
+    /// Overlap along the normal before resolution
+    pub depth: f32,
+    /// Middle of the overlap, on the normal through `b`'s center
+    pub point: [f32; 2],
+}
+
+/// A live body (40 bytes, inactive when `active` is 0)
+#[repr(C)]
+#[derive(Clone, Copy, Default, Debug, Pod, Zeroable)]
+pub struct Body {
+    /// Center position
+    pub position: [f32; 2],
+    pub velocity: [f32; 2],
+    /// Half width and height for boxes, radius (in x) for circles
+    pub half_size: [f32; 2],
+    pub shape: u32,
+    pub flags: u32,
+    pub active: u32,
+    pub _pad: u32,
+}
+
+impl Body {
+    /// Whether this slot holds a live body
+    #[inline]
+    pub fn is_active(&self) -> bool {
+        self.active != 0
+    }
+
+    /// Whether the body never moves
+    #[inline]
+    pub fn is_static(&self) -> bool {
+        self.flags & body_flags::STATIC != 0
+    }
+
+    /// Whether the body only reports contacts
+    #[inline]
+    pub fn is_sensor(&self) -> bool {
+        self.flags & body_flags::SENSOR != 0
+    }
+
+    /// Resistance to being pushed (0 for static bodies, else 1)
+    #[inline]
+    fn inverse_mass(&self) -> f32 {
+        if self.is_static() { 0.0 } else { 1.0 }
+    }
+}
+
+/// Fixed-size body pool and contact list (rolled back)
+///
+/// Handles are slot index + 1, so 0 is never a valid handle.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct PhysicsWorld {
+    bodies: [Body; MAX_BODIES],
+    contacts: [BodyContact; MAX_BODY_CONTACTS],
+    /// Acceleration applied to moving bodies (units per second²)
+    pub gravity: [f32; 2],
+    /// Contacts found by the last step
+    contact_count: u32,
+    _pad: u32,
+}
+
+impl Default for PhysicsWorld {
+    fn default() -> Self {
+        Self::zeroed()
+    }
+}
+
+impl PhysicsWorld {
+    /// Create a body. Returns its handle, or `None` if the world is full.
+    pub fn create(&mut self, shape: u32, position: Vec2, size: Vec2, flags: u32) -> Option<u32> {
+        let index = self.bodies.iter().position(|b| !b.is_active())?;
+        let half_size = match shape {
+            body_shape::CIRCLE => Vec2::new(size.x.max(0.0), 0.0),
+            _ => (size * 0.5).max(Vec2::ZERO),
+        };
+        self.bodies[index] = Body {
+            position: position.to_array(),
+            velocity: [0.0; 2],
+            half_size: half_size.to_array(),
+            shape,
+            flags,
+            active: 1,
+            _pad: 0,
+        };
+        Some(index as u32 + 1)
+    }
+
+    /// Remove a body. Returns `false` if the handle isn't active.
+    pub fn destroy(&mut self, handle: u32) -> bool {
+        let Some(body) = self.get_mut(handle) else {
+            return false;
+        };
+        *body = Body::default();
+        true
+    }
+
+    /// Look up a live body by handle
+    pub fn get(&self, handle: u32) -> Option<&Body> {
+        let index = (handle as usize).checked_sub(1)?;
+        self.bodies.get(index).filter(|b| b.is_active())
+    }
+
+    /// Look up a live body by handle for editing
+    pub fn get_mut(&mut self, handle: u32) -> Option<&mut Body> {
+        let index = (handle as usize).checked_sub(1)?;
+        self.bodies.get_mut(index).filter(|b| b.is_active())
+    }
+
+    /// Number of live bodies
+    pub fn active_count(&self) -> usize {
+        self.bodies.iter().filter(|b| b.is_active()).count()
+    }
+
+    /// Advance every body by `dt` seconds and resolve overlaps
+    ///
+    /// Moving bodies gain gravity and move by their velocity, then each
+    /// overlapping pair (in handle order) is pushed apart along the contact
+    /// normal and loses the velocity carrying it into the other body. Pairs
+    /// of static bodies are skipped. Contacts from the previous step are
+    /// discarded.
+    pub fn step(&mut self, dt: f32) {
+        self.contact_count = 0;
+        let gravity = Vec2::from_array(self.gravity);
+
+        for body in self.bodies.iter_mut() {
+            if !body.is_active() || body.is_static() {
+                continue;
+            }
+            let velocity = Vec2::from_array(body.velocity) + gravity * dt;
+            body.velocity = velocity.to_array();
+            body.position = (Vec2::from_array(body.position) + velocity * dt).to_array();
+        }
+
+        for i in 0..MAX_BODIES {
+            for j in i + 1..MAX_BODIES {
+                let (a, b) = (&self.bodies[i], &self.bodies[j]);
+                if !a.is_active() || !b.is_active() || (a.is_static() && b.is_static()) {
+                    continue;
+                }
+                let Some((normal, depth)) = overlap(a, b) else {
+                    continue;
+                };
+
+                let point = contact_point(b, normal, depth);
+                if !a.is_sensor() && !b.is_sensor() {
+                    self.resolve(i, j, normal, depth);
+                }
+
+                if (self.contact_count as usize) < MAX_BODY_CONTACTS {
+                    self.contacts[self.contact_count as usize] = BodyContact {
+                        a: i as u32 + 1,
+                        b: j as u32 + 1,
+                        normal: normal.to_array(),
+                        depth,
+                        point: point.to_array(),
+                    };
+                    self.contact_count += 1;
+                }
+            }
+        }
+    }
+
+    /// Push bodies `i` and `j` apart and cancel their closing velocity
+    fn resolve(&mut self, i: usize, j: usize, normal: Vec2, depth: f32) {
+        let (inv_a, inv_b) = (self.bodies[i].inverse_mass(), self.bodies[j].inverse_mass());
+        let total = inv_a + inv_b;
+        if total == 0.0 {
+            return;
+        }
+
+        let correction = normal * (depth / total);
+        let closing = (Vec2::from_array(self.bodies[j].velocity)
+            - Vec2::from_array(self.bodies[i].velocity))
+        .dot(normal);
+        let impulse = normal * (-closing).max(0.0) / total;
+
+        push(&mut self.bodies[i], -correction * inv_a, -impulse * inv_a);
+        push(&mut self.bodies[j], correction * inv_b, impulse * inv_b);
+    }
+
+    /// Every contact from the last step, in handle order
+    pub fn contacts(&self) -> &[BodyContact] {
+        &self.contacts[..self.contact_count as usize]
+    }
+}
+
+/// Middle of the overlap between `b` and a body touching it from `-normal`
+fn contact_point(b: &Body, normal: Vec2, depth: f32) -> Vec2 {
+    let half = Vec2::from_array(b.half_size);
+    let extent = match b.shape {
+        body_shape::CIRCLE => half.x,
+        _ => half.dot(normal.abs()),
+    };
+    Vec2::from_array(b.position) - normal * (extent - depth * 0.5)
+}
+
+/// Move a body and change its velocity
+#[inline]
+fn push(body: &mut Body, offset: Vec2, impulse: Vec2) {
+    body.position = (Vec2::from_array(body.position) + offset).to_array();
+    body.velocity = (Vec2::from_array(body.velocity) + impulse).to_array();
+}
+
+/// Contact normal (from `a` to `b`) and depth if the bodies overlap
+fn overlap(a: &Body, b: &Body) -> Option<(Vec2, f32)> {
+    let (pa, pb) = (Vec2::from_array(a.position), Vec2::from_array(b.position));
+    let (ha, hb) = (Vec2::from_array(a.half_size), Vec2::from_array(b.half_size));
+
+    match (a.shape, b.shape) {
+        (body_shape::CIRCLE, body_shape::CIRCLE) => {
+            let delta = pb - pa;
+            let reach = ha.x + hb.x;
+            let distance_sq = delta.length_squared();
+            if distance_sq >= reach * reach {
+                return None;
+            }
+            let distance = distance_sq.sqrt();
+            let normal = if distance > 0.0 {
+                delta / distance
+            } else {
+                Vec2::X
+            };
+            Some((normal, reach - distance))
+        }
+        (body_shape::CIRCLE, _) => box_circle(pb, hb, pa, ha.x).map(|(n, d)| (-n, d)),
+        (_, body_shape::CIRCLE) => box_circle(pa, ha, pb, hb.x),
+        _ => {
+            let delta = pb - pa;
+            let depth = ha + hb - delta.abs();
+            if depth.x <= 0.0 || depth.y <= 0.0 {
+                return None;
+            }
+            // Separate along the axis of least overlap
+            if depth.x < depth.y {
+                Some((Vec2::new(sign(delta.x), 0.0), depth.x))
+            } else {
+                Some((Vec2::new(0.0, sign(delta.y)), depth.y))
+            }
+        }
+    }
+}
+
+/// Box-versus-circle overlap, with the normal pointing from box to circle
+fn box_circle(center: Vec2, half: Vec2, circle: Vec2, radius: f32) -> Option<(Vec2, f32)> {
+    let delta = circle - center;
+    let closest = delta.clamp(-half, half);
+    let outside = delta - closest;
+
+    if outside != Vec2::ZERO {
+        let distance_sq = outside.length_squared();
+        if distance_sq >= radius * radius {
+            return None;
+        }
+        let distance = distance_sq.sqrt();
+        return Some((outside / distance, radius - distance));
+    }
+
+    // Circle center is inside the box: push out through the nearest edge
+    let gap = half - delta.abs();
+    if gap.x < gap.y {
+        Some((Vec2::new(sign(delta.x), 0.0), gap.x + radius))
+    } else {
+        Some((Vec2::new(0.0, sign(delta.y)), gap.y + radius))
+    }
+}
+
+/// Sign that treats 0 as positive, so coincident bodies still separate
+#[inline]
+fn sign(value: f32) -> f32 {
+    if value < 0.0 { -1.0 } else { 1.0 }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn world() -> PhysicsWorld {
+        PhysicsWorld::default()
+    }
+
+    fn position(world: &PhysicsWorld, handle: u32) -> Vec2 {
+        Vec2::from_array(world.get(handle).unwrap().position)
+    }
+
+    #[test]
+    fn test_physics_world_size() {
+        assert_eq!(std::mem::size_of::<Body>(), 40);
+        assert_eq!(std::mem::size_of::<BodyContact>(), 28);
+        assert_eq!(
+            std::mem::size_of::<PhysicsWorld>(),
+            MAX_BODIES * 40 + MAX_BODY_CONTACTS * 28 + 16
+        );
+    }
+
+    #[test]
+    fn test_box_lands_on_static_floor() {
+        let mut world = world();
+        world.gravity = [0.0, -10.0];
+        let floor = world
+            .create(
+                body_shape::AABB,
+                Vec2::new(0.0, -1.0),
+                Vec2::new(10.0, 2.0),
+                body_flags::STATIC,
+            )
+            .unwrap();
+        let crate_ = world
+            .create(body_shape::AABB, Vec2::new(0.0, 2.0), Vec2::ONE, 0)
+            .unwrap();
+
+        let mut touched = false;
+        for _ in 0..120 {
+            world.step(1.0 / 60.0);
+            touched |= world
+                .contacts()
+                .iter()
+                .any(|c| (c.a, c.b) == (floor, crate_));
+        }
+
+        assert!(touched);
+        // Resting on the floor's top edge, not sinking through it
+        assert!((position(&world, crate_).y - 0.5).abs() < 0.01);
+        assert!(world.get(crate_).unwrap().velocity[1].abs() < 0.2);
+        assert_eq!(position(&world, floor), Vec2::new(0.0, -1.0));
+    }
+
+    #[test]
+    fn test_equal_circles_share_the_push() {
+        let mut world = world();
+        let a = world
+            .create(body_shape::CIRCLE, Vec2::new(-0.5, 0.0), Vec2::ONE, 0)
+            .unwrap();
+        let b = world
+            .create(body_shape::CIRCLE, Vec2::new(0.5, 0.0), Vec2::ONE, 0)
+            .unwrap();
+        world.get_mut(a).unwrap().velocity = [1.0, 0.0];
+
+        world.step(0.0);
+        let contact = world.contacts()[0];
+        assert_eq!((contact.a, contact.b), (a, b));
+        assert_eq!(contact.normal, [1.0, 0.0]);
+        assert!((contact.depth - 1.0).abs() < 1e-6);
+        assert_eq!(contact.point, [0.0, 0.0]);
+
+        // Pushed apart evenly, closing velocity split between them
+        assert!((position(&world, a).x + 1.0).abs() < 1e-6);
+        assert!((position(&world, b).x - 1.0).abs() < 1e-6);
+        assert!((world.get(a).unwrap().velocity[0] - 0.5).abs() < 1e-6);
+        assert!((world.get(b).unwrap().velocity[0] - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_circle_against_box_corner_and_inside() {
+        let mut world = world();
+        let wall = world
+            .create(
+                body_shape::AABB,
+                Vec2::ZERO,
+                Vec2::splat(2.0),
+                body_flags::STATIC,
+            )
+            .unwrap();
+        // Near the top-right corner, outside the box
+        let ball = world
+            .create(body_shape::CIRCLE, Vec2::splat(1.5), Vec2::ONE, 0)
+            .unwrap();
+        world.step(0.0);
+        let normal = Vec2::from_array(world.contacts()[0].normal);
+        assert!((normal - Vec2::splat(std::f32::consts::FRAC_1_SQRT_2)).length() < 1e-5);
+        assert!(position(&world, ball).distance(Vec2::ONE) >= 1.0 - 1e-5);
+
+        // Center inside the box exits through the nearest edge
+        world.get_mut(ball).unwrap().position = [0.0, -0.8];
+        world.step(0.0);
+        assert_eq!(world.contacts()[0].normal, [0.0, -1.0]);
+        assert!((position(&world, ball).y + 2.0).abs() < 1e-5);
+        assert_eq!(position(&world, wall), Vec2::ZERO);
+    }
+
+    #[test]
+    fn test_sensor_reports_without_pushing() {
+        let mut world = world();
+        let pickup = world
+            .create(
+                body_shape::CIRCLE,
+                Vec2::ZERO,
+                Vec2::ONE,
+                body_flags::SENSOR,
+            )
+            .unwrap();
+        let player = world
+            .create(body_shape::AABB, Vec2::new(0.5, 0.0), Vec2::ONE, 0)
+            .unwrap();
+
+        world.step(0.0);
+        assert_eq!(world.contacts().len(), 1);
+        assert_eq!(
+            (world.contacts()[0].a, world.contacts()[0].b),
+            (pickup, player)
+        );
+        assert_eq!(position(&world, player), Vec2::new(0.5, 0.0));
+        assert_eq!(position(&world, pickup), Vec2::ZERO);
+    }
+
+    #[test]
+    fn test_step_is_deterministic_and_slots_reuse() {
+        let mut world = world();
+        world.gravity = [0.0, -9.8];
+        for i in 0..8 {
+            let handle = world
+                .create(
+                    body_shape::CIRCLE,
+                    Vec2::new(i as f32 * 0.3, i as f32),
+                    Vec2::splat(0.5),
+                    0,
+                )
+                .unwrap();
+            world.get_mut(handle).unwrap().velocity = [1.0 - i as f32 * 0.25, 0.0];
+        }
+
+        // Re-simulating from a snapshot gives bit-identical results
+        let snapshot = world;
+        let mut replay = snapshot;
+        for _ in 0..30 {
+            world.step(1.0 / 60.0);
+            replay.step(1.0 / 60.0);
+        }
+        assert_eq!(bytemuck::bytes_of(&world), bytemuck::bytes_of(&replay));
+
+        assert!(world.destroy(3));
+        assert!(!world.destroy(3));
+        assert!(world.get(0).is_none());
+        assert_eq!(world.active_count(), 7);
+        assert_eq!(
+            world.create(body_shape::AABB, Vec2::ZERO, Vec2::ONE, 0),
+            Some(3)
+        );
+    }
+}
@@ -0,0 +1,443 @@
+//! Host-simulated projectiles
+//!
+//! Projectiles move in a straight line at a constant velocity and are swept
+//! against game-supplied target spheres once per `projectile_step()`. Hits are
+//! queued as events the game polls during the same tick.
+//!
+//! The whole pool (projectiles and unread hits) is POD and lives in
+//! ZRollbackState, so stepping is deterministic and re-simulated ticks produce
+//! the same hits.
+
+use bytemuck::{Pod, Zeroable};
+use glam::Vec3;
+
+/// Maximum number of live projectiles
+pub const MAX_PROJECTILES: usize = 256;
+
+/// Maximum number of hit events queued per step (extra hits still apply but
+/// are not reported)
+pub const MAX_PROJECTILE_HITS: usize = 64;
+
+/// `last_target` value of a projectile that hasn't hit anything
+const NO_TARGET: u32 = u32::MAX;
+
+/// Projectile behaviour flags
+pub mod projectile_flags {
+    /// Keep flying through targets until `max_hits` hits
+    pub const PIERCE: u32 = 1 << 0;
+    /// Reflect off targets until `max_hits` hits
+    pub const BOUNCE: u32 = 1 << 1;
+}
+
+/// Projectile spawn parameters, read from WASM memory (48 bytes)
+#[repr(C)]
+#[derive(Clone, Copy, Default, Debug, Pod, Zeroable)]
+pub struct ProjectileDesc {
+    /// Spawn position
+    pub position: [f32; 3],
+    /// Velocity (world units / s)
+    pub velocity: [f32; 3],
+    /// Collision radius
+    pub radius: f32,
+    /// Ticks before the projectile despawns (0 = until it hits)
+    pub lifetime: u32,
+    /// `projectile_flags` bits
+    pub flags: u32,
+    /// Hits before a piercing or bouncing projectile despawns (0 = 1)
+    pub max_hits: u32,
+    /// Target layers this projectile collides with (bitmask)
+    pub target_mask: u32,
+    /// Game-defined value reported with hits
+    pub user_data: u32,
+}
+
+/// A collision target, read from WASM memory (20 bytes)
+#[repr(C)]
+#[derive(Clone, Copy, Default, Debug, Pod, Zeroable)]
+pub struct ProjectileTarget {
+    /// Sphere center
+    pub position: [f32; 3],
+    /// Sphere radius
+    pub radius: f32,
+    /// Layers this target belongs to (bitmask)
+    pub layers: u32,
+}
+
+/// A hit event, written to WASM memory (24 bytes)
+#[repr(C)]
+#[derive(Clone, Copy, Default, Debug, PartialEq, Pod, Zeroable)]
+pub struct ProjectileHit {
+    /// Projectile handle
+    pub projectile: u32,
+    /// Index of the target in the array passed to `projectile_step()`
+    pub target: u32,
+    /// The projectile's `user_data`
+    pub user_data: u32,
+    /// Projectile position at the moment of impact
+    pub position: [f32; 3],
+}
+
+/// A live projectile (52 bytes, inactive when `hits_left` is 0)
+#[repr(C)]
+#[derive(Clone, Copy, Default, Debug, Pod, Zeroable)]
+pub struct Projectile {
+    pub position: [f32; 3],
+    pub velocity: [f32; 3],
+    pub radius: f32,
+    /// Ticks left to live (0 = unlimited)
+    pub ticks_left: u32,
+    pub flags: u32,
+    /// Hits left before despawning
+    pub hits_left: u32,
+    pub target_mask: u32,
+    pub user_data: u32,
+    /// Target hit most recently, which can't be hit again straight away
+    pub last_target: u32,
+}
+
+impl Projectile {
+    /// Whether this slot holds a live projectile
+    #[inline]
+    pub fn is_active(&self) -> bool {
+        self.hits_left != 0
+    }
+}
+
+/// Fixed-size projectile pool and hit queue (rolled back)
+///
+/// Handles are slot index + 1, so 0 is never a valid handle.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct ProjectilePool {
+    slots: [Projectile; MAX_PROJECTILES],
+    hits: [ProjectileHit; MAX_PROJECTILE_HITS],
+    /// Hits queued by the last step
+    hit_count: u32,
+    /// Hits already returned by `next_hit()`
+    hit_read: u32,
+}
+
+impl Default for ProjectilePool {
+    fn default() -> Self {
+        Self::zeroed()
+    }
+}
+
+impl ProjectilePool {
+    /// Spawn a projectile. Returns its handle, or `None` if the pool is full.
+    pub fn spawn(&mut self, desc: &ProjectileDesc) -> Option<u32> {
+        let index = self.slots.iter().position(|p| !p.is_active())?;
+        self.slots[index] = Projectile {
+            position: desc.position,
+            velocity: desc.velocity,
+            radius: desc.radius.max(0.0),
+            ticks_left: desc.lifetime,
+            flags: desc.flags,
+            hits_left: desc.max_hits.max(1),
+            target_mask: desc.target_mask,
+            user_data: desc.user_data,
+            last_target: NO_TARGET,
+        };
+        Some(index as u32 + 1)
+    }
+
+    /// Remove a projectile. Returns `false` if the handle isn't active.
+    pub fn despawn(&mut self, handle: u32) -> bool {
+        let Some(projectile) = self.get_mut(handle) else {
+            return false;
+        };
+        *projectile = Projectile::default();
+        true
+    }
+
+    /// Look up a live projectile by handle
+    pub fn get(&self, handle: u32) -> Option<&Projectile> {
+        let index = (handle as usize).checked_sub(1)?;
+        self.slots.get(index).filter(|p| p.is_active())
+    }
+
+    fn get_mut(&mut self, handle: u32) -> Option<&mut Projectile> {
+        let index = (handle as usize).checked_sub(1)?;
+        self.slots.get_mut(index).filter(|p| p.is_active())
+    }
+
+    /// Live projectiles with their handles, in slot order
+    pub fn iter(&self) -> impl Iterator<Item = (u32, &Projectile)> {
+        self.slots
+            .iter()
+            .enumerate()
+            .filter(|(_, p)| p.is_active())
+            .map(|(i, p)| (i as u32 + 1, p))
+    }
+
+    /// Number of live projectiles
+    pub fn active_count(&self) -> usize {
+        self.slots.iter().filter(|p| p.is_active()).count()
+    }
+
+    /// Advance every projectile by `dt` seconds and collide it with `targets`
+    ///
+    /// Each projectile is swept from its old to its new position and hits the
+    /// first target (in the direction of travel) whose layers match its mask,
+    /// at most once per step. Hits left unread from the previous step are
+    /// discarded.
+    pub fn step(&mut self, dt: f32, targets: &[ProjectileTarget]) {
+        self.hit_count = 0;
+        self.hit_read = 0;
+
+        for index in 0..MAX_PROJECTILES {
+            let projectile = &mut self.slots[index];
+            if !projectile.is_active() {
+                continue;
+            }
+
+            let start = Vec3::from_array(projectile.position);
+            let velocity = Vec3::from_array(projectile.velocity);
+            let end = start + velocity * dt;
+
+            match first_hit(projectile, start, end, targets) {
+                Some((target, t)) => {
+                    let position = start.lerp(end, t);
+                    projectile.hits_left -= 1;
+                    projectile.last_target = target as u32;
+
+                    let event = ProjectileHit {
+                        projectile: index as u32 + 1,
+                        target: target as u32,
+                        user_data: projectile.user_data,
+                        position: position.to_array(),
+                    };
+
+                    if projectile.hits_left == 0 {
+                        *projectile = Projectile::default();
+                    } else if projectile.flags & projectile_flags::BOUNCE != 0 {
+                        let center = Vec3::from_array(targets[target].position);
+                        let normal =
+                            (position - center).normalize_or(-velocity.normalize_or_zero());
+                        projectile.position = position.to_array();
+                        projectile.velocity = velocity.reflect(normal).to_array();
+                    } else if projectile.flags & projectile_flags::PIERCE != 0 {
+                        projectile.position = end.to_array();
+                    } else {
+                        *projectile = Projectile::default();
+                    }
+
+                    if (self.hit_count as usize) < MAX_PROJECTILE_HITS {
+                        self.hits[self.hit_count as usize] = event;
+                        self.hit_count += 1;
+                    }
+                }
+                None => projectile.position = end.to_array(),
+            }
+
+            let projectile = &mut self.slots[index];
+            if projectile.is_active() && projectile.ticks_left != 0 {
+                projectile.ticks_left -= 1;
+                if projectile.ticks_left == 0 {
+                    *projectile = Projectile::default();
+                }
+            }
+        }
+    }
+
+    /// Every hit from the last step, read or not
+    pub fn hits(&self) -> &[ProjectileHit] {
+        &self.hits[..self.hit_count as usize]
+    }
+
+    /// Pop the next unread hit from the last step
+    pub fn next_hit(&mut self) -> Option<ProjectileHit> {
+        if self.hit_read >= self.hit_count {
+            return None;
+        }
+        let hit = self.hits[self.hit_read as usize];
+        self.hit_read += 1;
+        Some(hit)
+    }
+}
+
+/// Earliest target hit while moving from `start` to `end`
+///
+/// Returns the target index and the fraction of the move at impact. Targets
+/// already overlapping at `start` are hit at 0. Ties go to the lower index.
+fn first_hit(
+    projectile: &Projectile,
+    start: Vec3,
+    end: Vec3,
+    targets: &[ProjectileTarget],
+) -> Option<(usize, f32)> {
+    let delta = end - start;
+    let a = delta.length_squared();
+    let mut best: Option<(usize, f32)> = None;
+
+    for (i, target) in targets.iter().enumerate() {
+        if target.layers & projectile.target_mask == 0 || i as u32 == projectile.last_target {
+            continue;
+        }
+
+        let reach = projectile.radius + target.radius;
+        let offset = start - Vec3::from_array(target.position);
+        let c = offset.length_squared() - reach * reach;
+        let t = if c <= 0.0 {
+            0.0
+        } else {
+            // Solve |offset + delta*t| = reach for the entering root
+            let b = offset.dot(delta);
+            let discriminant = b * b - a * c;
+            if a == 0.0 || b >= 0.0 || discriminant < 0.0 {
+                continue;
+            }
+            (-b - discriminant.sqrt()) / a
+        };
+
+        if t <= 1.0 && best.is_none_or(|(_, best_t)| t < best_t) {
+            best = Some((i, t));
+        }
+    }
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bullet(x: f32, vx: f32) -> ProjectileDesc {
+        ProjectileDesc {
+            position: [x, 0.0, 0.0],
+            velocity: [vx, 0.0, 0.0],
+            radius: 0.1,
+            target_mask: 1,
+            user_data: 7,
+            ..Default::default()
+        }
+    }
+
+    fn target(x: f32) -> ProjectileTarget {
+        ProjectileTarget {
+            position: [x, 0.0, 0.0],
+            radius: 0.5,
+            layers: 1,
+        }
+    }
+
+    fn drain(pool: &mut ProjectilePool) -> Vec<ProjectileHit> {
+        std::iter::from_fn(|| pool.next_hit()).collect()
+    }
+
+    #[test]
+    fn test_projectile_pool_size() {
+        assert_eq!(std::mem::size_of::<ProjectileDesc>(), 48);
+        assert_eq!(std::mem::size_of::<ProjectileTarget>(), 20);
+        assert_eq!(std::mem::size_of::<ProjectileHit>(), 24);
+        assert_eq!(std::mem::size_of::<Projectile>(), 52);
+        assert_eq!(
+            std::mem::size_of::<ProjectilePool>(),
+            MAX_PROJECTILES * 52 + MAX_PROJECTILE_HITS * 24 + 8
+        );
+    }
+
+    #[test]
+    fn test_fast_projectile_does_not_tunnel() {
+        let mut pool = ProjectilePool::default();
+        let handle = pool.spawn(&bullet(0.0, 100.0)).unwrap();
+
+        // Moves 10 units in one step, straight through the target
+        pool.step(0.1, &[target(5.0)]);
+        let hits = drain(&mut pool);
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].projectile, handle);
+        assert_eq!(hits[0].target, 0);
+        assert_eq!(hits[0].user_data, 7);
+        assert!((hits[0].position[0] - 4.4).abs() < 1e-4);
+        assert_eq!(pool.active_count(), 0);
+    }
+
+    #[test]
+    fn test_hits_nearest_target_and_respects_mask() {
+        let mut pool = ProjectilePool::default();
+        pool.spawn(&bullet(0.0, 100.0));
+        let wrong_layer = ProjectileTarget {
+            layers: 2,
+            ..target(2.0)
+        };
+
+        pool.step(0.1, &[target(8.0), wrong_layer, target(5.0)]);
+        let hits = drain(&mut pool);
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].target, 2);
+    }
+
+    #[test]
+    fn test_pierce_hits_each_target_once() {
+        let mut pool = ProjectilePool::default();
+        let handle = pool
+            .spawn(&ProjectileDesc {
+                flags: projectile_flags::PIERCE,
+                max_hits: 3,
+                ..bullet(0.0, 10.0)
+            })
+            .unwrap();
+        let targets = [target(2.0), target(4.0)];
+
+        let mut hit_targets = Vec::new();
+        for _ in 0..10 {
+            pool.step(0.1, &targets);
+            hit_targets.extend(drain(&mut pool).iter().map(|h| h.target));
+        }
+        assert_eq!(hit_targets, vec![0, 1]);
+        assert_eq!(pool.get(handle).unwrap().hits_left, 1);
+    }
+
+    #[test]
+    fn test_bounce_reflects_velocity() {
+        let mut pool = ProjectilePool::default();
+        let handle = pool
+            .spawn(&ProjectileDesc {
+                flags: projectile_flags::BOUNCE,
+                max_hits: 2,
+                ..bullet(0.0, 10.0)
+            })
+            .unwrap();
+
+        pool.step(0.5, &[target(3.0)]);
+        assert_eq!(drain(&mut pool).len(), 1);
+        let projectile = pool.get(handle).unwrap();
+        assert!((projectile.position[0] - 2.4).abs() < 1e-4);
+        assert!((projectile.velocity[0] + 10.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_lifetime_and_slot_reuse() {
+        let mut pool = ProjectilePool::default();
+        let a = pool
+            .spawn(&ProjectileDesc {
+                lifetime: 2,
+                ..bullet(0.0, 1.0)
+            })
+            .unwrap();
+        let b = pool.spawn(&bullet(0.0, -1.0)).unwrap();
+
+        pool.step(0.1, &[]);
+        assert!(pool.get(a).is_some());
+        pool.step(0.1, &[]);
+        assert!(pool.get(a).is_none());
+        assert!((pool.get(b).unwrap().position[0] + 0.2).abs() < 1e-5);
+
+        assert!(pool.despawn(b));
+        assert!(!pool.despawn(b));
+        assert!(!pool.despawn(0));
+        assert_eq!(pool.spawn(&bullet(0.0, 0.0)), Some(a));
+    }
+
+    #[test]
+    fn test_unread_hits_are_dropped_on_step() {
+        let mut pool = ProjectilePool::default();
+        pool.spawn(&bullet(0.0, 100.0));
+        pool.spawn(&bullet(0.0, 100.0));
+
+        pool.step(0.1, &[target(5.0)]);
+        assert!(pool.next_hit().is_some());
+        pool.step(0.1, &[target(5.0)]);
+        assert_eq!(pool.next_hit(), None);
+    }
+}
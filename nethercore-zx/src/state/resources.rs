@@ -69,26 +69,91 @@ pub struct Font {
     pub char_width: u8,
     /// Height of each glyph in pixels
     pub char_height: u8,
-    /// First codepoint in the font
-    pub first_codepoint: u32,
-    /// Number of characters in the font
-    pub char_count: u32,
+    /// Codepoint ranges covered by the font, in atlas glyph order
+    pub ranges: Vec<FontRange>,
     /// Optional per-character widths for variable-width fonts (None = fixed-width)
     pub char_widths: Option<Vec<u8>>,
 }
 
+impl Font {
+    /// Total number of glyphs across all ranges
+    pub fn glyph_count(&self) -> u32 {
+        self.ranges.iter().map(|r| r.char_count).sum()
+    }
+
+    /// Atlas glyph index for `codepoint`, or `None` if no range covers it
+    pub fn glyph_index(&self, codepoint: u32) -> Option<usize> {
+        self.ranges.iter().find_map(|range| {
+            let offset = codepoint.checked_sub(range.first_codepoint)?;
+            (offset < range.char_count).then(|| (range.first_glyph + offset) as usize)
+        })
+    }
+
+    /// Width in pixels of the glyph at `glyph_index`
+    pub fn glyph_width(&self, glyph_index: usize) -> u8 {
+        self.char_widths
+            .as_ref()
+            .and_then(|widths| widths.get(glyph_index).copied())
+            .unwrap_or(self.char_width)
+    }
+}
+
+/// Contiguous block of codepoints in a bitmap font
+///
+/// Glyphs for each range follow the previous range's glyphs in the atlas grid,
+/// so a font can cover e.g. ASCII, Latin-1 and Kana from one texture.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FontRange {
+    /// First codepoint in the range
+    pub first_codepoint: u32,
+    /// Number of codepoints in the range
+    pub char_count: u32,
+    /// Atlas glyph index of `first_codepoint`
+    pub first_glyph: u32,
+}
+
 /// Pending texture load request
 ///
-/// Supports both RGBA8 (uncompressed) and BC7 (compressed) texture formats.
+/// Supports RGBA8 (uncompressed) and compressed (BC7, BC5, ETC2, EAC RG11) texture formats.
 #[derive(Debug)]
 pub struct PendingTexture {
     pub handle: u32,
     pub width: u32,
     pub height: u32,
-    /// Texture format (RGBA8, BC7, or BC7Linear)
+    /// Texture format (RGBA8, BC7, BC5, ETC2 or EAC RG11)
+    pub format: TextureFormat,
+    /// Pixel data (RGBA8) or compressed blocks
+    pub data: Vec<u8>,
+    /// ETC2/EAC copy of BC blocks for GPUs without BC support (empty if none)
+    pub etc2_data: Vec<u8>,
+}
+
+/// Save slot thumbnail to upload before the frame that draws it
+///
+/// Thumbnails change while the game runs, so unlike other textures they are
+/// uploaded (and re-uploaded) during the game loop.
+#[derive(Debug)]
+pub struct PendingThumbnail {
+    /// Game texture handle reserved for the slot
+    pub handle: u32,
+    /// RGBA8 pixels (`THUMBNAIL_WIDTH` × `THUMBNAIL_HEIGHT`)
+    pub pixels: Vec<u8>,
+}
+
+/// Pending texture array from `texture_array_create()`
+///
+/// Layers share the size and format of the textures they were built from.
+#[derive(Debug)]
+pub struct PendingTextureArray {
+    pub handle: u32,
+    pub width: u32,
+    pub height: u32,
+    pub layers: u32,
     pub format: TextureFormat,
-    /// Pixel data (RGBA8) or compressed blocks (BC7)
+    /// Every layer's data back to back
     pub data: Vec<u8>,
+    /// Every layer's ETC2/EAC copy back to back (empty unless all layers have one)
+    pub etc2_data: Vec<u8>,
 }
 
 /// Pending mesh load request (unpacked f32 data from user)
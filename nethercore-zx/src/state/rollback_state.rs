@@ -7,7 +7,17 @@
 //! All types are POD (Plain Old Data) using bytemuck for zero-copy serialization.
 
 use bytemuck::{Pod, Zeroable};
+use glam::Vec3;
+use nethercore_core::MAX_PLAYERS;
 use nethercore_core::console::ConsoleRollbackState;
+use nethercore_core::event::{Event, EventQueue};
+use nethercore_core::rollback::ConsoleDataVec;
+
+use super::{
+    ChunkResidency, CursorState, HitboxWorld, PathGrid, PhysicsWorld, ProjectilePool, zx_event_kind,
+};
+use crate::audio::SOURCE_SAMPLE_RATE;
+use crate::console::ZInput;
 
 /// Maximum number of sound effect channels
 pub const MAX_CHANNELS: usize = 16;
@@ -79,11 +89,321 @@ impl ChannelState {
     }
 }
 
-/// Audio playback state (340 bytes total)
+/// Maximum number of audio buses (including the built-in ones)
+pub const MAX_AUDIO_BUSES: usize = 8;
+
+/// Built-in audio bus indices
+pub mod audio_bus {
+    /// Master bus - scales and pauses every other bus
+    pub const MASTER: u32 = 0;
+    /// Music bus - PCM music, tracker and streamed music
+    pub const MUSIC: u32 = 1;
+    /// Sound effect bus - default bus for all channels
+    pub const SFX: u32 = 2;
+    /// Interface bus - menu clicks, cursor moves and other UI feedback
+    pub const UI: u32 = 3;
+    /// Voice bus - dialogue and announcer lines
+    pub const VOICE: u32 = 4;
+    /// Number of built-in buses (custom buses start here)
+    pub const BUILTIN_COUNT: u32 = 5;
+}
+
+/// Audio bus mix state (64 bytes, POD)
+///
+/// Every channel routes through one bus; music, tracker and streams always
+/// route through `audio_bus::MUSIC`. Each bus is a child of the master bus,
+/// so a channel's gain is `channel volume * bus volume * master volume`.
+/// A paused bus holds the playheads of everything routed through it.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct AudioBusState {
+    /// Volume per bus (0.0 to 1.0)
+    pub volume: [f32; MAX_AUDIO_BUSES],
+    /// Bus index per sound effect channel
+    pub channel_bus: [u8; MAX_CHANNELS],
+    /// Paused buses (bit N = bus N)
+    pub paused: u32,
+    /// Reserved for future use (maintains 64-byte size)
+    pub _reserved: [u32; 3],
+}
+
+impl Default for AudioBusState {
+    fn default() -> Self {
+        Self {
+            volume: [1.0; MAX_AUDIO_BUSES],
+            channel_bus: [audio_bus::SFX as u8; MAX_CHANNELS],
+            paused: 0,
+            _reserved: [0; 3],
+        }
+    }
+}
+
+impl AudioBusState {
+    /// Whether `bus` (or the master bus) is paused
+    #[inline]
+    pub fn is_paused(&self, bus: u32) -> bool {
+        let mask = (1 << audio_bus::MASTER) | (1 << (bus as usize % MAX_AUDIO_BUSES));
+        self.paused & mask != 0
+    }
+
+    /// Effective gain of `bus` after the master bus is applied
+    #[inline]
+    pub fn gain(&self, bus: u32) -> f32 {
+        let bus = bus as usize % MAX_AUDIO_BUSES;
+        if bus == audio_bus::MASTER as usize {
+            self.volume[bus]
+        } else {
+            self.volume[bus] * self.volume[audio_bus::MASTER as usize]
+        }
+    }
+
+    /// Bus that sound effect channel `channel` routes through
+    #[inline]
+    pub fn channel_bus(&self, channel: usize) -> u32 {
+        self.channel_bus[channel] as u32
+    }
+
+    /// Copy with each bus volume multiplied by the player's volume for it
+    ///
+    /// Player volumes come from host settings and never enter rollback
+    /// state; they are applied to the copy the mixer reads from.
+    pub fn with_player_volumes(&self, volumes: &[f32; MAX_AUDIO_BUSES]) -> Self {
+        let mut scaled = *self;
+        for (volume, player) in scaled.volume.iter_mut().zip(volumes) {
+            *volume *= player;
+        }
+        scaled
+    }
+}
+
+/// Maximum number of audio listeners (one per split-screen player)
+pub const MAX_LISTENERS: usize = 4;
+
+/// How positional sounds are mixed when several listeners are active
+pub mod listener_policy {
+    /// Each sound is heard by the listener closest to it
+    pub const NEAREST: u32 = 0;
+    /// Each sound is heard by every listener; gains combine and pan is
+    /// weighted toward the listeners that hear it loudest
+    pub const MIX: u32 = 1;
+}
+
+/// One audio listener (24 bytes, POD)
+#[repr(C)]
+#[derive(Clone, Copy, Default, Debug, Pod, Zeroable)]
+pub struct ListenerState {
+    /// World-space position
+    pub position: [f32; 3],
+    /// Facing direction (need not be normalized)
+    pub forward: [f32; 3],
+}
+
+impl ListenerState {
+    /// Gain (0.0 to 1.0) and pan (-1.0 to 1.0) of a sound at `position`
+    ///
+    /// Gain falls off quadratically to silence at `range`. Pan follows the
+    /// sound's direction relative to the listener's right, easing toward
+    /// center within one unit so sounds on top of the listener don't flip
+    /// between ears.
+    pub fn hear(&self, position: Vec3, range: f32) -> (f32, f32) {
+        let offset = position - Vec3::from(self.position);
+        let distance = offset.length();
+        let falloff = (1.0 - distance / range).clamp(0.0, 1.0);
+        let right = Vec3::from(self.forward)
+            .cross(Vec3::Y)
+            .try_normalize()
+            .unwrap_or(Vec3::X);
+        let pan = (right.dot(offset) / distance.max(1.0)).clamp(-1.0, 1.0);
+        (falloff * falloff, pan)
+    }
+}
+
+/// Audio listeners for positional sound (104 bytes, POD)
+///
+/// With no listener set, a default listener at the origin facing -Z is used.
+#[repr(C)]
+#[derive(Clone, Copy, Default, Debug, Pod, Zeroable)]
+pub struct AudioListenerState {
+    /// Listener per player
+    pub listeners: [ListenerState; MAX_LISTENERS],
+    /// Active listeners (bit N = player N)
+    pub active: u32,
+    /// Mixing policy (see listener_policy module)
+    pub policy: u32,
+}
+
+impl AudioListenerState {
+    /// Gain (0.0 to 1.0) and pan (-1.0 to 1.0) of a sound at `position`
+    /// heard through the active listeners
+    pub fn spatialize(&self, position: Vec3, range: f32) -> (f32, f32) {
+        if !range.is_finite() || range <= 0.0 || !position.is_finite() {
+            return (0.0, 0.0);
+        }
+        if self.active == 0 {
+            let default = ListenerState {
+                forward: [0.0, 0.0, -1.0],
+                ..Default::default()
+            };
+            return default.hear(position, range);
+        }
+
+        let active = self
+            .listeners
+            .iter()
+            .enumerate()
+            .filter(|&(i, _)| self.active & (1 << i) != 0)
+            .map(|(_, listener)| listener);
+
+        if self.policy == listener_policy::MIX {
+            // Gains combine like independent sources without exceeding 1.0
+            let mut silence = 1.0;
+            let (mut weighted_pan, mut total_gain) = (0.0, 0.0);
+            for listener in active {
+                let (gain, pan) = listener.hear(position, range);
+                silence *= 1.0 - gain;
+                weighted_pan += gain * pan;
+                total_gain += gain;
+            }
+            let pan = if total_gain > 0.0 {
+                weighted_pan / total_gain
+            } else {
+                0.0
+            };
+            (1.0 - silence, pan)
+        } else {
+            // Ties go to the lowest player index
+            active
+                .min_by(|a, b| {
+                    let da = position.distance_squared(Vec3::from(a.position));
+                    let db = position.distance_squared(Vec3::from(b.position));
+                    da.total_cmp(&db)
+                })
+                .map(|listener| listener.hear(position, range))
+                .unwrap_or((0.0, 0.0))
+        }
+    }
+}
+
+/// Maximum number of music stems that play in lockstep
+pub const MAX_MUSIC_LAYERS: usize = 4;
+
+/// Music stem layers (64 bytes, POD)
+///
+/// Stems share the music channel's playhead, so they can never drift apart.
+/// Layer 0 is the music channel's own sound; the others are mixed at the same
+/// position. Each layer's volume multiplies the music volume and ramps toward
+/// its target by `step` every tick.
+#[repr(C)]
+#[derive(Clone, Copy, Default, Debug, Pod, Zeroable)]
+pub struct MusicLayerState {
+    /// Sound handle per layer (0 = no layer)
+    pub sounds: [u32; MAX_MUSIC_LAYERS],
+    /// Current layer volume (0.0 to 1.0)
+    pub volume: [f32; MAX_MUSIC_LAYERS],
+    /// Volume the layer is fading toward
+    pub target: [f32; MAX_MUSIC_LAYERS],
+    /// Volume change per tick while fading
+    pub step: [f32; MAX_MUSIC_LAYERS],
+}
+
+impl MusicLayerState {
+    /// Whether stems are playing on top of `music`
+    #[inline]
+    pub fn is_active(&self, music: &ChannelState) -> bool {
+        music.sound != 0 && self.sounds[0] == music.sound
+    }
+
+    /// Fade layer `layer` to `volume` over `fade_ticks` ticks (0 = instant)
+    pub fn set_volume(&mut self, layer: usize, volume: f32, fade_ticks: u32) {
+        self.target[layer] = volume;
+        if fade_ticks == 0 {
+            self.volume[layer] = volume;
+            self.step[layer] = 0.0;
+        } else {
+            self.step[layer] = (volume - self.volume[layer]).abs() / fade_ticks as f32;
+        }
+    }
+
+    /// Advance every fade by one tick
+    pub fn step_fades(&mut self) {
+        for i in 0..MAX_MUSIC_LAYERS {
+            let (volume, target) = (self.volume[i], self.target[i]);
+            self.volume[i] = if volume < target {
+                (volume + self.step[i]).min(target)
+            } else {
+                (volume - self.step[i]).max(target)
+            };
+        }
+    }
+}
+
+/// Scheduled channel starts (128 bytes, POD)
+///
+/// A channel started by `play_sound_at()` holds its sound but stays silent for
+/// `ticks` whole audio frames, then for `samples` more source samples, so the
+/// sound starts on an exact sample regardless of when the tick ran. Channels
+/// started any other way have both at zero.
+#[repr(C)]
+#[derive(Clone, Copy, Default, Debug, Pod, Zeroable)]
+pub struct ChannelScheduleState {
+    /// Whole audio frames left to wait
+    pub ticks: [u32; MAX_CHANNELS],
+    /// Source samples left to wait after `ticks` runs out (24.8 fixed-point)
+    pub samples: [u32; MAX_CHANNELS],
+}
+
+impl ChannelScheduleState {
+    /// Whether `channel` is still waiting to start
+    #[inline]
+    pub fn is_waiting(&self, channel: usize) -> bool {
+        self.ticks[channel] != 0 || self.samples[channel] != 0
+    }
+
+    /// Start `channel` `ticks` frames plus `samples` source samples from now
+    #[inline]
+    pub fn set(&mut self, channel: usize, ticks: u32, samples: u32) {
+        self.ticks[channel] = ticks;
+        self.samples[channel] = samples << ChannelState::FRAC_BITS;
+    }
+
+    /// Start `channel` immediately
+    #[inline]
+    pub fn clear(&mut self, channel: usize) {
+        self.set(channel, 0, 0);
+    }
+
+    /// Count down one audio frame of `frame_len` output samples
+    ///
+    /// Returns how many output samples `channel` stays silent this frame
+    /// (`frame_len` while it keeps waiting, 0 once it has started).
+    pub fn begin_frame(&mut self, channel: usize, frame_len: u32, resample_ratio: f32) -> u32 {
+        if self.ticks[channel] > 0 {
+            self.ticks[channel] -= 1;
+            return frame_len;
+        }
+        let waiting = self.samples[channel];
+        if waiting == 0 {
+            return 0;
+        }
+
+        let step = resample_ratio * ChannelState::FRAC_ONE as f32;
+        let frame = (frame_len as f32 * step) as u32;
+        if waiting >= frame {
+            self.samples[channel] = waiting - frame;
+            return frame_len;
+        }
+        self.samples[channel] = 0;
+        ((waiting as f32 / step).round() as u32).min(frame_len)
+    }
+}
+
+/// Audio playback state (700 bytes total)
 ///
-/// Contains the state of all audio channels including the dedicated music channel.
-/// This entire structure is rolled back during netcode rollback, which means
-/// audio playback automatically syncs with game state.
+/// Contains the state of all audio channels including the dedicated music channel,
+/// plus the bus mix they route through, the listeners positional sounds are
+/// heard by, the music stem layers and scheduled channel starts. This entire structure is rolled back
+/// during netcode rollback, which means audio playback automatically syncs with
+/// game state.
 #[repr(C)]
 #[derive(Clone, Copy, Default, Debug, Pod, Zeroable)]
 pub struct AudioPlaybackState {
@@ -91,6 +411,14 @@ pub struct AudioPlaybackState {
     pub channels: [ChannelState; MAX_CHANNELS],
     /// Dedicated music channel
     pub music: ChannelState,
+    /// Bus volumes, pause flags and channel routing
+    pub buses: AudioBusState,
+    /// Listeners for positional sound
+    pub listeners: AudioListenerState,
+    /// Stem layers mixed with the music channel
+    pub music_layers: MusicLayerState,
+    /// Sample-accurate start times for `play_sound_at()`
+    pub schedule: ChannelScheduleState,
 }
 
 /// Tracker playback state flags
@@ -130,26 +458,381 @@ pub struct TrackerState {
     pub flags: u32,
     /// Sample-accurate position within the current tick
     pub tick_sample_pos: u32,
+    /// Rows played since music_play() or music_jump() (drives the beat clock)
+    pub rows_played: u32,
     /// Reserved for future use (maintains 64-byte alignment)
-    /// Using [u32; 10] instead of [u8; 40] because Default is only impl'd for arrays <= 32
-    pub _reserved: [u32; 10],
+    /// Using [u32; 9] instead of [u8; 36] because Default is only impl'd for arrays <= 32
+    pub _reserved: [u32; 9],
 }
 
-/// Nethercore ZX rollback state (404 bytes total)
+/// Beats per bar when `MusicClockState::beats_per_bar` is 0
+pub const DEFAULT_BEATS_PER_BAR: u32 = 4;
+/// Tracker rows per beat when `MusicClockState::rows_per_beat` is 0
+pub const DEFAULT_ROWS_PER_BEAT: u32 = 4;
+
+/// Beat clock for the playing music (16 bytes, POD)
 ///
-/// This is the console-specific state that gets rolled back along with
-/// WASM memory during netcode rollback. It contains audio playback state
-/// so that sounds automatically stay in sync with game state.
+/// Beats are derived from the music playhead rather than counted separately,
+/// so they stay locked to what is heard and roll back with it. Tracker music
+/// has a beat every `rows_per_beat` rows. PCM music carries no tempo, so it
+/// only has beats once `bpm` is set.
+#[repr(C)]
+#[derive(Clone, Copy, Default, Debug, Pod, Zeroable)]
+pub struct MusicClockState {
+    /// Tempo of PCM music (0 = no beats for PCM music)
+    pub bpm: f32,
+    /// Beats per bar (0 = `DEFAULT_BEATS_PER_BAR`)
+    pub beats_per_bar: u32,
+    /// Tracker rows per beat (0 = `DEFAULT_ROWS_PER_BEAT`)
+    pub rows_per_beat: u32,
+    /// One past the last beat queued as an event (0 = none yet)
+    pub next_beat: u32,
+}
+
+impl MusicClockState {
+    /// Beats per bar, with the default applied
+    #[inline]
+    pub fn beats_per_bar(&self) -> u32 {
+        if self.beats_per_bar == 0 {
+            DEFAULT_BEATS_PER_BAR
+        } else {
+            self.beats_per_bar
+        }
+    }
+
+    /// Tracker rows per beat, with the default applied
+    #[inline]
+    pub fn rows_per_beat(&self) -> u32 {
+        if self.rows_per_beat == 0 {
+            DEFAULT_ROWS_PER_BEAT
+        } else {
+            self.rows_per_beat
+        }
+    }
+
+    /// Playhead of the current music in beats, or `None` if it has no beats
+    ///
+    /// Tracker music takes priority over PCM music, like `music_type()`.
+    pub fn position(&self, music: &ChannelState, tracker: &TrackerState) -> Option<f64> {
+        if tracker.handle != 0 && (tracker.flags & tracker_flags::PLAYING) != 0 {
+            // Pattern delays can hold the tick at `speed`; stay inside the row
+            let speed = tracker.speed.max(1);
+            let row_fraction = tracker.tick.min(speed - 1) as f64 / speed as f64;
+            let rows = tracker.rows_played as f64 + row_fraction;
+            return Some(rows / self.rows_per_beat() as f64);
+        }
+        if music.sound != 0 && self.bpm > 0.0 {
+            let samples = music.position as f64 / ChannelState::FRAC_ONE as f64;
+            let seconds = samples / SOURCE_SAMPLE_RATE as f64;
+            return Some(seconds * self.bpm as f64 / 60.0);
+        }
+        None
+    }
+
+    /// Queue a `MUSIC_BEAT` event if the music has reached a beat it hasn't
+    /// reported yet
+    ///
+    /// Looping or jumping back reports the new beat as well.
+    pub fn queue_beat(
+        &mut self,
+        music: &ChannelState,
+        tracker: &TrackerState,
+        events: &mut EventQueue,
+    ) {
+        let Some(position) = self.position(music, tracker) else {
+            self.next_beat = 0;
+            return;
+        };
+        let beat = position as u32;
+        if beat.wrapping_add(1) == self.next_beat {
+            return;
+        }
+        self.next_beat = beat.wrapping_add(1);
+        let beats_per_bar = self.beats_per_bar();
+        events.push(Event::new(
+            zx_event_kind::MUSIC_BEAT,
+            [beat, beat / beats_per_bar, beat % beats_per_bar, 0, 0],
+        ));
+    }
+}
+
+/// Stream playback state flags
+pub mod stream_flags {
+    /// Stream is currently playing
+    pub const PLAYING: u32 = 1 << 0;
+    /// Stream should loop when reaching the end
+    pub const LOOPING: u32 = 1 << 1;
+    /// Stream playback is paused
+    pub const PAUSED: u32 = 1 << 2;
+}
+
+/// Streamed music playback state (32 bytes, POD)
+///
+/// Only the playhead is rolled back. Decoded audio lives in the mixer's
+/// decoder and is rebuilt from the compressed stream on demand, so the
+/// snapshot stays the same size no matter how long the track is.
+///
+/// Position is split into a whole sample index and a 32-bit fraction, giving
+/// sub-sample precision for tracks up to ~54 hours at 22050 Hz.
+#[repr(C)]
+#[derive(Clone, Copy, Default, Debug, Pod, Zeroable)]
+pub struct StreamState {
+    /// Stream handle (0 = no stream)
+    pub handle: u32,
+    /// Playback flags (see stream_flags module)
+    pub flags: u32,
+    /// Playhead position in source samples (integer part)
+    pub sample: u32,
+    /// Playhead position fraction (1/2^32 sample units)
+    pub frac: u32,
+    /// Volume (0.0 to 1.0)
+    pub volume: f32,
+    /// Bumped by `stream_play()`/`stream_seek()` so the audio thread can
+    /// tell deliberate jumps apart from normal playback drift
+    pub cue: u32,
+    /// Reserved for future use (maintains 32-byte size)
+    pub _reserved: [u32; 2],
+}
+
+impl StreamState {
+    /// Playhead as 32.32 fixed-point source samples
+    #[inline]
+    pub fn position(&self) -> u64 {
+        ((self.sample as u64) << 32) | self.frac as u64
+    }
+
+    /// Set the playhead from 32.32 fixed-point source samples
+    #[inline]
+    pub fn set_position(&mut self, position: u64) {
+        self.sample = (position >> 32) as u32;
+        self.frac = position as u32;
+    }
+
+    /// Whether the stream is playing and not paused
+    #[inline]
+    pub fn is_active(&self) -> bool {
+        self.handle != 0
+            && self.flags & stream_flags::PLAYING != 0
+            && self.flags & stream_flags::PAUSED == 0
+    }
+}
+
+/// Maximum team number (teams are 1-based, 0 = no team)
+pub const MAX_TEAMS: u32 = 8;
+
+/// Match state flags
+pub mod match_flags {
+    /// Match has ended (scores are frozen)
+    pub const OVER: u32 = 1 << 0;
+}
+
+/// Team assignment and score tracking for versus games (64 bytes, POD)
+///
+/// Rolled back with the rest of the console state so score changes made in
+/// `update()` stay consistent across rollback.
 #[repr(C)]
 #[derive(Clone, Copy, Default, Debug, Pod, Zeroable)]
+pub struct MatchState {
+    /// Per-player scores
+    pub scores: [i32; MAX_PLAYERS],
+    /// Per-player team (0 = no team, 1..=MAX_TEAMS)
+    pub teams: [u8; MAX_PLAYERS],
+    /// Match flags (see match_flags module)
+    pub flags: u32,
+    /// Reserved for future use (maintains 64-byte size)
+    pub _reserved: [u32; 5],
+}
+
+impl MatchState {
+    /// Whether the match has ended
+    #[inline]
+    pub fn is_over(&self) -> bool {
+        self.flags & match_flags::OVER != 0
+    }
+
+    /// Sum of the scores of every player on `team`
+    pub fn team_score(&self, team: u32) -> i32 {
+        self.teams
+            .iter()
+            .zip(&self.scores)
+            .filter(|&(&t, _)| t as u32 == team)
+            .fold(0i32, |total, (_, &score)| total.wrapping_add(score))
+    }
+
+    /// Player indices sorted for display: by team score, then player score
+    ///
+    /// Players without a team are ranked by their own score. Ties keep
+    /// player order, so the result is deterministic.
+    pub fn standings(&self, player_count: usize) -> Vec<usize> {
+        let count = player_count.min(MAX_PLAYERS);
+        let mut order: Vec<usize> = (0..count).collect();
+        order.sort_by_key(|&p| {
+            let team = self.teams[p] as u32;
+            let group = if team == 0 {
+                self.scores[p]
+            } else {
+                self.team_score(team)
+            };
+            (
+                std::cmp::Reverse(group),
+                team,
+                std::cmp::Reverse(self.scores[p]),
+            )
+        });
+        order
+    }
+}
+
+/// Maximum number of destructible props
+pub const MAX_DESTRUCTIBLES: usize = 32;
+
+/// Health and break state of one destructible prop (20 bytes, POD)
+///
+/// Rolled back so damage applied in `update()` is re-applied exactly once
+/// per re-simulated tick. Debris motion is derived from `broken_at`.
+#[repr(C)]
+#[derive(Clone, Copy, Default, Debug, Pod, Zeroable)]
+pub struct DestructibleState {
+    /// Remaining health (0 once broken)
+    pub health: f32,
+    /// Tick the prop broke at, plus one (0 = intact)
+    pub broken_at: u32,
+    /// Impulse from the breaking hit (local space, world units / s)
+    pub impulse: [f32; 3],
+}
+
+impl DestructibleState {
+    /// Whether the prop has broken into debris
+    #[inline]
+    pub fn is_broken(&self) -> bool {
+        self.broken_at != 0
+    }
+}
+
+/// Nethercore ZX rollback state (4228 bytes, plus pools in use)
+///
+/// This is the console-specific state that gets rolled back along with
+/// WASM memory during netcode rollback. It contains audio playback state
+/// so that sounds automatically stay in sync with game state, plus match
+/// scores so host-managed scoreboards do too.
+///
+/// Large pools that only some games use are allocated on first use, so
+/// games that never touch them don't pay for them in every snapshot.
+#[derive(Clone, Default, Debug)]
 pub struct ZRollbackState {
-    /// Audio playback state (channels + music) - 340 bytes
+    /// Audio playback state (channels + music + buses + listeners + stems + schedule) - 700 bytes
     pub audio: AudioPlaybackState,
     /// Tracker music playback state - 64 bytes
     pub tracker: TrackerState,
+    /// Team and score tracking - 64 bytes
+    pub match_state: MatchState,
+    /// Streamed music playback state - 32 bytes
+    pub stream: StreamState,
+    /// Destructible prop health - 640 bytes
+    pub destructibles: [DestructibleState; MAX_DESTRUCTIBLES],
+    /// Projectiles and pending hit events, once one is spawned - 14856 bytes
+    pub projectiles: Option<Box<ProjectilePool>>,
+    /// Hitbox actors and pending hit/clash events - 2312 bytes
+    pub hitboxes: HitboxWorld,
+    /// Streamed chunk per pool slot - 256 bytes
+    pub chunks: ChunkResidency,
+    /// Music beat clock - 16 bytes
+    pub music_clock: MusicClockState,
+    /// 2D physics bodies, gravity and last step's contacts, once created - 6928 bytes
+    pub physics: Option<Box<PhysicsWorld>>,
+    /// Shared pointer cursors - 144 bytes
+    pub cursor: CursorState,
+    /// Pathfinding cost grid, once `grid_create()` is called - 16392 bytes
+    pub path_grid: Option<Box<PathGrid>>,
 }
 
-impl ConsoleRollbackState for ZRollbackState {}
+impl ConsoleRollbackState for ZRollbackState {
+    fn save(&self, out: &mut ConsoleDataVec) {
+        out.extend_from_slice(bytemuck::bytes_of(&self.audio));
+        out.extend_from_slice(bytemuck::bytes_of(&self.tracker));
+        out.extend_from_slice(bytemuck::bytes_of(&self.match_state));
+        out.extend_from_slice(bytemuck::bytes_of(&self.stream));
+        out.extend_from_slice(bytemuck::cast_slice(&self.destructibles));
+        save_pool(out, self.projectiles.as_deref());
+        out.extend_from_slice(bytemuck::bytes_of(&self.hitboxes));
+        out.extend_from_slice(bytemuck::bytes_of(&self.chunks));
+        out.extend_from_slice(bytemuck::bytes_of(&self.music_clock));
+        save_pool(out, self.physics.as_deref());
+        out.extend_from_slice(bytemuck::bytes_of(&self.cursor));
+        save_pool(out, self.path_grid.as_deref());
+    }
+
+    fn load(&mut self, mut data: &[u8]) -> bool {
+        let data = &mut data;
+        let loaded = read_pod(data, &mut self.audio)
+            && read_pod(data, &mut self.tracker)
+            && read_pod(data, &mut self.match_state)
+            && read_pod(data, &mut self.stream)
+            && read_pod(data, &mut self.destructibles)
+            && load_pool(data, &mut self.projectiles)
+            && read_pod(data, &mut self.hitboxes)
+            && read_pod(data, &mut self.chunks)
+            && read_pod(data, &mut self.music_clock)
+            && load_pool(data, &mut self.physics)
+            && read_pod(data, &mut self.cursor)
+            && load_pool(data, &mut self.path_grid);
+        loaded && data.is_empty()
+    }
+
+    fn begin_tick(&mut self, inputs: &[u8], delta_time: f32) {
+        let inputs: &[ZInput] = bytemuck::cast_slice(inputs);
+        self.cursor.advance(inputs, delta_time);
+    }
+
+    fn queue_events(&mut self, events: &mut EventQueue) {
+        self.music_clock
+            .queue_beat(&self.audio.music, &self.tracker, events);
+    }
+}
+
+/// Append a lazily allocated pool: a presence byte, then its bytes if present
+fn save_pool<T: Pod>(out: &mut ConsoleDataVec, pool: Option<&T>) {
+    match pool {
+        Some(pool) => {
+            out.push(1);
+            out.extend_from_slice(bytemuck::bytes_of(pool));
+        }
+        None => out.push(0),
+    }
+}
+
+/// Read a lazily allocated pool written by `save_pool()`
+///
+/// Reuses the existing allocation when the pool is present in both.
+fn load_pool<T: Pod>(data: &mut &[u8], pool: &mut Option<Box<T>>) -> bool {
+    let Some((&present, rest)) = data.split_first() else {
+        return false;
+    };
+    *data = rest;
+    match present {
+        0 => {
+            *pool = None;
+            true
+        }
+        1 => {
+            let pool = pool.get_or_insert_with(|| Box::new(T::zeroed()));
+            read_pod(data, &mut **pool)
+        }
+        _ => false,
+    }
+}
+
+/// Copy the next `size_of::<T>()` bytes into `value` (any alignment)
+fn read_pod<T: Pod>(data: &mut &[u8], value: &mut T) -> bool {
+    let len = std::mem::size_of::<T>();
+    if data.len() < len {
+        return false;
+    }
+    let (bytes, rest) = data.split_at(len);
+    bytemuck::bytes_of_mut(value).copy_from_slice(bytes);
+    *data = rest;
+    true
+}
 
 #[cfg(test)]
 mod tests {
@@ -162,31 +845,273 @@ mod tests {
 
     #[test]
     fn test_audio_playback_state_size() {
-        // 16 channels * 20 bytes + 1 music channel * 20 bytes = 340 bytes
+        // 16 channels * 20 bytes + 1 music channel * 20 bytes + 64 bytes buses
+        // + 104 bytes listeners + 64 bytes music layers + 128 bytes schedule = 700 bytes
         assert_eq!(
             std::mem::size_of::<AudioPlaybackState>(),
-            MAX_CHANNELS * 20 + 20
+            MAX_CHANNELS * 20 + 20 + 64 + 104 + 64 + 128
         );
     }
 
+    #[test]
+    fn test_channel_schedule_state_size() {
+        assert_eq!(std::mem::size_of::<ChannelScheduleState>(), 128);
+    }
+
+    #[test]
+    fn test_channel_schedule_countdown() {
+        let mut schedule = ChannelScheduleState::default();
+        assert!(!schedule.is_waiting(3));
+
+        // One whole frame, then 400 source samples at half rate = 800 output samples
+        schedule.set(3, 1, 400);
+        assert!(schedule.is_waiting(3));
+        assert_eq!(schedule.begin_frame(3, 735, 0.5), 735);
+        assert_eq!(schedule.begin_frame(3, 735, 0.5), 735);
+        assert_eq!(schedule.begin_frame(3, 735, 0.5), 65);
+        assert!(!schedule.is_waiting(3));
+        assert_eq!(schedule.begin_frame(3, 735, 0.5), 0);
+    }
+
+    #[test]
+    fn test_audio_bus_state_size() {
+        // AudioBusState must be exactly 64 bytes for efficient rollback
+        assert_eq!(std::mem::size_of::<AudioBusState>(), 64);
+    }
+
+    #[test]
+    fn test_audio_bus_player_volumes() {
+        let mut buses = AudioBusState::default();
+        buses.volume[audio_bus::MUSIC as usize] = 0.5;
+
+        let mut player = [1.0; MAX_AUDIO_BUSES];
+        player[audio_bus::MUSIC as usize] = 0.5;
+        player[audio_bus::VOICE as usize] = 0.0;
+        let mixed = buses.with_player_volumes(&player);
+
+        // Player volumes multiply the game's mix without touching routing
+        assert_eq!(mixed.gain(audio_bus::MUSIC), 0.25);
+        assert_eq!(mixed.gain(audio_bus::VOICE), 0.0);
+        assert_eq!(mixed.gain(audio_bus::SFX), 1.0);
+        assert_eq!(mixed.channel_bus, buses.channel_bus);
+        assert_eq!(buses.gain(audio_bus::MUSIC), 0.5);
+    }
+
+    #[test]
+    fn test_music_layer_state_size() {
+        assert_eq!(std::mem::size_of::<MusicLayerState>(), 64);
+    }
+
+    #[test]
+    fn test_music_layer_fade() {
+        let mut layers = MusicLayerState::default();
+        layers.set_volume(1, 1.0, 4);
+        for _ in 0..2 {
+            layers.step_fades();
+        }
+        assert!((layers.volume[1] - 0.5).abs() < 1e-6);
+
+        // Overshoot clamps to the target, then holds
+        for _ in 0..3 {
+            layers.step_fades();
+        }
+        assert_eq!(layers.volume[1], 1.0);
+
+        layers.set_volume(1, 0.25, 0);
+        assert_eq!(layers.volume[1], 0.25);
+        layers.step_fades();
+        assert_eq!(layers.volume[1], 0.25);
+    }
+
     #[test]
     fn test_tracker_state_size() {
         // TrackerState must be exactly 64 bytes for efficient rollback
         assert_eq!(std::mem::size_of::<TrackerState>(), 64);
     }
 
+    #[test]
+    fn test_music_clock_state_size() {
+        assert_eq!(std::mem::size_of::<MusicClockState>(), 16);
+    }
+
+    #[test]
+    fn test_music_clock_tracker_beats() {
+        let clock = MusicClockState::default();
+        let music = ChannelState::default();
+        let mut tracker = TrackerState {
+            handle: 1,
+            flags: tracker_flags::PLAYING,
+            speed: 6,
+            ..Default::default()
+        };
+        assert_eq!(clock.position(&music, &tracker), Some(0.0));
+
+        // 4 rows per beat by default, plus the fraction of the current row
+        tracker.rows_played = 9;
+        tracker.tick = 3;
+        assert_eq!(clock.position(&music, &tracker), Some(2.375));
+
+        tracker.flags = 0;
+        assert_eq!(clock.position(&music, &tracker), None);
+    }
+
+    #[test]
+    fn test_music_clock_pcm_beats() {
+        let mut clock = MusicClockState::default();
+        let tracker = TrackerState::default();
+        // Three seconds in
+        let music = ChannelState {
+            sound: 1,
+            position: 3 * SOURCE_SAMPLE_RATE * ChannelState::FRAC_ONE,
+            ..Default::default()
+        };
+        assert_eq!(clock.position(&music, &tracker), None);
+
+        clock.bpm = 120.0;
+        assert_eq!(clock.position(&music, &tracker), Some(6.0));
+    }
+
+    #[test]
+    fn test_music_clock_queues_each_beat_once() {
+        let mut clock = MusicClockState {
+            beats_per_bar: 3,
+            ..Default::default()
+        };
+        let music = ChannelState::default();
+        let mut tracker = TrackerState {
+            handle: 1,
+            flags: tracker_flags::PLAYING,
+            speed: 6,
+            ..Default::default()
+        };
+        let mut events = EventQueue::default();
+
+        clock.queue_beat(&music, &tracker, &mut events);
+        clock.queue_beat(&music, &tracker, &mut events);
+        tracker.rows_played = 3;
+        clock.queue_beat(&music, &tracker, &mut events);
+        tracker.rows_played = 20;
+        clock.queue_beat(&music, &tracker, &mut events);
+
+        let beat = events.pop().unwrap();
+        assert_eq!(beat.kind, zx_event_kind::MUSIC_BEAT);
+        assert_eq!(beat.data, [0, 0, 0, 0, 0]);
+        // Beat 5 is the third beat of bar 1
+        assert_eq!(events.pop().unwrap().data, [5, 1, 2, 0, 0]);
+        assert!(events.is_empty());
+
+        // Stopping resets the clock so the next song reports beat 0 again
+        tracker.flags = 0;
+        clock.queue_beat(&music, &tracker, &mut events);
+        assert_eq!(clock.next_beat, 0);
+        assert!(events.is_empty());
+    }
+
+    #[test]
+    fn test_match_state_size() {
+        // MatchState must be exactly 64 bytes for efficient rollback
+        assert_eq!(std::mem::size_of::<MatchState>(), 64);
+    }
+
+    #[test]
+    fn test_stream_state_size() {
+        // StreamState must be exactly 32 bytes for efficient rollback
+        assert_eq!(std::mem::size_of::<StreamState>(), 32);
+    }
+
+    #[test]
+    fn test_destructible_state_size() {
+        assert_eq!(std::mem::size_of::<DestructibleState>(), 20);
+    }
+
     #[test]
     fn test_z_rollback_state_size() {
-        // 340 bytes audio + 64 bytes tracker = 404 bytes
-        assert_eq!(std::mem::size_of::<ZRollbackState>(), 404);
+        // 700 bytes audio + 64 bytes tracker + 64 bytes match + 32 bytes stream
+        // + 640 bytes destructibles + 2312 bytes hitboxes + 256 bytes chunks
+        // + 16 bytes music clock + 144 bytes cursors
+        // + 1 byte per lazily allocated pool = 4231 bytes
+        let mut snapshot = ConsoleDataVec::new();
+        ZRollbackState::default().save(&mut snapshot);
+        assert_eq!(snapshot.len(), 4231);
+        assert!(!snapshot.spilled());
+
+        // Pools add their bytes once allocated
+        let state = ZRollbackState {
+            projectiles: Some(Box::default()),
+            physics: Some(Box::default()),
+            path_grid: Some(Box::default()),
+            ..Default::default()
+        };
+        let mut snapshot = ConsoleDataVec::new();
+        state.save(&mut snapshot);
+        assert_eq!(snapshot.len(), 4231 + 14856 + 6928 + 16392);
     }
 
     #[test]
-    fn test_z_rollback_state_is_pod() {
-        // This compiles only if ZRollbackState is Pod
-        let state = ZRollbackState::default();
-        let bytes: &[u8] = bytemuck::bytes_of(&state);
-        assert_eq!(bytes.len(), std::mem::size_of::<ZRollbackState>());
+    fn test_z_rollback_state_save_load() {
+        let mut state = ZRollbackState::default();
+        state.match_state.scores[2] = 7;
+        let mut grid = Box::<PathGrid>::default();
+        grid.create(4, 4);
+        grid.set_cost(1, 2, 0);
+        state.path_grid = Some(grid);
+        let mut with_grid = ConsoleDataVec::new();
+        state.save(&mut with_grid);
+        let mut without_grid = ConsoleDataVec::new();
+        ZRollbackState::default().save(&mut without_grid);
+
+        // Rolling back past grid_create() frees the grid again
+        assert!(state.load(&without_grid));
+        assert!(state.path_grid.is_none());
+        assert_eq!(state.match_state.scores[2], 0);
+
+        let mut restored = ZRollbackState::default();
+        assert!(restored.load(&with_grid));
+        assert_eq!(restored.match_state.scores[2], 7);
+        let grid = restored.path_grid.as_deref().unwrap();
+        assert_eq!((grid.width, grid.height), (4, 4));
+        assert_eq!(grid.cost(1, 2), Some(0));
+
+        // Truncated or padded snapshots are rejected
+        assert!(!restored.load(&with_grid[..with_grid.len() - 1]));
+        let mut padded = with_grid.to_vec();
+        padded.push(0);
+        assert!(!restored.load(&padded));
+    }
+
+    #[test]
+    fn test_stream_position_fixed_point() {
+        let mut stream = StreamState::default();
+        stream.set_position((1_000_000u64 << 32) | (1 << 31));
+        assert_eq!(stream.sample, 1_000_000);
+        assert_eq!(stream.frac, 1 << 31);
+        assert_eq!(stream.position(), (1_000_000u64 << 32) | (1 << 31));
+    }
+
+    #[test]
+    fn test_match_standings() {
+        let state = MatchState {
+            teams: [1, 2, 1, 2, 0, 0, 0, 0],
+            scores: [5, 3, 1, 9, 0, 0, 0, 0],
+            ..Default::default()
+        };
+
+        assert_eq!(state.team_score(1), 6);
+        assert_eq!(state.team_score(2), 12);
+        // Team 2 (12) ahead of team 1 (6), best player first within a team
+        assert_eq!(state.standings(4), vec![3, 1, 0, 2]);
+    }
+
+    #[test]
+    fn test_z_rollback_state_round_trips() {
+        // Saving a loaded snapshot reproduces it byte for byte
+        let mut snapshot = ConsoleDataVec::new();
+        ZRollbackState::default().save(&mut snapshot);
+        let mut state = ZRollbackState::default();
+        assert!(state.load(&snapshot));
+        let mut again = ConsoleDataVec::new();
+        state.save(&mut again);
+        assert_eq!(snapshot, again);
     }
 
     #[test]
@@ -333,4 +1258,63 @@ mod tests {
         assert_eq!(ChannelState::FRAC_ONE, 256);
         assert_eq!(ChannelState::FRAC_MASK, 255);
     }
+
+    #[test]
+    fn test_audio_listener_state_size() {
+        assert_eq!(std::mem::size_of::<AudioListenerState>(), 104);
+    }
+
+    fn listener(x: f32, z: f32) -> ListenerState {
+        ListenerState {
+            position: [x, 0.0, z],
+            forward: [0.0, 0.0, -1.0],
+        }
+    }
+
+    #[test]
+    fn test_listener_gain_and_pan() {
+        let l = listener(0.0, 0.0);
+        let (gain, pan) = l.hear(Vec3::new(5.0, 0.0, 0.0), 10.0);
+        assert!((gain - 0.25).abs() < 1e-6);
+        assert_eq!(pan, 1.0);
+
+        let (gain, pan) = l.hear(Vec3::new(-3.0, 0.0, -4.0), 10.0);
+        assert!((gain - 0.25).abs() < 1e-6);
+        assert!((pan + 0.6).abs() < 1e-6);
+
+        assert_eq!(l.hear(Vec3::new(0.0, 0.0, 20.0), 10.0).0, 0.0);
+        // On top of the listener: full volume, centered
+        assert_eq!(l.hear(Vec3::ZERO, 10.0), (1.0, 0.0));
+    }
+
+    #[test]
+    fn test_spatialize_policies() {
+        let mut state = AudioListenerState::default();
+        // No listeners: default listener at the origin facing -Z
+        assert_eq!(state.spatialize(Vec3::new(-5.0, 0.0, 0.0), 10.0).1, -1.0);
+
+        state.listeners[0] = listener(-20.0, 0.0);
+        state.listeners[1] = listener(20.0, 0.0);
+        state.active = 0b11;
+
+        // Nearest: only player 1 hears a sound just left of them
+        let sound = Vec3::new(15.0, 0.0, 0.0);
+        let (gain, pan) = state.spatialize(sound, 10.0);
+        assert!((gain - 0.25).abs() < 1e-6);
+        assert_eq!(pan, -1.0);
+
+        // Mix: a sound between both players is heard by both
+        state.policy = listener_policy::MIX;
+        let (gain, pan) = state.spatialize(Vec3::ZERO, 40.0);
+        assert!((gain - 0.4375).abs() < 1e-6);
+        assert_eq!(pan, 0.0);
+
+        // Inactive listeners are ignored
+        state.active = 0b10;
+        state.policy = listener_policy::NEAREST;
+        assert_eq!(state.spatialize(Vec3::new(-20.0, 0.0, 0.0), 10.0).0, 0.0);
+
+        assert_eq!(state.spatialize(sound, 0.0), (0.0, 0.0));
+        assert_eq!(state.spatialize(Vec3::NAN, 10.0), (0.0, 0.0));
+    }
 }
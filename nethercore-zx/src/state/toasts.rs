@@ -0,0 +1,173 @@
+//! Host-drawn toast notifications
+//!
+//! Toasts are presentation-only: they live in FFI state, are never rolled
+//! back, and are drawn by the host after the game's `render()`. Up to
+//! [`MAX_VISIBLE_TOASTS`] are shown at once; the rest wait in the queue and
+//! don't start aging until a slot frees up.
+
+use std::collections::VecDeque;
+use std::time::Instant;
+
+/// Maximum number of toasts on screen at once
+pub const MAX_VISIBLE_TOASTS: usize = 4;
+
+/// Maximum number of toasts shown or waiting (further toasts are dropped)
+pub const MAX_QUEUED_TOASTS: usize = 16;
+
+/// Maximum toast text length in bytes
+pub const MAX_TOAST_LEN: usize = 64;
+
+/// Fade-in time in seconds
+pub const TOAST_FADE_IN: f32 = 0.15;
+
+/// Fade-out time in seconds
+pub const TOAST_FADE_OUT: f32 = 0.3;
+
+/// Longest frame step applied to toast ages, so a stall doesn't skip toasts
+const MAX_STEP: f32 = 0.1;
+
+/// A single queued or visible toast
+#[derive(Clone, Debug)]
+pub struct Toast {
+    pub text: String,
+    /// Icon texture handle (0 = none)
+    pub icon: u32,
+    /// Time on screen in seconds, including the fades
+    pub duration: f32,
+    /// Seconds since the toast became visible
+    pub age: f32,
+    /// Tick the toast was raised on (for de-duplicating rollback re-simulation)
+    tick: u64,
+}
+
+impl Toast {
+    /// Opacity from 0.0 to 1.0 for the current age
+    pub fn opacity(&self) -> f32 {
+        let fade_in = self.age / TOAST_FADE_IN;
+        let fade_out = (self.duration - self.age) / TOAST_FADE_OUT;
+        fade_in.min(fade_out).clamp(0.0, 1.0)
+    }
+}
+
+/// Toasts on screen followed by those waiting for a slot
+#[derive(Clone, Debug, Default)]
+pub struct ToastQueue {
+    toasts: VecDeque<Toast>,
+    last_update: Option<Instant>,
+}
+
+impl ToastQueue {
+    /// Queue a toast raised on `tick`
+    ///
+    /// Returns false if the queue is full or an identical toast was already
+    /// raised on the same tick (update() re-running during rollback).
+    pub fn push(&mut self, tick: u64, text: &str, icon: u32, duration: f32) -> bool {
+        if self
+            .toasts
+            .iter()
+            .any(|t| t.tick == tick && t.icon == icon && t.text == text)
+        {
+            return false;
+        }
+        if self.toasts.len() >= MAX_QUEUED_TOASTS {
+            return false;
+        }
+        self.toasts.push_back(Toast {
+            text: text.to_string(),
+            icon,
+            duration: duration.max(TOAST_FADE_IN + TOAST_FADE_OUT),
+            age: 0.0,
+            tick,
+        });
+        true
+    }
+
+    /// Age the visible toasts by `dt` seconds and drop the expired ones
+    pub fn advance(&mut self, dt: f32) {
+        for toast in self.toasts.iter_mut().take(MAX_VISIBLE_TOASTS) {
+            toast.age += dt;
+        }
+        self.toasts.retain(|t| t.age < t.duration);
+    }
+
+    /// Advance by the wall-clock time since the previous update
+    pub fn update(&mut self, now: Instant) {
+        if let Some(last) = self.last_update {
+            let dt = now.saturating_duration_since(last).as_secs_f32();
+            self.advance(dt.min(MAX_STEP));
+        }
+        self.last_update = Some(now);
+    }
+
+    /// Toasts currently on screen, oldest first
+    pub fn visible(&self) -> impl Iterator<Item = &Toast> {
+        self.toasts.iter().take(MAX_VISIBLE_TOASTS)
+    }
+
+    /// Number of toasts shown or waiting
+    pub fn len(&self) -> usize {
+        self.toasts.len()
+    }
+
+    /// True if there are no toasts shown or waiting
+    pub fn is_empty(&self) -> bool {
+        self.toasts.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_toast_fade() {
+        let mut queue = ToastQueue::default();
+        assert!(queue.push(0, "LAP RECORD!", 0, 2.0));
+
+        let opacity = |q: &ToastQueue| q.visible().next().unwrap().opacity();
+        assert_eq!(opacity(&queue), 0.0);
+        queue.advance(TOAST_FADE_IN);
+        assert_eq!(opacity(&queue), 1.0);
+        queue.advance(2.0 - TOAST_FADE_IN - TOAST_FADE_OUT * 0.5);
+        assert!((opacity(&queue) - 0.5).abs() < 1e-4);
+        queue.advance(TOAST_FADE_OUT);
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn test_toast_queueing() {
+        let mut queue = ToastQueue::default();
+        for i in 0..MAX_QUEUED_TOASTS {
+            assert!(queue.push(i as u64, "P2 DOWNED", 0, 1.0));
+        }
+        assert!(!queue.push(99, "P3 DOWNED", 0, 1.0));
+        assert_eq!(queue.visible().count(), MAX_VISIBLE_TOASTS);
+
+        // Waiting toasts don't age until they become visible
+        queue.advance(0.5);
+        assert_eq!(queue.toasts[MAX_VISIBLE_TOASTS].age, 0.0);
+        queue.advance(0.5);
+        assert_eq!(queue.len(), MAX_QUEUED_TOASTS - MAX_VISIBLE_TOASTS);
+        assert!(queue.visible().all(|t| t.age == 0.0));
+    }
+
+    #[test]
+    fn test_toast_rollback_dedupe() {
+        let mut queue = ToastQueue::default();
+        assert!(queue.push(10, "P2 DOWNED", 1, 3.0));
+        assert!(!queue.push(10, "P2 DOWNED", 1, 3.0));
+        assert!(queue.push(10, "P3 DOWNED", 1, 3.0));
+        assert!(queue.push(11, "P2 DOWNED", 1, 3.0));
+        assert_eq!(queue.len(), 3);
+    }
+
+    #[test]
+    fn test_toast_min_duration() {
+        let mut queue = ToastQueue::default();
+        queue.push(0, "GO", 0, 0.0);
+        assert_eq!(
+            queue.visible().next().unwrap().duration,
+            TOAST_FADE_IN + TOAST_FADE_OUT
+        );
+    }
+}
@@ -108,6 +108,7 @@ impl TrackerEngine {
                 }
 
                 state.row += 1;
+                state.rows_played = state.rows_played.wrapping_add(1);
                 self.current_row = state.row;
 
                 let (num_rows, song_length, restart_position) = {
@@ -246,6 +247,7 @@ impl TrackerEngine {
                     }
 
                     state.row += 1;
+                    state.rows_played = state.rows_played.wrapping_add(1);
                     self.current_row = state.row;
 
                     let (num_rows, song_length, restart_position) = {
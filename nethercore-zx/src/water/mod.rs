@@ -0,0 +1,240 @@
+//! Gerstner wave water surfaces
+//!
+//! A water plane is a square grid displaced by a sum of Gerstner waves. Each
+//! wave moves grid points in circles, so crests sharpen and troughs flatten
+//! like real swell instead of the rolling sine hills of a height-only wave.
+//!
+//! The surface is a pure function of time, so it is rebuilt from the tick
+//! count whenever it is drawn and needs no rollback state. `height()` samples
+//! the same surface for buoyancy and is safe to call from `update()`.
+
+#[cfg(test)]
+mod tests;
+
+use std::f32::consts::TAU;
+
+use glam::{Vec2, Vec3};
+
+/// Maximum number of water planes
+pub const MAX_WATER_PLANES: usize = 16;
+
+/// Maximum waves summed per plane
+pub const MAX_WATER_WAVES: u32 = 8;
+
+/// Grid quads along each side of a plane
+pub const WATER_GRID: u32 = 64;
+
+/// Floats per vertex (position, UV, normal)
+pub const WATER_VERTEX_FLOATS: usize = 8;
+
+/// Gravity used for the deep-water dispersion relation (m/s²)
+const GRAVITY: f32 = 9.81;
+
+/// How sharp crests get (0 = sine waves, 1 = crests just touching a cusp)
+const STEEPNESS: f32 = 0.6;
+
+/// Each wave is this fraction of the previous one's length (before jitter)
+const WAVELENGTH_FALLOFF: f32 = 0.6;
+
+/// Waves travel within this angle either side of the wind (radians)
+const DIRECTION_SPREAD: f32 = TAU / 6.0;
+
+/// Shortest wave, in grid cells (shorter waves would alias on the grid)
+const MIN_WAVELENGTH_CELLS: f32 = 4.0;
+
+/// Fixed-point iterations `height()` uses to undo horizontal displacement
+const HEIGHT_ITERATIONS: usize = 4;
+
+/// A single Gerstner wave
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GerstnerWave {
+    /// Unit direction of travel on the XZ plane
+    pub direction: Vec2,
+    /// Angular wavenumber (2π / wavelength)
+    pub wavenumber: f32,
+    /// Crest height above rest
+    pub amplitude: f32,
+    /// Angular frequency (radians per second)
+    pub frequency: f32,
+    /// Horizontal displacement scale (Q in the usual Gerstner notation)
+    pub steepness: f32,
+    /// Phase offset (radians)
+    pub phase: f32,
+}
+
+/// A square water surface centered on the origin at y = 0
+#[derive(Clone, Debug)]
+pub struct WaterPlane {
+    /// Side length in world units
+    pub size: f32,
+    pub waves: Vec<GerstnerWave>,
+    /// Triangle list for the grid (two triangles per quad, CCW from above)
+    pub indices: Vec<u16>,
+}
+
+impl WaterPlane {
+    /// Build a plane with `wave_count` seeded waves whose amplitudes add up
+    /// to `amplitude`
+    ///
+    /// Waves travel roughly along a seeded wind direction, each shorter and
+    /// lower than the last. Returns `None` for a non-positive size, a negative
+    /// amplitude or a wave count outside 1-8.
+    pub fn new(size: f32, wave_count: u32, amplitude: f32, seed: u32) -> Option<Self> {
+        let valid = size.is_finite()
+            && size > 0.0
+            && amplitude.is_finite()
+            && amplitude >= 0.0
+            && (1..=MAX_WATER_WAVES).contains(&wave_count);
+        if !valid {
+            return None;
+        }
+
+        let mut rng = Pcg32::new(seed);
+        let wind = rng.next_unit() * TAU;
+        let min_wavelength = size / WATER_GRID as f32 * MIN_WAVELENGTH_CELLS;
+
+        let mut wavelength = size * 0.5;
+        let mut waves = Vec::with_capacity(wave_count as usize);
+        for _ in 0..wave_count {
+            let length = (wavelength * (0.8 + 0.4 * rng.next_unit())).max(min_wavelength);
+            let angle = wind + (rng.next_unit() * 2.0 - 1.0) * DIRECTION_SPREAD;
+            let wavenumber = TAU / length;
+            waves.push(GerstnerWave {
+                direction: Vec2::from_angle(angle),
+                wavenumber,
+                // Scaled below so the amplitudes add up to `amplitude`
+                amplitude: length,
+                frequency: (GRAVITY * wavenumber).sqrt(),
+                steepness: 0.0,
+                phase: rng.next_unit() * TAU,
+            });
+            wavelength *= WAVELENGTH_FALLOFF;
+        }
+
+        // Keep every wave's height-to-length ratio the same, so short waves
+        // are proportionally lower
+        let total: f32 = waves.iter().map(|w| w.amplitude).sum();
+        let count = waves.len() as f32;
+        for wave in &mut waves {
+            wave.amplitude *= amplitude / total;
+            let wa = wave.wavenumber * wave.amplitude;
+            // Summed over all waves, Q·k·A stays at STEEPNESS so the surface never loops
+            wave.steepness = if wa > 0.0 {
+                STEEPNESS / (wa * count)
+            } else {
+                0.0
+            };
+        }
+
+        Some(Self {
+            size,
+            waves,
+            indices: grid_indices(),
+        })
+    }
+
+    /// Displaced position and normal of the rest point (`x`, `z`) at `time` seconds
+    pub fn displace(&self, x: f32, z: f32, time: f64) -> (Vec3, Vec3) {
+        let mut position = Vec3::new(x, 0.0, z);
+        let mut normal = Vec3::Y;
+        for wave in &self.waves {
+            let (sin, cos) = wave.angle(Vec2::new(x, z), time).sin_cos();
+            let wa = wave.wavenumber * wave.amplitude;
+            let qa = wave.steepness * wave.amplitude;
+
+            position.x += qa * wave.direction.x * cos;
+            position.y += wave.amplitude * sin;
+            position.z += qa * wave.direction.y * cos;
+
+            normal.x -= wave.direction.x * wa * cos;
+            normal.y -= wave.steepness * wa * sin;
+            normal.z -= wave.direction.y * wa * cos;
+        }
+        (position, normal.normalize_or(Vec3::Y))
+    }
+
+    /// Surface height at (`x`, `z`) at `time` seconds, in plane space
+    ///
+    /// Waves push points sideways, so this searches for the rest point that
+    /// ends up above (`x`, `z`) before reading its height.
+    pub fn height(&self, x: f32, z: f32, time: f64) -> f32 {
+        let target = Vec2::new(x, z);
+        let mut rest = target;
+        for _ in 0..HEIGHT_ITERATIONS {
+            let (position, _) = self.displace(rest.x, rest.y, time);
+            rest += target - Vec2::new(position.x, position.z);
+        }
+        self.displace(rest.x, rest.y, time).0.y
+    }
+
+    /// Write the grid's vertices at `time` seconds into `out`
+    /// (position, UV and normal per vertex, UVs 0-1 across the plane)
+    pub fn build_vertices(&self, time: f64, out: &mut Vec<f32>) {
+        let side = WATER_GRID + 1;
+        out.clear();
+        out.reserve((side * side) as usize * WATER_VERTEX_FLOATS);
+        for row in 0..side {
+            for col in 0..side {
+                let u = col as f32 / WATER_GRID as f32;
+                let v = row as f32 / WATER_GRID as f32;
+                let x = (u - 0.5) * self.size;
+                let z = (v - 0.5) * self.size;
+                let (position, normal) = self.displace(x, z, time);
+                out.extend_from_slice(&position.to_array());
+                out.extend_from_slice(&[u, v]);
+                out.extend_from_slice(&normal.to_array());
+            }
+        }
+    }
+}
+
+impl GerstnerWave {
+    /// Wave phase at rest point `point` (kept in 0..2π so long sessions stay precise)
+    #[inline]
+    fn angle(&self, point: Vec2, time: f64) -> f32 {
+        let travel = (self.frequency as f64 * time).rem_euclid(std::f64::consts::TAU) as f32;
+        self.wavenumber * self.direction.dot(point) - travel + self.phase
+    }
+}
+
+/// Triangle list for a `WATER_GRID` × `WATER_GRID` grid of quads
+fn grid_indices() -> Vec<u16> {
+    let side = WATER_GRID + 1;
+    let mut indices = Vec::with_capacity((WATER_GRID * WATER_GRID * 6) as usize);
+    for row in 0..WATER_GRID {
+        for col in 0..WATER_GRID {
+            let i0 = (row * side + col) as u16;
+            let i1 = i0 + 1;
+            let i2 = i0 + side as u16;
+            let i3 = i2 + 1;
+            indices.extend_from_slice(&[i0, i2, i1, i1, i2, i3]);
+        }
+    }
+    indices
+}
+
+/// PCG32 generator for seeding waves
+struct Pcg32(u64);
+
+impl Pcg32 {
+    fn new(seed: u32) -> Self {
+        let mut rng = Self(seed as u64);
+        rng.next_u32();
+        rng
+    }
+
+    fn next_u32(&mut self) -> u32 {
+        let old = self.0;
+        self.0 = old
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        let xorshifted = (((old >> 18) ^ old) >> 27) as u32;
+        let rot = (old >> 59) as u32;
+        xorshifted.rotate_right(rot)
+    }
+
+    /// Uniform float in [0, 1)
+    fn next_unit(&mut self) -> f32 {
+        (self.next_u32() >> 8) as f32 / (1u32 << 24) as f32
+    }
+}
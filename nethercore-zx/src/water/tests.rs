@@ -0,0 +1,104 @@
+//! Tests for Gerstner water surfaces
+
+use super::*;
+
+#[test]
+fn test_new_rejects_invalid_parameters() {
+    assert!(WaterPlane::new(0.0, 4, 1.0, 1).is_none());
+    assert!(WaterPlane::new(f32::NAN, 4, 1.0, 1).is_none());
+    assert!(WaterPlane::new(100.0, 0, 1.0, 1).is_none());
+    assert!(WaterPlane::new(100.0, MAX_WATER_WAVES + 1, 1.0, 1).is_none());
+    assert!(WaterPlane::new(100.0, 4, -1.0, 1).is_none());
+}
+
+#[test]
+fn test_waves_are_seeded() {
+    let a = WaterPlane::new(100.0, 4, 1.0, 7).unwrap();
+    let b = WaterPlane::new(100.0, 4, 1.0, 7).unwrap();
+    let c = WaterPlane::new(100.0, 4, 1.0, 8).unwrap();
+    assert_eq!(a.waves, b.waves);
+    assert_ne!(a.waves, c.waves);
+}
+
+#[test]
+fn test_amplitudes_sum_and_surface_never_loops() {
+    let plane = WaterPlane::new(100.0, 6, 2.0, 3).unwrap();
+    let total: f32 = plane.waves.iter().map(|w| w.amplitude).sum();
+    assert!((total - 2.0).abs() < 1e-4);
+
+    let sharpness: f32 = plane
+        .waves
+        .iter()
+        .map(|w| w.steepness * w.wavenumber * w.amplitude)
+        .sum();
+    assert!(sharpness <= 1.0);
+}
+
+#[test]
+fn test_flat_water_stays_at_rest() {
+    let plane = WaterPlane::new(50.0, 3, 0.0, 1).unwrap();
+    let (position, normal) = plane.displace(3.0, -2.0, 12.5);
+    assert_eq!(position, Vec3::new(3.0, 0.0, -2.0));
+    assert_eq!(normal, Vec3::Y);
+    assert_eq!(plane.height(3.0, -2.0, 12.5), 0.0);
+}
+
+#[test]
+fn test_surface_moves_over_time() {
+    let plane = WaterPlane::new(100.0, 4, 1.0, 11).unwrap();
+    let (a, _) = plane.displace(5.0, 5.0, 0.0);
+    let (b, _) = plane.displace(5.0, 5.0, 0.5);
+    assert_ne!(a, b);
+    assert!(a.y.abs() <= 1.0 + 1e-4);
+}
+
+#[test]
+fn test_height_matches_displaced_vertex() {
+    let plane = WaterPlane::new(100.0, 4, 1.5, 5).unwrap();
+    let time = 3.25;
+    let (vertex, _) = plane.displace(10.0, -4.0, time);
+    let height = plane.height(vertex.x, vertex.z, time);
+    assert!(
+        (height - vertex.y).abs() < 0.01,
+        "{} vs {}",
+        height,
+        vertex.y
+    );
+}
+
+#[test]
+fn test_normal_follows_slope() {
+    let plane = WaterPlane::new(100.0, 1, 1.0, 2).unwrap();
+    let wave = plane.waves[0];
+    // Where the wave's phase is 0 the surface is rising fastest, so it
+    // tilts back against the direction of travel
+    let point = wave.direction * (-wave.phase / wave.wavenumber);
+    let (_, normal) = plane.displace(point.x, point.y, 0.0);
+    assert!(normal.is_normalized());
+    assert!(Vec2::new(normal.x, normal.z).dot(wave.direction) < 0.0);
+}
+
+#[test]
+fn test_build_vertices_grid() {
+    let plane = WaterPlane::new(20.0, 2, 0.5, 9).unwrap();
+    let mut vertices = Vec::new();
+    plane.build_vertices(1.0, &mut vertices);
+
+    let side = (WATER_GRID + 1) as usize;
+    assert_eq!(vertices.len(), side * side * WATER_VERTEX_FLOATS);
+    assert_eq!(plane.indices.len(), (WATER_GRID * WATER_GRID * 6) as usize);
+    assert!(plane.indices.iter().all(|&i| (i as usize) < side * side));
+
+    // Corner UVs span the plane
+    assert_eq!(&vertices[3..5], &[0.0, 0.0]);
+    let last = (side * side - 1) * WATER_VERTEX_FLOATS;
+    assert_eq!(&vertices[last + 3..last + 5], &[1.0, 1.0]);
+
+    // First triangle faces up
+    let p = |i: u16| {
+        let o = i as usize * WATER_VERTEX_FLOATS;
+        Vec3::new(20.0 * vertices[o + 3], 0.0, 20.0 * vertices[o + 4])
+    };
+    let [a, b, c] = [plane.indices[0], plane.indices[1], plane.indices[2]];
+    assert!((p(b) - p(a)).cross(p(c) - p(a)).y > 0.0);
+}
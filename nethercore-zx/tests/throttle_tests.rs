@@ -0,0 +1,126 @@
+//! Tests for the guest-side update throttling helper (`include/zx/throttle.rs`).
+
+#[path = "../../include/zx/throttle.rs"]
+mod throttle;
+
+use throttle::{UPDATE_BUCKET_COUNT, UpdateThrottle};
+
+const THROTTLE: UpdateThrottle = UpdateThrottle::new(10.0, 30.0);
+
+#[test]
+fn test_bucket_edges_are_inclusive() {
+    let viewers = [[0.0, 0.0, 0.0]];
+    assert_eq!(THROTTLE.bucket([10.0, 0.0, 0.0], &viewers), 0);
+    assert_eq!(THROTTLE.bucket([10.01, 0.0, 0.0], &viewers), 1);
+    assert_eq!(THROTTLE.bucket([0.0, 30.0, 0.0], &viewers), 1);
+    assert_eq!(THROTTLE.bucket([0.0, 0.0, 30.01], &viewers), 2);
+}
+
+#[test]
+fn test_bucket_uses_nearest_viewer() {
+    let viewers = [[0.0, 0.0, 0.0], [100.0, 0.0, 0.0]];
+    assert_eq!(THROTTLE.bucket([95.0, 0.0, 0.0], &viewers), 0);
+    assert_eq!(THROTTLE.bucket([50.0, 0.0, 0.0], &viewers), 2);
+    // No viewers: everything runs at full rate
+    assert_eq!(THROTTLE.bucket([1000.0, 0.0, 0.0], &[]), 0);
+}
+
+#[test]
+fn test_partition_counts_and_ignores_extra_entries() {
+    let viewers = [[0.0, 0.0, 0.0]];
+    let positions = [
+        [0.0, 0.0, 0.0],
+        [10.0, 0.0, 0.0],
+        [20.0, 0.0, 0.0],
+        [30.0, 0.0, 0.0],
+        [40.0, 0.0, 0.0],
+    ];
+
+    let mut buckets = [0xFF; 7];
+    let counts = THROTTLE.partition(&positions, &viewers, &mut buckets);
+    assert_eq!(counts, [2, 2, 1]);
+    assert_eq!(buckets, [0, 0, 1, 1, 2, 0xFF, 0xFF]);
+
+    // A short bucket slice only partitions the entities it has room for
+    let mut buckets = [0xFF; 3];
+    let counts = THROTTLE.partition(&positions, &viewers, &mut buckets);
+    assert_eq!(counts, [2, 1, 0]);
+    assert_eq!(buckets, [0, 0, 1]);
+
+    let counts = THROTTLE.partition(&[], &viewers, &mut buckets);
+    assert_eq!(counts, [0; UPDATE_BUCKET_COUNT]);
+}
+
+#[test]
+fn test_interval_is_never_zero() {
+    assert_eq!(UpdateThrottle::interval(0), 1);
+    assert_eq!(UpdateThrottle::interval(1), 2);
+    assert_eq!(UpdateThrottle::interval(2), 4);
+    // Out-of-range buckets fall back to the slowest rate
+    assert_eq!(UpdateThrottle::interval(3), 4);
+    assert_eq!(UpdateThrottle::interval(u8::MAX), 4);
+}
+
+#[test]
+fn test_period_one_is_always_due() {
+    for tick in [0, 1, 2, 3, u64::MAX - 1, u64::MAX] {
+        for index in [0, 1, 7, u32::MAX] {
+            assert!(UpdateThrottle::is_due(0, index, tick));
+        }
+    }
+}
+
+#[test]
+fn test_is_due_staggers_by_index() {
+    // Bucket 1 alternates between even and odd indices
+    assert!(UpdateThrottle::is_due(1, 0, 0));
+    assert!(!UpdateThrottle::is_due(1, 1, 0));
+    assert!(!UpdateThrottle::is_due(1, 0, 1));
+    assert!(UpdateThrottle::is_due(1, 1, 1));
+
+    // Each tick runs exactly a quarter of bucket 2
+    for tick in 0..8 {
+        let due = (0..8)
+            .filter(|&i| UpdateThrottle::is_due(2, i, tick))
+            .count();
+        assert_eq!(due, 2);
+    }
+}
+
+#[test]
+fn test_is_due_keeps_cadence_across_tick_wraparound() {
+    let ticks = [
+        u64::MAX - 3,
+        u64::MAX - 2,
+        u64::MAX - 1,
+        u64::MAX,
+        0,
+        1,
+        2,
+        3,
+    ];
+    for bucket in 0..UPDATE_BUCKET_COUNT as u8 {
+        let interval = UpdateThrottle::interval(bucket) as usize;
+        for index in [0, 1, 2, 3, u32::MAX] {
+            let due: Vec<usize> = ticks
+                .iter()
+                .enumerate()
+                .filter(|&(_, &tick)| UpdateThrottle::is_due(bucket, index, tick))
+                .map(|(n, _)| n)
+                .collect();
+            assert_eq!(due.len(), ticks.len() / interval);
+            assert!(due.windows(2).all(|w| w[1] - w[0] == interval));
+        }
+    }
+}
+
+#[test]
+fn test_due_yields_index_and_interval() {
+    let buckets = [0, 1, 2, 1, 2, 2, 2];
+    let due: Vec<_> = UpdateThrottle::due(&buckets, 0).collect();
+    assert_eq!(due, [(0, 1), (4, 4)]);
+    let due: Vec<_> = UpdateThrottle::due(&buckets, 1).collect();
+    assert_eq!(due, [(0, 1), (1, 2), (3, 2)]);
+    let due: Vec<_> = UpdateThrottle::due(&buckets, 3).collect();
+    assert_eq!(due, [(0, 1), (1, 2), (3, 2), (5, 4)]);
+}
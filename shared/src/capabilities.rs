@@ -0,0 +1,91 @@
+//! Host capabilities a ROM must declare before it can use them.
+//!
+//! ROMs list the capabilities they need in the `[capabilities]` section of
+//! `nether.toml`, and `nether pack` bakes them into the ROM metadata. The host
+//! refuses anything that wasn't declared, so an untrusted ROM can't silently
+//! write to disk or open a network session.
+
+use bitcode::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+
+/// Capabilities declared by a ROM.
+///
+/// Everything defaults to `false` (denied).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Encode, Decode, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Capabilities {
+    /// Persist save slots and unlock tokens to disk.
+    ///
+    /// Without it, `save()` still works but data only lives until the game
+    /// is closed.
+    pub saves: bool,
+
+    /// Join or host online sessions.
+    pub network: bool,
+
+    /// Capture player media (microphone input for voice chat).
+    pub capture: bool,
+}
+
+impl Capabilities {
+    /// Every capability granted.
+    ///
+    /// Used for raw `.wasm` files run during development, which have no
+    /// metadata to declare anything.
+    pub const ALL: Self = Self {
+        saves: true,
+        network: true,
+        capture: true,
+    };
+
+    /// No capability granted.
+    pub const NONE: Self = Self {
+        saves: false,
+        network: false,
+        capture: false,
+    };
+
+    /// Names of the granted capabilities, in declaration order.
+    pub fn names(&self) -> Vec<&'static str> {
+        [
+            (self.saves, "saves"),
+            (self.network, "network"),
+            (self.capture, "capture"),
+        ]
+        .into_iter()
+        .filter_map(|(granted, name)| granted.then_some(name))
+        .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_denies_everything() {
+        assert_eq!(Capabilities::default(), Capabilities::NONE);
+        assert!(Capabilities::default().names().is_empty());
+    }
+
+    #[test]
+    fn test_names() {
+        let caps = Capabilities {
+            saves: true,
+            capture: true,
+            ..Default::default()
+        };
+        assert_eq!(caps.names(), vec!["saves", "capture"]);
+        assert_eq!(Capabilities::ALL.names().len(), 3);
+    }
+
+    #[test]
+    fn test_bitcode_roundtrip() {
+        let caps = Capabilities {
+            network: true,
+            ..Default::default()
+        };
+        let decoded: Capabilities = bitcode::decode(&bitcode::encode(&caps)).unwrap();
+        assert_eq!(decoded, caps);
+    }
+}
@@ -1,5 +1,7 @@
 //! Filesystem helpers shared across Nethercore tools and runtimes.
 
+use std::fs::File;
+use std::io::Read;
 use std::path::Path;
 
 use anyhow::{Context, Result};
@@ -12,10 +14,27 @@ pub const MAX_WASM_BYTES: u64 = 128 * 1024 * 1024; // 128 MiB
 pub const MAX_PNG_BYTES: u64 = 32 * 1024 * 1024; // 32 MiB
 
 /// Read a file into memory with a size cap.
+///
+/// The size is checked on the opened file and the read itself is capped, so a
+/// file swapped or grown after the check can't exceed the limit. Callers get
+/// an owned copy that other processes can't change underneath them.
 pub fn read_file_with_limit(path: &Path, max_bytes: u64) -> Result<Vec<u8>> {
-    let metadata = std::fs::metadata(path)
+    let file =
+        File::open(path).with_context(|| format!("Failed to open file: {}", path.display()))?;
+    let metadata = file
+        .metadata()
         .with_context(|| format!("Failed to read file metadata: {}", path.display()))?;
-    let len = metadata.len();
+    check_file_size(path, metadata.len(), max_bytes)?;
+
+    let mut bytes = Vec::with_capacity(metadata.len() as usize);
+    file.take(max_bytes + 1)
+        .read_to_end(&mut bytes)
+        .with_context(|| format!("Failed to read file: {}", path.display()))?;
+    check_file_size(path, bytes.len() as u64, max_bytes)?;
+    Ok(bytes)
+}
+
+fn check_file_size(path: &Path, len: u64, max_bytes: u64) -> Result<()> {
     if len > max_bytes {
         anyhow::bail!(
             "File too large: {} ({} bytes, max {} bytes)",
@@ -24,5 +43,5 @@ pub fn read_file_with_limit(path: &Path, max_bytes: u64) -> Result<Vec<u8>> {
             max_bytes
         );
     }
-    std::fs::read(path).with_context(|| format!("Failed to read file: {}", path.display()))
+    Ok(())
 }
@@ -12,6 +12,8 @@
 //! - **API Response Types**: [`Game`], [`Author`], [`GamesResponse`], [`RomUrlResponse`], [`VersionResponse`]
 //! - **Auth Types**: [`User`], [`AuthResponse`], [`ApiError`]
 //! - **Local Types**: [`LocalGameManifest`] for cached game metadata
+//! - **ROM Types**: [`Capabilities`] declared by ROMs and enforced by the host,
+//!   [`RomPatch`] delta updates between ROM versions
 //! - **Request Types**: [`RegisterRequest`], [`LoginRequest`], [`CreateGameRequest`], etc.
 //! - **Math Types**: [`BoneMatrix3x4`] for skeletal animation
 //!
@@ -29,6 +31,7 @@
 // Module declarations
 pub mod api;
 pub mod auth;
+pub mod capabilities;
 pub mod console;
 pub mod constants;
 pub mod fs;
@@ -38,11 +41,13 @@ pub mod math;
 pub mod netplay;
 pub mod requests;
 pub mod rom_format;
+pub mod rom_patch;
 pub mod screenshot;
 
 // Re-export public items explicitly for clarity
 pub use api::{Author, Game, GamesResponse, RomUrlResponse, VersionResponse};
 pub use auth::{ApiError, AuthResponse, LinkCodeResponse, User, error_codes};
+pub use capabilities::Capabilities;
 pub use console::{
     CONSOLES, ConsoleSpecs, ConsoleType, NETHERCORE_CHROMA_MEMORY_LIMIT,
     NETHERCORE_CHROMA_RESOLUTION, NETHERCORE_CHROMA_VRAM_LIMIT, NETHERCORE_ZX_RAM_LIMIT,
@@ -51,11 +56,15 @@ pub use console::{
     nethercore_chroma_specs, nethercore_zx_specs,
 };
 pub use constants::{BEARER_PREFIX, LOCAL_DEV_BASE_URL, LOCAL_FRONTEND_URL, PRODUCTION_URL};
-pub use fs::{MAX_PNG_BYTES, MAX_ROM_BYTES, MAX_WASM_BYTES, read_file_with_limit};
+pub use fs::{
+    MAX_PNG_BYTES, MAX_ROM_BYTES, MAX_WASM_BYTES, read_file_with_limit,
+};
 pub use ids::is_safe_game_id;
 pub use local::LocalGameManifest;
 pub use math::BoneMatrix3x4;
-pub use netplay::{NetplayMetadata, NetplayMismatch};
+pub use netplay::{
+    NetplayMetadata, NetplayMismatch, netplay_rom_hash, rom_content_hash, rom_hash_hex,
+};
 pub use requests::{
     CreateGameRequest, CreateGameResponse, LoginRequest, RegisterRequest, SuccessResponse,
     UpdateGameRequest, UploadUrls,
@@ -64,6 +73,7 @@ pub use rom_format::{
     ROM_FORMATS, RomFormat, ZX_ROM_FORMAT, get_console_type_by_extension,
     get_rom_format_by_console, get_rom_format_by_console_type, get_rom_format_by_extension,
 };
+pub use rom_patch::{ROM_PATCH_EXTENSION, ROM_PATCH_MAGIC, ROM_PATCH_VERSION, RomPatch};
 pub use screenshot::{
     SCREENSHOT_SIGNATURE_KEYWORD, ScreenshotPayload, ScreenshotSignError, SignedScreenshot,
     compute_pixel_hash, sign_screenshot, verify_screenshot,
@@ -3,6 +3,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::ZX_ROM_FORMAT;
+use crate::netplay::rom_hash_hex;
 
 /// Default console type for backward compatibility with old manifests.
 fn default_console_type() -> String {
@@ -30,4 +31,81 @@ pub struct LocalGameManifest {
     /// Defaults to "zx" for backward compatibility with old manifests.
     #[serde(default = "default_console_type")]
     pub console_type: String,
+    /// Platform author ID (UUID) the library recorded when it downloaded the ROM.
+    ///
+    /// Unlike `author`, this comes from the registry rather than the ROM itself,
+    /// so it can key per-publisher data such as unlock tokens.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub registry_author_id: Option<String>,
+    /// SHA-256 (hex) of the ROM file `registry_author_id` was recorded for.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub registry_rom_sha256: Option<String>,
+}
+
+impl LocalGameManifest {
+    /// Registry author ID, if it was recorded for exactly this ROM build
+    ///
+    /// `rom_hash` is the content hash of the ROM being loaded. Sideloaded,
+    /// patched or replaced ROMs don't match and have no verified author.
+    pub fn verified_author_id(&self, rom_hash: &[u8; 32]) -> Option<&str> {
+        let recorded = self.registry_rom_sha256.as_deref()?;
+        let author_id = self.registry_author_id.as_deref()?;
+        (!author_id.is_empty() && recorded.eq_ignore_ascii_case(&rom_hash_hex(rom_hash)))
+            .then_some(author_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::netplay::rom_content_hash;
+
+    fn manifest(author_id: Option<&str>, rom_sha256: Option<String>) -> LocalGameManifest {
+        LocalGameManifest {
+            id: "game".to_string(),
+            title: "Game".to_string(),
+            author: "someone".to_string(),
+            version: "1.0.0".to_string(),
+            downloaded_at: "2026-01-26T00:00:00Z".to_string(),
+            console_type: "zx".to_string(),
+            registry_author_id: author_id.map(str::to_string),
+            registry_rom_sha256: rom_sha256,
+        }
+    }
+
+    #[test]
+    fn test_verified_author_id_requires_matching_rom() {
+        let hash = rom_content_hash(b"rom");
+        let recorded = manifest(Some("author-uuid"), Some(rom_hash_hex(&hash)));
+        assert_eq!(recorded.verified_author_id(&hash), Some("author-uuid"));
+        assert_eq!(
+            recorded.verified_author_id(&rom_content_hash(b"patched rom")),
+            None
+        );
+    }
+
+    #[test]
+    fn test_verified_author_id_missing_fields() {
+        let hash = rom_content_hash(b"rom");
+        assert_eq!(
+            manifest(None, Some(rom_hash_hex(&hash))).verified_author_id(&hash),
+            None
+        );
+        assert_eq!(
+            manifest(Some("author-uuid"), None).verified_author_id(&hash),
+            None
+        );
+        assert_eq!(
+            manifest(Some(""), Some(rom_hash_hex(&hash))).verified_author_id(&hash),
+            None
+        );
+    }
+
+    #[test]
+    fn test_old_manifest_has_no_registry_author() {
+        let json = r#"{"id":"game","title":"Game","author":"someone","version":"1.0.0","downloaded_at":"2026-01-26T00:00:00Z"}"#;
+        let manifest: LocalGameManifest = serde_json::from_str(json).unwrap();
+        assert_eq!(manifest.registry_author_id, None);
+        assert_eq!(manifest.registry_rom_sha256, None);
+    }
 }
@@ -4,6 +4,7 @@
 //! game compatibility and establish multiplayer sessions.
 
 use bitcode::{Decode, Encode};
+use sha2::{Digest, Sha256};
 
 use crate::console::{ConsoleType, TickRate};
 
@@ -26,7 +27,7 @@ pub struct NetplayMetadata {
     /// Declared in nether.toml and baked into ROM.
     pub tick_rate: TickRate,
 
-    /// Maximum players supported (1-4)
+    /// Maximum players supported (1-8)
     ///
     /// Games with max_players >= 2 support netplay.
     /// Games with max_players == 1 are single-player only.
@@ -35,7 +36,9 @@ pub struct NetplayMetadata {
     /// xxHash3 of the WASM bytecode section
     ///
     /// Used by NCHS to ensure all players have identical game code.
-    /// Computed during `nether pack`.
+    /// Computed during `nether pack`. Hosts replace it with
+    /// [`netplay_rom_hash`] of the whole ROM when loading, so asset changes
+    /// are caught too.
     pub rom_hash: u64,
 }
 
@@ -104,6 +107,24 @@ impl NetplayMetadata {
     }
 }
 
+/// Content hash of a ROM build (SHA-256 of the ROM file bytes)
+///
+/// Any change to code, assets or metadata changes the hash, so it identifies
+/// an exact build. Games can read it with `rom_hash()`.
+pub fn rom_content_hash(rom_bytes: &[u8]) -> [u8; 32] {
+    Sha256::digest(rom_bytes).into()
+}
+
+/// Lowercase hex form of a content hash, as recorded in `manifest.json`
+pub fn rom_hash_hex(content_hash: &[u8; 32]) -> String {
+    hex::encode(content_hash)
+}
+
+/// Netplay ROM hash derived from a content hash (its first 8 bytes)
+pub fn netplay_rom_hash(content_hash: &[u8; 32]) -> u64 {
+    u64::from_le_bytes(content_hash[..8].try_into().unwrap())
+}
+
 /// Reasons why two games are incompatible for netplay.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum NetplayMismatch {
@@ -185,6 +206,15 @@ mod tests {
         assert!(meta1.validate_compatibility(&meta2).is_ok());
     }
 
+    #[test]
+    fn test_content_hash_identifies_build() {
+        let a = rom_content_hash(b"NCZX build 1");
+        let b = rom_content_hash(b"NCZX build 2");
+        assert_eq!(a, rom_content_hash(b"NCZX build 1"));
+        assert_ne!(a, b);
+        assert_ne!(netplay_rom_hash(&a), netplay_rom_hash(&b));
+    }
+
     #[test]
     fn test_compatibility_console_mismatch() {
         let meta1 = NetplayMetadata::new(ConsoleType::ZX, TickRate::Fixed60, 4, 0x12345678);
@@ -96,7 +96,7 @@ pub const ZX_ROM_FORMAT: RomFormat = RomFormat::new(
     nethercore_zx_specs().console_type,
     "nczx",
     b"NCZX",
-    1,
+    2,
     "nczxmesh",
     "nczxtex",
     "nczxsnd",
@@ -160,7 +160,7 @@ mod tests {
 
     #[test]
     fn test_zx_rom_format_version() {
-        assert_eq!(ZX_ROM_FORMAT.version, 1);
+        assert_eq!(ZX_ROM_FORMAT.version, 2);
     }
 
     #[test]
@@ -0,0 +1,370 @@
+//! Binary delta patches between two versions of a ROM.
+//!
+//! A patch (`.ncpatch`) stores only what changed between an old ROM and a new
+//! one: ranges copied from the old file plus inserted bytes. Updates to games
+//! with large asset sets can then ship as a few kilobytes instead of a full
+//! ROM. Both ends are identified by SHA-256, so a patch only applies to the
+//! exact ROM it was made from and always reproduces the exact new ROM.
+//!
+//! The format is console-agnostic; it works on raw ROM bytes.
+
+use std::collections::HashMap;
+
+use anyhow::Result;
+use bitcode::{Decode, Encode};
+use sha2::{Digest, Sha256};
+
+use crate::fs::MAX_ROM_BYTES;
+
+/// Patch file extension (without the dot)
+pub const ROM_PATCH_EXTENSION: &str = "ncpatch";
+
+/// Magic bytes at the start of a patch file
+pub const ROM_PATCH_MAGIC: &[u8; 4] = b"NCPT";
+
+/// Current patch format version
+pub const ROM_PATCH_VERSION: u32 = 1;
+
+/// Size of the blocks matched between the old and new ROM
+const BLOCK_SIZE: usize = 64;
+
+/// Multiplier for the rolling block hash
+const HASH_BASE: u64 = 0x0100_0000_01b3;
+
+/// One step in rebuilding the new ROM
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub enum PatchOp {
+    /// Copy `len` bytes from the old ROM starting at `offset`
+    Copy { offset: u64, len: u64 },
+    /// Append bytes stored in the patch
+    Insert(Vec<u8>),
+}
+
+/// Delta between two versions of a ROM
+#[derive(Debug, Clone, Encode, Decode)]
+pub struct RomPatch {
+    /// Patch format version
+    pub version: u32,
+
+    /// Game ID both ROMs belong to
+    pub game_id: String,
+
+    /// Game version of the old ROM
+    pub from_version: String,
+
+    /// Game version of the new ROM
+    pub to_version: String,
+
+    /// SHA-256 of the old ROM
+    pub source_hash: [u8; 32],
+
+    /// SHA-256 of the new ROM
+    pub target_hash: [u8; 32],
+
+    /// Size of the new ROM in bytes
+    pub target_len: u64,
+
+    /// Steps that rebuild the new ROM from the old one
+    pub ops: Vec<PatchOp>,
+}
+
+impl RomPatch {
+    /// Create a patch that turns `source` into `target`
+    pub fn create(
+        game_id: impl Into<String>,
+        from_version: impl Into<String>,
+        to_version: impl Into<String>,
+        source: &[u8],
+        target: &[u8],
+    ) -> Self {
+        Self {
+            version: ROM_PATCH_VERSION,
+            game_id: game_id.into(),
+            from_version: from_version.into(),
+            to_version: to_version.into(),
+            source_hash: sha256(source),
+            target_hash: sha256(target),
+            target_len: target.len() as u64,
+            ops: diff(source, target),
+        }
+    }
+
+    /// Whether this patch was made from `rom`
+    pub fn applies_to(&self, rom: &[u8]) -> bool {
+        sha256(rom) == self.source_hash
+    }
+
+    /// Number of bytes stored inline in the patch
+    pub fn inserted_bytes(&self) -> usize {
+        self.ops
+            .iter()
+            .map(|op| match op {
+                PatchOp::Insert(bytes) => bytes.len(),
+                PatchOp::Copy { .. } => 0,
+            })
+            .sum()
+    }
+
+    /// Rebuild the new ROM from `source`
+    ///
+    /// Fails if `source` isn't the ROM the patch was made from, or if the
+    /// result doesn't match the recorded hash.
+    pub fn apply(&self, source: &[u8]) -> Result<Vec<u8>> {
+        if !self.applies_to(source) {
+            anyhow::bail!(
+                "Patch for {} {} -> {} does not apply to this ROM (source hash mismatch)",
+                self.game_id,
+                self.from_version,
+                self.to_version
+            );
+        }
+        if self.target_len > MAX_ROM_BYTES {
+            anyhow::bail!(
+                "Patched ROM too large: {} bytes (max {} bytes)",
+                self.target_len,
+                MAX_ROM_BYTES
+            );
+        }
+
+        let mut out = Vec::with_capacity(self.target_len as usize);
+        for op in &self.ops {
+            match op {
+                PatchOp::Copy { offset, len } => {
+                    let range = usize::try_from(*offset)
+                        .ok()
+                        .zip(usize::try_from(*len).ok())
+                        .and_then(|(start, len)| Some(start..start.checked_add(len)?));
+                    let bytes = range
+                        .and_then(|range| source.get(range))
+                        .ok_or_else(|| anyhow::anyhow!("Patch copies outside the source ROM"))?;
+                    out.extend_from_slice(bytes);
+                }
+                PatchOp::Insert(bytes) => out.extend_from_slice(bytes),
+            }
+            if out.len() as u64 > self.target_len {
+                anyhow::bail!("Patch produces more data than the recorded ROM size");
+            }
+        }
+
+        if out.len() as u64 != self.target_len || sha256(&out) != self.target_hash {
+            anyhow::bail!("Patched ROM failed verification (target hash mismatch)");
+        }
+        Ok(out)
+    }
+
+    /// Serialize to bytes with magic header
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = ROM_PATCH_MAGIC.to_vec();
+        bytes.extend(bitcode::encode(self));
+        bytes
+    }
+
+    /// Deserialize from bytes and validate
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() < 4 || &bytes[0..4] != ROM_PATCH_MAGIC {
+            anyhow::bail!("Invalid ROM patch magic bytes (expected: \"NCPT\")");
+        }
+
+        let patch: RomPatch = bitcode::decode(&bytes[4..])
+            .map_err(|e| anyhow::anyhow!("Failed to decode ROM patch: {}", e))?;
+
+        if patch.version > ROM_PATCH_VERSION {
+            anyhow::bail!(
+                "Unsupported ROM patch version: {} (max supported: {})",
+                patch.version,
+                ROM_PATCH_VERSION
+            );
+        }
+
+        Ok(patch)
+    }
+}
+
+/// SHA-256 digest of `bytes`
+fn sha256(bytes: &[u8]) -> [u8; 32] {
+    Sha256::digest(bytes).into()
+}
+
+/// Polynomial hash of one block (matches the rolling update in [`diff`])
+fn block_hash(block: &[u8]) -> u64 {
+    block.iter().fold(0u64, |hash, &b| {
+        hash.wrapping_mul(HASH_BASE).wrapping_add(b as u64 + 1)
+    })
+}
+
+/// Compute the copy/insert steps that turn `old` into `new`
+///
+/// Indexes `old` in fixed blocks, then slides a rolling hash over `new`.
+/// Matching blocks are grown in both directions so shifted data (assets
+/// added or removed earlier in the file) still becomes a single copy.
+pub fn diff(old: &[u8], new: &[u8]) -> Vec<PatchOp> {
+    let mut ops = Vec::new();
+    if old.len() < BLOCK_SIZE || new.len() < BLOCK_SIZE {
+        if !new.is_empty() {
+            ops.push(PatchOp::Insert(new.to_vec()));
+        }
+        return ops;
+    }
+
+    let mut index: HashMap<u64, usize> = HashMap::new();
+    for start in (0..=old.len() - BLOCK_SIZE).step_by(BLOCK_SIZE) {
+        index
+            .entry(block_hash(&old[start..start + BLOCK_SIZE]))
+            .or_insert(start);
+    }
+
+    // Weight of the byte leaving the window
+    let out_weight = (1..BLOCK_SIZE).fold(1u64, |w, _| w.wrapping_mul(HASH_BASE));
+
+    let mut literal_start = 0;
+    let mut pos = 0;
+    let mut hash = block_hash(&new[..BLOCK_SIZE]);
+    while pos + BLOCK_SIZE <= new.len() {
+        let matched = index
+            .get(&hash)
+            .copied()
+            .filter(|&start| old[start..start + BLOCK_SIZE] == new[pos..pos + BLOCK_SIZE]);
+
+        if let Some(start) = matched {
+            // Grow backwards into pending literal bytes, then forwards
+            let mut back = 0;
+            while back < pos - literal_start
+                && back < start
+                && old[start - back - 1] == new[pos - back - 1]
+            {
+                back += 1;
+            }
+            let (old_start, new_start) = (start - back, pos - back);
+            let mut len = BLOCK_SIZE + back;
+            while new_start + len < new.len()
+                && old_start + len < old.len()
+                && old[old_start + len] == new[new_start + len]
+            {
+                len += 1;
+            }
+
+            if new_start > literal_start {
+                ops.push(PatchOp::Insert(new[literal_start..new_start].to_vec()));
+            }
+            ops.push(PatchOp::Copy {
+                offset: old_start as u64,
+                len: len as u64,
+            });
+
+            pos = new_start + len;
+            literal_start = pos;
+            if pos + BLOCK_SIZE <= new.len() {
+                hash = block_hash(&new[pos..pos + BLOCK_SIZE]);
+            }
+            continue;
+        }
+
+        if pos + BLOCK_SIZE < new.len() {
+            hash = hash
+                .wrapping_sub((new[pos] as u64 + 1).wrapping_mul(out_weight))
+                .wrapping_mul(HASH_BASE)
+                .wrapping_add(new[pos + BLOCK_SIZE] as u64 + 1);
+        }
+        pos += 1;
+    }
+
+    if literal_start < new.len() {
+        ops.push(PatchOp::Insert(new[literal_start..].to_vec()));
+    }
+    ops
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Deterministic pseudo-random bytes (incompressible enough for matching)
+    fn noise(len: usize, seed: u32) -> Vec<u8> {
+        let mut state = seed;
+        (0..len)
+            .map(|_| {
+                state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+                (state >> 24) as u8
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_rolling_hash_matches_block_hash() {
+        let data = noise(BLOCK_SIZE + 1, 7);
+        let out_weight = (1..BLOCK_SIZE).fold(1u64, |w, _| w.wrapping_mul(HASH_BASE));
+        let rolled = block_hash(&data[..BLOCK_SIZE])
+            .wrapping_sub((data[0] as u64 + 1).wrapping_mul(out_weight))
+            .wrapping_mul(HASH_BASE)
+            .wrapping_add(data[BLOCK_SIZE] as u64 + 1);
+        assert_eq!(rolled, block_hash(&data[1..]));
+    }
+
+    #[test]
+    fn test_patch_roundtrip_with_shifted_data() {
+        let old = noise(16 * 1024, 1);
+        let mut new = noise(300, 2);
+        new.extend_from_slice(&old[..8000]);
+        new.extend_from_slice(&noise(100, 3));
+        new.extend_from_slice(&old[9000..]);
+
+        let patch = RomPatch::create("neon-drift", "1.0.0", "1.1.0", &old, &new);
+        assert!(patch.inserted_bytes() < 1024);
+        assert_eq!(patch.apply(&old).unwrap(), new);
+
+        let decoded = RomPatch::from_bytes(&patch.to_bytes()).unwrap();
+        assert_eq!(decoded.ops, patch.ops);
+        assert_eq!(decoded.apply(&old).unwrap(), new);
+    }
+
+    #[test]
+    fn test_patch_small_inputs() {
+        let patch = RomPatch::create("tiny", "1", "2", b"abc", b"abcd");
+        assert_eq!(patch.ops, vec![PatchOp::Insert(b"abcd".to_vec())]);
+        assert_eq!(patch.apply(b"abc").unwrap(), b"abcd");
+
+        let empty = RomPatch::create("tiny", "1", "2", b"abc", b"");
+        assert!(empty.ops.is_empty());
+        assert!(empty.apply(b"abc").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_patch_rejects_wrong_source() {
+        let old = noise(4096, 1);
+        let new = noise(4096, 2);
+        let patch = RomPatch::create("game", "1", "2", &old, &new);
+
+        assert!(!patch.applies_to(&new));
+        assert!(patch.apply(&new).is_err());
+    }
+
+    #[test]
+    fn test_patch_rejects_tampered_ops() {
+        let old = noise(4096, 1);
+        let mut new = old.clone();
+        new[100] ^= 0xFF;
+        let mut patch = RomPatch::create("game", "1", "2", &old, &new);
+
+        patch.ops.push(PatchOp::Copy {
+            offset: old.len() as u64,
+            len: 1,
+        });
+        assert!(patch.apply(&old).is_err());
+
+        patch.ops.pop();
+        if let Some(PatchOp::Insert(bytes)) = patch
+            .ops
+            .iter_mut()
+            .find(|op| matches!(op, PatchOp::Insert(_)))
+        {
+            bytes[0] ^= 1;
+        }
+        assert!(patch.apply(&old).is_err());
+    }
+
+    #[test]
+    fn test_from_bytes_rejects_bad_magic() {
+        assert!(RomPatch::from_bytes(b"NCZX").is_err());
+        assert!(RomPatch::from_bytes(b"NC").is_err());
+    }
+}
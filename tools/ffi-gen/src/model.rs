@@ -44,6 +44,17 @@ impl Type {
             zig_type: map_rust_to_zig(rust_type),
         }
     }
+
+    /// Create a pointer type that may be null
+    ///
+    /// C pointers are always nullable; Zig needs an optional pointer (`?[*]T`).
+    pub fn nullable(rust_type: &str) -> Self {
+        let mut ty = Self::new(rust_type);
+        if rust_type.starts_with('*') {
+            ty.zig_type = format!("?{}", ty.zig_type);
+        }
+        ty
+    }
 }
 
 /// Module containing constants
@@ -125,6 +125,13 @@ fn prettify_category(name: &str) -> String {
         "assets" => "ROM Data Pack API (init-only)".to_string(),
         "embedded" => "Embedded Asset API".to_string(),
         "debug" => "Debug Inspection System".to_string(),
+        "cursor" => "Cursor Functions".to_string(),
+        "navigation" => "Navigation (Pathfinding)".to_string(),
+        "scoreboard" => "Teams & Scoreboard".to_string(),
+        "physics" => "2D Physics".to_string(),
+        "hitbox" => "Hitboxes".to_string(),
+        "projectile" => "Projectiles".to_string(),
+        "streaming" => "World Streaming".to_string(),
         "constants" => "Constants".to_string(),
         _ => {
             // Fallback: capitalize first letter
@@ -225,6 +232,7 @@ fn parse_foreign_mod(foreign_mod: &ItemForeignMod) -> Result<(Vec<FfiFunction>,
 fn parse_foreign_function(func: &ForeignItemFn, category: &str) -> Result<FfiFunction> {
     let name = func.sig.ident.to_string();
     let doc_comment = extract_doc_comments(&func.attrs);
+    let nullable = nullable_params(&doc_comment);
 
     // Parse parameters
     let mut params = Vec::new();
@@ -237,9 +245,14 @@ fn parse_foreign_function(func: &ForeignItemFn, category: &str) -> Result<FfiFun
             };
 
             let rust_type = type_to_string(&pat_type.ty);
+            let ty = if nullable.contains(&param_name) {
+                Type::nullable(&rust_type)
+            } else {
+                Type::new(&rust_type)
+            };
             params.push(Parameter {
                 name: param_name,
-                ty: Type::new(&rust_type),
+                ty,
             });
         }
     }
@@ -316,6 +329,34 @@ fn extract_doc_comments(attrs: &[syn::Attribute]) -> String {
     doc
 }
 
+/// Find parameters documented as optional pointers in an `# Arguments` list
+///
+/// A parameter is nullable when its entry says so, e.g.
+/// ``* `out_pos` — Output position, or null to skip``.
+fn nullable_params(doc: &str) -> Vec<String> {
+    let mut names = Vec::new();
+
+    for line in doc.lines() {
+        let Some(entry) = line.strip_prefix("* `") else {
+            continue;
+        };
+        let Some((params, description)) = entry.split_once(" — ") else {
+            continue;
+        };
+        if !description.contains("or null") {
+            continue;
+        }
+        // `x`, `y`, `z` — ... documents several parameters at once
+        names.extend(
+            params
+                .split(',')
+                .map(|name| name.trim().trim_matches('`').to_string()),
+        );
+    }
+
+    names
+}
+
 /// Extract category name from a comment (e.g., "// ========= System Functions =========")
 fn extract_category_from_comment(comment: &str) -> Option<String> {
     let trimmed = comment.trim();
@@ -426,6 +467,29 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_nullable_params() {
+        let source = r#"
+            extern "C" {
+                /// Evaluate a path.
+                ///
+                /// # Arguments
+                /// * `handle` — Path handle
+                /// * `out_pos` — Output position, or null to skip
+                /// * `out_a`, `out_b` — Outputs, or null to skip
+                pub fn path_eval(handle: u32, out_pos: *mut f32, out_a: *mut f32, out_b: *mut f32);
+            }
+        "#;
+
+        let model = parse_ffi_source(source).unwrap();
+        let params = &model.functions[0].params;
+        assert_eq!(params[0].ty.zig_type, "u32");
+        assert_eq!(params[1].ty.zig_type, "?[*]f32");
+        assert_eq!(params[1].ty.c_type, "float*");
+        assert_eq!(params[2].ty.zig_type, "?[*]f32");
+        assert_eq!(params[3].ty.zig_type, "?[*]f32");
+    }
+
     #[test]
     fn test_prettify_category() {
         assert_eq!(prettify_category("system"), "System Functions");
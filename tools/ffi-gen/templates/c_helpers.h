@@ -44,7 +44,30 @@ static inline float nczx_absf(float x) {
 
 // ROM loading helpers
 #define NCZX_ROM_TEXTURE(id) rom_texture((const uint8_t*)(id), (uint32_t)(sizeof(id) - 1))
+#define NCZX_ROM_ATLAS(id) rom_atlas((const uint8_t*)(id), (uint32_t)(sizeof(id) - 1))
+#define NCZX_ROM_CUBEMAP(id) rom_cubemap((const uint8_t*)(id), (uint32_t)(sizeof(id) - 1))
 #define NCZX_ROM_MESH(id) rom_mesh((const uint8_t*)(id), (uint32_t)(sizeof(id) - 1))
 #define NCZX_ROM_SOUND(id) rom_sound((const uint8_t*)(id), (uint32_t)(sizeof(id) - 1))
+#define NCZX_ROM_STREAM(id) rom_stream((const uint8_t*)(id), (uint32_t)(sizeof(id) - 1))
+#define NCZX_ROM_PATH(id) rom_path((const uint8_t*)(id), (uint32_t)(sizeof(id) - 1))
+#define NCZX_ROM_STRINGS(id) rom_strings((const uint8_t*)(id), (uint32_t)(sizeof(id) - 1))
 #define NCZX_ROM_FONT(id) rom_font((const uint8_t*)(id), (uint32_t)(sizeof(id) - 1))
 #define NCZX_ROM_SKELETON(id) rom_skeleton((const uint8_t*)(id), (uint32_t)(sizeof(id) - 1))
+
+// Update throttling helpers (deterministic, safe in update())
+// Bucket 0 updates every tick, 1 every 2 ticks, 2 every 4 ticks.
+// dist_sq is the squared distance to the nearest viewer from game state.
+static inline uint32_t nczx_update_bucket(float dist_sq, float near_dist, float far_dist) {
+    if (dist_sq <= near_dist * near_dist) return 0;
+    if (dist_sq <= far_dist * far_dist) return 1;
+    return 2;
+}
+
+static inline uint32_t nczx_update_interval(uint32_t bucket) {
+    return 1u << (bucket > 2 ? 2 : bucket);
+}
+
+// Entities are staggered by index so each tick runs a slice of every bucket
+static inline int nczx_update_due(uint32_t bucket, uint32_t index, uint64_t tick) {
+    return ((tick + index) % nczx_update_interval(bucket)) == 0;
+}
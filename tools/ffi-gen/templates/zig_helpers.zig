@@ -36,6 +36,14 @@ pub fn romTexture(id: []const u8) u32 {
     return rom_texture(id.ptr, @intCast(id.len));
 }
 
+pub fn romAtlas(id: []const u8) u32 {
+    return rom_atlas(id.ptr, @intCast(id.len));
+}
+
+pub fn romCubemap(id: []const u8) u32 {
+    return rom_cubemap(id.ptr, @intCast(id.len));
+}
+
 pub fn romMesh(id: []const u8) u32 {
     return rom_mesh(id.ptr, @intCast(id.len));
 }
@@ -44,6 +52,18 @@ pub fn romSound(id: []const u8) u32 {
     return rom_sound(id.ptr, @intCast(id.len));
 }
 
+pub fn romStream(id: []const u8) u32 {
+    return rom_stream(id.ptr, @intCast(id.len));
+}
+
+pub fn romPath(id: []const u8) u32 {
+    return rom_path(id.ptr, @intCast(id.len));
+}
+
+pub fn romStrings(id: []const u8) u32 {
+    return rom_strings(id.ptr, @intCast(id.len));
+}
+
 pub fn romFont(id: []const u8) u32 {
     return rom_font(id.ptr, @intCast(id.len));
 }
@@ -51,3 +71,21 @@ pub fn romFont(id: []const u8) u32 {
 pub fn romSkeleton(id: []const u8) u32 {
     return rom_skeleton(id.ptr, @intCast(id.len));
 }
+
+/// Update throttling helpers (deterministic, safe in update())
+/// Bucket 0 updates every tick, 1 every 2 ticks, 2 every 4 ticks.
+/// `dist_sq` is the squared distance to the nearest viewer from game state.
+pub fn updateBucket(dist_sq: f32, near: f32, far: f32) u32 {
+    if (dist_sq <= near * near) return 0;
+    if (dist_sq <= far * far) return 1;
+    return 2;
+}
+
+pub fn updateInterval(bucket: u32) u32 {
+    return @as(u32, 1) << @intCast(@min(bucket, 2));
+}
+
+/// Entities are staggered by index so each tick runs a slice of every bucket
+pub fn updateDue(bucket: u32, index: u32, tick: u64) bool {
+    return (tick +% index) % updateInterval(bucket) == 0;
+}
@@ -1,6 +1,12 @@
 // Build script for nether-cli
 //
-// This links the C++ standard library required by intel_tex_2's ISPC kernels.
+// This links the C++ standard library required by intel_tex_2's ISPC kernels,
+// and embeds the EPU opcode shader sources so `nether epu compile` can validate
+// layers against their `@epu_meta_begin`/`@epu_meta_end` blocks.
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
 
 fn main() {
     // Link C++ standard library for intel_tex_2's ISPC kernels
@@ -9,4 +15,41 @@ fn main() {
 
     #[cfg(target_os = "macos")]
     println!("cargo:rustc-link-lib=c++");
+
+    println!("cargo:rerun-if-changed=build.rs");
+    embed_epu_shaders();
+}
+
+/// Generate `epu_sources.rs` with an `include_str!` for every EPU opcode shader
+fn embed_epu_shaders() {
+    let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let shader_base = manifest_dir.join("../../nethercore-zx/shaders/epu");
+
+    let mut wgsl_files = Vec::new();
+    for dir in ["bounds", "features"] {
+        let dir = shader_base.join(dir);
+        println!("cargo:rerun-if-changed={}", dir.display());
+        let entries = fs::read_dir(&dir)
+            .unwrap_or_else(|e| panic!("Failed to read {}: {}", dir.display(), e));
+        for entry in entries {
+            let path = entry.expect("Failed to read directory entry").path();
+            if path.extension().is_some_and(|e| e == "wgsl") {
+                println!("cargo:rerun-if-changed={}", path.display());
+                wgsl_files.push(path);
+            }
+        }
+    }
+    wgsl_files.sort();
+
+    let mut code = String::from("/// EPU opcode shader sources as (file name, source)\n");
+    code.push_str("pub static EPU_SHADER_SOURCES: &[(&str, &str)] = &[\n");
+    for path in &wgsl_files {
+        let path = path.canonicalize().expect("Failed to resolve shader path");
+        let name = path.file_name().unwrap().to_string_lossy();
+        code.push_str(&format!("    ({:?}, include_str!({:?})),\n", name, path));
+    }
+    code.push_str("];\n");
+
+    let out_path = PathBuf::from(env::var("OUT_DIR").unwrap()).join("epu_sources.rs");
+    fs::write(&out_path, code).expect("Failed to write epu_sources.rs");
 }
@@ -105,6 +105,7 @@ pub fn execute(args: BuildArgs) -> Result<()> {
         manifest: manifest_path,
         output: args.output,
         wasm: Some(wasm_path),
+        override_pack: false,
     })?;
 
     Ok(())
@@ -0,0 +1,155 @@
+//! EPU layer fields and 128-bit packing
+//!
+//! Bit layout matches the `hi()`/`lo()` helpers used by the EPU examples:
+//!
+//! ```text
+//! hi: opcode(5) region(3) blend(3) meta5(5) color_a(24) color_b(24)
+//! lo: intensity(8) param_a(8) param_b(8) param_c(8) param_d(8) direction(16) alpha_a(4) alpha_b(4)
+//! ```
+
+/// Number of layers in an EPU environment
+pub const LAYER_COUNT: usize = 8;
+
+/// Region mask bits
+pub const REGION_SKY: u8 = 0b100;
+pub const REGION_WALLS: u8 = 0b010;
+pub const REGION_FLOOR: u8 = 0b001;
+pub const REGION_ALL: u8 = 0b111;
+
+/// Region names and their mask bits
+pub const REGIONS: [(&str, u8); 4] = [
+    ("all", REGION_ALL),
+    ("sky", REGION_SKY),
+    ("walls", REGION_WALLS),
+    ("floor", REGION_FLOOR),
+];
+
+/// Blend mode names, indexed by blend value
+pub const BLENDS: [&str; 8] = [
+    "add", "multiply", "max", "lerp", "screen", "hsv_mod", "min", "overlay",
+];
+
+/// Octahedral direction for +Y, in packed (u low byte, v high byte) order
+pub const DIR_UP: u16 = 0xFF80;
+
+/// A single validated EPU layer, unpacked
+///
+/// The default value is a NOP layer, which packs to `[0, 0]`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Layer {
+    pub opcode: u8,
+    pub region: u8,
+    pub blend: u8,
+    pub domain: u8,
+    pub variant: u8,
+    pub color_a: u32,
+    pub color_b: u32,
+    pub intensity: u8,
+    pub params: [u8; 4],
+    /// Octahedral direction, u in the low byte and v in the high byte
+    pub direction: u16,
+    pub alpha_a: u8,
+    pub alpha_b: u8,
+}
+
+impl Layer {
+    /// Pack into the `[hi, lo]` words the EPU consumes
+    pub fn pack(&self) -> [u64; 2] {
+        if self.opcode == 0 {
+            return [0, 0];
+        }
+
+        let meta5 = ((self.domain as u64 & 0x3) << 3) | (self.variant as u64 & 0x7);
+        let hi = ((self.opcode as u64 & 0x1F) << 59)
+            | ((self.region as u64 & 0x7) << 56)
+            | ((self.blend as u64 & 0x7) << 53)
+            | (meta5 << 48)
+            | ((self.color_a as u64 & 0xFFFFFF) << 24)
+            | (self.color_b as u64 & 0xFFFFFF);
+        let lo = ((self.intensity as u64) << 56)
+            | ((self.params[0] as u64) << 48)
+            | ((self.params[1] as u64) << 40)
+            | ((self.params[2] as u64) << 32)
+            | ((self.params[3] as u64) << 24)
+            | ((self.direction as u64) << 8)
+            | ((self.alpha_a as u64 & 0xF) << 4)
+            | (self.alpha_b as u64 & 0xF);
+        [hi, lo]
+    }
+}
+
+/// Encode a direction as octahedral u16 (u low byte, v high byte)
+///
+/// Returns `None` for a zero-length vector.
+pub fn encode_direction(dir: [f32; 3]) -> Option<u16> {
+    let dir = glam::Vec3::from(dir);
+    if dir.length_squared() <= f32::EPSILON {
+        return None;
+    }
+    let (u, v) = zx_common::encode_octahedral(dir);
+    let to_byte = |x: f32| ((x * 0.5 + 0.5) * 255.0).round().clamp(0.0, 255.0) as u16;
+    Some(to_byte(u) | (to_byte(v) << 8))
+}
+
+/// Human-readable region mask, e.g. `sky|walls`
+pub fn region_name(region: u8) -> String {
+    if region == REGION_ALL {
+        return "all".to_string();
+    }
+    let names: Vec<_> = REGIONS[1..]
+        .iter()
+        .filter(|(_, bit)| region & bit != 0)
+        .map(|(name, _)| *name)
+        .collect();
+    if names.is_empty() {
+        "none".to_string()
+    } else {
+        names.join("|")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nop_packs_to_zero() {
+        assert_eq!(Layer::default().pack(), [0, 0]);
+    }
+
+    #[test]
+    fn test_pack_matches_hi_lo_helpers() {
+        // hi_meta(OP_PLANE, REGION_FLOOR, BLEND_LERP, 0, 7, 0x060810, 0x020304)
+        // lo(200, 100, 25, 140, 0, DIR_UP, 15, 14), as in the epu-showcase presets
+        let layer = Layer {
+            opcode: 0x0F,
+            region: REGION_FLOOR,
+            blend: 3,
+            domain: 0,
+            variant: 7,
+            color_a: 0x060810,
+            color_b: 0x020304,
+            intensity: 200,
+            params: [100, 25, 140, 0],
+            direction: DIR_UP,
+            alpha_a: 15,
+            alpha_b: 14,
+        };
+        assert_eq!(layer.pack(), [0x7967_0608_1002_0304, 0xC864_198C_00FF_80FE]);
+    }
+
+    #[test]
+    fn test_encode_direction_axes() {
+        assert_eq!(encode_direction([0.0, 1.0, 0.0]), Some(DIR_UP));
+        assert_eq!(encode_direction([0.0, 0.0, 1.0]), Some(0x8080));
+        assert_eq!(encode_direction([0.0, 0.0, -1.0]), Some(0xFFFF));
+        assert_eq!(encode_direction([0.0, 0.0, 0.0]), None);
+    }
+
+    #[test]
+    fn test_region_name() {
+        assert_eq!(region_name(REGION_ALL), "all");
+        assert_eq!(region_name(REGION_SKY | REGION_WALLS), "sky|walls");
+        assert_eq!(region_name(0), "none");
+    }
+}
@@ -0,0 +1,32 @@
+//! EPU opcode metadata, parsed from the embedded shader sources
+
+use std::sync::OnceLock;
+
+use zx_common::wgsl_meta_parser::{parse_wgsl_meta, OpcodeMeta};
+
+include!(concat!(env!("OUT_DIR"), "/epu_sources.rs"));
+
+/// All known opcodes, sorted by opcode number
+pub fn opcodes() -> &'static [OpcodeMeta] {
+    static OPCODES: OnceLock<Vec<OpcodeMeta>> = OnceLock::new();
+    OPCODES.get_or_init(|| {
+        let mut opcodes: Vec<_> = EPU_SHADER_SOURCES
+            .iter()
+            .map(|(name, source)| parse_wgsl_meta(source, name))
+            .collect();
+        opcodes.sort_by_key(|meta| meta.opcode);
+        opcodes
+    })
+}
+
+/// Look up an opcode by number
+pub fn by_opcode(opcode: u8) -> Option<&'static OpcodeMeta> {
+    opcodes().iter().find(|meta| meta.opcode == opcode)
+}
+
+/// Look up an opcode by name (case-insensitive)
+pub fn by_name(name: &str) -> Option<&'static OpcodeMeta> {
+    opcodes()
+        .iter()
+        .find(|meta| meta.name.eq_ignore_ascii_case(name))
+}
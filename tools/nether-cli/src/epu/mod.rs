@@ -0,0 +1,201 @@
+//! EPU commands - compile environment descriptions into packed layer arrays
+//!
+//! Writing `hi()`/`lo()` words by hand is easy to get wrong. `nether epu compile`
+//! reads a TOML or JSON description, checks every opcode, variant and field
+//! against the shader metadata, and emits a `[[u64; 2]; 8]` constant.
+//!
+//! ```bash
+//! nether epu compile env.toml -o src/env.rs
+//! nether epu compile env.json --name NIGHT_CITY --preview env.png
+//! ```
+
+mod layer;
+mod meta;
+mod preview;
+mod spec;
+
+use anyhow::{Context, Result};
+use clap::Subcommand;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use layer::{region_name, Layer, BLENDS, LAYER_COUNT};
+
+/// EPU subcommands
+#[derive(Subcommand)]
+pub enum EpuAction {
+    /// Compile a TOML/JSON environment description into a Rust constant
+    Compile {
+        /// Environment description (.toml or .json)
+        input: PathBuf,
+
+        /// Output Rust file (prints to stdout if omitted)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+
+        /// Constant name (defaults to the input file name in SCREAMING_CASE)
+        #[arg(long)]
+        name: Option<String>,
+
+        /// Also write a PNG with color and intensity swatches for each layer
+        #[arg(long)]
+        preview: Option<PathBuf>,
+    },
+}
+
+/// Execute an EPU action
+pub fn execute(action: EpuAction) -> Result<()> {
+    match action {
+        EpuAction::Compile {
+            input,
+            output,
+            name,
+            preview,
+        } => compile(&input, output.as_deref(), name, preview.as_deref()),
+    }
+}
+
+fn compile(
+    input: &Path,
+    output: Option<&Path>,
+    name: Option<String>,
+    preview_path: Option<&Path>,
+) -> Result<()> {
+    let source =
+        fs::read_to_string(input).with_context(|| format!("Failed to read {}", input.display()))?;
+    let doc: serde_json::Value = match input.extension().and_then(|e| e.to_str()) {
+        Some("json") => serde_json::from_str(&source)
+            .with_context(|| format!("Failed to parse {}", input.display()))?,
+        _ => toml::from_str(&source)
+            .with_context(|| format!("Failed to parse {}", input.display()))?,
+    };
+
+    let layers = match spec::compile(&doc) {
+        Ok(layers) => layers,
+        Err(errors) => {
+            for error in &errors {
+                eprintln!("error: {}", error);
+            }
+            anyhow::bail!(
+                "{}: {} error(s) found, nothing written",
+                input.display(),
+                errors.len()
+            );
+        }
+    };
+
+    let name = match name {
+        Some(name) => name,
+        None => const_name(input),
+    };
+    let file_name = input.file_name().unwrap_or_default().to_string_lossy();
+    let code = emit_rust(&layers, &name, &file_name);
+
+    match output {
+        Some(path) => {
+            fs::write(path, code).with_context(|| format!("Failed to write {}", path.display()))?;
+            println!("Compiled {} -> {}", input.display(), path.display());
+        }
+        None => print!("{}", code),
+    }
+
+    if let Some(path) = preview_path {
+        preview::write(&layers, path)?;
+        println!("Wrote preview {}", path.display());
+    }
+
+    Ok(())
+}
+
+/// Derive a constant name from a file name, e.g. `night-city.toml` -> `NIGHT_CITY`
+fn const_name(input: &Path) -> String {
+    let stem = input.file_stem().unwrap_or_default().to_string_lossy();
+    let mut name: String = stem
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_uppercase()
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    if !name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_') {
+        name.insert_str(0, "ENV_");
+    }
+    name
+}
+
+/// Emit the layers as a Rust constant, one commented line per layer
+fn emit_rust(layers: &[Layer; LAYER_COUNT], name: &str, source_name: &str) -> String {
+    let mut code = String::new();
+    let _ = writeln!(
+        code,
+        "// Generated by `nether epu compile {}`. Edit the source and recompile.\n",
+        source_name
+    );
+    let _ = writeln!(code, "pub const {}: [[u64; 2]; {}] = [", name, LAYER_COUNT);
+    for layer in layers {
+        let [hi, lo] = layer.pack();
+        let _ = writeln!(code, "    // {}", describe(layer));
+        let _ = writeln!(code, "    [0x{:016X}, 0x{:016X}],", hi, lo);
+    }
+    code.push_str("];\n");
+    code
+}
+
+/// One-line summary of a layer, e.g. `SCATTER/STARS sky add`
+fn describe(layer: &Layer) -> String {
+    let Some(meta) = meta::by_opcode(layer.opcode).filter(|_| layer.opcode != 0) else {
+        return "NOP".to_string();
+    };
+    let mut text = meta.name.clone();
+    if let Some(variant) = meta.variants.get(layer.variant as usize) {
+        let _ = write!(text, "/{}", variant);
+    }
+    if let Some(domain) = meta
+        .domains
+        .get(layer.domain as usize)
+        .filter(|_| layer.domain != 0)
+    {
+        let _ = write!(text, " ({})", domain);
+    }
+    let _ = write!(
+        text,
+        " {} {}",
+        region_name(layer.region),
+        BLENDS[layer.blend as usize]
+    );
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_const_name() {
+        assert_eq!(const_name(Path::new("envs/night-city.toml")), "NIGHT_CITY");
+        assert_eq!(const_name(Path::new("1up.json")), "ENV_1UP");
+    }
+
+    #[test]
+    fn test_emit_rust() {
+        let doc = toml::from_str(
+            r#"
+            [[layer]]
+            opcode = "SCATTER"
+            region = "sky"
+            variant = "STARS"
+            "#,
+        )
+        .unwrap();
+        let layers = spec::compile(&doc).unwrap();
+        let code = emit_rust(&layers, "SKY", "sky.toml");
+
+        assert!(code.contains("pub const SKY: [[u64; 2]; 8] = ["));
+        assert!(code.contains("    // SCATTER/STARS sky add\n"));
+        assert_eq!(code.matches("    // NOP\n").count(), 7);
+    }
+}
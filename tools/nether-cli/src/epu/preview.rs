@@ -0,0 +1,84 @@
+//! Layer swatch preview
+//!
+//! Draws one row per layer showing `color_a` and `color_b` over a checkerboard
+//! (weighted by their alphas) with an intensity bar underneath. This is a quick
+//! palette check, not a render of the environment - use the EPU inspector or
+//! the game itself to see the final result.
+
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use image::{Rgb, RgbImage};
+
+use super::layer::{Layer, LAYER_COUNT};
+
+const WIDTH: u32 = 256;
+const ROW_HEIGHT: u32 = 32;
+const BAR_HEIGHT: u32 = 4;
+const CHECKER_SIZE: u32 = 8;
+
+/// Write a swatch PNG for `layers` to `path`
+pub fn write(layers: &[Layer; LAYER_COUNT], path: &Path) -> Result<()> {
+    render(layers)
+        .save(path)
+        .with_context(|| format!("Failed to write preview: {}", path.display()))
+}
+
+fn render(layers: &[Layer; LAYER_COUNT]) -> RgbImage {
+    RgbImage::from_fn(WIDTH, ROW_HEIGHT * LAYER_COUNT as u32, |x, y| {
+        let layer = &layers[(y / ROW_HEIGHT) as usize];
+        let row_y = y % ROW_HEIGHT;
+        let checker = if ((x / CHECKER_SIZE) + (y / CHECKER_SIZE)).is_multiple_of(2) {
+            [48, 48, 48]
+        } else {
+            [80, 80, 80]
+        };
+
+        if layer.opcode == 0 {
+            return Rgb(checker);
+        }
+        if row_y >= ROW_HEIGHT - BAR_HEIGHT {
+            let filled = x * 255 < layer.intensity as u32 * WIDTH;
+            return Rgb(if filled { [255, 255, 255] } else { [0, 0, 0] });
+        }
+
+        let (color, alpha) = if x < WIDTH / 2 {
+            (layer.color_a, layer.alpha_a)
+        } else {
+            (layer.color_b, layer.alpha_b)
+        };
+        let rgb = [(color >> 16) as u8, (color >> 8) as u8, color as u8];
+        let a = alpha as u32;
+        Rgb(std::array::from_fn(|i| {
+            ((rgb[i] as u32 * a + checker[i] as u32 * (15 - a)) / 15) as u8
+        }))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_swatches() {
+        let mut layers = [Layer::default(); LAYER_COUNT];
+        layers[0] = Layer {
+            opcode: 1,
+            color_a: 0xFF0000,
+            color_b: 0x0000FF,
+            intensity: 255,
+            alpha_a: 15,
+            alpha_b: 15,
+            ..Layer::default()
+        };
+
+        let image = render(&layers);
+        assert_eq!(image.dimensions(), (WIDTH, ROW_HEIGHT * LAYER_COUNT as u32));
+        assert_eq!(image.get_pixel(0, 0), &Rgb([255, 0, 0]));
+        assert_eq!(image.get_pixel(WIDTH - 1, 0), &Rgb([0, 0, 255]));
+        assert_eq!(
+            image.get_pixel(WIDTH - 1, ROW_HEIGHT - 1),
+            &Rgb([255, 255, 255])
+        );
+    }
+}
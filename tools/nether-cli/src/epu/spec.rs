@@ -0,0 +1,541 @@
+//! Environment description parsing and validation
+//!
+//! An environment is a list of up to 8 `layer` tables. Unused slots are NOP.
+//!
+//! ```toml
+//! [[layer]]
+//! opcode = "RAMP"
+//! color_a = "#6080C0"     # sky
+//! color_b = "#302820"     # floor
+//! softness = 0.2          # label alias for intensity, in field units
+//! param_d = 0xA5          # integers on raw field names are stored as-is
+//!
+//! [[layer]]
+//! opcode = "SCATTER"
+//! region = "sky"
+//! blend = "add"
+//! variant = "STARS"
+//! density = 120.0
+//! brightness = 0.8
+//! ```
+//!
+//! Byte fields (`intensity`, `param_a`..`param_d`) can be given by raw name or
+//! by the label from the opcode's shader metadata. Integers on a raw name are
+//! raw bytes (0-255); floats, and any value given by label, are in the field's
+//! units and are quantized to the nearest byte.
+
+use serde_json::{Map, Value};
+use zx_common::wgsl_meta_parser::{FieldSpec, MapKind, OpcodeMeta};
+
+use super::layer::{encode_direction, Layer, BLENDS, DIR_UP, LAYER_COUNT, REGIONS, REGION_ALL};
+use super::meta;
+
+/// Keys every non-NOP layer accepts, besides byte fields and their labels
+const LAYER_KEYS: [&str; 10] = [
+    "opcode",
+    "region",
+    "blend",
+    "variant",
+    "domain",
+    "color_a",
+    "color_b",
+    "direction",
+    "alpha_a",
+    "alpha_b",
+];
+
+/// Byte field names, in packing order
+const BYTE_FIELDS: [&str; 5] = ["intensity", "param_a", "param_b", "param_c", "param_d"];
+
+/// Named directions accepted by `direction`
+const DIRECTIONS: [(&str, [f32; 3]); 8] = [
+    ("up", [0.0, 1.0, 0.0]),
+    ("down", [0.0, -1.0, 0.0]),
+    ("+x", [1.0, 0.0, 0.0]),
+    ("-x", [-1.0, 0.0, 0.0]),
+    ("+y", [0.0, 1.0, 0.0]),
+    ("-y", [0.0, -1.0, 0.0]),
+    ("+z", [0.0, 0.0, 1.0]),
+    ("-z", [0.0, 0.0, -1.0]),
+];
+
+/// Compile an environment document into its 8 layers
+///
+/// Every problem found is returned, not just the first.
+pub fn compile(doc: &Value) -> Result<[Layer; LAYER_COUNT], Vec<String>> {
+    let mut errors = Vec::new();
+    let mut layers = [Layer::default(); LAYER_COUNT];
+
+    let Some(root) = doc.as_object() else {
+        return Err(vec!["expected a table with a `layer` array".to_string()]);
+    };
+    for key in root.keys().filter(|key| *key != "layer") {
+        errors.push(format!("unknown top-level key `{}`", key));
+    }
+
+    let entries = match root.get("layer") {
+        Some(Value::Array(entries)) => entries.as_slice(),
+        Some(_) => {
+            errors.push("`layer` must be an array of tables".to_string());
+            &[]
+        }
+        None => {
+            errors.push("no layers defined (add one or more `[[layer]]` tables)".to_string());
+            &[]
+        }
+    };
+    if entries.len() > LAYER_COUNT {
+        errors.push(format!(
+            "{} layers defined, but the EPU has only {}",
+            entries.len(),
+            LAYER_COUNT
+        ));
+    }
+
+    for (i, (entry, slot)) in entries.iter().zip(layers.iter_mut()).enumerate() {
+        let mut ctx = LayerErrors {
+            prefix: format!("layer {}", i),
+            errors: &mut errors,
+        };
+        match entry.as_object() {
+            Some(table) => *slot = compile_layer(table, &mut ctx),
+            None => ctx.push("expected a table".to_string()),
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(layers)
+    } else {
+        Err(errors)
+    }
+}
+
+/// Error sink that prefixes messages with the layer being compiled
+struct LayerErrors<'a> {
+    prefix: String,
+    errors: &'a mut Vec<String>,
+}
+
+impl LayerErrors<'_> {
+    fn push(&mut self, message: String) {
+        self.errors.push(format!("{}: {}", self.prefix, message));
+    }
+}
+
+fn compile_layer(table: &Map<String, Value>, ctx: &mut LayerErrors) -> Layer {
+    let Some(opcode) = table.get("opcode") else {
+        ctx.push("missing `opcode`".to_string());
+        return Layer::default();
+    };
+    let meta = match opcode {
+        Value::String(name) if name.eq_ignore_ascii_case("nop") => None,
+        Value::String(name) => match meta::by_name(name) {
+            Some(meta) => Some(meta),
+            None => {
+                ctx.push(format!("unknown opcode `{}`", name));
+                return Layer::default();
+            }
+        },
+        _ => match opcode.as_u64() {
+            Some(0) => None,
+            Some(n) => match u8::try_from(n).ok().and_then(meta::by_opcode) {
+                Some(meta) => Some(meta),
+                None => {
+                    ctx.push(format!("unknown opcode {}", n));
+                    return Layer::default();
+                }
+            },
+            None => {
+                ctx.push("`opcode` must be a name or number".to_string());
+                return Layer::default();
+            }
+        },
+    };
+
+    let Some(meta) = meta else {
+        if let Some(key) = table.keys().find(|key| *key != "opcode") {
+            ctx.push(format!("NOP layers take no other fields (found `{}`)", key));
+        }
+        return Layer::default();
+    };
+    ctx.prefix = format!("{} ({})", ctx.prefix, meta.name);
+
+    let mut layer = Layer {
+        opcode: meta.opcode,
+        region: REGION_ALL,
+        direction: DIR_UP,
+        alpha_a: 15,
+        alpha_b: 15,
+        ..Layer::default()
+    };
+
+    for key in table.keys() {
+        let known = LAYER_KEYS.contains(&key.as_str())
+            || BYTE_FIELDS.contains(&key.as_str())
+            || field_by_label(meta, key).is_some();
+        if !known {
+            ctx.push(format!("unknown field `{}`", key));
+        }
+    }
+
+    if let Some(value) = table.get("region") {
+        layer.region = parse_region(value).unwrap_or_else(|e| {
+            ctx.push(format!("region: {}", e));
+            REGION_ALL
+        });
+    }
+    if let Some(value) = table.get("blend") {
+        layer.blend = parse_named(value, &BLENDS, "blend mode").unwrap_or_else(|e| {
+            ctx.push(format!("blend: {}", e));
+            0
+        });
+    }
+    if let Some(value) = table.get("variant") {
+        layer.variant = parse_enum(value, &meta.variants, 8, "variant").unwrap_or_else(|e| {
+            ctx.push(format!("variant: {}", e));
+            0
+        });
+    }
+    if let Some(value) = table.get("domain") {
+        layer.domain = parse_enum(value, &meta.domains, 4, "domain").unwrap_or_else(|e| {
+            ctx.push(format!("domain: {}", e));
+            0
+        });
+    }
+    for (key, color) in [
+        ("color_a", &mut layer.color_a),
+        ("color_b", &mut layer.color_b),
+    ] {
+        if let Some(value) = table.get(key) {
+            *color = parse_color(value).unwrap_or_else(|e| {
+                ctx.push(format!("{}: {}", key, e));
+                0
+            });
+        }
+    }
+    for (key, alpha) in [
+        ("alpha_a", &mut layer.alpha_a),
+        ("alpha_b", &mut layer.alpha_b),
+    ] {
+        if let Some(value) = table.get(key) {
+            *alpha = parse_alpha(value).unwrap_or_else(|e| {
+                ctx.push(format!("{} = {} {}", key, value, e));
+                15
+            });
+        }
+    }
+    if let Some(value) = table.get("direction") {
+        layer.direction = parse_direction(value).unwrap_or_else(|e| {
+            ctx.push(format!("direction: {}", e));
+            DIR_UP
+        });
+    }
+
+    for (i, field_name) in BYTE_FIELDS.iter().enumerate() {
+        let spec = meta.fields.iter().find(|f| f.field_name == *field_name);
+        let by_label = spec
+            .filter(|spec| is_alias(&spec.label))
+            .and_then(|spec| table.get(&spec.label).map(|v| (spec, v)));
+        let raw = table.get(*field_name);
+
+        let byte = match (raw, by_label) {
+            (Some(_), Some((spec, _))) => {
+                ctx.push(format!(
+                    "`{}` and its label `{}` are both set",
+                    field_name, spec.label
+                ));
+                continue;
+            }
+            (Some(value), None) => parse_byte(value, spec, true),
+            (None, Some((spec, value))) => parse_byte(value, Some(spec), false),
+            (None, None) => continue,
+        };
+        let byte = byte.unwrap_or_else(|e| {
+            let key = match by_label {
+                Some((spec, _)) => spec.label.as_str(),
+                None => field_name,
+            };
+            let value = raw.or(by_label.map(|(_, v)| v)).unwrap();
+            ctx.push(format!("{} = {} {}", key, value, e));
+            0
+        });
+        if i == 0 {
+            layer.intensity = byte;
+        } else {
+            layer.params[i - 1] = byte;
+        }
+    }
+
+    layer
+}
+
+/// Whether a metadata label can be used as a key (`-` and `(varies)` cannot)
+fn is_alias(label: &str) -> bool {
+    !label.is_empty()
+        && label
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+        && !BYTE_FIELDS.contains(&label)
+        && !LAYER_KEYS.contains(&label)
+}
+
+fn field_by_label<'a>(meta: &'a OpcodeMeta, key: &str) -> Option<&'a FieldSpec> {
+    meta.fields
+        .iter()
+        .find(|spec| spec.label == key && is_alias(&spec.label))
+}
+
+/// Parse a byte field
+///
+/// Integers given by raw name are stored as-is; everything else is a value
+/// in the field's units, mapped through its metadata.
+fn parse_byte(value: &Value, spec: Option<&FieldSpec>, raw_name: bool) -> Result<u8, String> {
+    if raw_name && (value.is_i64() || value.is_u64()) {
+        return value
+            .as_u64()
+            .and_then(|n| u8::try_from(n).ok())
+            .ok_or_else(|| "is out of range (0-255)".to_string());
+    }
+    let Some(x) = value.as_f64() else {
+        return Err("must be a number".to_string());
+    };
+    let (min, max) = match spec.map(|spec| (spec.map, spec.min, spec.max)) {
+        Some((MapKind::U8Lerp, Some(min), Some(max))) => (min, max),
+        Some((MapKind::U8_01, _, _)) | None => (0.0, 1.0),
+        _ => return Err("only takes raw byte values (0-255)".to_string()),
+    };
+    let range_error = || format!("is out of range ({}-{})", min, max);
+    let (min, max) = (min as f64, max as f64);
+    let tolerance = (max - min).abs() * 1e-6;
+    if x < min - tolerance || x > max + tolerance {
+        return Err(range_error());
+    }
+    let t = ((x - min) / (max - min)).clamp(0.0, 1.0);
+    Ok((t * 255.0).round() as u8)
+}
+
+/// Parse a name from `names` (case-insensitive) or an index into it
+fn parse_named(value: &Value, names: &[&str], what: &str) -> Result<u8, String> {
+    match value {
+        Value::String(s) => names
+            .iter()
+            .position(|name| name.eq_ignore_ascii_case(s))
+            .map(|i| i as u8)
+            .ok_or_else(|| {
+                format!(
+                    "unknown {} `{}` (expected one of: {})",
+                    what,
+                    s,
+                    names.join(", ")
+                )
+            }),
+        _ => match value.as_u64() {
+            Some(n) if (n as usize) < names.len() => Ok(n as u8),
+            _ => Err(format!("{} is out of range (0-{})", value, names.len() - 1)),
+        },
+    }
+}
+
+/// Parse a variant or domain from the opcode's metadata
+///
+/// Opcodes without named entries still accept any index that fits the bits.
+fn parse_enum(value: &Value, names: &[String], bit_range: usize, what: &str) -> Result<u8, String> {
+    if names.is_empty() {
+        if value.is_string() {
+            return Err(format!("this opcode has no named {}s", what));
+        }
+        let names: Vec<&str> = vec![""; bit_range];
+        return parse_named(value, &names, what);
+    }
+    let names: Vec<&str> = names.iter().map(String::as_str).collect();
+    parse_named(value, &names, what)
+}
+
+fn parse_region(value: &Value) -> Result<u8, String> {
+    let parse_one = |s: &str| {
+        REGIONS
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(s))
+            .map(|(_, bits)| *bits)
+            .ok_or_else(|| format!("unknown region `{}` (expected all, sky, walls or floor)", s))
+    };
+    match value {
+        Value::String(s) => parse_one(s),
+        Value::Array(items) => items.iter().try_fold(0, |mask, item| match item {
+            Value::String(s) => Ok(mask | parse_one(s)?),
+            _ => Err("region lists must contain names".to_string()),
+        }),
+        _ => match value.as_u64() {
+            Some(n) if n <= REGION_ALL as u64 => Ok(n as u8),
+            _ => Err(format!("{} is out of range (0-7)", value)),
+        },
+    }
+}
+
+/// Parse `"#RRGGBB"`, `"0xRRGGBB"`, an integer, or an `[r, g, b]` array
+fn parse_color(value: &Value) -> Result<u32, String> {
+    match value {
+        Value::String(s) => {
+            let hex = s
+                .strip_prefix('#')
+                .or_else(|| s.strip_prefix("0x"))
+                .unwrap_or(s);
+            if hex.len() != 6 {
+                return Err(format!("`{}` is not an RRGGBB color", s));
+            }
+            u32::from_str_radix(hex, 16).map_err(|_| format!("`{}` is not an RRGGBB color", s))
+        }
+        Value::Array(items) if items.len() == 3 => {
+            items.iter().try_fold(0, |rgb, item| match item.as_u64() {
+                Some(c) if c <= 255 => Ok((rgb << 8) | c as u32),
+                _ => Err(format!("{} is out of range (0-255)", item)),
+            })
+        }
+        _ => match value.as_u64() {
+            Some(n) if n <= 0xFFFFFF => Ok(n as u32),
+            _ => Err(format!("{} is not an RRGGBB color", value)),
+        },
+    }
+}
+
+/// Parse an alpha as a raw nibble (0-15) or a float (0.0-1.0)
+fn parse_alpha(value: &Value) -> Result<u8, String> {
+    if value.is_i64() || value.is_u64() {
+        return match value.as_u64() {
+            Some(n) if n <= 15 => Ok(n as u8),
+            _ => Err("is out of range (0-15)".to_string()),
+        };
+    }
+    match value.as_f64() {
+        Some(x) if (0.0..=1.0).contains(&x) => Ok((x * 15.0).round() as u8),
+        Some(_) => Err("is out of range (0.0-1.0)".to_string()),
+        None => Err("must be a number".to_string()),
+    }
+}
+
+/// Parse a direction name, an `[x, y, z]` vector, or an authored `0xUUVV` value
+fn parse_direction(value: &Value) -> Result<u16, String> {
+    match value {
+        Value::String(s) => DIRECTIONS
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(s))
+            .and_then(|(_, v)| encode_direction(*v))
+            .ok_or_else(|| format!("unknown direction `{}`", s)),
+        Value::Array(items) if items.len() == 3 => {
+            let mut v = [0.0; 3];
+            for (out, item) in v.iter_mut().zip(items) {
+                *out = item.as_f64().ok_or("vector components must be numbers")? as f32;
+            }
+            encode_direction(v).ok_or_else(|| "vector has zero length".to_string())
+        }
+        _ => match value.as_u64() {
+            // Authored as 0xUUVV, like the lo() helper; stored with u in the low byte
+            Some(n) if n <= 0xFFFF => Ok(((n >> 8) | ((n & 0xFF) << 8)) as u16),
+            _ => Err(format!("{} is not a direction", value)),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn compile_toml(source: &str) -> Result<[Layer; LAYER_COUNT], Vec<String>> {
+        compile(&toml::from_str(source).unwrap())
+    }
+
+    #[test]
+    fn test_compile_plane_layer() {
+        let layers = compile_toml(
+            r##"
+            [[layer]]
+            opcode = "PLANE"
+            region = "floor"
+            blend = "lerp"
+            variant = "PAVEMENT"
+            color_a = "#060810"
+            color_b = 0x020304
+            intensity = 200
+            param_a = 100
+            param_b = 25
+            param_c = 140
+            direction = 0x80FF
+            alpha_b = 14
+            "##,
+        )
+        .unwrap();
+
+        assert_eq!(
+            layers[0].pack(),
+            [0x7967_0608_1002_0304, 0xC864_198C_00FF_80FE]
+        );
+        assert!(layers[1..].iter().all(|layer| layer.pack() == [0, 0]));
+    }
+
+    #[test]
+    fn test_labels_map_field_units() {
+        let layers = compile_toml(
+            r#"
+            [[layer]]
+            opcode = "SCATTER"
+            density = 256.0
+            size = 0.001
+            brightness = 0.5
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(layers[0].params[0], 255);
+        assert_eq!(layers[0].params[1], 0);
+        assert_eq!(layers[0].intensity, 128);
+    }
+
+    #[test]
+    fn test_reports_every_error() {
+        let errors = compile_toml(
+            r#"
+            [[layer]]
+            opcode = "SCATTER"
+            param_a = 300
+            variant = "COMETS"
+
+            [[layer]]
+            opcode = "WARP"
+            "#,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            errors,
+            [
+                "layer 0 (SCATTER): variant: unknown variant `COMETS` (expected one of: STARS, DUST, WINDOWS, BUBBLES, EMBERS, RAIN, SNOW)",
+                "layer 0 (SCATTER): param_a = 300 is out of range (0-255)",
+                "layer 1: unknown opcode `WARP`",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_mapped_value_out_of_range() {
+        let errors = compile_toml(
+            r#"
+            [[layer]]
+            opcode = "SCATTER"
+            density = 0.5
+            "#,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            errors,
+            ["layer 0 (SCATTER): density = 0.5 is out of range (1-256)"]
+        );
+    }
+
+    #[test]
+    fn test_too_many_layers() {
+        let source = "[[layer]]\nopcode = \"NOP\"\n".repeat(LAYER_COUNT + 1);
+        let errors = compile_toml(&source).unwrap_err();
+        assert_eq!(errors, ["9 layers defined, but the EPU has only 8"]);
+    }
+}
@@ -162,10 +162,11 @@ fn generate_manifest(game_id: &str, game_title: &str, is_rust_project: bool) ->
     content.push_str("#   1 = Matcap (stylized lighting via matcap texture)\n");
     content.push_str("#   2 = PBR Metallic-Roughness (physically based rendering)\n");
     content.push_str("#   3 = Blinn-Phong Specular-Shininess (classic lighting)\n");
+    content.push_str("#   4 = Toon (cel shading with banded light and outlines)\n");
     content.push_str("#\n");
     content.push_str("# Textures are automatically compressed based on render mode:\n");
     content.push_str("#   Mode 0: RGBA8 (uncompressed)\n");
-    content.push_str("#   Mode 1-3: BC7 (4x compression)\n");
+    content.push_str("#   Mode 1-4: BC7 (4x compression)\n");
     content.push('\n');
 
     // Assets section
@@ -185,10 +186,30 @@ fn generate_manifest(game_id: &str, game_title: &str, is_rust_project: bool) ->
     content.push_str("# id = \"jump\"\n");
     content.push_str("# path = \"assets/jump.wav\"\n");
     content.push_str("#\n");
+    content.push_str("# [[assets.streams]]     # Long music, decoded while playing\n");
+    content.push_str("# id = \"bgm\"\n");
+    content.push_str("# path = \"assets/bgm.wav\"\n");
+    content.push_str("#\n");
     content.push_str("# [[assets.keyframes]]\n");
     content.push_str("# id = \"walk\"\n");
     content.push_str("# path = \"assets/walk.nczxanim\"\n");
     content.push_str("#\n");
+    content.push_str("# [[assets.paths]]       # Spline for rom_path()/path_eval()\n");
+    content.push_str("# id = \"track1\"\n");
+    content.push_str("# closed = true\n");
+    content.push_str("# points = [[0, 0, 0], [40, 0, 0], [40, 0, 40], [0, 0, 40]]\n");
+    content.push_str("#\n");
+    content.push_str("# [[assets.cubemaps]]    # Sky for rom_cubemap()/sky_draw()\n");
+    content.push_str("# id = \"sky\"\n");
+    content
+        .push_str("# equirect = \"assets/sky.png\"   # or faces = [6 images: +X -X +Y -Y +Z -Z]\n");
+    content.push_str("#\n");
+    content.push_str("# [[assets.strings]]     # Translations for rom_strings()/str_get()\n");
+    content.push_str("# id = \"text\"\n");
+    content.push_str(
+        "# path = \"assets/text.csv\"    # key,en,fr,... or fluent = [\"en.ftl\", \"fr.ftl\"]\n",
+    );
+    content.push_str("#\n");
     content.push_str("# [[assets.data]]\n");
     content.push_str("# id = \"levels\"\n");
     content.push_str("# path = \"assets/levels.bin\"\n");
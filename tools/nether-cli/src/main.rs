@@ -8,6 +8,9 @@
 //! - `nether build` - Build game: compile + pack (main command)
 //! - `nether run` - Build and launch in emulator
 //! - `nether preview` - Browse ROM assets without running the game
+//! - `nether patch` - Create or apply delta updates between ROM builds
+//! - `nether rendezvous` - Run a lobby registry for in-game lobbies
+//! - `nether epu` - Compile EPU environment descriptions into layer arrays
 //!
 //! # Usage
 //!
@@ -49,10 +52,13 @@
 mod audio_convert;
 mod build;
 mod compile;
+mod epu;
 mod init;
 mod manifest;
 mod pack;
+mod patch;
 mod preview;
+mod rendezvous;
 mod replay;
 mod run;
 mod watch;
@@ -90,11 +96,26 @@ enum Commands {
     /// Browse ROM assets without running the game
     Preview(preview::PreviewArgs),
 
+    /// Delta updates: create or apply .ncpatch files between ROM builds
+    Patch {
+        #[command(subcommand)]
+        action: patch::PatchAction,
+    },
+
     /// Replay commands: record, play, execute scripts
     Replay {
         #[command(subcommand)]
         action: replay::ReplayAction,
     },
+
+    /// Run a rendezvous server for in-game lobby browsing
+    Rendezvous(rendezvous::RendezvousArgs),
+
+    /// EPU authoring: compile environment descriptions into layer arrays
+    Epu {
+        #[command(subcommand)]
+        action: epu::EpuAction,
+    },
 }
 
 fn main() -> Result<()> {
@@ -110,6 +131,9 @@ fn main() -> Result<()> {
         Commands::Build(args) => build::execute(args),
         Commands::Run(args) => run::execute(args),
         Commands::Preview(args) => preview::execute(args),
+        Commands::Patch { action } => patch::execute(action),
         Commands::Replay { action } => replay::execute(action),
+        Commands::Rendezvous(args) => rendezvous::execute(args),
+        Commands::Epu { action } => epu::execute(action),
     }
 }
@@ -3,10 +3,12 @@
 //! Shared manifest structures used by compile, pack, and build commands.
 
 use anyhow::{Context, Result};
+use nethercore_shared::capabilities::Capabilities;
 use nethercore_shared::console::TickRate;
 use nethercore_shared::is_safe_game_id;
 use serde::Deserialize;
 use std::path::{Path, PathBuf};
+use zx_common::PathKind;
 
 /// Nether.toml manifest structure
 #[derive(Debug, Deserialize)]
@@ -18,6 +20,9 @@ pub struct NetherManifest {
     pub assets: AssetsSection,
     #[serde(default)]
     pub netplay: NetplaySection,
+    /// Host capabilities the game needs (saves, network, capture)
+    #[serde(default)]
+    pub capabilities: Capabilities,
 }
 
 /// Game metadata section
@@ -38,7 +43,14 @@ pub struct GameSection {
     #[serde(default)]
     pub compress_textures: bool,
 
-    /// Render mode: 0=Lambert, 1=Matcap, 2=MRBP, 3=SSBP
+    /// GPU family compressed textures are encoded for: "bc" (desktop),
+    /// "etc2" (mobile/WebGL) or "both" (stores each texture twice; the
+    /// runtime uploads whichever the GPU supports).
+    /// Default: "bc"
+    #[serde(default)]
+    pub texture_target: TextureTarget,
+
+    /// Render mode: 0=Lambert, 1=Matcap, 2=MRBP, 3=SSBP, 4=Toon
     /// Default: 0 (Lambert)
     #[serde(default)]
     pub render_mode: u8,
@@ -49,10 +61,46 @@ pub struct GameSection {
     #[serde(default = "default_tick_rate")]
     pub tick_rate: u32,
 
-    /// Maximum players supported (1-4).
+    /// Maximum players supported (1-8).
     /// Default: 4 (multiplayer is Nethercore's core feature)
     #[serde(default = "default_max_players")]
     pub max_players: u8,
+
+    /// Allow players to sideload override packs (.nczxmod) that replace
+    /// textures, sounds and meshes by ID.
+    /// Default: false
+    #[serde(default)]
+    pub allow_override_packs: bool,
+}
+
+/// GPU family compressed textures are encoded for
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum TextureTarget {
+    /// BC7 (desktop GPUs)
+    #[default]
+    Bc,
+    /// ETC2 (mobile and WebGL GPUs)
+    Etc2,
+    /// BC7 plus an ETC2 copy
+    Both,
+}
+
+/// Per-texture compression quality (`quality` on a texture or atlas entry)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum TextureQuality {
+    /// Quick encode, fine for most textures
+    #[default]
+    Fast,
+    /// Slower, more careful encode (gradients, hero textures)
+    High,
+    /// Keep this texture uncompressed RGBA8 even when compression is on
+    /// (UI, fonts, palettes)
+    Lossless,
+    /// Store palette indices from an indexed (or 8-bit grayscale) PNG,
+    /// drawn through the palette bound with `palette_bind()`
+    Indexed,
 }
 
 fn default_tick_rate() -> u32 {
@@ -106,10 +154,21 @@ pub struct AssetsSection {
     pub animations: Vec<AssetEntry>, // Alias for keyframes
     #[serde(default)]
     pub sounds: Vec<AssetEntry>,
+    /// Long music tracks kept QOA-compressed and decoded during playback
+    #[serde(default)]
+    pub streams: Vec<AssetEntry>,
     #[serde(default)]
     pub trackers: Vec<AssetEntry>,
     #[serde(default)]
     pub data: Vec<AssetEntry>,
+    #[serde(default)]
+    pub atlases: Vec<AtlasEntry>,
+    #[serde(default)]
+    pub paths: Vec<PathEntry>,
+    #[serde(default)]
+    pub cubemaps: Vec<CubemapEntry>,
+    #[serde(default)]
+    pub strings: Vec<StringsEntry>,
 }
 
 /// Single asset entry
@@ -147,6 +206,86 @@ pub struct AssetEntry {
     /// registering it as a playable tracker.
     #[serde(default)]
     pub patterns: Option<bool>,
+
+    /// For texture assets: compression quality (default: "fast")
+    #[serde(default)]
+    pub quality: TextureQuality,
+}
+
+/// Texture atlas entry (several images packed into one texture)
+#[derive(Debug, Deserialize)]
+pub struct AtlasEntry {
+    /// Asset ID, usable with both `rom_atlas()` and `rom_texture()`
+    pub id: String,
+
+    /// Sprite image paths (relative to nether.toml).
+    /// The list order sets the sprite indices used by `atlas_bind()`.
+    pub sprites: Vec<String>,
+
+    /// Edge-extended border around each sprite in pixels (default: 2)
+    #[serde(default)]
+    pub padding: Option<u32>,
+
+    /// Compression quality (default: "fast")
+    #[serde(default)]
+    pub quality: TextureQuality,
+}
+
+/// Spline path entry (control points authored inline)
+#[derive(Debug, Deserialize)]
+pub struct PathEntry {
+    /// Asset ID used with `rom_path()`
+    pub id: String,
+
+    /// Curve type: "catmull-rom" (default, passes through every point) or
+    /// "bezier" (anchor, handle, handle, anchor, ...)
+    #[serde(default)]
+    pub kind: PathKind,
+
+    /// Whether the last point connects back to the first (default: false)
+    #[serde(default)]
+    pub closed: bool,
+
+    /// Control points as [x, y, z] in world units
+    pub points: Vec<[f32; 3]>,
+}
+
+/// Cubemap sky entry (six face images or one equirectangular panorama)
+#[derive(Debug, Deserialize)]
+pub struct CubemapEntry {
+    /// Asset ID used with `rom_cubemap()`
+    pub id: String,
+
+    /// Square face images in the order +X, -X, +Y, -Y, +Z, -Z (relative to
+    /// nether.toml)
+    #[serde(default)]
+    pub faces: Vec<String>,
+
+    /// Equirectangular (2:1) panorama, converted to faces at pack time.
+    /// The image center faces -Z.
+    #[serde(default)]
+    pub equirect: Option<String>,
+
+    /// Face size in pixels when converting a panorama (default: width / 4)
+    #[serde(default)]
+    pub size: Option<u32>,
+}
+
+/// Localized string table entry (one CSV or one Fluent file per language)
+#[derive(Debug, Deserialize)]
+pub struct StringsEntry {
+    /// Asset ID used with `rom_strings()`
+    pub id: String,
+
+    /// CSV with a header row `key,<lang>,<lang>,...` and one row per key
+    /// (relative to nether.toml). The first language is the fallback.
+    #[serde(default)]
+    pub path: Option<String>,
+
+    /// Fluent (.ftl) files, one per language, named after the language
+    /// (e.g. `locales/en.ftl`). The first file is the fallback.
+    #[serde(default)]
+    pub fluent: Vec<String>,
 }
 
 impl NetherManifest {
@@ -268,9 +407,9 @@ Consider setting build.wasm in nether.toml. Using newest: {}",
             );
         }
 
-        if self.game.render_mode > 3 {
+        if self.game.render_mode > 4 {
             anyhow::bail!(
-                "Invalid render_mode {} in nether.toml (must be 0-3: 0=Lambert, 1=Matcap, 2=MR-Blinn-Phong, 3=Specular-Shininess)",
+                "Invalid render_mode {} in nether.toml (must be 0-4: 0=Lambert, 1=Matcap, 2=MR-Blinn-Phong, 3=Specular-Shininess, 4=Toon)",
                 self.game.render_mode
             );
         }
@@ -284,9 +423,9 @@ Consider setting build.wasm in nether.toml. Using newest: {}",
         }
 
         // Validate max_players
-        if self.game.max_players < 1 || self.game.max_players > 4 {
+        if self.game.max_players < 1 || self.game.max_players > 8 {
             anyhow::bail!(
-                "Invalid max_players {} in nether.toml (must be 1-4)",
+                "Invalid max_players {} in nether.toml (must be 1-8)",
                 self.game.max_players
             );
         }
@@ -539,6 +678,62 @@ enabled = true
         assert_eq!(manifest.tick_rate(), TickRate::Fixed120);
     }
 
+    #[test]
+    fn test_capabilities_default_to_denied() {
+        let manifest = NetherManifest::parse(
+            r#"
+[game]
+id = "test"
+title = "Test"
+author = "Author"
+version = "1.0.0"
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(manifest.capabilities, Capabilities::NONE);
+    }
+
+    #[test]
+    fn test_capabilities_explicit() {
+        let manifest = NetherManifest::parse(
+            r#"
+[game]
+id = "test"
+title = "Test"
+author = "Author"
+version = "1.0.0"
+
+[capabilities]
+saves = true
+network = true
+"#,
+        )
+        .unwrap();
+
+        assert!(manifest.capabilities.saves);
+        assert!(manifest.capabilities.network);
+        assert!(!manifest.capabilities.capture);
+    }
+
+    #[test]
+    fn test_capabilities_unknown_rejected() {
+        let result = NetherManifest::parse(
+            r#"
+[game]
+id = "test"
+title = "Test"
+author = "Author"
+version = "1.0.0"
+
+[capabilities]
+filesystem = true
+"#,
+        );
+
+        assert!(result.is_err());
+    }
+
     #[test]
     fn test_tick_rate_invalid() {
         let manifest = NetherManifest::parse(
@@ -574,7 +769,24 @@ max_players = 0
     }
 
     #[test]
-    fn test_max_players_invalid_five() {
+    fn test_max_players_eight_is_valid() {
+        let manifest = NetherManifest::parse(
+            r#"
+[game]
+id = "test"
+title = "Test"
+author = "Author"
+version = "1.0.0"
+max_players = 8
+"#,
+        )
+        .unwrap();
+
+        assert!(manifest.validate().is_ok());
+    }
+
+    #[test]
+    fn test_max_players_invalid_nine() {
         let manifest = NetherManifest::parse(
             r#"
 [game]
@@ -582,7 +794,7 @@ id = "test"
 title = "Test"
 author = "Author"
 version = "1.0.0"
-max_players = 5
+max_players = 9
 "#,
         )
         .unwrap();
@@ -0,0 +1,146 @@
+//! Texture atlas packing.
+
+use anyhow::{Context, Result};
+use image::RgbaImage;
+use zx_common::{AtlasSprite, PackedAtlas, PackedTexture};
+
+use super::texture::{pack_rgba, TextureEncoding};
+use crate::manifest::TextureQuality;
+
+/// Largest atlas edge in pixels
+pub const MAX_ATLAS_SIZE: u32 = 4096;
+
+/// Default edge-extended border around each sprite, in pixels
+///
+/// Keeps linear filtering and BC7 blocks from bleeding neighbours into a sprite.
+pub const DEFAULT_ATLAS_PADDING: u32 = 2;
+
+/// Load sprite images and pack them into one atlas texture
+///
+/// Returns the atlas texture (same ID as the atlas) and its sprite layout.
+/// Sprite indices follow the order of `paths`.
+pub fn load_atlas(
+    id: &str,
+    paths: &[std::path::PathBuf],
+    padding: u32,
+    encoding: TextureEncoding,
+    quality: TextureQuality,
+) -> Result<(PackedTexture, PackedAtlas)> {
+    if paths.is_empty() {
+        anyhow::bail!("Atlas '{}' has no sprites", id);
+    }
+    if quality == TextureQuality::Indexed {
+        anyhow::bail!("Atlas '{}' can't use quality = \"indexed\"", id);
+    }
+
+    let images = paths
+        .iter()
+        .map(|path| {
+            image::open(path)
+                .map(|img| img.to_rgba8())
+                .with_context(|| format!("Failed to load atlas sprite: {}", path.display()))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let (atlas, sprites) =
+        pack_images(&images, padding).with_context(|| format!("Failed to pack atlas '{}'", id))?;
+    let (width, height) = atlas.dimensions();
+
+    let texture = pack_rgba(id, atlas.as_raw(), width, height, encoding, quality)?;
+    let layout = PackedAtlas::new(id, width as u16, height as u16, sprites);
+    Ok((texture, layout))
+}
+
+/// Shelf-pack images into a power-of-two wide atlas
+///
+/// Each image gets `padding` pixels of edge extension on every side. The atlas
+/// height is rounded up to a multiple of 4 so it can be block-compressed.
+pub fn pack_images(images: &[RgbaImage], padding: u32) -> Result<(RgbaImage, Vec<AtlasSprite>)> {
+    let cells: Vec<(u32, u32)> = images
+        .iter()
+        .map(|img| (img.width() + padding * 2, img.height() + padding * 2))
+        .collect();
+
+    let widest = cells.iter().map(|&(w, _)| w).max().unwrap_or(1);
+    if widest > MAX_ATLAS_SIZE {
+        anyhow::bail!(
+            "Sprite is {} pixels wide with padding (max {})",
+            widest,
+            MAX_ATLAS_SIZE
+        );
+    }
+
+    // Tallest first keeps shelves tight
+    let mut order: Vec<usize> = (0..images.len()).collect();
+    order.sort_by_key(|&i| (std::cmp::Reverse(cells[i].1), std::cmp::Reverse(cells[i].0)));
+
+    // Try power-of-two widths until the atlas is no taller than it is wide
+    let mut width = widest.next_power_of_two().max(4);
+    let (positions, height) = loop {
+        let (positions, height) = shelf_pack(&cells, &order, width);
+        if height <= width || width >= MAX_ATLAS_SIZE {
+            break (positions, height);
+        }
+        width *= 2;
+    };
+    let height = height.next_multiple_of(4).max(4);
+    if height > MAX_ATLAS_SIZE {
+        anyhow::bail!(
+            "Sprites do not fit in a {}x{} atlas",
+            MAX_ATLAS_SIZE,
+            MAX_ATLAS_SIZE
+        );
+    }
+
+    let mut atlas = RgbaImage::new(width, height);
+    let mut sprites = Vec::with_capacity(images.len());
+    for (img, &(cell_x, cell_y)) in images.iter().zip(&positions) {
+        blit_padded(&mut atlas, img, cell_x, cell_y, padding);
+        sprites.push(AtlasSprite {
+            x: (cell_x + padding) as u16,
+            y: (cell_y + padding) as u16,
+            width: img.width() as u16,
+            height: img.height() as u16,
+        });
+    }
+
+    Ok((atlas, sprites))
+}
+
+/// Place cells left to right in rows of the given width
+///
+/// Returns each cell's top-left corner (indexed like `cells`) and the total height.
+fn shelf_pack(cells: &[(u32, u32)], order: &[usize], width: u32) -> (Vec<(u32, u32)>, u32) {
+    let mut positions = vec![(0, 0); cells.len()];
+    let (mut x, mut y, mut shelf_height) = (0, 0, 0);
+
+    for &i in order {
+        let (w, h) = cells[i];
+        if x + w > width {
+            x = 0;
+            y += shelf_height;
+            shelf_height = 0;
+        }
+        positions[i] = (x, y);
+        x += w;
+        shelf_height = shelf_height.max(h);
+    }
+
+    (positions, y + shelf_height)
+}
+
+/// Copy `img` into the atlas, extending its edge pixels into the padding
+fn blit_padded(atlas: &mut RgbaImage, img: &RgbaImage, cell_x: u32, cell_y: u32, padding: u32) {
+    let (w, h) = img.dimensions();
+    if w == 0 || h == 0 {
+        return;
+    }
+
+    for y in 0..h + padding * 2 {
+        for x in 0..w + padding * 2 {
+            let src_x = x.saturating_sub(padding).min(w - 1);
+            let src_y = y.saturating_sub(padding).min(h - 1);
+            atlas.put_pixel(cell_x + x, cell_y + y, *img.get_pixel(src_x, src_y));
+        }
+    }
+}
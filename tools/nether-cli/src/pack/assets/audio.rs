@@ -2,7 +2,7 @@
 
 use anyhow::{Context, Result};
 use std::collections::HashSet;
-use zx_common::{PackedSound, PackedTracker, TrackerFormat};
+use zx_common::{PackedSound, PackedStream, PackedTracker, TrackerFormat};
 
 use super::utils::detect_tracker_format;
 
@@ -56,6 +56,12 @@ pub fn load_sound(id: &str, path: &std::path::Path) -> Result<PackedSound> {
     })
 }
 
+/// Load a stream from a WAV file (QOA-compressed for chunked playback)
+pub fn load_stream(id: &str, path: &std::path::Path) -> Result<PackedStream> {
+    let sound = load_sound(id, path)?;
+    Ok(PackedStream::from_pcm(id, &sound.data))
+}
+
 /// Validate that all non-empty instrument names in a tracker
 /// reference loaded sounds in the manifest
 pub fn validate_tracker_samples(
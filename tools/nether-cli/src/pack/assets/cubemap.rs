@@ -0,0 +1,129 @@
+//! Cubemap loading (six face images or an equirectangular panorama).
+
+use anyhow::{Context, Result};
+use image::RgbaImage;
+use std::f32::consts::{PI, TAU};
+use std::path::Path;
+use zx_common::{cube_face_direction, PackedCubemap, CUBEMAP_FACES};
+
+use crate::manifest::CubemapEntry;
+
+/// Load a cubemap entry into a packed cubemap
+pub fn load_cubemap(project_dir: &Path, entry: &CubemapEntry) -> Result<PackedCubemap> {
+    let open = |path: &str| {
+        let path = project_dir.join(path);
+        image::open(&path)
+            .map(|img| img.to_rgba8())
+            .with_context(|| format!("Failed to load cubemap image: {}", path.display()))
+    };
+
+    match (&entry.equirect, entry.faces.is_empty()) {
+        (Some(panorama), true) => {
+            let image = open(panorama)?;
+            let size = entry.size.unwrap_or(image.width() / 4);
+            if size == 0 || size > u16::MAX as u32 {
+                anyhow::bail!("Cubemap '{}' has invalid face size {}", entry.id, size);
+            }
+            Ok(PackedCubemap::new(
+                &entry.id,
+                size,
+                equirect_to_faces(&image, size),
+            ))
+        }
+        (None, false) => {
+            if entry.faces.len() != CUBEMAP_FACES {
+                anyhow::bail!(
+                    "Cubemap '{}' has {} faces; expected 6 (+X, -X, +Y, -Y, +Z, -Z)",
+                    entry.id,
+                    entry.faces.len()
+                );
+            }
+            let faces = entry
+                .faces
+                .iter()
+                .map(|path| open(path))
+                .collect::<Result<Vec<_>>>()?;
+
+            let size = faces[0].width();
+            if let Some((i, face)) = faces
+                .iter()
+                .enumerate()
+                .find(|(_, f)| f.dimensions() != (size, size))
+            {
+                anyhow::bail!(
+                    "Cubemap '{}' face {} is {}x{}; faces must be square and {}x{} like the first",
+                    entry.id,
+                    i,
+                    face.width(),
+                    face.height(),
+                    size,
+                    size
+                );
+            }
+            if size > u16::MAX as u32 {
+                anyhow::bail!("Cubemap '{}' faces are too large ({}px)", entry.id, size);
+            }
+            let data = faces
+                .iter()
+                .flat_map(|f| f.as_raw().iter().copied())
+                .collect();
+            Ok(PackedCubemap::new(&entry.id, size, data))
+        }
+        _ => anyhow::bail!(
+            "Cubemap '{}' needs either `faces` (6 images) or `equirect`, not both",
+            entry.id
+        ),
+    }
+}
+
+/// Resample an equirectangular panorama into six `size` x `size` faces
+///
+/// The panorama's center column faces -Z, with +X to its right and the top
+/// row straight up. Texels are filtered bilinearly, wrapping horizontally.
+pub fn equirect_to_faces(image: &RgbaImage, size: u32) -> Vec<u8> {
+    let (width, height) = image.dimensions();
+    let mut data = Vec::with_capacity((size * size * 4) as usize * CUBEMAP_FACES);
+
+    for face in 0..CUBEMAP_FACES {
+        for y in 0..size {
+            for x in 0..size {
+                let u = (x as f32 + 0.5) / size as f32;
+                let v = (y as f32 + 0.5) / size as f32;
+                let dir = cube_face_direction(face, u, v);
+
+                let lon = dir.x.atan2(-dir.z);
+                let lat = dir.y.clamp(-1.0, 1.0).asin();
+                let px = (0.5 + lon / TAU) * width as f32 - 0.5;
+                let py = (0.5 - lat / PI) * height as f32 - 0.5;
+                data.extend_from_slice(&sample_bilinear(image, px, py));
+            }
+        }
+    }
+    data
+}
+
+/// Bilinear sample at pixel coordinates (x wraps, y clamps)
+fn sample_bilinear(image: &RgbaImage, x: f32, y: f32) -> [u8; 4] {
+    let (width, height) = image.dimensions();
+    let (x0, y0) = (x.floor(), y.floor());
+    let (fx, fy) = (x - x0, y - y0);
+
+    let texel = |tx: i64, ty: i64| {
+        let tx = tx.rem_euclid(width as i64) as u32;
+        let ty = ty.clamp(0, height as i64 - 1) as u32;
+        image.get_pixel(tx, ty).0.map(f32::from)
+    };
+    let (x0, y0) = (x0 as i64, y0 as i64);
+    let [a, b, c, d] = [
+        texel(x0, y0),
+        texel(x0 + 1, y0),
+        texel(x0, y0 + 1),
+        texel(x0 + 1, y0 + 1),
+    ];
+
+    std::array::from_fn(|i| {
+        let top = a[i] + (b[i] - a[i]) * fx;
+        let bottom = c[i] + (d[i] - c[i]) * fx;
+        (top + (bottom - top) * fy).round() as u8
+    })
+}
@@ -33,6 +33,8 @@ pub fn load_mesh(id: &str, path: &std::path::Path) -> Result<PackedMesh> {
                 index_count: converted.index_count,
                 vertex_data: converted.vertex_data,
                 index_data: converted.indices,
+                sockets: converted.sockets,
+                morph_targets: converted.morph_targets,
             })
         }
 
@@ -48,6 +50,8 @@ pub fn load_mesh(id: &str, path: &std::path::Path) -> Result<PackedMesh> {
                 index_count: converted.index_count,
                 vertex_data: converted.vertex_data,
                 index_data: converted.indices,
+                sockets: converted.sockets,
+                morph_targets: converted.morph_targets,
             })
         }
 
@@ -114,5 +118,7 @@ fn load_mesh_native(id: &str, path: &std::path::Path) -> Result<PackedMesh> {
         index_count: header.index_count,
         vertex_data,
         index_data,
+        sockets: Vec::new(),
+        morph_targets: Vec::new(),
     })
 }
@@ -2,16 +2,20 @@
 
 use anyhow::{Context, Result};
 use std::collections::{HashMap, HashSet};
-use zx_common::{PackedSound, TextureFormat, ZXDataPack};
+use zx_common::{PackedSound, ZXDataPack};
 
 use crate::manifest::AssetsSection;
 
 // Sub-modules
 pub mod animation;
+pub mod atlas;
 pub mod audio;
+pub mod cubemap;
 pub mod data;
 pub mod mesh;
+pub mod path;
 pub mod skeleton;
+pub mod strings;
 pub mod texture;
 pub mod utils;
 
@@ -20,11 +24,15 @@ mod tests;
 
 // Re-export commonly used functions for backwards compatibility
 pub use animation::load_keyframes;
-pub use audio::{load_sound, load_tracker};
+pub use atlas::load_atlas;
+pub use audio::{load_sound, load_stream, load_tracker};
+pub use cubemap::load_cubemap;
 pub use data::load_data;
 pub use mesh::load_mesh;
+pub use path::load_path;
 pub use skeleton::load_skeleton;
-pub use texture::load_texture;
+pub use strings::load_strings;
+pub use texture::{load_texture, TextureEncoding};
 pub use utils::{detect_tracker_format, hash_sample_data, require_id, sanitize_name};
 
 /// Expanded keyframe entry (after wildcard resolution)
@@ -39,7 +47,7 @@ struct ExpandedKeyframeEntry {
 pub fn load_assets(
     project_dir: &std::path::Path,
     assets: &AssetsSection,
-    texture_format: TextureFormat,
+    texture_encoding: TextureEncoding,
 ) -> Result<ZXDataPack> {
     use rayon::prelude::*;
 
@@ -53,10 +61,35 @@ pub fn load_assets(
         .map(|entry| {
             let id = require_id(entry, "Texture")?;
             let path = project_dir.join(&entry.path);
-            load_texture(id, &path, texture_format)
+            load_texture(id, &path, texture_encoding, entry.quality)
         })
         .collect();
-    let textures = textures?;
+    let mut textures = textures?;
+
+    // Pack atlases in parallel (each becomes a texture plus a sprite layout)
+    for entry in &assets.atlases {
+        if assets
+            .textures
+            .iter()
+            .any(|t| t.id.as_deref() == Some(&entry.id))
+        {
+            return Err(anyhow::anyhow!(
+                "Atlas ID '{}' conflicts with a texture of the same ID",
+                entry.id
+            ));
+        }
+    }
+    let atlases: Result<Vec<_>> = assets
+        .atlases
+        .par_iter()
+        .map(|entry| {
+            let paths: Vec<_> = entry.sprites.iter().map(|p| project_dir.join(p)).collect();
+            let padding = entry.padding.unwrap_or(atlas::DEFAULT_ATLAS_PADDING);
+            load_atlas(&entry.id, &paths, padding, texture_encoding, entry.quality)
+        })
+        .collect();
+    let (atlas_textures, atlases): (Vec<_>, Vec<_>) = atlases?.into_iter().unzip();
+    textures.extend(atlas_textures);
 
     // Load meshes in parallel
     let meshes: Result<Vec<_>> = assets
@@ -388,6 +421,18 @@ pub fn load_assets(
     // Convert sound_map back to Vec for data pack
     let sounds: Vec<PackedSound> = sound_map.into_values().collect();
 
+    // Load streams in parallel (QOA-compressed, decoded during playback)
+    let streams: Result<Vec<_>> = assets
+        .streams
+        .par_iter()
+        .map(|entry| {
+            let id = require_id(entry, "Stream")?;
+            let path = project_dir.join(&entry.path);
+            load_stream(id, &path)
+        })
+        .collect();
+    let streams = streams?;
+
     // Load raw data in parallel
     let data: Result<Vec<_>> = assets
         .data
@@ -400,18 +445,50 @@ pub fn load_assets(
         .collect();
     let data = data?;
 
+    // Validate paths (control points are authored inline in nether.toml)
+    let paths: Result<Vec<_>> = assets.paths.iter().map(load_path).collect();
+    let paths = paths?;
+
+    // Load cubemaps in parallel (panoramas are resampled to faces)
+    let cubemaps: Result<Vec<_>> = assets
+        .cubemaps
+        .par_iter()
+        .map(|entry| load_cubemap(project_dir, entry))
+        .collect();
+    let cubemaps = cubemaps?;
+
+    // Import string tables (small, loaded sequentially)
+    let strings: Result<Vec<_>> = assets
+        .strings
+        .iter()
+        .map(|entry| load_strings(project_dir, entry))
+        .collect();
+    let strings = strings?;
+
     // Print results (after parallel loading completes)
     for texture in &textures {
-        let format_str = if texture.format.is_bc7() {
-            " [BC7]"
-        } else {
-            ""
+        let shows_format = texture.format.is_compressed() || texture.format.is_indexed();
+        let format_str = match (shows_format, texture.etc2()) {
+            (true, Some((etc2, _))) => {
+                format!(" [{} + {}]", texture.format.short_name(), etc2.short_name())
+            }
+            (true, None) => format!(" [{}]", texture.format.short_name()),
+            (false, _) => String::new(),
         };
         println!(
             "  Texture: {} ({}x{}){}",
             texture.id, texture.width, texture.height, format_str
         );
     }
+    for atlas in &atlases {
+        println!(
+            "  Atlas: {} ({}x{}, {} sprites)",
+            atlas.id,
+            atlas.width,
+            atlas.height,
+            atlas.sprite_count()
+        );
+    }
     for mesh in &meshes {
         println!("  Mesh: {} ({} vertices)", mesh.id, mesh.vertex_count);
     }
@@ -424,6 +501,14 @@ pub fn load_assets(
     for sound in &sounds {
         println!("  Sound: {} ({:.2}s)", sound.id, sound.duration_seconds());
     }
+    for stream in &streams {
+        println!(
+            "  Stream: {} ({:.2}s, {} KB)",
+            stream.id,
+            stream.duration_seconds(),
+            stream.data.len() / 1024
+        );
+    }
     for tracker in &trackers {
         println!(
             "  Tracker: {} ({} instruments)",
@@ -440,19 +525,45 @@ pub fn load_assets(
             skeleton.id, skeleton.bone_count
         );
     }
+    for (path, length) in &paths {
+        println!(
+            "  Path: {} ({} points, {:.1} units)",
+            path.id,
+            path.points.len(),
+            length
+        );
+    }
+    for cubemap in &cubemaps {
+        println!(
+            "  Cubemap: {} ({}x{} faces)",
+            cubemap.id, cubemap.size, cubemap.size
+        );
+    }
+    for table in &strings {
+        println!(
+            "  Strings: {} ({} keys, {})",
+            table.id,
+            table.keys.len(),
+            table.languages.join("/")
+        );
+    }
 
     let total = textures.len()
         + meshes.len()
         + skeletons.len()
         + keyframes.len()
         + sounds.len()
+        + streams.len()
         + trackers.len()
-        + data.len();
+        + data.len()
+        + paths.len()
+        + cubemaps.len()
+        + strings.len();
     if total > 0 {
         println!("  Total: {} assets", total);
     }
 
-    Ok(ZXDataPack::with_assets(
+    let mut pack = ZXDataPack::with_assets(
         textures,
         meshes,
         skeletons,
@@ -461,5 +572,11 @@ pub fn load_assets(
         sounds,
         data,
         trackers,
-    ))
+    );
+    pack.atlases = atlases;
+    pack.streams = streams;
+    pack.paths = paths.into_iter().map(|(path, _)| path).collect();
+    pack.cubemaps = cubemaps;
+    pack.strings = strings;
+    Ok(pack)
 }
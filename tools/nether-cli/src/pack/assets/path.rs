@@ -0,0 +1,33 @@
+//! Spline path validation.
+
+use anyhow::Result;
+use zx_common::{PackedPath, PathCurve, PathKind};
+
+use crate::manifest::PathEntry;
+
+/// Validate a path entry and convert it to a packed path
+///
+/// Returns the packed path and its arc length in world units.
+pub fn load_path(entry: &PathEntry) -> Result<(PackedPath, f32)> {
+    let path = PackedPath::new(&entry.id, entry.kind, entry.closed, entry.points.clone());
+    if path.segment_count().is_none() {
+        let expected = match (entry.kind, entry.closed) {
+            (PathKind::CatmullRom, false) => "at least 2",
+            (PathKind::CatmullRom, true) => "at least 3",
+            (PathKind::Bezier, false) => "3n+1 (anchor, handle, handle, anchor, ...)",
+            (PathKind::Bezier, true) => "a multiple of 3",
+        };
+        anyhow::bail!(
+            "Path '{}' has {} points; {:?} paths need {}",
+            entry.id,
+            entry.points.len(),
+            entry.kind,
+            expected
+        );
+    }
+    let curve = PathCurve::new(&path)
+        .ok_or_else(|| anyhow::anyhow!("Path '{}' has non-finite coordinates", entry.id))?;
+
+    let length = curve.length();
+    Ok((path, length))
+}
@@ -0,0 +1,246 @@
+//! Localized string table loading (CSV or Fluent files).
+
+use anyhow::{Context, Result};
+use std::collections::HashMap;
+use std::path::Path;
+use zx_common::PackedStrings;
+
+use crate::manifest::StringsEntry;
+
+/// Load a string table entry into a packed table
+pub fn load_strings(project_dir: &Path, entry: &StringsEntry) -> Result<PackedStrings> {
+    let read = |path: &str| {
+        let path = project_dir.join(path);
+        std::fs::read_to_string(&path)
+            .map(|text| text.trim_start_matches('\u{feff}').to_string())
+            .with_context(|| format!("Failed to read string table: {}", path.display()))
+    };
+
+    let (languages, rows) = match (&entry.path, entry.fluent.is_empty()) {
+        (Some(path), true) => {
+            parse_csv_table(&read(path)?).with_context(|| format!("In string table '{}'", path))?
+        }
+        (None, false) => {
+            let mut files = Vec::with_capacity(entry.fluent.len());
+            for path in &entry.fluent {
+                let language = Path::new(path)
+                    .file_stem()
+                    .and_then(|s| s.to_str())
+                    .filter(|s| !s.is_empty())
+                    .with_context(|| format!("Fluent file '{}' has no language name", path))?
+                    .to_string();
+                let messages = parse_fluent(&read(path)?)
+                    .with_context(|| format!("In Fluent file '{}'", path))?;
+                files.push((language, messages));
+            }
+            merge_languages(files)
+        }
+        _ => anyhow::bail!(
+            "String table '{}' needs either `path` (CSV) or `fluent` files, not both",
+            entry.id
+        ),
+    };
+
+    let mut seen = HashMap::new();
+    for (i, language) in languages.iter().enumerate() {
+        if let Some(first) = seen.insert(language.to_ascii_lowercase(), i) {
+            anyhow::bail!(
+                "String table '{}' lists language '{}' twice (columns {} and {})",
+                entry.id,
+                language,
+                first + 1,
+                i + 1
+            );
+        }
+    }
+
+    let keys = rows.iter().map(|(key, _)| key.clone()).collect();
+    let values = rows.into_iter().flat_map(|(_, cells)| cells).collect();
+    Ok(PackedStrings::new(&entry.id, languages, keys, values))
+}
+
+/// A parsed table: languages, then (key, one cell per language) rows
+type Table = (Vec<String>, Vec<(String, Vec<String>)>);
+
+/// Parse a CSV table with a `key,<lang>,...` header row
+fn parse_csv_table(text: &str) -> Result<Table> {
+    let mut records = parse_csv(text)?.into_iter();
+    let header = records.next().context("CSV has no header row")?;
+    let languages: Vec<String> = header
+        .iter()
+        .skip(1)
+        .map(|l| l.trim().to_string())
+        .collect();
+    if languages.is_empty() || languages.iter().any(String::is_empty) {
+        anyhow::bail!("CSV header must be `key,<language>,...` with named languages");
+    }
+
+    let mut rows: Vec<(String, Vec<String>)> = Vec::new();
+    let mut seen = HashMap::new();
+    for (line, mut record) in records.enumerate() {
+        if record.iter().all(|cell| cell.is_empty()) {
+            continue;
+        }
+        let row = line + 2;
+        if record.len() > languages.len() + 1 {
+            anyhow::bail!(
+                "Row {} has {} cells; the header has {}",
+                row,
+                record.len(),
+                languages.len() + 1
+            );
+        }
+        record.resize(languages.len() + 1, String::new());
+        let key = record.remove(0).trim().to_string();
+        if key.is_empty() {
+            anyhow::bail!("Row {} has an empty key", row);
+        }
+        if let Some(first) = seen.insert(key.clone(), row) {
+            anyhow::bail!("Key '{}' appears twice (rows {} and {})", key, first, row);
+        }
+        rows.push((key, record));
+    }
+    Ok((languages, rows))
+}
+
+/// Split CSV text into records (RFC 4180: quoted cells may hold commas,
+/// newlines and `""` escapes)
+fn parse_csv(text: &str) -> Result<Vec<Vec<String>>> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut cell = String::new();
+    let mut quoted = false;
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match (quoted, c) {
+            (true, '"') if chars.peek() == Some(&'"') => {
+                chars.next();
+                cell.push('"');
+            }
+            (true, '"') => quoted = false,
+            (true, c) => cell.push(c),
+            (false, '"') if cell.is_empty() => quoted = true,
+            (false, ',') => record.push(std::mem::take(&mut cell)),
+            (false, '\r') if chars.peek() == Some(&'\n') => {}
+            (false, '\n') => {
+                record.push(std::mem::take(&mut cell));
+                records.push(std::mem::take(&mut record));
+            }
+            (false, c) => cell.push(c),
+        }
+    }
+    if quoted {
+        anyhow::bail!("Unterminated quoted cell");
+    }
+    if !cell.is_empty() || !record.is_empty() {
+        record.push(cell);
+        records.push(record);
+    }
+    Ok(records)
+}
+
+/// Parse the messages of a Fluent (.ftl) file, in file order
+///
+/// Supports messages, multiline values, attributes (stored as
+/// `message.attribute`) and terms, which are substituted into messages that
+/// reference them as `{ -term }`. Other placeables such as `{ $count }` are
+/// kept verbatim for the game to fill in.
+pub fn parse_fluent(text: &str) -> Result<Vec<(String, String)>> {
+    // (id, lines) for messages and terms; attributes are separate entries
+    let mut entries: Vec<(String, Vec<String>)> = Vec::new();
+    let mut message: Option<String> = None;
+
+    for (line_no, line) in text.lines().enumerate() {
+        let indented = line.starts_with([' ', '\t']);
+        let trimmed = line.trim();
+
+        if !indented {
+            message = None;
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+            let (id, value) = trimmed
+                .split_once('=')
+                .with_context(|| format!("Line {}: expected `id = value`", line_no + 1))?;
+            let id = id.trim();
+            if !is_fluent_id(id.trim_start_matches('-')) {
+                anyhow::bail!("Line {}: invalid message id '{}'", line_no + 1, id);
+            }
+            entries.push((id.to_string(), first_line(value)));
+            message = Some(id.to_string());
+        } else if let Some(parent) = &message {
+            if let Some(attribute) = trimmed.strip_prefix('.') {
+                let (name, value) = attribute.split_once('=').with_context(|| {
+                    format!("Line {}: expected `.attribute = value`", line_no + 1)
+                })?;
+                entries.push((format!("{}.{}", parent, name.trim()), first_line(value)));
+            } else if let Some((_, lines)) = entries.last_mut() {
+                lines.push(trimmed.to_string());
+            }
+        } else if !trimmed.is_empty() {
+            anyhow::bail!("Line {}: indented text outside a message", line_no + 1);
+        }
+    }
+
+    let join = |lines: &[String]| {
+        let lines: Vec<&str> = lines
+            .iter()
+            .map(String::as_str)
+            .skip_while(|l| l.is_empty())
+            .collect();
+        lines.join("\n").trim_end().to_string()
+    };
+    let terms: HashMap<String, String> = entries
+        .iter()
+        .filter(|(id, _)| id.starts_with('-') && !id.contains('.'))
+        .map(|(id, lines)| (id.clone(), join(lines)))
+        .collect();
+
+    Ok(entries
+        .iter()
+        .filter(|(id, _)| !id.starts_with('-'))
+        .map(|(id, lines)| {
+            let mut value = join(lines);
+            for (term, text) in &terms {
+                value = value
+                    .replace(&format!("{{ {} }}", term), text)
+                    .replace(&format!("{{{}}}", term), text);
+            }
+            (id.clone(), value)
+        })
+        .collect())
+}
+
+/// Value text on the `id =` line, as the first line of the value
+fn first_line(value: &str) -> Vec<String> {
+    vec![value.trim().to_string()]
+}
+
+/// Whether `id` is a valid Fluent identifier (`[a-zA-Z][a-zA-Z0-9_-]*`)
+fn is_fluent_id(id: &str) -> bool {
+    let mut chars = id.chars();
+    chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
+/// Combine per-language message lists into one table
+///
+/// Keys are ordered by first appearance; a key missing from a language is
+/// left empty so lookups fall back to the first language.
+fn merge_languages(files: Vec<(String, Vec<(String, String)>)>) -> Table {
+    let languages: Vec<String> = files.iter().map(|(language, _)| language.clone()).collect();
+    let mut rows: Vec<(String, Vec<String>)> = Vec::new();
+    let mut index: HashMap<String, usize> = HashMap::new();
+
+    for (column, (_, messages)) in files.into_iter().enumerate() {
+        for (key, value) in messages {
+            let row = *index.entry(key.clone()).or_insert_with(|| {
+                rows.push((key, vec![String::new(); languages.len()]));
+                rows.len() - 1
+            });
+            rows[row].1[column] = value;
+        }
+    }
+    (languages, rows)
+}
@@ -3,18 +3,21 @@
 #[cfg(test)]
 mod tests {
     use super::super::load_assets;
-    use crate::manifest::{AssetsSection, NetherManifest};
+    use crate::manifest::{AssetsSection, NetherManifest, TextureQuality, TextureTarget};
     use crate::pack::assets::{
         animation::load_keyframes,
-        audio::load_sound,
+        audio::{load_sound, load_stream},
+        cubemap::equirect_to_faces,
         data::load_data,
         mesh::load_mesh,
-        texture::load_texture,
+        path::load_path,
+        strings::parse_fluent,
+        texture::{load_indexed, load_texture, pack_rgba, TextureEncoding},
         utils::{hash_sample_data, sanitize_name},
     };
     use tempfile::tempdir;
     use zx_common::{
-        vertex_stride_packed, NetherZXAnimationHeader, NetherZXMeshHeader, TextureFormat,
+        vertex_stride_packed, NetherZXAnimationHeader, NetherZXMeshHeader, PathKind, TextureFormat,
         FORMAT_COLOR, FORMAT_UV,
     };
 
@@ -122,7 +125,13 @@ version = "1.0.0"
         img.save(&img_path).unwrap();
 
         // Load it as RGBA8
-        let packed = load_texture("test_tex", &img_path, TextureFormat::Rgba8).unwrap();
+        let packed = load_texture(
+            "test_tex",
+            &img_path,
+            TextureFormat::Rgba8.into(),
+            TextureQuality::Fast,
+        )
+        .unwrap();
         assert_eq!(packed.id, "test_tex");
         assert_eq!(packed.width, 2);
         assert_eq!(packed.height, 2);
@@ -146,7 +155,13 @@ version = "1.0.0"
         img.save(&img_path).unwrap();
 
         // Load it as BC7
-        let packed = load_texture("test_tex", &img_path, TextureFormat::Bc7).unwrap();
+        let packed = load_texture(
+            "test_tex",
+            &img_path,
+            TextureFormat::Bc7.into(),
+            TextureQuality::Fast,
+        )
+        .unwrap();
         assert_eq!(packed.id, "test_tex");
         assert_eq!(packed.width, 16);
         assert_eq!(packed.height, 16);
@@ -155,6 +170,116 @@ version = "1.0.0"
         assert_eq!(packed.data.len(), 4 * 4 * 16);
     }
 
+    #[test]
+    fn test_pack_rgba_etc2_copy() {
+        let pixels = vec![128u8; 8 * 8 * 4];
+        let both = TextureEncoding {
+            format: TextureFormat::Bc7,
+            etc2_copy: true,
+        };
+
+        let packed = pack_rgba("tex", &pixels, 8, 8, both, TextureQuality::Fast).unwrap();
+        assert_eq!(packed.format, TextureFormat::Bc7);
+        let (format, data) = packed.etc2().unwrap();
+        assert_eq!(format, TextureFormat::Etc2);
+        assert_eq!(data.len(), packed.data.len());
+        assert!(packed.validate());
+
+        // ETC2-only packs store ETC2 as the main data
+        let etc2 = TextureFormat::Etc2.into();
+        let packed = pack_rgba("tex", &pixels, 8, 8, etc2, TextureQuality::High).unwrap();
+        assert_eq!(packed.format, TextureFormat::Etc2);
+        assert!(packed.etc2().is_none());
+        assert_eq!(packed.data.len(), 2 * 2 * 16);
+    }
+
+    #[test]
+    fn test_pack_rgba_lossless_skips_compression() {
+        let pixels = vec![7u8; 4 * 4 * 4];
+        let both = TextureEncoding {
+            format: TextureFormat::Bc7,
+            etc2_copy: true,
+        };
+        let packed = pack_rgba("ui", &pixels, 4, 4, both, TextureQuality::Lossless).unwrap();
+        assert_eq!(packed.format, TextureFormat::Rgba8);
+        assert_eq!(packed.data, pixels);
+        assert!(packed.etc2_data.is_empty());
+    }
+
+    #[test]
+    fn test_load_indexed_png_unpacks_indices() {
+        // 3x2 image, 2 bits per index: rows pack into one byte each
+        let mut png_data = Vec::new();
+        let mut encoder = png::Encoder::new(&mut png_data, 3, 2);
+        encoder.set_color(png::ColorType::Indexed);
+        encoder.set_depth(png::BitDepth::Two);
+        encoder.set_palette(vec![0u8; 4 * 3]);
+        let mut writer = encoder.write_header().unwrap();
+        writer
+            .write_image_data(&[0b00_01_10_00, 0b11_10_01_00])
+            .unwrap();
+        writer.finish().unwrap();
+
+        let packed = load_indexed("enemy", png_data.as_slice()).unwrap();
+        assert_eq!(packed.format, TextureFormat::Indexed8);
+        assert_eq!((packed.width, packed.height), (3, 2));
+        assert_eq!(packed.data, [0, 1, 2, 3, 2, 1]);
+        assert!(packed.validate());
+    }
+
+    #[test]
+    fn test_pack_rgba_rejects_indexed_quality() {
+        let pixels = vec![0u8; 4 * 4 * 4];
+        let encoding = TextureFormat::Rgba8.into();
+        assert!(pack_rgba("tex", &pixels, 4, 4, encoding, TextureQuality::Indexed).is_err());
+    }
+
+    #[test]
+    fn test_manifest_texture_quality_and_target() {
+        let manifest = NetherManifest::parse(
+            r#"
+[game]
+id = "test-game"
+title = "Test"
+author = "Test"
+version = "1.0.0"
+compress_textures = true
+texture_target = "both"
+
+[[assets.textures]]
+id = "sky"
+path = "sky.png"
+quality = "high"
+
+[[assets.textures]]
+id = "font"
+path = "font.png"
+quality = "lossless"
+
+[[assets.textures]]
+id = "rock"
+path = "rock.png"
+
+[[assets.textures]]
+id = "enemy"
+path = "enemy.png"
+quality = "indexed"
+"#,
+        )
+        .unwrap();
+        assert_eq!(manifest.game.texture_target, TextureTarget::Both);
+        let qualities: Vec<_> = manifest.assets.textures.iter().map(|t| t.quality).collect();
+        assert_eq!(
+            qualities,
+            [
+                TextureQuality::High,
+                TextureQuality::Lossless,
+                TextureQuality::Fast,
+                TextureQuality::Indexed
+            ]
+        );
+    }
+
     #[test]
     fn test_load_wav_basic() {
         let dir = tempdir().unwrap();
@@ -198,6 +323,43 @@ version = "1.0.0"
         assert_eq!(packed.data[1], 1000);
     }
 
+    #[test]
+    fn test_load_stream_compresses_wav() {
+        let dir = tempdir().unwrap();
+        let wav_path = dir.path().join("bgm.wav");
+
+        // 2 seconds of a 22050Hz mono sine
+        let samples: Vec<i16> = (0..44100)
+            .map(|i| ((i as f32 * 0.06).sin() * 12000.0) as i16)
+            .collect();
+        let data_size = samples.len() as u32 * 2;
+
+        let mut wav_data = vec![];
+        wav_data.extend_from_slice(b"RIFF");
+        wav_data.extend_from_slice(&(36 + data_size).to_le_bytes());
+        wav_data.extend_from_slice(b"WAVE");
+        wav_data.extend_from_slice(b"fmt ");
+        wav_data.extend_from_slice(&16u32.to_le_bytes());
+        wav_data.extend_from_slice(&1u16.to_le_bytes()); // PCM
+        wav_data.extend_from_slice(&1u16.to_le_bytes()); // Mono
+        wav_data.extend_from_slice(&22050u32.to_le_bytes());
+        wav_data.extend_from_slice(&44100u32.to_le_bytes());
+        wav_data.extend_from_slice(&2u16.to_le_bytes());
+        wav_data.extend_from_slice(&16u16.to_le_bytes());
+        wav_data.extend_from_slice(b"data");
+        wav_data.extend_from_slice(&data_size.to_le_bytes());
+        for sample in &samples {
+            wav_data.extend_from_slice(&sample.to_le_bytes());
+        }
+        std::fs::write(&wav_path, &wav_data).unwrap();
+
+        let stream = load_stream("bgm", &wav_path).unwrap();
+        assert_eq!(stream.id, "bgm");
+        assert_eq!(stream.total_samples, 44100);
+        // Stays compressed (~3.2 bits per sample)
+        assert!(stream.data.len() < data_size as usize / 4);
+    }
+
     #[test]
     fn test_load_wav_invalid() {
         let dir = tempdir().unwrap();
@@ -215,7 +377,7 @@ version = "1.0.0"
         let dir = tempdir().unwrap();
         let assets = AssetsSection::default();
 
-        let pack = load_assets(dir.path(), &assets, TextureFormat::Rgba8).unwrap();
+        let pack = load_assets(dir.path(), &assets, TextureFormat::Rgba8.into()).unwrap();
         assert!(pack.is_empty());
         assert_eq!(pack.asset_count(), 0);
     }
@@ -660,4 +822,336 @@ id_prefix = "enemy_"
 
         assert_eq!(hashes.len(), 100);
     }
+
+    #[test]
+    fn test_pack_atlas_sprites() {
+        use crate::pack::assets::atlas::pack_images;
+
+        let images = vec![
+            image::RgbaImage::from_pixel(8, 8, image::Rgba([255, 0, 0, 255])),
+            image::RgbaImage::from_pixel(16, 4, image::Rgba([0, 255, 0, 255])),
+            image::RgbaImage::from_pixel(4, 12, image::Rgba([0, 0, 255, 255])),
+        ];
+        let (atlas, sprites) = pack_images(&images, 1).unwrap();
+
+        assert!(atlas.width().is_power_of_two());
+        assert_eq!(atlas.height() % 4, 0);
+        assert_eq!(sprites.len(), 3);
+
+        // Sprites keep manifest order and sizes, without overlapping
+        for (sprite, img) in sprites.iter().zip(&images) {
+            assert_eq!(sprite.width as u32, img.width());
+            assert_eq!(sprite.height as u32, img.height());
+            let pixel = atlas.get_pixel(sprite.x as u32, sprite.y as u32);
+            assert_eq!(pixel, img.get_pixel(0, 0));
+        }
+        for (i, a) in sprites.iter().enumerate() {
+            for b in &sprites[i + 1..] {
+                let apart = a.x + a.width <= b.x
+                    || b.x + b.width <= a.x
+                    || a.y + a.height <= b.y
+                    || b.y + b.height <= a.y;
+                assert!(apart, "sprites overlap: {:?} {:?}", a, b);
+            }
+        }
+
+        // Padding repeats the edge pixels
+        let first = sprites[0];
+        assert_eq!(
+            atlas.get_pixel(first.x as u32 - 1, first.y as u32 - 1),
+            &image::Rgba([255, 0, 0, 255])
+        );
+    }
+
+    #[test]
+    fn test_load_assets_with_atlas() {
+        let dir = tempdir().unwrap();
+        for (name, size) in [("a.png", 8), ("b.png", 16)] {
+            image::RgbaImage::from_pixel(size, size, image::Rgba([255, 255, 255, 255]))
+                .save(dir.path().join(name))
+                .unwrap();
+        }
+
+        let manifest = NetherManifest::parse(
+            r#"
+[game]
+id = "atlas-game"
+title = "Atlas Game"
+author = "Author"
+version = "0.1.0"
+
+[[assets.atlases]]
+id = "enemies"
+sprites = ["a.png", "b.png"]
+"#,
+        )
+        .unwrap();
+
+        let pack = load_assets(dir.path(), &manifest.assets, TextureFormat::Rgba8.into()).unwrap();
+        let atlas = pack.find_atlas("enemies").unwrap();
+        let texture = pack.find_texture("enemies").unwrap();
+        assert_eq!((atlas.width, atlas.height), (texture.width, texture.height));
+        assert_eq!(atlas.sprite_count(), 2);
+        assert_eq!(atlas.sprites[1].width, 16);
+    }
+
+    #[test]
+    fn test_load_assets_with_path() {
+        let dir = tempdir().unwrap();
+        let manifest = NetherManifest::parse(
+            r#"
+[game]
+id = "path-game"
+title = "Path Game"
+author = "Author"
+version = "0.1.0"
+
+[[assets.paths]]
+id = "track1"
+closed = true
+points = [[0, 0, 0], [40, 0, 0], [40, 0, 40], [0, 0, 40]]
+
+[[assets.paths]]
+id = "flyby"
+kind = "bezier"
+points = [[0, 10, 0], [5, 10, 0], [10, 12, 0], [15, 15, 0]]
+"#,
+        )
+        .unwrap();
+
+        let pack = load_assets(dir.path(), &manifest.assets, TextureFormat::Rgba8.into()).unwrap();
+        let track = pack.find_path("track1").unwrap();
+        assert_eq!(track.kind, PathKind::CatmullRom);
+        assert!(track.closed);
+        assert_eq!(track.points.len(), 4);
+        assert_eq!(pack.find_path("flyby").unwrap().kind, PathKind::Bezier);
+    }
+
+    #[test]
+    fn test_load_path_rejects_bad_point_count() {
+        let manifest = NetherManifest::parse(
+            r#"
+[game]
+id = "path-game"
+title = "Path Game"
+author = "Author"
+version = "0.1.0"
+
+[[assets.paths]]
+id = "flyby"
+kind = "bezier"
+points = [[0, 0, 0], [1, 0, 0], [2, 0, 0]]
+"#,
+        )
+        .unwrap();
+
+        let err = load_path(&manifest.assets.paths[0]).unwrap_err();
+        assert!(err.to_string().contains("3n+1"));
+    }
+
+    #[test]
+    fn test_load_assets_with_cubemaps() {
+        let dir = tempdir().unwrap();
+        for face in 0..6u8 {
+            image::RgbaImage::from_pixel(4, 4, image::Rgba([face * 40, 0, 0, 255]))
+                .save(dir.path().join(format!("face{face}.png")))
+                .unwrap();
+        }
+        image::RgbaImage::from_pixel(32, 16, image::Rgba([0, 0, 255, 255]))
+            .save(dir.path().join("sky.png"))
+            .unwrap();
+
+        let manifest = NetherManifest::parse(
+            r#"
+[game]
+id = "sky-game"
+title = "Sky Game"
+author = "Author"
+version = "0.1.0"
+
+[[assets.cubemaps]]
+id = "box"
+faces = ["face0.png", "face1.png", "face2.png", "face3.png", "face4.png", "face5.png"]
+
+[[assets.cubemaps]]
+id = "pano"
+equirect = "sky.png"
+"#,
+        )
+        .unwrap();
+
+        let pack = load_assets(dir.path(), &manifest.assets, TextureFormat::Rgba8.into()).unwrap();
+        let cubemap = pack.find_cubemap("box").unwrap();
+        assert_eq!(cubemap.size, 4);
+        assert_eq!(&cubemap.face(5).unwrap()[..4], &[200, 0, 0, 255]);
+
+        // Panoramas default to width / 4 faces
+        let pano = pack.find_cubemap("pano").unwrap();
+        assert_eq!(pano.size, 8);
+        assert_eq!(pano.data.len(), 8 * 8 * 4 * 6);
+        assert!(pano.data.chunks_exact(4).all(|p| p == [0, 0, 255, 255]));
+    }
+
+    #[test]
+    fn test_load_cubemap_rejects_bad_faces() {
+        let dir = tempdir().unwrap();
+        for (name, (w, h)) in [("a.png", (4, 4)), ("b.png", (4, 2))] {
+            image::RgbaImage::new(w, h)
+                .save(dir.path().join(name))
+                .unwrap();
+        }
+
+        let parse = |faces: &str| {
+            NetherManifest::parse(&format!(
+                r#"
+[game]
+id = "sky-game"
+title = "Sky Game"
+author = "Author"
+version = "0.1.0"
+
+[[assets.cubemaps]]
+id = "box"
+faces = {faces}
+"#
+            ))
+            .unwrap()
+        };
+
+        let err = load_assets(
+            dir.path(),
+            &parse(r#"["a.png", "a.png"]"#).assets,
+            TextureFormat::Rgba8.into(),
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("expected 6"));
+
+        let err = load_assets(
+            dir.path(),
+            &parse(r#"["a.png", "a.png", "a.png", "b.png", "a.png", "a.png"]"#).assets,
+            TextureFormat::Rgba8.into(),
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("face 3"));
+    }
+
+    #[test]
+    fn test_equirect_to_faces_orientation() {
+        // Top half white, bottom half black, with a red band at the center column
+        let panorama = image::RgbaImage::from_fn(64, 32, |x, y| {
+            if (30..34).contains(&x) && (14..18).contains(&y) {
+                image::Rgba([255, 0, 0, 255])
+            } else if y < 16 {
+                image::Rgba([255, 255, 255, 255])
+            } else {
+                image::Rgba([0, 0, 0, 255])
+            }
+        });
+        let size = 8;
+        let data = equirect_to_faces(&panorama, size);
+        let face_len = (size * size * 4) as usize;
+        let texel = |face: usize, x: u32, y: u32| {
+            let i = face * face_len + ((y * size + x) * 4) as usize;
+            [data[i], data[i + 1], data[i + 2]]
+        };
+
+        assert_eq!(texel(2, 4, 4), [255, 255, 255]); // +Y is sky
+        assert_eq!(texel(3, 4, 4), [0, 0, 0]); // -Y is ground
+        assert_eq!(texel(5, 4, 4)[1], 0); // -Z looks at the panorama center
+        assert_eq!(texel(5, 4, 4)[0], 255);
+        assert_ne!(texel(4, 4, 4), [255, 0, 0]); // +Z looks at the seam
+    }
+
+    #[test]
+    fn test_load_assets_with_strings() {
+        let dir = tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("menus.csv"),
+            "\u{feff}key,en,fr\r\nmenu.start,Start,Jouer\r\nmenu.quote,\"Say \"\"hi\"\", then\nleave\",\r\n",
+        )
+        .unwrap();
+        std::fs::create_dir(dir.path().join("locales")).unwrap();
+        std::fs::write(
+            dir.path().join("locales/en.ftl"),
+            "-brand = Prism\nwelcome = Welcome to { -brand }!\nquit = Quit\n",
+        )
+        .unwrap();
+        std::fs::write(dir.path().join("locales/de.ftl"), "welcome = Willkommen\n").unwrap();
+
+        let manifest = NetherManifest::parse(
+            r#"
+[game]
+id = "text-game"
+title = "Text Game"
+author = "Author"
+version = "0.1.0"
+
+[[assets.strings]]
+id = "menus"
+path = "menus.csv"
+
+[[assets.strings]]
+id = "dialogue"
+fluent = ["locales/en.ftl", "locales/de.ftl"]
+"#,
+        )
+        .unwrap();
+
+        let pack = load_assets(dir.path(), &manifest.assets, TextureFormat::Rgba8.into()).unwrap();
+        let menus = pack.find_strings("menus").unwrap();
+        assert_eq!(menus.languages, ["en", "fr"]);
+        assert_eq!(menus.keys, ["menu.start", "menu.quote"]);
+        assert_eq!(menus.get(0, 1), Some("Jouer"));
+        assert_eq!(menus.get(1, 0), Some("Say \"hi\", then\nleave"));
+        assert_eq!(menus.get(1, 1), None);
+
+        let dialogue = pack.find_strings("dialogue").unwrap();
+        assert_eq!(dialogue.languages, ["en", "de"]);
+        assert_eq!(dialogue.keys, ["welcome", "quit"]);
+        assert_eq!(dialogue.get(0, 0), Some("Welcome to Prism!"));
+        assert_eq!(dialogue.get(0, 1), Some("Willkommen"));
+        assert_eq!(dialogue.get(1, 1), None);
+    }
+
+    #[test]
+    fn test_load_strings_rejects_duplicate_keys() {
+        let dir = tempdir().unwrap();
+        std::fs::write(dir.path().join("t.csv"), "key,en\na,1\na,2\n").unwrap();
+        let manifest = NetherManifest::parse(
+            r#"
+[game]
+id = "text-game"
+title = "Text Game"
+author = "Author"
+version = "0.1.0"
+
+[[assets.strings]]
+id = "t"
+path = "t.csv"
+"#,
+        )
+        .unwrap();
+
+        let err =
+            load_assets(dir.path(), &manifest.assets, TextureFormat::Rgba8.into()).unwrap_err();
+        assert!(format!("{:#}", err).contains("Key 'a' appears twice"));
+    }
+
+    #[test]
+    fn test_parse_fluent_multiline_and_attributes() {
+        let messages = parse_fluent(
+            "# Menus\n\
+             intro =\n    Long ago,\n    in a galaxy\nbutton = Play\n    .tooltip = Start a new run\n",
+        )
+        .unwrap();
+        assert_eq!(
+            messages,
+            [
+                ("intro".to_string(), "Long ago,\nin a galaxy".to_string()),
+                ("button".to_string(), "Play".to_string()),
+                ("button.tooltip".to_string(), "Start a new run".to_string()),
+            ]
+        );
+        assert!(parse_fluent("  stray\n").is_err());
+    }
 }
@@ -1,43 +1,156 @@
 //! Texture loading and compression.
 
 use anyhow::{Context, Result};
+use nether_export::etc2::{compress_eac_rg11, compress_etc2, EacEffort};
 use zx_common::{PackedTexture, TextureFormat};
 
+use crate::manifest::TextureQuality;
+
+/// How the packer encodes textures (from `compress_textures` and `texture_target`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TextureEncoding {
+    /// Format stored in `PackedTexture::data` (RGBA8, BC7 or ETC2)
+    pub format: TextureFormat,
+    /// Also store an ETC2 copy of BC-compressed textures
+    pub etc2_copy: bool,
+}
+
+impl From<TextureFormat> for TextureEncoding {
+    fn from(format: TextureFormat) -> Self {
+        Self {
+            format,
+            etc2_copy: false,
+        }
+    }
+}
+
 /// Load a texture from an image file (PNG, JPG, etc.)
 ///
-/// Compresses to BC7 if the format requires it.
+/// Compresses to BC7 and/or ETC2 if the encoding requires it.
+/// `TextureQuality::Indexed` keeps the palette indices of a PNG instead.
 pub fn load_texture(
     id: &str,
     path: &std::path::Path,
-    format: TextureFormat,
+    encoding: TextureEncoding,
+    quality: TextureQuality,
 ) -> Result<PackedTexture> {
+    if quality == TextureQuality::Indexed {
+        let file = std::fs::File::open(path)
+            .with_context(|| format!("Failed to open texture: {}", path.display()))?;
+        return load_indexed(id, file)
+            .with_context(|| format!("Failed to load indexed texture: {}", path.display()));
+    }
+
     let img =
         image::open(path).with_context(|| format!("Failed to load texture: {}", path.display()))?;
 
     let rgba = img.to_rgba8();
     let (width, height) = rgba.dimensions();
-    let pixels = rgba.as_raw();
+    pack_rgba(id, rgba.as_raw(), width, height, encoding, quality)
+}
 
-    // Compress or pass through based on format
-    let data = match format {
-        TextureFormat::Rgba8 => pixels.to_vec(),
-        TextureFormat::Bc7 => compress_bc7(pixels, width, height)?,
-        TextureFormat::Bc5 => compress_bc5(pixels, width, height)?,
+/// Load the palette indices of a PNG as an Indexed8 texture
+///
+/// Indexed PNGs of any bit depth give their palette indices (the PNG's own
+/// palette is ignored); 8-bit grayscale PNGs use the gray level as the index.
+pub fn load_indexed(id: &str, png_data: impl std::io::Read) -> Result<PackedTexture> {
+    let mut reader = png::Decoder::new(png_data).read_info()?;
+    let mut buf = vec![0u8; reader.output_buffer_size()];
+    let info = reader.next_frame(&mut buf)?;
+
+    let bits = match (info.color_type, info.bit_depth) {
+        (png::ColorType::Indexed, depth) => depth as usize,
+        (png::ColorType::Grayscale, png::BitDepth::Eight) => 8,
+        (color_type, depth) => anyhow::bail!(
+            "{:?} {}-bit PNG has no palette indices (save it as an indexed or 8-bit grayscale PNG)",
+            color_type,
+            depth as u8
+        ),
     };
 
+    // Rows are packed MSB-first at sub-byte depths; unpack to one index per byte
+    let (width, height) = (info.width as usize, info.height as usize);
+    let per_byte = 8 / bits;
+    let mask = ((1u16 << bits) - 1) as u8;
+    let mut indices = Vec::with_capacity(width * height);
+    for row in buf.chunks_exact(info.line_size).take(height) {
+        for x in 0..width {
+            let shift = (per_byte - 1 - x % per_byte) * bits;
+            indices.push((row[x / per_byte] >> shift) & mask);
+        }
+    }
+
     Ok(PackedTexture::with_format(
         id,
         width as u16,
         height as u16,
-        format,
-        data,
+        TextureFormat::Indexed8,
+        indices,
     ))
 }
 
+/// Pack RGBA8 pixels into a texture with the given encoding
+///
+/// `TextureQuality::Lossless` keeps the pixels uncompressed whatever the encoding.
+pub fn pack_rgba(
+    id: &str,
+    pixels: &[u8],
+    width: u32,
+    height: u32,
+    encoding: TextureEncoding,
+    quality: TextureQuality,
+) -> Result<PackedTexture> {
+    let format = match quality {
+        TextureQuality::Lossless => TextureFormat::Rgba8,
+        TextureQuality::Indexed => {
+            anyhow::bail!("'{}': indexed textures must be loaded from a PNG", id)
+        }
+        _ => encoding.format,
+    };
+    let high = quality == TextureQuality::High;
+    let effort = if high {
+        EacEffort::Thorough
+    } else {
+        EacEffort::Fast
+    };
+
+    // Compress or pass through based on format
+    let data = encode(pixels, width, height, format, high, effort)?;
+    let texture = PackedTexture::with_format(id, width as u16, height as u16, format, data);
+
+    match format.etc2_counterpart() {
+        Some(etc2) if encoding.etc2_copy => {
+            let etc2_data = encode(pixels, width, height, etc2, high, effort)?;
+            Ok(texture.with_etc2_data(etc2_data))
+        }
+        _ => Ok(texture),
+    }
+}
+
+/// Encode RGBA8 pixels in one format
+fn encode(
+    pixels: &[u8],
+    width: u32,
+    height: u32,
+    format: TextureFormat,
+    high: bool,
+    effort: EacEffort,
+) -> Result<Vec<u8>> {
+    match format {
+        TextureFormat::Rgba8 => Ok(pixels.to_vec()),
+        TextureFormat::Bc7 => compress_bc7(pixels, width, height, high),
+        TextureFormat::Bc5 => compress_bc5(pixels, width, height),
+        TextureFormat::Etc2 => compress_etc2(pixels, width, height, effort),
+        TextureFormat::EacRg11 => compress_eac_rg11(pixels, width, height, effort),
+        TextureFormat::Indexed8 => anyhow::bail!("RGBA8 pixels can't be encoded as Indexed8"),
+    }
+}
+
 /// Compress RGBA8 pixels to BC7 format
 ///
 /// Uses intel_tex_2 (ISPC-based) for high-quality BC7 compression.
-fn compress_bc7(pixels: &[u8], width: u32, height: u32) -> Result<Vec<u8>> {
+/// `high` trades encode time for quality with the slow settings.
+fn compress_bc7(pixels: &[u8], width: u32, height: u32, high: bool) -> Result<Vec<u8>> {
     use intel_tex_2::bc7;
 
     let w = width as usize;
@@ -83,8 +196,13 @@ fn compress_bc7(pixels: &[u8], width: u32, height: u32) -> Result<Vec<u8>> {
         data: &input_data,
     };
 
-    // Compress using intel_tex_2 BC7 (fast settings for good speed/quality balance)
-    bc7::compress_blocks_into(&bc7::opaque_fast_settings(), &surface, &mut output);
+    // Fast settings give a good speed/quality balance; slow ones are for hero textures
+    let settings = if high {
+        bc7::opaque_slow_settings()
+    } else {
+        bc7::opaque_fast_settings()
+    };
+    bc7::compress_blocks_into(&settings, &surface, &mut output);
 
     Ok(output)
 }
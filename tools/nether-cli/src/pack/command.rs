@@ -23,12 +23,23 @@ pub struct PackArgs {
     /// Path to WASM file (overrides auto-detection)
     #[arg(long)]
     pub wasm: Option<PathBuf>,
+
+    /// Build an override pack (.nczxmod) instead of a ROM
+    ///
+    /// `game.id` names the game to reskin and the assets replace the ROM's
+    /// textures, sounds and meshes with the same IDs. No WASM is needed.
+    #[arg(long)]
+    pub override_pack: bool,
 }
 
 /// Execute the pack command
 pub fn execute(args: PackArgs) -> Result<()> {
     let ctx = manifest::load_manifest(&args.manifest)?;
 
+    if args.override_pack {
+        return execute_override_pack(&ctx, args.output);
+    }
+
     println!(
         "Packing game: {} ({})",
         ctx.manifest.game.title, ctx.manifest.game.id
@@ -41,16 +52,19 @@ pub fn execute(args: PackArgs) -> Result<()> {
     println!("  WASM: {} ({} bytes)", wasm_path.display(), code.len());
 
     let rom_hash = xxh3_64(&code);
-    println!("  ROM hash: {:016x}", rom_hash);
+    println!("  Code hash: {:016x}", rom_hash);
 
     let render_mode = ctx.manifest.game.render_mode;
     let mode_name = validation::render_mode_name(render_mode);
     println!("  Render mode: {} ({})", render_mode, mode_name);
 
-    let texture_format = validation::select_texture_format(ctx.manifest.game.compress_textures);
+    let texture_encoding = validation::select_texture_encoding(
+        ctx.manifest.game.compress_textures,
+        ctx.manifest.game.texture_target,
+    );
     validation::warn_compression_mismatch(render_mode, ctx.manifest.game.compress_textures);
 
-    let data_pack = assets::load_assets(&ctx.project_dir, &ctx.manifest.assets, texture_format)?;
+    let data_pack = assets::load_assets(&ctx.project_dir, &ctx.manifest.assets, texture_encoding)?;
 
     let max_players = if ctx.manifest.netplay.enabled {
         ctx.manifest.game.max_players
@@ -69,10 +83,22 @@ pub fn execute(args: PackArgs) -> Result<()> {
             "  Netplay: enabled ({}Hz, {} players)",
             ctx.manifest.game.tick_rate, ctx.manifest.game.max_players
         );
+        if !ctx.manifest.capabilities.network && max_players > 1 {
+            println!(
+                "    (local only: declare `network = true` under [capabilities] for online play)"
+            );
+        }
     } else {
         println!("  Netplay: disabled");
     }
 
+    let capabilities = ctx.manifest.capabilities.names();
+    if capabilities.is_empty() {
+        println!("  Capabilities: none");
+    } else {
+        println!("  Capabilities: {}", capabilities.join(", "));
+    }
+
     let rom = output::build_rom(&ctx.manifest, code, data_pack, render_mode, netplay);
 
     rom.validate().context("ROM validation failed")?;
@@ -80,7 +106,50 @@ pub fn execute(args: PackArgs) -> Result<()> {
     let output_path = output::default_output_path(&ctx.project_dir, &ctx.manifest, args.output);
     let rom_bytes = output::serialize_rom(&rom)?;
     output::write_rom(&output_path, &rom_bytes)?;
-    output::print_summary(&output_path, rom_bytes.len(), &ctx.manifest);
+    output::print_summary(&output_path, &rom_bytes, &ctx.manifest);
+
+    Ok(())
+}
+
+/// Pack the manifest's assets into an override pack for `game.id`
+fn execute_override_pack(ctx: &manifest::ManifestContext, output: Option<PathBuf>) -> Result<()> {
+    println!(
+        "Packing override pack: {} (for {})",
+        ctx.manifest.game.title, ctx.manifest.game.id
+    );
+
+    let texture_encoding = validation::select_texture_encoding(
+        ctx.manifest.game.compress_textures,
+        ctx.manifest.game.texture_target,
+    );
+    let data_pack = assets::load_assets(&ctx.project_dir, &ctx.manifest.assets, texture_encoding)?;
+
+    let ignored = data_pack.skeletons.len()
+        + data_pack.keyframes.len()
+        + data_pack.fonts.len()
+        + data_pack.data.len()
+        + data_pack.trackers.len();
+    if ignored > 0 {
+        println!(
+            "  Warning: {} asset(s) are not textures, sounds or meshes and will be ignored",
+            ignored
+        );
+    }
+
+    let pack = output::build_override_pack(&ctx.manifest, data_pack);
+    let output_path = output::default_override_pack_path(&ctx.project_dir, &ctx.manifest, output);
+    let bytes = pack.to_bytes();
+    output::write_rom(&output_path, &bytes)?;
+
+    println!();
+    println!("Created: {} ({} bytes)", output_path.display(), bytes.len());
+    println!("  Game ID: {}", ctx.manifest.game.id);
+    println!(
+        "  Replacements: {} textures, {} sounds, {} meshes",
+        pack.data_pack.textures.len(),
+        pack.data_pack.sounds.len(),
+        pack.data_pack.meshes.len()
+    );
 
     Ok(())
 }
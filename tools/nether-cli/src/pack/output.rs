@@ -2,8 +2,10 @@ use anyhow::{Context, Result};
 use std::path::{Path, PathBuf};
 
 use nethercore_shared::netplay::NetplayMetadata;
-use nethercore_shared::ZX_ROM_FORMAT;
-use zx_common::{ZXDataPack, ZXMetadata, ZXRom};
+use nethercore_shared::{rom_content_hash, ZX_ROM_FORMAT};
+use zx_common::{
+    ZXDataPack, ZXMetadata, ZXOverridePack, ZXRom, OVERRIDE_PACK_EXTENSION, OVERRIDE_PACK_VERSION,
+};
 
 use crate::manifest::NetherManifest;
 
@@ -26,6 +28,8 @@ pub fn build_metadata(
         render_mode: Some(render_mode as u32),
         default_resolution: None,
         target_fps: None,
+        allow_override_packs: manifest.game.allow_override_packs,
+        capabilities: manifest.capabilities,
         netplay,
     }
 }
@@ -63,6 +67,26 @@ pub fn default_output_path(
     })
 }
 
+pub fn build_override_pack(manifest: &NetherManifest, data_pack: ZXDataPack) -> ZXOverridePack {
+    ZXOverridePack {
+        version: OVERRIDE_PACK_VERSION,
+        game_id: manifest.game.id.clone(),
+        title: manifest.game.title.clone(),
+        author: manifest.game.author.clone(),
+        data_pack,
+    }
+}
+
+pub fn default_override_pack_path(
+    project_dir: &Path,
+    manifest: &NetherManifest,
+    override_path: Option<PathBuf>,
+) -> PathBuf {
+    override_path.unwrap_or_else(|| {
+        project_dir.join(format!("{}.{}", manifest.game.id, OVERRIDE_PACK_EXTENSION))
+    })
+}
+
 pub fn serialize_rom(rom: &ZXRom) -> Result<Vec<u8>> {
     rom.to_bytes().context("Failed to serialize ROM")
 }
@@ -73,10 +97,20 @@ pub fn write_rom(output_path: &Path, rom_bytes: &[u8]) -> Result<()> {
     Ok(())
 }
 
-pub fn print_summary(output_path: &Path, rom_size: usize, manifest: &NetherManifest) {
+pub fn print_summary(output_path: &Path, rom_bytes: &[u8], manifest: &NetherManifest) {
+    let content_hash: String = rom_content_hash(rom_bytes)
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect();
+
     println!();
-    println!("Created: {} ({} bytes)", output_path.display(), rom_size);
+    println!(
+        "Created: {} ({} bytes)",
+        output_path.display(),
+        rom_bytes.len()
+    );
     println!("  Game ID: {}", manifest.game.id);
     println!("  Title: {}", manifest.game.title);
     println!("  Version: {}", manifest.game.version);
+    println!("  Content hash: {}", content_hash);
 }
@@ -1,29 +1,50 @@
 use zx_common::TextureFormat;
 
+use super::assets::TextureEncoding;
+use crate::manifest::TextureTarget;
+
 pub fn render_mode_name(render_mode: u8) -> &'static str {
     match render_mode {
         0 => "Lambert",
         1 => "Matcap",
         2 => "PBR",
         3 => "Hybrid",
+        4 => "Toon",
         _ => "Unknown",
     }
 }
 
-pub fn select_texture_format(compress_textures: bool) -> TextureFormat {
-    if compress_textures {
-        println!("  Texture compression: enabled (BC7, 4:1 ratio)");
-        TextureFormat::Bc7
-    } else {
+pub fn select_texture_encoding(
+    compress_textures: bool,
+    texture_target: TextureTarget,
+) -> TextureEncoding {
+    if !compress_textures {
         println!("  Texture compression: disabled (RGBA8, uncompressed)");
-        TextureFormat::Rgba8
+        return TextureFormat::Rgba8.into();
+    }
+    match texture_target {
+        TextureTarget::Bc => {
+            println!("  Texture compression: enabled (BC7, 4:1 ratio)");
+            TextureFormat::Bc7.into()
+        }
+        TextureTarget::Etc2 => {
+            println!("  Texture compression: enabled (ETC2, 4:1 ratio)");
+            TextureFormat::Etc2.into()
+        }
+        TextureTarget::Both => {
+            println!("  Texture compression: enabled (BC7 + ETC2 copy, 2:1 ratio)");
+            TextureEncoding {
+                format: TextureFormat::Bc7,
+                etc2_copy: true,
+            }
+        }
     }
 }
 
 pub fn warn_compression_mismatch(render_mode: u8, compress_textures: bool) {
     if render_mode > 0 && !compress_textures {
         eprintln!(
-            "  WARNING: Detected render_mode {} (Matcap/PBR/Hybrid) but compress_textures=false.",
+            "  WARNING: Detected render_mode {} (Matcap/PBR/Hybrid/Toon) but compress_textures=false.",
             render_mode
         );
         eprintln!("      Consider enabling texture compression for better performance:");
@@ -0,0 +1,136 @@
+//! Patch commands - create and apply ROM delta updates (.ncpatch)
+//!
+//! A patch holds only the bytes that changed between two builds of the same
+//! game, so updates to asset-heavy ROMs can ship as small downloads.
+//!
+//! ```bash
+//! nether patch create game-1.0.nczx game-1.1.nczx
+//! nether patch apply game-1.0.nczx game-1.0-to-1.1.ncpatch -o game-1.1.nczx
+//! ```
+
+use anyhow::{Context, Result};
+use clap::Subcommand;
+use std::path::{Path, PathBuf};
+
+use nethercore_shared::{read_file_with_limit, RomPatch, MAX_ROM_BYTES, ROM_PATCH_EXTENSION};
+use zx_common::ZXRom;
+
+/// Patch subcommands
+#[derive(Subcommand)]
+pub enum PatchAction {
+    /// Create a patch that updates one ROM build to another
+    Create {
+        /// Previous ROM (.nczx)
+        from: PathBuf,
+
+        /// Updated ROM (.nczx)
+        to: PathBuf,
+
+        /// Output patch file (defaults to <id>-<from>-to-<to>.ncpatch)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+
+    /// Apply a patch to a ROM, writing the updated ROM
+    Apply {
+        /// ROM the patch was created from (.nczx)
+        rom: PathBuf,
+
+        /// Patch file (.ncpatch)
+        patch: PathBuf,
+
+        /// Output ROM file (.nczx)
+        #[arg(short, long)]
+        output: PathBuf,
+    },
+}
+
+/// Execute a patch action
+pub fn execute(action: PatchAction) -> Result<()> {
+    match action {
+        PatchAction::Create { from, to, output } => create(&from, &to, output),
+        PatchAction::Apply { rom, patch, output } => apply(&rom, &patch, &output),
+    }
+}
+
+/// Read a ROM file and parse its metadata
+fn read_rom(path: &Path) -> Result<(Vec<u8>, ZXRom)> {
+    let bytes = read_file_with_limit(path, MAX_ROM_BYTES)?;
+    let rom = ZXRom::from_bytes(&bytes)
+        .with_context(|| format!("Failed to parse ROM: {}", path.display()))?;
+    Ok((bytes, rom))
+}
+
+fn create(from: &Path, to: &Path, output: Option<PathBuf>) -> Result<()> {
+    let (old_bytes, old_rom) = read_rom(from)?;
+    let (new_bytes, new_rom) = read_rom(to)?;
+
+    let (old_meta, new_meta) = (&old_rom.metadata, &new_rom.metadata);
+    if old_meta.id != new_meta.id {
+        anyhow::bail!(
+            "ROMs belong to different games ('{}' and '{}')",
+            old_meta.id,
+            new_meta.id
+        );
+    }
+    if old_bytes == new_bytes {
+        anyhow::bail!("ROMs are identical; nothing to patch");
+    }
+
+    println!(
+        "Diffing {} {} -> {}...",
+        new_meta.id, old_meta.version, new_meta.version
+    );
+    let patch = RomPatch::create(
+        &new_meta.id,
+        &old_meta.version,
+        &new_meta.version,
+        &old_bytes,
+        &new_bytes,
+    );
+    let bytes = patch.to_bytes();
+
+    let output = output.unwrap_or_else(|| {
+        PathBuf::from(format!(
+            "{}-{}-to-{}.{}",
+            new_meta.id, old_meta.version, new_meta.version, ROM_PATCH_EXTENSION
+        ))
+    });
+    std::fs::write(&output, &bytes)
+        .with_context(|| format!("Failed to write patch: {}", output.display()))?;
+
+    println!();
+    println!("Created: {} ({} bytes)", output.display(), bytes.len());
+    println!(
+        "  Updated ROM: {} bytes ({:.1}% shipped as patch)",
+        new_bytes.len(),
+        bytes.len() as f64 * 100.0 / new_bytes.len() as f64
+    );
+    println!(
+        "  Operations: {} ({} new bytes)",
+        patch.ops.len(),
+        patch.inserted_bytes()
+    );
+
+    Ok(())
+}
+
+fn apply(rom: &Path, patch_path: &Path, output: &Path) -> Result<()> {
+    let (old_bytes, _) = read_rom(rom)?;
+    let patch = RomPatch::from_bytes(&read_file_with_limit(patch_path, MAX_ROM_BYTES)?)
+        .with_context(|| format!("Failed to load patch: {}", patch_path.display()))?;
+
+    let new_bytes = patch.apply(&old_bytes)?;
+    ZXRom::from_bytes(&new_bytes).context("Patched ROM is not a valid ROM")?;
+
+    std::fs::write(output, &new_bytes)
+        .with_context(|| format!("Failed to write ROM: {}", output.display()))?;
+
+    println!(
+        "Patched {} {} -> {}",
+        patch.game_id, patch.from_version, patch.to_version
+    );
+    println!("Created: {} ({} bytes)", output.display(), new_bytes.len());
+
+    Ok(())
+}
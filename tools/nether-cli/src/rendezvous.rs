@@ -0,0 +1,26 @@
+//! Rendezvous command - run a lobby registry for in-game lobbies
+//!
+//! Players point `netplay.rendezvous_server` in their config at this server
+//! to host and browse lobbies from inside a game. Only lobby listings pass
+//! through it; game traffic goes directly between players.
+
+use anyhow::{Context, Result};
+use clap::Args;
+use nethercore_core::net::rendezvous::{serve, DEFAULT_RENDEZVOUS_PORT};
+
+/// Arguments for the rendezvous command
+#[derive(Args)]
+pub struct RendezvousArgs {
+    /// Address to listen on (UDP)
+    #[arg(long, default_value_t = format!("0.0.0.0:{}", DEFAULT_RENDEZVOUS_PORT))]
+    pub bind: String,
+}
+
+/// Execute the rendezvous command (runs until interrupted)
+pub fn execute(args: RendezvousArgs) -> Result<()> {
+    println!("=== Rendezvous Server ===");
+    println!("  Listening on {} (UDP)", args.bind);
+    println!("  Press Ctrl+C to stop");
+
+    serve(&args.bind).with_context(|| format!("Rendezvous server on {} failed", args.bind))
+}
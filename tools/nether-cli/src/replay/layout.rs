@@ -29,7 +29,8 @@ impl InputLayout for ZxInputLayout {
             }
         }
 
-        let mut bytes = vec![0u8; 8];
+        // pointer x/y (bytes 8-11) stay zero: scripts can't move the mouse
+        let mut bytes = vec![0u8; 12];
 
         // buttons: u16 (2 bytes)
         bytes[0] = (buttons & 0xFF) as u8;
@@ -140,7 +141,7 @@ impl InputLayout for ZxInputLayout {
     }
 
     fn input_size(&self) -> usize {
-        8
+        12
     }
 
     fn console_id(&self) -> u8 {
@@ -72,6 +72,18 @@ pub struct RunArgs {
     /// Run a replay script (.ncrs) for automated playback and screenshots
     #[arg(long, value_name = "FILE")]
     pub replay: Option<PathBuf>,
+
+    /// Render a recorded replay (slot number or .ncrp file) to a video instead of playing
+    #[arg(long, value_name = "SLOT|FILE", requires = "render_output")]
+    pub render_replay: Option<String>,
+
+    /// Output for --render-replay (.mp4/.webm via ffmpeg, .gif, or a directory for PNG frames)
+    #[arg(long, value_name = "PATH")]
+    pub render_output: Option<PathBuf>,
+
+    /// Output resolution for --render-replay (e.g. 1920x1080)
+    #[arg(long, value_name = "WxH")]
+    pub render_resolution: Option<String>,
 }
 
 /// Execute the run command
@@ -428,6 +440,21 @@ fn build_player_args(args: &RunArgs) -> Vec<String> {
         extra_args.push(replay.display().to_string());
     }
 
+    if let Some(ref source) = args.render_replay {
+        extra_args.push("--render-replay".to_string());
+        extra_args.push(source.clone());
+    }
+
+    if let Some(ref output) = args.render_output {
+        extra_args.push("--render-output".to_string());
+        extra_args.push(output.display().to_string());
+    }
+
+    if let Some(ref resolution) = args.render_resolution {
+        extra_args.push("--render-resolution".to_string());
+        extra_args.push(resolution.clone());
+    }
+
     extra_args
 }
 
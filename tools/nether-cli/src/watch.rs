@@ -69,6 +69,7 @@ fn collect_asset_paths(project_dir: &Path, assets: &AssetsSection, out: &mut Vec
     add_entries(&assets.keyframes, out);
     add_entries(&assets.animations, out);
     add_entries(&assets.sounds, out);
+    add_entries(&assets.streams, out);
     add_entries(&assets.trackers, out);
     add_entries(&assets.data, out);
 }
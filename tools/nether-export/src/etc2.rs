@@ -0,0 +1,374 @@
+//! ETC2 and EAC texture compression
+//!
+//! The mobile and WebGL counterparts of BC7 and BC5, for GPUs without BC
+//! support. Both formats use 16-byte 4×4 blocks, so they cost the same as
+//! their BC twins:
+//! - ETC2 RGBA8: an EAC alpha block followed by an ETC2 color block
+//! - EAC RG11: two EAC blocks (red, then green), for tangent-space normal maps
+//!
+//! Color blocks come from intel_tex_2's ETC1 encoder (every ETC1 block is a
+//! valid ETC2 block). EAC blocks are encoded here by searching the modifier
+//! tables for the closest fit.
+
+use anyhow::Result;
+
+/// EAC modifier tables (shared by the 8-bit alpha and 11-bit R/RG formats)
+const EAC_MODIFIERS: [[i32; 8]; 16] = [
+    [-3, -6, -9, -15, 2, 5, 8, 14],
+    [-3, -7, -10, -13, 2, 6, 9, 12],
+    [-2, -5, -8, -13, 1, 4, 7, 12],
+    [-2, -4, -6, -13, 1, 3, 5, 12],
+    [-3, -6, -8, -12, 2, 5, 7, 11],
+    [-3, -7, -9, -11, 2, 6, 8, 10],
+    [-4, -7, -8, -11, 3, 6, 7, 10],
+    [-3, -5, -8, -11, 2, 4, 7, 10],
+    [-2, -6, -8, -10, 1, 5, 7, 9],
+    [-2, -5, -8, -10, 1, 4, 7, 9],
+    [-2, -4, -8, -10, 1, 3, 7, 9],
+    [-2, -5, -7, -10, 1, 4, 6, 9],
+    [-3, -4, -7, -10, 2, 3, 6, 9],
+    [-1, -2, -3, -10, 0, 1, 2, 9],
+    [-4, -6, -8, -9, 3, 5, 7, 8],
+    [-3, -5, -7, -9, 2, 4, 6, 8],
+];
+
+/// How hard the EAC encoder searches for each block's parameters
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EacEffort {
+    /// Fit each table's range to the block's range (quick, good on most data)
+    #[default]
+    Fast,
+    /// Also try every multiplier and nearby base values (slower, fewer
+    /// banding artifacts on smooth gradients)
+    Thorough,
+}
+
+/// Value range of an EAC channel and how block parameters map into it
+#[derive(Clone, Copy)]
+struct EacChannel {
+    /// Largest decoded value
+    max: i32,
+    /// Scale applied to the base value and modifiers
+    scale: i32,
+    /// Added to the scaled base value
+    offset: i32,
+}
+
+impl EacChannel {
+    /// 8-bit alpha in ETC2 RGBA8
+    const ALPHA: Self = Self {
+        max: 255,
+        scale: 1,
+        offset: 0,
+    };
+
+    /// Unsigned 11-bit channel in EAC R11/RG11
+    const UNORM11: Self = Self {
+        max: 2047,
+        scale: 8,
+        offset: 4,
+    };
+
+    /// Decoded value of one pixel
+    #[inline]
+    fn decode(self, base: i32, multiplier: i32, modifier: i32) -> i32 {
+        (base * self.scale + self.offset + modifier * multiplier * self.scale).clamp(0, self.max)
+    }
+}
+
+/// Compress RGBA8 pixels to ETC2 RGBA8 (EAC alpha + ETC2 color)
+///
+/// # Arguments
+/// * `pixels` - RGBA8 pixel data (width × height × 4 bytes)
+/// * `width` - Image width in pixels
+/// * `height` - Image height in pixels
+/// * `effort` - Search effort for the alpha blocks
+///
+/// # Returns
+/// ETC2 block data (16 bytes per 4×4 block)
+pub fn compress_etc2(pixels: &[u8], width: u32, height: u32, effort: EacEffort) -> Result<Vec<u8>> {
+    use intel_tex_2::etc1;
+
+    let (padded, padded_width, padded_height) = pad_to_blocks(pixels, width, height)?;
+    let surface = intel_tex_2::RgbaSurface {
+        width: padded_width as u32,
+        height: padded_height as u32,
+        stride: (padded_width * 4) as u32,
+        data: &padded,
+    };
+    let color = etc1::compress_blocks(&etc1::slow_settings(), &surface);
+
+    let blocks_x = padded_width / 4;
+    let blocks_y = padded_height / 4;
+    let mut output = Vec::with_capacity(blocks_x * blocks_y * 16);
+    for (block, color_block) in color.chunks_exact(8).enumerate() {
+        let alpha = block_channel(&padded, padded_width, block % blocks_x, block / blocks_x, 3);
+        let targets = alpha.map(|a| a as i32);
+        output.extend_from_slice(&encode_eac_block(&targets, EacChannel::ALPHA, effort));
+        output.extend_from_slice(color_block);
+    }
+
+    Ok(output)
+}
+
+/// Compress RGBA8 pixels to EAC RG11 (red and green only) for normal maps
+///
+/// # Arguments
+/// * `pixels` - RGBA8 pixel data (width × height × 4 bytes) - only RG channels used
+/// * `width` - Image width in pixels
+/// * `height` - Image height in pixels
+/// * `effort` - Search effort for each channel
+///
+/// # Returns
+/// EAC RG11 block data (16 bytes per 4×4 block)
+pub fn compress_eac_rg11(
+    pixels: &[u8],
+    width: u32,
+    height: u32,
+    effort: EacEffort,
+) -> Result<Vec<u8>> {
+    let (padded, padded_width, padded_height) = pad_to_blocks(pixels, width, height)?;
+
+    let blocks_x = padded_width / 4;
+    let blocks_y = padded_height / 4;
+    let mut output = Vec::with_capacity(blocks_x * blocks_y * 16);
+    for by in 0..blocks_y {
+        for bx in 0..blocks_x {
+            for channel in 0..2 {
+                let values = block_channel(&padded, padded_width, bx, by, channel);
+                // Expand 8-bit values to the 11-bit range
+                let targets = values.map(|v| (v as i32 * 2047 + 127) / 255);
+                output.extend_from_slice(&encode_eac_block(&targets, EacChannel::UNORM11, effort));
+            }
+        }
+    }
+
+    Ok(output)
+}
+
+/// Copy pixels into a buffer whose size is a multiple of 4, extending edges
+fn pad_to_blocks(pixels: &[u8], width: u32, height: u32) -> Result<(Vec<u8>, usize, usize)> {
+    let w = width as usize;
+    let h = height as usize;
+    if w == 0 || h == 0 || pixels.len() != w * h * 4 {
+        anyhow::bail!(
+            "Invalid texture data: {}x{} needs {} bytes, got {}",
+            width,
+            height,
+            w * h * 4,
+            pixels.len()
+        );
+    }
+
+    let padded_width = w.div_ceil(4) * 4;
+    let padded_height = h.div_ceil(4) * 4;
+    if (padded_width, padded_height) == (w, h) {
+        return Ok((pixels.to_vec(), w, h));
+    }
+
+    let mut padded = vec![0u8; padded_width * padded_height * 4];
+    for y in 0..padded_height {
+        for x in 0..padded_width {
+            let src = (y.min(h - 1) * w + x.min(w - 1)) * 4;
+            let dst = (y * padded_width + x) * 4;
+            padded[dst..dst + 4].copy_from_slice(&pixels[src..src + 4]);
+        }
+    }
+    Ok((padded, padded_width, padded_height))
+}
+
+/// One channel of a 4×4 block, in EAC pixel order (column by column)
+fn block_channel(
+    pixels: &[u8],
+    stride_pixels: usize,
+    bx: usize,
+    by: usize,
+    channel: usize,
+) -> [u8; 16] {
+    let mut values = [0u8; 16];
+    for (i, value) in values.iter_mut().enumerate() {
+        let x = bx * 4 + i / 4;
+        let y = by * 4 + i % 4;
+        *value = pixels[(y * stride_pixels + x) * 4 + channel];
+    }
+    values
+}
+
+/// Encode one EAC block
+///
+/// Layout (64 bits, big-endian): base (8) | multiplier (4) | table (4) |
+/// sixteen 3-bit modifier indices, column by column.
+fn encode_eac_block(targets: &[i32; 16], channel: EacChannel, effort: EacEffort) -> [u8; 8] {
+    let lo = *targets.iter().min().unwrap_or(&0);
+    let hi = *targets.iter().max().unwrap_or(&0);
+
+    let mut best_error = i64::MAX;
+    let mut best = (0, 1, 0);
+    for (table, modifiers) in EAC_MODIFIERS.iter().enumerate() {
+        let span = (modifiers[7] - modifiers[3]) * channel.scale;
+        let fitted = ((hi - lo + span / 2) / span).clamp(1, 15);
+        let multipliers = match effort {
+            EacEffort::Fast => (fitted - 1).max(1)..=(fitted + 1).min(15),
+            EacEffort::Thorough => 1..=15,
+        };
+        for multiplier in multipliers {
+            // Center the table's range on the block's range
+            let middle = (modifiers[3] + modifiers[7]) * multiplier * channel.scale;
+            let center = (lo + hi - middle) / 2 - channel.offset;
+            let base = (center as f32 / channel.scale as f32).round() as i32;
+            let bases = match effort {
+                EacEffort::Fast => base..=base,
+                EacEffort::Thorough => base - 2..=base + 2,
+            };
+            for base in bases.map(|b| b.clamp(0, 255)) {
+                let error = block_error(targets, channel, base, multiplier, modifiers);
+                if error < best_error {
+                    best_error = error;
+                    best = (base, multiplier, table);
+                }
+            }
+        }
+        if best_error == 0 {
+            break;
+        }
+    }
+
+    let (base, multiplier, table) = best;
+    let modifiers = &EAC_MODIFIERS[table];
+    let mut bits = ((base as u64) << 56) | ((multiplier as u64) << 52) | ((table as u64) << 48);
+    for (i, &target) in targets.iter().enumerate() {
+        let index = nearest_modifier(target, channel, base, multiplier, modifiers);
+        bits |= (index as u64) << (45 - 3 * i);
+    }
+    bits.to_be_bytes()
+}
+
+/// Squared error of a block encoded with the given parameters
+fn block_error(
+    targets: &[i32; 16],
+    channel: EacChannel,
+    base: i32,
+    multiplier: i32,
+    modifiers: &[i32; 8],
+) -> i64 {
+    targets
+        .iter()
+        .map(|&target| {
+            let index = nearest_modifier(target, channel, base, multiplier, modifiers);
+            let diff = (channel.decode(base, multiplier, modifiers[index]) - target) as i64;
+            diff * diff
+        })
+        .sum()
+}
+
+/// Index of the modifier whose decoded value is closest to `target`
+fn nearest_modifier(
+    target: i32,
+    channel: EacChannel,
+    base: i32,
+    multiplier: i32,
+    modifiers: &[i32; 8],
+) -> usize {
+    (0..8)
+        .min_by_key(|&i| (channel.decode(base, multiplier, modifiers[i]) - target).abs())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Decode one EAC block back to 16 values in row-major order
+    fn decode_eac(block: &[u8], channel: EacChannel) -> [i32; 16] {
+        let bits = u64::from_be_bytes(block[..8].try_into().unwrap());
+        let base = (bits >> 56) as i32;
+        let multiplier = ((bits >> 52) & 0xF) as i32;
+        let modifiers = &EAC_MODIFIERS[((bits >> 48) & 0xF) as usize];
+        let mut values = [0; 16];
+        for i in 0..16 {
+            let index = ((bits >> (45 - 3 * i)) & 0x7) as usize;
+            // Stored column by column; return row by row
+            values[(i % 4) * 4 + i / 4] = channel.decode(base, multiplier, modifiers[index]);
+        }
+        values
+    }
+
+    fn rgba(width: u32, height: u32, pixel: impl Fn(u32, u32) -> [u8; 4]) -> Vec<u8> {
+        (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .flat_map(|(x, y)| pixel(x, y))
+            .collect()
+    }
+
+    #[test]
+    fn test_etc2_output_size() {
+        let pixels = rgba(30, 18, |_, _| [10, 20, 30, 255]);
+        let data = compress_etc2(&pixels, 30, 18, EacEffort::Fast).unwrap();
+        // 30×18 rounds up to 8×5 blocks
+        assert_eq!(data.len(), 8 * 5 * 16);
+    }
+
+    #[test]
+    fn test_etc2_alpha_block_comes_first() {
+        let pixels = rgba(4, 4, |_, _| [200, 100, 50, 77]);
+        let data = compress_etc2(&pixels, 4, 4, EacEffort::Fast).unwrap();
+        assert_eq!(decode_eac(&data[..8], EacChannel::ALPHA), [77; 16]);
+    }
+
+    #[test]
+    fn test_eac_alpha_gradient_is_close() {
+        let pixels = rgba(4, 4, |x, y| [0, 0, 0, (x * 40 + y * 20) as u8]);
+        for effort in [EacEffort::Fast, EacEffort::Thorough] {
+            let data = compress_etc2(&pixels, 4, 4, effort).unwrap();
+            let decoded = decode_eac(&data[..8], EacChannel::ALPHA);
+            for (i, value) in decoded.iter().enumerate() {
+                let expected = pixels[i * 4 + 3] as i32;
+                assert!(
+                    (value - expected).abs() <= 20,
+                    "{:?}: pixel {} decoded {} vs {}",
+                    effort,
+                    i,
+                    value,
+                    expected
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_thorough_is_never_worse() {
+        let pixels = rgba(4, 4, |x, y| {
+            [0, 0, 0, [3, 90, 91, 250][((x + y) % 4) as usize]]
+        });
+        let error = |effort| {
+            let data = compress_etc2(&pixels, 4, 4, effort).unwrap();
+            let decoded = decode_eac(&data[..8], EacChannel::ALPHA);
+            (0..16)
+                .map(|i| (decoded[i] - pixels[i * 4 + 3] as i32).pow(2))
+                .sum::<i32>()
+        };
+        assert!(error(EacEffort::Thorough) <= error(EacEffort::Fast));
+    }
+
+    #[test]
+    fn test_eac_rg11_channels() {
+        let pixels = rgba(4, 4, |x, _| [255, (x * 60) as u8, 9, 9]);
+        let data = compress_eac_rg11(&pixels, 4, 4, EacEffort::Fast).unwrap();
+        assert_eq!(data.len(), 16);
+
+        let red = decode_eac(&data[..8], EacChannel::UNORM11);
+        assert!(red.iter().all(|&r| r >= 2040));
+
+        let green = decode_eac(&data[8..], EacChannel::UNORM11);
+        for (i, value) in green.iter().enumerate() {
+            let expected = pixels[i * 4 + 1] as i32 * 2047 / 255;
+            assert!((value - expected).abs() <= 40, "{} vs {}", value, expected);
+        }
+    }
+
+    #[test]
+    fn test_rejects_mismatched_data() {
+        assert!(compress_etc2(&[0; 12], 2, 2, EacEffort::Fast).is_err());
+        assert!(compress_eac_rg11(&[], 0, 0, EacEffort::Fast).is_err());
+    }
+}
@@ -5,6 +5,7 @@
 pub mod animation;
 pub mod audio;
 pub mod codegen;
+pub mod etc2;
 pub mod formats;
 pub mod manifest;
 pub mod mesh;
@@ -1,12 +1,16 @@
 //! glTF/GLB mesh conversion
 
 use super::packing::{pack_vertices_skinned, parse_format_string};
-use super::types::{ConvertedMesh, SkinningData, MAX_INDEX_VALUE, MAX_JOINT_INDEX};
+use super::types::{ConvertedMesh, SkinningData, MAX_INDEX_VALUE, MAX_JOINT_INDEX, SOCKET_PREFIX};
 use anyhow::{bail, Context, Result};
+use glam::Mat4;
+use nethercore_shared::math::BoneMatrix3x4;
 use std::fs::File;
 use std::io::BufWriter;
 use std::path::Path;
 
+use zx_common::{MeshSocket, MorphTarget, MAX_MORPH_TARGETS};
+
 use crate::formats::write_nether_mesh;
 use crate::{
     vertex_stride_packed, FORMAT_COLOR, FORMAT_NORMAL, FORMAT_SKINNED, FORMAT_TANGENT, FORMAT_UV,
@@ -15,10 +19,21 @@ use crate::{
 /// Convert a glTF/GLB file to in-memory mesh data (for direct ROM packing)
 ///
 /// Automatically detects and includes skinning data (bone indices + weights)
-/// when present in the glTF file.
+/// when present in the glTF file. Nodes named `socket_<name>` become mesh
+/// sockets, and the primitive's morph targets become blend shapes.
 pub fn convert_gltf_to_memory(input: &Path) -> Result<ConvertedMesh> {
-    let (positions, uvs, colors, normals, tangents, skinning, indices, format) =
-        parse_gltf_file(input)?;
+    let (
+        positions,
+        uvs,
+        colors,
+        normals,
+        tangents,
+        skinning,
+        indices,
+        format,
+        sockets,
+        morph_targets,
+    ) = parse_gltf_file(input)?;
 
     // Pack vertex data
     let vertex_data = pack_vertices_skinned(
@@ -37,13 +52,39 @@ pub fn convert_gltf_to_memory(input: &Path) -> Result<ConvertedMesh> {
         index_count: indices.len() as u32,
         vertex_data,
         indices,
+        sockets,
+        morph_targets,
     })
 }
 
 /// Convert a glTF/GLB file to NetherMesh format
 pub fn convert_gltf(input: &Path, output: &Path, format_override: Option<&str>) -> Result<()> {
-    let (positions, uvs, colors, normals, tangents, skinning, indices, auto_format) =
-        parse_gltf_file(input)?;
+    let (
+        positions,
+        uvs,
+        colors,
+        normals,
+        tangents,
+        skinning,
+        indices,
+        auto_format,
+        sockets,
+        morph_targets,
+    ) = parse_gltf_file(input)?;
+
+    // The .nczxmesh format has no socket table; sockets only survive ROM packing
+    if !sockets.is_empty() {
+        tracing::warn!(
+            "Dropping {} socket(s): .nczxmesh files don't store sockets",
+            sockets.len()
+        );
+    }
+    if !morph_targets.is_empty() {
+        tracing::warn!(
+            "Dropping {} morph target(s): .nczxmesh files don't store morph targets",
+            morph_targets.len()
+        );
+    }
 
     // Use override format if provided, otherwise use auto-detected format
     let format = if let Some(fmt_str) = format_override {
@@ -84,7 +125,8 @@ pub fn convert_gltf(input: &Path, output: &Path, format_override: Option<&str>)
 
 /// Parse glTF file and extract vertex data + auto-detected format
 ///
-/// Returns: (positions, uvs, colors, normals, tangents, skinning, indices, format)
+/// Returns: (positions, uvs, colors, normals, tangents, skinning, indices, format, sockets,
+/// morph_targets)
 #[allow(clippy::type_complexity)]
 fn parse_gltf_file(
     input: &Path,
@@ -97,6 +139,8 @@ fn parse_gltf_file(
     Option<SkinningData<'static>>,
     Vec<u16>,
     u8,
+    Vec<MeshSocket>,
+    Vec<MorphTarget>,
 )> {
     let (document, buffers, _images) =
         gltf::import(input).with_context(|| format!("Failed to load glTF: {:?}", input))?;
@@ -243,7 +287,124 @@ fn parse_gltf_file(
         format |= FORMAT_TANGENT;
     }
 
+    let sockets = extract_sockets(&document, &mesh)?;
+
+    // Morph targets (optional) - per-vertex displacements of the bind pose
+    let mut morph_targets = Vec::new();
+    for (index, (target_positions, target_normals, _)) in reader.read_morph_targets().enumerate() {
+        if index >= MAX_MORPH_TARGETS {
+            bail!(
+                "Mesh has more than {} morph targets. Merge or remove blend shapes.",
+                MAX_MORPH_TARGETS
+            );
+        }
+        let target = MorphTarget {
+            positions: target_positions
+                .map(|iter| iter.collect())
+                .unwrap_or_else(|| vec![[0.0; 3]; positions.len()]),
+            normals: target_normals
+                .filter(|_| normals.is_some())
+                .map(|iter| iter.collect())
+                .unwrap_or_default(),
+        };
+        if target.positions.len() != positions.len()
+            || (!target.normals.is_empty() && target.normals.len() != positions.len())
+        {
+            bail!(
+                "Morph target {} has a different vertex count than the mesh ({} vertices)",
+                index,
+                positions.len()
+            );
+        }
+        morph_targets.push(target);
+    }
+
     Ok((
-        positions, uvs, colors, normals, tangents, skinning, indices, format,
+        positions,
+        uvs,
+        colors,
+        normals,
+        tangents,
+        skinning,
+        indices,
+        format,
+        sockets,
+        morph_targets,
     ))
 }
+
+/// Extract sockets from nodes named `socket_<name>`
+///
+/// Each socket's transform is its bind-pose placement relative to the node
+/// that instantiates `mesh`. A socket parented (directly or further down) to
+/// one of that node's skin joints follows the nearest such joint.
+fn extract_sockets(document: &gltf::Document, mesh: &gltf::Mesh) -> Result<Vec<MeshSocket>> {
+    // glTF only stores children, so build the parent links
+    let mut parents = vec![None; document.nodes().len()];
+    for node in document.nodes() {
+        for child in node.children() {
+            parents[child.index()] = Some(node.index());
+        }
+    }
+
+    let nodes: Vec<gltf::Node> = document.nodes().collect();
+    let global = |mut index: usize| {
+        let mut matrix = Mat4::IDENTITY;
+        loop {
+            matrix = Mat4::from_cols_array_2d(&nodes[index].transform().matrix()) * matrix;
+            match parents[index] {
+                Some(parent) => index = parent,
+                None => return matrix,
+            }
+        }
+    };
+
+    let mesh_node = nodes
+        .iter()
+        .find(|node| node.mesh().map(|m| m.index()) == Some(mesh.index()));
+    let to_mesh = mesh_node.map_or(Mat4::IDENTITY, |node| global(node.index()).inverse());
+    let joints: Vec<usize> = mesh_node
+        .and_then(|node| node.skin())
+        .map(|skin| skin.joints().map(|joint| joint.index()).collect())
+        .unwrap_or_default();
+
+    let mut sockets: Vec<MeshSocket> = Vec::new();
+    for node in &nodes {
+        let Some(name) = node.name().and_then(|n| n.strip_prefix(SOCKET_PREFIX)) else {
+            continue;
+        };
+        if name.is_empty() {
+            bail!(
+                "Socket node '{}' has no name after the prefix",
+                SOCKET_PREFIX
+            );
+        }
+        if sockets.iter().any(|socket| socket.name == name) {
+            bail!("Duplicate socket '{}'", name);
+        }
+
+        // Nearest ancestor that is a joint of the mesh's skin
+        let mut bone = MeshSocket::NO_BONE;
+        let mut ancestor = parents[node.index()];
+        while let Some(index) = ancestor {
+            if let Some(joint) = joints.iter().position(|&j| j == index) {
+                bone = joint as u32;
+                break;
+            }
+            ancestor = parents[index];
+        }
+
+        let matrix = to_mesh * global(node.index());
+        sockets.push(MeshSocket {
+            name: name.to_string(),
+            bone,
+            transform: BoneMatrix3x4::from_rows(
+                matrix.row(0).to_array(),
+                matrix.row(1).to_array(),
+                matrix.row(2).to_array(),
+            ),
+        });
+    }
+
+    Ok(sockets)
+}
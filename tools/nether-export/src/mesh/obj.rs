@@ -31,6 +31,8 @@ pub fn convert_obj_to_memory(input: &Path) -> Result<ConvertedMesh> {
         index_count: indices.len() as u32,
         vertex_data,
         indices,
+        sockets: Vec::new(),
+        morph_targets: Vec::new(),
     })
 }
 
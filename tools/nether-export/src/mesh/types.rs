@@ -1,5 +1,7 @@
 //! Types and constants for mesh conversion
 
+use zx_common::{MeshSocket, MorphTarget};
+
 /// Maximum index value for u16 indices (65535)
 /// Meshes with more vertices must be split before export.
 pub(crate) const MAX_INDEX_VALUE: u32 = u16::MAX as u32;
@@ -8,6 +10,9 @@ pub(crate) const MAX_INDEX_VALUE: u32 = u16::MAX as u32;
 /// Skeletons with more bones are not supported.
 pub(crate) const MAX_JOINT_INDEX: u16 = u8::MAX as u16;
 
+/// Node name prefix that marks a glTF node as a mesh socket
+pub(crate) const SOCKET_PREFIX: &str = "socket_";
+
 /// Skinning data: tuple of (bone indices, bone weights)
 pub(crate) type SkinningData<'a> = (&'a [[u8; 4]], &'a [[f32; 4]]);
 
@@ -23,4 +28,8 @@ pub struct ConvertedMesh {
     pub vertex_data: Vec<u8>,
     /// Index data (u16)
     pub indices: Vec<u16>,
+    /// Named attachment points (glTF only)
+    pub sockets: Vec<MeshSocket>,
+    /// Blend shapes (glTF only)
+    pub morph_targets: Vec<MorphTarget>,
 }
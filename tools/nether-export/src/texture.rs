@@ -4,6 +4,8 @@
 //! - RGBA8 (Mode 0): Uncompressed, pixel-perfect, 32 bpp
 //! - BC7 (Modes 1-3): Compressed RGBA, 8 bpp, 4× size reduction
 //! - BC5 (Normal maps): Compressed RG, 8 bpp, for tangent-space normals
+//!
+//! ETC2 and EAC RG11 are the equivalents of BC7 and BC5 for GPUs without BC support.
 
 use anyhow::{Context, Result};
 use image::GenericImageView;
@@ -13,6 +15,7 @@ use std::path::Path;
 
 use zx_common::TextureFormat;
 
+use crate::etc2::{compress_eac_rg11, compress_etc2, EacEffort};
 use crate::formats::write_nether_texture;
 
 /// Convert an image file to NetherTexture format (RGBA8)
@@ -42,6 +45,15 @@ pub fn convert_image_with_format(input: &Path, output: &Path, format: TextureFor
             let compressed = compress_bc5(pixels, width, height)?;
             (compressed, TextureFormat::Bc5)
         }
+        TextureFormat::Etc2 => {
+            let compressed = compress_etc2(pixels, width, height, EacEffort::Fast)?;
+            (compressed, TextureFormat::Etc2)
+        }
+        TextureFormat::EacRg11 => {
+            let compressed = compress_eac_rg11(pixels, width, height, EacEffort::Fast)?;
+            (compressed, TextureFormat::EacRg11)
+        }
+        TextureFormat::Indexed8 => (red_channel(pixels), TextureFormat::Indexed8),
     };
 
     // Write output
@@ -61,10 +73,12 @@ pub fn convert_image_with_format(input: &Path, output: &Path, format: TextureFor
         let original_size = (width * height * 4) as usize;
         let compressed_size = output_data.len();
         let ratio = original_size as f32 / compressed_size as f32;
-        let format_name = if output_format.is_bc7() { "BC7" } else { "BC5" };
         format!(
             " ({}: {} -> {} bytes, {:.1}× compression)",
-            format_name, original_size, compressed_size, ratio
+            output_format.short_name(),
+            original_size,
+            compressed_size,
+            ratio
         )
     } else {
         String::new()
@@ -236,11 +250,19 @@ pub fn process_texture_for_pack(
         TextureFormat::Rgba8 => pixels.to_vec(),
         TextureFormat::Bc7 => compress_bc7(pixels, width, height)?,
         TextureFormat::Bc5 => compress_bc5(pixels, width, height)?,
+        TextureFormat::Etc2 => compress_etc2(pixels, width, height, EacEffort::Fast)?,
+        TextureFormat::EacRg11 => compress_eac_rg11(pixels, width, height, EacEffort::Fast)?,
+        TextureFormat::Indexed8 => red_channel(pixels),
     };
 
     Ok((width as u16, height as u16, format, data))
 }
 
+/// Palette indices from RGBA8 pixels (the red channel of each pixel)
+fn red_channel(pixels: &[u8]) -> Vec<u8> {
+    pixels.chunks_exact(4).map(|px| px[0]).collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
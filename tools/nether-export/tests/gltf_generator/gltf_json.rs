@@ -1,6 +1,6 @@
 //! GLTF JSON structure building.
 
-use super::mesh_data::{BONE_COUNT, SEGMENT_HEIGHT};
+use super::mesh_data::{BONE_COUNT, SEGMENT_HEIGHT, SOCKET_OFFSET};
 use gltf_json as json;
 use json::validation::Checked::Valid;
 
@@ -14,6 +14,7 @@ pub(crate) fn build_gltf_json(
     const SPINE_NODE: u32 = 1;
     const HEAD_NODE: u32 = 2;
     const MESH_NODE: u32 = 3;
+    const SOCKET_NODE: u32 = 4;
 
     // Accessor indices (must match pack_binary_data order)
     const POS_ACCESSOR: u32 = 0;
@@ -60,7 +61,7 @@ pub(crate) fn build_gltf_json(
         // Node 2: Head bone
         json::Node {
             camera: None,
-            children: None,
+            children: Some(vec![json::Index::new(SOCKET_NODE)]),
             extensions: Default::default(),
             extras: Default::default(),
             matrix: None,
@@ -87,6 +88,21 @@ pub(crate) fn build_gltf_json(
             skin: Some(json::Index::new(0)),
             weights: None,
         },
+        // Node 4: Socket on the head bone
+        json::Node {
+            camera: None,
+            children: None,
+            extensions: Default::default(),
+            extras: Default::default(),
+            matrix: None,
+            mesh: None,
+            name: Some("socket_hat".to_string()),
+            rotation: None,
+            scale: None,
+            translation: Some([0.0, SOCKET_OFFSET, 0.0]),
+            skin: None,
+            weights: None,
+        },
     ];
 
     // Create mesh primitive
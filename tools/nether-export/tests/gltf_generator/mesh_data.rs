@@ -8,6 +8,8 @@ pub const BONE_COUNT: usize = 3;
 pub const FRAME_COUNT: usize = 30;
 /// Segment height between bones
 pub(crate) const SEGMENT_HEIGHT: f32 = 1.0;
+/// Height of the `socket_hat` socket above the head bone
+pub const SOCKET_OFFSET: f32 = 0.5;
 
 /// Mesh data for the test asset
 pub(crate) struct MeshData {
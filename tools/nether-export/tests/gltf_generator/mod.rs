@@ -11,7 +11,7 @@ mod gltf_json;
 mod mesh_data;
 mod partial_trs;
 
-pub use mesh_data::BONE_COUNT;
+pub use mesh_data::{BONE_COUNT, SOCKET_OFFSET};
 
 use mesh_data::{create_animation, create_mesh_data, create_skeleton};
 
@@ -20,6 +20,7 @@ use mesh_data::{create_animation, create_mesh_data, create_skeleton};
 /// Contains:
 /// - 3 stacked box segments (one per bone)
 /// - 3-bone skeleton (Root → Spine → Head)
+/// - `socket_hat` socket on the head bone
 /// - 30-frame wave animation
 pub fn generate_skinned_glb() -> Vec<u8> {
     // Build mesh data
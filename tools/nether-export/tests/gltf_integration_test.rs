@@ -144,6 +144,18 @@ fn test_glb_mesh_conversion() {
         "Index data size mismatch"
     );
 
+    // Verify the head socket: bone 2, two segments plus the offset above the origin
+    assert_eq!(result.sockets.len(), 1, "Expected one socket");
+    let socket = &result.sockets[0];
+    assert_eq!(socket.name, "hat");
+    assert_eq!(socket.bone, 2, "Socket should follow the head bone");
+    let expected_height = 2.0 + gltf_generator::SOCKET_OFFSET;
+    assert!(
+        (socket.transform.row1[3] - expected_height).abs() < 1e-5,
+        "Socket height mismatch: {}",
+        socket.transform.row1[3]
+    );
+
     println!(
         "Mesh conversion validated: {} vertices, {} indices, format=0x{:02X}, stride={}",
         result.vertex_count, result.index_count, result.format, stride
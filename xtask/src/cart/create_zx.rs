@@ -4,6 +4,7 @@ use std::path::{Path, PathBuf};
 
 use anyhow::{Context, Result};
 use clap::Args;
+use nethercore_shared::capabilities::Capabilities;
 use nethercore_shared::netplay::NetplayMetadata;
 use nethercore_shared::{
     is_safe_game_id, read_file_with_limit, MAX_PNG_BYTES, MAX_ROM_BYTES, MAX_WASM_BYTES,
@@ -73,6 +74,18 @@ pub struct CreateZxArgs {
     #[arg(long)]
     pub target_fps: Option<u32>,
 
+    /// Allow players to sideload override packs (.nczxmod) for this ROM
+    #[arg(long)]
+    pub allow_override_packs: bool,
+
+    /// Let the ROM persist save data to disk (`saves` capability)
+    #[arg(long)]
+    pub allow_saves: bool,
+
+    /// Let the ROM capture microphone input (`capture` capability)
+    #[arg(long)]
+    pub allow_capture: bool,
+
     /// Output ROM file path (.nczx)
     #[arg(long, short = 'o')]
     pub output: PathBuf,
@@ -144,8 +157,8 @@ pub fn execute(args: CreateZxArgs) -> Result<()> {
 
     // 4. Validate render mode
     if let Some(mode) = args.render_mode {
-        if mode > 3 {
-            anyhow::bail!("Invalid render mode: {} (must be 0-3)", mode);
+        if mode > 4 {
+            anyhow::bail!("Invalid render mode: {} (must be 0-4)", mode);
         }
     }
 
@@ -192,6 +205,12 @@ pub fn execute(args: CreateZxArgs) -> Result<()> {
         render_mode: args.render_mode,
         default_resolution: args.default_resolution.clone(),
         target_fps: args.target_fps,
+        allow_override_packs: args.allow_override_packs,
+        capabilities: Capabilities {
+            saves: args.allow_saves,
+            capture: args.allow_capture,
+            ..Default::default()
+        },
         netplay: NetplayMetadata {
             max_players: 1, // Single-player
             ..Default::default()
@@ -293,6 +312,9 @@ mod tests {
             render_mode: None,
             default_resolution: None,
             target_fps: None,
+            allow_override_packs: false,
+            allow_saves: false,
+            allow_capture: false,
             output: tmp.path().join("out.nczx"),
         }
     }
@@ -78,10 +78,18 @@ fn build_examples() -> Result<()> {
             let path = entry.path();
             let name = entry.file_name();
             let name_str = name.to_string_lossy();
+            // Skip _lib/ and other private directories, including crates inside them
+            let is_private = path
+                .strip_prefix(&examples_dir)
+                .map(|rel| {
+                    rel.components()
+                        .any(|c| c.as_os_str().to_string_lossy().starts_with('_'))
+                })
+                .unwrap_or(false);
             path.is_dir()
                 && path.join("Cargo.toml").exists()
                 && !skip_dirs.contains(&name_str.as_ref())
-                && !name_str.starts_with('_') // Skip _lib/ and other private directories
+                && !is_private
         })
         .collect();
 
@@ -0,0 +1,84 @@
+//! Cubemap face layout
+//!
+//! Cubemaps are six square faces in the order +X, -X, +Y, -Y, +Z, -Z, each
+//! stored top row first, using the usual cubemap orientation (the one sky
+//! tools export and GPUs sample). The builder uses this mapping to convert
+//! equirectangular skies, and the runtime uses it for sky shading, so both
+//! agree on which texel a direction lands in.
+
+use glam::Vec3;
+
+/// Number of faces in a cubemap
+pub const CUBEMAP_FACES: usize = 6;
+
+/// Unit direction through point (u, v) of a face
+///
+/// `face` is 0-5 (+X, -X, +Y, -Y, +Z, -Z). `u` runs left to right and `v` top
+/// to bottom, both 0..1.
+pub fn cube_face_direction(face: usize, u: f32, v: f32) -> Vec3 {
+    let s = 2.0 * u - 1.0;
+    let t = 2.0 * v - 1.0;
+    let dir = match face {
+        0 => Vec3::new(1.0, -t, -s),
+        1 => Vec3::new(-1.0, -t, s),
+        2 => Vec3::new(s, 1.0, t),
+        3 => Vec3::new(s, -1.0, -t),
+        4 => Vec3::new(s, -t, 1.0),
+        _ => Vec3::new(-s, -t, -1.0),
+    };
+    dir.normalize()
+}
+
+/// Face and (u, v) point a direction passes through
+///
+/// Inverse of [`cube_face_direction`]. Ties between axes go to X, then Y.
+pub fn cube_face_uv(dir: Vec3) -> (usize, f32, f32) {
+    let a = dir.abs();
+    let (face, s, t, major) = if a.x >= a.y && a.x >= a.z {
+        if dir.x >= 0.0 {
+            (0, -dir.z, -dir.y, a.x)
+        } else {
+            (1, dir.z, -dir.y, a.x)
+        }
+    } else if a.y >= a.z {
+        if dir.y >= 0.0 {
+            (2, dir.x, dir.z, a.y)
+        } else {
+            (3, dir.x, -dir.z, a.y)
+        }
+    } else if dir.z >= 0.0 {
+        (4, dir.x, -dir.y, a.z)
+    } else {
+        (5, -dir.x, -dir.y, a.z)
+    };
+    (face, 0.5 * (s / major + 1.0), 0.5 * (t / major + 1.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_face_centers_point_along_axes() {
+        let axes = [Vec3::X, -Vec3::X, Vec3::Y, -Vec3::Y, Vec3::Z, -Vec3::Z];
+        for (face, axis) in axes.into_iter().enumerate() {
+            assert!(cube_face_direction(face, 0.5, 0.5).distance(axis) < 1e-6);
+        }
+
+        // Side faces keep +Y at the top row
+        for face in [0, 1, 4, 5] {
+            assert!(cube_face_direction(face, 0.5, 0.0).y > 0.0);
+        }
+    }
+
+    #[test]
+    fn test_face_uv_roundtrip() {
+        for face in 0..CUBEMAP_FACES {
+            for (u, v) in [(0.1, 0.2), (0.5, 0.5), (0.9, 0.3), (0.25, 0.8)] {
+                let (f, u2, v2) = cube_face_uv(cube_face_direction(face, u, v));
+                assert_eq!(f, face);
+                assert!((u - u2).abs() < 1e-5 && (v - v2).abs() < 1e-5);
+            }
+        }
+    }
+}
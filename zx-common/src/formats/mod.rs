@@ -10,21 +10,29 @@
 //! serialization/deserialization.
 
 pub mod animation;
+pub mod cubemap;
 pub mod mesh;
+pub mod path;
 mod serialization;
 pub mod skeleton;
 pub mod sound;
+pub mod strings;
 pub mod texture;
 pub mod zx_data_pack;
+pub mod zx_override_pack;
 pub mod zx_rom;
 
 pub use animation::*;
+pub use cubemap::*;
 pub use mesh::*;
+pub use path::*;
 pub use serialization::BinarySerializable;
 pub use skeleton::*;
 pub use sound::*;
+pub use strings::*;
 pub use texture::*;
 pub use zx_data_pack::*;
+pub use zx_override_pack::*;
 pub use zx_rom::*;
 
 // Re-export ROM format from shared for convenience
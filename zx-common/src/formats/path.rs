@@ -0,0 +1,251 @@
+//! Spline path evaluation
+//!
+//! Evaluates [`PackedPath`] curves by arc length, so a caller advancing `t` at
+//! a constant rate moves along the curve at constant speed. Only basic float
+//! arithmetic and `sqrt` are used, so results match on every host — paths are
+//! evaluated during `update()` and must stay deterministic for rollback.
+
+use glam::Vec3;
+
+use super::{PackedPath, PathKind};
+
+/// Arc-length table samples per curve segment
+pub const PATH_SAMPLES_PER_SEGMENT: usize = 16;
+
+/// A spline path ready for evaluation
+#[derive(Debug, Clone)]
+pub struct PathCurve {
+    kind: PathKind,
+    closed: bool,
+    points: Vec<Vec3>,
+    /// Cumulative length at each sample (segments × samples + 1 entries)
+    lengths: Vec<f32>,
+}
+
+impl PathCurve {
+    /// Build a curve and its arc-length table
+    ///
+    /// Returns None if the point count doesn't fit the curve type (see
+    /// [`PackedPath::segment_count`]) or a coordinate isn't finite.
+    pub fn new(path: &PackedPath) -> Option<Self> {
+        let segments = path.segment_count()?;
+        if path.points.iter().flatten().any(|c| !c.is_finite()) {
+            return None;
+        }
+
+        let mut curve = Self {
+            kind: path.kind,
+            closed: path.closed,
+            points: path.points.iter().map(|&p| Vec3::from(p)).collect(),
+            lengths: Vec::with_capacity(segments * PATH_SAMPLES_PER_SEGMENT + 1),
+        };
+
+        let mut total = 0.0;
+        let mut prev = curve.points[0];
+        curve.lengths.push(0.0);
+        for segment in 0..segments {
+            let controls = curve.controls(segment);
+            for i in 1..=PATH_SAMPLES_PER_SEGMENT {
+                let u = i as f32 / PATH_SAMPLES_PER_SEGMENT as f32;
+                let point = bezier_point(&controls, u);
+                total += point.distance(prev);
+                curve.lengths.push(total);
+                prev = point;
+            }
+        }
+
+        Some(curve)
+    }
+
+    /// Total arc length in world units
+    pub fn length(&self) -> f32 {
+        self.lengths[self.lengths.len() - 1]
+    }
+
+    /// Whether the path loops back to its start
+    pub fn is_closed(&self) -> bool {
+        self.closed
+    }
+
+    /// Position and unit tangent at `t` (0.0-1.0 of the arc length)
+    ///
+    /// `t` is clamped on open paths and wraps on closed ones. The tangent
+    /// points in the direction of increasing `t`.
+    pub fn eval(&self, t: f32) -> (Vec3, Vec3) {
+        let t = if !t.is_finite() {
+            0.0
+        } else if self.closed {
+            t.rem_euclid(1.0)
+        } else {
+            t.clamp(0.0, 1.0)
+        };
+        let target = t * self.length();
+
+        // First sample at or past the target distance
+        let upper = self
+            .lengths
+            .partition_point(|&l| l < target)
+            .clamp(1, self.lengths.len() - 1);
+        let (l0, l1) = (self.lengths[upper - 1], self.lengths[upper]);
+        let frac = if l1 > l0 {
+            (target - l0) / (l1 - l0)
+        } else {
+            0.0
+        };
+
+        let sample = upper - 1;
+        let segment = sample / PATH_SAMPLES_PER_SEGMENT;
+        let u =
+            ((sample % PATH_SAMPLES_PER_SEGMENT) as f32 + frac) / PATH_SAMPLES_PER_SEGMENT as f32;
+
+        let controls = self.controls(segment);
+        let tangent = bezier_derivative(&controls, u)
+            .try_normalize()
+            .or_else(|| (controls[3] - controls[0]).try_normalize())
+            .unwrap_or(Vec3::ZERO);
+        (bezier_point(&controls, u), tangent)
+    }
+
+    /// Cubic Bézier control points of a segment
+    ///
+    /// Catmull-Rom segments are converted; open ends mirror their neighbor so
+    /// the curve starts and ends heading along the first and last legs.
+    fn controls(&self, segment: usize) -> [Vec3; 4] {
+        let n = self.points.len();
+        match self.kind {
+            PathKind::CatmullRom => {
+                let p1 = self.points[segment];
+                let p2 = self.points[(segment + 1) % n];
+                let p0 = match (segment, self.closed) {
+                    (0, false) => p1 * 2.0 - p2,
+                    _ => self.points[(segment + n - 1) % n],
+                };
+                let p3 = if !self.closed && segment + 2 >= n {
+                    p2 * 2.0 - p1
+                } else {
+                    self.points[(segment + 2) % n]
+                };
+                [p1, p1 + (p2 - p0) / 6.0, p2 - (p3 - p1) / 6.0, p2]
+            }
+            PathKind::Bezier => {
+                let base = segment * 3;
+                [
+                    self.points[base],
+                    self.points[base + 1],
+                    self.points[base + 2],
+                    self.points[(base + 3) % n],
+                ]
+            }
+        }
+    }
+}
+
+fn bezier_point(c: &[Vec3; 4], u: f32) -> Vec3 {
+    let mt = 1.0 - u;
+    c[0] * (mt * mt * mt)
+        + c[1] * (3.0 * mt * mt * u)
+        + c[2] * (3.0 * mt * u * u)
+        + c[3] * (u * u * u)
+}
+
+fn bezier_derivative(c: &[Vec3; 4], u: f32) -> Vec3 {
+    let mt = 1.0 - u;
+    (c[1] - c[0]) * (3.0 * mt * mt) + (c[2] - c[1]) * (6.0 * mt * u) + (c[3] - c[2]) * (3.0 * u * u)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_near(a: Vec3, b: Vec3) {
+        assert!(a.distance(b) < 1e-3, "{a} != {b}");
+    }
+
+    #[test]
+    fn test_catmull_rom_straight_line() {
+        let path = PackedPath::new(
+            "line",
+            PathKind::CatmullRom,
+            false,
+            vec![[0.0, 0.0, 0.0], [10.0, 0.0, 0.0], [20.0, 0.0, 0.0]],
+        );
+        let curve = PathCurve::new(&path).unwrap();
+
+        assert!((curve.length() - 20.0).abs() < 1e-3);
+        let (pos, tangent) = curve.eval(0.25);
+        assert_near(pos, Vec3::new(5.0, 0.0, 0.0));
+        assert_near(tangent, Vec3::X);
+
+        // Open paths clamp
+        assert_near(curve.eval(-1.0).0, Vec3::ZERO);
+        assert_near(curve.eval(2.0).0, Vec3::new(20.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn test_catmull_rom_closed_passes_through_points() {
+        let points = vec![
+            [0.0, 0.0, 0.0],
+            [10.0, 0.0, 0.0],
+            [10.0, 0.0, 10.0],
+            [0.0, 0.0, 10.0],
+        ];
+        let path = PackedPath::new("loop", PathKind::CatmullRom, true, points);
+        let curve = PathCurve::new(&path).unwrap();
+
+        // Symmetric loop: each point is a quarter of the way round
+        for (i, point) in path.points.iter().enumerate() {
+            assert_near(curve.eval(i as f32 * 0.25).0, Vec3::from(*point));
+        }
+        // Closed paths wrap
+        assert_near(curve.eval(1.25).0, curve.eval(0.25).0);
+        assert_near(curve.eval(-0.75).0, curve.eval(0.25).0);
+        // Tangents at points follow the neighbors (from the last point to the second)
+        assert_near(curve.eval(0.0).1, Vec3::new(1.0, 0.0, -1.0).normalize());
+    }
+
+    #[test]
+    fn test_bezier_segments() {
+        // Two straight segments with evenly spaced handles
+        let path = PackedPath::new(
+            "rail",
+            PathKind::Bezier,
+            false,
+            vec![
+                [0.0, 0.0, 0.0],
+                [0.0, 1.0, 0.0],
+                [0.0, 2.0, 0.0],
+                [0.0, 3.0, 0.0],
+                [0.0, 4.0, 0.0],
+                [0.0, 5.0, 0.0],
+                [0.0, 6.0, 0.0],
+            ],
+        );
+        let curve = PathCurve::new(&path).unwrap();
+
+        assert!((curve.length() - 6.0).abs() < 1e-3);
+        let (pos, tangent) = curve.eval(0.75);
+        assert_near(pos, Vec3::new(0.0, 4.5, 0.0));
+        assert_near(tangent, Vec3::Y);
+    }
+
+    #[test]
+    fn test_invalid_paths() {
+        let bezier =
+            |n: usize, closed| PackedPath::new("p", PathKind::Bezier, closed, vec![[0.0; 3]; n]);
+        assert_eq!(bezier(4, false).segment_count(), Some(1));
+        assert_eq!(bezier(5, false).segment_count(), None);
+        assert_eq!(bezier(6, true).segment_count(), Some(2));
+        assert_eq!(bezier(4, true).segment_count(), None);
+
+        let single = PackedPath::new("p", PathKind::CatmullRom, false, vec![[0.0; 3]]);
+        assert!(PathCurve::new(&single).is_none());
+
+        let nan = PackedPath::new(
+            "p",
+            PathKind::CatmullRom,
+            false,
+            vec![[0.0; 3], [f32::NAN, 0.0, 0.0]],
+        );
+        assert!(PathCurve::new(&nan).is_none());
+    }
+}
@@ -0,0 +1,122 @@
+//! Localized string lookup
+//!
+//! Builds a key index over a [`PackedStrings`] table and resolves locales to
+//! its language columns. A locale matches a language exactly (ignoring case)
+//! or, failing that, by its primary subtag, so `"pt-BR"` falls back to a
+//! `"pt"` column and `"fr"` picks `"fr-CA"` when that is the only French.
+
+use hashbrown::HashMap;
+
+use super::PackedStrings;
+
+/// A string table ready for lookups
+#[derive(Debug, Clone)]
+pub struct StringTable {
+    strings: PackedStrings,
+    /// Key → row
+    index: HashMap<String, usize>,
+}
+
+impl StringTable {
+    /// Build the key index for a packed table
+    ///
+    /// Returns None if the table has no languages or its value grid doesn't
+    /// match its keys and languages.
+    pub fn new(strings: PackedStrings) -> Option<Self> {
+        if strings.languages.is_empty()
+            || strings.values.len() != strings.keys.len() * strings.languages.len()
+        {
+            return None;
+        }
+        let index = strings
+            .keys
+            .iter()
+            .enumerate()
+            .map(|(i, key)| (key.clone(), i))
+            .collect();
+        Some(Self { strings, index })
+    }
+
+    /// Language codes, fallback first
+    pub fn languages(&self) -> &[String] {
+        &self.strings.languages
+    }
+
+    /// Column for a locale, if the table has that language
+    pub fn language_index(&self, locale: &str) -> Option<usize> {
+        let languages = &self.strings.languages;
+        if let Some(i) = languages
+            .iter()
+            .position(|l| l.eq_ignore_ascii_case(locale))
+        {
+            return Some(i);
+        }
+        let primary = primary_subtag(locale);
+        languages
+            .iter()
+            .position(|l| primary_subtag(l).eq_ignore_ascii_case(primary))
+    }
+
+    /// Text for `key` in `locale`, falling back to the first language
+    ///
+    /// Returns None if the table has no such key or neither cell has text.
+    pub fn get(&self, key: &str, locale: &str) -> Option<&str> {
+        let row = *self.index.get(key)?;
+        self.language_index(locale)
+            .and_then(|language| self.strings.get(row, language))
+            .or_else(|| self.strings.get(row, 0))
+    }
+}
+
+/// Language part of a locale code (`"pt"` for `"pt-BR"` or `"pt_BR"`)
+fn primary_subtag(locale: &str) -> &str {
+    locale.split(['-', '_']).next().unwrap_or(locale)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table() -> StringTable {
+        let strings = PackedStrings::new(
+            "menus",
+            vec!["en".into(), "fr".into(), "pt-BR".into()],
+            vec!["start".into(), "quit".into()],
+            ["Start", "Jouer", "Iniciar", "Quit", "", "Sair"]
+                .map(String::from)
+                .to_vec(),
+        );
+        StringTable::new(strings).unwrap()
+    }
+
+    #[test]
+    fn test_lookup_by_locale() {
+        let table = table();
+        assert_eq!(table.get("start", "en"), Some("Start"));
+        assert_eq!(table.get("start", "FR"), Some("Jouer"));
+        assert_eq!(table.get("start", "pt-BR"), Some("Iniciar"));
+        assert_eq!(table.get("missing", "en"), None);
+    }
+
+    #[test]
+    fn test_fallbacks() {
+        let table = table();
+        // Empty cell and unknown locale fall back to the first language
+        assert_eq!(table.get("quit", "fr"), Some("Quit"));
+        assert_eq!(table.get("quit", "de"), Some("Quit"));
+        // Primary subtag matches in both directions
+        assert_eq!(table.get("start", "fr-CA"), Some("Jouer"));
+        assert_eq!(table.get("start", "pt"), Some("Iniciar"));
+    }
+
+    #[test]
+    fn test_rejects_mismatched_grid() {
+        let strings = PackedStrings::new(
+            "bad",
+            vec!["en".into(), "fr".into()],
+            vec!["start".into()],
+            vec!["Start".into()],
+        );
+        assert!(StringTable::new(strings).is_none());
+    }
+}
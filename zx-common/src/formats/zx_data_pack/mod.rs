@@ -52,6 +52,26 @@ pub struct ZXDataPack {
     /// Tracker modules (XM pattern data + sample mappings)
     pub trackers: Vec<PackedTracker>,
 
+    /// Texture atlas layouts (pixels live in `textures` under the same ID)
+    #[serde(default)]
+    pub atlases: Vec<PackedAtlas>,
+
+    /// Streamed audio (QOA-compressed, decoded during playback)
+    #[serde(default)]
+    pub streams: Vec<PackedStream>,
+
+    /// Spline paths (track layouts, flight paths, camera rails)
+    #[serde(default)]
+    pub paths: Vec<PackedPath>,
+
+    /// Cubemap skies (six RGBA8 faces)
+    #[serde(default)]
+    pub cubemaps: Vec<PackedCubemap>,
+
+    /// Localized string tables (key → UTF-8 text per language)
+    #[serde(default)]
+    pub strings: Vec<PackedStrings>,
+
     // ========================================================================
     // Index caches for O(1) lookup (built lazily on first access)
     // ========================================================================
@@ -86,6 +106,26 @@ pub struct ZXDataPack {
     #[serde(skip)]
     #[bitcode(skip)]
     tracker_index: OnceLock<HashMap<String, usize>>,
+
+    #[serde(skip)]
+    #[bitcode(skip)]
+    atlas_index: OnceLock<HashMap<String, usize>>,
+
+    #[serde(skip)]
+    #[bitcode(skip)]
+    stream_index: OnceLock<HashMap<String, usize>>,
+
+    #[serde(skip)]
+    #[bitcode(skip)]
+    path_index: OnceLock<HashMap<String, usize>>,
+
+    #[serde(skip)]
+    #[bitcode(skip)]
+    cubemap_index: OnceLock<HashMap<String, usize>>,
+
+    #[serde(skip)]
+    #[bitcode(skip)]
+    strings_index: OnceLock<HashMap<String, usize>>,
 }
 
 impl ZXDataPack {
@@ -115,6 +155,11 @@ impl ZXDataPack {
             sounds,
             data,
             trackers,
+            atlases: Vec::new(),
+            streams: Vec::new(),
+            paths: Vec::new(),
+            cubemaps: Vec::new(),
+            strings: Vec::new(),
             // Index caches will be lazily initialized on first lookup
             texture_index: OnceLock::new(),
             mesh_index: OnceLock::new(),
@@ -124,6 +169,11 @@ impl ZXDataPack {
             sound_index: OnceLock::new(),
             data_index: OnceLock::new(),
             tracker_index: OnceLock::new(),
+            atlas_index: OnceLock::new(),
+            stream_index: OnceLock::new(),
+            path_index: OnceLock::new(),
+            cubemap_index: OnceLock::new(),
+            strings_index: OnceLock::new(),
         }
     }
 
@@ -137,6 +187,11 @@ impl ZXDataPack {
             && self.sounds.is_empty()
             && self.data.is_empty()
             && self.trackers.is_empty()
+            && self.atlases.is_empty()
+            && self.streams.is_empty()
+            && self.paths.is_empty()
+            && self.cubemaps.is_empty()
+            && self.strings.is_empty()
     }
 
     /// Get total asset count
@@ -149,6 +204,11 @@ impl ZXDataPack {
             + self.sounds.len()
             + self.data.len()
             + self.trackers.len()
+            + self.atlases.len()
+            + self.streams.len()
+            + self.paths.len()
+            + self.cubemaps.len()
+            + self.strings.len()
     }
 
     /// Find a texture by ID (O(1) lookup via lazy-initialized hash index)
@@ -214,6 +274,46 @@ impl ZXDataPack {
             .get_or_init(|| build_index(&self.trackers, |t| &t.id));
         index.get(id).map(|&i| &self.trackers[i])
     }
+
+    /// Find a texture atlas layout by ID (O(1) lookup via lazy-initialized hash index)
+    pub fn find_atlas(&self, id: &str) -> Option<&PackedAtlas> {
+        let index = self
+            .atlas_index
+            .get_or_init(|| build_index(&self.atlases, |a| &a.id));
+        index.get(id).map(|&i| &self.atlases[i])
+    }
+
+    /// Find a stream by ID (O(1) lookup via lazy-initialized hash index)
+    pub fn find_stream(&self, id: &str) -> Option<&PackedStream> {
+        let index = self
+            .stream_index
+            .get_or_init(|| build_index(&self.streams, |s| &s.id));
+        index.get(id).map(|&i| &self.streams[i])
+    }
+
+    /// Find a spline path by ID (O(1) lookup via lazy-initialized hash index)
+    pub fn find_path(&self, id: &str) -> Option<&PackedPath> {
+        let index = self
+            .path_index
+            .get_or_init(|| build_index(&self.paths, |p| &p.id));
+        index.get(id).map(|&i| &self.paths[i])
+    }
+
+    /// Find a cubemap by ID (O(1) lookup via lazy-initialized hash index)
+    pub fn find_cubemap(&self, id: &str) -> Option<&PackedCubemap> {
+        let index = self
+            .cubemap_index
+            .get_or_init(|| build_index(&self.cubemaps, |c| &c.id));
+        index.get(id).map(|&i| &self.cubemaps[i])
+    }
+
+    /// Find a string table by ID (O(1) lookup via lazy-initialized hash index)
+    pub fn find_strings(&self, id: &str) -> Option<&PackedStrings> {
+        let index = self
+            .strings_index
+            .get_or_init(|| build_index(&self.strings, |s| &s.id));
+        index.get(id).map(|&i| &self.strings[i])
+    }
 }
 
 /// Build a hash map index from a vector of items with string IDs
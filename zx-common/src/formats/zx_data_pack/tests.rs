@@ -22,6 +22,8 @@ fn test_data_pack_with_assets() {
         index_count: 3,
         vertex_data: vec![0; 36],
         index_data: vec![0, 1, 2],
+        sockets: vec![],
+        morph_targets: vec![],
     });
 
     assert!(!pack.is_empty());
@@ -59,6 +61,8 @@ fn test_mesh_format_flags() {
         index_count: 0,
         vertex_data: vec![],
         index_data: vec![],
+        sockets: vec![],
+        morph_targets: vec![],
     };
 
     assert!(mesh.has_uv());
@@ -77,6 +81,8 @@ fn test_mesh_stride() {
         index_count: 0,
         vertex_data: vec![],
         index_data: vec![],
+        sockets: vec![],
+        morph_targets: vec![],
     };
     assert_eq!(pos_only.stride(), 8);
 
@@ -88,6 +94,8 @@ fn test_mesh_stride() {
         index_count: 0,
         vertex_data: vec![],
         index_data: vec![],
+        sockets: vec![],
+        morph_targets: vec![],
     };
     assert_eq!(pos_uv_norm.stride(), 16);
 
@@ -99,6 +107,8 @@ fn test_mesh_stride() {
         index_count: 0,
         vertex_data: vec![],
         index_data: vec![],
+        sockets: vec![],
+        morph_targets: vec![],
     };
     assert_eq!(skinned.stride(), 28);
 }
@@ -129,6 +139,8 @@ fn test_find_mesh() {
         index_count: 36,
         vertex_data: vec![0; 24 * 20], // pos + uv
         index_data: vec![0; 36],
+        sockets: vec![],
+        morph_targets: vec![],
     });
     pack.meshes.push(PackedMesh {
         id: "sphere".to_string(),
@@ -137,6 +149,8 @@ fn test_find_mesh() {
         index_count: 200,
         vertex_data: vec![0; 100 * 12],
         index_data: vec![0; 200],
+        sockets: vec![],
+        morph_targets: vec![],
     });
 
     let cube = pack.find_mesh("cube");
@@ -233,6 +247,25 @@ fn test_find_sound() {
     assert!(pack.find_sound("missing").is_none());
 }
 
+#[test]
+fn test_find_stream() {
+    let mut pack = ZXDataPack::new();
+    pack.streams
+        .push(PackedStream::from_pcm("bgm", &vec![0i16; 22050 * 3])); // 3 sec
+    assert_eq!(pack.asset_count(), 1);
+
+    let bgm = pack.find_stream("bgm").unwrap();
+    assert_eq!(bgm.total_samples, 22050 * 3);
+    assert!((bgm.duration_seconds() - 3.0).abs() < 0.001);
+    // Stays compressed: ~3.2 bits per sample
+    assert!(bgm.data.len() < 22050 * 3 * 2 / 4);
+
+    assert!(pack.find_stream("missing").is_none());
+
+    let decoded: ZXDataPack = bitcode::decode(&bitcode::encode(&pack)).expect("decode failed");
+    assert_eq!(decoded.find_stream("bgm").unwrap().data, bgm.data);
+}
+
 #[test]
 fn test_find_data() {
     let mut pack = ZXDataPack::new();
@@ -318,6 +351,15 @@ fn test_serialization_roundtrip() {
         index_count: 3,
         vertex_data: vec![1, 2, 3],
         index_data: vec![0, 1, 2],
+        sockets: vec![MeshSocket {
+            name: "hand_r".to_string(),
+            bone: 7,
+            transform: BoneMatrix3x4::IDENTITY,
+        }],
+        morph_targets: vec![MorphTarget {
+            positions: vec![[0.0, 0.5, 0.0]; 3],
+            normals: vec![],
+        }],
     });
     pack.skeletons
         .push(PackedSkeleton::new("skel", vec![BoneMatrix3x4::IDENTITY]));
@@ -363,6 +405,11 @@ fn test_serialization_roundtrip() {
     // Verify content
     assert_eq!(decoded.find_texture("tex").unwrap().width, 4);
     assert_eq!(decoded.find_mesh("mesh").unwrap().format, 0b0101);
+    let mesh = decoded.find_mesh("mesh").unwrap();
+    assert_eq!(mesh.find_socket("hand_r"), Some(0));
+    assert_eq!(mesh.sockets[0].bone, 7);
+    assert_eq!(mesh.find_socket("hand_l"), None);
+    assert_eq!(mesh.morph_targets[0].positions[2], [0.0, 0.5, 0.0]);
     assert_eq!(decoded.find_skeleton("skel").unwrap().bone_count, 1);
     assert_eq!(decoded.find_keyframes("anim").unwrap().bone_count, 2);
     assert_eq!(decoded.find_keyframes("anim").unwrap().frame_count, 5);
@@ -402,6 +449,15 @@ fn test_texture_format_data_size_rgba8() {
     assert_eq!(TextureFormat::Rgba8.data_size(128, 64), 128 * 64 * 4);
 }
 
+#[test]
+fn test_texture_format_data_size_indexed8() {
+    // One palette index per pixel, no block rounding
+    assert_eq!(TextureFormat::Indexed8.data_size(30, 30), 30 * 30);
+    assert!(TextureFormat::Indexed8.is_indexed());
+    assert!(!TextureFormat::Indexed8.is_compressed());
+    assert_eq!(TextureFormat::Indexed8.etc2_counterpart(), None);
+}
+
 #[test]
 fn test_texture_format_data_size_bc7() {
     // 64×64 = 16×16 blocks × 16 bytes = 4096 bytes
@@ -452,6 +508,27 @@ fn test_packed_texture_with_format() {
     assert!(tex.validate());
 }
 
+#[test]
+fn test_packed_texture_etc2_copy() {
+    let tex = PackedTexture::with_format("material", 64, 64, TextureFormat::Bc7, vec![0; 4096]);
+    assert!(tex.etc2().is_none());
+
+    let tex = tex.with_etc2_data(vec![1; 4096]);
+    let (format, data) = tex.etc2().unwrap();
+    assert_eq!(format, TextureFormat::Etc2);
+    assert_eq!(data.len(), 4096);
+    assert!(tex.validate());
+
+    // A copy must match the BC data's size, and only BC formats have one
+    assert!(!tex.clone().with_etc2_data(vec![1; 16]).validate());
+    let rgba = PackedTexture::new("ui", 4, 4, vec![0; 64]).with_etc2_data(vec![0; 64]);
+    assert!(!rgba.validate());
+    assert_eq!(
+        TextureFormat::Bc5.etc2_counterpart(),
+        Some(TextureFormat::EacRg11)
+    );
+}
+
 #[test]
 fn test_packed_texture_dimensions_u32() {
     let tex = PackedTexture::new("test", 256, 128, vec![0; 256 * 128 * 4]);
@@ -526,3 +603,92 @@ fn test_packed_tracker() {
     assert_eq!(tracker.instrument_count(), 3);
     assert_eq!(tracker.pattern_data_size(), 1024);
 }
+
+#[test]
+fn test_find_atlas() {
+    let mut pack = ZXDataPack::new();
+    pack.atlases.push(PackedAtlas::new(
+        "enemies",
+        64,
+        32,
+        vec![
+            AtlasSprite {
+                x: 0,
+                y: 0,
+                width: 32,
+                height: 32,
+            },
+            AtlasSprite {
+                x: 32,
+                y: 0,
+                width: 16,
+                height: 16,
+            },
+        ],
+    ));
+
+    let atlas = pack.find_atlas("enemies").unwrap();
+    assert_eq!(atlas.sprite_count(), 2);
+    assert_eq!(atlas.uv_rect(0), Some([0.0, 0.0, 0.5, 1.0]));
+    assert_eq!(atlas.uv_rect(1), Some([0.5, 0.0, 0.75, 0.5]));
+    assert_eq!(atlas.uv_rect(2), None);
+
+    assert!(pack.find_atlas("missing").is_none());
+    assert_eq!(pack.asset_count(), 1);
+}
+
+#[test]
+fn test_find_path() {
+    let mut pack = ZXDataPack::new();
+    pack.paths.push(PackedPath::new(
+        "track1",
+        PathKind::CatmullRom,
+        true,
+        vec![[0.0, 0.0, 0.0], [10.0, 0.0, 0.0], [10.0, 0.0, 10.0]],
+    ));
+
+    let decoded: ZXDataPack = bitcode::decode(&bitcode::encode(&pack)).expect("decode failed");
+    let path = decoded.find_path("track1").unwrap();
+    assert_eq!(path.kind, PathKind::CatmullRom);
+    assert!(path.closed);
+    assert_eq!(path.segment_count(), Some(3));
+
+    assert!(decoded.find_path("missing").is_none());
+    assert_eq!(decoded.asset_count(), 1);
+}
+
+#[test]
+fn test_find_cubemap() {
+    let mut pack = ZXDataPack::new();
+    let data: Vec<u8> = (0..6u8).flat_map(|face| [face; 2 * 2 * 4]).collect();
+    pack.cubemaps.push(PackedCubemap::new("sky", 2, data));
+
+    let decoded: ZXDataPack = bitcode::decode(&bitcode::encode(&pack)).expect("decode failed");
+    let cubemap = decoded.find_cubemap("sky").unwrap();
+    assert_eq!(cubemap.size, 2);
+    assert_eq!(cubemap.face(3), Some(&[3u8; 16][..]));
+    assert_eq!(cubemap.face(6), None);
+
+    assert!(decoded.find_cubemap("missing").is_none());
+    assert_eq!(decoded.asset_count(), 1);
+}
+
+#[test]
+fn test_find_strings() {
+    let mut pack = ZXDataPack::new();
+    pack.strings.push(PackedStrings::new(
+        "menus",
+        vec!["en".into(), "de".into()],
+        vec!["start".into()],
+        vec!["Start".into(), "".into()],
+    ));
+
+    let decoded: ZXDataPack = bitcode::decode(&bitcode::encode(&pack)).expect("decode failed");
+    let strings = decoded.find_strings("menus").unwrap();
+    assert_eq!(strings.get(0, 0), Some("Start"));
+    assert_eq!(strings.get(0, 1), None);
+    assert_eq!(strings.get(0, 2), None);
+
+    assert!(decoded.find_strings("missing").is_none());
+    assert_eq!(decoded.asset_count(), 1);
+}
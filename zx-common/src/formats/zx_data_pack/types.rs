@@ -13,6 +13,10 @@ use serde::{Deserialize, Serialize};
 /// - compress_textures = false: RGBA8 — pixel-perfect, full alpha
 /// - compress_textures = true: BC7 — 4× compression, stipple transparency
 /// - Normal maps: BC5 — 2-channel RG, optimal for tangent-space normals
+/// - quality = "indexed": Indexed8 — one palette index per pixel, for palette swaps
+///
+/// ETC2 and EAC RG11 are the same size as BC7 and BC5 and stand in for them
+/// on GPUs without BC support (`texture_target` in nether.toml).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, Encode, Decode)]
 pub enum TextureFormat {
     /// Uncompressed RGBA8 (4 bytes per pixel)
@@ -27,6 +31,18 @@ pub enum TextureFormat {
     /// BC5 compressed (8 bits per pixel, 2-channel RG)
     /// Used for normal maps — Z reconstructed in shader: z = sqrt(1 - x² - y²)
     Bc5,
+
+    /// ETC2 RGBA8 compressed (8 bits per pixel, EAC alpha + ETC2 color)
+    /// Stands in for BC7 on GPUs without BC support
+    Etc2,
+
+    /// EAC RG11 compressed (8 bits per pixel, 2-channel RG)
+    /// Stands in for BC5 normal maps on GPUs without BC support
+    EacRg11,
+
+    /// Palette indices (1 byte per pixel)
+    /// Drawn through the palette bound with `palette_bind()`
+    Indexed8,
 }
 
 impl TextureFormat {
@@ -40,9 +56,23 @@ impl TextureFormat {
         matches!(self, TextureFormat::Bc5)
     }
 
-    /// Check if this format is compressed (BC5 or BC7)
+    /// Check if this format is compressed (BC5, BC7, ETC2 or EAC RG11)
     pub fn is_compressed(&self) -> bool {
-        matches!(self, TextureFormat::Bc7 | TextureFormat::Bc5)
+        !matches!(self, TextureFormat::Rgba8 | TextureFormat::Indexed8)
+    }
+
+    /// Check if this format holds palette indices
+    pub fn is_indexed(&self) -> bool {
+        matches!(self, TextureFormat::Indexed8)
+    }
+
+    /// ETC2-family format that can replace this one, if it is a BC format
+    pub fn etc2_counterpart(&self) -> Option<TextureFormat> {
+        match self {
+            TextureFormat::Bc7 => Some(TextureFormat::Etc2),
+            TextureFormat::Bc5 => Some(TextureFormat::EacRg11),
+            _ => None,
+        }
     }
 
     /// Calculate data size for given dimensions
@@ -52,8 +82,12 @@ impl TextureFormat {
 
         match self {
             TextureFormat::Rgba8 => w * h * 4,
-            TextureFormat::Bc7 | TextureFormat::Bc5 => {
-                // Both BC7 and BC5 use 16 bytes per 4x4 block
+            TextureFormat::Indexed8 => w * h,
+            TextureFormat::Bc7
+            | TextureFormat::Bc5
+            | TextureFormat::Etc2
+            | TextureFormat::EacRg11 => {
+                // Every compressed format uses 16 bytes per 4x4 block
                 let blocks_x = w.div_ceil(4);
                 let blocks_y = h.div_ceil(4);
                 blocks_x * blocks_y * 16
@@ -67,6 +101,21 @@ impl TextureFormat {
             TextureFormat::Rgba8 => "Rgba8Unorm",
             TextureFormat::Bc7 => "Bc7RgbaUnorm",
             TextureFormat::Bc5 => "Bc5RgUnorm",
+            TextureFormat::Etc2 => "Etc2Rgba8Unorm",
+            TextureFormat::EacRg11 => "EacRg11Unorm",
+            TextureFormat::Indexed8 => "R8Unorm",
+        }
+    }
+
+    /// Short display name (for build output)
+    pub fn short_name(&self) -> &'static str {
+        match self {
+            TextureFormat::Rgba8 => "RGBA8",
+            TextureFormat::Bc7 => "BC7",
+            TextureFormat::Bc5 => "BC5",
+            TextureFormat::Etc2 => "ETC2",
+            TextureFormat::EacRg11 => "EAC RG11",
+            TextureFormat::Indexed8 => "INDEXED8",
         }
     }
 }
@@ -92,6 +141,11 @@ pub struct PackedTexture {
 
     /// Pixel data (RGBA8) or compressed blocks (BC7)
     pub data: Vec<u8>,
+
+    /// ETC2/EAC copy of BC blocks, used on GPUs without BC support
+    /// (empty unless packed with `texture_target = "both"`)
+    #[serde(default)]
+    pub etc2_data: Vec<u8>,
 }
 
 impl PackedTexture {
@@ -103,6 +157,7 @@ impl PackedTexture {
             height: height as u16,
             format: TextureFormat::Rgba8,
             data,
+            etc2_data: Vec::new(),
         }
     }
 
@@ -120,9 +175,22 @@ impl PackedTexture {
             height,
             format,
             data,
+            etc2_data: Vec::new(),
         }
     }
 
+    /// Attach an ETC2/EAC copy of BC-compressed data
+    pub fn with_etc2_data(mut self, etc2_data: Vec<u8>) -> Self {
+        self.etc2_data = etc2_data;
+        self
+    }
+
+    /// The ETC2/EAC copy and its format, if this texture has one
+    pub fn etc2(&self) -> Option<(TextureFormat, &[u8])> {
+        let format = self.format.etc2_counterpart()?;
+        (!self.etc2_data.is_empty()).then_some((format, self.etc2_data.as_slice()))
+    }
+
     /// Get expected data size based on format
     pub fn expected_size(&self) -> usize {
         self.format.data_size(self.width, self.height)
@@ -130,7 +198,10 @@ impl PackedTexture {
 
     /// Validate that data size matches dimensions and format
     pub fn validate(&self) -> bool {
-        self.data.len() == self.expected_size()
+        let etc2_valid = self.etc2_data.is_empty()
+            || (self.format.etc2_counterpart().is_some()
+                && self.etc2_data.len() == self.expected_size());
+        self.data.len() == self.expected_size() && etc2_valid
     }
 
     /// Check if texture is BC7 compressed
@@ -144,6 +215,65 @@ impl PackedTexture {
     }
 }
 
+/// Sprite rectangle inside a texture atlas (pixels)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Encode, Decode)]
+pub struct AtlasSprite {
+    pub x: u16,
+    pub y: u16,
+    pub width: u16,
+    pub height: u16,
+}
+
+/// Texture atlas layout
+///
+/// The packed atlas pixels are stored as a regular [`PackedTexture`] with the
+/// same ID. Sprites keep the order they were listed in nether.toml, so games
+/// refer to them by index.
+#[derive(Debug, Clone, Serialize, Deserialize, Encode, Decode)]
+pub struct PackedAtlas {
+    /// Asset ID (shared with the atlas texture)
+    pub id: String,
+
+    /// Atlas texture width in pixels
+    pub width: u16,
+
+    /// Atlas texture height in pixels
+    pub height: u16,
+
+    /// Sprite rectangles, in manifest order
+    pub sprites: Vec<AtlasSprite>,
+}
+
+impl PackedAtlas {
+    /// Create a new atlas layout
+    pub fn new(id: impl Into<String>, width: u16, height: u16, sprites: Vec<AtlasSprite>) -> Self {
+        Self {
+            id: id.into(),
+            width,
+            height,
+            sprites,
+        }
+    }
+
+    /// Get sprite count
+    pub fn sprite_count(&self) -> usize {
+        self.sprites.len()
+    }
+
+    /// Normalized UV rectangle `[u0, v0, u1, v1]` of a sprite
+    pub fn uv_rect(&self, index: usize) -> Option<[f32; 4]> {
+        let sprite = self.sprites.get(index)?;
+        let w = self.width.max(1) as f32;
+        let h = self.height.max(1) as f32;
+        Some([
+            sprite.x as f32 / w,
+            sprite.y as f32 / h,
+            (sprite.x + sprite.width) as f32 / w,
+            (sprite.y + sprite.height) as f32 / h,
+        ])
+    }
+}
+
 /// Packed mesh (GPU-ready vertices + indices)
 ///
 /// Vertices are packed according to the vertex format flags (see asset-pipeline.md).
@@ -171,6 +301,12 @@ pub struct PackedMesh {
 
     /// Index buffer (u16 indices)
     pub index_data: Vec<u16>,
+
+    /// Named attachment points (weapons, props), in source order
+    pub sockets: Vec<MeshSocket>,
+
+    /// Blend shapes, in source order (at most [`MAX_MORPH_TARGETS`])
+    pub morph_targets: Vec<MorphTarget>,
 }
 
 impl PackedMesh {
@@ -205,6 +341,48 @@ impl PackedMesh {
     pub fn stride(&self) -> usize {
         crate::vertex_stride_packed(self.format) as usize
     }
+
+    /// Find a socket by name, returning its index
+    pub fn find_socket(&self, name: &str) -> Option<usize> {
+        self.sockets.iter().position(|socket| socket.name == name)
+    }
+}
+
+/// Named attachment point on a mesh
+///
+/// The transform is the socket's bind-pose placement in mesh space. A socket
+/// on a bone follows that bone exactly like a vertex fully weighted to it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Encode, Decode)]
+pub struct MeshSocket {
+    /// Socket name (e.g., "hand_r", "muzzle")
+    pub name: String,
+
+    /// Bone the socket follows, or [`MeshSocket::NO_BONE`]
+    pub bone: u32,
+
+    /// Mesh-space transform in the bind pose
+    pub transform: BoneMatrix3x4,
+}
+
+impl MeshSocket {
+    /// `bone` value of a socket that doesn't follow a bone
+    pub const NO_BONE: u32 = u32::MAX;
+}
+
+/// Maximum number of morph targets per mesh
+pub const MAX_MORPH_TARGETS: usize = 8;
+
+/// Morph target (blend shape) of a mesh
+///
+/// Displacements are scaled by the target's weight and added to the bind
+/// pose before skinning. Both lists have one entry per vertex.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Encode, Decode)]
+pub struct MorphTarget {
+    /// Position displacement per vertex
+    pub positions: Vec<[f32; 3]>,
+
+    /// Normal displacement per vertex (empty if the target keeps normals)
+    pub normals: Vec<[f32; 3]>,
 }
 
 /// Packed skeleton (inverse bind matrices only)
@@ -389,6 +567,144 @@ impl PackedSound {
     }
 }
 
+/// Packed stream (QOA-compressed audio for long music tracks)
+///
+/// Unlike `PackedSound`, streams stay compressed in memory and are decoded a
+/// frame (5120 samples) at a time during playback. Audio is 22050Hz mono.
+#[derive(Debug, Clone, Serialize, Deserialize, Encode, Decode)]
+pub struct PackedStream {
+    /// Asset ID (e.g., "bgm_level1", "title_theme")
+    pub id: String,
+
+    /// Total decoded sample count
+    pub total_samples: u32,
+
+    /// QOA frame data (see `nether_qoa`)
+    pub data: Vec<u8>,
+}
+
+impl PackedStream {
+    /// Create a new packed stream from QOA frame data
+    pub fn new(id: impl Into<String>, total_samples: u32, data: Vec<u8>) -> Self {
+        Self {
+            id: id.into(),
+            total_samples,
+            data,
+        }
+    }
+
+    /// Compress PCM samples (22050Hz mono i16) into a packed stream
+    pub fn from_pcm(id: impl Into<String>, samples: &[i16]) -> Self {
+        Self::new(id, samples.len() as u32, nether_qoa::encode_qoa(samples))
+    }
+
+    /// Get duration in seconds (at 22050Hz)
+    pub fn duration_seconds(&self) -> f32 {
+        self.total_samples as f32 / 22050.0
+    }
+}
+
+/// Curve type of a packed path
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, Encode, Decode)]
+#[serde(rename_all = "kebab-case")]
+pub enum PathKind {
+    /// Uniform Catmull-Rom spline through every control point
+    #[default]
+    CatmullRom,
+
+    /// Cubic Bézier segments: anchor, handle, handle, anchor, handle, …
+    /// Consecutive segments share their end anchors.
+    Bezier,
+}
+
+/// Packed spline path (track layouts, flight paths, camera rails)
+///
+/// Control points are in world units. The runtime builds an arc-length table
+/// when the path is loaded, so `path_eval()` moves at constant speed.
+#[derive(Debug, Clone, Serialize, Deserialize, Encode, Decode)]
+pub struct PackedPath {
+    /// Asset ID (e.g., "track1", "boss_flyby")
+    pub id: String,
+
+    /// Curve type
+    pub kind: PathKind,
+
+    /// Whether the last point connects back to the first
+    pub closed: bool,
+
+    /// Control points (x, y, z)
+    pub points: Vec<[f32; 3]>,
+}
+
+impl PackedPath {
+    /// Create a new packed path
+    pub fn new(id: impl Into<String>, kind: PathKind, closed: bool, points: Vec<[f32; 3]>) -> Self {
+        Self {
+            id: id.into(),
+            kind,
+            closed,
+            points,
+        }
+    }
+
+    /// Number of curve segments, or None if the point count doesn't fit the
+    /// curve type
+    ///
+    /// - Catmull-Rom: at least 2 points (3 if closed)
+    /// - Bézier: 3n+1 points, or 3n if closed (the last segment ends on the
+    ///   first anchor)
+    pub fn segment_count(&self) -> Option<usize> {
+        let n = self.points.len();
+        let segments = match (self.kind, self.closed) {
+            (PathKind::CatmullRom, false) if n >= 2 => n - 1,
+            (PathKind::CatmullRom, true) if n >= 3 => n,
+            (PathKind::Bezier, false) if n >= 4 && (n - 1).is_multiple_of(3) => (n - 1) / 3,
+            (PathKind::Bezier, true) if n >= 3 && n.is_multiple_of(3) => n / 3,
+            _ => return None,
+        };
+        Some(segments)
+    }
+}
+
+/// Packed cubemap (skies for `sky_draw()`)
+///
+/// Six square RGBA8 faces back to back, in the order +X, -X, +Y, -Y, +Z, -Z
+/// (see [`cube_face_direction`](crate::formats::cube_face_direction)).
+/// Equirectangular sources are converted to faces at pack time.
+#[derive(Debug, Clone, Serialize, Deserialize, Encode, Decode)]
+pub struct PackedCubemap {
+    /// Asset ID (e.g., "sky_day", "nebula")
+    pub id: String,
+
+    /// Face width and height in pixels
+    pub size: u16,
+
+    /// Pixel data of all six faces (RGBA8)
+    pub data: Vec<u8>,
+}
+
+impl PackedCubemap {
+    /// Create a new packed cubemap
+    pub fn new(id: impl Into<String>, size: u32, data: Vec<u8>) -> Self {
+        Self {
+            id: id.into(),
+            size: size as u16,
+            data,
+        }
+    }
+
+    /// Byte size of one face
+    pub fn face_len(&self) -> usize {
+        self.size as usize * self.size as usize * 4
+    }
+
+    /// Pixel data of one face (0-5), or None if the data is too short
+    pub fn face(&self, face: usize) -> Option<&[u8]> {
+        let len = self.face_len();
+        self.data.get(face * len..(face + 1) * len)
+    }
+}
+
 /// Packed raw data (levels, dialogue, custom formats)
 ///
 /// Opaque byte data that the game interprets. Use for levels, dialogue,
@@ -480,3 +796,50 @@ impl PackedTracker {
         self.pattern_data.len()
     }
 }
+
+/// Packed string table (localized text for `rom_strings()`)
+///
+/// A grid of UTF-8 strings with one row per key and one column per language.
+/// The first language is the fallback for cells left empty.
+#[derive(Debug, Clone, Serialize, Deserialize, Encode, Decode)]
+pub struct PackedStrings {
+    /// Asset ID (e.g., "menus", "dialogue")
+    pub id: String,
+
+    /// Language codes (e.g., "en", "fr", "pt-BR"), fallback first
+    pub languages: Vec<String>,
+
+    /// Message keys (e.g., "menu.start")
+    pub keys: Vec<String>,
+
+    /// Strings row by row: `values[key * languages.len() + language]`
+    pub values: Vec<String>,
+}
+
+impl PackedStrings {
+    /// Create a new packed string table
+    pub fn new(
+        id: impl Into<String>,
+        languages: Vec<String>,
+        keys: Vec<String>,
+        values: Vec<String>,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            languages,
+            keys,
+            values,
+        }
+    }
+
+    /// String for a key and language index, or None if the cell is empty
+    pub fn get(&self, key: usize, language: usize) -> Option<&str> {
+        if language >= self.languages.len() {
+            return None;
+        }
+        self.values
+            .get(key * self.languages.len() + language)
+            .map(String::as_str)
+            .filter(|s| !s.is_empty())
+    }
+}
@@ -0,0 +1,179 @@
+//! Nethercore ZX override pack format (`.nczxmod`)
+//!
+//! An override pack is a sideloaded data pack that replaces textures, sounds
+//! and meshes of a ROM by asset ID at load time, so games can be reskinned
+//! without rebuilding the ROM. Only ROMs packed with
+//! `allow_override_packs = true` accept them.
+//!
+//! Overrides only replace assets the ROM already contains; they cannot add
+//! new IDs or touch code, fonts, skeletons, animations, trackers or raw data.
+
+use bitcode::{Decode, Encode};
+
+use super::zx_data_pack::ZXDataPack;
+
+/// Override pack file extension (without the dot)
+pub const OVERRIDE_PACK_EXTENSION: &str = "nczxmod";
+
+/// Magic bytes at the start of an override pack file
+pub const OVERRIDE_PACK_MAGIC: &[u8; 4] = b"NCZM";
+
+/// Current override pack format version
+pub const OVERRIDE_PACK_VERSION: u32 = 1;
+
+/// Sideloaded asset overrides for one game
+#[derive(Debug, Clone, Encode, Decode)]
+pub struct ZXOverridePack {
+    /// Override pack format version
+    pub version: u32,
+
+    /// Game ID the pack targets (must match the ROM's metadata ID)
+    pub game_id: String,
+
+    /// Display name of the pack
+    pub title: String,
+
+    /// Pack author
+    pub author: String,
+
+    /// Replacement assets (only textures, sounds and meshes are applied)
+    pub data_pack: ZXDataPack,
+}
+
+impl ZXOverridePack {
+    /// Serialize to bytes with magic header
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = OVERRIDE_PACK_MAGIC.to_vec();
+        bytes.extend(bitcode::encode(self));
+        bytes
+    }
+
+    /// Deserialize from bytes and validate
+    pub fn from_bytes(bytes: &[u8]) -> anyhow::Result<Self> {
+        if bytes.len() < 4 || &bytes[0..4] != OVERRIDE_PACK_MAGIC {
+            anyhow::bail!("Invalid override pack magic bytes (expected: \"NCZM\")");
+        }
+
+        let pack: ZXOverridePack = bitcode::decode(&bytes[4..])
+            .map_err(|e| anyhow::anyhow!("Failed to decode override pack: {}", e))?;
+
+        if pack.version > OVERRIDE_PACK_VERSION {
+            anyhow::bail!(
+                "Unsupported override pack version: {} (max supported: {})",
+                pack.version,
+                OVERRIDE_PACK_VERSION
+            );
+        }
+        if pack.game_id.is_empty() {
+            anyhow::bail!("Override pack game ID cannot be empty");
+        }
+
+        Ok(pack)
+    }
+}
+
+/// Result of applying an override pack to a ROM's data pack
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OverrideReport {
+    /// Number of assets replaced
+    pub replaced: usize,
+    /// Override IDs with no matching asset in the ROM (ignored)
+    pub unmatched: Vec<String>,
+}
+
+impl ZXDataPack {
+    /// Replace textures, sounds and meshes by ID from `overrides`
+    ///
+    /// Overrides for IDs the ROM doesn't contain are ignored and reported.
+    pub fn apply_overrides(&mut self, overrides: &ZXDataPack) -> OverrideReport {
+        let mut report = OverrideReport::default();
+
+        for texture in &overrides.textures {
+            match self.textures.iter_mut().find(|t| t.id == texture.id) {
+                Some(slot) => {
+                    *slot = texture.clone();
+                    report.replaced += 1;
+                }
+                None => report.unmatched.push(texture.id.clone()),
+            }
+        }
+        for sound in &overrides.sounds {
+            match self.sounds.iter_mut().find(|s| s.id == sound.id) {
+                Some(slot) => {
+                    *slot = sound.clone();
+                    report.replaced += 1;
+                }
+                None => report.unmatched.push(sound.id.clone()),
+            }
+        }
+        for mesh in &overrides.meshes {
+            match self.meshes.iter_mut().find(|m| m.id == mesh.id) {
+                Some(slot) => {
+                    *slot = mesh.clone();
+                    report.replaced += 1;
+                }
+                None => report.unmatched.push(mesh.id.clone()),
+            }
+        }
+
+        report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::formats::{PackedSound, PackedTexture};
+
+    fn texture(id: &str, pixel: u8) -> PackedTexture {
+        PackedTexture::new(id, 1, 1, vec![pixel; 4])
+    }
+
+    fn sound(id: &str, sample: i16) -> PackedSound {
+        PackedSound::new(id, vec![sample; 4])
+    }
+
+    #[test]
+    fn test_override_pack_roundtrip() {
+        let mut data_pack = ZXDataPack::new();
+        data_pack.textures.push(texture("hero", 7));
+        let pack = ZXOverridePack {
+            version: OVERRIDE_PACK_VERSION,
+            game_id: "neon-drift".to_string(),
+            title: "Retro Cars".to_string(),
+            author: "Modder".to_string(),
+            data_pack,
+        };
+
+        let bytes = pack.to_bytes();
+        assert_eq!(&bytes[0..4], OVERRIDE_PACK_MAGIC);
+        let decoded = ZXOverridePack::from_bytes(&bytes).unwrap();
+        assert_eq!(decoded.game_id, "neon-drift");
+        assert_eq!(decoded.data_pack.textures[0].data, vec![7; 4]);
+
+        assert!(ZXOverridePack::from_bytes(b"NCZX").is_err());
+    }
+
+    #[test]
+    fn test_apply_overrides_replaces_by_id() {
+        let mut rom_pack = ZXDataPack::new();
+        rom_pack.textures.push(texture("hero", 1));
+        rom_pack.textures.push(texture("enemy", 2));
+        rom_pack.sounds.push(sound("jump", 3));
+
+        let mut overrides = ZXDataPack::new();
+        overrides.textures.push(texture("enemy", 9));
+        overrides.textures.push(texture("new", 9));
+        overrides.sounds.push(sound("jump", 10));
+
+        let report = rom_pack.apply_overrides(&overrides);
+        assert_eq!(report.replaced, 2);
+        assert_eq!(report.unmatched, vec!["new".to_string()]);
+
+        assert_eq!(rom_pack.textures.len(), 2);
+        assert_eq!(rom_pack.find_texture("hero").unwrap().data, vec![1; 4]);
+        assert_eq!(rom_pack.find_texture("enemy").unwrap().data, vec![9; 4]);
+        assert_eq!(rom_pack.find_sound("jump").unwrap().data, vec![10; 4]);
+        assert!(rom_pack.find_texture("new").is_none());
+    }
+}
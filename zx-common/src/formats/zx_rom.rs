@@ -18,11 +18,16 @@
 //! All ROM format constants are defined in [`nethercore_shared::ZX_ROM_FORMAT`]:
 //! - Extension: `"nczx"`
 //! - Magic bytes: `b"NCZX"`
-//! - Version: `1`
+//! - Version: `2`
+//!
+//! bitcode can't decode a struct whose fields have changed, so the format
+//! version is also written right after the magic bytes. ROMs from another
+//! format version are rejected up front with a hint to rebuild them.
 
 use bitcode::{Decode, Encode};
 
 use nethercore_shared::ZX_ROM_FORMAT;
+use nethercore_shared::capabilities::Capabilities;
 use nethercore_shared::local::LocalGameManifest;
 use nethercore_shared::netplay::NetplayMetadata;
 
@@ -39,8 +44,9 @@ use super::zx_data_pack::ZXDataPack;
 /// ┌─────────────────────────────────────────────────────────────━E
 /// ━E                  .nczx ROM File (≤16MB)                    ━E
 /// ├─────────────────────────────────────────────────────────────┤
-/// ━E NCZX Header (4 bytes)                                      ━E
+/// ━E NCZX Header (8 bytes)                                      ━E
 /// ━E ├── Magic: "NCZX"                                          ━E
+/// ━E ├── Version: u32                                           ━E
 /// ├─────────────────────────────────────────────────────────────┤
 /// ━E ZXRom (bitcode serialized)                                  ━E
 /// ━E ├── version: u32                                           ━E
@@ -53,7 +59,7 @@ use super::zx_data_pack::ZXDataPack;
 /// ```
 #[derive(Debug, Clone, Encode, Decode)]
 pub struct ZXRom {
-    /// ROM format version (currently 1)
+    /// ROM format version (currently 2)
     pub version: u32,
 
     /// Game metadata
@@ -126,6 +132,12 @@ pub struct ZXMetadata {
     /// Target FPS
     pub target_fps: Option<u32>,
 
+    /// Whether players may sideload override packs (`.nczxmod`) for this ROM
+    pub allow_override_packs: bool,
+
+    /// Host capabilities the ROM declared (saves, network, capture)
+    pub capabilities: Capabilities,
+
     /// Netplay metadata for NCHS (Nethercore Handshake) protocol.
     ///
     /// Contains console type, tick rate, max players, netplay enabled flag,
@@ -138,9 +150,11 @@ impl ZXRom {
     ///
     /// The output format is:
     /// - 4 bytes: Magic bytes "NCZX"
+    /// - 4 bytes: ROM format version (little-endian u32)
     /// - Remaining bytes: Bitcode-encoded ZXRom struct
     pub fn to_bytes(&self) -> anyhow::Result<Vec<u8>> {
         let mut bytes = ZX_ROM_FORMAT.magic.to_vec();
+        bytes.extend(self.version.to_le_bytes());
         let encoded = bitcode::encode(self);
         bytes.extend(encoded);
         Ok(bytes)
@@ -148,7 +162,8 @@ impl ZXRom {
 
     /// Deserialize ROM from bytes and validate
     ///
-    /// This checks magic bytes, deserializes the ROM, and runs validation.
+    /// This checks magic bytes and the format version, deserializes the ROM,
+    /// and runs validation.
     pub fn from_bytes(bytes: &[u8]) -> anyhow::Result<Self> {
         // Check magic bytes
         if bytes.len() < 4 || &bytes[0..4] != ZX_ROM_FORMAT.magic {
@@ -158,8 +173,22 @@ impl ZXRom {
             );
         }
 
+        // Check the format version before decoding: bitcode can't read a ROM
+        // whose structs have changed since it was packed. Version 1 ROMs have
+        // no version header, so anything but the current version is rejected.
+        let version = bytes
+            .get(4..8)
+            .map(|v| u32::from_le_bytes([v[0], v[1], v[2], v[3]]));
+        if version != Some(ZX_ROM_FORMAT.version) {
+            anyhow::bail!(
+                "Unsupported NCZX ROM format (this build reads version {}); \
+                 rebuild the ROM with `nether pack`",
+                ZX_ROM_FORMAT.version
+            );
+        }
+
         // Decode remaining bytes
-        let rom: ZXRom = bitcode::decode(&bytes[4..])
+        let rom: ZXRom = bitcode::decode(&bytes[8..])
             .map_err(|e| anyhow::anyhow!("Failed to decode NCZX ROM: {}", e))?;
 
         // Validate
@@ -221,6 +250,8 @@ impl ZXRom {
             version: self.metadata.version.clone(),
             downloaded_at: chrono::Utc::now().to_rfc3339(),
             console_type: ZX_ROM_FORMAT.console_type.to_string(),
+            registry_author_id: None,
+            registry_rom_sha256: None,
         }
     }
 }
@@ -246,6 +277,8 @@ mod tests {
                 render_mode: Some(2),
                 default_resolution: Some("640x480".to_string()),
                 target_fps: Some(60),
+                allow_override_packs: false,
+                capabilities: Capabilities::default(),
                 netplay: NetplayMetadata::new(
                     nethercore_shared::ConsoleType::ZX,
                     nethercore_shared::TickRate::Fixed60,
@@ -295,6 +328,23 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_other_format_versions_ask_for_rebuild() {
+        let rom = create_test_rom();
+
+        // Version 1 ROMs were the bitcode payload straight after the magic
+        let mut v1_bytes = ZX_ROM_FORMAT.magic.to_vec();
+        v1_bytes.extend(bitcode::encode(&rom));
+
+        let mut newer_bytes = rom.to_bytes().unwrap();
+        newer_bytes[4..8].copy_from_slice(&(ZX_ROM_FORMAT.version + 1).to_le_bytes());
+
+        for bytes in [v1_bytes, newer_bytes, ZX_ROM_FORMAT.magic.to_vec()] {
+            let err = ZXRom::from_bytes(&bytes).unwrap_err().to_string();
+            assert!(err.contains("rebuild the ROM with `nether pack`"), "{err}");
+        }
+    }
+
     #[test]
     fn test_validation_empty_id() {
         let mut rom = create_test_rom();
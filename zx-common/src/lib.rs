@@ -33,36 +33,59 @@ pub use packing::{
 
 // Re-export commonly used format items
 pub use formats::{
+    // Data pack types
+    AtlasSprite,
     BONE_TRANSFORM_SIZE,
     BoneTransform,
+    CUBEMAP_FACES,
     INVERSE_BIND_MATRIX_SIZE,
+    MAX_MORPH_TARGETS,
+    MeshSocket,
+    MorphTarget,
     NetherZXAnimationHeader,
     // Mesh/texture/skeleton types
     NetherZXMeshHeader,
     NetherZXSkeletonHeader,
     NetherZXTextureHeader,
+    // Override pack types
+    OVERRIDE_PACK_EXTENSION,
+    OVERRIDE_PACK_MAGIC,
+    OVERRIDE_PACK_VERSION,
+    OverrideReport,
+    PATH_SAMPLES_PER_SEGMENT,
     PLATFORM_BONE_KEYFRAME_SIZE,
-    // Data pack types
+    PackedAtlas,
+    PackedCubemap,
     PackedData,
     PackedFont,
     PackedGlyph,
     PackedKeyframes,
     PackedMesh,
+    PackedPath,
     PackedSkeleton,
     PackedSound,
+    PackedStream,
+    PackedStrings,
     PackedTexture,
     PackedTracker,
+    PathCurve,
+    PathKind,
     PlatformBoneKeyframe,
     // ROM format constants (from nethercore_shared)
     RomFormat,
     SAMPLE_RATE,
+    StringTable,
     TextureFormat,
     TrackerFormat,
     ZX_ROM_FORMAT,
     ZXDataPack,
     // ROM types
     ZXMetadata,
+    ZXOverridePack,
     ZXRom,
+    // Cubemap face layout
+    cube_face_direction,
+    cube_face_uv,
     // Animation types
     decode_bone_transform,
     decode_quat_smallest_three,
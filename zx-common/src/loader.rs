@@ -119,6 +119,7 @@ impl RomLoader for ZXRomLoader {
 mod tests {
     use super::*;
     use crate::ZXMetadata;
+    use nethercore_shared::capabilities::Capabilities;
     use nethercore_shared::netplay::NetplayMetadata;
     use std::path::PathBuf;
     use tempfile::TempDir;
@@ -151,6 +152,8 @@ mod tests {
                 render_mode: Some(2),
                 default_resolution: Some("640x480".to_string()),
                 target_fps: Some(60),
+                allow_override_packs: false,
+                capabilities: Capabilities::default(),
                 netplay: NetplayMetadata {
                     max_players: 1,
                     ..Default::default()